@@ -0,0 +1,42 @@
+extern crate xcb;
+
+use xcb::proto::xfixes::{self, Xfixes};
+use xcb::xfixes::{query_version, select_selection_input, SelectionEvents};
+use xcb::{
+    xcb_connect, xcb_disconnect, xcb_flush, xcb_get_setup, xcb_intern_atom, xcb_screen,
+    xcb_setup_roots_iterator, Event,
+};
+
+fn main() {
+    let connection = match xcb_connect(None, None) {
+        Ok(connection) => connection,
+        Err(_) => panic!("Couldn't establish connection to X11 Server"),
+    };
+
+    match connection.extension::<Xfixes>() {
+        Some(ref data) if data.present != 0 => {}
+        _ => panic!("The X11 Server doesn't support XFixes"),
+    }
+    // The server only accepts XFixes requests after the client has told it which version it
+    // speaks.
+    let (major, minor) = query_version(&connection).expect("Couldn't query the XFixes version");
+    println!("XFixes {}.{}", major, minor);
+
+    let setup = xcb_get_setup(&connection);
+    let iter = xcb_setup_roots_iterator(&setup);
+    let screen = xcb_screen(&iter);
+    let clipboard = xcb_intern_atom(&connection, false, b"CLIPBOARD")
+        .reply(&connection)
+        .expect("Couldn't intern CLIPBOARD")
+        .atom;
+
+    select_selection_input(&connection, screen.root, clipboard, SelectionEvents::ALL);
+    xcb_flush(&connection);
+
+    while let Some(event) = connection.wait_for_event() {
+        if let Event::Xfixes(xfixes::Event::SelectionNotify(notify)) = event {
+            println!("CLIPBOARD is now owned by window {:#x}", notify.owner);
+        }
+    }
+    xcb_disconnect(connection);
+}
@@ -2,7 +2,7 @@ extern crate xcb;
 
 use xcb::{
     xcb_connect, xcb_create_window, xcb_disconnect, xcb_flush, xcb_generate_id, xcb_get_setup,
-    xcb_map_window, xcb_screen, xcb_setup_roots_iterator,
+    xcb_map_window, xcb_screen, xcb_setup_roots_iterator, Event,
 };
 
 fn main() {
@@ -28,10 +28,17 @@ fn main() {
         xcb::constants::XCB_WINDOW_CLASS_INPUT_OUTPUT,
         screen.root_visual,
         0,
-        None,
+        &[],
     );
+    connection
+        .enable_close_requests(window_id)
+        .expect("Couldn't set WM_PROTOCOLS");
     xcb_map_window(&connection, window_id);
     xcb_flush(&connection);
-    loop {}
+    while let Some(event) = connection.wait_for_event() {
+        if let Event::CloseRequested { .. } = event {
+            break;
+        }
+    }
     xcb_disconnect(connection);
 }
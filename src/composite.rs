@@ -0,0 +1,140 @@
+//! Redirection of windows to off-screen storage with the Composite extension, undone when the
+//! handles are dropped.
+
+use proto::composite::constants::{
+    XCB_COMPOSITE_REDIRECT_AUTOMATIC, XCB_COMPOSITE_REDIRECT_MANUAL,
+};
+use proto::composite::{
+    xcb_composite_get_overlay_window, xcb_composite_name_window_pixmap,
+    xcb_composite_redirect_subwindows, xcb_composite_redirect_window,
+    xcb_composite_release_overlay_window, xcb_composite_unredirect_subwindows,
+    xcb_composite_unredirect_window,
+};
+use proto::xproto::{XCBPixmap, XCBWindow};
+use {xcb_generate_id, XCBConnection, XError};
+
+/// Who draws the contents of redirected windows to their parent.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Update {
+    /// The server keeps drawing the windows as if they were not redirected.
+    Automatic,
+    /// The client drawing the windows is in charge, only one client can do so at a time.
+    Manual,
+}
+
+impl Update {
+    fn raw(self) -> u8 {
+        match self {
+            Update::Automatic => XCB_COMPOSITE_REDIRECT_AUTOMATIC,
+            Update::Manual => XCB_COMPOSITE_REDIRECT_MANUAL,
+        }
+    }
+}
+
+/// A window, or all children of a window, redirected to off-screen storage.
+///
+/// The redirection is undone when this is dropped.
+pub struct Redirection<'a> {
+    connection: &'a XCBConnection,
+    window: XCBWindow,
+    update: Update,
+    subwindows: bool,
+}
+
+impl<'a> Redirection<'a> {
+    /// Redirects the hierarchy of `window` to off-screen storage.
+    pub fn window(
+        connection: &'a XCBConnection,
+        window: XCBWindow,
+        update: Update,
+    ) -> Redirection<'a> {
+        xcb_composite_redirect_window(connection, window, update.raw());
+        Redirection {
+            connection,
+            window,
+            update,
+            subwindows: false,
+        }
+    }
+
+    /// Redirects all current and future children of `window` to off-screen storage, as
+    /// compositing managers do with the root window.
+    pub fn subwindows(
+        connection: &'a XCBConnection,
+        window: XCBWindow,
+        update: Update,
+    ) -> Redirection<'a> {
+        xcb_composite_redirect_subwindows(connection, window, update.raw());
+        Redirection {
+            connection,
+            window,
+            update,
+            subwindows: true,
+        }
+    }
+
+    /// The window passed when redirecting.
+    pub fn window_id(&self) -> XCBWindow {
+        self.window
+    }
+
+    /// The update mode passed when redirecting.
+    pub fn update(&self) -> Update {
+        self.update
+    }
+}
+
+impl<'a> Drop for Redirection<'a> {
+    fn drop(&mut self) {
+        if self.subwindows {
+            xcb_composite_unredirect_subwindows(self.connection, self.window, self.update.raw());
+        } else {
+            xcb_composite_unredirect_window(self.connection, self.window, self.update.raw());
+        }
+    }
+}
+
+/// Names the off-screen storage of a redirected window as a pixmap.
+///
+/// The pixmap stays valid after the window has been resized or unmapped, in which case the
+/// window gets new storage that has to be named again. It has to be freed with `xcb_free_pixmap`.
+pub fn name_window_pixmap(connection: &XCBConnection, window: XCBWindow) -> XCBPixmap {
+    let pixmap = xcb_generate_id(connection);
+    xcb_composite_name_window_pixmap(connection, window, pixmap);
+    pixmap
+}
+
+/// The overlay window of a screen, which lies above all other windows and below the screen
+/// saver, for compositing managers to draw into.
+///
+/// The overlay window is released when this is dropped, and unmapped by the server once no
+/// client holds it anymore.
+pub struct OverlayWindow<'a> {
+    connection: &'a XCBConnection,
+    window: XCBWindow,
+}
+
+impl<'a> OverlayWindow<'a> {
+    /// Gets the overlay window of the screen `window` belongs to, mapping it if necessary.
+    pub fn get(
+        connection: &'a XCBConnection,
+        window: XCBWindow,
+    ) -> Result<OverlayWindow<'a>, XError> {
+        let reply = xcb_composite_get_overlay_window(connection, window).reply(connection)?;
+        Ok(OverlayWindow {
+            connection,
+            window: reply.overlay_win,
+        })
+    }
+
+    /// The overlay window.
+    pub fn window(&self) -> XCBWindow {
+        self.window
+    }
+}
+
+impl<'a> Drop for OverlayWindow<'a> {
+    fn drop(&mut self) {
+        xcb_composite_release_overlay_window(self.connection, self.window);
+    }
+}
@@ -1,5 +1,7 @@
 use std::os::raw::{c_long, c_uchar, c_uint, c_ushort};
 
+pub use proto::xproto::constants::*;
+
 pub const XCB_COPY_FROM_PARENT: c_long = 0;
 pub const XCB_WINDOW_CLASS_INPUT_OUTPUT: c_ushort = 1;
 
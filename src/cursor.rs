@@ -0,0 +1,669 @@
+//! Cursors: the core requests, the cursors of the `cursor` font and the cursor themes of
+//! Xcursor.
+//!
+//! A theme is a directory whose `cursors` subdirectory holds an Xcursor file per cursor name,
+//! e.g. `left_ptr`, and which may inherit the cursors of other themes. With the `render`
+//! feature, `Cursors` loads the cursors of the theme chosen by the user as ARGB cursors.
+
+use std::cmp;
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proto::xproto::{
+    xcb_close_font, xcb_create_cursor, xcb_create_glyph_cursor, xcb_free_cursor, xcb_open_font,
+    xcb_recolor_cursor, XCBCursor, XCBPixmap, XCBScreen,
+};
+use resources::Database;
+use {xcb_generate_id, XCBConnection, XCBVoidCookie};
+
+/// The names of the cursors of the `cursor` font, whose shape is twice the index. The glyph
+/// following each shape is its mask.
+const FONT_CURSORS: [&str; 77] = [
+    "X_cursor",
+    "arrow",
+    "based_arrow_down",
+    "based_arrow_up",
+    "boat",
+    "bogosity",
+    "bottom_left_corner",
+    "bottom_right_corner",
+    "bottom_side",
+    "bottom_tee",
+    "box_spiral",
+    "center_ptr",
+    "circle",
+    "clock",
+    "coffee_mug",
+    "cross",
+    "cross_reverse",
+    "crosshair",
+    "diamond_cross",
+    "dot",
+    "dotbox",
+    "double_arrow",
+    "draft_large",
+    "draft_small",
+    "draped_box",
+    "exchange",
+    "fleur",
+    "gobbler",
+    "gumby",
+    "hand1",
+    "hand2",
+    "heart",
+    "icon",
+    "iron_cross",
+    "left_ptr",
+    "left_side",
+    "left_tee",
+    "leftbutton",
+    "ll_angle",
+    "lr_angle",
+    "man",
+    "middlebutton",
+    "mouse",
+    "pencil",
+    "pirate",
+    "plus",
+    "question_arrow",
+    "right_ptr",
+    "right_side",
+    "right_tee",
+    "rightbutton",
+    "rtl_logo",
+    "sailboat",
+    "sb_down_arrow",
+    "sb_h_double_arrow",
+    "sb_left_arrow",
+    "sb_right_arrow",
+    "sb_up_arrow",
+    "sb_v_double_arrow",
+    "shuttle",
+    "sizing",
+    "spider",
+    "spraycan",
+    "star",
+    "target",
+    "tcross",
+    "top_left_arrow",
+    "top_left_corner",
+    "top_right_corner",
+    "top_side",
+    "top_tee",
+    "trek",
+    "ul_angle",
+    "umbrella",
+    "ur_angle",
+    "watch",
+    "xterm",
+];
+
+/// The directories searched for themes if `XCURSOR_PATH` is not set.
+const DEFAULT_PATH: &str =
+    "~/.local/share/icons:~/.icons:/usr/share/icons:/usr/share/pixmaps:/usr/X11R6/lib/X11/icons";
+
+/// How deeply themes may inherit from each other.
+const MAX_INHERIT_DEPTH: u32 = 16;
+
+/// Marks Xcursor files.
+const XCURSOR_MAGIC: &[u8] = b"Xcur";
+/// The type of the chunks of Xcursor files holding images.
+const XCURSOR_IMAGE_TYPE: u32 = 0xfffd_0002;
+
+/// A color of a core cursor, with 16 bits per component.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Color {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+}
+
+impl Color {
+    pub const BLACK: Color = Color {
+        red: 0,
+        green: 0,
+        blue: 0,
+    };
+    pub const WHITE: Color = Color {
+        red: 0xffff,
+        green: 0xffff,
+        blue: 0xffff,
+    };
+}
+
+/// Creates a cursor from bitmaps, showing `foreground` where `source` is set and `background`
+/// elsewhere, within the set bits of `mask` or everywhere without mask.
+///
+/// # Return value
+/// The cursor, with its hotspot at `x` and `y`.
+pub fn create_cursor(
+    connection: &XCBConnection,
+    source: XCBPixmap,
+    mask: Option<XCBPixmap>,
+    foreground: Color,
+    background: Color,
+    x: u16,
+    y: u16,
+) -> XCBCursor {
+    let cursor = xcb_generate_id(connection);
+    xcb_create_cursor(
+        connection,
+        cursor,
+        source,
+        mask.unwrap_or(0),
+        foreground.red,
+        foreground.green,
+        foreground.blue,
+        background.red,
+        background.green,
+        background.blue,
+        x,
+        y,
+    );
+    cursor
+}
+
+/// Returns the glyph of a cursor of the `cursor` font, e.g. `left_ptr` or `xterm`.
+pub fn font_shape(name: &str) -> Option<u16> {
+    FONT_CURSORS
+        .iter()
+        .position(|&font_cursor| font_cursor == name)
+        .map(|index| index as u16 * 2)
+}
+
+/// Creates a cursor from the glyph of the `cursor` font named `name`, e.g. `left_ptr` or
+/// `xterm`, which is drawn with the glyph following it as mask.
+///
+/// # Return value
+/// The cursor, or `None` if the font has no cursor of that name.
+pub fn create_glyph_cursor(
+    connection: &XCBConnection,
+    name: &str,
+    foreground: Color,
+    background: Color,
+) -> Option<XCBCursor> {
+    let shape = font_shape(name)?;
+    let font = xcb_generate_id(connection);
+    xcb_open_font(connection, font, b"cursor");
+    let cursor = xcb_generate_id(connection);
+    xcb_create_glyph_cursor(
+        connection,
+        cursor,
+        font,
+        font,
+        shape,
+        shape + 1,
+        foreground.red,
+        foreground.green,
+        foreground.blue,
+        background.red,
+        background.green,
+        background.blue,
+    );
+    // The cursor keeps the glyphs it was created from.
+    xcb_close_font(connection, font);
+    Some(cursor)
+}
+
+pub fn free_cursor(connection: &XCBConnection, cursor: XCBCursor) -> XCBVoidCookie {
+    xcb_free_cursor(connection, cursor)
+}
+
+/// Changes the colors of a core cursor, which has no effect on ARGB cursors.
+pub fn recolor_cursor(
+    connection: &XCBConnection,
+    cursor: XCBCursor,
+    foreground: Color,
+    background: Color,
+) -> XCBVoidCookie {
+    xcb_recolor_cursor(
+        connection,
+        cursor,
+        foreground.red,
+        foreground.green,
+        foreground.blue,
+        background.red,
+        background.green,
+        background.blue,
+    )
+}
+
+/// An image of an Xcursor file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    /// The nominal size the image was drawn for.
+    pub size: u32,
+    pub width: u32,
+    pub height: u32,
+    pub xhot: u32,
+    pub yhot: u32,
+    /// How long the image is shown in an animation, in milliseconds.
+    pub delay: u32,
+    /// The pixels in rows, as ARGB with premultiplied alpha.
+    pub pixels: Vec<u32>,
+}
+
+/// Reads the little endian word at `offset`.
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    let word = bytes.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_le_bytes([word[0], word[1], word[2], word[3]]))
+}
+
+/// Parses an Xcursor file.
+///
+/// # Return value
+/// The images of the file in their order, or `None` if it is malformed.
+pub fn parse_xcursor(bytes: &[u8]) -> Option<Vec<Image>> {
+    if !bytes.starts_with(XCURSOR_MAGIC) {
+        return None;
+    }
+    let header_size = read_u32(bytes, 4)? as usize;
+    let entries = read_u32(bytes, 12)? as usize;
+    let mut images = Vec::new();
+    for entry in 0..entries {
+        // Each entry of the table of contents holds the type, subtype and position of a chunk.
+        let entry = header_size.checked_add(entry.checked_mul(12)?)?;
+        if read_u32(bytes, entry)? != XCURSOR_IMAGE_TYPE {
+            continue;
+        }
+        let chunk = read_u32(bytes, entry + 8)? as usize;
+        let chunk_header_size = read_u32(bytes, chunk)? as usize;
+        let width = read_u32(bytes, chunk + 16)?;
+        let height = read_u32(bytes, chunk + 20)?;
+        let xhot = read_u32(bytes, chunk + 24)?;
+        let yhot = read_u32(bytes, chunk + 28)?;
+        // Like libXcursor, images have to be at least one pixel large with the hotspot within.
+        if width == 0 || height == 0 || width > 0x7fff || height > 0x7fff {
+            return None;
+        }
+        if xhot > width || yhot > height {
+            return None;
+        }
+        let pixels_start = chunk.checked_add(chunk_header_size)?;
+        let count = width as usize * height as usize;
+        if bytes.len() < pixels_start.checked_add(count * 4)? {
+            return None;
+        }
+        let pixels = (0..count)
+            .map(|pixel| read_u32(bytes, pixels_start + pixel * 4))
+            .collect::<Option<Vec<u32>>>()?;
+        images.push(Image {
+            size: read_u32(bytes, chunk + 8)?,
+            width,
+            height,
+            xhot,
+            yhot,
+            delay: read_u32(bytes, chunk + 32)?,
+            pixels,
+        });
+    }
+    Some(images)
+}
+
+/// Keeps the images of the nominal size closest to `size`, which are the frames of an
+/// animation if there is more than one.
+pub fn select_size(images: Vec<Image>, size: u32) -> Vec<Image> {
+    let best = match images
+        .iter()
+        .map(|image| image.size)
+        .min_by_key(|&candidate| (i64::from(candidate) - i64::from(size)).abs())
+    {
+        Some(best) => best,
+        None => return images,
+    };
+    images
+        .into_iter()
+        .filter(|image| image.size == best)
+        .collect()
+}
+
+/// A cursor theme and the size of its cursors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    pub name: String,
+    pub size: u32,
+    /// The directories containing the themes.
+    pub path: Vec<PathBuf>,
+}
+
+impl Theme {
+    /// Returns the theme chosen by the user, from `XCURSOR_THEME` and `XCURSOR_SIZE` or the
+    /// `Xcursor.theme` and `Xcursor.size` resources.
+    ///
+    /// Without a size it is derived from `Xft.dpi`, or else from the size of `screen`. The
+    /// themes are searched for in the directories of `XCURSOR_PATH`.
+    pub fn from_environment(resources: &Database, screen: &XCBScreen) -> Theme {
+        let name = env::var("XCURSOR_THEME")
+            .ok()
+            .or_else(|| resources.get("Xcursor.theme").map(str::to_owned))
+            .unwrap_or_else(|| "default".to_owned());
+        let size = env::var("XCURSOR_SIZE")
+            .ok()
+            .or_else(|| resources.get("Xcursor.size").map(str::to_owned))
+            .and_then(|size| size.trim().parse().ok())
+            .filter(|&size| size > 0)
+            .or_else(|| {
+                let dpi: u32 = resources.get("Xft.dpi")?.trim().parse().ok()?;
+                Some(dpi * 16 / 72).filter(|&size| size > 0)
+            })
+            .unwrap_or_else(|| {
+                u32::from(cmp::min(screen.width_in_pixels, screen.height_in_pixels)) / 48
+            });
+        let path = env::var("XCURSOR_PATH").unwrap_or_else(|_| DEFAULT_PATH.to_owned());
+        let home = env::var_os("HOME").map(PathBuf::from);
+        let path = path
+            .split(':')
+            .filter(|directory| !directory.is_empty())
+            .filter_map(|directory| match directory.strip_prefix('~') {
+                Some(rest) => home
+                    .as_ref()
+                    .map(|home| home.join(rest.trim_start_matches('/'))),
+                None => Some(PathBuf::from(directory)),
+            })
+            .collect();
+        Theme { name, size, path }
+    }
+
+    /// Returns the images of a cursor in the size of the theme, looking into the inherited
+    /// themes and the `default` theme if the theme lacks it.
+    pub fn load_images(&self, name: &str) -> Option<Vec<Image>> {
+        let mut visited = HashSet::new();
+        let file = self
+            .find_file(&self.name, name, &mut visited, 0)
+            .or_else(|| self.find_file("default", name, &mut visited, 0))?;
+        let images = parse_xcursor(&fs::read(file).ok()?)?;
+        Some(select_size(images, self.size)).filter(|images| !images.is_empty())
+    }
+
+    fn find_file(
+        &self,
+        theme: &str,
+        name: &str,
+        visited: &mut HashSet<String>,
+        depth: u32,
+    ) -> Option<PathBuf> {
+        if depth > MAX_INHERIT_DEPTH || !visited.insert(theme.to_owned()) {
+            return None;
+        }
+        for directory in &self.path {
+            let file = directory.join(theme).join("cursors").join(name);
+            if file.is_file() {
+                return Some(file);
+            }
+        }
+        for directory in &self.path {
+            for inherited in inherits(&directory.join(theme).join("index.theme")) {
+                if let Some(file) = self.find_file(&inherited, name, visited, depth + 1) {
+                    return Some(file);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Returns the themes listed by the `Inherits` key of an `index.theme` file.
+fn inherits(index: &Path) -> Vec<String> {
+    let text = match fs::read_to_string(index) {
+        Ok(text) => text,
+        Err(_) => return Vec::new(),
+    };
+    text.lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            Some(value).filter(|_| key.trim() == "Inherits")
+        })
+        .flat_map(|value| value.split([',', ';']))
+        .map(|theme| theme.trim().to_owned())
+        .filter(|theme| !theme.is_empty())
+        .collect()
+}
+
+#[cfg(feature = "render")]
+pub use self::themed::Cursors;
+
+#[cfg(feature = "render")]
+mod themed {
+    use std::cmp;
+    use std::collections::HashMap;
+
+    use super::{create_glyph_cursor, free_cursor, Color, Image, Theme};
+    use proto::render::{
+        xcb_render_create_anim_cursor, xcb_render_create_cursor, xcb_render_create_picture,
+        xcb_render_free_picture, xcb_render_query_pict_formats, xcb_render_query_version, Render,
+        XCBRenderAnimcursorelt, XCBRenderPictformat,
+    };
+    use proto::xproto::constants::XCB_IMAGE_FORMAT_Z_PIXMAP;
+    use proto::xproto::{
+        xcb_create_gc, xcb_create_pixmap, xcb_free_gc, xcb_free_pixmap, xcb_put_image, XCBCursor,
+        XCBScreen, XCBWindow,
+    };
+    use render::{find_standard_format, PictStandard};
+    use resources::Database;
+    use {xcb_generate_id, xcb_get_setup, XCBConnection, XError};
+
+    /// Loads the cursors of a theme as ARGB cursors through RENDER, falling back to the cursors
+    /// of the `cursor` font if the server lacks RENDER 0.5 or the theme lacks a cursor.
+    ///
+    /// The cursors are kept until the loader is dropped.
+    pub struct Cursors<'a> {
+        connection: &'a XCBConnection,
+        root: XCBWindow,
+        theme: Theme,
+        /// The ARGB format, if the server supports ARGB cursors.
+        format: Option<XCBRenderPictformat>,
+        /// Whether the server supports animated cursors, which came with RENDER 0.8.
+        animated: bool,
+        cursors: HashMap<String, XCBCursor>,
+    }
+
+    impl<'a> Cursors<'a> {
+        /// Creates a loader for the theme chosen by the user, see `Theme::from_environment`.
+        pub fn new(
+            connection: &'a XCBConnection,
+            screen: &XCBScreen,
+            resources: &Database,
+        ) -> Result<Cursors<'a>, XError> {
+            let theme = Theme::from_environment(resources, screen);
+            Cursors::with_theme(connection, screen.root, theme)
+        }
+
+        pub fn with_theme(
+            connection: &'a XCBConnection,
+            root: XCBWindow,
+            theme: Theme,
+        ) -> Result<Cursors<'a>, XError> {
+            let mut format = None;
+            let mut animated = false;
+            if connection
+                .extension::<Render>()
+                .is_some_and(|data| data.present != 0)
+            {
+                let version = xcb_render_query_version(connection, 0, 11).reply(connection)?;
+                let version = (version.major_version, version.minor_version);
+                if version >= (0, 5) {
+                    let formats = xcb_render_query_pict_formats(connection).reply(connection)?;
+                    format = find_standard_format(&formats, PictStandard::Argb32)
+                        .map(|format| format.id);
+                }
+                animated = version >= (0, 8);
+            }
+            Ok(Cursors {
+                connection,
+                root,
+                theme,
+                format,
+                animated,
+                cursors: HashMap::new(),
+            })
+        }
+
+        pub fn theme(&self) -> &Theme {
+            &self.theme
+        }
+
+        /// Returns the cursor named `name`, e.g. `left_ptr` or `xterm`, loading it the first
+        /// time.
+        ///
+        /// # Return value
+        /// The cursor, or `None` if neither the theme nor the `cursor` font has it.
+        pub fn load(&mut self, name: &str) -> Option<XCBCursor> {
+            if let Some(&cursor) = self.cursors.get(name) {
+                return Some(cursor);
+            }
+            let themed = match self.format {
+                Some(format) => self
+                    .theme
+                    .load_images(name)
+                    .map(|images| self.create_argb_cursor(format, &images)),
+                None => None,
+            };
+            let cursor = match themed {
+                Some(cursor) => cursor,
+                None => create_glyph_cursor(self.connection, name, Color::BLACK, Color::WHITE)?,
+            };
+            self.cursors.insert(name.to_owned(), cursor);
+            Some(cursor)
+        }
+
+        /// Creates a cursor from the images of an Xcursor file, animated if there are several
+        /// and the server supports it.
+        fn create_argb_cursor(&self, format: XCBRenderPictformat, images: &[Image]) -> XCBCursor {
+            if images.len() == 1 || !self.animated {
+                return self.create_image_cursor(format, &images[0]);
+            }
+            let frames: Vec<XCBRenderAnimcursorelt> = images
+                .iter()
+                .map(|image| XCBRenderAnimcursorelt {
+                    cursor: self.create_image_cursor(format, image),
+                    delay: image.delay,
+                })
+                .collect();
+            let cursor = xcb_generate_id(self.connection);
+            xcb_render_create_anim_cursor(self.connection, cursor, &frames);
+            // The animation keeps the cursors of its frames.
+            for frame in &frames {
+                free_cursor(self.connection, frame.cursor);
+            }
+            cursor
+        }
+
+        fn create_image_cursor(&self, format: XCBRenderPictformat, image: &Image) -> XCBCursor {
+            let connection = self.connection;
+            let (width, height) = (image.width as u16, image.height as u16);
+            let pixmap = xcb_generate_id(connection);
+            xcb_create_pixmap(connection, 32, pixmap, self.root, width, height);
+            let gc = xcb_generate_id(connection);
+            xcb_create_gc(connection, gc, pixmap, 0, &[]);
+            // The image is uploaded in strips of rows that fit into a request, which takes 24
+            // bytes besides the data.
+            let maximum_request_length = xcb_get_setup(connection).maximum_request_length as usize;
+            let row_length = image.width as usize * 4;
+            let rows_per_request = cmp::max((maximum_request_length * 4 - 24) / row_length, 1);
+            for (strip, rows) in image
+                .pixels
+                .chunks(rows_per_request * image.width as usize)
+                .enumerate()
+            {
+                let data: Vec<u8> = rows.iter().flat_map(|pixel| pixel.to_ne_bytes()).collect();
+                xcb_put_image(
+                    connection,
+                    XCB_IMAGE_FORMAT_Z_PIXMAP,
+                    pixmap,
+                    gc,
+                    width,
+                    (rows.len() / image.width as usize) as u16,
+                    0,
+                    (strip * rows_per_request) as i16,
+                    0,
+                    32,
+                    &data,
+                );
+            }
+            let picture = xcb_generate_id(connection);
+            xcb_render_create_picture(connection, picture, pixmap, format, 0, &[]);
+            let cursor = xcb_generate_id(connection);
+            xcb_render_create_cursor(
+                connection,
+                cursor,
+                picture,
+                image.xhot as u16,
+                image.yhot as u16,
+            );
+            // The cursor keeps a copy of the picture.
+            xcb_render_free_picture(connection, picture);
+            xcb_free_gc(connection, gc);
+            xcb_free_pixmap(connection, pixmap);
+            cursor
+        }
+    }
+
+    impl<'a> Drop for Cursors<'a> {
+        fn drop(&mut self) {
+            for &cursor in self.cursors.values() {
+                free_cursor(self.connection, cursor);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds an Xcursor file with one image of nominal size 24.
+    fn xcursor(width: u32, height: u32, xhot: u32, yhot: u32, pixels: &[u32]) -> Vec<u8> {
+        let mut words = vec![16, 0x0001_0000, 1];
+        words.extend_from_slice(&[XCURSOR_IMAGE_TYPE, 24, 28]);
+        words.extend_from_slice(&[36, XCURSOR_IMAGE_TYPE, 24, 1, width, height, xhot, yhot, 50]);
+        words.extend_from_slice(pixels);
+        let mut bytes = XCURSOR_MAGIC.to_vec();
+        for word in words {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn valid() {
+        let bytes = xcursor(2, 1, 1, 1, &[0xff00_0000, 0xffff_ffff]);
+        assert_eq!(
+            parse_xcursor(&bytes),
+            Some(vec![Image {
+                size: 24,
+                width: 2,
+                height: 1,
+                xhot: 1,
+                yhot: 1,
+                delay: 50,
+                pixels: vec![0xff00_0000, 0xffff_ffff],
+            }])
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = xcursor(2, 1, 0, 0, &[0, 0]);
+        // Within the table of contents, the image chunk and the pixels.
+        for &length in &[20, 36, 60, bytes.len() - 1] {
+            assert_eq!(parse_xcursor(&bytes[..length]), None, "{} bytes", length);
+        }
+
+        // The table of contents lists more entries than the file holds.
+        let mut bytes = bytes;
+        bytes[12] = 100;
+        assert_eq!(parse_xcursor(&bytes), None);
+    }
+
+    #[test]
+    fn invalid_image() {
+        assert_eq!(parse_xcursor(&xcursor(0, 1, 0, 0, &[])), None);
+        assert_eq!(parse_xcursor(&xcursor(1, 0, 0, 0, &[])), None);
+        assert_eq!(parse_xcursor(&xcursor(0x8000, 1, 0, 0, &[])), None);
+        assert_eq!(parse_xcursor(&xcursor(1, 1, 2, 0, &[0])), None);
+        assert_eq!(parse_xcursor(&xcursor(1, 1, 0, 2, &[0])), None);
+        assert_eq!(parse_xcursor(b"Xcus"), None);
+    }
+}
@@ -0,0 +1,167 @@
+//! Tracking of the areas of drawables that have changed, with the Damage extension.
+//!
+//! A `Damage` object makes the server report changes of a drawable, and a `DamageAccumulator`
+//! collects the reported rectangles, together with those of `Expose` events, until the
+//! drawables are repainted.
+//!
+//! The server only accepts Damage requests once `query_version` has been called. Regions passed
+//! to `Damage::subtract` are XFixes regions, which additionally need `xfixes::query_version`.
+
+use std::collections::hash_map::{self, HashMap};
+
+use proto::damage::constants::{XCB_DAMAGE_MAJOR_VERSION, XCB_DAMAGE_MINOR_VERSION};
+use proto::damage::{
+    self, xcb_damage_create, xcb_damage_destroy, xcb_damage_query_version, xcb_damage_subtract,
+    XCBDamageDamage,
+};
+use proto::xfixes::constants::XCB_XFIXES_REGION_NONE;
+use proto::xfixes::XCBXfixesRegion;
+use proto::xproto::{self, XCBDrawable, XCBRectangle};
+use {xcb_generate_id, Event, XCBConnection, XError};
+
+/// Tells the server the version of Damage the client speaks, which it has to do before any
+/// other Damage request.
+///
+/// # Return value
+/// The major and minor version both sides support.
+pub fn query_version(connection: &XCBConnection) -> Result<(u32, u32), XError> {
+    let reply = xcb_damage_query_version(
+        connection,
+        XCB_DAMAGE_MAJOR_VERSION,
+        XCB_DAMAGE_MINOR_VERSION,
+    )
+    .reply(connection)?;
+    Ok((reply.major_version, reply.minor_version))
+}
+
+/// Bit set in the `level` of a `DamageNotify` event if more events for the same damage follow.
+pub const NOTIFY_MORE: u8 = 0x80;
+
+/// A Damage object monitoring a drawable, which is destroyed when dropped.
+pub struct Damage<'a> {
+    connection: &'a XCBConnection,
+    id: XCBDamageDamage,
+    drawable: XCBDrawable,
+}
+
+impl<'a> Damage<'a> {
+    /// Starts monitoring `drawable`, reporting the changes as described by `level`, one of the
+    /// `XCB_DAMAGE_REPORT_LEVEL_*` constants.
+    pub fn create(connection: &'a XCBConnection, drawable: XCBDrawable, level: u8) -> Damage<'a> {
+        let id = xcb_generate_id(connection);
+        xcb_damage_create(connection, id, drawable, level);
+        Damage {
+            connection,
+            id,
+            drawable,
+        }
+    }
+
+    /// The id the server knows the Damage object by.
+    pub fn id(&self) -> XCBDamageDamage {
+        self.id
+    }
+
+    /// The drawable being monitored.
+    pub fn drawable(&self) -> XCBDrawable {
+        self.drawable
+    }
+
+    /// Removes `repair` from the damaged area and stores what was removed in `parts`, unless it
+    /// is `XCB_XFIXES_REGION_NONE`.
+    pub fn subtract(&self, repair: XCBXfixesRegion, parts: XCBXfixesRegion) {
+        xcb_damage_subtract(self.connection, self.id, repair, parts);
+    }
+
+    /// Marks the whole drawable as repaired, so that further changes are reported again.
+    pub fn repair(&self) {
+        self.subtract(XCB_XFIXES_REGION_NONE, XCB_XFIXES_REGION_NONE);
+    }
+}
+
+impl<'a> Drop for Damage<'a> {
+    fn drop(&mut self) {
+        xcb_damage_destroy(self.connection, self.id);
+    }
+}
+
+/// The rectangles that need to be repainted, per drawable.
+#[derive(Default)]
+pub struct DamageAccumulator {
+    areas: HashMap<XCBDrawable, Vec<XCBRectangle>>,
+}
+
+impl DamageAccumulator {
+    pub fn new() -> DamageAccumulator {
+        DamageAccumulator {
+            areas: HashMap::new(),
+        }
+    }
+
+    /// Adds a damaged rectangle of `drawable`.
+    ///
+    /// Rectangles covered by others are dropped, so repeated damage of the same area does not
+    /// pile up.
+    pub fn add(&mut self, drawable: XCBDrawable, area: XCBRectangle) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let rectangles = self.areas.entry(drawable).or_default();
+        if rectangles
+            .iter()
+            .any(|rectangle| contains(rectangle, &area))
+        {
+            return;
+        }
+        rectangles.retain(|rectangle| !contains(&area, rectangle));
+        rectangles.push(area);
+    }
+
+    /// Adds the area reported by a `DamageNotify` or `Expose` event.
+    ///
+    /// # Return value
+    /// Whether the event was one of these.
+    pub fn handle(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::Damage(damage::Event::Notify(ref notify)) => {
+                self.add(notify.drawable, notify.area);
+                true
+            }
+            Event::Core(xproto::Event::Expose(ref expose)) => {
+                let area = XCBRectangle {
+                    x: expose.x as i16,
+                    y: expose.y as i16,
+                    width: expose.width,
+                    height: expose.height,
+                };
+                self.add(expose.window, area);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the damaged rectangles of `drawable` and forgets them.
+    pub fn take(&mut self, drawable: XCBDrawable) -> Vec<XCBRectangle> {
+        self.areas.remove(&drawable).unwrap_or_default()
+    }
+
+    /// Returns the damaged rectangles of all drawables and forgets them.
+    pub fn drain(&mut self) -> hash_map::Drain<'_, XCBDrawable, Vec<XCBRectangle>> {
+        self.areas.drain()
+    }
+
+    /// Whether no damage has been added since the rectangles were last taken.
+    pub fn is_empty(&self) -> bool {
+        self.areas.is_empty()
+    }
+}
+
+fn contains(outer: &XCBRectangle, inner: &XCBRectangle) -> bool {
+    let right = |rectangle: &XCBRectangle| i32::from(rectangle.x) + i32::from(rectangle.width);
+    let bottom = |rectangle: &XCBRectangle| i32::from(rectangle.y) + i32::from(rectangle.height);
+    outer.x <= inner.x
+        && outer.y <= inner.y
+        && right(outer) >= right(inner)
+        && bottom(outer) >= bottom(inner)
+}
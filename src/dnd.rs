@@ -0,0 +1,411 @@
+//! The XDND protocol for drag and drop between clients.
+//!
+//! The source of a drag sends client messages to the window under the pointer, which takes part
+//! in the protocol if it has the `XdndAware` property, and the target answers them. The dropped
+//! data is transferred through the `XdndSelection` selection, which the source offers and the
+//! target requests with a `selection::Clipboard`.
+
+use property;
+use proto::xproto::constants::{
+    XCB_ATOM_ATOM, XCB_ATOM_NONE, XCB_ATOM_WINDOW, XCB_EVENT_MASK_NO_EVENT, XCB_WINDOW_NONE,
+};
+use proto::xproto::{xcb_translate_coordinates, XCBClientMessageEvent};
+use send::{send_event, ClientMessage, ClientMessageData};
+use {intern_atoms, XCBAtom, XCBConnection, XCBTimestamp, XCBVoidCookie, XCBWindow, XError};
+
+/// The version of the protocol that is implemented.
+pub const VERSION: u8 = 5;
+
+/// The atoms of the XDND protocol.
+#[derive(Copy, Clone, Debug)]
+pub struct Atoms {
+    pub xdnd_aware: XCBAtom,
+    pub xdnd_proxy: XCBAtom,
+    pub xdnd_enter: XCBAtom,
+    pub xdnd_position: XCBAtom,
+    pub xdnd_status: XCBAtom,
+    pub xdnd_leave: XCBAtom,
+    pub xdnd_drop: XCBAtom,
+    pub xdnd_finished: XCBAtom,
+    pub xdnd_selection: XCBAtom,
+    pub xdnd_type_list: XCBAtom,
+    pub xdnd_action_list: XCBAtom,
+    pub xdnd_action_copy: XCBAtom,
+    pub xdnd_action_move: XCBAtom,
+    pub xdnd_action_link: XCBAtom,
+    pub xdnd_action_ask: XCBAtom,
+    pub xdnd_action_private: XCBAtom,
+}
+
+impl Atoms {
+    pub fn intern(connection: &XCBConnection) -> Result<Atoms, XError> {
+        let atoms = intern_atoms(
+            connection,
+            &[
+                "XdndAware",
+                "XdndProxy",
+                "XdndEnter",
+                "XdndPosition",
+                "XdndStatus",
+                "XdndLeave",
+                "XdndDrop",
+                "XdndFinished",
+                "XdndSelection",
+                "XdndTypeList",
+                "XdndActionList",
+                "XdndActionCopy",
+                "XdndActionMove",
+                "XdndActionLink",
+                "XdndActionAsk",
+                "XdndActionPrivate",
+            ],
+        )?;
+        Ok(Atoms {
+            xdnd_aware: atoms[0],
+            xdnd_proxy: atoms[1],
+            xdnd_enter: atoms[2],
+            xdnd_position: atoms[3],
+            xdnd_status: atoms[4],
+            xdnd_leave: atoms[5],
+            xdnd_drop: atoms[6],
+            xdnd_finished: atoms[7],
+            xdnd_selection: atoms[8],
+            xdnd_type_list: atoms[9],
+            xdnd_action_list: atoms[10],
+            xdnd_action_copy: atoms[11],
+            xdnd_action_move: atoms[12],
+            xdnd_action_link: atoms[13],
+            xdnd_action_ask: atoms[14],
+            xdnd_action_private: atoms[15],
+        })
+    }
+}
+
+/// A message of the XDND protocol. The messages of the source are sent to the target and name
+/// the source, those of the target are sent to the source and name the target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Message {
+    /// The pointer entered the target while dragging.
+    Enter {
+        source: XCBWindow,
+        /// The version used for the drag, the lower of those of the source and the target.
+        version: u8,
+        /// The first three types of the data, the remaining ones being listed in the
+        /// `XdndTypeList` property of the source if `more_types` is set.
+        types: Vec<XCBAtom>,
+        more_types: bool,
+    },
+    /// The pointer moved over the target.
+    Position {
+        source: XCBWindow,
+        /// The position of the pointer relative to the root window.
+        x: i16,
+        y: i16,
+        time: XCBTimestamp,
+        /// The action the user asks for, e.g. `XdndActionCopy`.
+        action: XCBAtom,
+    },
+    /// The target tells whether it accepts a drop at the last position.
+    Status {
+        target: XCBWindow,
+        accept: bool,
+        /// Whether the target wants a `Position` for every move. Otherwise the source leaves
+        /// out the moves within the rectangle, given relative to the root window.
+        want_position: bool,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        /// The action the target will perform, or `XCB_ATOM_NONE` if it does not accept.
+        action: XCBAtom,
+    },
+    /// The pointer left the target, or the drag was cancelled.
+    Leave { source: XCBWindow },
+    /// The data is dropped on the target, which requests it with the time of the drop.
+    Drop {
+        source: XCBWindow,
+        time: XCBTimestamp,
+    },
+    /// The target is done with a drop.
+    Finished {
+        target: XCBWindow,
+        success: bool,
+        /// The action the target performed, or `XCB_ATOM_NONE` if it failed.
+        action: XCBAtom,
+    },
+}
+
+/// Packs two coordinates or sizes into one word, the first one in the high half.
+fn pack(high: u16, low: u16) -> u32 {
+    (u32::from(high) << 16) | u32::from(low)
+}
+
+/// Sends and receives the messages of the XDND protocol and reads and writes its properties.
+pub struct Dnd<'a> {
+    connection: &'a XCBConnection,
+    atoms: Atoms,
+}
+
+impl<'a> Dnd<'a> {
+    /// Interns the atoms of the XDND protocol.
+    pub fn new(connection: &'a XCBConnection) -> Result<Dnd<'a>, XError> {
+        Ok(Dnd {
+            connection,
+            atoms: Atoms::intern(connection)?,
+        })
+    }
+
+    pub fn atoms(&self) -> &Atoms {
+        &self.atoms
+    }
+
+    /// Makes `window` a target of drags, which is required for top-level windows only.
+    pub fn set_aware(&self, window: XCBWindow) -> XCBVoidCookie {
+        property::set_words(
+            self.connection,
+            window,
+            self.atoms.xdnd_aware,
+            XCB_ATOM_ATOM,
+            &[u32::from(VERSION)],
+        )
+    }
+
+    /// Returns the version of the protocol `window` supports as a target, or `None` if it
+    /// does not take part in it.
+    pub fn get_aware(&self, window: XCBWindow) -> Result<Option<u8>, XError> {
+        let words = property::get_words(
+            self.connection,
+            window,
+            self.atoms.xdnd_aware,
+            XCB_ATOM_ATOM,
+        )?;
+        Ok(words.and_then(|words| words.first().map(|&version| version as u8)))
+    }
+
+    /// Finds the target of a drag while the pointer is at `x` and `y` of `root`: the outermost
+    /// window under the pointer that takes part in the protocol.
+    ///
+    /// # Return value
+    /// The window and its version, or `None` if no window under the pointer takes part.
+    pub fn find_target(
+        &self,
+        root: XCBWindow,
+        x: i16,
+        y: i16,
+    ) -> Result<Option<(XCBWindow, u8)>, XError> {
+        let mut window = root;
+        loop {
+            let reply = xcb_translate_coordinates(self.connection, root, window, x, y)
+                .reply(self.connection)?;
+            if reply.child == XCB_WINDOW_NONE {
+                return Ok(None);
+            }
+            window = reply.child;
+            if let Some(version) = self.get_aware(window)? {
+                return Ok(Some((window, version)));
+            }
+        }
+    }
+
+    /// Returns the window that receives the messages for `target`, which is the window named
+    /// by its `XdndProxy` property if it has one.
+    pub fn proxy(&self, target: XCBWindow) -> Result<XCBWindow, XError> {
+        let words = property::get_words(
+            self.connection,
+            target,
+            self.atoms.xdnd_proxy,
+            XCB_ATOM_WINDOW,
+        )?;
+        match words.as_ref().and_then(|words| words.first()) {
+            Some(&proxy) => Ok(proxy),
+            None => Ok(target),
+        }
+    }
+
+    /// Lists all types of the data of a drag in the `XdndTypeList` property of the source,
+    /// which is needed for more than three types.
+    pub fn set_type_list(&self, source: XCBWindow, types: &[XCBAtom]) -> XCBVoidCookie {
+        property::set_words(
+            self.connection,
+            source,
+            self.atoms.xdnd_type_list,
+            XCB_ATOM_ATOM,
+            types,
+        )
+    }
+
+    /// Returns all types of the data of a drag announced by an `Enter` message.
+    pub fn types(&self, enter: &Message) -> Result<Vec<XCBAtom>, XError> {
+        match *enter {
+            Message::Enter {
+                source,
+                ref types,
+                more_types: true,
+                ..
+            } => Ok(property::get_words(
+                self.connection,
+                source,
+                self.atoms.xdnd_type_list,
+                XCB_ATOM_ATOM,
+            )?
+            .unwrap_or_else(|| types.clone())),
+            Message::Enter { ref types, .. } => Ok(types.clone()),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Lists the actions the source offers for `XdndActionAsk` in its `XdndActionList`
+    /// property.
+    pub fn set_action_list(&self, source: XCBWindow, actions: &[XCBAtom]) -> XCBVoidCookie {
+        property::set_words(
+            self.connection,
+            source,
+            self.atoms.xdnd_action_list,
+            XCB_ATOM_ATOM,
+            actions,
+        )
+    }
+
+    /// Returns the actions the source offers for `XdndActionAsk`.
+    pub fn get_action_list(&self, source: XCBWindow) -> Result<Vec<XCBAtom>, XError> {
+        let words = property::get_words(
+            self.connection,
+            source,
+            self.atoms.xdnd_action_list,
+            XCB_ATOM_ATOM,
+        )?;
+        Ok(words.unwrap_or_default())
+    }
+
+    /// Sends a message to `window`, which is the target for the messages of the source and
+    /// the source for those of the target. The messages for a target go to `destination`,
+    /// which is the target itself unless it has a proxy, see `proxy`.
+    pub fn send(
+        &self,
+        destination: XCBWindow,
+        window: XCBWindow,
+        message: &Message,
+    ) -> XCBVoidCookie {
+        let atoms = &self.atoms;
+        let (type_, data) = match *message {
+            Message::Enter {
+                source,
+                version,
+                ref types,
+                more_types,
+            } => {
+                let mut data = [
+                    source,
+                    (u32::from(version) << 24) | more_types as u32,
+                    XCB_ATOM_NONE,
+                    XCB_ATOM_NONE,
+                    XCB_ATOM_NONE,
+                ];
+                for (word, &type_) in data[2..].iter_mut().zip(types) {
+                    *word = type_;
+                }
+                (atoms.xdnd_enter, data)
+            }
+            Message::Position {
+                source,
+                x,
+                y,
+                time,
+                action,
+            } => (
+                atoms.xdnd_position,
+                [source, 0, pack(x as u16, y as u16), time, action],
+            ),
+            Message::Status {
+                target,
+                accept,
+                want_position,
+                x,
+                y,
+                width,
+                height,
+                action,
+            } => (
+                atoms.xdnd_status,
+                [
+                    target,
+                    accept as u32 | (want_position as u32) << 1,
+                    pack(x as u16, y as u16),
+                    pack(width, height),
+                    action,
+                ],
+            ),
+            Message::Leave { source } => (atoms.xdnd_leave, [source, 0, 0, 0, 0]),
+            Message::Drop { source, time } => (atoms.xdnd_drop, [source, 0, time, 0, 0]),
+            Message::Finished {
+                target,
+                success,
+                action,
+            } => (atoms.xdnd_finished, [target, success as u32, action, 0, 0]),
+        };
+        let message = ClientMessage::new(window, type_, ClientMessageData::U32(data));
+        send_event(
+            self.connection,
+            false,
+            destination,
+            XCB_EVENT_MASK_NO_EVENT,
+            &message,
+        )
+    }
+
+    /// Decodes a client message as a message of the XDND protocol.
+    pub fn decode(&self, message: &XCBClientMessageEvent) -> Option<Message> {
+        let data = match ClientMessage::from_event(message)?.data {
+            ClientMessageData::U32(data) => data,
+            _ => return None,
+        };
+        let atoms = &self.atoms;
+        let message = if message.type_ == atoms.xdnd_enter {
+            Message::Enter {
+                source: data[0],
+                version: (data[1] >> 24) as u8,
+                types: data[2..]
+                    .iter()
+                    .cloned()
+                    .filter(|&type_| type_ != XCB_ATOM_NONE)
+                    .collect(),
+                more_types: data[1] & 1 != 0,
+            }
+        } else if message.type_ == atoms.xdnd_position {
+            Message::Position {
+                source: data[0],
+                x: (data[2] >> 16) as i16,
+                y: data[2] as i16,
+                time: data[3],
+                action: data[4],
+            }
+        } else if message.type_ == atoms.xdnd_status {
+            Message::Status {
+                target: data[0],
+                accept: data[1] & 1 != 0,
+                want_position: data[1] & 2 != 0,
+                x: (data[2] >> 16) as i16,
+                y: data[2] as i16,
+                width: (data[3] >> 16) as u16,
+                height: data[3] as u16,
+                action: data[4],
+            }
+        } else if message.type_ == atoms.xdnd_leave {
+            Message::Leave { source: data[0] }
+        } else if message.type_ == atoms.xdnd_drop {
+            Message::Drop {
+                source: data[0],
+                time: data[2],
+            }
+        } else if message.type_ == atoms.xdnd_finished {
+            Message::Finished {
+                target: data[0],
+                success: data[1] & 1 != 0,
+                action: data[2],
+            }
+        } else {
+            return None;
+        };
+        Some(message)
+    }
+}
@@ -0,0 +1,331 @@
+//! Decoding of the events received from the server into the types of the core protocol and of
+//! the enabled extensions.
+//!
+//! Extensions are assigned their event and error codes by the server, so the offsets reported by
+//! QueryExtension are used to tell which extension an event or error belongs to.
+
+use std::collections::HashMap;
+
+use property;
+use proto;
+use proto::xproto::constants::{
+    XCB_ATOM_ATOM, XCB_EVENT_MASK_SUBSTRUCTURE_NOTIFY, XCB_EVENT_MASK_SUBSTRUCTURE_REDIRECT,
+    XCB_PROP_MODE_APPEND,
+};
+use proto::xproto::{
+    xcb_change_property, xcb_get_geometry, xcb_setup_roots_iterator, XCBClientMessageEvent,
+    XCBDestroyNotifyEvent, XCBGeGenericEvent,
+};
+use send::{send_event, ClientMessage, ClientMessageData};
+use {
+    cdef, intern_atoms, xcb_flush, xcb_get_setup, CloseRequests, XCBConnection, XCBGenericError,
+    XCBGenericEvent, XCBWindow, XError,
+};
+
+/// Code of the events carrying errors of requests whose errors are not checked.
+const ERROR: u8 = 0;
+/// Code of the generic events used by extensions for events not fitting into 32 bytes.
+const GE_GENERIC: u8 = 35;
+/// Codes below this are used by the core protocol, those above it are assigned to extensions.
+const FIRST_EXTENSION_CODE: u8 = 64;
+
+macro_rules! events {
+    (
+        events: { $($feature:tt $variant:ident($event:ty) $module:ident :: $extension:ident,)* }
+        generic: { $($g_feature:tt $g_variant:ident($g_event:ty) $g_module:ident :: $g_extension:ident,)* }
+        errors: { $($e_feature:tt $e_module:ident :: $e_extension:ident,)* }
+    ) => {
+        /// An event of the core protocol or of one of the enabled extensions.
+        pub enum Event {
+            /// An event of the core protocol.
+            Core(proto::xproto::Event),
+            /// The window manager asks to close a window, which takes part in the
+            /// `WM_DELETE_WINDOW` protocol since `XCBConnection::enable_close_requests`.
+            CloseRequested { window: XCBWindow },
+            $(
+                #[cfg(feature = $feature)]
+                $variant($event),
+            )*
+            /// An error caused by a request whose errors are not checked.
+            Error(Box<XCBGenericError>),
+            /// An event that is not known, e.g. because the extension sending it is not enabled.
+            Unknown(Box<XCBGenericEvent>),
+        }
+
+        impl XCBConnection {
+            #[allow(unused_mut, unused_variables)]
+            fn decode_extension_event(&self, code: u8, mut event: Box<XCBGenericEvent>) -> Event {
+                $(
+                    #[cfg(feature = $feature)]
+                    {
+                        if let Some(data) = self.extension::<proto::$module::$extension>() {
+                            if data.present != 0 && code >= data.first_event {
+                                let number = code - data.first_event;
+                                match <$event>::decode(number, event) {
+                                    Ok(event) => return Event::$variant(event),
+                                    Err(unknown) => event = unknown,
+                                }
+                            }
+                        }
+                    }
+                )*
+                Event::Unknown(event)
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn decode_generic_event(
+                &self,
+                extension: u8,
+                event_type: u16,
+                mut event: Box<XCBGenericEvent>,
+            ) -> Event {
+                $(
+                    #[cfg(feature = $g_feature)]
+                    {
+                        if let Some(data) = self.extension::<proto::$g_module::$g_extension>() {
+                            if data.present != 0 && data.major_opcode == extension {
+                                match <$g_event>::decode_generic(event_type, event) {
+                                    Ok(event) => return Event::$g_variant(event),
+                                    Err(unknown) => event = unknown,
+                                }
+                            }
+                        }
+                    }
+                )*
+                Event::Unknown(event)
+            }
+
+            #[allow(unused_variables)]
+            fn extension_error_name(&self, code: u8) -> Option<&'static str> {
+                $(
+                    #[cfg(feature = $e_feature)]
+                    {
+                        if let Some(data) = self.extension::<proto::$e_module::$e_extension>() {
+                            if data.present != 0 && code >= data.first_error {
+                                let name = proto::$e_module::error_name(code - data.first_error);
+                                if name.is_some() {
+                                    return name;
+                                }
+                            }
+                        }
+                    }
+                )*
+                None
+            }
+        }
+    };
+}
+
+events! {
+    events: {
+        "damage" Damage(proto::damage::Event) damage::Damage,
+        "dri2" Dri2(proto::dri2::Event) dri2::Dri2,
+        "glx" Glx(proto::glx::Event) glx::Glx,
+        "present" Present(proto::present::Event) present::Present,
+        "randr" Randr(::randr::Event) randr::Randr,
+        "screensaver" Screensaver(proto::screensaver::Event) screensaver::Screensaver,
+        "shape" Shape(proto::shape::Event) shape::Shape,
+        "shm" Shm(proto::shm::Event) shm::Shm,
+        "sync" Sync(proto::sync::Event) sync::Sync,
+        "xfixes" Xfixes(proto::xfixes::Event) xfixes::Xfixes,
+        "xinput" Xinput(proto::xinput::Event) xinput::Xinput,
+        "xkb" Xkb(proto::xkb::Event) xkb::Xkb,
+        "xprint" Xprint(proto::xprint::Event) xprint::Xprint,
+        "xv" Xv(proto::xv::Event) xv::Xv,
+    }
+    generic: {
+        "present" Present(proto::present::Event) present::Present,
+        "xinput" Xinput(proto::xinput::Event) xinput::Xinput,
+    }
+    errors: {
+        "damage" damage::Damage,
+        "glx" glx::Glx,
+        "randr" randr::Randr,
+        "record" record::Record,
+        "render" render::Render,
+        "shm" shm::Shm,
+        "sync" sync::Sync,
+        "xfixes" xfixes::Xfixes,
+        "xinput" xinput::Xinput,
+        "xkb" xkb::Xkb,
+        "xprint" xprint::Xprint,
+        "xv" xv::Xv,
+    }
+}
+
+impl XCBConnection {
+    /// Sorts an event received from the server into the core protocol or the extension it
+    /// belongs to.
+    ///
+    /// Deciding whether an event belongs to an extension requires its QueryExtension data, which
+    /// is requested the first time an event with a code assigned to extensions is decoded,
+    /// unless the extension has been prefetched.
+    pub fn decode_event(&self, event: Box<XCBGenericEvent>) -> Event {
+        let code = event.response_type & 0x7f;
+        let raw = Box::into_raw(event);
+        unsafe {
+            match code {
+                ERROR => Event::Error(Box::from_raw(raw as *mut XCBGenericError)),
+                GE_GENERIC => {
+                    let (extension, event_type) = {
+                        let generic = &*(raw as *const XCBGeGenericEvent);
+                        (generic.extension, generic.event_type)
+                    };
+                    self.decode_generic_event(extension, event_type, Box::from_raw(raw))
+                }
+                code if code < FIRST_EXTENSION_CODE => {
+                    match proto::xproto::Event::decode(code, Box::from_raw(raw)) {
+                        Ok(proto::xproto::Event::ClientMessage(message)) => {
+                            self.decode_client_message(message)
+                        }
+                        Ok(proto::xproto::Event::DestroyNotify(notify)) => {
+                            self.forget_root(&notify);
+                            Event::Core(proto::xproto::Event::DestroyNotify(notify))
+                        }
+                        Ok(event) => Event::Core(event),
+                        Err(event) => Event::Unknown(event),
+                    }
+                }
+                code => self.decode_extension_event(code, Box::from_raw(raw)),
+            }
+        }
+    }
+
+    /// Makes `window` take part in the `WM_DELETE_WINDOW` and `_NET_WM_PING` protocols by
+    /// adding them to its `WM_PROTOCOLS`.
+    ///
+    /// Afterwards the requests of the window manager to close the window are decoded as
+    /// `Event::CloseRequested`, and its pings, which tell whether the client still responds,
+    /// are answered while they are decoded. On displays with several screens the root window
+    /// pongs are sent to is looked up here and forgotten when the `DestroyNotify` of the window
+    /// is decoded.
+    pub fn enable_close_requests(&self, window: XCBWindow) -> Result<(), XError> {
+        if self.close_requests.borrow().is_none() {
+            let atoms = intern_atoms(self, &["WM_PROTOCOLS", "WM_DELETE_WINDOW", "_NET_WM_PING"])?;
+            *self.close_requests.borrow_mut() = Some(CloseRequests {
+                wm_protocols: atoms[0],
+                wm_delete_window: atoms[1],
+                net_wm_ping: atoms[2],
+                roots: HashMap::new(),
+            });
+        }
+        if self.single_root().is_none() {
+            let root = xcb_get_geometry(self, window).reply(self)?.root;
+            let mut close_requests = self.close_requests.borrow_mut();
+            close_requests.as_mut().unwrap().roots.insert(window, root);
+        }
+        let close_requests = self.close_requests.borrow();
+        let close_requests = close_requests.as_ref().unwrap();
+
+        let protocols =
+            property::get_words(self, window, close_requests.wm_protocols, XCB_ATOM_ATOM)?
+                .unwrap_or_default();
+        let missing: Vec<u8> = [close_requests.wm_delete_window, close_requests.net_wm_ping]
+            .iter()
+            .filter(|atom| !protocols.contains(atom))
+            .flat_map(|atom| atom.to_ne_bytes())
+            .collect();
+        xcb_change_property(
+            self,
+            XCB_PROP_MODE_APPEND,
+            window,
+            close_requests.wm_protocols,
+            XCB_ATOM_ATOM,
+            32,
+            missing.len() as u32 / 4,
+            &missing,
+        );
+        Ok(())
+    }
+
+    /// Decodes a `ClientMessage` of the protocols set up by `enable_close_requests`, and
+    /// answers pings.
+    fn decode_client_message(&self, message: Box<XCBClientMessageEvent>) -> Event {
+        if let Some(ref close_requests) = *self.close_requests.borrow() {
+            let protocol = match ClientMessage::from_event(&message) {
+                Some(ClientMessage {
+                    type_,
+                    data: ClientMessageData::U32(data),
+                    ..
+                }) if type_ == close_requests.wm_protocols => Some(data[0]),
+                _ => None,
+            };
+            if protocol == Some(close_requests.wm_delete_window) {
+                return Event::CloseRequested {
+                    window: message.window,
+                };
+            }
+            if protocol == Some(close_requests.net_wm_ping) {
+                let root = self
+                    .single_root()
+                    .or_else(|| close_requests.roots.get(&message.window).cloned());
+                if let Some(root) = root {
+                    // The pong is the ping sent back to the root window.
+                    let mut pong = *message;
+                    pong.window = root;
+                    send_event(
+                        self,
+                        false,
+                        root,
+                        XCB_EVENT_MASK_SUBSTRUCTURE_NOTIFY | XCB_EVENT_MASK_SUBSTRUCTURE_REDIRECT,
+                        &pong,
+                    );
+                    xcb_flush(self);
+                }
+            }
+        }
+        Event::Core(proto::xproto::Event::ClientMessage(message))
+    }
+
+    /// Returns the root window if the display has a single screen, so that it is the root of
+    /// every window.
+    fn single_root(&self) -> Option<XCBWindow> {
+        let setup = xcb_get_setup(self);
+        let iter = xcb_setup_roots_iterator(&setup);
+        if iter.rem == 1 {
+            Some(unsafe { (*iter.data).root })
+        } else {
+            None
+        }
+    }
+
+    /// Forgets the root window recorded for a destroyed window by `enable_close_requests`.
+    fn forget_root(&self, notify: &XCBDestroyNotifyEvent) {
+        if let Some(ref mut close_requests) = *self.close_requests.borrow_mut() {
+            close_requests.roots.remove(&notify.window);
+        }
+    }
+
+    /// Blocks until the next event arrives and decodes it.
+    ///
+    /// # Return value
+    /// The event, or `None` if the connection has been shut down.
+    pub fn wait_for_event(&self) -> Option<Event> {
+        let event = unsafe { cdef::xcb_wait_for_event(self.raw) };
+        if event.is_null() {
+            None
+        } else {
+            Some(self.decode_event(unsafe { Box::from_raw(event) }))
+        }
+    }
+
+    /// Returns and decodes the next event if one has arrived already.
+    pub fn poll_for_event(&self) -> Option<Event> {
+        let event = unsafe { cdef::xcb_poll_for_event(self.raw) };
+        if event.is_null() {
+            None
+        } else {
+            Some(self.decode_event(unsafe { Box::from_raw(event) }))
+        }
+    }
+
+    /// Returns the name of an error of the core protocol or of one of the enabled extensions,
+    /// e.g. `Window` or `BadOutput`.
+    pub fn error_name(&self, error: &XCBGenericError) -> Option<&'static str> {
+        if error.error_code < 128 {
+            proto::xproto::error_name(error.error_code)
+        } else {
+            self.extension_error_name(error.error_code)
+        }
+    }
+}
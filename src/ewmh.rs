@@ -0,0 +1,706 @@
+//! Typed access to the properties and client messages of the Extended Window Manager Hints.
+//!
+//! `Ewmh` serves both sides of the protocol: clients such as panels read the properties of the
+//! root window and ask the window manager for changes with client messages, which the window
+//! manager decodes with `Ewmh::decode_request` before updating the properties.
+
+use property;
+use proto::xproto::constants::{
+    XCB_ATOM_ATOM, XCB_ATOM_CARDINAL, XCB_ATOM_WINDOW, XCB_EVENT_MASK_SUBSTRUCTURE_NOTIFY,
+    XCB_EVENT_MASK_SUBSTRUCTURE_REDIRECT,
+};
+use proto::xproto::{xcb_get_property_value, XCBClientMessageEvent};
+use send::{send_event, ClientMessage, ClientMessageData};
+use {intern_atoms, XCBAtom, XCBConnection, XCBTimestamp, XCBVoidCookie, XCBWindow, XError};
+
+macro_rules! atoms {
+    ($($field:ident: $name:expr,)*) => {
+        /// The atoms of the EWMH, interned at once.
+        #[derive(Copy, Clone, Debug)]
+        pub struct Atoms {
+            $(pub $field: XCBAtom,)*
+        }
+
+        impl Atoms {
+            pub fn intern(connection: &XCBConnection) -> Result<Atoms, XError> {
+                let mut atoms = intern_atoms(connection, &[$($name),*])?.into_iter();
+                Ok(Atoms {
+                    $($field: atoms.next().unwrap(),)*
+                })
+            }
+        }
+    };
+}
+
+atoms! {
+    utf8_string: "UTF8_STRING",
+    net_supported: "_NET_SUPPORTED",
+    net_client_list: "_NET_CLIENT_LIST",
+    net_client_list_stacking: "_NET_CLIENT_LIST_STACKING",
+    net_number_of_desktops: "_NET_NUMBER_OF_DESKTOPS",
+    net_current_desktop: "_NET_CURRENT_DESKTOP",
+    net_active_window: "_NET_ACTIVE_WINDOW",
+    net_wm_name: "_NET_WM_NAME",
+    net_wm_state: "_NET_WM_STATE",
+    net_wm_state_modal: "_NET_WM_STATE_MODAL",
+    net_wm_state_sticky: "_NET_WM_STATE_STICKY",
+    net_wm_state_maximized_vert: "_NET_WM_STATE_MAXIMIZED_VERT",
+    net_wm_state_maximized_horz: "_NET_WM_STATE_MAXIMIZED_HORZ",
+    net_wm_state_shaded: "_NET_WM_STATE_SHADED",
+    net_wm_state_skip_taskbar: "_NET_WM_STATE_SKIP_TASKBAR",
+    net_wm_state_skip_pager: "_NET_WM_STATE_SKIP_PAGER",
+    net_wm_state_hidden: "_NET_WM_STATE_HIDDEN",
+    net_wm_state_fullscreen: "_NET_WM_STATE_FULLSCREEN",
+    net_wm_state_above: "_NET_WM_STATE_ABOVE",
+    net_wm_state_below: "_NET_WM_STATE_BELOW",
+    net_wm_state_demands_attention: "_NET_WM_STATE_DEMANDS_ATTENTION",
+    net_wm_state_focused: "_NET_WM_STATE_FOCUSED",
+    net_wm_window_type: "_NET_WM_WINDOW_TYPE",
+    net_wm_window_type_desktop: "_NET_WM_WINDOW_TYPE_DESKTOP",
+    net_wm_window_type_dock: "_NET_WM_WINDOW_TYPE_DOCK",
+    net_wm_window_type_toolbar: "_NET_WM_WINDOW_TYPE_TOOLBAR",
+    net_wm_window_type_menu: "_NET_WM_WINDOW_TYPE_MENU",
+    net_wm_window_type_utility: "_NET_WM_WINDOW_TYPE_UTILITY",
+    net_wm_window_type_splash: "_NET_WM_WINDOW_TYPE_SPLASH",
+    net_wm_window_type_dialog: "_NET_WM_WINDOW_TYPE_DIALOG",
+    net_wm_window_type_dropdown_menu: "_NET_WM_WINDOW_TYPE_DROPDOWN_MENU",
+    net_wm_window_type_popup_menu: "_NET_WM_WINDOW_TYPE_POPUP_MENU",
+    net_wm_window_type_tooltip: "_NET_WM_WINDOW_TYPE_TOOLTIP",
+    net_wm_window_type_notification: "_NET_WM_WINDOW_TYPE_NOTIFICATION",
+    net_wm_window_type_combo: "_NET_WM_WINDOW_TYPE_COMBO",
+    net_wm_window_type_dnd: "_NET_WM_WINDOW_TYPE_DND",
+    net_wm_window_type_normal: "_NET_WM_WINDOW_TYPE_NORMAL",
+    net_wm_strut_partial: "_NET_WM_STRUT_PARTIAL",
+    net_wm_icon: "_NET_WM_ICON",
+    net_wm_pid: "_NET_WM_PID",
+    net_frame_extents: "_NET_FRAME_EXTENTS",
+    net_wm_moveresize: "_NET_WM_MOVERESIZE",
+}
+
+/// How a `_NET_WM_STATE` request changes the states.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StateAction {
+    Remove,
+    Add,
+    Toggle,
+}
+
+impl StateAction {
+    fn raw(self) -> u32 {
+        match self {
+            StateAction::Remove => 0,
+            StateAction::Add => 1,
+            StateAction::Toggle => 2,
+        }
+    }
+
+    fn from_raw(action: u32) -> Option<StateAction> {
+        match action {
+            0 => Some(StateAction::Remove),
+            1 => Some(StateAction::Add),
+            2 => Some(StateAction::Toggle),
+            _ => None,
+        }
+    }
+}
+
+/// Who sends a request, which lets the window manager treat requests of pagers and taskbars,
+/// which act on behalf of the user, differently from those of applications.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// A client that predates the source indication.
+    Legacy,
+    Application,
+    Pager,
+}
+
+impl Source {
+    fn raw(self) -> u32 {
+        match self {
+            Source::Legacy => 0,
+            Source::Application => 1,
+            Source::Pager => 2,
+        }
+    }
+
+    fn from_raw(source: u32) -> Source {
+        match source {
+            1 => Source::Application,
+            2 => Source::Pager,
+            _ => Source::Legacy,
+        }
+    }
+}
+
+/// The edge or corner a window is resized at, or whether it is moved, by `_NET_WM_MOVERESIZE`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MoveResize {
+    SizeTopLeft,
+    SizeTop,
+    SizeTopRight,
+    SizeRight,
+    SizeBottomRight,
+    SizeBottom,
+    SizeBottomLeft,
+    SizeLeft,
+    Move,
+    /// Resizing with the keyboard.
+    SizeKeyboard,
+    /// Moving with the keyboard.
+    MoveKeyboard,
+    /// Cancels a move or resize started before, e.g. because the button was released before
+    /// the window manager grabbed the pointer.
+    Cancel,
+}
+
+impl MoveResize {
+    const ALL: [MoveResize; 12] = [
+        MoveResize::SizeTopLeft,
+        MoveResize::SizeTop,
+        MoveResize::SizeTopRight,
+        MoveResize::SizeRight,
+        MoveResize::SizeBottomRight,
+        MoveResize::SizeBottom,
+        MoveResize::SizeBottomLeft,
+        MoveResize::SizeLeft,
+        MoveResize::Move,
+        MoveResize::SizeKeyboard,
+        MoveResize::MoveKeyboard,
+        MoveResize::Cancel,
+    ];
+
+    fn raw(self) -> u32 {
+        self as u32
+    }
+
+    fn from_raw(direction: u32) -> Option<MoveResize> {
+        MoveResize::ALL.get(direction as usize).cloned()
+    }
+}
+
+/// The space a window reserves at the edges of the screen, `_NET_WM_STRUT_PARTIAL`.
+///
+/// Each width is measured from the edge of the screen, and applies to the range of the edge
+/// between start and end.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StrutPartial {
+    pub left: u32,
+    pub right: u32,
+    pub top: u32,
+    pub bottom: u32,
+    pub left_start_y: u32,
+    pub left_end_y: u32,
+    pub right_start_y: u32,
+    pub right_end_y: u32,
+    pub top_start_x: u32,
+    pub top_end_x: u32,
+    pub bottom_start_x: u32,
+    pub bottom_end_x: u32,
+}
+
+impl StrutPartial {
+    fn from_words(words: &[u32]) -> Option<StrutPartial> {
+        if words.len() < 12 {
+            return None;
+        }
+        Some(StrutPartial {
+            left: words[0],
+            right: words[1],
+            top: words[2],
+            bottom: words[3],
+            left_start_y: words[4],
+            left_end_y: words[5],
+            right_start_y: words[6],
+            right_end_y: words[7],
+            top_start_x: words[8],
+            top_end_x: words[9],
+            bottom_start_x: words[10],
+            bottom_end_x: words[11],
+        })
+    }
+
+    fn to_words(self) -> [u32; 12] {
+        [
+            self.left,
+            self.right,
+            self.top,
+            self.bottom,
+            self.left_start_y,
+            self.left_end_y,
+            self.right_start_y,
+            self.right_end_y,
+            self.top_start_x,
+            self.top_end_x,
+            self.bottom_start_x,
+            self.bottom_end_x,
+        ]
+    }
+}
+
+/// The widths of the frame the window manager added around a window, `_NET_FRAME_EXTENTS`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FrameExtents {
+    pub left: u32,
+    pub right: u32,
+    pub top: u32,
+    pub bottom: u32,
+}
+
+/// An icon of a window, one of those in `_NET_WM_ICON`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WmIcon {
+    pub width: u32,
+    pub height: u32,
+    /// The pixels in rows from top to bottom, as ARGB with non-premultiplied alpha.
+    pub pixels: Vec<u32>,
+}
+
+/// A request sent to the window manager with a client message to the root window.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Request {
+    NumberOfDesktops(u32),
+    CurrentDesktop {
+        desktop: u32,
+        time: XCBTimestamp,
+    },
+    ActiveWindow {
+        window: XCBWindow,
+        source: Source,
+        time: XCBTimestamp,
+        /// The active window of the requesting client, if any.
+        current_active: XCBWindow,
+    },
+    WmState {
+        window: XCBWindow,
+        action: StateAction,
+        /// The states to change, the second one being `XCB_ATOM_NONE` if only one is changed.
+        states: [XCBAtom; 2],
+        source: Source,
+    },
+    MoveResize {
+        window: XCBWindow,
+        x_root: i32,
+        y_root: i32,
+        direction: MoveResize,
+        button: u32,
+        source: Source,
+    },
+}
+
+/// Reads and writes the EWMH properties of windows and sends and decodes its requests.
+pub struct Ewmh<'a> {
+    connection: &'a XCBConnection,
+    atoms: Atoms,
+}
+
+impl<'a> Ewmh<'a> {
+    /// Interns the atoms of the EWMH.
+    pub fn new(connection: &'a XCBConnection) -> Result<Ewmh<'a>, XError> {
+        Ok(Ewmh {
+            connection,
+            atoms: Atoms::intern(connection)?,
+        })
+    }
+
+    pub fn atoms(&self) -> &Atoms {
+        &self.atoms
+    }
+
+    fn get_words(
+        &self,
+        window: XCBWindow,
+        property: XCBAtom,
+        type_: XCBAtom,
+    ) -> Result<Vec<u32>, XError> {
+        Ok(property::get_words(self.connection, window, property, type_)?.unwrap_or_default())
+    }
+
+    fn get_word(
+        &self,
+        window: XCBWindow,
+        property: XCBAtom,
+        type_: XCBAtom,
+    ) -> Result<Option<u32>, XError> {
+        Ok(self.get_words(window, property, type_)?.first().cloned())
+    }
+
+    fn set_words(
+        &self,
+        window: XCBWindow,
+        property: XCBAtom,
+        type_: XCBAtom,
+        words: &[u32],
+    ) -> XCBVoidCookie {
+        property::set_words(self.connection, window, property, type_, words)
+    }
+
+    /// Sends a request about `window` to the window manager managing `root`.
+    fn send_request(
+        &self,
+        root: XCBWindow,
+        window: XCBWindow,
+        type_: XCBAtom,
+        data: [u32; 5],
+    ) -> XCBVoidCookie {
+        let message = ClientMessage::new(window, type_, ClientMessageData::U32(data));
+        send_event(
+            self.connection,
+            false,
+            root,
+            XCB_EVENT_MASK_SUBSTRUCTURE_NOTIFY | XCB_EVENT_MASK_SUBSTRUCTURE_REDIRECT,
+            &message,
+        )
+    }
+
+    /// Decodes a client message sent to the root window as one of the requests to the window
+    /// manager.
+    pub fn decode_request(&self, message: &XCBClientMessageEvent) -> Option<Request> {
+        let data = match ClientMessage::from_event(message)?.data {
+            ClientMessageData::U32(data) => data,
+            _ => return None,
+        };
+        let atoms = &self.atoms;
+        let request = if message.type_ == atoms.net_number_of_desktops {
+            Request::NumberOfDesktops(data[0])
+        } else if message.type_ == atoms.net_current_desktop {
+            Request::CurrentDesktop {
+                desktop: data[0],
+                time: data[1],
+            }
+        } else if message.type_ == atoms.net_active_window {
+            Request::ActiveWindow {
+                window: message.window,
+                source: Source::from_raw(data[0]),
+                time: data[1],
+                current_active: data[2],
+            }
+        } else if message.type_ == atoms.net_wm_state {
+            Request::WmState {
+                window: message.window,
+                action: StateAction::from_raw(data[0])?,
+                states: [data[1], data[2]],
+                source: Source::from_raw(data[3]),
+            }
+        } else if message.type_ == atoms.net_wm_moveresize {
+            Request::MoveResize {
+                window: message.window,
+                x_root: data[0] as i32,
+                y_root: data[1] as i32,
+                direction: MoveResize::from_raw(data[2])?,
+                button: data[3],
+                source: Source::from_raw(data[4]),
+            }
+        } else {
+            return None;
+        };
+        Some(request)
+    }
+
+    /// Returns `_NET_SUPPORTED`, the hints the window manager supports.
+    pub fn get_supported(&self, root: XCBWindow) -> Result<Vec<XCBAtom>, XError> {
+        self.get_words(root, self.atoms.net_supported, XCB_ATOM_ATOM)
+    }
+
+    /// Sets `_NET_SUPPORTED`, the hints the window manager supports.
+    pub fn set_supported(&self, root: XCBWindow, supported: &[XCBAtom]) -> XCBVoidCookie {
+        self.set_words(root, self.atoms.net_supported, XCB_ATOM_ATOM, supported)
+    }
+
+    /// Returns `_NET_CLIENT_LIST`, the managed windows in the order they were mapped.
+    pub fn get_client_list(&self, root: XCBWindow) -> Result<Vec<XCBWindow>, XError> {
+        self.get_words(root, self.atoms.net_client_list, XCB_ATOM_WINDOW)
+    }
+
+    /// Sets `_NET_CLIENT_LIST`, the managed windows in the order they were mapped.
+    pub fn set_client_list(&self, root: XCBWindow, windows: &[XCBWindow]) -> XCBVoidCookie {
+        self.set_words(root, self.atoms.net_client_list, XCB_ATOM_WINDOW, windows)
+    }
+
+    /// Returns `_NET_CLIENT_LIST_STACKING`, the managed windows from bottom to top.
+    pub fn get_client_list_stacking(&self, root: XCBWindow) -> Result<Vec<XCBWindow>, XError> {
+        self.get_words(root, self.atoms.net_client_list_stacking, XCB_ATOM_WINDOW)
+    }
+
+    /// Sets `_NET_CLIENT_LIST_STACKING`, the managed windows from bottom to top.
+    pub fn set_client_list_stacking(
+        &self,
+        root: XCBWindow,
+        windows: &[XCBWindow],
+    ) -> XCBVoidCookie {
+        self.set_words(
+            root,
+            self.atoms.net_client_list_stacking,
+            XCB_ATOM_WINDOW,
+            windows,
+        )
+    }
+
+    /// Returns `_NET_NUMBER_OF_DESKTOPS`.
+    pub fn get_number_of_desktops(&self, root: XCBWindow) -> Result<Option<u32>, XError> {
+        self.get_word(root, self.atoms.net_number_of_desktops, XCB_ATOM_CARDINAL)
+    }
+
+    /// Sets `_NET_NUMBER_OF_DESKTOPS`, which is done by the window manager.
+    pub fn set_number_of_desktops(&self, root: XCBWindow, number: u32) -> XCBVoidCookie {
+        self.set_words(
+            root,
+            self.atoms.net_number_of_desktops,
+            XCB_ATOM_CARDINAL,
+            &[number],
+        )
+    }
+
+    /// Asks the window manager to change the number of desktops.
+    pub fn request_number_of_desktops(&self, root: XCBWindow, number: u32) -> XCBVoidCookie {
+        self.send_request(
+            root,
+            root,
+            self.atoms.net_number_of_desktops,
+            [number, 0, 0, 0, 0],
+        )
+    }
+
+    /// Returns `_NET_CURRENT_DESKTOP`, the index of the current desktop.
+    pub fn get_current_desktop(&self, root: XCBWindow) -> Result<Option<u32>, XError> {
+        self.get_word(root, self.atoms.net_current_desktop, XCB_ATOM_CARDINAL)
+    }
+
+    /// Sets `_NET_CURRENT_DESKTOP`, which is done by the window manager.
+    pub fn set_current_desktop(&self, root: XCBWindow, desktop: u32) -> XCBVoidCookie {
+        self.set_words(
+            root,
+            self.atoms.net_current_desktop,
+            XCB_ATOM_CARDINAL,
+            &[desktop],
+        )
+    }
+
+    /// Asks the window manager to switch to another desktop.
+    pub fn request_current_desktop(
+        &self,
+        root: XCBWindow,
+        desktop: u32,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie {
+        self.send_request(
+            root,
+            root,
+            self.atoms.net_current_desktop,
+            [desktop, time, 0, 0, 0],
+        )
+    }
+
+    /// Returns `_NET_ACTIVE_WINDOW`, the focused window, or `None` if no window is active.
+    pub fn get_active_window(&self, root: XCBWindow) -> Result<Option<XCBWindow>, XError> {
+        Ok(self
+            .get_word(root, self.atoms.net_active_window, XCB_ATOM_WINDOW)?
+            .filter(|&window| window != 0))
+    }
+
+    /// Sets `_NET_ACTIVE_WINDOW`, which is done by the window manager.
+    pub fn set_active_window(&self, root: XCBWindow, window: XCBWindow) -> XCBVoidCookie {
+        self.set_words(
+            root,
+            self.atoms.net_active_window,
+            XCB_ATOM_WINDOW,
+            &[window],
+        )
+    }
+
+    /// Asks the window manager to activate `window`, with `current_active` being the active
+    /// window of the requesting client, if any.
+    pub fn request_active_window(
+        &self,
+        root: XCBWindow,
+        window: XCBWindow,
+        source: Source,
+        time: XCBTimestamp,
+        current_active: XCBWindow,
+    ) -> XCBVoidCookie {
+        self.send_request(
+            root,
+            window,
+            self.atoms.net_active_window,
+            [source.raw(), time, current_active, 0, 0],
+        )
+    }
+
+    /// Returns `_NET_WM_NAME`, the title of a window in UTF-8.
+    pub fn get_wm_name(&self, window: XCBWindow) -> Result<Option<String>, XError> {
+        let utf8_string = self.atoms.utf8_string;
+        Ok(
+            property::get(self.connection, window, self.atoms.net_wm_name, utf8_string)?
+                .filter(|reply| reply.format == 8)
+                .map(|reply| String::from_utf8_lossy(xcb_get_property_value(&reply)).into_owned()),
+        )
+    }
+
+    /// Sets `_NET_WM_NAME`, the title of a window in UTF-8.
+    pub fn set_wm_name(&self, window: XCBWindow, name: &str) -> XCBVoidCookie {
+        property::set(
+            self.connection,
+            window,
+            self.atoms.net_wm_name,
+            self.atoms.utf8_string,
+            8,
+            name.as_bytes(),
+        )
+    }
+
+    /// Returns `_NET_WM_STATE`, the states of a window, e.g. `atoms().net_wm_state_fullscreen`.
+    pub fn get_wm_state(&self, window: XCBWindow) -> Result<Vec<XCBAtom>, XError> {
+        self.get_words(window, self.atoms.net_wm_state, XCB_ATOM_ATOM)
+    }
+
+    /// Sets `_NET_WM_STATE`, which is done by the window manager while the window is mapped,
+    /// and by the client before mapping it.
+    pub fn set_wm_state(&self, window: XCBWindow, states: &[XCBAtom]) -> XCBVoidCookie {
+        self.set_words(window, self.atoms.net_wm_state, XCB_ATOM_ATOM, states)
+    }
+
+    /// Asks the window manager to change one or two states of a mapped window, passing
+    /// `XCB_ATOM_NONE` as `second` to change only one.
+    pub fn request_wm_state(
+        &self,
+        root: XCBWindow,
+        window: XCBWindow,
+        action: StateAction,
+        first: XCBAtom,
+        second: XCBAtom,
+        source: Source,
+    ) -> XCBVoidCookie {
+        self.send_request(
+            root,
+            window,
+            self.atoms.net_wm_state,
+            [action.raw(), first, second, source.raw(), 0],
+        )
+    }
+
+    /// Returns `_NET_WM_WINDOW_TYPE`, the types of a window in order of preference, e.g.
+    /// `atoms().net_wm_window_type_dock`.
+    pub fn get_wm_window_type(&self, window: XCBWindow) -> Result<Vec<XCBAtom>, XError> {
+        self.get_words(window, self.atoms.net_wm_window_type, XCB_ATOM_ATOM)
+    }
+
+    /// Sets `_NET_WM_WINDOW_TYPE`, the types of a window in order of preference.
+    pub fn set_wm_window_type(&self, window: XCBWindow, types: &[XCBAtom]) -> XCBVoidCookie {
+        self.set_words(window, self.atoms.net_wm_window_type, XCB_ATOM_ATOM, types)
+    }
+
+    /// Returns `_NET_WM_STRUT_PARTIAL`, the space a window reserves at the edges of the screen.
+    pub fn get_wm_strut_partial(&self, window: XCBWindow) -> Result<Option<StrutPartial>, XError> {
+        let words = self.get_words(window, self.atoms.net_wm_strut_partial, XCB_ATOM_CARDINAL)?;
+        Ok(StrutPartial::from_words(&words))
+    }
+
+    /// Sets `_NET_WM_STRUT_PARTIAL`, the space a window reserves at the edges of the screen.
+    pub fn set_wm_strut_partial(&self, window: XCBWindow, strut: &StrutPartial) -> XCBVoidCookie {
+        self.set_words(
+            window,
+            self.atoms.net_wm_strut_partial,
+            XCB_ATOM_CARDINAL,
+            &strut.to_words(),
+        )
+    }
+
+    /// Returns `_NET_WM_ICON`, the icons of a window in different sizes.
+    pub fn get_wm_icon(&self, window: XCBWindow) -> Result<Vec<WmIcon>, XError> {
+        let words = self.get_words(window, self.atoms.net_wm_icon, XCB_ATOM_CARDINAL)?;
+        let mut icons = Vec::new();
+        let mut rest = &words[..];
+        while let [width, height, ref pixels @ ..] = *rest {
+            let size = width as usize * height as usize;
+            if size == 0 || pixels.len() < size {
+                break;
+            }
+            icons.push(WmIcon {
+                width,
+                height,
+                pixels: pixels[..size].to_vec(),
+            });
+            rest = &pixels[size..];
+        }
+        Ok(icons)
+    }
+
+    /// Sets `_NET_WM_ICON`, the icons of a window in different sizes.
+    ///
+    /// # Panics
+    /// If the pixels of an icon do not match its size.
+    pub fn set_wm_icon(&self, window: XCBWindow, icons: &[WmIcon]) -> XCBVoidCookie {
+        let mut words = Vec::new();
+        for icon in icons {
+            assert_eq!(
+                icon.pixels.len(),
+                icon.width as usize * icon.height as usize
+            );
+            words.push(icon.width);
+            words.push(icon.height);
+            words.extend_from_slice(&icon.pixels);
+        }
+        self.set_words(window, self.atoms.net_wm_icon, XCB_ATOM_CARDINAL, &words)
+    }
+
+    /// Returns `_NET_WM_PID`, the process id of the client owning a window.
+    pub fn get_wm_pid(&self, window: XCBWindow) -> Result<Option<u32>, XError> {
+        self.get_word(window, self.atoms.net_wm_pid, XCB_ATOM_CARDINAL)
+    }
+
+    /// Sets `_NET_WM_PID`, the process id of the client owning a window.
+    pub fn set_wm_pid(&self, window: XCBWindow, pid: u32) -> XCBVoidCookie {
+        self.set_words(window, self.atoms.net_wm_pid, XCB_ATOM_CARDINAL, &[pid])
+    }
+
+    /// Returns `_NET_FRAME_EXTENTS`, the widths of the frame around a window.
+    pub fn get_frame_extents(&self, window: XCBWindow) -> Result<Option<FrameExtents>, XError> {
+        let words = self.get_words(window, self.atoms.net_frame_extents, XCB_ATOM_CARDINAL)?;
+        Ok(match words[..] {
+            [left, right, top, bottom, ..] => Some(FrameExtents {
+                left,
+                right,
+                top,
+                bottom,
+            }),
+            _ => None,
+        })
+    }
+
+    /// Sets `_NET_FRAME_EXTENTS`, which is done by the window manager.
+    pub fn set_frame_extents(&self, window: XCBWindow, extents: &FrameExtents) -> XCBVoidCookie {
+        self.set_words(
+            window,
+            self.atoms.net_frame_extents,
+            XCB_ATOM_CARDINAL,
+            &[extents.left, extents.right, extents.top, extents.bottom],
+        )
+    }
+
+    /// Asks the window manager to start moving or resizing a window with the pointer at
+    /// `x_root` and `y_root`, e.g. when a client side decoration is dragged with `button`.
+    ///
+    /// The client has to ungrab the pointer first, so that the window manager can grab it.
+    pub fn request_moveresize(
+        &self,
+        root: XCBWindow,
+        window: XCBWindow,
+        x_root: i32,
+        y_root: i32,
+        direction: MoveResize,
+        button: u32,
+        source: Source,
+    ) -> XCBVoidCookie {
+        self.send_request(
+            root,
+            window,
+            self.atoms.net_wm_moveresize,
+            [
+                x_root as u32,
+                y_root as u32,
+                direction.raw(),
+                button,
+                source.raw(),
+            ],
+        )
+    }
+}
@@ -0,0 +1,112 @@
+//! Typed requests for the input focus and the state of the pointer and keyboard.
+//!
+//! Requests without special values, e.g. `xcb_query_pointer`, `xcb_get_motion_events` and
+//! `xcb_change_active_pointer_grab`, are used as generated.
+
+use proto::xproto::constants::{
+    XCB_INPUT_FOCUS_NONE, XCB_INPUT_FOCUS_PARENT, XCB_INPUT_FOCUS_POINTER_ROOT, XCB_WINDOW_NONE,
+};
+use proto::xproto::{
+    xcb_get_input_focus, xcb_set_input_focus, xcb_warp_pointer, XCBKeycode, XCBQueryKeymapReply,
+    XCBTimestamp, XCBWindow,
+};
+use {XCBConnection, XCBVoidCookie, XError};
+
+/// The window receiving the keyboard input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Focus {
+    /// The keyboard input is discarded.
+    None,
+    /// The input goes to the root window of the screen the pointer is on, which changes when
+    /// the pointer moves to another screen.
+    PointerRoot,
+    Window(XCBWindow),
+}
+
+impl Focus {
+    fn raw(self) -> XCBWindow {
+        match self {
+            Focus::None => XCB_INPUT_FOCUS_NONE,
+            Focus::PointerRoot => XCB_INPUT_FOCUS_POINTER_ROOT,
+            Focus::Window(window) => window,
+        }
+    }
+
+    fn from_raw(focus: XCBWindow) -> Focus {
+        match focus {
+            XCB_INPUT_FOCUS_NONE => Focus::None,
+            XCB_INPUT_FOCUS_POINTER_ROOT => Focus::PointerRoot,
+            window => Focus::Window(window),
+        }
+    }
+}
+
+/// What the focus reverts to when the focused window becomes unviewable.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RevertTo {
+    None,
+    PointerRoot,
+    /// The closest viewable ancestor of the window.
+    Parent,
+}
+
+impl RevertTo {
+    fn raw(self) -> u8 {
+        match self {
+            RevertTo::None => XCB_INPUT_FOCUS_NONE as u8,
+            RevertTo::PointerRoot => XCB_INPUT_FOCUS_POINTER_ROOT as u8,
+            RevertTo::Parent => XCB_INPUT_FOCUS_PARENT as u8,
+        }
+    }
+
+    fn from_raw(revert_to: u8) -> RevertTo {
+        match u32::from(revert_to) {
+            XCB_INPUT_FOCUS_POINTER_ROOT => RevertTo::PointerRoot,
+            XCB_INPUT_FOCUS_PARENT => RevertTo::Parent,
+            _ => RevertTo::None,
+        }
+    }
+}
+
+/// Gives the keyboard input to `focus`.
+///
+/// The request is ignored if `time` is earlier than the last change of the focus, so a window
+/// manager should pass the time of the event that caused the change rather than
+/// `XCB_TIME_CURRENT_TIME`.
+pub fn set_input_focus(
+    connection: &XCBConnection,
+    focus: Focus,
+    revert_to: RevertTo,
+    time: XCBTimestamp,
+) -> XCBVoidCookie {
+    xcb_set_input_focus(connection, revert_to.raw(), focus.raw(), time)
+}
+
+/// Returns the window receiving the keyboard input and what the focus reverts to.
+pub fn get_input_focus(connection: &XCBConnection) -> Result<(Focus, RevertTo), XError> {
+    let reply = xcb_get_input_focus(connection).reply(connection)?;
+    Ok((
+        Focus::from_raw(reply.focus),
+        RevertTo::from_raw(reply.revert_to),
+    ))
+}
+
+/// Moves the pointer to `x` and `y` relative to the origin of `window`.
+pub fn warp_pointer_to(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    x: i16,
+    y: i16,
+) -> XCBVoidCookie {
+    xcb_warp_pointer(connection, XCB_WINDOW_NONE, window, 0, 0, 0, 0, x, y)
+}
+
+/// Whether a key is pressed according to a reply to `xcb_query_keymap`.
+pub fn is_key_pressed(keymap: &XCBQueryKeymapReply, keycode: XCBKeycode) -> bool {
+    keymap.keys[keycode as usize / 8] & (1 << (keycode % 8)) != 0
+}
+
+/// Returns the keycodes of the pressed keys according to a reply to `xcb_query_keymap`.
+pub fn pressed_keys(keymap: &XCBQueryKeymapReply) -> impl Iterator<Item = XCBKeycode> + '_ {
+    (0..=255).filter(move |&keycode| is_key_pressed(keymap, keycode))
+}
@@ -0,0 +1,395 @@
+//! Grabs of the pointer, the keyboard, keys and buttons, released when their handles are
+//! dropped.
+//!
+//! Passive grabs of keys and buttons match the modifiers exactly, so a hotkey grabbed for
+//! `Mod4` does not trigger while NumLock or CapsLock is on. `Grab::key` and `Grab::button` thus
+//! also grab the combinations with the modifiers given as `ignored`, which `lock_modifiers`
+//! returns for the current keyboard mapping.
+
+use std::error::Error;
+use std::fmt;
+
+use keysyms::KeySymbols;
+use proto::keysym::XK_Num_Lock;
+use proto::xproto::constants::{
+    XCB_ALLOW_ASYNC_BOTH, XCB_ALLOW_ASYNC_KEYBOARD, XCB_ALLOW_ASYNC_POINTER,
+    XCB_ALLOW_REPLAY_KEYBOARD, XCB_ALLOW_REPLAY_POINTER, XCB_ALLOW_SYNC_BOTH,
+    XCB_ALLOW_SYNC_KEYBOARD, XCB_ALLOW_SYNC_POINTER, XCB_GRAB_MODE_ASYNC, XCB_GRAB_MODE_SYNC,
+    XCB_GRAB_STATUS_ALREADY_GRABBED, XCB_GRAB_STATUS_FROZEN, XCB_GRAB_STATUS_INVALID_TIME,
+    XCB_GRAB_STATUS_NOT_VIEWABLE, XCB_GRAB_STATUS_SUCCESS, XCB_MOD_MASK_ANY, XCB_MOD_MASK_LOCK,
+    XCB_TIME_CURRENT_TIME,
+};
+use proto::xproto::{
+    xcb_allow_events, xcb_grab_button, xcb_grab_key, xcb_grab_keyboard, xcb_grab_pointer,
+    xcb_ungrab_button, xcb_ungrab_key, xcb_ungrab_keyboard, xcb_ungrab_pointer, XCBCursor,
+    XCBKeycode, XCBTimestamp, XCBWindow,
+};
+use {XCBConnection, XCBVoidCookie};
+
+/// The outcome of an active grab of the pointer or keyboard.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GrabStatus {
+    Success,
+    /// Another client has grabbed the device.
+    AlreadyGrabbed,
+    /// The time is earlier than the last grab of the device, or later than the server time.
+    InvalidTime,
+    /// The grab or confine window is not viewable.
+    NotViewable,
+    /// The device is frozen by a synchronous grab of another client.
+    Frozen,
+}
+
+impl GrabStatus {
+    fn from_raw(status: u8) -> Result<GrabStatus, Box<dyn Error>> {
+        match status {
+            XCB_GRAB_STATUS_SUCCESS => Ok(GrabStatus::Success),
+            XCB_GRAB_STATUS_ALREADY_GRABBED => Ok(GrabStatus::AlreadyGrabbed),
+            XCB_GRAB_STATUS_INVALID_TIME => Ok(GrabStatus::InvalidTime),
+            XCB_GRAB_STATUS_NOT_VIEWABLE => Ok(GrabStatus::NotViewable),
+            XCB_GRAB_STATUS_FROZEN => Ok(GrabStatus::Frozen),
+            _ => Err(From::from(format!("unknown grab status {}", status))),
+        }
+    }
+}
+
+impl fmt::Display for GrabStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match *self {
+            GrabStatus::Success => "the grab succeeded",
+            GrabStatus::AlreadyGrabbed => "the device is grabbed by another client",
+            GrabStatus::InvalidTime => "the grab time is invalid",
+            GrabStatus::NotViewable => "the grab window is not viewable",
+            GrabStatus::Frozen => "the device is frozen by another grab",
+        };
+        f.write_str(description)
+    }
+}
+
+impl Error for GrabStatus {}
+
+/// Whether the events of a grabbed device are processed as usual or frozen until
+/// `allow_events` is called.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GrabMode {
+    Sync,
+    Async,
+}
+
+impl GrabMode {
+    fn raw(self) -> u8 {
+        match self {
+            GrabMode::Sync => XCB_GRAB_MODE_SYNC,
+            GrabMode::Async => XCB_GRAB_MODE_ASYNC,
+        }
+    }
+}
+
+/// How `allow_events` releases the events of devices frozen by a synchronous grab.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AllowEvents {
+    AsyncPointer,
+    SyncPointer,
+    /// Releases the pointer grab activated by a passive button grab and sends the event again,
+    /// as if the grab had not existed.
+    ReplayPointer,
+    AsyncKeyboard,
+    SyncKeyboard,
+    /// Releases the keyboard grab activated by a passive key grab and sends the event again,
+    /// as if the grab had not existed.
+    ReplayKeyboard,
+    AsyncBoth,
+    SyncBoth,
+}
+
+impl AllowEvents {
+    fn raw(self) -> u8 {
+        match self {
+            AllowEvents::AsyncPointer => XCB_ALLOW_ASYNC_POINTER,
+            AllowEvents::SyncPointer => XCB_ALLOW_SYNC_POINTER,
+            AllowEvents::ReplayPointer => XCB_ALLOW_REPLAY_POINTER,
+            AllowEvents::AsyncKeyboard => XCB_ALLOW_ASYNC_KEYBOARD,
+            AllowEvents::SyncKeyboard => XCB_ALLOW_SYNC_KEYBOARD,
+            AllowEvents::ReplayKeyboard => XCB_ALLOW_REPLAY_KEYBOARD,
+            AllowEvents::AsyncBoth => XCB_ALLOW_ASYNC_BOTH,
+            AllowEvents::SyncBoth => XCB_ALLOW_SYNC_BOTH,
+        }
+    }
+}
+
+/// Releases the events of devices frozen by a synchronous grab of this client.
+pub fn allow_events(
+    connection: &XCBConnection,
+    mode: AllowEvents,
+    time: XCBTimestamp,
+) -> XCBVoidCookie {
+    xcb_allow_events(connection, mode.raw(), time)
+}
+
+/// Actively grabs the pointer, so that its events are reported to this client only.
+///
+/// The pointer is confined to `confine_to` unless it is `XCB_WINDOW_NONE`, and shows `cursor`
+/// unless it is `XCB_CURSOR_NONE`.
+pub fn grab_pointer(
+    connection: &XCBConnection,
+    owner_events: bool,
+    grab_window: XCBWindow,
+    event_mask: u16,
+    pointer_mode: GrabMode,
+    keyboard_mode: GrabMode,
+    confine_to: XCBWindow,
+    cursor: XCBCursor,
+    time: XCBTimestamp,
+) -> Result<GrabStatus, Box<dyn Error>> {
+    let reply = xcb_grab_pointer(
+        connection,
+        owner_events,
+        grab_window,
+        event_mask,
+        pointer_mode.raw(),
+        keyboard_mode.raw(),
+        confine_to,
+        cursor,
+        time,
+    )
+    .reply(connection)?;
+    GrabStatus::from_raw(reply.status)
+}
+
+/// Actively grabs the keyboard, so that its events are reported to this client only.
+pub fn grab_keyboard(
+    connection: &XCBConnection,
+    owner_events: bool,
+    grab_window: XCBWindow,
+    time: XCBTimestamp,
+    pointer_mode: GrabMode,
+    keyboard_mode: GrabMode,
+) -> Result<GrabStatus, Box<dyn Error>> {
+    let reply = xcb_grab_keyboard(
+        connection,
+        owner_events,
+        grab_window,
+        time,
+        pointer_mode.raw(),
+        keyboard_mode.raw(),
+    )
+    .reply(connection)?;
+    GrabStatus::from_raw(reply.status)
+}
+
+/// Returns the modifiers that passive grabs usually ignore: Lock and the modifier NumLock is
+/// bound to.
+pub fn lock_modifiers(symbols: &KeySymbols) -> u16 {
+    XCB_MOD_MASK_LOCK | symbols.modifier_mask(XK_Num_Lock)
+}
+
+/// Returns `modifiers` combined with every subset of `ignored`.
+fn combinations(modifiers: u16, ignored: u16) -> Vec<u16> {
+    if modifiers & XCB_MOD_MASK_ANY != 0 {
+        return vec![modifiers];
+    }
+    let ignored = ignored & !modifiers;
+    let mut combinations = Vec::new();
+    let mut subset: u16 = 0;
+    loop {
+        combinations.push(modifiers | subset);
+        if subset == ignored {
+            return combinations;
+        }
+        subset = subset.wrapping_sub(ignored) & ignored;
+    }
+}
+
+enum Kind {
+    Pointer,
+    Keyboard,
+    Key {
+        window: XCBWindow,
+        keycode: XCBKeycode,
+        modifiers: Vec<u16>,
+    },
+    Button {
+        window: XCBWindow,
+        button: u8,
+        modifiers: Vec<u16>,
+    },
+}
+
+/// A grab of this client, which is released when dropped.
+pub struct Grab<'a> {
+    connection: &'a XCBConnection,
+    kind: Kind,
+}
+
+impl<'a> Grab<'a> {
+    /// Actively grabs the pointer, see `grab_pointer`.
+    ///
+    /// # Return value
+    /// The grab, or the `GrabStatus` as error if the grab failed.
+    pub fn pointer(
+        connection: &'a XCBConnection,
+        owner_events: bool,
+        grab_window: XCBWindow,
+        event_mask: u16,
+        pointer_mode: GrabMode,
+        keyboard_mode: GrabMode,
+        confine_to: XCBWindow,
+        cursor: XCBCursor,
+    ) -> Result<Grab<'a>, Box<dyn Error>> {
+        match grab_pointer(
+            connection,
+            owner_events,
+            grab_window,
+            event_mask,
+            pointer_mode,
+            keyboard_mode,
+            confine_to,
+            cursor,
+            XCB_TIME_CURRENT_TIME,
+        )? {
+            GrabStatus::Success => Ok(Grab {
+                connection,
+                kind: Kind::Pointer,
+            }),
+            status => Err(Box::new(status)),
+        }
+    }
+
+    /// Actively grabs the keyboard, see `grab_keyboard`.
+    ///
+    /// # Return value
+    /// The grab, or the `GrabStatus` as error if the grab failed.
+    pub fn keyboard(
+        connection: &'a XCBConnection,
+        owner_events: bool,
+        grab_window: XCBWindow,
+        pointer_mode: GrabMode,
+        keyboard_mode: GrabMode,
+    ) -> Result<Grab<'a>, Box<dyn Error>> {
+        match grab_keyboard(
+            connection,
+            owner_events,
+            grab_window,
+            XCB_TIME_CURRENT_TIME,
+            pointer_mode,
+            keyboard_mode,
+        )? {
+            GrabStatus::Success => Ok(Grab {
+                connection,
+                kind: Kind::Keyboard,
+            }),
+            status => Err(Box::new(status)),
+        }
+    }
+
+    /// Passively grabs a key pressed with `modifiers` in `grab_window`, together with every
+    /// combination of the `ignored` modifiers.
+    ///
+    /// The grab fails with an Access error if another client grabbed the same combination,
+    /// which is reported asynchronously.
+    pub fn key(
+        connection: &'a XCBConnection,
+        owner_events: bool,
+        grab_window: XCBWindow,
+        modifiers: u16,
+        ignored: u16,
+        keycode: XCBKeycode,
+        pointer_mode: GrabMode,
+        keyboard_mode: GrabMode,
+    ) -> Grab<'a> {
+        let modifiers = combinations(modifiers, ignored);
+        for &modifiers in &modifiers {
+            xcb_grab_key(
+                connection,
+                owner_events,
+                grab_window,
+                modifiers,
+                keycode,
+                pointer_mode.raw(),
+                keyboard_mode.raw(),
+            );
+        }
+        Grab {
+            connection,
+            kind: Kind::Key {
+                window: grab_window,
+                keycode,
+                modifiers,
+            },
+        }
+    }
+
+    /// Passively grabs a button pressed with `modifiers` in `grab_window`, together with every
+    /// combination of the `ignored` modifiers.
+    ///
+    /// The grab fails with an Access error if another client grabbed the same combination,
+    /// which is reported asynchronously.
+    pub fn button(
+        connection: &'a XCBConnection,
+        owner_events: bool,
+        grab_window: XCBWindow,
+        event_mask: u16,
+        pointer_mode: GrabMode,
+        keyboard_mode: GrabMode,
+        confine_to: XCBWindow,
+        cursor: XCBCursor,
+        button: u8,
+        modifiers: u16,
+        ignored: u16,
+    ) -> Grab<'a> {
+        let modifiers = combinations(modifiers, ignored);
+        for &modifiers in &modifiers {
+            xcb_grab_button(
+                connection,
+                owner_events,
+                grab_window,
+                event_mask,
+                pointer_mode.raw(),
+                keyboard_mode.raw(),
+                confine_to,
+                cursor,
+                button,
+                modifiers,
+            );
+        }
+        Grab {
+            connection,
+            kind: Kind::Button {
+                window: grab_window,
+                button,
+                modifiers,
+            },
+        }
+    }
+}
+
+impl<'a> Drop for Grab<'a> {
+    fn drop(&mut self) {
+        match self.kind {
+            Kind::Pointer => {
+                xcb_ungrab_pointer(self.connection, XCB_TIME_CURRENT_TIME);
+            }
+            Kind::Keyboard => {
+                xcb_ungrab_keyboard(self.connection, XCB_TIME_CURRENT_TIME);
+            }
+            Kind::Key {
+                window,
+                keycode,
+                ref modifiers,
+            } => {
+                for &modifiers in modifiers {
+                    xcb_ungrab_key(self.connection, keycode, window, modifiers);
+                }
+            }
+            Kind::Button {
+                window,
+                button,
+                ref modifiers,
+            } => {
+                for &modifiers in modifiers {
+                    xcb_ungrab_button(self.connection, button, window, modifiers);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,556 @@
+//! Typed access to the window properties defined by the Inter-Client Communication Conventions
+//! Manual.
+//!
+//! Text is written as `STRING` if it is Latin-1 and as `UTF8_STRING` otherwise, and read from
+//! either of them.
+
+use property;
+use proto::xproto::constants::{
+    XCB_ATOM_ATOM, XCB_ATOM_STRING, XCB_ATOM_WINDOW, XCB_ATOM_WM_CLASS, XCB_ATOM_WM_CLIENT_MACHINE,
+    XCB_ATOM_WM_HINTS, XCB_ATOM_WM_ICON_NAME, XCB_ATOM_WM_NAME, XCB_ATOM_WM_NORMAL_HINTS,
+    XCB_ATOM_WM_SIZE_HINTS, XCB_ATOM_WM_TRANSIENT_FOR, XCB_GET_PROPERTY_TYPE_ANY,
+};
+use proto::xproto::{xcb_get_property_value, XCBPixmap};
+use {intern_atoms, XCBAtom, XCBConnection, XCBVoidCookie, XCBWindow, XError};
+
+/// The atoms of the ICCCM that are not predefined by the core protocol.
+#[derive(Copy, Clone, Debug)]
+pub struct Atoms {
+    pub wm_protocols: XCBAtom,
+    pub wm_delete_window: XCBAtom,
+    pub wm_take_focus: XCBAtom,
+    pub wm_state: XCBAtom,
+    pub wm_change_state: XCBAtom,
+    pub utf8_string: XCBAtom,
+}
+
+impl Atoms {
+    pub fn intern(connection: &XCBConnection) -> Result<Atoms, XError> {
+        let atoms = intern_atoms(
+            connection,
+            &[
+                "WM_PROTOCOLS",
+                "WM_DELETE_WINDOW",
+                "WM_TAKE_FOCUS",
+                "WM_STATE",
+                "WM_CHANGE_STATE",
+                "UTF8_STRING",
+            ],
+        )?;
+        Ok(Atoms {
+            wm_protocols: atoms[0],
+            wm_delete_window: atoms[1],
+            wm_take_focus: atoms[2],
+            wm_state: atoms[3],
+            wm_change_state: atoms[4],
+            utf8_string: atoms[5],
+        })
+    }
+}
+
+/// The state of a top-level window, as stored in `WM_STATE` by the window manager.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WmState {
+    Withdrawn,
+    Normal,
+    Iconic,
+}
+
+impl WmState {
+    fn raw(self) -> u32 {
+        match self {
+            WmState::Withdrawn => 0,
+            WmState::Normal => 1,
+            WmState::Iconic => 3,
+        }
+    }
+
+    fn from_raw(state: u32) -> Option<WmState> {
+        match state {
+            0 => Some(WmState::Withdrawn),
+            1 => Some(WmState::Normal),
+            3 => Some(WmState::Iconic),
+            _ => None,
+        }
+    }
+}
+
+/// The instance and class name of a window, stored in `WM_CLASS`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WmClass {
+    pub instance: String,
+    pub class: String,
+}
+
+const INPUT_HINT: u32 = 1 << 0;
+const STATE_HINT: u32 = 1 << 1;
+const ICON_PIXMAP_HINT: u32 = 1 << 2;
+const ICON_WINDOW_HINT: u32 = 1 << 3;
+const ICON_POSITION_HINT: u32 = 1 << 4;
+const ICON_MASK_HINT: u32 = 1 << 5;
+const WINDOW_GROUP_HINT: u32 = 1 << 6;
+const URGENCY_HINT: u32 = 1 << 8;
+
+/// The contents of `WM_HINTS`, each field being present if its flag is set.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct WmHints {
+    /// Whether the window relies on the window manager to get the input focus.
+    pub input: Option<bool>,
+    /// The state the window is mapped in, `Normal` or `Iconic`.
+    pub initial_state: Option<WmState>,
+    pub icon_pixmap: Option<XCBPixmap>,
+    pub icon_window: Option<XCBWindow>,
+    pub icon_position: Option<(i32, i32)>,
+    pub icon_mask: Option<XCBPixmap>,
+    pub window_group: Option<XCBWindow>,
+    pub urgent: bool,
+}
+
+impl WmHints {
+    fn from_words(words: &[u32]) -> Option<WmHints> {
+        // Clients predating ICCCM version 1 write 8 words, without the window group.
+        if words.len() < 8 {
+            return None;
+        }
+        let flags = words[0];
+        let flag = |bit: u32| flags & bit != 0;
+        Some(WmHints {
+            input: if flag(INPUT_HINT) {
+                Some(words[1] != 0)
+            } else {
+                None
+            },
+            initial_state: if flag(STATE_HINT) {
+                WmState::from_raw(words[2])
+            } else {
+                None
+            },
+            icon_pixmap: if flag(ICON_PIXMAP_HINT) {
+                Some(words[3])
+            } else {
+                None
+            },
+            icon_window: if flag(ICON_WINDOW_HINT) {
+                Some(words[4])
+            } else {
+                None
+            },
+            icon_position: if flag(ICON_POSITION_HINT) {
+                Some((words[5] as i32, words[6] as i32))
+            } else {
+                None
+            },
+            icon_mask: if flag(ICON_MASK_HINT) {
+                Some(words[7])
+            } else {
+                None
+            },
+            window_group: if flag(WINDOW_GROUP_HINT) {
+                words.get(8).cloned()
+            } else {
+                None
+            },
+            urgent: flag(URGENCY_HINT),
+        })
+    }
+
+    fn to_words(self) -> [u32; 9] {
+        let mut words = [0; 9];
+        if let Some(input) = self.input {
+            words[0] |= INPUT_HINT;
+            words[1] = input as u32;
+        }
+        if let Some(state) = self.initial_state {
+            words[0] |= STATE_HINT;
+            words[2] = state.raw();
+        }
+        if let Some(pixmap) = self.icon_pixmap {
+            words[0] |= ICON_PIXMAP_HINT;
+            words[3] = pixmap;
+        }
+        if let Some(window) = self.icon_window {
+            words[0] |= ICON_WINDOW_HINT;
+            words[4] = window;
+        }
+        if let Some((x, y)) = self.icon_position {
+            words[0] |= ICON_POSITION_HINT;
+            words[5] = x as u32;
+            words[6] = y as u32;
+        }
+        if let Some(mask) = self.icon_mask {
+            words[0] |= ICON_MASK_HINT;
+            words[7] = mask;
+        }
+        if let Some(group) = self.window_group {
+            words[0] |= WINDOW_GROUP_HINT;
+            words[8] = group;
+        }
+        if self.urgent {
+            words[0] |= URGENCY_HINT;
+        }
+        words
+    }
+}
+
+const US_POSITION: u32 = 1 << 0;
+const US_SIZE: u32 = 1 << 1;
+const P_POSITION: u32 = 1 << 2;
+const P_SIZE: u32 = 1 << 3;
+const P_MIN_SIZE: u32 = 1 << 4;
+const P_MAX_SIZE: u32 = 1 << 5;
+const P_RESIZE_INC: u32 = 1 << 6;
+const P_ASPECT: u32 = 1 << 7;
+const P_BASE_SIZE: u32 = 1 << 8;
+const P_WIN_GRAVITY: u32 = 1 << 9;
+
+/// The contents of `WM_NORMAL_HINTS`, a `WM_SIZE_HINTS` property, each field being present if
+/// its flag is set.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SizeHints {
+    /// The position of the window, which the window manager only has to respect if
+    /// `user_specified` is set.
+    pub position: Option<(i32, i32)>,
+    /// The size of the window, which the window manager only has to respect if
+    /// `user_specified` is set.
+    pub size: Option<(i32, i32)>,
+    /// Whether the position and size were requested by the user rather than the program.
+    pub user_specified: bool,
+    pub min_size: Option<(i32, i32)>,
+    pub max_size: Option<(i32, i32)>,
+    /// The steps by which the window is resized, starting from `base_size`.
+    pub resize_inc: Option<(i32, i32)>,
+    /// The minimum and maximum aspect ratios, each as numerator and denominator.
+    pub aspect: Option<((i32, i32), (i32, i32))>,
+    pub base_size: Option<(i32, i32)>,
+    /// One of the `XCB_GRAVITY_*` constants.
+    pub win_gravity: Option<u32>,
+}
+
+impl SizeHints {
+    fn from_words(words: &[u32]) -> Option<SizeHints> {
+        // Clients predating ICCCM version 1 write 15 words, without base size and gravity.
+        if words.len() < 15 {
+            return None;
+        }
+        let word = |index: usize| words.get(index).map_or(0, |&word| word as i32);
+        let pair = |flag: u32, index: usize| {
+            if words[0] & flag != 0 {
+                Some((word(index), word(index + 1)))
+            } else {
+                None
+            }
+        };
+        Some(SizeHints {
+            position: pair(US_POSITION | P_POSITION, 1),
+            size: pair(US_SIZE | P_SIZE, 3),
+            user_specified: words[0] & (US_POSITION | US_SIZE) != 0,
+            min_size: pair(P_MIN_SIZE, 5),
+            max_size: pair(P_MAX_SIZE, 7),
+            resize_inc: pair(P_RESIZE_INC, 9),
+            aspect: pair(P_ASPECT, 11).map(|min| (min, (word(13), word(14)))),
+            base_size: pair(P_BASE_SIZE, 15),
+            win_gravity: if words[0] & P_WIN_GRAVITY != 0 {
+                Some(word(17) as u32)
+            } else {
+                None
+            },
+        })
+    }
+
+    fn to_words(self) -> [u32; 18] {
+        let mut words = [0; 18];
+        {
+            let mut pair = |value: Option<(i32, i32)>, flag: u32, index: usize| {
+                if let Some((first, second)) = value {
+                    words[0] |= flag;
+                    words[index] = first as u32;
+                    words[index + 1] = second as u32;
+                }
+            };
+            let (position, size) = if self.user_specified {
+                (US_POSITION, US_SIZE)
+            } else {
+                (P_POSITION, P_SIZE)
+            };
+            pair(self.position, position, 1);
+            pair(self.size, size, 3);
+            pair(self.min_size, P_MIN_SIZE, 5);
+            pair(self.max_size, P_MAX_SIZE, 7);
+            pair(self.resize_inc, P_RESIZE_INC, 9);
+            pair(self.aspect.map(|(min, _)| min), P_ASPECT, 11);
+            pair(self.aspect.map(|(_, max)| max), P_ASPECT, 13);
+            pair(self.base_size, P_BASE_SIZE, 15);
+        }
+        if let Some(gravity) = self.win_gravity {
+            words[0] |= P_WIN_GRAVITY;
+            words[17] = gravity;
+        }
+        words
+    }
+}
+
+/// Encodes text as Latin-1, or returns `None` if it has other characters.
+fn latin1(text: &str) -> Option<Vec<u8>> {
+    text.chars()
+        .map(|character| {
+            if (character as u32) < 0x100 {
+                Some(character as u8)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Decodes Latin-1 text.
+fn from_latin1(bytes: &[u8]) -> String {
+    bytes.iter().map(|&byte| char::from(byte)).collect()
+}
+
+/// Reads and writes the ICCCM properties of windows.
+pub struct Icccm<'a> {
+    connection: &'a XCBConnection,
+    atoms: Atoms,
+}
+
+impl<'a> Icccm<'a> {
+    /// Interns the atoms of the ICCCM.
+    pub fn new(connection: &'a XCBConnection) -> Result<Icccm<'a>, XError> {
+        Ok(Icccm {
+            connection,
+            atoms: Atoms::intern(connection)?,
+        })
+    }
+
+    pub fn atoms(&self) -> &Atoms {
+        &self.atoms
+    }
+
+    /// Returns a text property, decoding `UTF8_STRING` as UTF-8 and everything else, e.g.
+    /// `STRING`, as Latin-1.
+    pub fn get_text(&self, window: XCBWindow, property: XCBAtom) -> Result<Option<String>, XError> {
+        let reply =
+            match property::get(self.connection, window, property, XCB_GET_PROPERTY_TYPE_ANY)? {
+                Some(reply) if reply.format == 8 => reply,
+                _ => return Ok(None),
+            };
+        let value = xcb_get_property_value(&reply);
+        if reply.type_ == self.atoms.utf8_string {
+            Ok(Some(String::from_utf8_lossy(value).into_owned()))
+        } else {
+            Ok(Some(from_latin1(value)))
+        }
+    }
+
+    /// Sets a text property, as `STRING` if the text is Latin-1 and as `UTF8_STRING` otherwise.
+    pub fn set_text(&self, window: XCBWindow, property: XCBAtom, text: &str) -> XCBVoidCookie {
+        match latin1(text) {
+            Some(bytes) => property::set(
+                self.connection,
+                window,
+                property,
+                XCB_ATOM_STRING,
+                8,
+                &bytes,
+            ),
+            None => property::set(
+                self.connection,
+                window,
+                property,
+                self.atoms.utf8_string,
+                8,
+                text.as_bytes(),
+            ),
+        }
+    }
+
+    /// Returns `WM_NAME`, the title of a window.
+    pub fn get_wm_name(&self, window: XCBWindow) -> Result<Option<String>, XError> {
+        self.get_text(window, XCB_ATOM_WM_NAME)
+    }
+
+    /// Sets `WM_NAME`, the title of a window.
+    pub fn set_wm_name(&self, window: XCBWindow, name: &str) -> XCBVoidCookie {
+        self.set_text(window, XCB_ATOM_WM_NAME, name)
+    }
+
+    /// Returns `WM_ICON_NAME`, the title of the icon of a window.
+    pub fn get_wm_icon_name(&self, window: XCBWindow) -> Result<Option<String>, XError> {
+        self.get_text(window, XCB_ATOM_WM_ICON_NAME)
+    }
+
+    /// Sets `WM_ICON_NAME`, the title of the icon of a window.
+    pub fn set_wm_icon_name(&self, window: XCBWindow, name: &str) -> XCBVoidCookie {
+        self.set_text(window, XCB_ATOM_WM_ICON_NAME, name)
+    }
+
+    /// Returns `WM_CLIENT_MACHINE`, the name of the host the client runs on.
+    pub fn get_wm_client_machine(&self, window: XCBWindow) -> Result<Option<String>, XError> {
+        self.get_text(window, XCB_ATOM_WM_CLIENT_MACHINE)
+    }
+
+    /// Sets `WM_CLIENT_MACHINE`, the name of the host the client runs on.
+    pub fn set_wm_client_machine(&self, window: XCBWindow, host: &str) -> XCBVoidCookie {
+        self.set_text(window, XCB_ATOM_WM_CLIENT_MACHINE, host)
+    }
+
+    /// Returns `WM_CLASS`, the instance and class name of a window.
+    pub fn get_wm_class(&self, window: XCBWindow) -> Result<Option<WmClass>, XError> {
+        let reply =
+            match property::get(self.connection, window, XCB_ATOM_WM_CLASS, XCB_ATOM_STRING)? {
+                Some(reply) if reply.format == 8 => reply,
+                _ => return Ok(None),
+            };
+        let mut names = xcb_get_property_value(&reply).split(|&byte| byte == 0);
+        Ok(Some(WmClass {
+            instance: from_latin1(names.next().unwrap_or(&[])),
+            class: from_latin1(names.next().unwrap_or(&[])),
+        }))
+    }
+
+    /// Sets `WM_CLASS`, the instance and class name of a window.
+    ///
+    /// The property is of type `STRING`, so characters other than Latin-1 are replaced with
+    /// `?`.
+    pub fn set_wm_class(&self, window: XCBWindow, instance: &str, class: &str) -> XCBVoidCookie {
+        let mut data = Vec::with_capacity(instance.len() + class.len() + 2);
+        for name in &[instance, class] {
+            data.extend(name.chars().map(|character| {
+                if (character as u32) < 0x100 {
+                    character as u8
+                } else {
+                    b'?'
+                }
+            }));
+            data.push(0);
+        }
+        property::set(
+            self.connection,
+            window,
+            XCB_ATOM_WM_CLASS,
+            XCB_ATOM_STRING,
+            8,
+            &data,
+        )
+    }
+
+    /// Returns `WM_HINTS`.
+    pub fn get_wm_hints(&self, window: XCBWindow) -> Result<Option<WmHints>, XError> {
+        Ok(property::get_words(
+            self.connection,
+            window,
+            XCB_ATOM_WM_HINTS,
+            XCB_ATOM_WM_HINTS,
+        )?
+        .and_then(|words| WmHints::from_words(&words)))
+    }
+
+    /// Sets `WM_HINTS`.
+    pub fn set_wm_hints(&self, window: XCBWindow, hints: &WmHints) -> XCBVoidCookie {
+        property::set_words(
+            self.connection,
+            window,
+            XCB_ATOM_WM_HINTS,
+            XCB_ATOM_WM_HINTS,
+            &hints.to_words(),
+        )
+    }
+
+    /// Returns `WM_NORMAL_HINTS`, the size hints of a window in the normal state.
+    pub fn get_wm_normal_hints(&self, window: XCBWindow) -> Result<Option<SizeHints>, XError> {
+        Ok(property::get_words(
+            self.connection,
+            window,
+            XCB_ATOM_WM_NORMAL_HINTS,
+            XCB_ATOM_WM_SIZE_HINTS,
+        )?
+        .and_then(|words| SizeHints::from_words(&words)))
+    }
+
+    /// Sets `WM_NORMAL_HINTS`, the size hints of a window in the normal state.
+    pub fn set_wm_normal_hints(&self, window: XCBWindow, hints: &SizeHints) -> XCBVoidCookie {
+        property::set_words(
+            self.connection,
+            window,
+            XCB_ATOM_WM_NORMAL_HINTS,
+            XCB_ATOM_WM_SIZE_HINTS,
+            &hints.to_words(),
+        )
+    }
+
+    /// Returns `WM_PROTOCOLS`, the protocols a window takes part in, e.g.
+    /// `atoms().wm_delete_window`.
+    pub fn get_wm_protocols(&self, window: XCBWindow) -> Result<Vec<XCBAtom>, XError> {
+        Ok(property::get_words(
+            self.connection,
+            window,
+            self.atoms.wm_protocols,
+            XCB_ATOM_ATOM,
+        )?
+        .unwrap_or_default())
+    }
+
+    /// Sets `WM_PROTOCOLS`, the protocols a window takes part in.
+    pub fn set_wm_protocols(&self, window: XCBWindow, protocols: &[XCBAtom]) -> XCBVoidCookie {
+        property::set_words(
+            self.connection,
+            window,
+            self.atoms.wm_protocols,
+            XCB_ATOM_ATOM,
+            protocols,
+        )
+    }
+
+    /// Returns `WM_TRANSIENT_FOR`, the window a dialog belongs to.
+    pub fn get_wm_transient_for(&self, window: XCBWindow) -> Result<Option<XCBWindow>, XError> {
+        Ok(property::get_words(
+            self.connection,
+            window,
+            XCB_ATOM_WM_TRANSIENT_FOR,
+            XCB_ATOM_WINDOW,
+        )?
+        .and_then(|words| words.first().cloned()))
+    }
+
+    /// Sets `WM_TRANSIENT_FOR`, the window a dialog belongs to.
+    pub fn set_wm_transient_for(&self, window: XCBWindow, owner: XCBWindow) -> XCBVoidCookie {
+        property::set_words(
+            self.connection,
+            window,
+            XCB_ATOM_WM_TRANSIENT_FOR,
+            XCB_ATOM_WINDOW,
+            &[owner],
+        )
+    }
+
+    /// Returns `WM_STATE`, the state of a window and its icon window, as set by the window
+    /// manager.
+    pub fn get_wm_state(&self, window: XCBWindow) -> Result<Option<(WmState, XCBWindow)>, XError> {
+        let state = self.atoms.wm_state;
+        Ok(
+            property::get_words(self.connection, window, state, state)?.and_then(
+                |words| match words[..] {
+                    [state, icon, ..] => WmState::from_raw(state).map(|state| (state, icon)),
+                    _ => None,
+                },
+            ),
+        )
+    }
+
+    /// Sets `WM_STATE`, which is done by the window manager.
+    pub fn set_wm_state(
+        &self,
+        window: XCBWindow,
+        state: WmState,
+        icon: XCBWindow,
+    ) -> XCBVoidCookie {
+        let wm_state = self.atoms.wm_state;
+        property::set_words(
+            self.connection,
+            window,
+            wm_state,
+            wm_state,
+            &[state.raw(), icon],
+        )
+    }
+}
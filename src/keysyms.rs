@@ -0,0 +1,271 @@
+//! Translation between keycodes and keysyms with the core keyboard mapping, for clients that do
+//! not use XKB.
+//!
+//! `KeySymbols` follows the rules of the core protocol for choosing a keysym from the list of a
+//! key: the group is selected by the modifier bound to `Mode_switch`, and the keysym within the
+//! group by Shift, Lock and NumLock.
+
+use proto::keysym::{
+    char_to_keysym, keysym_from_name, keysym_to_char, XK_Caps_Lock, XK_KP_Equal, XK_KP_Space,
+    XK_Mode_switch, XK_Num_Lock, XK_Shift_Lock,
+};
+use proto::xproto::constants::{
+    XCB_MAPPING_KEYBOARD, XCB_MAPPING_MODIFIER, XCB_MOD_MASK_1, XCB_MOD_MASK_2, XCB_MOD_MASK_3,
+    XCB_MOD_MASK_4, XCB_MOD_MASK_5, XCB_MOD_MASK_ANY, XCB_MOD_MASK_CONTROL, XCB_MOD_MASK_LOCK,
+    XCB_MOD_MASK_SHIFT,
+};
+use proto::xproto::{
+    self, xcb_get_keyboard_mapping, xcb_get_keyboard_mapping_keysyms, xcb_get_modifier_mapping,
+    xcb_get_modifier_mapping_keycodes, XCBKeycode, XCBKeysym,
+};
+use {xcb_get_setup, Event, XCBConnection, XError};
+
+/// The keysym of keys without a symbol, `NoSymbol`.
+pub const NO_SYMBOL: u32 = 0;
+
+/// How the Lock modifier affects the keysyms.
+#[derive(Clone, Copy, PartialEq)]
+enum LockMode {
+    Ignored,
+    CapsLock,
+    ShiftLock,
+}
+
+/// The keyboard and modifier mapping of the core protocol.
+pub struct KeySymbols<'a> {
+    connection: &'a XCBConnection,
+    min_keycode: XCBKeycode,
+    keysyms_per_keycode: usize,
+    keysyms: Vec<XCBKeysym>,
+    keycodes_per_modifier: usize,
+    modifier_keycodes: Vec<XCBKeycode>,
+    mode_switch: u16,
+    num_lock: u16,
+    lock: LockMode,
+}
+
+impl<'a> KeySymbols<'a> {
+    /// Loads the keyboard and modifier mapping of the server.
+    pub fn new(connection: &'a XCBConnection) -> Result<KeySymbols<'a>, XError> {
+        let mut symbols = KeySymbols {
+            connection,
+            min_keycode: 0,
+            keysyms_per_keycode: 0,
+            keysyms: Vec::new(),
+            keycodes_per_modifier: 0,
+            modifier_keycodes: Vec::new(),
+            mode_switch: 0,
+            num_lock: 0,
+            lock: LockMode::Ignored,
+        };
+        symbols.refresh()?;
+        Ok(symbols)
+    }
+
+    /// Loads the keyboard and modifier mapping again.
+    pub fn refresh(&mut self) -> Result<(), XError> {
+        let setup = xcb_get_setup(self.connection);
+        let count = setup.max_keycode - setup.min_keycode + 1;
+        let keyboard = xcb_get_keyboard_mapping(self.connection, setup.min_keycode, count);
+        let modifiers = xcb_get_modifier_mapping(self.connection);
+
+        let keyboard = keyboard.reply(self.connection)?;
+        self.min_keycode = setup.min_keycode;
+        self.keysyms_per_keycode = keyboard.keysyms_per_keycode as usize;
+        self.keysyms = xcb_get_keyboard_mapping_keysyms(&keyboard).to_vec();
+
+        let modifiers = modifiers.reply(self.connection)?;
+        self.keycodes_per_modifier = modifiers.keycodes_per_modifier as usize;
+        self.modifier_keycodes = xcb_get_modifier_mapping_keycodes(&modifiers).to_vec();
+
+        self.mode_switch = self.modifier_mask(XK_Mode_switch);
+        self.num_lock = self.modifier_mask(XK_Num_Lock);
+        let lock = self.modifier_keycodes(1);
+        self.lock = if self.any_keysym(lock, XK_Caps_Lock) {
+            LockMode::CapsLock
+        } else if self.any_keysym(lock, XK_Shift_Lock) {
+            LockMode::ShiftLock
+        } else {
+            LockMode::Ignored
+        };
+        Ok(())
+    }
+
+    /// Loads the mapping again if a `MappingNotify` event reports a change of the keyboard or
+    /// modifier mapping.
+    ///
+    /// # Return value
+    /// Whether the event was such a `MappingNotify` event.
+    pub fn handle(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::Core(xproto::Event::MappingNotify(ref notify))
+                if notify.request == XCB_MAPPING_KEYBOARD
+                    || notify.request == XCB_MAPPING_MODIFIER =>
+            {
+                let _ = self.refresh();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The keysyms of a key.
+    fn keysyms(&self, keycode: XCBKeycode) -> &[XCBKeysym] {
+        let index = match keycode.checked_sub(self.min_keycode) {
+            Some(index) => index as usize * self.keysyms_per_keycode,
+            None => return &[],
+        };
+        self.keysyms
+            .get(index..index + self.keysyms_per_keycode)
+            .unwrap_or(&[])
+    }
+
+    /// The keycodes bound to the modifier with the given index, 0 being Shift and 7 Mod5.
+    fn modifier_keycodes(&self, modifier: usize) -> &[XCBKeycode] {
+        let start = modifier * self.keycodes_per_modifier;
+        self.modifier_keycodes
+            .get(start..start + self.keycodes_per_modifier)
+            .unwrap_or(&[])
+    }
+
+    fn any_keysym(&self, keycodes: &[XCBKeycode], keysym: u32) -> bool {
+        keycodes
+            .iter()
+            .any(|&keycode| keycode != 0 && self.keysyms(keycode).contains(&keysym))
+    }
+
+    /// Returns the keysym in column `column` of the list of a key, without applying any rules,
+    /// or `NO_SYMBOL`.
+    pub fn keysym(&self, keycode: XCBKeycode, column: usize) -> u32 {
+        self.keysyms(keycode)
+            .get(column)
+            .cloned()
+            .unwrap_or(NO_SYMBOL)
+    }
+
+    /// Returns the keysym of a key in the state reported by a key event, or `NO_SYMBOL`.
+    pub fn keysym_for_state(&self, keycode: XCBKeycode, state: u16) -> u32 {
+        let keysyms = self.keysyms(keycode);
+        let column = |index: usize| keysyms.get(index).cloned().unwrap_or(NO_SYMBOL);
+
+        // The second group is used if Mode_switch is active and the key has symbols for it.
+        let (mut first, mut second) = (column(0), column(1));
+        if state & self.mode_switch != 0 && (column(2) != NO_SYMBOL || column(3) != NO_SYMBOL) {
+            first = column(2);
+            second = column(3);
+        }
+        // A group of a single letter holds its lower and upper case.
+        if second == NO_SYMBOL {
+            let (lower, upper) = convert_case(first);
+            first = lower;
+            second = upper;
+        }
+
+        let shift = state & XCB_MOD_MASK_SHIFT != 0;
+        let lock = if state & XCB_MOD_MASK_LOCK != 0 {
+            self.lock
+        } else {
+            LockMode::Ignored
+        };
+        if state & self.num_lock != 0 && is_keypad(second) {
+            return if shift || lock == LockMode::ShiftLock {
+                first
+            } else {
+                second
+            };
+        }
+        match (shift, lock) {
+            (false, LockMode::Ignored) => first,
+            (false, LockMode::CapsLock) => convert_case(first).1,
+            (true, LockMode::CapsLock) => convert_case(second).1,
+            _ => second,
+        }
+    }
+
+    /// Returns the keycodes of the keys that have `keysym` in their list.
+    pub fn keycodes(&self, keysym: u32) -> Vec<XCBKeycode> {
+        if keysym == NO_SYMBOL || self.keysyms_per_keycode == 0 {
+            return Vec::new();
+        }
+        self.keysyms
+            .chunks(self.keysyms_per_keycode)
+            .enumerate()
+            .filter(|&(_, keysyms)| keysyms.contains(&keysym))
+            .map(|(index, _)| self.min_keycode + index as XCBKeycode)
+            .collect()
+    }
+
+    /// Returns the mask of the modifiers that a key with `keysym` is bound to, e.g. the mask of
+    /// NumLock for `XK_Num_Lock`, or 0.
+    pub fn modifier_mask(&self, keysym: u32) -> u16 {
+        (0..8)
+            .filter(|&modifier| self.any_keysym(self.modifier_keycodes(modifier), keysym))
+            .fold(0, |mask, modifier| mask | 1 << modifier)
+    }
+
+    /// Parses a key binding like `Mod4+Return` or `Control+Shift+t`.
+    ///
+    /// The modifiers are `Shift`, `Lock`, `Control` (or `Ctrl`), `Mod1` to `Mod5` and `Any`,
+    /// the key is the name of a keysym without the `XK_` prefix, e.g. `plus` for `+`, or a
+    /// single character.
+    ///
+    /// # Return value
+    /// The modifier mask and the keycodes of the key, or `None` if the binding is malformed or
+    /// no key has the keysym.
+    pub fn parse_binding(&self, binding: &str) -> Option<(u16, Vec<XCBKeycode>)> {
+        let mut parts = binding.split('+').map(str::trim).collect::<Vec<_>>();
+        let key = parts.pop()?;
+
+        let mut modifiers = 0;
+        for part in parts {
+            modifiers |= match part {
+                "Shift" => XCB_MOD_MASK_SHIFT,
+                "Lock" => XCB_MOD_MASK_LOCK,
+                "Control" | "Ctrl" => XCB_MOD_MASK_CONTROL,
+                "Mod1" => XCB_MOD_MASK_1,
+                "Mod2" => XCB_MOD_MASK_2,
+                "Mod3" => XCB_MOD_MASK_3,
+                "Mod4" => XCB_MOD_MASK_4,
+                "Mod5" => XCB_MOD_MASK_5,
+                "Any" => XCB_MOD_MASK_ANY,
+                _ => return None,
+            };
+        }
+
+        let keysym = keysym_from_name(key).or_else(|| single(key.chars()).map(char_to_keysym))?;
+        let keycodes = self.keycodes(keysym);
+        if keycodes.is_empty() {
+            return None;
+        }
+        Some((modifiers, keycodes))
+    }
+}
+
+/// Returns the lower and upper case keysyms of a keysym, which are the keysym itself if it is
+/// not a letter.
+fn convert_case(keysym: u32) -> (u32, u32) {
+    let character = match keysym_to_char(keysym) {
+        Some(character) => character,
+        None => return (keysym, keysym),
+    };
+    let convert = |converted: Option<char>| match converted {
+        Some(converted) if converted != character => char_to_keysym(converted),
+        _ => keysym,
+    };
+    (
+        convert(single(character.to_lowercase())),
+        convert(single(character.to_uppercase())),
+    )
+}
+
+/// Returns the only character of `characters`, or `None` if there are none or several.
+fn single<I: Iterator<Item = char>>(mut characters: I) -> Option<char> {
+    match (characters.next(), characters.next()) {
+        (Some(character), None) => Some(character),
+        _ => None,
+    }
+}
+
+fn is_keypad(keysym: u32) -> bool {
+    (XK_KP_Space..=XK_KP_Equal).contains(&keysym)
+}
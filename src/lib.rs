@@ -1,9 +1,52 @@
+#![allow(clippy::too_many_arguments)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::error::Error;
 use std::ffi::CString;
+use std::fmt;
 use std::ptr::{null, null_mut};
+use std::task::Poll;
 
 pub mod cdef;
+#[cfg(feature = "composite")]
+pub mod composite;
 pub mod constants;
+pub mod cursor;
+#[cfg(feature = "damage")]
+pub mod damage;
+pub mod dnd;
+pub mod event;
+pub mod ewmh;
+pub mod focus;
+pub mod grab;
+pub mod icccm;
+pub mod keysyms;
+mod property;
+pub mod proto;
+#[cfg(feature = "randr")]
+pub mod randr;
+#[cfg(feature = "render")]
+pub mod render;
+pub mod resources;
+pub mod selection;
+pub mod send;
+#[cfg(feature = "shm")]
+pub mod shm;
+pub mod systray;
+pub mod xembed;
+#[cfg(feature = "xfixes")]
+pub mod xfixes;
+#[cfg(feature = "xinput")]
+pub mod xinput;
+#[cfg(feature = "xkb")]
+pub mod xkb;
+#[cfg(feature = "xkbcommon")]
+pub mod xkbcommon;
+pub mod xsettings;
+
+pub use event::Event;
+pub use proto::xproto::*;
 
 /// A simple wrapper struct for raw pointers that are not meant to be freed by application code.
 pub struct DoNotFree<T> {
@@ -24,6 +67,9 @@ pub type XCBColormap = cdef::XCBColormap;
 pub type XCBWindow = cdef::XCBWindow;
 pub type XCBVisualId = cdef::XCBVisualId;
 pub type XCBVoidCookie = cdef::XCBVoidCookie;
+pub type XCBCookie<R> = cdef::XCBCookie<R>;
+pub type XCBGenericIterator = cdef::XCBGenericIterator;
+pub type XCBExtension = cdef::XCBExtension;
 pub type XCBInternAtomCookie = cdef::XCBInternAtomCookie;
 pub type XCBGenericError = cdef::XCBGenericError;
 pub type XCBInternAtomReply = cdef::XCBInternAtomReply;
@@ -41,6 +87,62 @@ pub type XCBClientMessageData = cdef::XCBClientMessageData;
 
 pub struct XCBConnection {
     raw: *mut cdef::XCBConnection,
+    close_requests: RefCell<Option<CloseRequests>>,
+}
+
+/// The atoms of the protocols set up by `XCBConnection::enable_close_requests`.
+struct CloseRequests {
+    wm_protocols: XCBAtom,
+    wm_delete_window: XCBAtom,
+    net_wm_ping: XCBAtom,
+    /// The root windows of the windows taking part, which are only needed to answer pings when
+    /// the display has several screens.
+    roots: HashMap<XCBWindow, XCBWindow>,
+}
+
+/// Error returned when the reply to a request could not be retrieved.
+#[derive(Debug)]
+pub enum XError {
+    /// The server answered the request with an error.
+    Protocol(Box<XCBGenericError>),
+    /// The connection was shut down before the reply arrived.
+    Connection,
+}
+
+impl fmt::Display for XError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            XError::Protocol(ref error) => write!(
+                f,
+                "X protocol error {} (major opcode {}, minor opcode {})",
+                error.error_code, error.major_code, error.minor_code
+            ),
+            XError::Connection => write!(f, "the X connection has been shut down"),
+        }
+    }
+}
+
+impl Error for XError {}
+
+/// A reply the server sends in response to a request.
+pub trait Reply: Sized {
+    /// Calls the `_reply` function of the request this is the reply to.
+    ///
+    /// # Safety
+    /// `connection` has to be a valid connection and `e` either null or a valid pointer.
+    unsafe fn reply(
+        connection: *mut cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self;
+}
+
+/// An extension to the core protocol, provided by one of libxcb's extension libraries.
+///
+/// The types implementing this are generated, e.g. `proto::randr::Randr`.
+pub trait Extension {
+    /// The `xcb_extension_t` the library identifies the extension with.
+    fn id() -> *mut XCBExtension;
 }
 
 pub fn xcb_connect(
@@ -64,19 +166,44 @@ pub fn xcb_connect(
         }
     }
 
-    let ptr_screen;
-    match screen {
-        None => {
-            ptr_screen = null_mut::<std::os::raw::c_int>();
-        }
-        Some(value) => {
-            ptr_screen = value as *mut std::os::raw::c_int;
-        }
-    }
+    let ptr_screen = match screen {
+        None => null_mut::<std::os::raw::c_int>(),
+        Some(value) => value as *mut std::os::raw::c_int,
+    };
 
     let connection = unsafe { cdef::xcb_connect(ptr_displayname, ptr_screen) };
 
-    Ok(XCBConnection { raw: connection })
+    Ok(XCBConnection {
+        raw: connection,
+        close_requests: RefCell::new(None),
+    })
+}
+
+impl XCBConnection {
+    /// Returns what the server reported when the extension was queried. Unless it has been
+    /// prefetched, this blocks on a QueryExtension round trip the first time it is called for an
+    /// extension, later calls return the cached data.
+    ///
+    /// # Return value
+    /// Whether the extension is present along with its major opcode and the codes of its first
+    /// event and first error, or `None` if the connection has been shut down. The data is not
+    /// to be freed by application code.
+    pub fn extension<E: Extension>(&self) -> Option<DoNotFree<XCBQueryExtensionReply>> {
+        let data = unsafe { cdef::xcb_get_extension_data(self.raw, E::id()) };
+        if data.is_null() {
+            None
+        } else {
+            Some(DoNotFree {
+                data: data as *mut XCBQueryExtensionReply,
+            })
+        }
+    }
+
+    /// Sends the QueryExtension request for an extension without waiting for the reply, so a
+    /// later call to `extension` does not block.
+    pub fn prefetch_extension<E: Extension>(&self) {
+        unsafe { cdef::xcb_prefetch_extension_data(self.raw, E::id()) }
+    }
 }
 
 pub fn xcb_disconnect(connection: XCBConnection) {
@@ -87,52 +214,6 @@ pub fn xcb_generate_id(connection: &XCBConnection) -> u32 {
     unsafe { cdef::xcb_generate_id(connection.raw) }
 }
 
-pub fn xcb_create_window(
-    connection: &XCBConnection,
-    depth: u8,
-    window_id: u32,
-    parent_id: u32,
-    x: i16,
-    y: i16,
-    width: u16,
-    height: u16,
-    border_width: u16,
-    class: u16,
-    visual: u32,
-    value_mask: u32,
-    value_list: Option<&[u32]>,
-) -> XCBVoidCookie {
-    let value_list_ptr: *const u32;
-    match value_list {
-        None => {
-            value_list_ptr = null();
-        }
-        Some(value) => {
-            let value_list_vec = Vec::from(value);
-            value_list_ptr = value_list_vec.as_ptr();
-            std::mem::forget(value_list_vec);
-        }
-    }
-
-    unsafe {
-        cdef::xcb_create_window(
-            connection.raw,
-            depth,
-            window_id,
-            parent_id,
-            x,
-            y,
-            width,
-            height,
-            border_width,
-            class,
-            visual,
-            value_mask,
-            value_list_ptr,
-        )
-    }
-}
-
 /// Accessor for setup data returned by the server when the connection was initialized.
 ///
 /// # Parameters
@@ -149,18 +230,6 @@ pub fn xcb_get_setup(connection: &XCBConnection) -> DoNotFree<cdef::XCBSetup> {
     }
 }
 
-/// Creates an iterator for iterating over the available screens contained in the setup.
-///
-/// # Parameters
-/// ## setup
-/// The setup.
-///
-/// # Return value
-/// An iterator.
-pub fn xcb_setup_roots_iterator(setup: &DoNotFree<cdef::XCBSetup>) -> XCBScreenIterator {
-    unsafe { cdef::xcb_setup_roots_iterator(setup.data) }
-}
-
 /// Accessor for a screen iterator's current data.
 ///
 /// # Parameters
@@ -173,19 +242,6 @@ pub fn xcb_screen(iter: &XCBScreenIterator) -> DoNotFree<XCBScreen> {
     DoNotFree::<XCBScreen> { data: iter.data }
 }
 
-/// Advances the given iterator to the next screen.
-///
-/// # Parameters
-/// ## iter
-/// The iterator
-pub fn xcb_screen_next(iter: &mut XCBScreenIterator) {
-    unsafe { cdef::xcb_screen_next(iter as *mut cdef::XCBScreenIterator) }
-}
-
-pub fn xcb_map_window(connection: &XCBConnection, window: XCBWindow) -> XCBVoidCookie {
-    unsafe { cdef::xcb_map_window(connection.raw, window) }
-}
-
 pub fn xcb_flush(connection: &XCBConnection) -> i32 {
     unsafe { cdef::xcb_flush(connection.raw) }
 }
@@ -194,9 +250,9 @@ pub fn xcb_poll_for_event(connection: &XCBConnection) -> Option<Box<XCBGenericEv
     unsafe {
         let event = cdef::xcb_poll_for_event(connection.raw);
         if event.is_null() {
-            return None;
+            None
         } else {
-            return Some(Box::from_raw(event));
+            Some(Box::from_raw(event))
         }
     }
 }
@@ -205,140 +261,141 @@ pub fn xcb_wait_for_event(connection: &XCBConnection) -> Option<Box<XCBGenericEv
     unsafe {
         let event = cdef::xcb_wait_for_event(connection.raw);
         if event.is_null() {
-            return None;
+            None
         } else {
-            return Some(Box::from_raw(event));
+            Some(Box::from_raw(event))
         }
     }
 }
 
-pub fn xcb_destroy_window(connection: &XCBConnection, window: XCBWindow) -> XCBVoidCookie {
-    unsafe { cdef::xcb_destroy_window(connection.raw, window) }
+/// Interns the atoms with the given names, sending all requests before waiting for the first
+/// reply.
+pub fn intern_atoms(connection: &XCBConnection, names: &[&str]) -> Result<Vec<XCBAtom>, XError> {
+    let cookies: Vec<_> = names
+        .iter()
+        .map(|name| proto::xproto::xcb_intern_atom(connection, false, name.as_bytes()))
+        .collect();
+    cookies
+        .into_iter()
+        .map(|cookie| cookie.reply(connection).map(|reply| reply.atom))
+        .collect()
 }
 
-pub fn xcb_intern_atom(
-    connection: &XCBConnection,
-    only_if_exists: bool,
-    name: &str,
-) -> Result<XCBInternAtomCookie, std::ffi::NulError> {
-    match CString::new(name) {
-        Err(err) => {
-            return Err(err);
-        }
-        Ok(cstr) => {
-            let cookie = unsafe {
-                cdef::xcb_intern_atom(
-                    connection.raw,
-                    if only_if_exists { 1 } else { 0 },
-                    name.chars().count() as std::os::raw::c_ushort,
-                    cstr.as_ptr(),
-                )
-            };
-            return Ok(cookie);
+fn poll_result<R>(
+    status: std::os::raw::c_int,
+    reply: *mut std::os::raw::c_void,
+    error: *mut XCBGenericError,
+) -> Poll<Result<Box<R>, XError>> {
+    if status == 0 {
+        return Poll::Pending;
+    }
+
+    unsafe {
+        if !reply.is_null() {
+            Poll::Ready(Ok(Box::from_raw(reply as *mut R)))
+        } else if !error.is_null() {
+            Poll::Ready(Err(XError::Protocol(Box::from_raw(error))))
+        } else {
+            Poll::Ready(Err(XError::Connection))
         }
     }
 }
 
-pub fn xcb_intern_atom_reply(
+/// Checks whether the reply or error for a request has arrived, without blocking.
+///
+/// # Parameters
+/// ## connection
+/// The connection.
+/// ## request
+/// The sequence number of the request.
+///
+/// # Return value
+/// `Poll::Pending` if the server has not answered yet, otherwise the reply or the error.
+///
+/// # Safety
+/// `R` has to be the reply type of the request identified by `request`.
+pub unsafe fn xcb_poll_for_reply<R>(
     connection: &XCBConnection,
-    cookie: XCBInternAtomCookie,
-    e: Option<*mut *mut XCBGenericError>,
-) -> Box<XCBInternAtomReply> {
-    let e_ptr = match e {
-        None => std::ptr::null_mut::<*mut XCBGenericError>(),
-        Some(value) => value,
-    };
-
-    unsafe { Box::from_raw(cdef::xcb_intern_atom_reply(connection.raw, cookie, e_ptr)) }
+    request: u32,
+) -> Poll<Result<Box<R>, XError>> {
+    let mut reply = null_mut::<std::os::raw::c_void>();
+    let mut error = null_mut::<XCBGenericError>();
+    let status = cdef::xcb_poll_for_reply(connection.raw, request, &mut reply, &mut error);
+    poll_result(status, reply, error)
 }
 
-pub fn xcb_change_property(
+/// Like `xcb_poll_for_reply`, but takes a full 64 bit sequence number.
+///
+/// # Safety
+/// `R` has to be the reply type of the request identified by `request`.
+pub unsafe fn xcb_poll_for_reply64<R>(
     connection: &XCBConnection,
-    mode: std::os::raw::c_uchar,
-    window: XCBWindow,
-    property: XCBAtom,
-    property_type: XCBAtom,
-    format: std::os::raw::c_uchar,
-    data_len: std::os::raw::c_uint,
-    data: *const std::os::raw::c_void,
-) -> XCBVoidCookie {
-    unsafe {
-        cdef::xcb_change_property(
-            connection.raw,
-            mode,
-            window,
-            property,
-            property_type,
-            format,
-            data_len,
-            data,
-        )
-    }
+    request: u64,
+) -> Poll<Result<Box<R>, XError>> {
+    let mut reply = null_mut::<std::os::raw::c_void>();
+    let mut error = null_mut::<XCBGenericError>();
+    let status = cdef::xcb_poll_for_reply64(connection.raw, request, &mut reply, &mut error);
+    poll_result(status, reply, error)
 }
 
-pub fn xcb_get_atom_name(connection: &XCBConnection, atom: XCBAtom) -> XCBGetAtomNameCookie {
-    unsafe { cdef::xcb_get_atom_name(connection.raw, atom) }
+impl<R> XCBCookie<R> {
+    /// Tells the connection that the reply or error of this request will never be asked for,
+    /// so it can be freed as soon as it arrives.
+    pub fn discard(self, connection: &XCBConnection) {
+        unsafe { cdef::xcb_discard_reply(connection.raw, self.sequence()) }
+    }
 }
 
-pub fn xcb_get_atom_name_reply(
-    connection: &XCBConnection,
-    cookie: XCBGetAtomNameCookie,
-    e: Option<*mut *mut XCBGenericError>,
-) -> *mut XCBGetAtomNameReply {
-    unsafe {
-        match e {
-            None => {
-                return cdef::xcb_get_atom_name_reply(
-                    connection.raw,
-                    cookie,
-                    null_mut::<*mut XCBGenericError>(),
-                );
-            }
-            Some(e) => {
-                return cdef::xcb_get_atom_name_reply(connection.raw, cookie, e);
-            }
+impl XCBVoidCookie {
+    /// Blocks until the server has processed this request and returns the error it caused.
+    ///
+    /// Only requests sent with one of the `_checked` functions report their error here, the
+    /// errors of all others are delivered as events.
+    pub fn check(self, connection: &XCBConnection) -> Result<(), XError> {
+        let error = unsafe { cdef::xcb_request_check(connection.raw, self) };
+        if error.is_null() {
+            Ok(())
+        } else {
+            Err(XError::Protocol(unsafe { Box::from_raw(error) }))
         }
     }
-}
 
-pub fn xcb_create_colormap(
-    connection: &XCBConnection,
-    alloc: u8,
-    mid: XCBColormap,
-    window: XCBWindow,
-    visual: XCBVisualId,
-) -> XCBVoidCookie {
-    unsafe { cdef::xcb_create_colormap(connection.raw, alloc, mid, window, visual) }
+    /// Tells the connection that the error of this `_checked` request will never be asked for,
+    /// so it can be freed as soon as it arrives.
+    pub fn discard(self, connection: &XCBConnection) {
+        unsafe { cdef::xcb_discard_reply(connection.raw, self.sequence()) }
+    }
 }
 
-pub fn xcb_clear_area(
-    connection: &XCBConnection,
-    exposures: bool,
-    window: XCBWindow,
-    x: i16,
-    y: i16,
-    width: u16,
-    height: u16,
-) -> XCBVoidCookie {
-    let exposures = if exposures { 1 } else { 0 };
-    unsafe { cdef::xcb_clear_area(connection.raw, exposures, window, x, y, width, height) }
-}
+impl<R: Reply> XCBCookie<R> {
+    /// Blocks until the reply to this request has arrived.
+    pub fn reply(self, connection: &XCBConnection) -> Result<Box<R>, XError> {
+        let mut error = null_mut::<XCBGenericError>();
+        unsafe {
+            let reply = R::reply(connection.raw, self, &mut error);
+            if !reply.is_null() {
+                Ok(Box::from_raw(reply))
+            } else if !error.is_null() {
+                Err(XError::Protocol(Box::from_raw(error)))
+            } else {
+                Err(XError::Connection)
+            }
+        }
+    }
 
-pub fn xcb_send_event(
-    connection: &XCBConnection,
-    propagate: bool,
-    destination: XCBWindow,
-    event_mask: u32,
-    event: Box<XCBGenericEvent>,
-) -> XCBVoidCookie {
-    let propagate = if propagate { 1 } else { 0 };
-    unsafe {
-        cdef::xcb_send_event(
-            connection.raw,
-            propagate,
-            destination,
-            event_mask,
-            Box::into_raw(event),
-        )
+    /// Checks whether the reply to this request has arrived, without blocking.
+    ///
+    /// # Return value
+    /// The reply or the error once the server has answered, otherwise `Err` with the cookie to
+    /// poll again later. The reply is handed out only once, so the cookie is consumed with it.
+    ///
+    /// Unlike `xcb_poll_for_reply` this does not return a `Poll`: `Poll::Pending` could not
+    /// carry the cookie back, and polling through a cookie kept by reference after the reply
+    /// was handed out would wait for a reply that never comes again.
+    pub fn poll(self, connection: &XCBConnection) -> Result<Result<Box<R>, XError>, Self> {
+        match unsafe { xcb_poll_for_reply(connection, self.sequence()) } {
+            Poll::Ready(result) => Ok(result),
+            Poll::Pending => Err(self),
+        }
     }
 }
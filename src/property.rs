@@ -0,0 +1,83 @@
+//! Reading and writing the window properties of the conventions between clients.
+//!
+//! Properties of format 32 are exchanged as native endian words, which is how libxcb delivers
+//! them.
+
+use proto::xproto::constants::{XCB_ATOM_NONE, XCB_GET_PROPERTY_TYPE_ANY, XCB_PROP_MODE_REPLACE};
+use proto::xproto::{
+    xcb_change_property, xcb_get_property, xcb_get_property_value, XCBGetPropertyReply,
+};
+use {XCBAtom, XCBConnection, XCBVoidCookie, XCBWindow, XError};
+
+/// Returns a property of `window` if it has the type `type_`, or any type for
+/// `XCB_GET_PROPERTY_TYPE_ANY`.
+pub fn get(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    property: XCBAtom,
+    type_: XCBAtom,
+) -> Result<Option<Box<XCBGetPropertyReply>>, XError> {
+    let reply = xcb_get_property(connection, false, window, property, type_, 0, u32::MAX)
+        .reply(connection)?;
+    if reply.type_ == XCB_ATOM_NONE || (type_ != XCB_GET_PROPERTY_TYPE_ANY && reply.type_ != type_)
+    {
+        return Ok(None);
+    }
+    Ok(Some(reply))
+}
+
+/// Returns the words of a property of format 32, or nothing for other formats.
+pub fn words(reply: &XCBGetPropertyReply) -> Vec<u32> {
+    if reply.format != 32 {
+        return Vec::new();
+    }
+    xcb_get_property_value(reply)
+        .chunks(4)
+        .filter(|bytes| bytes.len() == 4)
+        .map(|bytes| u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .collect()
+}
+
+/// Returns the words of a property of format 32, see `get`.
+pub fn get_words(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    property: XCBAtom,
+    type_: XCBAtom,
+) -> Result<Option<Vec<u32>>, XError> {
+    Ok(get(connection, window, property, type_)?.map(|reply| words(&reply)))
+}
+
+/// Replaces a property with `data`, which holds values of `format` bits.
+pub fn set(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    property: XCBAtom,
+    type_: XCBAtom,
+    format: u8,
+    data: &[u8],
+) -> XCBVoidCookie {
+    let length = data.len() / (format as usize / 8);
+    xcb_change_property(
+        connection,
+        XCB_PROP_MODE_REPLACE,
+        window,
+        property,
+        type_,
+        format,
+        length as u32,
+        data,
+    )
+}
+
+/// Replaces a property with words of format 32.
+pub fn set_words(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    property: XCBAtom,
+    type_: XCBAtom,
+    words: &[u32],
+) -> XCBVoidCookie {
+    let data: Vec<u8> = words.iter().flat_map(|word| word.to_ne_bytes()).collect();
+    set(connection, window, property, type_, 32, &data)
+}
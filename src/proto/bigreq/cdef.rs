@@ -0,0 +1,39 @@
+// Generated by xcbgen from xml/bigreq.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use cdef::{XCBConnection, XCBCookie, XCBExtension, XCBGenericError};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBBigRequestsEnableRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBBigRequestsEnableCookie = XCBCookie<XCBBigRequestsEnableReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBBigRequestsEnableReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub maximum_request_length: u32,
+}
+
+#[link(name = "xcb")]
+extern "system" {
+    pub static mut xcb_big_requests_id: XCBExtension;
+    pub fn xcb_big_requests_enable_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBBigRequestsEnableCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBBigRequestsEnableReply;
+    pub fn xcb_big_requests_enable(connection: *mut XCBConnection) -> XCBBigRequestsEnableCookie;
+    pub fn xcb_big_requests_enable_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBBigRequestsEnableCookie;
+}
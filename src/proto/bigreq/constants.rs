@@ -0,0 +1,5 @@
+// Generated by xcbgen from xml/bigreq.xml, do not edit.
+
+pub const XCB_BIG_REQUESTS_MAJOR_VERSION: u32 = 0;
+pub const XCB_BIG_REQUESTS_MINOR_VERSION: u32 = 0;
+pub const XCB_BIG_REQUESTS_ENABLE: u8 = 0;
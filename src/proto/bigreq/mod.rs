@@ -0,0 +1,37 @@
+// Generated by xcbgen from xml/bigreq.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError};
+
+pub type XCBBigRequestsEnableRequest = cdef::XCBBigRequestsEnableRequest;
+pub type XCBBigRequestsEnableCookie = cdef::XCBBigRequestsEnableCookie;
+pub type XCBBigRequestsEnableReply = cdef::XCBBigRequestsEnableReply;
+
+/// The BigRequests extension, known to the server as `BIG-REQUESTS`.
+pub struct Bigreq;
+
+impl Extension for Bigreq {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_big_requests_id)
+    }
+}
+
+impl Reply for XCBBigRequestsEnableReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_big_requests_enable_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_big_requests_enable(connection: &XCBConnection) -> XCBBigRequestsEnableCookie {
+    unsafe { cdef::xcb_big_requests_enable(connection.raw) }
+}
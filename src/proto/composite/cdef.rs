@@ -0,0 +1,227 @@
+// Generated by xcbgen from xml/composite.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use cdef::{XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+use proto::xfixes::cdef::XCBXfixesRegion;
+use proto::xproto::cdef::{XCBPixmap, XCBWindow};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCompositeQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub client_major_version: u32,
+    pub client_minor_version: u32,
+}
+
+pub type XCBCompositeQueryVersionCookie = XCBCookie<XCBCompositeQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCompositeQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCompositeRedirectWindowRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub update: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCompositeRedirectSubwindowsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub update: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCompositeUnredirectWindowRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub update: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCompositeUnredirectSubwindowsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub update: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCompositeCreateRegionFromBorderClipRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub region: XCBXfixesRegion,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCompositeNameWindowPixmapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub pixmap: XCBPixmap,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCompositeGetOverlayWindowRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBCompositeGetOverlayWindowCookie = XCBCookie<XCBCompositeGetOverlayWindowReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCompositeGetOverlayWindowReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub overlay_win: XCBWindow,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCompositeReleaseOverlayWindowRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+#[link(name = "xcb-composite")]
+extern "system" {
+    pub static mut xcb_composite_id: XCBExtension;
+    pub fn xcb_composite_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBCompositeQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBCompositeQueryVersionReply;
+    pub fn xcb_composite_query_version(
+        connection: *mut XCBConnection,
+        client_major_version: u32,
+        client_minor_version: u32,
+    ) -> XCBCompositeQueryVersionCookie;
+    pub fn xcb_composite_query_version_unchecked(
+        connection: *mut XCBConnection,
+        client_major_version: u32,
+        client_minor_version: u32,
+    ) -> XCBCompositeQueryVersionCookie;
+    pub fn xcb_composite_redirect_window_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        update: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_redirect_window(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        update: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_redirect_subwindows_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        update: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_redirect_subwindows(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        update: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_unredirect_window_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        update: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_unredirect_window(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        update: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_unredirect_subwindows_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        update: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_unredirect_subwindows(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        update: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_create_region_from_border_clip_checked(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_create_region_from_border_clip(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_name_window_pixmap_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        pixmap: XCBPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_name_window_pixmap(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        pixmap: XCBPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_get_overlay_window_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBCompositeGetOverlayWindowCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBCompositeGetOverlayWindowReply;
+    pub fn xcb_composite_get_overlay_window(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBCompositeGetOverlayWindowCookie;
+    pub fn xcb_composite_get_overlay_window_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBCompositeGetOverlayWindowCookie;
+    pub fn xcb_composite_release_overlay_window_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_composite_release_overlay_window(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+}
@@ -0,0 +1,15 @@
+// Generated by xcbgen from xml/composite.xml, do not edit.
+
+pub const XCB_COMPOSITE_MAJOR_VERSION: u32 = 0;
+pub const XCB_COMPOSITE_MINOR_VERSION: u32 = 4;
+pub const XCB_COMPOSITE_REDIRECT_AUTOMATIC: u8 = 0;
+pub const XCB_COMPOSITE_REDIRECT_MANUAL: u8 = 1;
+pub const XCB_COMPOSITE_QUERY_VERSION: u8 = 0;
+pub const XCB_COMPOSITE_REDIRECT_WINDOW: u8 = 1;
+pub const XCB_COMPOSITE_REDIRECT_SUBWINDOWS: u8 = 2;
+pub const XCB_COMPOSITE_UNREDIRECT_WINDOW: u8 = 3;
+pub const XCB_COMPOSITE_UNREDIRECT_SUBWINDOWS: u8 = 4;
+pub const XCB_COMPOSITE_CREATE_REGION_FROM_BORDER_CLIP: u8 = 5;
+pub const XCB_COMPOSITE_NAME_WINDOW_PIXMAP: u8 = 6;
+pub const XCB_COMPOSITE_GET_OVERLAY_WINDOW: u8 = 7;
+pub const XCB_COMPOSITE_RELEASE_OVERLAY_WINDOW: u8 = 8;
@@ -0,0 +1,132 @@
+// Generated by xcbgen from xml/composite.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+
+use proto::xfixes::XCBXfixesRegion;
+use proto::xproto::{XCBPixmap, XCBWindow};
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+
+pub type XCBCompositeQueryVersionRequest = cdef::XCBCompositeQueryVersionRequest;
+pub type XCBCompositeQueryVersionCookie = cdef::XCBCompositeQueryVersionCookie;
+pub type XCBCompositeQueryVersionReply = cdef::XCBCompositeQueryVersionReply;
+pub type XCBCompositeRedirectWindowRequest = cdef::XCBCompositeRedirectWindowRequest;
+pub type XCBCompositeRedirectSubwindowsRequest = cdef::XCBCompositeRedirectSubwindowsRequest;
+pub type XCBCompositeUnredirectWindowRequest = cdef::XCBCompositeUnredirectWindowRequest;
+pub type XCBCompositeUnredirectSubwindowsRequest = cdef::XCBCompositeUnredirectSubwindowsRequest;
+pub type XCBCompositeCreateRegionFromBorderClipRequest =
+    cdef::XCBCompositeCreateRegionFromBorderClipRequest;
+pub type XCBCompositeNameWindowPixmapRequest = cdef::XCBCompositeNameWindowPixmapRequest;
+pub type XCBCompositeGetOverlayWindowRequest = cdef::XCBCompositeGetOverlayWindowRequest;
+pub type XCBCompositeGetOverlayWindowCookie = cdef::XCBCompositeGetOverlayWindowCookie;
+pub type XCBCompositeGetOverlayWindowReply = cdef::XCBCompositeGetOverlayWindowReply;
+pub type XCBCompositeReleaseOverlayWindowRequest = cdef::XCBCompositeReleaseOverlayWindowRequest;
+
+/// The Composite extension, known to the server as `Composite`.
+pub struct Composite;
+
+impl Extension for Composite {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_composite_id)
+    }
+}
+
+impl Reply for XCBCompositeQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_composite_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_composite_query_version(
+    connection: &XCBConnection,
+    client_major_version: u32,
+    client_minor_version: u32,
+) -> XCBCompositeQueryVersionCookie {
+    unsafe {
+        cdef::xcb_composite_query_version(
+            connection.raw,
+            client_major_version,
+            client_minor_version,
+        )
+    }
+}
+
+pub fn xcb_composite_redirect_window(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    update: u8,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_composite_redirect_window(connection.raw, window, update) }
+}
+
+pub fn xcb_composite_redirect_subwindows(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    update: u8,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_composite_redirect_subwindows(connection.raw, window, update) }
+}
+
+pub fn xcb_composite_unredirect_window(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    update: u8,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_composite_unredirect_window(connection.raw, window, update) }
+}
+
+pub fn xcb_composite_unredirect_subwindows(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    update: u8,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_composite_unredirect_subwindows(connection.raw, window, update) }
+}
+
+pub fn xcb_composite_create_region_from_border_clip(
+    connection: &XCBConnection,
+    region: XCBXfixesRegion,
+    window: XCBWindow,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_composite_create_region_from_border_clip(connection.raw, region, window) }
+}
+
+pub fn xcb_composite_name_window_pixmap(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    pixmap: XCBPixmap,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_composite_name_window_pixmap(connection.raw, window, pixmap) }
+}
+
+impl Reply for XCBCompositeGetOverlayWindowReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_composite_get_overlay_window_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_composite_get_overlay_window(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBCompositeGetOverlayWindowCookie {
+    unsafe { cdef::xcb_composite_get_overlay_window(connection.raw, window) }
+}
+
+pub fn xcb_composite_release_overlay_window(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_composite_release_overlay_window(connection.raw, window) }
+}
@@ -0,0 +1,171 @@
+// Generated by xcbgen from xml/damage.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xfixes::cdef::XCBXfixesRegion;
+use proto::xproto::cdef::{XCBDrawable, XCBRectangle, XCBTimestamp};
+
+pub type XCBDamageDamage = u32;
+
+#[repr(C)]
+pub struct XCBDamageDamageIterator {
+    pub data: *mut XCBDamageDamage,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDamageBadDamageError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDamageQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub client_major_version: u32,
+    pub client_minor_version: u32,
+}
+
+pub type XCBDamageQueryVersionCookie = XCBCookie<XCBDamageQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDamageQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDamageCreateRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub damage: XCBDamageDamage,
+    pub drawable: XCBDrawable,
+    pub level: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDamageDestroyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub damage: XCBDamageDamage,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDamageSubtractRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub damage: XCBDamageDamage,
+    pub repair: XCBXfixesRegion,
+    pub parts: XCBXfixesRegion,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDamageAddRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub region: XCBXfixesRegion,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDamageNotifyEvent {
+    pub response_type: u8,
+    pub level: u8,
+    pub sequence: u16,
+    pub drawable: XCBDrawable,
+    pub damage: XCBDamageDamage,
+    pub timestamp: XCBTimestamp,
+    pub area: XCBRectangle,
+    pub geometry: XCBRectangle,
+}
+
+#[link(name = "xcb-damage")]
+extern "system" {
+    pub static mut xcb_damage_id: XCBExtension;
+    pub fn xcb_damage_damage_next(i: *mut XCBDamageDamageIterator);
+    pub fn xcb_damage_damage_end(i: XCBDamageDamageIterator) -> XCBGenericIterator;
+    pub fn xcb_damage_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDamageQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDamageQueryVersionReply;
+    pub fn xcb_damage_query_version(
+        connection: *mut XCBConnection,
+        client_major_version: u32,
+        client_minor_version: u32,
+    ) -> XCBDamageQueryVersionCookie;
+    pub fn xcb_damage_query_version_unchecked(
+        connection: *mut XCBConnection,
+        client_major_version: u32,
+        client_minor_version: u32,
+    ) -> XCBDamageQueryVersionCookie;
+    pub fn xcb_damage_create_checked(
+        connection: *mut XCBConnection,
+        damage: XCBDamageDamage,
+        drawable: XCBDrawable,
+        level: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_damage_create(
+        connection: *mut XCBConnection,
+        damage: XCBDamageDamage,
+        drawable: XCBDrawable,
+        level: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_damage_destroy_checked(
+        connection: *mut XCBConnection,
+        damage: XCBDamageDamage,
+    ) -> XCBVoidCookie;
+    pub fn xcb_damage_destroy(
+        connection: *mut XCBConnection,
+        damage: XCBDamageDamage,
+    ) -> XCBVoidCookie;
+    pub fn xcb_damage_subtract_checked(
+        connection: *mut XCBConnection,
+        damage: XCBDamageDamage,
+        repair: XCBXfixesRegion,
+        parts: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_damage_subtract(
+        connection: *mut XCBConnection,
+        damage: XCBDamageDamage,
+        repair: XCBXfixesRegion,
+        parts: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_damage_add_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        region: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_damage_add(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        region: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+}
@@ -0,0 +1,15 @@
+// Generated by xcbgen from xml/damage.xml, do not edit.
+
+pub const XCB_DAMAGE_MAJOR_VERSION: u32 = 1;
+pub const XCB_DAMAGE_MINOR_VERSION: u32 = 1;
+pub const XCB_DAMAGE_REPORT_LEVEL_RAW_RECTANGLES: u8 = 0;
+pub const XCB_DAMAGE_REPORT_LEVEL_DELTA_RECTANGLES: u8 = 1;
+pub const XCB_DAMAGE_REPORT_LEVEL_BOUNDING_BOX: u8 = 2;
+pub const XCB_DAMAGE_REPORT_LEVEL_NON_EMPTY: u8 = 3;
+pub const XCB_DAMAGE_BAD_DAMAGE: u8 = 0;
+pub const XCB_DAMAGE_QUERY_VERSION: u8 = 0;
+pub const XCB_DAMAGE_CREATE: u8 = 1;
+pub const XCB_DAMAGE_DESTROY: u8 = 2;
+pub const XCB_DAMAGE_SUBTRACT: u8 = 3;
+pub const XCB_DAMAGE_ADD: u8 = 4;
+pub const XCB_DAMAGE_NOTIFY: u8 = 0;
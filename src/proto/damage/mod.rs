@@ -0,0 +1,120 @@
+// Generated by xcbgen from xml/damage.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+
+use proto::xfixes::XCBXfixesRegion;
+use proto::xproto::XCBDrawable;
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBDamageDamage = cdef::XCBDamageDamage;
+pub type XCBDamageDamageIterator = cdef::XCBDamageDamageIterator;
+pub type XCBDamageBadDamageError = cdef::XCBDamageBadDamageError;
+pub type XCBDamageQueryVersionRequest = cdef::XCBDamageQueryVersionRequest;
+pub type XCBDamageQueryVersionCookie = cdef::XCBDamageQueryVersionCookie;
+pub type XCBDamageQueryVersionReply = cdef::XCBDamageQueryVersionReply;
+pub type XCBDamageCreateRequest = cdef::XCBDamageCreateRequest;
+pub type XCBDamageDestroyRequest = cdef::XCBDamageDestroyRequest;
+pub type XCBDamageSubtractRequest = cdef::XCBDamageSubtractRequest;
+pub type XCBDamageAddRequest = cdef::XCBDamageAddRequest;
+pub type XCBDamageNotifyEvent = cdef::XCBDamageNotifyEvent;
+
+/// The Damage extension, known to the server as `DAMAGE`.
+pub struct Damage;
+
+impl Extension for Damage {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_damage_id)
+    }
+}
+
+pub fn xcb_damage_damage_next(iter: &mut XCBDamageDamageIterator) {
+    unsafe { cdef::xcb_damage_damage_next(iter) }
+}
+
+impl Reply for XCBDamageQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_damage_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_damage_query_version(
+    connection: &XCBConnection,
+    client_major_version: u32,
+    client_minor_version: u32,
+) -> XCBDamageQueryVersionCookie {
+    unsafe {
+        cdef::xcb_damage_query_version(connection.raw, client_major_version, client_minor_version)
+    }
+}
+
+pub fn xcb_damage_create(
+    connection: &XCBConnection,
+    damage: XCBDamageDamage,
+    drawable: XCBDrawable,
+    level: u8,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_damage_create(connection.raw, damage, drawable, level) }
+}
+
+pub fn xcb_damage_destroy(connection: &XCBConnection, damage: XCBDamageDamage) -> XCBVoidCookie {
+    unsafe { cdef::xcb_damage_destroy(connection.raw, damage) }
+}
+
+pub fn xcb_damage_subtract(
+    connection: &XCBConnection,
+    damage: XCBDamageDamage,
+    repair: XCBXfixesRegion,
+    parts: XCBXfixesRegion,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_damage_subtract(connection.raw, damage, repair, parts) }
+}
+
+pub fn xcb_damage_add(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    region: XCBXfixesRegion,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_damage_add(connection.raw, drawable, region) }
+}
+
+/// An event of the Damage extension.
+pub enum Event {
+    Notify(Box<XCBDamageNotifyEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::Notify(Box::from_raw(
+                    raw as *mut XCBDamageNotifyEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
+
+/// Returns the name of the error whose code, relative to the first error of the extension, is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        0 => Some("BadDamage"),
+        _ => None,
+    }
+}
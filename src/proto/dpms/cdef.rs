@@ -0,0 +1,194 @@
+// Generated by xcbgen from xml/dpms.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use cdef::{XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDpmsGetVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub client_major_version: u16,
+    pub client_minor_version: u16,
+}
+
+pub type XCBDpmsGetVersionCookie = XCBCookie<XCBDpmsGetVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDpmsGetVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub server_major_version: u16,
+    pub server_minor_version: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDpmsCapableRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBDpmsCapableCookie = XCBCookie<XCBDpmsCapableReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDpmsCapableReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub capable: u8,
+    pub pad1: [u8; 23],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDpmsGetTimeoutsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBDpmsGetTimeoutsCookie = XCBCookie<XCBDpmsGetTimeoutsReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDpmsGetTimeoutsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub standby_timeout: u16,
+    pub suspend_timeout: u16,
+    pub off_timeout: u16,
+    pub pad1: [u8; 18],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDpmsSetTimeoutsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub standby_timeout: u16,
+    pub suspend_timeout: u16,
+    pub off_timeout: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDpmsEnableRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDpmsDisableRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDpmsForceLevelRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub power_level: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDpmsInfoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBDpmsInfoCookie = XCBCookie<XCBDpmsInfoReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDpmsInfoReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub power_level: u16,
+    pub state: u8,
+    pub pad1: [u8; 21],
+}
+
+#[link(name = "xcb-dpms")]
+extern "system" {
+    pub static mut xcb_dpms_id: XCBExtension;
+    pub fn xcb_dpms_get_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDpmsGetVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDpmsGetVersionReply;
+    pub fn xcb_dpms_get_version(
+        connection: *mut XCBConnection,
+        client_major_version: u16,
+        client_minor_version: u16,
+    ) -> XCBDpmsGetVersionCookie;
+    pub fn xcb_dpms_get_version_unchecked(
+        connection: *mut XCBConnection,
+        client_major_version: u16,
+        client_minor_version: u16,
+    ) -> XCBDpmsGetVersionCookie;
+    pub fn xcb_dpms_capable_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDpmsCapableCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDpmsCapableReply;
+    pub fn xcb_dpms_capable(connection: *mut XCBConnection) -> XCBDpmsCapableCookie;
+    pub fn xcb_dpms_capable_unchecked(connection: *mut XCBConnection) -> XCBDpmsCapableCookie;
+    pub fn xcb_dpms_get_timeouts_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDpmsGetTimeoutsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDpmsGetTimeoutsReply;
+    pub fn xcb_dpms_get_timeouts(connection: *mut XCBConnection) -> XCBDpmsGetTimeoutsCookie;
+    pub fn xcb_dpms_get_timeouts_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBDpmsGetTimeoutsCookie;
+    pub fn xcb_dpms_set_timeouts_checked(
+        connection: *mut XCBConnection,
+        standby_timeout: u16,
+        suspend_timeout: u16,
+        off_timeout: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dpms_set_timeouts(
+        connection: *mut XCBConnection,
+        standby_timeout: u16,
+        suspend_timeout: u16,
+        off_timeout: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dpms_enable_checked(connection: *mut XCBConnection) -> XCBVoidCookie;
+    pub fn xcb_dpms_enable(connection: *mut XCBConnection) -> XCBVoidCookie;
+    pub fn xcb_dpms_disable_checked(connection: *mut XCBConnection) -> XCBVoidCookie;
+    pub fn xcb_dpms_disable(connection: *mut XCBConnection) -> XCBVoidCookie;
+    pub fn xcb_dpms_force_level_checked(
+        connection: *mut XCBConnection,
+        power_level: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dpms_force_level(connection: *mut XCBConnection, power_level: u16) -> XCBVoidCookie;
+    pub fn xcb_dpms_info_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDpmsInfoCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDpmsInfoReply;
+    pub fn xcb_dpms_info(connection: *mut XCBConnection) -> XCBDpmsInfoCookie;
+    pub fn xcb_dpms_info_unchecked(connection: *mut XCBConnection) -> XCBDpmsInfoCookie;
+}
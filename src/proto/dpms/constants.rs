@@ -0,0 +1,16 @@
+// Generated by xcbgen from xml/dpms.xml, do not edit.
+
+pub const XCB_DPMS_MAJOR_VERSION: u32 = 0;
+pub const XCB_DPMS_MINOR_VERSION: u32 = 0;
+pub const XCB_DPMS_GET_VERSION: u8 = 0;
+pub const XCB_DPMS_CAPABLE: u8 = 1;
+pub const XCB_DPMS_GET_TIMEOUTS: u8 = 2;
+pub const XCB_DPMS_SET_TIMEOUTS: u8 = 3;
+pub const XCB_DPMS_ENABLE: u8 = 4;
+pub const XCB_DPMS_DISABLE: u8 = 5;
+pub const XCB_DPMS_DPMS_MODE_ON: u16 = 0;
+pub const XCB_DPMS_DPMS_MODE_STANDBY: u16 = 1;
+pub const XCB_DPMS_DPMS_MODE_SUSPEND: u16 = 2;
+pub const XCB_DPMS_DPMS_MODE_OFF: u16 = 3;
+pub const XCB_DPMS_FORCE_LEVEL: u8 = 6;
+pub const XCB_DPMS_INFO: u8 = 7;
@@ -0,0 +1,126 @@
+// Generated by xcbgen from xml/dpms.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+
+pub type XCBDpmsGetVersionRequest = cdef::XCBDpmsGetVersionRequest;
+pub type XCBDpmsGetVersionCookie = cdef::XCBDpmsGetVersionCookie;
+pub type XCBDpmsGetVersionReply = cdef::XCBDpmsGetVersionReply;
+pub type XCBDpmsCapableRequest = cdef::XCBDpmsCapableRequest;
+pub type XCBDpmsCapableCookie = cdef::XCBDpmsCapableCookie;
+pub type XCBDpmsCapableReply = cdef::XCBDpmsCapableReply;
+pub type XCBDpmsGetTimeoutsRequest = cdef::XCBDpmsGetTimeoutsRequest;
+pub type XCBDpmsGetTimeoutsCookie = cdef::XCBDpmsGetTimeoutsCookie;
+pub type XCBDpmsGetTimeoutsReply = cdef::XCBDpmsGetTimeoutsReply;
+pub type XCBDpmsSetTimeoutsRequest = cdef::XCBDpmsSetTimeoutsRequest;
+pub type XCBDpmsEnableRequest = cdef::XCBDpmsEnableRequest;
+pub type XCBDpmsDisableRequest = cdef::XCBDpmsDisableRequest;
+pub type XCBDpmsForceLevelRequest = cdef::XCBDpmsForceLevelRequest;
+pub type XCBDpmsInfoRequest = cdef::XCBDpmsInfoRequest;
+pub type XCBDpmsInfoCookie = cdef::XCBDpmsInfoCookie;
+pub type XCBDpmsInfoReply = cdef::XCBDpmsInfoReply;
+
+/// The DPMS extension, known to the server as `DPMS`.
+pub struct Dpms;
+
+impl Extension for Dpms {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_dpms_id)
+    }
+}
+
+impl Reply for XCBDpmsGetVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dpms_get_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dpms_get_version(
+    connection: &XCBConnection,
+    client_major_version: u16,
+    client_minor_version: u16,
+) -> XCBDpmsGetVersionCookie {
+    unsafe {
+        cdef::xcb_dpms_get_version(connection.raw, client_major_version, client_minor_version)
+    }
+}
+
+impl Reply for XCBDpmsCapableReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dpms_capable_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dpms_capable(connection: &XCBConnection) -> XCBDpmsCapableCookie {
+    unsafe { cdef::xcb_dpms_capable(connection.raw) }
+}
+
+impl Reply for XCBDpmsGetTimeoutsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dpms_get_timeouts_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dpms_get_timeouts(connection: &XCBConnection) -> XCBDpmsGetTimeoutsCookie {
+    unsafe { cdef::xcb_dpms_get_timeouts(connection.raw) }
+}
+
+pub fn xcb_dpms_set_timeouts(
+    connection: &XCBConnection,
+    standby_timeout: u16,
+    suspend_timeout: u16,
+    off_timeout: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_dpms_set_timeouts(
+            connection.raw,
+            standby_timeout,
+            suspend_timeout,
+            off_timeout,
+        )
+    }
+}
+
+pub fn xcb_dpms_enable(connection: &XCBConnection) -> XCBVoidCookie {
+    unsafe { cdef::xcb_dpms_enable(connection.raw) }
+}
+
+pub fn xcb_dpms_disable(connection: &XCBConnection) -> XCBVoidCookie {
+    unsafe { cdef::xcb_dpms_disable(connection.raw) }
+}
+
+pub fn xcb_dpms_force_level(connection: &XCBConnection, power_level: u16) -> XCBVoidCookie {
+    unsafe { cdef::xcb_dpms_force_level(connection.raw, power_level) }
+}
+
+impl Reply for XCBDpmsInfoReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dpms_info_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dpms_info(connection: &XCBConnection) -> XCBDpmsInfoCookie {
+    unsafe { cdef::xcb_dpms_info(connection.raw) }
+}
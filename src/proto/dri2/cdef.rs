@@ -0,0 +1,624 @@
+// Generated by xcbgen from xml/dri2.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int, c_void};
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xproto::cdef::{XCBDrawable, XCBWindow};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2Dri2Buffer {
+    pub attachment: u32,
+    pub name: u32,
+    pub pitch: u32,
+    pub cpp: u32,
+    pub flags: u32,
+}
+
+#[repr(C)]
+pub struct XCBDri2Dri2BufferIterator {
+    pub data: *mut XCBDri2Dri2Buffer,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2AttachFormat {
+    pub attachment: u32,
+    pub format: u32,
+}
+
+#[repr(C)]
+pub struct XCBDri2AttachFormatIterator {
+    pub data: *mut XCBDri2AttachFormat,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2QueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+pub type XCBDri2QueryVersionCookie = XCBCookie<XCBDri2QueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2QueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2ConnectRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub driver_type: u32,
+}
+
+pub type XCBDri2ConnectCookie = XCBCookie<XCBDri2ConnectReply>;
+
+#[repr(C)]
+pub struct XCBDri2ConnectReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub driver_name_length: u32,
+    pub device_name_length: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2AuthenticateRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub magic: u32,
+}
+
+pub type XCBDri2AuthenticateCookie = XCBCookie<XCBDri2AuthenticateReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2AuthenticateReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub authenticated: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2CreateDrawableRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2DestroyDrawableRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+}
+
+#[repr(C)]
+pub struct XCBDri2GetBuffersRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub count: u32,
+}
+
+pub type XCBDri2GetBuffersCookie = XCBCookie<XCBDri2GetBuffersReply>;
+
+#[repr(C)]
+pub struct XCBDri2GetBuffersReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub width: u32,
+    pub height: u32,
+    pub count: u32,
+    pub pad1: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2CopyRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub region: u32,
+    pub dest: u32,
+    pub src: u32,
+}
+
+pub type XCBDri2CopyRegionCookie = XCBCookie<XCBDri2CopyRegionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2CopyRegionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+}
+
+#[repr(C)]
+pub struct XCBDri2GetBuffersWithFormatRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub count: u32,
+}
+
+pub type XCBDri2GetBuffersWithFormatCookie = XCBCookie<XCBDri2GetBuffersWithFormatReply>;
+
+#[repr(C)]
+pub struct XCBDri2GetBuffersWithFormatReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub width: u32,
+    pub height: u32,
+    pub count: u32,
+    pub pad1: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2SwapBuffersRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub target_msc_hi: u32,
+    pub target_msc_lo: u32,
+    pub divisor_hi: u32,
+    pub divisor_lo: u32,
+    pub remainder_hi: u32,
+    pub remainder_lo: u32,
+}
+
+pub type XCBDri2SwapBuffersCookie = XCBCookie<XCBDri2SwapBuffersReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2SwapBuffersReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub swap_hi: u32,
+    pub swap_lo: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2GetMscRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+}
+
+pub type XCBDri2GetMscCookie = XCBCookie<XCBDri2GetMscReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2GetMscReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub ust_hi: u32,
+    pub ust_lo: u32,
+    pub msc_hi: u32,
+    pub msc_lo: u32,
+    pub sbc_hi: u32,
+    pub sbc_lo: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2WaitMscRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub target_msc_hi: u32,
+    pub target_msc_lo: u32,
+    pub divisor_hi: u32,
+    pub divisor_lo: u32,
+    pub remainder_hi: u32,
+    pub remainder_lo: u32,
+}
+
+pub type XCBDri2WaitMscCookie = XCBCookie<XCBDri2WaitMscReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2WaitMscReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub ust_hi: u32,
+    pub ust_lo: u32,
+    pub msc_hi: u32,
+    pub msc_lo: u32,
+    pub sbc_hi: u32,
+    pub sbc_lo: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2WaitSbcRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub target_sbc_hi: u32,
+    pub target_sbc_lo: u32,
+}
+
+pub type XCBDri2WaitSbcCookie = XCBCookie<XCBDri2WaitSbcReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2WaitSbcReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub ust_hi: u32,
+    pub ust_lo: u32,
+    pub msc_hi: u32,
+    pub msc_lo: u32,
+    pub sbc_hi: u32,
+    pub sbc_lo: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2SwapIntervalRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub interval: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2GetParamRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub param: u32,
+}
+
+pub type XCBDri2GetParamCookie = XCBCookie<XCBDri2GetParamReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2GetParamReply {
+    pub response_type: u8,
+    pub is_param_recognized: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub value_hi: u32,
+    pub value_lo: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2BufferSwapCompleteEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub event_type: u16,
+    pub pad1: [u8; 2],
+    pub drawable: XCBDrawable,
+    pub ust_hi: u32,
+    pub ust_lo: u32,
+    pub msc_hi: u32,
+    pub msc_lo: u32,
+    pub sbc: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri2InvalidateBuffersEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub drawable: XCBDrawable,
+}
+
+#[link(name = "xcb-dri2")]
+extern "system" {
+    pub static mut xcb_dri2_id: XCBExtension;
+    pub fn xcb_dri2_dri2_buffer_next(i: *mut XCBDri2Dri2BufferIterator);
+    pub fn xcb_dri2_dri2_buffer_end(i: XCBDri2Dri2BufferIterator) -> XCBGenericIterator;
+    pub fn xcb_dri2_attach_format_next(i: *mut XCBDri2AttachFormatIterator);
+    pub fn xcb_dri2_attach_format_end(i: XCBDri2AttachFormatIterator) -> XCBGenericIterator;
+    pub fn xcb_dri2_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri2QueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri2QueryVersionReply;
+    pub fn xcb_dri2_query_version(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+    ) -> XCBDri2QueryVersionCookie;
+    pub fn xcb_dri2_query_version_unchecked(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+    ) -> XCBDri2QueryVersionCookie;
+    pub fn xcb_dri2_connect_driver_name(R: *const XCBDri2ConnectReply) -> *mut c_char;
+    pub fn xcb_dri2_connect_driver_name_length(R: *const XCBDri2ConnectReply) -> c_int;
+    pub fn xcb_dri2_connect_driver_name_end(R: *const XCBDri2ConnectReply) -> XCBGenericIterator;
+    pub fn xcb_dri2_connect_alignment_pad(R: *const XCBDri2ConnectReply) -> *mut c_void;
+    pub fn xcb_dri2_connect_alignment_pad_length(R: *const XCBDri2ConnectReply) -> c_int;
+    pub fn xcb_dri2_connect_alignment_pad_end(R: *const XCBDri2ConnectReply) -> XCBGenericIterator;
+    pub fn xcb_dri2_connect_device_name(R: *const XCBDri2ConnectReply) -> *mut c_char;
+    pub fn xcb_dri2_connect_device_name_length(R: *const XCBDri2ConnectReply) -> c_int;
+    pub fn xcb_dri2_connect_device_name_end(R: *const XCBDri2ConnectReply) -> XCBGenericIterator;
+    pub fn xcb_dri2_connect_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri2ConnectCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri2ConnectReply;
+    pub fn xcb_dri2_connect(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        driver_type: u32,
+    ) -> XCBDri2ConnectCookie;
+    pub fn xcb_dri2_connect_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        driver_type: u32,
+    ) -> XCBDri2ConnectCookie;
+    pub fn xcb_dri2_authenticate_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri2AuthenticateCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri2AuthenticateReply;
+    pub fn xcb_dri2_authenticate(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        magic: u32,
+    ) -> XCBDri2AuthenticateCookie;
+    pub fn xcb_dri2_authenticate_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        magic: u32,
+    ) -> XCBDri2AuthenticateCookie;
+    pub fn xcb_dri2_create_drawable_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dri2_create_drawable(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dri2_destroy_drawable_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dri2_destroy_drawable(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dri2_get_buffers_buffers(R: *const XCBDri2GetBuffersReply)
+        -> *mut XCBDri2Dri2Buffer;
+    pub fn xcb_dri2_get_buffers_buffers_length(R: *const XCBDri2GetBuffersReply) -> c_int;
+    pub fn xcb_dri2_get_buffers_buffers_iterator(
+        R: *const XCBDri2GetBuffersReply,
+    ) -> XCBDri2Dri2BufferIterator;
+    pub fn xcb_dri2_get_buffers_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri2GetBuffersCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri2GetBuffersReply;
+    pub fn xcb_dri2_get_buffers(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        count: u32,
+        attachments_len: u32,
+        attachments: *const u32,
+    ) -> XCBDri2GetBuffersCookie;
+    pub fn xcb_dri2_get_buffers_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        count: u32,
+        attachments_len: u32,
+        attachments: *const u32,
+    ) -> XCBDri2GetBuffersCookie;
+    pub fn xcb_dri2_copy_region_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri2CopyRegionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri2CopyRegionReply;
+    pub fn xcb_dri2_copy_region(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        region: u32,
+        dest: u32,
+        src: u32,
+    ) -> XCBDri2CopyRegionCookie;
+    pub fn xcb_dri2_copy_region_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        region: u32,
+        dest: u32,
+        src: u32,
+    ) -> XCBDri2CopyRegionCookie;
+    pub fn xcb_dri2_get_buffers_with_format_buffers(
+        R: *const XCBDri2GetBuffersWithFormatReply,
+    ) -> *mut XCBDri2Dri2Buffer;
+    pub fn xcb_dri2_get_buffers_with_format_buffers_length(
+        R: *const XCBDri2GetBuffersWithFormatReply,
+    ) -> c_int;
+    pub fn xcb_dri2_get_buffers_with_format_buffers_iterator(
+        R: *const XCBDri2GetBuffersWithFormatReply,
+    ) -> XCBDri2Dri2BufferIterator;
+    pub fn xcb_dri2_get_buffers_with_format_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri2GetBuffersWithFormatCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri2GetBuffersWithFormatReply;
+    pub fn xcb_dri2_get_buffers_with_format(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        count: u32,
+        attachments_len: u32,
+        attachments: *const XCBDri2AttachFormat,
+    ) -> XCBDri2GetBuffersWithFormatCookie;
+    pub fn xcb_dri2_get_buffers_with_format_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        count: u32,
+        attachments_len: u32,
+        attachments: *const XCBDri2AttachFormat,
+    ) -> XCBDri2GetBuffersWithFormatCookie;
+    pub fn xcb_dri2_swap_buffers_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri2SwapBuffersCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri2SwapBuffersReply;
+    pub fn xcb_dri2_swap_buffers(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        target_msc_hi: u32,
+        target_msc_lo: u32,
+        divisor_hi: u32,
+        divisor_lo: u32,
+        remainder_hi: u32,
+        remainder_lo: u32,
+    ) -> XCBDri2SwapBuffersCookie;
+    pub fn xcb_dri2_swap_buffers_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        target_msc_hi: u32,
+        target_msc_lo: u32,
+        divisor_hi: u32,
+        divisor_lo: u32,
+        remainder_hi: u32,
+        remainder_lo: u32,
+    ) -> XCBDri2SwapBuffersCookie;
+    pub fn xcb_dri2_get_msc_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri2GetMscCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri2GetMscReply;
+    pub fn xcb_dri2_get_msc(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBDri2GetMscCookie;
+    pub fn xcb_dri2_get_msc_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBDri2GetMscCookie;
+    pub fn xcb_dri2_wait_msc_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri2WaitMscCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri2WaitMscReply;
+    pub fn xcb_dri2_wait_msc(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        target_msc_hi: u32,
+        target_msc_lo: u32,
+        divisor_hi: u32,
+        divisor_lo: u32,
+        remainder_hi: u32,
+        remainder_lo: u32,
+    ) -> XCBDri2WaitMscCookie;
+    pub fn xcb_dri2_wait_msc_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        target_msc_hi: u32,
+        target_msc_lo: u32,
+        divisor_hi: u32,
+        divisor_lo: u32,
+        remainder_hi: u32,
+        remainder_lo: u32,
+    ) -> XCBDri2WaitMscCookie;
+    pub fn xcb_dri2_wait_sbc_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri2WaitSbcCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri2WaitSbcReply;
+    pub fn xcb_dri2_wait_sbc(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        target_sbc_hi: u32,
+        target_sbc_lo: u32,
+    ) -> XCBDri2WaitSbcCookie;
+    pub fn xcb_dri2_wait_sbc_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        target_sbc_hi: u32,
+        target_sbc_lo: u32,
+    ) -> XCBDri2WaitSbcCookie;
+    pub fn xcb_dri2_swap_interval_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        interval: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dri2_swap_interval(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        interval: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dri2_get_param_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri2GetParamCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri2GetParamReply;
+    pub fn xcb_dri2_get_param(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        param: u32,
+    ) -> XCBDri2GetParamCookie;
+    pub fn xcb_dri2_get_param_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        param: u32,
+    ) -> XCBDri2GetParamCookie;
+}
@@ -0,0 +1,36 @@
+// Generated by xcbgen from xml/dri2.xml, do not edit.
+
+pub const XCB_DRI2_MAJOR_VERSION: u32 = 1;
+pub const XCB_DRI2_MINOR_VERSION: u32 = 4;
+pub const XCB_DRI2_ATTACHMENT_BUFFER_FRONT_LEFT: u32 = 0;
+pub const XCB_DRI2_ATTACHMENT_BUFFER_BACK_LEFT: u32 = 1;
+pub const XCB_DRI2_ATTACHMENT_BUFFER_FRONT_RIGHT: u32 = 2;
+pub const XCB_DRI2_ATTACHMENT_BUFFER_BACK_RIGHT: u32 = 3;
+pub const XCB_DRI2_ATTACHMENT_BUFFER_DEPTH: u32 = 4;
+pub const XCB_DRI2_ATTACHMENT_BUFFER_STENCIL: u32 = 5;
+pub const XCB_DRI2_ATTACHMENT_BUFFER_ACCUM: u32 = 6;
+pub const XCB_DRI2_ATTACHMENT_BUFFER_FAKE_FRONT_LEFT: u32 = 7;
+pub const XCB_DRI2_ATTACHMENT_BUFFER_FAKE_FRONT_RIGHT: u32 = 8;
+pub const XCB_DRI2_ATTACHMENT_BUFFER_DEPTH_STENCIL: u32 = 9;
+pub const XCB_DRI2_ATTACHMENT_BUFFER_HIZ: u32 = 10;
+pub const XCB_DRI2_DRIVER_TYPE_DRI: u32 = 0;
+pub const XCB_DRI2_DRIVER_TYPE_VDPAU: u32 = 1;
+pub const XCB_DRI2_EVENT_TYPE_EXCHANGE_COMPLETE: u16 = 1;
+pub const XCB_DRI2_EVENT_TYPE_BLIT_COMPLETE: u16 = 2;
+pub const XCB_DRI2_EVENT_TYPE_FLIP_COMPLETE: u16 = 3;
+pub const XCB_DRI2_QUERY_VERSION: u8 = 0;
+pub const XCB_DRI2_CONNECT: u8 = 1;
+pub const XCB_DRI2_AUTHENTICATE: u8 = 2;
+pub const XCB_DRI2_CREATE_DRAWABLE: u8 = 3;
+pub const XCB_DRI2_DESTROY_DRAWABLE: u8 = 4;
+pub const XCB_DRI2_GET_BUFFERS: u8 = 5;
+pub const XCB_DRI2_COPY_REGION: u8 = 6;
+pub const XCB_DRI2_GET_BUFFERS_WITH_FORMAT: u8 = 7;
+pub const XCB_DRI2_SWAP_BUFFERS: u8 = 8;
+pub const XCB_DRI2_GET_MSC: u8 = 9;
+pub const XCB_DRI2_WAIT_MSC: u8 = 10;
+pub const XCB_DRI2_WAIT_SBC: u8 = 11;
+pub const XCB_DRI2_SWAP_INTERVAL: u8 = 12;
+pub const XCB_DRI2_GET_PARAM: u8 = 13;
+pub const XCB_DRI2_BUFFER_SWAP_COMPLETE: u8 = 0;
+pub const XCB_DRI2_INVALIDATE_BUFFERS: u8 = 1;
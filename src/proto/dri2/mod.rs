@@ -0,0 +1,417 @@
+// Generated by xcbgen from xml/dri2.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use proto::xproto::{XCBDrawable, XCBWindow};
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBDri2Dri2Buffer = cdef::XCBDri2Dri2Buffer;
+pub type XCBDri2Dri2BufferIterator = cdef::XCBDri2Dri2BufferIterator;
+pub type XCBDri2AttachFormat = cdef::XCBDri2AttachFormat;
+pub type XCBDri2AttachFormatIterator = cdef::XCBDri2AttachFormatIterator;
+pub type XCBDri2QueryVersionRequest = cdef::XCBDri2QueryVersionRequest;
+pub type XCBDri2QueryVersionCookie = cdef::XCBDri2QueryVersionCookie;
+pub type XCBDri2QueryVersionReply = cdef::XCBDri2QueryVersionReply;
+pub type XCBDri2ConnectRequest = cdef::XCBDri2ConnectRequest;
+pub type XCBDri2ConnectCookie = cdef::XCBDri2ConnectCookie;
+pub type XCBDri2ConnectReply = cdef::XCBDri2ConnectReply;
+pub type XCBDri2AuthenticateRequest = cdef::XCBDri2AuthenticateRequest;
+pub type XCBDri2AuthenticateCookie = cdef::XCBDri2AuthenticateCookie;
+pub type XCBDri2AuthenticateReply = cdef::XCBDri2AuthenticateReply;
+pub type XCBDri2CreateDrawableRequest = cdef::XCBDri2CreateDrawableRequest;
+pub type XCBDri2DestroyDrawableRequest = cdef::XCBDri2DestroyDrawableRequest;
+pub type XCBDri2GetBuffersRequest = cdef::XCBDri2GetBuffersRequest;
+pub type XCBDri2GetBuffersCookie = cdef::XCBDri2GetBuffersCookie;
+pub type XCBDri2GetBuffersReply = cdef::XCBDri2GetBuffersReply;
+pub type XCBDri2CopyRegionRequest = cdef::XCBDri2CopyRegionRequest;
+pub type XCBDri2CopyRegionCookie = cdef::XCBDri2CopyRegionCookie;
+pub type XCBDri2CopyRegionReply = cdef::XCBDri2CopyRegionReply;
+pub type XCBDri2GetBuffersWithFormatRequest = cdef::XCBDri2GetBuffersWithFormatRequest;
+pub type XCBDri2GetBuffersWithFormatCookie = cdef::XCBDri2GetBuffersWithFormatCookie;
+pub type XCBDri2GetBuffersWithFormatReply = cdef::XCBDri2GetBuffersWithFormatReply;
+pub type XCBDri2SwapBuffersRequest = cdef::XCBDri2SwapBuffersRequest;
+pub type XCBDri2SwapBuffersCookie = cdef::XCBDri2SwapBuffersCookie;
+pub type XCBDri2SwapBuffersReply = cdef::XCBDri2SwapBuffersReply;
+pub type XCBDri2GetMscRequest = cdef::XCBDri2GetMscRequest;
+pub type XCBDri2GetMscCookie = cdef::XCBDri2GetMscCookie;
+pub type XCBDri2GetMscReply = cdef::XCBDri2GetMscReply;
+pub type XCBDri2WaitMscRequest = cdef::XCBDri2WaitMscRequest;
+pub type XCBDri2WaitMscCookie = cdef::XCBDri2WaitMscCookie;
+pub type XCBDri2WaitMscReply = cdef::XCBDri2WaitMscReply;
+pub type XCBDri2WaitSbcRequest = cdef::XCBDri2WaitSbcRequest;
+pub type XCBDri2WaitSbcCookie = cdef::XCBDri2WaitSbcCookie;
+pub type XCBDri2WaitSbcReply = cdef::XCBDri2WaitSbcReply;
+pub type XCBDri2SwapIntervalRequest = cdef::XCBDri2SwapIntervalRequest;
+pub type XCBDri2GetParamRequest = cdef::XCBDri2GetParamRequest;
+pub type XCBDri2GetParamCookie = cdef::XCBDri2GetParamCookie;
+pub type XCBDri2GetParamReply = cdef::XCBDri2GetParamReply;
+pub type XCBDri2BufferSwapCompleteEvent = cdef::XCBDri2BufferSwapCompleteEvent;
+pub type XCBDri2InvalidateBuffersEvent = cdef::XCBDri2InvalidateBuffersEvent;
+
+/// The DRI2 extension, known to the server as `DRI2`.
+pub struct Dri2;
+
+impl Extension for Dri2 {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_dri2_id)
+    }
+}
+
+pub fn xcb_dri2_dri2_buffer_next(iter: &mut XCBDri2Dri2BufferIterator) {
+    unsafe { cdef::xcb_dri2_dri2_buffer_next(iter) }
+}
+
+pub fn xcb_dri2_attach_format_next(iter: &mut XCBDri2AttachFormatIterator) {
+    unsafe { cdef::xcb_dri2_attach_format_next(iter) }
+}
+
+impl Reply for XCBDri2QueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri2_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri2_query_version(
+    connection: &XCBConnection,
+    major_version: u32,
+    minor_version: u32,
+) -> XCBDri2QueryVersionCookie {
+    unsafe { cdef::xcb_dri2_query_version(connection.raw, major_version, minor_version) }
+}
+
+pub fn xcb_dri2_connect_driver_name(reply: &XCBDri2ConnectReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_dri2_connect_driver_name(reply) as *const u8,
+            cdef::xcb_dri2_connect_driver_name_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_dri2_connect_alignment_pad(reply: &XCBDri2ConnectReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_dri2_connect_alignment_pad(reply) as *const u8,
+            cdef::xcb_dri2_connect_alignment_pad_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_dri2_connect_device_name(reply: &XCBDri2ConnectReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_dri2_connect_device_name(reply) as *const u8,
+            cdef::xcb_dri2_connect_device_name_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBDri2ConnectReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri2_connect_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri2_connect(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    driver_type: u32,
+) -> XCBDri2ConnectCookie {
+    unsafe { cdef::xcb_dri2_connect(connection.raw, window, driver_type) }
+}
+
+impl Reply for XCBDri2AuthenticateReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri2_authenticate_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri2_authenticate(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    magic: u32,
+) -> XCBDri2AuthenticateCookie {
+    unsafe { cdef::xcb_dri2_authenticate(connection.raw, window, magic) }
+}
+
+pub fn xcb_dri2_create_drawable(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_dri2_create_drawable(connection.raw, drawable) }
+}
+
+pub fn xcb_dri2_destroy_drawable(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_dri2_destroy_drawable(connection.raw, drawable) }
+}
+
+pub fn xcb_dri2_get_buffers_buffers(reply: &XCBDri2GetBuffersReply) -> &[XCBDri2Dri2Buffer] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_dri2_get_buffers_buffers(reply),
+            cdef::xcb_dri2_get_buffers_buffers_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBDri2GetBuffersReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri2_get_buffers_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri2_get_buffers(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    count: u32,
+    attachments: &[u32],
+) -> XCBDri2GetBuffersCookie {
+    unsafe {
+        cdef::xcb_dri2_get_buffers(
+            connection.raw,
+            drawable,
+            count,
+            attachments.len() as u32,
+            attachments.as_ptr(),
+        )
+    }
+}
+
+impl Reply for XCBDri2CopyRegionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri2_copy_region_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri2_copy_region(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    region: u32,
+    dest: u32,
+    src: u32,
+) -> XCBDri2CopyRegionCookie {
+    unsafe { cdef::xcb_dri2_copy_region(connection.raw, drawable, region, dest, src) }
+}
+
+pub fn xcb_dri2_get_buffers_with_format_buffers(
+    reply: &XCBDri2GetBuffersWithFormatReply,
+) -> &[XCBDri2Dri2Buffer] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_dri2_get_buffers_with_format_buffers(reply),
+            cdef::xcb_dri2_get_buffers_with_format_buffers_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBDri2GetBuffersWithFormatReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri2_get_buffers_with_format_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri2_get_buffers_with_format(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    count: u32,
+    attachments: &[XCBDri2AttachFormat],
+) -> XCBDri2GetBuffersWithFormatCookie {
+    unsafe {
+        cdef::xcb_dri2_get_buffers_with_format(
+            connection.raw,
+            drawable,
+            count,
+            attachments.len() as u32,
+            attachments.as_ptr(),
+        )
+    }
+}
+
+impl Reply for XCBDri2SwapBuffersReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri2_swap_buffers_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri2_swap_buffers(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    target_msc_hi: u32,
+    target_msc_lo: u32,
+    divisor_hi: u32,
+    divisor_lo: u32,
+    remainder_hi: u32,
+    remainder_lo: u32,
+) -> XCBDri2SwapBuffersCookie {
+    unsafe {
+        cdef::xcb_dri2_swap_buffers(
+            connection.raw,
+            drawable,
+            target_msc_hi,
+            target_msc_lo,
+            divisor_hi,
+            divisor_lo,
+            remainder_hi,
+            remainder_lo,
+        )
+    }
+}
+
+impl Reply for XCBDri2GetMscReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri2_get_msc_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri2_get_msc(connection: &XCBConnection, drawable: XCBDrawable) -> XCBDri2GetMscCookie {
+    unsafe { cdef::xcb_dri2_get_msc(connection.raw, drawable) }
+}
+
+impl Reply for XCBDri2WaitMscReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri2_wait_msc_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri2_wait_msc(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    target_msc_hi: u32,
+    target_msc_lo: u32,
+    divisor_hi: u32,
+    divisor_lo: u32,
+    remainder_hi: u32,
+    remainder_lo: u32,
+) -> XCBDri2WaitMscCookie {
+    unsafe {
+        cdef::xcb_dri2_wait_msc(
+            connection.raw,
+            drawable,
+            target_msc_hi,
+            target_msc_lo,
+            divisor_hi,
+            divisor_lo,
+            remainder_hi,
+            remainder_lo,
+        )
+    }
+}
+
+impl Reply for XCBDri2WaitSbcReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri2_wait_sbc_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri2_wait_sbc(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    target_sbc_hi: u32,
+    target_sbc_lo: u32,
+) -> XCBDri2WaitSbcCookie {
+    unsafe { cdef::xcb_dri2_wait_sbc(connection.raw, drawable, target_sbc_hi, target_sbc_lo) }
+}
+
+pub fn xcb_dri2_swap_interval(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    interval: u32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_dri2_swap_interval(connection.raw, drawable, interval) }
+}
+
+impl Reply for XCBDri2GetParamReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri2_get_param_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri2_get_param(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    param: u32,
+) -> XCBDri2GetParamCookie {
+    unsafe { cdef::xcb_dri2_get_param(connection.raw, drawable, param) }
+}
+
+/// An event of the DRI2 extension.
+pub enum Event {
+    BufferSwapComplete(Box<XCBDri2BufferSwapCompleteEvent>),
+    InvalidateBuffers(Box<XCBDri2InvalidateBuffersEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::BufferSwapComplete(Box::from_raw(
+                    raw as *mut XCBDri2BufferSwapCompleteEvent,
+                ))),
+                1 => Ok(Event::InvalidateBuffers(Box::from_raw(
+                    raw as *mut XCBDri2InvalidateBuffersEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
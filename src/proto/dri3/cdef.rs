@@ -0,0 +1,431 @@
+// Generated by xcbgen from xml/dri3.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xproto::cdef::{XCBDrawable, XCBPixmap, XCBWindow};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri3QueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+pub type XCBDri3QueryVersionCookie = XCBCookie<XCBDri3QueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri3QueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri3OpenRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub provider: u32,
+}
+
+pub type XCBDri3OpenCookie = XCBCookie<XCBDri3OpenReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri3OpenReply {
+    pub response_type: u8,
+    pub nfd: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad0: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri3PixmapFromBufferRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub pixmap: XCBPixmap,
+    pub drawable: XCBDrawable,
+    pub size: u32,
+    pub width: u16,
+    pub height: u16,
+    pub stride: u16,
+    pub depth: u8,
+    pub bpp: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri3BufferFromPixmapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub pixmap: XCBPixmap,
+}
+
+pub type XCBDri3BufferFromPixmapCookie = XCBCookie<XCBDri3BufferFromPixmapReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri3BufferFromPixmapReply {
+    pub response_type: u8,
+    pub nfd: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub size: u32,
+    pub width: u16,
+    pub height: u16,
+    pub stride: u16,
+    pub depth: u8,
+    pub bpp: u8,
+    pub pad0: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri3FenceFromFdRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub fence: u32,
+    pub initially_triggered: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri3FdFromFenceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub fence: u32,
+}
+
+pub type XCBDri3FdFromFenceCookie = XCBCookie<XCBDri3FdFromFenceReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri3FdFromFenceReply {
+    pub response_type: u8,
+    pub nfd: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad0: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri3GetSupportedModifiersRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: u32,
+    pub depth: u8,
+    pub bpp: u8,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBDri3GetSupportedModifiersCookie = XCBCookie<XCBDri3GetSupportedModifiersReply>;
+
+#[repr(C)]
+pub struct XCBDri3GetSupportedModifiersReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_window_modifiers: u32,
+    pub num_screen_modifiers: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+pub struct XCBDri3PixmapFromBuffersRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub pixmap: XCBPixmap,
+    pub window: XCBWindow,
+    pub num_buffers: u8,
+    pub pad0: [u8; 3],
+    pub width: u16,
+    pub height: u16,
+    pub stride0: u32,
+    pub offset0: u32,
+    pub stride1: u32,
+    pub offset1: u32,
+    pub stride2: u32,
+    pub offset2: u32,
+    pub stride3: u32,
+    pub offset3: u32,
+    pub depth: u8,
+    pub bpp: u8,
+    pub pad1: [u8; 2],
+    pub modifier: u64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDri3BuffersFromPixmapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub pixmap: XCBPixmap,
+}
+
+pub type XCBDri3BuffersFromPixmapCookie = XCBCookie<XCBDri3BuffersFromPixmapReply>;
+
+#[repr(C)]
+pub struct XCBDri3BuffersFromPixmapReply {
+    pub response_type: u8,
+    pub nfd: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub width: u16,
+    pub height: u16,
+    pub pad0: [u8; 4],
+    pub modifier: u64,
+    pub depth: u8,
+    pub bpp: u8,
+    pub pad1: [u8; 6],
+}
+
+#[link(name = "xcb-dri3")]
+extern "system" {
+    pub static mut xcb_dri3_id: XCBExtension;
+    pub fn xcb_dri3_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri3QueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri3QueryVersionReply;
+    pub fn xcb_dri3_query_version(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+    ) -> XCBDri3QueryVersionCookie;
+    pub fn xcb_dri3_query_version_unchecked(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+    ) -> XCBDri3QueryVersionCookie;
+    pub fn xcb_dri3_open_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri3OpenCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri3OpenReply;
+    pub fn xcb_dri3_open_reply_fds(
+        connection: *mut XCBConnection,
+        reply: *mut XCBDri3OpenReply,
+    ) -> *mut c_int;
+    pub fn xcb_dri3_open(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        provider: u32,
+    ) -> XCBDri3OpenCookie;
+    pub fn xcb_dri3_open_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        provider: u32,
+    ) -> XCBDri3OpenCookie;
+    pub fn xcb_dri3_pixmap_from_buffer_checked(
+        connection: *mut XCBConnection,
+        pixmap: XCBPixmap,
+        drawable: XCBDrawable,
+        size: u32,
+        width: u16,
+        height: u16,
+        stride: u16,
+        depth: u8,
+        bpp: u8,
+        pixmap_fd: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dri3_pixmap_from_buffer(
+        connection: *mut XCBConnection,
+        pixmap: XCBPixmap,
+        drawable: XCBDrawable,
+        size: u32,
+        width: u16,
+        height: u16,
+        stride: u16,
+        depth: u8,
+        bpp: u8,
+        pixmap_fd: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dri3_buffer_from_pixmap_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri3BufferFromPixmapCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri3BufferFromPixmapReply;
+    pub fn xcb_dri3_buffer_from_pixmap_reply_fds(
+        connection: *mut XCBConnection,
+        reply: *mut XCBDri3BufferFromPixmapReply,
+    ) -> *mut c_int;
+    pub fn xcb_dri3_buffer_from_pixmap(
+        connection: *mut XCBConnection,
+        pixmap: XCBPixmap,
+    ) -> XCBDri3BufferFromPixmapCookie;
+    pub fn xcb_dri3_buffer_from_pixmap_unchecked(
+        connection: *mut XCBConnection,
+        pixmap: XCBPixmap,
+    ) -> XCBDri3BufferFromPixmapCookie;
+    pub fn xcb_dri3_fence_from_fd_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        fence: u32,
+        initially_triggered: u8,
+        fence_fd: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dri3_fence_from_fd(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        fence: u32,
+        initially_triggered: u8,
+        fence_fd: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dri3_fd_from_fence_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri3FdFromFenceCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri3FdFromFenceReply;
+    pub fn xcb_dri3_fd_from_fence_reply_fds(
+        connection: *mut XCBConnection,
+        reply: *mut XCBDri3FdFromFenceReply,
+    ) -> *mut c_int;
+    pub fn xcb_dri3_fd_from_fence(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        fence: u32,
+    ) -> XCBDri3FdFromFenceCookie;
+    pub fn xcb_dri3_fd_from_fence_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        fence: u32,
+    ) -> XCBDri3FdFromFenceCookie;
+    pub fn xcb_dri3_get_supported_modifiers_window_modifiers(
+        R: *const XCBDri3GetSupportedModifiersReply,
+    ) -> *mut u64;
+    pub fn xcb_dri3_get_supported_modifiers_window_modifiers_length(
+        R: *const XCBDri3GetSupportedModifiersReply,
+    ) -> c_int;
+    pub fn xcb_dri3_get_supported_modifiers_window_modifiers_end(
+        R: *const XCBDri3GetSupportedModifiersReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_dri3_get_supported_modifiers_screen_modifiers(
+        R: *const XCBDri3GetSupportedModifiersReply,
+    ) -> *mut u64;
+    pub fn xcb_dri3_get_supported_modifiers_screen_modifiers_length(
+        R: *const XCBDri3GetSupportedModifiersReply,
+    ) -> c_int;
+    pub fn xcb_dri3_get_supported_modifiers_screen_modifiers_end(
+        R: *const XCBDri3GetSupportedModifiersReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_dri3_get_supported_modifiers_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri3GetSupportedModifiersCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri3GetSupportedModifiersReply;
+    pub fn xcb_dri3_get_supported_modifiers(
+        connection: *mut XCBConnection,
+        window: u32,
+        depth: u8,
+        bpp: u8,
+    ) -> XCBDri3GetSupportedModifiersCookie;
+    pub fn xcb_dri3_get_supported_modifiers_unchecked(
+        connection: *mut XCBConnection,
+        window: u32,
+        depth: u8,
+        bpp: u8,
+    ) -> XCBDri3GetSupportedModifiersCookie;
+    pub fn xcb_dri3_pixmap_from_buffers_checked(
+        connection: *mut XCBConnection,
+        pixmap: XCBPixmap,
+        window: XCBWindow,
+        num_buffers: u8,
+        width: u16,
+        height: u16,
+        stride0: u32,
+        offset0: u32,
+        stride1: u32,
+        offset1: u32,
+        stride2: u32,
+        offset2: u32,
+        stride3: u32,
+        offset3: u32,
+        depth: u8,
+        bpp: u8,
+        modifier: u64,
+        buffers: *const i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dri3_pixmap_from_buffers(
+        connection: *mut XCBConnection,
+        pixmap: XCBPixmap,
+        window: XCBWindow,
+        num_buffers: u8,
+        width: u16,
+        height: u16,
+        stride0: u32,
+        offset0: u32,
+        stride1: u32,
+        offset1: u32,
+        stride2: u32,
+        offset2: u32,
+        stride3: u32,
+        offset3: u32,
+        depth: u8,
+        bpp: u8,
+        modifier: u64,
+        buffers: *const i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_dri3_buffers_from_pixmap_strides(
+        R: *const XCBDri3BuffersFromPixmapReply,
+    ) -> *mut u32;
+    pub fn xcb_dri3_buffers_from_pixmap_strides_length(
+        R: *const XCBDri3BuffersFromPixmapReply,
+    ) -> c_int;
+    pub fn xcb_dri3_buffers_from_pixmap_strides_end(
+        R: *const XCBDri3BuffersFromPixmapReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_dri3_buffers_from_pixmap_offsets(
+        R: *const XCBDri3BuffersFromPixmapReply,
+    ) -> *mut u32;
+    pub fn xcb_dri3_buffers_from_pixmap_offsets_length(
+        R: *const XCBDri3BuffersFromPixmapReply,
+    ) -> c_int;
+    pub fn xcb_dri3_buffers_from_pixmap_offsets_end(
+        R: *const XCBDri3BuffersFromPixmapReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_dri3_buffers_from_pixmap_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBDri3BuffersFromPixmapCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBDri3BuffersFromPixmapReply;
+    pub fn xcb_dri3_buffers_from_pixmap_reply_fds(
+        connection: *mut XCBConnection,
+        reply: *mut XCBDri3BuffersFromPixmapReply,
+    ) -> *mut c_int;
+    pub fn xcb_dri3_buffers_from_pixmap(
+        connection: *mut XCBConnection,
+        pixmap: XCBPixmap,
+    ) -> XCBDri3BuffersFromPixmapCookie;
+    pub fn xcb_dri3_buffers_from_pixmap_unchecked(
+        connection: *mut XCBConnection,
+        pixmap: XCBPixmap,
+    ) -> XCBDri3BuffersFromPixmapCookie;
+}
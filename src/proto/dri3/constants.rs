@@ -0,0 +1,13 @@
+// Generated by xcbgen from xml/dri3.xml, do not edit.
+
+pub const XCB_DRI3_MAJOR_VERSION: u32 = 1;
+pub const XCB_DRI3_MINOR_VERSION: u32 = 2;
+pub const XCB_DRI3_QUERY_VERSION: u8 = 0;
+pub const XCB_DRI3_OPEN: u8 = 1;
+pub const XCB_DRI3_PIXMAP_FROM_BUFFER: u8 = 2;
+pub const XCB_DRI3_BUFFER_FROM_PIXMAP: u8 = 3;
+pub const XCB_DRI3_FENCE_FROM_FD: u8 = 4;
+pub const XCB_DRI3_FD_FROM_FENCE: u8 = 5;
+pub const XCB_DRI3_GET_SUPPORTED_MODIFIERS: u8 = 6;
+pub const XCB_DRI3_PIXMAP_FROM_BUFFERS: u8 = 7;
+pub const XCB_DRI3_BUFFERS_FROM_PIXMAP: u8 = 8;
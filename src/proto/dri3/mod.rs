@@ -0,0 +1,279 @@
+// Generated by xcbgen from xml/dri3.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use proto::xproto::{XCBDrawable, XCBPixmap, XCBWindow};
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+
+pub type XCBDri3QueryVersionRequest = cdef::XCBDri3QueryVersionRequest;
+pub type XCBDri3QueryVersionCookie = cdef::XCBDri3QueryVersionCookie;
+pub type XCBDri3QueryVersionReply = cdef::XCBDri3QueryVersionReply;
+pub type XCBDri3OpenRequest = cdef::XCBDri3OpenRequest;
+pub type XCBDri3OpenCookie = cdef::XCBDri3OpenCookie;
+pub type XCBDri3OpenReply = cdef::XCBDri3OpenReply;
+pub type XCBDri3PixmapFromBufferRequest = cdef::XCBDri3PixmapFromBufferRequest;
+pub type XCBDri3BufferFromPixmapRequest = cdef::XCBDri3BufferFromPixmapRequest;
+pub type XCBDri3BufferFromPixmapCookie = cdef::XCBDri3BufferFromPixmapCookie;
+pub type XCBDri3BufferFromPixmapReply = cdef::XCBDri3BufferFromPixmapReply;
+pub type XCBDri3FenceFromFdRequest = cdef::XCBDri3FenceFromFdRequest;
+pub type XCBDri3FdFromFenceRequest = cdef::XCBDri3FdFromFenceRequest;
+pub type XCBDri3FdFromFenceCookie = cdef::XCBDri3FdFromFenceCookie;
+pub type XCBDri3FdFromFenceReply = cdef::XCBDri3FdFromFenceReply;
+pub type XCBDri3GetSupportedModifiersRequest = cdef::XCBDri3GetSupportedModifiersRequest;
+pub type XCBDri3GetSupportedModifiersCookie = cdef::XCBDri3GetSupportedModifiersCookie;
+pub type XCBDri3GetSupportedModifiersReply = cdef::XCBDri3GetSupportedModifiersReply;
+pub type XCBDri3PixmapFromBuffersRequest = cdef::XCBDri3PixmapFromBuffersRequest;
+pub type XCBDri3BuffersFromPixmapRequest = cdef::XCBDri3BuffersFromPixmapRequest;
+pub type XCBDri3BuffersFromPixmapCookie = cdef::XCBDri3BuffersFromPixmapCookie;
+pub type XCBDri3BuffersFromPixmapReply = cdef::XCBDri3BuffersFromPixmapReply;
+
+/// The DRI3 extension, known to the server as `DRI3`.
+pub struct Dri3;
+
+impl Extension for Dri3 {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_dri3_id)
+    }
+}
+
+impl Reply for XCBDri3QueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri3_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri3_query_version(
+    connection: &XCBConnection,
+    major_version: u32,
+    minor_version: u32,
+) -> XCBDri3QueryVersionCookie {
+    unsafe { cdef::xcb_dri3_query_version(connection.raw, major_version, minor_version) }
+}
+
+impl Reply for XCBDri3OpenReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri3_open_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri3_open(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    provider: u32,
+) -> XCBDri3OpenCookie {
+    unsafe { cdef::xcb_dri3_open(connection.raw, drawable, provider) }
+}
+
+pub fn xcb_dri3_pixmap_from_buffer(
+    connection: &XCBConnection,
+    pixmap: XCBPixmap,
+    drawable: XCBDrawable,
+    size: u32,
+    width: u16,
+    height: u16,
+    stride: u16,
+    depth: u8,
+    bpp: u8,
+    pixmap_fd: i32,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_dri3_pixmap_from_buffer(
+            connection.raw,
+            pixmap,
+            drawable,
+            size,
+            width,
+            height,
+            stride,
+            depth,
+            bpp,
+            pixmap_fd,
+        )
+    }
+}
+
+impl Reply for XCBDri3BufferFromPixmapReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri3_buffer_from_pixmap_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri3_buffer_from_pixmap(
+    connection: &XCBConnection,
+    pixmap: XCBPixmap,
+) -> XCBDri3BufferFromPixmapCookie {
+    unsafe { cdef::xcb_dri3_buffer_from_pixmap(connection.raw, pixmap) }
+}
+
+pub fn xcb_dri3_fence_from_fd(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    fence: u32,
+    initially_triggered: bool,
+    fence_fd: i32,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_dri3_fence_from_fd(
+            connection.raw,
+            drawable,
+            fence,
+            initially_triggered as u8,
+            fence_fd,
+        )
+    }
+}
+
+impl Reply for XCBDri3FdFromFenceReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri3_fd_from_fence_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri3_fd_from_fence(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    fence: u32,
+) -> XCBDri3FdFromFenceCookie {
+    unsafe { cdef::xcb_dri3_fd_from_fence(connection.raw, drawable, fence) }
+}
+
+pub fn xcb_dri3_get_supported_modifiers_window_modifiers(
+    reply: &XCBDri3GetSupportedModifiersReply,
+) -> &[u64] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_dri3_get_supported_modifiers_window_modifiers(reply),
+            cdef::xcb_dri3_get_supported_modifiers_window_modifiers_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_dri3_get_supported_modifiers_screen_modifiers(
+    reply: &XCBDri3GetSupportedModifiersReply,
+) -> &[u64] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_dri3_get_supported_modifiers_screen_modifiers(reply),
+            cdef::xcb_dri3_get_supported_modifiers_screen_modifiers_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBDri3GetSupportedModifiersReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri3_get_supported_modifiers_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri3_get_supported_modifiers(
+    connection: &XCBConnection,
+    window: u32,
+    depth: u8,
+    bpp: u8,
+) -> XCBDri3GetSupportedModifiersCookie {
+    unsafe { cdef::xcb_dri3_get_supported_modifiers(connection.raw, window, depth, bpp) }
+}
+
+pub fn xcb_dri3_pixmap_from_buffers(
+    connection: &XCBConnection,
+    pixmap: XCBPixmap,
+    window: XCBWindow,
+    width: u16,
+    height: u16,
+    stride0: u32,
+    offset0: u32,
+    stride1: u32,
+    offset1: u32,
+    stride2: u32,
+    offset2: u32,
+    stride3: u32,
+    offset3: u32,
+    depth: u8,
+    bpp: u8,
+    modifier: u64,
+    buffers: &[i32],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_dri3_pixmap_from_buffers(
+            connection.raw,
+            pixmap,
+            window,
+            buffers.len() as u8,
+            width,
+            height,
+            stride0,
+            offset0,
+            stride1,
+            offset1,
+            stride2,
+            offset2,
+            stride3,
+            offset3,
+            depth,
+            bpp,
+            modifier,
+            buffers.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_dri3_buffers_from_pixmap_strides(reply: &XCBDri3BuffersFromPixmapReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_dri3_buffers_from_pixmap_strides(reply),
+            cdef::xcb_dri3_buffers_from_pixmap_strides_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_dri3_buffers_from_pixmap_offsets(reply: &XCBDri3BuffersFromPixmapReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_dri3_buffers_from_pixmap_offsets(reply),
+            cdef::xcb_dri3_buffers_from_pixmap_offsets_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBDri3BuffersFromPixmapReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_dri3_buffers_from_pixmap_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_dri3_buffers_from_pixmap(
+    connection: &XCBConnection,
+    pixmap: XCBPixmap,
+) -> XCBDri3BuffersFromPixmapCookie {
+    unsafe { cdef::xcb_dri3_buffers_from_pixmap(connection.raw, pixmap) }
+}
@@ -0,0 +1,4039 @@
+// Generated by xcbgen from xml/glx.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int};
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xproto::cdef::{XCBFont, XCBPixmap, XCBVisualid, XCBWindow};
+
+pub type XCBGlxPixmap = u32;
+
+#[repr(C)]
+pub struct XCBGlxPixmapIterator {
+    pub data: *mut XCBGlxPixmap,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBGlxContext = u32;
+
+#[repr(C)]
+pub struct XCBGlxContextIterator {
+    pub data: *mut XCBGlxContext,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBGlxPbuffer = u32;
+
+#[repr(C)]
+pub struct XCBGlxPbufferIterator {
+    pub data: *mut XCBGlxPbuffer,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBGlxWindow = u32;
+
+#[repr(C)]
+pub struct XCBGlxWindowIterator {
+    pub data: *mut XCBGlxWindow,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBGlxFbconfig = u32;
+
+#[repr(C)]
+pub struct XCBGlxFbconfigIterator {
+    pub data: *mut XCBGlxFbconfig,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBGlxDrawable = u32;
+
+#[repr(C)]
+pub struct XCBGlxDrawableIterator {
+    pub data: *mut XCBGlxDrawable,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBGlxFloat32 = f32;
+
+#[repr(C)]
+pub struct XCBGlxFloat32Iterator {
+    pub data: *mut XCBGlxFloat32,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBGlxFloat64 = f64;
+
+#[repr(C)]
+pub struct XCBGlxFloat64Iterator {
+    pub data: *mut XCBGlxFloat64,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBGlxBool32 = u32;
+
+#[repr(C)]
+pub struct XCBGlxBool32Iterator {
+    pub data: *mut XCBGlxBool32,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBGlxContextTag = u32;
+
+#[repr(C)]
+pub struct XCBGlxContextTagIterator {
+    pub data: *mut XCBGlxContextTag,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGenericError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+    pub bad_value: u32,
+    pub minor_opcode: u16,
+    pub major_opcode: u8,
+    pub pad0: [u8; 21],
+}
+
+pub type XCBGlxBadContextError = XCBGlxGenericError;
+
+pub type XCBGlxBadContextStateError = XCBGlxGenericError;
+
+pub type XCBGlxBadDrawableError = XCBGlxGenericError;
+
+pub type XCBGlxBadPixmapError = XCBGlxGenericError;
+
+pub type XCBGlxBadContextTagError = XCBGlxGenericError;
+
+pub type XCBGlxBadCurrentWindowError = XCBGlxGenericError;
+
+pub type XCBGlxBadRenderRequestError = XCBGlxGenericError;
+
+pub type XCBGlxBadLargeRequestError = XCBGlxGenericError;
+
+pub type XCBGlxUnsupportedPrivateRequestError = XCBGlxGenericError;
+
+pub type XCBGlxBadFbConfigError = XCBGlxGenericError;
+
+pub type XCBGlxBadPbufferError = XCBGlxGenericError;
+
+pub type XCBGlxBadCurrentDrawableError = XCBGlxGenericError;
+
+pub type XCBGlxBadWindowError = XCBGlxGenericError;
+
+pub type XCBGlxGlxBadProfileArbError = XCBGlxGenericError;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxPbufferClobberEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub event_type: u16,
+    pub draw_type: u16,
+    pub drawable: XCBGlxDrawable,
+    pub b_mask: u32,
+    pub aux_buffer: u16,
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+    pub count: u16,
+    pub pad1: [u8; 4],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxBufferSwapCompleteEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub event_type: u16,
+    pub pad1: [u8; 2],
+    pub drawable: XCBGlxDrawable,
+    pub ust_hi: u32,
+    pub ust_lo: u32,
+    pub msc_hi: u32,
+    pub msc_lo: u32,
+    pub sbc: u32,
+}
+
+#[repr(C)]
+pub struct XCBGlxRenderRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+}
+
+#[repr(C)]
+pub struct XCBGlxRenderLargeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub request_num: u16,
+    pub request_total: u16,
+    pub data_len: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBGlxContext,
+    pub visual: XCBVisualid,
+    pub screen: u32,
+    pub share_list: XCBGlxContext,
+    pub is_direct: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxDestroyContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBGlxContext,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxMakeCurrentRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBGlxDrawable,
+    pub context: XCBGlxContext,
+    pub old_context_tag: XCBGlxContextTag,
+}
+
+pub type XCBGlxMakeCurrentCookie = XCBCookie<XCBGlxMakeCurrentReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxMakeCurrentReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_tag: XCBGlxContextTag,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxIsDirectRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBGlxContext,
+}
+
+pub type XCBGlxIsDirectCookie = XCBCookie<XCBGlxIsDirectReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxIsDirectReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub is_direct: u8,
+    pub pad1: [u8; 23],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+pub type XCBGlxQueryVersionCookie = XCBCookie<XCBGlxQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxWaitGlRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxWaitXRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxCopyContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub src: XCBGlxContext,
+    pub dest: XCBGlxContext,
+    pub mask: u32,
+    pub src_context_tag: XCBGlxContextTag,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxSwapBuffersRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub drawable: XCBGlxDrawable,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxUseXFontRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub font: XCBFont,
+    pub first: u32,
+    pub count: u32,
+    pub list_base: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxCreateGlxPixmapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+    pub visual: XCBVisualid,
+    pub pixmap: XCBPixmap,
+    pub glx_pixmap: XCBGlxPixmap,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetVisualConfigsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+}
+
+pub type XCBGlxGetVisualConfigsCookie = XCBCookie<XCBGlxGetVisualConfigsReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetVisualConfigsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_visuals: u32,
+    pub num_properties: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxDestroyGlxPixmapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub glx_pixmap: XCBGlxPixmap,
+}
+
+#[repr(C)]
+pub struct XCBGlxVendorPrivateRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub vendor_code: u32,
+    pub context_tag: XCBGlxContextTag,
+}
+
+#[repr(C)]
+pub struct XCBGlxVendorPrivateWithReplyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub vendor_code: u32,
+    pub context_tag: XCBGlxContextTag,
+}
+
+pub type XCBGlxVendorPrivateWithReplyCookie = XCBCookie<XCBGlxVendorPrivateWithReplyReply>;
+
+#[repr(C)]
+pub struct XCBGlxVendorPrivateWithReplyReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub retval: u32,
+    pub data1: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxQueryExtensionsStringRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+}
+
+pub type XCBGlxQueryExtensionsStringCookie = XCBCookie<XCBGlxQueryExtensionsStringReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxQueryExtensionsStringReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub pad2: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxQueryServerStringRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+    pub name: u32,
+}
+
+pub type XCBGlxQueryServerStringCookie = XCBCookie<XCBGlxQueryServerStringReply>;
+
+#[repr(C)]
+pub struct XCBGlxQueryServerStringReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub str_len: u32,
+    pub pad2: [u8; 16],
+}
+
+#[repr(C)]
+pub struct XCBGlxClientInfoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub str_len: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetFbConfigsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+}
+
+pub type XCBGlxGetFbConfigsCookie = XCBCookie<XCBGlxGetFbConfigsReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetFbConfigsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_FB_configs: u32,
+    pub num_properties: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+pub struct XCBGlxCreatePixmapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+    pub fbconfig: XCBGlxFbconfig,
+    pub pixmap: XCBPixmap,
+    pub glx_pixmap: XCBGlxPixmap,
+    pub num_attribs: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxDestroyPixmapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub glx_pixmap: XCBGlxPixmap,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxCreateNewContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBGlxContext,
+    pub fbconfig: XCBGlxFbconfig,
+    pub screen: u32,
+    pub render_type: u32,
+    pub share_list: XCBGlxContext,
+    pub is_direct: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxQueryContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBGlxContext,
+}
+
+pub type XCBGlxQueryContextCookie = XCBCookie<XCBGlxQueryContextReply>;
+
+#[repr(C)]
+pub struct XCBGlxQueryContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_attribs: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxMakeContextCurrentRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub old_context_tag: XCBGlxContextTag,
+    pub drawable: XCBGlxDrawable,
+    pub read_drawable: XCBGlxDrawable,
+    pub context: XCBGlxContext,
+}
+
+pub type XCBGlxMakeContextCurrentCookie = XCBCookie<XCBGlxMakeContextCurrentReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxMakeContextCurrentReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_tag: XCBGlxContextTag,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBGlxCreatePbufferRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+    pub fbconfig: XCBGlxFbconfig,
+    pub pbuffer: XCBGlxPbuffer,
+    pub num_attribs: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxDestroyPbufferRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub pbuffer: XCBGlxPbuffer,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetDrawableAttributesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBGlxDrawable,
+}
+
+pub type XCBGlxGetDrawableAttributesCookie = XCBCookie<XCBGlxGetDrawableAttributesReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetDrawableAttributesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_attribs: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBGlxChangeDrawableAttributesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBGlxDrawable,
+    pub num_attribs: u32,
+}
+
+#[repr(C)]
+pub struct XCBGlxCreateWindowRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+    pub fbconfig: XCBGlxFbconfig,
+    pub window: XCBWindow,
+    pub glx_window: XCBGlxWindow,
+    pub num_attribs: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxDeleteWindowRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub glxwindow: XCBGlxWindow,
+}
+
+#[repr(C)]
+pub struct XCBGlxSetClientInfoArbRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub num_versions: u32,
+    pub gl_str_len: u32,
+    pub glx_str_len: u32,
+}
+
+#[repr(C)]
+pub struct XCBGlxCreateContextAttribsArbRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBGlxContext,
+    pub fbconfig: XCBGlxFbconfig,
+    pub screen: u32,
+    pub share_list: XCBGlxContext,
+    pub is_direct: u8,
+    pub pad0: [u8; 3],
+    pub num_attribs: u32,
+}
+
+#[repr(C)]
+pub struct XCBGlxSetClientInfo2arbRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub num_versions: u32,
+    pub gl_str_len: u32,
+    pub glx_str_len: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxNewListRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub list: u32,
+    pub mode: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxEndListRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxDeleteListsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub list: u32,
+    pub range: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGenListsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub range: i32,
+}
+
+pub type XCBGlxGenListsCookie = XCBCookie<XCBGlxGenListsReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGenListsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxFeedbackBufferRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub size: i32,
+    pub type_: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxSelectBufferRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub size: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxRenderModeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub mode: u32,
+}
+
+pub type XCBGlxRenderModeCookie = XCBCookie<XCBGlxRenderModeReply>;
+
+#[repr(C)]
+pub struct XCBGlxRenderModeReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: u32,
+    pub n: u32,
+    pub new_mode: u32,
+    pub pad1: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxFinishRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+}
+
+pub type XCBGlxFinishCookie = XCBCookie<XCBGlxFinishReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxFinishReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxPixelStorefRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub pname: u32,
+    pub datum: XCBGlxFloat32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxPixelStoreiRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub pname: u32,
+    pub datum: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxReadPixelsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: u8,
+    pub lsb_first: u8,
+}
+
+pub type XCBGlxReadPixelsCookie = XCBCookie<XCBGlxReadPixelsReply>;
+
+#[repr(C)]
+pub struct XCBGlxReadPixelsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetBooleanvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub pname: i32,
+}
+
+pub type XCBGlxGetBooleanvCookie = XCBCookie<XCBGlxGetBooleanvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetBooleanvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: u8,
+    pub pad2: [u8; 15],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetClipPlaneRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub plane: i32,
+}
+
+pub type XCBGlxGetClipPlaneCookie = XCBCookie<XCBGlxGetClipPlaneReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetClipPlaneReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetDoublevRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetDoublevCookie = XCBCookie<XCBGlxGetDoublevReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetDoublevReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat64,
+    pub pad2: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetErrorRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+}
+
+pub type XCBGlxGetErrorCookie = XCBCookie<XCBGlxGetErrorReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetErrorReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub error: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetFloatvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetFloatvCookie = XCBCookie<XCBGlxGetFloatvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetFloatvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetIntegervRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetIntegervCookie = XCBCookie<XCBGlxGetIntegervReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetIntegervReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetLightfvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub light: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetLightfvCookie = XCBCookie<XCBGlxGetLightfvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetLightfvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetLightivRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub light: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetLightivCookie = XCBCookie<XCBGlxGetLightivReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetLightivReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetMapdvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub query: u32,
+}
+
+pub type XCBGlxGetMapdvCookie = XCBCookie<XCBGlxGetMapdvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetMapdvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat64,
+    pub pad2: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetMapfvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub query: u32,
+}
+
+pub type XCBGlxGetMapfvCookie = XCBCookie<XCBGlxGetMapfvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetMapfvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetMapivRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub query: u32,
+}
+
+pub type XCBGlxGetMapivCookie = XCBCookie<XCBGlxGetMapivReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetMapivReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetMaterialfvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub face: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetMaterialfvCookie = XCBCookie<XCBGlxGetMaterialfvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetMaterialfvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetMaterialivRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub face: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetMaterialivCookie = XCBCookie<XCBGlxGetMaterialivReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetMaterialivReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetPixelMapfvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub map: u32,
+}
+
+pub type XCBGlxGetPixelMapfvCookie = XCBCookie<XCBGlxGetPixelMapfvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetPixelMapfvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetPixelMapuivRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub map: u32,
+}
+
+pub type XCBGlxGetPixelMapuivCookie = XCBCookie<XCBGlxGetPixelMapuivReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetPixelMapuivReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: u32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetPixelMapusvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub map: u32,
+}
+
+pub type XCBGlxGetPixelMapusvCookie = XCBCookie<XCBGlxGetPixelMapusvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetPixelMapusvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: u16,
+    pub pad2: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetPolygonStippleRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub lsb_first: u8,
+}
+
+pub type XCBGlxGetPolygonStippleCookie = XCBCookie<XCBGlxGetPolygonStippleReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetPolygonStippleReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetStringRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub name: u32,
+}
+
+pub type XCBGlxGetStringCookie = XCBCookie<XCBGlxGetStringReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetStringReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub pad2: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetTexEnvfvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetTexEnvfvCookie = XCBCookie<XCBGlxGetTexEnvfvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetTexEnvfvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetTexEnvivRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetTexEnvivCookie = XCBCookie<XCBGlxGetTexEnvivReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetTexEnvivReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetTexGendvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub coord: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetTexGendvCookie = XCBCookie<XCBGlxGetTexGendvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetTexGendvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat64,
+    pub pad2: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetTexGenfvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub coord: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetTexGenfvCookie = XCBCookie<XCBGlxGetTexGenfvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetTexGenfvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetTexGenivRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub coord: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetTexGenivCookie = XCBCookie<XCBGlxGetTexGenivReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetTexGenivReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetTexImageRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub level: i32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: u8,
+}
+
+pub type XCBGlxGetTexImageCookie = XCBCookie<XCBGlxGetTexImageReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetTexImageReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 8],
+    pub width: i32,
+    pub height: i32,
+    pub depth: i32,
+    pub pad2: [u8; 4],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetTexParameterfvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetTexParameterfvCookie = XCBCookie<XCBGlxGetTexParameterfvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetTexParameterfvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetTexParameterivRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetTexParameterivCookie = XCBCookie<XCBGlxGetTexParameterivReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetTexParameterivReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetTexLevelParameterfvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub level: i32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetTexLevelParameterfvCookie = XCBCookie<XCBGlxGetTexLevelParameterfvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetTexLevelParameterfvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetTexLevelParameterivRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub level: i32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetTexLevelParameterivCookie = XCBCookie<XCBGlxGetTexLevelParameterivReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetTexLevelParameterivReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxIsEnabledRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub capability: u32,
+}
+
+pub type XCBGlxIsEnabledCookie = XCBCookie<XCBGlxIsEnabledReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxIsEnabledReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: XCBGlxBool32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxIsListRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub list: u32,
+}
+
+pub type XCBGlxIsListCookie = XCBCookie<XCBGlxIsListReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxIsListReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: XCBGlxBool32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxFlushRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+}
+
+#[repr(C)]
+pub struct XCBGlxAreTexturesResidentRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub n: i32,
+}
+
+pub type XCBGlxAreTexturesResidentCookie = XCBCookie<XCBGlxAreTexturesResidentReply>;
+
+#[repr(C)]
+pub struct XCBGlxAreTexturesResidentReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: XCBGlxBool32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBGlxDeleteTexturesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub n: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGenTexturesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub n: i32,
+}
+
+pub type XCBGlxGenTexturesCookie = XCBCookie<XCBGlxGenTexturesReply>;
+
+#[repr(C)]
+pub struct XCBGlxGenTexturesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxIsTextureRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub texture: u32,
+}
+
+pub type XCBGlxIsTextureCookie = XCBCookie<XCBGlxIsTextureReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxIsTextureReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: XCBGlxBool32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetColorTableRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: u8,
+}
+
+pub type XCBGlxGetColorTableCookie = XCBCookie<XCBGlxGetColorTableReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetColorTableReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 8],
+    pub width: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetColorTableParameterfvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetColorTableParameterfvCookie = XCBCookie<XCBGlxGetColorTableParameterfvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetColorTableParameterfvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetColorTableParameterivRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetColorTableParameterivCookie = XCBCookie<XCBGlxGetColorTableParameterivReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetColorTableParameterivReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetConvolutionFilterRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: u8,
+}
+
+pub type XCBGlxGetConvolutionFilterCookie = XCBCookie<XCBGlxGetConvolutionFilterReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetConvolutionFilterReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 8],
+    pub width: i32,
+    pub height: i32,
+    pub pad2: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetConvolutionParameterfvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetConvolutionParameterfvCookie = XCBCookie<XCBGlxGetConvolutionParameterfvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetConvolutionParameterfvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetConvolutionParameterivRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetConvolutionParameterivCookie = XCBCookie<XCBGlxGetConvolutionParameterivReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetConvolutionParameterivReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetSeparableFilterRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: u8,
+}
+
+pub type XCBGlxGetSeparableFilterCookie = XCBCookie<XCBGlxGetSeparableFilterReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetSeparableFilterReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 8],
+    pub row_w: i32,
+    pub col_h: i32,
+    pub pad2: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetHistogramRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: u8,
+    pub reset: u8,
+}
+
+pub type XCBGlxGetHistogramCookie = XCBCookie<XCBGlxGetHistogramReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetHistogramReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 8],
+    pub width: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetHistogramParameterfvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetHistogramParameterfvCookie = XCBCookie<XCBGlxGetHistogramParameterfvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetHistogramParameterfvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetHistogramParameterivRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetHistogramParameterivCookie = XCBCookie<XCBGlxGetHistogramParameterivReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetHistogramParameterivReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetMinmaxRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: u8,
+    pub reset: u8,
+}
+
+pub type XCBGlxGetMinmaxCookie = XCBCookie<XCBGlxGetMinmaxReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetMinmaxReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetMinmaxParameterfvRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetMinmaxParameterfvCookie = XCBCookie<XCBGlxGetMinmaxParameterfvReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetMinmaxParameterfvReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: XCBGlxFloat32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetMinmaxParameterivRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetMinmaxParameterivCookie = XCBCookie<XCBGlxGetMinmaxParameterivReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetMinmaxParameterivReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetCompressedTexImageArbRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub level: i32,
+}
+
+pub type XCBGlxGetCompressedTexImageArbCookie = XCBCookie<XCBGlxGetCompressedTexImageArbReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetCompressedTexImageArbReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 8],
+    pub size: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+pub struct XCBGlxDeleteQueriesArbRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub n: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGenQueriesArbRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub n: i32,
+}
+
+pub type XCBGlxGenQueriesArbCookie = XCBCookie<XCBGlxGenQueriesArbReply>;
+
+#[repr(C)]
+pub struct XCBGlxGenQueriesArbReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxIsQueryArbRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub id: u32,
+}
+
+pub type XCBGlxIsQueryArbCookie = XCBCookie<XCBGlxIsQueryArbReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxIsQueryArbReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: XCBGlxBool32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetQueryivArbRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetQueryivArbCookie = XCBCookie<XCBGlxGetQueryivArbReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetQueryivArbReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetQueryObjectivArbRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub id: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetQueryObjectivArbCookie = XCBCookie<XCBGlxGetQueryObjectivArbReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetQueryObjectivArbReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: i32,
+    pub pad2: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGlxGetQueryObjectuivArbRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_tag: XCBGlxContextTag,
+    pub id: u32,
+    pub pname: u32,
+}
+
+pub type XCBGlxGetQueryObjectuivArbCookie = XCBCookie<XCBGlxGetQueryObjectuivArbReply>;
+
+#[repr(C)]
+pub struct XCBGlxGetQueryObjectuivArbReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 4],
+    pub n: u32,
+    pub datum: u32,
+    pub pad2: [u8; 12],
+}
+
+#[link(name = "xcb-glx")]
+extern "system" {
+    pub static mut xcb_glx_id: XCBExtension;
+    pub fn xcb_glx_pixmap_next(i: *mut XCBGlxPixmapIterator);
+    pub fn xcb_glx_pixmap_end(i: XCBGlxPixmapIterator) -> XCBGenericIterator;
+    pub fn xcb_glx_context_next(i: *mut XCBGlxContextIterator);
+    pub fn xcb_glx_context_end(i: XCBGlxContextIterator) -> XCBGenericIterator;
+    pub fn xcb_glx_pbuffer_next(i: *mut XCBGlxPbufferIterator);
+    pub fn xcb_glx_pbuffer_end(i: XCBGlxPbufferIterator) -> XCBGenericIterator;
+    pub fn xcb_glx_window_next(i: *mut XCBGlxWindowIterator);
+    pub fn xcb_glx_window_end(i: XCBGlxWindowIterator) -> XCBGenericIterator;
+    pub fn xcb_glx_fbconfig_next(i: *mut XCBGlxFbconfigIterator);
+    pub fn xcb_glx_fbconfig_end(i: XCBGlxFbconfigIterator) -> XCBGenericIterator;
+    pub fn xcb_glx_drawable_next(i: *mut XCBGlxDrawableIterator);
+    pub fn xcb_glx_drawable_end(i: XCBGlxDrawableIterator) -> XCBGenericIterator;
+    pub fn xcb_glx_float32_next(i: *mut XCBGlxFloat32Iterator);
+    pub fn xcb_glx_float32_end(i: XCBGlxFloat32Iterator) -> XCBGenericIterator;
+    pub fn xcb_glx_float64_next(i: *mut XCBGlxFloat64Iterator);
+    pub fn xcb_glx_float64_end(i: XCBGlxFloat64Iterator) -> XCBGenericIterator;
+    pub fn xcb_glx_bool32_next(i: *mut XCBGlxBool32Iterator);
+    pub fn xcb_glx_bool32_end(i: XCBGlxBool32Iterator) -> XCBGenericIterator;
+    pub fn xcb_glx_context_tag_next(i: *mut XCBGlxContextTagIterator);
+    pub fn xcb_glx_context_tag_end(i: XCBGlxContextTagIterator) -> XCBGenericIterator;
+    pub fn xcb_glx_render_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_render(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_render_large_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        request_num: u16,
+        request_total: u16,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_render_large(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        request_num: u16,
+        request_total: u16,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_create_context_checked(
+        connection: *mut XCBConnection,
+        context: XCBGlxContext,
+        visual: XCBVisualid,
+        screen: u32,
+        share_list: XCBGlxContext,
+        is_direct: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_create_context(
+        connection: *mut XCBConnection,
+        context: XCBGlxContext,
+        visual: XCBVisualid,
+        screen: u32,
+        share_list: XCBGlxContext,
+        is_direct: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_destroy_context_checked(
+        connection: *mut XCBConnection,
+        context: XCBGlxContext,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_destroy_context(
+        connection: *mut XCBConnection,
+        context: XCBGlxContext,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_make_current_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxMakeCurrentCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxMakeCurrentReply;
+    pub fn xcb_glx_make_current(
+        connection: *mut XCBConnection,
+        drawable: XCBGlxDrawable,
+        context: XCBGlxContext,
+        old_context_tag: XCBGlxContextTag,
+    ) -> XCBGlxMakeCurrentCookie;
+    pub fn xcb_glx_make_current_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBGlxDrawable,
+        context: XCBGlxContext,
+        old_context_tag: XCBGlxContextTag,
+    ) -> XCBGlxMakeCurrentCookie;
+    pub fn xcb_glx_is_direct_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxIsDirectCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxIsDirectReply;
+    pub fn xcb_glx_is_direct(
+        connection: *mut XCBConnection,
+        context: XCBGlxContext,
+    ) -> XCBGlxIsDirectCookie;
+    pub fn xcb_glx_is_direct_unchecked(
+        connection: *mut XCBConnection,
+        context: XCBGlxContext,
+    ) -> XCBGlxIsDirectCookie;
+    pub fn xcb_glx_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxQueryVersionReply;
+    pub fn xcb_glx_query_version(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+    ) -> XCBGlxQueryVersionCookie;
+    pub fn xcb_glx_query_version_unchecked(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+    ) -> XCBGlxQueryVersionCookie;
+    pub fn xcb_glx_wait_gl_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_wait_gl(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_wait_x_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_wait_x(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_copy_context_checked(
+        connection: *mut XCBConnection,
+        src: XCBGlxContext,
+        dest: XCBGlxContext,
+        mask: u32,
+        src_context_tag: XCBGlxContextTag,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_copy_context(
+        connection: *mut XCBConnection,
+        src: XCBGlxContext,
+        dest: XCBGlxContext,
+        mask: u32,
+        src_context_tag: XCBGlxContextTag,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_swap_buffers_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        drawable: XCBGlxDrawable,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_swap_buffers(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        drawable: XCBGlxDrawable,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_use_x_font_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        font: XCBFont,
+        first: u32,
+        count: u32,
+        list_base: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_use_x_font(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        font: XCBFont,
+        first: u32,
+        count: u32,
+        list_base: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_create_glx_pixmap_checked(
+        connection: *mut XCBConnection,
+        screen: u32,
+        visual: XCBVisualid,
+        pixmap: XCBPixmap,
+        glx_pixmap: XCBGlxPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_create_glx_pixmap(
+        connection: *mut XCBConnection,
+        screen: u32,
+        visual: XCBVisualid,
+        pixmap: XCBPixmap,
+        glx_pixmap: XCBGlxPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_get_visual_configs_property_list(
+        R: *const XCBGlxGetVisualConfigsReply,
+    ) -> *mut u32;
+    pub fn xcb_glx_get_visual_configs_property_list_length(
+        R: *const XCBGlxGetVisualConfigsReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_visual_configs_property_list_end(
+        R: *const XCBGlxGetVisualConfigsReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_visual_configs_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetVisualConfigsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetVisualConfigsReply;
+    pub fn xcb_glx_get_visual_configs(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBGlxGetVisualConfigsCookie;
+    pub fn xcb_glx_get_visual_configs_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBGlxGetVisualConfigsCookie;
+    pub fn xcb_glx_destroy_glx_pixmap_checked(
+        connection: *mut XCBConnection,
+        glx_pixmap: XCBGlxPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_destroy_glx_pixmap(
+        connection: *mut XCBConnection,
+        glx_pixmap: XCBGlxPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_vendor_private_checked(
+        connection: *mut XCBConnection,
+        vendor_code: u32,
+        context_tag: XCBGlxContextTag,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_vendor_private(
+        connection: *mut XCBConnection,
+        vendor_code: u32,
+        context_tag: XCBGlxContextTag,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_vendor_private_with_reply_data_2(
+        R: *const XCBGlxVendorPrivateWithReplyReply,
+    ) -> *mut u8;
+    pub fn xcb_glx_vendor_private_with_reply_data_2_length(
+        R: *const XCBGlxVendorPrivateWithReplyReply,
+    ) -> c_int;
+    pub fn xcb_glx_vendor_private_with_reply_data_2_end(
+        R: *const XCBGlxVendorPrivateWithReplyReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_vendor_private_with_reply_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxVendorPrivateWithReplyCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxVendorPrivateWithReplyReply;
+    pub fn xcb_glx_vendor_private_with_reply(
+        connection: *mut XCBConnection,
+        vendor_code: u32,
+        context_tag: XCBGlxContextTag,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBGlxVendorPrivateWithReplyCookie;
+    pub fn xcb_glx_vendor_private_with_reply_unchecked(
+        connection: *mut XCBConnection,
+        vendor_code: u32,
+        context_tag: XCBGlxContextTag,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBGlxVendorPrivateWithReplyCookie;
+    pub fn xcb_glx_query_extensions_string_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxQueryExtensionsStringCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxQueryExtensionsStringReply;
+    pub fn xcb_glx_query_extensions_string(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBGlxQueryExtensionsStringCookie;
+    pub fn xcb_glx_query_extensions_string_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBGlxQueryExtensionsStringCookie;
+    pub fn xcb_glx_query_server_string_string(
+        R: *const XCBGlxQueryServerStringReply,
+    ) -> *mut c_char;
+    pub fn xcb_glx_query_server_string_string_length(
+        R: *const XCBGlxQueryServerStringReply,
+    ) -> c_int;
+    pub fn xcb_glx_query_server_string_string_end(
+        R: *const XCBGlxQueryServerStringReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_query_server_string_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxQueryServerStringCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxQueryServerStringReply;
+    pub fn xcb_glx_query_server_string(
+        connection: *mut XCBConnection,
+        screen: u32,
+        name: u32,
+    ) -> XCBGlxQueryServerStringCookie;
+    pub fn xcb_glx_query_server_string_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+        name: u32,
+    ) -> XCBGlxQueryServerStringCookie;
+    pub fn xcb_glx_client_info_checked(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+        str_len: u32,
+        string: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_client_info(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+        str_len: u32,
+        string: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_get_fb_configs_property_list(R: *const XCBGlxGetFbConfigsReply) -> *mut u32;
+    pub fn xcb_glx_get_fb_configs_property_list_length(R: *const XCBGlxGetFbConfigsReply) -> c_int;
+    pub fn xcb_glx_get_fb_configs_property_list_end(
+        R: *const XCBGlxGetFbConfigsReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_fb_configs_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetFbConfigsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetFbConfigsReply;
+    pub fn xcb_glx_get_fb_configs(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBGlxGetFbConfigsCookie;
+    pub fn xcb_glx_get_fb_configs_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBGlxGetFbConfigsCookie;
+    pub fn xcb_glx_create_pixmap_checked(
+        connection: *mut XCBConnection,
+        screen: u32,
+        fbconfig: XCBGlxFbconfig,
+        pixmap: XCBPixmap,
+        glx_pixmap: XCBGlxPixmap,
+        num_attribs: u32,
+        attribs: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_create_pixmap(
+        connection: *mut XCBConnection,
+        screen: u32,
+        fbconfig: XCBGlxFbconfig,
+        pixmap: XCBPixmap,
+        glx_pixmap: XCBGlxPixmap,
+        num_attribs: u32,
+        attribs: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_destroy_pixmap_checked(
+        connection: *mut XCBConnection,
+        glx_pixmap: XCBGlxPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_destroy_pixmap(
+        connection: *mut XCBConnection,
+        glx_pixmap: XCBGlxPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_create_new_context_checked(
+        connection: *mut XCBConnection,
+        context: XCBGlxContext,
+        fbconfig: XCBGlxFbconfig,
+        screen: u32,
+        render_type: u32,
+        share_list: XCBGlxContext,
+        is_direct: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_create_new_context(
+        connection: *mut XCBConnection,
+        context: XCBGlxContext,
+        fbconfig: XCBGlxFbconfig,
+        screen: u32,
+        render_type: u32,
+        share_list: XCBGlxContext,
+        is_direct: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_query_context_attribs(R: *const XCBGlxQueryContextReply) -> *mut u32;
+    pub fn xcb_glx_query_context_attribs_length(R: *const XCBGlxQueryContextReply) -> c_int;
+    pub fn xcb_glx_query_context_attribs_end(
+        R: *const XCBGlxQueryContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_query_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxQueryContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxQueryContextReply;
+    pub fn xcb_glx_query_context(
+        connection: *mut XCBConnection,
+        context: XCBGlxContext,
+    ) -> XCBGlxQueryContextCookie;
+    pub fn xcb_glx_query_context_unchecked(
+        connection: *mut XCBConnection,
+        context: XCBGlxContext,
+    ) -> XCBGlxQueryContextCookie;
+    pub fn xcb_glx_make_context_current_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxMakeContextCurrentCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxMakeContextCurrentReply;
+    pub fn xcb_glx_make_context_current(
+        connection: *mut XCBConnection,
+        old_context_tag: XCBGlxContextTag,
+        drawable: XCBGlxDrawable,
+        read_drawable: XCBGlxDrawable,
+        context: XCBGlxContext,
+    ) -> XCBGlxMakeContextCurrentCookie;
+    pub fn xcb_glx_make_context_current_unchecked(
+        connection: *mut XCBConnection,
+        old_context_tag: XCBGlxContextTag,
+        drawable: XCBGlxDrawable,
+        read_drawable: XCBGlxDrawable,
+        context: XCBGlxContext,
+    ) -> XCBGlxMakeContextCurrentCookie;
+    pub fn xcb_glx_create_pbuffer_checked(
+        connection: *mut XCBConnection,
+        screen: u32,
+        fbconfig: XCBGlxFbconfig,
+        pbuffer: XCBGlxPbuffer,
+        num_attribs: u32,
+        attribs: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_create_pbuffer(
+        connection: *mut XCBConnection,
+        screen: u32,
+        fbconfig: XCBGlxFbconfig,
+        pbuffer: XCBGlxPbuffer,
+        num_attribs: u32,
+        attribs: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_destroy_pbuffer_checked(
+        connection: *mut XCBConnection,
+        pbuffer: XCBGlxPbuffer,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_destroy_pbuffer(
+        connection: *mut XCBConnection,
+        pbuffer: XCBGlxPbuffer,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_get_drawable_attributes_attribs(
+        R: *const XCBGlxGetDrawableAttributesReply,
+    ) -> *mut u32;
+    pub fn xcb_glx_get_drawable_attributes_attribs_length(
+        R: *const XCBGlxGetDrawableAttributesReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_drawable_attributes_attribs_end(
+        R: *const XCBGlxGetDrawableAttributesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_drawable_attributes_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetDrawableAttributesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetDrawableAttributesReply;
+    pub fn xcb_glx_get_drawable_attributes(
+        connection: *mut XCBConnection,
+        drawable: XCBGlxDrawable,
+    ) -> XCBGlxGetDrawableAttributesCookie;
+    pub fn xcb_glx_get_drawable_attributes_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBGlxDrawable,
+    ) -> XCBGlxGetDrawableAttributesCookie;
+    pub fn xcb_glx_change_drawable_attributes_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBGlxDrawable,
+        num_attribs: u32,
+        attribs: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_change_drawable_attributes(
+        connection: *mut XCBConnection,
+        drawable: XCBGlxDrawable,
+        num_attribs: u32,
+        attribs: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_create_window_checked(
+        connection: *mut XCBConnection,
+        screen: u32,
+        fbconfig: XCBGlxFbconfig,
+        window: XCBWindow,
+        glx_window: XCBGlxWindow,
+        num_attribs: u32,
+        attribs: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_create_window(
+        connection: *mut XCBConnection,
+        screen: u32,
+        fbconfig: XCBGlxFbconfig,
+        window: XCBWindow,
+        glx_window: XCBGlxWindow,
+        num_attribs: u32,
+        attribs: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_delete_window_checked(
+        connection: *mut XCBConnection,
+        glxwindow: XCBGlxWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_delete_window(
+        connection: *mut XCBConnection,
+        glxwindow: XCBGlxWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_set_client_info_arb_checked(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+        num_versions: u32,
+        gl_str_len: u32,
+        glx_str_len: u32,
+        gl_versions: *const u32,
+        gl_extension_string: *const c_char,
+        glx_extension_string: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_set_client_info_arb(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+        num_versions: u32,
+        gl_str_len: u32,
+        glx_str_len: u32,
+        gl_versions: *const u32,
+        gl_extension_string: *const c_char,
+        glx_extension_string: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_create_context_attribs_arb_checked(
+        connection: *mut XCBConnection,
+        context: XCBGlxContext,
+        fbconfig: XCBGlxFbconfig,
+        screen: u32,
+        share_list: XCBGlxContext,
+        is_direct: u8,
+        num_attribs: u32,
+        attribs: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_create_context_attribs_arb(
+        connection: *mut XCBConnection,
+        context: XCBGlxContext,
+        fbconfig: XCBGlxFbconfig,
+        screen: u32,
+        share_list: XCBGlxContext,
+        is_direct: u8,
+        num_attribs: u32,
+        attribs: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_set_client_info_2arb_checked(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+        num_versions: u32,
+        gl_str_len: u32,
+        glx_str_len: u32,
+        gl_versions: *const u32,
+        gl_extension_string: *const c_char,
+        glx_extension_string: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_set_client_info_2arb(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+        num_versions: u32,
+        gl_str_len: u32,
+        glx_str_len: u32,
+        gl_versions: *const u32,
+        gl_extension_string: *const c_char,
+        glx_extension_string: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_new_list_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        list: u32,
+        mode: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_new_list(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        list: u32,
+        mode: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_end_list_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_end_list(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_delete_lists_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        list: u32,
+        range: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_delete_lists(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        list: u32,
+        range: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_gen_lists_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGenListsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGenListsReply;
+    pub fn xcb_glx_gen_lists(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        range: i32,
+    ) -> XCBGlxGenListsCookie;
+    pub fn xcb_glx_gen_lists_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        range: i32,
+    ) -> XCBGlxGenListsCookie;
+    pub fn xcb_glx_feedback_buffer_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        size: i32,
+        type_: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_feedback_buffer(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        size: i32,
+        type_: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_select_buffer_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        size: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_select_buffer(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        size: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_render_mode_data(R: *const XCBGlxRenderModeReply) -> *mut u32;
+    pub fn xcb_glx_render_mode_data_length(R: *const XCBGlxRenderModeReply) -> c_int;
+    pub fn xcb_glx_render_mode_data_end(R: *const XCBGlxRenderModeReply) -> XCBGenericIterator;
+    pub fn xcb_glx_render_mode_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxRenderModeCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxRenderModeReply;
+    pub fn xcb_glx_render_mode(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        mode: u32,
+    ) -> XCBGlxRenderModeCookie;
+    pub fn xcb_glx_render_mode_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        mode: u32,
+    ) -> XCBGlxRenderModeCookie;
+    pub fn xcb_glx_finish_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxFinishCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxFinishReply;
+    pub fn xcb_glx_finish(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+    ) -> XCBGlxFinishCookie;
+    pub fn xcb_glx_finish_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+    ) -> XCBGlxFinishCookie;
+    pub fn xcb_glx_pixel_storef_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        pname: u32,
+        datum: XCBGlxFloat32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_pixel_storef(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        pname: u32,
+        datum: XCBGlxFloat32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_pixel_storei_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        pname: u32,
+        datum: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_pixel_storei(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        pname: u32,
+        datum: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_read_pixels_data(R: *const XCBGlxReadPixelsReply) -> *mut u8;
+    pub fn xcb_glx_read_pixels_data_length(R: *const XCBGlxReadPixelsReply) -> c_int;
+    pub fn xcb_glx_read_pixels_data_end(R: *const XCBGlxReadPixelsReply) -> XCBGenericIterator;
+    pub fn xcb_glx_read_pixels_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxReadPixelsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxReadPixelsReply;
+    pub fn xcb_glx_read_pixels(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+        lsb_first: u8,
+    ) -> XCBGlxReadPixelsCookie;
+    pub fn xcb_glx_read_pixels_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+        lsb_first: u8,
+    ) -> XCBGlxReadPixelsCookie;
+    pub fn xcb_glx_get_booleanv_data(R: *const XCBGlxGetBooleanvReply) -> *mut u8;
+    pub fn xcb_glx_get_booleanv_data_length(R: *const XCBGlxGetBooleanvReply) -> c_int;
+    pub fn xcb_glx_get_booleanv_data_end(R: *const XCBGlxGetBooleanvReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_booleanv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetBooleanvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetBooleanvReply;
+    pub fn xcb_glx_get_booleanv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        pname: i32,
+    ) -> XCBGlxGetBooleanvCookie;
+    pub fn xcb_glx_get_booleanv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        pname: i32,
+    ) -> XCBGlxGetBooleanvCookie;
+    pub fn xcb_glx_get_clip_plane_data(R: *const XCBGlxGetClipPlaneReply) -> *mut XCBGlxFloat64;
+    pub fn xcb_glx_get_clip_plane_data_length(R: *const XCBGlxGetClipPlaneReply) -> c_int;
+    pub fn xcb_glx_get_clip_plane_data_end(R: *const XCBGlxGetClipPlaneReply)
+        -> XCBGenericIterator;
+    pub fn xcb_glx_get_clip_plane_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetClipPlaneCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetClipPlaneReply;
+    pub fn xcb_glx_get_clip_plane(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        plane: i32,
+    ) -> XCBGlxGetClipPlaneCookie;
+    pub fn xcb_glx_get_clip_plane_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        plane: i32,
+    ) -> XCBGlxGetClipPlaneCookie;
+    pub fn xcb_glx_get_doublev_data(R: *const XCBGlxGetDoublevReply) -> *mut XCBGlxFloat64;
+    pub fn xcb_glx_get_doublev_data_length(R: *const XCBGlxGetDoublevReply) -> c_int;
+    pub fn xcb_glx_get_doublev_data_end(R: *const XCBGlxGetDoublevReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_doublev_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetDoublevCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetDoublevReply;
+    pub fn xcb_glx_get_doublev(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        pname: u32,
+    ) -> XCBGlxGetDoublevCookie;
+    pub fn xcb_glx_get_doublev_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        pname: u32,
+    ) -> XCBGlxGetDoublevCookie;
+    pub fn xcb_glx_get_error_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetErrorCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetErrorReply;
+    pub fn xcb_glx_get_error(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+    ) -> XCBGlxGetErrorCookie;
+    pub fn xcb_glx_get_error_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+    ) -> XCBGlxGetErrorCookie;
+    pub fn xcb_glx_get_floatv_data(R: *const XCBGlxGetFloatvReply) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_floatv_data_length(R: *const XCBGlxGetFloatvReply) -> c_int;
+    pub fn xcb_glx_get_floatv_data_end(R: *const XCBGlxGetFloatvReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_floatv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetFloatvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetFloatvReply;
+    pub fn xcb_glx_get_floatv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        pname: u32,
+    ) -> XCBGlxGetFloatvCookie;
+    pub fn xcb_glx_get_floatv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        pname: u32,
+    ) -> XCBGlxGetFloatvCookie;
+    pub fn xcb_glx_get_integerv_data(R: *const XCBGlxGetIntegervReply) -> *mut i32;
+    pub fn xcb_glx_get_integerv_data_length(R: *const XCBGlxGetIntegervReply) -> c_int;
+    pub fn xcb_glx_get_integerv_data_end(R: *const XCBGlxGetIntegervReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_integerv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetIntegervCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetIntegervReply;
+    pub fn xcb_glx_get_integerv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        pname: u32,
+    ) -> XCBGlxGetIntegervCookie;
+    pub fn xcb_glx_get_integerv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        pname: u32,
+    ) -> XCBGlxGetIntegervCookie;
+    pub fn xcb_glx_get_lightfv_data(R: *const XCBGlxGetLightfvReply) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_lightfv_data_length(R: *const XCBGlxGetLightfvReply) -> c_int;
+    pub fn xcb_glx_get_lightfv_data_end(R: *const XCBGlxGetLightfvReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_lightfv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetLightfvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetLightfvReply;
+    pub fn xcb_glx_get_lightfv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        light: u32,
+        pname: u32,
+    ) -> XCBGlxGetLightfvCookie;
+    pub fn xcb_glx_get_lightfv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        light: u32,
+        pname: u32,
+    ) -> XCBGlxGetLightfvCookie;
+    pub fn xcb_glx_get_lightiv_data(R: *const XCBGlxGetLightivReply) -> *mut i32;
+    pub fn xcb_glx_get_lightiv_data_length(R: *const XCBGlxGetLightivReply) -> c_int;
+    pub fn xcb_glx_get_lightiv_data_end(R: *const XCBGlxGetLightivReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_lightiv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetLightivCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetLightivReply;
+    pub fn xcb_glx_get_lightiv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        light: u32,
+        pname: u32,
+    ) -> XCBGlxGetLightivCookie;
+    pub fn xcb_glx_get_lightiv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        light: u32,
+        pname: u32,
+    ) -> XCBGlxGetLightivCookie;
+    pub fn xcb_glx_get_mapdv_data(R: *const XCBGlxGetMapdvReply) -> *mut XCBGlxFloat64;
+    pub fn xcb_glx_get_mapdv_data_length(R: *const XCBGlxGetMapdvReply) -> c_int;
+    pub fn xcb_glx_get_mapdv_data_end(R: *const XCBGlxGetMapdvReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_mapdv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetMapdvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetMapdvReply;
+    pub fn xcb_glx_get_mapdv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        query: u32,
+    ) -> XCBGlxGetMapdvCookie;
+    pub fn xcb_glx_get_mapdv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        query: u32,
+    ) -> XCBGlxGetMapdvCookie;
+    pub fn xcb_glx_get_mapfv_data(R: *const XCBGlxGetMapfvReply) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_mapfv_data_length(R: *const XCBGlxGetMapfvReply) -> c_int;
+    pub fn xcb_glx_get_mapfv_data_end(R: *const XCBGlxGetMapfvReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_mapfv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetMapfvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetMapfvReply;
+    pub fn xcb_glx_get_mapfv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        query: u32,
+    ) -> XCBGlxGetMapfvCookie;
+    pub fn xcb_glx_get_mapfv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        query: u32,
+    ) -> XCBGlxGetMapfvCookie;
+    pub fn xcb_glx_get_mapiv_data(R: *const XCBGlxGetMapivReply) -> *mut i32;
+    pub fn xcb_glx_get_mapiv_data_length(R: *const XCBGlxGetMapivReply) -> c_int;
+    pub fn xcb_glx_get_mapiv_data_end(R: *const XCBGlxGetMapivReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_mapiv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetMapivCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetMapivReply;
+    pub fn xcb_glx_get_mapiv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        query: u32,
+    ) -> XCBGlxGetMapivCookie;
+    pub fn xcb_glx_get_mapiv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        query: u32,
+    ) -> XCBGlxGetMapivCookie;
+    pub fn xcb_glx_get_materialfv_data(R: *const XCBGlxGetMaterialfvReply) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_materialfv_data_length(R: *const XCBGlxGetMaterialfvReply) -> c_int;
+    pub fn xcb_glx_get_materialfv_data_end(
+        R: *const XCBGlxGetMaterialfvReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_materialfv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetMaterialfvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetMaterialfvReply;
+    pub fn xcb_glx_get_materialfv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        face: u32,
+        pname: u32,
+    ) -> XCBGlxGetMaterialfvCookie;
+    pub fn xcb_glx_get_materialfv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        face: u32,
+        pname: u32,
+    ) -> XCBGlxGetMaterialfvCookie;
+    pub fn xcb_glx_get_materialiv_data(R: *const XCBGlxGetMaterialivReply) -> *mut i32;
+    pub fn xcb_glx_get_materialiv_data_length(R: *const XCBGlxGetMaterialivReply) -> c_int;
+    pub fn xcb_glx_get_materialiv_data_end(
+        R: *const XCBGlxGetMaterialivReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_materialiv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetMaterialivCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetMaterialivReply;
+    pub fn xcb_glx_get_materialiv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        face: u32,
+        pname: u32,
+    ) -> XCBGlxGetMaterialivCookie;
+    pub fn xcb_glx_get_materialiv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        face: u32,
+        pname: u32,
+    ) -> XCBGlxGetMaterialivCookie;
+    pub fn xcb_glx_get_pixel_mapfv_data(R: *const XCBGlxGetPixelMapfvReply) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_pixel_mapfv_data_length(R: *const XCBGlxGetPixelMapfvReply) -> c_int;
+    pub fn xcb_glx_get_pixel_mapfv_data_end(
+        R: *const XCBGlxGetPixelMapfvReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_pixel_mapfv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetPixelMapfvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetPixelMapfvReply;
+    pub fn xcb_glx_get_pixel_mapfv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        map: u32,
+    ) -> XCBGlxGetPixelMapfvCookie;
+    pub fn xcb_glx_get_pixel_mapfv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        map: u32,
+    ) -> XCBGlxGetPixelMapfvCookie;
+    pub fn xcb_glx_get_pixel_mapuiv_data(R: *const XCBGlxGetPixelMapuivReply) -> *mut u32;
+    pub fn xcb_glx_get_pixel_mapuiv_data_length(R: *const XCBGlxGetPixelMapuivReply) -> c_int;
+    pub fn xcb_glx_get_pixel_mapuiv_data_end(
+        R: *const XCBGlxGetPixelMapuivReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_pixel_mapuiv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetPixelMapuivCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetPixelMapuivReply;
+    pub fn xcb_glx_get_pixel_mapuiv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        map: u32,
+    ) -> XCBGlxGetPixelMapuivCookie;
+    pub fn xcb_glx_get_pixel_mapuiv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        map: u32,
+    ) -> XCBGlxGetPixelMapuivCookie;
+    pub fn xcb_glx_get_pixel_mapusv_data(R: *const XCBGlxGetPixelMapusvReply) -> *mut u16;
+    pub fn xcb_glx_get_pixel_mapusv_data_length(R: *const XCBGlxGetPixelMapusvReply) -> c_int;
+    pub fn xcb_glx_get_pixel_mapusv_data_end(
+        R: *const XCBGlxGetPixelMapusvReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_pixel_mapusv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetPixelMapusvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetPixelMapusvReply;
+    pub fn xcb_glx_get_pixel_mapusv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        map: u32,
+    ) -> XCBGlxGetPixelMapusvCookie;
+    pub fn xcb_glx_get_pixel_mapusv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        map: u32,
+    ) -> XCBGlxGetPixelMapusvCookie;
+    pub fn xcb_glx_get_polygon_stipple_data(R: *const XCBGlxGetPolygonStippleReply) -> *mut u8;
+    pub fn xcb_glx_get_polygon_stipple_data_length(R: *const XCBGlxGetPolygonStippleReply)
+        -> c_int;
+    pub fn xcb_glx_get_polygon_stipple_data_end(
+        R: *const XCBGlxGetPolygonStippleReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_polygon_stipple_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetPolygonStippleCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetPolygonStippleReply;
+    pub fn xcb_glx_get_polygon_stipple(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        lsb_first: u8,
+    ) -> XCBGlxGetPolygonStippleCookie;
+    pub fn xcb_glx_get_polygon_stipple_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        lsb_first: u8,
+    ) -> XCBGlxGetPolygonStippleCookie;
+    pub fn xcb_glx_get_string_string(R: *const XCBGlxGetStringReply) -> *mut c_char;
+    pub fn xcb_glx_get_string_string_length(R: *const XCBGlxGetStringReply) -> c_int;
+    pub fn xcb_glx_get_string_string_end(R: *const XCBGlxGetStringReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_string_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetStringCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetStringReply;
+    pub fn xcb_glx_get_string(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        name: u32,
+    ) -> XCBGlxGetStringCookie;
+    pub fn xcb_glx_get_string_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        name: u32,
+    ) -> XCBGlxGetStringCookie;
+    pub fn xcb_glx_get_tex_envfv_data(R: *const XCBGlxGetTexEnvfvReply) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_tex_envfv_data_length(R: *const XCBGlxGetTexEnvfvReply) -> c_int;
+    pub fn xcb_glx_get_tex_envfv_data_end(R: *const XCBGlxGetTexEnvfvReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_tex_envfv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetTexEnvfvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetTexEnvfvReply;
+    pub fn xcb_glx_get_tex_envfv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexEnvfvCookie;
+    pub fn xcb_glx_get_tex_envfv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexEnvfvCookie;
+    pub fn xcb_glx_get_tex_enviv_data(R: *const XCBGlxGetTexEnvivReply) -> *mut i32;
+    pub fn xcb_glx_get_tex_enviv_data_length(R: *const XCBGlxGetTexEnvivReply) -> c_int;
+    pub fn xcb_glx_get_tex_enviv_data_end(R: *const XCBGlxGetTexEnvivReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_tex_enviv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetTexEnvivCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetTexEnvivReply;
+    pub fn xcb_glx_get_tex_enviv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexEnvivCookie;
+    pub fn xcb_glx_get_tex_enviv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexEnvivCookie;
+    pub fn xcb_glx_get_tex_gendv_data(R: *const XCBGlxGetTexGendvReply) -> *mut XCBGlxFloat64;
+    pub fn xcb_glx_get_tex_gendv_data_length(R: *const XCBGlxGetTexGendvReply) -> c_int;
+    pub fn xcb_glx_get_tex_gendv_data_end(R: *const XCBGlxGetTexGendvReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_tex_gendv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetTexGendvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetTexGendvReply;
+    pub fn xcb_glx_get_tex_gendv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        coord: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexGendvCookie;
+    pub fn xcb_glx_get_tex_gendv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        coord: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexGendvCookie;
+    pub fn xcb_glx_get_tex_genfv_data(R: *const XCBGlxGetTexGenfvReply) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_tex_genfv_data_length(R: *const XCBGlxGetTexGenfvReply) -> c_int;
+    pub fn xcb_glx_get_tex_genfv_data_end(R: *const XCBGlxGetTexGenfvReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_tex_genfv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetTexGenfvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetTexGenfvReply;
+    pub fn xcb_glx_get_tex_genfv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        coord: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexGenfvCookie;
+    pub fn xcb_glx_get_tex_genfv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        coord: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexGenfvCookie;
+    pub fn xcb_glx_get_tex_geniv_data(R: *const XCBGlxGetTexGenivReply) -> *mut i32;
+    pub fn xcb_glx_get_tex_geniv_data_length(R: *const XCBGlxGetTexGenivReply) -> c_int;
+    pub fn xcb_glx_get_tex_geniv_data_end(R: *const XCBGlxGetTexGenivReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_tex_geniv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetTexGenivCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetTexGenivReply;
+    pub fn xcb_glx_get_tex_geniv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        coord: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexGenivCookie;
+    pub fn xcb_glx_get_tex_geniv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        coord: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexGenivCookie;
+    pub fn xcb_glx_get_tex_image_data(R: *const XCBGlxGetTexImageReply) -> *mut u8;
+    pub fn xcb_glx_get_tex_image_data_length(R: *const XCBGlxGetTexImageReply) -> c_int;
+    pub fn xcb_glx_get_tex_image_data_end(R: *const XCBGlxGetTexImageReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_tex_image_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetTexImageCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetTexImageReply;
+    pub fn xcb_glx_get_tex_image(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        level: i32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+    ) -> XCBGlxGetTexImageCookie;
+    pub fn xcb_glx_get_tex_image_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        level: i32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+    ) -> XCBGlxGetTexImageCookie;
+    pub fn xcb_glx_get_tex_parameterfv_data(
+        R: *const XCBGlxGetTexParameterfvReply,
+    ) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_tex_parameterfv_data_length(R: *const XCBGlxGetTexParameterfvReply)
+        -> c_int;
+    pub fn xcb_glx_get_tex_parameterfv_data_end(
+        R: *const XCBGlxGetTexParameterfvReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_tex_parameterfv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetTexParameterfvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetTexParameterfvReply;
+    pub fn xcb_glx_get_tex_parameterfv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexParameterfvCookie;
+    pub fn xcb_glx_get_tex_parameterfv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexParameterfvCookie;
+    pub fn xcb_glx_get_tex_parameteriv_data(R: *const XCBGlxGetTexParameterivReply) -> *mut i32;
+    pub fn xcb_glx_get_tex_parameteriv_data_length(R: *const XCBGlxGetTexParameterivReply)
+        -> c_int;
+    pub fn xcb_glx_get_tex_parameteriv_data_end(
+        R: *const XCBGlxGetTexParameterivReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_tex_parameteriv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetTexParameterivCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetTexParameterivReply;
+    pub fn xcb_glx_get_tex_parameteriv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexParameterivCookie;
+    pub fn xcb_glx_get_tex_parameteriv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetTexParameterivCookie;
+    pub fn xcb_glx_get_tex_level_parameterfv_data(
+        R: *const XCBGlxGetTexLevelParameterfvReply,
+    ) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_tex_level_parameterfv_data_length(
+        R: *const XCBGlxGetTexLevelParameterfvReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_tex_level_parameterfv_data_end(
+        R: *const XCBGlxGetTexLevelParameterfvReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_tex_level_parameterfv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetTexLevelParameterfvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetTexLevelParameterfvReply;
+    pub fn xcb_glx_get_tex_level_parameterfv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        level: i32,
+        pname: u32,
+    ) -> XCBGlxGetTexLevelParameterfvCookie;
+    pub fn xcb_glx_get_tex_level_parameterfv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        level: i32,
+        pname: u32,
+    ) -> XCBGlxGetTexLevelParameterfvCookie;
+    pub fn xcb_glx_get_tex_level_parameteriv_data(
+        R: *const XCBGlxGetTexLevelParameterivReply,
+    ) -> *mut i32;
+    pub fn xcb_glx_get_tex_level_parameteriv_data_length(
+        R: *const XCBGlxGetTexLevelParameterivReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_tex_level_parameteriv_data_end(
+        R: *const XCBGlxGetTexLevelParameterivReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_tex_level_parameteriv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetTexLevelParameterivCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetTexLevelParameterivReply;
+    pub fn xcb_glx_get_tex_level_parameteriv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        level: i32,
+        pname: u32,
+    ) -> XCBGlxGetTexLevelParameterivCookie;
+    pub fn xcb_glx_get_tex_level_parameteriv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        level: i32,
+        pname: u32,
+    ) -> XCBGlxGetTexLevelParameterivCookie;
+    pub fn xcb_glx_is_enabled_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxIsEnabledCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxIsEnabledReply;
+    pub fn xcb_glx_is_enabled(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        capability: u32,
+    ) -> XCBGlxIsEnabledCookie;
+    pub fn xcb_glx_is_enabled_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        capability: u32,
+    ) -> XCBGlxIsEnabledCookie;
+    pub fn xcb_glx_is_list_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxIsListCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxIsListReply;
+    pub fn xcb_glx_is_list(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        list: u32,
+    ) -> XCBGlxIsListCookie;
+    pub fn xcb_glx_is_list_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        list: u32,
+    ) -> XCBGlxIsListCookie;
+    pub fn xcb_glx_flush_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_flush(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_are_textures_resident_data(R: *const XCBGlxAreTexturesResidentReply) -> *mut u8;
+    pub fn xcb_glx_are_textures_resident_data_length(
+        R: *const XCBGlxAreTexturesResidentReply,
+    ) -> c_int;
+    pub fn xcb_glx_are_textures_resident_data_end(
+        R: *const XCBGlxAreTexturesResidentReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_are_textures_resident_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxAreTexturesResidentCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxAreTexturesResidentReply;
+    pub fn xcb_glx_are_textures_resident(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        n: i32,
+        textures: *const u32,
+    ) -> XCBGlxAreTexturesResidentCookie;
+    pub fn xcb_glx_are_textures_resident_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        n: i32,
+        textures: *const u32,
+    ) -> XCBGlxAreTexturesResidentCookie;
+    pub fn xcb_glx_delete_textures_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        n: i32,
+        textures: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_delete_textures(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        n: i32,
+        textures: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_gen_textures_data(R: *const XCBGlxGenTexturesReply) -> *mut u32;
+    pub fn xcb_glx_gen_textures_data_length(R: *const XCBGlxGenTexturesReply) -> c_int;
+    pub fn xcb_glx_gen_textures_data_end(R: *const XCBGlxGenTexturesReply) -> XCBGenericIterator;
+    pub fn xcb_glx_gen_textures_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGenTexturesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGenTexturesReply;
+    pub fn xcb_glx_gen_textures(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        n: i32,
+    ) -> XCBGlxGenTexturesCookie;
+    pub fn xcb_glx_gen_textures_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        n: i32,
+    ) -> XCBGlxGenTexturesCookie;
+    pub fn xcb_glx_is_texture_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxIsTextureCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxIsTextureReply;
+    pub fn xcb_glx_is_texture(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        texture: u32,
+    ) -> XCBGlxIsTextureCookie;
+    pub fn xcb_glx_is_texture_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        texture: u32,
+    ) -> XCBGlxIsTextureCookie;
+    pub fn xcb_glx_get_color_table_data(R: *const XCBGlxGetColorTableReply) -> *mut u8;
+    pub fn xcb_glx_get_color_table_data_length(R: *const XCBGlxGetColorTableReply) -> c_int;
+    pub fn xcb_glx_get_color_table_data_end(
+        R: *const XCBGlxGetColorTableReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_color_table_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetColorTableCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetColorTableReply;
+    pub fn xcb_glx_get_color_table(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+    ) -> XCBGlxGetColorTableCookie;
+    pub fn xcb_glx_get_color_table_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+    ) -> XCBGlxGetColorTableCookie;
+    pub fn xcb_glx_get_color_table_parameterfv_data(
+        R: *const XCBGlxGetColorTableParameterfvReply,
+    ) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_color_table_parameterfv_data_length(
+        R: *const XCBGlxGetColorTableParameterfvReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_color_table_parameterfv_data_end(
+        R: *const XCBGlxGetColorTableParameterfvReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_color_table_parameterfv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetColorTableParameterfvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetColorTableParameterfvReply;
+    pub fn xcb_glx_get_color_table_parameterfv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetColorTableParameterfvCookie;
+    pub fn xcb_glx_get_color_table_parameterfv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetColorTableParameterfvCookie;
+    pub fn xcb_glx_get_color_table_parameteriv_data(
+        R: *const XCBGlxGetColorTableParameterivReply,
+    ) -> *mut i32;
+    pub fn xcb_glx_get_color_table_parameteriv_data_length(
+        R: *const XCBGlxGetColorTableParameterivReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_color_table_parameteriv_data_end(
+        R: *const XCBGlxGetColorTableParameterivReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_color_table_parameteriv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetColorTableParameterivCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetColorTableParameterivReply;
+    pub fn xcb_glx_get_color_table_parameteriv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetColorTableParameterivCookie;
+    pub fn xcb_glx_get_color_table_parameteriv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetColorTableParameterivCookie;
+    pub fn xcb_glx_get_convolution_filter_data(
+        R: *const XCBGlxGetConvolutionFilterReply,
+    ) -> *mut u8;
+    pub fn xcb_glx_get_convolution_filter_data_length(
+        R: *const XCBGlxGetConvolutionFilterReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_convolution_filter_data_end(
+        R: *const XCBGlxGetConvolutionFilterReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_convolution_filter_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetConvolutionFilterCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetConvolutionFilterReply;
+    pub fn xcb_glx_get_convolution_filter(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+    ) -> XCBGlxGetConvolutionFilterCookie;
+    pub fn xcb_glx_get_convolution_filter_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+    ) -> XCBGlxGetConvolutionFilterCookie;
+    pub fn xcb_glx_get_convolution_parameterfv_data(
+        R: *const XCBGlxGetConvolutionParameterfvReply,
+    ) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_convolution_parameterfv_data_length(
+        R: *const XCBGlxGetConvolutionParameterfvReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_convolution_parameterfv_data_end(
+        R: *const XCBGlxGetConvolutionParameterfvReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_convolution_parameterfv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetConvolutionParameterfvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetConvolutionParameterfvReply;
+    pub fn xcb_glx_get_convolution_parameterfv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetConvolutionParameterfvCookie;
+    pub fn xcb_glx_get_convolution_parameterfv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetConvolutionParameterfvCookie;
+    pub fn xcb_glx_get_convolution_parameteriv_data(
+        R: *const XCBGlxGetConvolutionParameterivReply,
+    ) -> *mut i32;
+    pub fn xcb_glx_get_convolution_parameteriv_data_length(
+        R: *const XCBGlxGetConvolutionParameterivReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_convolution_parameteriv_data_end(
+        R: *const XCBGlxGetConvolutionParameterivReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_convolution_parameteriv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetConvolutionParameterivCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetConvolutionParameterivReply;
+    pub fn xcb_glx_get_convolution_parameteriv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetConvolutionParameterivCookie;
+    pub fn xcb_glx_get_convolution_parameteriv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetConvolutionParameterivCookie;
+    pub fn xcb_glx_get_separable_filter_rows_and_cols(
+        R: *const XCBGlxGetSeparableFilterReply,
+    ) -> *mut u8;
+    pub fn xcb_glx_get_separable_filter_rows_and_cols_length(
+        R: *const XCBGlxGetSeparableFilterReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_separable_filter_rows_and_cols_end(
+        R: *const XCBGlxGetSeparableFilterReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_separable_filter_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetSeparableFilterCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetSeparableFilterReply;
+    pub fn xcb_glx_get_separable_filter(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+    ) -> XCBGlxGetSeparableFilterCookie;
+    pub fn xcb_glx_get_separable_filter_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+    ) -> XCBGlxGetSeparableFilterCookie;
+    pub fn xcb_glx_get_histogram_data(R: *const XCBGlxGetHistogramReply) -> *mut u8;
+    pub fn xcb_glx_get_histogram_data_length(R: *const XCBGlxGetHistogramReply) -> c_int;
+    pub fn xcb_glx_get_histogram_data_end(R: *const XCBGlxGetHistogramReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_histogram_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetHistogramCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetHistogramReply;
+    pub fn xcb_glx_get_histogram(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+        reset: u8,
+    ) -> XCBGlxGetHistogramCookie;
+    pub fn xcb_glx_get_histogram_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+        reset: u8,
+    ) -> XCBGlxGetHistogramCookie;
+    pub fn xcb_glx_get_histogram_parameterfv_data(
+        R: *const XCBGlxGetHistogramParameterfvReply,
+    ) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_histogram_parameterfv_data_length(
+        R: *const XCBGlxGetHistogramParameterfvReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_histogram_parameterfv_data_end(
+        R: *const XCBGlxGetHistogramParameterfvReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_histogram_parameterfv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetHistogramParameterfvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetHistogramParameterfvReply;
+    pub fn xcb_glx_get_histogram_parameterfv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetHistogramParameterfvCookie;
+    pub fn xcb_glx_get_histogram_parameterfv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetHistogramParameterfvCookie;
+    pub fn xcb_glx_get_histogram_parameteriv_data(
+        R: *const XCBGlxGetHistogramParameterivReply,
+    ) -> *mut i32;
+    pub fn xcb_glx_get_histogram_parameteriv_data_length(
+        R: *const XCBGlxGetHistogramParameterivReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_histogram_parameteriv_data_end(
+        R: *const XCBGlxGetHistogramParameterivReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_histogram_parameteriv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetHistogramParameterivCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetHistogramParameterivReply;
+    pub fn xcb_glx_get_histogram_parameteriv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetHistogramParameterivCookie;
+    pub fn xcb_glx_get_histogram_parameteriv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetHistogramParameterivCookie;
+    pub fn xcb_glx_get_minmax_data(R: *const XCBGlxGetMinmaxReply) -> *mut u8;
+    pub fn xcb_glx_get_minmax_data_length(R: *const XCBGlxGetMinmaxReply) -> c_int;
+    pub fn xcb_glx_get_minmax_data_end(R: *const XCBGlxGetMinmaxReply) -> XCBGenericIterator;
+    pub fn xcb_glx_get_minmax_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetMinmaxCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetMinmaxReply;
+    pub fn xcb_glx_get_minmax(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+        reset: u8,
+    ) -> XCBGlxGetMinmaxCookie;
+    pub fn xcb_glx_get_minmax_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        format: u32,
+        type_: u32,
+        swap_bytes: u8,
+        reset: u8,
+    ) -> XCBGlxGetMinmaxCookie;
+    pub fn xcb_glx_get_minmax_parameterfv_data(
+        R: *const XCBGlxGetMinmaxParameterfvReply,
+    ) -> *mut XCBGlxFloat32;
+    pub fn xcb_glx_get_minmax_parameterfv_data_length(
+        R: *const XCBGlxGetMinmaxParameterfvReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_minmax_parameterfv_data_end(
+        R: *const XCBGlxGetMinmaxParameterfvReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_minmax_parameterfv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetMinmaxParameterfvCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetMinmaxParameterfvReply;
+    pub fn xcb_glx_get_minmax_parameterfv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetMinmaxParameterfvCookie;
+    pub fn xcb_glx_get_minmax_parameterfv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetMinmaxParameterfvCookie;
+    pub fn xcb_glx_get_minmax_parameteriv_data(
+        R: *const XCBGlxGetMinmaxParameterivReply,
+    ) -> *mut i32;
+    pub fn xcb_glx_get_minmax_parameteriv_data_length(
+        R: *const XCBGlxGetMinmaxParameterivReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_minmax_parameteriv_data_end(
+        R: *const XCBGlxGetMinmaxParameterivReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_minmax_parameteriv_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetMinmaxParameterivCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetMinmaxParameterivReply;
+    pub fn xcb_glx_get_minmax_parameteriv(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetMinmaxParameterivCookie;
+    pub fn xcb_glx_get_minmax_parameteriv_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetMinmaxParameterivCookie;
+    pub fn xcb_glx_get_compressed_tex_image_arb_data(
+        R: *const XCBGlxGetCompressedTexImageArbReply,
+    ) -> *mut u8;
+    pub fn xcb_glx_get_compressed_tex_image_arb_data_length(
+        R: *const XCBGlxGetCompressedTexImageArbReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_compressed_tex_image_arb_data_end(
+        R: *const XCBGlxGetCompressedTexImageArbReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_compressed_tex_image_arb_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetCompressedTexImageArbCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetCompressedTexImageArbReply;
+    pub fn xcb_glx_get_compressed_tex_image_arb(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        level: i32,
+    ) -> XCBGlxGetCompressedTexImageArbCookie;
+    pub fn xcb_glx_get_compressed_tex_image_arb_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        level: i32,
+    ) -> XCBGlxGetCompressedTexImageArbCookie;
+    pub fn xcb_glx_delete_queries_arb_checked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        n: i32,
+        ids: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_delete_queries_arb(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        n: i32,
+        ids: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_glx_gen_queries_arb_data(R: *const XCBGlxGenQueriesArbReply) -> *mut u32;
+    pub fn xcb_glx_gen_queries_arb_data_length(R: *const XCBGlxGenQueriesArbReply) -> c_int;
+    pub fn xcb_glx_gen_queries_arb_data_end(
+        R: *const XCBGlxGenQueriesArbReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_gen_queries_arb_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGenQueriesArbCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGenQueriesArbReply;
+    pub fn xcb_glx_gen_queries_arb(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        n: i32,
+    ) -> XCBGlxGenQueriesArbCookie;
+    pub fn xcb_glx_gen_queries_arb_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        n: i32,
+    ) -> XCBGlxGenQueriesArbCookie;
+    pub fn xcb_glx_is_query_arb_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxIsQueryArbCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxIsQueryArbReply;
+    pub fn xcb_glx_is_query_arb(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        id: u32,
+    ) -> XCBGlxIsQueryArbCookie;
+    pub fn xcb_glx_is_query_arb_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        id: u32,
+    ) -> XCBGlxIsQueryArbCookie;
+    pub fn xcb_glx_get_queryiv_arb_data(R: *const XCBGlxGetQueryivArbReply) -> *mut i32;
+    pub fn xcb_glx_get_queryiv_arb_data_length(R: *const XCBGlxGetQueryivArbReply) -> c_int;
+    pub fn xcb_glx_get_queryiv_arb_data_end(
+        R: *const XCBGlxGetQueryivArbReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_queryiv_arb_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetQueryivArbCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetQueryivArbReply;
+    pub fn xcb_glx_get_queryiv_arb(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetQueryivArbCookie;
+    pub fn xcb_glx_get_queryiv_arb_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        target: u32,
+        pname: u32,
+    ) -> XCBGlxGetQueryivArbCookie;
+    pub fn xcb_glx_get_query_objectiv_arb_data(
+        R: *const XCBGlxGetQueryObjectivArbReply,
+    ) -> *mut i32;
+    pub fn xcb_glx_get_query_objectiv_arb_data_length(
+        R: *const XCBGlxGetQueryObjectivArbReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_query_objectiv_arb_data_end(
+        R: *const XCBGlxGetQueryObjectivArbReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_query_objectiv_arb_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetQueryObjectivArbCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetQueryObjectivArbReply;
+    pub fn xcb_glx_get_query_objectiv_arb(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        id: u32,
+        pname: u32,
+    ) -> XCBGlxGetQueryObjectivArbCookie;
+    pub fn xcb_glx_get_query_objectiv_arb_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        id: u32,
+        pname: u32,
+    ) -> XCBGlxGetQueryObjectivArbCookie;
+    pub fn xcb_glx_get_query_objectuiv_arb_data(
+        R: *const XCBGlxGetQueryObjectuivArbReply,
+    ) -> *mut u32;
+    pub fn xcb_glx_get_query_objectuiv_arb_data_length(
+        R: *const XCBGlxGetQueryObjectuivArbReply,
+    ) -> c_int;
+    pub fn xcb_glx_get_query_objectuiv_arb_data_end(
+        R: *const XCBGlxGetQueryObjectuivArbReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_glx_get_query_objectuiv_arb_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGlxGetQueryObjectuivArbCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGlxGetQueryObjectuivArbReply;
+    pub fn xcb_glx_get_query_objectuiv_arb(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        id: u32,
+        pname: u32,
+    ) -> XCBGlxGetQueryObjectuivArbCookie;
+    pub fn xcb_glx_get_query_objectuiv_arb_unchecked(
+        connection: *mut XCBConnection,
+        context_tag: XCBGlxContextTag,
+        id: u32,
+        pname: u32,
+    ) -> XCBGlxGetQueryObjectuivArbCookie;
+}
@@ -0,0 +1,150 @@
+// Generated by xcbgen from xml/glx.xml, do not edit.
+
+pub const XCB_GLX_MAJOR_VERSION: u32 = 1;
+pub const XCB_GLX_MINOR_VERSION: u32 = 4;
+pub const XCB_GLX_GENERIC: i32 = -1;
+pub const XCB_GLX_BAD_CONTEXT: u8 = 0;
+pub const XCB_GLX_BAD_CONTEXT_STATE: u8 = 1;
+pub const XCB_GLX_BAD_DRAWABLE: u8 = 2;
+pub const XCB_GLX_BAD_PIXMAP: u8 = 3;
+pub const XCB_GLX_BAD_CONTEXT_TAG: u8 = 4;
+pub const XCB_GLX_BAD_CURRENT_WINDOW: u8 = 5;
+pub const XCB_GLX_BAD_RENDER_REQUEST: u8 = 6;
+pub const XCB_GLX_BAD_LARGE_REQUEST: u8 = 7;
+pub const XCB_GLX_UNSUPPORTED_PRIVATE_REQUEST: u8 = 8;
+pub const XCB_GLX_BAD_FB_CONFIG: u8 = 9;
+pub const XCB_GLX_BAD_PBUFFER: u8 = 10;
+pub const XCB_GLX_BAD_CURRENT_DRAWABLE: u8 = 11;
+pub const XCB_GLX_BAD_WINDOW: u8 = 12;
+pub const XCB_GLX_GLX_BAD_PROFILE_ARB: u8 = 13;
+pub const XCB_GLX_PBUFFER_CLOBBER: u8 = 0;
+pub const XCB_GLX_BUFFER_SWAP_COMPLETE: u8 = 1;
+pub const XCB_GLX_PBCET_DAMAGED: u32 = 32791;
+pub const XCB_GLX_PBCET_SAVED: u32 = 32792;
+pub const XCB_GLX_PBCDT_WINDOW: u32 = 32793;
+pub const XCB_GLX_PBCDT_PBUFFER: u32 = 32794;
+pub const XCB_GLX_RENDER: u8 = 1;
+pub const XCB_GLX_RENDER_LARGE: u8 = 2;
+pub const XCB_GLX_CREATE_CONTEXT: u8 = 3;
+pub const XCB_GLX_DESTROY_CONTEXT: u8 = 4;
+pub const XCB_GLX_MAKE_CURRENT: u8 = 5;
+pub const XCB_GLX_IS_DIRECT: u8 = 6;
+pub const XCB_GLX_QUERY_VERSION: u8 = 7;
+pub const XCB_GLX_WAIT_GL: u8 = 8;
+pub const XCB_GLX_WAIT_X: u8 = 9;
+pub const XCB_GLX_COPY_CONTEXT: u8 = 10;
+pub const XCB_GLX_GC_GL_CURRENT_BIT: u32 = 1;
+pub const XCB_GLX_GC_GL_POINT_BIT: u32 = 2;
+pub const XCB_GLX_GC_GL_LINE_BIT: u32 = 4;
+pub const XCB_GLX_GC_GL_POLYGON_BIT: u32 = 8;
+pub const XCB_GLX_GC_GL_POLYGON_STIPPLE_BIT: u32 = 16;
+pub const XCB_GLX_GC_GL_PIXEL_MODE_BIT: u32 = 32;
+pub const XCB_GLX_GC_GL_LIGHTING_BIT: u32 = 64;
+pub const XCB_GLX_GC_GL_FOG_BIT: u32 = 128;
+pub const XCB_GLX_GC_GL_DEPTH_BUFFER_BIT: u32 = 256;
+pub const XCB_GLX_GC_GL_ACCUM_BUFFER_BIT: u32 = 512;
+pub const XCB_GLX_GC_GL_STENCIL_BUFFER_BIT: u32 = 1024;
+pub const XCB_GLX_GC_GL_VIEWPORT_BIT: u32 = 2048;
+pub const XCB_GLX_GC_GL_TRANSFORM_BIT: u32 = 4096;
+pub const XCB_GLX_GC_GL_ENABLE_BIT: u32 = 8192;
+pub const XCB_GLX_GC_GL_COLOR_BUFFER_BIT: u32 = 16384;
+pub const XCB_GLX_GC_GL_HINT_BIT: u32 = 32768;
+pub const XCB_GLX_GC_GL_EVAL_BIT: u32 = 65536;
+pub const XCB_GLX_GC_GL_LIST_BIT: u32 = 131072;
+pub const XCB_GLX_GC_GL_TEXTURE_BIT: u32 = 262144;
+pub const XCB_GLX_GC_GL_SCISSOR_BIT: u32 = 524288;
+pub const XCB_GLX_GC_GL_ALL_ATTRIB_BITS: u32 = 16777215;
+pub const XCB_GLX_SWAP_BUFFERS: u8 = 11;
+pub const XCB_GLX_USE_X_FONT: u8 = 12;
+pub const XCB_GLX_CREATE_GLX_PIXMAP: u8 = 13;
+pub const XCB_GLX_GET_VISUAL_CONFIGS: u8 = 14;
+pub const XCB_GLX_DESTROY_GLX_PIXMAP: u8 = 15;
+pub const XCB_GLX_VENDOR_PRIVATE: u8 = 16;
+pub const XCB_GLX_VENDOR_PRIVATE_WITH_REPLY: u8 = 17;
+pub const XCB_GLX_QUERY_EXTENSIONS_STRING: u8 = 18;
+pub const XCB_GLX_QUERY_SERVER_STRING: u8 = 19;
+pub const XCB_GLX_CLIENT_INFO: u8 = 20;
+pub const XCB_GLX_GET_FB_CONFIGS: u8 = 21;
+pub const XCB_GLX_CREATE_PIXMAP: u8 = 22;
+pub const XCB_GLX_DESTROY_PIXMAP: u8 = 23;
+pub const XCB_GLX_CREATE_NEW_CONTEXT: u8 = 24;
+pub const XCB_GLX_QUERY_CONTEXT: u8 = 25;
+pub const XCB_GLX_MAKE_CONTEXT_CURRENT: u8 = 26;
+pub const XCB_GLX_CREATE_PBUFFER: u8 = 27;
+pub const XCB_GLX_DESTROY_PBUFFER: u8 = 28;
+pub const XCB_GLX_GET_DRAWABLE_ATTRIBUTES: u8 = 29;
+pub const XCB_GLX_CHANGE_DRAWABLE_ATTRIBUTES: u8 = 30;
+pub const XCB_GLX_CREATE_WINDOW: u8 = 31;
+pub const XCB_GLX_DELETE_WINDOW: u8 = 32;
+pub const XCB_GLX_SET_CLIENT_INFO_ARB: u8 = 33;
+pub const XCB_GLX_CREATE_CONTEXT_ATTRIBS_ARB: u8 = 34;
+pub const XCB_GLX_SET_CLIENT_INFO_2ARB: u8 = 35;
+pub const XCB_GLX_NEW_LIST: u8 = 101;
+pub const XCB_GLX_END_LIST: u8 = 102;
+pub const XCB_GLX_DELETE_LISTS: u8 = 103;
+pub const XCB_GLX_GEN_LISTS: u8 = 104;
+pub const XCB_GLX_FEEDBACK_BUFFER: u8 = 105;
+pub const XCB_GLX_SELECT_BUFFER: u8 = 106;
+pub const XCB_GLX_RENDER_MODE: u8 = 107;
+pub const XCB_GLX_RM_GL_RENDER: u32 = 7168;
+pub const XCB_GLX_RM_GL_FEEDBACK: u32 = 7169;
+pub const XCB_GLX_RM_GL_SELECT: u32 = 7170;
+pub const XCB_GLX_FINISH: u8 = 108;
+pub const XCB_GLX_PIXEL_STOREF: u8 = 109;
+pub const XCB_GLX_PIXEL_STOREI: u8 = 110;
+pub const XCB_GLX_READ_PIXELS: u8 = 111;
+pub const XCB_GLX_GET_BOOLEANV: u8 = 112;
+pub const XCB_GLX_GET_CLIP_PLANE: u8 = 113;
+pub const XCB_GLX_GET_DOUBLEV: u8 = 114;
+pub const XCB_GLX_GET_ERROR: u8 = 115;
+pub const XCB_GLX_GET_FLOATV: u8 = 116;
+pub const XCB_GLX_GET_INTEGERV: u8 = 117;
+pub const XCB_GLX_GET_LIGHTFV: u8 = 118;
+pub const XCB_GLX_GET_LIGHTIV: u8 = 119;
+pub const XCB_GLX_GET_MAPDV: u8 = 120;
+pub const XCB_GLX_GET_MAPFV: u8 = 121;
+pub const XCB_GLX_GET_MAPIV: u8 = 122;
+pub const XCB_GLX_GET_MATERIALFV: u8 = 123;
+pub const XCB_GLX_GET_MATERIALIV: u8 = 124;
+pub const XCB_GLX_GET_PIXEL_MAPFV: u8 = 125;
+pub const XCB_GLX_GET_PIXEL_MAPUIV: u8 = 126;
+pub const XCB_GLX_GET_PIXEL_MAPUSV: u8 = 127;
+pub const XCB_GLX_GET_POLYGON_STIPPLE: u8 = 128;
+pub const XCB_GLX_GET_STRING: u8 = 129;
+pub const XCB_GLX_GET_TEX_ENVFV: u8 = 130;
+pub const XCB_GLX_GET_TEX_ENVIV: u8 = 131;
+pub const XCB_GLX_GET_TEX_GENDV: u8 = 132;
+pub const XCB_GLX_GET_TEX_GENFV: u8 = 133;
+pub const XCB_GLX_GET_TEX_GENIV: u8 = 134;
+pub const XCB_GLX_GET_TEX_IMAGE: u8 = 135;
+pub const XCB_GLX_GET_TEX_PARAMETERFV: u8 = 136;
+pub const XCB_GLX_GET_TEX_PARAMETERIV: u8 = 137;
+pub const XCB_GLX_GET_TEX_LEVEL_PARAMETERFV: u8 = 138;
+pub const XCB_GLX_GET_TEX_LEVEL_PARAMETERIV: u8 = 139;
+pub const XCB_GLX_IS_ENABLED: u8 = 140;
+pub const XCB_GLX_IS_LIST: u8 = 141;
+pub const XCB_GLX_FLUSH: u8 = 142;
+pub const XCB_GLX_ARE_TEXTURES_RESIDENT: u8 = 143;
+pub const XCB_GLX_DELETE_TEXTURES: u8 = 144;
+pub const XCB_GLX_GEN_TEXTURES: u8 = 145;
+pub const XCB_GLX_IS_TEXTURE: u8 = 146;
+pub const XCB_GLX_GET_COLOR_TABLE: u8 = 147;
+pub const XCB_GLX_GET_COLOR_TABLE_PARAMETERFV: u8 = 148;
+pub const XCB_GLX_GET_COLOR_TABLE_PARAMETERIV: u8 = 149;
+pub const XCB_GLX_GET_CONVOLUTION_FILTER: u8 = 150;
+pub const XCB_GLX_GET_CONVOLUTION_PARAMETERFV: u8 = 151;
+pub const XCB_GLX_GET_CONVOLUTION_PARAMETERIV: u8 = 152;
+pub const XCB_GLX_GET_SEPARABLE_FILTER: u8 = 153;
+pub const XCB_GLX_GET_HISTOGRAM: u8 = 154;
+pub const XCB_GLX_GET_HISTOGRAM_PARAMETERFV: u8 = 155;
+pub const XCB_GLX_GET_HISTOGRAM_PARAMETERIV: u8 = 156;
+pub const XCB_GLX_GET_MINMAX: u8 = 157;
+pub const XCB_GLX_GET_MINMAX_PARAMETERFV: u8 = 158;
+pub const XCB_GLX_GET_MINMAX_PARAMETERIV: u8 = 159;
+pub const XCB_GLX_GET_COMPRESSED_TEX_IMAGE_ARB: u8 = 160;
+pub const XCB_GLX_DELETE_QUERIES_ARB: u8 = 161;
+pub const XCB_GLX_GEN_QUERIES_ARB: u8 = 162;
+pub const XCB_GLX_IS_QUERY_ARB: u8 = 163;
+pub const XCB_GLX_GET_QUERYIV_ARB: u8 = 164;
+pub const XCB_GLX_GET_QUERY_OBJECTIV_ARB: u8 = 165;
+pub const XCB_GLX_GET_QUERY_OBJECTUIV_ARB: u8 = 166;
@@ -0,0 +1,2698 @@
+// Generated by xcbgen from xml/glx.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::os::raw::c_char;
+use std::ptr;
+use std::slice;
+
+use proto::xproto::{XCBFont, XCBPixmap, XCBVisualid, XCBWindow};
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBGlxPixmap = cdef::XCBGlxPixmap;
+pub type XCBGlxPixmapIterator = cdef::XCBGlxPixmapIterator;
+pub type XCBGlxContext = cdef::XCBGlxContext;
+pub type XCBGlxContextIterator = cdef::XCBGlxContextIterator;
+pub type XCBGlxPbuffer = cdef::XCBGlxPbuffer;
+pub type XCBGlxPbufferIterator = cdef::XCBGlxPbufferIterator;
+pub type XCBGlxWindow = cdef::XCBGlxWindow;
+pub type XCBGlxWindowIterator = cdef::XCBGlxWindowIterator;
+pub type XCBGlxFbconfig = cdef::XCBGlxFbconfig;
+pub type XCBGlxFbconfigIterator = cdef::XCBGlxFbconfigIterator;
+pub type XCBGlxDrawable = cdef::XCBGlxDrawable;
+pub type XCBGlxDrawableIterator = cdef::XCBGlxDrawableIterator;
+pub type XCBGlxFloat32 = cdef::XCBGlxFloat32;
+pub type XCBGlxFloat32Iterator = cdef::XCBGlxFloat32Iterator;
+pub type XCBGlxFloat64 = cdef::XCBGlxFloat64;
+pub type XCBGlxFloat64Iterator = cdef::XCBGlxFloat64Iterator;
+pub type XCBGlxBool32 = cdef::XCBGlxBool32;
+pub type XCBGlxBool32Iterator = cdef::XCBGlxBool32Iterator;
+pub type XCBGlxContextTag = cdef::XCBGlxContextTag;
+pub type XCBGlxContextTagIterator = cdef::XCBGlxContextTagIterator;
+pub type XCBGlxGenericError = cdef::XCBGlxGenericError;
+pub type XCBGlxBadContextError = cdef::XCBGlxBadContextError;
+pub type XCBGlxBadContextStateError = cdef::XCBGlxBadContextStateError;
+pub type XCBGlxBadDrawableError = cdef::XCBGlxBadDrawableError;
+pub type XCBGlxBadPixmapError = cdef::XCBGlxBadPixmapError;
+pub type XCBGlxBadContextTagError = cdef::XCBGlxBadContextTagError;
+pub type XCBGlxBadCurrentWindowError = cdef::XCBGlxBadCurrentWindowError;
+pub type XCBGlxBadRenderRequestError = cdef::XCBGlxBadRenderRequestError;
+pub type XCBGlxBadLargeRequestError = cdef::XCBGlxBadLargeRequestError;
+pub type XCBGlxUnsupportedPrivateRequestError = cdef::XCBGlxUnsupportedPrivateRequestError;
+pub type XCBGlxBadFbConfigError = cdef::XCBGlxBadFbConfigError;
+pub type XCBGlxBadPbufferError = cdef::XCBGlxBadPbufferError;
+pub type XCBGlxBadCurrentDrawableError = cdef::XCBGlxBadCurrentDrawableError;
+pub type XCBGlxBadWindowError = cdef::XCBGlxBadWindowError;
+pub type XCBGlxGlxBadProfileArbError = cdef::XCBGlxGlxBadProfileArbError;
+pub type XCBGlxPbufferClobberEvent = cdef::XCBGlxPbufferClobberEvent;
+pub type XCBGlxBufferSwapCompleteEvent = cdef::XCBGlxBufferSwapCompleteEvent;
+pub type XCBGlxRenderRequest = cdef::XCBGlxRenderRequest;
+pub type XCBGlxRenderLargeRequest = cdef::XCBGlxRenderLargeRequest;
+pub type XCBGlxCreateContextRequest = cdef::XCBGlxCreateContextRequest;
+pub type XCBGlxDestroyContextRequest = cdef::XCBGlxDestroyContextRequest;
+pub type XCBGlxMakeCurrentRequest = cdef::XCBGlxMakeCurrentRequest;
+pub type XCBGlxMakeCurrentCookie = cdef::XCBGlxMakeCurrentCookie;
+pub type XCBGlxMakeCurrentReply = cdef::XCBGlxMakeCurrentReply;
+pub type XCBGlxIsDirectRequest = cdef::XCBGlxIsDirectRequest;
+pub type XCBGlxIsDirectCookie = cdef::XCBGlxIsDirectCookie;
+pub type XCBGlxIsDirectReply = cdef::XCBGlxIsDirectReply;
+pub type XCBGlxQueryVersionRequest = cdef::XCBGlxQueryVersionRequest;
+pub type XCBGlxQueryVersionCookie = cdef::XCBGlxQueryVersionCookie;
+pub type XCBGlxQueryVersionReply = cdef::XCBGlxQueryVersionReply;
+pub type XCBGlxWaitGlRequest = cdef::XCBGlxWaitGlRequest;
+pub type XCBGlxWaitXRequest = cdef::XCBGlxWaitXRequest;
+pub type XCBGlxCopyContextRequest = cdef::XCBGlxCopyContextRequest;
+pub type XCBGlxSwapBuffersRequest = cdef::XCBGlxSwapBuffersRequest;
+pub type XCBGlxUseXFontRequest = cdef::XCBGlxUseXFontRequest;
+pub type XCBGlxCreateGlxPixmapRequest = cdef::XCBGlxCreateGlxPixmapRequest;
+pub type XCBGlxGetVisualConfigsRequest = cdef::XCBGlxGetVisualConfigsRequest;
+pub type XCBGlxGetVisualConfigsCookie = cdef::XCBGlxGetVisualConfigsCookie;
+pub type XCBGlxGetVisualConfigsReply = cdef::XCBGlxGetVisualConfigsReply;
+pub type XCBGlxDestroyGlxPixmapRequest = cdef::XCBGlxDestroyGlxPixmapRequest;
+pub type XCBGlxVendorPrivateRequest = cdef::XCBGlxVendorPrivateRequest;
+pub type XCBGlxVendorPrivateWithReplyRequest = cdef::XCBGlxVendorPrivateWithReplyRequest;
+pub type XCBGlxVendorPrivateWithReplyCookie = cdef::XCBGlxVendorPrivateWithReplyCookie;
+pub type XCBGlxVendorPrivateWithReplyReply = cdef::XCBGlxVendorPrivateWithReplyReply;
+pub type XCBGlxQueryExtensionsStringRequest = cdef::XCBGlxQueryExtensionsStringRequest;
+pub type XCBGlxQueryExtensionsStringCookie = cdef::XCBGlxQueryExtensionsStringCookie;
+pub type XCBGlxQueryExtensionsStringReply = cdef::XCBGlxQueryExtensionsStringReply;
+pub type XCBGlxQueryServerStringRequest = cdef::XCBGlxQueryServerStringRequest;
+pub type XCBGlxQueryServerStringCookie = cdef::XCBGlxQueryServerStringCookie;
+pub type XCBGlxQueryServerStringReply = cdef::XCBGlxQueryServerStringReply;
+pub type XCBGlxClientInfoRequest = cdef::XCBGlxClientInfoRequest;
+pub type XCBGlxGetFbConfigsRequest = cdef::XCBGlxGetFbConfigsRequest;
+pub type XCBGlxGetFbConfigsCookie = cdef::XCBGlxGetFbConfigsCookie;
+pub type XCBGlxGetFbConfigsReply = cdef::XCBGlxGetFbConfigsReply;
+pub type XCBGlxCreatePixmapRequest = cdef::XCBGlxCreatePixmapRequest;
+pub type XCBGlxDestroyPixmapRequest = cdef::XCBGlxDestroyPixmapRequest;
+pub type XCBGlxCreateNewContextRequest = cdef::XCBGlxCreateNewContextRequest;
+pub type XCBGlxQueryContextRequest = cdef::XCBGlxQueryContextRequest;
+pub type XCBGlxQueryContextCookie = cdef::XCBGlxQueryContextCookie;
+pub type XCBGlxQueryContextReply = cdef::XCBGlxQueryContextReply;
+pub type XCBGlxMakeContextCurrentRequest = cdef::XCBGlxMakeContextCurrentRequest;
+pub type XCBGlxMakeContextCurrentCookie = cdef::XCBGlxMakeContextCurrentCookie;
+pub type XCBGlxMakeContextCurrentReply = cdef::XCBGlxMakeContextCurrentReply;
+pub type XCBGlxCreatePbufferRequest = cdef::XCBGlxCreatePbufferRequest;
+pub type XCBGlxDestroyPbufferRequest = cdef::XCBGlxDestroyPbufferRequest;
+pub type XCBGlxGetDrawableAttributesRequest = cdef::XCBGlxGetDrawableAttributesRequest;
+pub type XCBGlxGetDrawableAttributesCookie = cdef::XCBGlxGetDrawableAttributesCookie;
+pub type XCBGlxGetDrawableAttributesReply = cdef::XCBGlxGetDrawableAttributesReply;
+pub type XCBGlxChangeDrawableAttributesRequest = cdef::XCBGlxChangeDrawableAttributesRequest;
+pub type XCBGlxCreateWindowRequest = cdef::XCBGlxCreateWindowRequest;
+pub type XCBGlxDeleteWindowRequest = cdef::XCBGlxDeleteWindowRequest;
+pub type XCBGlxSetClientInfoArbRequest = cdef::XCBGlxSetClientInfoArbRequest;
+pub type XCBGlxCreateContextAttribsArbRequest = cdef::XCBGlxCreateContextAttribsArbRequest;
+pub type XCBGlxSetClientInfo2arbRequest = cdef::XCBGlxSetClientInfo2arbRequest;
+pub type XCBGlxNewListRequest = cdef::XCBGlxNewListRequest;
+pub type XCBGlxEndListRequest = cdef::XCBGlxEndListRequest;
+pub type XCBGlxDeleteListsRequest = cdef::XCBGlxDeleteListsRequest;
+pub type XCBGlxGenListsRequest = cdef::XCBGlxGenListsRequest;
+pub type XCBGlxGenListsCookie = cdef::XCBGlxGenListsCookie;
+pub type XCBGlxGenListsReply = cdef::XCBGlxGenListsReply;
+pub type XCBGlxFeedbackBufferRequest = cdef::XCBGlxFeedbackBufferRequest;
+pub type XCBGlxSelectBufferRequest = cdef::XCBGlxSelectBufferRequest;
+pub type XCBGlxRenderModeRequest = cdef::XCBGlxRenderModeRequest;
+pub type XCBGlxRenderModeCookie = cdef::XCBGlxRenderModeCookie;
+pub type XCBGlxRenderModeReply = cdef::XCBGlxRenderModeReply;
+pub type XCBGlxFinishRequest = cdef::XCBGlxFinishRequest;
+pub type XCBGlxFinishCookie = cdef::XCBGlxFinishCookie;
+pub type XCBGlxFinishReply = cdef::XCBGlxFinishReply;
+pub type XCBGlxPixelStorefRequest = cdef::XCBGlxPixelStorefRequest;
+pub type XCBGlxPixelStoreiRequest = cdef::XCBGlxPixelStoreiRequest;
+pub type XCBGlxReadPixelsRequest = cdef::XCBGlxReadPixelsRequest;
+pub type XCBGlxReadPixelsCookie = cdef::XCBGlxReadPixelsCookie;
+pub type XCBGlxReadPixelsReply = cdef::XCBGlxReadPixelsReply;
+pub type XCBGlxGetBooleanvRequest = cdef::XCBGlxGetBooleanvRequest;
+pub type XCBGlxGetBooleanvCookie = cdef::XCBGlxGetBooleanvCookie;
+pub type XCBGlxGetBooleanvReply = cdef::XCBGlxGetBooleanvReply;
+pub type XCBGlxGetClipPlaneRequest = cdef::XCBGlxGetClipPlaneRequest;
+pub type XCBGlxGetClipPlaneCookie = cdef::XCBGlxGetClipPlaneCookie;
+pub type XCBGlxGetClipPlaneReply = cdef::XCBGlxGetClipPlaneReply;
+pub type XCBGlxGetDoublevRequest = cdef::XCBGlxGetDoublevRequest;
+pub type XCBGlxGetDoublevCookie = cdef::XCBGlxGetDoublevCookie;
+pub type XCBGlxGetDoublevReply = cdef::XCBGlxGetDoublevReply;
+pub type XCBGlxGetErrorRequest = cdef::XCBGlxGetErrorRequest;
+pub type XCBGlxGetErrorCookie = cdef::XCBGlxGetErrorCookie;
+pub type XCBGlxGetErrorReply = cdef::XCBGlxGetErrorReply;
+pub type XCBGlxGetFloatvRequest = cdef::XCBGlxGetFloatvRequest;
+pub type XCBGlxGetFloatvCookie = cdef::XCBGlxGetFloatvCookie;
+pub type XCBGlxGetFloatvReply = cdef::XCBGlxGetFloatvReply;
+pub type XCBGlxGetIntegervRequest = cdef::XCBGlxGetIntegervRequest;
+pub type XCBGlxGetIntegervCookie = cdef::XCBGlxGetIntegervCookie;
+pub type XCBGlxGetIntegervReply = cdef::XCBGlxGetIntegervReply;
+pub type XCBGlxGetLightfvRequest = cdef::XCBGlxGetLightfvRequest;
+pub type XCBGlxGetLightfvCookie = cdef::XCBGlxGetLightfvCookie;
+pub type XCBGlxGetLightfvReply = cdef::XCBGlxGetLightfvReply;
+pub type XCBGlxGetLightivRequest = cdef::XCBGlxGetLightivRequest;
+pub type XCBGlxGetLightivCookie = cdef::XCBGlxGetLightivCookie;
+pub type XCBGlxGetLightivReply = cdef::XCBGlxGetLightivReply;
+pub type XCBGlxGetMapdvRequest = cdef::XCBGlxGetMapdvRequest;
+pub type XCBGlxGetMapdvCookie = cdef::XCBGlxGetMapdvCookie;
+pub type XCBGlxGetMapdvReply = cdef::XCBGlxGetMapdvReply;
+pub type XCBGlxGetMapfvRequest = cdef::XCBGlxGetMapfvRequest;
+pub type XCBGlxGetMapfvCookie = cdef::XCBGlxGetMapfvCookie;
+pub type XCBGlxGetMapfvReply = cdef::XCBGlxGetMapfvReply;
+pub type XCBGlxGetMapivRequest = cdef::XCBGlxGetMapivRequest;
+pub type XCBGlxGetMapivCookie = cdef::XCBGlxGetMapivCookie;
+pub type XCBGlxGetMapivReply = cdef::XCBGlxGetMapivReply;
+pub type XCBGlxGetMaterialfvRequest = cdef::XCBGlxGetMaterialfvRequest;
+pub type XCBGlxGetMaterialfvCookie = cdef::XCBGlxGetMaterialfvCookie;
+pub type XCBGlxGetMaterialfvReply = cdef::XCBGlxGetMaterialfvReply;
+pub type XCBGlxGetMaterialivRequest = cdef::XCBGlxGetMaterialivRequest;
+pub type XCBGlxGetMaterialivCookie = cdef::XCBGlxGetMaterialivCookie;
+pub type XCBGlxGetMaterialivReply = cdef::XCBGlxGetMaterialivReply;
+pub type XCBGlxGetPixelMapfvRequest = cdef::XCBGlxGetPixelMapfvRequest;
+pub type XCBGlxGetPixelMapfvCookie = cdef::XCBGlxGetPixelMapfvCookie;
+pub type XCBGlxGetPixelMapfvReply = cdef::XCBGlxGetPixelMapfvReply;
+pub type XCBGlxGetPixelMapuivRequest = cdef::XCBGlxGetPixelMapuivRequest;
+pub type XCBGlxGetPixelMapuivCookie = cdef::XCBGlxGetPixelMapuivCookie;
+pub type XCBGlxGetPixelMapuivReply = cdef::XCBGlxGetPixelMapuivReply;
+pub type XCBGlxGetPixelMapusvRequest = cdef::XCBGlxGetPixelMapusvRequest;
+pub type XCBGlxGetPixelMapusvCookie = cdef::XCBGlxGetPixelMapusvCookie;
+pub type XCBGlxGetPixelMapusvReply = cdef::XCBGlxGetPixelMapusvReply;
+pub type XCBGlxGetPolygonStippleRequest = cdef::XCBGlxGetPolygonStippleRequest;
+pub type XCBGlxGetPolygonStippleCookie = cdef::XCBGlxGetPolygonStippleCookie;
+pub type XCBGlxGetPolygonStippleReply = cdef::XCBGlxGetPolygonStippleReply;
+pub type XCBGlxGetStringRequest = cdef::XCBGlxGetStringRequest;
+pub type XCBGlxGetStringCookie = cdef::XCBGlxGetStringCookie;
+pub type XCBGlxGetStringReply = cdef::XCBGlxGetStringReply;
+pub type XCBGlxGetTexEnvfvRequest = cdef::XCBGlxGetTexEnvfvRequest;
+pub type XCBGlxGetTexEnvfvCookie = cdef::XCBGlxGetTexEnvfvCookie;
+pub type XCBGlxGetTexEnvfvReply = cdef::XCBGlxGetTexEnvfvReply;
+pub type XCBGlxGetTexEnvivRequest = cdef::XCBGlxGetTexEnvivRequest;
+pub type XCBGlxGetTexEnvivCookie = cdef::XCBGlxGetTexEnvivCookie;
+pub type XCBGlxGetTexEnvivReply = cdef::XCBGlxGetTexEnvivReply;
+pub type XCBGlxGetTexGendvRequest = cdef::XCBGlxGetTexGendvRequest;
+pub type XCBGlxGetTexGendvCookie = cdef::XCBGlxGetTexGendvCookie;
+pub type XCBGlxGetTexGendvReply = cdef::XCBGlxGetTexGendvReply;
+pub type XCBGlxGetTexGenfvRequest = cdef::XCBGlxGetTexGenfvRequest;
+pub type XCBGlxGetTexGenfvCookie = cdef::XCBGlxGetTexGenfvCookie;
+pub type XCBGlxGetTexGenfvReply = cdef::XCBGlxGetTexGenfvReply;
+pub type XCBGlxGetTexGenivRequest = cdef::XCBGlxGetTexGenivRequest;
+pub type XCBGlxGetTexGenivCookie = cdef::XCBGlxGetTexGenivCookie;
+pub type XCBGlxGetTexGenivReply = cdef::XCBGlxGetTexGenivReply;
+pub type XCBGlxGetTexImageRequest = cdef::XCBGlxGetTexImageRequest;
+pub type XCBGlxGetTexImageCookie = cdef::XCBGlxGetTexImageCookie;
+pub type XCBGlxGetTexImageReply = cdef::XCBGlxGetTexImageReply;
+pub type XCBGlxGetTexParameterfvRequest = cdef::XCBGlxGetTexParameterfvRequest;
+pub type XCBGlxGetTexParameterfvCookie = cdef::XCBGlxGetTexParameterfvCookie;
+pub type XCBGlxGetTexParameterfvReply = cdef::XCBGlxGetTexParameterfvReply;
+pub type XCBGlxGetTexParameterivRequest = cdef::XCBGlxGetTexParameterivRequest;
+pub type XCBGlxGetTexParameterivCookie = cdef::XCBGlxGetTexParameterivCookie;
+pub type XCBGlxGetTexParameterivReply = cdef::XCBGlxGetTexParameterivReply;
+pub type XCBGlxGetTexLevelParameterfvRequest = cdef::XCBGlxGetTexLevelParameterfvRequest;
+pub type XCBGlxGetTexLevelParameterfvCookie = cdef::XCBGlxGetTexLevelParameterfvCookie;
+pub type XCBGlxGetTexLevelParameterfvReply = cdef::XCBGlxGetTexLevelParameterfvReply;
+pub type XCBGlxGetTexLevelParameterivRequest = cdef::XCBGlxGetTexLevelParameterivRequest;
+pub type XCBGlxGetTexLevelParameterivCookie = cdef::XCBGlxGetTexLevelParameterivCookie;
+pub type XCBGlxGetTexLevelParameterivReply = cdef::XCBGlxGetTexLevelParameterivReply;
+pub type XCBGlxIsEnabledRequest = cdef::XCBGlxIsEnabledRequest;
+pub type XCBGlxIsEnabledCookie = cdef::XCBGlxIsEnabledCookie;
+pub type XCBGlxIsEnabledReply = cdef::XCBGlxIsEnabledReply;
+pub type XCBGlxIsListRequest = cdef::XCBGlxIsListRequest;
+pub type XCBGlxIsListCookie = cdef::XCBGlxIsListCookie;
+pub type XCBGlxIsListReply = cdef::XCBGlxIsListReply;
+pub type XCBGlxFlushRequest = cdef::XCBGlxFlushRequest;
+pub type XCBGlxAreTexturesResidentRequest = cdef::XCBGlxAreTexturesResidentRequest;
+pub type XCBGlxAreTexturesResidentCookie = cdef::XCBGlxAreTexturesResidentCookie;
+pub type XCBGlxAreTexturesResidentReply = cdef::XCBGlxAreTexturesResidentReply;
+pub type XCBGlxDeleteTexturesRequest = cdef::XCBGlxDeleteTexturesRequest;
+pub type XCBGlxGenTexturesRequest = cdef::XCBGlxGenTexturesRequest;
+pub type XCBGlxGenTexturesCookie = cdef::XCBGlxGenTexturesCookie;
+pub type XCBGlxGenTexturesReply = cdef::XCBGlxGenTexturesReply;
+pub type XCBGlxIsTextureRequest = cdef::XCBGlxIsTextureRequest;
+pub type XCBGlxIsTextureCookie = cdef::XCBGlxIsTextureCookie;
+pub type XCBGlxIsTextureReply = cdef::XCBGlxIsTextureReply;
+pub type XCBGlxGetColorTableRequest = cdef::XCBGlxGetColorTableRequest;
+pub type XCBGlxGetColorTableCookie = cdef::XCBGlxGetColorTableCookie;
+pub type XCBGlxGetColorTableReply = cdef::XCBGlxGetColorTableReply;
+pub type XCBGlxGetColorTableParameterfvRequest = cdef::XCBGlxGetColorTableParameterfvRequest;
+pub type XCBGlxGetColorTableParameterfvCookie = cdef::XCBGlxGetColorTableParameterfvCookie;
+pub type XCBGlxGetColorTableParameterfvReply = cdef::XCBGlxGetColorTableParameterfvReply;
+pub type XCBGlxGetColorTableParameterivRequest = cdef::XCBGlxGetColorTableParameterivRequest;
+pub type XCBGlxGetColorTableParameterivCookie = cdef::XCBGlxGetColorTableParameterivCookie;
+pub type XCBGlxGetColorTableParameterivReply = cdef::XCBGlxGetColorTableParameterivReply;
+pub type XCBGlxGetConvolutionFilterRequest = cdef::XCBGlxGetConvolutionFilterRequest;
+pub type XCBGlxGetConvolutionFilterCookie = cdef::XCBGlxGetConvolutionFilterCookie;
+pub type XCBGlxGetConvolutionFilterReply = cdef::XCBGlxGetConvolutionFilterReply;
+pub type XCBGlxGetConvolutionParameterfvRequest = cdef::XCBGlxGetConvolutionParameterfvRequest;
+pub type XCBGlxGetConvolutionParameterfvCookie = cdef::XCBGlxGetConvolutionParameterfvCookie;
+pub type XCBGlxGetConvolutionParameterfvReply = cdef::XCBGlxGetConvolutionParameterfvReply;
+pub type XCBGlxGetConvolutionParameterivRequest = cdef::XCBGlxGetConvolutionParameterivRequest;
+pub type XCBGlxGetConvolutionParameterivCookie = cdef::XCBGlxGetConvolutionParameterivCookie;
+pub type XCBGlxGetConvolutionParameterivReply = cdef::XCBGlxGetConvolutionParameterivReply;
+pub type XCBGlxGetSeparableFilterRequest = cdef::XCBGlxGetSeparableFilterRequest;
+pub type XCBGlxGetSeparableFilterCookie = cdef::XCBGlxGetSeparableFilterCookie;
+pub type XCBGlxGetSeparableFilterReply = cdef::XCBGlxGetSeparableFilterReply;
+pub type XCBGlxGetHistogramRequest = cdef::XCBGlxGetHistogramRequest;
+pub type XCBGlxGetHistogramCookie = cdef::XCBGlxGetHistogramCookie;
+pub type XCBGlxGetHistogramReply = cdef::XCBGlxGetHistogramReply;
+pub type XCBGlxGetHistogramParameterfvRequest = cdef::XCBGlxGetHistogramParameterfvRequest;
+pub type XCBGlxGetHistogramParameterfvCookie = cdef::XCBGlxGetHistogramParameterfvCookie;
+pub type XCBGlxGetHistogramParameterfvReply = cdef::XCBGlxGetHistogramParameterfvReply;
+pub type XCBGlxGetHistogramParameterivRequest = cdef::XCBGlxGetHistogramParameterivRequest;
+pub type XCBGlxGetHistogramParameterivCookie = cdef::XCBGlxGetHistogramParameterivCookie;
+pub type XCBGlxGetHistogramParameterivReply = cdef::XCBGlxGetHistogramParameterivReply;
+pub type XCBGlxGetMinmaxRequest = cdef::XCBGlxGetMinmaxRequest;
+pub type XCBGlxGetMinmaxCookie = cdef::XCBGlxGetMinmaxCookie;
+pub type XCBGlxGetMinmaxReply = cdef::XCBGlxGetMinmaxReply;
+pub type XCBGlxGetMinmaxParameterfvRequest = cdef::XCBGlxGetMinmaxParameterfvRequest;
+pub type XCBGlxGetMinmaxParameterfvCookie = cdef::XCBGlxGetMinmaxParameterfvCookie;
+pub type XCBGlxGetMinmaxParameterfvReply = cdef::XCBGlxGetMinmaxParameterfvReply;
+pub type XCBGlxGetMinmaxParameterivRequest = cdef::XCBGlxGetMinmaxParameterivRequest;
+pub type XCBGlxGetMinmaxParameterivCookie = cdef::XCBGlxGetMinmaxParameterivCookie;
+pub type XCBGlxGetMinmaxParameterivReply = cdef::XCBGlxGetMinmaxParameterivReply;
+pub type XCBGlxGetCompressedTexImageArbRequest = cdef::XCBGlxGetCompressedTexImageArbRequest;
+pub type XCBGlxGetCompressedTexImageArbCookie = cdef::XCBGlxGetCompressedTexImageArbCookie;
+pub type XCBGlxGetCompressedTexImageArbReply = cdef::XCBGlxGetCompressedTexImageArbReply;
+pub type XCBGlxDeleteQueriesArbRequest = cdef::XCBGlxDeleteQueriesArbRequest;
+pub type XCBGlxGenQueriesArbRequest = cdef::XCBGlxGenQueriesArbRequest;
+pub type XCBGlxGenQueriesArbCookie = cdef::XCBGlxGenQueriesArbCookie;
+pub type XCBGlxGenQueriesArbReply = cdef::XCBGlxGenQueriesArbReply;
+pub type XCBGlxIsQueryArbRequest = cdef::XCBGlxIsQueryArbRequest;
+pub type XCBGlxIsQueryArbCookie = cdef::XCBGlxIsQueryArbCookie;
+pub type XCBGlxIsQueryArbReply = cdef::XCBGlxIsQueryArbReply;
+pub type XCBGlxGetQueryivArbRequest = cdef::XCBGlxGetQueryivArbRequest;
+pub type XCBGlxGetQueryivArbCookie = cdef::XCBGlxGetQueryivArbCookie;
+pub type XCBGlxGetQueryivArbReply = cdef::XCBGlxGetQueryivArbReply;
+pub type XCBGlxGetQueryObjectivArbRequest = cdef::XCBGlxGetQueryObjectivArbRequest;
+pub type XCBGlxGetQueryObjectivArbCookie = cdef::XCBGlxGetQueryObjectivArbCookie;
+pub type XCBGlxGetQueryObjectivArbReply = cdef::XCBGlxGetQueryObjectivArbReply;
+pub type XCBGlxGetQueryObjectuivArbRequest = cdef::XCBGlxGetQueryObjectuivArbRequest;
+pub type XCBGlxGetQueryObjectuivArbCookie = cdef::XCBGlxGetQueryObjectuivArbCookie;
+pub type XCBGlxGetQueryObjectuivArbReply = cdef::XCBGlxGetQueryObjectuivArbReply;
+
+/// The Glx extension, known to the server as `GLX`.
+pub struct Glx;
+
+impl Extension for Glx {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_glx_id)
+    }
+}
+
+pub fn xcb_glx_pixmap_next(iter: &mut XCBGlxPixmapIterator) {
+    unsafe { cdef::xcb_glx_pixmap_next(iter) }
+}
+
+pub fn xcb_glx_context_next(iter: &mut XCBGlxContextIterator) {
+    unsafe { cdef::xcb_glx_context_next(iter) }
+}
+
+pub fn xcb_glx_pbuffer_next(iter: &mut XCBGlxPbufferIterator) {
+    unsafe { cdef::xcb_glx_pbuffer_next(iter) }
+}
+
+pub fn xcb_glx_window_next(iter: &mut XCBGlxWindowIterator) {
+    unsafe { cdef::xcb_glx_window_next(iter) }
+}
+
+pub fn xcb_glx_fbconfig_next(iter: &mut XCBGlxFbconfigIterator) {
+    unsafe { cdef::xcb_glx_fbconfig_next(iter) }
+}
+
+pub fn xcb_glx_drawable_next(iter: &mut XCBGlxDrawableIterator) {
+    unsafe { cdef::xcb_glx_drawable_next(iter) }
+}
+
+pub fn xcb_glx_float32_next(iter: &mut XCBGlxFloat32Iterator) {
+    unsafe { cdef::xcb_glx_float32_next(iter) }
+}
+
+pub fn xcb_glx_float64_next(iter: &mut XCBGlxFloat64Iterator) {
+    unsafe { cdef::xcb_glx_float64_next(iter) }
+}
+
+pub fn xcb_glx_bool32_next(iter: &mut XCBGlxBool32Iterator) {
+    unsafe { cdef::xcb_glx_bool32_next(iter) }
+}
+
+pub fn xcb_glx_context_tag_next(iter: &mut XCBGlxContextTagIterator) {
+    unsafe { cdef::xcb_glx_context_tag_next(iter) }
+}
+
+pub fn xcb_glx_render(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    data: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_glx_render(
+            connection.raw,
+            context_tag,
+            data.len() as u32,
+            data.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_glx_render_large(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    request_num: u16,
+    request_total: u16,
+    data: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_glx_render_large(
+            connection.raw,
+            context_tag,
+            request_num,
+            request_total,
+            data.len() as u32,
+            data.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_glx_create_context(
+    connection: &XCBConnection,
+    context: XCBGlxContext,
+    visual: XCBVisualid,
+    screen: u32,
+    share_list: XCBGlxContext,
+    is_direct: bool,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_glx_create_context(
+            connection.raw,
+            context,
+            visual,
+            screen,
+            share_list,
+            is_direct as u8,
+        )
+    }
+}
+
+pub fn xcb_glx_destroy_context(
+    connection: &XCBConnection,
+    context: XCBGlxContext,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_destroy_context(connection.raw, context) }
+}
+
+impl Reply for XCBGlxMakeCurrentReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_make_current_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_make_current(
+    connection: &XCBConnection,
+    drawable: XCBGlxDrawable,
+    context: XCBGlxContext,
+    old_context_tag: XCBGlxContextTag,
+) -> XCBGlxMakeCurrentCookie {
+    unsafe { cdef::xcb_glx_make_current(connection.raw, drawable, context, old_context_tag) }
+}
+
+impl Reply for XCBGlxIsDirectReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_is_direct_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_is_direct(
+    connection: &XCBConnection,
+    context: XCBGlxContext,
+) -> XCBGlxIsDirectCookie {
+    unsafe { cdef::xcb_glx_is_direct(connection.raw, context) }
+}
+
+impl Reply for XCBGlxQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_query_version(
+    connection: &XCBConnection,
+    major_version: u32,
+    minor_version: u32,
+) -> XCBGlxQueryVersionCookie {
+    unsafe { cdef::xcb_glx_query_version(connection.raw, major_version, minor_version) }
+}
+
+pub fn xcb_glx_wait_gl(connection: &XCBConnection, context_tag: XCBGlxContextTag) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_wait_gl(connection.raw, context_tag) }
+}
+
+pub fn xcb_glx_wait_x(connection: &XCBConnection, context_tag: XCBGlxContextTag) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_wait_x(connection.raw, context_tag) }
+}
+
+pub fn xcb_glx_copy_context(
+    connection: &XCBConnection,
+    src: XCBGlxContext,
+    dest: XCBGlxContext,
+    mask: u32,
+    src_context_tag: XCBGlxContextTag,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_copy_context(connection.raw, src, dest, mask, src_context_tag) }
+}
+
+pub fn xcb_glx_swap_buffers(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    drawable: XCBGlxDrawable,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_swap_buffers(connection.raw, context_tag, drawable) }
+}
+
+pub fn xcb_glx_use_x_font(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    font: XCBFont,
+    first: u32,
+    count: u32,
+    list_base: u32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_use_x_font(connection.raw, context_tag, font, first, count, list_base) }
+}
+
+pub fn xcb_glx_create_glx_pixmap(
+    connection: &XCBConnection,
+    screen: u32,
+    visual: XCBVisualid,
+    pixmap: XCBPixmap,
+    glx_pixmap: XCBGlxPixmap,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_create_glx_pixmap(connection.raw, screen, visual, pixmap, glx_pixmap) }
+}
+
+pub fn xcb_glx_get_visual_configs_property_list(reply: &XCBGlxGetVisualConfigsReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_visual_configs_property_list(reply),
+            cdef::xcb_glx_get_visual_configs_property_list_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetVisualConfigsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_visual_configs_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_visual_configs(
+    connection: &XCBConnection,
+    screen: u32,
+) -> XCBGlxGetVisualConfigsCookie {
+    unsafe { cdef::xcb_glx_get_visual_configs(connection.raw, screen) }
+}
+
+pub fn xcb_glx_destroy_glx_pixmap(
+    connection: &XCBConnection,
+    glx_pixmap: XCBGlxPixmap,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_destroy_glx_pixmap(connection.raw, glx_pixmap) }
+}
+
+pub fn xcb_glx_vendor_private(
+    connection: &XCBConnection,
+    vendor_code: u32,
+    context_tag: XCBGlxContextTag,
+    data: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_glx_vendor_private(
+            connection.raw,
+            vendor_code,
+            context_tag,
+            data.len() as u32,
+            data.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_glx_vendor_private_with_reply_data_2(
+    reply: &XCBGlxVendorPrivateWithReplyReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_vendor_private_with_reply_data_2(reply),
+            cdef::xcb_glx_vendor_private_with_reply_data_2_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxVendorPrivateWithReplyReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_vendor_private_with_reply_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_vendor_private_with_reply(
+    connection: &XCBConnection,
+    vendor_code: u32,
+    context_tag: XCBGlxContextTag,
+    data: &[u8],
+) -> XCBGlxVendorPrivateWithReplyCookie {
+    unsafe {
+        cdef::xcb_glx_vendor_private_with_reply(
+            connection.raw,
+            vendor_code,
+            context_tag,
+            data.len() as u32,
+            data.as_ptr(),
+        )
+    }
+}
+
+impl Reply for XCBGlxQueryExtensionsStringReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_query_extensions_string_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_query_extensions_string(
+    connection: &XCBConnection,
+    screen: u32,
+) -> XCBGlxQueryExtensionsStringCookie {
+    unsafe { cdef::xcb_glx_query_extensions_string(connection.raw, screen) }
+}
+
+pub fn xcb_glx_query_server_string_string(reply: &XCBGlxQueryServerStringReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_query_server_string_string(reply) as *const u8,
+            cdef::xcb_glx_query_server_string_string_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxQueryServerStringReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_query_server_string_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_query_server_string(
+    connection: &XCBConnection,
+    screen: u32,
+    name: u32,
+) -> XCBGlxQueryServerStringCookie {
+    unsafe { cdef::xcb_glx_query_server_string(connection.raw, screen, name) }
+}
+
+pub fn xcb_glx_client_info(
+    connection: &XCBConnection,
+    major_version: u32,
+    minor_version: u32,
+    string: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_glx_client_info(
+            connection.raw,
+            major_version,
+            minor_version,
+            string.len() as u32,
+            string.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_glx_get_fb_configs_property_list(reply: &XCBGlxGetFbConfigsReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_fb_configs_property_list(reply),
+            cdef::xcb_glx_get_fb_configs_property_list_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetFbConfigsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_fb_configs_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_fb_configs(connection: &XCBConnection, screen: u32) -> XCBGlxGetFbConfigsCookie {
+    unsafe { cdef::xcb_glx_get_fb_configs(connection.raw, screen) }
+}
+
+pub fn xcb_glx_create_pixmap(
+    connection: &XCBConnection,
+    screen: u32,
+    fbconfig: XCBGlxFbconfig,
+    pixmap: XCBPixmap,
+    glx_pixmap: XCBGlxPixmap,
+    num_attribs: u32,
+    attribs: &[u32],
+) -> XCBVoidCookie {
+    assert!(attribs.len() >= (num_attribs as usize * 2));
+    unsafe {
+        cdef::xcb_glx_create_pixmap(
+            connection.raw,
+            screen,
+            fbconfig,
+            pixmap,
+            glx_pixmap,
+            num_attribs,
+            attribs.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_glx_destroy_pixmap(
+    connection: &XCBConnection,
+    glx_pixmap: XCBGlxPixmap,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_destroy_pixmap(connection.raw, glx_pixmap) }
+}
+
+pub fn xcb_glx_create_new_context(
+    connection: &XCBConnection,
+    context: XCBGlxContext,
+    fbconfig: XCBGlxFbconfig,
+    screen: u32,
+    render_type: u32,
+    share_list: XCBGlxContext,
+    is_direct: bool,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_glx_create_new_context(
+            connection.raw,
+            context,
+            fbconfig,
+            screen,
+            render_type,
+            share_list,
+            is_direct as u8,
+        )
+    }
+}
+
+pub fn xcb_glx_query_context_attribs(reply: &XCBGlxQueryContextReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_query_context_attribs(reply),
+            cdef::xcb_glx_query_context_attribs_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxQueryContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_query_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_query_context(
+    connection: &XCBConnection,
+    context: XCBGlxContext,
+) -> XCBGlxQueryContextCookie {
+    unsafe { cdef::xcb_glx_query_context(connection.raw, context) }
+}
+
+impl Reply for XCBGlxMakeContextCurrentReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_make_context_current_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_make_context_current(
+    connection: &XCBConnection,
+    old_context_tag: XCBGlxContextTag,
+    drawable: XCBGlxDrawable,
+    read_drawable: XCBGlxDrawable,
+    context: XCBGlxContext,
+) -> XCBGlxMakeContextCurrentCookie {
+    unsafe {
+        cdef::xcb_glx_make_context_current(
+            connection.raw,
+            old_context_tag,
+            drawable,
+            read_drawable,
+            context,
+        )
+    }
+}
+
+pub fn xcb_glx_create_pbuffer(
+    connection: &XCBConnection,
+    screen: u32,
+    fbconfig: XCBGlxFbconfig,
+    pbuffer: XCBGlxPbuffer,
+    num_attribs: u32,
+    attribs: &[u32],
+) -> XCBVoidCookie {
+    assert!(attribs.len() >= (num_attribs as usize * 2));
+    unsafe {
+        cdef::xcb_glx_create_pbuffer(
+            connection.raw,
+            screen,
+            fbconfig,
+            pbuffer,
+            num_attribs,
+            attribs.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_glx_destroy_pbuffer(
+    connection: &XCBConnection,
+    pbuffer: XCBGlxPbuffer,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_destroy_pbuffer(connection.raw, pbuffer) }
+}
+
+pub fn xcb_glx_get_drawable_attributes_attribs(reply: &XCBGlxGetDrawableAttributesReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_drawable_attributes_attribs(reply),
+            cdef::xcb_glx_get_drawable_attributes_attribs_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetDrawableAttributesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_drawable_attributes_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_drawable_attributes(
+    connection: &XCBConnection,
+    drawable: XCBGlxDrawable,
+) -> XCBGlxGetDrawableAttributesCookie {
+    unsafe { cdef::xcb_glx_get_drawable_attributes(connection.raw, drawable) }
+}
+
+pub fn xcb_glx_change_drawable_attributes(
+    connection: &XCBConnection,
+    drawable: XCBGlxDrawable,
+    num_attribs: u32,
+    attribs: &[u32],
+) -> XCBVoidCookie {
+    assert!(attribs.len() >= (num_attribs as usize * 2));
+    unsafe {
+        cdef::xcb_glx_change_drawable_attributes(
+            connection.raw,
+            drawable,
+            num_attribs,
+            attribs.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_glx_create_window(
+    connection: &XCBConnection,
+    screen: u32,
+    fbconfig: XCBGlxFbconfig,
+    window: XCBWindow,
+    glx_window: XCBGlxWindow,
+    num_attribs: u32,
+    attribs: &[u32],
+) -> XCBVoidCookie {
+    assert!(attribs.len() >= (num_attribs as usize * 2));
+    unsafe {
+        cdef::xcb_glx_create_window(
+            connection.raw,
+            screen,
+            fbconfig,
+            window,
+            glx_window,
+            num_attribs,
+            attribs.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_glx_delete_window(connection: &XCBConnection, glxwindow: XCBGlxWindow) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_delete_window(connection.raw, glxwindow) }
+}
+
+pub fn xcb_glx_set_client_info_arb(
+    connection: &XCBConnection,
+    major_version: u32,
+    minor_version: u32,
+    num_versions: u32,
+    gl_versions: &[u32],
+    gl_extension_string: &[u8],
+    glx_extension_string: &[u8],
+) -> XCBVoidCookie {
+    assert!(gl_versions.len() >= (num_versions as usize * 2));
+    unsafe {
+        cdef::xcb_glx_set_client_info_arb(
+            connection.raw,
+            major_version,
+            minor_version,
+            num_versions,
+            gl_extension_string.len() as u32,
+            glx_extension_string.len() as u32,
+            gl_versions.as_ptr(),
+            gl_extension_string.as_ptr() as *const c_char,
+            glx_extension_string.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_glx_create_context_attribs_arb(
+    connection: &XCBConnection,
+    context: XCBGlxContext,
+    fbconfig: XCBGlxFbconfig,
+    screen: u32,
+    share_list: XCBGlxContext,
+    is_direct: bool,
+    num_attribs: u32,
+    attribs: &[u32],
+) -> XCBVoidCookie {
+    assert!(attribs.len() >= (num_attribs as usize * 2));
+    unsafe {
+        cdef::xcb_glx_create_context_attribs_arb(
+            connection.raw,
+            context,
+            fbconfig,
+            screen,
+            share_list,
+            is_direct as u8,
+            num_attribs,
+            attribs.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_glx_set_client_info_2arb(
+    connection: &XCBConnection,
+    major_version: u32,
+    minor_version: u32,
+    num_versions: u32,
+    gl_versions: &[u32],
+    gl_extension_string: &[u8],
+    glx_extension_string: &[u8],
+) -> XCBVoidCookie {
+    assert!(gl_versions.len() >= (num_versions as usize * 3));
+    unsafe {
+        cdef::xcb_glx_set_client_info_2arb(
+            connection.raw,
+            major_version,
+            minor_version,
+            num_versions,
+            gl_extension_string.len() as u32,
+            glx_extension_string.len() as u32,
+            gl_versions.as_ptr(),
+            gl_extension_string.as_ptr() as *const c_char,
+            glx_extension_string.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_glx_new_list(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    list: u32,
+    mode: u32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_new_list(connection.raw, context_tag, list, mode) }
+}
+
+pub fn xcb_glx_end_list(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_end_list(connection.raw, context_tag) }
+}
+
+pub fn xcb_glx_delete_lists(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    list: u32,
+    range: i32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_delete_lists(connection.raw, context_tag, list, range) }
+}
+
+impl Reply for XCBGlxGenListsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_gen_lists_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_gen_lists(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    range: i32,
+) -> XCBGlxGenListsCookie {
+    unsafe { cdef::xcb_glx_gen_lists(connection.raw, context_tag, range) }
+}
+
+pub fn xcb_glx_feedback_buffer(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    size: i32,
+    type_: i32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_feedback_buffer(connection.raw, context_tag, size, type_) }
+}
+
+pub fn xcb_glx_select_buffer(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    size: i32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_select_buffer(connection.raw, context_tag, size) }
+}
+
+pub fn xcb_glx_render_mode_data(reply: &XCBGlxRenderModeReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_render_mode_data(reply),
+            cdef::xcb_glx_render_mode_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxRenderModeReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_render_mode_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_render_mode(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    mode: u32,
+) -> XCBGlxRenderModeCookie {
+    unsafe { cdef::xcb_glx_render_mode(connection.raw, context_tag, mode) }
+}
+
+impl Reply for XCBGlxFinishReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_finish_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_finish(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+) -> XCBGlxFinishCookie {
+    unsafe { cdef::xcb_glx_finish(connection.raw, context_tag) }
+}
+
+pub fn xcb_glx_pixel_storef(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    pname: u32,
+    datum: XCBGlxFloat32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_pixel_storef(connection.raw, context_tag, pname, datum) }
+}
+
+pub fn xcb_glx_pixel_storei(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    pname: u32,
+    datum: i32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_pixel_storei(connection.raw, context_tag, pname, datum) }
+}
+
+pub fn xcb_glx_read_pixels_data(reply: &XCBGlxReadPixelsReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_read_pixels_data(reply),
+            cdef::xcb_glx_read_pixels_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxReadPixelsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_read_pixels_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_read_pixels(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    format: u32,
+    type_: u32,
+    swap_bytes: bool,
+    lsb_first: bool,
+) -> XCBGlxReadPixelsCookie {
+    unsafe {
+        cdef::xcb_glx_read_pixels(
+            connection.raw,
+            context_tag,
+            x,
+            y,
+            width,
+            height,
+            format,
+            type_,
+            swap_bytes as u8,
+            lsb_first as u8,
+        )
+    }
+}
+
+pub fn xcb_glx_get_booleanv_data(reply: &XCBGlxGetBooleanvReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_booleanv_data(reply),
+            cdef::xcb_glx_get_booleanv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetBooleanvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_booleanv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_booleanv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    pname: i32,
+) -> XCBGlxGetBooleanvCookie {
+    unsafe { cdef::xcb_glx_get_booleanv(connection.raw, context_tag, pname) }
+}
+
+pub fn xcb_glx_get_clip_plane_data(reply: &XCBGlxGetClipPlaneReply) -> &[XCBGlxFloat64] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_clip_plane_data(reply),
+            cdef::xcb_glx_get_clip_plane_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetClipPlaneReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_clip_plane_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_clip_plane(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    plane: i32,
+) -> XCBGlxGetClipPlaneCookie {
+    unsafe { cdef::xcb_glx_get_clip_plane(connection.raw, context_tag, plane) }
+}
+
+pub fn xcb_glx_get_doublev_data(reply: &XCBGlxGetDoublevReply) -> &[XCBGlxFloat64] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_doublev_data(reply),
+            cdef::xcb_glx_get_doublev_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetDoublevReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_doublev_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_doublev(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    pname: u32,
+) -> XCBGlxGetDoublevCookie {
+    unsafe { cdef::xcb_glx_get_doublev(connection.raw, context_tag, pname) }
+}
+
+impl Reply for XCBGlxGetErrorReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_error_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_error(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+) -> XCBGlxGetErrorCookie {
+    unsafe { cdef::xcb_glx_get_error(connection.raw, context_tag) }
+}
+
+pub fn xcb_glx_get_floatv_data(reply: &XCBGlxGetFloatvReply) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_floatv_data(reply),
+            cdef::xcb_glx_get_floatv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetFloatvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_floatv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_floatv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    pname: u32,
+) -> XCBGlxGetFloatvCookie {
+    unsafe { cdef::xcb_glx_get_floatv(connection.raw, context_tag, pname) }
+}
+
+pub fn xcb_glx_get_integerv_data(reply: &XCBGlxGetIntegervReply) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_integerv_data(reply),
+            cdef::xcb_glx_get_integerv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetIntegervReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_integerv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_integerv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    pname: u32,
+) -> XCBGlxGetIntegervCookie {
+    unsafe { cdef::xcb_glx_get_integerv(connection.raw, context_tag, pname) }
+}
+
+pub fn xcb_glx_get_lightfv_data(reply: &XCBGlxGetLightfvReply) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_lightfv_data(reply),
+            cdef::xcb_glx_get_lightfv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetLightfvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_lightfv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_lightfv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    light: u32,
+    pname: u32,
+) -> XCBGlxGetLightfvCookie {
+    unsafe { cdef::xcb_glx_get_lightfv(connection.raw, context_tag, light, pname) }
+}
+
+pub fn xcb_glx_get_lightiv_data(reply: &XCBGlxGetLightivReply) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_lightiv_data(reply),
+            cdef::xcb_glx_get_lightiv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetLightivReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_lightiv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_lightiv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    light: u32,
+    pname: u32,
+) -> XCBGlxGetLightivCookie {
+    unsafe { cdef::xcb_glx_get_lightiv(connection.raw, context_tag, light, pname) }
+}
+
+pub fn xcb_glx_get_mapdv_data(reply: &XCBGlxGetMapdvReply) -> &[XCBGlxFloat64] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_mapdv_data(reply),
+            cdef::xcb_glx_get_mapdv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetMapdvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_mapdv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_mapdv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    query: u32,
+) -> XCBGlxGetMapdvCookie {
+    unsafe { cdef::xcb_glx_get_mapdv(connection.raw, context_tag, target, query) }
+}
+
+pub fn xcb_glx_get_mapfv_data(reply: &XCBGlxGetMapfvReply) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_mapfv_data(reply),
+            cdef::xcb_glx_get_mapfv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetMapfvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_mapfv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_mapfv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    query: u32,
+) -> XCBGlxGetMapfvCookie {
+    unsafe { cdef::xcb_glx_get_mapfv(connection.raw, context_tag, target, query) }
+}
+
+pub fn xcb_glx_get_mapiv_data(reply: &XCBGlxGetMapivReply) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_mapiv_data(reply),
+            cdef::xcb_glx_get_mapiv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetMapivReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_mapiv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_mapiv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    query: u32,
+) -> XCBGlxGetMapivCookie {
+    unsafe { cdef::xcb_glx_get_mapiv(connection.raw, context_tag, target, query) }
+}
+
+pub fn xcb_glx_get_materialfv_data(reply: &XCBGlxGetMaterialfvReply) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_materialfv_data(reply),
+            cdef::xcb_glx_get_materialfv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetMaterialfvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_materialfv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_materialfv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    face: u32,
+    pname: u32,
+) -> XCBGlxGetMaterialfvCookie {
+    unsafe { cdef::xcb_glx_get_materialfv(connection.raw, context_tag, face, pname) }
+}
+
+pub fn xcb_glx_get_materialiv_data(reply: &XCBGlxGetMaterialivReply) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_materialiv_data(reply),
+            cdef::xcb_glx_get_materialiv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetMaterialivReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_materialiv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_materialiv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    face: u32,
+    pname: u32,
+) -> XCBGlxGetMaterialivCookie {
+    unsafe { cdef::xcb_glx_get_materialiv(connection.raw, context_tag, face, pname) }
+}
+
+pub fn xcb_glx_get_pixel_mapfv_data(reply: &XCBGlxGetPixelMapfvReply) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_pixel_mapfv_data(reply),
+            cdef::xcb_glx_get_pixel_mapfv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetPixelMapfvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_pixel_mapfv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_pixel_mapfv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    map: u32,
+) -> XCBGlxGetPixelMapfvCookie {
+    unsafe { cdef::xcb_glx_get_pixel_mapfv(connection.raw, context_tag, map) }
+}
+
+pub fn xcb_glx_get_pixel_mapuiv_data(reply: &XCBGlxGetPixelMapuivReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_pixel_mapuiv_data(reply),
+            cdef::xcb_glx_get_pixel_mapuiv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetPixelMapuivReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_pixel_mapuiv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_pixel_mapuiv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    map: u32,
+) -> XCBGlxGetPixelMapuivCookie {
+    unsafe { cdef::xcb_glx_get_pixel_mapuiv(connection.raw, context_tag, map) }
+}
+
+pub fn xcb_glx_get_pixel_mapusv_data(reply: &XCBGlxGetPixelMapusvReply) -> &[u16] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_pixel_mapusv_data(reply),
+            cdef::xcb_glx_get_pixel_mapusv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetPixelMapusvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_pixel_mapusv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_pixel_mapusv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    map: u32,
+) -> XCBGlxGetPixelMapusvCookie {
+    unsafe { cdef::xcb_glx_get_pixel_mapusv(connection.raw, context_tag, map) }
+}
+
+pub fn xcb_glx_get_polygon_stipple_data(reply: &XCBGlxGetPolygonStippleReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_polygon_stipple_data(reply),
+            cdef::xcb_glx_get_polygon_stipple_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetPolygonStippleReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_polygon_stipple_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_polygon_stipple(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    lsb_first: bool,
+) -> XCBGlxGetPolygonStippleCookie {
+    unsafe { cdef::xcb_glx_get_polygon_stipple(connection.raw, context_tag, lsb_first as u8) }
+}
+
+pub fn xcb_glx_get_string_string(reply: &XCBGlxGetStringReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_string_string(reply) as *const u8,
+            cdef::xcb_glx_get_string_string_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetStringReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_string_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_string(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    name: u32,
+) -> XCBGlxGetStringCookie {
+    unsafe { cdef::xcb_glx_get_string(connection.raw, context_tag, name) }
+}
+
+pub fn xcb_glx_get_tex_envfv_data(reply: &XCBGlxGetTexEnvfvReply) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_tex_envfv_data(reply),
+            cdef::xcb_glx_get_tex_envfv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetTexEnvfvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_tex_envfv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_tex_envfv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetTexEnvfvCookie {
+    unsafe { cdef::xcb_glx_get_tex_envfv(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_tex_enviv_data(reply: &XCBGlxGetTexEnvivReply) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_tex_enviv_data(reply),
+            cdef::xcb_glx_get_tex_enviv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetTexEnvivReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_tex_enviv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_tex_enviv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetTexEnvivCookie {
+    unsafe { cdef::xcb_glx_get_tex_enviv(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_tex_gendv_data(reply: &XCBGlxGetTexGendvReply) -> &[XCBGlxFloat64] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_tex_gendv_data(reply),
+            cdef::xcb_glx_get_tex_gendv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetTexGendvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_tex_gendv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_tex_gendv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    coord: u32,
+    pname: u32,
+) -> XCBGlxGetTexGendvCookie {
+    unsafe { cdef::xcb_glx_get_tex_gendv(connection.raw, context_tag, coord, pname) }
+}
+
+pub fn xcb_glx_get_tex_genfv_data(reply: &XCBGlxGetTexGenfvReply) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_tex_genfv_data(reply),
+            cdef::xcb_glx_get_tex_genfv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetTexGenfvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_tex_genfv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_tex_genfv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    coord: u32,
+    pname: u32,
+) -> XCBGlxGetTexGenfvCookie {
+    unsafe { cdef::xcb_glx_get_tex_genfv(connection.raw, context_tag, coord, pname) }
+}
+
+pub fn xcb_glx_get_tex_geniv_data(reply: &XCBGlxGetTexGenivReply) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_tex_geniv_data(reply),
+            cdef::xcb_glx_get_tex_geniv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetTexGenivReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_tex_geniv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_tex_geniv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    coord: u32,
+    pname: u32,
+) -> XCBGlxGetTexGenivCookie {
+    unsafe { cdef::xcb_glx_get_tex_geniv(connection.raw, context_tag, coord, pname) }
+}
+
+pub fn xcb_glx_get_tex_image_data(reply: &XCBGlxGetTexImageReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_tex_image_data(reply),
+            cdef::xcb_glx_get_tex_image_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetTexImageReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_tex_image_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_tex_image(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    level: i32,
+    format: u32,
+    type_: u32,
+    swap_bytes: bool,
+) -> XCBGlxGetTexImageCookie {
+    unsafe {
+        cdef::xcb_glx_get_tex_image(
+            connection.raw,
+            context_tag,
+            target,
+            level,
+            format,
+            type_,
+            swap_bytes as u8,
+        )
+    }
+}
+
+pub fn xcb_glx_get_tex_parameterfv_data(reply: &XCBGlxGetTexParameterfvReply) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_tex_parameterfv_data(reply),
+            cdef::xcb_glx_get_tex_parameterfv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetTexParameterfvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_tex_parameterfv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_tex_parameterfv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetTexParameterfvCookie {
+    unsafe { cdef::xcb_glx_get_tex_parameterfv(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_tex_parameteriv_data(reply: &XCBGlxGetTexParameterivReply) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_tex_parameteriv_data(reply),
+            cdef::xcb_glx_get_tex_parameteriv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetTexParameterivReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_tex_parameteriv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_tex_parameteriv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetTexParameterivCookie {
+    unsafe { cdef::xcb_glx_get_tex_parameteriv(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_tex_level_parameterfv_data(
+    reply: &XCBGlxGetTexLevelParameterfvReply,
+) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_tex_level_parameterfv_data(reply),
+            cdef::xcb_glx_get_tex_level_parameterfv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetTexLevelParameterfvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_tex_level_parameterfv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_tex_level_parameterfv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    level: i32,
+    pname: u32,
+) -> XCBGlxGetTexLevelParameterfvCookie {
+    unsafe {
+        cdef::xcb_glx_get_tex_level_parameterfv(connection.raw, context_tag, target, level, pname)
+    }
+}
+
+pub fn xcb_glx_get_tex_level_parameteriv_data(reply: &XCBGlxGetTexLevelParameterivReply) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_tex_level_parameteriv_data(reply),
+            cdef::xcb_glx_get_tex_level_parameteriv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetTexLevelParameterivReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_tex_level_parameteriv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_tex_level_parameteriv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    level: i32,
+    pname: u32,
+) -> XCBGlxGetTexLevelParameterivCookie {
+    unsafe {
+        cdef::xcb_glx_get_tex_level_parameteriv(connection.raw, context_tag, target, level, pname)
+    }
+}
+
+impl Reply for XCBGlxIsEnabledReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_is_enabled_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_is_enabled(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    capability: u32,
+) -> XCBGlxIsEnabledCookie {
+    unsafe { cdef::xcb_glx_is_enabled(connection.raw, context_tag, capability) }
+}
+
+impl Reply for XCBGlxIsListReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_is_list_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_is_list(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    list: u32,
+) -> XCBGlxIsListCookie {
+    unsafe { cdef::xcb_glx_is_list(connection.raw, context_tag, list) }
+}
+
+pub fn xcb_glx_flush(connection: &XCBConnection, context_tag: XCBGlxContextTag) -> XCBVoidCookie {
+    unsafe { cdef::xcb_glx_flush(connection.raw, context_tag) }
+}
+
+pub fn xcb_glx_are_textures_resident_data(reply: &XCBGlxAreTexturesResidentReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_are_textures_resident_data(reply),
+            cdef::xcb_glx_are_textures_resident_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxAreTexturesResidentReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_are_textures_resident_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_are_textures_resident(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    textures: &[u32],
+) -> XCBGlxAreTexturesResidentCookie {
+    unsafe {
+        cdef::xcb_glx_are_textures_resident(
+            connection.raw,
+            context_tag,
+            textures.len() as i32,
+            textures.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_glx_delete_textures(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    textures: &[u32],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_glx_delete_textures(
+            connection.raw,
+            context_tag,
+            textures.len() as i32,
+            textures.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_glx_gen_textures_data(reply: &XCBGlxGenTexturesReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_gen_textures_data(reply),
+            cdef::xcb_glx_gen_textures_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGenTexturesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_gen_textures_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_gen_textures(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    n: i32,
+) -> XCBGlxGenTexturesCookie {
+    unsafe { cdef::xcb_glx_gen_textures(connection.raw, context_tag, n) }
+}
+
+impl Reply for XCBGlxIsTextureReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_is_texture_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_is_texture(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    texture: u32,
+) -> XCBGlxIsTextureCookie {
+    unsafe { cdef::xcb_glx_is_texture(connection.raw, context_tag, texture) }
+}
+
+pub fn xcb_glx_get_color_table_data(reply: &XCBGlxGetColorTableReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_color_table_data(reply),
+            cdef::xcb_glx_get_color_table_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetColorTableReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_color_table_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_color_table(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    format: u32,
+    type_: u32,
+    swap_bytes: bool,
+) -> XCBGlxGetColorTableCookie {
+    unsafe {
+        cdef::xcb_glx_get_color_table(
+            connection.raw,
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes as u8,
+        )
+    }
+}
+
+pub fn xcb_glx_get_color_table_parameterfv_data(
+    reply: &XCBGlxGetColorTableParameterfvReply,
+) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_color_table_parameterfv_data(reply),
+            cdef::xcb_glx_get_color_table_parameterfv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetColorTableParameterfvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_color_table_parameterfv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_color_table_parameterfv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetColorTableParameterfvCookie {
+    unsafe { cdef::xcb_glx_get_color_table_parameterfv(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_color_table_parameteriv_data(
+    reply: &XCBGlxGetColorTableParameterivReply,
+) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_color_table_parameteriv_data(reply),
+            cdef::xcb_glx_get_color_table_parameteriv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetColorTableParameterivReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_color_table_parameteriv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_color_table_parameteriv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetColorTableParameterivCookie {
+    unsafe { cdef::xcb_glx_get_color_table_parameteriv(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_convolution_filter_data(reply: &XCBGlxGetConvolutionFilterReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_convolution_filter_data(reply),
+            cdef::xcb_glx_get_convolution_filter_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetConvolutionFilterReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_convolution_filter_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_convolution_filter(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    format: u32,
+    type_: u32,
+    swap_bytes: bool,
+) -> XCBGlxGetConvolutionFilterCookie {
+    unsafe {
+        cdef::xcb_glx_get_convolution_filter(
+            connection.raw,
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes as u8,
+        )
+    }
+}
+
+pub fn xcb_glx_get_convolution_parameterfv_data(
+    reply: &XCBGlxGetConvolutionParameterfvReply,
+) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_convolution_parameterfv_data(reply),
+            cdef::xcb_glx_get_convolution_parameterfv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetConvolutionParameterfvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_convolution_parameterfv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_convolution_parameterfv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetConvolutionParameterfvCookie {
+    unsafe { cdef::xcb_glx_get_convolution_parameterfv(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_convolution_parameteriv_data(
+    reply: &XCBGlxGetConvolutionParameterivReply,
+) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_convolution_parameteriv_data(reply),
+            cdef::xcb_glx_get_convolution_parameteriv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetConvolutionParameterivReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_convolution_parameteriv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_convolution_parameteriv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetConvolutionParameterivCookie {
+    unsafe { cdef::xcb_glx_get_convolution_parameteriv(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_separable_filter_rows_and_cols(reply: &XCBGlxGetSeparableFilterReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_separable_filter_rows_and_cols(reply),
+            cdef::xcb_glx_get_separable_filter_rows_and_cols_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetSeparableFilterReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_separable_filter_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_separable_filter(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    format: u32,
+    type_: u32,
+    swap_bytes: bool,
+) -> XCBGlxGetSeparableFilterCookie {
+    unsafe {
+        cdef::xcb_glx_get_separable_filter(
+            connection.raw,
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes as u8,
+        )
+    }
+}
+
+pub fn xcb_glx_get_histogram_data(reply: &XCBGlxGetHistogramReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_histogram_data(reply),
+            cdef::xcb_glx_get_histogram_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetHistogramReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_histogram_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_histogram(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    format: u32,
+    type_: u32,
+    swap_bytes: bool,
+    reset: bool,
+) -> XCBGlxGetHistogramCookie {
+    unsafe {
+        cdef::xcb_glx_get_histogram(
+            connection.raw,
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes as u8,
+            reset as u8,
+        )
+    }
+}
+
+pub fn xcb_glx_get_histogram_parameterfv_data(
+    reply: &XCBGlxGetHistogramParameterfvReply,
+) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_histogram_parameterfv_data(reply),
+            cdef::xcb_glx_get_histogram_parameterfv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetHistogramParameterfvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_histogram_parameterfv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_histogram_parameterfv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetHistogramParameterfvCookie {
+    unsafe { cdef::xcb_glx_get_histogram_parameterfv(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_histogram_parameteriv_data(
+    reply: &XCBGlxGetHistogramParameterivReply,
+) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_histogram_parameteriv_data(reply),
+            cdef::xcb_glx_get_histogram_parameteriv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetHistogramParameterivReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_histogram_parameteriv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_histogram_parameteriv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetHistogramParameterivCookie {
+    unsafe { cdef::xcb_glx_get_histogram_parameteriv(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_minmax_data(reply: &XCBGlxGetMinmaxReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_minmax_data(reply),
+            cdef::xcb_glx_get_minmax_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetMinmaxReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_minmax_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_minmax(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    format: u32,
+    type_: u32,
+    swap_bytes: bool,
+    reset: bool,
+) -> XCBGlxGetMinmaxCookie {
+    unsafe {
+        cdef::xcb_glx_get_minmax(
+            connection.raw,
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes as u8,
+            reset as u8,
+        )
+    }
+}
+
+pub fn xcb_glx_get_minmax_parameterfv_data(
+    reply: &XCBGlxGetMinmaxParameterfvReply,
+) -> &[XCBGlxFloat32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_minmax_parameterfv_data(reply),
+            cdef::xcb_glx_get_minmax_parameterfv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetMinmaxParameterfvReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_minmax_parameterfv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_minmax_parameterfv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetMinmaxParameterfvCookie {
+    unsafe { cdef::xcb_glx_get_minmax_parameterfv(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_minmax_parameteriv_data(reply: &XCBGlxGetMinmaxParameterivReply) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_minmax_parameteriv_data(reply),
+            cdef::xcb_glx_get_minmax_parameteriv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetMinmaxParameterivReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_minmax_parameteriv_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_minmax_parameteriv(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetMinmaxParameterivCookie {
+    unsafe { cdef::xcb_glx_get_minmax_parameteriv(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_compressed_tex_image_arb_data(
+    reply: &XCBGlxGetCompressedTexImageArbReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_compressed_tex_image_arb_data(reply),
+            cdef::xcb_glx_get_compressed_tex_image_arb_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetCompressedTexImageArbReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_compressed_tex_image_arb_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_compressed_tex_image_arb(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    level: i32,
+) -> XCBGlxGetCompressedTexImageArbCookie {
+    unsafe {
+        cdef::xcb_glx_get_compressed_tex_image_arb(connection.raw, context_tag, target, level)
+    }
+}
+
+pub fn xcb_glx_delete_queries_arb(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    ids: &[u32],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_glx_delete_queries_arb(
+            connection.raw,
+            context_tag,
+            ids.len() as i32,
+            ids.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_glx_gen_queries_arb_data(reply: &XCBGlxGenQueriesArbReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_gen_queries_arb_data(reply),
+            cdef::xcb_glx_gen_queries_arb_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGenQueriesArbReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_gen_queries_arb_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_gen_queries_arb(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    n: i32,
+) -> XCBGlxGenQueriesArbCookie {
+    unsafe { cdef::xcb_glx_gen_queries_arb(connection.raw, context_tag, n) }
+}
+
+impl Reply for XCBGlxIsQueryArbReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_is_query_arb_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_is_query_arb(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    id: u32,
+) -> XCBGlxIsQueryArbCookie {
+    unsafe { cdef::xcb_glx_is_query_arb(connection.raw, context_tag, id) }
+}
+
+pub fn xcb_glx_get_queryiv_arb_data(reply: &XCBGlxGetQueryivArbReply) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_queryiv_arb_data(reply),
+            cdef::xcb_glx_get_queryiv_arb_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetQueryivArbReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_queryiv_arb_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_queryiv_arb(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    target: u32,
+    pname: u32,
+) -> XCBGlxGetQueryivArbCookie {
+    unsafe { cdef::xcb_glx_get_queryiv_arb(connection.raw, context_tag, target, pname) }
+}
+
+pub fn xcb_glx_get_query_objectiv_arb_data(reply: &XCBGlxGetQueryObjectivArbReply) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_query_objectiv_arb_data(reply),
+            cdef::xcb_glx_get_query_objectiv_arb_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetQueryObjectivArbReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_query_objectiv_arb_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_query_objectiv_arb(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    id: u32,
+    pname: u32,
+) -> XCBGlxGetQueryObjectivArbCookie {
+    unsafe { cdef::xcb_glx_get_query_objectiv_arb(connection.raw, context_tag, id, pname) }
+}
+
+pub fn xcb_glx_get_query_objectuiv_arb_data(reply: &XCBGlxGetQueryObjectuivArbReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_glx_get_query_objectuiv_arb_data(reply),
+            cdef::xcb_glx_get_query_objectuiv_arb_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGlxGetQueryObjectuivArbReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_glx_get_query_objectuiv_arb_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_glx_get_query_objectuiv_arb(
+    connection: &XCBConnection,
+    context_tag: XCBGlxContextTag,
+    id: u32,
+    pname: u32,
+) -> XCBGlxGetQueryObjectuivArbCookie {
+    unsafe { cdef::xcb_glx_get_query_objectuiv_arb(connection.raw, context_tag, id, pname) }
+}
+
+/// An event of the Glx extension.
+pub enum Event {
+    PbufferClobber(Box<XCBGlxPbufferClobberEvent>),
+    BufferSwapComplete(Box<XCBGlxBufferSwapCompleteEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::PbufferClobber(Box::from_raw(
+                    raw as *mut XCBGlxPbufferClobberEvent,
+                ))),
+                1 => Ok(Event::BufferSwapComplete(Box::from_raw(
+                    raw as *mut XCBGlxBufferSwapCompleteEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
+
+/// Returns the name of the error whose code, relative to the first error of the extension, is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        0 => Some("BadContext"),
+        1 => Some("BadContextState"),
+        2 => Some("BadDrawable"),
+        3 => Some("BadPixmap"),
+        4 => Some("BadContextTag"),
+        5 => Some("BadCurrentWindow"),
+        6 => Some("BadRenderRequest"),
+        7 => Some("BadLargeRequest"),
+        8 => Some("UnsupportedPrivateRequest"),
+        9 => Some("BadFBConfig"),
+        10 => Some("BadPbuffer"),
+        11 => Some("BadCurrentDrawable"),
+        12 => Some("BadWindow"),
+        13 => Some("GLXBadProfileARB"),
+        _ => None,
+    }
+}
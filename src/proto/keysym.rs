@@ -0,0 +1,5903 @@
+// Generated by xcbgen from xml/keysymdef.h, do not edit.
+
+//! The keysyms of the X protocol, which name the symbols engraved on keys.
+
+#![allow(non_upper_case_globals)]
+
+pub const XK_VoidSymbol: u32 = 0xffffff;
+pub const XK_BackSpace: u32 = 0xff08;
+pub const XK_Tab: u32 = 0xff09;
+pub const XK_Linefeed: u32 = 0xff0a;
+pub const XK_Clear: u32 = 0xff0b;
+pub const XK_Return: u32 = 0xff0d;
+pub const XK_Pause: u32 = 0xff13;
+pub const XK_Scroll_Lock: u32 = 0xff14;
+pub const XK_Sys_Req: u32 = 0xff15;
+pub const XK_Escape: u32 = 0xff1b;
+pub const XK_Delete: u32 = 0xffff;
+pub const XK_Multi_key: u32 = 0xff20;
+pub const XK_Codeinput: u32 = 0xff37;
+pub const XK_SingleCandidate: u32 = 0xff3c;
+pub const XK_MultipleCandidate: u32 = 0xff3d;
+pub const XK_PreviousCandidate: u32 = 0xff3e;
+pub const XK_Kanji: u32 = 0xff21;
+pub const XK_Muhenkan: u32 = 0xff22;
+pub const XK_Henkan_Mode: u32 = 0xff23;
+pub const XK_Henkan: u32 = 0xff23;
+pub const XK_Romaji: u32 = 0xff24;
+pub const XK_Hiragana: u32 = 0xff25;
+pub const XK_Katakana: u32 = 0xff26;
+pub const XK_Hiragana_Katakana: u32 = 0xff27;
+pub const XK_Zenkaku: u32 = 0xff28;
+pub const XK_Hankaku: u32 = 0xff29;
+pub const XK_Zenkaku_Hankaku: u32 = 0xff2a;
+pub const XK_Touroku: u32 = 0xff2b;
+pub const XK_Massyo: u32 = 0xff2c;
+pub const XK_Kana_Lock: u32 = 0xff2d;
+pub const XK_Kana_Shift: u32 = 0xff2e;
+pub const XK_Eisu_Shift: u32 = 0xff2f;
+pub const XK_Eisu_toggle: u32 = 0xff30;
+pub const XK_Kanji_Bangou: u32 = 0xff37;
+pub const XK_Zen_Koho: u32 = 0xff3d;
+pub const XK_Mae_Koho: u32 = 0xff3e;
+pub const XK_Home: u32 = 0xff50;
+pub const XK_Left: u32 = 0xff51;
+pub const XK_Up: u32 = 0xff52;
+pub const XK_Right: u32 = 0xff53;
+pub const XK_Down: u32 = 0xff54;
+pub const XK_Prior: u32 = 0xff55;
+pub const XK_Page_Up: u32 = 0xff55;
+pub const XK_Next: u32 = 0xff56;
+pub const XK_Page_Down: u32 = 0xff56;
+pub const XK_End: u32 = 0xff57;
+pub const XK_Begin: u32 = 0xff58;
+pub const XK_Select: u32 = 0xff60;
+pub const XK_Print: u32 = 0xff61;
+pub const XK_Execute: u32 = 0xff62;
+pub const XK_Insert: u32 = 0xff63;
+pub const XK_Undo: u32 = 0xff65;
+pub const XK_Redo: u32 = 0xff66;
+pub const XK_Menu: u32 = 0xff67;
+pub const XK_Find: u32 = 0xff68;
+pub const XK_Cancel: u32 = 0xff69;
+pub const XK_Help: u32 = 0xff6a;
+pub const XK_Break: u32 = 0xff6b;
+pub const XK_Mode_switch: u32 = 0xff7e;
+pub const XK_script_switch: u32 = 0xff7e;
+pub const XK_Num_Lock: u32 = 0xff7f;
+pub const XK_KP_Space: u32 = 0xff80;
+pub const XK_KP_Tab: u32 = 0xff89;
+pub const XK_KP_Enter: u32 = 0xff8d;
+pub const XK_KP_F1: u32 = 0xff91;
+pub const XK_KP_F2: u32 = 0xff92;
+pub const XK_KP_F3: u32 = 0xff93;
+pub const XK_KP_F4: u32 = 0xff94;
+pub const XK_KP_Home: u32 = 0xff95;
+pub const XK_KP_Left: u32 = 0xff96;
+pub const XK_KP_Up: u32 = 0xff97;
+pub const XK_KP_Right: u32 = 0xff98;
+pub const XK_KP_Down: u32 = 0xff99;
+pub const XK_KP_Prior: u32 = 0xff9a;
+pub const XK_KP_Page_Up: u32 = 0xff9a;
+pub const XK_KP_Next: u32 = 0xff9b;
+pub const XK_KP_Page_Down: u32 = 0xff9b;
+pub const XK_KP_End: u32 = 0xff9c;
+pub const XK_KP_Begin: u32 = 0xff9d;
+pub const XK_KP_Insert: u32 = 0xff9e;
+pub const XK_KP_Delete: u32 = 0xff9f;
+pub const XK_KP_Equal: u32 = 0xffbd;
+pub const XK_KP_Multiply: u32 = 0xffaa;
+pub const XK_KP_Add: u32 = 0xffab;
+pub const XK_KP_Separator: u32 = 0xffac;
+pub const XK_KP_Subtract: u32 = 0xffad;
+pub const XK_KP_Decimal: u32 = 0xffae;
+pub const XK_KP_Divide: u32 = 0xffaf;
+pub const XK_KP_0: u32 = 0xffb0;
+pub const XK_KP_1: u32 = 0xffb1;
+pub const XK_KP_2: u32 = 0xffb2;
+pub const XK_KP_3: u32 = 0xffb3;
+pub const XK_KP_4: u32 = 0xffb4;
+pub const XK_KP_5: u32 = 0xffb5;
+pub const XK_KP_6: u32 = 0xffb6;
+pub const XK_KP_7: u32 = 0xffb7;
+pub const XK_KP_8: u32 = 0xffb8;
+pub const XK_KP_9: u32 = 0xffb9;
+pub const XK_F1: u32 = 0xffbe;
+pub const XK_F2: u32 = 0xffbf;
+pub const XK_F3: u32 = 0xffc0;
+pub const XK_F4: u32 = 0xffc1;
+pub const XK_F5: u32 = 0xffc2;
+pub const XK_F6: u32 = 0xffc3;
+pub const XK_F7: u32 = 0xffc4;
+pub const XK_F8: u32 = 0xffc5;
+pub const XK_F9: u32 = 0xffc6;
+pub const XK_F10: u32 = 0xffc7;
+pub const XK_F11: u32 = 0xffc8;
+pub const XK_L1: u32 = 0xffc8;
+pub const XK_F12: u32 = 0xffc9;
+pub const XK_L2: u32 = 0xffc9;
+pub const XK_F13: u32 = 0xffca;
+pub const XK_L3: u32 = 0xffca;
+pub const XK_F14: u32 = 0xffcb;
+pub const XK_L4: u32 = 0xffcb;
+pub const XK_F15: u32 = 0xffcc;
+pub const XK_L5: u32 = 0xffcc;
+pub const XK_F16: u32 = 0xffcd;
+pub const XK_L6: u32 = 0xffcd;
+pub const XK_F17: u32 = 0xffce;
+pub const XK_L7: u32 = 0xffce;
+pub const XK_F18: u32 = 0xffcf;
+pub const XK_L8: u32 = 0xffcf;
+pub const XK_F19: u32 = 0xffd0;
+pub const XK_L9: u32 = 0xffd0;
+pub const XK_F20: u32 = 0xffd1;
+pub const XK_L10: u32 = 0xffd1;
+pub const XK_F21: u32 = 0xffd2;
+pub const XK_R1: u32 = 0xffd2;
+pub const XK_F22: u32 = 0xffd3;
+pub const XK_R2: u32 = 0xffd3;
+pub const XK_F23: u32 = 0xffd4;
+pub const XK_R3: u32 = 0xffd4;
+pub const XK_F24: u32 = 0xffd5;
+pub const XK_R4: u32 = 0xffd5;
+pub const XK_F25: u32 = 0xffd6;
+pub const XK_R5: u32 = 0xffd6;
+pub const XK_F26: u32 = 0xffd7;
+pub const XK_R6: u32 = 0xffd7;
+pub const XK_F27: u32 = 0xffd8;
+pub const XK_R7: u32 = 0xffd8;
+pub const XK_F28: u32 = 0xffd9;
+pub const XK_R8: u32 = 0xffd9;
+pub const XK_F29: u32 = 0xffda;
+pub const XK_R9: u32 = 0xffda;
+pub const XK_F30: u32 = 0xffdb;
+pub const XK_R10: u32 = 0xffdb;
+pub const XK_F31: u32 = 0xffdc;
+pub const XK_R11: u32 = 0xffdc;
+pub const XK_F32: u32 = 0xffdd;
+pub const XK_R12: u32 = 0xffdd;
+pub const XK_F33: u32 = 0xffde;
+pub const XK_R13: u32 = 0xffde;
+pub const XK_F34: u32 = 0xffdf;
+pub const XK_R14: u32 = 0xffdf;
+pub const XK_F35: u32 = 0xffe0;
+pub const XK_R15: u32 = 0xffe0;
+pub const XK_Shift_L: u32 = 0xffe1;
+pub const XK_Shift_R: u32 = 0xffe2;
+pub const XK_Control_L: u32 = 0xffe3;
+pub const XK_Control_R: u32 = 0xffe4;
+pub const XK_Caps_Lock: u32 = 0xffe5;
+pub const XK_Shift_Lock: u32 = 0xffe6;
+pub const XK_Meta_L: u32 = 0xffe7;
+pub const XK_Meta_R: u32 = 0xffe8;
+pub const XK_Alt_L: u32 = 0xffe9;
+pub const XK_Alt_R: u32 = 0xffea;
+pub const XK_Super_L: u32 = 0xffeb;
+pub const XK_Super_R: u32 = 0xffec;
+pub const XK_Hyper_L: u32 = 0xffed;
+pub const XK_Hyper_R: u32 = 0xffee;
+pub const XK_ISO_Lock: u32 = 0xfe01;
+pub const XK_ISO_Level2_Latch: u32 = 0xfe02;
+pub const XK_ISO_Level3_Shift: u32 = 0xfe03;
+pub const XK_ISO_Level3_Latch: u32 = 0xfe04;
+pub const XK_ISO_Level3_Lock: u32 = 0xfe05;
+pub const XK_ISO_Level5_Shift: u32 = 0xfe11;
+pub const XK_ISO_Level5_Latch: u32 = 0xfe12;
+pub const XK_ISO_Level5_Lock: u32 = 0xfe13;
+pub const XK_ISO_Group_Shift: u32 = 0xff7e;
+pub const XK_ISO_Group_Latch: u32 = 0xfe06;
+pub const XK_ISO_Group_Lock: u32 = 0xfe07;
+pub const XK_ISO_Next_Group: u32 = 0xfe08;
+pub const XK_ISO_Next_Group_Lock: u32 = 0xfe09;
+pub const XK_ISO_Prev_Group: u32 = 0xfe0a;
+pub const XK_ISO_Prev_Group_Lock: u32 = 0xfe0b;
+pub const XK_ISO_First_Group: u32 = 0xfe0c;
+pub const XK_ISO_First_Group_Lock: u32 = 0xfe0d;
+pub const XK_ISO_Last_Group: u32 = 0xfe0e;
+pub const XK_ISO_Last_Group_Lock: u32 = 0xfe0f;
+pub const XK_ISO_Left_Tab: u32 = 0xfe20;
+pub const XK_ISO_Move_Line_Up: u32 = 0xfe21;
+pub const XK_ISO_Move_Line_Down: u32 = 0xfe22;
+pub const XK_ISO_Partial_Line_Up: u32 = 0xfe23;
+pub const XK_ISO_Partial_Line_Down: u32 = 0xfe24;
+pub const XK_ISO_Partial_Space_Left: u32 = 0xfe25;
+pub const XK_ISO_Partial_Space_Right: u32 = 0xfe26;
+pub const XK_ISO_Set_Margin_Left: u32 = 0xfe27;
+pub const XK_ISO_Set_Margin_Right: u32 = 0xfe28;
+pub const XK_ISO_Release_Margin_Left: u32 = 0xfe29;
+pub const XK_ISO_Release_Margin_Right: u32 = 0xfe2a;
+pub const XK_ISO_Release_Both_Margins: u32 = 0xfe2b;
+pub const XK_ISO_Fast_Cursor_Left: u32 = 0xfe2c;
+pub const XK_ISO_Fast_Cursor_Right: u32 = 0xfe2d;
+pub const XK_ISO_Fast_Cursor_Up: u32 = 0xfe2e;
+pub const XK_ISO_Fast_Cursor_Down: u32 = 0xfe2f;
+pub const XK_ISO_Continuous_Underline: u32 = 0xfe30;
+pub const XK_ISO_Discontinuous_Underline: u32 = 0xfe31;
+pub const XK_ISO_Emphasize: u32 = 0xfe32;
+pub const XK_ISO_Center_Object: u32 = 0xfe33;
+pub const XK_ISO_Enter: u32 = 0xfe34;
+pub const XK_dead_grave: u32 = 0xfe50;
+pub const XK_dead_acute: u32 = 0xfe51;
+pub const XK_dead_circumflex: u32 = 0xfe52;
+pub const XK_dead_tilde: u32 = 0xfe53;
+pub const XK_dead_perispomeni: u32 = 0xfe53;
+pub const XK_dead_macron: u32 = 0xfe54;
+pub const XK_dead_breve: u32 = 0xfe55;
+pub const XK_dead_abovedot: u32 = 0xfe56;
+pub const XK_dead_diaeresis: u32 = 0xfe57;
+pub const XK_dead_abovering: u32 = 0xfe58;
+pub const XK_dead_doubleacute: u32 = 0xfe59;
+pub const XK_dead_caron: u32 = 0xfe5a;
+pub const XK_dead_cedilla: u32 = 0xfe5b;
+pub const XK_dead_ogonek: u32 = 0xfe5c;
+pub const XK_dead_iota: u32 = 0xfe5d;
+pub const XK_dead_voiced_sound: u32 = 0xfe5e;
+pub const XK_dead_semivoiced_sound: u32 = 0xfe5f;
+pub const XK_dead_belowdot: u32 = 0xfe60;
+pub const XK_dead_hook: u32 = 0xfe61;
+pub const XK_dead_horn: u32 = 0xfe62;
+pub const XK_dead_stroke: u32 = 0xfe63;
+pub const XK_dead_abovecomma: u32 = 0xfe64;
+pub const XK_dead_psili: u32 = 0xfe64;
+pub const XK_dead_abovereversedcomma: u32 = 0xfe65;
+pub const XK_dead_dasia: u32 = 0xfe65;
+pub const XK_dead_doublegrave: u32 = 0xfe66;
+pub const XK_dead_belowring: u32 = 0xfe67;
+pub const XK_dead_belowmacron: u32 = 0xfe68;
+pub const XK_dead_belowcircumflex: u32 = 0xfe69;
+pub const XK_dead_belowtilde: u32 = 0xfe6a;
+pub const XK_dead_belowbreve: u32 = 0xfe6b;
+pub const XK_dead_belowdiaeresis: u32 = 0xfe6c;
+pub const XK_dead_invertedbreve: u32 = 0xfe6d;
+pub const XK_dead_belowcomma: u32 = 0xfe6e;
+pub const XK_dead_currency: u32 = 0xfe6f;
+pub const XK_dead_lowline: u32 = 0xfe90;
+pub const XK_dead_aboveverticalline: u32 = 0xfe91;
+pub const XK_dead_belowverticalline: u32 = 0xfe92;
+pub const XK_dead_longsolidusoverlay: u32 = 0xfe93;
+pub const XK_dead_a: u32 = 0xfe80;
+pub const XK_dead_A: u32 = 0xfe81;
+pub const XK_dead_e: u32 = 0xfe82;
+pub const XK_dead_E: u32 = 0xfe83;
+pub const XK_dead_i: u32 = 0xfe84;
+pub const XK_dead_I: u32 = 0xfe85;
+pub const XK_dead_o: u32 = 0xfe86;
+pub const XK_dead_O: u32 = 0xfe87;
+pub const XK_dead_u: u32 = 0xfe88;
+pub const XK_dead_U: u32 = 0xfe89;
+pub const XK_dead_small_schwa: u32 = 0xfe8a;
+pub const XK_dead_capital_schwa: u32 = 0xfe8b;
+pub const XK_dead_greek: u32 = 0xfe8c;
+pub const XK_First_Virtual_Screen: u32 = 0xfed0;
+pub const XK_Prev_Virtual_Screen: u32 = 0xfed1;
+pub const XK_Next_Virtual_Screen: u32 = 0xfed2;
+pub const XK_Last_Virtual_Screen: u32 = 0xfed4;
+pub const XK_Terminate_Server: u32 = 0xfed5;
+pub const XK_AccessX_Enable: u32 = 0xfe70;
+pub const XK_AccessX_Feedback_Enable: u32 = 0xfe71;
+pub const XK_RepeatKeys_Enable: u32 = 0xfe72;
+pub const XK_SlowKeys_Enable: u32 = 0xfe73;
+pub const XK_BounceKeys_Enable: u32 = 0xfe74;
+pub const XK_StickyKeys_Enable: u32 = 0xfe75;
+pub const XK_MouseKeys_Enable: u32 = 0xfe76;
+pub const XK_MouseKeys_Accel_Enable: u32 = 0xfe77;
+pub const XK_Overlay1_Enable: u32 = 0xfe78;
+pub const XK_Overlay2_Enable: u32 = 0xfe79;
+pub const XK_AudibleBell_Enable: u32 = 0xfe7a;
+pub const XK_Pointer_Left: u32 = 0xfee0;
+pub const XK_Pointer_Right: u32 = 0xfee1;
+pub const XK_Pointer_Up: u32 = 0xfee2;
+pub const XK_Pointer_Down: u32 = 0xfee3;
+pub const XK_Pointer_UpLeft: u32 = 0xfee4;
+pub const XK_Pointer_UpRight: u32 = 0xfee5;
+pub const XK_Pointer_DownLeft: u32 = 0xfee6;
+pub const XK_Pointer_DownRight: u32 = 0xfee7;
+pub const XK_Pointer_Button_Dflt: u32 = 0xfee8;
+pub const XK_Pointer_Button1: u32 = 0xfee9;
+pub const XK_Pointer_Button2: u32 = 0xfeea;
+pub const XK_Pointer_Button3: u32 = 0xfeeb;
+pub const XK_Pointer_Button4: u32 = 0xfeec;
+pub const XK_Pointer_Button5: u32 = 0xfeed;
+pub const XK_Pointer_DblClick_Dflt: u32 = 0xfeee;
+pub const XK_Pointer_DblClick1: u32 = 0xfeef;
+pub const XK_Pointer_DblClick2: u32 = 0xfef0;
+pub const XK_Pointer_DblClick3: u32 = 0xfef1;
+pub const XK_Pointer_DblClick4: u32 = 0xfef2;
+pub const XK_Pointer_DblClick5: u32 = 0xfef3;
+pub const XK_Pointer_Drag_Dflt: u32 = 0xfef4;
+pub const XK_Pointer_Drag1: u32 = 0xfef5;
+pub const XK_Pointer_Drag2: u32 = 0xfef6;
+pub const XK_Pointer_Drag3: u32 = 0xfef7;
+pub const XK_Pointer_Drag4: u32 = 0xfef8;
+pub const XK_Pointer_Drag5: u32 = 0xfefd;
+pub const XK_Pointer_EnableKeys: u32 = 0xfef9;
+pub const XK_Pointer_Accelerate: u32 = 0xfefa;
+pub const XK_Pointer_DfltBtnNext: u32 = 0xfefb;
+pub const XK_Pointer_DfltBtnPrev: u32 = 0xfefc;
+pub const XK_ch: u32 = 0xfea0;
+pub const XK_Ch: u32 = 0xfea1;
+pub const XK_CH: u32 = 0xfea2;
+pub const XK_c_h: u32 = 0xfea3;
+pub const XK_C_h: u32 = 0xfea4;
+pub const XK_C_H: u32 = 0xfea5;
+pub const XK_3270_Duplicate: u32 = 0xfd01;
+pub const XK_3270_FieldMark: u32 = 0xfd02;
+pub const XK_3270_Right2: u32 = 0xfd03;
+pub const XK_3270_Left2: u32 = 0xfd04;
+pub const XK_3270_BackTab: u32 = 0xfd05;
+pub const XK_3270_EraseEOF: u32 = 0xfd06;
+pub const XK_3270_EraseInput: u32 = 0xfd07;
+pub const XK_3270_Reset: u32 = 0xfd08;
+pub const XK_3270_Quit: u32 = 0xfd09;
+pub const XK_3270_PA1: u32 = 0xfd0a;
+pub const XK_3270_PA2: u32 = 0xfd0b;
+pub const XK_3270_PA3: u32 = 0xfd0c;
+pub const XK_3270_Test: u32 = 0xfd0d;
+pub const XK_3270_Attn: u32 = 0xfd0e;
+pub const XK_3270_CursorBlink: u32 = 0xfd0f;
+pub const XK_3270_AltCursor: u32 = 0xfd10;
+pub const XK_3270_KeyClick: u32 = 0xfd11;
+pub const XK_3270_Jump: u32 = 0xfd12;
+pub const XK_3270_Ident: u32 = 0xfd13;
+pub const XK_3270_Rule: u32 = 0xfd14;
+pub const XK_3270_Copy: u32 = 0xfd15;
+pub const XK_3270_Play: u32 = 0xfd16;
+pub const XK_3270_Setup: u32 = 0xfd17;
+pub const XK_3270_Record: u32 = 0xfd18;
+pub const XK_3270_ChangeScreen: u32 = 0xfd19;
+pub const XK_3270_DeleteWord: u32 = 0xfd1a;
+pub const XK_3270_ExSelect: u32 = 0xfd1b;
+pub const XK_3270_CursorSelect: u32 = 0xfd1c;
+pub const XK_3270_PrintScreen: u32 = 0xfd1d;
+pub const XK_3270_Enter: u32 = 0xfd1e;
+pub const XK_space: u32 = 0x0020;
+pub const XK_exclam: u32 = 0x0021;
+pub const XK_quotedbl: u32 = 0x0022;
+pub const XK_numbersign: u32 = 0x0023;
+pub const XK_dollar: u32 = 0x0024;
+pub const XK_percent: u32 = 0x0025;
+pub const XK_ampersand: u32 = 0x0026;
+pub const XK_apostrophe: u32 = 0x0027;
+pub const XK_quoteright: u32 = 0x0027;
+pub const XK_parenleft: u32 = 0x0028;
+pub const XK_parenright: u32 = 0x0029;
+pub const XK_asterisk: u32 = 0x002a;
+pub const XK_plus: u32 = 0x002b;
+pub const XK_comma: u32 = 0x002c;
+pub const XK_minus: u32 = 0x002d;
+pub const XK_period: u32 = 0x002e;
+pub const XK_slash: u32 = 0x002f;
+pub const XK_0: u32 = 0x0030;
+pub const XK_1: u32 = 0x0031;
+pub const XK_2: u32 = 0x0032;
+pub const XK_3: u32 = 0x0033;
+pub const XK_4: u32 = 0x0034;
+pub const XK_5: u32 = 0x0035;
+pub const XK_6: u32 = 0x0036;
+pub const XK_7: u32 = 0x0037;
+pub const XK_8: u32 = 0x0038;
+pub const XK_9: u32 = 0x0039;
+pub const XK_colon: u32 = 0x003a;
+pub const XK_semicolon: u32 = 0x003b;
+pub const XK_less: u32 = 0x003c;
+pub const XK_equal: u32 = 0x003d;
+pub const XK_greater: u32 = 0x003e;
+pub const XK_question: u32 = 0x003f;
+pub const XK_at: u32 = 0x0040;
+pub const XK_A: u32 = 0x0041;
+pub const XK_B: u32 = 0x0042;
+pub const XK_C: u32 = 0x0043;
+pub const XK_D: u32 = 0x0044;
+pub const XK_E: u32 = 0x0045;
+pub const XK_F: u32 = 0x0046;
+pub const XK_G: u32 = 0x0047;
+pub const XK_H: u32 = 0x0048;
+pub const XK_I: u32 = 0x0049;
+pub const XK_J: u32 = 0x004a;
+pub const XK_K: u32 = 0x004b;
+pub const XK_L: u32 = 0x004c;
+pub const XK_M: u32 = 0x004d;
+pub const XK_N: u32 = 0x004e;
+pub const XK_O: u32 = 0x004f;
+pub const XK_P: u32 = 0x0050;
+pub const XK_Q: u32 = 0x0051;
+pub const XK_R: u32 = 0x0052;
+pub const XK_S: u32 = 0x0053;
+pub const XK_T: u32 = 0x0054;
+pub const XK_U: u32 = 0x0055;
+pub const XK_V: u32 = 0x0056;
+pub const XK_W: u32 = 0x0057;
+pub const XK_X: u32 = 0x0058;
+pub const XK_Y: u32 = 0x0059;
+pub const XK_Z: u32 = 0x005a;
+pub const XK_bracketleft: u32 = 0x005b;
+pub const XK_backslash: u32 = 0x005c;
+pub const XK_bracketright: u32 = 0x005d;
+pub const XK_asciicircum: u32 = 0x005e;
+pub const XK_underscore: u32 = 0x005f;
+pub const XK_grave: u32 = 0x0060;
+pub const XK_quoteleft: u32 = 0x0060;
+pub const XK_a: u32 = 0x0061;
+pub const XK_b: u32 = 0x0062;
+pub const XK_c: u32 = 0x0063;
+pub const XK_d: u32 = 0x0064;
+pub const XK_e: u32 = 0x0065;
+pub const XK_f: u32 = 0x0066;
+pub const XK_g: u32 = 0x0067;
+pub const XK_h: u32 = 0x0068;
+pub const XK_i: u32 = 0x0069;
+pub const XK_j: u32 = 0x006a;
+pub const XK_k: u32 = 0x006b;
+pub const XK_l: u32 = 0x006c;
+pub const XK_m: u32 = 0x006d;
+pub const XK_n: u32 = 0x006e;
+pub const XK_o: u32 = 0x006f;
+pub const XK_p: u32 = 0x0070;
+pub const XK_q: u32 = 0x0071;
+pub const XK_r: u32 = 0x0072;
+pub const XK_s: u32 = 0x0073;
+pub const XK_t: u32 = 0x0074;
+pub const XK_u: u32 = 0x0075;
+pub const XK_v: u32 = 0x0076;
+pub const XK_w: u32 = 0x0077;
+pub const XK_x: u32 = 0x0078;
+pub const XK_y: u32 = 0x0079;
+pub const XK_z: u32 = 0x007a;
+pub const XK_braceleft: u32 = 0x007b;
+pub const XK_bar: u32 = 0x007c;
+pub const XK_braceright: u32 = 0x007d;
+pub const XK_asciitilde: u32 = 0x007e;
+pub const XK_nobreakspace: u32 = 0x00a0;
+pub const XK_exclamdown: u32 = 0x00a1;
+pub const XK_cent: u32 = 0x00a2;
+pub const XK_sterling: u32 = 0x00a3;
+pub const XK_currency: u32 = 0x00a4;
+pub const XK_yen: u32 = 0x00a5;
+pub const XK_brokenbar: u32 = 0x00a6;
+pub const XK_section: u32 = 0x00a7;
+pub const XK_diaeresis: u32 = 0x00a8;
+pub const XK_copyright: u32 = 0x00a9;
+pub const XK_ordfeminine: u32 = 0x00aa;
+pub const XK_guillemotleft: u32 = 0x00ab;
+pub const XK_notsign: u32 = 0x00ac;
+pub const XK_hyphen: u32 = 0x00ad;
+pub const XK_registered: u32 = 0x00ae;
+pub const XK_macron: u32 = 0x00af;
+pub const XK_degree: u32 = 0x00b0;
+pub const XK_plusminus: u32 = 0x00b1;
+pub const XK_twosuperior: u32 = 0x00b2;
+pub const XK_threesuperior: u32 = 0x00b3;
+pub const XK_acute: u32 = 0x00b4;
+pub const XK_mu: u32 = 0x00b5;
+pub const XK_paragraph: u32 = 0x00b6;
+pub const XK_periodcentered: u32 = 0x00b7;
+pub const XK_cedilla: u32 = 0x00b8;
+pub const XK_onesuperior: u32 = 0x00b9;
+pub const XK_masculine: u32 = 0x00ba;
+pub const XK_guillemotright: u32 = 0x00bb;
+pub const XK_onequarter: u32 = 0x00bc;
+pub const XK_onehalf: u32 = 0x00bd;
+pub const XK_threequarters: u32 = 0x00be;
+pub const XK_questiondown: u32 = 0x00bf;
+pub const XK_Agrave: u32 = 0x00c0;
+pub const XK_Aacute: u32 = 0x00c1;
+pub const XK_Acircumflex: u32 = 0x00c2;
+pub const XK_Atilde: u32 = 0x00c3;
+pub const XK_Adiaeresis: u32 = 0x00c4;
+pub const XK_Aring: u32 = 0x00c5;
+pub const XK_AE: u32 = 0x00c6;
+pub const XK_Ccedilla: u32 = 0x00c7;
+pub const XK_Egrave: u32 = 0x00c8;
+pub const XK_Eacute: u32 = 0x00c9;
+pub const XK_Ecircumflex: u32 = 0x00ca;
+pub const XK_Ediaeresis: u32 = 0x00cb;
+pub const XK_Igrave: u32 = 0x00cc;
+pub const XK_Iacute: u32 = 0x00cd;
+pub const XK_Icircumflex: u32 = 0x00ce;
+pub const XK_Idiaeresis: u32 = 0x00cf;
+pub const XK_ETH: u32 = 0x00d0;
+pub const XK_Eth: u32 = 0x00d0;
+pub const XK_Ntilde: u32 = 0x00d1;
+pub const XK_Ograve: u32 = 0x00d2;
+pub const XK_Oacute: u32 = 0x00d3;
+pub const XK_Ocircumflex: u32 = 0x00d4;
+pub const XK_Otilde: u32 = 0x00d5;
+pub const XK_Odiaeresis: u32 = 0x00d6;
+pub const XK_multiply: u32 = 0x00d7;
+pub const XK_Oslash: u32 = 0x00d8;
+pub const XK_Ooblique: u32 = 0x00d8;
+pub const XK_Ugrave: u32 = 0x00d9;
+pub const XK_Uacute: u32 = 0x00da;
+pub const XK_Ucircumflex: u32 = 0x00db;
+pub const XK_Udiaeresis: u32 = 0x00dc;
+pub const XK_Yacute: u32 = 0x00dd;
+pub const XK_THORN: u32 = 0x00de;
+pub const XK_Thorn: u32 = 0x00de;
+pub const XK_ssharp: u32 = 0x00df;
+pub const XK_agrave: u32 = 0x00e0;
+pub const XK_aacute: u32 = 0x00e1;
+pub const XK_acircumflex: u32 = 0x00e2;
+pub const XK_atilde: u32 = 0x00e3;
+pub const XK_adiaeresis: u32 = 0x00e4;
+pub const XK_aring: u32 = 0x00e5;
+pub const XK_ae: u32 = 0x00e6;
+pub const XK_ccedilla: u32 = 0x00e7;
+pub const XK_egrave: u32 = 0x00e8;
+pub const XK_eacute: u32 = 0x00e9;
+pub const XK_ecircumflex: u32 = 0x00ea;
+pub const XK_ediaeresis: u32 = 0x00eb;
+pub const XK_igrave: u32 = 0x00ec;
+pub const XK_iacute: u32 = 0x00ed;
+pub const XK_icircumflex: u32 = 0x00ee;
+pub const XK_idiaeresis: u32 = 0x00ef;
+pub const XK_eth: u32 = 0x00f0;
+pub const XK_ntilde: u32 = 0x00f1;
+pub const XK_ograve: u32 = 0x00f2;
+pub const XK_oacute: u32 = 0x00f3;
+pub const XK_ocircumflex: u32 = 0x00f4;
+pub const XK_otilde: u32 = 0x00f5;
+pub const XK_odiaeresis: u32 = 0x00f6;
+pub const XK_division: u32 = 0x00f7;
+pub const XK_oslash: u32 = 0x00f8;
+pub const XK_ooblique: u32 = 0x00f8;
+pub const XK_ugrave: u32 = 0x00f9;
+pub const XK_uacute: u32 = 0x00fa;
+pub const XK_ucircumflex: u32 = 0x00fb;
+pub const XK_udiaeresis: u32 = 0x00fc;
+pub const XK_yacute: u32 = 0x00fd;
+pub const XK_thorn: u32 = 0x00fe;
+pub const XK_ydiaeresis: u32 = 0x00ff;
+pub const XK_Aogonek: u32 = 0x01a1;
+pub const XK_breve: u32 = 0x01a2;
+pub const XK_Lstroke: u32 = 0x01a3;
+pub const XK_Lcaron: u32 = 0x01a5;
+pub const XK_Sacute: u32 = 0x01a6;
+pub const XK_Scaron: u32 = 0x01a9;
+pub const XK_Scedilla: u32 = 0x01aa;
+pub const XK_Tcaron: u32 = 0x01ab;
+pub const XK_Zacute: u32 = 0x01ac;
+pub const XK_Zcaron: u32 = 0x01ae;
+pub const XK_Zabovedot: u32 = 0x01af;
+pub const XK_aogonek: u32 = 0x01b1;
+pub const XK_ogonek: u32 = 0x01b2;
+pub const XK_lstroke: u32 = 0x01b3;
+pub const XK_lcaron: u32 = 0x01b5;
+pub const XK_sacute: u32 = 0x01b6;
+pub const XK_caron: u32 = 0x01b7;
+pub const XK_scaron: u32 = 0x01b9;
+pub const XK_scedilla: u32 = 0x01ba;
+pub const XK_tcaron: u32 = 0x01bb;
+pub const XK_zacute: u32 = 0x01bc;
+pub const XK_doubleacute: u32 = 0x01bd;
+pub const XK_zcaron: u32 = 0x01be;
+pub const XK_zabovedot: u32 = 0x01bf;
+pub const XK_Racute: u32 = 0x01c0;
+pub const XK_Abreve: u32 = 0x01c3;
+pub const XK_Lacute: u32 = 0x01c5;
+pub const XK_Cacute: u32 = 0x01c6;
+pub const XK_Ccaron: u32 = 0x01c8;
+pub const XK_Eogonek: u32 = 0x01ca;
+pub const XK_Ecaron: u32 = 0x01cc;
+pub const XK_Dcaron: u32 = 0x01cf;
+pub const XK_Dstroke: u32 = 0x01d0;
+pub const XK_Nacute: u32 = 0x01d1;
+pub const XK_Ncaron: u32 = 0x01d2;
+pub const XK_Odoubleacute: u32 = 0x01d5;
+pub const XK_Rcaron: u32 = 0x01d8;
+pub const XK_Uring: u32 = 0x01d9;
+pub const XK_Udoubleacute: u32 = 0x01db;
+pub const XK_Tcedilla: u32 = 0x01de;
+pub const XK_racute: u32 = 0x01e0;
+pub const XK_abreve: u32 = 0x01e3;
+pub const XK_lacute: u32 = 0x01e5;
+pub const XK_cacute: u32 = 0x01e6;
+pub const XK_ccaron: u32 = 0x01e8;
+pub const XK_eogonek: u32 = 0x01ea;
+pub const XK_ecaron: u32 = 0x01ec;
+pub const XK_dcaron: u32 = 0x01ef;
+pub const XK_dstroke: u32 = 0x01f0;
+pub const XK_nacute: u32 = 0x01f1;
+pub const XK_ncaron: u32 = 0x01f2;
+pub const XK_odoubleacute: u32 = 0x01f5;
+pub const XK_rcaron: u32 = 0x01f8;
+pub const XK_uring: u32 = 0x01f9;
+pub const XK_udoubleacute: u32 = 0x01fb;
+pub const XK_tcedilla: u32 = 0x01fe;
+pub const XK_abovedot: u32 = 0x01ff;
+pub const XK_Hstroke: u32 = 0x02a1;
+pub const XK_Hcircumflex: u32 = 0x02a6;
+pub const XK_Iabovedot: u32 = 0x02a9;
+pub const XK_Gbreve: u32 = 0x02ab;
+pub const XK_Jcircumflex: u32 = 0x02ac;
+pub const XK_hstroke: u32 = 0x02b1;
+pub const XK_hcircumflex: u32 = 0x02b6;
+pub const XK_idotless: u32 = 0x02b9;
+pub const XK_gbreve: u32 = 0x02bb;
+pub const XK_jcircumflex: u32 = 0x02bc;
+pub const XK_Cabovedot: u32 = 0x02c5;
+pub const XK_Ccircumflex: u32 = 0x02c6;
+pub const XK_Gabovedot: u32 = 0x02d5;
+pub const XK_Gcircumflex: u32 = 0x02d8;
+pub const XK_Ubreve: u32 = 0x02dd;
+pub const XK_Scircumflex: u32 = 0x02de;
+pub const XK_cabovedot: u32 = 0x02e5;
+pub const XK_ccircumflex: u32 = 0x02e6;
+pub const XK_gabovedot: u32 = 0x02f5;
+pub const XK_gcircumflex: u32 = 0x02f8;
+pub const XK_ubreve: u32 = 0x02fd;
+pub const XK_scircumflex: u32 = 0x02fe;
+pub const XK_kra: u32 = 0x03a2;
+pub const XK_kappa: u32 = 0x03a2;
+pub const XK_Rcedilla: u32 = 0x03a3;
+pub const XK_Itilde: u32 = 0x03a5;
+pub const XK_Lcedilla: u32 = 0x03a6;
+pub const XK_Emacron: u32 = 0x03aa;
+pub const XK_Gcedilla: u32 = 0x03ab;
+pub const XK_Tslash: u32 = 0x03ac;
+pub const XK_rcedilla: u32 = 0x03b3;
+pub const XK_itilde: u32 = 0x03b5;
+pub const XK_lcedilla: u32 = 0x03b6;
+pub const XK_emacron: u32 = 0x03ba;
+pub const XK_gcedilla: u32 = 0x03bb;
+pub const XK_tslash: u32 = 0x03bc;
+pub const XK_ENG: u32 = 0x03bd;
+pub const XK_eng: u32 = 0x03bf;
+pub const XK_Amacron: u32 = 0x03c0;
+pub const XK_Iogonek: u32 = 0x03c7;
+pub const XK_Eabovedot: u32 = 0x03cc;
+pub const XK_Imacron: u32 = 0x03cf;
+pub const XK_Ncedilla: u32 = 0x03d1;
+pub const XK_Omacron: u32 = 0x03d2;
+pub const XK_Kcedilla: u32 = 0x03d3;
+pub const XK_Uogonek: u32 = 0x03d9;
+pub const XK_Utilde: u32 = 0x03dd;
+pub const XK_Umacron: u32 = 0x03de;
+pub const XK_amacron: u32 = 0x03e0;
+pub const XK_iogonek: u32 = 0x03e7;
+pub const XK_eabovedot: u32 = 0x03ec;
+pub const XK_imacron: u32 = 0x03ef;
+pub const XK_ncedilla: u32 = 0x03f1;
+pub const XK_omacron: u32 = 0x03f2;
+pub const XK_kcedilla: u32 = 0x03f3;
+pub const XK_uogonek: u32 = 0x03f9;
+pub const XK_utilde: u32 = 0x03fd;
+pub const XK_umacron: u32 = 0x03fe;
+pub const XK_Wcircumflex: u32 = 0x1000174;
+pub const XK_wcircumflex: u32 = 0x1000175;
+pub const XK_Ycircumflex: u32 = 0x1000176;
+pub const XK_ycircumflex: u32 = 0x1000177;
+pub const XK_Babovedot: u32 = 0x1001e02;
+pub const XK_babovedot: u32 = 0x1001e03;
+pub const XK_Dabovedot: u32 = 0x1001e0a;
+pub const XK_dabovedot: u32 = 0x1001e0b;
+pub const XK_Fabovedot: u32 = 0x1001e1e;
+pub const XK_fabovedot: u32 = 0x1001e1f;
+pub const XK_Mabovedot: u32 = 0x1001e40;
+pub const XK_mabovedot: u32 = 0x1001e41;
+pub const XK_Pabovedot: u32 = 0x1001e56;
+pub const XK_pabovedot: u32 = 0x1001e57;
+pub const XK_Sabovedot: u32 = 0x1001e60;
+pub const XK_sabovedot: u32 = 0x1001e61;
+pub const XK_Tabovedot: u32 = 0x1001e6a;
+pub const XK_tabovedot: u32 = 0x1001e6b;
+pub const XK_Wgrave: u32 = 0x1001e80;
+pub const XK_wgrave: u32 = 0x1001e81;
+pub const XK_Wacute: u32 = 0x1001e82;
+pub const XK_wacute: u32 = 0x1001e83;
+pub const XK_Wdiaeresis: u32 = 0x1001e84;
+pub const XK_wdiaeresis: u32 = 0x1001e85;
+pub const XK_Ygrave: u32 = 0x1001ef2;
+pub const XK_ygrave: u32 = 0x1001ef3;
+pub const XK_OE: u32 = 0x13bc;
+pub const XK_oe: u32 = 0x13bd;
+pub const XK_Ydiaeresis: u32 = 0x13be;
+pub const XK_overline: u32 = 0x047e;
+pub const XK_kana_fullstop: u32 = 0x04a1;
+pub const XK_kana_openingbracket: u32 = 0x04a2;
+pub const XK_kana_closingbracket: u32 = 0x04a3;
+pub const XK_kana_comma: u32 = 0x04a4;
+pub const XK_kana_conjunctive: u32 = 0x04a5;
+pub const XK_kana_middledot: u32 = 0x04a5;
+pub const XK_kana_WO: u32 = 0x04a6;
+pub const XK_kana_a: u32 = 0x04a7;
+pub const XK_kana_i: u32 = 0x04a8;
+pub const XK_kana_u: u32 = 0x04a9;
+pub const XK_kana_e: u32 = 0x04aa;
+pub const XK_kana_o: u32 = 0x04ab;
+pub const XK_kana_ya: u32 = 0x04ac;
+pub const XK_kana_yu: u32 = 0x04ad;
+pub const XK_kana_yo: u32 = 0x04ae;
+pub const XK_kana_tsu: u32 = 0x04af;
+pub const XK_kana_tu: u32 = 0x04af;
+pub const XK_prolongedsound: u32 = 0x04b0;
+pub const XK_kana_A: u32 = 0x04b1;
+pub const XK_kana_I: u32 = 0x04b2;
+pub const XK_kana_U: u32 = 0x04b3;
+pub const XK_kana_E: u32 = 0x04b4;
+pub const XK_kana_O: u32 = 0x04b5;
+pub const XK_kana_KA: u32 = 0x04b6;
+pub const XK_kana_KI: u32 = 0x04b7;
+pub const XK_kana_KU: u32 = 0x04b8;
+pub const XK_kana_KE: u32 = 0x04b9;
+pub const XK_kana_KO: u32 = 0x04ba;
+pub const XK_kana_SA: u32 = 0x04bb;
+pub const XK_kana_SHI: u32 = 0x04bc;
+pub const XK_kana_SU: u32 = 0x04bd;
+pub const XK_kana_SE: u32 = 0x04be;
+pub const XK_kana_SO: u32 = 0x04bf;
+pub const XK_kana_TA: u32 = 0x04c0;
+pub const XK_kana_CHI: u32 = 0x04c1;
+pub const XK_kana_TI: u32 = 0x04c1;
+pub const XK_kana_TSU: u32 = 0x04c2;
+pub const XK_kana_TU: u32 = 0x04c2;
+pub const XK_kana_TE: u32 = 0x04c3;
+pub const XK_kana_TO: u32 = 0x04c4;
+pub const XK_kana_NA: u32 = 0x04c5;
+pub const XK_kana_NI: u32 = 0x04c6;
+pub const XK_kana_NU: u32 = 0x04c7;
+pub const XK_kana_NE: u32 = 0x04c8;
+pub const XK_kana_NO: u32 = 0x04c9;
+pub const XK_kana_HA: u32 = 0x04ca;
+pub const XK_kana_HI: u32 = 0x04cb;
+pub const XK_kana_FU: u32 = 0x04cc;
+pub const XK_kana_HU: u32 = 0x04cc;
+pub const XK_kana_HE: u32 = 0x04cd;
+pub const XK_kana_HO: u32 = 0x04ce;
+pub const XK_kana_MA: u32 = 0x04cf;
+pub const XK_kana_MI: u32 = 0x04d0;
+pub const XK_kana_MU: u32 = 0x04d1;
+pub const XK_kana_ME: u32 = 0x04d2;
+pub const XK_kana_MO: u32 = 0x04d3;
+pub const XK_kana_YA: u32 = 0x04d4;
+pub const XK_kana_YU: u32 = 0x04d5;
+pub const XK_kana_YO: u32 = 0x04d6;
+pub const XK_kana_RA: u32 = 0x04d7;
+pub const XK_kana_RI: u32 = 0x04d8;
+pub const XK_kana_RU: u32 = 0x04d9;
+pub const XK_kana_RE: u32 = 0x04da;
+pub const XK_kana_RO: u32 = 0x04db;
+pub const XK_kana_WA: u32 = 0x04dc;
+pub const XK_kana_N: u32 = 0x04dd;
+pub const XK_voicedsound: u32 = 0x04de;
+pub const XK_semivoicedsound: u32 = 0x04df;
+pub const XK_kana_switch: u32 = 0xff7e;
+pub const XK_Farsi_0: u32 = 0x10006f0;
+pub const XK_Farsi_1: u32 = 0x10006f1;
+pub const XK_Farsi_2: u32 = 0x10006f2;
+pub const XK_Farsi_3: u32 = 0x10006f3;
+pub const XK_Farsi_4: u32 = 0x10006f4;
+pub const XK_Farsi_5: u32 = 0x10006f5;
+pub const XK_Farsi_6: u32 = 0x10006f6;
+pub const XK_Farsi_7: u32 = 0x10006f7;
+pub const XK_Farsi_8: u32 = 0x10006f8;
+pub const XK_Farsi_9: u32 = 0x10006f9;
+pub const XK_Arabic_percent: u32 = 0x100066a;
+pub const XK_Arabic_superscript_alef: u32 = 0x1000670;
+pub const XK_Arabic_tteh: u32 = 0x1000679;
+pub const XK_Arabic_peh: u32 = 0x100067e;
+pub const XK_Arabic_tcheh: u32 = 0x1000686;
+pub const XK_Arabic_ddal: u32 = 0x1000688;
+pub const XK_Arabic_rreh: u32 = 0x1000691;
+pub const XK_Arabic_comma: u32 = 0x05ac;
+pub const XK_Arabic_fullstop: u32 = 0x10006d4;
+pub const XK_Arabic_0: u32 = 0x1000660;
+pub const XK_Arabic_1: u32 = 0x1000661;
+pub const XK_Arabic_2: u32 = 0x1000662;
+pub const XK_Arabic_3: u32 = 0x1000663;
+pub const XK_Arabic_4: u32 = 0x1000664;
+pub const XK_Arabic_5: u32 = 0x1000665;
+pub const XK_Arabic_6: u32 = 0x1000666;
+pub const XK_Arabic_7: u32 = 0x1000667;
+pub const XK_Arabic_8: u32 = 0x1000668;
+pub const XK_Arabic_9: u32 = 0x1000669;
+pub const XK_Arabic_semicolon: u32 = 0x05bb;
+pub const XK_Arabic_question_mark: u32 = 0x05bf;
+pub const XK_Arabic_hamza: u32 = 0x05c1;
+pub const XK_Arabic_maddaonalef: u32 = 0x05c2;
+pub const XK_Arabic_hamzaonalef: u32 = 0x05c3;
+pub const XK_Arabic_hamzaonwaw: u32 = 0x05c4;
+pub const XK_Arabic_hamzaunderalef: u32 = 0x05c5;
+pub const XK_Arabic_hamzaonyeh: u32 = 0x05c6;
+pub const XK_Arabic_alef: u32 = 0x05c7;
+pub const XK_Arabic_beh: u32 = 0x05c8;
+pub const XK_Arabic_tehmarbuta: u32 = 0x05c9;
+pub const XK_Arabic_teh: u32 = 0x05ca;
+pub const XK_Arabic_theh: u32 = 0x05cb;
+pub const XK_Arabic_jeem: u32 = 0x05cc;
+pub const XK_Arabic_hah: u32 = 0x05cd;
+pub const XK_Arabic_khah: u32 = 0x05ce;
+pub const XK_Arabic_dal: u32 = 0x05cf;
+pub const XK_Arabic_thal: u32 = 0x05d0;
+pub const XK_Arabic_ra: u32 = 0x05d1;
+pub const XK_Arabic_zain: u32 = 0x05d2;
+pub const XK_Arabic_seen: u32 = 0x05d3;
+pub const XK_Arabic_sheen: u32 = 0x05d4;
+pub const XK_Arabic_sad: u32 = 0x05d5;
+pub const XK_Arabic_dad: u32 = 0x05d6;
+pub const XK_Arabic_tah: u32 = 0x05d7;
+pub const XK_Arabic_zah: u32 = 0x05d8;
+pub const XK_Arabic_ain: u32 = 0x05d9;
+pub const XK_Arabic_ghain: u32 = 0x05da;
+pub const XK_Arabic_tatweel: u32 = 0x05e0;
+pub const XK_Arabic_feh: u32 = 0x05e1;
+pub const XK_Arabic_qaf: u32 = 0x05e2;
+pub const XK_Arabic_kaf: u32 = 0x05e3;
+pub const XK_Arabic_lam: u32 = 0x05e4;
+pub const XK_Arabic_meem: u32 = 0x05e5;
+pub const XK_Arabic_noon: u32 = 0x05e6;
+pub const XK_Arabic_ha: u32 = 0x05e7;
+pub const XK_Arabic_heh: u32 = 0x05e7;
+pub const XK_Arabic_waw: u32 = 0x05e8;
+pub const XK_Arabic_alefmaksura: u32 = 0x05e9;
+pub const XK_Arabic_yeh: u32 = 0x05ea;
+pub const XK_Arabic_fathatan: u32 = 0x05eb;
+pub const XK_Arabic_dammatan: u32 = 0x05ec;
+pub const XK_Arabic_kasratan: u32 = 0x05ed;
+pub const XK_Arabic_fatha: u32 = 0x05ee;
+pub const XK_Arabic_damma: u32 = 0x05ef;
+pub const XK_Arabic_kasra: u32 = 0x05f0;
+pub const XK_Arabic_shadda: u32 = 0x05f1;
+pub const XK_Arabic_sukun: u32 = 0x05f2;
+pub const XK_Arabic_madda_above: u32 = 0x1000653;
+pub const XK_Arabic_hamza_above: u32 = 0x1000654;
+pub const XK_Arabic_hamza_below: u32 = 0x1000655;
+pub const XK_Arabic_jeh: u32 = 0x1000698;
+pub const XK_Arabic_veh: u32 = 0x10006a4;
+pub const XK_Arabic_keheh: u32 = 0x10006a9;
+pub const XK_Arabic_gaf: u32 = 0x10006af;
+pub const XK_Arabic_noon_ghunna: u32 = 0x10006ba;
+pub const XK_Arabic_heh_doachashmee: u32 = 0x10006be;
+pub const XK_Farsi_yeh: u32 = 0x10006cc;
+pub const XK_Arabic_farsi_yeh: u32 = 0x10006cc;
+pub const XK_Arabic_yeh_baree: u32 = 0x10006d2;
+pub const XK_Arabic_heh_goal: u32 = 0x10006c1;
+pub const XK_Arabic_switch: u32 = 0xff7e;
+pub const XK_Cyrillic_GHE_bar: u32 = 0x1000492;
+pub const XK_Cyrillic_ghe_bar: u32 = 0x1000493;
+pub const XK_Cyrillic_ZHE_descender: u32 = 0x1000496;
+pub const XK_Cyrillic_zhe_descender: u32 = 0x1000497;
+pub const XK_Cyrillic_KA_descender: u32 = 0x100049a;
+pub const XK_Cyrillic_ka_descender: u32 = 0x100049b;
+pub const XK_Cyrillic_KA_vertstroke: u32 = 0x100049c;
+pub const XK_Cyrillic_ka_vertstroke: u32 = 0x100049d;
+pub const XK_Cyrillic_EN_descender: u32 = 0x10004a2;
+pub const XK_Cyrillic_en_descender: u32 = 0x10004a3;
+pub const XK_Cyrillic_U_straight: u32 = 0x10004ae;
+pub const XK_Cyrillic_u_straight: u32 = 0x10004af;
+pub const XK_Cyrillic_U_straight_bar: u32 = 0x10004b0;
+pub const XK_Cyrillic_u_straight_bar: u32 = 0x10004b1;
+pub const XK_Cyrillic_HA_descender: u32 = 0x10004b2;
+pub const XK_Cyrillic_ha_descender: u32 = 0x10004b3;
+pub const XK_Cyrillic_CHE_descender: u32 = 0x10004b6;
+pub const XK_Cyrillic_che_descender: u32 = 0x10004b7;
+pub const XK_Cyrillic_CHE_vertstroke: u32 = 0x10004b8;
+pub const XK_Cyrillic_che_vertstroke: u32 = 0x10004b9;
+pub const XK_Cyrillic_SHHA: u32 = 0x10004ba;
+pub const XK_Cyrillic_shha: u32 = 0x10004bb;
+pub const XK_Cyrillic_SCHWA: u32 = 0x10004d8;
+pub const XK_Cyrillic_schwa: u32 = 0x10004d9;
+pub const XK_Cyrillic_I_macron: u32 = 0x10004e2;
+pub const XK_Cyrillic_i_macron: u32 = 0x10004e3;
+pub const XK_Cyrillic_O_bar: u32 = 0x10004e8;
+pub const XK_Cyrillic_o_bar: u32 = 0x10004e9;
+pub const XK_Cyrillic_U_macron: u32 = 0x10004ee;
+pub const XK_Cyrillic_u_macron: u32 = 0x10004ef;
+pub const XK_Serbian_dje: u32 = 0x06a1;
+pub const XK_Macedonia_gje: u32 = 0x06a2;
+pub const XK_Cyrillic_io: u32 = 0x06a3;
+pub const XK_Ukrainian_ie: u32 = 0x06a4;
+pub const XK_Ukranian_je: u32 = 0x06a4;
+pub const XK_Macedonia_dse: u32 = 0x06a5;
+pub const XK_Ukrainian_i: u32 = 0x06a6;
+pub const XK_Ukranian_i: u32 = 0x06a6;
+pub const XK_Ukrainian_yi: u32 = 0x06a7;
+pub const XK_Ukranian_yi: u32 = 0x06a7;
+pub const XK_Cyrillic_je: u32 = 0x06a8;
+pub const XK_Serbian_je: u32 = 0x06a8;
+pub const XK_Cyrillic_lje: u32 = 0x06a9;
+pub const XK_Serbian_lje: u32 = 0x06a9;
+pub const XK_Cyrillic_nje: u32 = 0x06aa;
+pub const XK_Serbian_nje: u32 = 0x06aa;
+pub const XK_Serbian_tshe: u32 = 0x06ab;
+pub const XK_Macedonia_kje: u32 = 0x06ac;
+pub const XK_Ukrainian_ghe_with_upturn: u32 = 0x06ad;
+pub const XK_Byelorussian_shortu: u32 = 0x06ae;
+pub const XK_Cyrillic_dzhe: u32 = 0x06af;
+pub const XK_Serbian_dze: u32 = 0x06af;
+pub const XK_numerosign: u32 = 0x06b0;
+pub const XK_Serbian_DJE: u32 = 0x06b1;
+pub const XK_Macedonia_GJE: u32 = 0x06b2;
+pub const XK_Cyrillic_IO: u32 = 0x06b3;
+pub const XK_Ukrainian_IE: u32 = 0x06b4;
+pub const XK_Ukranian_JE: u32 = 0x06b4;
+pub const XK_Macedonia_DSE: u32 = 0x06b5;
+pub const XK_Ukrainian_I: u32 = 0x06b6;
+pub const XK_Ukranian_I: u32 = 0x06b6;
+pub const XK_Ukrainian_YI: u32 = 0x06b7;
+pub const XK_Ukranian_YI: u32 = 0x06b7;
+pub const XK_Cyrillic_JE: u32 = 0x06b8;
+pub const XK_Serbian_JE: u32 = 0x06b8;
+pub const XK_Cyrillic_LJE: u32 = 0x06b9;
+pub const XK_Serbian_LJE: u32 = 0x06b9;
+pub const XK_Cyrillic_NJE: u32 = 0x06ba;
+pub const XK_Serbian_NJE: u32 = 0x06ba;
+pub const XK_Serbian_TSHE: u32 = 0x06bb;
+pub const XK_Macedonia_KJE: u32 = 0x06bc;
+pub const XK_Ukrainian_GHE_WITH_UPTURN: u32 = 0x06bd;
+pub const XK_Byelorussian_SHORTU: u32 = 0x06be;
+pub const XK_Cyrillic_DZHE: u32 = 0x06bf;
+pub const XK_Serbian_DZE: u32 = 0x06bf;
+pub const XK_Cyrillic_yu: u32 = 0x06c0;
+pub const XK_Cyrillic_a: u32 = 0x06c1;
+pub const XK_Cyrillic_be: u32 = 0x06c2;
+pub const XK_Cyrillic_tse: u32 = 0x06c3;
+pub const XK_Cyrillic_de: u32 = 0x06c4;
+pub const XK_Cyrillic_ie: u32 = 0x06c5;
+pub const XK_Cyrillic_ef: u32 = 0x06c6;
+pub const XK_Cyrillic_ghe: u32 = 0x06c7;
+pub const XK_Cyrillic_ha: u32 = 0x06c8;
+pub const XK_Cyrillic_i: u32 = 0x06c9;
+pub const XK_Cyrillic_shorti: u32 = 0x06ca;
+pub const XK_Cyrillic_ka: u32 = 0x06cb;
+pub const XK_Cyrillic_el: u32 = 0x06cc;
+pub const XK_Cyrillic_em: u32 = 0x06cd;
+pub const XK_Cyrillic_en: u32 = 0x06ce;
+pub const XK_Cyrillic_o: u32 = 0x06cf;
+pub const XK_Cyrillic_pe: u32 = 0x06d0;
+pub const XK_Cyrillic_ya: u32 = 0x06d1;
+pub const XK_Cyrillic_er: u32 = 0x06d2;
+pub const XK_Cyrillic_es: u32 = 0x06d3;
+pub const XK_Cyrillic_te: u32 = 0x06d4;
+pub const XK_Cyrillic_u: u32 = 0x06d5;
+pub const XK_Cyrillic_zhe: u32 = 0x06d6;
+pub const XK_Cyrillic_ve: u32 = 0x06d7;
+pub const XK_Cyrillic_softsign: u32 = 0x06d8;
+pub const XK_Cyrillic_yeru: u32 = 0x06d9;
+pub const XK_Cyrillic_ze: u32 = 0x06da;
+pub const XK_Cyrillic_sha: u32 = 0x06db;
+pub const XK_Cyrillic_e: u32 = 0x06dc;
+pub const XK_Cyrillic_shcha: u32 = 0x06dd;
+pub const XK_Cyrillic_che: u32 = 0x06de;
+pub const XK_Cyrillic_hardsign: u32 = 0x06df;
+pub const XK_Cyrillic_YU: u32 = 0x06e0;
+pub const XK_Cyrillic_A: u32 = 0x06e1;
+pub const XK_Cyrillic_BE: u32 = 0x06e2;
+pub const XK_Cyrillic_TSE: u32 = 0x06e3;
+pub const XK_Cyrillic_DE: u32 = 0x06e4;
+pub const XK_Cyrillic_IE: u32 = 0x06e5;
+pub const XK_Cyrillic_EF: u32 = 0x06e6;
+pub const XK_Cyrillic_GHE: u32 = 0x06e7;
+pub const XK_Cyrillic_HA: u32 = 0x06e8;
+pub const XK_Cyrillic_I: u32 = 0x06e9;
+pub const XK_Cyrillic_SHORTI: u32 = 0x06ea;
+pub const XK_Cyrillic_KA: u32 = 0x06eb;
+pub const XK_Cyrillic_EL: u32 = 0x06ec;
+pub const XK_Cyrillic_EM: u32 = 0x06ed;
+pub const XK_Cyrillic_EN: u32 = 0x06ee;
+pub const XK_Cyrillic_O: u32 = 0x06ef;
+pub const XK_Cyrillic_PE: u32 = 0x06f0;
+pub const XK_Cyrillic_YA: u32 = 0x06f1;
+pub const XK_Cyrillic_ER: u32 = 0x06f2;
+pub const XK_Cyrillic_ES: u32 = 0x06f3;
+pub const XK_Cyrillic_TE: u32 = 0x06f4;
+pub const XK_Cyrillic_U: u32 = 0x06f5;
+pub const XK_Cyrillic_ZHE: u32 = 0x06f6;
+pub const XK_Cyrillic_VE: u32 = 0x06f7;
+pub const XK_Cyrillic_SOFTSIGN: u32 = 0x06f8;
+pub const XK_Cyrillic_YERU: u32 = 0x06f9;
+pub const XK_Cyrillic_ZE: u32 = 0x06fa;
+pub const XK_Cyrillic_SHA: u32 = 0x06fb;
+pub const XK_Cyrillic_E: u32 = 0x06fc;
+pub const XK_Cyrillic_SHCHA: u32 = 0x06fd;
+pub const XK_Cyrillic_CHE: u32 = 0x06fe;
+pub const XK_Cyrillic_HARDSIGN: u32 = 0x06ff;
+pub const XK_Greek_ALPHAaccent: u32 = 0x07a1;
+pub const XK_Greek_EPSILONaccent: u32 = 0x07a2;
+pub const XK_Greek_ETAaccent: u32 = 0x07a3;
+pub const XK_Greek_IOTAaccent: u32 = 0x07a4;
+pub const XK_Greek_IOTAdieresis: u32 = 0x07a5;
+pub const XK_Greek_IOTAdiaeresis: u32 = 0x07a5;
+pub const XK_Greek_OMICRONaccent: u32 = 0x07a7;
+pub const XK_Greek_UPSILONaccent: u32 = 0x07a8;
+pub const XK_Greek_UPSILONdieresis: u32 = 0x07a9;
+pub const XK_Greek_OMEGAaccent: u32 = 0x07ab;
+pub const XK_Greek_accentdieresis: u32 = 0x07ae;
+pub const XK_Greek_horizbar: u32 = 0x07af;
+pub const XK_Greek_alphaaccent: u32 = 0x07b1;
+pub const XK_Greek_epsilonaccent: u32 = 0x07b2;
+pub const XK_Greek_etaaccent: u32 = 0x07b3;
+pub const XK_Greek_iotaaccent: u32 = 0x07b4;
+pub const XK_Greek_iotadieresis: u32 = 0x07b5;
+pub const XK_Greek_iotaaccentdieresis: u32 = 0x07b6;
+pub const XK_Greek_omicronaccent: u32 = 0x07b7;
+pub const XK_Greek_upsilonaccent: u32 = 0x07b8;
+pub const XK_Greek_upsilondieresis: u32 = 0x07b9;
+pub const XK_Greek_upsilonaccentdieresis: u32 = 0x07ba;
+pub const XK_Greek_omegaaccent: u32 = 0x07bb;
+pub const XK_Greek_ALPHA: u32 = 0x07c1;
+pub const XK_Greek_BETA: u32 = 0x07c2;
+pub const XK_Greek_GAMMA: u32 = 0x07c3;
+pub const XK_Greek_DELTA: u32 = 0x07c4;
+pub const XK_Greek_EPSILON: u32 = 0x07c5;
+pub const XK_Greek_ZETA: u32 = 0x07c6;
+pub const XK_Greek_ETA: u32 = 0x07c7;
+pub const XK_Greek_THETA: u32 = 0x07c8;
+pub const XK_Greek_IOTA: u32 = 0x07c9;
+pub const XK_Greek_KAPPA: u32 = 0x07ca;
+pub const XK_Greek_LAMDA: u32 = 0x07cb;
+pub const XK_Greek_LAMBDA: u32 = 0x07cb;
+pub const XK_Greek_MU: u32 = 0x07cc;
+pub const XK_Greek_NU: u32 = 0x07cd;
+pub const XK_Greek_XI: u32 = 0x07ce;
+pub const XK_Greek_OMICRON: u32 = 0x07cf;
+pub const XK_Greek_PI: u32 = 0x07d0;
+pub const XK_Greek_RHO: u32 = 0x07d1;
+pub const XK_Greek_SIGMA: u32 = 0x07d2;
+pub const XK_Greek_TAU: u32 = 0x07d4;
+pub const XK_Greek_UPSILON: u32 = 0x07d5;
+pub const XK_Greek_PHI: u32 = 0x07d6;
+pub const XK_Greek_CHI: u32 = 0x07d7;
+pub const XK_Greek_PSI: u32 = 0x07d8;
+pub const XK_Greek_OMEGA: u32 = 0x07d9;
+pub const XK_Greek_alpha: u32 = 0x07e1;
+pub const XK_Greek_beta: u32 = 0x07e2;
+pub const XK_Greek_gamma: u32 = 0x07e3;
+pub const XK_Greek_delta: u32 = 0x07e4;
+pub const XK_Greek_epsilon: u32 = 0x07e5;
+pub const XK_Greek_zeta: u32 = 0x07e6;
+pub const XK_Greek_eta: u32 = 0x07e7;
+pub const XK_Greek_theta: u32 = 0x07e8;
+pub const XK_Greek_iota: u32 = 0x07e9;
+pub const XK_Greek_kappa: u32 = 0x07ea;
+pub const XK_Greek_lamda: u32 = 0x07eb;
+pub const XK_Greek_lambda: u32 = 0x07eb;
+pub const XK_Greek_mu: u32 = 0x07ec;
+pub const XK_Greek_nu: u32 = 0x07ed;
+pub const XK_Greek_xi: u32 = 0x07ee;
+pub const XK_Greek_omicron: u32 = 0x07ef;
+pub const XK_Greek_pi: u32 = 0x07f0;
+pub const XK_Greek_rho: u32 = 0x07f1;
+pub const XK_Greek_sigma: u32 = 0x07f2;
+pub const XK_Greek_finalsmallsigma: u32 = 0x07f3;
+pub const XK_Greek_tau: u32 = 0x07f4;
+pub const XK_Greek_upsilon: u32 = 0x07f5;
+pub const XK_Greek_phi: u32 = 0x07f6;
+pub const XK_Greek_chi: u32 = 0x07f7;
+pub const XK_Greek_psi: u32 = 0x07f8;
+pub const XK_Greek_omega: u32 = 0x07f9;
+pub const XK_Greek_switch: u32 = 0xff7e;
+pub const XK_leftradical: u32 = 0x08a1;
+pub const XK_topleftradical: u32 = 0x08a2;
+pub const XK_horizconnector: u32 = 0x08a3;
+pub const XK_topintegral: u32 = 0x08a4;
+pub const XK_botintegral: u32 = 0x08a5;
+pub const XK_vertconnector: u32 = 0x08a6;
+pub const XK_topleftsqbracket: u32 = 0x08a7;
+pub const XK_botleftsqbracket: u32 = 0x08a8;
+pub const XK_toprightsqbracket: u32 = 0x08a9;
+pub const XK_botrightsqbracket: u32 = 0x08aa;
+pub const XK_topleftparens: u32 = 0x08ab;
+pub const XK_botleftparens: u32 = 0x08ac;
+pub const XK_toprightparens: u32 = 0x08ad;
+pub const XK_botrightparens: u32 = 0x08ae;
+pub const XK_leftmiddlecurlybrace: u32 = 0x08af;
+pub const XK_rightmiddlecurlybrace: u32 = 0x08b0;
+pub const XK_topleftsummation: u32 = 0x08b1;
+pub const XK_botleftsummation: u32 = 0x08b2;
+pub const XK_topvertsummationconnector: u32 = 0x08b3;
+pub const XK_botvertsummationconnector: u32 = 0x08b4;
+pub const XK_toprightsummation: u32 = 0x08b5;
+pub const XK_botrightsummation: u32 = 0x08b6;
+pub const XK_rightmiddlesummation: u32 = 0x08b7;
+pub const XK_lessthanequal: u32 = 0x08bc;
+pub const XK_notequal: u32 = 0x08bd;
+pub const XK_greaterthanequal: u32 = 0x08be;
+pub const XK_integral: u32 = 0x08bf;
+pub const XK_therefore: u32 = 0x08c0;
+pub const XK_variation: u32 = 0x08c1;
+pub const XK_infinity: u32 = 0x08c2;
+pub const XK_nabla: u32 = 0x08c5;
+pub const XK_approximate: u32 = 0x08c8;
+pub const XK_similarequal: u32 = 0x08c9;
+pub const XK_ifonlyif: u32 = 0x08cd;
+pub const XK_implies: u32 = 0x08ce;
+pub const XK_identical: u32 = 0x08cf;
+pub const XK_radical: u32 = 0x08d6;
+pub const XK_includedin: u32 = 0x08da;
+pub const XK_includes: u32 = 0x08db;
+pub const XK_intersection: u32 = 0x08dc;
+pub const XK_union: u32 = 0x08dd;
+pub const XK_logicaland: u32 = 0x08de;
+pub const XK_logicalor: u32 = 0x08df;
+pub const XK_partialderivative: u32 = 0x08ef;
+pub const XK_function: u32 = 0x08f6;
+pub const XK_leftarrow: u32 = 0x08fb;
+pub const XK_uparrow: u32 = 0x08fc;
+pub const XK_rightarrow: u32 = 0x08fd;
+pub const XK_downarrow: u32 = 0x08fe;
+pub const XK_blank: u32 = 0x09df;
+pub const XK_soliddiamond: u32 = 0x09e0;
+pub const XK_checkerboard: u32 = 0x09e1;
+pub const XK_ht: u32 = 0x09e2;
+pub const XK_ff: u32 = 0x09e3;
+pub const XK_cr: u32 = 0x09e4;
+pub const XK_lf: u32 = 0x09e5;
+pub const XK_nl: u32 = 0x09e8;
+pub const XK_vt: u32 = 0x09e9;
+pub const XK_lowrightcorner: u32 = 0x09ea;
+pub const XK_uprightcorner: u32 = 0x09eb;
+pub const XK_upleftcorner: u32 = 0x09ec;
+pub const XK_lowleftcorner: u32 = 0x09ed;
+pub const XK_crossinglines: u32 = 0x09ee;
+pub const XK_horizlinescan1: u32 = 0x09ef;
+pub const XK_horizlinescan3: u32 = 0x09f0;
+pub const XK_horizlinescan5: u32 = 0x09f1;
+pub const XK_horizlinescan7: u32 = 0x09f2;
+pub const XK_horizlinescan9: u32 = 0x09f3;
+pub const XK_leftt: u32 = 0x09f4;
+pub const XK_rightt: u32 = 0x09f5;
+pub const XK_bott: u32 = 0x09f6;
+pub const XK_topt: u32 = 0x09f7;
+pub const XK_vertbar: u32 = 0x09f8;
+pub const XK_emspace: u32 = 0x0aa1;
+pub const XK_enspace: u32 = 0x0aa2;
+pub const XK_em3space: u32 = 0x0aa3;
+pub const XK_em4space: u32 = 0x0aa4;
+pub const XK_digitspace: u32 = 0x0aa5;
+pub const XK_punctspace: u32 = 0x0aa6;
+pub const XK_thinspace: u32 = 0x0aa7;
+pub const XK_hairspace: u32 = 0x0aa8;
+pub const XK_emdash: u32 = 0x0aa9;
+pub const XK_endash: u32 = 0x0aaa;
+pub const XK_signifblank: u32 = 0x0aac;
+pub const XK_ellipsis: u32 = 0x0aae;
+pub const XK_doubbaselinedot: u32 = 0x0aaf;
+pub const XK_onethird: u32 = 0x0ab0;
+pub const XK_twothirds: u32 = 0x0ab1;
+pub const XK_onefifth: u32 = 0x0ab2;
+pub const XK_twofifths: u32 = 0x0ab3;
+pub const XK_threefifths: u32 = 0x0ab4;
+pub const XK_fourfifths: u32 = 0x0ab5;
+pub const XK_onesixth: u32 = 0x0ab6;
+pub const XK_fivesixths: u32 = 0x0ab7;
+pub const XK_careof: u32 = 0x0ab8;
+pub const XK_figdash: u32 = 0x0abb;
+pub const XK_leftanglebracket: u32 = 0x0abc;
+pub const XK_decimalpoint: u32 = 0x0abd;
+pub const XK_rightanglebracket: u32 = 0x0abe;
+pub const XK_marker: u32 = 0x0abf;
+pub const XK_oneeighth: u32 = 0x0ac3;
+pub const XK_threeeighths: u32 = 0x0ac4;
+pub const XK_fiveeighths: u32 = 0x0ac5;
+pub const XK_seveneighths: u32 = 0x0ac6;
+pub const XK_trademark: u32 = 0x0ac9;
+pub const XK_signaturemark: u32 = 0x0aca;
+pub const XK_trademarkincircle: u32 = 0x0acb;
+pub const XK_leftopentriangle: u32 = 0x0acc;
+pub const XK_rightopentriangle: u32 = 0x0acd;
+pub const XK_emopencircle: u32 = 0x0ace;
+pub const XK_emopenrectangle: u32 = 0x0acf;
+pub const XK_leftsinglequotemark: u32 = 0x0ad0;
+pub const XK_rightsinglequotemark: u32 = 0x0ad1;
+pub const XK_leftdoublequotemark: u32 = 0x0ad2;
+pub const XK_rightdoublequotemark: u32 = 0x0ad3;
+pub const XK_prescription: u32 = 0x0ad4;
+pub const XK_permille: u32 = 0x0ad5;
+pub const XK_minutes: u32 = 0x0ad6;
+pub const XK_seconds: u32 = 0x0ad7;
+pub const XK_latincross: u32 = 0x0ad9;
+pub const XK_hexagram: u32 = 0x0ada;
+pub const XK_filledrectbullet: u32 = 0x0adb;
+pub const XK_filledlefttribullet: u32 = 0x0adc;
+pub const XK_filledrighttribullet: u32 = 0x0add;
+pub const XK_emfilledcircle: u32 = 0x0ade;
+pub const XK_emfilledrect: u32 = 0x0adf;
+pub const XK_enopencircbullet: u32 = 0x0ae0;
+pub const XK_enopensquarebullet: u32 = 0x0ae1;
+pub const XK_openrectbullet: u32 = 0x0ae2;
+pub const XK_opentribulletup: u32 = 0x0ae3;
+pub const XK_opentribulletdown: u32 = 0x0ae4;
+pub const XK_openstar: u32 = 0x0ae5;
+pub const XK_enfilledcircbullet: u32 = 0x0ae6;
+pub const XK_enfilledsqbullet: u32 = 0x0ae7;
+pub const XK_filledtribulletup: u32 = 0x0ae8;
+pub const XK_filledtribulletdown: u32 = 0x0ae9;
+pub const XK_leftpointer: u32 = 0x0aea;
+pub const XK_rightpointer: u32 = 0x0aeb;
+pub const XK_club: u32 = 0x0aec;
+pub const XK_diamond: u32 = 0x0aed;
+pub const XK_heart: u32 = 0x0aee;
+pub const XK_maltesecross: u32 = 0x0af0;
+pub const XK_dagger: u32 = 0x0af1;
+pub const XK_doubledagger: u32 = 0x0af2;
+pub const XK_checkmark: u32 = 0x0af3;
+pub const XK_ballotcross: u32 = 0x0af4;
+pub const XK_musicalsharp: u32 = 0x0af5;
+pub const XK_musicalflat: u32 = 0x0af6;
+pub const XK_malesymbol: u32 = 0x0af7;
+pub const XK_femalesymbol: u32 = 0x0af8;
+pub const XK_telephone: u32 = 0x0af9;
+pub const XK_telephonerecorder: u32 = 0x0afa;
+pub const XK_phonographcopyright: u32 = 0x0afb;
+pub const XK_caret: u32 = 0x0afc;
+pub const XK_singlelowquotemark: u32 = 0x0afd;
+pub const XK_doublelowquotemark: u32 = 0x0afe;
+pub const XK_cursor: u32 = 0x0aff;
+pub const XK_leftcaret: u32 = 0x0ba3;
+pub const XK_rightcaret: u32 = 0x0ba6;
+pub const XK_downcaret: u32 = 0x0ba8;
+pub const XK_upcaret: u32 = 0x0ba9;
+pub const XK_overbar: u32 = 0x0bc0;
+pub const XK_downtack: u32 = 0x0bc2;
+pub const XK_upshoe: u32 = 0x0bc3;
+pub const XK_downstile: u32 = 0x0bc4;
+pub const XK_underbar: u32 = 0x0bc6;
+pub const XK_jot: u32 = 0x0bca;
+pub const XK_quad: u32 = 0x0bcc;
+pub const XK_uptack: u32 = 0x0bce;
+pub const XK_circle: u32 = 0x0bcf;
+pub const XK_upstile: u32 = 0x0bd3;
+pub const XK_downshoe: u32 = 0x0bd6;
+pub const XK_rightshoe: u32 = 0x0bd8;
+pub const XK_leftshoe: u32 = 0x0bda;
+pub const XK_lefttack: u32 = 0x0bdc;
+pub const XK_righttack: u32 = 0x0bfc;
+pub const XK_hebrew_doublelowline: u32 = 0x0cdf;
+pub const XK_hebrew_aleph: u32 = 0x0ce0;
+pub const XK_hebrew_bet: u32 = 0x0ce1;
+pub const XK_hebrew_beth: u32 = 0x0ce1;
+pub const XK_hebrew_gimel: u32 = 0x0ce2;
+pub const XK_hebrew_gimmel: u32 = 0x0ce2;
+pub const XK_hebrew_dalet: u32 = 0x0ce3;
+pub const XK_hebrew_daleth: u32 = 0x0ce3;
+pub const XK_hebrew_he: u32 = 0x0ce4;
+pub const XK_hebrew_waw: u32 = 0x0ce5;
+pub const XK_hebrew_zain: u32 = 0x0ce6;
+pub const XK_hebrew_zayin: u32 = 0x0ce6;
+pub const XK_hebrew_chet: u32 = 0x0ce7;
+pub const XK_hebrew_het: u32 = 0x0ce7;
+pub const XK_hebrew_tet: u32 = 0x0ce8;
+pub const XK_hebrew_teth: u32 = 0x0ce8;
+pub const XK_hebrew_yod: u32 = 0x0ce9;
+pub const XK_hebrew_finalkaph: u32 = 0x0cea;
+pub const XK_hebrew_kaph: u32 = 0x0ceb;
+pub const XK_hebrew_lamed: u32 = 0x0cec;
+pub const XK_hebrew_finalmem: u32 = 0x0ced;
+pub const XK_hebrew_mem: u32 = 0x0cee;
+pub const XK_hebrew_finalnun: u32 = 0x0cef;
+pub const XK_hebrew_nun: u32 = 0x0cf0;
+pub const XK_hebrew_samech: u32 = 0x0cf1;
+pub const XK_hebrew_samekh: u32 = 0x0cf1;
+pub const XK_hebrew_ayin: u32 = 0x0cf2;
+pub const XK_hebrew_finalpe: u32 = 0x0cf3;
+pub const XK_hebrew_pe: u32 = 0x0cf4;
+pub const XK_hebrew_finalzade: u32 = 0x0cf5;
+pub const XK_hebrew_finalzadi: u32 = 0x0cf5;
+pub const XK_hebrew_zade: u32 = 0x0cf6;
+pub const XK_hebrew_zadi: u32 = 0x0cf6;
+pub const XK_hebrew_qoph: u32 = 0x0cf7;
+pub const XK_hebrew_kuf: u32 = 0x0cf7;
+pub const XK_hebrew_resh: u32 = 0x0cf8;
+pub const XK_hebrew_shin: u32 = 0x0cf9;
+pub const XK_hebrew_taw: u32 = 0x0cfa;
+pub const XK_hebrew_taf: u32 = 0x0cfa;
+pub const XK_Hebrew_switch: u32 = 0xff7e;
+pub const XK_Thai_kokai: u32 = 0x0da1;
+pub const XK_Thai_khokhai: u32 = 0x0da2;
+pub const XK_Thai_khokhuat: u32 = 0x0da3;
+pub const XK_Thai_khokhwai: u32 = 0x0da4;
+pub const XK_Thai_khokhon: u32 = 0x0da5;
+pub const XK_Thai_khorakhang: u32 = 0x0da6;
+pub const XK_Thai_ngongu: u32 = 0x0da7;
+pub const XK_Thai_chochan: u32 = 0x0da8;
+pub const XK_Thai_choching: u32 = 0x0da9;
+pub const XK_Thai_chochang: u32 = 0x0daa;
+pub const XK_Thai_soso: u32 = 0x0dab;
+pub const XK_Thai_chochoe: u32 = 0x0dac;
+pub const XK_Thai_yoying: u32 = 0x0dad;
+pub const XK_Thai_dochada: u32 = 0x0dae;
+pub const XK_Thai_topatak: u32 = 0x0daf;
+pub const XK_Thai_thothan: u32 = 0x0db0;
+pub const XK_Thai_thonangmontho: u32 = 0x0db1;
+pub const XK_Thai_thophuthao: u32 = 0x0db2;
+pub const XK_Thai_nonen: u32 = 0x0db3;
+pub const XK_Thai_dodek: u32 = 0x0db4;
+pub const XK_Thai_totao: u32 = 0x0db5;
+pub const XK_Thai_thothung: u32 = 0x0db6;
+pub const XK_Thai_thothahan: u32 = 0x0db7;
+pub const XK_Thai_thothong: u32 = 0x0db8;
+pub const XK_Thai_nonu: u32 = 0x0db9;
+pub const XK_Thai_bobaimai: u32 = 0x0dba;
+pub const XK_Thai_popla: u32 = 0x0dbb;
+pub const XK_Thai_phophung: u32 = 0x0dbc;
+pub const XK_Thai_fofa: u32 = 0x0dbd;
+pub const XK_Thai_phophan: u32 = 0x0dbe;
+pub const XK_Thai_fofan: u32 = 0x0dbf;
+pub const XK_Thai_phosamphao: u32 = 0x0dc0;
+pub const XK_Thai_moma: u32 = 0x0dc1;
+pub const XK_Thai_yoyak: u32 = 0x0dc2;
+pub const XK_Thai_rorua: u32 = 0x0dc3;
+pub const XK_Thai_ru: u32 = 0x0dc4;
+pub const XK_Thai_loling: u32 = 0x0dc5;
+pub const XK_Thai_lu: u32 = 0x0dc6;
+pub const XK_Thai_wowaen: u32 = 0x0dc7;
+pub const XK_Thai_sosala: u32 = 0x0dc8;
+pub const XK_Thai_sorusi: u32 = 0x0dc9;
+pub const XK_Thai_sosua: u32 = 0x0dca;
+pub const XK_Thai_hohip: u32 = 0x0dcb;
+pub const XK_Thai_lochula: u32 = 0x0dcc;
+pub const XK_Thai_oang: u32 = 0x0dcd;
+pub const XK_Thai_honokhuk: u32 = 0x0dce;
+pub const XK_Thai_paiyannoi: u32 = 0x0dcf;
+pub const XK_Thai_saraa: u32 = 0x0dd0;
+pub const XK_Thai_maihanakat: u32 = 0x0dd1;
+pub const XK_Thai_saraaa: u32 = 0x0dd2;
+pub const XK_Thai_saraam: u32 = 0x0dd3;
+pub const XK_Thai_sarai: u32 = 0x0dd4;
+pub const XK_Thai_saraii: u32 = 0x0dd5;
+pub const XK_Thai_saraue: u32 = 0x0dd6;
+pub const XK_Thai_sarauee: u32 = 0x0dd7;
+pub const XK_Thai_sarau: u32 = 0x0dd8;
+pub const XK_Thai_sarauu: u32 = 0x0dd9;
+pub const XK_Thai_phinthu: u32 = 0x0dda;
+pub const XK_Thai_maihanakat_maitho: u32 = 0x0dde;
+pub const XK_Thai_baht: u32 = 0x0ddf;
+pub const XK_Thai_sarae: u32 = 0x0de0;
+pub const XK_Thai_saraae: u32 = 0x0de1;
+pub const XK_Thai_sarao: u32 = 0x0de2;
+pub const XK_Thai_saraaimaimuan: u32 = 0x0de3;
+pub const XK_Thai_saraaimaimalai: u32 = 0x0de4;
+pub const XK_Thai_lakkhangyao: u32 = 0x0de5;
+pub const XK_Thai_maiyamok: u32 = 0x0de6;
+pub const XK_Thai_maitaikhu: u32 = 0x0de7;
+pub const XK_Thai_maiek: u32 = 0x0de8;
+pub const XK_Thai_maitho: u32 = 0x0de9;
+pub const XK_Thai_maitri: u32 = 0x0dea;
+pub const XK_Thai_maichattawa: u32 = 0x0deb;
+pub const XK_Thai_thanthakhat: u32 = 0x0dec;
+pub const XK_Thai_nikhahit: u32 = 0x0ded;
+pub const XK_Thai_leksun: u32 = 0x0df0;
+pub const XK_Thai_leknung: u32 = 0x0df1;
+pub const XK_Thai_leksong: u32 = 0x0df2;
+pub const XK_Thai_leksam: u32 = 0x0df3;
+pub const XK_Thai_leksi: u32 = 0x0df4;
+pub const XK_Thai_lekha: u32 = 0x0df5;
+pub const XK_Thai_lekhok: u32 = 0x0df6;
+pub const XK_Thai_lekchet: u32 = 0x0df7;
+pub const XK_Thai_lekpaet: u32 = 0x0df8;
+pub const XK_Thai_lekkao: u32 = 0x0df9;
+pub const XK_Hangul: u32 = 0xff31;
+pub const XK_Hangul_Start: u32 = 0xff32;
+pub const XK_Hangul_End: u32 = 0xff33;
+pub const XK_Hangul_Hanja: u32 = 0xff34;
+pub const XK_Hangul_Jamo: u32 = 0xff35;
+pub const XK_Hangul_Romaja: u32 = 0xff36;
+pub const XK_Hangul_Codeinput: u32 = 0xff37;
+pub const XK_Hangul_Jeonja: u32 = 0xff38;
+pub const XK_Hangul_Banja: u32 = 0xff39;
+pub const XK_Hangul_PreHanja: u32 = 0xff3a;
+pub const XK_Hangul_PostHanja: u32 = 0xff3b;
+pub const XK_Hangul_SingleCandidate: u32 = 0xff3c;
+pub const XK_Hangul_MultipleCandidate: u32 = 0xff3d;
+pub const XK_Hangul_PreviousCandidate: u32 = 0xff3e;
+pub const XK_Hangul_Special: u32 = 0xff3f;
+pub const XK_Hangul_switch: u32 = 0xff7e;
+pub const XK_Hangul_Kiyeog: u32 = 0x0ea1;
+pub const XK_Hangul_SsangKiyeog: u32 = 0x0ea2;
+pub const XK_Hangul_KiyeogSios: u32 = 0x0ea3;
+pub const XK_Hangul_Nieun: u32 = 0x0ea4;
+pub const XK_Hangul_NieunJieuj: u32 = 0x0ea5;
+pub const XK_Hangul_NieunHieuh: u32 = 0x0ea6;
+pub const XK_Hangul_Dikeud: u32 = 0x0ea7;
+pub const XK_Hangul_SsangDikeud: u32 = 0x0ea8;
+pub const XK_Hangul_Rieul: u32 = 0x0ea9;
+pub const XK_Hangul_RieulKiyeog: u32 = 0x0eaa;
+pub const XK_Hangul_RieulMieum: u32 = 0x0eab;
+pub const XK_Hangul_RieulPieub: u32 = 0x0eac;
+pub const XK_Hangul_RieulSios: u32 = 0x0ead;
+pub const XK_Hangul_RieulTieut: u32 = 0x0eae;
+pub const XK_Hangul_RieulPhieuf: u32 = 0x0eaf;
+pub const XK_Hangul_RieulHieuh: u32 = 0x0eb0;
+pub const XK_Hangul_Mieum: u32 = 0x0eb1;
+pub const XK_Hangul_Pieub: u32 = 0x0eb2;
+pub const XK_Hangul_SsangPieub: u32 = 0x0eb3;
+pub const XK_Hangul_PieubSios: u32 = 0x0eb4;
+pub const XK_Hangul_Sios: u32 = 0x0eb5;
+pub const XK_Hangul_SsangSios: u32 = 0x0eb6;
+pub const XK_Hangul_Ieung: u32 = 0x0eb7;
+pub const XK_Hangul_Jieuj: u32 = 0x0eb8;
+pub const XK_Hangul_SsangJieuj: u32 = 0x0eb9;
+pub const XK_Hangul_Cieuc: u32 = 0x0eba;
+pub const XK_Hangul_Khieuq: u32 = 0x0ebb;
+pub const XK_Hangul_Tieut: u32 = 0x0ebc;
+pub const XK_Hangul_Phieuf: u32 = 0x0ebd;
+pub const XK_Hangul_Hieuh: u32 = 0x0ebe;
+pub const XK_Hangul_A: u32 = 0x0ebf;
+pub const XK_Hangul_AE: u32 = 0x0ec0;
+pub const XK_Hangul_YA: u32 = 0x0ec1;
+pub const XK_Hangul_YAE: u32 = 0x0ec2;
+pub const XK_Hangul_EO: u32 = 0x0ec3;
+pub const XK_Hangul_E: u32 = 0x0ec4;
+pub const XK_Hangul_YEO: u32 = 0x0ec5;
+pub const XK_Hangul_YE: u32 = 0x0ec6;
+pub const XK_Hangul_O: u32 = 0x0ec7;
+pub const XK_Hangul_WA: u32 = 0x0ec8;
+pub const XK_Hangul_WAE: u32 = 0x0ec9;
+pub const XK_Hangul_OE: u32 = 0x0eca;
+pub const XK_Hangul_YO: u32 = 0x0ecb;
+pub const XK_Hangul_U: u32 = 0x0ecc;
+pub const XK_Hangul_WEO: u32 = 0x0ecd;
+pub const XK_Hangul_WE: u32 = 0x0ece;
+pub const XK_Hangul_WI: u32 = 0x0ecf;
+pub const XK_Hangul_YU: u32 = 0x0ed0;
+pub const XK_Hangul_EU: u32 = 0x0ed1;
+pub const XK_Hangul_YI: u32 = 0x0ed2;
+pub const XK_Hangul_I: u32 = 0x0ed3;
+pub const XK_Hangul_J_Kiyeog: u32 = 0x0ed4;
+pub const XK_Hangul_J_SsangKiyeog: u32 = 0x0ed5;
+pub const XK_Hangul_J_KiyeogSios: u32 = 0x0ed6;
+pub const XK_Hangul_J_Nieun: u32 = 0x0ed7;
+pub const XK_Hangul_J_NieunJieuj: u32 = 0x0ed8;
+pub const XK_Hangul_J_NieunHieuh: u32 = 0x0ed9;
+pub const XK_Hangul_J_Dikeud: u32 = 0x0eda;
+pub const XK_Hangul_J_Rieul: u32 = 0x0edb;
+pub const XK_Hangul_J_RieulKiyeog: u32 = 0x0edc;
+pub const XK_Hangul_J_RieulMieum: u32 = 0x0edd;
+pub const XK_Hangul_J_RieulPieub: u32 = 0x0ede;
+pub const XK_Hangul_J_RieulSios: u32 = 0x0edf;
+pub const XK_Hangul_J_RieulTieut: u32 = 0x0ee0;
+pub const XK_Hangul_J_RieulPhieuf: u32 = 0x0ee1;
+pub const XK_Hangul_J_RieulHieuh: u32 = 0x0ee2;
+pub const XK_Hangul_J_Mieum: u32 = 0x0ee3;
+pub const XK_Hangul_J_Pieub: u32 = 0x0ee4;
+pub const XK_Hangul_J_PieubSios: u32 = 0x0ee5;
+pub const XK_Hangul_J_Sios: u32 = 0x0ee6;
+pub const XK_Hangul_J_SsangSios: u32 = 0x0ee7;
+pub const XK_Hangul_J_Ieung: u32 = 0x0ee8;
+pub const XK_Hangul_J_Jieuj: u32 = 0x0ee9;
+pub const XK_Hangul_J_Cieuc: u32 = 0x0eea;
+pub const XK_Hangul_J_Khieuq: u32 = 0x0eeb;
+pub const XK_Hangul_J_Tieut: u32 = 0x0eec;
+pub const XK_Hangul_J_Phieuf: u32 = 0x0eed;
+pub const XK_Hangul_J_Hieuh: u32 = 0x0eee;
+pub const XK_Hangul_RieulYeorinHieuh: u32 = 0x0eef;
+pub const XK_Hangul_SunkyeongeumMieum: u32 = 0x0ef0;
+pub const XK_Hangul_SunkyeongeumPieub: u32 = 0x0ef1;
+pub const XK_Hangul_PanSios: u32 = 0x0ef2;
+pub const XK_Hangul_KkogjiDalrinIeung: u32 = 0x0ef3;
+pub const XK_Hangul_SunkyeongeumPhieuf: u32 = 0x0ef4;
+pub const XK_Hangul_YeorinHieuh: u32 = 0x0ef5;
+pub const XK_Hangul_AraeA: u32 = 0x0ef6;
+pub const XK_Hangul_AraeAE: u32 = 0x0ef7;
+pub const XK_Hangul_J_PanSios: u32 = 0x0ef8;
+pub const XK_Hangul_J_KkogjiDalrinIeung: u32 = 0x0ef9;
+pub const XK_Hangul_J_YeorinHieuh: u32 = 0x0efa;
+pub const XK_Korean_Won: u32 = 0x0eff;
+pub const XK_Armenian_ligature_ew: u32 = 0x1000587;
+pub const XK_Armenian_full_stop: u32 = 0x1000589;
+pub const XK_Armenian_verjaket: u32 = 0x1000589;
+pub const XK_Armenian_separation_mark: u32 = 0x100055d;
+pub const XK_Armenian_but: u32 = 0x100055d;
+pub const XK_Armenian_hyphen: u32 = 0x100058a;
+pub const XK_Armenian_yentamna: u32 = 0x100058a;
+pub const XK_Armenian_exclam: u32 = 0x100055c;
+pub const XK_Armenian_amanak: u32 = 0x100055c;
+pub const XK_Armenian_accent: u32 = 0x100055b;
+pub const XK_Armenian_shesht: u32 = 0x100055b;
+pub const XK_Armenian_question: u32 = 0x100055e;
+pub const XK_Armenian_paruyk: u32 = 0x100055e;
+pub const XK_Armenian_AYB: u32 = 0x1000531;
+pub const XK_Armenian_ayb: u32 = 0x1000561;
+pub const XK_Armenian_BEN: u32 = 0x1000532;
+pub const XK_Armenian_ben: u32 = 0x1000562;
+pub const XK_Armenian_GIM: u32 = 0x1000533;
+pub const XK_Armenian_gim: u32 = 0x1000563;
+pub const XK_Armenian_DA: u32 = 0x1000534;
+pub const XK_Armenian_da: u32 = 0x1000564;
+pub const XK_Armenian_YECH: u32 = 0x1000535;
+pub const XK_Armenian_yech: u32 = 0x1000565;
+pub const XK_Armenian_ZA: u32 = 0x1000536;
+pub const XK_Armenian_za: u32 = 0x1000566;
+pub const XK_Armenian_E: u32 = 0x1000537;
+pub const XK_Armenian_e: u32 = 0x1000567;
+pub const XK_Armenian_AT: u32 = 0x1000538;
+pub const XK_Armenian_at: u32 = 0x1000568;
+pub const XK_Armenian_TO: u32 = 0x1000539;
+pub const XK_Armenian_to: u32 = 0x1000569;
+pub const XK_Armenian_ZHE: u32 = 0x100053a;
+pub const XK_Armenian_zhe: u32 = 0x100056a;
+pub const XK_Armenian_INI: u32 = 0x100053b;
+pub const XK_Armenian_ini: u32 = 0x100056b;
+pub const XK_Armenian_LYUN: u32 = 0x100053c;
+pub const XK_Armenian_lyun: u32 = 0x100056c;
+pub const XK_Armenian_KHE: u32 = 0x100053d;
+pub const XK_Armenian_khe: u32 = 0x100056d;
+pub const XK_Armenian_TSA: u32 = 0x100053e;
+pub const XK_Armenian_tsa: u32 = 0x100056e;
+pub const XK_Armenian_KEN: u32 = 0x100053f;
+pub const XK_Armenian_ken: u32 = 0x100056f;
+pub const XK_Armenian_HO: u32 = 0x1000540;
+pub const XK_Armenian_ho: u32 = 0x1000570;
+pub const XK_Armenian_DZA: u32 = 0x1000541;
+pub const XK_Armenian_dza: u32 = 0x1000571;
+pub const XK_Armenian_GHAT: u32 = 0x1000542;
+pub const XK_Armenian_ghat: u32 = 0x1000572;
+pub const XK_Armenian_TCHE: u32 = 0x1000543;
+pub const XK_Armenian_tche: u32 = 0x1000573;
+pub const XK_Armenian_MEN: u32 = 0x1000544;
+pub const XK_Armenian_men: u32 = 0x1000574;
+pub const XK_Armenian_HI: u32 = 0x1000545;
+pub const XK_Armenian_hi: u32 = 0x1000575;
+pub const XK_Armenian_NU: u32 = 0x1000546;
+pub const XK_Armenian_nu: u32 = 0x1000576;
+pub const XK_Armenian_SHA: u32 = 0x1000547;
+pub const XK_Armenian_sha: u32 = 0x1000577;
+pub const XK_Armenian_VO: u32 = 0x1000548;
+pub const XK_Armenian_vo: u32 = 0x1000578;
+pub const XK_Armenian_CHA: u32 = 0x1000549;
+pub const XK_Armenian_cha: u32 = 0x1000579;
+pub const XK_Armenian_PE: u32 = 0x100054a;
+pub const XK_Armenian_pe: u32 = 0x100057a;
+pub const XK_Armenian_JE: u32 = 0x100054b;
+pub const XK_Armenian_je: u32 = 0x100057b;
+pub const XK_Armenian_RA: u32 = 0x100054c;
+pub const XK_Armenian_ra: u32 = 0x100057c;
+pub const XK_Armenian_SE: u32 = 0x100054d;
+pub const XK_Armenian_se: u32 = 0x100057d;
+pub const XK_Armenian_VEV: u32 = 0x100054e;
+pub const XK_Armenian_vev: u32 = 0x100057e;
+pub const XK_Armenian_TYUN: u32 = 0x100054f;
+pub const XK_Armenian_tyun: u32 = 0x100057f;
+pub const XK_Armenian_RE: u32 = 0x1000550;
+pub const XK_Armenian_re: u32 = 0x1000580;
+pub const XK_Armenian_TSO: u32 = 0x1000551;
+pub const XK_Armenian_tso: u32 = 0x1000581;
+pub const XK_Armenian_VYUN: u32 = 0x1000552;
+pub const XK_Armenian_vyun: u32 = 0x1000582;
+pub const XK_Armenian_PYUR: u32 = 0x1000553;
+pub const XK_Armenian_pyur: u32 = 0x1000583;
+pub const XK_Armenian_KE: u32 = 0x1000554;
+pub const XK_Armenian_ke: u32 = 0x1000584;
+pub const XK_Armenian_O: u32 = 0x1000555;
+pub const XK_Armenian_o: u32 = 0x1000585;
+pub const XK_Armenian_FE: u32 = 0x1000556;
+pub const XK_Armenian_fe: u32 = 0x1000586;
+pub const XK_Armenian_apostrophe: u32 = 0x100055a;
+pub const XK_Georgian_an: u32 = 0x10010d0;
+pub const XK_Georgian_ban: u32 = 0x10010d1;
+pub const XK_Georgian_gan: u32 = 0x10010d2;
+pub const XK_Georgian_don: u32 = 0x10010d3;
+pub const XK_Georgian_en: u32 = 0x10010d4;
+pub const XK_Georgian_vin: u32 = 0x10010d5;
+pub const XK_Georgian_zen: u32 = 0x10010d6;
+pub const XK_Georgian_tan: u32 = 0x10010d7;
+pub const XK_Georgian_in: u32 = 0x10010d8;
+pub const XK_Georgian_kan: u32 = 0x10010d9;
+pub const XK_Georgian_las: u32 = 0x10010da;
+pub const XK_Georgian_man: u32 = 0x10010db;
+pub const XK_Georgian_nar: u32 = 0x10010dc;
+pub const XK_Georgian_on: u32 = 0x10010dd;
+pub const XK_Georgian_par: u32 = 0x10010de;
+pub const XK_Georgian_zhar: u32 = 0x10010df;
+pub const XK_Georgian_rae: u32 = 0x10010e0;
+pub const XK_Georgian_san: u32 = 0x10010e1;
+pub const XK_Georgian_tar: u32 = 0x10010e2;
+pub const XK_Georgian_un: u32 = 0x10010e3;
+pub const XK_Georgian_phar: u32 = 0x10010e4;
+pub const XK_Georgian_khar: u32 = 0x10010e5;
+pub const XK_Georgian_ghan: u32 = 0x10010e6;
+pub const XK_Georgian_qar: u32 = 0x10010e7;
+pub const XK_Georgian_shin: u32 = 0x10010e8;
+pub const XK_Georgian_chin: u32 = 0x10010e9;
+pub const XK_Georgian_can: u32 = 0x10010ea;
+pub const XK_Georgian_jil: u32 = 0x10010eb;
+pub const XK_Georgian_cil: u32 = 0x10010ec;
+pub const XK_Georgian_char: u32 = 0x10010ed;
+pub const XK_Georgian_xan: u32 = 0x10010ee;
+pub const XK_Georgian_jhan: u32 = 0x10010ef;
+pub const XK_Georgian_hae: u32 = 0x10010f0;
+pub const XK_Georgian_he: u32 = 0x10010f1;
+pub const XK_Georgian_hie: u32 = 0x10010f2;
+pub const XK_Georgian_we: u32 = 0x10010f3;
+pub const XK_Georgian_har: u32 = 0x10010f4;
+pub const XK_Georgian_hoe: u32 = 0x10010f5;
+pub const XK_Georgian_fi: u32 = 0x10010f6;
+pub const XK_Xabovedot: u32 = 0x1001e8a;
+pub const XK_Ibreve: u32 = 0x100012c;
+pub const XK_Zstroke: u32 = 0x10001b5;
+pub const XK_Gcaron: u32 = 0x10001e6;
+pub const XK_Ocaron: u32 = 0x10001d1;
+pub const XK_Obarred: u32 = 0x100019f;
+pub const XK_xabovedot: u32 = 0x1001e8b;
+pub const XK_ibreve: u32 = 0x100012d;
+pub const XK_zstroke: u32 = 0x10001b6;
+pub const XK_gcaron: u32 = 0x10001e7;
+pub const XK_ocaron: u32 = 0x10001d2;
+pub const XK_obarred: u32 = 0x1000275;
+pub const XK_SCHWA: u32 = 0x100018f;
+pub const XK_schwa: u32 = 0x1000259;
+pub const XK_EZH: u32 = 0x10001b7;
+pub const XK_ezh: u32 = 0x1000292;
+pub const XK_Lbelowdot: u32 = 0x1001e36;
+pub const XK_lbelowdot: u32 = 0x1001e37;
+pub const XK_Abelowdot: u32 = 0x1001ea0;
+pub const XK_abelowdot: u32 = 0x1001ea1;
+pub const XK_Ahook: u32 = 0x1001ea2;
+pub const XK_ahook: u32 = 0x1001ea3;
+pub const XK_Acircumflexacute: u32 = 0x1001ea4;
+pub const XK_acircumflexacute: u32 = 0x1001ea5;
+pub const XK_Acircumflexgrave: u32 = 0x1001ea6;
+pub const XK_acircumflexgrave: u32 = 0x1001ea7;
+pub const XK_Acircumflexhook: u32 = 0x1001ea8;
+pub const XK_acircumflexhook: u32 = 0x1001ea9;
+pub const XK_Acircumflextilde: u32 = 0x1001eaa;
+pub const XK_acircumflextilde: u32 = 0x1001eab;
+pub const XK_Acircumflexbelowdot: u32 = 0x1001eac;
+pub const XK_acircumflexbelowdot: u32 = 0x1001ead;
+pub const XK_Abreveacute: u32 = 0x1001eae;
+pub const XK_abreveacute: u32 = 0x1001eaf;
+pub const XK_Abrevegrave: u32 = 0x1001eb0;
+pub const XK_abrevegrave: u32 = 0x1001eb1;
+pub const XK_Abrevehook: u32 = 0x1001eb2;
+pub const XK_abrevehook: u32 = 0x1001eb3;
+pub const XK_Abrevetilde: u32 = 0x1001eb4;
+pub const XK_abrevetilde: u32 = 0x1001eb5;
+pub const XK_Abrevebelowdot: u32 = 0x1001eb6;
+pub const XK_abrevebelowdot: u32 = 0x1001eb7;
+pub const XK_Ebelowdot: u32 = 0x1001eb8;
+pub const XK_ebelowdot: u32 = 0x1001eb9;
+pub const XK_Ehook: u32 = 0x1001eba;
+pub const XK_ehook: u32 = 0x1001ebb;
+pub const XK_Etilde: u32 = 0x1001ebc;
+pub const XK_etilde: u32 = 0x1001ebd;
+pub const XK_Ecircumflexacute: u32 = 0x1001ebe;
+pub const XK_ecircumflexacute: u32 = 0x1001ebf;
+pub const XK_Ecircumflexgrave: u32 = 0x1001ec0;
+pub const XK_ecircumflexgrave: u32 = 0x1001ec1;
+pub const XK_Ecircumflexhook: u32 = 0x1001ec2;
+pub const XK_ecircumflexhook: u32 = 0x1001ec3;
+pub const XK_Ecircumflextilde: u32 = 0x1001ec4;
+pub const XK_ecircumflextilde: u32 = 0x1001ec5;
+pub const XK_Ecircumflexbelowdot: u32 = 0x1001ec6;
+pub const XK_ecircumflexbelowdot: u32 = 0x1001ec7;
+pub const XK_Ihook: u32 = 0x1001ec8;
+pub const XK_ihook: u32 = 0x1001ec9;
+pub const XK_Ibelowdot: u32 = 0x1001eca;
+pub const XK_ibelowdot: u32 = 0x1001ecb;
+pub const XK_Obelowdot: u32 = 0x1001ecc;
+pub const XK_obelowdot: u32 = 0x1001ecd;
+pub const XK_Ohook: u32 = 0x1001ece;
+pub const XK_ohook: u32 = 0x1001ecf;
+pub const XK_Ocircumflexacute: u32 = 0x1001ed0;
+pub const XK_ocircumflexacute: u32 = 0x1001ed1;
+pub const XK_Ocircumflexgrave: u32 = 0x1001ed2;
+pub const XK_ocircumflexgrave: u32 = 0x1001ed3;
+pub const XK_Ocircumflexhook: u32 = 0x1001ed4;
+pub const XK_ocircumflexhook: u32 = 0x1001ed5;
+pub const XK_Ocircumflextilde: u32 = 0x1001ed6;
+pub const XK_ocircumflextilde: u32 = 0x1001ed7;
+pub const XK_Ocircumflexbelowdot: u32 = 0x1001ed8;
+pub const XK_ocircumflexbelowdot: u32 = 0x1001ed9;
+pub const XK_Ohornacute: u32 = 0x1001eda;
+pub const XK_ohornacute: u32 = 0x1001edb;
+pub const XK_Ohorngrave: u32 = 0x1001edc;
+pub const XK_ohorngrave: u32 = 0x1001edd;
+pub const XK_Ohornhook: u32 = 0x1001ede;
+pub const XK_ohornhook: u32 = 0x1001edf;
+pub const XK_Ohorntilde: u32 = 0x1001ee0;
+pub const XK_ohorntilde: u32 = 0x1001ee1;
+pub const XK_Ohornbelowdot: u32 = 0x1001ee2;
+pub const XK_ohornbelowdot: u32 = 0x1001ee3;
+pub const XK_Ubelowdot: u32 = 0x1001ee4;
+pub const XK_ubelowdot: u32 = 0x1001ee5;
+pub const XK_Uhook: u32 = 0x1001ee6;
+pub const XK_uhook: u32 = 0x1001ee7;
+pub const XK_Uhornacute: u32 = 0x1001ee8;
+pub const XK_uhornacute: u32 = 0x1001ee9;
+pub const XK_Uhorngrave: u32 = 0x1001eea;
+pub const XK_uhorngrave: u32 = 0x1001eeb;
+pub const XK_Uhornhook: u32 = 0x1001eec;
+pub const XK_uhornhook: u32 = 0x1001eed;
+pub const XK_Uhorntilde: u32 = 0x1001eee;
+pub const XK_uhorntilde: u32 = 0x1001eef;
+pub const XK_Uhornbelowdot: u32 = 0x1001ef0;
+pub const XK_uhornbelowdot: u32 = 0x1001ef1;
+pub const XK_Ybelowdot: u32 = 0x1001ef4;
+pub const XK_ybelowdot: u32 = 0x1001ef5;
+pub const XK_Yhook: u32 = 0x1001ef6;
+pub const XK_yhook: u32 = 0x1001ef7;
+pub const XK_Ytilde: u32 = 0x1001ef8;
+pub const XK_ytilde: u32 = 0x1001ef9;
+pub const XK_Ohorn: u32 = 0x10001a0;
+pub const XK_ohorn: u32 = 0x10001a1;
+pub const XK_Uhorn: u32 = 0x10001af;
+pub const XK_uhorn: u32 = 0x10001b0;
+pub const XK_combining_tilde: u32 = 0x1000303;
+pub const XK_combining_grave: u32 = 0x1000300;
+pub const XK_combining_acute: u32 = 0x1000301;
+pub const XK_combining_hook: u32 = 0x1000309;
+pub const XK_combining_belowdot: u32 = 0x1000323;
+pub const XK_EcuSign: u32 = 0x10020a0;
+pub const XK_ColonSign: u32 = 0x10020a1;
+pub const XK_CruzeiroSign: u32 = 0x10020a2;
+pub const XK_FFrancSign: u32 = 0x10020a3;
+pub const XK_LiraSign: u32 = 0x10020a4;
+pub const XK_MillSign: u32 = 0x10020a5;
+pub const XK_NairaSign: u32 = 0x10020a6;
+pub const XK_PesetaSign: u32 = 0x10020a7;
+pub const XK_RupeeSign: u32 = 0x10020a8;
+pub const XK_WonSign: u32 = 0x10020a9;
+pub const XK_NewSheqelSign: u32 = 0x10020aa;
+pub const XK_DongSign: u32 = 0x10020ab;
+pub const XK_EuroSign: u32 = 0x20ac;
+pub const XK_zerosuperior: u32 = 0x1002070;
+pub const XK_foursuperior: u32 = 0x1002074;
+pub const XK_fivesuperior: u32 = 0x1002075;
+pub const XK_sixsuperior: u32 = 0x1002076;
+pub const XK_sevensuperior: u32 = 0x1002077;
+pub const XK_eightsuperior: u32 = 0x1002078;
+pub const XK_ninesuperior: u32 = 0x1002079;
+pub const XK_zerosubscript: u32 = 0x1002080;
+pub const XK_onesubscript: u32 = 0x1002081;
+pub const XK_twosubscript: u32 = 0x1002082;
+pub const XK_threesubscript: u32 = 0x1002083;
+pub const XK_foursubscript: u32 = 0x1002084;
+pub const XK_fivesubscript: u32 = 0x1002085;
+pub const XK_sixsubscript: u32 = 0x1002086;
+pub const XK_sevensubscript: u32 = 0x1002087;
+pub const XK_eightsubscript: u32 = 0x1002088;
+pub const XK_ninesubscript: u32 = 0x1002089;
+pub const XK_partdifferential: u32 = 0x1002202;
+pub const XK_emptyset: u32 = 0x1002205;
+pub const XK_elementof: u32 = 0x1002208;
+pub const XK_notelementof: u32 = 0x1002209;
+pub const XK_containsas: u32 = 0x100220b;
+pub const XK_squareroot: u32 = 0x100221a;
+pub const XK_cuberoot: u32 = 0x100221b;
+pub const XK_fourthroot: u32 = 0x100221c;
+pub const XK_dintegral: u32 = 0x100222c;
+pub const XK_tintegral: u32 = 0x100222d;
+pub const XK_because: u32 = 0x1002235;
+pub const XK_approxeq: u32 = 0x1002248;
+pub const XK_notapproxeq: u32 = 0x1002247;
+pub const XK_notidentical: u32 = 0x1002262;
+pub const XK_stricteq: u32 = 0x1002263;
+pub const XK_braille_dot_1: u32 = 0xfff1;
+pub const XK_braille_dot_2: u32 = 0xfff2;
+pub const XK_braille_dot_3: u32 = 0xfff3;
+pub const XK_braille_dot_4: u32 = 0xfff4;
+pub const XK_braille_dot_5: u32 = 0xfff5;
+pub const XK_braille_dot_6: u32 = 0xfff6;
+pub const XK_braille_dot_7: u32 = 0xfff7;
+pub const XK_braille_dot_8: u32 = 0xfff8;
+pub const XK_braille_dot_9: u32 = 0xfff9;
+pub const XK_braille_dot_10: u32 = 0xfffa;
+pub const XK_braille_blank: u32 = 0x1002800;
+pub const XK_braille_dots_1: u32 = 0x1002801;
+pub const XK_braille_dots_2: u32 = 0x1002802;
+pub const XK_braille_dots_12: u32 = 0x1002803;
+pub const XK_braille_dots_3: u32 = 0x1002804;
+pub const XK_braille_dots_13: u32 = 0x1002805;
+pub const XK_braille_dots_23: u32 = 0x1002806;
+pub const XK_braille_dots_123: u32 = 0x1002807;
+pub const XK_braille_dots_4: u32 = 0x1002808;
+pub const XK_braille_dots_14: u32 = 0x1002809;
+pub const XK_braille_dots_24: u32 = 0x100280a;
+pub const XK_braille_dots_124: u32 = 0x100280b;
+pub const XK_braille_dots_34: u32 = 0x100280c;
+pub const XK_braille_dots_134: u32 = 0x100280d;
+pub const XK_braille_dots_234: u32 = 0x100280e;
+pub const XK_braille_dots_1234: u32 = 0x100280f;
+pub const XK_braille_dots_5: u32 = 0x1002810;
+pub const XK_braille_dots_15: u32 = 0x1002811;
+pub const XK_braille_dots_25: u32 = 0x1002812;
+pub const XK_braille_dots_125: u32 = 0x1002813;
+pub const XK_braille_dots_35: u32 = 0x1002814;
+pub const XK_braille_dots_135: u32 = 0x1002815;
+pub const XK_braille_dots_235: u32 = 0x1002816;
+pub const XK_braille_dots_1235: u32 = 0x1002817;
+pub const XK_braille_dots_45: u32 = 0x1002818;
+pub const XK_braille_dots_145: u32 = 0x1002819;
+pub const XK_braille_dots_245: u32 = 0x100281a;
+pub const XK_braille_dots_1245: u32 = 0x100281b;
+pub const XK_braille_dots_345: u32 = 0x100281c;
+pub const XK_braille_dots_1345: u32 = 0x100281d;
+pub const XK_braille_dots_2345: u32 = 0x100281e;
+pub const XK_braille_dots_12345: u32 = 0x100281f;
+pub const XK_braille_dots_6: u32 = 0x1002820;
+pub const XK_braille_dots_16: u32 = 0x1002821;
+pub const XK_braille_dots_26: u32 = 0x1002822;
+pub const XK_braille_dots_126: u32 = 0x1002823;
+pub const XK_braille_dots_36: u32 = 0x1002824;
+pub const XK_braille_dots_136: u32 = 0x1002825;
+pub const XK_braille_dots_236: u32 = 0x1002826;
+pub const XK_braille_dots_1236: u32 = 0x1002827;
+pub const XK_braille_dots_46: u32 = 0x1002828;
+pub const XK_braille_dots_146: u32 = 0x1002829;
+pub const XK_braille_dots_246: u32 = 0x100282a;
+pub const XK_braille_dots_1246: u32 = 0x100282b;
+pub const XK_braille_dots_346: u32 = 0x100282c;
+pub const XK_braille_dots_1346: u32 = 0x100282d;
+pub const XK_braille_dots_2346: u32 = 0x100282e;
+pub const XK_braille_dots_12346: u32 = 0x100282f;
+pub const XK_braille_dots_56: u32 = 0x1002830;
+pub const XK_braille_dots_156: u32 = 0x1002831;
+pub const XK_braille_dots_256: u32 = 0x1002832;
+pub const XK_braille_dots_1256: u32 = 0x1002833;
+pub const XK_braille_dots_356: u32 = 0x1002834;
+pub const XK_braille_dots_1356: u32 = 0x1002835;
+pub const XK_braille_dots_2356: u32 = 0x1002836;
+pub const XK_braille_dots_12356: u32 = 0x1002837;
+pub const XK_braille_dots_456: u32 = 0x1002838;
+pub const XK_braille_dots_1456: u32 = 0x1002839;
+pub const XK_braille_dots_2456: u32 = 0x100283a;
+pub const XK_braille_dots_12456: u32 = 0x100283b;
+pub const XK_braille_dots_3456: u32 = 0x100283c;
+pub const XK_braille_dots_13456: u32 = 0x100283d;
+pub const XK_braille_dots_23456: u32 = 0x100283e;
+pub const XK_braille_dots_123456: u32 = 0x100283f;
+pub const XK_braille_dots_7: u32 = 0x1002840;
+pub const XK_braille_dots_17: u32 = 0x1002841;
+pub const XK_braille_dots_27: u32 = 0x1002842;
+pub const XK_braille_dots_127: u32 = 0x1002843;
+pub const XK_braille_dots_37: u32 = 0x1002844;
+pub const XK_braille_dots_137: u32 = 0x1002845;
+pub const XK_braille_dots_237: u32 = 0x1002846;
+pub const XK_braille_dots_1237: u32 = 0x1002847;
+pub const XK_braille_dots_47: u32 = 0x1002848;
+pub const XK_braille_dots_147: u32 = 0x1002849;
+pub const XK_braille_dots_247: u32 = 0x100284a;
+pub const XK_braille_dots_1247: u32 = 0x100284b;
+pub const XK_braille_dots_347: u32 = 0x100284c;
+pub const XK_braille_dots_1347: u32 = 0x100284d;
+pub const XK_braille_dots_2347: u32 = 0x100284e;
+pub const XK_braille_dots_12347: u32 = 0x100284f;
+pub const XK_braille_dots_57: u32 = 0x1002850;
+pub const XK_braille_dots_157: u32 = 0x1002851;
+pub const XK_braille_dots_257: u32 = 0x1002852;
+pub const XK_braille_dots_1257: u32 = 0x1002853;
+pub const XK_braille_dots_357: u32 = 0x1002854;
+pub const XK_braille_dots_1357: u32 = 0x1002855;
+pub const XK_braille_dots_2357: u32 = 0x1002856;
+pub const XK_braille_dots_12357: u32 = 0x1002857;
+pub const XK_braille_dots_457: u32 = 0x1002858;
+pub const XK_braille_dots_1457: u32 = 0x1002859;
+pub const XK_braille_dots_2457: u32 = 0x100285a;
+pub const XK_braille_dots_12457: u32 = 0x100285b;
+pub const XK_braille_dots_3457: u32 = 0x100285c;
+pub const XK_braille_dots_13457: u32 = 0x100285d;
+pub const XK_braille_dots_23457: u32 = 0x100285e;
+pub const XK_braille_dots_123457: u32 = 0x100285f;
+pub const XK_braille_dots_67: u32 = 0x1002860;
+pub const XK_braille_dots_167: u32 = 0x1002861;
+pub const XK_braille_dots_267: u32 = 0x1002862;
+pub const XK_braille_dots_1267: u32 = 0x1002863;
+pub const XK_braille_dots_367: u32 = 0x1002864;
+pub const XK_braille_dots_1367: u32 = 0x1002865;
+pub const XK_braille_dots_2367: u32 = 0x1002866;
+pub const XK_braille_dots_12367: u32 = 0x1002867;
+pub const XK_braille_dots_467: u32 = 0x1002868;
+pub const XK_braille_dots_1467: u32 = 0x1002869;
+pub const XK_braille_dots_2467: u32 = 0x100286a;
+pub const XK_braille_dots_12467: u32 = 0x100286b;
+pub const XK_braille_dots_3467: u32 = 0x100286c;
+pub const XK_braille_dots_13467: u32 = 0x100286d;
+pub const XK_braille_dots_23467: u32 = 0x100286e;
+pub const XK_braille_dots_123467: u32 = 0x100286f;
+pub const XK_braille_dots_567: u32 = 0x1002870;
+pub const XK_braille_dots_1567: u32 = 0x1002871;
+pub const XK_braille_dots_2567: u32 = 0x1002872;
+pub const XK_braille_dots_12567: u32 = 0x1002873;
+pub const XK_braille_dots_3567: u32 = 0x1002874;
+pub const XK_braille_dots_13567: u32 = 0x1002875;
+pub const XK_braille_dots_23567: u32 = 0x1002876;
+pub const XK_braille_dots_123567: u32 = 0x1002877;
+pub const XK_braille_dots_4567: u32 = 0x1002878;
+pub const XK_braille_dots_14567: u32 = 0x1002879;
+pub const XK_braille_dots_24567: u32 = 0x100287a;
+pub const XK_braille_dots_124567: u32 = 0x100287b;
+pub const XK_braille_dots_34567: u32 = 0x100287c;
+pub const XK_braille_dots_134567: u32 = 0x100287d;
+pub const XK_braille_dots_234567: u32 = 0x100287e;
+pub const XK_braille_dots_1234567: u32 = 0x100287f;
+pub const XK_braille_dots_8: u32 = 0x1002880;
+pub const XK_braille_dots_18: u32 = 0x1002881;
+pub const XK_braille_dots_28: u32 = 0x1002882;
+pub const XK_braille_dots_128: u32 = 0x1002883;
+pub const XK_braille_dots_38: u32 = 0x1002884;
+pub const XK_braille_dots_138: u32 = 0x1002885;
+pub const XK_braille_dots_238: u32 = 0x1002886;
+pub const XK_braille_dots_1238: u32 = 0x1002887;
+pub const XK_braille_dots_48: u32 = 0x1002888;
+pub const XK_braille_dots_148: u32 = 0x1002889;
+pub const XK_braille_dots_248: u32 = 0x100288a;
+pub const XK_braille_dots_1248: u32 = 0x100288b;
+pub const XK_braille_dots_348: u32 = 0x100288c;
+pub const XK_braille_dots_1348: u32 = 0x100288d;
+pub const XK_braille_dots_2348: u32 = 0x100288e;
+pub const XK_braille_dots_12348: u32 = 0x100288f;
+pub const XK_braille_dots_58: u32 = 0x1002890;
+pub const XK_braille_dots_158: u32 = 0x1002891;
+pub const XK_braille_dots_258: u32 = 0x1002892;
+pub const XK_braille_dots_1258: u32 = 0x1002893;
+pub const XK_braille_dots_358: u32 = 0x1002894;
+pub const XK_braille_dots_1358: u32 = 0x1002895;
+pub const XK_braille_dots_2358: u32 = 0x1002896;
+pub const XK_braille_dots_12358: u32 = 0x1002897;
+pub const XK_braille_dots_458: u32 = 0x1002898;
+pub const XK_braille_dots_1458: u32 = 0x1002899;
+pub const XK_braille_dots_2458: u32 = 0x100289a;
+pub const XK_braille_dots_12458: u32 = 0x100289b;
+pub const XK_braille_dots_3458: u32 = 0x100289c;
+pub const XK_braille_dots_13458: u32 = 0x100289d;
+pub const XK_braille_dots_23458: u32 = 0x100289e;
+pub const XK_braille_dots_123458: u32 = 0x100289f;
+pub const XK_braille_dots_68: u32 = 0x10028a0;
+pub const XK_braille_dots_168: u32 = 0x10028a1;
+pub const XK_braille_dots_268: u32 = 0x10028a2;
+pub const XK_braille_dots_1268: u32 = 0x10028a3;
+pub const XK_braille_dots_368: u32 = 0x10028a4;
+pub const XK_braille_dots_1368: u32 = 0x10028a5;
+pub const XK_braille_dots_2368: u32 = 0x10028a6;
+pub const XK_braille_dots_12368: u32 = 0x10028a7;
+pub const XK_braille_dots_468: u32 = 0x10028a8;
+pub const XK_braille_dots_1468: u32 = 0x10028a9;
+pub const XK_braille_dots_2468: u32 = 0x10028aa;
+pub const XK_braille_dots_12468: u32 = 0x10028ab;
+pub const XK_braille_dots_3468: u32 = 0x10028ac;
+pub const XK_braille_dots_13468: u32 = 0x10028ad;
+pub const XK_braille_dots_23468: u32 = 0x10028ae;
+pub const XK_braille_dots_123468: u32 = 0x10028af;
+pub const XK_braille_dots_568: u32 = 0x10028b0;
+pub const XK_braille_dots_1568: u32 = 0x10028b1;
+pub const XK_braille_dots_2568: u32 = 0x10028b2;
+pub const XK_braille_dots_12568: u32 = 0x10028b3;
+pub const XK_braille_dots_3568: u32 = 0x10028b4;
+pub const XK_braille_dots_13568: u32 = 0x10028b5;
+pub const XK_braille_dots_23568: u32 = 0x10028b6;
+pub const XK_braille_dots_123568: u32 = 0x10028b7;
+pub const XK_braille_dots_4568: u32 = 0x10028b8;
+pub const XK_braille_dots_14568: u32 = 0x10028b9;
+pub const XK_braille_dots_24568: u32 = 0x10028ba;
+pub const XK_braille_dots_124568: u32 = 0x10028bb;
+pub const XK_braille_dots_34568: u32 = 0x10028bc;
+pub const XK_braille_dots_134568: u32 = 0x10028bd;
+pub const XK_braille_dots_234568: u32 = 0x10028be;
+pub const XK_braille_dots_1234568: u32 = 0x10028bf;
+pub const XK_braille_dots_78: u32 = 0x10028c0;
+pub const XK_braille_dots_178: u32 = 0x10028c1;
+pub const XK_braille_dots_278: u32 = 0x10028c2;
+pub const XK_braille_dots_1278: u32 = 0x10028c3;
+pub const XK_braille_dots_378: u32 = 0x10028c4;
+pub const XK_braille_dots_1378: u32 = 0x10028c5;
+pub const XK_braille_dots_2378: u32 = 0x10028c6;
+pub const XK_braille_dots_12378: u32 = 0x10028c7;
+pub const XK_braille_dots_478: u32 = 0x10028c8;
+pub const XK_braille_dots_1478: u32 = 0x10028c9;
+pub const XK_braille_dots_2478: u32 = 0x10028ca;
+pub const XK_braille_dots_12478: u32 = 0x10028cb;
+pub const XK_braille_dots_3478: u32 = 0x10028cc;
+pub const XK_braille_dots_13478: u32 = 0x10028cd;
+pub const XK_braille_dots_23478: u32 = 0x10028ce;
+pub const XK_braille_dots_123478: u32 = 0x10028cf;
+pub const XK_braille_dots_578: u32 = 0x10028d0;
+pub const XK_braille_dots_1578: u32 = 0x10028d1;
+pub const XK_braille_dots_2578: u32 = 0x10028d2;
+pub const XK_braille_dots_12578: u32 = 0x10028d3;
+pub const XK_braille_dots_3578: u32 = 0x10028d4;
+pub const XK_braille_dots_13578: u32 = 0x10028d5;
+pub const XK_braille_dots_23578: u32 = 0x10028d6;
+pub const XK_braille_dots_123578: u32 = 0x10028d7;
+pub const XK_braille_dots_4578: u32 = 0x10028d8;
+pub const XK_braille_dots_14578: u32 = 0x10028d9;
+pub const XK_braille_dots_24578: u32 = 0x10028da;
+pub const XK_braille_dots_124578: u32 = 0x10028db;
+pub const XK_braille_dots_34578: u32 = 0x10028dc;
+pub const XK_braille_dots_134578: u32 = 0x10028dd;
+pub const XK_braille_dots_234578: u32 = 0x10028de;
+pub const XK_braille_dots_1234578: u32 = 0x10028df;
+pub const XK_braille_dots_678: u32 = 0x10028e0;
+pub const XK_braille_dots_1678: u32 = 0x10028e1;
+pub const XK_braille_dots_2678: u32 = 0x10028e2;
+pub const XK_braille_dots_12678: u32 = 0x10028e3;
+pub const XK_braille_dots_3678: u32 = 0x10028e4;
+pub const XK_braille_dots_13678: u32 = 0x10028e5;
+pub const XK_braille_dots_23678: u32 = 0x10028e6;
+pub const XK_braille_dots_123678: u32 = 0x10028e7;
+pub const XK_braille_dots_4678: u32 = 0x10028e8;
+pub const XK_braille_dots_14678: u32 = 0x10028e9;
+pub const XK_braille_dots_24678: u32 = 0x10028ea;
+pub const XK_braille_dots_124678: u32 = 0x10028eb;
+pub const XK_braille_dots_34678: u32 = 0x10028ec;
+pub const XK_braille_dots_134678: u32 = 0x10028ed;
+pub const XK_braille_dots_234678: u32 = 0x10028ee;
+pub const XK_braille_dots_1234678: u32 = 0x10028ef;
+pub const XK_braille_dots_5678: u32 = 0x10028f0;
+pub const XK_braille_dots_15678: u32 = 0x10028f1;
+pub const XK_braille_dots_25678: u32 = 0x10028f2;
+pub const XK_braille_dots_125678: u32 = 0x10028f3;
+pub const XK_braille_dots_35678: u32 = 0x10028f4;
+pub const XK_braille_dots_135678: u32 = 0x10028f5;
+pub const XK_braille_dots_235678: u32 = 0x10028f6;
+pub const XK_braille_dots_1235678: u32 = 0x10028f7;
+pub const XK_braille_dots_45678: u32 = 0x10028f8;
+pub const XK_braille_dots_145678: u32 = 0x10028f9;
+pub const XK_braille_dots_245678: u32 = 0x10028fa;
+pub const XK_braille_dots_1245678: u32 = 0x10028fb;
+pub const XK_braille_dots_345678: u32 = 0x10028fc;
+pub const XK_braille_dots_1345678: u32 = 0x10028fd;
+pub const XK_braille_dots_2345678: u32 = 0x10028fe;
+pub const XK_braille_dots_12345678: u32 = 0x10028ff;
+pub const XK_Sinh_ng: u32 = 0x1000d82;
+pub const XK_Sinh_h2: u32 = 0x1000d83;
+pub const XK_Sinh_a: u32 = 0x1000d85;
+pub const XK_Sinh_aa: u32 = 0x1000d86;
+pub const XK_Sinh_ae: u32 = 0x1000d87;
+pub const XK_Sinh_aee: u32 = 0x1000d88;
+pub const XK_Sinh_i: u32 = 0x1000d89;
+pub const XK_Sinh_ii: u32 = 0x1000d8a;
+pub const XK_Sinh_u: u32 = 0x1000d8b;
+pub const XK_Sinh_uu: u32 = 0x1000d8c;
+pub const XK_Sinh_ri: u32 = 0x1000d8d;
+pub const XK_Sinh_rii: u32 = 0x1000d8e;
+pub const XK_Sinh_lu: u32 = 0x1000d8f;
+pub const XK_Sinh_luu: u32 = 0x1000d90;
+pub const XK_Sinh_e: u32 = 0x1000d91;
+pub const XK_Sinh_ee: u32 = 0x1000d92;
+pub const XK_Sinh_ai: u32 = 0x1000d93;
+pub const XK_Sinh_o: u32 = 0x1000d94;
+pub const XK_Sinh_oo: u32 = 0x1000d95;
+pub const XK_Sinh_au: u32 = 0x1000d96;
+pub const XK_Sinh_ka: u32 = 0x1000d9a;
+pub const XK_Sinh_kha: u32 = 0x1000d9b;
+pub const XK_Sinh_ga: u32 = 0x1000d9c;
+pub const XK_Sinh_gha: u32 = 0x1000d9d;
+pub const XK_Sinh_ng2: u32 = 0x1000d9e;
+pub const XK_Sinh_nga: u32 = 0x1000d9f;
+pub const XK_Sinh_ca: u32 = 0x1000da0;
+pub const XK_Sinh_cha: u32 = 0x1000da1;
+pub const XK_Sinh_ja: u32 = 0x1000da2;
+pub const XK_Sinh_jha: u32 = 0x1000da3;
+pub const XK_Sinh_nya: u32 = 0x1000da4;
+pub const XK_Sinh_jnya: u32 = 0x1000da5;
+pub const XK_Sinh_nja: u32 = 0x1000da6;
+pub const XK_Sinh_tta: u32 = 0x1000da7;
+pub const XK_Sinh_ttha: u32 = 0x1000da8;
+pub const XK_Sinh_dda: u32 = 0x1000da9;
+pub const XK_Sinh_ddha: u32 = 0x1000daa;
+pub const XK_Sinh_nna: u32 = 0x1000dab;
+pub const XK_Sinh_ndda: u32 = 0x1000dac;
+pub const XK_Sinh_tha: u32 = 0x1000dad;
+pub const XK_Sinh_thha: u32 = 0x1000dae;
+pub const XK_Sinh_dha: u32 = 0x1000daf;
+pub const XK_Sinh_dhha: u32 = 0x1000db0;
+pub const XK_Sinh_na: u32 = 0x1000db1;
+pub const XK_Sinh_ndha: u32 = 0x1000db3;
+pub const XK_Sinh_pa: u32 = 0x1000db4;
+pub const XK_Sinh_pha: u32 = 0x1000db5;
+pub const XK_Sinh_ba: u32 = 0x1000db6;
+pub const XK_Sinh_bha: u32 = 0x1000db7;
+pub const XK_Sinh_ma: u32 = 0x1000db8;
+pub const XK_Sinh_mba: u32 = 0x1000db9;
+pub const XK_Sinh_ya: u32 = 0x1000dba;
+pub const XK_Sinh_ra: u32 = 0x1000dbb;
+pub const XK_Sinh_la: u32 = 0x1000dbd;
+pub const XK_Sinh_va: u32 = 0x1000dc0;
+pub const XK_Sinh_sha: u32 = 0x1000dc1;
+pub const XK_Sinh_ssha: u32 = 0x1000dc2;
+pub const XK_Sinh_sa: u32 = 0x1000dc3;
+pub const XK_Sinh_ha: u32 = 0x1000dc4;
+pub const XK_Sinh_lla: u32 = 0x1000dc5;
+pub const XK_Sinh_fa: u32 = 0x1000dc6;
+pub const XK_Sinh_al: u32 = 0x1000dca;
+pub const XK_Sinh_aa2: u32 = 0x1000dcf;
+pub const XK_Sinh_ae2: u32 = 0x1000dd0;
+pub const XK_Sinh_aee2: u32 = 0x1000dd1;
+pub const XK_Sinh_i2: u32 = 0x1000dd2;
+pub const XK_Sinh_ii2: u32 = 0x1000dd3;
+pub const XK_Sinh_u2: u32 = 0x1000dd4;
+pub const XK_Sinh_uu2: u32 = 0x1000dd6;
+pub const XK_Sinh_ru2: u32 = 0x1000dd8;
+pub const XK_Sinh_e2: u32 = 0x1000dd9;
+pub const XK_Sinh_ee2: u32 = 0x1000dda;
+pub const XK_Sinh_ai2: u32 = 0x1000ddb;
+pub const XK_Sinh_o2: u32 = 0x1000ddc;
+pub const XK_Sinh_oo2: u32 = 0x1000ddd;
+pub const XK_Sinh_au2: u32 = 0x1000dde;
+pub const XK_Sinh_lu2: u32 = 0x1000ddf;
+pub const XK_Sinh_ruu2: u32 = 0x1000df2;
+pub const XK_Sinh_luu2: u32 = 0x1000df3;
+pub const XK_Sinh_kunddaliya: u32 = 0x1000df4;
+
+/// The keysyms corresponding to a character, sorted by keysym.
+static CHARACTERS: [(u32, char); 1625] = [
+    (0x0020, '\u{20}'),
+    (0x0021, '\u{21}'),
+    (0x0022, '\u{22}'),
+    (0x0023, '\u{23}'),
+    (0x0024, '\u{24}'),
+    (0x0025, '\u{25}'),
+    (0x0026, '\u{26}'),
+    (0x0027, '\u{27}'),
+    (0x0028, '\u{28}'),
+    (0x0029, '\u{29}'),
+    (0x002a, '\u{2a}'),
+    (0x002b, '\u{2b}'),
+    (0x002c, '\u{2c}'),
+    (0x002d, '\u{2d}'),
+    (0x002e, '\u{2e}'),
+    (0x002f, '\u{2f}'),
+    (0x0030, '\u{30}'),
+    (0x0031, '\u{31}'),
+    (0x0032, '\u{32}'),
+    (0x0033, '\u{33}'),
+    (0x0034, '\u{34}'),
+    (0x0035, '\u{35}'),
+    (0x0036, '\u{36}'),
+    (0x0037, '\u{37}'),
+    (0x0038, '\u{38}'),
+    (0x0039, '\u{39}'),
+    (0x003a, '\u{3a}'),
+    (0x003b, '\u{3b}'),
+    (0x003c, '\u{3c}'),
+    (0x003d, '\u{3d}'),
+    (0x003e, '\u{3e}'),
+    (0x003f, '\u{3f}'),
+    (0x0040, '\u{40}'),
+    (0x0041, '\u{41}'),
+    (0x0042, '\u{42}'),
+    (0x0043, '\u{43}'),
+    (0x0044, '\u{44}'),
+    (0x0045, '\u{45}'),
+    (0x0046, '\u{46}'),
+    (0x0047, '\u{47}'),
+    (0x0048, '\u{48}'),
+    (0x0049, '\u{49}'),
+    (0x004a, '\u{4a}'),
+    (0x004b, '\u{4b}'),
+    (0x004c, '\u{4c}'),
+    (0x004d, '\u{4d}'),
+    (0x004e, '\u{4e}'),
+    (0x004f, '\u{4f}'),
+    (0x0050, '\u{50}'),
+    (0x0051, '\u{51}'),
+    (0x0052, '\u{52}'),
+    (0x0053, '\u{53}'),
+    (0x0054, '\u{54}'),
+    (0x0055, '\u{55}'),
+    (0x0056, '\u{56}'),
+    (0x0057, '\u{57}'),
+    (0x0058, '\u{58}'),
+    (0x0059, '\u{59}'),
+    (0x005a, '\u{5a}'),
+    (0x005b, '\u{5b}'),
+    (0x005c, '\u{5c}'),
+    (0x005d, '\u{5d}'),
+    (0x005e, '\u{5e}'),
+    (0x005f, '\u{5f}'),
+    (0x0060, '\u{60}'),
+    (0x0061, '\u{61}'),
+    (0x0062, '\u{62}'),
+    (0x0063, '\u{63}'),
+    (0x0064, '\u{64}'),
+    (0x0065, '\u{65}'),
+    (0x0066, '\u{66}'),
+    (0x0067, '\u{67}'),
+    (0x0068, '\u{68}'),
+    (0x0069, '\u{69}'),
+    (0x006a, '\u{6a}'),
+    (0x006b, '\u{6b}'),
+    (0x006c, '\u{6c}'),
+    (0x006d, '\u{6d}'),
+    (0x006e, '\u{6e}'),
+    (0x006f, '\u{6f}'),
+    (0x0070, '\u{70}'),
+    (0x0071, '\u{71}'),
+    (0x0072, '\u{72}'),
+    (0x0073, '\u{73}'),
+    (0x0074, '\u{74}'),
+    (0x0075, '\u{75}'),
+    (0x0076, '\u{76}'),
+    (0x0077, '\u{77}'),
+    (0x0078, '\u{78}'),
+    (0x0079, '\u{79}'),
+    (0x007a, '\u{7a}'),
+    (0x007b, '\u{7b}'),
+    (0x007c, '\u{7c}'),
+    (0x007d, '\u{7d}'),
+    (0x007e, '\u{7e}'),
+    (0x00a0, '\u{a0}'),
+    (0x00a1, '\u{a1}'),
+    (0x00a2, '\u{a2}'),
+    (0x00a3, '\u{a3}'),
+    (0x00a4, '\u{a4}'),
+    (0x00a5, '\u{a5}'),
+    (0x00a6, '\u{a6}'),
+    (0x00a7, '\u{a7}'),
+    (0x00a8, '\u{a8}'),
+    (0x00a9, '\u{a9}'),
+    (0x00aa, '\u{aa}'),
+    (0x00ab, '\u{ab}'),
+    (0x00ac, '\u{ac}'),
+    (0x00ad, '\u{ad}'),
+    (0x00ae, '\u{ae}'),
+    (0x00af, '\u{af}'),
+    (0x00b0, '\u{b0}'),
+    (0x00b1, '\u{b1}'),
+    (0x00b2, '\u{b2}'),
+    (0x00b3, '\u{b3}'),
+    (0x00b4, '\u{b4}'),
+    (0x00b5, '\u{b5}'),
+    (0x00b6, '\u{b6}'),
+    (0x00b7, '\u{b7}'),
+    (0x00b8, '\u{b8}'),
+    (0x00b9, '\u{b9}'),
+    (0x00ba, '\u{ba}'),
+    (0x00bb, '\u{bb}'),
+    (0x00bc, '\u{bc}'),
+    (0x00bd, '\u{bd}'),
+    (0x00be, '\u{be}'),
+    (0x00bf, '\u{bf}'),
+    (0x00c0, '\u{c0}'),
+    (0x00c1, '\u{c1}'),
+    (0x00c2, '\u{c2}'),
+    (0x00c3, '\u{c3}'),
+    (0x00c4, '\u{c4}'),
+    (0x00c5, '\u{c5}'),
+    (0x00c6, '\u{c6}'),
+    (0x00c7, '\u{c7}'),
+    (0x00c8, '\u{c8}'),
+    (0x00c9, '\u{c9}'),
+    (0x00ca, '\u{ca}'),
+    (0x00cb, '\u{cb}'),
+    (0x00cc, '\u{cc}'),
+    (0x00cd, '\u{cd}'),
+    (0x00ce, '\u{ce}'),
+    (0x00cf, '\u{cf}'),
+    (0x00d0, '\u{d0}'),
+    (0x00d1, '\u{d1}'),
+    (0x00d2, '\u{d2}'),
+    (0x00d3, '\u{d3}'),
+    (0x00d4, '\u{d4}'),
+    (0x00d5, '\u{d5}'),
+    (0x00d6, '\u{d6}'),
+    (0x00d7, '\u{d7}'),
+    (0x00d8, '\u{d8}'),
+    (0x00d9, '\u{d9}'),
+    (0x00da, '\u{da}'),
+    (0x00db, '\u{db}'),
+    (0x00dc, '\u{dc}'),
+    (0x00dd, '\u{dd}'),
+    (0x00de, '\u{de}'),
+    (0x00df, '\u{df}'),
+    (0x00e0, '\u{e0}'),
+    (0x00e1, '\u{e1}'),
+    (0x00e2, '\u{e2}'),
+    (0x00e3, '\u{e3}'),
+    (0x00e4, '\u{e4}'),
+    (0x00e5, '\u{e5}'),
+    (0x00e6, '\u{e6}'),
+    (0x00e7, '\u{e7}'),
+    (0x00e8, '\u{e8}'),
+    (0x00e9, '\u{e9}'),
+    (0x00ea, '\u{ea}'),
+    (0x00eb, '\u{eb}'),
+    (0x00ec, '\u{ec}'),
+    (0x00ed, '\u{ed}'),
+    (0x00ee, '\u{ee}'),
+    (0x00ef, '\u{ef}'),
+    (0x00f0, '\u{f0}'),
+    (0x00f1, '\u{f1}'),
+    (0x00f2, '\u{f2}'),
+    (0x00f3, '\u{f3}'),
+    (0x00f4, '\u{f4}'),
+    (0x00f5, '\u{f5}'),
+    (0x00f6, '\u{f6}'),
+    (0x00f7, '\u{f7}'),
+    (0x00f8, '\u{f8}'),
+    (0x00f9, '\u{f9}'),
+    (0x00fa, '\u{fa}'),
+    (0x00fb, '\u{fb}'),
+    (0x00fc, '\u{fc}'),
+    (0x00fd, '\u{fd}'),
+    (0x00fe, '\u{fe}'),
+    (0x00ff, '\u{ff}'),
+    (0x01a1, '\u{104}'),
+    (0x01a2, '\u{2d8}'),
+    (0x01a3, '\u{141}'),
+    (0x01a5, '\u{13d}'),
+    (0x01a6, '\u{15a}'),
+    (0x01a9, '\u{160}'),
+    (0x01aa, '\u{15e}'),
+    (0x01ab, '\u{164}'),
+    (0x01ac, '\u{179}'),
+    (0x01ae, '\u{17d}'),
+    (0x01af, '\u{17b}'),
+    (0x01b1, '\u{105}'),
+    (0x01b2, '\u{2db}'),
+    (0x01b3, '\u{142}'),
+    (0x01b5, '\u{13e}'),
+    (0x01b6, '\u{15b}'),
+    (0x01b7, '\u{2c7}'),
+    (0x01b9, '\u{161}'),
+    (0x01ba, '\u{15f}'),
+    (0x01bb, '\u{165}'),
+    (0x01bc, '\u{17a}'),
+    (0x01bd, '\u{2dd}'),
+    (0x01be, '\u{17e}'),
+    (0x01bf, '\u{17c}'),
+    (0x01c0, '\u{154}'),
+    (0x01c3, '\u{102}'),
+    (0x01c5, '\u{139}'),
+    (0x01c6, '\u{106}'),
+    (0x01c8, '\u{10c}'),
+    (0x01ca, '\u{118}'),
+    (0x01cc, '\u{11a}'),
+    (0x01cf, '\u{10e}'),
+    (0x01d0, '\u{110}'),
+    (0x01d1, '\u{143}'),
+    (0x01d2, '\u{147}'),
+    (0x01d5, '\u{150}'),
+    (0x01d8, '\u{158}'),
+    (0x01d9, '\u{16e}'),
+    (0x01db, '\u{170}'),
+    (0x01de, '\u{162}'),
+    (0x01e0, '\u{155}'),
+    (0x01e3, '\u{103}'),
+    (0x01e5, '\u{13a}'),
+    (0x01e6, '\u{107}'),
+    (0x01e8, '\u{10d}'),
+    (0x01ea, '\u{119}'),
+    (0x01ec, '\u{11b}'),
+    (0x01ef, '\u{10f}'),
+    (0x01f0, '\u{111}'),
+    (0x01f1, '\u{144}'),
+    (0x01f2, '\u{148}'),
+    (0x01f5, '\u{151}'),
+    (0x01f8, '\u{159}'),
+    (0x01f9, '\u{16f}'),
+    (0x01fb, '\u{171}'),
+    (0x01fe, '\u{163}'),
+    (0x01ff, '\u{2d9}'),
+    (0x02a1, '\u{126}'),
+    (0x02a6, '\u{124}'),
+    (0x02a9, '\u{130}'),
+    (0x02ab, '\u{11e}'),
+    (0x02ac, '\u{134}'),
+    (0x02b1, '\u{127}'),
+    (0x02b6, '\u{125}'),
+    (0x02b9, '\u{131}'),
+    (0x02bb, '\u{11f}'),
+    (0x02bc, '\u{135}'),
+    (0x02c5, '\u{10a}'),
+    (0x02c6, '\u{108}'),
+    (0x02d5, '\u{120}'),
+    (0x02d8, '\u{11c}'),
+    (0x02dd, '\u{16c}'),
+    (0x02de, '\u{15c}'),
+    (0x02e5, '\u{10b}'),
+    (0x02e6, '\u{109}'),
+    (0x02f5, '\u{121}'),
+    (0x02f8, '\u{11d}'),
+    (0x02fd, '\u{16d}'),
+    (0x02fe, '\u{15d}'),
+    (0x03a2, '\u{138}'),
+    (0x03a3, '\u{156}'),
+    (0x03a5, '\u{128}'),
+    (0x03a6, '\u{13b}'),
+    (0x03aa, '\u{112}'),
+    (0x03ab, '\u{122}'),
+    (0x03ac, '\u{166}'),
+    (0x03b3, '\u{157}'),
+    (0x03b5, '\u{129}'),
+    (0x03b6, '\u{13c}'),
+    (0x03ba, '\u{113}'),
+    (0x03bb, '\u{123}'),
+    (0x03bc, '\u{167}'),
+    (0x03bd, '\u{14a}'),
+    (0x03bf, '\u{14b}'),
+    (0x03c0, '\u{100}'),
+    (0x03c7, '\u{12e}'),
+    (0x03cc, '\u{116}'),
+    (0x03cf, '\u{12a}'),
+    (0x03d1, '\u{145}'),
+    (0x03d2, '\u{14c}'),
+    (0x03d3, '\u{136}'),
+    (0x03d9, '\u{172}'),
+    (0x03dd, '\u{168}'),
+    (0x03de, '\u{16a}'),
+    (0x03e0, '\u{101}'),
+    (0x03e7, '\u{12f}'),
+    (0x03ec, '\u{117}'),
+    (0x03ef, '\u{12b}'),
+    (0x03f1, '\u{146}'),
+    (0x03f2, '\u{14d}'),
+    (0x03f3, '\u{137}'),
+    (0x03f9, '\u{173}'),
+    (0x03fd, '\u{169}'),
+    (0x03fe, '\u{16b}'),
+    (0x047e, '\u{203e}'),
+    (0x04a1, '\u{3002}'),
+    (0x04a2, '\u{300c}'),
+    (0x04a3, '\u{300d}'),
+    (0x04a4, '\u{3001}'),
+    (0x04a5, '\u{30fb}'),
+    (0x04a6, '\u{30f2}'),
+    (0x04a7, '\u{30a1}'),
+    (0x04a8, '\u{30a3}'),
+    (0x04a9, '\u{30a5}'),
+    (0x04aa, '\u{30a7}'),
+    (0x04ab, '\u{30a9}'),
+    (0x04ac, '\u{30e3}'),
+    (0x04ad, '\u{30e5}'),
+    (0x04ae, '\u{30e7}'),
+    (0x04af, '\u{30c3}'),
+    (0x04b0, '\u{30fc}'),
+    (0x04b1, '\u{30a2}'),
+    (0x04b2, '\u{30a4}'),
+    (0x04b3, '\u{30a6}'),
+    (0x04b4, '\u{30a8}'),
+    (0x04b5, '\u{30aa}'),
+    (0x04b6, '\u{30ab}'),
+    (0x04b7, '\u{30ad}'),
+    (0x04b8, '\u{30af}'),
+    (0x04b9, '\u{30b1}'),
+    (0x04ba, '\u{30b3}'),
+    (0x04bb, '\u{30b5}'),
+    (0x04bc, '\u{30b7}'),
+    (0x04bd, '\u{30b9}'),
+    (0x04be, '\u{30bb}'),
+    (0x04bf, '\u{30bd}'),
+    (0x04c0, '\u{30bf}'),
+    (0x04c1, '\u{30c1}'),
+    (0x04c2, '\u{30c4}'),
+    (0x04c3, '\u{30c6}'),
+    (0x04c4, '\u{30c8}'),
+    (0x04c5, '\u{30ca}'),
+    (0x04c6, '\u{30cb}'),
+    (0x04c7, '\u{30cc}'),
+    (0x04c8, '\u{30cd}'),
+    (0x04c9, '\u{30ce}'),
+    (0x04ca, '\u{30cf}'),
+    (0x04cb, '\u{30d2}'),
+    (0x04cc, '\u{30d5}'),
+    (0x04cd, '\u{30d8}'),
+    (0x04ce, '\u{30db}'),
+    (0x04cf, '\u{30de}'),
+    (0x04d0, '\u{30df}'),
+    (0x04d1, '\u{30e0}'),
+    (0x04d2, '\u{30e1}'),
+    (0x04d3, '\u{30e2}'),
+    (0x04d4, '\u{30e4}'),
+    (0x04d5, '\u{30e6}'),
+    (0x04d6, '\u{30e8}'),
+    (0x04d7, '\u{30e9}'),
+    (0x04d8, '\u{30ea}'),
+    (0x04d9, '\u{30eb}'),
+    (0x04da, '\u{30ec}'),
+    (0x04db, '\u{30ed}'),
+    (0x04dc, '\u{30ef}'),
+    (0x04dd, '\u{30f3}'),
+    (0x04de, '\u{309b}'),
+    (0x04df, '\u{309c}'),
+    (0x05ac, '\u{60c}'),
+    (0x05bb, '\u{61b}'),
+    (0x05bf, '\u{61f}'),
+    (0x05c1, '\u{621}'),
+    (0x05c2, '\u{622}'),
+    (0x05c3, '\u{623}'),
+    (0x05c4, '\u{624}'),
+    (0x05c5, '\u{625}'),
+    (0x05c6, '\u{626}'),
+    (0x05c7, '\u{627}'),
+    (0x05c8, '\u{628}'),
+    (0x05c9, '\u{629}'),
+    (0x05ca, '\u{62a}'),
+    (0x05cb, '\u{62b}'),
+    (0x05cc, '\u{62c}'),
+    (0x05cd, '\u{62d}'),
+    (0x05ce, '\u{62e}'),
+    (0x05cf, '\u{62f}'),
+    (0x05d0, '\u{630}'),
+    (0x05d1, '\u{631}'),
+    (0x05d2, '\u{632}'),
+    (0x05d3, '\u{633}'),
+    (0x05d4, '\u{634}'),
+    (0x05d5, '\u{635}'),
+    (0x05d6, '\u{636}'),
+    (0x05d7, '\u{637}'),
+    (0x05d8, '\u{638}'),
+    (0x05d9, '\u{639}'),
+    (0x05da, '\u{63a}'),
+    (0x05e0, '\u{640}'),
+    (0x05e1, '\u{641}'),
+    (0x05e2, '\u{642}'),
+    (0x05e3, '\u{643}'),
+    (0x05e4, '\u{644}'),
+    (0x05e5, '\u{645}'),
+    (0x05e6, '\u{646}'),
+    (0x05e7, '\u{647}'),
+    (0x05e8, '\u{648}'),
+    (0x05e9, '\u{649}'),
+    (0x05ea, '\u{64a}'),
+    (0x05eb, '\u{64b}'),
+    (0x05ec, '\u{64c}'),
+    (0x05ed, '\u{64d}'),
+    (0x05ee, '\u{64e}'),
+    (0x05ef, '\u{64f}'),
+    (0x05f0, '\u{650}'),
+    (0x05f1, '\u{651}'),
+    (0x05f2, '\u{652}'),
+    (0x06a1, '\u{452}'),
+    (0x06a2, '\u{453}'),
+    (0x06a3, '\u{451}'),
+    (0x06a4, '\u{454}'),
+    (0x06a5, '\u{455}'),
+    (0x06a6, '\u{456}'),
+    (0x06a7, '\u{457}'),
+    (0x06a8, '\u{458}'),
+    (0x06a9, '\u{459}'),
+    (0x06aa, '\u{45a}'),
+    (0x06ab, '\u{45b}'),
+    (0x06ac, '\u{45c}'),
+    (0x06ad, '\u{491}'),
+    (0x06ae, '\u{45e}'),
+    (0x06af, '\u{45f}'),
+    (0x06b0, '\u{2116}'),
+    (0x06b1, '\u{402}'),
+    (0x06b2, '\u{403}'),
+    (0x06b3, '\u{401}'),
+    (0x06b4, '\u{404}'),
+    (0x06b5, '\u{405}'),
+    (0x06b6, '\u{406}'),
+    (0x06b7, '\u{407}'),
+    (0x06b8, '\u{408}'),
+    (0x06b9, '\u{409}'),
+    (0x06ba, '\u{40a}'),
+    (0x06bb, '\u{40b}'),
+    (0x06bc, '\u{40c}'),
+    (0x06bd, '\u{490}'),
+    (0x06be, '\u{40e}'),
+    (0x06bf, '\u{40f}'),
+    (0x06c0, '\u{44e}'),
+    (0x06c1, '\u{430}'),
+    (0x06c2, '\u{431}'),
+    (0x06c3, '\u{446}'),
+    (0x06c4, '\u{434}'),
+    (0x06c5, '\u{435}'),
+    (0x06c6, '\u{444}'),
+    (0x06c7, '\u{433}'),
+    (0x06c8, '\u{445}'),
+    (0x06c9, '\u{438}'),
+    (0x06ca, '\u{439}'),
+    (0x06cb, '\u{43a}'),
+    (0x06cc, '\u{43b}'),
+    (0x06cd, '\u{43c}'),
+    (0x06ce, '\u{43d}'),
+    (0x06cf, '\u{43e}'),
+    (0x06d0, '\u{43f}'),
+    (0x06d1, '\u{44f}'),
+    (0x06d2, '\u{440}'),
+    (0x06d3, '\u{441}'),
+    (0x06d4, '\u{442}'),
+    (0x06d5, '\u{443}'),
+    (0x06d6, '\u{436}'),
+    (0x06d7, '\u{432}'),
+    (0x06d8, '\u{44c}'),
+    (0x06d9, '\u{44b}'),
+    (0x06da, '\u{437}'),
+    (0x06db, '\u{448}'),
+    (0x06dc, '\u{44d}'),
+    (0x06dd, '\u{449}'),
+    (0x06de, '\u{447}'),
+    (0x06df, '\u{44a}'),
+    (0x06e0, '\u{42e}'),
+    (0x06e1, '\u{410}'),
+    (0x06e2, '\u{411}'),
+    (0x06e3, '\u{426}'),
+    (0x06e4, '\u{414}'),
+    (0x06e5, '\u{415}'),
+    (0x06e6, '\u{424}'),
+    (0x06e7, '\u{413}'),
+    (0x06e8, '\u{425}'),
+    (0x06e9, '\u{418}'),
+    (0x06ea, '\u{419}'),
+    (0x06eb, '\u{41a}'),
+    (0x06ec, '\u{41b}'),
+    (0x06ed, '\u{41c}'),
+    (0x06ee, '\u{41d}'),
+    (0x06ef, '\u{41e}'),
+    (0x06f0, '\u{41f}'),
+    (0x06f1, '\u{42f}'),
+    (0x06f2, '\u{420}'),
+    (0x06f3, '\u{421}'),
+    (0x06f4, '\u{422}'),
+    (0x06f5, '\u{423}'),
+    (0x06f6, '\u{416}'),
+    (0x06f7, '\u{412}'),
+    (0x06f8, '\u{42c}'),
+    (0x06f9, '\u{42b}'),
+    (0x06fa, '\u{417}'),
+    (0x06fb, '\u{428}'),
+    (0x06fc, '\u{42d}'),
+    (0x06fd, '\u{429}'),
+    (0x06fe, '\u{427}'),
+    (0x06ff, '\u{42a}'),
+    (0x07a1, '\u{386}'),
+    (0x07a2, '\u{388}'),
+    (0x07a3, '\u{389}'),
+    (0x07a4, '\u{38a}'),
+    (0x07a5, '\u{3aa}'),
+    (0x07a7, '\u{38c}'),
+    (0x07a8, '\u{38e}'),
+    (0x07a9, '\u{3ab}'),
+    (0x07ab, '\u{38f}'),
+    (0x07ae, '\u{385}'),
+    (0x07af, '\u{2015}'),
+    (0x07b1, '\u{3ac}'),
+    (0x07b2, '\u{3ad}'),
+    (0x07b3, '\u{3ae}'),
+    (0x07b4, '\u{3af}'),
+    (0x07b5, '\u{3ca}'),
+    (0x07b6, '\u{390}'),
+    (0x07b7, '\u{3cc}'),
+    (0x07b8, '\u{3cd}'),
+    (0x07b9, '\u{3cb}'),
+    (0x07ba, '\u{3b0}'),
+    (0x07bb, '\u{3ce}'),
+    (0x07c1, '\u{391}'),
+    (0x07c2, '\u{392}'),
+    (0x07c3, '\u{393}'),
+    (0x07c4, '\u{394}'),
+    (0x07c5, '\u{395}'),
+    (0x07c6, '\u{396}'),
+    (0x07c7, '\u{397}'),
+    (0x07c8, '\u{398}'),
+    (0x07c9, '\u{399}'),
+    (0x07ca, '\u{39a}'),
+    (0x07cb, '\u{39b}'),
+    (0x07cc, '\u{39c}'),
+    (0x07cd, '\u{39d}'),
+    (0x07ce, '\u{39e}'),
+    (0x07cf, '\u{39f}'),
+    (0x07d0, '\u{3a0}'),
+    (0x07d1, '\u{3a1}'),
+    (0x07d2, '\u{3a3}'),
+    (0x07d4, '\u{3a4}'),
+    (0x07d5, '\u{3a5}'),
+    (0x07d6, '\u{3a6}'),
+    (0x07d7, '\u{3a7}'),
+    (0x07d8, '\u{3a8}'),
+    (0x07d9, '\u{3a9}'),
+    (0x07e1, '\u{3b1}'),
+    (0x07e2, '\u{3b2}'),
+    (0x07e3, '\u{3b3}'),
+    (0x07e4, '\u{3b4}'),
+    (0x07e5, '\u{3b5}'),
+    (0x07e6, '\u{3b6}'),
+    (0x07e7, '\u{3b7}'),
+    (0x07e8, '\u{3b8}'),
+    (0x07e9, '\u{3b9}'),
+    (0x07ea, '\u{3ba}'),
+    (0x07eb, '\u{3bb}'),
+    (0x07ec, '\u{3bc}'),
+    (0x07ed, '\u{3bd}'),
+    (0x07ee, '\u{3be}'),
+    (0x07ef, '\u{3bf}'),
+    (0x07f0, '\u{3c0}'),
+    (0x07f1, '\u{3c1}'),
+    (0x07f2, '\u{3c3}'),
+    (0x07f3, '\u{3c2}'),
+    (0x07f4, '\u{3c4}'),
+    (0x07f5, '\u{3c5}'),
+    (0x07f6, '\u{3c6}'),
+    (0x07f7, '\u{3c7}'),
+    (0x07f8, '\u{3c8}'),
+    (0x07f9, '\u{3c9}'),
+    (0x08a1, '\u{23b7}'),
+    (0x08a4, '\u{2320}'),
+    (0x08a5, '\u{2321}'),
+    (0x08a7, '\u{23a1}'),
+    (0x08a8, '\u{23a3}'),
+    (0x08a9, '\u{23a4}'),
+    (0x08aa, '\u{23a6}'),
+    (0x08ab, '\u{239b}'),
+    (0x08ac, '\u{239d}'),
+    (0x08ad, '\u{239e}'),
+    (0x08ae, '\u{23a0}'),
+    (0x08af, '\u{23a8}'),
+    (0x08b0, '\u{23ac}'),
+    (0x08bc, '\u{2264}'),
+    (0x08bd, '\u{2260}'),
+    (0x08be, '\u{2265}'),
+    (0x08bf, '\u{222b}'),
+    (0x08c0, '\u{2234}'),
+    (0x08c1, '\u{221d}'),
+    (0x08c2, '\u{221e}'),
+    (0x08c5, '\u{2207}'),
+    (0x08c8, '\u{223c}'),
+    (0x08c9, '\u{2243}'),
+    (0x08cd, '\u{21d4}'),
+    (0x08ce, '\u{21d2}'),
+    (0x08cf, '\u{2261}'),
+    (0x08d6, '\u{221a}'),
+    (0x08da, '\u{2282}'),
+    (0x08db, '\u{2283}'),
+    (0x08dc, '\u{2229}'),
+    (0x08dd, '\u{222a}'),
+    (0x08de, '\u{2227}'),
+    (0x08df, '\u{2228}'),
+    (0x08ef, '\u{2202}'),
+    (0x08f6, '\u{192}'),
+    (0x08fb, '\u{2190}'),
+    (0x08fc, '\u{2191}'),
+    (0x08fd, '\u{2192}'),
+    (0x08fe, '\u{2193}'),
+    (0x09e0, '\u{25c6}'),
+    (0x09e1, '\u{2592}'),
+    (0x09e2, '\u{2409}'),
+    (0x09e3, '\u{240c}'),
+    (0x09e4, '\u{240d}'),
+    (0x09e5, '\u{240a}'),
+    (0x09e8, '\u{2424}'),
+    (0x09e9, '\u{240b}'),
+    (0x09ea, '\u{2518}'),
+    (0x09eb, '\u{2510}'),
+    (0x09ec, '\u{250c}'),
+    (0x09ed, '\u{2514}'),
+    (0x09ee, '\u{253c}'),
+    (0x09ef, '\u{23ba}'),
+    (0x09f0, '\u{23bb}'),
+    (0x09f1, '\u{2500}'),
+    (0x09f2, '\u{23bc}'),
+    (0x09f3, '\u{23bd}'),
+    (0x09f4, '\u{251c}'),
+    (0x09f5, '\u{2524}'),
+    (0x09f6, '\u{2534}'),
+    (0x09f7, '\u{252c}'),
+    (0x09f8, '\u{2502}'),
+    (0x0aa1, '\u{2003}'),
+    (0x0aa2, '\u{2002}'),
+    (0x0aa3, '\u{2004}'),
+    (0x0aa4, '\u{2005}'),
+    (0x0aa5, '\u{2007}'),
+    (0x0aa6, '\u{2008}'),
+    (0x0aa7, '\u{2009}'),
+    (0x0aa8, '\u{200a}'),
+    (0x0aa9, '\u{2014}'),
+    (0x0aaa, '\u{2013}'),
+    (0x0aae, '\u{2026}'),
+    (0x0aaf, '\u{2025}'),
+    (0x0ab0, '\u{2153}'),
+    (0x0ab1, '\u{2154}'),
+    (0x0ab2, '\u{2155}'),
+    (0x0ab3, '\u{2156}'),
+    (0x0ab4, '\u{2157}'),
+    (0x0ab5, '\u{2158}'),
+    (0x0ab6, '\u{2159}'),
+    (0x0ab7, '\u{215a}'),
+    (0x0ab8, '\u{2105}'),
+    (0x0abb, '\u{2012}'),
+    (0x0ac3, '\u{215b}'),
+    (0x0ac4, '\u{215c}'),
+    (0x0ac5, '\u{215d}'),
+    (0x0ac6, '\u{215e}'),
+    (0x0ac9, '\u{2122}'),
+    (0x0ad0, '\u{2018}'),
+    (0x0ad1, '\u{2019}'),
+    (0x0ad2, '\u{201c}'),
+    (0x0ad3, '\u{201d}'),
+    (0x0ad4, '\u{211e}'),
+    (0x0ad5, '\u{2030}'),
+    (0x0ad6, '\u{2032}'),
+    (0x0ad7, '\u{2033}'),
+    (0x0ad9, '\u{271d}'),
+    (0x0aec, '\u{2663}'),
+    (0x0aed, '\u{2666}'),
+    (0x0aee, '\u{2665}'),
+    (0x0af0, '\u{2720}'),
+    (0x0af1, '\u{2020}'),
+    (0x0af2, '\u{2021}'),
+    (0x0af3, '\u{2713}'),
+    (0x0af4, '\u{2717}'),
+    (0x0af5, '\u{266f}'),
+    (0x0af6, '\u{266d}'),
+    (0x0af7, '\u{2642}'),
+    (0x0af8, '\u{2640}'),
+    (0x0af9, '\u{260e}'),
+    (0x0afa, '\u{2315}'),
+    (0x0afb, '\u{2117}'),
+    (0x0afc, '\u{2038}'),
+    (0x0afd, '\u{201a}'),
+    (0x0afe, '\u{201e}'),
+    (0x0bc2, '\u{22a4}'),
+    (0x0bc4, '\u{230a}'),
+    (0x0bca, '\u{2218}'),
+    (0x0bcc, '\u{2395}'),
+    (0x0bce, '\u{22a5}'),
+    (0x0bcf, '\u{25cb}'),
+    (0x0bd3, '\u{2308}'),
+    (0x0bdc, '\u{22a3}'),
+    (0x0bfc, '\u{22a2}'),
+    (0x0cdf, '\u{2017}'),
+    (0x0ce0, '\u{5d0}'),
+    (0x0ce1, '\u{5d1}'),
+    (0x0ce2, '\u{5d2}'),
+    (0x0ce3, '\u{5d3}'),
+    (0x0ce4, '\u{5d4}'),
+    (0x0ce5, '\u{5d5}'),
+    (0x0ce6, '\u{5d6}'),
+    (0x0ce7, '\u{5d7}'),
+    (0x0ce8, '\u{5d8}'),
+    (0x0ce9, '\u{5d9}'),
+    (0x0cea, '\u{5da}'),
+    (0x0ceb, '\u{5db}'),
+    (0x0cec, '\u{5dc}'),
+    (0x0ced, '\u{5dd}'),
+    (0x0cee, '\u{5de}'),
+    (0x0cef, '\u{5df}'),
+    (0x0cf0, '\u{5e0}'),
+    (0x0cf1, '\u{5e1}'),
+    (0x0cf2, '\u{5e2}'),
+    (0x0cf3, '\u{5e3}'),
+    (0x0cf4, '\u{5e4}'),
+    (0x0cf5, '\u{5e5}'),
+    (0x0cf6, '\u{5e6}'),
+    (0x0cf7, '\u{5e7}'),
+    (0x0cf8, '\u{5e8}'),
+    (0x0cf9, '\u{5e9}'),
+    (0x0cfa, '\u{5ea}'),
+    (0x0da1, '\u{e01}'),
+    (0x0da2, '\u{e02}'),
+    (0x0da3, '\u{e03}'),
+    (0x0da4, '\u{e04}'),
+    (0x0da5, '\u{e05}'),
+    (0x0da6, '\u{e06}'),
+    (0x0da7, '\u{e07}'),
+    (0x0da8, '\u{e08}'),
+    (0x0da9, '\u{e09}'),
+    (0x0daa, '\u{e0a}'),
+    (0x0dab, '\u{e0b}'),
+    (0x0dac, '\u{e0c}'),
+    (0x0dad, '\u{e0d}'),
+    (0x0dae, '\u{e0e}'),
+    (0x0daf, '\u{e0f}'),
+    (0x0db0, '\u{e10}'),
+    (0x0db1, '\u{e11}'),
+    (0x0db2, '\u{e12}'),
+    (0x0db3, '\u{e13}'),
+    (0x0db4, '\u{e14}'),
+    (0x0db5, '\u{e15}'),
+    (0x0db6, '\u{e16}'),
+    (0x0db7, '\u{e17}'),
+    (0x0db8, '\u{e18}'),
+    (0x0db9, '\u{e19}'),
+    (0x0dba, '\u{e1a}'),
+    (0x0dbb, '\u{e1b}'),
+    (0x0dbc, '\u{e1c}'),
+    (0x0dbd, '\u{e1d}'),
+    (0x0dbe, '\u{e1e}'),
+    (0x0dbf, '\u{e1f}'),
+    (0x0dc0, '\u{e20}'),
+    (0x0dc1, '\u{e21}'),
+    (0x0dc2, '\u{e22}'),
+    (0x0dc3, '\u{e23}'),
+    (0x0dc4, '\u{e24}'),
+    (0x0dc5, '\u{e25}'),
+    (0x0dc6, '\u{e26}'),
+    (0x0dc7, '\u{e27}'),
+    (0x0dc8, '\u{e28}'),
+    (0x0dc9, '\u{e29}'),
+    (0x0dca, '\u{e2a}'),
+    (0x0dcb, '\u{e2b}'),
+    (0x0dcc, '\u{e2c}'),
+    (0x0dcd, '\u{e2d}'),
+    (0x0dce, '\u{e2e}'),
+    (0x0dcf, '\u{e2f}'),
+    (0x0dd0, '\u{e30}'),
+    (0x0dd1, '\u{e31}'),
+    (0x0dd2, '\u{e32}'),
+    (0x0dd3, '\u{e33}'),
+    (0x0dd4, '\u{e34}'),
+    (0x0dd5, '\u{e35}'),
+    (0x0dd6, '\u{e36}'),
+    (0x0dd7, '\u{e37}'),
+    (0x0dd8, '\u{e38}'),
+    (0x0dd9, '\u{e39}'),
+    (0x0dda, '\u{e3a}'),
+    (0x0ddf, '\u{e3f}'),
+    (0x0de0, '\u{e40}'),
+    (0x0de1, '\u{e41}'),
+    (0x0de2, '\u{e42}'),
+    (0x0de3, '\u{e43}'),
+    (0x0de4, '\u{e44}'),
+    (0x0de5, '\u{e45}'),
+    (0x0de6, '\u{e46}'),
+    (0x0de7, '\u{e47}'),
+    (0x0de8, '\u{e48}'),
+    (0x0de9, '\u{e49}'),
+    (0x0dea, '\u{e4a}'),
+    (0x0deb, '\u{e4b}'),
+    (0x0dec, '\u{e4c}'),
+    (0x0ded, '\u{e4d}'),
+    (0x0df0, '\u{e50}'),
+    (0x0df1, '\u{e51}'),
+    (0x0df2, '\u{e52}'),
+    (0x0df3, '\u{e53}'),
+    (0x0df4, '\u{e54}'),
+    (0x0df5, '\u{e55}'),
+    (0x0df6, '\u{e56}'),
+    (0x0df7, '\u{e57}'),
+    (0x0df8, '\u{e58}'),
+    (0x0df9, '\u{e59}'),
+    (0x0ea1, '\u{3131}'),
+    (0x0ea2, '\u{3132}'),
+    (0x0ea3, '\u{3133}'),
+    (0x0ea4, '\u{3134}'),
+    (0x0ea5, '\u{3135}'),
+    (0x0ea6, '\u{3136}'),
+    (0x0ea7, '\u{3137}'),
+    (0x0ea8, '\u{3138}'),
+    (0x0ea9, '\u{3139}'),
+    (0x0eaa, '\u{313a}'),
+    (0x0eab, '\u{313b}'),
+    (0x0eac, '\u{313c}'),
+    (0x0ead, '\u{313d}'),
+    (0x0eae, '\u{313e}'),
+    (0x0eaf, '\u{313f}'),
+    (0x0eb0, '\u{3140}'),
+    (0x0eb1, '\u{3141}'),
+    (0x0eb2, '\u{3142}'),
+    (0x0eb3, '\u{3143}'),
+    (0x0eb4, '\u{3144}'),
+    (0x0eb5, '\u{3145}'),
+    (0x0eb6, '\u{3146}'),
+    (0x0eb7, '\u{3147}'),
+    (0x0eb8, '\u{3148}'),
+    (0x0eb9, '\u{3149}'),
+    (0x0eba, '\u{314a}'),
+    (0x0ebb, '\u{314b}'),
+    (0x0ebc, '\u{314c}'),
+    (0x0ebd, '\u{314d}'),
+    (0x0ebe, '\u{314e}'),
+    (0x0ebf, '\u{314f}'),
+    (0x0ec0, '\u{3150}'),
+    (0x0ec1, '\u{3151}'),
+    (0x0ec2, '\u{3152}'),
+    (0x0ec3, '\u{3153}'),
+    (0x0ec4, '\u{3154}'),
+    (0x0ec5, '\u{3155}'),
+    (0x0ec6, '\u{3156}'),
+    (0x0ec7, '\u{3157}'),
+    (0x0ec8, '\u{3158}'),
+    (0x0ec9, '\u{3159}'),
+    (0x0eca, '\u{315a}'),
+    (0x0ecb, '\u{315b}'),
+    (0x0ecc, '\u{315c}'),
+    (0x0ecd, '\u{315d}'),
+    (0x0ece, '\u{315e}'),
+    (0x0ecf, '\u{315f}'),
+    (0x0ed0, '\u{3160}'),
+    (0x0ed1, '\u{3161}'),
+    (0x0ed2, '\u{3162}'),
+    (0x0ed3, '\u{3163}'),
+    (0x0ed4, '\u{11a8}'),
+    (0x0ed5, '\u{11a9}'),
+    (0x0ed6, '\u{11aa}'),
+    (0x0ed7, '\u{11ab}'),
+    (0x0ed8, '\u{11ac}'),
+    (0x0ed9, '\u{11ad}'),
+    (0x0eda, '\u{11ae}'),
+    (0x0edb, '\u{11af}'),
+    (0x0edc, '\u{11b0}'),
+    (0x0edd, '\u{11b1}'),
+    (0x0ede, '\u{11b2}'),
+    (0x0edf, '\u{11b3}'),
+    (0x0ee0, '\u{11b4}'),
+    (0x0ee1, '\u{11b5}'),
+    (0x0ee2, '\u{11b6}'),
+    (0x0ee3, '\u{11b7}'),
+    (0x0ee4, '\u{11b8}'),
+    (0x0ee5, '\u{11b9}'),
+    (0x0ee6, '\u{11ba}'),
+    (0x0ee7, '\u{11bb}'),
+    (0x0ee8, '\u{11bc}'),
+    (0x0ee9, '\u{11bd}'),
+    (0x0eea, '\u{11be}'),
+    (0x0eeb, '\u{11bf}'),
+    (0x0eec, '\u{11c0}'),
+    (0x0eed, '\u{11c1}'),
+    (0x0eee, '\u{11c2}'),
+    (0x0eef, '\u{316d}'),
+    (0x0ef0, '\u{3171}'),
+    (0x0ef1, '\u{3178}'),
+    (0x0ef2, '\u{317f}'),
+    (0x0ef3, '\u{3181}'),
+    (0x0ef4, '\u{3184}'),
+    (0x0ef5, '\u{3186}'),
+    (0x0ef6, '\u{318d}'),
+    (0x0ef7, '\u{318e}'),
+    (0x0ef8, '\u{11eb}'),
+    (0x0ef9, '\u{11f0}'),
+    (0x0efa, '\u{11f9}'),
+    (0x13bc, '\u{152}'),
+    (0x13bd, '\u{153}'),
+    (0x13be, '\u{178}'),
+    (0x20ac, '\u{20ac}'),
+    (0x100012c, '\u{12c}'),
+    (0x100012d, '\u{12d}'),
+    (0x1000174, '\u{174}'),
+    (0x1000175, '\u{175}'),
+    (0x1000176, '\u{176}'),
+    (0x1000177, '\u{177}'),
+    (0x100018f, '\u{18f}'),
+    (0x100019f, '\u{19f}'),
+    (0x10001a0, '\u{1a0}'),
+    (0x10001a1, '\u{1a1}'),
+    (0x10001af, '\u{1af}'),
+    (0x10001b0, '\u{1b0}'),
+    (0x10001b5, '\u{1b5}'),
+    (0x10001b6, '\u{1b6}'),
+    (0x10001b7, '\u{1b7}'),
+    (0x10001d1, '\u{1d1}'),
+    (0x10001d2, '\u{1d2}'),
+    (0x10001e6, '\u{1e6}'),
+    (0x10001e7, '\u{1e7}'),
+    (0x1000259, '\u{259}'),
+    (0x1000275, '\u{275}'),
+    (0x1000292, '\u{292}'),
+    (0x1000300, '\u{300}'),
+    (0x1000301, '\u{301}'),
+    (0x1000303, '\u{303}'),
+    (0x1000309, '\u{309}'),
+    (0x1000323, '\u{323}'),
+    (0x1000492, '\u{492}'),
+    (0x1000493, '\u{493}'),
+    (0x1000496, '\u{496}'),
+    (0x1000497, '\u{497}'),
+    (0x100049a, '\u{49a}'),
+    (0x100049b, '\u{49b}'),
+    (0x100049c, '\u{49c}'),
+    (0x100049d, '\u{49d}'),
+    (0x10004a2, '\u{4a2}'),
+    (0x10004a3, '\u{4a3}'),
+    (0x10004ae, '\u{4ae}'),
+    (0x10004af, '\u{4af}'),
+    (0x10004b0, '\u{4b0}'),
+    (0x10004b1, '\u{4b1}'),
+    (0x10004b2, '\u{4b2}'),
+    (0x10004b3, '\u{4b3}'),
+    (0x10004b6, '\u{4b6}'),
+    (0x10004b7, '\u{4b7}'),
+    (0x10004b8, '\u{4b8}'),
+    (0x10004b9, '\u{4b9}'),
+    (0x10004ba, '\u{4ba}'),
+    (0x10004bb, '\u{4bb}'),
+    (0x10004d8, '\u{4d8}'),
+    (0x10004d9, '\u{4d9}'),
+    (0x10004e2, '\u{4e2}'),
+    (0x10004e3, '\u{4e3}'),
+    (0x10004e8, '\u{4e8}'),
+    (0x10004e9, '\u{4e9}'),
+    (0x10004ee, '\u{4ee}'),
+    (0x10004ef, '\u{4ef}'),
+    (0x1000531, '\u{531}'),
+    (0x1000532, '\u{532}'),
+    (0x1000533, '\u{533}'),
+    (0x1000534, '\u{534}'),
+    (0x1000535, '\u{535}'),
+    (0x1000536, '\u{536}'),
+    (0x1000537, '\u{537}'),
+    (0x1000538, '\u{538}'),
+    (0x1000539, '\u{539}'),
+    (0x100053a, '\u{53a}'),
+    (0x100053b, '\u{53b}'),
+    (0x100053c, '\u{53c}'),
+    (0x100053d, '\u{53d}'),
+    (0x100053e, '\u{53e}'),
+    (0x100053f, '\u{53f}'),
+    (0x1000540, '\u{540}'),
+    (0x1000541, '\u{541}'),
+    (0x1000542, '\u{542}'),
+    (0x1000543, '\u{543}'),
+    (0x1000544, '\u{544}'),
+    (0x1000545, '\u{545}'),
+    (0x1000546, '\u{546}'),
+    (0x1000547, '\u{547}'),
+    (0x1000548, '\u{548}'),
+    (0x1000549, '\u{549}'),
+    (0x100054a, '\u{54a}'),
+    (0x100054b, '\u{54b}'),
+    (0x100054c, '\u{54c}'),
+    (0x100054d, '\u{54d}'),
+    (0x100054e, '\u{54e}'),
+    (0x100054f, '\u{54f}'),
+    (0x1000550, '\u{550}'),
+    (0x1000551, '\u{551}'),
+    (0x1000552, '\u{552}'),
+    (0x1000553, '\u{553}'),
+    (0x1000554, '\u{554}'),
+    (0x1000555, '\u{555}'),
+    (0x1000556, '\u{556}'),
+    (0x100055a, '\u{55a}'),
+    (0x100055b, '\u{55b}'),
+    (0x100055c, '\u{55c}'),
+    (0x100055d, '\u{55d}'),
+    (0x100055e, '\u{55e}'),
+    (0x1000561, '\u{561}'),
+    (0x1000562, '\u{562}'),
+    (0x1000563, '\u{563}'),
+    (0x1000564, '\u{564}'),
+    (0x1000565, '\u{565}'),
+    (0x1000566, '\u{566}'),
+    (0x1000567, '\u{567}'),
+    (0x1000568, '\u{568}'),
+    (0x1000569, '\u{569}'),
+    (0x100056a, '\u{56a}'),
+    (0x100056b, '\u{56b}'),
+    (0x100056c, '\u{56c}'),
+    (0x100056d, '\u{56d}'),
+    (0x100056e, '\u{56e}'),
+    (0x100056f, '\u{56f}'),
+    (0x1000570, '\u{570}'),
+    (0x1000571, '\u{571}'),
+    (0x1000572, '\u{572}'),
+    (0x1000573, '\u{573}'),
+    (0x1000574, '\u{574}'),
+    (0x1000575, '\u{575}'),
+    (0x1000576, '\u{576}'),
+    (0x1000577, '\u{577}'),
+    (0x1000578, '\u{578}'),
+    (0x1000579, '\u{579}'),
+    (0x100057a, '\u{57a}'),
+    (0x100057b, '\u{57b}'),
+    (0x100057c, '\u{57c}'),
+    (0x100057d, '\u{57d}'),
+    (0x100057e, '\u{57e}'),
+    (0x100057f, '\u{57f}'),
+    (0x1000580, '\u{580}'),
+    (0x1000581, '\u{581}'),
+    (0x1000582, '\u{582}'),
+    (0x1000583, '\u{583}'),
+    (0x1000584, '\u{584}'),
+    (0x1000585, '\u{585}'),
+    (0x1000586, '\u{586}'),
+    (0x1000587, '\u{587}'),
+    (0x1000589, '\u{589}'),
+    (0x100058a, '\u{58a}'),
+    (0x1000653, '\u{653}'),
+    (0x1000654, '\u{654}'),
+    (0x1000655, '\u{655}'),
+    (0x1000660, '\u{660}'),
+    (0x1000661, '\u{661}'),
+    (0x1000662, '\u{662}'),
+    (0x1000663, '\u{663}'),
+    (0x1000664, '\u{664}'),
+    (0x1000665, '\u{665}'),
+    (0x1000666, '\u{666}'),
+    (0x1000667, '\u{667}'),
+    (0x1000668, '\u{668}'),
+    (0x1000669, '\u{669}'),
+    (0x100066a, '\u{66a}'),
+    (0x1000670, '\u{670}'),
+    (0x1000679, '\u{679}'),
+    (0x100067e, '\u{67e}'),
+    (0x1000686, '\u{686}'),
+    (0x1000688, '\u{688}'),
+    (0x1000691, '\u{691}'),
+    (0x1000698, '\u{698}'),
+    (0x10006a4, '\u{6a4}'),
+    (0x10006a9, '\u{6a9}'),
+    (0x10006af, '\u{6af}'),
+    (0x10006ba, '\u{6ba}'),
+    (0x10006be, '\u{6be}'),
+    (0x10006c1, '\u{6c1}'),
+    (0x10006cc, '\u{6cc}'),
+    (0x10006d2, '\u{6d2}'),
+    (0x10006d4, '\u{6d4}'),
+    (0x10006f0, '\u{6f0}'),
+    (0x10006f1, '\u{6f1}'),
+    (0x10006f2, '\u{6f2}'),
+    (0x10006f3, '\u{6f3}'),
+    (0x10006f4, '\u{6f4}'),
+    (0x10006f5, '\u{6f5}'),
+    (0x10006f6, '\u{6f6}'),
+    (0x10006f7, '\u{6f7}'),
+    (0x10006f8, '\u{6f8}'),
+    (0x10006f9, '\u{6f9}'),
+    (0x1000d82, '\u{d82}'),
+    (0x1000d83, '\u{d83}'),
+    (0x1000d85, '\u{d85}'),
+    (0x1000d86, '\u{d86}'),
+    (0x1000d87, '\u{d87}'),
+    (0x1000d88, '\u{d88}'),
+    (0x1000d89, '\u{d89}'),
+    (0x1000d8a, '\u{d8a}'),
+    (0x1000d8b, '\u{d8b}'),
+    (0x1000d8c, '\u{d8c}'),
+    (0x1000d8d, '\u{d8d}'),
+    (0x1000d8e, '\u{d8e}'),
+    (0x1000d8f, '\u{d8f}'),
+    (0x1000d90, '\u{d90}'),
+    (0x1000d91, '\u{d91}'),
+    (0x1000d92, '\u{d92}'),
+    (0x1000d93, '\u{d93}'),
+    (0x1000d94, '\u{d94}'),
+    (0x1000d95, '\u{d95}'),
+    (0x1000d96, '\u{d96}'),
+    (0x1000d9a, '\u{d9a}'),
+    (0x1000d9b, '\u{d9b}'),
+    (0x1000d9c, '\u{d9c}'),
+    (0x1000d9d, '\u{d9d}'),
+    (0x1000d9e, '\u{d9e}'),
+    (0x1000d9f, '\u{d9f}'),
+    (0x1000da0, '\u{da0}'),
+    (0x1000da1, '\u{da1}'),
+    (0x1000da2, '\u{da2}'),
+    (0x1000da3, '\u{da3}'),
+    (0x1000da4, '\u{da4}'),
+    (0x1000da5, '\u{da5}'),
+    (0x1000da6, '\u{da6}'),
+    (0x1000da7, '\u{da7}'),
+    (0x1000da8, '\u{da8}'),
+    (0x1000da9, '\u{da9}'),
+    (0x1000daa, '\u{daa}'),
+    (0x1000dab, '\u{dab}'),
+    (0x1000dac, '\u{dac}'),
+    (0x1000dad, '\u{dad}'),
+    (0x1000dae, '\u{dae}'),
+    (0x1000daf, '\u{daf}'),
+    (0x1000db0, '\u{db0}'),
+    (0x1000db1, '\u{db1}'),
+    (0x1000db3, '\u{db3}'),
+    (0x1000db4, '\u{db4}'),
+    (0x1000db5, '\u{db5}'),
+    (0x1000db6, '\u{db6}'),
+    (0x1000db7, '\u{db7}'),
+    (0x1000db8, '\u{db8}'),
+    (0x1000db9, '\u{db9}'),
+    (0x1000dba, '\u{dba}'),
+    (0x1000dbb, '\u{dbb}'),
+    (0x1000dbd, '\u{dbd}'),
+    (0x1000dc0, '\u{dc0}'),
+    (0x1000dc1, '\u{dc1}'),
+    (0x1000dc2, '\u{dc2}'),
+    (0x1000dc3, '\u{dc3}'),
+    (0x1000dc4, '\u{dc4}'),
+    (0x1000dc5, '\u{dc5}'),
+    (0x1000dc6, '\u{dc6}'),
+    (0x1000dca, '\u{dca}'),
+    (0x1000dcf, '\u{dcf}'),
+    (0x1000dd0, '\u{dd0}'),
+    (0x1000dd1, '\u{dd1}'),
+    (0x1000dd2, '\u{dd2}'),
+    (0x1000dd3, '\u{dd3}'),
+    (0x1000dd4, '\u{dd4}'),
+    (0x1000dd6, '\u{dd6}'),
+    (0x1000dd8, '\u{dd8}'),
+    (0x1000dd9, '\u{dd9}'),
+    (0x1000dda, '\u{dda}'),
+    (0x1000ddb, '\u{ddb}'),
+    (0x1000ddc, '\u{ddc}'),
+    (0x1000ddd, '\u{ddd}'),
+    (0x1000dde, '\u{dde}'),
+    (0x1000ddf, '\u{ddf}'),
+    (0x1000df2, '\u{df2}'),
+    (0x1000df3, '\u{df3}'),
+    (0x1000df4, '\u{df4}'),
+    (0x10010d0, '\u{10d0}'),
+    (0x10010d1, '\u{10d1}'),
+    (0x10010d2, '\u{10d2}'),
+    (0x10010d3, '\u{10d3}'),
+    (0x10010d4, '\u{10d4}'),
+    (0x10010d5, '\u{10d5}'),
+    (0x10010d6, '\u{10d6}'),
+    (0x10010d7, '\u{10d7}'),
+    (0x10010d8, '\u{10d8}'),
+    (0x10010d9, '\u{10d9}'),
+    (0x10010da, '\u{10da}'),
+    (0x10010db, '\u{10db}'),
+    (0x10010dc, '\u{10dc}'),
+    (0x10010dd, '\u{10dd}'),
+    (0x10010de, '\u{10de}'),
+    (0x10010df, '\u{10df}'),
+    (0x10010e0, '\u{10e0}'),
+    (0x10010e1, '\u{10e1}'),
+    (0x10010e2, '\u{10e2}'),
+    (0x10010e3, '\u{10e3}'),
+    (0x10010e4, '\u{10e4}'),
+    (0x10010e5, '\u{10e5}'),
+    (0x10010e6, '\u{10e6}'),
+    (0x10010e7, '\u{10e7}'),
+    (0x10010e8, '\u{10e8}'),
+    (0x10010e9, '\u{10e9}'),
+    (0x10010ea, '\u{10ea}'),
+    (0x10010eb, '\u{10eb}'),
+    (0x10010ec, '\u{10ec}'),
+    (0x10010ed, '\u{10ed}'),
+    (0x10010ee, '\u{10ee}'),
+    (0x10010ef, '\u{10ef}'),
+    (0x10010f0, '\u{10f0}'),
+    (0x10010f1, '\u{10f1}'),
+    (0x10010f2, '\u{10f2}'),
+    (0x10010f3, '\u{10f3}'),
+    (0x10010f4, '\u{10f4}'),
+    (0x10010f5, '\u{10f5}'),
+    (0x10010f6, '\u{10f6}'),
+    (0x1001e02, '\u{1e02}'),
+    (0x1001e03, '\u{1e03}'),
+    (0x1001e0a, '\u{1e0a}'),
+    (0x1001e0b, '\u{1e0b}'),
+    (0x1001e1e, '\u{1e1e}'),
+    (0x1001e1f, '\u{1e1f}'),
+    (0x1001e36, '\u{1e36}'),
+    (0x1001e37, '\u{1e37}'),
+    (0x1001e40, '\u{1e40}'),
+    (0x1001e41, '\u{1e41}'),
+    (0x1001e56, '\u{1e56}'),
+    (0x1001e57, '\u{1e57}'),
+    (0x1001e60, '\u{1e60}'),
+    (0x1001e61, '\u{1e61}'),
+    (0x1001e6a, '\u{1e6a}'),
+    (0x1001e6b, '\u{1e6b}'),
+    (0x1001e80, '\u{1e80}'),
+    (0x1001e81, '\u{1e81}'),
+    (0x1001e82, '\u{1e82}'),
+    (0x1001e83, '\u{1e83}'),
+    (0x1001e84, '\u{1e84}'),
+    (0x1001e85, '\u{1e85}'),
+    (0x1001e8a, '\u{1e8a}'),
+    (0x1001e8b, '\u{1e8b}'),
+    (0x1001ea0, '\u{1ea0}'),
+    (0x1001ea1, '\u{1ea1}'),
+    (0x1001ea2, '\u{1ea2}'),
+    (0x1001ea3, '\u{1ea3}'),
+    (0x1001ea4, '\u{1ea4}'),
+    (0x1001ea5, '\u{1ea5}'),
+    (0x1001ea6, '\u{1ea6}'),
+    (0x1001ea7, '\u{1ea7}'),
+    (0x1001ea8, '\u{1ea8}'),
+    (0x1001ea9, '\u{1ea9}'),
+    (0x1001eaa, '\u{1eaa}'),
+    (0x1001eab, '\u{1eab}'),
+    (0x1001eac, '\u{1eac}'),
+    (0x1001ead, '\u{1ead}'),
+    (0x1001eae, '\u{1eae}'),
+    (0x1001eaf, '\u{1eaf}'),
+    (0x1001eb0, '\u{1eb0}'),
+    (0x1001eb1, '\u{1eb1}'),
+    (0x1001eb2, '\u{1eb2}'),
+    (0x1001eb3, '\u{1eb3}'),
+    (0x1001eb4, '\u{1eb4}'),
+    (0x1001eb5, '\u{1eb5}'),
+    (0x1001eb6, '\u{1eb6}'),
+    (0x1001eb7, '\u{1eb7}'),
+    (0x1001eb8, '\u{1eb8}'),
+    (0x1001eb9, '\u{1eb9}'),
+    (0x1001eba, '\u{1eba}'),
+    (0x1001ebb, '\u{1ebb}'),
+    (0x1001ebc, '\u{1ebc}'),
+    (0x1001ebd, '\u{1ebd}'),
+    (0x1001ebe, '\u{1ebe}'),
+    (0x1001ebf, '\u{1ebf}'),
+    (0x1001ec0, '\u{1ec0}'),
+    (0x1001ec1, '\u{1ec1}'),
+    (0x1001ec2, '\u{1ec2}'),
+    (0x1001ec3, '\u{1ec3}'),
+    (0x1001ec4, '\u{1ec4}'),
+    (0x1001ec5, '\u{1ec5}'),
+    (0x1001ec6, '\u{1ec6}'),
+    (0x1001ec7, '\u{1ec7}'),
+    (0x1001ec8, '\u{1ec8}'),
+    (0x1001ec9, '\u{1ec9}'),
+    (0x1001eca, '\u{1eca}'),
+    (0x1001ecb, '\u{1ecb}'),
+    (0x1001ecc, '\u{1ecc}'),
+    (0x1001ecd, '\u{1ecd}'),
+    (0x1001ece, '\u{1ece}'),
+    (0x1001ecf, '\u{1ecf}'),
+    (0x1001ed0, '\u{1ed0}'),
+    (0x1001ed1, '\u{1ed1}'),
+    (0x1001ed2, '\u{1ed2}'),
+    (0x1001ed3, '\u{1ed3}'),
+    (0x1001ed4, '\u{1ed4}'),
+    (0x1001ed5, '\u{1ed5}'),
+    (0x1001ed6, '\u{1ed6}'),
+    (0x1001ed7, '\u{1ed7}'),
+    (0x1001ed8, '\u{1ed8}'),
+    (0x1001ed9, '\u{1ed9}'),
+    (0x1001eda, '\u{1eda}'),
+    (0x1001edb, '\u{1edb}'),
+    (0x1001edc, '\u{1edc}'),
+    (0x1001edd, '\u{1edd}'),
+    (0x1001ede, '\u{1ede}'),
+    (0x1001edf, '\u{1edf}'),
+    (0x1001ee0, '\u{1ee0}'),
+    (0x1001ee1, '\u{1ee1}'),
+    (0x1001ee2, '\u{1ee2}'),
+    (0x1001ee3, '\u{1ee3}'),
+    (0x1001ee4, '\u{1ee4}'),
+    (0x1001ee5, '\u{1ee5}'),
+    (0x1001ee6, '\u{1ee6}'),
+    (0x1001ee7, '\u{1ee7}'),
+    (0x1001ee8, '\u{1ee8}'),
+    (0x1001ee9, '\u{1ee9}'),
+    (0x1001eea, '\u{1eea}'),
+    (0x1001eeb, '\u{1eeb}'),
+    (0x1001eec, '\u{1eec}'),
+    (0x1001eed, '\u{1eed}'),
+    (0x1001eee, '\u{1eee}'),
+    (0x1001eef, '\u{1eef}'),
+    (0x1001ef0, '\u{1ef0}'),
+    (0x1001ef1, '\u{1ef1}'),
+    (0x1001ef2, '\u{1ef2}'),
+    (0x1001ef3, '\u{1ef3}'),
+    (0x1001ef4, '\u{1ef4}'),
+    (0x1001ef5, '\u{1ef5}'),
+    (0x1001ef6, '\u{1ef6}'),
+    (0x1001ef7, '\u{1ef7}'),
+    (0x1001ef8, '\u{1ef8}'),
+    (0x1001ef9, '\u{1ef9}'),
+    (0x1002070, '\u{2070}'),
+    (0x1002074, '\u{2074}'),
+    (0x1002075, '\u{2075}'),
+    (0x1002076, '\u{2076}'),
+    (0x1002077, '\u{2077}'),
+    (0x1002078, '\u{2078}'),
+    (0x1002079, '\u{2079}'),
+    (0x1002080, '\u{2080}'),
+    (0x1002081, '\u{2081}'),
+    (0x1002082, '\u{2082}'),
+    (0x1002083, '\u{2083}'),
+    (0x1002084, '\u{2084}'),
+    (0x1002085, '\u{2085}'),
+    (0x1002086, '\u{2086}'),
+    (0x1002087, '\u{2087}'),
+    (0x1002088, '\u{2088}'),
+    (0x1002089, '\u{2089}'),
+    (0x10020a0, '\u{20a0}'),
+    (0x10020a1, '\u{20a1}'),
+    (0x10020a2, '\u{20a2}'),
+    (0x10020a3, '\u{20a3}'),
+    (0x10020a4, '\u{20a4}'),
+    (0x10020a5, '\u{20a5}'),
+    (0x10020a6, '\u{20a6}'),
+    (0x10020a7, '\u{20a7}'),
+    (0x10020a8, '\u{20a8}'),
+    (0x10020a9, '\u{20a9}'),
+    (0x10020aa, '\u{20aa}'),
+    (0x10020ab, '\u{20ab}'),
+    (0x1002202, '\u{2202}'),
+    (0x1002205, '\u{2205}'),
+    (0x1002208, '\u{2208}'),
+    (0x1002209, '\u{2209}'),
+    (0x100220b, '\u{220b}'),
+    (0x100221a, '\u{221a}'),
+    (0x100221b, '\u{221b}'),
+    (0x100221c, '\u{221c}'),
+    (0x100222c, '\u{222c}'),
+    (0x100222d, '\u{222d}'),
+    (0x1002235, '\u{2235}'),
+    (0x1002262, '\u{2262}'),
+    (0x1002263, '\u{2263}'),
+    (0x1002800, '\u{2800}'),
+    (0x1002801, '\u{2801}'),
+    (0x1002802, '\u{2802}'),
+    (0x1002803, '\u{2803}'),
+    (0x1002804, '\u{2804}'),
+    (0x1002805, '\u{2805}'),
+    (0x1002806, '\u{2806}'),
+    (0x1002807, '\u{2807}'),
+    (0x1002808, '\u{2808}'),
+    (0x1002809, '\u{2809}'),
+    (0x100280a, '\u{280a}'),
+    (0x100280b, '\u{280b}'),
+    (0x100280c, '\u{280c}'),
+    (0x100280d, '\u{280d}'),
+    (0x100280e, '\u{280e}'),
+    (0x100280f, '\u{280f}'),
+    (0x1002810, '\u{2810}'),
+    (0x1002811, '\u{2811}'),
+    (0x1002812, '\u{2812}'),
+    (0x1002813, '\u{2813}'),
+    (0x1002814, '\u{2814}'),
+    (0x1002815, '\u{2815}'),
+    (0x1002816, '\u{2816}'),
+    (0x1002817, '\u{2817}'),
+    (0x1002818, '\u{2818}'),
+    (0x1002819, '\u{2819}'),
+    (0x100281a, '\u{281a}'),
+    (0x100281b, '\u{281b}'),
+    (0x100281c, '\u{281c}'),
+    (0x100281d, '\u{281d}'),
+    (0x100281e, '\u{281e}'),
+    (0x100281f, '\u{281f}'),
+    (0x1002820, '\u{2820}'),
+    (0x1002821, '\u{2821}'),
+    (0x1002822, '\u{2822}'),
+    (0x1002823, '\u{2823}'),
+    (0x1002824, '\u{2824}'),
+    (0x1002825, '\u{2825}'),
+    (0x1002826, '\u{2826}'),
+    (0x1002827, '\u{2827}'),
+    (0x1002828, '\u{2828}'),
+    (0x1002829, '\u{2829}'),
+    (0x100282a, '\u{282a}'),
+    (0x100282b, '\u{282b}'),
+    (0x100282c, '\u{282c}'),
+    (0x100282d, '\u{282d}'),
+    (0x100282e, '\u{282e}'),
+    (0x100282f, '\u{282f}'),
+    (0x1002830, '\u{2830}'),
+    (0x1002831, '\u{2831}'),
+    (0x1002832, '\u{2832}'),
+    (0x1002833, '\u{2833}'),
+    (0x1002834, '\u{2834}'),
+    (0x1002835, '\u{2835}'),
+    (0x1002836, '\u{2836}'),
+    (0x1002837, '\u{2837}'),
+    (0x1002838, '\u{2838}'),
+    (0x1002839, '\u{2839}'),
+    (0x100283a, '\u{283a}'),
+    (0x100283b, '\u{283b}'),
+    (0x100283c, '\u{283c}'),
+    (0x100283d, '\u{283d}'),
+    (0x100283e, '\u{283e}'),
+    (0x100283f, '\u{283f}'),
+    (0x1002840, '\u{2840}'),
+    (0x1002841, '\u{2841}'),
+    (0x1002842, '\u{2842}'),
+    (0x1002843, '\u{2843}'),
+    (0x1002844, '\u{2844}'),
+    (0x1002845, '\u{2845}'),
+    (0x1002846, '\u{2846}'),
+    (0x1002847, '\u{2847}'),
+    (0x1002848, '\u{2848}'),
+    (0x1002849, '\u{2849}'),
+    (0x100284a, '\u{284a}'),
+    (0x100284b, '\u{284b}'),
+    (0x100284c, '\u{284c}'),
+    (0x100284d, '\u{284d}'),
+    (0x100284e, '\u{284e}'),
+    (0x100284f, '\u{284f}'),
+    (0x1002850, '\u{2850}'),
+    (0x1002851, '\u{2851}'),
+    (0x1002852, '\u{2852}'),
+    (0x1002853, '\u{2853}'),
+    (0x1002854, '\u{2854}'),
+    (0x1002855, '\u{2855}'),
+    (0x1002856, '\u{2856}'),
+    (0x1002857, '\u{2857}'),
+    (0x1002858, '\u{2858}'),
+    (0x1002859, '\u{2859}'),
+    (0x100285a, '\u{285a}'),
+    (0x100285b, '\u{285b}'),
+    (0x100285c, '\u{285c}'),
+    (0x100285d, '\u{285d}'),
+    (0x100285e, '\u{285e}'),
+    (0x100285f, '\u{285f}'),
+    (0x1002860, '\u{2860}'),
+    (0x1002861, '\u{2861}'),
+    (0x1002862, '\u{2862}'),
+    (0x1002863, '\u{2863}'),
+    (0x1002864, '\u{2864}'),
+    (0x1002865, '\u{2865}'),
+    (0x1002866, '\u{2866}'),
+    (0x1002867, '\u{2867}'),
+    (0x1002868, '\u{2868}'),
+    (0x1002869, '\u{2869}'),
+    (0x100286a, '\u{286a}'),
+    (0x100286b, '\u{286b}'),
+    (0x100286c, '\u{286c}'),
+    (0x100286d, '\u{286d}'),
+    (0x100286e, '\u{286e}'),
+    (0x100286f, '\u{286f}'),
+    (0x1002870, '\u{2870}'),
+    (0x1002871, '\u{2871}'),
+    (0x1002872, '\u{2872}'),
+    (0x1002873, '\u{2873}'),
+    (0x1002874, '\u{2874}'),
+    (0x1002875, '\u{2875}'),
+    (0x1002876, '\u{2876}'),
+    (0x1002877, '\u{2877}'),
+    (0x1002878, '\u{2878}'),
+    (0x1002879, '\u{2879}'),
+    (0x100287a, '\u{287a}'),
+    (0x100287b, '\u{287b}'),
+    (0x100287c, '\u{287c}'),
+    (0x100287d, '\u{287d}'),
+    (0x100287e, '\u{287e}'),
+    (0x100287f, '\u{287f}'),
+    (0x1002880, '\u{2880}'),
+    (0x1002881, '\u{2881}'),
+    (0x1002882, '\u{2882}'),
+    (0x1002883, '\u{2883}'),
+    (0x1002884, '\u{2884}'),
+    (0x1002885, '\u{2885}'),
+    (0x1002886, '\u{2886}'),
+    (0x1002887, '\u{2887}'),
+    (0x1002888, '\u{2888}'),
+    (0x1002889, '\u{2889}'),
+    (0x100288a, '\u{288a}'),
+    (0x100288b, '\u{288b}'),
+    (0x100288c, '\u{288c}'),
+    (0x100288d, '\u{288d}'),
+    (0x100288e, '\u{288e}'),
+    (0x100288f, '\u{288f}'),
+    (0x1002890, '\u{2890}'),
+    (0x1002891, '\u{2891}'),
+    (0x1002892, '\u{2892}'),
+    (0x1002893, '\u{2893}'),
+    (0x1002894, '\u{2894}'),
+    (0x1002895, '\u{2895}'),
+    (0x1002896, '\u{2896}'),
+    (0x1002897, '\u{2897}'),
+    (0x1002898, '\u{2898}'),
+    (0x1002899, '\u{2899}'),
+    (0x100289a, '\u{289a}'),
+    (0x100289b, '\u{289b}'),
+    (0x100289c, '\u{289c}'),
+    (0x100289d, '\u{289d}'),
+    (0x100289e, '\u{289e}'),
+    (0x100289f, '\u{289f}'),
+    (0x10028a0, '\u{28a0}'),
+    (0x10028a1, '\u{28a1}'),
+    (0x10028a2, '\u{28a2}'),
+    (0x10028a3, '\u{28a3}'),
+    (0x10028a4, '\u{28a4}'),
+    (0x10028a5, '\u{28a5}'),
+    (0x10028a6, '\u{28a6}'),
+    (0x10028a7, '\u{28a7}'),
+    (0x10028a8, '\u{28a8}'),
+    (0x10028a9, '\u{28a9}'),
+    (0x10028aa, '\u{28aa}'),
+    (0x10028ab, '\u{28ab}'),
+    (0x10028ac, '\u{28ac}'),
+    (0x10028ad, '\u{28ad}'),
+    (0x10028ae, '\u{28ae}'),
+    (0x10028af, '\u{28af}'),
+    (0x10028b0, '\u{28b0}'),
+    (0x10028b1, '\u{28b1}'),
+    (0x10028b2, '\u{28b2}'),
+    (0x10028b3, '\u{28b3}'),
+    (0x10028b4, '\u{28b4}'),
+    (0x10028b5, '\u{28b5}'),
+    (0x10028b6, '\u{28b6}'),
+    (0x10028b7, '\u{28b7}'),
+    (0x10028b8, '\u{28b8}'),
+    (0x10028b9, '\u{28b9}'),
+    (0x10028ba, '\u{28ba}'),
+    (0x10028bb, '\u{28bb}'),
+    (0x10028bc, '\u{28bc}'),
+    (0x10028bd, '\u{28bd}'),
+    (0x10028be, '\u{28be}'),
+    (0x10028bf, '\u{28bf}'),
+    (0x10028c0, '\u{28c0}'),
+    (0x10028c1, '\u{28c1}'),
+    (0x10028c2, '\u{28c2}'),
+    (0x10028c3, '\u{28c3}'),
+    (0x10028c4, '\u{28c4}'),
+    (0x10028c5, '\u{28c5}'),
+    (0x10028c6, '\u{28c6}'),
+    (0x10028c7, '\u{28c7}'),
+    (0x10028c8, '\u{28c8}'),
+    (0x10028c9, '\u{28c9}'),
+    (0x10028ca, '\u{28ca}'),
+    (0x10028cb, '\u{28cb}'),
+    (0x10028cc, '\u{28cc}'),
+    (0x10028cd, '\u{28cd}'),
+    (0x10028ce, '\u{28ce}'),
+    (0x10028cf, '\u{28cf}'),
+    (0x10028d0, '\u{28d0}'),
+    (0x10028d1, '\u{28d1}'),
+    (0x10028d2, '\u{28d2}'),
+    (0x10028d3, '\u{28d3}'),
+    (0x10028d4, '\u{28d4}'),
+    (0x10028d5, '\u{28d5}'),
+    (0x10028d6, '\u{28d6}'),
+    (0x10028d7, '\u{28d7}'),
+    (0x10028d8, '\u{28d8}'),
+    (0x10028d9, '\u{28d9}'),
+    (0x10028da, '\u{28da}'),
+    (0x10028db, '\u{28db}'),
+    (0x10028dc, '\u{28dc}'),
+    (0x10028dd, '\u{28dd}'),
+    (0x10028de, '\u{28de}'),
+    (0x10028df, '\u{28df}'),
+    (0x10028e0, '\u{28e0}'),
+    (0x10028e1, '\u{28e1}'),
+    (0x10028e2, '\u{28e2}'),
+    (0x10028e3, '\u{28e3}'),
+    (0x10028e4, '\u{28e4}'),
+    (0x10028e5, '\u{28e5}'),
+    (0x10028e6, '\u{28e6}'),
+    (0x10028e7, '\u{28e7}'),
+    (0x10028e8, '\u{28e8}'),
+    (0x10028e9, '\u{28e9}'),
+    (0x10028ea, '\u{28ea}'),
+    (0x10028eb, '\u{28eb}'),
+    (0x10028ec, '\u{28ec}'),
+    (0x10028ed, '\u{28ed}'),
+    (0x10028ee, '\u{28ee}'),
+    (0x10028ef, '\u{28ef}'),
+    (0x10028f0, '\u{28f0}'),
+    (0x10028f1, '\u{28f1}'),
+    (0x10028f2, '\u{28f2}'),
+    (0x10028f3, '\u{28f3}'),
+    (0x10028f4, '\u{28f4}'),
+    (0x10028f5, '\u{28f5}'),
+    (0x10028f6, '\u{28f6}'),
+    (0x10028f7, '\u{28f7}'),
+    (0x10028f8, '\u{28f8}'),
+    (0x10028f9, '\u{28f9}'),
+    (0x10028fa, '\u{28fa}'),
+    (0x10028fb, '\u{28fb}'),
+    (0x10028fc, '\u{28fc}'),
+    (0x10028fd, '\u{28fd}'),
+    (0x10028fe, '\u{28fe}'),
+    (0x10028ff, '\u{28ff}'),
+];
+
+/// The names of the keysyms without the `XK_` prefix, sorted by name.
+static NAMES: [(&str, u32); 2104] = [
+    ("0", XK_0),
+    ("1", XK_1),
+    ("2", XK_2),
+    ("3", XK_3),
+    ("3270_AltCursor", XK_3270_AltCursor),
+    ("3270_Attn", XK_3270_Attn),
+    ("3270_BackTab", XK_3270_BackTab),
+    ("3270_ChangeScreen", XK_3270_ChangeScreen),
+    ("3270_Copy", XK_3270_Copy),
+    ("3270_CursorBlink", XK_3270_CursorBlink),
+    ("3270_CursorSelect", XK_3270_CursorSelect),
+    ("3270_DeleteWord", XK_3270_DeleteWord),
+    ("3270_Duplicate", XK_3270_Duplicate),
+    ("3270_Enter", XK_3270_Enter),
+    ("3270_EraseEOF", XK_3270_EraseEOF),
+    ("3270_EraseInput", XK_3270_EraseInput),
+    ("3270_ExSelect", XK_3270_ExSelect),
+    ("3270_FieldMark", XK_3270_FieldMark),
+    ("3270_Ident", XK_3270_Ident),
+    ("3270_Jump", XK_3270_Jump),
+    ("3270_KeyClick", XK_3270_KeyClick),
+    ("3270_Left2", XK_3270_Left2),
+    ("3270_PA1", XK_3270_PA1),
+    ("3270_PA2", XK_3270_PA2),
+    ("3270_PA3", XK_3270_PA3),
+    ("3270_Play", XK_3270_Play),
+    ("3270_PrintScreen", XK_3270_PrintScreen),
+    ("3270_Quit", XK_3270_Quit),
+    ("3270_Record", XK_3270_Record),
+    ("3270_Reset", XK_3270_Reset),
+    ("3270_Right2", XK_3270_Right2),
+    ("3270_Rule", XK_3270_Rule),
+    ("3270_Setup", XK_3270_Setup),
+    ("3270_Test", XK_3270_Test),
+    ("4", XK_4),
+    ("5", XK_5),
+    ("6", XK_6),
+    ("7", XK_7),
+    ("8", XK_8),
+    ("9", XK_9),
+    ("A", XK_A),
+    ("AE", XK_AE),
+    ("Aacute", XK_Aacute),
+    ("Abelowdot", XK_Abelowdot),
+    ("Abreve", XK_Abreve),
+    ("Abreveacute", XK_Abreveacute),
+    ("Abrevebelowdot", XK_Abrevebelowdot),
+    ("Abrevegrave", XK_Abrevegrave),
+    ("Abrevehook", XK_Abrevehook),
+    ("Abrevetilde", XK_Abrevetilde),
+    ("AccessX_Enable", XK_AccessX_Enable),
+    ("AccessX_Feedback_Enable", XK_AccessX_Feedback_Enable),
+    ("Acircumflex", XK_Acircumflex),
+    ("Acircumflexacute", XK_Acircumflexacute),
+    ("Acircumflexbelowdot", XK_Acircumflexbelowdot),
+    ("Acircumflexgrave", XK_Acircumflexgrave),
+    ("Acircumflexhook", XK_Acircumflexhook),
+    ("Acircumflextilde", XK_Acircumflextilde),
+    ("Adiaeresis", XK_Adiaeresis),
+    ("Agrave", XK_Agrave),
+    ("Ahook", XK_Ahook),
+    ("Alt_L", XK_Alt_L),
+    ("Alt_R", XK_Alt_R),
+    ("Amacron", XK_Amacron),
+    ("Aogonek", XK_Aogonek),
+    ("Arabic_0", XK_Arabic_0),
+    ("Arabic_1", XK_Arabic_1),
+    ("Arabic_2", XK_Arabic_2),
+    ("Arabic_3", XK_Arabic_3),
+    ("Arabic_4", XK_Arabic_4),
+    ("Arabic_5", XK_Arabic_5),
+    ("Arabic_6", XK_Arabic_6),
+    ("Arabic_7", XK_Arabic_7),
+    ("Arabic_8", XK_Arabic_8),
+    ("Arabic_9", XK_Arabic_9),
+    ("Arabic_ain", XK_Arabic_ain),
+    ("Arabic_alef", XK_Arabic_alef),
+    ("Arabic_alefmaksura", XK_Arabic_alefmaksura),
+    ("Arabic_beh", XK_Arabic_beh),
+    ("Arabic_comma", XK_Arabic_comma),
+    ("Arabic_dad", XK_Arabic_dad),
+    ("Arabic_dal", XK_Arabic_dal),
+    ("Arabic_damma", XK_Arabic_damma),
+    ("Arabic_dammatan", XK_Arabic_dammatan),
+    ("Arabic_ddal", XK_Arabic_ddal),
+    ("Arabic_farsi_yeh", XK_Arabic_farsi_yeh),
+    ("Arabic_fatha", XK_Arabic_fatha),
+    ("Arabic_fathatan", XK_Arabic_fathatan),
+    ("Arabic_feh", XK_Arabic_feh),
+    ("Arabic_fullstop", XK_Arabic_fullstop),
+    ("Arabic_gaf", XK_Arabic_gaf),
+    ("Arabic_ghain", XK_Arabic_ghain),
+    ("Arabic_ha", XK_Arabic_ha),
+    ("Arabic_hah", XK_Arabic_hah),
+    ("Arabic_hamza", XK_Arabic_hamza),
+    ("Arabic_hamza_above", XK_Arabic_hamza_above),
+    ("Arabic_hamza_below", XK_Arabic_hamza_below),
+    ("Arabic_hamzaonalef", XK_Arabic_hamzaonalef),
+    ("Arabic_hamzaonwaw", XK_Arabic_hamzaonwaw),
+    ("Arabic_hamzaonyeh", XK_Arabic_hamzaonyeh),
+    ("Arabic_hamzaunderalef", XK_Arabic_hamzaunderalef),
+    ("Arabic_heh", XK_Arabic_heh),
+    ("Arabic_heh_doachashmee", XK_Arabic_heh_doachashmee),
+    ("Arabic_heh_goal", XK_Arabic_heh_goal),
+    ("Arabic_jeem", XK_Arabic_jeem),
+    ("Arabic_jeh", XK_Arabic_jeh),
+    ("Arabic_kaf", XK_Arabic_kaf),
+    ("Arabic_kasra", XK_Arabic_kasra),
+    ("Arabic_kasratan", XK_Arabic_kasratan),
+    ("Arabic_keheh", XK_Arabic_keheh),
+    ("Arabic_khah", XK_Arabic_khah),
+    ("Arabic_lam", XK_Arabic_lam),
+    ("Arabic_madda_above", XK_Arabic_madda_above),
+    ("Arabic_maddaonalef", XK_Arabic_maddaonalef),
+    ("Arabic_meem", XK_Arabic_meem),
+    ("Arabic_noon", XK_Arabic_noon),
+    ("Arabic_noon_ghunna", XK_Arabic_noon_ghunna),
+    ("Arabic_peh", XK_Arabic_peh),
+    ("Arabic_percent", XK_Arabic_percent),
+    ("Arabic_qaf", XK_Arabic_qaf),
+    ("Arabic_question_mark", XK_Arabic_question_mark),
+    ("Arabic_ra", XK_Arabic_ra),
+    ("Arabic_rreh", XK_Arabic_rreh),
+    ("Arabic_sad", XK_Arabic_sad),
+    ("Arabic_seen", XK_Arabic_seen),
+    ("Arabic_semicolon", XK_Arabic_semicolon),
+    ("Arabic_shadda", XK_Arabic_shadda),
+    ("Arabic_sheen", XK_Arabic_sheen),
+    ("Arabic_sukun", XK_Arabic_sukun),
+    ("Arabic_superscript_alef", XK_Arabic_superscript_alef),
+    ("Arabic_switch", XK_Arabic_switch),
+    ("Arabic_tah", XK_Arabic_tah),
+    ("Arabic_tatweel", XK_Arabic_tatweel),
+    ("Arabic_tcheh", XK_Arabic_tcheh),
+    ("Arabic_teh", XK_Arabic_teh),
+    ("Arabic_tehmarbuta", XK_Arabic_tehmarbuta),
+    ("Arabic_thal", XK_Arabic_thal),
+    ("Arabic_theh", XK_Arabic_theh),
+    ("Arabic_tteh", XK_Arabic_tteh),
+    ("Arabic_veh", XK_Arabic_veh),
+    ("Arabic_waw", XK_Arabic_waw),
+    ("Arabic_yeh", XK_Arabic_yeh),
+    ("Arabic_yeh_baree", XK_Arabic_yeh_baree),
+    ("Arabic_zah", XK_Arabic_zah),
+    ("Arabic_zain", XK_Arabic_zain),
+    ("Aring", XK_Aring),
+    ("Armenian_AT", XK_Armenian_AT),
+    ("Armenian_AYB", XK_Armenian_AYB),
+    ("Armenian_BEN", XK_Armenian_BEN),
+    ("Armenian_CHA", XK_Armenian_CHA),
+    ("Armenian_DA", XK_Armenian_DA),
+    ("Armenian_DZA", XK_Armenian_DZA),
+    ("Armenian_E", XK_Armenian_E),
+    ("Armenian_FE", XK_Armenian_FE),
+    ("Armenian_GHAT", XK_Armenian_GHAT),
+    ("Armenian_GIM", XK_Armenian_GIM),
+    ("Armenian_HI", XK_Armenian_HI),
+    ("Armenian_HO", XK_Armenian_HO),
+    ("Armenian_INI", XK_Armenian_INI),
+    ("Armenian_JE", XK_Armenian_JE),
+    ("Armenian_KE", XK_Armenian_KE),
+    ("Armenian_KEN", XK_Armenian_KEN),
+    ("Armenian_KHE", XK_Armenian_KHE),
+    ("Armenian_LYUN", XK_Armenian_LYUN),
+    ("Armenian_MEN", XK_Armenian_MEN),
+    ("Armenian_NU", XK_Armenian_NU),
+    ("Armenian_O", XK_Armenian_O),
+    ("Armenian_PE", XK_Armenian_PE),
+    ("Armenian_PYUR", XK_Armenian_PYUR),
+    ("Armenian_RA", XK_Armenian_RA),
+    ("Armenian_RE", XK_Armenian_RE),
+    ("Armenian_SE", XK_Armenian_SE),
+    ("Armenian_SHA", XK_Armenian_SHA),
+    ("Armenian_TCHE", XK_Armenian_TCHE),
+    ("Armenian_TO", XK_Armenian_TO),
+    ("Armenian_TSA", XK_Armenian_TSA),
+    ("Armenian_TSO", XK_Armenian_TSO),
+    ("Armenian_TYUN", XK_Armenian_TYUN),
+    ("Armenian_VEV", XK_Armenian_VEV),
+    ("Armenian_VO", XK_Armenian_VO),
+    ("Armenian_VYUN", XK_Armenian_VYUN),
+    ("Armenian_YECH", XK_Armenian_YECH),
+    ("Armenian_ZA", XK_Armenian_ZA),
+    ("Armenian_ZHE", XK_Armenian_ZHE),
+    ("Armenian_accent", XK_Armenian_accent),
+    ("Armenian_amanak", XK_Armenian_amanak),
+    ("Armenian_apostrophe", XK_Armenian_apostrophe),
+    ("Armenian_at", XK_Armenian_at),
+    ("Armenian_ayb", XK_Armenian_ayb),
+    ("Armenian_ben", XK_Armenian_ben),
+    ("Armenian_but", XK_Armenian_but),
+    ("Armenian_cha", XK_Armenian_cha),
+    ("Armenian_da", XK_Armenian_da),
+    ("Armenian_dza", XK_Armenian_dza),
+    ("Armenian_e", XK_Armenian_e),
+    ("Armenian_exclam", XK_Armenian_exclam),
+    ("Armenian_fe", XK_Armenian_fe),
+    ("Armenian_full_stop", XK_Armenian_full_stop),
+    ("Armenian_ghat", XK_Armenian_ghat),
+    ("Armenian_gim", XK_Armenian_gim),
+    ("Armenian_hi", XK_Armenian_hi),
+    ("Armenian_ho", XK_Armenian_ho),
+    ("Armenian_hyphen", XK_Armenian_hyphen),
+    ("Armenian_ini", XK_Armenian_ini),
+    ("Armenian_je", XK_Armenian_je),
+    ("Armenian_ke", XK_Armenian_ke),
+    ("Armenian_ken", XK_Armenian_ken),
+    ("Armenian_khe", XK_Armenian_khe),
+    ("Armenian_ligature_ew", XK_Armenian_ligature_ew),
+    ("Armenian_lyun", XK_Armenian_lyun),
+    ("Armenian_men", XK_Armenian_men),
+    ("Armenian_nu", XK_Armenian_nu),
+    ("Armenian_o", XK_Armenian_o),
+    ("Armenian_paruyk", XK_Armenian_paruyk),
+    ("Armenian_pe", XK_Armenian_pe),
+    ("Armenian_pyur", XK_Armenian_pyur),
+    ("Armenian_question", XK_Armenian_question),
+    ("Armenian_ra", XK_Armenian_ra),
+    ("Armenian_re", XK_Armenian_re),
+    ("Armenian_se", XK_Armenian_se),
+    ("Armenian_separation_mark", XK_Armenian_separation_mark),
+    ("Armenian_sha", XK_Armenian_sha),
+    ("Armenian_shesht", XK_Armenian_shesht),
+    ("Armenian_tche", XK_Armenian_tche),
+    ("Armenian_to", XK_Armenian_to),
+    ("Armenian_tsa", XK_Armenian_tsa),
+    ("Armenian_tso", XK_Armenian_tso),
+    ("Armenian_tyun", XK_Armenian_tyun),
+    ("Armenian_verjaket", XK_Armenian_verjaket),
+    ("Armenian_vev", XK_Armenian_vev),
+    ("Armenian_vo", XK_Armenian_vo),
+    ("Armenian_vyun", XK_Armenian_vyun),
+    ("Armenian_yech", XK_Armenian_yech),
+    ("Armenian_yentamna", XK_Armenian_yentamna),
+    ("Armenian_za", XK_Armenian_za),
+    ("Armenian_zhe", XK_Armenian_zhe),
+    ("Atilde", XK_Atilde),
+    ("AudibleBell_Enable", XK_AudibleBell_Enable),
+    ("B", XK_B),
+    ("Babovedot", XK_Babovedot),
+    ("BackSpace", XK_BackSpace),
+    ("Begin", XK_Begin),
+    ("BounceKeys_Enable", XK_BounceKeys_Enable),
+    ("Break", XK_Break),
+    ("Byelorussian_SHORTU", XK_Byelorussian_SHORTU),
+    ("Byelorussian_shortu", XK_Byelorussian_shortu),
+    ("C", XK_C),
+    ("CH", XK_CH),
+    ("C_H", XK_C_H),
+    ("C_h", XK_C_h),
+    ("Cabovedot", XK_Cabovedot),
+    ("Cacute", XK_Cacute),
+    ("Cancel", XK_Cancel),
+    ("Caps_Lock", XK_Caps_Lock),
+    ("Ccaron", XK_Ccaron),
+    ("Ccedilla", XK_Ccedilla),
+    ("Ccircumflex", XK_Ccircumflex),
+    ("Ch", XK_Ch),
+    ("Clear", XK_Clear),
+    ("Codeinput", XK_Codeinput),
+    ("ColonSign", XK_ColonSign),
+    ("Control_L", XK_Control_L),
+    ("Control_R", XK_Control_R),
+    ("CruzeiroSign", XK_CruzeiroSign),
+    ("Cyrillic_A", XK_Cyrillic_A),
+    ("Cyrillic_BE", XK_Cyrillic_BE),
+    ("Cyrillic_CHE", XK_Cyrillic_CHE),
+    ("Cyrillic_CHE_descender", XK_Cyrillic_CHE_descender),
+    ("Cyrillic_CHE_vertstroke", XK_Cyrillic_CHE_vertstroke),
+    ("Cyrillic_DE", XK_Cyrillic_DE),
+    ("Cyrillic_DZHE", XK_Cyrillic_DZHE),
+    ("Cyrillic_E", XK_Cyrillic_E),
+    ("Cyrillic_EF", XK_Cyrillic_EF),
+    ("Cyrillic_EL", XK_Cyrillic_EL),
+    ("Cyrillic_EM", XK_Cyrillic_EM),
+    ("Cyrillic_EN", XK_Cyrillic_EN),
+    ("Cyrillic_EN_descender", XK_Cyrillic_EN_descender),
+    ("Cyrillic_ER", XK_Cyrillic_ER),
+    ("Cyrillic_ES", XK_Cyrillic_ES),
+    ("Cyrillic_GHE", XK_Cyrillic_GHE),
+    ("Cyrillic_GHE_bar", XK_Cyrillic_GHE_bar),
+    ("Cyrillic_HA", XK_Cyrillic_HA),
+    ("Cyrillic_HARDSIGN", XK_Cyrillic_HARDSIGN),
+    ("Cyrillic_HA_descender", XK_Cyrillic_HA_descender),
+    ("Cyrillic_I", XK_Cyrillic_I),
+    ("Cyrillic_IE", XK_Cyrillic_IE),
+    ("Cyrillic_IO", XK_Cyrillic_IO),
+    ("Cyrillic_I_macron", XK_Cyrillic_I_macron),
+    ("Cyrillic_JE", XK_Cyrillic_JE),
+    ("Cyrillic_KA", XK_Cyrillic_KA),
+    ("Cyrillic_KA_descender", XK_Cyrillic_KA_descender),
+    ("Cyrillic_KA_vertstroke", XK_Cyrillic_KA_vertstroke),
+    ("Cyrillic_LJE", XK_Cyrillic_LJE),
+    ("Cyrillic_NJE", XK_Cyrillic_NJE),
+    ("Cyrillic_O", XK_Cyrillic_O),
+    ("Cyrillic_O_bar", XK_Cyrillic_O_bar),
+    ("Cyrillic_PE", XK_Cyrillic_PE),
+    ("Cyrillic_SCHWA", XK_Cyrillic_SCHWA),
+    ("Cyrillic_SHA", XK_Cyrillic_SHA),
+    ("Cyrillic_SHCHA", XK_Cyrillic_SHCHA),
+    ("Cyrillic_SHHA", XK_Cyrillic_SHHA),
+    ("Cyrillic_SHORTI", XK_Cyrillic_SHORTI),
+    ("Cyrillic_SOFTSIGN", XK_Cyrillic_SOFTSIGN),
+    ("Cyrillic_TE", XK_Cyrillic_TE),
+    ("Cyrillic_TSE", XK_Cyrillic_TSE),
+    ("Cyrillic_U", XK_Cyrillic_U),
+    ("Cyrillic_U_macron", XK_Cyrillic_U_macron),
+    ("Cyrillic_U_straight", XK_Cyrillic_U_straight),
+    ("Cyrillic_U_straight_bar", XK_Cyrillic_U_straight_bar),
+    ("Cyrillic_VE", XK_Cyrillic_VE),
+    ("Cyrillic_YA", XK_Cyrillic_YA),
+    ("Cyrillic_YERU", XK_Cyrillic_YERU),
+    ("Cyrillic_YU", XK_Cyrillic_YU),
+    ("Cyrillic_ZE", XK_Cyrillic_ZE),
+    ("Cyrillic_ZHE", XK_Cyrillic_ZHE),
+    ("Cyrillic_ZHE_descender", XK_Cyrillic_ZHE_descender),
+    ("Cyrillic_a", XK_Cyrillic_a),
+    ("Cyrillic_be", XK_Cyrillic_be),
+    ("Cyrillic_che", XK_Cyrillic_che),
+    ("Cyrillic_che_descender", XK_Cyrillic_che_descender),
+    ("Cyrillic_che_vertstroke", XK_Cyrillic_che_vertstroke),
+    ("Cyrillic_de", XK_Cyrillic_de),
+    ("Cyrillic_dzhe", XK_Cyrillic_dzhe),
+    ("Cyrillic_e", XK_Cyrillic_e),
+    ("Cyrillic_ef", XK_Cyrillic_ef),
+    ("Cyrillic_el", XK_Cyrillic_el),
+    ("Cyrillic_em", XK_Cyrillic_em),
+    ("Cyrillic_en", XK_Cyrillic_en),
+    ("Cyrillic_en_descender", XK_Cyrillic_en_descender),
+    ("Cyrillic_er", XK_Cyrillic_er),
+    ("Cyrillic_es", XK_Cyrillic_es),
+    ("Cyrillic_ghe", XK_Cyrillic_ghe),
+    ("Cyrillic_ghe_bar", XK_Cyrillic_ghe_bar),
+    ("Cyrillic_ha", XK_Cyrillic_ha),
+    ("Cyrillic_ha_descender", XK_Cyrillic_ha_descender),
+    ("Cyrillic_hardsign", XK_Cyrillic_hardsign),
+    ("Cyrillic_i", XK_Cyrillic_i),
+    ("Cyrillic_i_macron", XK_Cyrillic_i_macron),
+    ("Cyrillic_ie", XK_Cyrillic_ie),
+    ("Cyrillic_io", XK_Cyrillic_io),
+    ("Cyrillic_je", XK_Cyrillic_je),
+    ("Cyrillic_ka", XK_Cyrillic_ka),
+    ("Cyrillic_ka_descender", XK_Cyrillic_ka_descender),
+    ("Cyrillic_ka_vertstroke", XK_Cyrillic_ka_vertstroke),
+    ("Cyrillic_lje", XK_Cyrillic_lje),
+    ("Cyrillic_nje", XK_Cyrillic_nje),
+    ("Cyrillic_o", XK_Cyrillic_o),
+    ("Cyrillic_o_bar", XK_Cyrillic_o_bar),
+    ("Cyrillic_pe", XK_Cyrillic_pe),
+    ("Cyrillic_schwa", XK_Cyrillic_schwa),
+    ("Cyrillic_sha", XK_Cyrillic_sha),
+    ("Cyrillic_shcha", XK_Cyrillic_shcha),
+    ("Cyrillic_shha", XK_Cyrillic_shha),
+    ("Cyrillic_shorti", XK_Cyrillic_shorti),
+    ("Cyrillic_softsign", XK_Cyrillic_softsign),
+    ("Cyrillic_te", XK_Cyrillic_te),
+    ("Cyrillic_tse", XK_Cyrillic_tse),
+    ("Cyrillic_u", XK_Cyrillic_u),
+    ("Cyrillic_u_macron", XK_Cyrillic_u_macron),
+    ("Cyrillic_u_straight", XK_Cyrillic_u_straight),
+    ("Cyrillic_u_straight_bar", XK_Cyrillic_u_straight_bar),
+    ("Cyrillic_ve", XK_Cyrillic_ve),
+    ("Cyrillic_ya", XK_Cyrillic_ya),
+    ("Cyrillic_yeru", XK_Cyrillic_yeru),
+    ("Cyrillic_yu", XK_Cyrillic_yu),
+    ("Cyrillic_ze", XK_Cyrillic_ze),
+    ("Cyrillic_zhe", XK_Cyrillic_zhe),
+    ("Cyrillic_zhe_descender", XK_Cyrillic_zhe_descender),
+    ("D", XK_D),
+    ("Dabovedot", XK_Dabovedot),
+    ("Dcaron", XK_Dcaron),
+    ("Delete", XK_Delete),
+    ("DongSign", XK_DongSign),
+    ("Down", XK_Down),
+    ("Dstroke", XK_Dstroke),
+    ("E", XK_E),
+    ("ENG", XK_ENG),
+    ("ETH", XK_ETH),
+    ("EZH", XK_EZH),
+    ("Eabovedot", XK_Eabovedot),
+    ("Eacute", XK_Eacute),
+    ("Ebelowdot", XK_Ebelowdot),
+    ("Ecaron", XK_Ecaron),
+    ("Ecircumflex", XK_Ecircumflex),
+    ("Ecircumflexacute", XK_Ecircumflexacute),
+    ("Ecircumflexbelowdot", XK_Ecircumflexbelowdot),
+    ("Ecircumflexgrave", XK_Ecircumflexgrave),
+    ("Ecircumflexhook", XK_Ecircumflexhook),
+    ("Ecircumflextilde", XK_Ecircumflextilde),
+    ("EcuSign", XK_EcuSign),
+    ("Ediaeresis", XK_Ediaeresis),
+    ("Egrave", XK_Egrave),
+    ("Ehook", XK_Ehook),
+    ("Eisu_Shift", XK_Eisu_Shift),
+    ("Eisu_toggle", XK_Eisu_toggle),
+    ("Emacron", XK_Emacron),
+    ("End", XK_End),
+    ("Eogonek", XK_Eogonek),
+    ("Escape", XK_Escape),
+    ("Eth", XK_Eth),
+    ("Etilde", XK_Etilde),
+    ("EuroSign", XK_EuroSign),
+    ("Execute", XK_Execute),
+    ("F", XK_F),
+    ("F1", XK_F1),
+    ("F10", XK_F10),
+    ("F11", XK_F11),
+    ("F12", XK_F12),
+    ("F13", XK_F13),
+    ("F14", XK_F14),
+    ("F15", XK_F15),
+    ("F16", XK_F16),
+    ("F17", XK_F17),
+    ("F18", XK_F18),
+    ("F19", XK_F19),
+    ("F2", XK_F2),
+    ("F20", XK_F20),
+    ("F21", XK_F21),
+    ("F22", XK_F22),
+    ("F23", XK_F23),
+    ("F24", XK_F24),
+    ("F25", XK_F25),
+    ("F26", XK_F26),
+    ("F27", XK_F27),
+    ("F28", XK_F28),
+    ("F29", XK_F29),
+    ("F3", XK_F3),
+    ("F30", XK_F30),
+    ("F31", XK_F31),
+    ("F32", XK_F32),
+    ("F33", XK_F33),
+    ("F34", XK_F34),
+    ("F35", XK_F35),
+    ("F4", XK_F4),
+    ("F5", XK_F5),
+    ("F6", XK_F6),
+    ("F7", XK_F7),
+    ("F8", XK_F8),
+    ("F9", XK_F9),
+    ("FFrancSign", XK_FFrancSign),
+    ("Fabovedot", XK_Fabovedot),
+    ("Farsi_0", XK_Farsi_0),
+    ("Farsi_1", XK_Farsi_1),
+    ("Farsi_2", XK_Farsi_2),
+    ("Farsi_3", XK_Farsi_3),
+    ("Farsi_4", XK_Farsi_4),
+    ("Farsi_5", XK_Farsi_5),
+    ("Farsi_6", XK_Farsi_6),
+    ("Farsi_7", XK_Farsi_7),
+    ("Farsi_8", XK_Farsi_8),
+    ("Farsi_9", XK_Farsi_9),
+    ("Farsi_yeh", XK_Farsi_yeh),
+    ("Find", XK_Find),
+    ("First_Virtual_Screen", XK_First_Virtual_Screen),
+    ("G", XK_G),
+    ("Gabovedot", XK_Gabovedot),
+    ("Gbreve", XK_Gbreve),
+    ("Gcaron", XK_Gcaron),
+    ("Gcedilla", XK_Gcedilla),
+    ("Gcircumflex", XK_Gcircumflex),
+    ("Georgian_an", XK_Georgian_an),
+    ("Georgian_ban", XK_Georgian_ban),
+    ("Georgian_can", XK_Georgian_can),
+    ("Georgian_char", XK_Georgian_char),
+    ("Georgian_chin", XK_Georgian_chin),
+    ("Georgian_cil", XK_Georgian_cil),
+    ("Georgian_don", XK_Georgian_don),
+    ("Georgian_en", XK_Georgian_en),
+    ("Georgian_fi", XK_Georgian_fi),
+    ("Georgian_gan", XK_Georgian_gan),
+    ("Georgian_ghan", XK_Georgian_ghan),
+    ("Georgian_hae", XK_Georgian_hae),
+    ("Georgian_har", XK_Georgian_har),
+    ("Georgian_he", XK_Georgian_he),
+    ("Georgian_hie", XK_Georgian_hie),
+    ("Georgian_hoe", XK_Georgian_hoe),
+    ("Georgian_in", XK_Georgian_in),
+    ("Georgian_jhan", XK_Georgian_jhan),
+    ("Georgian_jil", XK_Georgian_jil),
+    ("Georgian_kan", XK_Georgian_kan),
+    ("Georgian_khar", XK_Georgian_khar),
+    ("Georgian_las", XK_Georgian_las),
+    ("Georgian_man", XK_Georgian_man),
+    ("Georgian_nar", XK_Georgian_nar),
+    ("Georgian_on", XK_Georgian_on),
+    ("Georgian_par", XK_Georgian_par),
+    ("Georgian_phar", XK_Georgian_phar),
+    ("Georgian_qar", XK_Georgian_qar),
+    ("Georgian_rae", XK_Georgian_rae),
+    ("Georgian_san", XK_Georgian_san),
+    ("Georgian_shin", XK_Georgian_shin),
+    ("Georgian_tan", XK_Georgian_tan),
+    ("Georgian_tar", XK_Georgian_tar),
+    ("Georgian_un", XK_Georgian_un),
+    ("Georgian_vin", XK_Georgian_vin),
+    ("Georgian_we", XK_Georgian_we),
+    ("Georgian_xan", XK_Georgian_xan),
+    ("Georgian_zen", XK_Georgian_zen),
+    ("Georgian_zhar", XK_Georgian_zhar),
+    ("Greek_ALPHA", XK_Greek_ALPHA),
+    ("Greek_ALPHAaccent", XK_Greek_ALPHAaccent),
+    ("Greek_BETA", XK_Greek_BETA),
+    ("Greek_CHI", XK_Greek_CHI),
+    ("Greek_DELTA", XK_Greek_DELTA),
+    ("Greek_EPSILON", XK_Greek_EPSILON),
+    ("Greek_EPSILONaccent", XK_Greek_EPSILONaccent),
+    ("Greek_ETA", XK_Greek_ETA),
+    ("Greek_ETAaccent", XK_Greek_ETAaccent),
+    ("Greek_GAMMA", XK_Greek_GAMMA),
+    ("Greek_IOTA", XK_Greek_IOTA),
+    ("Greek_IOTAaccent", XK_Greek_IOTAaccent),
+    ("Greek_IOTAdiaeresis", XK_Greek_IOTAdiaeresis),
+    ("Greek_IOTAdieresis", XK_Greek_IOTAdieresis),
+    ("Greek_KAPPA", XK_Greek_KAPPA),
+    ("Greek_LAMBDA", XK_Greek_LAMBDA),
+    ("Greek_LAMDA", XK_Greek_LAMDA),
+    ("Greek_MU", XK_Greek_MU),
+    ("Greek_NU", XK_Greek_NU),
+    ("Greek_OMEGA", XK_Greek_OMEGA),
+    ("Greek_OMEGAaccent", XK_Greek_OMEGAaccent),
+    ("Greek_OMICRON", XK_Greek_OMICRON),
+    ("Greek_OMICRONaccent", XK_Greek_OMICRONaccent),
+    ("Greek_PHI", XK_Greek_PHI),
+    ("Greek_PI", XK_Greek_PI),
+    ("Greek_PSI", XK_Greek_PSI),
+    ("Greek_RHO", XK_Greek_RHO),
+    ("Greek_SIGMA", XK_Greek_SIGMA),
+    ("Greek_TAU", XK_Greek_TAU),
+    ("Greek_THETA", XK_Greek_THETA),
+    ("Greek_UPSILON", XK_Greek_UPSILON),
+    ("Greek_UPSILONaccent", XK_Greek_UPSILONaccent),
+    ("Greek_UPSILONdieresis", XK_Greek_UPSILONdieresis),
+    ("Greek_XI", XK_Greek_XI),
+    ("Greek_ZETA", XK_Greek_ZETA),
+    ("Greek_accentdieresis", XK_Greek_accentdieresis),
+    ("Greek_alpha", XK_Greek_alpha),
+    ("Greek_alphaaccent", XK_Greek_alphaaccent),
+    ("Greek_beta", XK_Greek_beta),
+    ("Greek_chi", XK_Greek_chi),
+    ("Greek_delta", XK_Greek_delta),
+    ("Greek_epsilon", XK_Greek_epsilon),
+    ("Greek_epsilonaccent", XK_Greek_epsilonaccent),
+    ("Greek_eta", XK_Greek_eta),
+    ("Greek_etaaccent", XK_Greek_etaaccent),
+    ("Greek_finalsmallsigma", XK_Greek_finalsmallsigma),
+    ("Greek_gamma", XK_Greek_gamma),
+    ("Greek_horizbar", XK_Greek_horizbar),
+    ("Greek_iota", XK_Greek_iota),
+    ("Greek_iotaaccent", XK_Greek_iotaaccent),
+    ("Greek_iotaaccentdieresis", XK_Greek_iotaaccentdieresis),
+    ("Greek_iotadieresis", XK_Greek_iotadieresis),
+    ("Greek_kappa", XK_Greek_kappa),
+    ("Greek_lambda", XK_Greek_lambda),
+    ("Greek_lamda", XK_Greek_lamda),
+    ("Greek_mu", XK_Greek_mu),
+    ("Greek_nu", XK_Greek_nu),
+    ("Greek_omega", XK_Greek_omega),
+    ("Greek_omegaaccent", XK_Greek_omegaaccent),
+    ("Greek_omicron", XK_Greek_omicron),
+    ("Greek_omicronaccent", XK_Greek_omicronaccent),
+    ("Greek_phi", XK_Greek_phi),
+    ("Greek_pi", XK_Greek_pi),
+    ("Greek_psi", XK_Greek_psi),
+    ("Greek_rho", XK_Greek_rho),
+    ("Greek_sigma", XK_Greek_sigma),
+    ("Greek_switch", XK_Greek_switch),
+    ("Greek_tau", XK_Greek_tau),
+    ("Greek_theta", XK_Greek_theta),
+    ("Greek_upsilon", XK_Greek_upsilon),
+    ("Greek_upsilonaccent", XK_Greek_upsilonaccent),
+    (
+        "Greek_upsilonaccentdieresis",
+        XK_Greek_upsilonaccentdieresis,
+    ),
+    ("Greek_upsilondieresis", XK_Greek_upsilondieresis),
+    ("Greek_xi", XK_Greek_xi),
+    ("Greek_zeta", XK_Greek_zeta),
+    ("H", XK_H),
+    ("Hangul", XK_Hangul),
+    ("Hangul_A", XK_Hangul_A),
+    ("Hangul_AE", XK_Hangul_AE),
+    ("Hangul_AraeA", XK_Hangul_AraeA),
+    ("Hangul_AraeAE", XK_Hangul_AraeAE),
+    ("Hangul_Banja", XK_Hangul_Banja),
+    ("Hangul_Cieuc", XK_Hangul_Cieuc),
+    ("Hangul_Codeinput", XK_Hangul_Codeinput),
+    ("Hangul_Dikeud", XK_Hangul_Dikeud),
+    ("Hangul_E", XK_Hangul_E),
+    ("Hangul_EO", XK_Hangul_EO),
+    ("Hangul_EU", XK_Hangul_EU),
+    ("Hangul_End", XK_Hangul_End),
+    ("Hangul_Hanja", XK_Hangul_Hanja),
+    ("Hangul_Hieuh", XK_Hangul_Hieuh),
+    ("Hangul_I", XK_Hangul_I),
+    ("Hangul_Ieung", XK_Hangul_Ieung),
+    ("Hangul_J_Cieuc", XK_Hangul_J_Cieuc),
+    ("Hangul_J_Dikeud", XK_Hangul_J_Dikeud),
+    ("Hangul_J_Hieuh", XK_Hangul_J_Hieuh),
+    ("Hangul_J_Ieung", XK_Hangul_J_Ieung),
+    ("Hangul_J_Jieuj", XK_Hangul_J_Jieuj),
+    ("Hangul_J_Khieuq", XK_Hangul_J_Khieuq),
+    ("Hangul_J_Kiyeog", XK_Hangul_J_Kiyeog),
+    ("Hangul_J_KiyeogSios", XK_Hangul_J_KiyeogSios),
+    ("Hangul_J_KkogjiDalrinIeung", XK_Hangul_J_KkogjiDalrinIeung),
+    ("Hangul_J_Mieum", XK_Hangul_J_Mieum),
+    ("Hangul_J_Nieun", XK_Hangul_J_Nieun),
+    ("Hangul_J_NieunHieuh", XK_Hangul_J_NieunHieuh),
+    ("Hangul_J_NieunJieuj", XK_Hangul_J_NieunJieuj),
+    ("Hangul_J_PanSios", XK_Hangul_J_PanSios),
+    ("Hangul_J_Phieuf", XK_Hangul_J_Phieuf),
+    ("Hangul_J_Pieub", XK_Hangul_J_Pieub),
+    ("Hangul_J_PieubSios", XK_Hangul_J_PieubSios),
+    ("Hangul_J_Rieul", XK_Hangul_J_Rieul),
+    ("Hangul_J_RieulHieuh", XK_Hangul_J_RieulHieuh),
+    ("Hangul_J_RieulKiyeog", XK_Hangul_J_RieulKiyeog),
+    ("Hangul_J_RieulMieum", XK_Hangul_J_RieulMieum),
+    ("Hangul_J_RieulPhieuf", XK_Hangul_J_RieulPhieuf),
+    ("Hangul_J_RieulPieub", XK_Hangul_J_RieulPieub),
+    ("Hangul_J_RieulSios", XK_Hangul_J_RieulSios),
+    ("Hangul_J_RieulTieut", XK_Hangul_J_RieulTieut),
+    ("Hangul_J_Sios", XK_Hangul_J_Sios),
+    ("Hangul_J_SsangKiyeog", XK_Hangul_J_SsangKiyeog),
+    ("Hangul_J_SsangSios", XK_Hangul_J_SsangSios),
+    ("Hangul_J_Tieut", XK_Hangul_J_Tieut),
+    ("Hangul_J_YeorinHieuh", XK_Hangul_J_YeorinHieuh),
+    ("Hangul_Jamo", XK_Hangul_Jamo),
+    ("Hangul_Jeonja", XK_Hangul_Jeonja),
+    ("Hangul_Jieuj", XK_Hangul_Jieuj),
+    ("Hangul_Khieuq", XK_Hangul_Khieuq),
+    ("Hangul_Kiyeog", XK_Hangul_Kiyeog),
+    ("Hangul_KiyeogSios", XK_Hangul_KiyeogSios),
+    ("Hangul_KkogjiDalrinIeung", XK_Hangul_KkogjiDalrinIeung),
+    ("Hangul_Mieum", XK_Hangul_Mieum),
+    ("Hangul_MultipleCandidate", XK_Hangul_MultipleCandidate),
+    ("Hangul_Nieun", XK_Hangul_Nieun),
+    ("Hangul_NieunHieuh", XK_Hangul_NieunHieuh),
+    ("Hangul_NieunJieuj", XK_Hangul_NieunJieuj),
+    ("Hangul_O", XK_Hangul_O),
+    ("Hangul_OE", XK_Hangul_OE),
+    ("Hangul_PanSios", XK_Hangul_PanSios),
+    ("Hangul_Phieuf", XK_Hangul_Phieuf),
+    ("Hangul_Pieub", XK_Hangul_Pieub),
+    ("Hangul_PieubSios", XK_Hangul_PieubSios),
+    ("Hangul_PostHanja", XK_Hangul_PostHanja),
+    ("Hangul_PreHanja", XK_Hangul_PreHanja),
+    ("Hangul_PreviousCandidate", XK_Hangul_PreviousCandidate),
+    ("Hangul_Rieul", XK_Hangul_Rieul),
+    ("Hangul_RieulHieuh", XK_Hangul_RieulHieuh),
+    ("Hangul_RieulKiyeog", XK_Hangul_RieulKiyeog),
+    ("Hangul_RieulMieum", XK_Hangul_RieulMieum),
+    ("Hangul_RieulPhieuf", XK_Hangul_RieulPhieuf),
+    ("Hangul_RieulPieub", XK_Hangul_RieulPieub),
+    ("Hangul_RieulSios", XK_Hangul_RieulSios),
+    ("Hangul_RieulTieut", XK_Hangul_RieulTieut),
+    ("Hangul_RieulYeorinHieuh", XK_Hangul_RieulYeorinHieuh),
+    ("Hangul_Romaja", XK_Hangul_Romaja),
+    ("Hangul_SingleCandidate", XK_Hangul_SingleCandidate),
+    ("Hangul_Sios", XK_Hangul_Sios),
+    ("Hangul_Special", XK_Hangul_Special),
+    ("Hangul_SsangDikeud", XK_Hangul_SsangDikeud),
+    ("Hangul_SsangJieuj", XK_Hangul_SsangJieuj),
+    ("Hangul_SsangKiyeog", XK_Hangul_SsangKiyeog),
+    ("Hangul_SsangPieub", XK_Hangul_SsangPieub),
+    ("Hangul_SsangSios", XK_Hangul_SsangSios),
+    ("Hangul_Start", XK_Hangul_Start),
+    ("Hangul_SunkyeongeumMieum", XK_Hangul_SunkyeongeumMieum),
+    ("Hangul_SunkyeongeumPhieuf", XK_Hangul_SunkyeongeumPhieuf),
+    ("Hangul_SunkyeongeumPieub", XK_Hangul_SunkyeongeumPieub),
+    ("Hangul_Tieut", XK_Hangul_Tieut),
+    ("Hangul_U", XK_Hangul_U),
+    ("Hangul_WA", XK_Hangul_WA),
+    ("Hangul_WAE", XK_Hangul_WAE),
+    ("Hangul_WE", XK_Hangul_WE),
+    ("Hangul_WEO", XK_Hangul_WEO),
+    ("Hangul_WI", XK_Hangul_WI),
+    ("Hangul_YA", XK_Hangul_YA),
+    ("Hangul_YAE", XK_Hangul_YAE),
+    ("Hangul_YE", XK_Hangul_YE),
+    ("Hangul_YEO", XK_Hangul_YEO),
+    ("Hangul_YI", XK_Hangul_YI),
+    ("Hangul_YO", XK_Hangul_YO),
+    ("Hangul_YU", XK_Hangul_YU),
+    ("Hangul_YeorinHieuh", XK_Hangul_YeorinHieuh),
+    ("Hangul_switch", XK_Hangul_switch),
+    ("Hankaku", XK_Hankaku),
+    ("Hcircumflex", XK_Hcircumflex),
+    ("Hebrew_switch", XK_Hebrew_switch),
+    ("Help", XK_Help),
+    ("Henkan", XK_Henkan),
+    ("Henkan_Mode", XK_Henkan_Mode),
+    ("Hiragana", XK_Hiragana),
+    ("Hiragana_Katakana", XK_Hiragana_Katakana),
+    ("Home", XK_Home),
+    ("Hstroke", XK_Hstroke),
+    ("Hyper_L", XK_Hyper_L),
+    ("Hyper_R", XK_Hyper_R),
+    ("I", XK_I),
+    ("ISO_Center_Object", XK_ISO_Center_Object),
+    ("ISO_Continuous_Underline", XK_ISO_Continuous_Underline),
+    (
+        "ISO_Discontinuous_Underline",
+        XK_ISO_Discontinuous_Underline,
+    ),
+    ("ISO_Emphasize", XK_ISO_Emphasize),
+    ("ISO_Enter", XK_ISO_Enter),
+    ("ISO_Fast_Cursor_Down", XK_ISO_Fast_Cursor_Down),
+    ("ISO_Fast_Cursor_Left", XK_ISO_Fast_Cursor_Left),
+    ("ISO_Fast_Cursor_Right", XK_ISO_Fast_Cursor_Right),
+    ("ISO_Fast_Cursor_Up", XK_ISO_Fast_Cursor_Up),
+    ("ISO_First_Group", XK_ISO_First_Group),
+    ("ISO_First_Group_Lock", XK_ISO_First_Group_Lock),
+    ("ISO_Group_Latch", XK_ISO_Group_Latch),
+    ("ISO_Group_Lock", XK_ISO_Group_Lock),
+    ("ISO_Group_Shift", XK_ISO_Group_Shift),
+    ("ISO_Last_Group", XK_ISO_Last_Group),
+    ("ISO_Last_Group_Lock", XK_ISO_Last_Group_Lock),
+    ("ISO_Left_Tab", XK_ISO_Left_Tab),
+    ("ISO_Level2_Latch", XK_ISO_Level2_Latch),
+    ("ISO_Level3_Latch", XK_ISO_Level3_Latch),
+    ("ISO_Level3_Lock", XK_ISO_Level3_Lock),
+    ("ISO_Level3_Shift", XK_ISO_Level3_Shift),
+    ("ISO_Level5_Latch", XK_ISO_Level5_Latch),
+    ("ISO_Level5_Lock", XK_ISO_Level5_Lock),
+    ("ISO_Level5_Shift", XK_ISO_Level5_Shift),
+    ("ISO_Lock", XK_ISO_Lock),
+    ("ISO_Move_Line_Down", XK_ISO_Move_Line_Down),
+    ("ISO_Move_Line_Up", XK_ISO_Move_Line_Up),
+    ("ISO_Next_Group", XK_ISO_Next_Group),
+    ("ISO_Next_Group_Lock", XK_ISO_Next_Group_Lock),
+    ("ISO_Partial_Line_Down", XK_ISO_Partial_Line_Down),
+    ("ISO_Partial_Line_Up", XK_ISO_Partial_Line_Up),
+    ("ISO_Partial_Space_Left", XK_ISO_Partial_Space_Left),
+    ("ISO_Partial_Space_Right", XK_ISO_Partial_Space_Right),
+    ("ISO_Prev_Group", XK_ISO_Prev_Group),
+    ("ISO_Prev_Group_Lock", XK_ISO_Prev_Group_Lock),
+    ("ISO_Release_Both_Margins", XK_ISO_Release_Both_Margins),
+    ("ISO_Release_Margin_Left", XK_ISO_Release_Margin_Left),
+    ("ISO_Release_Margin_Right", XK_ISO_Release_Margin_Right),
+    ("ISO_Set_Margin_Left", XK_ISO_Set_Margin_Left),
+    ("ISO_Set_Margin_Right", XK_ISO_Set_Margin_Right),
+    ("Iabovedot", XK_Iabovedot),
+    ("Iacute", XK_Iacute),
+    ("Ibelowdot", XK_Ibelowdot),
+    ("Ibreve", XK_Ibreve),
+    ("Icircumflex", XK_Icircumflex),
+    ("Idiaeresis", XK_Idiaeresis),
+    ("Igrave", XK_Igrave),
+    ("Ihook", XK_Ihook),
+    ("Imacron", XK_Imacron),
+    ("Insert", XK_Insert),
+    ("Iogonek", XK_Iogonek),
+    ("Itilde", XK_Itilde),
+    ("J", XK_J),
+    ("Jcircumflex", XK_Jcircumflex),
+    ("K", XK_K),
+    ("KP_0", XK_KP_0),
+    ("KP_1", XK_KP_1),
+    ("KP_2", XK_KP_2),
+    ("KP_3", XK_KP_3),
+    ("KP_4", XK_KP_4),
+    ("KP_5", XK_KP_5),
+    ("KP_6", XK_KP_6),
+    ("KP_7", XK_KP_7),
+    ("KP_8", XK_KP_8),
+    ("KP_9", XK_KP_9),
+    ("KP_Add", XK_KP_Add),
+    ("KP_Begin", XK_KP_Begin),
+    ("KP_Decimal", XK_KP_Decimal),
+    ("KP_Delete", XK_KP_Delete),
+    ("KP_Divide", XK_KP_Divide),
+    ("KP_Down", XK_KP_Down),
+    ("KP_End", XK_KP_End),
+    ("KP_Enter", XK_KP_Enter),
+    ("KP_Equal", XK_KP_Equal),
+    ("KP_F1", XK_KP_F1),
+    ("KP_F2", XK_KP_F2),
+    ("KP_F3", XK_KP_F3),
+    ("KP_F4", XK_KP_F4),
+    ("KP_Home", XK_KP_Home),
+    ("KP_Insert", XK_KP_Insert),
+    ("KP_Left", XK_KP_Left),
+    ("KP_Multiply", XK_KP_Multiply),
+    ("KP_Next", XK_KP_Next),
+    ("KP_Page_Down", XK_KP_Page_Down),
+    ("KP_Page_Up", XK_KP_Page_Up),
+    ("KP_Prior", XK_KP_Prior),
+    ("KP_Right", XK_KP_Right),
+    ("KP_Separator", XK_KP_Separator),
+    ("KP_Space", XK_KP_Space),
+    ("KP_Subtract", XK_KP_Subtract),
+    ("KP_Tab", XK_KP_Tab),
+    ("KP_Up", XK_KP_Up),
+    ("Kana_Lock", XK_Kana_Lock),
+    ("Kana_Shift", XK_Kana_Shift),
+    ("Kanji", XK_Kanji),
+    ("Kanji_Bangou", XK_Kanji_Bangou),
+    ("Katakana", XK_Katakana),
+    ("Kcedilla", XK_Kcedilla),
+    ("Korean_Won", XK_Korean_Won),
+    ("L", XK_L),
+    ("L1", XK_L1),
+    ("L10", XK_L10),
+    ("L2", XK_L2),
+    ("L3", XK_L3),
+    ("L4", XK_L4),
+    ("L5", XK_L5),
+    ("L6", XK_L6),
+    ("L7", XK_L7),
+    ("L8", XK_L8),
+    ("L9", XK_L9),
+    ("Lacute", XK_Lacute),
+    ("Last_Virtual_Screen", XK_Last_Virtual_Screen),
+    ("Lbelowdot", XK_Lbelowdot),
+    ("Lcaron", XK_Lcaron),
+    ("Lcedilla", XK_Lcedilla),
+    ("Left", XK_Left),
+    ("Linefeed", XK_Linefeed),
+    ("LiraSign", XK_LiraSign),
+    ("Lstroke", XK_Lstroke),
+    ("M", XK_M),
+    ("Mabovedot", XK_Mabovedot),
+    ("Macedonia_DSE", XK_Macedonia_DSE),
+    ("Macedonia_GJE", XK_Macedonia_GJE),
+    ("Macedonia_KJE", XK_Macedonia_KJE),
+    ("Macedonia_dse", XK_Macedonia_dse),
+    ("Macedonia_gje", XK_Macedonia_gje),
+    ("Macedonia_kje", XK_Macedonia_kje),
+    ("Mae_Koho", XK_Mae_Koho),
+    ("Massyo", XK_Massyo),
+    ("Menu", XK_Menu),
+    ("Meta_L", XK_Meta_L),
+    ("Meta_R", XK_Meta_R),
+    ("MillSign", XK_MillSign),
+    ("Mode_switch", XK_Mode_switch),
+    ("MouseKeys_Accel_Enable", XK_MouseKeys_Accel_Enable),
+    ("MouseKeys_Enable", XK_MouseKeys_Enable),
+    ("Muhenkan", XK_Muhenkan),
+    ("Multi_key", XK_Multi_key),
+    ("MultipleCandidate", XK_MultipleCandidate),
+    ("N", XK_N),
+    ("Nacute", XK_Nacute),
+    ("NairaSign", XK_NairaSign),
+    ("Ncaron", XK_Ncaron),
+    ("Ncedilla", XK_Ncedilla),
+    ("NewSheqelSign", XK_NewSheqelSign),
+    ("Next", XK_Next),
+    ("Next_Virtual_Screen", XK_Next_Virtual_Screen),
+    ("Ntilde", XK_Ntilde),
+    ("Num_Lock", XK_Num_Lock),
+    ("O", XK_O),
+    ("OE", XK_OE),
+    ("Oacute", XK_Oacute),
+    ("Obarred", XK_Obarred),
+    ("Obelowdot", XK_Obelowdot),
+    ("Ocaron", XK_Ocaron),
+    ("Ocircumflex", XK_Ocircumflex),
+    ("Ocircumflexacute", XK_Ocircumflexacute),
+    ("Ocircumflexbelowdot", XK_Ocircumflexbelowdot),
+    ("Ocircumflexgrave", XK_Ocircumflexgrave),
+    ("Ocircumflexhook", XK_Ocircumflexhook),
+    ("Ocircumflextilde", XK_Ocircumflextilde),
+    ("Odiaeresis", XK_Odiaeresis),
+    ("Odoubleacute", XK_Odoubleacute),
+    ("Ograve", XK_Ograve),
+    ("Ohook", XK_Ohook),
+    ("Ohorn", XK_Ohorn),
+    ("Ohornacute", XK_Ohornacute),
+    ("Ohornbelowdot", XK_Ohornbelowdot),
+    ("Ohorngrave", XK_Ohorngrave),
+    ("Ohornhook", XK_Ohornhook),
+    ("Ohorntilde", XK_Ohorntilde),
+    ("Omacron", XK_Omacron),
+    ("Ooblique", XK_Ooblique),
+    ("Oslash", XK_Oslash),
+    ("Otilde", XK_Otilde),
+    ("Overlay1_Enable", XK_Overlay1_Enable),
+    ("Overlay2_Enable", XK_Overlay2_Enable),
+    ("P", XK_P),
+    ("Pabovedot", XK_Pabovedot),
+    ("Page_Down", XK_Page_Down),
+    ("Page_Up", XK_Page_Up),
+    ("Pause", XK_Pause),
+    ("PesetaSign", XK_PesetaSign),
+    ("Pointer_Accelerate", XK_Pointer_Accelerate),
+    ("Pointer_Button1", XK_Pointer_Button1),
+    ("Pointer_Button2", XK_Pointer_Button2),
+    ("Pointer_Button3", XK_Pointer_Button3),
+    ("Pointer_Button4", XK_Pointer_Button4),
+    ("Pointer_Button5", XK_Pointer_Button5),
+    ("Pointer_Button_Dflt", XK_Pointer_Button_Dflt),
+    ("Pointer_DblClick1", XK_Pointer_DblClick1),
+    ("Pointer_DblClick2", XK_Pointer_DblClick2),
+    ("Pointer_DblClick3", XK_Pointer_DblClick3),
+    ("Pointer_DblClick4", XK_Pointer_DblClick4),
+    ("Pointer_DblClick5", XK_Pointer_DblClick5),
+    ("Pointer_DblClick_Dflt", XK_Pointer_DblClick_Dflt),
+    ("Pointer_DfltBtnNext", XK_Pointer_DfltBtnNext),
+    ("Pointer_DfltBtnPrev", XK_Pointer_DfltBtnPrev),
+    ("Pointer_Down", XK_Pointer_Down),
+    ("Pointer_DownLeft", XK_Pointer_DownLeft),
+    ("Pointer_DownRight", XK_Pointer_DownRight),
+    ("Pointer_Drag1", XK_Pointer_Drag1),
+    ("Pointer_Drag2", XK_Pointer_Drag2),
+    ("Pointer_Drag3", XK_Pointer_Drag3),
+    ("Pointer_Drag4", XK_Pointer_Drag4),
+    ("Pointer_Drag5", XK_Pointer_Drag5),
+    ("Pointer_Drag_Dflt", XK_Pointer_Drag_Dflt),
+    ("Pointer_EnableKeys", XK_Pointer_EnableKeys),
+    ("Pointer_Left", XK_Pointer_Left),
+    ("Pointer_Right", XK_Pointer_Right),
+    ("Pointer_Up", XK_Pointer_Up),
+    ("Pointer_UpLeft", XK_Pointer_UpLeft),
+    ("Pointer_UpRight", XK_Pointer_UpRight),
+    ("Prev_Virtual_Screen", XK_Prev_Virtual_Screen),
+    ("PreviousCandidate", XK_PreviousCandidate),
+    ("Print", XK_Print),
+    ("Prior", XK_Prior),
+    ("Q", XK_Q),
+    ("R", XK_R),
+    ("R1", XK_R1),
+    ("R10", XK_R10),
+    ("R11", XK_R11),
+    ("R12", XK_R12),
+    ("R13", XK_R13),
+    ("R14", XK_R14),
+    ("R15", XK_R15),
+    ("R2", XK_R2),
+    ("R3", XK_R3),
+    ("R4", XK_R4),
+    ("R5", XK_R5),
+    ("R6", XK_R6),
+    ("R7", XK_R7),
+    ("R8", XK_R8),
+    ("R9", XK_R9),
+    ("Racute", XK_Racute),
+    ("Rcaron", XK_Rcaron),
+    ("Rcedilla", XK_Rcedilla),
+    ("Redo", XK_Redo),
+    ("RepeatKeys_Enable", XK_RepeatKeys_Enable),
+    ("Return", XK_Return),
+    ("Right", XK_Right),
+    ("Romaji", XK_Romaji),
+    ("RupeeSign", XK_RupeeSign),
+    ("S", XK_S),
+    ("SCHWA", XK_SCHWA),
+    ("Sabovedot", XK_Sabovedot),
+    ("Sacute", XK_Sacute),
+    ("Scaron", XK_Scaron),
+    ("Scedilla", XK_Scedilla),
+    ("Scircumflex", XK_Scircumflex),
+    ("Scroll_Lock", XK_Scroll_Lock),
+    ("Select", XK_Select),
+    ("Serbian_DJE", XK_Serbian_DJE),
+    ("Serbian_DZE", XK_Serbian_DZE),
+    ("Serbian_JE", XK_Serbian_JE),
+    ("Serbian_LJE", XK_Serbian_LJE),
+    ("Serbian_NJE", XK_Serbian_NJE),
+    ("Serbian_TSHE", XK_Serbian_TSHE),
+    ("Serbian_dje", XK_Serbian_dje),
+    ("Serbian_dze", XK_Serbian_dze),
+    ("Serbian_je", XK_Serbian_je),
+    ("Serbian_lje", XK_Serbian_lje),
+    ("Serbian_nje", XK_Serbian_nje),
+    ("Serbian_tshe", XK_Serbian_tshe),
+    ("Shift_L", XK_Shift_L),
+    ("Shift_Lock", XK_Shift_Lock),
+    ("Shift_R", XK_Shift_R),
+    ("SingleCandidate", XK_SingleCandidate),
+    ("Sinh_a", XK_Sinh_a),
+    ("Sinh_aa", XK_Sinh_aa),
+    ("Sinh_aa2", XK_Sinh_aa2),
+    ("Sinh_ae", XK_Sinh_ae),
+    ("Sinh_ae2", XK_Sinh_ae2),
+    ("Sinh_aee", XK_Sinh_aee),
+    ("Sinh_aee2", XK_Sinh_aee2),
+    ("Sinh_ai", XK_Sinh_ai),
+    ("Sinh_ai2", XK_Sinh_ai2),
+    ("Sinh_al", XK_Sinh_al),
+    ("Sinh_au", XK_Sinh_au),
+    ("Sinh_au2", XK_Sinh_au2),
+    ("Sinh_ba", XK_Sinh_ba),
+    ("Sinh_bha", XK_Sinh_bha),
+    ("Sinh_ca", XK_Sinh_ca),
+    ("Sinh_cha", XK_Sinh_cha),
+    ("Sinh_dda", XK_Sinh_dda),
+    ("Sinh_ddha", XK_Sinh_ddha),
+    ("Sinh_dha", XK_Sinh_dha),
+    ("Sinh_dhha", XK_Sinh_dhha),
+    ("Sinh_e", XK_Sinh_e),
+    ("Sinh_e2", XK_Sinh_e2),
+    ("Sinh_ee", XK_Sinh_ee),
+    ("Sinh_ee2", XK_Sinh_ee2),
+    ("Sinh_fa", XK_Sinh_fa),
+    ("Sinh_ga", XK_Sinh_ga),
+    ("Sinh_gha", XK_Sinh_gha),
+    ("Sinh_h2", XK_Sinh_h2),
+    ("Sinh_ha", XK_Sinh_ha),
+    ("Sinh_i", XK_Sinh_i),
+    ("Sinh_i2", XK_Sinh_i2),
+    ("Sinh_ii", XK_Sinh_ii),
+    ("Sinh_ii2", XK_Sinh_ii2),
+    ("Sinh_ja", XK_Sinh_ja),
+    ("Sinh_jha", XK_Sinh_jha),
+    ("Sinh_jnya", XK_Sinh_jnya),
+    ("Sinh_ka", XK_Sinh_ka),
+    ("Sinh_kha", XK_Sinh_kha),
+    ("Sinh_kunddaliya", XK_Sinh_kunddaliya),
+    ("Sinh_la", XK_Sinh_la),
+    ("Sinh_lla", XK_Sinh_lla),
+    ("Sinh_lu", XK_Sinh_lu),
+    ("Sinh_lu2", XK_Sinh_lu2),
+    ("Sinh_luu", XK_Sinh_luu),
+    ("Sinh_luu2", XK_Sinh_luu2),
+    ("Sinh_ma", XK_Sinh_ma),
+    ("Sinh_mba", XK_Sinh_mba),
+    ("Sinh_na", XK_Sinh_na),
+    ("Sinh_ndda", XK_Sinh_ndda),
+    ("Sinh_ndha", XK_Sinh_ndha),
+    ("Sinh_ng", XK_Sinh_ng),
+    ("Sinh_ng2", XK_Sinh_ng2),
+    ("Sinh_nga", XK_Sinh_nga),
+    ("Sinh_nja", XK_Sinh_nja),
+    ("Sinh_nna", XK_Sinh_nna),
+    ("Sinh_nya", XK_Sinh_nya),
+    ("Sinh_o", XK_Sinh_o),
+    ("Sinh_o2", XK_Sinh_o2),
+    ("Sinh_oo", XK_Sinh_oo),
+    ("Sinh_oo2", XK_Sinh_oo2),
+    ("Sinh_pa", XK_Sinh_pa),
+    ("Sinh_pha", XK_Sinh_pha),
+    ("Sinh_ra", XK_Sinh_ra),
+    ("Sinh_ri", XK_Sinh_ri),
+    ("Sinh_rii", XK_Sinh_rii),
+    ("Sinh_ru2", XK_Sinh_ru2),
+    ("Sinh_ruu2", XK_Sinh_ruu2),
+    ("Sinh_sa", XK_Sinh_sa),
+    ("Sinh_sha", XK_Sinh_sha),
+    ("Sinh_ssha", XK_Sinh_ssha),
+    ("Sinh_tha", XK_Sinh_tha),
+    ("Sinh_thha", XK_Sinh_thha),
+    ("Sinh_tta", XK_Sinh_tta),
+    ("Sinh_ttha", XK_Sinh_ttha),
+    ("Sinh_u", XK_Sinh_u),
+    ("Sinh_u2", XK_Sinh_u2),
+    ("Sinh_uu", XK_Sinh_uu),
+    ("Sinh_uu2", XK_Sinh_uu2),
+    ("Sinh_va", XK_Sinh_va),
+    ("Sinh_ya", XK_Sinh_ya),
+    ("SlowKeys_Enable", XK_SlowKeys_Enable),
+    ("StickyKeys_Enable", XK_StickyKeys_Enable),
+    ("Super_L", XK_Super_L),
+    ("Super_R", XK_Super_R),
+    ("Sys_Req", XK_Sys_Req),
+    ("T", XK_T),
+    ("THORN", XK_THORN),
+    ("Tab", XK_Tab),
+    ("Tabovedot", XK_Tabovedot),
+    ("Tcaron", XK_Tcaron),
+    ("Tcedilla", XK_Tcedilla),
+    ("Terminate_Server", XK_Terminate_Server),
+    ("Thai_baht", XK_Thai_baht),
+    ("Thai_bobaimai", XK_Thai_bobaimai),
+    ("Thai_chochan", XK_Thai_chochan),
+    ("Thai_chochang", XK_Thai_chochang),
+    ("Thai_choching", XK_Thai_choching),
+    ("Thai_chochoe", XK_Thai_chochoe),
+    ("Thai_dochada", XK_Thai_dochada),
+    ("Thai_dodek", XK_Thai_dodek),
+    ("Thai_fofa", XK_Thai_fofa),
+    ("Thai_fofan", XK_Thai_fofan),
+    ("Thai_hohip", XK_Thai_hohip),
+    ("Thai_honokhuk", XK_Thai_honokhuk),
+    ("Thai_khokhai", XK_Thai_khokhai),
+    ("Thai_khokhon", XK_Thai_khokhon),
+    ("Thai_khokhuat", XK_Thai_khokhuat),
+    ("Thai_khokhwai", XK_Thai_khokhwai),
+    ("Thai_khorakhang", XK_Thai_khorakhang),
+    ("Thai_kokai", XK_Thai_kokai),
+    ("Thai_lakkhangyao", XK_Thai_lakkhangyao),
+    ("Thai_lekchet", XK_Thai_lekchet),
+    ("Thai_lekha", XK_Thai_lekha),
+    ("Thai_lekhok", XK_Thai_lekhok),
+    ("Thai_lekkao", XK_Thai_lekkao),
+    ("Thai_leknung", XK_Thai_leknung),
+    ("Thai_lekpaet", XK_Thai_lekpaet),
+    ("Thai_leksam", XK_Thai_leksam),
+    ("Thai_leksi", XK_Thai_leksi),
+    ("Thai_leksong", XK_Thai_leksong),
+    ("Thai_leksun", XK_Thai_leksun),
+    ("Thai_lochula", XK_Thai_lochula),
+    ("Thai_loling", XK_Thai_loling),
+    ("Thai_lu", XK_Thai_lu),
+    ("Thai_maichattawa", XK_Thai_maichattawa),
+    ("Thai_maiek", XK_Thai_maiek),
+    ("Thai_maihanakat", XK_Thai_maihanakat),
+    ("Thai_maihanakat_maitho", XK_Thai_maihanakat_maitho),
+    ("Thai_maitaikhu", XK_Thai_maitaikhu),
+    ("Thai_maitho", XK_Thai_maitho),
+    ("Thai_maitri", XK_Thai_maitri),
+    ("Thai_maiyamok", XK_Thai_maiyamok),
+    ("Thai_moma", XK_Thai_moma),
+    ("Thai_ngongu", XK_Thai_ngongu),
+    ("Thai_nikhahit", XK_Thai_nikhahit),
+    ("Thai_nonen", XK_Thai_nonen),
+    ("Thai_nonu", XK_Thai_nonu),
+    ("Thai_oang", XK_Thai_oang),
+    ("Thai_paiyannoi", XK_Thai_paiyannoi),
+    ("Thai_phinthu", XK_Thai_phinthu),
+    ("Thai_phophan", XK_Thai_phophan),
+    ("Thai_phophung", XK_Thai_phophung),
+    ("Thai_phosamphao", XK_Thai_phosamphao),
+    ("Thai_popla", XK_Thai_popla),
+    ("Thai_rorua", XK_Thai_rorua),
+    ("Thai_ru", XK_Thai_ru),
+    ("Thai_saraa", XK_Thai_saraa),
+    ("Thai_saraaa", XK_Thai_saraaa),
+    ("Thai_saraae", XK_Thai_saraae),
+    ("Thai_saraaimaimalai", XK_Thai_saraaimaimalai),
+    ("Thai_saraaimaimuan", XK_Thai_saraaimaimuan),
+    ("Thai_saraam", XK_Thai_saraam),
+    ("Thai_sarae", XK_Thai_sarae),
+    ("Thai_sarai", XK_Thai_sarai),
+    ("Thai_saraii", XK_Thai_saraii),
+    ("Thai_sarao", XK_Thai_sarao),
+    ("Thai_sarau", XK_Thai_sarau),
+    ("Thai_saraue", XK_Thai_saraue),
+    ("Thai_sarauee", XK_Thai_sarauee),
+    ("Thai_sarauu", XK_Thai_sarauu),
+    ("Thai_sorusi", XK_Thai_sorusi),
+    ("Thai_sosala", XK_Thai_sosala),
+    ("Thai_soso", XK_Thai_soso),
+    ("Thai_sosua", XK_Thai_sosua),
+    ("Thai_thanthakhat", XK_Thai_thanthakhat),
+    ("Thai_thonangmontho", XK_Thai_thonangmontho),
+    ("Thai_thophuthao", XK_Thai_thophuthao),
+    ("Thai_thothahan", XK_Thai_thothahan),
+    ("Thai_thothan", XK_Thai_thothan),
+    ("Thai_thothong", XK_Thai_thothong),
+    ("Thai_thothung", XK_Thai_thothung),
+    ("Thai_topatak", XK_Thai_topatak),
+    ("Thai_totao", XK_Thai_totao),
+    ("Thai_wowaen", XK_Thai_wowaen),
+    ("Thai_yoyak", XK_Thai_yoyak),
+    ("Thai_yoying", XK_Thai_yoying),
+    ("Thorn", XK_Thorn),
+    ("Touroku", XK_Touroku),
+    ("Tslash", XK_Tslash),
+    ("U", XK_U),
+    ("Uacute", XK_Uacute),
+    ("Ubelowdot", XK_Ubelowdot),
+    ("Ubreve", XK_Ubreve),
+    ("Ucircumflex", XK_Ucircumflex),
+    ("Udiaeresis", XK_Udiaeresis),
+    ("Udoubleacute", XK_Udoubleacute),
+    ("Ugrave", XK_Ugrave),
+    ("Uhook", XK_Uhook),
+    ("Uhorn", XK_Uhorn),
+    ("Uhornacute", XK_Uhornacute),
+    ("Uhornbelowdot", XK_Uhornbelowdot),
+    ("Uhorngrave", XK_Uhorngrave),
+    ("Uhornhook", XK_Uhornhook),
+    ("Uhorntilde", XK_Uhorntilde),
+    ("Ukrainian_GHE_WITH_UPTURN", XK_Ukrainian_GHE_WITH_UPTURN),
+    ("Ukrainian_I", XK_Ukrainian_I),
+    ("Ukrainian_IE", XK_Ukrainian_IE),
+    ("Ukrainian_YI", XK_Ukrainian_YI),
+    ("Ukrainian_ghe_with_upturn", XK_Ukrainian_ghe_with_upturn),
+    ("Ukrainian_i", XK_Ukrainian_i),
+    ("Ukrainian_ie", XK_Ukrainian_ie),
+    ("Ukrainian_yi", XK_Ukrainian_yi),
+    ("Ukranian_I", XK_Ukranian_I),
+    ("Ukranian_JE", XK_Ukranian_JE),
+    ("Ukranian_YI", XK_Ukranian_YI),
+    ("Ukranian_i", XK_Ukranian_i),
+    ("Ukranian_je", XK_Ukranian_je),
+    ("Ukranian_yi", XK_Ukranian_yi),
+    ("Umacron", XK_Umacron),
+    ("Undo", XK_Undo),
+    ("Uogonek", XK_Uogonek),
+    ("Up", XK_Up),
+    ("Uring", XK_Uring),
+    ("Utilde", XK_Utilde),
+    ("V", XK_V),
+    ("VoidSymbol", XK_VoidSymbol),
+    ("W", XK_W),
+    ("Wacute", XK_Wacute),
+    ("Wcircumflex", XK_Wcircumflex),
+    ("Wdiaeresis", XK_Wdiaeresis),
+    ("Wgrave", XK_Wgrave),
+    ("WonSign", XK_WonSign),
+    ("X", XK_X),
+    ("Xabovedot", XK_Xabovedot),
+    ("Y", XK_Y),
+    ("Yacute", XK_Yacute),
+    ("Ybelowdot", XK_Ybelowdot),
+    ("Ycircumflex", XK_Ycircumflex),
+    ("Ydiaeresis", XK_Ydiaeresis),
+    ("Ygrave", XK_Ygrave),
+    ("Yhook", XK_Yhook),
+    ("Ytilde", XK_Ytilde),
+    ("Z", XK_Z),
+    ("Zabovedot", XK_Zabovedot),
+    ("Zacute", XK_Zacute),
+    ("Zcaron", XK_Zcaron),
+    ("Zen_Koho", XK_Zen_Koho),
+    ("Zenkaku", XK_Zenkaku),
+    ("Zenkaku_Hankaku", XK_Zenkaku_Hankaku),
+    ("Zstroke", XK_Zstroke),
+    ("a", XK_a),
+    ("aacute", XK_aacute),
+    ("abelowdot", XK_abelowdot),
+    ("abovedot", XK_abovedot),
+    ("abreve", XK_abreve),
+    ("abreveacute", XK_abreveacute),
+    ("abrevebelowdot", XK_abrevebelowdot),
+    ("abrevegrave", XK_abrevegrave),
+    ("abrevehook", XK_abrevehook),
+    ("abrevetilde", XK_abrevetilde),
+    ("acircumflex", XK_acircumflex),
+    ("acircumflexacute", XK_acircumflexacute),
+    ("acircumflexbelowdot", XK_acircumflexbelowdot),
+    ("acircumflexgrave", XK_acircumflexgrave),
+    ("acircumflexhook", XK_acircumflexhook),
+    ("acircumflextilde", XK_acircumflextilde),
+    ("acute", XK_acute),
+    ("adiaeresis", XK_adiaeresis),
+    ("ae", XK_ae),
+    ("agrave", XK_agrave),
+    ("ahook", XK_ahook),
+    ("amacron", XK_amacron),
+    ("ampersand", XK_ampersand),
+    ("aogonek", XK_aogonek),
+    ("apostrophe", XK_apostrophe),
+    ("approxeq", XK_approxeq),
+    ("approximate", XK_approximate),
+    ("aring", XK_aring),
+    ("asciicircum", XK_asciicircum),
+    ("asciitilde", XK_asciitilde),
+    ("asterisk", XK_asterisk),
+    ("at", XK_at),
+    ("atilde", XK_atilde),
+    ("b", XK_b),
+    ("babovedot", XK_babovedot),
+    ("backslash", XK_backslash),
+    ("ballotcross", XK_ballotcross),
+    ("bar", XK_bar),
+    ("because", XK_because),
+    ("blank", XK_blank),
+    ("botintegral", XK_botintegral),
+    ("botleftparens", XK_botleftparens),
+    ("botleftsqbracket", XK_botleftsqbracket),
+    ("botleftsummation", XK_botleftsummation),
+    ("botrightparens", XK_botrightparens),
+    ("botrightsqbracket", XK_botrightsqbracket),
+    ("botrightsummation", XK_botrightsummation),
+    ("bott", XK_bott),
+    ("botvertsummationconnector", XK_botvertsummationconnector),
+    ("braceleft", XK_braceleft),
+    ("braceright", XK_braceright),
+    ("bracketleft", XK_bracketleft),
+    ("bracketright", XK_bracketright),
+    ("braille_blank", XK_braille_blank),
+    ("braille_dot_1", XK_braille_dot_1),
+    ("braille_dot_10", XK_braille_dot_10),
+    ("braille_dot_2", XK_braille_dot_2),
+    ("braille_dot_3", XK_braille_dot_3),
+    ("braille_dot_4", XK_braille_dot_4),
+    ("braille_dot_5", XK_braille_dot_5),
+    ("braille_dot_6", XK_braille_dot_6),
+    ("braille_dot_7", XK_braille_dot_7),
+    ("braille_dot_8", XK_braille_dot_8),
+    ("braille_dot_9", XK_braille_dot_9),
+    ("braille_dots_1", XK_braille_dots_1),
+    ("braille_dots_12", XK_braille_dots_12),
+    ("braille_dots_123", XK_braille_dots_123),
+    ("braille_dots_1234", XK_braille_dots_1234),
+    ("braille_dots_12345", XK_braille_dots_12345),
+    ("braille_dots_123456", XK_braille_dots_123456),
+    ("braille_dots_1234567", XK_braille_dots_1234567),
+    ("braille_dots_12345678", XK_braille_dots_12345678),
+    ("braille_dots_1234568", XK_braille_dots_1234568),
+    ("braille_dots_123457", XK_braille_dots_123457),
+    ("braille_dots_1234578", XK_braille_dots_1234578),
+    ("braille_dots_123458", XK_braille_dots_123458),
+    ("braille_dots_12346", XK_braille_dots_12346),
+    ("braille_dots_123467", XK_braille_dots_123467),
+    ("braille_dots_1234678", XK_braille_dots_1234678),
+    ("braille_dots_123468", XK_braille_dots_123468),
+    ("braille_dots_12347", XK_braille_dots_12347),
+    ("braille_dots_123478", XK_braille_dots_123478),
+    ("braille_dots_12348", XK_braille_dots_12348),
+    ("braille_dots_1235", XK_braille_dots_1235),
+    ("braille_dots_12356", XK_braille_dots_12356),
+    ("braille_dots_123567", XK_braille_dots_123567),
+    ("braille_dots_1235678", XK_braille_dots_1235678),
+    ("braille_dots_123568", XK_braille_dots_123568),
+    ("braille_dots_12357", XK_braille_dots_12357),
+    ("braille_dots_123578", XK_braille_dots_123578),
+    ("braille_dots_12358", XK_braille_dots_12358),
+    ("braille_dots_1236", XK_braille_dots_1236),
+    ("braille_dots_12367", XK_braille_dots_12367),
+    ("braille_dots_123678", XK_braille_dots_123678),
+    ("braille_dots_12368", XK_braille_dots_12368),
+    ("braille_dots_1237", XK_braille_dots_1237),
+    ("braille_dots_12378", XK_braille_dots_12378),
+    ("braille_dots_1238", XK_braille_dots_1238),
+    ("braille_dots_124", XK_braille_dots_124),
+    ("braille_dots_1245", XK_braille_dots_1245),
+    ("braille_dots_12456", XK_braille_dots_12456),
+    ("braille_dots_124567", XK_braille_dots_124567),
+    ("braille_dots_1245678", XK_braille_dots_1245678),
+    ("braille_dots_124568", XK_braille_dots_124568),
+    ("braille_dots_12457", XK_braille_dots_12457),
+    ("braille_dots_124578", XK_braille_dots_124578),
+    ("braille_dots_12458", XK_braille_dots_12458),
+    ("braille_dots_1246", XK_braille_dots_1246),
+    ("braille_dots_12467", XK_braille_dots_12467),
+    ("braille_dots_124678", XK_braille_dots_124678),
+    ("braille_dots_12468", XK_braille_dots_12468),
+    ("braille_dots_1247", XK_braille_dots_1247),
+    ("braille_dots_12478", XK_braille_dots_12478),
+    ("braille_dots_1248", XK_braille_dots_1248),
+    ("braille_dots_125", XK_braille_dots_125),
+    ("braille_dots_1256", XK_braille_dots_1256),
+    ("braille_dots_12567", XK_braille_dots_12567),
+    ("braille_dots_125678", XK_braille_dots_125678),
+    ("braille_dots_12568", XK_braille_dots_12568),
+    ("braille_dots_1257", XK_braille_dots_1257),
+    ("braille_dots_12578", XK_braille_dots_12578),
+    ("braille_dots_1258", XK_braille_dots_1258),
+    ("braille_dots_126", XK_braille_dots_126),
+    ("braille_dots_1267", XK_braille_dots_1267),
+    ("braille_dots_12678", XK_braille_dots_12678),
+    ("braille_dots_1268", XK_braille_dots_1268),
+    ("braille_dots_127", XK_braille_dots_127),
+    ("braille_dots_1278", XK_braille_dots_1278),
+    ("braille_dots_128", XK_braille_dots_128),
+    ("braille_dots_13", XK_braille_dots_13),
+    ("braille_dots_134", XK_braille_dots_134),
+    ("braille_dots_1345", XK_braille_dots_1345),
+    ("braille_dots_13456", XK_braille_dots_13456),
+    ("braille_dots_134567", XK_braille_dots_134567),
+    ("braille_dots_1345678", XK_braille_dots_1345678),
+    ("braille_dots_134568", XK_braille_dots_134568),
+    ("braille_dots_13457", XK_braille_dots_13457),
+    ("braille_dots_134578", XK_braille_dots_134578),
+    ("braille_dots_13458", XK_braille_dots_13458),
+    ("braille_dots_1346", XK_braille_dots_1346),
+    ("braille_dots_13467", XK_braille_dots_13467),
+    ("braille_dots_134678", XK_braille_dots_134678),
+    ("braille_dots_13468", XK_braille_dots_13468),
+    ("braille_dots_1347", XK_braille_dots_1347),
+    ("braille_dots_13478", XK_braille_dots_13478),
+    ("braille_dots_1348", XK_braille_dots_1348),
+    ("braille_dots_135", XK_braille_dots_135),
+    ("braille_dots_1356", XK_braille_dots_1356),
+    ("braille_dots_13567", XK_braille_dots_13567),
+    ("braille_dots_135678", XK_braille_dots_135678),
+    ("braille_dots_13568", XK_braille_dots_13568),
+    ("braille_dots_1357", XK_braille_dots_1357),
+    ("braille_dots_13578", XK_braille_dots_13578),
+    ("braille_dots_1358", XK_braille_dots_1358),
+    ("braille_dots_136", XK_braille_dots_136),
+    ("braille_dots_1367", XK_braille_dots_1367),
+    ("braille_dots_13678", XK_braille_dots_13678),
+    ("braille_dots_1368", XK_braille_dots_1368),
+    ("braille_dots_137", XK_braille_dots_137),
+    ("braille_dots_1378", XK_braille_dots_1378),
+    ("braille_dots_138", XK_braille_dots_138),
+    ("braille_dots_14", XK_braille_dots_14),
+    ("braille_dots_145", XK_braille_dots_145),
+    ("braille_dots_1456", XK_braille_dots_1456),
+    ("braille_dots_14567", XK_braille_dots_14567),
+    ("braille_dots_145678", XK_braille_dots_145678),
+    ("braille_dots_14568", XK_braille_dots_14568),
+    ("braille_dots_1457", XK_braille_dots_1457),
+    ("braille_dots_14578", XK_braille_dots_14578),
+    ("braille_dots_1458", XK_braille_dots_1458),
+    ("braille_dots_146", XK_braille_dots_146),
+    ("braille_dots_1467", XK_braille_dots_1467),
+    ("braille_dots_14678", XK_braille_dots_14678),
+    ("braille_dots_1468", XK_braille_dots_1468),
+    ("braille_dots_147", XK_braille_dots_147),
+    ("braille_dots_1478", XK_braille_dots_1478),
+    ("braille_dots_148", XK_braille_dots_148),
+    ("braille_dots_15", XK_braille_dots_15),
+    ("braille_dots_156", XK_braille_dots_156),
+    ("braille_dots_1567", XK_braille_dots_1567),
+    ("braille_dots_15678", XK_braille_dots_15678),
+    ("braille_dots_1568", XK_braille_dots_1568),
+    ("braille_dots_157", XK_braille_dots_157),
+    ("braille_dots_1578", XK_braille_dots_1578),
+    ("braille_dots_158", XK_braille_dots_158),
+    ("braille_dots_16", XK_braille_dots_16),
+    ("braille_dots_167", XK_braille_dots_167),
+    ("braille_dots_1678", XK_braille_dots_1678),
+    ("braille_dots_168", XK_braille_dots_168),
+    ("braille_dots_17", XK_braille_dots_17),
+    ("braille_dots_178", XK_braille_dots_178),
+    ("braille_dots_18", XK_braille_dots_18),
+    ("braille_dots_2", XK_braille_dots_2),
+    ("braille_dots_23", XK_braille_dots_23),
+    ("braille_dots_234", XK_braille_dots_234),
+    ("braille_dots_2345", XK_braille_dots_2345),
+    ("braille_dots_23456", XK_braille_dots_23456),
+    ("braille_dots_234567", XK_braille_dots_234567),
+    ("braille_dots_2345678", XK_braille_dots_2345678),
+    ("braille_dots_234568", XK_braille_dots_234568),
+    ("braille_dots_23457", XK_braille_dots_23457),
+    ("braille_dots_234578", XK_braille_dots_234578),
+    ("braille_dots_23458", XK_braille_dots_23458),
+    ("braille_dots_2346", XK_braille_dots_2346),
+    ("braille_dots_23467", XK_braille_dots_23467),
+    ("braille_dots_234678", XK_braille_dots_234678),
+    ("braille_dots_23468", XK_braille_dots_23468),
+    ("braille_dots_2347", XK_braille_dots_2347),
+    ("braille_dots_23478", XK_braille_dots_23478),
+    ("braille_dots_2348", XK_braille_dots_2348),
+    ("braille_dots_235", XK_braille_dots_235),
+    ("braille_dots_2356", XK_braille_dots_2356),
+    ("braille_dots_23567", XK_braille_dots_23567),
+    ("braille_dots_235678", XK_braille_dots_235678),
+    ("braille_dots_23568", XK_braille_dots_23568),
+    ("braille_dots_2357", XK_braille_dots_2357),
+    ("braille_dots_23578", XK_braille_dots_23578),
+    ("braille_dots_2358", XK_braille_dots_2358),
+    ("braille_dots_236", XK_braille_dots_236),
+    ("braille_dots_2367", XK_braille_dots_2367),
+    ("braille_dots_23678", XK_braille_dots_23678),
+    ("braille_dots_2368", XK_braille_dots_2368),
+    ("braille_dots_237", XK_braille_dots_237),
+    ("braille_dots_2378", XK_braille_dots_2378),
+    ("braille_dots_238", XK_braille_dots_238),
+    ("braille_dots_24", XK_braille_dots_24),
+    ("braille_dots_245", XK_braille_dots_245),
+    ("braille_dots_2456", XK_braille_dots_2456),
+    ("braille_dots_24567", XK_braille_dots_24567),
+    ("braille_dots_245678", XK_braille_dots_245678),
+    ("braille_dots_24568", XK_braille_dots_24568),
+    ("braille_dots_2457", XK_braille_dots_2457),
+    ("braille_dots_24578", XK_braille_dots_24578),
+    ("braille_dots_2458", XK_braille_dots_2458),
+    ("braille_dots_246", XK_braille_dots_246),
+    ("braille_dots_2467", XK_braille_dots_2467),
+    ("braille_dots_24678", XK_braille_dots_24678),
+    ("braille_dots_2468", XK_braille_dots_2468),
+    ("braille_dots_247", XK_braille_dots_247),
+    ("braille_dots_2478", XK_braille_dots_2478),
+    ("braille_dots_248", XK_braille_dots_248),
+    ("braille_dots_25", XK_braille_dots_25),
+    ("braille_dots_256", XK_braille_dots_256),
+    ("braille_dots_2567", XK_braille_dots_2567),
+    ("braille_dots_25678", XK_braille_dots_25678),
+    ("braille_dots_2568", XK_braille_dots_2568),
+    ("braille_dots_257", XK_braille_dots_257),
+    ("braille_dots_2578", XK_braille_dots_2578),
+    ("braille_dots_258", XK_braille_dots_258),
+    ("braille_dots_26", XK_braille_dots_26),
+    ("braille_dots_267", XK_braille_dots_267),
+    ("braille_dots_2678", XK_braille_dots_2678),
+    ("braille_dots_268", XK_braille_dots_268),
+    ("braille_dots_27", XK_braille_dots_27),
+    ("braille_dots_278", XK_braille_dots_278),
+    ("braille_dots_28", XK_braille_dots_28),
+    ("braille_dots_3", XK_braille_dots_3),
+    ("braille_dots_34", XK_braille_dots_34),
+    ("braille_dots_345", XK_braille_dots_345),
+    ("braille_dots_3456", XK_braille_dots_3456),
+    ("braille_dots_34567", XK_braille_dots_34567),
+    ("braille_dots_345678", XK_braille_dots_345678),
+    ("braille_dots_34568", XK_braille_dots_34568),
+    ("braille_dots_3457", XK_braille_dots_3457),
+    ("braille_dots_34578", XK_braille_dots_34578),
+    ("braille_dots_3458", XK_braille_dots_3458),
+    ("braille_dots_346", XK_braille_dots_346),
+    ("braille_dots_3467", XK_braille_dots_3467),
+    ("braille_dots_34678", XK_braille_dots_34678),
+    ("braille_dots_3468", XK_braille_dots_3468),
+    ("braille_dots_347", XK_braille_dots_347),
+    ("braille_dots_3478", XK_braille_dots_3478),
+    ("braille_dots_348", XK_braille_dots_348),
+    ("braille_dots_35", XK_braille_dots_35),
+    ("braille_dots_356", XK_braille_dots_356),
+    ("braille_dots_3567", XK_braille_dots_3567),
+    ("braille_dots_35678", XK_braille_dots_35678),
+    ("braille_dots_3568", XK_braille_dots_3568),
+    ("braille_dots_357", XK_braille_dots_357),
+    ("braille_dots_3578", XK_braille_dots_3578),
+    ("braille_dots_358", XK_braille_dots_358),
+    ("braille_dots_36", XK_braille_dots_36),
+    ("braille_dots_367", XK_braille_dots_367),
+    ("braille_dots_3678", XK_braille_dots_3678),
+    ("braille_dots_368", XK_braille_dots_368),
+    ("braille_dots_37", XK_braille_dots_37),
+    ("braille_dots_378", XK_braille_dots_378),
+    ("braille_dots_38", XK_braille_dots_38),
+    ("braille_dots_4", XK_braille_dots_4),
+    ("braille_dots_45", XK_braille_dots_45),
+    ("braille_dots_456", XK_braille_dots_456),
+    ("braille_dots_4567", XK_braille_dots_4567),
+    ("braille_dots_45678", XK_braille_dots_45678),
+    ("braille_dots_4568", XK_braille_dots_4568),
+    ("braille_dots_457", XK_braille_dots_457),
+    ("braille_dots_4578", XK_braille_dots_4578),
+    ("braille_dots_458", XK_braille_dots_458),
+    ("braille_dots_46", XK_braille_dots_46),
+    ("braille_dots_467", XK_braille_dots_467),
+    ("braille_dots_4678", XK_braille_dots_4678),
+    ("braille_dots_468", XK_braille_dots_468),
+    ("braille_dots_47", XK_braille_dots_47),
+    ("braille_dots_478", XK_braille_dots_478),
+    ("braille_dots_48", XK_braille_dots_48),
+    ("braille_dots_5", XK_braille_dots_5),
+    ("braille_dots_56", XK_braille_dots_56),
+    ("braille_dots_567", XK_braille_dots_567),
+    ("braille_dots_5678", XK_braille_dots_5678),
+    ("braille_dots_568", XK_braille_dots_568),
+    ("braille_dots_57", XK_braille_dots_57),
+    ("braille_dots_578", XK_braille_dots_578),
+    ("braille_dots_58", XK_braille_dots_58),
+    ("braille_dots_6", XK_braille_dots_6),
+    ("braille_dots_67", XK_braille_dots_67),
+    ("braille_dots_678", XK_braille_dots_678),
+    ("braille_dots_68", XK_braille_dots_68),
+    ("braille_dots_7", XK_braille_dots_7),
+    ("braille_dots_78", XK_braille_dots_78),
+    ("braille_dots_8", XK_braille_dots_8),
+    ("breve", XK_breve),
+    ("brokenbar", XK_brokenbar),
+    ("c", XK_c),
+    ("c_h", XK_c_h),
+    ("cabovedot", XK_cabovedot),
+    ("cacute", XK_cacute),
+    ("careof", XK_careof),
+    ("caret", XK_caret),
+    ("caron", XK_caron),
+    ("ccaron", XK_ccaron),
+    ("ccedilla", XK_ccedilla),
+    ("ccircumflex", XK_ccircumflex),
+    ("cedilla", XK_cedilla),
+    ("cent", XK_cent),
+    ("ch", XK_ch),
+    ("checkerboard", XK_checkerboard),
+    ("checkmark", XK_checkmark),
+    ("circle", XK_circle),
+    ("club", XK_club),
+    ("colon", XK_colon),
+    ("combining_acute", XK_combining_acute),
+    ("combining_belowdot", XK_combining_belowdot),
+    ("combining_grave", XK_combining_grave),
+    ("combining_hook", XK_combining_hook),
+    ("combining_tilde", XK_combining_tilde),
+    ("comma", XK_comma),
+    ("containsas", XK_containsas),
+    ("copyright", XK_copyright),
+    ("cr", XK_cr),
+    ("crossinglines", XK_crossinglines),
+    ("cuberoot", XK_cuberoot),
+    ("currency", XK_currency),
+    ("cursor", XK_cursor),
+    ("d", XK_d),
+    ("dabovedot", XK_dabovedot),
+    ("dagger", XK_dagger),
+    ("dcaron", XK_dcaron),
+    ("dead_A", XK_dead_A),
+    ("dead_E", XK_dead_E),
+    ("dead_I", XK_dead_I),
+    ("dead_O", XK_dead_O),
+    ("dead_U", XK_dead_U),
+    ("dead_a", XK_dead_a),
+    ("dead_abovecomma", XK_dead_abovecomma),
+    ("dead_abovedot", XK_dead_abovedot),
+    ("dead_abovereversedcomma", XK_dead_abovereversedcomma),
+    ("dead_abovering", XK_dead_abovering),
+    ("dead_aboveverticalline", XK_dead_aboveverticalline),
+    ("dead_acute", XK_dead_acute),
+    ("dead_belowbreve", XK_dead_belowbreve),
+    ("dead_belowcircumflex", XK_dead_belowcircumflex),
+    ("dead_belowcomma", XK_dead_belowcomma),
+    ("dead_belowdiaeresis", XK_dead_belowdiaeresis),
+    ("dead_belowdot", XK_dead_belowdot),
+    ("dead_belowmacron", XK_dead_belowmacron),
+    ("dead_belowring", XK_dead_belowring),
+    ("dead_belowtilde", XK_dead_belowtilde),
+    ("dead_belowverticalline", XK_dead_belowverticalline),
+    ("dead_breve", XK_dead_breve),
+    ("dead_capital_schwa", XK_dead_capital_schwa),
+    ("dead_caron", XK_dead_caron),
+    ("dead_cedilla", XK_dead_cedilla),
+    ("dead_circumflex", XK_dead_circumflex),
+    ("dead_currency", XK_dead_currency),
+    ("dead_dasia", XK_dead_dasia),
+    ("dead_diaeresis", XK_dead_diaeresis),
+    ("dead_doubleacute", XK_dead_doubleacute),
+    ("dead_doublegrave", XK_dead_doublegrave),
+    ("dead_e", XK_dead_e),
+    ("dead_grave", XK_dead_grave),
+    ("dead_greek", XK_dead_greek),
+    ("dead_hook", XK_dead_hook),
+    ("dead_horn", XK_dead_horn),
+    ("dead_i", XK_dead_i),
+    ("dead_invertedbreve", XK_dead_invertedbreve),
+    ("dead_iota", XK_dead_iota),
+    ("dead_longsolidusoverlay", XK_dead_longsolidusoverlay),
+    ("dead_lowline", XK_dead_lowline),
+    ("dead_macron", XK_dead_macron),
+    ("dead_o", XK_dead_o),
+    ("dead_ogonek", XK_dead_ogonek),
+    ("dead_perispomeni", XK_dead_perispomeni),
+    ("dead_psili", XK_dead_psili),
+    ("dead_semivoiced_sound", XK_dead_semivoiced_sound),
+    ("dead_small_schwa", XK_dead_small_schwa),
+    ("dead_stroke", XK_dead_stroke),
+    ("dead_tilde", XK_dead_tilde),
+    ("dead_u", XK_dead_u),
+    ("dead_voiced_sound", XK_dead_voiced_sound),
+    ("decimalpoint", XK_decimalpoint),
+    ("degree", XK_degree),
+    ("diaeresis", XK_diaeresis),
+    ("diamond", XK_diamond),
+    ("digitspace", XK_digitspace),
+    ("dintegral", XK_dintegral),
+    ("division", XK_division),
+    ("dollar", XK_dollar),
+    ("doubbaselinedot", XK_doubbaselinedot),
+    ("doubleacute", XK_doubleacute),
+    ("doubledagger", XK_doubledagger),
+    ("doublelowquotemark", XK_doublelowquotemark),
+    ("downarrow", XK_downarrow),
+    ("downcaret", XK_downcaret),
+    ("downshoe", XK_downshoe),
+    ("downstile", XK_downstile),
+    ("downtack", XK_downtack),
+    ("dstroke", XK_dstroke),
+    ("e", XK_e),
+    ("eabovedot", XK_eabovedot),
+    ("eacute", XK_eacute),
+    ("ebelowdot", XK_ebelowdot),
+    ("ecaron", XK_ecaron),
+    ("ecircumflex", XK_ecircumflex),
+    ("ecircumflexacute", XK_ecircumflexacute),
+    ("ecircumflexbelowdot", XK_ecircumflexbelowdot),
+    ("ecircumflexgrave", XK_ecircumflexgrave),
+    ("ecircumflexhook", XK_ecircumflexhook),
+    ("ecircumflextilde", XK_ecircumflextilde),
+    ("ediaeresis", XK_ediaeresis),
+    ("egrave", XK_egrave),
+    ("ehook", XK_ehook),
+    ("eightsubscript", XK_eightsubscript),
+    ("eightsuperior", XK_eightsuperior),
+    ("elementof", XK_elementof),
+    ("ellipsis", XK_ellipsis),
+    ("em3space", XK_em3space),
+    ("em4space", XK_em4space),
+    ("emacron", XK_emacron),
+    ("emdash", XK_emdash),
+    ("emfilledcircle", XK_emfilledcircle),
+    ("emfilledrect", XK_emfilledrect),
+    ("emopencircle", XK_emopencircle),
+    ("emopenrectangle", XK_emopenrectangle),
+    ("emptyset", XK_emptyset),
+    ("emspace", XK_emspace),
+    ("endash", XK_endash),
+    ("enfilledcircbullet", XK_enfilledcircbullet),
+    ("enfilledsqbullet", XK_enfilledsqbullet),
+    ("eng", XK_eng),
+    ("enopencircbullet", XK_enopencircbullet),
+    ("enopensquarebullet", XK_enopensquarebullet),
+    ("enspace", XK_enspace),
+    ("eogonek", XK_eogonek),
+    ("equal", XK_equal),
+    ("eth", XK_eth),
+    ("etilde", XK_etilde),
+    ("exclam", XK_exclam),
+    ("exclamdown", XK_exclamdown),
+    ("ezh", XK_ezh),
+    ("f", XK_f),
+    ("fabovedot", XK_fabovedot),
+    ("femalesymbol", XK_femalesymbol),
+    ("ff", XK_ff),
+    ("figdash", XK_figdash),
+    ("filledlefttribullet", XK_filledlefttribullet),
+    ("filledrectbullet", XK_filledrectbullet),
+    ("filledrighttribullet", XK_filledrighttribullet),
+    ("filledtribulletdown", XK_filledtribulletdown),
+    ("filledtribulletup", XK_filledtribulletup),
+    ("fiveeighths", XK_fiveeighths),
+    ("fivesixths", XK_fivesixths),
+    ("fivesubscript", XK_fivesubscript),
+    ("fivesuperior", XK_fivesuperior),
+    ("fourfifths", XK_fourfifths),
+    ("foursubscript", XK_foursubscript),
+    ("foursuperior", XK_foursuperior),
+    ("fourthroot", XK_fourthroot),
+    ("function", XK_function),
+    ("g", XK_g),
+    ("gabovedot", XK_gabovedot),
+    ("gbreve", XK_gbreve),
+    ("gcaron", XK_gcaron),
+    ("gcedilla", XK_gcedilla),
+    ("gcircumflex", XK_gcircumflex),
+    ("grave", XK_grave),
+    ("greater", XK_greater),
+    ("greaterthanequal", XK_greaterthanequal),
+    ("guillemotleft", XK_guillemotleft),
+    ("guillemotright", XK_guillemotright),
+    ("h", XK_h),
+    ("hairspace", XK_hairspace),
+    ("hcircumflex", XK_hcircumflex),
+    ("heart", XK_heart),
+    ("hebrew_aleph", XK_hebrew_aleph),
+    ("hebrew_ayin", XK_hebrew_ayin),
+    ("hebrew_bet", XK_hebrew_bet),
+    ("hebrew_beth", XK_hebrew_beth),
+    ("hebrew_chet", XK_hebrew_chet),
+    ("hebrew_dalet", XK_hebrew_dalet),
+    ("hebrew_daleth", XK_hebrew_daleth),
+    ("hebrew_doublelowline", XK_hebrew_doublelowline),
+    ("hebrew_finalkaph", XK_hebrew_finalkaph),
+    ("hebrew_finalmem", XK_hebrew_finalmem),
+    ("hebrew_finalnun", XK_hebrew_finalnun),
+    ("hebrew_finalpe", XK_hebrew_finalpe),
+    ("hebrew_finalzade", XK_hebrew_finalzade),
+    ("hebrew_finalzadi", XK_hebrew_finalzadi),
+    ("hebrew_gimel", XK_hebrew_gimel),
+    ("hebrew_gimmel", XK_hebrew_gimmel),
+    ("hebrew_he", XK_hebrew_he),
+    ("hebrew_het", XK_hebrew_het),
+    ("hebrew_kaph", XK_hebrew_kaph),
+    ("hebrew_kuf", XK_hebrew_kuf),
+    ("hebrew_lamed", XK_hebrew_lamed),
+    ("hebrew_mem", XK_hebrew_mem),
+    ("hebrew_nun", XK_hebrew_nun),
+    ("hebrew_pe", XK_hebrew_pe),
+    ("hebrew_qoph", XK_hebrew_qoph),
+    ("hebrew_resh", XK_hebrew_resh),
+    ("hebrew_samech", XK_hebrew_samech),
+    ("hebrew_samekh", XK_hebrew_samekh),
+    ("hebrew_shin", XK_hebrew_shin),
+    ("hebrew_taf", XK_hebrew_taf),
+    ("hebrew_taw", XK_hebrew_taw),
+    ("hebrew_tet", XK_hebrew_tet),
+    ("hebrew_teth", XK_hebrew_teth),
+    ("hebrew_waw", XK_hebrew_waw),
+    ("hebrew_yod", XK_hebrew_yod),
+    ("hebrew_zade", XK_hebrew_zade),
+    ("hebrew_zadi", XK_hebrew_zadi),
+    ("hebrew_zain", XK_hebrew_zain),
+    ("hebrew_zayin", XK_hebrew_zayin),
+    ("hexagram", XK_hexagram),
+    ("horizconnector", XK_horizconnector),
+    ("horizlinescan1", XK_horizlinescan1),
+    ("horizlinescan3", XK_horizlinescan3),
+    ("horizlinescan5", XK_horizlinescan5),
+    ("horizlinescan7", XK_horizlinescan7),
+    ("horizlinescan9", XK_horizlinescan9),
+    ("hstroke", XK_hstroke),
+    ("ht", XK_ht),
+    ("hyphen", XK_hyphen),
+    ("i", XK_i),
+    ("iacute", XK_iacute),
+    ("ibelowdot", XK_ibelowdot),
+    ("ibreve", XK_ibreve),
+    ("icircumflex", XK_icircumflex),
+    ("identical", XK_identical),
+    ("idiaeresis", XK_idiaeresis),
+    ("idotless", XK_idotless),
+    ("ifonlyif", XK_ifonlyif),
+    ("igrave", XK_igrave),
+    ("ihook", XK_ihook),
+    ("imacron", XK_imacron),
+    ("implies", XK_implies),
+    ("includedin", XK_includedin),
+    ("includes", XK_includes),
+    ("infinity", XK_infinity),
+    ("integral", XK_integral),
+    ("intersection", XK_intersection),
+    ("iogonek", XK_iogonek),
+    ("itilde", XK_itilde),
+    ("j", XK_j),
+    ("jcircumflex", XK_jcircumflex),
+    ("jot", XK_jot),
+    ("k", XK_k),
+    ("kana_A", XK_kana_A),
+    ("kana_CHI", XK_kana_CHI),
+    ("kana_E", XK_kana_E),
+    ("kana_FU", XK_kana_FU),
+    ("kana_HA", XK_kana_HA),
+    ("kana_HE", XK_kana_HE),
+    ("kana_HI", XK_kana_HI),
+    ("kana_HO", XK_kana_HO),
+    ("kana_HU", XK_kana_HU),
+    ("kana_I", XK_kana_I),
+    ("kana_KA", XK_kana_KA),
+    ("kana_KE", XK_kana_KE),
+    ("kana_KI", XK_kana_KI),
+    ("kana_KO", XK_kana_KO),
+    ("kana_KU", XK_kana_KU),
+    ("kana_MA", XK_kana_MA),
+    ("kana_ME", XK_kana_ME),
+    ("kana_MI", XK_kana_MI),
+    ("kana_MO", XK_kana_MO),
+    ("kana_MU", XK_kana_MU),
+    ("kana_N", XK_kana_N),
+    ("kana_NA", XK_kana_NA),
+    ("kana_NE", XK_kana_NE),
+    ("kana_NI", XK_kana_NI),
+    ("kana_NO", XK_kana_NO),
+    ("kana_NU", XK_kana_NU),
+    ("kana_O", XK_kana_O),
+    ("kana_RA", XK_kana_RA),
+    ("kana_RE", XK_kana_RE),
+    ("kana_RI", XK_kana_RI),
+    ("kana_RO", XK_kana_RO),
+    ("kana_RU", XK_kana_RU),
+    ("kana_SA", XK_kana_SA),
+    ("kana_SE", XK_kana_SE),
+    ("kana_SHI", XK_kana_SHI),
+    ("kana_SO", XK_kana_SO),
+    ("kana_SU", XK_kana_SU),
+    ("kana_TA", XK_kana_TA),
+    ("kana_TE", XK_kana_TE),
+    ("kana_TI", XK_kana_TI),
+    ("kana_TO", XK_kana_TO),
+    ("kana_TSU", XK_kana_TSU),
+    ("kana_TU", XK_kana_TU),
+    ("kana_U", XK_kana_U),
+    ("kana_WA", XK_kana_WA),
+    ("kana_WO", XK_kana_WO),
+    ("kana_YA", XK_kana_YA),
+    ("kana_YO", XK_kana_YO),
+    ("kana_YU", XK_kana_YU),
+    ("kana_a", XK_kana_a),
+    ("kana_closingbracket", XK_kana_closingbracket),
+    ("kana_comma", XK_kana_comma),
+    ("kana_conjunctive", XK_kana_conjunctive),
+    ("kana_e", XK_kana_e),
+    ("kana_fullstop", XK_kana_fullstop),
+    ("kana_i", XK_kana_i),
+    ("kana_middledot", XK_kana_middledot),
+    ("kana_o", XK_kana_o),
+    ("kana_openingbracket", XK_kana_openingbracket),
+    ("kana_switch", XK_kana_switch),
+    ("kana_tsu", XK_kana_tsu),
+    ("kana_tu", XK_kana_tu),
+    ("kana_u", XK_kana_u),
+    ("kana_ya", XK_kana_ya),
+    ("kana_yo", XK_kana_yo),
+    ("kana_yu", XK_kana_yu),
+    ("kappa", XK_kappa),
+    ("kcedilla", XK_kcedilla),
+    ("kra", XK_kra),
+    ("l", XK_l),
+    ("lacute", XK_lacute),
+    ("latincross", XK_latincross),
+    ("lbelowdot", XK_lbelowdot),
+    ("lcaron", XK_lcaron),
+    ("lcedilla", XK_lcedilla),
+    ("leftanglebracket", XK_leftanglebracket),
+    ("leftarrow", XK_leftarrow),
+    ("leftcaret", XK_leftcaret),
+    ("leftdoublequotemark", XK_leftdoublequotemark),
+    ("leftmiddlecurlybrace", XK_leftmiddlecurlybrace),
+    ("leftopentriangle", XK_leftopentriangle),
+    ("leftpointer", XK_leftpointer),
+    ("leftradical", XK_leftradical),
+    ("leftshoe", XK_leftshoe),
+    ("leftsinglequotemark", XK_leftsinglequotemark),
+    ("leftt", XK_leftt),
+    ("lefttack", XK_lefttack),
+    ("less", XK_less),
+    ("lessthanequal", XK_lessthanequal),
+    ("lf", XK_lf),
+    ("logicaland", XK_logicaland),
+    ("logicalor", XK_logicalor),
+    ("lowleftcorner", XK_lowleftcorner),
+    ("lowrightcorner", XK_lowrightcorner),
+    ("lstroke", XK_lstroke),
+    ("m", XK_m),
+    ("mabovedot", XK_mabovedot),
+    ("macron", XK_macron),
+    ("malesymbol", XK_malesymbol),
+    ("maltesecross", XK_maltesecross),
+    ("marker", XK_marker),
+    ("masculine", XK_masculine),
+    ("minus", XK_minus),
+    ("minutes", XK_minutes),
+    ("mu", XK_mu),
+    ("multiply", XK_multiply),
+    ("musicalflat", XK_musicalflat),
+    ("musicalsharp", XK_musicalsharp),
+    ("n", XK_n),
+    ("nabla", XK_nabla),
+    ("nacute", XK_nacute),
+    ("ncaron", XK_ncaron),
+    ("ncedilla", XK_ncedilla),
+    ("ninesubscript", XK_ninesubscript),
+    ("ninesuperior", XK_ninesuperior),
+    ("nl", XK_nl),
+    ("nobreakspace", XK_nobreakspace),
+    ("notapproxeq", XK_notapproxeq),
+    ("notelementof", XK_notelementof),
+    ("notequal", XK_notequal),
+    ("notidentical", XK_notidentical),
+    ("notsign", XK_notsign),
+    ("ntilde", XK_ntilde),
+    ("numbersign", XK_numbersign),
+    ("numerosign", XK_numerosign),
+    ("o", XK_o),
+    ("oacute", XK_oacute),
+    ("obarred", XK_obarred),
+    ("obelowdot", XK_obelowdot),
+    ("ocaron", XK_ocaron),
+    ("ocircumflex", XK_ocircumflex),
+    ("ocircumflexacute", XK_ocircumflexacute),
+    ("ocircumflexbelowdot", XK_ocircumflexbelowdot),
+    ("ocircumflexgrave", XK_ocircumflexgrave),
+    ("ocircumflexhook", XK_ocircumflexhook),
+    ("ocircumflextilde", XK_ocircumflextilde),
+    ("odiaeresis", XK_odiaeresis),
+    ("odoubleacute", XK_odoubleacute),
+    ("oe", XK_oe),
+    ("ogonek", XK_ogonek),
+    ("ograve", XK_ograve),
+    ("ohook", XK_ohook),
+    ("ohorn", XK_ohorn),
+    ("ohornacute", XK_ohornacute),
+    ("ohornbelowdot", XK_ohornbelowdot),
+    ("ohorngrave", XK_ohorngrave),
+    ("ohornhook", XK_ohornhook),
+    ("ohorntilde", XK_ohorntilde),
+    ("omacron", XK_omacron),
+    ("oneeighth", XK_oneeighth),
+    ("onefifth", XK_onefifth),
+    ("onehalf", XK_onehalf),
+    ("onequarter", XK_onequarter),
+    ("onesixth", XK_onesixth),
+    ("onesubscript", XK_onesubscript),
+    ("onesuperior", XK_onesuperior),
+    ("onethird", XK_onethird),
+    ("ooblique", XK_ooblique),
+    ("openrectbullet", XK_openrectbullet),
+    ("openstar", XK_openstar),
+    ("opentribulletdown", XK_opentribulletdown),
+    ("opentribulletup", XK_opentribulletup),
+    ("ordfeminine", XK_ordfeminine),
+    ("oslash", XK_oslash),
+    ("otilde", XK_otilde),
+    ("overbar", XK_overbar),
+    ("overline", XK_overline),
+    ("p", XK_p),
+    ("pabovedot", XK_pabovedot),
+    ("paragraph", XK_paragraph),
+    ("parenleft", XK_parenleft),
+    ("parenright", XK_parenright),
+    ("partdifferential", XK_partdifferential),
+    ("partialderivative", XK_partialderivative),
+    ("percent", XK_percent),
+    ("period", XK_period),
+    ("periodcentered", XK_periodcentered),
+    ("permille", XK_permille),
+    ("phonographcopyright", XK_phonographcopyright),
+    ("plus", XK_plus),
+    ("plusminus", XK_plusminus),
+    ("prescription", XK_prescription),
+    ("prolongedsound", XK_prolongedsound),
+    ("punctspace", XK_punctspace),
+    ("q", XK_q),
+    ("quad", XK_quad),
+    ("question", XK_question),
+    ("questiondown", XK_questiondown),
+    ("quotedbl", XK_quotedbl),
+    ("quoteleft", XK_quoteleft),
+    ("quoteright", XK_quoteright),
+    ("r", XK_r),
+    ("racute", XK_racute),
+    ("radical", XK_radical),
+    ("rcaron", XK_rcaron),
+    ("rcedilla", XK_rcedilla),
+    ("registered", XK_registered),
+    ("rightanglebracket", XK_rightanglebracket),
+    ("rightarrow", XK_rightarrow),
+    ("rightcaret", XK_rightcaret),
+    ("rightdoublequotemark", XK_rightdoublequotemark),
+    ("rightmiddlecurlybrace", XK_rightmiddlecurlybrace),
+    ("rightmiddlesummation", XK_rightmiddlesummation),
+    ("rightopentriangle", XK_rightopentriangle),
+    ("rightpointer", XK_rightpointer),
+    ("rightshoe", XK_rightshoe),
+    ("rightsinglequotemark", XK_rightsinglequotemark),
+    ("rightt", XK_rightt),
+    ("righttack", XK_righttack),
+    ("s", XK_s),
+    ("sabovedot", XK_sabovedot),
+    ("sacute", XK_sacute),
+    ("scaron", XK_scaron),
+    ("scedilla", XK_scedilla),
+    ("schwa", XK_schwa),
+    ("scircumflex", XK_scircumflex),
+    ("script_switch", XK_script_switch),
+    ("seconds", XK_seconds),
+    ("section", XK_section),
+    ("semicolon", XK_semicolon),
+    ("semivoicedsound", XK_semivoicedsound),
+    ("seveneighths", XK_seveneighths),
+    ("sevensubscript", XK_sevensubscript),
+    ("sevensuperior", XK_sevensuperior),
+    ("signaturemark", XK_signaturemark),
+    ("signifblank", XK_signifblank),
+    ("similarequal", XK_similarequal),
+    ("singlelowquotemark", XK_singlelowquotemark),
+    ("sixsubscript", XK_sixsubscript),
+    ("sixsuperior", XK_sixsuperior),
+    ("slash", XK_slash),
+    ("soliddiamond", XK_soliddiamond),
+    ("space", XK_space),
+    ("squareroot", XK_squareroot),
+    ("ssharp", XK_ssharp),
+    ("sterling", XK_sterling),
+    ("stricteq", XK_stricteq),
+    ("t", XK_t),
+    ("tabovedot", XK_tabovedot),
+    ("tcaron", XK_tcaron),
+    ("tcedilla", XK_tcedilla),
+    ("telephone", XK_telephone),
+    ("telephonerecorder", XK_telephonerecorder),
+    ("therefore", XK_therefore),
+    ("thinspace", XK_thinspace),
+    ("thorn", XK_thorn),
+    ("threeeighths", XK_threeeighths),
+    ("threefifths", XK_threefifths),
+    ("threequarters", XK_threequarters),
+    ("threesubscript", XK_threesubscript),
+    ("threesuperior", XK_threesuperior),
+    ("tintegral", XK_tintegral),
+    ("topintegral", XK_topintegral),
+    ("topleftparens", XK_topleftparens),
+    ("topleftradical", XK_topleftradical),
+    ("topleftsqbracket", XK_topleftsqbracket),
+    ("topleftsummation", XK_topleftsummation),
+    ("toprightparens", XK_toprightparens),
+    ("toprightsqbracket", XK_toprightsqbracket),
+    ("toprightsummation", XK_toprightsummation),
+    ("topt", XK_topt),
+    ("topvertsummationconnector", XK_topvertsummationconnector),
+    ("trademark", XK_trademark),
+    ("trademarkincircle", XK_trademarkincircle),
+    ("tslash", XK_tslash),
+    ("twofifths", XK_twofifths),
+    ("twosubscript", XK_twosubscript),
+    ("twosuperior", XK_twosuperior),
+    ("twothirds", XK_twothirds),
+    ("u", XK_u),
+    ("uacute", XK_uacute),
+    ("ubelowdot", XK_ubelowdot),
+    ("ubreve", XK_ubreve),
+    ("ucircumflex", XK_ucircumflex),
+    ("udiaeresis", XK_udiaeresis),
+    ("udoubleacute", XK_udoubleacute),
+    ("ugrave", XK_ugrave),
+    ("uhook", XK_uhook),
+    ("uhorn", XK_uhorn),
+    ("uhornacute", XK_uhornacute),
+    ("uhornbelowdot", XK_uhornbelowdot),
+    ("uhorngrave", XK_uhorngrave),
+    ("uhornhook", XK_uhornhook),
+    ("uhorntilde", XK_uhorntilde),
+    ("umacron", XK_umacron),
+    ("underbar", XK_underbar),
+    ("underscore", XK_underscore),
+    ("union", XK_union),
+    ("uogonek", XK_uogonek),
+    ("uparrow", XK_uparrow),
+    ("upcaret", XK_upcaret),
+    ("upleftcorner", XK_upleftcorner),
+    ("uprightcorner", XK_uprightcorner),
+    ("upshoe", XK_upshoe),
+    ("upstile", XK_upstile),
+    ("uptack", XK_uptack),
+    ("uring", XK_uring),
+    ("utilde", XK_utilde),
+    ("v", XK_v),
+    ("variation", XK_variation),
+    ("vertbar", XK_vertbar),
+    ("vertconnector", XK_vertconnector),
+    ("voicedsound", XK_voicedsound),
+    ("vt", XK_vt),
+    ("w", XK_w),
+    ("wacute", XK_wacute),
+    ("wcircumflex", XK_wcircumflex),
+    ("wdiaeresis", XK_wdiaeresis),
+    ("wgrave", XK_wgrave),
+    ("x", XK_x),
+    ("xabovedot", XK_xabovedot),
+    ("y", XK_y),
+    ("yacute", XK_yacute),
+    ("ybelowdot", XK_ybelowdot),
+    ("ycircumflex", XK_ycircumflex),
+    ("ydiaeresis", XK_ydiaeresis),
+    ("yen", XK_yen),
+    ("ygrave", XK_ygrave),
+    ("yhook", XK_yhook),
+    ("ytilde", XK_ytilde),
+    ("z", XK_z),
+    ("zabovedot", XK_zabovedot),
+    ("zacute", XK_zacute),
+    ("zcaron", XK_zcaron),
+    ("zerosubscript", XK_zerosubscript),
+    ("zerosuperior", XK_zerosuperior),
+    ("zstroke", XK_zstroke),
+];
+
+/// Returns the character typed by a key with the given keysym, if any.
+///
+/// Besides the keysyms of characters this covers the keypad and the keys that type control
+/// characters, e.g. `XK_Return` and `XK_BackSpace`.
+pub fn keysym_to_char(keysym: u32) -> Option<char> {
+    match keysym {
+        // The keysyms of Unicode characters not covered by a legacy keysym.
+        0x0100_0100..=0x0110_ffff => char::from_u32(keysym - 0x0100_0000),
+        XK_BackSpace | XK_Tab | XK_Linefeed | XK_Clear | XK_Return | XK_Escape | XK_KP_Tab
+        | XK_KP_Enter | XK_Delete => char::from_u32(keysym & 0x7f),
+        XK_KP_Space => Some(' '),
+        XK_KP_Multiply..=XK_KP_9 => char::from_u32(keysym & 0x7f),
+        XK_KP_Equal => Some('='),
+        _ => CHARACTERS
+            .binary_search_by_key(&keysym, |&(keysym, _)| keysym)
+            .ok()
+            .map(|index| CHARACTERS[index].1),
+    }
+}
+
+/// Returns the keysym of a character.
+///
+/// Characters with a legacy keysym are mapped to it, all others to their Unicode keysym.
+pub fn char_to_keysym(character: char) -> u32 {
+    let code = character as u32;
+    match code {
+        0x20..=0x7e | 0xa0..=0xff => code,
+        _ => CHARACTERS
+            .iter()
+            .find(|&&(_, candidate)| candidate == character)
+            .map_or(0x0100_0000 + code, |&(keysym, _)| keysym),
+    }
+}
+
+/// Returns the keysym with the given name, e.g. `Return` for `XK_Return`.
+pub fn keysym_from_name(name: &str) -> Option<u32> {
+    NAMES
+        .binary_search_by_key(&name, |&(name, _)| name)
+        .ok()
+        .map(|index| NAMES[index].1)
+}
+
+/// Returns a name of the keysym, e.g. `Return` for `XK_Return`.
+pub fn keysym_name(keysym: u32) -> Option<&'static str> {
+    NAMES
+        .iter()
+        .find(|&&(_, candidate)| candidate == keysym)
+        .map(|&(name, _)| name)
+}
@@ -0,0 +1,58 @@
+// Generated by xcbgen, do not edit.
+
+pub mod bigreq;
+#[cfg(feature = "composite")]
+pub mod composite;
+#[cfg(feature = "damage")]
+pub mod damage;
+#[cfg(feature = "dpms")]
+pub mod dpms;
+#[cfg(feature = "dri2")]
+pub mod dri2;
+#[cfg(feature = "dri3")]
+pub mod dri3;
+#[cfg(feature = "glx")]
+pub mod glx;
+pub mod keysym;
+#[cfg(feature = "present")]
+pub mod present;
+#[cfg(feature = "randr")]
+pub mod randr;
+#[cfg(feature = "record")]
+pub mod record;
+#[cfg(feature = "render")]
+pub mod render;
+#[cfg(feature = "res")]
+pub mod res;
+#[cfg(feature = "screensaver")]
+pub mod screensaver;
+#[cfg(feature = "shape")]
+pub mod shape;
+#[cfg(feature = "shm")]
+pub mod shm;
+#[cfg(feature = "sync")]
+pub mod sync;
+pub mod xc_misc;
+#[cfg(feature = "xevie")]
+pub mod xevie;
+#[cfg(feature = "xf86dri")]
+pub mod xf86dri;
+#[cfg(feature = "xfixes")]
+pub mod xfixes;
+#[cfg(feature = "xinerama")]
+pub mod xinerama;
+#[cfg(feature = "xinput")]
+pub mod xinput;
+#[cfg(feature = "xkb")]
+pub mod xkb;
+#[cfg(feature = "xprint")]
+pub mod xprint;
+pub mod xproto;
+#[cfg(feature = "xselinux")]
+pub mod xselinux;
+#[cfg(feature = "xtest")]
+pub mod xtest;
+#[cfg(feature = "xv")]
+pub mod xv;
+#[cfg(feature = "xvmc")]
+pub mod xvmc;
@@ -0,0 +1,340 @@
+// Generated by xcbgen from xml/present.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::randr::cdef::XCBRandrCrtc;
+use proto::sync::cdef::XCBSyncFence;
+use proto::xfixes::cdef::XCBXfixesRegion;
+use proto::xproto::cdef::{XCBPixmap, XCBRectangle, XCBWindow};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBPresentNotify {
+    pub window: XCBWindow,
+    pub serial: u32,
+}
+
+#[repr(C)]
+pub struct XCBPresentNotifyIterator {
+    pub data: *mut XCBPresentNotify,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBPresentQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+pub type XCBPresentQueryVersionCookie = XCBCookie<XCBPresentQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBPresentQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+#[repr(C)]
+pub struct XCBPresentPixmapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub pixmap: XCBPixmap,
+    pub serial: u32,
+    pub valid: XCBXfixesRegion,
+    pub update: XCBXfixesRegion,
+    pub x_off: i16,
+    pub y_off: i16,
+    pub target_crtc: XCBRandrCrtc,
+    pub wait_fence: XCBSyncFence,
+    pub idle_fence: XCBSyncFence,
+    pub options: u32,
+    pub pad0: [u8; 4],
+    pub target_msc: u64,
+    pub divisor: u64,
+    pub remainder: u64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBPresentNotifyMscRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub serial: u32,
+    pub pad0: [u8; 4],
+    pub target_msc: u64,
+    pub divisor: u64,
+    pub remainder: u64,
+}
+
+pub type XCBPresentEvent = u32;
+
+#[repr(C)]
+pub struct XCBPresentEventIterator {
+    pub data: *mut XCBPresentEvent,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBPresentSelectInputRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub eid: XCBPresentEvent,
+    pub window: XCBWindow,
+    pub event_mask: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBPresentQueryCapabilitiesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub target: u32,
+}
+
+pub type XCBPresentQueryCapabilitiesCookie = XCBCookie<XCBPresentQueryCapabilitiesReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBPresentQueryCapabilitiesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub capabilities: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBPresentGenericEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub evtype: u16,
+    pub pad0: [u8; 2],
+    pub event: XCBPresentEvent,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBPresentConfigureNotifyEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub pad0: [u8; 2],
+    pub event: XCBPresentEvent,
+    pub window: XCBWindow,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub off_x: i16,
+    pub off_y: i16,
+    pub full_sequence: u32,
+    pub pixmap_width: u16,
+    pub pixmap_height: u16,
+    pub pixmap_flags: u32,
+}
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct XCBPresentCompleteNotifyEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub kind: u8,
+    pub mode: u8,
+    pub event: XCBPresentEvent,
+    pub window: XCBWindow,
+    pub serial: u32,
+    pub ust: u64,
+    pub full_sequence: u32,
+    pub msc: u64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBPresentIdleNotifyEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub pad0: [u8; 2],
+    pub event: XCBPresentEvent,
+    pub window: XCBWindow,
+    pub serial: u32,
+    pub pixmap: XCBPixmap,
+    pub idle_fence: XCBSyncFence,
+    pub full_sequence: u32,
+}
+
+#[repr(C, packed)]
+pub struct XCBPresentRedirectNotifyEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub update_window: u8,
+    pub pad0: u8,
+    pub event: XCBPresentEvent,
+    pub event_window: XCBWindow,
+    pub window: XCBWindow,
+    pub pixmap: XCBPixmap,
+    pub serial: u32,
+    pub full_sequence: u32,
+    pub valid_region: XCBXfixesRegion,
+    pub update_region: XCBXfixesRegion,
+    pub valid_rect: XCBRectangle,
+    pub update_rect: XCBRectangle,
+    pub x_off: i16,
+    pub y_off: i16,
+    pub target_crtc: XCBRandrCrtc,
+    pub wait_fence: XCBSyncFence,
+    pub idle_fence: XCBSyncFence,
+    pub options: u32,
+    pub pad1: [u8; 4],
+    pub target_msc: u64,
+    pub divisor: u64,
+    pub remainder: u64,
+}
+
+#[link(name = "xcb-present")]
+extern "system" {
+    pub static mut xcb_present_id: XCBExtension;
+    pub fn xcb_present_notify_next(i: *mut XCBPresentNotifyIterator);
+    pub fn xcb_present_notify_end(i: XCBPresentNotifyIterator) -> XCBGenericIterator;
+    pub fn xcb_present_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBPresentQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBPresentQueryVersionReply;
+    pub fn xcb_present_query_version(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+    ) -> XCBPresentQueryVersionCookie;
+    pub fn xcb_present_query_version_unchecked(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+    ) -> XCBPresentQueryVersionCookie;
+    pub fn xcb_present_pixmap_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        pixmap: XCBPixmap,
+        serial: u32,
+        valid: XCBXfixesRegion,
+        update: XCBXfixesRegion,
+        x_off: i16,
+        y_off: i16,
+        target_crtc: XCBRandrCrtc,
+        wait_fence: XCBSyncFence,
+        idle_fence: XCBSyncFence,
+        options: u32,
+        target_msc: u64,
+        divisor: u64,
+        remainder: u64,
+        notifies_len: u32,
+        notifies: *const XCBPresentNotify,
+    ) -> XCBVoidCookie;
+    pub fn xcb_present_pixmap(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        pixmap: XCBPixmap,
+        serial: u32,
+        valid: XCBXfixesRegion,
+        update: XCBXfixesRegion,
+        x_off: i16,
+        y_off: i16,
+        target_crtc: XCBRandrCrtc,
+        wait_fence: XCBSyncFence,
+        idle_fence: XCBSyncFence,
+        options: u32,
+        target_msc: u64,
+        divisor: u64,
+        remainder: u64,
+        notifies_len: u32,
+        notifies: *const XCBPresentNotify,
+    ) -> XCBVoidCookie;
+    pub fn xcb_present_notify_msc_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        serial: u32,
+        target_msc: u64,
+        divisor: u64,
+        remainder: u64,
+    ) -> XCBVoidCookie;
+    pub fn xcb_present_notify_msc(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        serial: u32,
+        target_msc: u64,
+        divisor: u64,
+        remainder: u64,
+    ) -> XCBVoidCookie;
+    pub fn xcb_present_event_next(i: *mut XCBPresentEventIterator);
+    pub fn xcb_present_event_end(i: XCBPresentEventIterator) -> XCBGenericIterator;
+    pub fn xcb_present_select_input_checked(
+        connection: *mut XCBConnection,
+        eid: XCBPresentEvent,
+        window: XCBWindow,
+        event_mask: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_present_select_input(
+        connection: *mut XCBConnection,
+        eid: XCBPresentEvent,
+        window: XCBWindow,
+        event_mask: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_present_query_capabilities_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBPresentQueryCapabilitiesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBPresentQueryCapabilitiesReply;
+    pub fn xcb_present_query_capabilities(
+        connection: *mut XCBConnection,
+        target: u32,
+    ) -> XCBPresentQueryCapabilitiesCookie;
+    pub fn xcb_present_query_capabilities_unchecked(
+        connection: *mut XCBConnection,
+        target: u32,
+    ) -> XCBPresentQueryCapabilitiesCookie;
+    pub fn xcb_present_redirect_notify_notifies(
+        R: *const XCBPresentRedirectNotifyEvent,
+    ) -> *mut XCBPresentNotify;
+    pub fn xcb_present_redirect_notify_notifies_length(
+        R: *const XCBPresentRedirectNotifyEvent,
+    ) -> c_int;
+    pub fn xcb_present_redirect_notify_notifies_iterator(
+        R: *const XCBPresentRedirectNotifyEvent,
+    ) -> XCBPresentNotifyIterator;
+}
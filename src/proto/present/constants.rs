@@ -0,0 +1,38 @@
+// Generated by xcbgen from xml/present.xml, do not edit.
+
+pub const XCB_PRESENT_MAJOR_VERSION: u32 = 1;
+pub const XCB_PRESENT_MINOR_VERSION: u32 = 2;
+pub const XCB_PRESENT_EVENT_CONFIGURE_NOTIFY: u32 = 0;
+pub const XCB_PRESENT_EVENT_COMPLETE_NOTIFY: u32 = 1;
+pub const XCB_PRESENT_EVENT_IDLE_NOTIFY: u32 = 2;
+pub const XCB_PRESENT_EVENT_REDIRECT_NOTIFY: u32 = 3;
+pub const XCB_PRESENT_EVENT_MASK_NO_EVENT: u32 = 0;
+pub const XCB_PRESENT_EVENT_MASK_CONFIGURE_NOTIFY: u32 = 1;
+pub const XCB_PRESENT_EVENT_MASK_COMPLETE_NOTIFY: u32 = 2;
+pub const XCB_PRESENT_EVENT_MASK_IDLE_NOTIFY: u32 = 4;
+pub const XCB_PRESENT_EVENT_MASK_REDIRECT_NOTIFY: u32 = 8;
+pub const XCB_PRESENT_OPTION_NONE: u32 = 0;
+pub const XCB_PRESENT_OPTION_ASYNC: u32 = 1;
+pub const XCB_PRESENT_OPTION_COPY: u32 = 2;
+pub const XCB_PRESENT_OPTION_UST: u32 = 4;
+pub const XCB_PRESENT_OPTION_SUBOPTIMAL: u32 = 8;
+pub const XCB_PRESENT_CAPABILITY_NONE: u32 = 0;
+pub const XCB_PRESENT_CAPABILITY_ASYNC: u32 = 1;
+pub const XCB_PRESENT_CAPABILITY_FENCE: u32 = 2;
+pub const XCB_PRESENT_CAPABILITY_UST: u32 = 4;
+pub const XCB_PRESENT_COMPLETE_KIND_PIXMAP: u8 = 0;
+pub const XCB_PRESENT_COMPLETE_KIND_NOTIFY_MSC: u8 = 1;
+pub const XCB_PRESENT_COMPLETE_MODE_COPY: u8 = 0;
+pub const XCB_PRESENT_COMPLETE_MODE_FLIP: u8 = 1;
+pub const XCB_PRESENT_COMPLETE_MODE_SKIP: u8 = 2;
+pub const XCB_PRESENT_COMPLETE_MODE_SUBOPTIMAL_COPY: u8 = 3;
+pub const XCB_PRESENT_QUERY_VERSION: u8 = 0;
+pub const XCB_PRESENT_PIXMAP: u8 = 1;
+pub const XCB_PRESENT_NOTIFY_MSC: u8 = 2;
+pub const XCB_PRESENT_SELECT_INPUT: u8 = 3;
+pub const XCB_PRESENT_QUERY_CAPABILITIES: u8 = 4;
+pub const XCB_PRESENT_GENERIC: u8 = 0;
+pub const XCB_PRESENT_CONFIGURE_NOTIFY: u16 = 0;
+pub const XCB_PRESENT_COMPLETE_NOTIFY: u16 = 1;
+pub const XCB_PRESENT_IDLE_NOTIFY: u16 = 2;
+pub const XCB_PRESENT_REDIRECT_NOTIFY: u16 = 3;
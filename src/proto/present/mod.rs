@@ -0,0 +1,222 @@
+// Generated by xcbgen from xml/present.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use proto::randr::XCBRandrCrtc;
+use proto::sync::XCBSyncFence;
+use proto::xfixes::XCBXfixesRegion;
+use proto::xproto::{XCBPixmap, XCBWindow};
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBPresentNotify = cdef::XCBPresentNotify;
+pub type XCBPresentNotifyIterator = cdef::XCBPresentNotifyIterator;
+pub type XCBPresentQueryVersionRequest = cdef::XCBPresentQueryVersionRequest;
+pub type XCBPresentQueryVersionCookie = cdef::XCBPresentQueryVersionCookie;
+pub type XCBPresentQueryVersionReply = cdef::XCBPresentQueryVersionReply;
+pub type XCBPresentPixmapRequest = cdef::XCBPresentPixmapRequest;
+pub type XCBPresentNotifyMscRequest = cdef::XCBPresentNotifyMscRequest;
+pub type XCBPresentEvent = cdef::XCBPresentEvent;
+pub type XCBPresentEventIterator = cdef::XCBPresentEventIterator;
+pub type XCBPresentSelectInputRequest = cdef::XCBPresentSelectInputRequest;
+pub type XCBPresentQueryCapabilitiesRequest = cdef::XCBPresentQueryCapabilitiesRequest;
+pub type XCBPresentQueryCapabilitiesCookie = cdef::XCBPresentQueryCapabilitiesCookie;
+pub type XCBPresentQueryCapabilitiesReply = cdef::XCBPresentQueryCapabilitiesReply;
+pub type XCBPresentGenericEvent = cdef::XCBPresentGenericEvent;
+pub type XCBPresentConfigureNotifyEvent = cdef::XCBPresentConfigureNotifyEvent;
+pub type XCBPresentCompleteNotifyEvent = cdef::XCBPresentCompleteNotifyEvent;
+pub type XCBPresentIdleNotifyEvent = cdef::XCBPresentIdleNotifyEvent;
+pub type XCBPresentRedirectNotifyEvent = cdef::XCBPresentRedirectNotifyEvent;
+
+/// The Present extension, known to the server as `Present`.
+pub struct Present;
+
+impl Extension for Present {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_present_id)
+    }
+}
+
+pub fn xcb_present_notify_next(iter: &mut XCBPresentNotifyIterator) {
+    unsafe { cdef::xcb_present_notify_next(iter) }
+}
+
+impl Reply for XCBPresentQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_present_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_present_query_version(
+    connection: &XCBConnection,
+    major_version: u32,
+    minor_version: u32,
+) -> XCBPresentQueryVersionCookie {
+    unsafe { cdef::xcb_present_query_version(connection.raw, major_version, minor_version) }
+}
+
+pub fn xcb_present_pixmap(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    pixmap: XCBPixmap,
+    serial: u32,
+    valid: XCBXfixesRegion,
+    update: XCBXfixesRegion,
+    x_off: i16,
+    y_off: i16,
+    target_crtc: XCBRandrCrtc,
+    wait_fence: XCBSyncFence,
+    idle_fence: XCBSyncFence,
+    options: u32,
+    target_msc: u64,
+    divisor: u64,
+    remainder: u64,
+    notifies: &[XCBPresentNotify],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_present_pixmap(
+            connection.raw,
+            window,
+            pixmap,
+            serial,
+            valid,
+            update,
+            x_off,
+            y_off,
+            target_crtc,
+            wait_fence,
+            idle_fence,
+            options,
+            target_msc,
+            divisor,
+            remainder,
+            notifies.len() as u32,
+            notifies.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_present_notify_msc(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    serial: u32,
+    target_msc: u64,
+    divisor: u64,
+    remainder: u64,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_present_notify_msc(
+            connection.raw,
+            window,
+            serial,
+            target_msc,
+            divisor,
+            remainder,
+        )
+    }
+}
+
+pub fn xcb_present_event_next(iter: &mut XCBPresentEventIterator) {
+    unsafe { cdef::xcb_present_event_next(iter) }
+}
+
+pub fn xcb_present_select_input(
+    connection: &XCBConnection,
+    eid: XCBPresentEvent,
+    window: XCBWindow,
+    event_mask: u32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_present_select_input(connection.raw, eid, window, event_mask) }
+}
+
+impl Reply for XCBPresentQueryCapabilitiesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_present_query_capabilities_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_present_query_capabilities(
+    connection: &XCBConnection,
+    target: u32,
+) -> XCBPresentQueryCapabilitiesCookie {
+    unsafe { cdef::xcb_present_query_capabilities(connection.raw, target) }
+}
+
+pub fn xcb_present_redirect_notify_notifies(
+    event: &XCBPresentRedirectNotifyEvent,
+) -> &[XCBPresentNotify] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_present_redirect_notify_notifies(event),
+            cdef::xcb_present_redirect_notify_notifies_length(event) as usize,
+        )
+    }
+}
+
+/// An event of the Present extension.
+pub enum Event {
+    Generic(Box<XCBPresentGenericEvent>),
+    ConfigureNotify(Box<XCBPresentConfigureNotifyEvent>),
+    CompleteNotify(Box<XCBPresentCompleteNotifyEvent>),
+    IdleNotify(Box<XCBPresentIdleNotifyEvent>),
+    RedirectNotify(Box<XCBPresentRedirectNotifyEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::Generic(Box::from_raw(
+                    raw as *mut XCBPresentGenericEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+    /// Decodes a generic event whose type is `event_type`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode_generic(
+        event_type: u16,
+        event: Box<XCBGenericEvent>,
+    ) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match event_type {
+                0 => Ok(Event::ConfigureNotify(Box::from_raw(
+                    raw as *mut XCBPresentConfigureNotifyEvent,
+                ))),
+                1 => Ok(Event::CompleteNotify(Box::from_raw(
+                    raw as *mut XCBPresentCompleteNotifyEvent,
+                ))),
+                2 => Ok(Event::IdleNotify(Box::from_raw(
+                    raw as *mut XCBPresentIdleNotifyEvent,
+                ))),
+                3 => Ok(Event::RedirectNotify(Box::from_raw(
+                    raw as *mut XCBPresentRedirectNotifyEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
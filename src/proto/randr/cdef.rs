@@ -0,0 +1,2242 @@
+// Generated by xcbgen from xml/randr.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int, c_void};
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::render::cdef::{XCBRenderFixed, XCBRenderTransform};
+use proto::xproto::cdef::{XCBAtom, XCBTimestamp, XCBWindow};
+
+pub type XCBRandrMode = u32;
+
+#[repr(C)]
+pub struct XCBRandrModeIterator {
+    pub data: *mut XCBRandrMode,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBRandrCrtc = u32;
+
+#[repr(C)]
+pub struct XCBRandrCrtcIterator {
+    pub data: *mut XCBRandrCrtc,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBRandrOutput = u32;
+
+#[repr(C)]
+pub struct XCBRandrOutputIterator {
+    pub data: *mut XCBRandrOutput,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBRandrProvider = u32;
+
+#[repr(C)]
+pub struct XCBRandrProviderIterator {
+    pub data: *mut XCBRandrProvider,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBRandrLease = u32;
+
+#[repr(C)]
+pub struct XCBRandrLeaseIterator {
+    pub data: *mut XCBRandrLease,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrBadOutputError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrBadCrtcError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrBadModeError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrBadProviderError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrScreenSize {
+    pub width: u16,
+    pub height: u16,
+    pub mwidth: u16,
+    pub mheight: u16,
+}
+
+#[repr(C)]
+pub struct XCBRandrScreenSizeIterator {
+    pub data: *mut XCBRandrScreenSize,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBRandrRefreshRates {
+    pub nRates: u16,
+}
+
+#[repr(C)]
+pub struct XCBRandrRefreshRatesIterator {
+    pub data: *mut XCBRandrRefreshRates,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+pub type XCBRandrQueryVersionCookie = XCBCookie<XCBRandrQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrSetScreenConfigRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub timestamp: XCBTimestamp,
+    pub config_timestamp: XCBTimestamp,
+    pub sizeID: u16,
+    pub rotation: u16,
+    pub rate: u16,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBRandrSetScreenConfigCookie = XCBCookie<XCBRandrSetScreenConfigReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrSetScreenConfigReply {
+    pub response_type: u8,
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub new_timestamp: XCBTimestamp,
+    pub config_timestamp: XCBTimestamp,
+    pub root: XCBWindow,
+    pub subpixel_order: u16,
+    pub pad0: [u8; 10],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrSelectInputRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub enable: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetScreenInfoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBRandrGetScreenInfoCookie = XCBCookie<XCBRandrGetScreenInfoReply>;
+
+#[repr(C)]
+pub struct XCBRandrGetScreenInfoReply {
+    pub response_type: u8,
+    pub rotations: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub root: XCBWindow,
+    pub timestamp: XCBTimestamp,
+    pub config_timestamp: XCBTimestamp,
+    pub nSizes: u16,
+    pub sizeID: u16,
+    pub rotation: u16,
+    pub rate: u16,
+    pub nInfo: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetScreenSizeRangeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBRandrGetScreenSizeRangeCookie = XCBCookie<XCBRandrGetScreenSizeRangeReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetScreenSizeRangeReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub min_width: u16,
+    pub min_height: u16,
+    pub max_width: u16,
+    pub max_height: u16,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrSetScreenSizeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub width: u16,
+    pub height: u16,
+    pub mm_width: u32,
+    pub mm_height: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrModeInfo {
+    pub id: u32,
+    pub width: u16,
+    pub height: u16,
+    pub dot_clock: u32,
+    pub hsync_start: u16,
+    pub hsync_end: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vsync_start: u16,
+    pub vsync_end: u16,
+    pub vtotal: u16,
+    pub name_len: u16,
+    pub mode_flags: u32,
+}
+
+#[repr(C)]
+pub struct XCBRandrModeInfoIterator {
+    pub data: *mut XCBRandrModeInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetScreenResourcesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBRandrGetScreenResourcesCookie = XCBCookie<XCBRandrGetScreenResourcesReply>;
+
+#[repr(C)]
+pub struct XCBRandrGetScreenResourcesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: XCBTimestamp,
+    pub config_timestamp: XCBTimestamp,
+    pub num_crtcs: u16,
+    pub num_outputs: u16,
+    pub num_modes: u16,
+    pub names_len: u16,
+    pub pad1: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetOutputInfoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub output: XCBRandrOutput,
+    pub config_timestamp: XCBTimestamp,
+}
+
+pub type XCBRandrGetOutputInfoCookie = XCBCookie<XCBRandrGetOutputInfoReply>;
+
+#[repr(C)]
+pub struct XCBRandrGetOutputInfoReply {
+    pub response_type: u8,
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: XCBTimestamp,
+    pub crtc: XCBRandrCrtc,
+    pub mm_width: u32,
+    pub mm_height: u32,
+    pub connection: u8,
+    pub subpixel_order: u8,
+    pub num_crtcs: u16,
+    pub num_modes: u16,
+    pub num_preferred: u16,
+    pub num_clones: u16,
+    pub name_len: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrListOutputPropertiesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub output: XCBRandrOutput,
+}
+
+pub type XCBRandrListOutputPropertiesCookie = XCBCookie<XCBRandrListOutputPropertiesReply>;
+
+#[repr(C)]
+pub struct XCBRandrListOutputPropertiesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_atoms: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrQueryOutputPropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub output: XCBRandrOutput,
+    pub property: XCBAtom,
+}
+
+pub type XCBRandrQueryOutputPropertyCookie = XCBCookie<XCBRandrQueryOutputPropertyReply>;
+
+#[repr(C)]
+pub struct XCBRandrQueryOutputPropertyReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pending: u8,
+    pub range: u8,
+    pub immutable: u8,
+    pub pad1: [u8; 21],
+}
+
+#[repr(C)]
+pub struct XCBRandrConfigureOutputPropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub output: XCBRandrOutput,
+    pub property: XCBAtom,
+    pub pending: u8,
+    pub range: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBRandrChangeOutputPropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub output: XCBRandrOutput,
+    pub property: XCBAtom,
+    pub type_: XCBAtom,
+    pub format: u8,
+    pub mode: u8,
+    pub pad0: [u8; 2],
+    pub num_units: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrDeleteOutputPropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub output: XCBRandrOutput,
+    pub property: XCBAtom,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetOutputPropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub output: XCBRandrOutput,
+    pub property: XCBAtom,
+    pub type_: XCBAtom,
+    pub long_offset: u32,
+    pub long_length: u32,
+    pub delete: u8,
+    pub pending: u8,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBRandrGetOutputPropertyCookie = XCBCookie<XCBRandrGetOutputPropertyReply>;
+
+#[repr(C)]
+pub struct XCBRandrGetOutputPropertyReply {
+    pub response_type: u8,
+    pub format: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub type_: XCBAtom,
+    pub bytes_after: u32,
+    pub num_items: u32,
+    pub pad0: [u8; 12],
+}
+
+#[repr(C)]
+pub struct XCBRandrCreateModeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub mode_info: XCBRandrModeInfo,
+}
+
+pub type XCBRandrCreateModeCookie = XCBCookie<XCBRandrCreateModeReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrCreateModeReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub mode: XCBRandrMode,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrDestroyModeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub mode: XCBRandrMode,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrAddOutputModeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub output: XCBRandrOutput,
+    pub mode: XCBRandrMode,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrDeleteOutputModeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub output: XCBRandrOutput,
+    pub mode: XCBRandrMode,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetCrtcInfoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub crtc: XCBRandrCrtc,
+    pub config_timestamp: XCBTimestamp,
+}
+
+pub type XCBRandrGetCrtcInfoCookie = XCBCookie<XCBRandrGetCrtcInfoReply>;
+
+#[repr(C)]
+pub struct XCBRandrGetCrtcInfoReply {
+    pub response_type: u8,
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: XCBTimestamp,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub mode: XCBRandrMode,
+    pub rotation: u16,
+    pub rotations: u16,
+    pub num_outputs: u16,
+    pub num_possible_outputs: u16,
+}
+
+#[repr(C)]
+pub struct XCBRandrSetCrtcConfigRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub crtc: XCBRandrCrtc,
+    pub timestamp: XCBTimestamp,
+    pub config_timestamp: XCBTimestamp,
+    pub x: i16,
+    pub y: i16,
+    pub mode: XCBRandrMode,
+    pub rotation: u16,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBRandrSetCrtcConfigCookie = XCBCookie<XCBRandrSetCrtcConfigReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrSetCrtcConfigReply {
+    pub response_type: u8,
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: XCBTimestamp,
+    pub pad0: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetCrtcGammaSizeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub crtc: XCBRandrCrtc,
+}
+
+pub type XCBRandrGetCrtcGammaSizeCookie = XCBCookie<XCBRandrGetCrtcGammaSizeReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetCrtcGammaSizeReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub size: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetCrtcGammaRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub crtc: XCBRandrCrtc,
+}
+
+pub type XCBRandrGetCrtcGammaCookie = XCBCookie<XCBRandrGetCrtcGammaReply>;
+
+#[repr(C)]
+pub struct XCBRandrGetCrtcGammaReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub size: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+pub struct XCBRandrSetCrtcGammaRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub crtc: XCBRandrCrtc,
+    pub size: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetScreenResourcesCurrentRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBRandrGetScreenResourcesCurrentCookie =
+    XCBCookie<XCBRandrGetScreenResourcesCurrentReply>;
+
+#[repr(C)]
+pub struct XCBRandrGetScreenResourcesCurrentReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: XCBTimestamp,
+    pub config_timestamp: XCBTimestamp,
+    pub num_crtcs: u16,
+    pub num_outputs: u16,
+    pub num_modes: u16,
+    pub names_len: u16,
+    pub pad1: [u8; 8],
+}
+
+#[repr(C)]
+pub struct XCBRandrSetCrtcTransformRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub crtc: XCBRandrCrtc,
+    pub transform: XCBRenderTransform,
+    pub filter_len: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetCrtcTransformRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub crtc: XCBRandrCrtc,
+}
+
+pub type XCBRandrGetCrtcTransformCookie = XCBCookie<XCBRandrGetCrtcTransformReply>;
+
+#[repr(C)]
+pub struct XCBRandrGetCrtcTransformReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pending_transform: XCBRenderTransform,
+    pub has_transforms: u8,
+    pub pad1: [u8; 3],
+    pub current_transform: XCBRenderTransform,
+    pub pad2: [u8; 4],
+    pub pending_len: u16,
+    pub pending_nparams: u16,
+    pub current_len: u16,
+    pub current_nparams: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetPanningRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub crtc: XCBRandrCrtc,
+}
+
+pub type XCBRandrGetPanningCookie = XCBCookie<XCBRandrGetPanningReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetPanningReply {
+    pub response_type: u8,
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: XCBTimestamp,
+    pub left: u16,
+    pub top: u16,
+    pub width: u16,
+    pub height: u16,
+    pub track_left: u16,
+    pub track_top: u16,
+    pub track_width: u16,
+    pub track_height: u16,
+    pub border_left: i16,
+    pub border_top: i16,
+    pub border_right: i16,
+    pub border_bottom: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrSetPanningRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub crtc: XCBRandrCrtc,
+    pub timestamp: XCBTimestamp,
+    pub left: u16,
+    pub top: u16,
+    pub width: u16,
+    pub height: u16,
+    pub track_left: u16,
+    pub track_top: u16,
+    pub track_width: u16,
+    pub track_height: u16,
+    pub border_left: i16,
+    pub border_top: i16,
+    pub border_right: i16,
+    pub border_bottom: i16,
+}
+
+pub type XCBRandrSetPanningCookie = XCBCookie<XCBRandrSetPanningReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrSetPanningReply {
+    pub response_type: u8,
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: XCBTimestamp,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrSetOutputPrimaryRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub output: XCBRandrOutput,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetOutputPrimaryRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBRandrGetOutputPrimaryCookie = XCBCookie<XCBRandrGetOutputPrimaryReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetOutputPrimaryReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub output: XCBRandrOutput,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetProvidersRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBRandrGetProvidersCookie = XCBCookie<XCBRandrGetProvidersReply>;
+
+#[repr(C)]
+pub struct XCBRandrGetProvidersReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: XCBTimestamp,
+    pub num_providers: u16,
+    pub pad1: [u8; 18],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetProviderInfoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub provider: XCBRandrProvider,
+    pub config_timestamp: XCBTimestamp,
+}
+
+pub type XCBRandrGetProviderInfoCookie = XCBCookie<XCBRandrGetProviderInfoReply>;
+
+#[repr(C)]
+pub struct XCBRandrGetProviderInfoReply {
+    pub response_type: u8,
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: XCBTimestamp,
+    pub capabilities: u32,
+    pub num_crtcs: u16,
+    pub num_outputs: u16,
+    pub num_associated_providers: u16,
+    pub name_len: u16,
+    pub pad0: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrSetProviderOffloadSinkRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub provider: XCBRandrProvider,
+    pub sink_provider: XCBRandrProvider,
+    pub config_timestamp: XCBTimestamp,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrSetProviderOutputSourceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub provider: XCBRandrProvider,
+    pub source_provider: XCBRandrProvider,
+    pub config_timestamp: XCBTimestamp,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrListProviderPropertiesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub provider: XCBRandrProvider,
+}
+
+pub type XCBRandrListProviderPropertiesCookie = XCBCookie<XCBRandrListProviderPropertiesReply>;
+
+#[repr(C)]
+pub struct XCBRandrListProviderPropertiesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_atoms: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrQueryProviderPropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub provider: XCBRandrProvider,
+    pub property: XCBAtom,
+}
+
+pub type XCBRandrQueryProviderPropertyCookie = XCBCookie<XCBRandrQueryProviderPropertyReply>;
+
+#[repr(C)]
+pub struct XCBRandrQueryProviderPropertyReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pending: u8,
+    pub range: u8,
+    pub immutable: u8,
+    pub pad1: [u8; 21],
+}
+
+#[repr(C)]
+pub struct XCBRandrConfigureProviderPropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub provider: XCBRandrProvider,
+    pub property: XCBAtom,
+    pub pending: u8,
+    pub range: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBRandrChangeProviderPropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub provider: XCBRandrProvider,
+    pub property: XCBAtom,
+    pub type_: XCBAtom,
+    pub format: u8,
+    pub mode: u8,
+    pub pad0: [u8; 2],
+    pub num_items: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrDeleteProviderPropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub provider: XCBRandrProvider,
+    pub property: XCBAtom,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetProviderPropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub provider: XCBRandrProvider,
+    pub property: XCBAtom,
+    pub type_: XCBAtom,
+    pub long_offset: u32,
+    pub long_length: u32,
+    pub delete: u8,
+    pub pending: u8,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBRandrGetProviderPropertyCookie = XCBCookie<XCBRandrGetProviderPropertyReply>;
+
+#[repr(C)]
+pub struct XCBRandrGetProviderPropertyReply {
+    pub response_type: u8,
+    pub format: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub type_: XCBAtom,
+    pub bytes_after: u32,
+    pub num_items: u32,
+    pub pad0: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrScreenChangeNotifyEvent {
+    pub response_type: u8,
+    pub rotation: u8,
+    pub sequence: u16,
+    pub timestamp: XCBTimestamp,
+    pub config_timestamp: XCBTimestamp,
+    pub root: XCBWindow,
+    pub request_window: XCBWindow,
+    pub sizeID: u16,
+    pub subpixel_order: u16,
+    pub width: u16,
+    pub height: u16,
+    pub mwidth: u16,
+    pub mheight: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrCrtcChange {
+    pub timestamp: XCBTimestamp,
+    pub window: XCBWindow,
+    pub crtc: XCBRandrCrtc,
+    pub mode: XCBRandrMode,
+    pub rotation: u16,
+    pub pad0: [u8; 2],
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+}
+
+#[repr(C)]
+pub struct XCBRandrCrtcChangeIterator {
+    pub data: *mut XCBRandrCrtcChange,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrOutputChange {
+    pub timestamp: XCBTimestamp,
+    pub config_timestamp: XCBTimestamp,
+    pub window: XCBWindow,
+    pub output: XCBRandrOutput,
+    pub crtc: XCBRandrCrtc,
+    pub mode: XCBRandrMode,
+    pub rotation: u16,
+    pub connection: u8,
+    pub subpixel_order: u8,
+}
+
+#[repr(C)]
+pub struct XCBRandrOutputChangeIterator {
+    pub data: *mut XCBRandrOutputChange,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrOutputProperty {
+    pub window: XCBWindow,
+    pub output: XCBRandrOutput,
+    pub atom: XCBAtom,
+    pub timestamp: XCBTimestamp,
+    pub status: u8,
+    pub pad0: [u8; 11],
+}
+
+#[repr(C)]
+pub struct XCBRandrOutputPropertyIterator {
+    pub data: *mut XCBRandrOutputProperty,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrProviderChange {
+    pub timestamp: XCBTimestamp,
+    pub window: XCBWindow,
+    pub provider: XCBRandrProvider,
+    pub pad0: [u8; 16],
+}
+
+#[repr(C)]
+pub struct XCBRandrProviderChangeIterator {
+    pub data: *mut XCBRandrProviderChange,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrProviderProperty {
+    pub window: XCBWindow,
+    pub provider: XCBRandrProvider,
+    pub atom: XCBAtom,
+    pub timestamp: XCBTimestamp,
+    pub state: u8,
+    pub pad0: [u8; 11],
+}
+
+#[repr(C)]
+pub struct XCBRandrProviderPropertyIterator {
+    pub data: *mut XCBRandrProviderProperty,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrResourceChange {
+    pub timestamp: XCBTimestamp,
+    pub window: XCBWindow,
+    pub pad0: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBRandrResourceChangeIterator {
+    pub data: *mut XCBRandrResourceChange,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBRandrMonitorInfo {
+    pub name: XCBAtom,
+    pub primary: u8,
+    pub automatic: u8,
+    pub nOutput: u16,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub width_in_millimeters: u32,
+    pub height_in_millimeters: u32,
+}
+
+#[repr(C)]
+pub struct XCBRandrMonitorInfoIterator {
+    pub data: *mut XCBRandrMonitorInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrGetMonitorsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub get_active: u8,
+}
+
+pub type XCBRandrGetMonitorsCookie = XCBCookie<XCBRandrGetMonitorsReply>;
+
+#[repr(C)]
+pub struct XCBRandrGetMonitorsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: XCBTimestamp,
+    pub nMonitors: u32,
+    pub nOutputs: u32,
+    pub pad1: [u8; 12],
+}
+
+#[repr(C)]
+pub struct XCBRandrSetMonitorRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrDeleteMonitorRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub name: XCBAtom,
+}
+
+#[repr(C)]
+pub struct XCBRandrCreateLeaseRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub lid: XCBRandrLease,
+    pub num_crtcs: u16,
+    pub num_outputs: u16,
+}
+
+pub type XCBRandrCreateLeaseCookie = XCBCookie<XCBRandrCreateLeaseReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrCreateLeaseReply {
+    pub response_type: u8,
+    pub nfd: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad0: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrFreeLeaseRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub lid: XCBRandrLease,
+    pub terminate: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrLeaseNotify {
+    pub timestamp: XCBTimestamp,
+    pub window: XCBWindow,
+    pub lease: XCBRandrLease,
+    pub created: u8,
+    pub pad0: [u8; 15],
+}
+
+#[repr(C)]
+pub struct XCBRandrLeaseNotifyIterator {
+    pub data: *mut XCBRandrLeaseNotify,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union XCBRandrNotifyData {
+    pub cc: XCBRandrCrtcChange,
+    pub oc: XCBRandrOutputChange,
+    pub op: XCBRandrOutputProperty,
+    pub pc: XCBRandrProviderChange,
+    pub pp: XCBRandrProviderProperty,
+    pub rc: XCBRandrResourceChange,
+    pub lc: XCBRandrLeaseNotify,
+}
+
+#[repr(C)]
+pub struct XCBRandrNotifyDataIterator {
+    pub data: *mut XCBRandrNotifyData,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRandrNotifyEvent {
+    pub response_type: u8,
+    pub subCode: u8,
+    pub sequence: u16,
+    pub u: XCBRandrNotifyData,
+}
+
+#[link(name = "xcb-randr")]
+extern "system" {
+    pub static mut xcb_randr_id: XCBExtension;
+    pub fn xcb_randr_mode_next(i: *mut XCBRandrModeIterator);
+    pub fn xcb_randr_mode_end(i: XCBRandrModeIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_crtc_next(i: *mut XCBRandrCrtcIterator);
+    pub fn xcb_randr_crtc_end(i: XCBRandrCrtcIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_output_next(i: *mut XCBRandrOutputIterator);
+    pub fn xcb_randr_output_end(i: XCBRandrOutputIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_provider_next(i: *mut XCBRandrProviderIterator);
+    pub fn xcb_randr_provider_end(i: XCBRandrProviderIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_lease_next(i: *mut XCBRandrLeaseIterator);
+    pub fn xcb_randr_lease_end(i: XCBRandrLeaseIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_screen_size_next(i: *mut XCBRandrScreenSizeIterator);
+    pub fn xcb_randr_screen_size_end(i: XCBRandrScreenSizeIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_refresh_rates_next(i: *mut XCBRandrRefreshRatesIterator);
+    pub fn xcb_randr_refresh_rates_end(i: XCBRandrRefreshRatesIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_refresh_rates_rates(R: *const XCBRandrRefreshRates) -> *mut u16;
+    pub fn xcb_randr_refresh_rates_rates_length(R: *const XCBRandrRefreshRates) -> c_int;
+    pub fn xcb_randr_refresh_rates_rates_end(R: *const XCBRandrRefreshRates) -> XCBGenericIterator;
+    pub fn xcb_randr_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrQueryVersionReply;
+    pub fn xcb_randr_query_version(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+    ) -> XCBRandrQueryVersionCookie;
+    pub fn xcb_randr_query_version_unchecked(
+        connection: *mut XCBConnection,
+        major_version: u32,
+        minor_version: u32,
+    ) -> XCBRandrQueryVersionCookie;
+    pub fn xcb_randr_set_screen_config_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrSetScreenConfigCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrSetScreenConfigReply;
+    pub fn xcb_randr_set_screen_config(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        timestamp: XCBTimestamp,
+        config_timestamp: XCBTimestamp,
+        sizeID: u16,
+        rotation: u16,
+        rate: u16,
+    ) -> XCBRandrSetScreenConfigCookie;
+    pub fn xcb_randr_set_screen_config_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        timestamp: XCBTimestamp,
+        config_timestamp: XCBTimestamp,
+        sizeID: u16,
+        rotation: u16,
+        rate: u16,
+    ) -> XCBRandrSetScreenConfigCookie;
+    pub fn xcb_randr_select_input_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        enable: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_select_input(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        enable: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_get_screen_info_sizes(
+        R: *const XCBRandrGetScreenInfoReply,
+    ) -> *mut XCBRandrScreenSize;
+    pub fn xcb_randr_get_screen_info_sizes_length(R: *const XCBRandrGetScreenInfoReply) -> c_int;
+    pub fn xcb_randr_get_screen_info_sizes_iterator(
+        R: *const XCBRandrGetScreenInfoReply,
+    ) -> XCBRandrScreenSizeIterator;
+    pub fn xcb_randr_get_screen_info_rates_length(R: *const XCBRandrGetScreenInfoReply) -> c_int;
+    pub fn xcb_randr_get_screen_info_rates_iterator(
+        R: *const XCBRandrGetScreenInfoReply,
+    ) -> XCBRandrRefreshRatesIterator;
+    pub fn xcb_randr_get_screen_info_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetScreenInfoCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetScreenInfoReply;
+    pub fn xcb_randr_get_screen_info(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBRandrGetScreenInfoCookie;
+    pub fn xcb_randr_get_screen_info_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBRandrGetScreenInfoCookie;
+    pub fn xcb_randr_get_screen_size_range_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetScreenSizeRangeCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetScreenSizeRangeReply;
+    pub fn xcb_randr_get_screen_size_range(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBRandrGetScreenSizeRangeCookie;
+    pub fn xcb_randr_get_screen_size_range_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBRandrGetScreenSizeRangeCookie;
+    pub fn xcb_randr_set_screen_size_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        width: u16,
+        height: u16,
+        mm_width: u32,
+        mm_height: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_set_screen_size(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        width: u16,
+        height: u16,
+        mm_width: u32,
+        mm_height: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_mode_info_next(i: *mut XCBRandrModeInfoIterator);
+    pub fn xcb_randr_mode_info_end(i: XCBRandrModeInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_get_screen_resources_crtcs(
+        R: *const XCBRandrGetScreenResourcesReply,
+    ) -> *mut XCBRandrCrtc;
+    pub fn xcb_randr_get_screen_resources_crtcs_length(
+        R: *const XCBRandrGetScreenResourcesReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_screen_resources_crtcs_end(
+        R: *const XCBRandrGetScreenResourcesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_screen_resources_outputs(
+        R: *const XCBRandrGetScreenResourcesReply,
+    ) -> *mut XCBRandrOutput;
+    pub fn xcb_randr_get_screen_resources_outputs_length(
+        R: *const XCBRandrGetScreenResourcesReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_screen_resources_outputs_end(
+        R: *const XCBRandrGetScreenResourcesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_screen_resources_modes(
+        R: *const XCBRandrGetScreenResourcesReply,
+    ) -> *mut XCBRandrModeInfo;
+    pub fn xcb_randr_get_screen_resources_modes_length(
+        R: *const XCBRandrGetScreenResourcesReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_screen_resources_modes_iterator(
+        R: *const XCBRandrGetScreenResourcesReply,
+    ) -> XCBRandrModeInfoIterator;
+    pub fn xcb_randr_get_screen_resources_names(
+        R: *const XCBRandrGetScreenResourcesReply,
+    ) -> *mut u8;
+    pub fn xcb_randr_get_screen_resources_names_length(
+        R: *const XCBRandrGetScreenResourcesReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_screen_resources_names_end(
+        R: *const XCBRandrGetScreenResourcesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_screen_resources_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetScreenResourcesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetScreenResourcesReply;
+    pub fn xcb_randr_get_screen_resources(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBRandrGetScreenResourcesCookie;
+    pub fn xcb_randr_get_screen_resources_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBRandrGetScreenResourcesCookie;
+    pub fn xcb_randr_get_output_info_crtcs(
+        R: *const XCBRandrGetOutputInfoReply,
+    ) -> *mut XCBRandrCrtc;
+    pub fn xcb_randr_get_output_info_crtcs_length(R: *const XCBRandrGetOutputInfoReply) -> c_int;
+    pub fn xcb_randr_get_output_info_crtcs_end(
+        R: *const XCBRandrGetOutputInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_output_info_modes(
+        R: *const XCBRandrGetOutputInfoReply,
+    ) -> *mut XCBRandrMode;
+    pub fn xcb_randr_get_output_info_modes_length(R: *const XCBRandrGetOutputInfoReply) -> c_int;
+    pub fn xcb_randr_get_output_info_modes_end(
+        R: *const XCBRandrGetOutputInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_output_info_clones(
+        R: *const XCBRandrGetOutputInfoReply,
+    ) -> *mut XCBRandrOutput;
+    pub fn xcb_randr_get_output_info_clones_length(R: *const XCBRandrGetOutputInfoReply) -> c_int;
+    pub fn xcb_randr_get_output_info_clones_end(
+        R: *const XCBRandrGetOutputInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_output_info_name(R: *const XCBRandrGetOutputInfoReply) -> *mut u8;
+    pub fn xcb_randr_get_output_info_name_length(R: *const XCBRandrGetOutputInfoReply) -> c_int;
+    pub fn xcb_randr_get_output_info_name_end(
+        R: *const XCBRandrGetOutputInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_output_info_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetOutputInfoCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetOutputInfoReply;
+    pub fn xcb_randr_get_output_info(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        config_timestamp: XCBTimestamp,
+    ) -> XCBRandrGetOutputInfoCookie;
+    pub fn xcb_randr_get_output_info_unchecked(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        config_timestamp: XCBTimestamp,
+    ) -> XCBRandrGetOutputInfoCookie;
+    pub fn xcb_randr_list_output_properties_atoms(
+        R: *const XCBRandrListOutputPropertiesReply,
+    ) -> *mut XCBAtom;
+    pub fn xcb_randr_list_output_properties_atoms_length(
+        R: *const XCBRandrListOutputPropertiesReply,
+    ) -> c_int;
+    pub fn xcb_randr_list_output_properties_atoms_end(
+        R: *const XCBRandrListOutputPropertiesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_list_output_properties_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrListOutputPropertiesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrListOutputPropertiesReply;
+    pub fn xcb_randr_list_output_properties(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+    ) -> XCBRandrListOutputPropertiesCookie;
+    pub fn xcb_randr_list_output_properties_unchecked(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+    ) -> XCBRandrListOutputPropertiesCookie;
+    pub fn xcb_randr_query_output_property_valid_values(
+        R: *const XCBRandrQueryOutputPropertyReply,
+    ) -> *mut i32;
+    pub fn xcb_randr_query_output_property_valid_values_length(
+        R: *const XCBRandrQueryOutputPropertyReply,
+    ) -> c_int;
+    pub fn xcb_randr_query_output_property_valid_values_end(
+        R: *const XCBRandrQueryOutputPropertyReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_query_output_property_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrQueryOutputPropertyCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrQueryOutputPropertyReply;
+    pub fn xcb_randr_query_output_property(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        property: XCBAtom,
+    ) -> XCBRandrQueryOutputPropertyCookie;
+    pub fn xcb_randr_query_output_property_unchecked(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        property: XCBAtom,
+    ) -> XCBRandrQueryOutputPropertyCookie;
+    pub fn xcb_randr_configure_output_property_checked(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        property: XCBAtom,
+        pending: u8,
+        range: u8,
+        values_len: u32,
+        values: *const i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_configure_output_property(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        property: XCBAtom,
+        pending: u8,
+        range: u8,
+        values_len: u32,
+        values: *const i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_change_output_property_checked(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        property: XCBAtom,
+        type_: XCBAtom,
+        format: u8,
+        mode: u8,
+        num_units: u32,
+        data: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_change_output_property(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        property: XCBAtom,
+        type_: XCBAtom,
+        format: u8,
+        mode: u8,
+        num_units: u32,
+        data: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_delete_output_property_checked(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        property: XCBAtom,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_delete_output_property(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        property: XCBAtom,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_get_output_property_data(
+        R: *const XCBRandrGetOutputPropertyReply,
+    ) -> *mut c_void;
+    pub fn xcb_randr_get_output_property_data_length(
+        R: *const XCBRandrGetOutputPropertyReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_output_property_data_end(
+        R: *const XCBRandrGetOutputPropertyReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_output_property_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetOutputPropertyCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetOutputPropertyReply;
+    pub fn xcb_randr_get_output_property(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        property: XCBAtom,
+        type_: XCBAtom,
+        long_offset: u32,
+        long_length: u32,
+        delete: u8,
+        pending: u8,
+    ) -> XCBRandrGetOutputPropertyCookie;
+    pub fn xcb_randr_get_output_property_unchecked(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        property: XCBAtom,
+        type_: XCBAtom,
+        long_offset: u32,
+        long_length: u32,
+        delete: u8,
+        pending: u8,
+    ) -> XCBRandrGetOutputPropertyCookie;
+    pub fn xcb_randr_create_mode_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrCreateModeCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrCreateModeReply;
+    pub fn xcb_randr_create_mode(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        mode_info: XCBRandrModeInfo,
+        name_len: u32,
+        name: *const c_char,
+    ) -> XCBRandrCreateModeCookie;
+    pub fn xcb_randr_create_mode_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        mode_info: XCBRandrModeInfo,
+        name_len: u32,
+        name: *const c_char,
+    ) -> XCBRandrCreateModeCookie;
+    pub fn xcb_randr_destroy_mode_checked(
+        connection: *mut XCBConnection,
+        mode: XCBRandrMode,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_destroy_mode(
+        connection: *mut XCBConnection,
+        mode: XCBRandrMode,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_add_output_mode_checked(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        mode: XCBRandrMode,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_add_output_mode(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        mode: XCBRandrMode,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_delete_output_mode_checked(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        mode: XCBRandrMode,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_delete_output_mode(
+        connection: *mut XCBConnection,
+        output: XCBRandrOutput,
+        mode: XCBRandrMode,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_get_crtc_info_outputs(
+        R: *const XCBRandrGetCrtcInfoReply,
+    ) -> *mut XCBRandrOutput;
+    pub fn xcb_randr_get_crtc_info_outputs_length(R: *const XCBRandrGetCrtcInfoReply) -> c_int;
+    pub fn xcb_randr_get_crtc_info_outputs_end(
+        R: *const XCBRandrGetCrtcInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_crtc_info_possible(
+        R: *const XCBRandrGetCrtcInfoReply,
+    ) -> *mut XCBRandrOutput;
+    pub fn xcb_randr_get_crtc_info_possible_length(R: *const XCBRandrGetCrtcInfoReply) -> c_int;
+    pub fn xcb_randr_get_crtc_info_possible_end(
+        R: *const XCBRandrGetCrtcInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_crtc_info_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetCrtcInfoCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetCrtcInfoReply;
+    pub fn xcb_randr_get_crtc_info(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+        config_timestamp: XCBTimestamp,
+    ) -> XCBRandrGetCrtcInfoCookie;
+    pub fn xcb_randr_get_crtc_info_unchecked(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+        config_timestamp: XCBTimestamp,
+    ) -> XCBRandrGetCrtcInfoCookie;
+    pub fn xcb_randr_set_crtc_config_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrSetCrtcConfigCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrSetCrtcConfigReply;
+    pub fn xcb_randr_set_crtc_config(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+        timestamp: XCBTimestamp,
+        config_timestamp: XCBTimestamp,
+        x: i16,
+        y: i16,
+        mode: XCBRandrMode,
+        rotation: u16,
+        outputs_len: u32,
+        outputs: *const XCBRandrOutput,
+    ) -> XCBRandrSetCrtcConfigCookie;
+    pub fn xcb_randr_set_crtc_config_unchecked(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+        timestamp: XCBTimestamp,
+        config_timestamp: XCBTimestamp,
+        x: i16,
+        y: i16,
+        mode: XCBRandrMode,
+        rotation: u16,
+        outputs_len: u32,
+        outputs: *const XCBRandrOutput,
+    ) -> XCBRandrSetCrtcConfigCookie;
+    pub fn xcb_randr_get_crtc_gamma_size_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetCrtcGammaSizeCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetCrtcGammaSizeReply;
+    pub fn xcb_randr_get_crtc_gamma_size(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+    ) -> XCBRandrGetCrtcGammaSizeCookie;
+    pub fn xcb_randr_get_crtc_gamma_size_unchecked(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+    ) -> XCBRandrGetCrtcGammaSizeCookie;
+    pub fn xcb_randr_get_crtc_gamma_red(R: *const XCBRandrGetCrtcGammaReply) -> *mut u16;
+    pub fn xcb_randr_get_crtc_gamma_red_length(R: *const XCBRandrGetCrtcGammaReply) -> c_int;
+    pub fn xcb_randr_get_crtc_gamma_red_end(
+        R: *const XCBRandrGetCrtcGammaReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_crtc_gamma_green(R: *const XCBRandrGetCrtcGammaReply) -> *mut u16;
+    pub fn xcb_randr_get_crtc_gamma_green_length(R: *const XCBRandrGetCrtcGammaReply) -> c_int;
+    pub fn xcb_randr_get_crtc_gamma_green_end(
+        R: *const XCBRandrGetCrtcGammaReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_crtc_gamma_blue(R: *const XCBRandrGetCrtcGammaReply) -> *mut u16;
+    pub fn xcb_randr_get_crtc_gamma_blue_length(R: *const XCBRandrGetCrtcGammaReply) -> c_int;
+    pub fn xcb_randr_get_crtc_gamma_blue_end(
+        R: *const XCBRandrGetCrtcGammaReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_crtc_gamma_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetCrtcGammaCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetCrtcGammaReply;
+    pub fn xcb_randr_get_crtc_gamma(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+    ) -> XCBRandrGetCrtcGammaCookie;
+    pub fn xcb_randr_get_crtc_gamma_unchecked(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+    ) -> XCBRandrGetCrtcGammaCookie;
+    pub fn xcb_randr_set_crtc_gamma_checked(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+        size: u16,
+        red: *const u16,
+        green: *const u16,
+        blue: *const u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_set_crtc_gamma(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+        size: u16,
+        red: *const u16,
+        green: *const u16,
+        blue: *const u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_get_screen_resources_current_crtcs(
+        R: *const XCBRandrGetScreenResourcesCurrentReply,
+    ) -> *mut XCBRandrCrtc;
+    pub fn xcb_randr_get_screen_resources_current_crtcs_length(
+        R: *const XCBRandrGetScreenResourcesCurrentReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_screen_resources_current_crtcs_end(
+        R: *const XCBRandrGetScreenResourcesCurrentReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_screen_resources_current_outputs(
+        R: *const XCBRandrGetScreenResourcesCurrentReply,
+    ) -> *mut XCBRandrOutput;
+    pub fn xcb_randr_get_screen_resources_current_outputs_length(
+        R: *const XCBRandrGetScreenResourcesCurrentReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_screen_resources_current_outputs_end(
+        R: *const XCBRandrGetScreenResourcesCurrentReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_screen_resources_current_modes(
+        R: *const XCBRandrGetScreenResourcesCurrentReply,
+    ) -> *mut XCBRandrModeInfo;
+    pub fn xcb_randr_get_screen_resources_current_modes_length(
+        R: *const XCBRandrGetScreenResourcesCurrentReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_screen_resources_current_modes_iterator(
+        R: *const XCBRandrGetScreenResourcesCurrentReply,
+    ) -> XCBRandrModeInfoIterator;
+    pub fn xcb_randr_get_screen_resources_current_names(
+        R: *const XCBRandrGetScreenResourcesCurrentReply,
+    ) -> *mut u8;
+    pub fn xcb_randr_get_screen_resources_current_names_length(
+        R: *const XCBRandrGetScreenResourcesCurrentReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_screen_resources_current_names_end(
+        R: *const XCBRandrGetScreenResourcesCurrentReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_screen_resources_current_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetScreenResourcesCurrentCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetScreenResourcesCurrentReply;
+    pub fn xcb_randr_get_screen_resources_current(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBRandrGetScreenResourcesCurrentCookie;
+    pub fn xcb_randr_get_screen_resources_current_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBRandrGetScreenResourcesCurrentCookie;
+    pub fn xcb_randr_set_crtc_transform_checked(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+        transform: XCBRenderTransform,
+        filter_len: u16,
+        filter_name: *const c_char,
+        filter_params_len: u32,
+        filter_params: *const XCBRenderFixed,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_set_crtc_transform(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+        transform: XCBRenderTransform,
+        filter_len: u16,
+        filter_name: *const c_char,
+        filter_params_len: u32,
+        filter_params: *const XCBRenderFixed,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_get_crtc_transform_pending_filter_name(
+        R: *const XCBRandrGetCrtcTransformReply,
+    ) -> *mut c_char;
+    pub fn xcb_randr_get_crtc_transform_pending_filter_name_length(
+        R: *const XCBRandrGetCrtcTransformReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_crtc_transform_pending_filter_name_end(
+        R: *const XCBRandrGetCrtcTransformReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_crtc_transform_pending_params(
+        R: *const XCBRandrGetCrtcTransformReply,
+    ) -> *mut XCBRenderFixed;
+    pub fn xcb_randr_get_crtc_transform_pending_params_length(
+        R: *const XCBRandrGetCrtcTransformReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_crtc_transform_pending_params_end(
+        R: *const XCBRandrGetCrtcTransformReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_crtc_transform_current_filter_name(
+        R: *const XCBRandrGetCrtcTransformReply,
+    ) -> *mut c_char;
+    pub fn xcb_randr_get_crtc_transform_current_filter_name_length(
+        R: *const XCBRandrGetCrtcTransformReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_crtc_transform_current_filter_name_end(
+        R: *const XCBRandrGetCrtcTransformReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_crtc_transform_current_params(
+        R: *const XCBRandrGetCrtcTransformReply,
+    ) -> *mut XCBRenderFixed;
+    pub fn xcb_randr_get_crtc_transform_current_params_length(
+        R: *const XCBRandrGetCrtcTransformReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_crtc_transform_current_params_end(
+        R: *const XCBRandrGetCrtcTransformReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_crtc_transform_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetCrtcTransformCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetCrtcTransformReply;
+    pub fn xcb_randr_get_crtc_transform(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+    ) -> XCBRandrGetCrtcTransformCookie;
+    pub fn xcb_randr_get_crtc_transform_unchecked(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+    ) -> XCBRandrGetCrtcTransformCookie;
+    pub fn xcb_randr_get_panning_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetPanningCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetPanningReply;
+    pub fn xcb_randr_get_panning(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+    ) -> XCBRandrGetPanningCookie;
+    pub fn xcb_randr_get_panning_unchecked(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+    ) -> XCBRandrGetPanningCookie;
+    pub fn xcb_randr_set_panning_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrSetPanningCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrSetPanningReply;
+    pub fn xcb_randr_set_panning(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+        timestamp: XCBTimestamp,
+        left: u16,
+        top: u16,
+        width: u16,
+        height: u16,
+        track_left: u16,
+        track_top: u16,
+        track_width: u16,
+        track_height: u16,
+        border_left: i16,
+        border_top: i16,
+        border_right: i16,
+        border_bottom: i16,
+    ) -> XCBRandrSetPanningCookie;
+    pub fn xcb_randr_set_panning_unchecked(
+        connection: *mut XCBConnection,
+        crtc: XCBRandrCrtc,
+        timestamp: XCBTimestamp,
+        left: u16,
+        top: u16,
+        width: u16,
+        height: u16,
+        track_left: u16,
+        track_top: u16,
+        track_width: u16,
+        track_height: u16,
+        border_left: i16,
+        border_top: i16,
+        border_right: i16,
+        border_bottom: i16,
+    ) -> XCBRandrSetPanningCookie;
+    pub fn xcb_randr_set_output_primary_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        output: XCBRandrOutput,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_set_output_primary(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        output: XCBRandrOutput,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_get_output_primary_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetOutputPrimaryCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetOutputPrimaryReply;
+    pub fn xcb_randr_get_output_primary(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBRandrGetOutputPrimaryCookie;
+    pub fn xcb_randr_get_output_primary_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBRandrGetOutputPrimaryCookie;
+    pub fn xcb_randr_get_providers_providers(
+        R: *const XCBRandrGetProvidersReply,
+    ) -> *mut XCBRandrProvider;
+    pub fn xcb_randr_get_providers_providers_length(R: *const XCBRandrGetProvidersReply) -> c_int;
+    pub fn xcb_randr_get_providers_providers_end(
+        R: *const XCBRandrGetProvidersReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_providers_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetProvidersCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetProvidersReply;
+    pub fn xcb_randr_get_providers(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBRandrGetProvidersCookie;
+    pub fn xcb_randr_get_providers_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBRandrGetProvidersCookie;
+    pub fn xcb_randr_get_provider_info_crtcs(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> *mut XCBRandrCrtc;
+    pub fn xcb_randr_get_provider_info_crtcs_length(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_provider_info_crtcs_end(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_provider_info_outputs(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> *mut XCBRandrOutput;
+    pub fn xcb_randr_get_provider_info_outputs_length(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_provider_info_outputs_end(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_provider_info_associated_providers(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> *mut XCBRandrProvider;
+    pub fn xcb_randr_get_provider_info_associated_providers_length(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_provider_info_associated_providers_end(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_provider_info_associated_capability(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> *mut u32;
+    pub fn xcb_randr_get_provider_info_associated_capability_length(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_provider_info_associated_capability_end(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_provider_info_name(R: *const XCBRandrGetProviderInfoReply) -> *mut c_char;
+    pub fn xcb_randr_get_provider_info_name_length(R: *const XCBRandrGetProviderInfoReply)
+        -> c_int;
+    pub fn xcb_randr_get_provider_info_name_end(
+        R: *const XCBRandrGetProviderInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_provider_info_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetProviderInfoCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetProviderInfoReply;
+    pub fn xcb_randr_get_provider_info(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        config_timestamp: XCBTimestamp,
+    ) -> XCBRandrGetProviderInfoCookie;
+    pub fn xcb_randr_get_provider_info_unchecked(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        config_timestamp: XCBTimestamp,
+    ) -> XCBRandrGetProviderInfoCookie;
+    pub fn xcb_randr_set_provider_offload_sink_checked(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        sink_provider: XCBRandrProvider,
+        config_timestamp: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_set_provider_offload_sink(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        sink_provider: XCBRandrProvider,
+        config_timestamp: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_set_provider_output_source_checked(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        source_provider: XCBRandrProvider,
+        config_timestamp: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_set_provider_output_source(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        source_provider: XCBRandrProvider,
+        config_timestamp: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_list_provider_properties_atoms(
+        R: *const XCBRandrListProviderPropertiesReply,
+    ) -> *mut XCBAtom;
+    pub fn xcb_randr_list_provider_properties_atoms_length(
+        R: *const XCBRandrListProviderPropertiesReply,
+    ) -> c_int;
+    pub fn xcb_randr_list_provider_properties_atoms_end(
+        R: *const XCBRandrListProviderPropertiesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_list_provider_properties_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrListProviderPropertiesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrListProviderPropertiesReply;
+    pub fn xcb_randr_list_provider_properties(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+    ) -> XCBRandrListProviderPropertiesCookie;
+    pub fn xcb_randr_list_provider_properties_unchecked(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+    ) -> XCBRandrListProviderPropertiesCookie;
+    pub fn xcb_randr_query_provider_property_valid_values(
+        R: *const XCBRandrQueryProviderPropertyReply,
+    ) -> *mut i32;
+    pub fn xcb_randr_query_provider_property_valid_values_length(
+        R: *const XCBRandrQueryProviderPropertyReply,
+    ) -> c_int;
+    pub fn xcb_randr_query_provider_property_valid_values_end(
+        R: *const XCBRandrQueryProviderPropertyReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_query_provider_property_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrQueryProviderPropertyCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrQueryProviderPropertyReply;
+    pub fn xcb_randr_query_provider_property(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        property: XCBAtom,
+    ) -> XCBRandrQueryProviderPropertyCookie;
+    pub fn xcb_randr_query_provider_property_unchecked(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        property: XCBAtom,
+    ) -> XCBRandrQueryProviderPropertyCookie;
+    pub fn xcb_randr_configure_provider_property_checked(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        property: XCBAtom,
+        pending: u8,
+        range: u8,
+        values_len: u32,
+        values: *const i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_configure_provider_property(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        property: XCBAtom,
+        pending: u8,
+        range: u8,
+        values_len: u32,
+        values: *const i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_change_provider_property_checked(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        property: XCBAtom,
+        type_: XCBAtom,
+        format: u8,
+        mode: u8,
+        num_items: u32,
+        data: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_change_provider_property(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        property: XCBAtom,
+        type_: XCBAtom,
+        format: u8,
+        mode: u8,
+        num_items: u32,
+        data: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_delete_provider_property_checked(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        property: XCBAtom,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_delete_provider_property(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        property: XCBAtom,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_get_provider_property_data(
+        R: *const XCBRandrGetProviderPropertyReply,
+    ) -> *mut c_void;
+    pub fn xcb_randr_get_provider_property_data_length(
+        R: *const XCBRandrGetProviderPropertyReply,
+    ) -> c_int;
+    pub fn xcb_randr_get_provider_property_data_end(
+        R: *const XCBRandrGetProviderPropertyReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_get_provider_property_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetProviderPropertyCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetProviderPropertyReply;
+    pub fn xcb_randr_get_provider_property(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        property: XCBAtom,
+        type_: XCBAtom,
+        long_offset: u32,
+        long_length: u32,
+        delete: u8,
+        pending: u8,
+    ) -> XCBRandrGetProviderPropertyCookie;
+    pub fn xcb_randr_get_provider_property_unchecked(
+        connection: *mut XCBConnection,
+        provider: XCBRandrProvider,
+        property: XCBAtom,
+        type_: XCBAtom,
+        long_offset: u32,
+        long_length: u32,
+        delete: u8,
+        pending: u8,
+    ) -> XCBRandrGetProviderPropertyCookie;
+    pub fn xcb_randr_crtc_change_next(i: *mut XCBRandrCrtcChangeIterator);
+    pub fn xcb_randr_crtc_change_end(i: XCBRandrCrtcChangeIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_output_change_next(i: *mut XCBRandrOutputChangeIterator);
+    pub fn xcb_randr_output_change_end(i: XCBRandrOutputChangeIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_output_property_next(i: *mut XCBRandrOutputPropertyIterator);
+    pub fn xcb_randr_output_property_end(i: XCBRandrOutputPropertyIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_provider_change_next(i: *mut XCBRandrProviderChangeIterator);
+    pub fn xcb_randr_provider_change_end(i: XCBRandrProviderChangeIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_provider_property_next(i: *mut XCBRandrProviderPropertyIterator);
+    pub fn xcb_randr_provider_property_end(
+        i: XCBRandrProviderPropertyIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_randr_resource_change_next(i: *mut XCBRandrResourceChangeIterator);
+    pub fn xcb_randr_resource_change_end(i: XCBRandrResourceChangeIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_monitor_info_next(i: *mut XCBRandrMonitorInfoIterator);
+    pub fn xcb_randr_monitor_info_end(i: XCBRandrMonitorInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_monitor_info_outputs(R: *const XCBRandrMonitorInfo) -> *mut XCBRandrOutput;
+    pub fn xcb_randr_monitor_info_outputs_length(R: *const XCBRandrMonitorInfo) -> c_int;
+    pub fn xcb_randr_monitor_info_outputs_end(R: *const XCBRandrMonitorInfo) -> XCBGenericIterator;
+    pub fn xcb_randr_get_monitors_monitors_length(R: *const XCBRandrGetMonitorsReply) -> c_int;
+    pub fn xcb_randr_get_monitors_monitors_iterator(
+        R: *const XCBRandrGetMonitorsReply,
+    ) -> XCBRandrMonitorInfoIterator;
+    pub fn xcb_randr_get_monitors_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrGetMonitorsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrGetMonitorsReply;
+    pub fn xcb_randr_get_monitors(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        get_active: u8,
+    ) -> XCBRandrGetMonitorsCookie;
+    pub fn xcb_randr_get_monitors_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        get_active: u8,
+    ) -> XCBRandrGetMonitorsCookie;
+    pub fn xcb_randr_set_monitor_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        monitorinfo: *const XCBRandrMonitorInfo,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_set_monitor(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        monitorinfo: *const XCBRandrMonitorInfo,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_delete_monitor_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        name: XCBAtom,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_delete_monitor(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        name: XCBAtom,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_create_lease_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRandrCreateLeaseCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRandrCreateLeaseReply;
+    pub fn xcb_randr_create_lease_reply_fds(
+        connection: *mut XCBConnection,
+        reply: *mut XCBRandrCreateLeaseReply,
+    ) -> *mut c_int;
+    pub fn xcb_randr_create_lease(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        lid: XCBRandrLease,
+        num_crtcs: u16,
+        num_outputs: u16,
+        crtcs: *const XCBRandrCrtc,
+        outputs: *const XCBRandrOutput,
+    ) -> XCBRandrCreateLeaseCookie;
+    pub fn xcb_randr_create_lease_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        lid: XCBRandrLease,
+        num_crtcs: u16,
+        num_outputs: u16,
+        crtcs: *const XCBRandrCrtc,
+        outputs: *const XCBRandrOutput,
+    ) -> XCBRandrCreateLeaseCookie;
+    pub fn xcb_randr_free_lease_checked(
+        connection: *mut XCBConnection,
+        lid: XCBRandrLease,
+        terminate: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_free_lease(
+        connection: *mut XCBConnection,
+        lid: XCBRandrLease,
+        terminate: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_randr_lease_notify_next(i: *mut XCBRandrLeaseNotifyIterator);
+    pub fn xcb_randr_lease_notify_end(i: XCBRandrLeaseNotifyIterator) -> XCBGenericIterator;
+    pub fn xcb_randr_notify_data_next(i: *mut XCBRandrNotifyDataIterator);
+    pub fn xcb_randr_notify_data_end(i: XCBRandrNotifyDataIterator) -> XCBGenericIterator;
+}
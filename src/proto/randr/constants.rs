@@ -0,0 +1,105 @@
+// Generated by xcbgen from xml/randr.xml, do not edit.
+
+pub const XCB_RANDR_MAJOR_VERSION: u32 = 1;
+pub const XCB_RANDR_MINOR_VERSION: u32 = 6;
+pub const XCB_RANDR_BAD_OUTPUT: u8 = 0;
+pub const XCB_RANDR_BAD_CRTC: u8 = 1;
+pub const XCB_RANDR_BAD_MODE: u8 = 2;
+pub const XCB_RANDR_BAD_PROVIDER: u8 = 3;
+pub const XCB_RANDR_ROTATION_ROTATE_0: u16 = 1;
+pub const XCB_RANDR_ROTATION_ROTATE_90: u16 = 2;
+pub const XCB_RANDR_ROTATION_ROTATE_180: u16 = 4;
+pub const XCB_RANDR_ROTATION_ROTATE_270: u16 = 8;
+pub const XCB_RANDR_ROTATION_REFLECT_X: u16 = 16;
+pub const XCB_RANDR_ROTATION_REFLECT_Y: u16 = 32;
+pub const XCB_RANDR_QUERY_VERSION: u8 = 0;
+pub const XCB_RANDR_SET_CONFIG_SUCCESS: u8 = 0;
+pub const XCB_RANDR_SET_CONFIG_INVALID_CONFIG_TIME: u8 = 1;
+pub const XCB_RANDR_SET_CONFIG_INVALID_TIME: u8 = 2;
+pub const XCB_RANDR_SET_CONFIG_FAILED: u8 = 3;
+pub const XCB_RANDR_SET_SCREEN_CONFIG: u8 = 2;
+pub const XCB_RANDR_NOTIFY_MASK_SCREEN_CHANGE: u16 = 1;
+pub const XCB_RANDR_NOTIFY_MASK_CRTC_CHANGE: u16 = 2;
+pub const XCB_RANDR_NOTIFY_MASK_OUTPUT_CHANGE: u16 = 4;
+pub const XCB_RANDR_NOTIFY_MASK_OUTPUT_PROPERTY: u16 = 8;
+pub const XCB_RANDR_NOTIFY_MASK_PROVIDER_CHANGE: u16 = 16;
+pub const XCB_RANDR_NOTIFY_MASK_PROVIDER_PROPERTY: u16 = 32;
+pub const XCB_RANDR_NOTIFY_MASK_RESOURCE_CHANGE: u16 = 64;
+pub const XCB_RANDR_NOTIFY_MASK_LEASE: u16 = 128;
+pub const XCB_RANDR_SELECT_INPUT: u8 = 4;
+pub const XCB_RANDR_GET_SCREEN_INFO: u8 = 5;
+pub const XCB_RANDR_GET_SCREEN_SIZE_RANGE: u8 = 6;
+pub const XCB_RANDR_SET_SCREEN_SIZE: u8 = 7;
+pub const XCB_RANDR_MODE_FLAG_HSYNC_POSITIVE: u32 = 1;
+pub const XCB_RANDR_MODE_FLAG_HSYNC_NEGATIVE: u32 = 2;
+pub const XCB_RANDR_MODE_FLAG_VSYNC_POSITIVE: u32 = 4;
+pub const XCB_RANDR_MODE_FLAG_VSYNC_NEGATIVE: u32 = 8;
+pub const XCB_RANDR_MODE_FLAG_INTERLACE: u32 = 16;
+pub const XCB_RANDR_MODE_FLAG_DOUBLE_SCAN: u32 = 32;
+pub const XCB_RANDR_MODE_FLAG_CSYNC: u32 = 64;
+pub const XCB_RANDR_MODE_FLAG_CSYNC_POSITIVE: u32 = 128;
+pub const XCB_RANDR_MODE_FLAG_CSYNC_NEGATIVE: u32 = 256;
+pub const XCB_RANDR_MODE_FLAG_HSKEW_PRESENT: u32 = 512;
+pub const XCB_RANDR_MODE_FLAG_BCAST: u32 = 1024;
+pub const XCB_RANDR_MODE_FLAG_PIXEL_MULTIPLEX: u32 = 2048;
+pub const XCB_RANDR_MODE_FLAG_DOUBLE_CLOCK: u32 = 4096;
+pub const XCB_RANDR_MODE_FLAG_HALVE_CLOCK: u32 = 8192;
+pub const XCB_RANDR_GET_SCREEN_RESOURCES: u8 = 8;
+pub const XCB_RANDR_CONNECTION_CONNECTED: u8 = 0;
+pub const XCB_RANDR_CONNECTION_DISCONNECTED: u8 = 1;
+pub const XCB_RANDR_CONNECTION_UNKNOWN: u8 = 2;
+pub const XCB_RANDR_GET_OUTPUT_INFO: u8 = 9;
+pub const XCB_RANDR_LIST_OUTPUT_PROPERTIES: u8 = 10;
+pub const XCB_RANDR_QUERY_OUTPUT_PROPERTY: u8 = 11;
+pub const XCB_RANDR_CONFIGURE_OUTPUT_PROPERTY: u8 = 12;
+pub const XCB_RANDR_CHANGE_OUTPUT_PROPERTY: u8 = 13;
+pub const XCB_RANDR_DELETE_OUTPUT_PROPERTY: u8 = 14;
+pub const XCB_RANDR_GET_OUTPUT_PROPERTY: u8 = 15;
+pub const XCB_RANDR_CREATE_MODE: u8 = 16;
+pub const XCB_RANDR_DESTROY_MODE: u8 = 17;
+pub const XCB_RANDR_ADD_OUTPUT_MODE: u8 = 18;
+pub const XCB_RANDR_DELETE_OUTPUT_MODE: u8 = 19;
+pub const XCB_RANDR_GET_CRTC_INFO: u8 = 20;
+pub const XCB_RANDR_SET_CRTC_CONFIG: u8 = 21;
+pub const XCB_RANDR_GET_CRTC_GAMMA_SIZE: u8 = 22;
+pub const XCB_RANDR_GET_CRTC_GAMMA: u8 = 23;
+pub const XCB_RANDR_SET_CRTC_GAMMA: u8 = 24;
+pub const XCB_RANDR_GET_SCREEN_RESOURCES_CURRENT: u8 = 25;
+pub const XCB_RANDR_TRANSFORM_UNIT: u32 = 1;
+pub const XCB_RANDR_TRANSFORM_SCALE_UP: u32 = 2;
+pub const XCB_RANDR_TRANSFORM_SCALE_DOWN: u32 = 4;
+pub const XCB_RANDR_TRANSFORM_PROJECTIVE: u32 = 8;
+pub const XCB_RANDR_SET_CRTC_TRANSFORM: u8 = 26;
+pub const XCB_RANDR_GET_CRTC_TRANSFORM: u8 = 27;
+pub const XCB_RANDR_GET_PANNING: u8 = 28;
+pub const XCB_RANDR_SET_PANNING: u8 = 29;
+pub const XCB_RANDR_SET_OUTPUT_PRIMARY: u8 = 30;
+pub const XCB_RANDR_GET_OUTPUT_PRIMARY: u8 = 31;
+pub const XCB_RANDR_GET_PROVIDERS: u8 = 32;
+pub const XCB_RANDR_PROVIDER_CAPABILITY_SOURCE_OUTPUT: u32 = 1;
+pub const XCB_RANDR_PROVIDER_CAPABILITY_SINK_OUTPUT: u32 = 2;
+pub const XCB_RANDR_PROVIDER_CAPABILITY_SOURCE_OFFLOAD: u32 = 4;
+pub const XCB_RANDR_PROVIDER_CAPABILITY_SINK_OFFLOAD: u32 = 8;
+pub const XCB_RANDR_GET_PROVIDER_INFO: u8 = 33;
+pub const XCB_RANDR_SET_PROVIDER_OFFLOAD_SINK: u8 = 34;
+pub const XCB_RANDR_SET_PROVIDER_OUTPUT_SOURCE: u8 = 35;
+pub const XCB_RANDR_LIST_PROVIDER_PROPERTIES: u8 = 36;
+pub const XCB_RANDR_QUERY_PROVIDER_PROPERTY: u8 = 37;
+pub const XCB_RANDR_CONFIGURE_PROVIDER_PROPERTY: u8 = 38;
+pub const XCB_RANDR_CHANGE_PROVIDER_PROPERTY: u8 = 39;
+pub const XCB_RANDR_DELETE_PROVIDER_PROPERTY: u8 = 40;
+pub const XCB_RANDR_GET_PROVIDER_PROPERTY: u8 = 41;
+pub const XCB_RANDR_SCREEN_CHANGE_NOTIFY: u8 = 0;
+pub const XCB_RANDR_NOTIFY_CRTC_CHANGE: u8 = 0;
+pub const XCB_RANDR_NOTIFY_OUTPUT_CHANGE: u8 = 1;
+pub const XCB_RANDR_NOTIFY_OUTPUT_PROPERTY: u8 = 2;
+pub const XCB_RANDR_NOTIFY_PROVIDER_CHANGE: u8 = 3;
+pub const XCB_RANDR_NOTIFY_PROVIDER_PROPERTY: u8 = 4;
+pub const XCB_RANDR_NOTIFY_RESOURCE_CHANGE: u8 = 5;
+pub const XCB_RANDR_NOTIFY_LEASE: u8 = 6;
+pub const XCB_RANDR_GET_MONITORS: u8 = 42;
+pub const XCB_RANDR_SET_MONITOR: u8 = 43;
+pub const XCB_RANDR_DELETE_MONITOR: u8 = 44;
+pub const XCB_RANDR_CREATE_LEASE: u8 = 45;
+pub const XCB_RANDR_FREE_LEASE: u8 = 46;
+pub const XCB_RANDR_NOTIFY: u8 = 1;
@@ -0,0 +1,1468 @@
+// Generated by xcbgen from xml/randr.xml, do not edit.
+
+//! Requests whose parameters the safe wrappers cannot express only have raw bindings
+//! in `cdef`:
+//!
+//! - `xcb_randr_set_monitor`
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::slice;
+
+use proto::render::{XCBRenderFixed, XCBRenderTransform};
+use proto::xproto::{XCBAtom, XCBTimestamp, XCBWindow};
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBRandrMode = cdef::XCBRandrMode;
+pub type XCBRandrModeIterator = cdef::XCBRandrModeIterator;
+pub type XCBRandrCrtc = cdef::XCBRandrCrtc;
+pub type XCBRandrCrtcIterator = cdef::XCBRandrCrtcIterator;
+pub type XCBRandrOutput = cdef::XCBRandrOutput;
+pub type XCBRandrOutputIterator = cdef::XCBRandrOutputIterator;
+pub type XCBRandrProvider = cdef::XCBRandrProvider;
+pub type XCBRandrProviderIterator = cdef::XCBRandrProviderIterator;
+pub type XCBRandrLease = cdef::XCBRandrLease;
+pub type XCBRandrLeaseIterator = cdef::XCBRandrLeaseIterator;
+pub type XCBRandrBadOutputError = cdef::XCBRandrBadOutputError;
+pub type XCBRandrBadCrtcError = cdef::XCBRandrBadCrtcError;
+pub type XCBRandrBadModeError = cdef::XCBRandrBadModeError;
+pub type XCBRandrBadProviderError = cdef::XCBRandrBadProviderError;
+pub type XCBRandrScreenSize = cdef::XCBRandrScreenSize;
+pub type XCBRandrScreenSizeIterator = cdef::XCBRandrScreenSizeIterator;
+pub type XCBRandrRefreshRates = cdef::XCBRandrRefreshRates;
+pub type XCBRandrRefreshRatesIterator = cdef::XCBRandrRefreshRatesIterator;
+pub type XCBRandrQueryVersionRequest = cdef::XCBRandrQueryVersionRequest;
+pub type XCBRandrQueryVersionCookie = cdef::XCBRandrQueryVersionCookie;
+pub type XCBRandrQueryVersionReply = cdef::XCBRandrQueryVersionReply;
+pub type XCBRandrSetScreenConfigRequest = cdef::XCBRandrSetScreenConfigRequest;
+pub type XCBRandrSetScreenConfigCookie = cdef::XCBRandrSetScreenConfigCookie;
+pub type XCBRandrSetScreenConfigReply = cdef::XCBRandrSetScreenConfigReply;
+pub type XCBRandrSelectInputRequest = cdef::XCBRandrSelectInputRequest;
+pub type XCBRandrGetScreenInfoRequest = cdef::XCBRandrGetScreenInfoRequest;
+pub type XCBRandrGetScreenInfoCookie = cdef::XCBRandrGetScreenInfoCookie;
+pub type XCBRandrGetScreenInfoReply = cdef::XCBRandrGetScreenInfoReply;
+pub type XCBRandrGetScreenSizeRangeRequest = cdef::XCBRandrGetScreenSizeRangeRequest;
+pub type XCBRandrGetScreenSizeRangeCookie = cdef::XCBRandrGetScreenSizeRangeCookie;
+pub type XCBRandrGetScreenSizeRangeReply = cdef::XCBRandrGetScreenSizeRangeReply;
+pub type XCBRandrSetScreenSizeRequest = cdef::XCBRandrSetScreenSizeRequest;
+pub type XCBRandrModeInfo = cdef::XCBRandrModeInfo;
+pub type XCBRandrModeInfoIterator = cdef::XCBRandrModeInfoIterator;
+pub type XCBRandrGetScreenResourcesRequest = cdef::XCBRandrGetScreenResourcesRequest;
+pub type XCBRandrGetScreenResourcesCookie = cdef::XCBRandrGetScreenResourcesCookie;
+pub type XCBRandrGetScreenResourcesReply = cdef::XCBRandrGetScreenResourcesReply;
+pub type XCBRandrGetOutputInfoRequest = cdef::XCBRandrGetOutputInfoRequest;
+pub type XCBRandrGetOutputInfoCookie = cdef::XCBRandrGetOutputInfoCookie;
+pub type XCBRandrGetOutputInfoReply = cdef::XCBRandrGetOutputInfoReply;
+pub type XCBRandrListOutputPropertiesRequest = cdef::XCBRandrListOutputPropertiesRequest;
+pub type XCBRandrListOutputPropertiesCookie = cdef::XCBRandrListOutputPropertiesCookie;
+pub type XCBRandrListOutputPropertiesReply = cdef::XCBRandrListOutputPropertiesReply;
+pub type XCBRandrQueryOutputPropertyRequest = cdef::XCBRandrQueryOutputPropertyRequest;
+pub type XCBRandrQueryOutputPropertyCookie = cdef::XCBRandrQueryOutputPropertyCookie;
+pub type XCBRandrQueryOutputPropertyReply = cdef::XCBRandrQueryOutputPropertyReply;
+pub type XCBRandrConfigureOutputPropertyRequest = cdef::XCBRandrConfigureOutputPropertyRequest;
+pub type XCBRandrChangeOutputPropertyRequest = cdef::XCBRandrChangeOutputPropertyRequest;
+pub type XCBRandrDeleteOutputPropertyRequest = cdef::XCBRandrDeleteOutputPropertyRequest;
+pub type XCBRandrGetOutputPropertyRequest = cdef::XCBRandrGetOutputPropertyRequest;
+pub type XCBRandrGetOutputPropertyCookie = cdef::XCBRandrGetOutputPropertyCookie;
+pub type XCBRandrGetOutputPropertyReply = cdef::XCBRandrGetOutputPropertyReply;
+pub type XCBRandrCreateModeRequest = cdef::XCBRandrCreateModeRequest;
+pub type XCBRandrCreateModeCookie = cdef::XCBRandrCreateModeCookie;
+pub type XCBRandrCreateModeReply = cdef::XCBRandrCreateModeReply;
+pub type XCBRandrDestroyModeRequest = cdef::XCBRandrDestroyModeRequest;
+pub type XCBRandrAddOutputModeRequest = cdef::XCBRandrAddOutputModeRequest;
+pub type XCBRandrDeleteOutputModeRequest = cdef::XCBRandrDeleteOutputModeRequest;
+pub type XCBRandrGetCrtcInfoRequest = cdef::XCBRandrGetCrtcInfoRequest;
+pub type XCBRandrGetCrtcInfoCookie = cdef::XCBRandrGetCrtcInfoCookie;
+pub type XCBRandrGetCrtcInfoReply = cdef::XCBRandrGetCrtcInfoReply;
+pub type XCBRandrSetCrtcConfigRequest = cdef::XCBRandrSetCrtcConfigRequest;
+pub type XCBRandrSetCrtcConfigCookie = cdef::XCBRandrSetCrtcConfigCookie;
+pub type XCBRandrSetCrtcConfigReply = cdef::XCBRandrSetCrtcConfigReply;
+pub type XCBRandrGetCrtcGammaSizeRequest = cdef::XCBRandrGetCrtcGammaSizeRequest;
+pub type XCBRandrGetCrtcGammaSizeCookie = cdef::XCBRandrGetCrtcGammaSizeCookie;
+pub type XCBRandrGetCrtcGammaSizeReply = cdef::XCBRandrGetCrtcGammaSizeReply;
+pub type XCBRandrGetCrtcGammaRequest = cdef::XCBRandrGetCrtcGammaRequest;
+pub type XCBRandrGetCrtcGammaCookie = cdef::XCBRandrGetCrtcGammaCookie;
+pub type XCBRandrGetCrtcGammaReply = cdef::XCBRandrGetCrtcGammaReply;
+pub type XCBRandrSetCrtcGammaRequest = cdef::XCBRandrSetCrtcGammaRequest;
+pub type XCBRandrGetScreenResourcesCurrentRequest = cdef::XCBRandrGetScreenResourcesCurrentRequest;
+pub type XCBRandrGetScreenResourcesCurrentCookie = cdef::XCBRandrGetScreenResourcesCurrentCookie;
+pub type XCBRandrGetScreenResourcesCurrentReply = cdef::XCBRandrGetScreenResourcesCurrentReply;
+pub type XCBRandrSetCrtcTransformRequest = cdef::XCBRandrSetCrtcTransformRequest;
+pub type XCBRandrGetCrtcTransformRequest = cdef::XCBRandrGetCrtcTransformRequest;
+pub type XCBRandrGetCrtcTransformCookie = cdef::XCBRandrGetCrtcTransformCookie;
+pub type XCBRandrGetCrtcTransformReply = cdef::XCBRandrGetCrtcTransformReply;
+pub type XCBRandrGetPanningRequest = cdef::XCBRandrGetPanningRequest;
+pub type XCBRandrGetPanningCookie = cdef::XCBRandrGetPanningCookie;
+pub type XCBRandrGetPanningReply = cdef::XCBRandrGetPanningReply;
+pub type XCBRandrSetPanningRequest = cdef::XCBRandrSetPanningRequest;
+pub type XCBRandrSetPanningCookie = cdef::XCBRandrSetPanningCookie;
+pub type XCBRandrSetPanningReply = cdef::XCBRandrSetPanningReply;
+pub type XCBRandrSetOutputPrimaryRequest = cdef::XCBRandrSetOutputPrimaryRequest;
+pub type XCBRandrGetOutputPrimaryRequest = cdef::XCBRandrGetOutputPrimaryRequest;
+pub type XCBRandrGetOutputPrimaryCookie = cdef::XCBRandrGetOutputPrimaryCookie;
+pub type XCBRandrGetOutputPrimaryReply = cdef::XCBRandrGetOutputPrimaryReply;
+pub type XCBRandrGetProvidersRequest = cdef::XCBRandrGetProvidersRequest;
+pub type XCBRandrGetProvidersCookie = cdef::XCBRandrGetProvidersCookie;
+pub type XCBRandrGetProvidersReply = cdef::XCBRandrGetProvidersReply;
+pub type XCBRandrGetProviderInfoRequest = cdef::XCBRandrGetProviderInfoRequest;
+pub type XCBRandrGetProviderInfoCookie = cdef::XCBRandrGetProviderInfoCookie;
+pub type XCBRandrGetProviderInfoReply = cdef::XCBRandrGetProviderInfoReply;
+pub type XCBRandrSetProviderOffloadSinkRequest = cdef::XCBRandrSetProviderOffloadSinkRequest;
+pub type XCBRandrSetProviderOutputSourceRequest = cdef::XCBRandrSetProviderOutputSourceRequest;
+pub type XCBRandrListProviderPropertiesRequest = cdef::XCBRandrListProviderPropertiesRequest;
+pub type XCBRandrListProviderPropertiesCookie = cdef::XCBRandrListProviderPropertiesCookie;
+pub type XCBRandrListProviderPropertiesReply = cdef::XCBRandrListProviderPropertiesReply;
+pub type XCBRandrQueryProviderPropertyRequest = cdef::XCBRandrQueryProviderPropertyRequest;
+pub type XCBRandrQueryProviderPropertyCookie = cdef::XCBRandrQueryProviderPropertyCookie;
+pub type XCBRandrQueryProviderPropertyReply = cdef::XCBRandrQueryProviderPropertyReply;
+pub type XCBRandrConfigureProviderPropertyRequest = cdef::XCBRandrConfigureProviderPropertyRequest;
+pub type XCBRandrChangeProviderPropertyRequest = cdef::XCBRandrChangeProviderPropertyRequest;
+pub type XCBRandrDeleteProviderPropertyRequest = cdef::XCBRandrDeleteProviderPropertyRequest;
+pub type XCBRandrGetProviderPropertyRequest = cdef::XCBRandrGetProviderPropertyRequest;
+pub type XCBRandrGetProviderPropertyCookie = cdef::XCBRandrGetProviderPropertyCookie;
+pub type XCBRandrGetProviderPropertyReply = cdef::XCBRandrGetProviderPropertyReply;
+pub type XCBRandrScreenChangeNotifyEvent = cdef::XCBRandrScreenChangeNotifyEvent;
+pub type XCBRandrCrtcChange = cdef::XCBRandrCrtcChange;
+pub type XCBRandrCrtcChangeIterator = cdef::XCBRandrCrtcChangeIterator;
+pub type XCBRandrOutputChange = cdef::XCBRandrOutputChange;
+pub type XCBRandrOutputChangeIterator = cdef::XCBRandrOutputChangeIterator;
+pub type XCBRandrOutputProperty = cdef::XCBRandrOutputProperty;
+pub type XCBRandrOutputPropertyIterator = cdef::XCBRandrOutputPropertyIterator;
+pub type XCBRandrProviderChange = cdef::XCBRandrProviderChange;
+pub type XCBRandrProviderChangeIterator = cdef::XCBRandrProviderChangeIterator;
+pub type XCBRandrProviderProperty = cdef::XCBRandrProviderProperty;
+pub type XCBRandrProviderPropertyIterator = cdef::XCBRandrProviderPropertyIterator;
+pub type XCBRandrResourceChange = cdef::XCBRandrResourceChange;
+pub type XCBRandrResourceChangeIterator = cdef::XCBRandrResourceChangeIterator;
+pub type XCBRandrMonitorInfo = cdef::XCBRandrMonitorInfo;
+pub type XCBRandrMonitorInfoIterator = cdef::XCBRandrMonitorInfoIterator;
+pub type XCBRandrGetMonitorsRequest = cdef::XCBRandrGetMonitorsRequest;
+pub type XCBRandrGetMonitorsCookie = cdef::XCBRandrGetMonitorsCookie;
+pub type XCBRandrGetMonitorsReply = cdef::XCBRandrGetMonitorsReply;
+pub type XCBRandrSetMonitorRequest = cdef::XCBRandrSetMonitorRequest;
+pub type XCBRandrDeleteMonitorRequest = cdef::XCBRandrDeleteMonitorRequest;
+pub type XCBRandrCreateLeaseRequest = cdef::XCBRandrCreateLeaseRequest;
+pub type XCBRandrCreateLeaseCookie = cdef::XCBRandrCreateLeaseCookie;
+pub type XCBRandrCreateLeaseReply = cdef::XCBRandrCreateLeaseReply;
+pub type XCBRandrFreeLeaseRequest = cdef::XCBRandrFreeLeaseRequest;
+pub type XCBRandrLeaseNotify = cdef::XCBRandrLeaseNotify;
+pub type XCBRandrLeaseNotifyIterator = cdef::XCBRandrLeaseNotifyIterator;
+pub type XCBRandrNotifyData = cdef::XCBRandrNotifyData;
+pub type XCBRandrNotifyDataIterator = cdef::XCBRandrNotifyDataIterator;
+pub type XCBRandrNotifyEvent = cdef::XCBRandrNotifyEvent;
+
+/// The RandR extension, known to the server as `RANDR`.
+pub struct Randr;
+
+impl Extension for Randr {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_randr_id)
+    }
+}
+
+pub fn xcb_randr_mode_next(iter: &mut XCBRandrModeIterator) {
+    unsafe { cdef::xcb_randr_mode_next(iter) }
+}
+
+pub fn xcb_randr_crtc_next(iter: &mut XCBRandrCrtcIterator) {
+    unsafe { cdef::xcb_randr_crtc_next(iter) }
+}
+
+pub fn xcb_randr_output_next(iter: &mut XCBRandrOutputIterator) {
+    unsafe { cdef::xcb_randr_output_next(iter) }
+}
+
+pub fn xcb_randr_provider_next(iter: &mut XCBRandrProviderIterator) {
+    unsafe { cdef::xcb_randr_provider_next(iter) }
+}
+
+pub fn xcb_randr_lease_next(iter: &mut XCBRandrLeaseIterator) {
+    unsafe { cdef::xcb_randr_lease_next(iter) }
+}
+
+pub fn xcb_randr_screen_size_next(iter: &mut XCBRandrScreenSizeIterator) {
+    unsafe { cdef::xcb_randr_screen_size_next(iter) }
+}
+
+pub fn xcb_randr_refresh_rates_next(iter: &mut XCBRandrRefreshRatesIterator) {
+    unsafe { cdef::xcb_randr_refresh_rates_next(iter) }
+}
+
+pub fn xcb_randr_refresh_rates_rates(data: &XCBRandrRefreshRates) -> &[u16] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_refresh_rates_rates(data),
+            cdef::xcb_randr_refresh_rates_rates_length(data) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_query_version(
+    connection: &XCBConnection,
+    major_version: u32,
+    minor_version: u32,
+) -> XCBRandrQueryVersionCookie {
+    unsafe { cdef::xcb_randr_query_version(connection.raw, major_version, minor_version) }
+}
+
+impl Reply for XCBRandrSetScreenConfigReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_set_screen_config_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_set_screen_config(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    timestamp: XCBTimestamp,
+    config_timestamp: XCBTimestamp,
+    sizeID: u16,
+    rotation: u16,
+    rate: u16,
+) -> XCBRandrSetScreenConfigCookie {
+    unsafe {
+        cdef::xcb_randr_set_screen_config(
+            connection.raw,
+            window,
+            timestamp,
+            config_timestamp,
+            sizeID,
+            rotation,
+            rate,
+        )
+    }
+}
+
+pub fn xcb_randr_select_input(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    enable: u16,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_randr_select_input(connection.raw, window, enable) }
+}
+
+pub fn xcb_randr_get_screen_info_sizes(
+    reply: &XCBRandrGetScreenInfoReply,
+) -> &[XCBRandrScreenSize] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_screen_info_sizes(reply),
+            cdef::xcb_randr_get_screen_info_sizes_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_screen_info_rates_iterator(
+    reply: &XCBRandrGetScreenInfoReply,
+) -> XCBRandrRefreshRatesIterator {
+    unsafe { cdef::xcb_randr_get_screen_info_rates_iterator(reply) }
+}
+
+impl Reply for XCBRandrGetScreenInfoReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_screen_info_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_screen_info(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBRandrGetScreenInfoCookie {
+    unsafe { cdef::xcb_randr_get_screen_info(connection.raw, window) }
+}
+
+impl Reply for XCBRandrGetScreenSizeRangeReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_screen_size_range_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_screen_size_range(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBRandrGetScreenSizeRangeCookie {
+    unsafe { cdef::xcb_randr_get_screen_size_range(connection.raw, window) }
+}
+
+pub fn xcb_randr_set_screen_size(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    width: u16,
+    height: u16,
+    mm_width: u32,
+    mm_height: u32,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_randr_set_screen_size(connection.raw, window, width, height, mm_width, mm_height)
+    }
+}
+
+pub fn xcb_randr_mode_info_next(iter: &mut XCBRandrModeInfoIterator) {
+    unsafe { cdef::xcb_randr_mode_info_next(iter) }
+}
+
+pub fn xcb_randr_get_screen_resources_crtcs(
+    reply: &XCBRandrGetScreenResourcesReply,
+) -> &[XCBRandrCrtc] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_screen_resources_crtcs(reply),
+            cdef::xcb_randr_get_screen_resources_crtcs_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_screen_resources_outputs(
+    reply: &XCBRandrGetScreenResourcesReply,
+) -> &[XCBRandrOutput] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_screen_resources_outputs(reply),
+            cdef::xcb_randr_get_screen_resources_outputs_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_screen_resources_modes(
+    reply: &XCBRandrGetScreenResourcesReply,
+) -> &[XCBRandrModeInfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_screen_resources_modes(reply),
+            cdef::xcb_randr_get_screen_resources_modes_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_screen_resources_names(reply: &XCBRandrGetScreenResourcesReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_screen_resources_names(reply),
+            cdef::xcb_randr_get_screen_resources_names_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrGetScreenResourcesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_screen_resources_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_screen_resources(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBRandrGetScreenResourcesCookie {
+    unsafe { cdef::xcb_randr_get_screen_resources(connection.raw, window) }
+}
+
+pub fn xcb_randr_get_output_info_crtcs(reply: &XCBRandrGetOutputInfoReply) -> &[XCBRandrCrtc] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_output_info_crtcs(reply),
+            cdef::xcb_randr_get_output_info_crtcs_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_output_info_modes(reply: &XCBRandrGetOutputInfoReply) -> &[XCBRandrMode] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_output_info_modes(reply),
+            cdef::xcb_randr_get_output_info_modes_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_output_info_clones(reply: &XCBRandrGetOutputInfoReply) -> &[XCBRandrOutput] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_output_info_clones(reply),
+            cdef::xcb_randr_get_output_info_clones_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_output_info_name(reply: &XCBRandrGetOutputInfoReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_output_info_name(reply),
+            cdef::xcb_randr_get_output_info_name_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrGetOutputInfoReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_output_info_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_output_info(
+    connection: &XCBConnection,
+    output: XCBRandrOutput,
+    config_timestamp: XCBTimestamp,
+) -> XCBRandrGetOutputInfoCookie {
+    unsafe { cdef::xcb_randr_get_output_info(connection.raw, output, config_timestamp) }
+}
+
+pub fn xcb_randr_list_output_properties_atoms(
+    reply: &XCBRandrListOutputPropertiesReply,
+) -> &[XCBAtom] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_list_output_properties_atoms(reply),
+            cdef::xcb_randr_list_output_properties_atoms_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrListOutputPropertiesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_list_output_properties_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_list_output_properties(
+    connection: &XCBConnection,
+    output: XCBRandrOutput,
+) -> XCBRandrListOutputPropertiesCookie {
+    unsafe { cdef::xcb_randr_list_output_properties(connection.raw, output) }
+}
+
+pub fn xcb_randr_query_output_property_valid_values(
+    reply: &XCBRandrQueryOutputPropertyReply,
+) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_query_output_property_valid_values(reply),
+            cdef::xcb_randr_query_output_property_valid_values_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrQueryOutputPropertyReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_query_output_property_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_query_output_property(
+    connection: &XCBConnection,
+    output: XCBRandrOutput,
+    property: XCBAtom,
+) -> XCBRandrQueryOutputPropertyCookie {
+    unsafe { cdef::xcb_randr_query_output_property(connection.raw, output, property) }
+}
+
+pub fn xcb_randr_configure_output_property(
+    connection: &XCBConnection,
+    output: XCBRandrOutput,
+    property: XCBAtom,
+    pending: bool,
+    range: bool,
+    values: &[i32],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_randr_configure_output_property(
+            connection.raw,
+            output,
+            property,
+            pending as u8,
+            range as u8,
+            values.len() as u32,
+            values.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_randr_change_output_property(
+    connection: &XCBConnection,
+    output: XCBRandrOutput,
+    property: XCBAtom,
+    type_: XCBAtom,
+    format: u8,
+    mode: u8,
+    num_units: u32,
+    data: &[u8],
+) -> XCBVoidCookie {
+    assert!(data.len() >= (num_units as usize * (format as usize / 8)));
+    unsafe {
+        cdef::xcb_randr_change_output_property(
+            connection.raw,
+            output,
+            property,
+            type_,
+            format,
+            mode,
+            num_units,
+            data.as_ptr() as *const c_void,
+        )
+    }
+}
+
+pub fn xcb_randr_delete_output_property(
+    connection: &XCBConnection,
+    output: XCBRandrOutput,
+    property: XCBAtom,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_randr_delete_output_property(connection.raw, output, property) }
+}
+
+pub fn xcb_randr_get_output_property_data(reply: &XCBRandrGetOutputPropertyReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_output_property_data(reply) as *const u8,
+            cdef::xcb_randr_get_output_property_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrGetOutputPropertyReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_output_property_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_output_property(
+    connection: &XCBConnection,
+    output: XCBRandrOutput,
+    property: XCBAtom,
+    type_: XCBAtom,
+    long_offset: u32,
+    long_length: u32,
+    delete: bool,
+    pending: bool,
+) -> XCBRandrGetOutputPropertyCookie {
+    unsafe {
+        cdef::xcb_randr_get_output_property(
+            connection.raw,
+            output,
+            property,
+            type_,
+            long_offset,
+            long_length,
+            delete as u8,
+            pending as u8,
+        )
+    }
+}
+
+impl Reply for XCBRandrCreateModeReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_create_mode_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_create_mode(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    mode_info: XCBRandrModeInfo,
+    name: &[u8],
+) -> XCBRandrCreateModeCookie {
+    unsafe {
+        cdef::xcb_randr_create_mode(
+            connection.raw,
+            window,
+            mode_info,
+            name.len() as u32,
+            name.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_randr_destroy_mode(connection: &XCBConnection, mode: XCBRandrMode) -> XCBVoidCookie {
+    unsafe { cdef::xcb_randr_destroy_mode(connection.raw, mode) }
+}
+
+pub fn xcb_randr_add_output_mode(
+    connection: &XCBConnection,
+    output: XCBRandrOutput,
+    mode: XCBRandrMode,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_randr_add_output_mode(connection.raw, output, mode) }
+}
+
+pub fn xcb_randr_delete_output_mode(
+    connection: &XCBConnection,
+    output: XCBRandrOutput,
+    mode: XCBRandrMode,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_randr_delete_output_mode(connection.raw, output, mode) }
+}
+
+pub fn xcb_randr_get_crtc_info_outputs(reply: &XCBRandrGetCrtcInfoReply) -> &[XCBRandrOutput] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_crtc_info_outputs(reply),
+            cdef::xcb_randr_get_crtc_info_outputs_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_crtc_info_possible(reply: &XCBRandrGetCrtcInfoReply) -> &[XCBRandrOutput] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_crtc_info_possible(reply),
+            cdef::xcb_randr_get_crtc_info_possible_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrGetCrtcInfoReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_crtc_info_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_crtc_info(
+    connection: &XCBConnection,
+    crtc: XCBRandrCrtc,
+    config_timestamp: XCBTimestamp,
+) -> XCBRandrGetCrtcInfoCookie {
+    unsafe { cdef::xcb_randr_get_crtc_info(connection.raw, crtc, config_timestamp) }
+}
+
+impl Reply for XCBRandrSetCrtcConfigReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_set_crtc_config_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_set_crtc_config(
+    connection: &XCBConnection,
+    crtc: XCBRandrCrtc,
+    timestamp: XCBTimestamp,
+    config_timestamp: XCBTimestamp,
+    x: i16,
+    y: i16,
+    mode: XCBRandrMode,
+    rotation: u16,
+    outputs: &[XCBRandrOutput],
+) -> XCBRandrSetCrtcConfigCookie {
+    unsafe {
+        cdef::xcb_randr_set_crtc_config(
+            connection.raw,
+            crtc,
+            timestamp,
+            config_timestamp,
+            x,
+            y,
+            mode,
+            rotation,
+            outputs.len() as u32,
+            outputs.as_ptr(),
+        )
+    }
+}
+
+impl Reply for XCBRandrGetCrtcGammaSizeReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_crtc_gamma_size_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_crtc_gamma_size(
+    connection: &XCBConnection,
+    crtc: XCBRandrCrtc,
+) -> XCBRandrGetCrtcGammaSizeCookie {
+    unsafe { cdef::xcb_randr_get_crtc_gamma_size(connection.raw, crtc) }
+}
+
+pub fn xcb_randr_get_crtc_gamma_red(reply: &XCBRandrGetCrtcGammaReply) -> &[u16] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_crtc_gamma_red(reply),
+            cdef::xcb_randr_get_crtc_gamma_red_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_crtc_gamma_green(reply: &XCBRandrGetCrtcGammaReply) -> &[u16] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_crtc_gamma_green(reply),
+            cdef::xcb_randr_get_crtc_gamma_green_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_crtc_gamma_blue(reply: &XCBRandrGetCrtcGammaReply) -> &[u16] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_crtc_gamma_blue(reply),
+            cdef::xcb_randr_get_crtc_gamma_blue_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrGetCrtcGammaReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_crtc_gamma_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_crtc_gamma(
+    connection: &XCBConnection,
+    crtc: XCBRandrCrtc,
+) -> XCBRandrGetCrtcGammaCookie {
+    unsafe { cdef::xcb_randr_get_crtc_gamma(connection.raw, crtc) }
+}
+
+pub fn xcb_randr_set_crtc_gamma(
+    connection: &XCBConnection,
+    crtc: XCBRandrCrtc,
+    size: u16,
+    red: &[u16],
+    green: &[u16],
+    blue: &[u16],
+) -> XCBVoidCookie {
+    assert!(red.len() >= size as usize);
+    assert!(green.len() >= size as usize);
+    assert!(blue.len() >= size as usize);
+    unsafe {
+        cdef::xcb_randr_set_crtc_gamma(
+            connection.raw,
+            crtc,
+            size,
+            red.as_ptr(),
+            green.as_ptr(),
+            blue.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_randr_get_screen_resources_current_crtcs(
+    reply: &XCBRandrGetScreenResourcesCurrentReply,
+) -> &[XCBRandrCrtc] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_screen_resources_current_crtcs(reply),
+            cdef::xcb_randr_get_screen_resources_current_crtcs_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_screen_resources_current_outputs(
+    reply: &XCBRandrGetScreenResourcesCurrentReply,
+) -> &[XCBRandrOutput] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_screen_resources_current_outputs(reply),
+            cdef::xcb_randr_get_screen_resources_current_outputs_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_screen_resources_current_modes(
+    reply: &XCBRandrGetScreenResourcesCurrentReply,
+) -> &[XCBRandrModeInfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_screen_resources_current_modes(reply),
+            cdef::xcb_randr_get_screen_resources_current_modes_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_screen_resources_current_names(
+    reply: &XCBRandrGetScreenResourcesCurrentReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_screen_resources_current_names(reply),
+            cdef::xcb_randr_get_screen_resources_current_names_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrGetScreenResourcesCurrentReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_screen_resources_current_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_screen_resources_current(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBRandrGetScreenResourcesCurrentCookie {
+    unsafe { cdef::xcb_randr_get_screen_resources_current(connection.raw, window) }
+}
+
+pub fn xcb_randr_set_crtc_transform(
+    connection: &XCBConnection,
+    crtc: XCBRandrCrtc,
+    transform: XCBRenderTransform,
+    filter_name: &[u8],
+    filter_params: &[XCBRenderFixed],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_randr_set_crtc_transform(
+            connection.raw,
+            crtc,
+            transform,
+            filter_name.len() as u16,
+            filter_name.as_ptr() as *const c_char,
+            filter_params.len() as u32,
+            filter_params.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_randr_get_crtc_transform_pending_filter_name(
+    reply: &XCBRandrGetCrtcTransformReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_crtc_transform_pending_filter_name(reply) as *const u8,
+            cdef::xcb_randr_get_crtc_transform_pending_filter_name_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_crtc_transform_pending_params(
+    reply: &XCBRandrGetCrtcTransformReply,
+) -> &[XCBRenderFixed] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_crtc_transform_pending_params(reply),
+            cdef::xcb_randr_get_crtc_transform_pending_params_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_crtc_transform_current_filter_name(
+    reply: &XCBRandrGetCrtcTransformReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_crtc_transform_current_filter_name(reply) as *const u8,
+            cdef::xcb_randr_get_crtc_transform_current_filter_name_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_crtc_transform_current_params(
+    reply: &XCBRandrGetCrtcTransformReply,
+) -> &[XCBRenderFixed] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_crtc_transform_current_params(reply),
+            cdef::xcb_randr_get_crtc_transform_current_params_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrGetCrtcTransformReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_crtc_transform_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_crtc_transform(
+    connection: &XCBConnection,
+    crtc: XCBRandrCrtc,
+) -> XCBRandrGetCrtcTransformCookie {
+    unsafe { cdef::xcb_randr_get_crtc_transform(connection.raw, crtc) }
+}
+
+impl Reply for XCBRandrGetPanningReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_panning_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_panning(
+    connection: &XCBConnection,
+    crtc: XCBRandrCrtc,
+) -> XCBRandrGetPanningCookie {
+    unsafe { cdef::xcb_randr_get_panning(connection.raw, crtc) }
+}
+
+impl Reply for XCBRandrSetPanningReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_set_panning_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_set_panning(
+    connection: &XCBConnection,
+    crtc: XCBRandrCrtc,
+    timestamp: XCBTimestamp,
+    left: u16,
+    top: u16,
+    width: u16,
+    height: u16,
+    track_left: u16,
+    track_top: u16,
+    track_width: u16,
+    track_height: u16,
+    border_left: i16,
+    border_top: i16,
+    border_right: i16,
+    border_bottom: i16,
+) -> XCBRandrSetPanningCookie {
+    unsafe {
+        cdef::xcb_randr_set_panning(
+            connection.raw,
+            crtc,
+            timestamp,
+            left,
+            top,
+            width,
+            height,
+            track_left,
+            track_top,
+            track_width,
+            track_height,
+            border_left,
+            border_top,
+            border_right,
+            border_bottom,
+        )
+    }
+}
+
+pub fn xcb_randr_set_output_primary(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    output: XCBRandrOutput,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_randr_set_output_primary(connection.raw, window, output) }
+}
+
+impl Reply for XCBRandrGetOutputPrimaryReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_output_primary_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_output_primary(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBRandrGetOutputPrimaryCookie {
+    unsafe { cdef::xcb_randr_get_output_primary(connection.raw, window) }
+}
+
+pub fn xcb_randr_get_providers_providers(reply: &XCBRandrGetProvidersReply) -> &[XCBRandrProvider] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_providers_providers(reply),
+            cdef::xcb_randr_get_providers_providers_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrGetProvidersReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_providers_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_providers(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBRandrGetProvidersCookie {
+    unsafe { cdef::xcb_randr_get_providers(connection.raw, window) }
+}
+
+pub fn xcb_randr_get_provider_info_crtcs(reply: &XCBRandrGetProviderInfoReply) -> &[XCBRandrCrtc] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_provider_info_crtcs(reply),
+            cdef::xcb_randr_get_provider_info_crtcs_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_provider_info_outputs(
+    reply: &XCBRandrGetProviderInfoReply,
+) -> &[XCBRandrOutput] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_provider_info_outputs(reply),
+            cdef::xcb_randr_get_provider_info_outputs_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_provider_info_associated_providers(
+    reply: &XCBRandrGetProviderInfoReply,
+) -> &[XCBRandrProvider] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_provider_info_associated_providers(reply),
+            cdef::xcb_randr_get_provider_info_associated_providers_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_provider_info_associated_capability(
+    reply: &XCBRandrGetProviderInfoReply,
+) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_provider_info_associated_capability(reply),
+            cdef::xcb_randr_get_provider_info_associated_capability_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_provider_info_name(reply: &XCBRandrGetProviderInfoReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_provider_info_name(reply) as *const u8,
+            cdef::xcb_randr_get_provider_info_name_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrGetProviderInfoReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_provider_info_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_provider_info(
+    connection: &XCBConnection,
+    provider: XCBRandrProvider,
+    config_timestamp: XCBTimestamp,
+) -> XCBRandrGetProviderInfoCookie {
+    unsafe { cdef::xcb_randr_get_provider_info(connection.raw, provider, config_timestamp) }
+}
+
+pub fn xcb_randr_set_provider_offload_sink(
+    connection: &XCBConnection,
+    provider: XCBRandrProvider,
+    sink_provider: XCBRandrProvider,
+    config_timestamp: XCBTimestamp,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_randr_set_provider_offload_sink(
+            connection.raw,
+            provider,
+            sink_provider,
+            config_timestamp,
+        )
+    }
+}
+
+pub fn xcb_randr_set_provider_output_source(
+    connection: &XCBConnection,
+    provider: XCBRandrProvider,
+    source_provider: XCBRandrProvider,
+    config_timestamp: XCBTimestamp,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_randr_set_provider_output_source(
+            connection.raw,
+            provider,
+            source_provider,
+            config_timestamp,
+        )
+    }
+}
+
+pub fn xcb_randr_list_provider_properties_atoms(
+    reply: &XCBRandrListProviderPropertiesReply,
+) -> &[XCBAtom] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_list_provider_properties_atoms(reply),
+            cdef::xcb_randr_list_provider_properties_atoms_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrListProviderPropertiesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_list_provider_properties_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_list_provider_properties(
+    connection: &XCBConnection,
+    provider: XCBRandrProvider,
+) -> XCBRandrListProviderPropertiesCookie {
+    unsafe { cdef::xcb_randr_list_provider_properties(connection.raw, provider) }
+}
+
+pub fn xcb_randr_query_provider_property_valid_values(
+    reply: &XCBRandrQueryProviderPropertyReply,
+) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_query_provider_property_valid_values(reply),
+            cdef::xcb_randr_query_provider_property_valid_values_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrQueryProviderPropertyReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_query_provider_property_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_query_provider_property(
+    connection: &XCBConnection,
+    provider: XCBRandrProvider,
+    property: XCBAtom,
+) -> XCBRandrQueryProviderPropertyCookie {
+    unsafe { cdef::xcb_randr_query_provider_property(connection.raw, provider, property) }
+}
+
+pub fn xcb_randr_configure_provider_property(
+    connection: &XCBConnection,
+    provider: XCBRandrProvider,
+    property: XCBAtom,
+    pending: bool,
+    range: bool,
+    values: &[i32],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_randr_configure_provider_property(
+            connection.raw,
+            provider,
+            property,
+            pending as u8,
+            range as u8,
+            values.len() as u32,
+            values.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_randr_change_provider_property(
+    connection: &XCBConnection,
+    provider: XCBRandrProvider,
+    property: XCBAtom,
+    type_: XCBAtom,
+    format: u8,
+    mode: u8,
+    num_items: u32,
+    data: &[u8],
+) -> XCBVoidCookie {
+    assert!(data.len() >= (num_items as usize * (format as usize / 8)));
+    unsafe {
+        cdef::xcb_randr_change_provider_property(
+            connection.raw,
+            provider,
+            property,
+            type_,
+            format,
+            mode,
+            num_items,
+            data.as_ptr() as *const c_void,
+        )
+    }
+}
+
+pub fn xcb_randr_delete_provider_property(
+    connection: &XCBConnection,
+    provider: XCBRandrProvider,
+    property: XCBAtom,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_randr_delete_provider_property(connection.raw, provider, property) }
+}
+
+pub fn xcb_randr_get_provider_property_data(reply: &XCBRandrGetProviderPropertyReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_get_provider_property_data(reply) as *const u8,
+            cdef::xcb_randr_get_provider_property_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRandrGetProviderPropertyReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_provider_property_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_provider_property(
+    connection: &XCBConnection,
+    provider: XCBRandrProvider,
+    property: XCBAtom,
+    type_: XCBAtom,
+    long_offset: u32,
+    long_length: u32,
+    delete: bool,
+    pending: bool,
+) -> XCBRandrGetProviderPropertyCookie {
+    unsafe {
+        cdef::xcb_randr_get_provider_property(
+            connection.raw,
+            provider,
+            property,
+            type_,
+            long_offset,
+            long_length,
+            delete as u8,
+            pending as u8,
+        )
+    }
+}
+
+pub fn xcb_randr_crtc_change_next(iter: &mut XCBRandrCrtcChangeIterator) {
+    unsafe { cdef::xcb_randr_crtc_change_next(iter) }
+}
+
+pub fn xcb_randr_output_change_next(iter: &mut XCBRandrOutputChangeIterator) {
+    unsafe { cdef::xcb_randr_output_change_next(iter) }
+}
+
+pub fn xcb_randr_output_property_next(iter: &mut XCBRandrOutputPropertyIterator) {
+    unsafe { cdef::xcb_randr_output_property_next(iter) }
+}
+
+pub fn xcb_randr_provider_change_next(iter: &mut XCBRandrProviderChangeIterator) {
+    unsafe { cdef::xcb_randr_provider_change_next(iter) }
+}
+
+pub fn xcb_randr_provider_property_next(iter: &mut XCBRandrProviderPropertyIterator) {
+    unsafe { cdef::xcb_randr_provider_property_next(iter) }
+}
+
+pub fn xcb_randr_resource_change_next(iter: &mut XCBRandrResourceChangeIterator) {
+    unsafe { cdef::xcb_randr_resource_change_next(iter) }
+}
+
+pub fn xcb_randr_monitor_info_next(iter: &mut XCBRandrMonitorInfoIterator) {
+    unsafe { cdef::xcb_randr_monitor_info_next(iter) }
+}
+
+pub fn xcb_randr_monitor_info_outputs(data: &XCBRandrMonitorInfo) -> &[XCBRandrOutput] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_randr_monitor_info_outputs(data),
+            cdef::xcb_randr_monitor_info_outputs_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_randr_get_monitors_monitors_iterator(
+    reply: &XCBRandrGetMonitorsReply,
+) -> XCBRandrMonitorInfoIterator {
+    unsafe { cdef::xcb_randr_get_monitors_monitors_iterator(reply) }
+}
+
+impl Reply for XCBRandrGetMonitorsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_get_monitors_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_get_monitors(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    get_active: bool,
+) -> XCBRandrGetMonitorsCookie {
+    unsafe { cdef::xcb_randr_get_monitors(connection.raw, window, get_active as u8) }
+}
+
+pub fn xcb_randr_delete_monitor(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    name: XCBAtom,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_randr_delete_monitor(connection.raw, window, name) }
+}
+
+impl Reply for XCBRandrCreateLeaseReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_randr_create_lease_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_randr_create_lease(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    lid: XCBRandrLease,
+    crtcs: &[XCBRandrCrtc],
+    outputs: &[XCBRandrOutput],
+) -> XCBRandrCreateLeaseCookie {
+    unsafe {
+        cdef::xcb_randr_create_lease(
+            connection.raw,
+            window,
+            lid,
+            crtcs.len() as u16,
+            outputs.len() as u16,
+            crtcs.as_ptr(),
+            outputs.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_randr_free_lease(
+    connection: &XCBConnection,
+    lid: XCBRandrLease,
+    terminate: u8,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_randr_free_lease(connection.raw, lid, terminate) }
+}
+
+pub fn xcb_randr_lease_notify_next(iter: &mut XCBRandrLeaseNotifyIterator) {
+    unsafe { cdef::xcb_randr_lease_notify_next(iter) }
+}
+
+pub fn xcb_randr_notify_data_next(iter: &mut XCBRandrNotifyDataIterator) {
+    unsafe { cdef::xcb_randr_notify_data_next(iter) }
+}
+
+/// An event of the RandR extension.
+pub enum Event {
+    ScreenChangeNotify(Box<XCBRandrScreenChangeNotifyEvent>),
+    Notify(Box<XCBRandrNotifyEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::ScreenChangeNotify(Box::from_raw(
+                    raw as *mut XCBRandrScreenChangeNotifyEvent,
+                ))),
+                1 => Ok(Event::Notify(Box::from_raw(
+                    raw as *mut XCBRandrNotifyEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
+
+/// Returns the name of the error whose code, relative to the first error of the extension, is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        0 => Some("BadOutput"),
+        1 => Some("BadCrtc"),
+        2 => Some("BadMode"),
+        3 => Some("BadProvider"),
+        _ => None,
+    }
+}
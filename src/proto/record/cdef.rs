@@ -0,0 +1,386 @@
+// Generated by xcbgen from xml/record.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+
+pub type XCBRecordContext = u32;
+
+#[repr(C)]
+pub struct XCBRecordContextIterator {
+    pub data: *mut XCBRecordContext,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRecordRange8 {
+    pub first: u8,
+    pub last: u8,
+}
+
+#[repr(C)]
+pub struct XCBRecordRange8Iterator {
+    pub data: *mut XCBRecordRange8,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRecordRange16 {
+    pub first: u16,
+    pub last: u16,
+}
+
+#[repr(C)]
+pub struct XCBRecordRange16Iterator {
+    pub data: *mut XCBRecordRange16,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRecordExtRange {
+    pub major: XCBRecordRange8,
+    pub minor: XCBRecordRange16,
+}
+
+#[repr(C)]
+pub struct XCBRecordExtRangeIterator {
+    pub data: *mut XCBRecordExtRange,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRecordRange {
+    pub core_requests: XCBRecordRange8,
+    pub core_replies: XCBRecordRange8,
+    pub ext_requests: XCBRecordExtRange,
+    pub ext_replies: XCBRecordExtRange,
+    pub delivered_events: XCBRecordRange8,
+    pub device_events: XCBRecordRange8,
+    pub errors: XCBRecordRange8,
+    pub client_started: u8,
+    pub client_died: u8,
+}
+
+#[repr(C)]
+pub struct XCBRecordRangeIterator {
+    pub data: *mut XCBRecordRange,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBRecordElementHeader = u8;
+
+#[repr(C)]
+pub struct XCBRecordElementHeaderIterator {
+    pub data: *mut XCBRecordElementHeader,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBRecordClientSpec = u32;
+
+#[repr(C)]
+pub struct XCBRecordClientSpecIterator {
+    pub data: *mut XCBRecordClientSpec,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBRecordClientInfo {
+    pub client_resource: XCBRecordClientSpec,
+    pub num_ranges: u32,
+}
+
+#[repr(C)]
+pub struct XCBRecordClientInfoIterator {
+    pub data: *mut XCBRecordClientInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRecordBadContextError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+    pub invalid_record: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRecordQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+
+pub type XCBRecordQueryVersionCookie = XCBCookie<XCBRecordQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRecordQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+
+#[repr(C)]
+pub struct XCBRecordCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBRecordContext,
+    pub element_header: XCBRecordElementHeader,
+    pub pad0: [u8; 3],
+    pub num_client_specs: u32,
+    pub num_ranges: u32,
+}
+
+#[repr(C)]
+pub struct XCBRecordRegisterClientsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBRecordContext,
+    pub element_header: XCBRecordElementHeader,
+    pub pad0: [u8; 3],
+    pub num_client_specs: u32,
+    pub num_ranges: u32,
+}
+
+#[repr(C)]
+pub struct XCBRecordUnregisterClientsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBRecordContext,
+    pub num_client_specs: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRecordGetContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBRecordContext,
+}
+
+pub type XCBRecordGetContextCookie = XCBCookie<XCBRecordGetContextReply>;
+
+#[repr(C)]
+pub struct XCBRecordGetContextReply {
+    pub response_type: u8,
+    pub enabled: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub element_header: XCBRecordElementHeader,
+    pub pad0: [u8; 3],
+    pub num_intercepted_clients: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRecordEnableContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBRecordContext,
+}
+
+pub type XCBRecordEnableContextCookie = XCBCookie<XCBRecordEnableContextReply>;
+
+#[repr(C)]
+pub struct XCBRecordEnableContextReply {
+    pub response_type: u8,
+    pub category: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub element_header: XCBRecordElementHeader,
+    pub client_swapped: u8,
+    pub pad0: [u8; 2],
+    pub xid_base: u32,
+    pub server_time: u32,
+    pub rec_sequence_num: u32,
+    pub pad1: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRecordDisableContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBRecordContext,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRecordFreeContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBRecordContext,
+}
+
+#[link(name = "xcb-record")]
+extern "system" {
+    pub static mut xcb_record_id: XCBExtension;
+    pub fn xcb_record_context_next(i: *mut XCBRecordContextIterator);
+    pub fn xcb_record_context_end(i: XCBRecordContextIterator) -> XCBGenericIterator;
+    pub fn xcb_record_range_8_next(i: *mut XCBRecordRange8Iterator);
+    pub fn xcb_record_range_8_end(i: XCBRecordRange8Iterator) -> XCBGenericIterator;
+    pub fn xcb_record_range_16_next(i: *mut XCBRecordRange16Iterator);
+    pub fn xcb_record_range_16_end(i: XCBRecordRange16Iterator) -> XCBGenericIterator;
+    pub fn xcb_record_ext_range_next(i: *mut XCBRecordExtRangeIterator);
+    pub fn xcb_record_ext_range_end(i: XCBRecordExtRangeIterator) -> XCBGenericIterator;
+    pub fn xcb_record_range_next(i: *mut XCBRecordRangeIterator);
+    pub fn xcb_record_range_end(i: XCBRecordRangeIterator) -> XCBGenericIterator;
+    pub fn xcb_record_element_header_next(i: *mut XCBRecordElementHeaderIterator);
+    pub fn xcb_record_element_header_end(i: XCBRecordElementHeaderIterator) -> XCBGenericIterator;
+    pub fn xcb_record_client_spec_next(i: *mut XCBRecordClientSpecIterator);
+    pub fn xcb_record_client_spec_end(i: XCBRecordClientSpecIterator) -> XCBGenericIterator;
+    pub fn xcb_record_client_info_next(i: *mut XCBRecordClientInfoIterator);
+    pub fn xcb_record_client_info_end(i: XCBRecordClientInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_record_client_info_ranges(R: *const XCBRecordClientInfo) -> *mut XCBRecordRange;
+    pub fn xcb_record_client_info_ranges_length(R: *const XCBRecordClientInfo) -> c_int;
+    pub fn xcb_record_client_info_ranges_iterator(
+        R: *const XCBRecordClientInfo,
+    ) -> XCBRecordRangeIterator;
+    pub fn xcb_record_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRecordQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRecordQueryVersionReply;
+    pub fn xcb_record_query_version(
+        connection: *mut XCBConnection,
+        major_version: u16,
+        minor_version: u16,
+    ) -> XCBRecordQueryVersionCookie;
+    pub fn xcb_record_query_version_unchecked(
+        connection: *mut XCBConnection,
+        major_version: u16,
+        minor_version: u16,
+    ) -> XCBRecordQueryVersionCookie;
+    pub fn xcb_record_create_context_checked(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+        element_header: XCBRecordElementHeader,
+        num_client_specs: u32,
+        num_ranges: u32,
+        client_specs: *const XCBRecordClientSpec,
+        ranges: *const XCBRecordRange,
+    ) -> XCBVoidCookie;
+    pub fn xcb_record_create_context(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+        element_header: XCBRecordElementHeader,
+        num_client_specs: u32,
+        num_ranges: u32,
+        client_specs: *const XCBRecordClientSpec,
+        ranges: *const XCBRecordRange,
+    ) -> XCBVoidCookie;
+    pub fn xcb_record_register_clients_checked(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+        element_header: XCBRecordElementHeader,
+        num_client_specs: u32,
+        num_ranges: u32,
+        client_specs: *const XCBRecordClientSpec,
+        ranges: *const XCBRecordRange,
+    ) -> XCBVoidCookie;
+    pub fn xcb_record_register_clients(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+        element_header: XCBRecordElementHeader,
+        num_client_specs: u32,
+        num_ranges: u32,
+        client_specs: *const XCBRecordClientSpec,
+        ranges: *const XCBRecordRange,
+    ) -> XCBVoidCookie;
+    pub fn xcb_record_unregister_clients_checked(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+        num_client_specs: u32,
+        client_specs: *const XCBRecordClientSpec,
+    ) -> XCBVoidCookie;
+    pub fn xcb_record_unregister_clients(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+        num_client_specs: u32,
+        client_specs: *const XCBRecordClientSpec,
+    ) -> XCBVoidCookie;
+    pub fn xcb_record_get_context_intercepted_clients_length(
+        R: *const XCBRecordGetContextReply,
+    ) -> c_int;
+    pub fn xcb_record_get_context_intercepted_clients_iterator(
+        R: *const XCBRecordGetContextReply,
+    ) -> XCBRecordClientInfoIterator;
+    pub fn xcb_record_get_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRecordGetContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRecordGetContextReply;
+    pub fn xcb_record_get_context(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+    ) -> XCBRecordGetContextCookie;
+    pub fn xcb_record_get_context_unchecked(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+    ) -> XCBRecordGetContextCookie;
+    pub fn xcb_record_enable_context_data(R: *const XCBRecordEnableContextReply) -> *mut u8;
+    pub fn xcb_record_enable_context_data_length(R: *const XCBRecordEnableContextReply) -> c_int;
+    pub fn xcb_record_enable_context_data_end(
+        R: *const XCBRecordEnableContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_record_enable_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRecordEnableContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRecordEnableContextReply;
+    pub fn xcb_record_enable_context(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+    ) -> XCBRecordEnableContextCookie;
+    pub fn xcb_record_enable_context_unchecked(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+    ) -> XCBRecordEnableContextCookie;
+    pub fn xcb_record_disable_context_checked(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+    ) -> XCBVoidCookie;
+    pub fn xcb_record_disable_context(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+    ) -> XCBVoidCookie;
+    pub fn xcb_record_free_context_checked(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+    ) -> XCBVoidCookie;
+    pub fn xcb_record_free_context(
+        connection: *mut XCBConnection,
+        context: XCBRecordContext,
+    ) -> XCBVoidCookie;
+}
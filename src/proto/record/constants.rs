@@ -0,0 +1,19 @@
+// Generated by xcbgen from xml/record.xml, do not edit.
+
+pub const XCB_RECORD_MAJOR_VERSION: u32 = 1;
+pub const XCB_RECORD_MINOR_VERSION: u32 = 13;
+pub const XCB_RECORD_H_TYPE_FROM_SERVER_TIME: u32 = 1;
+pub const XCB_RECORD_H_TYPE_FROM_CLIENT_TIME: u32 = 2;
+pub const XCB_RECORD_H_TYPE_FROM_CLIENT_SEQUENCE: u32 = 4;
+pub const XCB_RECORD_CS_CURRENT_CLIENTS: u32 = 1;
+pub const XCB_RECORD_CS_FUTURE_CLIENTS: u32 = 2;
+pub const XCB_RECORD_CS_ALL_CLIENTS: u32 = 3;
+pub const XCB_RECORD_BAD_CONTEXT: u8 = 0;
+pub const XCB_RECORD_QUERY_VERSION: u8 = 0;
+pub const XCB_RECORD_CREATE_CONTEXT: u8 = 1;
+pub const XCB_RECORD_REGISTER_CLIENTS: u8 = 2;
+pub const XCB_RECORD_UNREGISTER_CLIENTS: u8 = 3;
+pub const XCB_RECORD_GET_CONTEXT: u8 = 4;
+pub const XCB_RECORD_ENABLE_CONTEXT: u8 = 5;
+pub const XCB_RECORD_DISABLE_CONTEXT: u8 = 6;
+pub const XCB_RECORD_FREE_CONTEXT: u8 = 7;
@@ -0,0 +1,237 @@
+// Generated by xcbgen from xml/record.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+
+pub type XCBRecordContext = cdef::XCBRecordContext;
+pub type XCBRecordContextIterator = cdef::XCBRecordContextIterator;
+pub type XCBRecordRange8 = cdef::XCBRecordRange8;
+pub type XCBRecordRange8Iterator = cdef::XCBRecordRange8Iterator;
+pub type XCBRecordRange16 = cdef::XCBRecordRange16;
+pub type XCBRecordRange16Iterator = cdef::XCBRecordRange16Iterator;
+pub type XCBRecordExtRange = cdef::XCBRecordExtRange;
+pub type XCBRecordExtRangeIterator = cdef::XCBRecordExtRangeIterator;
+pub type XCBRecordRange = cdef::XCBRecordRange;
+pub type XCBRecordRangeIterator = cdef::XCBRecordRangeIterator;
+pub type XCBRecordElementHeader = cdef::XCBRecordElementHeader;
+pub type XCBRecordElementHeaderIterator = cdef::XCBRecordElementHeaderIterator;
+pub type XCBRecordClientSpec = cdef::XCBRecordClientSpec;
+pub type XCBRecordClientSpecIterator = cdef::XCBRecordClientSpecIterator;
+pub type XCBRecordClientInfo = cdef::XCBRecordClientInfo;
+pub type XCBRecordClientInfoIterator = cdef::XCBRecordClientInfoIterator;
+pub type XCBRecordBadContextError = cdef::XCBRecordBadContextError;
+pub type XCBRecordQueryVersionRequest = cdef::XCBRecordQueryVersionRequest;
+pub type XCBRecordQueryVersionCookie = cdef::XCBRecordQueryVersionCookie;
+pub type XCBRecordQueryVersionReply = cdef::XCBRecordQueryVersionReply;
+pub type XCBRecordCreateContextRequest = cdef::XCBRecordCreateContextRequest;
+pub type XCBRecordRegisterClientsRequest = cdef::XCBRecordRegisterClientsRequest;
+pub type XCBRecordUnregisterClientsRequest = cdef::XCBRecordUnregisterClientsRequest;
+pub type XCBRecordGetContextRequest = cdef::XCBRecordGetContextRequest;
+pub type XCBRecordGetContextCookie = cdef::XCBRecordGetContextCookie;
+pub type XCBRecordGetContextReply = cdef::XCBRecordGetContextReply;
+pub type XCBRecordEnableContextRequest = cdef::XCBRecordEnableContextRequest;
+pub type XCBRecordEnableContextCookie = cdef::XCBRecordEnableContextCookie;
+pub type XCBRecordEnableContextReply = cdef::XCBRecordEnableContextReply;
+pub type XCBRecordDisableContextRequest = cdef::XCBRecordDisableContextRequest;
+pub type XCBRecordFreeContextRequest = cdef::XCBRecordFreeContextRequest;
+
+/// The Record extension, known to the server as `RECORD`.
+pub struct Record;
+
+impl Extension for Record {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_record_id)
+    }
+}
+
+pub fn xcb_record_context_next(iter: &mut XCBRecordContextIterator) {
+    unsafe { cdef::xcb_record_context_next(iter) }
+}
+
+pub fn xcb_record_range_8_next(iter: &mut XCBRecordRange8Iterator) {
+    unsafe { cdef::xcb_record_range_8_next(iter) }
+}
+
+pub fn xcb_record_range_16_next(iter: &mut XCBRecordRange16Iterator) {
+    unsafe { cdef::xcb_record_range_16_next(iter) }
+}
+
+pub fn xcb_record_ext_range_next(iter: &mut XCBRecordExtRangeIterator) {
+    unsafe { cdef::xcb_record_ext_range_next(iter) }
+}
+
+pub fn xcb_record_range_next(iter: &mut XCBRecordRangeIterator) {
+    unsafe { cdef::xcb_record_range_next(iter) }
+}
+
+pub fn xcb_record_element_header_next(iter: &mut XCBRecordElementHeaderIterator) {
+    unsafe { cdef::xcb_record_element_header_next(iter) }
+}
+
+pub fn xcb_record_client_spec_next(iter: &mut XCBRecordClientSpecIterator) {
+    unsafe { cdef::xcb_record_client_spec_next(iter) }
+}
+
+pub fn xcb_record_client_info_next(iter: &mut XCBRecordClientInfoIterator) {
+    unsafe { cdef::xcb_record_client_info_next(iter) }
+}
+
+pub fn xcb_record_client_info_ranges(data: &XCBRecordClientInfo) -> &[XCBRecordRange] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_record_client_info_ranges(data),
+            cdef::xcb_record_client_info_ranges_length(data) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRecordQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_record_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_record_query_version(
+    connection: &XCBConnection,
+    major_version: u16,
+    minor_version: u16,
+) -> XCBRecordQueryVersionCookie {
+    unsafe { cdef::xcb_record_query_version(connection.raw, major_version, minor_version) }
+}
+
+pub fn xcb_record_create_context(
+    connection: &XCBConnection,
+    context: XCBRecordContext,
+    element_header: XCBRecordElementHeader,
+    client_specs: &[XCBRecordClientSpec],
+    ranges: &[XCBRecordRange],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_record_create_context(
+            connection.raw,
+            context,
+            element_header,
+            client_specs.len() as u32,
+            ranges.len() as u32,
+            client_specs.as_ptr(),
+            ranges.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_record_register_clients(
+    connection: &XCBConnection,
+    context: XCBRecordContext,
+    element_header: XCBRecordElementHeader,
+    client_specs: &[XCBRecordClientSpec],
+    ranges: &[XCBRecordRange],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_record_register_clients(
+            connection.raw,
+            context,
+            element_header,
+            client_specs.len() as u32,
+            ranges.len() as u32,
+            client_specs.as_ptr(),
+            ranges.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_record_unregister_clients(
+    connection: &XCBConnection,
+    context: XCBRecordContext,
+    client_specs: &[XCBRecordClientSpec],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_record_unregister_clients(
+            connection.raw,
+            context,
+            client_specs.len() as u32,
+            client_specs.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_record_get_context_intercepted_clients_iterator(
+    reply: &XCBRecordGetContextReply,
+) -> XCBRecordClientInfoIterator {
+    unsafe { cdef::xcb_record_get_context_intercepted_clients_iterator(reply) }
+}
+
+impl Reply for XCBRecordGetContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_record_get_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_record_get_context(
+    connection: &XCBConnection,
+    context: XCBRecordContext,
+) -> XCBRecordGetContextCookie {
+    unsafe { cdef::xcb_record_get_context(connection.raw, context) }
+}
+
+pub fn xcb_record_enable_context_data(reply: &XCBRecordEnableContextReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_record_enable_context_data(reply),
+            cdef::xcb_record_enable_context_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRecordEnableContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_record_enable_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_record_enable_context(
+    connection: &XCBConnection,
+    context: XCBRecordContext,
+) -> XCBRecordEnableContextCookie {
+    unsafe { cdef::xcb_record_enable_context(connection.raw, context) }
+}
+
+pub fn xcb_record_disable_context(
+    connection: &XCBConnection,
+    context: XCBRecordContext,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_record_disable_context(connection.raw, context) }
+}
+
+pub fn xcb_record_free_context(
+    connection: &XCBConnection,
+    context: XCBRecordContext,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_record_free_context(connection.raw, context) }
+}
+
+/// Returns the name of the error whose code, relative to the first error of the extension, is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        0 => Some("BadContext"),
+        _ => None,
+    }
+}
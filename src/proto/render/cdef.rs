@@ -0,0 +1,1357 @@
+// Generated by xcbgen from xml/render.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int, c_void};
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xproto::cdef::{
+    XCBColormap, XCBCursor, XCBDrawable, XCBRectangle, XCBStrIterator, XCBVisualid,
+};
+
+pub type XCBRenderGlyph = u32;
+
+#[repr(C)]
+pub struct XCBRenderGlyphIterator {
+    pub data: *mut XCBRenderGlyph,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBRenderGlyphset = u32;
+
+#[repr(C)]
+pub struct XCBRenderGlyphsetIterator {
+    pub data: *mut XCBRenderGlyphset,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBRenderPicture = u32;
+
+#[repr(C)]
+pub struct XCBRenderPictureIterator {
+    pub data: *mut XCBRenderPicture,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBRenderPictformat = u32;
+
+#[repr(C)]
+pub struct XCBRenderPictformatIterator {
+    pub data: *mut XCBRenderPictformat,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBRenderFixed = i32;
+
+#[repr(C)]
+pub struct XCBRenderFixedIterator {
+    pub data: *mut XCBRenderFixed,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderPictFormatError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderPictureError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderPictOpError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderGlyphSetError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderGlyphError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderDirectformat {
+    pub red_shift: u16,
+    pub red_mask: u16,
+    pub green_shift: u16,
+    pub green_mask: u16,
+    pub blue_shift: u16,
+    pub blue_mask: u16,
+    pub alpha_shift: u16,
+    pub alpha_mask: u16,
+}
+
+#[repr(C)]
+pub struct XCBRenderDirectformatIterator {
+    pub data: *mut XCBRenderDirectformat,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderPictforminfo {
+    pub id: XCBRenderPictformat,
+    pub type_: u8,
+    pub depth: u8,
+    pub pad0: [u8; 2],
+    pub direct: XCBRenderDirectformat,
+    pub colormap: XCBColormap,
+}
+
+#[repr(C)]
+pub struct XCBRenderPictforminfoIterator {
+    pub data: *mut XCBRenderPictforminfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderPictvisual {
+    pub visual: XCBVisualid,
+    pub format: XCBRenderPictformat,
+}
+
+#[repr(C)]
+pub struct XCBRenderPictvisualIterator {
+    pub data: *mut XCBRenderPictvisual,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBRenderPictdepth {
+    pub depth: u8,
+    pub pad0: u8,
+    pub num_visuals: u16,
+    pub pad1: [u8; 4],
+}
+
+#[repr(C)]
+pub struct XCBRenderPictdepthIterator {
+    pub data: *mut XCBRenderPictdepth,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBRenderPictscreen {
+    pub num_depths: u32,
+    pub fallback: XCBRenderPictformat,
+}
+
+#[repr(C)]
+pub struct XCBRenderPictscreenIterator {
+    pub data: *mut XCBRenderPictscreen,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderIndexvalue {
+    pub pixel: u32,
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub alpha: u16,
+}
+
+#[repr(C)]
+pub struct XCBRenderIndexvalueIterator {
+    pub data: *mut XCBRenderIndexvalue,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderColor {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub alpha: u16,
+}
+
+#[repr(C)]
+pub struct XCBRenderColorIterator {
+    pub data: *mut XCBRenderColor,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderPointfix {
+    pub x: XCBRenderFixed,
+    pub y: XCBRenderFixed,
+}
+
+#[repr(C)]
+pub struct XCBRenderPointfixIterator {
+    pub data: *mut XCBRenderPointfix,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderLinefix {
+    pub p1: XCBRenderPointfix,
+    pub p2: XCBRenderPointfix,
+}
+
+#[repr(C)]
+pub struct XCBRenderLinefixIterator {
+    pub data: *mut XCBRenderLinefix,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderTriangle {
+    pub p1: XCBRenderPointfix,
+    pub p2: XCBRenderPointfix,
+    pub p3: XCBRenderPointfix,
+}
+
+#[repr(C)]
+pub struct XCBRenderTriangleIterator {
+    pub data: *mut XCBRenderTriangle,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderTrapezoid {
+    pub top: XCBRenderFixed,
+    pub bottom: XCBRenderFixed,
+    pub left: XCBRenderLinefix,
+    pub right: XCBRenderLinefix,
+}
+
+#[repr(C)]
+pub struct XCBRenderTrapezoidIterator {
+    pub data: *mut XCBRenderTrapezoid,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderGlyphinfo {
+    pub width: u16,
+    pub height: u16,
+    pub x: i16,
+    pub y: i16,
+    pub x_off: i16,
+    pub y_off: i16,
+}
+
+#[repr(C)]
+pub struct XCBRenderGlyphinfoIterator {
+    pub data: *mut XCBRenderGlyphinfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub client_major_version: u32,
+    pub client_minor_version: u32,
+}
+
+pub type XCBRenderQueryVersionCookie = XCBCookie<XCBRenderQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderQueryPictFormatsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBRenderQueryPictFormatsCookie = XCBCookie<XCBRenderQueryPictFormatsReply>;
+
+#[repr(C)]
+pub struct XCBRenderQueryPictFormatsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_formats: u32,
+    pub num_screens: u32,
+    pub num_depths: u32,
+    pub num_visuals: u32,
+    pub num_subpixel: u32,
+    pub pad1: [u8; 4],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderQueryPictIndexValuesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub format: XCBRenderPictformat,
+}
+
+pub type XCBRenderQueryPictIndexValuesCookie = XCBCookie<XCBRenderQueryPictIndexValuesReply>;
+
+#[repr(C)]
+pub struct XCBRenderQueryPictIndexValuesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_values: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBRenderCreatePictureRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub pid: XCBRenderPicture,
+    pub drawable: XCBDrawable,
+    pub format: XCBRenderPictformat,
+    pub value_mask: u32,
+}
+
+#[repr(C)]
+pub struct XCBRenderChangePictureRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub picture: XCBRenderPicture,
+    pub value_mask: u32,
+}
+
+#[repr(C)]
+pub struct XCBRenderSetPictureClipRectanglesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub picture: XCBRenderPicture,
+    pub clip_x_origin: i16,
+    pub clip_y_origin: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderFreePictureRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub picture: XCBRenderPicture,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderCompositeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub op: u8,
+    pub pad0: [u8; 3],
+    pub src: XCBRenderPicture,
+    pub mask: XCBRenderPicture,
+    pub dst: XCBRenderPicture,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub mask_x: i16,
+    pub mask_y: i16,
+    pub dst_x: i16,
+    pub dst_y: i16,
+    pub width: u16,
+    pub height: u16,
+}
+
+#[repr(C)]
+pub struct XCBRenderTrapezoidsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub op: u8,
+    pub pad0: [u8; 3],
+    pub src: XCBRenderPicture,
+    pub dst: XCBRenderPicture,
+    pub mask_format: XCBRenderPictformat,
+    pub src_x: i16,
+    pub src_y: i16,
+}
+
+#[repr(C)]
+pub struct XCBRenderTrianglesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub op: u8,
+    pub pad0: [u8; 3],
+    pub src: XCBRenderPicture,
+    pub dst: XCBRenderPicture,
+    pub mask_format: XCBRenderPictformat,
+    pub src_x: i16,
+    pub src_y: i16,
+}
+
+#[repr(C)]
+pub struct XCBRenderTriStripRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub op: u8,
+    pub pad0: [u8; 3],
+    pub src: XCBRenderPicture,
+    pub dst: XCBRenderPicture,
+    pub mask_format: XCBRenderPictformat,
+    pub src_x: i16,
+    pub src_y: i16,
+}
+
+#[repr(C)]
+pub struct XCBRenderTriFanRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub op: u8,
+    pub pad0: [u8; 3],
+    pub src: XCBRenderPicture,
+    pub dst: XCBRenderPicture,
+    pub mask_format: XCBRenderPictformat,
+    pub src_x: i16,
+    pub src_y: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderCreateGlyphSetRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub gsid: XCBRenderGlyphset,
+    pub format: XCBRenderPictformat,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderReferenceGlyphSetRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub gsid: XCBRenderGlyphset,
+    pub existing: XCBRenderGlyphset,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderFreeGlyphSetRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub glyphset: XCBRenderGlyphset,
+}
+
+#[repr(C)]
+pub struct XCBRenderAddGlyphsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub glyphset: XCBRenderGlyphset,
+    pub glyphs_len: u32,
+}
+
+#[repr(C)]
+pub struct XCBRenderFreeGlyphsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub glyphset: XCBRenderGlyphset,
+}
+
+#[repr(C)]
+pub struct XCBRenderCompositeGlyphs8Request {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub op: u8,
+    pub pad0: [u8; 3],
+    pub src: XCBRenderPicture,
+    pub dst: XCBRenderPicture,
+    pub mask_format: XCBRenderPictformat,
+    pub glyphset: XCBRenderGlyphset,
+    pub src_x: i16,
+    pub src_y: i16,
+}
+
+#[repr(C)]
+pub struct XCBRenderCompositeGlyphs16Request {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub op: u8,
+    pub pad0: [u8; 3],
+    pub src: XCBRenderPicture,
+    pub dst: XCBRenderPicture,
+    pub mask_format: XCBRenderPictformat,
+    pub glyphset: XCBRenderGlyphset,
+    pub src_x: i16,
+    pub src_y: i16,
+}
+
+#[repr(C)]
+pub struct XCBRenderCompositeGlyphs32Request {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub op: u8,
+    pub pad0: [u8; 3],
+    pub src: XCBRenderPicture,
+    pub dst: XCBRenderPicture,
+    pub mask_format: XCBRenderPictformat,
+    pub glyphset: XCBRenderGlyphset,
+    pub src_x: i16,
+    pub src_y: i16,
+}
+
+#[repr(C)]
+pub struct XCBRenderFillRectanglesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub op: u8,
+    pub pad0: [u8; 3],
+    pub dst: XCBRenderPicture,
+    pub color: XCBRenderColor,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderCreateCursorRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub cid: XCBCursor,
+    pub source: XCBRenderPicture,
+    pub x: u16,
+    pub y: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderTransform {
+    pub matrix11: XCBRenderFixed,
+    pub matrix12: XCBRenderFixed,
+    pub matrix13: XCBRenderFixed,
+    pub matrix21: XCBRenderFixed,
+    pub matrix22: XCBRenderFixed,
+    pub matrix23: XCBRenderFixed,
+    pub matrix31: XCBRenderFixed,
+    pub matrix32: XCBRenderFixed,
+    pub matrix33: XCBRenderFixed,
+}
+
+#[repr(C)]
+pub struct XCBRenderTransformIterator {
+    pub data: *mut XCBRenderTransform,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderSetPictureTransformRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub picture: XCBRenderPicture,
+    pub transform: XCBRenderTransform,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderQueryFiltersRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+}
+
+pub type XCBRenderQueryFiltersCookie = XCBCookie<XCBRenderQueryFiltersReply>;
+
+#[repr(C)]
+pub struct XCBRenderQueryFiltersReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_aliases: u32,
+    pub num_filters: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+pub struct XCBRenderSetPictureFilterRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub picture: XCBRenderPicture,
+    pub filter_len: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderAnimcursorelt {
+    pub cursor: XCBCursor,
+    pub delay: u32,
+}
+
+#[repr(C)]
+pub struct XCBRenderAnimcursoreltIterator {
+    pub data: *mut XCBRenderAnimcursorelt,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBRenderCreateAnimCursorRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub cid: XCBCursor,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderSpanfix {
+    pub l: XCBRenderFixed,
+    pub r: XCBRenderFixed,
+    pub y: XCBRenderFixed,
+}
+
+#[repr(C)]
+pub struct XCBRenderSpanfixIterator {
+    pub data: *mut XCBRenderSpanfix,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderTrap {
+    pub top: XCBRenderSpanfix,
+    pub bot: XCBRenderSpanfix,
+}
+
+#[repr(C)]
+pub struct XCBRenderTrapIterator {
+    pub data: *mut XCBRenderTrap,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBRenderAddTrapsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub picture: XCBRenderPicture,
+    pub x_off: i16,
+    pub y_off: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRenderCreateSolidFillRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub picture: XCBRenderPicture,
+    pub color: XCBRenderColor,
+}
+
+#[repr(C)]
+pub struct XCBRenderCreateLinearGradientRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub picture: XCBRenderPicture,
+    pub p1: XCBRenderPointfix,
+    pub p2: XCBRenderPointfix,
+    pub num_stops: u32,
+}
+
+#[repr(C)]
+pub struct XCBRenderCreateRadialGradientRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub picture: XCBRenderPicture,
+    pub inner: XCBRenderPointfix,
+    pub outer: XCBRenderPointfix,
+    pub inner_radius: XCBRenderFixed,
+    pub outer_radius: XCBRenderFixed,
+    pub num_stops: u32,
+}
+
+#[repr(C)]
+pub struct XCBRenderCreateConicalGradientRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub picture: XCBRenderPicture,
+    pub center: XCBRenderPointfix,
+    pub angle: XCBRenderFixed,
+    pub num_stops: u32,
+}
+
+#[link(name = "xcb-render")]
+extern "system" {
+    pub static mut xcb_render_id: XCBExtension;
+    pub fn xcb_render_glyph_next(i: *mut XCBRenderGlyphIterator);
+    pub fn xcb_render_glyph_end(i: XCBRenderGlyphIterator) -> XCBGenericIterator;
+    pub fn xcb_render_glyphset_next(i: *mut XCBRenderGlyphsetIterator);
+    pub fn xcb_render_glyphset_end(i: XCBRenderGlyphsetIterator) -> XCBGenericIterator;
+    pub fn xcb_render_picture_next(i: *mut XCBRenderPictureIterator);
+    pub fn xcb_render_picture_end(i: XCBRenderPictureIterator) -> XCBGenericIterator;
+    pub fn xcb_render_pictformat_next(i: *mut XCBRenderPictformatIterator);
+    pub fn xcb_render_pictformat_end(i: XCBRenderPictformatIterator) -> XCBGenericIterator;
+    pub fn xcb_render_fixed_next(i: *mut XCBRenderFixedIterator);
+    pub fn xcb_render_fixed_end(i: XCBRenderFixedIterator) -> XCBGenericIterator;
+    pub fn xcb_render_directformat_next(i: *mut XCBRenderDirectformatIterator);
+    pub fn xcb_render_directformat_end(i: XCBRenderDirectformatIterator) -> XCBGenericIterator;
+    pub fn xcb_render_pictforminfo_next(i: *mut XCBRenderPictforminfoIterator);
+    pub fn xcb_render_pictforminfo_end(i: XCBRenderPictforminfoIterator) -> XCBGenericIterator;
+    pub fn xcb_render_pictvisual_next(i: *mut XCBRenderPictvisualIterator);
+    pub fn xcb_render_pictvisual_end(i: XCBRenderPictvisualIterator) -> XCBGenericIterator;
+    pub fn xcb_render_pictdepth_next(i: *mut XCBRenderPictdepthIterator);
+    pub fn xcb_render_pictdepth_end(i: XCBRenderPictdepthIterator) -> XCBGenericIterator;
+    pub fn xcb_render_pictdepth_visuals(R: *const XCBRenderPictdepth) -> *mut XCBRenderPictvisual;
+    pub fn xcb_render_pictdepth_visuals_length(R: *const XCBRenderPictdepth) -> c_int;
+    pub fn xcb_render_pictdepth_visuals_iterator(
+        R: *const XCBRenderPictdepth,
+    ) -> XCBRenderPictvisualIterator;
+    pub fn xcb_render_pictscreen_next(i: *mut XCBRenderPictscreenIterator);
+    pub fn xcb_render_pictscreen_end(i: XCBRenderPictscreenIterator) -> XCBGenericIterator;
+    pub fn xcb_render_pictscreen_depths_length(R: *const XCBRenderPictscreen) -> c_int;
+    pub fn xcb_render_pictscreen_depths_iterator(
+        R: *const XCBRenderPictscreen,
+    ) -> XCBRenderPictdepthIterator;
+    pub fn xcb_render_indexvalue_next(i: *mut XCBRenderIndexvalueIterator);
+    pub fn xcb_render_indexvalue_end(i: XCBRenderIndexvalueIterator) -> XCBGenericIterator;
+    pub fn xcb_render_color_next(i: *mut XCBRenderColorIterator);
+    pub fn xcb_render_color_end(i: XCBRenderColorIterator) -> XCBGenericIterator;
+    pub fn xcb_render_pointfix_next(i: *mut XCBRenderPointfixIterator);
+    pub fn xcb_render_pointfix_end(i: XCBRenderPointfixIterator) -> XCBGenericIterator;
+    pub fn xcb_render_linefix_next(i: *mut XCBRenderLinefixIterator);
+    pub fn xcb_render_linefix_end(i: XCBRenderLinefixIterator) -> XCBGenericIterator;
+    pub fn xcb_render_triangle_next(i: *mut XCBRenderTriangleIterator);
+    pub fn xcb_render_triangle_end(i: XCBRenderTriangleIterator) -> XCBGenericIterator;
+    pub fn xcb_render_trapezoid_next(i: *mut XCBRenderTrapezoidIterator);
+    pub fn xcb_render_trapezoid_end(i: XCBRenderTrapezoidIterator) -> XCBGenericIterator;
+    pub fn xcb_render_glyphinfo_next(i: *mut XCBRenderGlyphinfoIterator);
+    pub fn xcb_render_glyphinfo_end(i: XCBRenderGlyphinfoIterator) -> XCBGenericIterator;
+    pub fn xcb_render_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRenderQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRenderQueryVersionReply;
+    pub fn xcb_render_query_version(
+        connection: *mut XCBConnection,
+        client_major_version: u32,
+        client_minor_version: u32,
+    ) -> XCBRenderQueryVersionCookie;
+    pub fn xcb_render_query_version_unchecked(
+        connection: *mut XCBConnection,
+        client_major_version: u32,
+        client_minor_version: u32,
+    ) -> XCBRenderQueryVersionCookie;
+    pub fn xcb_render_query_pict_formats_formats(
+        R: *const XCBRenderQueryPictFormatsReply,
+    ) -> *mut XCBRenderPictforminfo;
+    pub fn xcb_render_query_pict_formats_formats_length(
+        R: *const XCBRenderQueryPictFormatsReply,
+    ) -> c_int;
+    pub fn xcb_render_query_pict_formats_formats_iterator(
+        R: *const XCBRenderQueryPictFormatsReply,
+    ) -> XCBRenderPictforminfoIterator;
+    pub fn xcb_render_query_pict_formats_screens_length(
+        R: *const XCBRenderQueryPictFormatsReply,
+    ) -> c_int;
+    pub fn xcb_render_query_pict_formats_screens_iterator(
+        R: *const XCBRenderQueryPictFormatsReply,
+    ) -> XCBRenderPictscreenIterator;
+    pub fn xcb_render_query_pict_formats_subpixels(
+        R: *const XCBRenderQueryPictFormatsReply,
+    ) -> *mut u32;
+    pub fn xcb_render_query_pict_formats_subpixels_length(
+        R: *const XCBRenderQueryPictFormatsReply,
+    ) -> c_int;
+    pub fn xcb_render_query_pict_formats_subpixels_end(
+        R: *const XCBRenderQueryPictFormatsReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_render_query_pict_formats_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRenderQueryPictFormatsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRenderQueryPictFormatsReply;
+    pub fn xcb_render_query_pict_formats(
+        connection: *mut XCBConnection,
+    ) -> XCBRenderQueryPictFormatsCookie;
+    pub fn xcb_render_query_pict_formats_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBRenderQueryPictFormatsCookie;
+    pub fn xcb_render_query_pict_index_values_values(
+        R: *const XCBRenderQueryPictIndexValuesReply,
+    ) -> *mut XCBRenderIndexvalue;
+    pub fn xcb_render_query_pict_index_values_values_length(
+        R: *const XCBRenderQueryPictIndexValuesReply,
+    ) -> c_int;
+    pub fn xcb_render_query_pict_index_values_values_iterator(
+        R: *const XCBRenderQueryPictIndexValuesReply,
+    ) -> XCBRenderIndexvalueIterator;
+    pub fn xcb_render_query_pict_index_values_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRenderQueryPictIndexValuesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRenderQueryPictIndexValuesReply;
+    pub fn xcb_render_query_pict_index_values(
+        connection: *mut XCBConnection,
+        format: XCBRenderPictformat,
+    ) -> XCBRenderQueryPictIndexValuesCookie;
+    pub fn xcb_render_query_pict_index_values_unchecked(
+        connection: *mut XCBConnection,
+        format: XCBRenderPictformat,
+    ) -> XCBRenderQueryPictIndexValuesCookie;
+    pub fn xcb_render_create_picture_checked(
+        connection: *mut XCBConnection,
+        pid: XCBRenderPicture,
+        drawable: XCBDrawable,
+        format: XCBRenderPictformat,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_picture(
+        connection: *mut XCBConnection,
+        pid: XCBRenderPicture,
+        drawable: XCBDrawable,
+        format: XCBRenderPictformat,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_change_picture_checked(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_change_picture(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_set_picture_clip_rectangles_checked(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        clip_x_origin: i16,
+        clip_y_origin: i16,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_set_picture_clip_rectangles(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        clip_x_origin: i16,
+        clip_y_origin: i16,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_free_picture_checked(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_free_picture(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_composite_checked(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        mask: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        src_x: i16,
+        src_y: i16,
+        mask_x: i16,
+        mask_y: i16,
+        dst_x: i16,
+        dst_y: i16,
+        width: u16,
+        height: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_composite(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        mask: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        src_x: i16,
+        src_y: i16,
+        mask_x: i16,
+        mask_y: i16,
+        dst_x: i16,
+        dst_y: i16,
+        width: u16,
+        height: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_trapezoids_checked(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        src_x: i16,
+        src_y: i16,
+        traps_len: u32,
+        traps: *const XCBRenderTrapezoid,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_trapezoids(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        src_x: i16,
+        src_y: i16,
+        traps_len: u32,
+        traps: *const XCBRenderTrapezoid,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_triangles_checked(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        src_x: i16,
+        src_y: i16,
+        triangles_len: u32,
+        triangles: *const XCBRenderTriangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_triangles(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        src_x: i16,
+        src_y: i16,
+        triangles_len: u32,
+        triangles: *const XCBRenderTriangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_tri_strip_checked(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        src_x: i16,
+        src_y: i16,
+        points_len: u32,
+        points: *const XCBRenderPointfix,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_tri_strip(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        src_x: i16,
+        src_y: i16,
+        points_len: u32,
+        points: *const XCBRenderPointfix,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_tri_fan_checked(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        src_x: i16,
+        src_y: i16,
+        points_len: u32,
+        points: *const XCBRenderPointfix,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_tri_fan(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        src_x: i16,
+        src_y: i16,
+        points_len: u32,
+        points: *const XCBRenderPointfix,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_glyph_set_checked(
+        connection: *mut XCBConnection,
+        gsid: XCBRenderGlyphset,
+        format: XCBRenderPictformat,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_glyph_set(
+        connection: *mut XCBConnection,
+        gsid: XCBRenderGlyphset,
+        format: XCBRenderPictformat,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_reference_glyph_set_checked(
+        connection: *mut XCBConnection,
+        gsid: XCBRenderGlyphset,
+        existing: XCBRenderGlyphset,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_reference_glyph_set(
+        connection: *mut XCBConnection,
+        gsid: XCBRenderGlyphset,
+        existing: XCBRenderGlyphset,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_free_glyph_set_checked(
+        connection: *mut XCBConnection,
+        glyphset: XCBRenderGlyphset,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_free_glyph_set(
+        connection: *mut XCBConnection,
+        glyphset: XCBRenderGlyphset,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_add_glyphs_checked(
+        connection: *mut XCBConnection,
+        glyphset: XCBRenderGlyphset,
+        glyphs_len: u32,
+        glyphids: *const u32,
+        glyphs: *const XCBRenderGlyphinfo,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_add_glyphs(
+        connection: *mut XCBConnection,
+        glyphset: XCBRenderGlyphset,
+        glyphs_len: u32,
+        glyphids: *const u32,
+        glyphs: *const XCBRenderGlyphinfo,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_free_glyphs_checked(
+        connection: *mut XCBConnection,
+        glyphset: XCBRenderGlyphset,
+        glyphs_len: u32,
+        glyphs: *const XCBRenderGlyph,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_free_glyphs(
+        connection: *mut XCBConnection,
+        glyphset: XCBRenderGlyphset,
+        glyphs_len: u32,
+        glyphs: *const XCBRenderGlyph,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_composite_glyphs_8_checked(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        glyphset: XCBRenderGlyphset,
+        src_x: i16,
+        src_y: i16,
+        glyphcmds_len: u32,
+        glyphcmds: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_composite_glyphs_8(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        glyphset: XCBRenderGlyphset,
+        src_x: i16,
+        src_y: i16,
+        glyphcmds_len: u32,
+        glyphcmds: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_composite_glyphs_16_checked(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        glyphset: XCBRenderGlyphset,
+        src_x: i16,
+        src_y: i16,
+        glyphcmds_len: u32,
+        glyphcmds: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_composite_glyphs_16(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        glyphset: XCBRenderGlyphset,
+        src_x: i16,
+        src_y: i16,
+        glyphcmds_len: u32,
+        glyphcmds: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_composite_glyphs_32_checked(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        glyphset: XCBRenderGlyphset,
+        src_x: i16,
+        src_y: i16,
+        glyphcmds_len: u32,
+        glyphcmds: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_composite_glyphs_32(
+        connection: *mut XCBConnection,
+        op: u8,
+        src: XCBRenderPicture,
+        dst: XCBRenderPicture,
+        mask_format: XCBRenderPictformat,
+        glyphset: XCBRenderGlyphset,
+        src_x: i16,
+        src_y: i16,
+        glyphcmds_len: u32,
+        glyphcmds: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_fill_rectangles_checked(
+        connection: *mut XCBConnection,
+        op: u8,
+        dst: XCBRenderPicture,
+        color: XCBRenderColor,
+        rects_len: u32,
+        rects: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_fill_rectangles(
+        connection: *mut XCBConnection,
+        op: u8,
+        dst: XCBRenderPicture,
+        color: XCBRenderColor,
+        rects_len: u32,
+        rects: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_cursor_checked(
+        connection: *mut XCBConnection,
+        cid: XCBCursor,
+        source: XCBRenderPicture,
+        x: u16,
+        y: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_cursor(
+        connection: *mut XCBConnection,
+        cid: XCBCursor,
+        source: XCBRenderPicture,
+        x: u16,
+        y: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_transform_next(i: *mut XCBRenderTransformIterator);
+    pub fn xcb_render_transform_end(i: XCBRenderTransformIterator) -> XCBGenericIterator;
+    pub fn xcb_render_set_picture_transform_checked(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        transform: XCBRenderTransform,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_set_picture_transform(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        transform: XCBRenderTransform,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_query_filters_aliases(R: *const XCBRenderQueryFiltersReply) -> *mut u16;
+    pub fn xcb_render_query_filters_aliases_length(R: *const XCBRenderQueryFiltersReply) -> c_int;
+    pub fn xcb_render_query_filters_aliases_end(
+        R: *const XCBRenderQueryFiltersReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_render_query_filters_filters_length(R: *const XCBRenderQueryFiltersReply) -> c_int;
+    pub fn xcb_render_query_filters_filters_iterator(
+        R: *const XCBRenderQueryFiltersReply,
+    ) -> XCBStrIterator;
+    pub fn xcb_render_query_filters_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBRenderQueryFiltersCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBRenderQueryFiltersReply;
+    pub fn xcb_render_query_filters(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBRenderQueryFiltersCookie;
+    pub fn xcb_render_query_filters_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBRenderQueryFiltersCookie;
+    pub fn xcb_render_set_picture_filter_checked(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        filter_len: u16,
+        filter: *const c_char,
+        values_len: u32,
+        values: *const XCBRenderFixed,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_set_picture_filter(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        filter_len: u16,
+        filter: *const c_char,
+        values_len: u32,
+        values: *const XCBRenderFixed,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_animcursorelt_next(i: *mut XCBRenderAnimcursoreltIterator);
+    pub fn xcb_render_animcursorelt_end(i: XCBRenderAnimcursoreltIterator) -> XCBGenericIterator;
+    pub fn xcb_render_create_anim_cursor_checked(
+        connection: *mut XCBConnection,
+        cid: XCBCursor,
+        cursors_len: u32,
+        cursors: *const XCBRenderAnimcursorelt,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_anim_cursor(
+        connection: *mut XCBConnection,
+        cid: XCBCursor,
+        cursors_len: u32,
+        cursors: *const XCBRenderAnimcursorelt,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_spanfix_next(i: *mut XCBRenderSpanfixIterator);
+    pub fn xcb_render_spanfix_end(i: XCBRenderSpanfixIterator) -> XCBGenericIterator;
+    pub fn xcb_render_trap_next(i: *mut XCBRenderTrapIterator);
+    pub fn xcb_render_trap_end(i: XCBRenderTrapIterator) -> XCBGenericIterator;
+    pub fn xcb_render_add_traps_checked(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        x_off: i16,
+        y_off: i16,
+        traps_len: u32,
+        traps: *const XCBRenderTrap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_add_traps(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        x_off: i16,
+        y_off: i16,
+        traps_len: u32,
+        traps: *const XCBRenderTrap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_solid_fill_checked(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        color: XCBRenderColor,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_solid_fill(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        color: XCBRenderColor,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_linear_gradient_checked(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        p1: XCBRenderPointfix,
+        p2: XCBRenderPointfix,
+        num_stops: u32,
+        stops: *const XCBRenderFixed,
+        colors: *const XCBRenderColor,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_linear_gradient(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        p1: XCBRenderPointfix,
+        p2: XCBRenderPointfix,
+        num_stops: u32,
+        stops: *const XCBRenderFixed,
+        colors: *const XCBRenderColor,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_radial_gradient_checked(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        inner: XCBRenderPointfix,
+        outer: XCBRenderPointfix,
+        inner_radius: XCBRenderFixed,
+        outer_radius: XCBRenderFixed,
+        num_stops: u32,
+        stops: *const XCBRenderFixed,
+        colors: *const XCBRenderColor,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_radial_gradient(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        inner: XCBRenderPointfix,
+        outer: XCBRenderPointfix,
+        inner_radius: XCBRenderFixed,
+        outer_radius: XCBRenderFixed,
+        num_stops: u32,
+        stops: *const XCBRenderFixed,
+        colors: *const XCBRenderColor,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_conical_gradient_checked(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        center: XCBRenderPointfix,
+        angle: XCBRenderFixed,
+        num_stops: u32,
+        stops: *const XCBRenderFixed,
+        colors: *const XCBRenderColor,
+    ) -> XCBVoidCookie;
+    pub fn xcb_render_create_conical_gradient(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        center: XCBRenderPointfix,
+        angle: XCBRenderFixed,
+        num_stops: u32,
+        stops: *const XCBRenderFixed,
+        colors: *const XCBRenderColor,
+    ) -> XCBVoidCookie;
+}
@@ -0,0 +1,123 @@
+// Generated by xcbgen from xml/render.xml, do not edit.
+
+pub const XCB_RENDER_MAJOR_VERSION: u32 = 0;
+pub const XCB_RENDER_MINOR_VERSION: u32 = 11;
+pub const XCB_RENDER_PICT_TYPE_INDEXED: u8 = 0;
+pub const XCB_RENDER_PICT_TYPE_DIRECT: u8 = 1;
+pub const XCB_RENDER_PICTURE_NONE: u32 = 0;
+pub const XCB_RENDER_PICT_OP_CLEAR: u8 = 0;
+pub const XCB_RENDER_PICT_OP_SRC: u8 = 1;
+pub const XCB_RENDER_PICT_OP_DST: u8 = 2;
+pub const XCB_RENDER_PICT_OP_OVER: u8 = 3;
+pub const XCB_RENDER_PICT_OP_OVER_REVERSE: u8 = 4;
+pub const XCB_RENDER_PICT_OP_IN: u8 = 5;
+pub const XCB_RENDER_PICT_OP_IN_REVERSE: u8 = 6;
+pub const XCB_RENDER_PICT_OP_OUT: u8 = 7;
+pub const XCB_RENDER_PICT_OP_OUT_REVERSE: u8 = 8;
+pub const XCB_RENDER_PICT_OP_ATOP: u8 = 9;
+pub const XCB_RENDER_PICT_OP_ATOP_REVERSE: u8 = 10;
+pub const XCB_RENDER_PICT_OP_XOR: u8 = 11;
+pub const XCB_RENDER_PICT_OP_ADD: u8 = 12;
+pub const XCB_RENDER_PICT_OP_SATURATE: u8 = 13;
+pub const XCB_RENDER_PICT_OP_DISJOINT_CLEAR: u8 = 16;
+pub const XCB_RENDER_PICT_OP_DISJOINT_SRC: u8 = 17;
+pub const XCB_RENDER_PICT_OP_DISJOINT_DST: u8 = 18;
+pub const XCB_RENDER_PICT_OP_DISJOINT_OVER: u8 = 19;
+pub const XCB_RENDER_PICT_OP_DISJOINT_OVER_REVERSE: u8 = 20;
+pub const XCB_RENDER_PICT_OP_DISJOINT_IN: u8 = 21;
+pub const XCB_RENDER_PICT_OP_DISJOINT_IN_REVERSE: u8 = 22;
+pub const XCB_RENDER_PICT_OP_DISJOINT_OUT: u8 = 23;
+pub const XCB_RENDER_PICT_OP_DISJOINT_OUT_REVERSE: u8 = 24;
+pub const XCB_RENDER_PICT_OP_DISJOINT_ATOP: u8 = 25;
+pub const XCB_RENDER_PICT_OP_DISJOINT_ATOP_REVERSE: u8 = 26;
+pub const XCB_RENDER_PICT_OP_DISJOINT_XOR: u8 = 27;
+pub const XCB_RENDER_PICT_OP_CONJOINT_CLEAR: u8 = 32;
+pub const XCB_RENDER_PICT_OP_CONJOINT_SRC: u8 = 33;
+pub const XCB_RENDER_PICT_OP_CONJOINT_DST: u8 = 34;
+pub const XCB_RENDER_PICT_OP_CONJOINT_OVER: u8 = 35;
+pub const XCB_RENDER_PICT_OP_CONJOINT_OVER_REVERSE: u8 = 36;
+pub const XCB_RENDER_PICT_OP_CONJOINT_IN: u8 = 37;
+pub const XCB_RENDER_PICT_OP_CONJOINT_IN_REVERSE: u8 = 38;
+pub const XCB_RENDER_PICT_OP_CONJOINT_OUT: u8 = 39;
+pub const XCB_RENDER_PICT_OP_CONJOINT_OUT_REVERSE: u8 = 40;
+pub const XCB_RENDER_PICT_OP_CONJOINT_ATOP: u8 = 41;
+pub const XCB_RENDER_PICT_OP_CONJOINT_ATOP_REVERSE: u8 = 42;
+pub const XCB_RENDER_PICT_OP_CONJOINT_XOR: u8 = 43;
+pub const XCB_RENDER_PICT_OP_MULTIPLY: u8 = 48;
+pub const XCB_RENDER_PICT_OP_SCREEN: u8 = 49;
+pub const XCB_RENDER_PICT_OP_OVERLAY: u8 = 50;
+pub const XCB_RENDER_PICT_OP_DARKEN: u8 = 51;
+pub const XCB_RENDER_PICT_OP_LIGHTEN: u8 = 52;
+pub const XCB_RENDER_PICT_OP_COLOR_DODGE: u8 = 53;
+pub const XCB_RENDER_PICT_OP_COLOR_BURN: u8 = 54;
+pub const XCB_RENDER_PICT_OP_HARD_LIGHT: u8 = 55;
+pub const XCB_RENDER_PICT_OP_SOFT_LIGHT: u8 = 56;
+pub const XCB_RENDER_PICT_OP_DIFFERENCE: u8 = 57;
+pub const XCB_RENDER_PICT_OP_EXCLUSION: u8 = 58;
+pub const XCB_RENDER_PICT_OP_HSL_HUE: u8 = 59;
+pub const XCB_RENDER_PICT_OP_HSL_SATURATION: u8 = 60;
+pub const XCB_RENDER_PICT_OP_HSL_COLOR: u8 = 61;
+pub const XCB_RENDER_PICT_OP_HSL_LUMINOSITY: u8 = 62;
+pub const XCB_RENDER_POLY_EDGE_SHARP: u32 = 0;
+pub const XCB_RENDER_POLY_EDGE_SMOOTH: u32 = 1;
+pub const XCB_RENDER_POLY_MODE_PRECISE: u32 = 0;
+pub const XCB_RENDER_POLY_MODE_IMPRECISE: u32 = 1;
+pub const XCB_RENDER_CP_REPEAT: u32 = 1;
+pub const XCB_RENDER_CP_ALPHA_MAP: u32 = 2;
+pub const XCB_RENDER_CP_ALPHA_X_ORIGIN: u32 = 4;
+pub const XCB_RENDER_CP_ALPHA_Y_ORIGIN: u32 = 8;
+pub const XCB_RENDER_CP_CLIP_X_ORIGIN: u32 = 16;
+pub const XCB_RENDER_CP_CLIP_Y_ORIGIN: u32 = 32;
+pub const XCB_RENDER_CP_CLIP_MASK: u32 = 64;
+pub const XCB_RENDER_CP_GRAPHICS_EXPOSURE: u32 = 128;
+pub const XCB_RENDER_CP_SUBWINDOW_MODE: u32 = 256;
+pub const XCB_RENDER_CP_POLY_EDGE: u32 = 512;
+pub const XCB_RENDER_CP_POLY_MODE: u32 = 1024;
+pub const XCB_RENDER_CP_DITHER: u32 = 2048;
+pub const XCB_RENDER_CP_COMPONENT_ALPHA: u32 = 4096;
+pub const XCB_RENDER_SUB_PIXEL_UNKNOWN: u16 = 0;
+pub const XCB_RENDER_SUB_PIXEL_HORIZONTAL_RGB: u16 = 1;
+pub const XCB_RENDER_SUB_PIXEL_HORIZONTAL_BGR: u16 = 2;
+pub const XCB_RENDER_SUB_PIXEL_VERTICAL_RGB: u16 = 3;
+pub const XCB_RENDER_SUB_PIXEL_VERTICAL_BGR: u16 = 4;
+pub const XCB_RENDER_SUB_PIXEL_NONE: u16 = 5;
+pub const XCB_RENDER_REPEAT_NONE: u32 = 0;
+pub const XCB_RENDER_REPEAT_NORMAL: u32 = 1;
+pub const XCB_RENDER_REPEAT_PAD: u32 = 2;
+pub const XCB_RENDER_REPEAT_REFLECT: u32 = 3;
+pub const XCB_RENDER_PICT_FORMAT: u8 = 0;
+pub const XCB_RENDER_PICTURE: u8 = 1;
+pub const XCB_RENDER_PICT_OP: u8 = 2;
+pub const XCB_RENDER_GLYPH_SET: u8 = 3;
+pub const XCB_RENDER_GLYPH: u8 = 4;
+pub const XCB_RENDER_QUERY_VERSION: u8 = 0;
+pub const XCB_RENDER_QUERY_PICT_FORMATS: u8 = 1;
+pub const XCB_RENDER_QUERY_PICT_INDEX_VALUES: u8 = 2;
+pub const XCB_RENDER_CREATE_PICTURE: u8 = 4;
+pub const XCB_RENDER_CHANGE_PICTURE: u8 = 5;
+pub const XCB_RENDER_SET_PICTURE_CLIP_RECTANGLES: u8 = 6;
+pub const XCB_RENDER_FREE_PICTURE: u8 = 7;
+pub const XCB_RENDER_COMPOSITE: u8 = 8;
+pub const XCB_RENDER_TRAPEZOIDS: u8 = 10;
+pub const XCB_RENDER_TRIANGLES: u8 = 11;
+pub const XCB_RENDER_TRI_STRIP: u8 = 12;
+pub const XCB_RENDER_TRI_FAN: u8 = 13;
+pub const XCB_RENDER_CREATE_GLYPH_SET: u8 = 17;
+pub const XCB_RENDER_REFERENCE_GLYPH_SET: u8 = 18;
+pub const XCB_RENDER_FREE_GLYPH_SET: u8 = 19;
+pub const XCB_RENDER_ADD_GLYPHS: u8 = 20;
+pub const XCB_RENDER_FREE_GLYPHS: u8 = 22;
+pub const XCB_RENDER_COMPOSITE_GLYPHS_8: u8 = 23;
+pub const XCB_RENDER_COMPOSITE_GLYPHS_16: u8 = 24;
+pub const XCB_RENDER_COMPOSITE_GLYPHS_32: u8 = 25;
+pub const XCB_RENDER_FILL_RECTANGLES: u8 = 26;
+pub const XCB_RENDER_CREATE_CURSOR: u8 = 27;
+pub const XCB_RENDER_SET_PICTURE_TRANSFORM: u8 = 28;
+pub const XCB_RENDER_QUERY_FILTERS: u8 = 29;
+pub const XCB_RENDER_SET_PICTURE_FILTER: u8 = 30;
+pub const XCB_RENDER_CREATE_ANIM_CURSOR: u8 = 31;
+pub const XCB_RENDER_ADD_TRAPS: u8 = 32;
+pub const XCB_RENDER_CREATE_SOLID_FILL: u8 = 33;
+pub const XCB_RENDER_CREATE_LINEAR_GRADIENT: u8 = 34;
+pub const XCB_RENDER_CREATE_RADIAL_GRADIENT: u8 = 35;
+pub const XCB_RENDER_CREATE_CONICAL_GRADIENT: u8 = 36;
@@ -0,0 +1,855 @@
+// Generated by xcbgen from xml/render.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::slice;
+
+use proto::xproto::{XCBCursor, XCBDrawable, XCBRectangle, XCBStrIterator};
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+
+pub type XCBRenderGlyph = cdef::XCBRenderGlyph;
+pub type XCBRenderGlyphIterator = cdef::XCBRenderGlyphIterator;
+pub type XCBRenderGlyphset = cdef::XCBRenderGlyphset;
+pub type XCBRenderGlyphsetIterator = cdef::XCBRenderGlyphsetIterator;
+pub type XCBRenderPicture = cdef::XCBRenderPicture;
+pub type XCBRenderPictureIterator = cdef::XCBRenderPictureIterator;
+pub type XCBRenderPictformat = cdef::XCBRenderPictformat;
+pub type XCBRenderPictformatIterator = cdef::XCBRenderPictformatIterator;
+pub type XCBRenderFixed = cdef::XCBRenderFixed;
+pub type XCBRenderFixedIterator = cdef::XCBRenderFixedIterator;
+pub type XCBRenderPictFormatError = cdef::XCBRenderPictFormatError;
+pub type XCBRenderPictureError = cdef::XCBRenderPictureError;
+pub type XCBRenderPictOpError = cdef::XCBRenderPictOpError;
+pub type XCBRenderGlyphSetError = cdef::XCBRenderGlyphSetError;
+pub type XCBRenderGlyphError = cdef::XCBRenderGlyphError;
+pub type XCBRenderDirectformat = cdef::XCBRenderDirectformat;
+pub type XCBRenderDirectformatIterator = cdef::XCBRenderDirectformatIterator;
+pub type XCBRenderPictforminfo = cdef::XCBRenderPictforminfo;
+pub type XCBRenderPictforminfoIterator = cdef::XCBRenderPictforminfoIterator;
+pub type XCBRenderPictvisual = cdef::XCBRenderPictvisual;
+pub type XCBRenderPictvisualIterator = cdef::XCBRenderPictvisualIterator;
+pub type XCBRenderPictdepth = cdef::XCBRenderPictdepth;
+pub type XCBRenderPictdepthIterator = cdef::XCBRenderPictdepthIterator;
+pub type XCBRenderPictscreen = cdef::XCBRenderPictscreen;
+pub type XCBRenderPictscreenIterator = cdef::XCBRenderPictscreenIterator;
+pub type XCBRenderIndexvalue = cdef::XCBRenderIndexvalue;
+pub type XCBRenderIndexvalueIterator = cdef::XCBRenderIndexvalueIterator;
+pub type XCBRenderColor = cdef::XCBRenderColor;
+pub type XCBRenderColorIterator = cdef::XCBRenderColorIterator;
+pub type XCBRenderPointfix = cdef::XCBRenderPointfix;
+pub type XCBRenderPointfixIterator = cdef::XCBRenderPointfixIterator;
+pub type XCBRenderLinefix = cdef::XCBRenderLinefix;
+pub type XCBRenderLinefixIterator = cdef::XCBRenderLinefixIterator;
+pub type XCBRenderTriangle = cdef::XCBRenderTriangle;
+pub type XCBRenderTriangleIterator = cdef::XCBRenderTriangleIterator;
+pub type XCBRenderTrapezoid = cdef::XCBRenderTrapezoid;
+pub type XCBRenderTrapezoidIterator = cdef::XCBRenderTrapezoidIterator;
+pub type XCBRenderGlyphinfo = cdef::XCBRenderGlyphinfo;
+pub type XCBRenderGlyphinfoIterator = cdef::XCBRenderGlyphinfoIterator;
+pub type XCBRenderQueryVersionRequest = cdef::XCBRenderQueryVersionRequest;
+pub type XCBRenderQueryVersionCookie = cdef::XCBRenderQueryVersionCookie;
+pub type XCBRenderQueryVersionReply = cdef::XCBRenderQueryVersionReply;
+pub type XCBRenderQueryPictFormatsRequest = cdef::XCBRenderQueryPictFormatsRequest;
+pub type XCBRenderQueryPictFormatsCookie = cdef::XCBRenderQueryPictFormatsCookie;
+pub type XCBRenderQueryPictFormatsReply = cdef::XCBRenderQueryPictFormatsReply;
+pub type XCBRenderQueryPictIndexValuesRequest = cdef::XCBRenderQueryPictIndexValuesRequest;
+pub type XCBRenderQueryPictIndexValuesCookie = cdef::XCBRenderQueryPictIndexValuesCookie;
+pub type XCBRenderQueryPictIndexValuesReply = cdef::XCBRenderQueryPictIndexValuesReply;
+pub type XCBRenderCreatePictureRequest = cdef::XCBRenderCreatePictureRequest;
+pub type XCBRenderChangePictureRequest = cdef::XCBRenderChangePictureRequest;
+pub type XCBRenderSetPictureClipRectanglesRequest = cdef::XCBRenderSetPictureClipRectanglesRequest;
+pub type XCBRenderFreePictureRequest = cdef::XCBRenderFreePictureRequest;
+pub type XCBRenderCompositeRequest = cdef::XCBRenderCompositeRequest;
+pub type XCBRenderTrapezoidsRequest = cdef::XCBRenderTrapezoidsRequest;
+pub type XCBRenderTrianglesRequest = cdef::XCBRenderTrianglesRequest;
+pub type XCBRenderTriStripRequest = cdef::XCBRenderTriStripRequest;
+pub type XCBRenderTriFanRequest = cdef::XCBRenderTriFanRequest;
+pub type XCBRenderCreateGlyphSetRequest = cdef::XCBRenderCreateGlyphSetRequest;
+pub type XCBRenderReferenceGlyphSetRequest = cdef::XCBRenderReferenceGlyphSetRequest;
+pub type XCBRenderFreeGlyphSetRequest = cdef::XCBRenderFreeGlyphSetRequest;
+pub type XCBRenderAddGlyphsRequest = cdef::XCBRenderAddGlyphsRequest;
+pub type XCBRenderFreeGlyphsRequest = cdef::XCBRenderFreeGlyphsRequest;
+pub type XCBRenderCompositeGlyphs8Request = cdef::XCBRenderCompositeGlyphs8Request;
+pub type XCBRenderCompositeGlyphs16Request = cdef::XCBRenderCompositeGlyphs16Request;
+pub type XCBRenderCompositeGlyphs32Request = cdef::XCBRenderCompositeGlyphs32Request;
+pub type XCBRenderFillRectanglesRequest = cdef::XCBRenderFillRectanglesRequest;
+pub type XCBRenderCreateCursorRequest = cdef::XCBRenderCreateCursorRequest;
+pub type XCBRenderTransform = cdef::XCBRenderTransform;
+pub type XCBRenderTransformIterator = cdef::XCBRenderTransformIterator;
+pub type XCBRenderSetPictureTransformRequest = cdef::XCBRenderSetPictureTransformRequest;
+pub type XCBRenderQueryFiltersRequest = cdef::XCBRenderQueryFiltersRequest;
+pub type XCBRenderQueryFiltersCookie = cdef::XCBRenderQueryFiltersCookie;
+pub type XCBRenderQueryFiltersReply = cdef::XCBRenderQueryFiltersReply;
+pub type XCBRenderSetPictureFilterRequest = cdef::XCBRenderSetPictureFilterRequest;
+pub type XCBRenderAnimcursorelt = cdef::XCBRenderAnimcursorelt;
+pub type XCBRenderAnimcursoreltIterator = cdef::XCBRenderAnimcursoreltIterator;
+pub type XCBRenderCreateAnimCursorRequest = cdef::XCBRenderCreateAnimCursorRequest;
+pub type XCBRenderSpanfix = cdef::XCBRenderSpanfix;
+pub type XCBRenderSpanfixIterator = cdef::XCBRenderSpanfixIterator;
+pub type XCBRenderTrap = cdef::XCBRenderTrap;
+pub type XCBRenderTrapIterator = cdef::XCBRenderTrapIterator;
+pub type XCBRenderAddTrapsRequest = cdef::XCBRenderAddTrapsRequest;
+pub type XCBRenderCreateSolidFillRequest = cdef::XCBRenderCreateSolidFillRequest;
+pub type XCBRenderCreateLinearGradientRequest = cdef::XCBRenderCreateLinearGradientRequest;
+pub type XCBRenderCreateRadialGradientRequest = cdef::XCBRenderCreateRadialGradientRequest;
+pub type XCBRenderCreateConicalGradientRequest = cdef::XCBRenderCreateConicalGradientRequest;
+
+/// The Render extension, known to the server as `RENDER`.
+pub struct Render;
+
+impl Extension for Render {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_render_id)
+    }
+}
+
+pub fn xcb_render_glyph_next(iter: &mut XCBRenderGlyphIterator) {
+    unsafe { cdef::xcb_render_glyph_next(iter) }
+}
+
+pub fn xcb_render_glyphset_next(iter: &mut XCBRenderGlyphsetIterator) {
+    unsafe { cdef::xcb_render_glyphset_next(iter) }
+}
+
+pub fn xcb_render_picture_next(iter: &mut XCBRenderPictureIterator) {
+    unsafe { cdef::xcb_render_picture_next(iter) }
+}
+
+pub fn xcb_render_pictformat_next(iter: &mut XCBRenderPictformatIterator) {
+    unsafe { cdef::xcb_render_pictformat_next(iter) }
+}
+
+pub fn xcb_render_fixed_next(iter: &mut XCBRenderFixedIterator) {
+    unsafe { cdef::xcb_render_fixed_next(iter) }
+}
+
+pub fn xcb_render_directformat_next(iter: &mut XCBRenderDirectformatIterator) {
+    unsafe { cdef::xcb_render_directformat_next(iter) }
+}
+
+pub fn xcb_render_pictforminfo_next(iter: &mut XCBRenderPictforminfoIterator) {
+    unsafe { cdef::xcb_render_pictforminfo_next(iter) }
+}
+
+pub fn xcb_render_pictvisual_next(iter: &mut XCBRenderPictvisualIterator) {
+    unsafe { cdef::xcb_render_pictvisual_next(iter) }
+}
+
+pub fn xcb_render_pictdepth_next(iter: &mut XCBRenderPictdepthIterator) {
+    unsafe { cdef::xcb_render_pictdepth_next(iter) }
+}
+
+pub fn xcb_render_pictdepth_visuals(data: &XCBRenderPictdepth) -> &[XCBRenderPictvisual] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_render_pictdepth_visuals(data),
+            cdef::xcb_render_pictdepth_visuals_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_render_pictscreen_next(iter: &mut XCBRenderPictscreenIterator) {
+    unsafe { cdef::xcb_render_pictscreen_next(iter) }
+}
+
+pub fn xcb_render_pictscreen_depths_iterator(
+    data: &XCBRenderPictscreen,
+) -> XCBRenderPictdepthIterator {
+    unsafe { cdef::xcb_render_pictscreen_depths_iterator(data) }
+}
+
+pub fn xcb_render_indexvalue_next(iter: &mut XCBRenderIndexvalueIterator) {
+    unsafe { cdef::xcb_render_indexvalue_next(iter) }
+}
+
+pub fn xcb_render_color_next(iter: &mut XCBRenderColorIterator) {
+    unsafe { cdef::xcb_render_color_next(iter) }
+}
+
+pub fn xcb_render_pointfix_next(iter: &mut XCBRenderPointfixIterator) {
+    unsafe { cdef::xcb_render_pointfix_next(iter) }
+}
+
+pub fn xcb_render_linefix_next(iter: &mut XCBRenderLinefixIterator) {
+    unsafe { cdef::xcb_render_linefix_next(iter) }
+}
+
+pub fn xcb_render_triangle_next(iter: &mut XCBRenderTriangleIterator) {
+    unsafe { cdef::xcb_render_triangle_next(iter) }
+}
+
+pub fn xcb_render_trapezoid_next(iter: &mut XCBRenderTrapezoidIterator) {
+    unsafe { cdef::xcb_render_trapezoid_next(iter) }
+}
+
+pub fn xcb_render_glyphinfo_next(iter: &mut XCBRenderGlyphinfoIterator) {
+    unsafe { cdef::xcb_render_glyphinfo_next(iter) }
+}
+
+impl Reply for XCBRenderQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_render_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_render_query_version(
+    connection: &XCBConnection,
+    client_major_version: u32,
+    client_minor_version: u32,
+) -> XCBRenderQueryVersionCookie {
+    unsafe {
+        cdef::xcb_render_query_version(connection.raw, client_major_version, client_minor_version)
+    }
+}
+
+pub fn xcb_render_query_pict_formats_formats(
+    reply: &XCBRenderQueryPictFormatsReply,
+) -> &[XCBRenderPictforminfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_render_query_pict_formats_formats(reply),
+            cdef::xcb_render_query_pict_formats_formats_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_render_query_pict_formats_screens_iterator(
+    reply: &XCBRenderQueryPictFormatsReply,
+) -> XCBRenderPictscreenIterator {
+    unsafe { cdef::xcb_render_query_pict_formats_screens_iterator(reply) }
+}
+
+pub fn xcb_render_query_pict_formats_subpixels(reply: &XCBRenderQueryPictFormatsReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_render_query_pict_formats_subpixels(reply),
+            cdef::xcb_render_query_pict_formats_subpixels_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRenderQueryPictFormatsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_render_query_pict_formats_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_render_query_pict_formats(
+    connection: &XCBConnection,
+) -> XCBRenderQueryPictFormatsCookie {
+    unsafe { cdef::xcb_render_query_pict_formats(connection.raw) }
+}
+
+pub fn xcb_render_query_pict_index_values_values(
+    reply: &XCBRenderQueryPictIndexValuesReply,
+) -> &[XCBRenderIndexvalue] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_render_query_pict_index_values_values(reply),
+            cdef::xcb_render_query_pict_index_values_values_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBRenderQueryPictIndexValuesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_render_query_pict_index_values_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_render_query_pict_index_values(
+    connection: &XCBConnection,
+    format: XCBRenderPictformat,
+) -> XCBRenderQueryPictIndexValuesCookie {
+    unsafe { cdef::xcb_render_query_pict_index_values(connection.raw, format) }
+}
+
+pub fn xcb_render_create_picture(
+    connection: &XCBConnection,
+    pid: XCBRenderPicture,
+    drawable: XCBDrawable,
+    format: XCBRenderPictformat,
+    value_mask: u32,
+    value_list: &[u32],
+) -> XCBVoidCookie {
+    assert!(value_list.len() >= value_mask.count_ones() as usize);
+    unsafe {
+        cdef::xcb_render_create_picture(
+            connection.raw,
+            pid,
+            drawable,
+            format,
+            value_mask,
+            value_list.as_ptr() as *const c_void,
+        )
+    }
+}
+
+pub fn xcb_render_change_picture(
+    connection: &XCBConnection,
+    picture: XCBRenderPicture,
+    value_mask: u32,
+    value_list: &[u32],
+) -> XCBVoidCookie {
+    assert!(value_list.len() >= value_mask.count_ones() as usize);
+    unsafe {
+        cdef::xcb_render_change_picture(
+            connection.raw,
+            picture,
+            value_mask,
+            value_list.as_ptr() as *const c_void,
+        )
+    }
+}
+
+pub fn xcb_render_set_picture_clip_rectangles(
+    connection: &XCBConnection,
+    picture: XCBRenderPicture,
+    clip_x_origin: i16,
+    clip_y_origin: i16,
+    rectangles: &[XCBRectangle],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_set_picture_clip_rectangles(
+            connection.raw,
+            picture,
+            clip_x_origin,
+            clip_y_origin,
+            rectangles.len() as u32,
+            rectangles.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_free_picture(
+    connection: &XCBConnection,
+    picture: XCBRenderPicture,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_render_free_picture(connection.raw, picture) }
+}
+
+pub fn xcb_render_composite(
+    connection: &XCBConnection,
+    op: u8,
+    src: XCBRenderPicture,
+    mask: XCBRenderPicture,
+    dst: XCBRenderPicture,
+    src_x: i16,
+    src_y: i16,
+    mask_x: i16,
+    mask_y: i16,
+    dst_x: i16,
+    dst_y: i16,
+    width: u16,
+    height: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_composite(
+            connection.raw,
+            op,
+            src,
+            mask,
+            dst,
+            src_x,
+            src_y,
+            mask_x,
+            mask_y,
+            dst_x,
+            dst_y,
+            width,
+            height,
+        )
+    }
+}
+
+pub fn xcb_render_trapezoids(
+    connection: &XCBConnection,
+    op: u8,
+    src: XCBRenderPicture,
+    dst: XCBRenderPicture,
+    mask_format: XCBRenderPictformat,
+    src_x: i16,
+    src_y: i16,
+    traps: &[XCBRenderTrapezoid],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_trapezoids(
+            connection.raw,
+            op,
+            src,
+            dst,
+            mask_format,
+            src_x,
+            src_y,
+            traps.len() as u32,
+            traps.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_triangles(
+    connection: &XCBConnection,
+    op: u8,
+    src: XCBRenderPicture,
+    dst: XCBRenderPicture,
+    mask_format: XCBRenderPictformat,
+    src_x: i16,
+    src_y: i16,
+    triangles: &[XCBRenderTriangle],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_triangles(
+            connection.raw,
+            op,
+            src,
+            dst,
+            mask_format,
+            src_x,
+            src_y,
+            triangles.len() as u32,
+            triangles.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_tri_strip(
+    connection: &XCBConnection,
+    op: u8,
+    src: XCBRenderPicture,
+    dst: XCBRenderPicture,
+    mask_format: XCBRenderPictformat,
+    src_x: i16,
+    src_y: i16,
+    points: &[XCBRenderPointfix],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_tri_strip(
+            connection.raw,
+            op,
+            src,
+            dst,
+            mask_format,
+            src_x,
+            src_y,
+            points.len() as u32,
+            points.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_tri_fan(
+    connection: &XCBConnection,
+    op: u8,
+    src: XCBRenderPicture,
+    dst: XCBRenderPicture,
+    mask_format: XCBRenderPictformat,
+    src_x: i16,
+    src_y: i16,
+    points: &[XCBRenderPointfix],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_tri_fan(
+            connection.raw,
+            op,
+            src,
+            dst,
+            mask_format,
+            src_x,
+            src_y,
+            points.len() as u32,
+            points.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_create_glyph_set(
+    connection: &XCBConnection,
+    gsid: XCBRenderGlyphset,
+    format: XCBRenderPictformat,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_render_create_glyph_set(connection.raw, gsid, format) }
+}
+
+pub fn xcb_render_reference_glyph_set(
+    connection: &XCBConnection,
+    gsid: XCBRenderGlyphset,
+    existing: XCBRenderGlyphset,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_render_reference_glyph_set(connection.raw, gsid, existing) }
+}
+
+pub fn xcb_render_free_glyph_set(
+    connection: &XCBConnection,
+    glyphset: XCBRenderGlyphset,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_render_free_glyph_set(connection.raw, glyphset) }
+}
+
+pub fn xcb_render_add_glyphs(
+    connection: &XCBConnection,
+    glyphset: XCBRenderGlyphset,
+    glyphs_len: u32,
+    glyphids: &[u32],
+    glyphs: &[XCBRenderGlyphinfo],
+    data: &[u8],
+) -> XCBVoidCookie {
+    assert!(glyphids.len() >= glyphs_len as usize);
+    assert!(glyphs.len() >= glyphs_len as usize);
+    unsafe {
+        cdef::xcb_render_add_glyphs(
+            connection.raw,
+            glyphset,
+            glyphs_len,
+            glyphids.as_ptr(),
+            glyphs.as_ptr(),
+            data.len() as u32,
+            data.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_free_glyphs(
+    connection: &XCBConnection,
+    glyphset: XCBRenderGlyphset,
+    glyphs: &[XCBRenderGlyph],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_free_glyphs(
+            connection.raw,
+            glyphset,
+            glyphs.len() as u32,
+            glyphs.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_composite_glyphs_8(
+    connection: &XCBConnection,
+    op: u8,
+    src: XCBRenderPicture,
+    dst: XCBRenderPicture,
+    mask_format: XCBRenderPictformat,
+    glyphset: XCBRenderGlyphset,
+    src_x: i16,
+    src_y: i16,
+    glyphcmds: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_composite_glyphs_8(
+            connection.raw,
+            op,
+            src,
+            dst,
+            mask_format,
+            glyphset,
+            src_x,
+            src_y,
+            glyphcmds.len() as u32,
+            glyphcmds.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_composite_glyphs_16(
+    connection: &XCBConnection,
+    op: u8,
+    src: XCBRenderPicture,
+    dst: XCBRenderPicture,
+    mask_format: XCBRenderPictformat,
+    glyphset: XCBRenderGlyphset,
+    src_x: i16,
+    src_y: i16,
+    glyphcmds: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_composite_glyphs_16(
+            connection.raw,
+            op,
+            src,
+            dst,
+            mask_format,
+            glyphset,
+            src_x,
+            src_y,
+            glyphcmds.len() as u32,
+            glyphcmds.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_composite_glyphs_32(
+    connection: &XCBConnection,
+    op: u8,
+    src: XCBRenderPicture,
+    dst: XCBRenderPicture,
+    mask_format: XCBRenderPictformat,
+    glyphset: XCBRenderGlyphset,
+    src_x: i16,
+    src_y: i16,
+    glyphcmds: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_composite_glyphs_32(
+            connection.raw,
+            op,
+            src,
+            dst,
+            mask_format,
+            glyphset,
+            src_x,
+            src_y,
+            glyphcmds.len() as u32,
+            glyphcmds.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_fill_rectangles(
+    connection: &XCBConnection,
+    op: u8,
+    dst: XCBRenderPicture,
+    color: XCBRenderColor,
+    rects: &[XCBRectangle],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_fill_rectangles(
+            connection.raw,
+            op,
+            dst,
+            color,
+            rects.len() as u32,
+            rects.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_create_cursor(
+    connection: &XCBConnection,
+    cid: XCBCursor,
+    source: XCBRenderPicture,
+    x: u16,
+    y: u16,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_render_create_cursor(connection.raw, cid, source, x, y) }
+}
+
+pub fn xcb_render_transform_next(iter: &mut XCBRenderTransformIterator) {
+    unsafe { cdef::xcb_render_transform_next(iter) }
+}
+
+pub fn xcb_render_set_picture_transform(
+    connection: &XCBConnection,
+    picture: XCBRenderPicture,
+    transform: XCBRenderTransform,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_render_set_picture_transform(connection.raw, picture, transform) }
+}
+
+pub fn xcb_render_query_filters_aliases(reply: &XCBRenderQueryFiltersReply) -> &[u16] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_render_query_filters_aliases(reply),
+            cdef::xcb_render_query_filters_aliases_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_render_query_filters_filters_iterator(
+    reply: &XCBRenderQueryFiltersReply,
+) -> XCBStrIterator {
+    unsafe { cdef::xcb_render_query_filters_filters_iterator(reply) }
+}
+
+impl Reply for XCBRenderQueryFiltersReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_render_query_filters_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_render_query_filters(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+) -> XCBRenderQueryFiltersCookie {
+    unsafe { cdef::xcb_render_query_filters(connection.raw, drawable) }
+}
+
+pub fn xcb_render_set_picture_filter(
+    connection: &XCBConnection,
+    picture: XCBRenderPicture,
+    filter: &[u8],
+    values: &[XCBRenderFixed],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_set_picture_filter(
+            connection.raw,
+            picture,
+            filter.len() as u16,
+            filter.as_ptr() as *const c_char,
+            values.len() as u32,
+            values.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_animcursorelt_next(iter: &mut XCBRenderAnimcursoreltIterator) {
+    unsafe { cdef::xcb_render_animcursorelt_next(iter) }
+}
+
+pub fn xcb_render_create_anim_cursor(
+    connection: &XCBConnection,
+    cid: XCBCursor,
+    cursors: &[XCBRenderAnimcursorelt],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_create_anim_cursor(
+            connection.raw,
+            cid,
+            cursors.len() as u32,
+            cursors.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_spanfix_next(iter: &mut XCBRenderSpanfixIterator) {
+    unsafe { cdef::xcb_render_spanfix_next(iter) }
+}
+
+pub fn xcb_render_trap_next(iter: &mut XCBRenderTrapIterator) {
+    unsafe { cdef::xcb_render_trap_next(iter) }
+}
+
+pub fn xcb_render_add_traps(
+    connection: &XCBConnection,
+    picture: XCBRenderPicture,
+    x_off: i16,
+    y_off: i16,
+    traps: &[XCBRenderTrap],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_render_add_traps(
+            connection.raw,
+            picture,
+            x_off,
+            y_off,
+            traps.len() as u32,
+            traps.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_create_solid_fill(
+    connection: &XCBConnection,
+    picture: XCBRenderPicture,
+    color: XCBRenderColor,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_render_create_solid_fill(connection.raw, picture, color) }
+}
+
+pub fn xcb_render_create_linear_gradient(
+    connection: &XCBConnection,
+    picture: XCBRenderPicture,
+    p1: XCBRenderPointfix,
+    p2: XCBRenderPointfix,
+    num_stops: u32,
+    stops: &[XCBRenderFixed],
+    colors: &[XCBRenderColor],
+) -> XCBVoidCookie {
+    assert!(stops.len() >= num_stops as usize);
+    assert!(colors.len() >= num_stops as usize);
+    unsafe {
+        cdef::xcb_render_create_linear_gradient(
+            connection.raw,
+            picture,
+            p1,
+            p2,
+            num_stops,
+            stops.as_ptr(),
+            colors.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_create_radial_gradient(
+    connection: &XCBConnection,
+    picture: XCBRenderPicture,
+    inner: XCBRenderPointfix,
+    outer: XCBRenderPointfix,
+    inner_radius: XCBRenderFixed,
+    outer_radius: XCBRenderFixed,
+    num_stops: u32,
+    stops: &[XCBRenderFixed],
+    colors: &[XCBRenderColor],
+) -> XCBVoidCookie {
+    assert!(stops.len() >= num_stops as usize);
+    assert!(colors.len() >= num_stops as usize);
+    unsafe {
+        cdef::xcb_render_create_radial_gradient(
+            connection.raw,
+            picture,
+            inner,
+            outer,
+            inner_radius,
+            outer_radius,
+            num_stops,
+            stops.as_ptr(),
+            colors.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_render_create_conical_gradient(
+    connection: &XCBConnection,
+    picture: XCBRenderPicture,
+    center: XCBRenderPointfix,
+    angle: XCBRenderFixed,
+    num_stops: u32,
+    stops: &[XCBRenderFixed],
+    colors: &[XCBRenderColor],
+) -> XCBVoidCookie {
+    assert!(stops.len() >= num_stops as usize);
+    assert!(colors.len() >= num_stops as usize);
+    unsafe {
+        cdef::xcb_render_create_conical_gradient(
+            connection.raw,
+            picture,
+            center,
+            angle,
+            num_stops,
+            stops.as_ptr(),
+            colors.as_ptr(),
+        )
+    }
+}
+
+/// Returns the name of the error whose code, relative to the first error of the extension, is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        0 => Some("PictFormat"),
+        1 => Some("Picture"),
+        2 => Some("PictOp"),
+        3 => Some("GlyphSet"),
+        4 => Some("Glyph"),
+        _ => None,
+    }
+}
@@ -0,0 +1,372 @@
+// Generated by xcbgen from xml/res.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+use cdef::{XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator};
+use proto::xproto::cdef::XCBAtom;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBResClient {
+    pub resource_base: u32,
+    pub resource_mask: u32,
+}
+
+#[repr(C)]
+pub struct XCBResClientIterator {
+    pub data: *mut XCBResClient,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBResType {
+    pub resource_type: XCBAtom,
+    pub count: u32,
+}
+
+#[repr(C)]
+pub struct XCBResTypeIterator {
+    pub data: *mut XCBResType,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBResClientIdSpec {
+    pub client: u32,
+    pub mask: u32,
+}
+
+#[repr(C)]
+pub struct XCBResClientIdSpecIterator {
+    pub data: *mut XCBResClientIdSpec,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBResClientIdValue {
+    pub spec: XCBResClientIdSpec,
+    pub length: u32,
+}
+
+#[repr(C)]
+pub struct XCBResClientIdValueIterator {
+    pub data: *mut XCBResClientIdValue,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBResResourceIdSpec {
+    pub resource: u32,
+    pub type_: u32,
+}
+
+#[repr(C)]
+pub struct XCBResResourceIdSpecIterator {
+    pub data: *mut XCBResResourceIdSpec,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBResResourceSizeSpec {
+    pub spec: XCBResResourceIdSpec,
+    pub bytes: u32,
+    pub ref_count: u32,
+    pub use_count: u32,
+}
+
+#[repr(C)]
+pub struct XCBResResourceSizeSpecIterator {
+    pub data: *mut XCBResResourceSizeSpec,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBResResourceSizeValue {
+    pub size: XCBResResourceSizeSpec,
+    pub num_cross_references: u32,
+}
+
+#[repr(C)]
+pub struct XCBResResourceSizeValueIterator {
+    pub data: *mut XCBResResourceSizeValue,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBResQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub client_major: u8,
+    pub client_minor: u8,
+}
+
+pub type XCBResQueryVersionCookie = XCBCookie<XCBResQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBResQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub server_major: u16,
+    pub server_minor: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBResQueryClientsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBResQueryClientsCookie = XCBCookie<XCBResQueryClientsReply>;
+
+#[repr(C)]
+pub struct XCBResQueryClientsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_clients: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBResQueryClientResourcesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub xid: u32,
+}
+
+pub type XCBResQueryClientResourcesCookie = XCBCookie<XCBResQueryClientResourcesReply>;
+
+#[repr(C)]
+pub struct XCBResQueryClientResourcesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_types: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBResQueryClientPixmapBytesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub xid: u32,
+}
+
+pub type XCBResQueryClientPixmapBytesCookie = XCBCookie<XCBResQueryClientPixmapBytesReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBResQueryClientPixmapBytesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub bytes: u32,
+    pub bytes_overflow: u32,
+}
+
+#[repr(C)]
+pub struct XCBResQueryClientIdsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub num_specs: u32,
+}
+
+pub type XCBResQueryClientIdsCookie = XCBCookie<XCBResQueryClientIdsReply>;
+
+#[repr(C)]
+pub struct XCBResQueryClientIdsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_ids: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBResQueryResourceBytesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub client: u32,
+    pub num_specs: u32,
+}
+
+pub type XCBResQueryResourceBytesCookie = XCBCookie<XCBResQueryResourceBytesReply>;
+
+#[repr(C)]
+pub struct XCBResQueryResourceBytesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_sizes: u32,
+    pub pad1: [u8; 20],
+}
+
+#[link(name = "xcb-res")]
+extern "system" {
+    pub static mut xcb_res_id: XCBExtension;
+    pub fn xcb_res_client_next(i: *mut XCBResClientIterator);
+    pub fn xcb_res_client_end(i: XCBResClientIterator) -> XCBGenericIterator;
+    pub fn xcb_res_type_next(i: *mut XCBResTypeIterator);
+    pub fn xcb_res_type_end(i: XCBResTypeIterator) -> XCBGenericIterator;
+    pub fn xcb_res_client_id_spec_next(i: *mut XCBResClientIdSpecIterator);
+    pub fn xcb_res_client_id_spec_end(i: XCBResClientIdSpecIterator) -> XCBGenericIterator;
+    pub fn xcb_res_client_id_value_next(i: *mut XCBResClientIdValueIterator);
+    pub fn xcb_res_client_id_value_end(i: XCBResClientIdValueIterator) -> XCBGenericIterator;
+    pub fn xcb_res_client_id_value_value(R: *const XCBResClientIdValue) -> *mut u32;
+    pub fn xcb_res_client_id_value_value_length(R: *const XCBResClientIdValue) -> c_int;
+    pub fn xcb_res_client_id_value_value_end(R: *const XCBResClientIdValue) -> XCBGenericIterator;
+    pub fn xcb_res_resource_id_spec_next(i: *mut XCBResResourceIdSpecIterator);
+    pub fn xcb_res_resource_id_spec_end(i: XCBResResourceIdSpecIterator) -> XCBGenericIterator;
+    pub fn xcb_res_resource_size_spec_next(i: *mut XCBResResourceSizeSpecIterator);
+    pub fn xcb_res_resource_size_spec_end(i: XCBResResourceSizeSpecIterator) -> XCBGenericIterator;
+    pub fn xcb_res_resource_size_value_next(i: *mut XCBResResourceSizeValueIterator);
+    pub fn xcb_res_resource_size_value_end(
+        i: XCBResResourceSizeValueIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_res_resource_size_value_cross_references(
+        R: *const XCBResResourceSizeValue,
+    ) -> *mut XCBResResourceSizeSpec;
+    pub fn xcb_res_resource_size_value_cross_references_length(
+        R: *const XCBResResourceSizeValue,
+    ) -> c_int;
+    pub fn xcb_res_resource_size_value_cross_references_iterator(
+        R: *const XCBResResourceSizeValue,
+    ) -> XCBResResourceSizeSpecIterator;
+    pub fn xcb_res_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBResQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBResQueryVersionReply;
+    pub fn xcb_res_query_version(
+        connection: *mut XCBConnection,
+        client_major: u8,
+        client_minor: u8,
+    ) -> XCBResQueryVersionCookie;
+    pub fn xcb_res_query_version_unchecked(
+        connection: *mut XCBConnection,
+        client_major: u8,
+        client_minor: u8,
+    ) -> XCBResQueryVersionCookie;
+    pub fn xcb_res_query_clients_clients(R: *const XCBResQueryClientsReply) -> *mut XCBResClient;
+    pub fn xcb_res_query_clients_clients_length(R: *const XCBResQueryClientsReply) -> c_int;
+    pub fn xcb_res_query_clients_clients_iterator(
+        R: *const XCBResQueryClientsReply,
+    ) -> XCBResClientIterator;
+    pub fn xcb_res_query_clients_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBResQueryClientsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBResQueryClientsReply;
+    pub fn xcb_res_query_clients(connection: *mut XCBConnection) -> XCBResQueryClientsCookie;
+    pub fn xcb_res_query_clients_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBResQueryClientsCookie;
+    pub fn xcb_res_query_client_resources_types(
+        R: *const XCBResQueryClientResourcesReply,
+    ) -> *mut XCBResType;
+    pub fn xcb_res_query_client_resources_types_length(
+        R: *const XCBResQueryClientResourcesReply,
+    ) -> c_int;
+    pub fn xcb_res_query_client_resources_types_iterator(
+        R: *const XCBResQueryClientResourcesReply,
+    ) -> XCBResTypeIterator;
+    pub fn xcb_res_query_client_resources_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBResQueryClientResourcesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBResQueryClientResourcesReply;
+    pub fn xcb_res_query_client_resources(
+        connection: *mut XCBConnection,
+        xid: u32,
+    ) -> XCBResQueryClientResourcesCookie;
+    pub fn xcb_res_query_client_resources_unchecked(
+        connection: *mut XCBConnection,
+        xid: u32,
+    ) -> XCBResQueryClientResourcesCookie;
+    pub fn xcb_res_query_client_pixmap_bytes_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBResQueryClientPixmapBytesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBResQueryClientPixmapBytesReply;
+    pub fn xcb_res_query_client_pixmap_bytes(
+        connection: *mut XCBConnection,
+        xid: u32,
+    ) -> XCBResQueryClientPixmapBytesCookie;
+    pub fn xcb_res_query_client_pixmap_bytes_unchecked(
+        connection: *mut XCBConnection,
+        xid: u32,
+    ) -> XCBResQueryClientPixmapBytesCookie;
+    pub fn xcb_res_query_client_ids_ids_length(R: *const XCBResQueryClientIdsReply) -> c_int;
+    pub fn xcb_res_query_client_ids_ids_iterator(
+        R: *const XCBResQueryClientIdsReply,
+    ) -> XCBResClientIdValueIterator;
+    pub fn xcb_res_query_client_ids_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBResQueryClientIdsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBResQueryClientIdsReply;
+    pub fn xcb_res_query_client_ids(
+        connection: *mut XCBConnection,
+        num_specs: u32,
+        specs: *const XCBResClientIdSpec,
+    ) -> XCBResQueryClientIdsCookie;
+    pub fn xcb_res_query_client_ids_unchecked(
+        connection: *mut XCBConnection,
+        num_specs: u32,
+        specs: *const XCBResClientIdSpec,
+    ) -> XCBResQueryClientIdsCookie;
+    pub fn xcb_res_query_resource_bytes_sizes_length(
+        R: *const XCBResQueryResourceBytesReply,
+    ) -> c_int;
+    pub fn xcb_res_query_resource_bytes_sizes_iterator(
+        R: *const XCBResQueryResourceBytesReply,
+    ) -> XCBResResourceSizeValueIterator;
+    pub fn xcb_res_query_resource_bytes_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBResQueryResourceBytesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBResQueryResourceBytesReply;
+    pub fn xcb_res_query_resource_bytes(
+        connection: *mut XCBConnection,
+        client: u32,
+        num_specs: u32,
+        specs: *const XCBResResourceIdSpec,
+    ) -> XCBResQueryResourceBytesCookie;
+    pub fn xcb_res_query_resource_bytes_unchecked(
+        connection: *mut XCBConnection,
+        client: u32,
+        num_specs: u32,
+        specs: *const XCBResResourceIdSpec,
+    ) -> XCBResQueryResourceBytesCookie;
+}
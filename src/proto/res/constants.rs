@@ -0,0 +1,12 @@
+// Generated by xcbgen from xml/res.xml, do not edit.
+
+pub const XCB_RES_MAJOR_VERSION: u32 = 1;
+pub const XCB_RES_MINOR_VERSION: u32 = 2;
+pub const XCB_RES_CLIENT_ID_MASK_CLIENT_XID: u32 = 1;
+pub const XCB_RES_CLIENT_ID_MASK_LOCAL_CLIENT_PID: u32 = 2;
+pub const XCB_RES_QUERY_VERSION: u8 = 0;
+pub const XCB_RES_QUERY_CLIENTS: u8 = 1;
+pub const XCB_RES_QUERY_CLIENT_RESOURCES: u8 = 2;
+pub const XCB_RES_QUERY_CLIENT_PIXMAP_BYTES: u8 = 3;
+pub const XCB_RES_QUERY_CLIENT_IDS: u8 = 4;
+pub const XCB_RES_QUERY_RESOURCE_BYTES: u8 = 5;
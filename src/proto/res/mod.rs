@@ -0,0 +1,241 @@
+// Generated by xcbgen from xml/res.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError};
+
+pub type XCBResClient = cdef::XCBResClient;
+pub type XCBResClientIterator = cdef::XCBResClientIterator;
+pub type XCBResType = cdef::XCBResType;
+pub type XCBResTypeIterator = cdef::XCBResTypeIterator;
+pub type XCBResClientIdSpec = cdef::XCBResClientIdSpec;
+pub type XCBResClientIdSpecIterator = cdef::XCBResClientIdSpecIterator;
+pub type XCBResClientIdValue = cdef::XCBResClientIdValue;
+pub type XCBResClientIdValueIterator = cdef::XCBResClientIdValueIterator;
+pub type XCBResResourceIdSpec = cdef::XCBResResourceIdSpec;
+pub type XCBResResourceIdSpecIterator = cdef::XCBResResourceIdSpecIterator;
+pub type XCBResResourceSizeSpec = cdef::XCBResResourceSizeSpec;
+pub type XCBResResourceSizeSpecIterator = cdef::XCBResResourceSizeSpecIterator;
+pub type XCBResResourceSizeValue = cdef::XCBResResourceSizeValue;
+pub type XCBResResourceSizeValueIterator = cdef::XCBResResourceSizeValueIterator;
+pub type XCBResQueryVersionRequest = cdef::XCBResQueryVersionRequest;
+pub type XCBResQueryVersionCookie = cdef::XCBResQueryVersionCookie;
+pub type XCBResQueryVersionReply = cdef::XCBResQueryVersionReply;
+pub type XCBResQueryClientsRequest = cdef::XCBResQueryClientsRequest;
+pub type XCBResQueryClientsCookie = cdef::XCBResQueryClientsCookie;
+pub type XCBResQueryClientsReply = cdef::XCBResQueryClientsReply;
+pub type XCBResQueryClientResourcesRequest = cdef::XCBResQueryClientResourcesRequest;
+pub type XCBResQueryClientResourcesCookie = cdef::XCBResQueryClientResourcesCookie;
+pub type XCBResQueryClientResourcesReply = cdef::XCBResQueryClientResourcesReply;
+pub type XCBResQueryClientPixmapBytesRequest = cdef::XCBResQueryClientPixmapBytesRequest;
+pub type XCBResQueryClientPixmapBytesCookie = cdef::XCBResQueryClientPixmapBytesCookie;
+pub type XCBResQueryClientPixmapBytesReply = cdef::XCBResQueryClientPixmapBytesReply;
+pub type XCBResQueryClientIdsRequest = cdef::XCBResQueryClientIdsRequest;
+pub type XCBResQueryClientIdsCookie = cdef::XCBResQueryClientIdsCookie;
+pub type XCBResQueryClientIdsReply = cdef::XCBResQueryClientIdsReply;
+pub type XCBResQueryResourceBytesRequest = cdef::XCBResQueryResourceBytesRequest;
+pub type XCBResQueryResourceBytesCookie = cdef::XCBResQueryResourceBytesCookie;
+pub type XCBResQueryResourceBytesReply = cdef::XCBResQueryResourceBytesReply;
+
+/// The Res extension, known to the server as `X-Resource`.
+pub struct Res;
+
+impl Extension for Res {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_res_id)
+    }
+}
+
+pub fn xcb_res_client_next(iter: &mut XCBResClientIterator) {
+    unsafe { cdef::xcb_res_client_next(iter) }
+}
+
+pub fn xcb_res_type_next(iter: &mut XCBResTypeIterator) {
+    unsafe { cdef::xcb_res_type_next(iter) }
+}
+
+pub fn xcb_res_client_id_spec_next(iter: &mut XCBResClientIdSpecIterator) {
+    unsafe { cdef::xcb_res_client_id_spec_next(iter) }
+}
+
+pub fn xcb_res_client_id_value_next(iter: &mut XCBResClientIdValueIterator) {
+    unsafe { cdef::xcb_res_client_id_value_next(iter) }
+}
+
+pub fn xcb_res_client_id_value_value(data: &XCBResClientIdValue) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_res_client_id_value_value(data),
+            cdef::xcb_res_client_id_value_value_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_res_resource_id_spec_next(iter: &mut XCBResResourceIdSpecIterator) {
+    unsafe { cdef::xcb_res_resource_id_spec_next(iter) }
+}
+
+pub fn xcb_res_resource_size_spec_next(iter: &mut XCBResResourceSizeSpecIterator) {
+    unsafe { cdef::xcb_res_resource_size_spec_next(iter) }
+}
+
+pub fn xcb_res_resource_size_value_next(iter: &mut XCBResResourceSizeValueIterator) {
+    unsafe { cdef::xcb_res_resource_size_value_next(iter) }
+}
+
+pub fn xcb_res_resource_size_value_cross_references(
+    data: &XCBResResourceSizeValue,
+) -> &[XCBResResourceSizeSpec] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_res_resource_size_value_cross_references(data),
+            cdef::xcb_res_resource_size_value_cross_references_length(data) as usize,
+        )
+    }
+}
+
+impl Reply for XCBResQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_res_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_res_query_version(
+    connection: &XCBConnection,
+    client_major: u8,
+    client_minor: u8,
+) -> XCBResQueryVersionCookie {
+    unsafe { cdef::xcb_res_query_version(connection.raw, client_major, client_minor) }
+}
+
+pub fn xcb_res_query_clients_clients(reply: &XCBResQueryClientsReply) -> &[XCBResClient] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_res_query_clients_clients(reply),
+            cdef::xcb_res_query_clients_clients_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBResQueryClientsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_res_query_clients_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_res_query_clients(connection: &XCBConnection) -> XCBResQueryClientsCookie {
+    unsafe { cdef::xcb_res_query_clients(connection.raw) }
+}
+
+pub fn xcb_res_query_client_resources_types(
+    reply: &XCBResQueryClientResourcesReply,
+) -> &[XCBResType] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_res_query_client_resources_types(reply),
+            cdef::xcb_res_query_client_resources_types_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBResQueryClientResourcesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_res_query_client_resources_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_res_query_client_resources(
+    connection: &XCBConnection,
+    xid: u32,
+) -> XCBResQueryClientResourcesCookie {
+    unsafe { cdef::xcb_res_query_client_resources(connection.raw, xid) }
+}
+
+impl Reply for XCBResQueryClientPixmapBytesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_res_query_client_pixmap_bytes_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_res_query_client_pixmap_bytes(
+    connection: &XCBConnection,
+    xid: u32,
+) -> XCBResQueryClientPixmapBytesCookie {
+    unsafe { cdef::xcb_res_query_client_pixmap_bytes(connection.raw, xid) }
+}
+
+pub fn xcb_res_query_client_ids_ids_iterator(
+    reply: &XCBResQueryClientIdsReply,
+) -> XCBResClientIdValueIterator {
+    unsafe { cdef::xcb_res_query_client_ids_ids_iterator(reply) }
+}
+
+impl Reply for XCBResQueryClientIdsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_res_query_client_ids_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_res_query_client_ids(
+    connection: &XCBConnection,
+    specs: &[XCBResClientIdSpec],
+) -> XCBResQueryClientIdsCookie {
+    unsafe { cdef::xcb_res_query_client_ids(connection.raw, specs.len() as u32, specs.as_ptr()) }
+}
+
+pub fn xcb_res_query_resource_bytes_sizes_iterator(
+    reply: &XCBResQueryResourceBytesReply,
+) -> XCBResResourceSizeValueIterator {
+    unsafe { cdef::xcb_res_query_resource_bytes_sizes_iterator(reply) }
+}
+
+impl Reply for XCBResQueryResourceBytesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_res_query_resource_bytes_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_res_query_resource_bytes(
+    connection: &XCBConnection,
+    client: u32,
+    specs: &[XCBResResourceIdSpec],
+) -> XCBResQueryResourceBytesCookie {
+    unsafe {
+        cdef::xcb_res_query_resource_bytes(
+            connection.raw,
+            client,
+            specs.len() as u32,
+            specs.as_ptr(),
+        )
+    }
+}
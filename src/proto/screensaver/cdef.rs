@@ -0,0 +1,202 @@
+// Generated by xcbgen from xml/screensaver.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::c_void;
+
+use cdef::{XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+use proto::xproto::cdef::{XCBDrawable, XCBTimestamp, XCBVisualid, XCBWindow};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBScreensaverQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub client_major_version: u8,
+    pub client_minor_version: u8,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBScreensaverQueryVersionCookie = XCBCookie<XCBScreensaverQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBScreensaverQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub server_major_version: u16,
+    pub server_minor_version: u16,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBScreensaverQueryInfoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+}
+
+pub type XCBScreensaverQueryInfoCookie = XCBCookie<XCBScreensaverQueryInfoReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBScreensaverQueryInfoReply {
+    pub response_type: u8,
+    pub state: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub saver_window: XCBWindow,
+    pub ms_until_server: u32,
+    pub ms_since_user_input: u32,
+    pub event_mask: u32,
+    pub kind: u8,
+    pub pad0: [u8; 7],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBScreensaverSelectInputRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub event_mask: u32,
+}
+
+#[repr(C)]
+pub struct XCBScreensaverSetAttributesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub border_width: u16,
+    pub class: u8,
+    pub depth: u8,
+    pub visual: XCBVisualid,
+    pub value_mask: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBScreensaverUnsetAttributesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBScreensaverSuspendRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub suspend: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBScreensaverNotifyEvent {
+    pub response_type: u8,
+    pub state: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub root: XCBWindow,
+    pub window: XCBWindow,
+    pub kind: u8,
+    pub forced: u8,
+    pub pad0: [u8; 14],
+}
+
+#[link(name = "xcb-screensaver")]
+extern "system" {
+    pub static mut xcb_screensaver_id: XCBExtension;
+    pub fn xcb_screensaver_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBScreensaverQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBScreensaverQueryVersionReply;
+    pub fn xcb_screensaver_query_version(
+        connection: *mut XCBConnection,
+        client_major_version: u8,
+        client_minor_version: u8,
+    ) -> XCBScreensaverQueryVersionCookie;
+    pub fn xcb_screensaver_query_version_unchecked(
+        connection: *mut XCBConnection,
+        client_major_version: u8,
+        client_minor_version: u8,
+    ) -> XCBScreensaverQueryVersionCookie;
+    pub fn xcb_screensaver_query_info_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBScreensaverQueryInfoCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBScreensaverQueryInfoReply;
+    pub fn xcb_screensaver_query_info(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBScreensaverQueryInfoCookie;
+    pub fn xcb_screensaver_query_info_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBScreensaverQueryInfoCookie;
+    pub fn xcb_screensaver_select_input_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        event_mask: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_screensaver_select_input(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        event_mask: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_screensaver_set_attributes_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        border_width: u16,
+        class: u8,
+        depth: u8,
+        visual: XCBVisualid,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_screensaver_set_attributes(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        border_width: u16,
+        class: u8,
+        depth: u8,
+        visual: XCBVisualid,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_screensaver_unset_attributes_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBVoidCookie;
+    pub fn xcb_screensaver_unset_attributes(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBVoidCookie;
+    pub fn xcb_screensaver_suspend_checked(
+        connection: *mut XCBConnection,
+        suspend: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_screensaver_suspend(connection: *mut XCBConnection, suspend: u32) -> XCBVoidCookie;
+}
@@ -0,0 +1,20 @@
+// Generated by xcbgen from xml/screensaver.xml, do not edit.
+
+pub const XCB_SCREENSAVER_MAJOR_VERSION: u32 = 1;
+pub const XCB_SCREENSAVER_MINOR_VERSION: u32 = 1;
+pub const XCB_SCREENSAVER_KIND_BLANKED: u8 = 0;
+pub const XCB_SCREENSAVER_KIND_INTERNAL: u8 = 1;
+pub const XCB_SCREENSAVER_KIND_EXTERNAL: u8 = 2;
+pub const XCB_SCREENSAVER_EVENT_NOTIFY_MASK: u32 = 1;
+pub const XCB_SCREENSAVER_EVENT_CYCLE_MASK: u32 = 2;
+pub const XCB_SCREENSAVER_STATE_OFF: u8 = 0;
+pub const XCB_SCREENSAVER_STATE_ON: u8 = 1;
+pub const XCB_SCREENSAVER_STATE_CYCLE: u8 = 2;
+pub const XCB_SCREENSAVER_STATE_DISABLED: u8 = 3;
+pub const XCB_SCREENSAVER_QUERY_VERSION: u8 = 0;
+pub const XCB_SCREENSAVER_QUERY_INFO: u8 = 1;
+pub const XCB_SCREENSAVER_SELECT_INPUT: u8 = 2;
+pub const XCB_SCREENSAVER_SET_ATTRIBUTES: u8 = 3;
+pub const XCB_SCREENSAVER_UNSET_ATTRIBUTES: u8 = 4;
+pub const XCB_SCREENSAVER_SUSPEND: u8 = 5;
+pub const XCB_SCREENSAVER_NOTIFY: u8 = 0;
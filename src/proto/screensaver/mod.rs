@@ -0,0 +1,151 @@
+// Generated by xcbgen from xml/screensaver.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::os::raw::c_void;
+use std::ptr;
+
+use proto::xproto::{XCBDrawable, XCBVisualid};
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBScreensaverQueryVersionRequest = cdef::XCBScreensaverQueryVersionRequest;
+pub type XCBScreensaverQueryVersionCookie = cdef::XCBScreensaverQueryVersionCookie;
+pub type XCBScreensaverQueryVersionReply = cdef::XCBScreensaverQueryVersionReply;
+pub type XCBScreensaverQueryInfoRequest = cdef::XCBScreensaverQueryInfoRequest;
+pub type XCBScreensaverQueryInfoCookie = cdef::XCBScreensaverQueryInfoCookie;
+pub type XCBScreensaverQueryInfoReply = cdef::XCBScreensaverQueryInfoReply;
+pub type XCBScreensaverSelectInputRequest = cdef::XCBScreensaverSelectInputRequest;
+pub type XCBScreensaverSetAttributesRequest = cdef::XCBScreensaverSetAttributesRequest;
+pub type XCBScreensaverUnsetAttributesRequest = cdef::XCBScreensaverUnsetAttributesRequest;
+pub type XCBScreensaverSuspendRequest = cdef::XCBScreensaverSuspendRequest;
+pub type XCBScreensaverNotifyEvent = cdef::XCBScreensaverNotifyEvent;
+
+/// The ScreenSaver extension, known to the server as `MIT-SCREEN-SAVER`.
+pub struct Screensaver;
+
+impl Extension for Screensaver {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_screensaver_id)
+    }
+}
+
+impl Reply for XCBScreensaverQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_screensaver_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_screensaver_query_version(
+    connection: &XCBConnection,
+    client_major_version: u8,
+    client_minor_version: u8,
+) -> XCBScreensaverQueryVersionCookie {
+    unsafe {
+        cdef::xcb_screensaver_query_version(
+            connection.raw,
+            client_major_version,
+            client_minor_version,
+        )
+    }
+}
+
+impl Reply for XCBScreensaverQueryInfoReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_screensaver_query_info_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_screensaver_query_info(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+) -> XCBScreensaverQueryInfoCookie {
+    unsafe { cdef::xcb_screensaver_query_info(connection.raw, drawable) }
+}
+
+pub fn xcb_screensaver_select_input(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    event_mask: u32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_screensaver_select_input(connection.raw, drawable, event_mask) }
+}
+
+pub fn xcb_screensaver_set_attributes(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+    border_width: u16,
+    class: u8,
+    depth: u8,
+    visual: XCBVisualid,
+    value_mask: u32,
+    value_list: &[u32],
+) -> XCBVoidCookie {
+    assert!(value_list.len() >= value_mask.count_ones() as usize);
+    unsafe {
+        cdef::xcb_screensaver_set_attributes(
+            connection.raw,
+            drawable,
+            x,
+            y,
+            width,
+            height,
+            border_width,
+            class,
+            depth,
+            visual,
+            value_mask,
+            value_list.as_ptr() as *const c_void,
+        )
+    }
+}
+
+pub fn xcb_screensaver_unset_attributes(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_screensaver_unset_attributes(connection.raw, drawable) }
+}
+
+pub fn xcb_screensaver_suspend(connection: &XCBConnection, suspend: u32) -> XCBVoidCookie {
+    unsafe { cdef::xcb_screensaver_suspend(connection.raw, suspend) }
+}
+
+/// An event of the ScreenSaver extension.
+pub enum Event {
+    Notify(Box<XCBScreensaverNotifyEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::Notify(Box::from_raw(
+                    raw as *mut XCBScreensaverNotifyEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
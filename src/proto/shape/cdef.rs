@@ -0,0 +1,360 @@
+// Generated by xcbgen from xml/shape.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xproto::cdef::{XCBPixmap, XCBRectangle, XCBRectangleIterator, XCBTimestamp, XCBWindow};
+
+pub type XCBShapeOp = u8;
+
+#[repr(C)]
+pub struct XCBShapeOpIterator {
+    pub data: *mut XCBShapeOp,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBShapeKind = u8;
+
+#[repr(C)]
+pub struct XCBShapeKindIterator {
+    pub data: *mut XCBShapeKind,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShapeNotifyEvent {
+    pub response_type: u8,
+    pub shape_kind: XCBShapeKind,
+    pub sequence: u16,
+    pub affected_window: XCBWindow,
+    pub extents_x: i16,
+    pub extents_y: i16,
+    pub extents_width: u16,
+    pub extents_height: u16,
+    pub server_time: XCBTimestamp,
+    pub shaped: u8,
+    pub pad0: [u8; 11],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShapeQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBShapeQueryVersionCookie = XCBCookie<XCBShapeQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShapeQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+
+#[repr(C)]
+pub struct XCBShapeRectanglesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub operation: XCBShapeOp,
+    pub destination_kind: XCBShapeKind,
+    pub ordering: u8,
+    pub pad0: u8,
+    pub destination_window: XCBWindow,
+    pub x_offset: i16,
+    pub y_offset: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShapeMaskRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub operation: XCBShapeOp,
+    pub destination_kind: XCBShapeKind,
+    pub pad0: [u8; 2],
+    pub destination_window: XCBWindow,
+    pub x_offset: i16,
+    pub y_offset: i16,
+    pub source_bitmap: XCBPixmap,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShapeCombineRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub operation: XCBShapeOp,
+    pub destination_kind: XCBShapeKind,
+    pub source_kind: XCBShapeKind,
+    pub pad0: u8,
+    pub destination_window: XCBWindow,
+    pub x_offset: i16,
+    pub y_offset: i16,
+    pub source_window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShapeOffsetRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub destination_kind: XCBShapeKind,
+    pub pad0: [u8; 3],
+    pub destination_window: XCBWindow,
+    pub x_offset: i16,
+    pub y_offset: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShapeQueryExtentsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub destination_window: XCBWindow,
+}
+
+pub type XCBShapeQueryExtentsCookie = XCBCookie<XCBShapeQueryExtentsReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShapeQueryExtentsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub bounding_shaped: u8,
+    pub clip_shaped: u8,
+    pub pad1: [u8; 2],
+    pub bounding_shape_extents_x: i16,
+    pub bounding_shape_extents_y: i16,
+    pub bounding_shape_extents_width: u16,
+    pub bounding_shape_extents_height: u16,
+    pub clip_shape_extents_x: i16,
+    pub clip_shape_extents_y: i16,
+    pub clip_shape_extents_width: u16,
+    pub clip_shape_extents_height: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShapeSelectInputRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub destination_window: XCBWindow,
+    pub enable: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShapeInputSelectedRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub destination_window: XCBWindow,
+}
+
+pub type XCBShapeInputSelectedCookie = XCBCookie<XCBShapeInputSelectedReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShapeInputSelectedReply {
+    pub response_type: u8,
+    pub enabled: u8,
+    pub sequence: u16,
+    pub length: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShapeGetRectanglesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub source_kind: XCBShapeKind,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBShapeGetRectanglesCookie = XCBCookie<XCBShapeGetRectanglesReply>;
+
+#[repr(C)]
+pub struct XCBShapeGetRectanglesReply {
+    pub response_type: u8,
+    pub ordering: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub rectangles_len: u32,
+    pub pad0: [u8; 20],
+}
+
+#[link(name = "xcb-shape")]
+extern "system" {
+    pub static mut xcb_shape_id: XCBExtension;
+    pub fn xcb_shape_op_next(i: *mut XCBShapeOpIterator);
+    pub fn xcb_shape_op_end(i: XCBShapeOpIterator) -> XCBGenericIterator;
+    pub fn xcb_shape_kind_next(i: *mut XCBShapeKindIterator);
+    pub fn xcb_shape_kind_end(i: XCBShapeKindIterator) -> XCBGenericIterator;
+    pub fn xcb_shape_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBShapeQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBShapeQueryVersionReply;
+    pub fn xcb_shape_query_version(connection: *mut XCBConnection) -> XCBShapeQueryVersionCookie;
+    pub fn xcb_shape_query_version_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBShapeQueryVersionCookie;
+    pub fn xcb_shape_rectangles_checked(
+        connection: *mut XCBConnection,
+        operation: XCBShapeOp,
+        destination_kind: XCBShapeKind,
+        ordering: u8,
+        destination_window: XCBWindow,
+        x_offset: i16,
+        y_offset: i16,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shape_rectangles(
+        connection: *mut XCBConnection,
+        operation: XCBShapeOp,
+        destination_kind: XCBShapeKind,
+        ordering: u8,
+        destination_window: XCBWindow,
+        x_offset: i16,
+        y_offset: i16,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shape_mask_checked(
+        connection: *mut XCBConnection,
+        operation: XCBShapeOp,
+        destination_kind: XCBShapeKind,
+        destination_window: XCBWindow,
+        x_offset: i16,
+        y_offset: i16,
+        source_bitmap: XCBPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shape_mask(
+        connection: *mut XCBConnection,
+        operation: XCBShapeOp,
+        destination_kind: XCBShapeKind,
+        destination_window: XCBWindow,
+        x_offset: i16,
+        y_offset: i16,
+        source_bitmap: XCBPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shape_combine_checked(
+        connection: *mut XCBConnection,
+        operation: XCBShapeOp,
+        destination_kind: XCBShapeKind,
+        source_kind: XCBShapeKind,
+        destination_window: XCBWindow,
+        x_offset: i16,
+        y_offset: i16,
+        source_window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shape_combine(
+        connection: *mut XCBConnection,
+        operation: XCBShapeOp,
+        destination_kind: XCBShapeKind,
+        source_kind: XCBShapeKind,
+        destination_window: XCBWindow,
+        x_offset: i16,
+        y_offset: i16,
+        source_window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shape_offset_checked(
+        connection: *mut XCBConnection,
+        destination_kind: XCBShapeKind,
+        destination_window: XCBWindow,
+        x_offset: i16,
+        y_offset: i16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shape_offset(
+        connection: *mut XCBConnection,
+        destination_kind: XCBShapeKind,
+        destination_window: XCBWindow,
+        x_offset: i16,
+        y_offset: i16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shape_query_extents_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBShapeQueryExtentsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBShapeQueryExtentsReply;
+    pub fn xcb_shape_query_extents(
+        connection: *mut XCBConnection,
+        destination_window: XCBWindow,
+    ) -> XCBShapeQueryExtentsCookie;
+    pub fn xcb_shape_query_extents_unchecked(
+        connection: *mut XCBConnection,
+        destination_window: XCBWindow,
+    ) -> XCBShapeQueryExtentsCookie;
+    pub fn xcb_shape_select_input_checked(
+        connection: *mut XCBConnection,
+        destination_window: XCBWindow,
+        enable: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shape_select_input(
+        connection: *mut XCBConnection,
+        destination_window: XCBWindow,
+        enable: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shape_input_selected_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBShapeInputSelectedCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBShapeInputSelectedReply;
+    pub fn xcb_shape_input_selected(
+        connection: *mut XCBConnection,
+        destination_window: XCBWindow,
+    ) -> XCBShapeInputSelectedCookie;
+    pub fn xcb_shape_input_selected_unchecked(
+        connection: *mut XCBConnection,
+        destination_window: XCBWindow,
+    ) -> XCBShapeInputSelectedCookie;
+    pub fn xcb_shape_get_rectangles_rectangles(
+        R: *const XCBShapeGetRectanglesReply,
+    ) -> *mut XCBRectangle;
+    pub fn xcb_shape_get_rectangles_rectangles_length(
+        R: *const XCBShapeGetRectanglesReply,
+    ) -> c_int;
+    pub fn xcb_shape_get_rectangles_rectangles_iterator(
+        R: *const XCBShapeGetRectanglesReply,
+    ) -> XCBRectangleIterator;
+    pub fn xcb_shape_get_rectangles_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBShapeGetRectanglesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBShapeGetRectanglesReply;
+    pub fn xcb_shape_get_rectangles(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        source_kind: XCBShapeKind,
+    ) -> XCBShapeGetRectanglesCookie;
+    pub fn xcb_shape_get_rectangles_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        source_kind: XCBShapeKind,
+    ) -> XCBShapeGetRectanglesCookie;
+}
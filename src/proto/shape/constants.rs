@@ -0,0 +1,22 @@
+// Generated by xcbgen from xml/shape.xml, do not edit.
+
+pub const XCB_SHAPE_MAJOR_VERSION: u32 = 1;
+pub const XCB_SHAPE_MINOR_VERSION: u32 = 1;
+pub const XCB_SHAPE_SO_SET: u8 = 0;
+pub const XCB_SHAPE_SO_UNION: u8 = 1;
+pub const XCB_SHAPE_SO_INTERSECT: u8 = 2;
+pub const XCB_SHAPE_SO_SUBTRACT: u8 = 3;
+pub const XCB_SHAPE_SO_INVERT: u8 = 4;
+pub const XCB_SHAPE_SK_BOUNDING: u8 = 0;
+pub const XCB_SHAPE_SK_CLIP: u8 = 1;
+pub const XCB_SHAPE_SK_INPUT: u8 = 2;
+pub const XCB_SHAPE_NOTIFY: u8 = 0;
+pub const XCB_SHAPE_QUERY_VERSION: u8 = 0;
+pub const XCB_SHAPE_RECTANGLES: u8 = 1;
+pub const XCB_SHAPE_MASK: u8 = 2;
+pub const XCB_SHAPE_COMBINE: u8 = 3;
+pub const XCB_SHAPE_OFFSET: u8 = 4;
+pub const XCB_SHAPE_QUERY_EXTENTS: u8 = 5;
+pub const XCB_SHAPE_SELECT_INPUT: u8 = 6;
+pub const XCB_SHAPE_INPUT_SELECTED: u8 = 7;
+pub const XCB_SHAPE_GET_RECTANGLES: u8 = 8;
@@ -0,0 +1,249 @@
+// Generated by xcbgen from xml/shape.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use proto::xproto::{XCBPixmap, XCBRectangle, XCBWindow};
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBShapeOp = cdef::XCBShapeOp;
+pub type XCBShapeOpIterator = cdef::XCBShapeOpIterator;
+pub type XCBShapeKind = cdef::XCBShapeKind;
+pub type XCBShapeKindIterator = cdef::XCBShapeKindIterator;
+pub type XCBShapeNotifyEvent = cdef::XCBShapeNotifyEvent;
+pub type XCBShapeQueryVersionRequest = cdef::XCBShapeQueryVersionRequest;
+pub type XCBShapeQueryVersionCookie = cdef::XCBShapeQueryVersionCookie;
+pub type XCBShapeQueryVersionReply = cdef::XCBShapeQueryVersionReply;
+pub type XCBShapeRectanglesRequest = cdef::XCBShapeRectanglesRequest;
+pub type XCBShapeMaskRequest = cdef::XCBShapeMaskRequest;
+pub type XCBShapeCombineRequest = cdef::XCBShapeCombineRequest;
+pub type XCBShapeOffsetRequest = cdef::XCBShapeOffsetRequest;
+pub type XCBShapeQueryExtentsRequest = cdef::XCBShapeQueryExtentsRequest;
+pub type XCBShapeQueryExtentsCookie = cdef::XCBShapeQueryExtentsCookie;
+pub type XCBShapeQueryExtentsReply = cdef::XCBShapeQueryExtentsReply;
+pub type XCBShapeSelectInputRequest = cdef::XCBShapeSelectInputRequest;
+pub type XCBShapeInputSelectedRequest = cdef::XCBShapeInputSelectedRequest;
+pub type XCBShapeInputSelectedCookie = cdef::XCBShapeInputSelectedCookie;
+pub type XCBShapeInputSelectedReply = cdef::XCBShapeInputSelectedReply;
+pub type XCBShapeGetRectanglesRequest = cdef::XCBShapeGetRectanglesRequest;
+pub type XCBShapeGetRectanglesCookie = cdef::XCBShapeGetRectanglesCookie;
+pub type XCBShapeGetRectanglesReply = cdef::XCBShapeGetRectanglesReply;
+
+/// The Shape extension, known to the server as `SHAPE`.
+pub struct Shape;
+
+impl Extension for Shape {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_shape_id)
+    }
+}
+
+pub fn xcb_shape_op_next(iter: &mut XCBShapeOpIterator) {
+    unsafe { cdef::xcb_shape_op_next(iter) }
+}
+
+pub fn xcb_shape_kind_next(iter: &mut XCBShapeKindIterator) {
+    unsafe { cdef::xcb_shape_kind_next(iter) }
+}
+
+impl Reply for XCBShapeQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_shape_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_shape_query_version(connection: &XCBConnection) -> XCBShapeQueryVersionCookie {
+    unsafe { cdef::xcb_shape_query_version(connection.raw) }
+}
+
+pub fn xcb_shape_rectangles(
+    connection: &XCBConnection,
+    operation: XCBShapeOp,
+    destination_kind: XCBShapeKind,
+    ordering: u8,
+    destination_window: XCBWindow,
+    x_offset: i16,
+    y_offset: i16,
+    rectangles: &[XCBRectangle],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_shape_rectangles(
+            connection.raw,
+            operation,
+            destination_kind,
+            ordering,
+            destination_window,
+            x_offset,
+            y_offset,
+            rectangles.len() as u32,
+            rectangles.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_shape_mask(
+    connection: &XCBConnection,
+    operation: XCBShapeOp,
+    destination_kind: XCBShapeKind,
+    destination_window: XCBWindow,
+    x_offset: i16,
+    y_offset: i16,
+    source_bitmap: XCBPixmap,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_shape_mask(
+            connection.raw,
+            operation,
+            destination_kind,
+            destination_window,
+            x_offset,
+            y_offset,
+            source_bitmap,
+        )
+    }
+}
+
+pub fn xcb_shape_combine(
+    connection: &XCBConnection,
+    operation: XCBShapeOp,
+    destination_kind: XCBShapeKind,
+    source_kind: XCBShapeKind,
+    destination_window: XCBWindow,
+    x_offset: i16,
+    y_offset: i16,
+    source_window: XCBWindow,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_shape_combine(
+            connection.raw,
+            operation,
+            destination_kind,
+            source_kind,
+            destination_window,
+            x_offset,
+            y_offset,
+            source_window,
+        )
+    }
+}
+
+pub fn xcb_shape_offset(
+    connection: &XCBConnection,
+    destination_kind: XCBShapeKind,
+    destination_window: XCBWindow,
+    x_offset: i16,
+    y_offset: i16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_shape_offset(
+            connection.raw,
+            destination_kind,
+            destination_window,
+            x_offset,
+            y_offset,
+        )
+    }
+}
+
+impl Reply for XCBShapeQueryExtentsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_shape_query_extents_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_shape_query_extents(
+    connection: &XCBConnection,
+    destination_window: XCBWindow,
+) -> XCBShapeQueryExtentsCookie {
+    unsafe { cdef::xcb_shape_query_extents(connection.raw, destination_window) }
+}
+
+pub fn xcb_shape_select_input(
+    connection: &XCBConnection,
+    destination_window: XCBWindow,
+    enable: bool,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_shape_select_input(connection.raw, destination_window, enable as u8) }
+}
+
+impl Reply for XCBShapeInputSelectedReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_shape_input_selected_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_shape_input_selected(
+    connection: &XCBConnection,
+    destination_window: XCBWindow,
+) -> XCBShapeInputSelectedCookie {
+    unsafe { cdef::xcb_shape_input_selected(connection.raw, destination_window) }
+}
+
+pub fn xcb_shape_get_rectangles_rectangles(reply: &XCBShapeGetRectanglesReply) -> &[XCBRectangle] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_shape_get_rectangles_rectangles(reply),
+            cdef::xcb_shape_get_rectangles_rectangles_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBShapeGetRectanglesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_shape_get_rectangles_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_shape_get_rectangles(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    source_kind: XCBShapeKind,
+) -> XCBShapeGetRectanglesCookie {
+    unsafe { cdef::xcb_shape_get_rectangles(connection.raw, window, source_kind) }
+}
+
+/// An event of the Shape extension.
+pub enum Event {
+    Notify(Box<XCBShapeNotifyEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::Notify(Box::from_raw(
+                    raw as *mut XCBShapeNotifyEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
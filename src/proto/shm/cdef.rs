@@ -0,0 +1,338 @@
+// Generated by xcbgen from xml/shm.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xproto::cdef::{XCBDrawable, XCBGcontext, XCBPixmap, XCBValueError, XCBVisualid};
+
+pub type XCBShmSeg = u32;
+
+#[repr(C)]
+pub struct XCBShmSegIterator {
+    pub data: *mut XCBShmSeg,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShmCompletionEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub drawable: XCBDrawable,
+    pub minor_event: u16,
+    pub major_event: u8,
+    pub pad1: u8,
+    pub shmseg: XCBShmSeg,
+    pub offset: u32,
+}
+
+pub type XCBShmBadSegError = XCBValueError;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShmQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBShmQueryVersionCookie = XCBCookie<XCBShmQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShmQueryVersionReply {
+    pub response_type: u8,
+    pub shared_pixmaps: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+    pub uid: u16,
+    pub gid: u16,
+    pub pixmap_format: u8,
+    pub pad0: [u8; 15],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShmAttachRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub shmseg: XCBShmSeg,
+    pub shmid: u32,
+    pub read_only: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShmDetachRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub shmseg: XCBShmSeg,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShmPutImageRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub total_width: u16,
+    pub total_height: u16,
+    pub src_x: u16,
+    pub src_y: u16,
+    pub src_width: u16,
+    pub src_height: u16,
+    pub dst_x: i16,
+    pub dst_y: i16,
+    pub depth: u8,
+    pub format: u8,
+    pub send_event: u8,
+    pub pad0: u8,
+    pub shmseg: XCBShmSeg,
+    pub offset: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShmGetImageRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub plane_mask: u32,
+    pub format: u8,
+    pub pad0: [u8; 3],
+    pub shmseg: XCBShmSeg,
+    pub offset: u32,
+}
+
+pub type XCBShmGetImageCookie = XCBCookie<XCBShmGetImageReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShmGetImageReply {
+    pub response_type: u8,
+    pub depth: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub visual: XCBVisualid,
+    pub size: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShmCreatePixmapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub pid: XCBPixmap,
+    pub drawable: XCBDrawable,
+    pub width: u16,
+    pub height: u16,
+    pub depth: u8,
+    pub pad0: [u8; 3],
+    pub shmseg: XCBShmSeg,
+    pub offset: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShmAttachFdRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub shmseg: XCBShmSeg,
+    pub read_only: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShmCreateSegmentRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub shmseg: XCBShmSeg,
+    pub size: u32,
+    pub read_only: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBShmCreateSegmentCookie = XCBCookie<XCBShmCreateSegmentReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBShmCreateSegmentReply {
+    pub response_type: u8,
+    pub nfd: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad0: [u8; 24],
+}
+
+#[link(name = "xcb-shm")]
+extern "system" {
+    pub static mut xcb_shm_id: XCBExtension;
+    pub fn xcb_shm_seg_next(i: *mut XCBShmSegIterator);
+    pub fn xcb_shm_seg_end(i: XCBShmSegIterator) -> XCBGenericIterator;
+    pub fn xcb_shm_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBShmQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBShmQueryVersionReply;
+    pub fn xcb_shm_query_version(connection: *mut XCBConnection) -> XCBShmQueryVersionCookie;
+    pub fn xcb_shm_query_version_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBShmQueryVersionCookie;
+    pub fn xcb_shm_attach_checked(
+        connection: *mut XCBConnection,
+        shmseg: XCBShmSeg,
+        shmid: u32,
+        read_only: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shm_attach(
+        connection: *mut XCBConnection,
+        shmseg: XCBShmSeg,
+        shmid: u32,
+        read_only: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shm_detach_checked(
+        connection: *mut XCBConnection,
+        shmseg: XCBShmSeg,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shm_detach(connection: *mut XCBConnection, shmseg: XCBShmSeg) -> XCBVoidCookie;
+    pub fn xcb_shm_put_image_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        total_width: u16,
+        total_height: u16,
+        src_x: u16,
+        src_y: u16,
+        src_width: u16,
+        src_height: u16,
+        dst_x: i16,
+        dst_y: i16,
+        depth: u8,
+        format: u8,
+        send_event: u8,
+        shmseg: XCBShmSeg,
+        offset: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shm_put_image(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        total_width: u16,
+        total_height: u16,
+        src_x: u16,
+        src_y: u16,
+        src_width: u16,
+        src_height: u16,
+        dst_x: i16,
+        dst_y: i16,
+        depth: u8,
+        format: u8,
+        send_event: u8,
+        shmseg: XCBShmSeg,
+        offset: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shm_get_image_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBShmGetImageCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBShmGetImageReply;
+    pub fn xcb_shm_get_image(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        plane_mask: u32,
+        format: u8,
+        shmseg: XCBShmSeg,
+        offset: u32,
+    ) -> XCBShmGetImageCookie;
+    pub fn xcb_shm_get_image_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        plane_mask: u32,
+        format: u8,
+        shmseg: XCBShmSeg,
+        offset: u32,
+    ) -> XCBShmGetImageCookie;
+    pub fn xcb_shm_create_pixmap_checked(
+        connection: *mut XCBConnection,
+        pid: XCBPixmap,
+        drawable: XCBDrawable,
+        width: u16,
+        height: u16,
+        depth: u8,
+        shmseg: XCBShmSeg,
+        offset: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shm_create_pixmap(
+        connection: *mut XCBConnection,
+        pid: XCBPixmap,
+        drawable: XCBDrawable,
+        width: u16,
+        height: u16,
+        depth: u8,
+        shmseg: XCBShmSeg,
+        offset: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shm_attach_fd_checked(
+        connection: *mut XCBConnection,
+        shmseg: XCBShmSeg,
+        shm_fd: i32,
+        read_only: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shm_attach_fd(
+        connection: *mut XCBConnection,
+        shmseg: XCBShmSeg,
+        shm_fd: i32,
+        read_only: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_shm_create_segment_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBShmCreateSegmentCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBShmCreateSegmentReply;
+    pub fn xcb_shm_create_segment_reply_fds(
+        connection: *mut XCBConnection,
+        reply: *mut XCBShmCreateSegmentReply,
+    ) -> *mut c_int;
+    pub fn xcb_shm_create_segment(
+        connection: *mut XCBConnection,
+        shmseg: XCBShmSeg,
+        size: u32,
+        read_only: u8,
+    ) -> XCBShmCreateSegmentCookie;
+    pub fn xcb_shm_create_segment_unchecked(
+        connection: *mut XCBConnection,
+        shmseg: XCBShmSeg,
+        size: u32,
+        read_only: u8,
+    ) -> XCBShmCreateSegmentCookie;
+}
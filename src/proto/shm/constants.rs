@@ -0,0 +1,14 @@
+// Generated by xcbgen from xml/shm.xml, do not edit.
+
+pub const XCB_SHM_MAJOR_VERSION: u32 = 1;
+pub const XCB_SHM_MINOR_VERSION: u32 = 2;
+pub const XCB_SHM_COMPLETION: u8 = 0;
+pub const XCB_SHM_BAD_SEG: u8 = 0;
+pub const XCB_SHM_QUERY_VERSION: u8 = 0;
+pub const XCB_SHM_ATTACH: u8 = 1;
+pub const XCB_SHM_DETACH: u8 = 2;
+pub const XCB_SHM_PUT_IMAGE: u8 = 3;
+pub const XCB_SHM_GET_IMAGE: u8 = 4;
+pub const XCB_SHM_CREATE_PIXMAP: u8 = 5;
+pub const XCB_SHM_ATTACH_FD: u8 = 6;
+pub const XCB_SHM_CREATE_SEGMENT: u8 = 7;
@@ -0,0 +1,233 @@
+// Generated by xcbgen from xml/shm.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+
+use proto::xproto::{XCBDrawable, XCBGcontext, XCBPixmap};
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBShmSeg = cdef::XCBShmSeg;
+pub type XCBShmSegIterator = cdef::XCBShmSegIterator;
+pub type XCBShmCompletionEvent = cdef::XCBShmCompletionEvent;
+pub type XCBShmBadSegError = cdef::XCBShmBadSegError;
+pub type XCBShmQueryVersionRequest = cdef::XCBShmQueryVersionRequest;
+pub type XCBShmQueryVersionCookie = cdef::XCBShmQueryVersionCookie;
+pub type XCBShmQueryVersionReply = cdef::XCBShmQueryVersionReply;
+pub type XCBShmAttachRequest = cdef::XCBShmAttachRequest;
+pub type XCBShmDetachRequest = cdef::XCBShmDetachRequest;
+pub type XCBShmPutImageRequest = cdef::XCBShmPutImageRequest;
+pub type XCBShmGetImageRequest = cdef::XCBShmGetImageRequest;
+pub type XCBShmGetImageCookie = cdef::XCBShmGetImageCookie;
+pub type XCBShmGetImageReply = cdef::XCBShmGetImageReply;
+pub type XCBShmCreatePixmapRequest = cdef::XCBShmCreatePixmapRequest;
+pub type XCBShmAttachFdRequest = cdef::XCBShmAttachFdRequest;
+pub type XCBShmCreateSegmentRequest = cdef::XCBShmCreateSegmentRequest;
+pub type XCBShmCreateSegmentCookie = cdef::XCBShmCreateSegmentCookie;
+pub type XCBShmCreateSegmentReply = cdef::XCBShmCreateSegmentReply;
+
+/// The Shm extension, known to the server as `MIT-SHM`.
+pub struct Shm;
+
+impl Extension for Shm {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_shm_id)
+    }
+}
+
+pub fn xcb_shm_seg_next(iter: &mut XCBShmSegIterator) {
+    unsafe { cdef::xcb_shm_seg_next(iter) }
+}
+
+impl Reply for XCBShmQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_shm_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_shm_query_version(connection: &XCBConnection) -> XCBShmQueryVersionCookie {
+    unsafe { cdef::xcb_shm_query_version(connection.raw) }
+}
+
+pub fn xcb_shm_attach(
+    connection: &XCBConnection,
+    shmseg: XCBShmSeg,
+    shmid: u32,
+    read_only: bool,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_shm_attach(connection.raw, shmseg, shmid, read_only as u8) }
+}
+
+pub fn xcb_shm_detach(connection: &XCBConnection, shmseg: XCBShmSeg) -> XCBVoidCookie {
+    unsafe { cdef::xcb_shm_detach(connection.raw, shmseg) }
+}
+
+pub fn xcb_shm_put_image(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    total_width: u16,
+    total_height: u16,
+    src_x: u16,
+    src_y: u16,
+    src_width: u16,
+    src_height: u16,
+    dst_x: i16,
+    dst_y: i16,
+    depth: u8,
+    format: u8,
+    send_event: bool,
+    shmseg: XCBShmSeg,
+    offset: u32,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_shm_put_image(
+            connection.raw,
+            drawable,
+            gc,
+            total_width,
+            total_height,
+            src_x,
+            src_y,
+            src_width,
+            src_height,
+            dst_x,
+            dst_y,
+            depth,
+            format,
+            send_event as u8,
+            shmseg,
+            offset,
+        )
+    }
+}
+
+impl Reply for XCBShmGetImageReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_shm_get_image_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_shm_get_image(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+    plane_mask: u32,
+    format: u8,
+    shmseg: XCBShmSeg,
+    offset: u32,
+) -> XCBShmGetImageCookie {
+    unsafe {
+        cdef::xcb_shm_get_image(
+            connection.raw,
+            drawable,
+            x,
+            y,
+            width,
+            height,
+            plane_mask,
+            format,
+            shmseg,
+            offset,
+        )
+    }
+}
+
+pub fn xcb_shm_create_pixmap(
+    connection: &XCBConnection,
+    pid: XCBPixmap,
+    drawable: XCBDrawable,
+    width: u16,
+    height: u16,
+    depth: u8,
+    shmseg: XCBShmSeg,
+    offset: u32,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_shm_create_pixmap(
+            connection.raw,
+            pid,
+            drawable,
+            width,
+            height,
+            depth,
+            shmseg,
+            offset,
+        )
+    }
+}
+
+pub fn xcb_shm_attach_fd(
+    connection: &XCBConnection,
+    shmseg: XCBShmSeg,
+    shm_fd: i32,
+    read_only: bool,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_shm_attach_fd(connection.raw, shmseg, shm_fd, read_only as u8) }
+}
+
+impl Reply for XCBShmCreateSegmentReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_shm_create_segment_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_shm_create_segment(
+    connection: &XCBConnection,
+    shmseg: XCBShmSeg,
+    size: u32,
+    read_only: bool,
+) -> XCBShmCreateSegmentCookie {
+    unsafe { cdef::xcb_shm_create_segment(connection.raw, shmseg, size, read_only as u8) }
+}
+
+/// An event of the Shm extension.
+pub enum Event {
+    Completion(Box<XCBShmCompletionEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::Completion(Box::from_raw(
+                    raw as *mut XCBShmCompletionEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
+
+/// Returns the name of the error whose code, relative to the first error of the extension, is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        0 => Some("BadSeg"),
+        _ => None,
+    }
+}
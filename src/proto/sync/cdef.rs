@@ -0,0 +1,649 @@
+// Generated by xcbgen from xml/sync.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int, c_void};
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xproto::cdef::{XCBDrawable, XCBTimestamp};
+
+pub type XCBSyncAlarm = u32;
+
+#[repr(C)]
+pub struct XCBSyncAlarmIterator {
+    pub data: *mut XCBSyncAlarm,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBSyncCounter = u32;
+
+#[repr(C)]
+pub struct XCBSyncCounterIterator {
+    pub data: *mut XCBSyncCounter,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBSyncFence = u32;
+
+#[repr(C)]
+pub struct XCBSyncFenceIterator {
+    pub data: *mut XCBSyncFence,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncInt64 {
+    pub hi: i32,
+    pub lo: u32,
+}
+
+#[repr(C)]
+pub struct XCBSyncInt64Iterator {
+    pub data: *mut XCBSyncInt64,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBSyncSystemcounter {
+    pub counter: XCBSyncCounter,
+    pub resolution: XCBSyncInt64,
+    pub name_len: u16,
+}
+
+#[repr(C)]
+pub struct XCBSyncSystemcounterIterator {
+    pub data: *mut XCBSyncSystemcounter,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncTrigger {
+    pub counter: XCBSyncCounter,
+    pub wait_type: u32,
+    pub wait_value: XCBSyncInt64,
+    pub test_type: u32,
+}
+
+#[repr(C)]
+pub struct XCBSyncTriggerIterator {
+    pub data: *mut XCBSyncTrigger,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncWaitcondition {
+    pub trigger: XCBSyncTrigger,
+    pub event_threshold: XCBSyncInt64,
+}
+
+#[repr(C)]
+pub struct XCBSyncWaitconditionIterator {
+    pub data: *mut XCBSyncWaitcondition,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncCounterError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+    pub bad_counter: u32,
+    pub minor_opcode: u16,
+    pub major_opcode: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncAlarmError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+    pub bad_alarm: u32,
+    pub minor_opcode: u16,
+    pub major_opcode: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncInitializeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub desired_major_version: u8,
+    pub desired_minor_version: u8,
+}
+
+pub type XCBSyncInitializeCookie = XCBCookie<XCBSyncInitializeReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncInitializeReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u8,
+    pub minor_version: u8,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncListSystemCountersRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBSyncListSystemCountersCookie = XCBCookie<XCBSyncListSystemCountersReply>;
+
+#[repr(C)]
+pub struct XCBSyncListSystemCountersReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub counters_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncCreateCounterRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub id: XCBSyncCounter,
+    pub initial_value: XCBSyncInt64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncDestroyCounterRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub counter: XCBSyncCounter,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncQueryCounterRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub counter: XCBSyncCounter,
+}
+
+pub type XCBSyncQueryCounterCookie = XCBCookie<XCBSyncQueryCounterReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncQueryCounterReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub counter_value: XCBSyncInt64,
+}
+
+#[repr(C)]
+pub struct XCBSyncAwaitRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncChangeCounterRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub counter: XCBSyncCounter,
+    pub amount: XCBSyncInt64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncSetCounterRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub counter: XCBSyncCounter,
+    pub value: XCBSyncInt64,
+}
+
+#[repr(C)]
+pub struct XCBSyncCreateAlarmRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub id: XCBSyncAlarm,
+    pub value_mask: u32,
+}
+
+#[repr(C)]
+pub struct XCBSyncChangeAlarmRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub id: XCBSyncAlarm,
+    pub value_mask: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncDestroyAlarmRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub alarm: XCBSyncAlarm,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncQueryAlarmRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub alarm: XCBSyncAlarm,
+}
+
+pub type XCBSyncQueryAlarmCookie = XCBCookie<XCBSyncQueryAlarmReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncQueryAlarmReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub trigger: XCBSyncTrigger,
+    pub delta: XCBSyncInt64,
+    pub events: u8,
+    pub state: u8,
+    pub pad1: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncSetPriorityRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub id: u32,
+    pub priority: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncGetPriorityRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub id: u32,
+}
+
+pub type XCBSyncGetPriorityCookie = XCBCookie<XCBSyncGetPriorityReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncGetPriorityReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub priority: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncCreateFenceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub fence: XCBSyncFence,
+    pub initially_triggered: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncTriggerFenceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub fence: XCBSyncFence,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncResetFenceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub fence: XCBSyncFence,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncDestroyFenceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub fence: XCBSyncFence,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncQueryFenceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub fence: XCBSyncFence,
+}
+
+pub type XCBSyncQueryFenceCookie = XCBCookie<XCBSyncQueryFenceReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncQueryFenceReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub triggered: u8,
+    pub pad1: [u8; 23],
+}
+
+#[repr(C)]
+pub struct XCBSyncAwaitFenceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncCounterNotifyEvent {
+    pub response_type: u8,
+    pub kind: u8,
+    pub sequence: u16,
+    pub counter: XCBSyncCounter,
+    pub wait_value: XCBSyncInt64,
+    pub counter_value: XCBSyncInt64,
+    pub timestamp: XCBTimestamp,
+    pub count: u16,
+    pub destroyed: u8,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSyncAlarmNotifyEvent {
+    pub response_type: u8,
+    pub kind: u8,
+    pub sequence: u16,
+    pub alarm: XCBSyncAlarm,
+    pub counter_value: XCBSyncInt64,
+    pub alarm_value: XCBSyncInt64,
+    pub timestamp: XCBTimestamp,
+    pub state: u8,
+    pub pad0: [u8; 3],
+}
+
+#[link(name = "xcb-sync")]
+extern "system" {
+    pub static mut xcb_sync_id: XCBExtension;
+    pub fn xcb_sync_alarm_next(i: *mut XCBSyncAlarmIterator);
+    pub fn xcb_sync_alarm_end(i: XCBSyncAlarmIterator) -> XCBGenericIterator;
+    pub fn xcb_sync_counter_next(i: *mut XCBSyncCounterIterator);
+    pub fn xcb_sync_counter_end(i: XCBSyncCounterIterator) -> XCBGenericIterator;
+    pub fn xcb_sync_fence_next(i: *mut XCBSyncFenceIterator);
+    pub fn xcb_sync_fence_end(i: XCBSyncFenceIterator) -> XCBGenericIterator;
+    pub fn xcb_sync_int64_next(i: *mut XCBSyncInt64Iterator);
+    pub fn xcb_sync_int64_end(i: XCBSyncInt64Iterator) -> XCBGenericIterator;
+    pub fn xcb_sync_systemcounter_next(i: *mut XCBSyncSystemcounterIterator);
+    pub fn xcb_sync_systemcounter_end(i: XCBSyncSystemcounterIterator) -> XCBGenericIterator;
+    pub fn xcb_sync_systemcounter_name(R: *const XCBSyncSystemcounter) -> *mut c_char;
+    pub fn xcb_sync_systemcounter_name_length(R: *const XCBSyncSystemcounter) -> c_int;
+    pub fn xcb_sync_systemcounter_name_end(R: *const XCBSyncSystemcounter) -> XCBGenericIterator;
+    pub fn xcb_sync_trigger_next(i: *mut XCBSyncTriggerIterator);
+    pub fn xcb_sync_trigger_end(i: XCBSyncTriggerIterator) -> XCBGenericIterator;
+    pub fn xcb_sync_waitcondition_next(i: *mut XCBSyncWaitconditionIterator);
+    pub fn xcb_sync_waitcondition_end(i: XCBSyncWaitconditionIterator) -> XCBGenericIterator;
+    pub fn xcb_sync_initialize_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSyncInitializeCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSyncInitializeReply;
+    pub fn xcb_sync_initialize(
+        connection: *mut XCBConnection,
+        desired_major_version: u8,
+        desired_minor_version: u8,
+    ) -> XCBSyncInitializeCookie;
+    pub fn xcb_sync_initialize_unchecked(
+        connection: *mut XCBConnection,
+        desired_major_version: u8,
+        desired_minor_version: u8,
+    ) -> XCBSyncInitializeCookie;
+    pub fn xcb_sync_list_system_counters_counters_length(
+        R: *const XCBSyncListSystemCountersReply,
+    ) -> c_int;
+    pub fn xcb_sync_list_system_counters_counters_iterator(
+        R: *const XCBSyncListSystemCountersReply,
+    ) -> XCBSyncSystemcounterIterator;
+    pub fn xcb_sync_list_system_counters_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSyncListSystemCountersCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSyncListSystemCountersReply;
+    pub fn xcb_sync_list_system_counters(
+        connection: *mut XCBConnection,
+    ) -> XCBSyncListSystemCountersCookie;
+    pub fn xcb_sync_list_system_counters_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBSyncListSystemCountersCookie;
+    pub fn xcb_sync_create_counter_checked(
+        connection: *mut XCBConnection,
+        id: XCBSyncCounter,
+        initial_value: XCBSyncInt64,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_create_counter(
+        connection: *mut XCBConnection,
+        id: XCBSyncCounter,
+        initial_value: XCBSyncInt64,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_destroy_counter_checked(
+        connection: *mut XCBConnection,
+        counter: XCBSyncCounter,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_destroy_counter(
+        connection: *mut XCBConnection,
+        counter: XCBSyncCounter,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_query_counter_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSyncQueryCounterCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSyncQueryCounterReply;
+    pub fn xcb_sync_query_counter(
+        connection: *mut XCBConnection,
+        counter: XCBSyncCounter,
+    ) -> XCBSyncQueryCounterCookie;
+    pub fn xcb_sync_query_counter_unchecked(
+        connection: *mut XCBConnection,
+        counter: XCBSyncCounter,
+    ) -> XCBSyncQueryCounterCookie;
+    pub fn xcb_sync_await_checked(
+        connection: *mut XCBConnection,
+        wait_list_len: u32,
+        wait_list: *const XCBSyncWaitcondition,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_await(
+        connection: *mut XCBConnection,
+        wait_list_len: u32,
+        wait_list: *const XCBSyncWaitcondition,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_change_counter_checked(
+        connection: *mut XCBConnection,
+        counter: XCBSyncCounter,
+        amount: XCBSyncInt64,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_change_counter(
+        connection: *mut XCBConnection,
+        counter: XCBSyncCounter,
+        amount: XCBSyncInt64,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_set_counter_checked(
+        connection: *mut XCBConnection,
+        counter: XCBSyncCounter,
+        value: XCBSyncInt64,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_set_counter(
+        connection: *mut XCBConnection,
+        counter: XCBSyncCounter,
+        value: XCBSyncInt64,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_create_alarm_checked(
+        connection: *mut XCBConnection,
+        id: XCBSyncAlarm,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_create_alarm(
+        connection: *mut XCBConnection,
+        id: XCBSyncAlarm,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_change_alarm_checked(
+        connection: *mut XCBConnection,
+        id: XCBSyncAlarm,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_change_alarm(
+        connection: *mut XCBConnection,
+        id: XCBSyncAlarm,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_destroy_alarm_checked(
+        connection: *mut XCBConnection,
+        alarm: XCBSyncAlarm,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_destroy_alarm(
+        connection: *mut XCBConnection,
+        alarm: XCBSyncAlarm,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_query_alarm_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSyncQueryAlarmCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSyncQueryAlarmReply;
+    pub fn xcb_sync_query_alarm(
+        connection: *mut XCBConnection,
+        alarm: XCBSyncAlarm,
+    ) -> XCBSyncQueryAlarmCookie;
+    pub fn xcb_sync_query_alarm_unchecked(
+        connection: *mut XCBConnection,
+        alarm: XCBSyncAlarm,
+    ) -> XCBSyncQueryAlarmCookie;
+    pub fn xcb_sync_set_priority_checked(
+        connection: *mut XCBConnection,
+        id: u32,
+        priority: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_set_priority(
+        connection: *mut XCBConnection,
+        id: u32,
+        priority: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_get_priority_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSyncGetPriorityCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSyncGetPriorityReply;
+    pub fn xcb_sync_get_priority(
+        connection: *mut XCBConnection,
+        id: u32,
+    ) -> XCBSyncGetPriorityCookie;
+    pub fn xcb_sync_get_priority_unchecked(
+        connection: *mut XCBConnection,
+        id: u32,
+    ) -> XCBSyncGetPriorityCookie;
+    pub fn xcb_sync_create_fence_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        fence: XCBSyncFence,
+        initially_triggered: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_create_fence(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        fence: XCBSyncFence,
+        initially_triggered: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_trigger_fence_checked(
+        connection: *mut XCBConnection,
+        fence: XCBSyncFence,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_trigger_fence(
+        connection: *mut XCBConnection,
+        fence: XCBSyncFence,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_reset_fence_checked(
+        connection: *mut XCBConnection,
+        fence: XCBSyncFence,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_reset_fence(
+        connection: *mut XCBConnection,
+        fence: XCBSyncFence,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_destroy_fence_checked(
+        connection: *mut XCBConnection,
+        fence: XCBSyncFence,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_destroy_fence(
+        connection: *mut XCBConnection,
+        fence: XCBSyncFence,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_query_fence_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSyncQueryFenceCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSyncQueryFenceReply;
+    pub fn xcb_sync_query_fence(
+        connection: *mut XCBConnection,
+        fence: XCBSyncFence,
+    ) -> XCBSyncQueryFenceCookie;
+    pub fn xcb_sync_query_fence_unchecked(
+        connection: *mut XCBConnection,
+        fence: XCBSyncFence,
+    ) -> XCBSyncQueryFenceCookie;
+    pub fn xcb_sync_await_fence_checked(
+        connection: *mut XCBConnection,
+        fence_list_len: u32,
+        fence_list: *const XCBSyncFence,
+    ) -> XCBVoidCookie;
+    pub fn xcb_sync_await_fence(
+        connection: *mut XCBConnection,
+        fence_list_len: u32,
+        fence_list: *const XCBSyncFence,
+    ) -> XCBVoidCookie;
+}
@@ -0,0 +1,43 @@
+// Generated by xcbgen from xml/sync.xml, do not edit.
+
+pub const XCB_SYNC_MAJOR_VERSION: u32 = 3;
+pub const XCB_SYNC_MINOR_VERSION: u32 = 1;
+pub const XCB_SYNC_ALARMSTATE_ACTIVE: u8 = 0;
+pub const XCB_SYNC_ALARMSTATE_INACTIVE: u8 = 1;
+pub const XCB_SYNC_ALARMSTATE_DESTROYED: u8 = 2;
+pub const XCB_SYNC_TESTTYPE_POSITIVE_TRANSITION: u32 = 0;
+pub const XCB_SYNC_TESTTYPE_NEGATIVE_TRANSITION: u32 = 1;
+pub const XCB_SYNC_TESTTYPE_POSITIVE_COMPARISON: u32 = 2;
+pub const XCB_SYNC_TESTTYPE_NEGATIVE_COMPARISON: u32 = 3;
+pub const XCB_SYNC_VALUETYPE_ABSOLUTE: u32 = 0;
+pub const XCB_SYNC_VALUETYPE_RELATIVE: u32 = 1;
+pub const XCB_SYNC_CA_COUNTER: u32 = 1;
+pub const XCB_SYNC_CA_VALUE_TYPE: u32 = 2;
+pub const XCB_SYNC_CA_VALUE: u32 = 4;
+pub const XCB_SYNC_CA_TEST_TYPE: u32 = 8;
+pub const XCB_SYNC_CA_DELTA: u32 = 16;
+pub const XCB_SYNC_CA_EVENTS: u32 = 32;
+pub const XCB_SYNC_COUNTER: u8 = 0;
+pub const XCB_SYNC_ALARM: u8 = 1;
+pub const XCB_SYNC_INITIALIZE: u8 = 0;
+pub const XCB_SYNC_LIST_SYSTEM_COUNTERS: u8 = 1;
+pub const XCB_SYNC_CREATE_COUNTER: u8 = 2;
+pub const XCB_SYNC_DESTROY_COUNTER: u8 = 6;
+pub const XCB_SYNC_QUERY_COUNTER: u8 = 5;
+pub const XCB_SYNC_AWAIT: u8 = 7;
+pub const XCB_SYNC_CHANGE_COUNTER: u8 = 4;
+pub const XCB_SYNC_SET_COUNTER: u8 = 3;
+pub const XCB_SYNC_CREATE_ALARM: u8 = 8;
+pub const XCB_SYNC_CHANGE_ALARM: u8 = 9;
+pub const XCB_SYNC_DESTROY_ALARM: u8 = 11;
+pub const XCB_SYNC_QUERY_ALARM: u8 = 10;
+pub const XCB_SYNC_SET_PRIORITY: u8 = 12;
+pub const XCB_SYNC_GET_PRIORITY: u8 = 13;
+pub const XCB_SYNC_CREATE_FENCE: u8 = 14;
+pub const XCB_SYNC_TRIGGER_FENCE: u8 = 15;
+pub const XCB_SYNC_RESET_FENCE: u8 = 16;
+pub const XCB_SYNC_DESTROY_FENCE: u8 = 17;
+pub const XCB_SYNC_QUERY_FENCE: u8 = 18;
+pub const XCB_SYNC_AWAIT_FENCE: u8 = 19;
+pub const XCB_SYNC_COUNTER_NOTIFY: u8 = 0;
+pub const XCB_SYNC_ALARM_NOTIFY: u8 = 1;
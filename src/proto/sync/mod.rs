@@ -0,0 +1,338 @@
+// Generated by xcbgen from xml/sync.xml, do not edit.
+
+//! Requests whose parameters the safe wrappers cannot express only have raw bindings
+//! in `cdef`:
+//!
+//! - `xcb_sync_create_alarm`
+//! - `xcb_sync_change_alarm`
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use proto::xproto::XCBDrawable;
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBSyncAlarm = cdef::XCBSyncAlarm;
+pub type XCBSyncAlarmIterator = cdef::XCBSyncAlarmIterator;
+pub type XCBSyncCounter = cdef::XCBSyncCounter;
+pub type XCBSyncCounterIterator = cdef::XCBSyncCounterIterator;
+pub type XCBSyncFence = cdef::XCBSyncFence;
+pub type XCBSyncFenceIterator = cdef::XCBSyncFenceIterator;
+pub type XCBSyncInt64 = cdef::XCBSyncInt64;
+pub type XCBSyncInt64Iterator = cdef::XCBSyncInt64Iterator;
+pub type XCBSyncSystemcounter = cdef::XCBSyncSystemcounter;
+pub type XCBSyncSystemcounterIterator = cdef::XCBSyncSystemcounterIterator;
+pub type XCBSyncTrigger = cdef::XCBSyncTrigger;
+pub type XCBSyncTriggerIterator = cdef::XCBSyncTriggerIterator;
+pub type XCBSyncWaitcondition = cdef::XCBSyncWaitcondition;
+pub type XCBSyncWaitconditionIterator = cdef::XCBSyncWaitconditionIterator;
+pub type XCBSyncCounterError = cdef::XCBSyncCounterError;
+pub type XCBSyncAlarmError = cdef::XCBSyncAlarmError;
+pub type XCBSyncInitializeRequest = cdef::XCBSyncInitializeRequest;
+pub type XCBSyncInitializeCookie = cdef::XCBSyncInitializeCookie;
+pub type XCBSyncInitializeReply = cdef::XCBSyncInitializeReply;
+pub type XCBSyncListSystemCountersRequest = cdef::XCBSyncListSystemCountersRequest;
+pub type XCBSyncListSystemCountersCookie = cdef::XCBSyncListSystemCountersCookie;
+pub type XCBSyncListSystemCountersReply = cdef::XCBSyncListSystemCountersReply;
+pub type XCBSyncCreateCounterRequest = cdef::XCBSyncCreateCounterRequest;
+pub type XCBSyncDestroyCounterRequest = cdef::XCBSyncDestroyCounterRequest;
+pub type XCBSyncQueryCounterRequest = cdef::XCBSyncQueryCounterRequest;
+pub type XCBSyncQueryCounterCookie = cdef::XCBSyncQueryCounterCookie;
+pub type XCBSyncQueryCounterReply = cdef::XCBSyncQueryCounterReply;
+pub type XCBSyncAwaitRequest = cdef::XCBSyncAwaitRequest;
+pub type XCBSyncChangeCounterRequest = cdef::XCBSyncChangeCounterRequest;
+pub type XCBSyncSetCounterRequest = cdef::XCBSyncSetCounterRequest;
+pub type XCBSyncCreateAlarmRequest = cdef::XCBSyncCreateAlarmRequest;
+pub type XCBSyncChangeAlarmRequest = cdef::XCBSyncChangeAlarmRequest;
+pub type XCBSyncDestroyAlarmRequest = cdef::XCBSyncDestroyAlarmRequest;
+pub type XCBSyncQueryAlarmRequest = cdef::XCBSyncQueryAlarmRequest;
+pub type XCBSyncQueryAlarmCookie = cdef::XCBSyncQueryAlarmCookie;
+pub type XCBSyncQueryAlarmReply = cdef::XCBSyncQueryAlarmReply;
+pub type XCBSyncSetPriorityRequest = cdef::XCBSyncSetPriorityRequest;
+pub type XCBSyncGetPriorityRequest = cdef::XCBSyncGetPriorityRequest;
+pub type XCBSyncGetPriorityCookie = cdef::XCBSyncGetPriorityCookie;
+pub type XCBSyncGetPriorityReply = cdef::XCBSyncGetPriorityReply;
+pub type XCBSyncCreateFenceRequest = cdef::XCBSyncCreateFenceRequest;
+pub type XCBSyncTriggerFenceRequest = cdef::XCBSyncTriggerFenceRequest;
+pub type XCBSyncResetFenceRequest = cdef::XCBSyncResetFenceRequest;
+pub type XCBSyncDestroyFenceRequest = cdef::XCBSyncDestroyFenceRequest;
+pub type XCBSyncQueryFenceRequest = cdef::XCBSyncQueryFenceRequest;
+pub type XCBSyncQueryFenceCookie = cdef::XCBSyncQueryFenceCookie;
+pub type XCBSyncQueryFenceReply = cdef::XCBSyncQueryFenceReply;
+pub type XCBSyncAwaitFenceRequest = cdef::XCBSyncAwaitFenceRequest;
+pub type XCBSyncCounterNotifyEvent = cdef::XCBSyncCounterNotifyEvent;
+pub type XCBSyncAlarmNotifyEvent = cdef::XCBSyncAlarmNotifyEvent;
+
+/// The Sync extension, known to the server as `SYNC`.
+pub struct Sync;
+
+impl Extension for Sync {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_sync_id)
+    }
+}
+
+pub fn xcb_sync_alarm_next(iter: &mut XCBSyncAlarmIterator) {
+    unsafe { cdef::xcb_sync_alarm_next(iter) }
+}
+
+pub fn xcb_sync_counter_next(iter: &mut XCBSyncCounterIterator) {
+    unsafe { cdef::xcb_sync_counter_next(iter) }
+}
+
+pub fn xcb_sync_fence_next(iter: &mut XCBSyncFenceIterator) {
+    unsafe { cdef::xcb_sync_fence_next(iter) }
+}
+
+pub fn xcb_sync_int64_next(iter: &mut XCBSyncInt64Iterator) {
+    unsafe { cdef::xcb_sync_int64_next(iter) }
+}
+
+pub fn xcb_sync_systemcounter_next(iter: &mut XCBSyncSystemcounterIterator) {
+    unsafe { cdef::xcb_sync_systemcounter_next(iter) }
+}
+
+pub fn xcb_sync_systemcounter_name(data: &XCBSyncSystemcounter) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_sync_systemcounter_name(data) as *const u8,
+            cdef::xcb_sync_systemcounter_name_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_sync_trigger_next(iter: &mut XCBSyncTriggerIterator) {
+    unsafe { cdef::xcb_sync_trigger_next(iter) }
+}
+
+pub fn xcb_sync_waitcondition_next(iter: &mut XCBSyncWaitconditionIterator) {
+    unsafe { cdef::xcb_sync_waitcondition_next(iter) }
+}
+
+impl Reply for XCBSyncInitializeReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_sync_initialize_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_sync_initialize(
+    connection: &XCBConnection,
+    desired_major_version: u8,
+    desired_minor_version: u8,
+) -> XCBSyncInitializeCookie {
+    unsafe {
+        cdef::xcb_sync_initialize(connection.raw, desired_major_version, desired_minor_version)
+    }
+}
+
+pub fn xcb_sync_list_system_counters_counters_iterator(
+    reply: &XCBSyncListSystemCountersReply,
+) -> XCBSyncSystemcounterIterator {
+    unsafe { cdef::xcb_sync_list_system_counters_counters_iterator(reply) }
+}
+
+impl Reply for XCBSyncListSystemCountersReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_sync_list_system_counters_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_sync_list_system_counters(
+    connection: &XCBConnection,
+) -> XCBSyncListSystemCountersCookie {
+    unsafe { cdef::xcb_sync_list_system_counters(connection.raw) }
+}
+
+pub fn xcb_sync_create_counter(
+    connection: &XCBConnection,
+    id: XCBSyncCounter,
+    initial_value: XCBSyncInt64,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_sync_create_counter(connection.raw, id, initial_value) }
+}
+
+pub fn xcb_sync_destroy_counter(
+    connection: &XCBConnection,
+    counter: XCBSyncCounter,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_sync_destroy_counter(connection.raw, counter) }
+}
+
+impl Reply for XCBSyncQueryCounterReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_sync_query_counter_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_sync_query_counter(
+    connection: &XCBConnection,
+    counter: XCBSyncCounter,
+) -> XCBSyncQueryCounterCookie {
+    unsafe { cdef::xcb_sync_query_counter(connection.raw, counter) }
+}
+
+pub fn xcb_sync_await(
+    connection: &XCBConnection,
+    wait_list: &[XCBSyncWaitcondition],
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_sync_await(connection.raw, wait_list.len() as u32, wait_list.as_ptr()) }
+}
+
+pub fn xcb_sync_change_counter(
+    connection: &XCBConnection,
+    counter: XCBSyncCounter,
+    amount: XCBSyncInt64,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_sync_change_counter(connection.raw, counter, amount) }
+}
+
+pub fn xcb_sync_set_counter(
+    connection: &XCBConnection,
+    counter: XCBSyncCounter,
+    value: XCBSyncInt64,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_sync_set_counter(connection.raw, counter, value) }
+}
+
+pub fn xcb_sync_destroy_alarm(connection: &XCBConnection, alarm: XCBSyncAlarm) -> XCBVoidCookie {
+    unsafe { cdef::xcb_sync_destroy_alarm(connection.raw, alarm) }
+}
+
+impl Reply for XCBSyncQueryAlarmReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_sync_query_alarm_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_sync_query_alarm(
+    connection: &XCBConnection,
+    alarm: XCBSyncAlarm,
+) -> XCBSyncQueryAlarmCookie {
+    unsafe { cdef::xcb_sync_query_alarm(connection.raw, alarm) }
+}
+
+pub fn xcb_sync_set_priority(connection: &XCBConnection, id: u32, priority: i32) -> XCBVoidCookie {
+    unsafe { cdef::xcb_sync_set_priority(connection.raw, id, priority) }
+}
+
+impl Reply for XCBSyncGetPriorityReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_sync_get_priority_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_sync_get_priority(connection: &XCBConnection, id: u32) -> XCBSyncGetPriorityCookie {
+    unsafe { cdef::xcb_sync_get_priority(connection.raw, id) }
+}
+
+pub fn xcb_sync_create_fence(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    fence: XCBSyncFence,
+    initially_triggered: bool,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_sync_create_fence(connection.raw, drawable, fence, initially_triggered as u8)
+    }
+}
+
+pub fn xcb_sync_trigger_fence(connection: &XCBConnection, fence: XCBSyncFence) -> XCBVoidCookie {
+    unsafe { cdef::xcb_sync_trigger_fence(connection.raw, fence) }
+}
+
+pub fn xcb_sync_reset_fence(connection: &XCBConnection, fence: XCBSyncFence) -> XCBVoidCookie {
+    unsafe { cdef::xcb_sync_reset_fence(connection.raw, fence) }
+}
+
+pub fn xcb_sync_destroy_fence(connection: &XCBConnection, fence: XCBSyncFence) -> XCBVoidCookie {
+    unsafe { cdef::xcb_sync_destroy_fence(connection.raw, fence) }
+}
+
+impl Reply for XCBSyncQueryFenceReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_sync_query_fence_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_sync_query_fence(
+    connection: &XCBConnection,
+    fence: XCBSyncFence,
+) -> XCBSyncQueryFenceCookie {
+    unsafe { cdef::xcb_sync_query_fence(connection.raw, fence) }
+}
+
+pub fn xcb_sync_await_fence(
+    connection: &XCBConnection,
+    fence_list: &[XCBSyncFence],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_sync_await_fence(connection.raw, fence_list.len() as u32, fence_list.as_ptr())
+    }
+}
+
+/// An event of the Sync extension.
+pub enum Event {
+    CounterNotify(Box<XCBSyncCounterNotifyEvent>),
+    AlarmNotify(Box<XCBSyncAlarmNotifyEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::CounterNotify(Box::from_raw(
+                    raw as *mut XCBSyncCounterNotifyEvent,
+                ))),
+                1 => Ok(Event::AlarmNotify(Box::from_raw(
+                    raw as *mut XCBSyncAlarmNotifyEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
+
+/// Returns the name of the error whose code, relative to the first error of the extension, is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        0 => Some("Counter"),
+        1 => Some("Alarm"),
+        _ => None,
+    }
+}
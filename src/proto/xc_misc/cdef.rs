@@ -0,0 +1,119 @@
+// Generated by xcbgen from xml/xc_misc.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+use cdef::{XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXcMiscGetVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub client_major_version: u16,
+    pub client_minor_version: u16,
+}
+
+pub type XCBXcMiscGetVersionCookie = XCBCookie<XCBXcMiscGetVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXcMiscGetVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub server_major_version: u16,
+    pub server_minor_version: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXcMiscGetXidRangeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBXcMiscGetXidRangeCookie = XCBCookie<XCBXcMiscGetXidRangeReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXcMiscGetXidRangeReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub start_id: u32,
+    pub count: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXcMiscGetXidListRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub count: u32,
+}
+
+pub type XCBXcMiscGetXidListCookie = XCBCookie<XCBXcMiscGetXidListReply>;
+
+#[repr(C)]
+pub struct XCBXcMiscGetXidListReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub ids_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[link(name = "xcb")]
+extern "system" {
+    pub static mut xcb_xc_misc_id: XCBExtension;
+    pub fn xcb_xc_misc_get_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXcMiscGetVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXcMiscGetVersionReply;
+    pub fn xcb_xc_misc_get_version(
+        connection: *mut XCBConnection,
+        client_major_version: u16,
+        client_minor_version: u16,
+    ) -> XCBXcMiscGetVersionCookie;
+    pub fn xcb_xc_misc_get_version_unchecked(
+        connection: *mut XCBConnection,
+        client_major_version: u16,
+        client_minor_version: u16,
+    ) -> XCBXcMiscGetVersionCookie;
+    pub fn xcb_xc_misc_get_xid_range_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXcMiscGetXidRangeCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXcMiscGetXidRangeReply;
+    pub fn xcb_xc_misc_get_xid_range(connection: *mut XCBConnection) -> XCBXcMiscGetXidRangeCookie;
+    pub fn xcb_xc_misc_get_xid_range_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBXcMiscGetXidRangeCookie;
+    pub fn xcb_xc_misc_get_xid_list_ids(R: *const XCBXcMiscGetXidListReply) -> *mut u32;
+    pub fn xcb_xc_misc_get_xid_list_ids_length(R: *const XCBXcMiscGetXidListReply) -> c_int;
+    pub fn xcb_xc_misc_get_xid_list_ids_end(
+        R: *const XCBXcMiscGetXidListReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xc_misc_get_xid_list_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXcMiscGetXidListCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXcMiscGetXidListReply;
+    pub fn xcb_xc_misc_get_xid_list(
+        connection: *mut XCBConnection,
+        count: u32,
+    ) -> XCBXcMiscGetXidListCookie;
+    pub fn xcb_xc_misc_get_xid_list_unchecked(
+        connection: *mut XCBConnection,
+        count: u32,
+    ) -> XCBXcMiscGetXidListCookie;
+}
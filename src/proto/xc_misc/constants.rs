@@ -0,0 +1,7 @@
+// Generated by xcbgen from xml/xc_misc.xml, do not edit.
+
+pub const XCB_XC_MISC_MAJOR_VERSION: u32 = 1;
+pub const XCB_XC_MISC_MINOR_VERSION: u32 = 1;
+pub const XCB_XC_MISC_GET_VERSION: u8 = 0;
+pub const XCB_XC_MISC_GET_XID_RANGE: u8 = 1;
+pub const XCB_XC_MISC_GET_XID_LIST: u8 = 2;
@@ -0,0 +1,90 @@
+// Generated by xcbgen from xml/xc_misc.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError};
+
+pub type XCBXcMiscGetVersionRequest = cdef::XCBXcMiscGetVersionRequest;
+pub type XCBXcMiscGetVersionCookie = cdef::XCBXcMiscGetVersionCookie;
+pub type XCBXcMiscGetVersionReply = cdef::XCBXcMiscGetVersionReply;
+pub type XCBXcMiscGetXidRangeRequest = cdef::XCBXcMiscGetXidRangeRequest;
+pub type XCBXcMiscGetXidRangeCookie = cdef::XCBXcMiscGetXidRangeCookie;
+pub type XCBXcMiscGetXidRangeReply = cdef::XCBXcMiscGetXidRangeReply;
+pub type XCBXcMiscGetXidListRequest = cdef::XCBXcMiscGetXidListRequest;
+pub type XCBXcMiscGetXidListCookie = cdef::XCBXcMiscGetXidListCookie;
+pub type XCBXcMiscGetXidListReply = cdef::XCBXcMiscGetXidListReply;
+
+/// The XCMisc extension, known to the server as `XC-MISC`.
+pub struct XcMisc;
+
+impl Extension for XcMisc {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_xc_misc_id)
+    }
+}
+
+impl Reply for XCBXcMiscGetVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xc_misc_get_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xc_misc_get_version(
+    connection: &XCBConnection,
+    client_major_version: u16,
+    client_minor_version: u16,
+) -> XCBXcMiscGetVersionCookie {
+    unsafe {
+        cdef::xcb_xc_misc_get_version(connection.raw, client_major_version, client_minor_version)
+    }
+}
+
+impl Reply for XCBXcMiscGetXidRangeReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xc_misc_get_xid_range_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xc_misc_get_xid_range(connection: &XCBConnection) -> XCBXcMiscGetXidRangeCookie {
+    unsafe { cdef::xcb_xc_misc_get_xid_range(connection.raw) }
+}
+
+pub fn xcb_xc_misc_get_xid_list_ids(reply: &XCBXcMiscGetXidListReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xc_misc_get_xid_list_ids(reply),
+            cdef::xcb_xc_misc_get_xid_list_ids_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXcMiscGetXidListReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xc_misc_get_xid_list_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xc_misc_get_xid_list(
+    connection: &XCBConnection,
+    count: u32,
+) -> XCBXcMiscGetXidListCookie {
+    unsafe { cdef::xcb_xc_misc_get_xid_list(connection.raw, count) }
+}
@@ -0,0 +1,197 @@
+// Generated by xcbgen from xml/xevie.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+use cdef::{XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXevieQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub client_major_version: u16,
+    pub client_minor_version: u16,
+}
+
+pub type XCBXevieQueryVersionCookie = XCBCookie<XCBXevieQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXevieQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub server_major_version: u16,
+    pub server_minor_version: u16,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXevieStartRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+}
+
+pub type XCBXevieStartCookie = XCBCookie<XCBXevieStartReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXevieStartReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXevieEndRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub cmap: u32,
+}
+
+pub type XCBXevieEndCookie = XCBCookie<XCBXevieEndReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXevieEndReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXevieEvent {
+    pub pad0: [u8; 32],
+}
+
+#[repr(C)]
+pub struct XCBXevieEventIterator {
+    pub data: *mut XCBXevieEvent,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXevieSendRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub event: XCBXevieEvent,
+    pub data_type: u32,
+    pub pad0: [u8; 64],
+}
+
+pub type XCBXevieSendCookie = XCBCookie<XCBXevieSendReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXevieSendReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXevieSelectInputRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub event_mask: u32,
+}
+
+pub type XCBXevieSelectInputCookie = XCBCookie<XCBXevieSelectInputReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXevieSelectInputReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 24],
+}
+
+#[link(name = "xcb-xevie")]
+extern "system" {
+    pub static mut xcb_xevie_id: XCBExtension;
+    pub fn xcb_xevie_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXevieQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXevieQueryVersionReply;
+    pub fn xcb_xevie_query_version(
+        connection: *mut XCBConnection,
+        client_major_version: u16,
+        client_minor_version: u16,
+    ) -> XCBXevieQueryVersionCookie;
+    pub fn xcb_xevie_query_version_unchecked(
+        connection: *mut XCBConnection,
+        client_major_version: u16,
+        client_minor_version: u16,
+    ) -> XCBXevieQueryVersionCookie;
+    pub fn xcb_xevie_start_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXevieStartCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXevieStartReply;
+    pub fn xcb_xevie_start(connection: *mut XCBConnection, screen: u32) -> XCBXevieStartCookie;
+    pub fn xcb_xevie_start_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBXevieStartCookie;
+    pub fn xcb_xevie_end_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXevieEndCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXevieEndReply;
+    pub fn xcb_xevie_end(connection: *mut XCBConnection, cmap: u32) -> XCBXevieEndCookie;
+    pub fn xcb_xevie_end_unchecked(connection: *mut XCBConnection, cmap: u32) -> XCBXevieEndCookie;
+    pub fn xcb_xevie_event_next(i: *mut XCBXevieEventIterator);
+    pub fn xcb_xevie_event_end(i: XCBXevieEventIterator) -> XCBGenericIterator;
+    pub fn xcb_xevie_send_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXevieSendCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXevieSendReply;
+    pub fn xcb_xevie_send(
+        connection: *mut XCBConnection,
+        event: XCBXevieEvent,
+        data_type: u32,
+    ) -> XCBXevieSendCookie;
+    pub fn xcb_xevie_send_unchecked(
+        connection: *mut XCBConnection,
+        event: XCBXevieEvent,
+        data_type: u32,
+    ) -> XCBXevieSendCookie;
+    pub fn xcb_xevie_select_input_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXevieSelectInputCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXevieSelectInputReply;
+    pub fn xcb_xevie_select_input(
+        connection: *mut XCBConnection,
+        event_mask: u32,
+    ) -> XCBXevieSelectInputCookie;
+    pub fn xcb_xevie_select_input_unchecked(
+        connection: *mut XCBConnection,
+        event_mask: u32,
+    ) -> XCBXevieSelectInputCookie;
+}
@@ -0,0 +1,11 @@
+// Generated by xcbgen from xml/xevie.xml, do not edit.
+
+pub const XCB_XEVIE_MAJOR_VERSION: u32 = 1;
+pub const XCB_XEVIE_MINOR_VERSION: u32 = 0;
+pub const XCB_XEVIE_QUERY_VERSION: u8 = 0;
+pub const XCB_XEVIE_START: u8 = 1;
+pub const XCB_XEVIE_END: u8 = 2;
+pub const XCB_XEVIE_DATATYPE_UNMODIFIED: u32 = 0;
+pub const XCB_XEVIE_DATATYPE_MODIFIED: u32 = 1;
+pub const XCB_XEVIE_SEND: u8 = 3;
+pub const XCB_XEVIE_SELECT_INPUT: u8 = 4;
@@ -0,0 +1,124 @@
+// Generated by xcbgen from xml/xevie.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError};
+
+pub type XCBXevieQueryVersionRequest = cdef::XCBXevieQueryVersionRequest;
+pub type XCBXevieQueryVersionCookie = cdef::XCBXevieQueryVersionCookie;
+pub type XCBXevieQueryVersionReply = cdef::XCBXevieQueryVersionReply;
+pub type XCBXevieStartRequest = cdef::XCBXevieStartRequest;
+pub type XCBXevieStartCookie = cdef::XCBXevieStartCookie;
+pub type XCBXevieStartReply = cdef::XCBXevieStartReply;
+pub type XCBXevieEndRequest = cdef::XCBXevieEndRequest;
+pub type XCBXevieEndCookie = cdef::XCBXevieEndCookie;
+pub type XCBXevieEndReply = cdef::XCBXevieEndReply;
+pub type XCBXevieEvent = cdef::XCBXevieEvent;
+pub type XCBXevieEventIterator = cdef::XCBXevieEventIterator;
+pub type XCBXevieSendRequest = cdef::XCBXevieSendRequest;
+pub type XCBXevieSendCookie = cdef::XCBXevieSendCookie;
+pub type XCBXevieSendReply = cdef::XCBXevieSendReply;
+pub type XCBXevieSelectInputRequest = cdef::XCBXevieSelectInputRequest;
+pub type XCBXevieSelectInputCookie = cdef::XCBXevieSelectInputCookie;
+pub type XCBXevieSelectInputReply = cdef::XCBXevieSelectInputReply;
+
+/// The Xevie extension, known to the server as `XEVIE`.
+pub struct Xevie;
+
+impl Extension for Xevie {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_xevie_id)
+    }
+}
+
+impl Reply for XCBXevieQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xevie_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xevie_query_version(
+    connection: &XCBConnection,
+    client_major_version: u16,
+    client_minor_version: u16,
+) -> XCBXevieQueryVersionCookie {
+    unsafe {
+        cdef::xcb_xevie_query_version(connection.raw, client_major_version, client_minor_version)
+    }
+}
+
+impl Reply for XCBXevieStartReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xevie_start_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xevie_start(connection: &XCBConnection, screen: u32) -> XCBXevieStartCookie {
+    unsafe { cdef::xcb_xevie_start(connection.raw, screen) }
+}
+
+impl Reply for XCBXevieEndReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xevie_end_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xevie_end(connection: &XCBConnection, cmap: u32) -> XCBXevieEndCookie {
+    unsafe { cdef::xcb_xevie_end(connection.raw, cmap) }
+}
+
+pub fn xcb_xevie_event_next(iter: &mut XCBXevieEventIterator) {
+    unsafe { cdef::xcb_xevie_event_next(iter) }
+}
+
+impl Reply for XCBXevieSendReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xevie_send_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xevie_send(
+    connection: &XCBConnection,
+    event: XCBXevieEvent,
+    data_type: u32,
+) -> XCBXevieSendCookie {
+    unsafe { cdef::xcb_xevie_send(connection.raw, event, data_type) }
+}
+
+impl Reply for XCBXevieSelectInputReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xevie_select_input_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xevie_select_input(
+    connection: &XCBConnection,
+    event_mask: u32,
+) -> XCBXevieSelectInputCookie {
+    unsafe { cdef::xcb_xevie_select_input(connection.raw, event_mask) }
+}
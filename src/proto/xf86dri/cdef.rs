@@ -0,0 +1,472 @@
+// Generated by xcbgen from xml/xf86dri.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int};
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driDrmClipRect {
+    pub x1: i16,
+    pub y1: i16,
+    pub x2: i16,
+    pub x3: i16,
+}
+
+#[repr(C)]
+pub struct XCBXf86driDrmClipRectIterator {
+    pub data: *mut XCBXf86driDrmClipRect,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBXf86driQueryVersionCookie = XCBCookie<XCBXf86driQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub dri_major_version: u16,
+    pub dri_minor_version: u16,
+    pub dri_minor_patch: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driQueryDirectRenderingCapableRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+}
+
+pub type XCBXf86driQueryDirectRenderingCapableCookie =
+    XCBCookie<XCBXf86driQueryDirectRenderingCapableReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driQueryDirectRenderingCapableReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub is_capable: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driOpenConnectionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+}
+
+pub type XCBXf86driOpenConnectionCookie = XCBCookie<XCBXf86driOpenConnectionReply>;
+
+#[repr(C)]
+pub struct XCBXf86driOpenConnectionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub sarea_handle_low: u32,
+    pub sarea_handle_high: u32,
+    pub bus_id_len: u32,
+    pub pad1: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driCloseConnectionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driGetClientDriverNameRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+}
+
+pub type XCBXf86driGetClientDriverNameCookie = XCBCookie<XCBXf86driGetClientDriverNameReply>;
+
+#[repr(C)]
+pub struct XCBXf86driGetClientDriverNameReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub client_driver_major_version: u32,
+    pub client_driver_minor_version: u32,
+    pub client_driver_patch_version: u32,
+    pub client_driver_name_len: u32,
+    pub pad1: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+    pub visual: u32,
+    pub context: u32,
+}
+
+pub type XCBXf86driCreateContextCookie = XCBCookie<XCBXf86driCreateContextReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driCreateContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub hw_context: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driDestroyContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+    pub context: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driCreateDrawableRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+    pub drawable: u32,
+}
+
+pub type XCBXf86driCreateDrawableCookie = XCBCookie<XCBXf86driCreateDrawableReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driCreateDrawableReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub hw_drawable_handle: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driDestroyDrawableRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+    pub drawable: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driGetDrawableInfoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+    pub drawable: u32,
+}
+
+pub type XCBXf86driGetDrawableInfoCookie = XCBCookie<XCBXf86driGetDrawableInfoReply>;
+
+#[repr(C)]
+pub struct XCBXf86driGetDrawableInfoReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub drawable_table_index: u32,
+    pub drawable_table_stamp: u32,
+    pub drawable_origin_X: i16,
+    pub drawable_origin_Y: i16,
+    pub drawable_size_W: i16,
+    pub drawable_size_H: i16,
+    pub num_clip_rects: u32,
+    pub back_x: i16,
+    pub back_y: i16,
+    pub num_back_clip_rects: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driGetDeviceInfoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+}
+
+pub type XCBXf86driGetDeviceInfoCookie = XCBCookie<XCBXf86driGetDeviceInfoReply>;
+
+#[repr(C)]
+pub struct XCBXf86driGetDeviceInfoReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub framebuffer_handle_low: u32,
+    pub framebuffer_handle_high: u32,
+    pub framebuffer_origin_offset: u32,
+    pub framebuffer_size: u32,
+    pub framebuffer_stride: u32,
+    pub device_private_size: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driAuthConnectionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub screen: u32,
+    pub magic: u32,
+}
+
+pub type XCBXf86driAuthConnectionCookie = XCBCookie<XCBXf86driAuthConnectionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXf86driAuthConnectionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub authenticated: u32,
+}
+
+#[link(name = "xcb-xf86dri")]
+extern "system" {
+    pub static mut xcb_xf86dri_id: XCBExtension;
+    pub fn xcb_xf86dri_drm_clip_rect_next(i: *mut XCBXf86driDrmClipRectIterator);
+    pub fn xcb_xf86dri_drm_clip_rect_end(i: XCBXf86driDrmClipRectIterator) -> XCBGenericIterator;
+    pub fn xcb_xf86dri_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXf86driQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXf86driQueryVersionReply;
+    pub fn xcb_xf86dri_query_version(
+        connection: *mut XCBConnection,
+    ) -> XCBXf86driQueryVersionCookie;
+    pub fn xcb_xf86dri_query_version_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBXf86driQueryVersionCookie;
+    pub fn xcb_xf86dri_query_direct_rendering_capable_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXf86driQueryDirectRenderingCapableCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXf86driQueryDirectRenderingCapableReply;
+    pub fn xcb_xf86dri_query_direct_rendering_capable(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBXf86driQueryDirectRenderingCapableCookie;
+    pub fn xcb_xf86dri_query_direct_rendering_capable_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBXf86driQueryDirectRenderingCapableCookie;
+    pub fn xcb_xf86dri_open_connection_bus_id(
+        R: *const XCBXf86driOpenConnectionReply,
+    ) -> *mut c_char;
+    pub fn xcb_xf86dri_open_connection_bus_id_length(
+        R: *const XCBXf86driOpenConnectionReply,
+    ) -> c_int;
+    pub fn xcb_xf86dri_open_connection_bus_id_end(
+        R: *const XCBXf86driOpenConnectionReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xf86dri_open_connection_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXf86driOpenConnectionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXf86driOpenConnectionReply;
+    pub fn xcb_xf86dri_open_connection(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBXf86driOpenConnectionCookie;
+    pub fn xcb_xf86dri_open_connection_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBXf86driOpenConnectionCookie;
+    pub fn xcb_xf86dri_close_connection_checked(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xf86dri_close_connection(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xf86dri_get_client_driver_name_client_driver_name(
+        R: *const XCBXf86driGetClientDriverNameReply,
+    ) -> *mut c_char;
+    pub fn xcb_xf86dri_get_client_driver_name_client_driver_name_length(
+        R: *const XCBXf86driGetClientDriverNameReply,
+    ) -> c_int;
+    pub fn xcb_xf86dri_get_client_driver_name_client_driver_name_end(
+        R: *const XCBXf86driGetClientDriverNameReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xf86dri_get_client_driver_name_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXf86driGetClientDriverNameCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXf86driGetClientDriverNameReply;
+    pub fn xcb_xf86dri_get_client_driver_name(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBXf86driGetClientDriverNameCookie;
+    pub fn xcb_xf86dri_get_client_driver_name_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBXf86driGetClientDriverNameCookie;
+    pub fn xcb_xf86dri_create_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXf86driCreateContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXf86driCreateContextReply;
+    pub fn xcb_xf86dri_create_context(
+        connection: *mut XCBConnection,
+        screen: u32,
+        visual: u32,
+        context: u32,
+    ) -> XCBXf86driCreateContextCookie;
+    pub fn xcb_xf86dri_create_context_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+        visual: u32,
+        context: u32,
+    ) -> XCBXf86driCreateContextCookie;
+    pub fn xcb_xf86dri_destroy_context_checked(
+        connection: *mut XCBConnection,
+        screen: u32,
+        context: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xf86dri_destroy_context(
+        connection: *mut XCBConnection,
+        screen: u32,
+        context: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xf86dri_create_drawable_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXf86driCreateDrawableCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXf86driCreateDrawableReply;
+    pub fn xcb_xf86dri_create_drawable(
+        connection: *mut XCBConnection,
+        screen: u32,
+        drawable: u32,
+    ) -> XCBXf86driCreateDrawableCookie;
+    pub fn xcb_xf86dri_create_drawable_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+        drawable: u32,
+    ) -> XCBXf86driCreateDrawableCookie;
+    pub fn xcb_xf86dri_destroy_drawable_checked(
+        connection: *mut XCBConnection,
+        screen: u32,
+        drawable: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xf86dri_destroy_drawable(
+        connection: *mut XCBConnection,
+        screen: u32,
+        drawable: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xf86dri_get_drawable_info_clip_rects(
+        R: *const XCBXf86driGetDrawableInfoReply,
+    ) -> *mut XCBXf86driDrmClipRect;
+    pub fn xcb_xf86dri_get_drawable_info_clip_rects_length(
+        R: *const XCBXf86driGetDrawableInfoReply,
+    ) -> c_int;
+    pub fn xcb_xf86dri_get_drawable_info_clip_rects_iterator(
+        R: *const XCBXf86driGetDrawableInfoReply,
+    ) -> XCBXf86driDrmClipRectIterator;
+    pub fn xcb_xf86dri_get_drawable_info_back_clip_rects(
+        R: *const XCBXf86driGetDrawableInfoReply,
+    ) -> *mut XCBXf86driDrmClipRect;
+    pub fn xcb_xf86dri_get_drawable_info_back_clip_rects_length(
+        R: *const XCBXf86driGetDrawableInfoReply,
+    ) -> c_int;
+    pub fn xcb_xf86dri_get_drawable_info_back_clip_rects_iterator(
+        R: *const XCBXf86driGetDrawableInfoReply,
+    ) -> XCBXf86driDrmClipRectIterator;
+    pub fn xcb_xf86dri_get_drawable_info_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXf86driGetDrawableInfoCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXf86driGetDrawableInfoReply;
+    pub fn xcb_xf86dri_get_drawable_info(
+        connection: *mut XCBConnection,
+        screen: u32,
+        drawable: u32,
+    ) -> XCBXf86driGetDrawableInfoCookie;
+    pub fn xcb_xf86dri_get_drawable_info_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+        drawable: u32,
+    ) -> XCBXf86driGetDrawableInfoCookie;
+    pub fn xcb_xf86dri_get_device_info_device_private(
+        R: *const XCBXf86driGetDeviceInfoReply,
+    ) -> *mut u32;
+    pub fn xcb_xf86dri_get_device_info_device_private_length(
+        R: *const XCBXf86driGetDeviceInfoReply,
+    ) -> c_int;
+    pub fn xcb_xf86dri_get_device_info_device_private_end(
+        R: *const XCBXf86driGetDeviceInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xf86dri_get_device_info_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXf86driGetDeviceInfoCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXf86driGetDeviceInfoReply;
+    pub fn xcb_xf86dri_get_device_info(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBXf86driGetDeviceInfoCookie;
+    pub fn xcb_xf86dri_get_device_info_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+    ) -> XCBXf86driGetDeviceInfoCookie;
+    pub fn xcb_xf86dri_auth_connection_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXf86driAuthConnectionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXf86driAuthConnectionReply;
+    pub fn xcb_xf86dri_auth_connection(
+        connection: *mut XCBConnection,
+        screen: u32,
+        magic: u32,
+    ) -> XCBXf86driAuthConnectionCookie;
+    pub fn xcb_xf86dri_auth_connection_unchecked(
+        connection: *mut XCBConnection,
+        screen: u32,
+        magic: u32,
+    ) -> XCBXf86driAuthConnectionCookie;
+}
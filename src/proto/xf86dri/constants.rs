@@ -0,0 +1,16 @@
+// Generated by xcbgen from xml/xf86dri.xml, do not edit.
+
+pub const XCB_XF86DRI_MAJOR_VERSION: u32 = 4;
+pub const XCB_XF86DRI_MINOR_VERSION: u32 = 1;
+pub const XCB_XF86DRI_QUERY_VERSION: u8 = 0;
+pub const XCB_XF86DRI_QUERY_DIRECT_RENDERING_CAPABLE: u8 = 1;
+pub const XCB_XF86DRI_OPEN_CONNECTION: u8 = 2;
+pub const XCB_XF86DRI_CLOSE_CONNECTION: u8 = 3;
+pub const XCB_XF86DRI_GET_CLIENT_DRIVER_NAME: u8 = 4;
+pub const XCB_XF86DRI_CREATE_CONTEXT: u8 = 5;
+pub const XCB_XF86DRI_DESTROY_CONTEXT: u8 = 6;
+pub const XCB_XF86DRI_CREATE_DRAWABLE: u8 = 7;
+pub const XCB_XF86DRI_DESTROY_DRAWABLE: u8 = 8;
+pub const XCB_XF86DRI_GET_DRAWABLE_INFO: u8 = 9;
+pub const XCB_XF86DRI_GET_DEVICE_INFO: u8 = 10;
+pub const XCB_XF86DRI_AUTH_CONNECTION: u8 = 11;
@@ -0,0 +1,286 @@
+// Generated by xcbgen from xml/xf86dri.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+
+pub type XCBXf86driDrmClipRect = cdef::XCBXf86driDrmClipRect;
+pub type XCBXf86driDrmClipRectIterator = cdef::XCBXf86driDrmClipRectIterator;
+pub type XCBXf86driQueryVersionRequest = cdef::XCBXf86driQueryVersionRequest;
+pub type XCBXf86driQueryVersionCookie = cdef::XCBXf86driQueryVersionCookie;
+pub type XCBXf86driQueryVersionReply = cdef::XCBXf86driQueryVersionReply;
+pub type XCBXf86driQueryDirectRenderingCapableRequest =
+    cdef::XCBXf86driQueryDirectRenderingCapableRequest;
+pub type XCBXf86driQueryDirectRenderingCapableCookie =
+    cdef::XCBXf86driQueryDirectRenderingCapableCookie;
+pub type XCBXf86driQueryDirectRenderingCapableReply =
+    cdef::XCBXf86driQueryDirectRenderingCapableReply;
+pub type XCBXf86driOpenConnectionRequest = cdef::XCBXf86driOpenConnectionRequest;
+pub type XCBXf86driOpenConnectionCookie = cdef::XCBXf86driOpenConnectionCookie;
+pub type XCBXf86driOpenConnectionReply = cdef::XCBXf86driOpenConnectionReply;
+pub type XCBXf86driCloseConnectionRequest = cdef::XCBXf86driCloseConnectionRequest;
+pub type XCBXf86driGetClientDriverNameRequest = cdef::XCBXf86driGetClientDriverNameRequest;
+pub type XCBXf86driGetClientDriverNameCookie = cdef::XCBXf86driGetClientDriverNameCookie;
+pub type XCBXf86driGetClientDriverNameReply = cdef::XCBXf86driGetClientDriverNameReply;
+pub type XCBXf86driCreateContextRequest = cdef::XCBXf86driCreateContextRequest;
+pub type XCBXf86driCreateContextCookie = cdef::XCBXf86driCreateContextCookie;
+pub type XCBXf86driCreateContextReply = cdef::XCBXf86driCreateContextReply;
+pub type XCBXf86driDestroyContextRequest = cdef::XCBXf86driDestroyContextRequest;
+pub type XCBXf86driCreateDrawableRequest = cdef::XCBXf86driCreateDrawableRequest;
+pub type XCBXf86driCreateDrawableCookie = cdef::XCBXf86driCreateDrawableCookie;
+pub type XCBXf86driCreateDrawableReply = cdef::XCBXf86driCreateDrawableReply;
+pub type XCBXf86driDestroyDrawableRequest = cdef::XCBXf86driDestroyDrawableRequest;
+pub type XCBXf86driGetDrawableInfoRequest = cdef::XCBXf86driGetDrawableInfoRequest;
+pub type XCBXf86driGetDrawableInfoCookie = cdef::XCBXf86driGetDrawableInfoCookie;
+pub type XCBXf86driGetDrawableInfoReply = cdef::XCBXf86driGetDrawableInfoReply;
+pub type XCBXf86driGetDeviceInfoRequest = cdef::XCBXf86driGetDeviceInfoRequest;
+pub type XCBXf86driGetDeviceInfoCookie = cdef::XCBXf86driGetDeviceInfoCookie;
+pub type XCBXf86driGetDeviceInfoReply = cdef::XCBXf86driGetDeviceInfoReply;
+pub type XCBXf86driAuthConnectionRequest = cdef::XCBXf86driAuthConnectionRequest;
+pub type XCBXf86driAuthConnectionCookie = cdef::XCBXf86driAuthConnectionCookie;
+pub type XCBXf86driAuthConnectionReply = cdef::XCBXf86driAuthConnectionReply;
+
+/// The XF86Dri extension, known to the server as `XFree86-DRI`.
+pub struct Xf86dri;
+
+impl Extension for Xf86dri {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_xf86dri_id)
+    }
+}
+
+pub fn xcb_xf86dri_drm_clip_rect_next(iter: &mut XCBXf86driDrmClipRectIterator) {
+    unsafe { cdef::xcb_xf86dri_drm_clip_rect_next(iter) }
+}
+
+impl Reply for XCBXf86driQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xf86dri_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xf86dri_query_version(connection: &XCBConnection) -> XCBXf86driQueryVersionCookie {
+    unsafe { cdef::xcb_xf86dri_query_version(connection.raw) }
+}
+
+impl Reply for XCBXf86driQueryDirectRenderingCapableReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xf86dri_query_direct_rendering_capable_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xf86dri_query_direct_rendering_capable(
+    connection: &XCBConnection,
+    screen: u32,
+) -> XCBXf86driQueryDirectRenderingCapableCookie {
+    unsafe { cdef::xcb_xf86dri_query_direct_rendering_capable(connection.raw, screen) }
+}
+
+pub fn xcb_xf86dri_open_connection_bus_id(reply: &XCBXf86driOpenConnectionReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xf86dri_open_connection_bus_id(reply) as *const u8,
+            cdef::xcb_xf86dri_open_connection_bus_id_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXf86driOpenConnectionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xf86dri_open_connection_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xf86dri_open_connection(
+    connection: &XCBConnection,
+    screen: u32,
+) -> XCBXf86driOpenConnectionCookie {
+    unsafe { cdef::xcb_xf86dri_open_connection(connection.raw, screen) }
+}
+
+pub fn xcb_xf86dri_close_connection(connection: &XCBConnection, screen: u32) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xf86dri_close_connection(connection.raw, screen) }
+}
+
+pub fn xcb_xf86dri_get_client_driver_name_client_driver_name(
+    reply: &XCBXf86driGetClientDriverNameReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xf86dri_get_client_driver_name_client_driver_name(reply) as *const u8,
+            cdef::xcb_xf86dri_get_client_driver_name_client_driver_name_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXf86driGetClientDriverNameReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xf86dri_get_client_driver_name_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xf86dri_get_client_driver_name(
+    connection: &XCBConnection,
+    screen: u32,
+) -> XCBXf86driGetClientDriverNameCookie {
+    unsafe { cdef::xcb_xf86dri_get_client_driver_name(connection.raw, screen) }
+}
+
+impl Reply for XCBXf86driCreateContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xf86dri_create_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xf86dri_create_context(
+    connection: &XCBConnection,
+    screen: u32,
+    visual: u32,
+    context: u32,
+) -> XCBXf86driCreateContextCookie {
+    unsafe { cdef::xcb_xf86dri_create_context(connection.raw, screen, visual, context) }
+}
+
+pub fn xcb_xf86dri_destroy_context(
+    connection: &XCBConnection,
+    screen: u32,
+    context: u32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xf86dri_destroy_context(connection.raw, screen, context) }
+}
+
+impl Reply for XCBXf86driCreateDrawableReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xf86dri_create_drawable_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xf86dri_create_drawable(
+    connection: &XCBConnection,
+    screen: u32,
+    drawable: u32,
+) -> XCBXf86driCreateDrawableCookie {
+    unsafe { cdef::xcb_xf86dri_create_drawable(connection.raw, screen, drawable) }
+}
+
+pub fn xcb_xf86dri_destroy_drawable(
+    connection: &XCBConnection,
+    screen: u32,
+    drawable: u32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xf86dri_destroy_drawable(connection.raw, screen, drawable) }
+}
+
+pub fn xcb_xf86dri_get_drawable_info_clip_rects(
+    reply: &XCBXf86driGetDrawableInfoReply,
+) -> &[XCBXf86driDrmClipRect] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xf86dri_get_drawable_info_clip_rects(reply),
+            cdef::xcb_xf86dri_get_drawable_info_clip_rects_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_xf86dri_get_drawable_info_back_clip_rects(
+    reply: &XCBXf86driGetDrawableInfoReply,
+) -> &[XCBXf86driDrmClipRect] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xf86dri_get_drawable_info_back_clip_rects(reply),
+            cdef::xcb_xf86dri_get_drawable_info_back_clip_rects_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXf86driGetDrawableInfoReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xf86dri_get_drawable_info_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xf86dri_get_drawable_info(
+    connection: &XCBConnection,
+    screen: u32,
+    drawable: u32,
+) -> XCBXf86driGetDrawableInfoCookie {
+    unsafe { cdef::xcb_xf86dri_get_drawable_info(connection.raw, screen, drawable) }
+}
+
+pub fn xcb_xf86dri_get_device_info_device_private(reply: &XCBXf86driGetDeviceInfoReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xf86dri_get_device_info_device_private(reply),
+            cdef::xcb_xf86dri_get_device_info_device_private_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXf86driGetDeviceInfoReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xf86dri_get_device_info_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xf86dri_get_device_info(
+    connection: &XCBConnection,
+    screen: u32,
+) -> XCBXf86driGetDeviceInfoCookie {
+    unsafe { cdef::xcb_xf86dri_get_device_info(connection.raw, screen) }
+}
+
+impl Reply for XCBXf86driAuthConnectionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xf86dri_auth_connection_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xf86dri_auth_connection(
+    connection: &XCBConnection,
+    screen: u32,
+    magic: u32,
+) -> XCBXf86driAuthConnectionCookie {
+    unsafe { cdef::xcb_xf86dri_auth_connection(connection.raw, screen, magic) }
+}
@@ -0,0 +1,935 @@
+// Generated by xcbgen from xml/xfixes.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int};
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::render::cdef::XCBRenderPicture;
+use proto::shape::cdef::XCBShapeKind;
+use proto::xproto::cdef::{
+    XCBAtom, XCBCursor, XCBGcontext, XCBPixmap, XCBRectangle, XCBRectangleIterator, XCBTimestamp,
+    XCBWindow,
+};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub client_major_version: u32,
+    pub client_minor_version: u32,
+}
+
+pub type XCBXfixesQueryVersionCookie = XCBCookie<XCBXfixesQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesChangeSaveSetRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub mode: u8,
+    pub target: u8,
+    pub map: u8,
+    pub pad0: u8,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesSelectionNotifyEvent {
+    pub response_type: u8,
+    pub subtype: u8,
+    pub sequence: u16,
+    pub window: XCBWindow,
+    pub owner: XCBWindow,
+    pub selection: XCBAtom,
+    pub timestamp: XCBTimestamp,
+    pub selection_timestamp: XCBTimestamp,
+    pub pad0: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesSelectSelectionInputRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub selection: XCBAtom,
+    pub event_mask: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesCursorNotifyEvent {
+    pub response_type: u8,
+    pub subtype: u8,
+    pub sequence: u16,
+    pub window: XCBWindow,
+    pub cursor_serial: u32,
+    pub timestamp: XCBTimestamp,
+    pub name: XCBAtom,
+    pub pad0: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesSelectCursorInputRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub event_mask: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesGetCursorImageRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBXfixesGetCursorImageCookie = XCBCookie<XCBXfixesGetCursorImageReply>;
+
+#[repr(C)]
+pub struct XCBXfixesGetCursorImageReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub xhot: u16,
+    pub yhot: u16,
+    pub cursor_serial: u32,
+    pub pad1: [u8; 8],
+}
+
+pub type XCBXfixesRegion = u32;
+
+#[repr(C)]
+pub struct XCBXfixesRegionIterator {
+    pub data: *mut XCBXfixesRegion,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesBadRegionError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+pub struct XCBXfixesCreateRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub region: XCBXfixesRegion,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesCreateRegionFromBitmapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub region: XCBXfixesRegion,
+    pub bitmap: XCBPixmap,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesCreateRegionFromWindowRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub region: XCBXfixesRegion,
+    pub window: XCBWindow,
+    pub kind: XCBShapeKind,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesCreateRegionFromGcRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub region: XCBXfixesRegion,
+    pub gc: XCBGcontext,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesCreateRegionFromPictureRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub region: XCBXfixesRegion,
+    pub picture: XCBRenderPicture,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesDestroyRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub region: XCBXfixesRegion,
+}
+
+#[repr(C)]
+pub struct XCBXfixesSetRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub region: XCBXfixesRegion,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesCopyRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub source: XCBXfixesRegion,
+    pub destination: XCBXfixesRegion,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesUnionRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub source1: XCBXfixesRegion,
+    pub source2: XCBXfixesRegion,
+    pub destination: XCBXfixesRegion,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesIntersectRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub source1: XCBXfixesRegion,
+    pub source2: XCBXfixesRegion,
+    pub destination: XCBXfixesRegion,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesSubtractRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub source1: XCBXfixesRegion,
+    pub source2: XCBXfixesRegion,
+    pub destination: XCBXfixesRegion,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesInvertRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub source: XCBXfixesRegion,
+    pub bounds: XCBRectangle,
+    pub destination: XCBXfixesRegion,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesTranslateRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub region: XCBXfixesRegion,
+    pub dx: i16,
+    pub dy: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesRegionExtentsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub source: XCBXfixesRegion,
+    pub destination: XCBXfixesRegion,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesFetchRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub region: XCBXfixesRegion,
+}
+
+pub type XCBXfixesFetchRegionCookie = XCBCookie<XCBXfixesFetchRegionReply>;
+
+#[repr(C)]
+pub struct XCBXfixesFetchRegionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub extents: XCBRectangle,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesSetGcClipRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub gc: XCBGcontext,
+    pub region: XCBXfixesRegion,
+    pub x_origin: i16,
+    pub y_origin: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesSetWindowShapeRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub dest: XCBWindow,
+    pub dest_kind: XCBShapeKind,
+    pub pad0: [u8; 3],
+    pub x_offset: i16,
+    pub y_offset: i16,
+    pub region: XCBXfixesRegion,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesSetPictureClipRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub picture: XCBRenderPicture,
+    pub region: XCBXfixesRegion,
+    pub x_origin: i16,
+    pub y_origin: i16,
+}
+
+#[repr(C)]
+pub struct XCBXfixesSetCursorNameRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub cursor: XCBCursor,
+    pub nbytes: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesGetCursorNameRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub cursor: XCBCursor,
+}
+
+pub type XCBXfixesGetCursorNameCookie = XCBCookie<XCBXfixesGetCursorNameReply>;
+
+#[repr(C)]
+pub struct XCBXfixesGetCursorNameReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub atom: XCBAtom,
+    pub nbytes: u16,
+    pub pad1: [u8; 18],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesGetCursorImageAndNameRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBXfixesGetCursorImageAndNameCookie = XCBCookie<XCBXfixesGetCursorImageAndNameReply>;
+
+#[repr(C)]
+pub struct XCBXfixesGetCursorImageAndNameReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub xhot: u16,
+    pub yhot: u16,
+    pub cursor_serial: u32,
+    pub cursor_atom: XCBAtom,
+    pub nbytes: u16,
+    pub pad1: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesChangeCursorRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub source: XCBCursor,
+    pub destination: XCBCursor,
+}
+
+#[repr(C)]
+pub struct XCBXfixesChangeCursorByNameRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub src: XCBCursor,
+    pub nbytes: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesExpandRegionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub source: XCBXfixesRegion,
+    pub destination: XCBXfixesRegion,
+    pub left: u16,
+    pub right: u16,
+    pub top: u16,
+    pub bottom: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesHideCursorRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesShowCursorRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBXfixesBarrier = u32;
+
+#[repr(C)]
+pub struct XCBXfixesBarrierIterator {
+    pub data: *mut XCBXfixesBarrier,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXfixesCreatePointerBarrierRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub barrier: XCBXfixesBarrier,
+    pub window: XCBWindow,
+    pub x1: u16,
+    pub y1: u16,
+    pub x2: u16,
+    pub y2: u16,
+    pub directions: u32,
+    pub pad0: [u8; 2],
+    pub num_devices: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXfixesDeletePointerBarrierRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub barrier: XCBXfixesBarrier,
+}
+
+#[link(name = "xcb-xfixes")]
+extern "system" {
+    pub static mut xcb_xfixes_id: XCBExtension;
+    pub fn xcb_xfixes_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXfixesQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXfixesQueryVersionReply;
+    pub fn xcb_xfixes_query_version(
+        connection: *mut XCBConnection,
+        client_major_version: u32,
+        client_minor_version: u32,
+    ) -> XCBXfixesQueryVersionCookie;
+    pub fn xcb_xfixes_query_version_unchecked(
+        connection: *mut XCBConnection,
+        client_major_version: u32,
+        client_minor_version: u32,
+    ) -> XCBXfixesQueryVersionCookie;
+    pub fn xcb_xfixes_change_save_set_checked(
+        connection: *mut XCBConnection,
+        mode: u8,
+        target: u8,
+        map: u8,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_change_save_set(
+        connection: *mut XCBConnection,
+        mode: u8,
+        target: u8,
+        map: u8,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_select_selection_input_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        selection: XCBAtom,
+        event_mask: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_select_selection_input(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        selection: XCBAtom,
+        event_mask: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_select_cursor_input_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        event_mask: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_select_cursor_input(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        event_mask: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_get_cursor_image_cursor_image(
+        R: *const XCBXfixesGetCursorImageReply,
+    ) -> *mut u32;
+    pub fn xcb_xfixes_get_cursor_image_cursor_image_length(
+        R: *const XCBXfixesGetCursorImageReply,
+    ) -> c_int;
+    pub fn xcb_xfixes_get_cursor_image_cursor_image_end(
+        R: *const XCBXfixesGetCursorImageReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xfixes_get_cursor_image_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXfixesGetCursorImageCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXfixesGetCursorImageReply;
+    pub fn xcb_xfixes_get_cursor_image(
+        connection: *mut XCBConnection,
+    ) -> XCBXfixesGetCursorImageCookie;
+    pub fn xcb_xfixes_get_cursor_image_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBXfixesGetCursorImageCookie;
+    pub fn xcb_xfixes_region_next(i: *mut XCBXfixesRegionIterator);
+    pub fn xcb_xfixes_region_end(i: XCBXfixesRegionIterator) -> XCBGenericIterator;
+    pub fn xcb_xfixes_create_region_checked(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_create_region(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_create_region_from_bitmap_checked(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        bitmap: XCBPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_create_region_from_bitmap(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        bitmap: XCBPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_create_region_from_window_checked(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        window: XCBWindow,
+        kind: XCBShapeKind,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_create_region_from_window(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        window: XCBWindow,
+        kind: XCBShapeKind,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_create_region_from_gc_checked(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        gc: XCBGcontext,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_create_region_from_gc(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        gc: XCBGcontext,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_create_region_from_picture_checked(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        picture: XCBRenderPicture,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_create_region_from_picture(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        picture: XCBRenderPicture,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_destroy_region_checked(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_destroy_region(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_set_region_checked(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_set_region(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_copy_region_checked(
+        connection: *mut XCBConnection,
+        source: XCBXfixesRegion,
+        destination: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_copy_region(
+        connection: *mut XCBConnection,
+        source: XCBXfixesRegion,
+        destination: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_union_region_checked(
+        connection: *mut XCBConnection,
+        source1: XCBXfixesRegion,
+        source2: XCBXfixesRegion,
+        destination: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_union_region(
+        connection: *mut XCBConnection,
+        source1: XCBXfixesRegion,
+        source2: XCBXfixesRegion,
+        destination: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_intersect_region_checked(
+        connection: *mut XCBConnection,
+        source1: XCBXfixesRegion,
+        source2: XCBXfixesRegion,
+        destination: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_intersect_region(
+        connection: *mut XCBConnection,
+        source1: XCBXfixesRegion,
+        source2: XCBXfixesRegion,
+        destination: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_subtract_region_checked(
+        connection: *mut XCBConnection,
+        source1: XCBXfixesRegion,
+        source2: XCBXfixesRegion,
+        destination: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_subtract_region(
+        connection: *mut XCBConnection,
+        source1: XCBXfixesRegion,
+        source2: XCBXfixesRegion,
+        destination: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_invert_region_checked(
+        connection: *mut XCBConnection,
+        source: XCBXfixesRegion,
+        bounds: XCBRectangle,
+        destination: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_invert_region(
+        connection: *mut XCBConnection,
+        source: XCBXfixesRegion,
+        bounds: XCBRectangle,
+        destination: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_translate_region_checked(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        dx: i16,
+        dy: i16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_translate_region(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+        dx: i16,
+        dy: i16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_region_extents_checked(
+        connection: *mut XCBConnection,
+        source: XCBXfixesRegion,
+        destination: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_region_extents(
+        connection: *mut XCBConnection,
+        source: XCBXfixesRegion,
+        destination: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_fetch_region_rectangles(
+        R: *const XCBXfixesFetchRegionReply,
+    ) -> *mut XCBRectangle;
+    pub fn xcb_xfixes_fetch_region_rectangles_length(R: *const XCBXfixesFetchRegionReply) -> c_int;
+    pub fn xcb_xfixes_fetch_region_rectangles_iterator(
+        R: *const XCBXfixesFetchRegionReply,
+    ) -> XCBRectangleIterator;
+    pub fn xcb_xfixes_fetch_region_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXfixesFetchRegionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXfixesFetchRegionReply;
+    pub fn xcb_xfixes_fetch_region(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+    ) -> XCBXfixesFetchRegionCookie;
+    pub fn xcb_xfixes_fetch_region_unchecked(
+        connection: *mut XCBConnection,
+        region: XCBXfixesRegion,
+    ) -> XCBXfixesFetchRegionCookie;
+    pub fn xcb_xfixes_set_gc_clip_region_checked(
+        connection: *mut XCBConnection,
+        gc: XCBGcontext,
+        region: XCBXfixesRegion,
+        x_origin: i16,
+        y_origin: i16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_set_gc_clip_region(
+        connection: *mut XCBConnection,
+        gc: XCBGcontext,
+        region: XCBXfixesRegion,
+        x_origin: i16,
+        y_origin: i16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_set_window_shape_region_checked(
+        connection: *mut XCBConnection,
+        dest: XCBWindow,
+        dest_kind: XCBShapeKind,
+        x_offset: i16,
+        y_offset: i16,
+        region: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_set_window_shape_region(
+        connection: *mut XCBConnection,
+        dest: XCBWindow,
+        dest_kind: XCBShapeKind,
+        x_offset: i16,
+        y_offset: i16,
+        region: XCBXfixesRegion,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_set_picture_clip_region_checked(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        region: XCBXfixesRegion,
+        x_origin: i16,
+        y_origin: i16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_set_picture_clip_region(
+        connection: *mut XCBConnection,
+        picture: XCBRenderPicture,
+        region: XCBXfixesRegion,
+        x_origin: i16,
+        y_origin: i16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_set_cursor_name_checked(
+        connection: *mut XCBConnection,
+        cursor: XCBCursor,
+        nbytes: u16,
+        name: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_set_cursor_name(
+        connection: *mut XCBConnection,
+        cursor: XCBCursor,
+        nbytes: u16,
+        name: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_get_cursor_name_name(R: *const XCBXfixesGetCursorNameReply) -> *mut c_char;
+    pub fn xcb_xfixes_get_cursor_name_name_length(R: *const XCBXfixesGetCursorNameReply) -> c_int;
+    pub fn xcb_xfixes_get_cursor_name_name_end(
+        R: *const XCBXfixesGetCursorNameReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xfixes_get_cursor_name_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXfixesGetCursorNameCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXfixesGetCursorNameReply;
+    pub fn xcb_xfixes_get_cursor_name(
+        connection: *mut XCBConnection,
+        cursor: XCBCursor,
+    ) -> XCBXfixesGetCursorNameCookie;
+    pub fn xcb_xfixes_get_cursor_name_unchecked(
+        connection: *mut XCBConnection,
+        cursor: XCBCursor,
+    ) -> XCBXfixesGetCursorNameCookie;
+    pub fn xcb_xfixes_get_cursor_image_and_name_cursor_image(
+        R: *const XCBXfixesGetCursorImageAndNameReply,
+    ) -> *mut u32;
+    pub fn xcb_xfixes_get_cursor_image_and_name_cursor_image_length(
+        R: *const XCBXfixesGetCursorImageAndNameReply,
+    ) -> c_int;
+    pub fn xcb_xfixes_get_cursor_image_and_name_cursor_image_end(
+        R: *const XCBXfixesGetCursorImageAndNameReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xfixes_get_cursor_image_and_name_name(
+        R: *const XCBXfixesGetCursorImageAndNameReply,
+    ) -> *mut c_char;
+    pub fn xcb_xfixes_get_cursor_image_and_name_name_length(
+        R: *const XCBXfixesGetCursorImageAndNameReply,
+    ) -> c_int;
+    pub fn xcb_xfixes_get_cursor_image_and_name_name_end(
+        R: *const XCBXfixesGetCursorImageAndNameReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xfixes_get_cursor_image_and_name_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXfixesGetCursorImageAndNameCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXfixesGetCursorImageAndNameReply;
+    pub fn xcb_xfixes_get_cursor_image_and_name(
+        connection: *mut XCBConnection,
+    ) -> XCBXfixesGetCursorImageAndNameCookie;
+    pub fn xcb_xfixes_get_cursor_image_and_name_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBXfixesGetCursorImageAndNameCookie;
+    pub fn xcb_xfixes_change_cursor_checked(
+        connection: *mut XCBConnection,
+        source: XCBCursor,
+        destination: XCBCursor,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_change_cursor(
+        connection: *mut XCBConnection,
+        source: XCBCursor,
+        destination: XCBCursor,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_change_cursor_by_name_checked(
+        connection: *mut XCBConnection,
+        src: XCBCursor,
+        nbytes: u16,
+        name: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_change_cursor_by_name(
+        connection: *mut XCBConnection,
+        src: XCBCursor,
+        nbytes: u16,
+        name: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_expand_region_checked(
+        connection: *mut XCBConnection,
+        source: XCBXfixesRegion,
+        destination: XCBXfixesRegion,
+        left: u16,
+        right: u16,
+        top: u16,
+        bottom: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_expand_region(
+        connection: *mut XCBConnection,
+        source: XCBXfixesRegion,
+        destination: XCBXfixesRegion,
+        left: u16,
+        right: u16,
+        top: u16,
+        bottom: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_hide_cursor_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_hide_cursor(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_show_cursor_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_show_cursor(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_barrier_next(i: *mut XCBXfixesBarrierIterator);
+    pub fn xcb_xfixes_barrier_end(i: XCBXfixesBarrierIterator) -> XCBGenericIterator;
+    pub fn xcb_xfixes_create_pointer_barrier_checked(
+        connection: *mut XCBConnection,
+        barrier: XCBXfixesBarrier,
+        window: XCBWindow,
+        x1: u16,
+        y1: u16,
+        x2: u16,
+        y2: u16,
+        directions: u32,
+        num_devices: u16,
+        devices: *const u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_create_pointer_barrier(
+        connection: *mut XCBConnection,
+        barrier: XCBXfixesBarrier,
+        window: XCBWindow,
+        x1: u16,
+        y1: u16,
+        x2: u16,
+        y2: u16,
+        directions: u32,
+        num_devices: u16,
+        devices: *const u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_delete_pointer_barrier_checked(
+        connection: *mut XCBConnection,
+        barrier: XCBXfixesBarrier,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xfixes_delete_pointer_barrier(
+        connection: *mut XCBConnection,
+        barrier: XCBXfixesBarrier,
+    ) -> XCBVoidCookie;
+}
@@ -0,0 +1,59 @@
+// Generated by xcbgen from xml/xfixes.xml, do not edit.
+
+pub const XCB_XFIXES_MAJOR_VERSION: u32 = 5;
+pub const XCB_XFIXES_MINOR_VERSION: u32 = 0;
+pub const XCB_XFIXES_QUERY_VERSION: u8 = 0;
+pub const XCB_XFIXES_SAVE_SET_MODE_INSERT: u8 = 0;
+pub const XCB_XFIXES_SAVE_SET_MODE_DELETE: u8 = 1;
+pub const XCB_XFIXES_SAVE_SET_TARGET_NEAREST: u8 = 0;
+pub const XCB_XFIXES_SAVE_SET_TARGET_ROOT: u8 = 1;
+pub const XCB_XFIXES_SAVE_SET_MAPPING_MAP: u8 = 0;
+pub const XCB_XFIXES_SAVE_SET_MAPPING_UNMAP: u8 = 1;
+pub const XCB_XFIXES_CHANGE_SAVE_SET: u8 = 1;
+pub const XCB_XFIXES_SELECTION_EVENT_SET_SELECTION_OWNER: u8 = 0;
+pub const XCB_XFIXES_SELECTION_EVENT_SELECTION_WINDOW_DESTROY: u8 = 1;
+pub const XCB_XFIXES_SELECTION_EVENT_SELECTION_CLIENT_CLOSE: u8 = 2;
+pub const XCB_XFIXES_SELECTION_EVENT_MASK_SET_SELECTION_OWNER: u32 = 1;
+pub const XCB_XFIXES_SELECTION_EVENT_MASK_SELECTION_WINDOW_DESTROY: u32 = 2;
+pub const XCB_XFIXES_SELECTION_EVENT_MASK_SELECTION_CLIENT_CLOSE: u32 = 4;
+pub const XCB_XFIXES_SELECTION_NOTIFY: u8 = 0;
+pub const XCB_XFIXES_SELECT_SELECTION_INPUT: u8 = 2;
+pub const XCB_XFIXES_CURSOR_NOTIFY_DISPLAY_CURSOR: u8 = 0;
+pub const XCB_XFIXES_CURSOR_NOTIFY_MASK_DISPLAY_CURSOR: u32 = 1;
+pub const XCB_XFIXES_CURSOR_NOTIFY: u8 = 1;
+pub const XCB_XFIXES_SELECT_CURSOR_INPUT: u8 = 3;
+pub const XCB_XFIXES_GET_CURSOR_IMAGE: u8 = 4;
+pub const XCB_XFIXES_BAD_REGION: u8 = 0;
+pub const XCB_XFIXES_REGION_NONE: u32 = 0;
+pub const XCB_XFIXES_CREATE_REGION: u8 = 5;
+pub const XCB_XFIXES_CREATE_REGION_FROM_BITMAP: u8 = 6;
+pub const XCB_XFIXES_CREATE_REGION_FROM_WINDOW: u8 = 7;
+pub const XCB_XFIXES_CREATE_REGION_FROM_GC: u8 = 8;
+pub const XCB_XFIXES_CREATE_REGION_FROM_PICTURE: u8 = 9;
+pub const XCB_XFIXES_DESTROY_REGION: u8 = 10;
+pub const XCB_XFIXES_SET_REGION: u8 = 11;
+pub const XCB_XFIXES_COPY_REGION: u8 = 12;
+pub const XCB_XFIXES_UNION_REGION: u8 = 13;
+pub const XCB_XFIXES_INTERSECT_REGION: u8 = 14;
+pub const XCB_XFIXES_SUBTRACT_REGION: u8 = 15;
+pub const XCB_XFIXES_INVERT_REGION: u8 = 16;
+pub const XCB_XFIXES_TRANSLATE_REGION: u8 = 17;
+pub const XCB_XFIXES_REGION_EXTENTS: u8 = 18;
+pub const XCB_XFIXES_FETCH_REGION: u8 = 19;
+pub const XCB_XFIXES_SET_GC_CLIP_REGION: u8 = 20;
+pub const XCB_XFIXES_SET_WINDOW_SHAPE_REGION: u8 = 21;
+pub const XCB_XFIXES_SET_PICTURE_CLIP_REGION: u8 = 22;
+pub const XCB_XFIXES_SET_CURSOR_NAME: u8 = 23;
+pub const XCB_XFIXES_GET_CURSOR_NAME: u8 = 24;
+pub const XCB_XFIXES_GET_CURSOR_IMAGE_AND_NAME: u8 = 25;
+pub const XCB_XFIXES_CHANGE_CURSOR: u8 = 26;
+pub const XCB_XFIXES_CHANGE_CURSOR_BY_NAME: u8 = 27;
+pub const XCB_XFIXES_EXPAND_REGION: u8 = 28;
+pub const XCB_XFIXES_HIDE_CURSOR: u8 = 29;
+pub const XCB_XFIXES_SHOW_CURSOR: u8 = 30;
+pub const XCB_XFIXES_BARRIER_DIRECTIONS_POSITIVE_X: u32 = 1;
+pub const XCB_XFIXES_BARRIER_DIRECTIONS_POSITIVE_Y: u32 = 2;
+pub const XCB_XFIXES_BARRIER_DIRECTIONS_NEGATIVE_X: u32 = 4;
+pub const XCB_XFIXES_BARRIER_DIRECTIONS_NEGATIVE_Y: u32 = 8;
+pub const XCB_XFIXES_CREATE_POINTER_BARRIER: u8 = 31;
+pub const XCB_XFIXES_DELETE_POINTER_BARRIER: u8 = 32;
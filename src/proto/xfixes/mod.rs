@@ -0,0 +1,563 @@
+// Generated by xcbgen from xml/xfixes.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::os::raw::c_char;
+use std::ptr;
+use std::slice;
+
+use proto::render::XCBRenderPicture;
+use proto::shape::XCBShapeKind;
+use proto::xproto::{XCBAtom, XCBCursor, XCBGcontext, XCBPixmap, XCBRectangle, XCBWindow};
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBXfixesQueryVersionRequest = cdef::XCBXfixesQueryVersionRequest;
+pub type XCBXfixesQueryVersionCookie = cdef::XCBXfixesQueryVersionCookie;
+pub type XCBXfixesQueryVersionReply = cdef::XCBXfixesQueryVersionReply;
+pub type XCBXfixesChangeSaveSetRequest = cdef::XCBXfixesChangeSaveSetRequest;
+pub type XCBXfixesSelectionNotifyEvent = cdef::XCBXfixesSelectionNotifyEvent;
+pub type XCBXfixesSelectSelectionInputRequest = cdef::XCBXfixesSelectSelectionInputRequest;
+pub type XCBXfixesCursorNotifyEvent = cdef::XCBXfixesCursorNotifyEvent;
+pub type XCBXfixesSelectCursorInputRequest = cdef::XCBXfixesSelectCursorInputRequest;
+pub type XCBXfixesGetCursorImageRequest = cdef::XCBXfixesGetCursorImageRequest;
+pub type XCBXfixesGetCursorImageCookie = cdef::XCBXfixesGetCursorImageCookie;
+pub type XCBXfixesGetCursorImageReply = cdef::XCBXfixesGetCursorImageReply;
+pub type XCBXfixesRegion = cdef::XCBXfixesRegion;
+pub type XCBXfixesRegionIterator = cdef::XCBXfixesRegionIterator;
+pub type XCBXfixesBadRegionError = cdef::XCBXfixesBadRegionError;
+pub type XCBXfixesCreateRegionRequest = cdef::XCBXfixesCreateRegionRequest;
+pub type XCBXfixesCreateRegionFromBitmapRequest = cdef::XCBXfixesCreateRegionFromBitmapRequest;
+pub type XCBXfixesCreateRegionFromWindowRequest = cdef::XCBXfixesCreateRegionFromWindowRequest;
+pub type XCBXfixesCreateRegionFromGcRequest = cdef::XCBXfixesCreateRegionFromGcRequest;
+pub type XCBXfixesCreateRegionFromPictureRequest = cdef::XCBXfixesCreateRegionFromPictureRequest;
+pub type XCBXfixesDestroyRegionRequest = cdef::XCBXfixesDestroyRegionRequest;
+pub type XCBXfixesSetRegionRequest = cdef::XCBXfixesSetRegionRequest;
+pub type XCBXfixesCopyRegionRequest = cdef::XCBXfixesCopyRegionRequest;
+pub type XCBXfixesUnionRegionRequest = cdef::XCBXfixesUnionRegionRequest;
+pub type XCBXfixesIntersectRegionRequest = cdef::XCBXfixesIntersectRegionRequest;
+pub type XCBXfixesSubtractRegionRequest = cdef::XCBXfixesSubtractRegionRequest;
+pub type XCBXfixesInvertRegionRequest = cdef::XCBXfixesInvertRegionRequest;
+pub type XCBXfixesTranslateRegionRequest = cdef::XCBXfixesTranslateRegionRequest;
+pub type XCBXfixesRegionExtentsRequest = cdef::XCBXfixesRegionExtentsRequest;
+pub type XCBXfixesFetchRegionRequest = cdef::XCBXfixesFetchRegionRequest;
+pub type XCBXfixesFetchRegionCookie = cdef::XCBXfixesFetchRegionCookie;
+pub type XCBXfixesFetchRegionReply = cdef::XCBXfixesFetchRegionReply;
+pub type XCBXfixesSetGcClipRegionRequest = cdef::XCBXfixesSetGcClipRegionRequest;
+pub type XCBXfixesSetWindowShapeRegionRequest = cdef::XCBXfixesSetWindowShapeRegionRequest;
+pub type XCBXfixesSetPictureClipRegionRequest = cdef::XCBXfixesSetPictureClipRegionRequest;
+pub type XCBXfixesSetCursorNameRequest = cdef::XCBXfixesSetCursorNameRequest;
+pub type XCBXfixesGetCursorNameRequest = cdef::XCBXfixesGetCursorNameRequest;
+pub type XCBXfixesGetCursorNameCookie = cdef::XCBXfixesGetCursorNameCookie;
+pub type XCBXfixesGetCursorNameReply = cdef::XCBXfixesGetCursorNameReply;
+pub type XCBXfixesGetCursorImageAndNameRequest = cdef::XCBXfixesGetCursorImageAndNameRequest;
+pub type XCBXfixesGetCursorImageAndNameCookie = cdef::XCBXfixesGetCursorImageAndNameCookie;
+pub type XCBXfixesGetCursorImageAndNameReply = cdef::XCBXfixesGetCursorImageAndNameReply;
+pub type XCBXfixesChangeCursorRequest = cdef::XCBXfixesChangeCursorRequest;
+pub type XCBXfixesChangeCursorByNameRequest = cdef::XCBXfixesChangeCursorByNameRequest;
+pub type XCBXfixesExpandRegionRequest = cdef::XCBXfixesExpandRegionRequest;
+pub type XCBXfixesHideCursorRequest = cdef::XCBXfixesHideCursorRequest;
+pub type XCBXfixesShowCursorRequest = cdef::XCBXfixesShowCursorRequest;
+pub type XCBXfixesBarrier = cdef::XCBXfixesBarrier;
+pub type XCBXfixesBarrierIterator = cdef::XCBXfixesBarrierIterator;
+pub type XCBXfixesCreatePointerBarrierRequest = cdef::XCBXfixesCreatePointerBarrierRequest;
+pub type XCBXfixesDeletePointerBarrierRequest = cdef::XCBXfixesDeletePointerBarrierRequest;
+
+/// The XFixes extension, known to the server as `XFIXES`.
+pub struct Xfixes;
+
+impl Extension for Xfixes {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_xfixes_id)
+    }
+}
+
+impl Reply for XCBXfixesQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xfixes_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xfixes_query_version(
+    connection: &XCBConnection,
+    client_major_version: u32,
+    client_minor_version: u32,
+) -> XCBXfixesQueryVersionCookie {
+    unsafe {
+        cdef::xcb_xfixes_query_version(connection.raw, client_major_version, client_minor_version)
+    }
+}
+
+pub fn xcb_xfixes_change_save_set(
+    connection: &XCBConnection,
+    mode: u8,
+    target: u8,
+    map: u8,
+    window: XCBWindow,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_change_save_set(connection.raw, mode, target, map, window) }
+}
+
+pub fn xcb_xfixes_select_selection_input(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    selection: XCBAtom,
+    event_mask: u32,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xfixes_select_selection_input(connection.raw, window, selection, event_mask)
+    }
+}
+
+pub fn xcb_xfixes_select_cursor_input(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    event_mask: u32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_select_cursor_input(connection.raw, window, event_mask) }
+}
+
+pub fn xcb_xfixes_get_cursor_image_cursor_image(reply: &XCBXfixesGetCursorImageReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xfixes_get_cursor_image_cursor_image(reply),
+            cdef::xcb_xfixes_get_cursor_image_cursor_image_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXfixesGetCursorImageReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xfixes_get_cursor_image_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xfixes_get_cursor_image(connection: &XCBConnection) -> XCBXfixesGetCursorImageCookie {
+    unsafe { cdef::xcb_xfixes_get_cursor_image(connection.raw) }
+}
+
+pub fn xcb_xfixes_region_next(iter: &mut XCBXfixesRegionIterator) {
+    unsafe { cdef::xcb_xfixes_region_next(iter) }
+}
+
+pub fn xcb_xfixes_create_region(
+    connection: &XCBConnection,
+    region: XCBXfixesRegion,
+    rectangles: &[XCBRectangle],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xfixes_create_region(
+            connection.raw,
+            region,
+            rectangles.len() as u32,
+            rectangles.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_xfixes_create_region_from_bitmap(
+    connection: &XCBConnection,
+    region: XCBXfixesRegion,
+    bitmap: XCBPixmap,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_create_region_from_bitmap(connection.raw, region, bitmap) }
+}
+
+pub fn xcb_xfixes_create_region_from_window(
+    connection: &XCBConnection,
+    region: XCBXfixesRegion,
+    window: XCBWindow,
+    kind: XCBShapeKind,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_create_region_from_window(connection.raw, region, window, kind) }
+}
+
+pub fn xcb_xfixes_create_region_from_gc(
+    connection: &XCBConnection,
+    region: XCBXfixesRegion,
+    gc: XCBGcontext,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_create_region_from_gc(connection.raw, region, gc) }
+}
+
+pub fn xcb_xfixes_create_region_from_picture(
+    connection: &XCBConnection,
+    region: XCBXfixesRegion,
+    picture: XCBRenderPicture,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_create_region_from_picture(connection.raw, region, picture) }
+}
+
+pub fn xcb_xfixes_destroy_region(
+    connection: &XCBConnection,
+    region: XCBXfixesRegion,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_destroy_region(connection.raw, region) }
+}
+
+pub fn xcb_xfixes_set_region(
+    connection: &XCBConnection,
+    region: XCBXfixesRegion,
+    rectangles: &[XCBRectangle],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xfixes_set_region(
+            connection.raw,
+            region,
+            rectangles.len() as u32,
+            rectangles.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_xfixes_copy_region(
+    connection: &XCBConnection,
+    source: XCBXfixesRegion,
+    destination: XCBXfixesRegion,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_copy_region(connection.raw, source, destination) }
+}
+
+pub fn xcb_xfixes_union_region(
+    connection: &XCBConnection,
+    source1: XCBXfixesRegion,
+    source2: XCBXfixesRegion,
+    destination: XCBXfixesRegion,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_union_region(connection.raw, source1, source2, destination) }
+}
+
+pub fn xcb_xfixes_intersect_region(
+    connection: &XCBConnection,
+    source1: XCBXfixesRegion,
+    source2: XCBXfixesRegion,
+    destination: XCBXfixesRegion,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_intersect_region(connection.raw, source1, source2, destination) }
+}
+
+pub fn xcb_xfixes_subtract_region(
+    connection: &XCBConnection,
+    source1: XCBXfixesRegion,
+    source2: XCBXfixesRegion,
+    destination: XCBXfixesRegion,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_subtract_region(connection.raw, source1, source2, destination) }
+}
+
+pub fn xcb_xfixes_invert_region(
+    connection: &XCBConnection,
+    source: XCBXfixesRegion,
+    bounds: XCBRectangle,
+    destination: XCBXfixesRegion,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_invert_region(connection.raw, source, bounds, destination) }
+}
+
+pub fn xcb_xfixes_translate_region(
+    connection: &XCBConnection,
+    region: XCBXfixesRegion,
+    dx: i16,
+    dy: i16,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_translate_region(connection.raw, region, dx, dy) }
+}
+
+pub fn xcb_xfixes_region_extents(
+    connection: &XCBConnection,
+    source: XCBXfixesRegion,
+    destination: XCBXfixesRegion,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_region_extents(connection.raw, source, destination) }
+}
+
+pub fn xcb_xfixes_fetch_region_rectangles(reply: &XCBXfixesFetchRegionReply) -> &[XCBRectangle] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xfixes_fetch_region_rectangles(reply),
+            cdef::xcb_xfixes_fetch_region_rectangles_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXfixesFetchRegionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xfixes_fetch_region_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xfixes_fetch_region(
+    connection: &XCBConnection,
+    region: XCBXfixesRegion,
+) -> XCBXfixesFetchRegionCookie {
+    unsafe { cdef::xcb_xfixes_fetch_region(connection.raw, region) }
+}
+
+pub fn xcb_xfixes_set_gc_clip_region(
+    connection: &XCBConnection,
+    gc: XCBGcontext,
+    region: XCBXfixesRegion,
+    x_origin: i16,
+    y_origin: i16,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_set_gc_clip_region(connection.raw, gc, region, x_origin, y_origin) }
+}
+
+pub fn xcb_xfixes_set_window_shape_region(
+    connection: &XCBConnection,
+    dest: XCBWindow,
+    dest_kind: XCBShapeKind,
+    x_offset: i16,
+    y_offset: i16,
+    region: XCBXfixesRegion,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xfixes_set_window_shape_region(
+            connection.raw,
+            dest,
+            dest_kind,
+            x_offset,
+            y_offset,
+            region,
+        )
+    }
+}
+
+pub fn xcb_xfixes_set_picture_clip_region(
+    connection: &XCBConnection,
+    picture: XCBRenderPicture,
+    region: XCBXfixesRegion,
+    x_origin: i16,
+    y_origin: i16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xfixes_set_picture_clip_region(
+            connection.raw,
+            picture,
+            region,
+            x_origin,
+            y_origin,
+        )
+    }
+}
+
+pub fn xcb_xfixes_set_cursor_name(
+    connection: &XCBConnection,
+    cursor: XCBCursor,
+    name: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xfixes_set_cursor_name(
+            connection.raw,
+            cursor,
+            name.len() as u16,
+            name.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_xfixes_get_cursor_name_name(reply: &XCBXfixesGetCursorNameReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xfixes_get_cursor_name_name(reply) as *const u8,
+            cdef::xcb_xfixes_get_cursor_name_name_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXfixesGetCursorNameReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xfixes_get_cursor_name_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xfixes_get_cursor_name(
+    connection: &XCBConnection,
+    cursor: XCBCursor,
+) -> XCBXfixesGetCursorNameCookie {
+    unsafe { cdef::xcb_xfixes_get_cursor_name(connection.raw, cursor) }
+}
+
+pub fn xcb_xfixes_get_cursor_image_and_name_cursor_image(
+    reply: &XCBXfixesGetCursorImageAndNameReply,
+) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xfixes_get_cursor_image_and_name_cursor_image(reply),
+            cdef::xcb_xfixes_get_cursor_image_and_name_cursor_image_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_xfixes_get_cursor_image_and_name_name(
+    reply: &XCBXfixesGetCursorImageAndNameReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xfixes_get_cursor_image_and_name_name(reply) as *const u8,
+            cdef::xcb_xfixes_get_cursor_image_and_name_name_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXfixesGetCursorImageAndNameReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xfixes_get_cursor_image_and_name_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xfixes_get_cursor_image_and_name(
+    connection: &XCBConnection,
+) -> XCBXfixesGetCursorImageAndNameCookie {
+    unsafe { cdef::xcb_xfixes_get_cursor_image_and_name(connection.raw) }
+}
+
+pub fn xcb_xfixes_change_cursor(
+    connection: &XCBConnection,
+    source: XCBCursor,
+    destination: XCBCursor,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_change_cursor(connection.raw, source, destination) }
+}
+
+pub fn xcb_xfixes_change_cursor_by_name(
+    connection: &XCBConnection,
+    src: XCBCursor,
+    name: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xfixes_change_cursor_by_name(
+            connection.raw,
+            src,
+            name.len() as u16,
+            name.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_xfixes_expand_region(
+    connection: &XCBConnection,
+    source: XCBXfixesRegion,
+    destination: XCBXfixesRegion,
+    left: u16,
+    right: u16,
+    top: u16,
+    bottom: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xfixes_expand_region(
+            connection.raw,
+            source,
+            destination,
+            left,
+            right,
+            top,
+            bottom,
+        )
+    }
+}
+
+pub fn xcb_xfixes_hide_cursor(connection: &XCBConnection, window: XCBWindow) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_hide_cursor(connection.raw, window) }
+}
+
+pub fn xcb_xfixes_show_cursor(connection: &XCBConnection, window: XCBWindow) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_show_cursor(connection.raw, window) }
+}
+
+pub fn xcb_xfixes_barrier_next(iter: &mut XCBXfixesBarrierIterator) {
+    unsafe { cdef::xcb_xfixes_barrier_next(iter) }
+}
+
+pub fn xcb_xfixes_create_pointer_barrier(
+    connection: &XCBConnection,
+    barrier: XCBXfixesBarrier,
+    window: XCBWindow,
+    x1: u16,
+    y1: u16,
+    x2: u16,
+    y2: u16,
+    directions: u32,
+    devices: &[u16],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xfixes_create_pointer_barrier(
+            connection.raw,
+            barrier,
+            window,
+            x1,
+            y1,
+            x2,
+            y2,
+            directions,
+            devices.len() as u16,
+            devices.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_xfixes_delete_pointer_barrier(
+    connection: &XCBConnection,
+    barrier: XCBXfixesBarrier,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xfixes_delete_pointer_barrier(connection.raw, barrier) }
+}
+
+/// An event of the XFixes extension.
+pub enum Event {
+    SelectionNotify(Box<XCBXfixesSelectionNotifyEvent>),
+    CursorNotify(Box<XCBXfixesCursorNotifyEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::SelectionNotify(Box::from_raw(
+                    raw as *mut XCBXfixesSelectionNotifyEvent,
+                ))),
+                1 => Ok(Event::CursorNotify(Box::from_raw(
+                    raw as *mut XCBXfixesCursorNotifyEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
+
+/// Returns the name of the error whose code, relative to the first error of the extension, is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        0 => Some("BadRegion"),
+        _ => None,
+    }
+}
@@ -0,0 +1,246 @@
+// Generated by xcbgen from xml/xinerama.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+use cdef::{XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator};
+use proto::xproto::cdef::XCBWindow;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXineramaScreenInfo {
+    pub x_org: i16,
+    pub y_org: i16,
+    pub width: u16,
+    pub height: u16,
+}
+
+#[repr(C)]
+pub struct XCBXineramaScreenInfoIterator {
+    pub data: *mut XCBXineramaScreenInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXineramaQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub major: u8,
+    pub minor: u8,
+}
+
+pub type XCBXineramaQueryVersionCookie = XCBCookie<XCBXineramaQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXineramaQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major: u16,
+    pub minor: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXineramaGetStateRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBXineramaGetStateCookie = XCBCookie<XCBXineramaGetStateReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXineramaGetStateReply {
+    pub response_type: u8,
+    pub state: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXineramaGetScreenCountRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBXineramaGetScreenCountCookie = XCBCookie<XCBXineramaGetScreenCountReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXineramaGetScreenCountReply {
+    pub response_type: u8,
+    pub screen_count: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXineramaGetScreenSizeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub screen: u32,
+}
+
+pub type XCBXineramaGetScreenSizeCookie = XCBCookie<XCBXineramaGetScreenSizeReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXineramaGetScreenSizeReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub width: u32,
+    pub height: u32,
+    pub window: XCBWindow,
+    pub screen: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXineramaIsActiveRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBXineramaIsActiveCookie = XCBCookie<XCBXineramaIsActiveReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXineramaIsActiveReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub state: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXineramaQueryScreensRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBXineramaQueryScreensCookie = XCBCookie<XCBXineramaQueryScreensReply>;
+
+#[repr(C)]
+pub struct XCBXineramaQueryScreensReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub number: u32,
+    pub pad1: [u8; 20],
+}
+
+#[link(name = "xcb-xinerama")]
+extern "system" {
+    pub static mut xcb_xinerama_id: XCBExtension;
+    pub fn xcb_xinerama_screen_info_next(i: *mut XCBXineramaScreenInfoIterator);
+    pub fn xcb_xinerama_screen_info_end(i: XCBXineramaScreenInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_xinerama_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXineramaQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXineramaQueryVersionReply;
+    pub fn xcb_xinerama_query_version(
+        connection: *mut XCBConnection,
+        major: u8,
+        minor: u8,
+    ) -> XCBXineramaQueryVersionCookie;
+    pub fn xcb_xinerama_query_version_unchecked(
+        connection: *mut XCBConnection,
+        major: u8,
+        minor: u8,
+    ) -> XCBXineramaQueryVersionCookie;
+    pub fn xcb_xinerama_get_state_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXineramaGetStateCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXineramaGetStateReply;
+    pub fn xcb_xinerama_get_state(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBXineramaGetStateCookie;
+    pub fn xcb_xinerama_get_state_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBXineramaGetStateCookie;
+    pub fn xcb_xinerama_get_screen_count_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXineramaGetScreenCountCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXineramaGetScreenCountReply;
+    pub fn xcb_xinerama_get_screen_count(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBXineramaGetScreenCountCookie;
+    pub fn xcb_xinerama_get_screen_count_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBXineramaGetScreenCountCookie;
+    pub fn xcb_xinerama_get_screen_size_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXineramaGetScreenSizeCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXineramaGetScreenSizeReply;
+    pub fn xcb_xinerama_get_screen_size(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        screen: u32,
+    ) -> XCBXineramaGetScreenSizeCookie;
+    pub fn xcb_xinerama_get_screen_size_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        screen: u32,
+    ) -> XCBXineramaGetScreenSizeCookie;
+    pub fn xcb_xinerama_is_active_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXineramaIsActiveCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXineramaIsActiveReply;
+    pub fn xcb_xinerama_is_active(connection: *mut XCBConnection) -> XCBXineramaIsActiveCookie;
+    pub fn xcb_xinerama_is_active_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBXineramaIsActiveCookie;
+    pub fn xcb_xinerama_query_screens_screen_info(
+        R: *const XCBXineramaQueryScreensReply,
+    ) -> *mut XCBXineramaScreenInfo;
+    pub fn xcb_xinerama_query_screens_screen_info_length(
+        R: *const XCBXineramaQueryScreensReply,
+    ) -> c_int;
+    pub fn xcb_xinerama_query_screens_screen_info_iterator(
+        R: *const XCBXineramaQueryScreensReply,
+    ) -> XCBXineramaScreenInfoIterator;
+    pub fn xcb_xinerama_query_screens_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXineramaQueryScreensCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXineramaQueryScreensReply;
+    pub fn xcb_xinerama_query_screens(
+        connection: *mut XCBConnection,
+    ) -> XCBXineramaQueryScreensCookie;
+    pub fn xcb_xinerama_query_screens_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBXineramaQueryScreensCookie;
+}
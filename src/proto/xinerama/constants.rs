@@ -0,0 +1,10 @@
+// Generated by xcbgen from xml/xinerama.xml, do not edit.
+
+pub const XCB_XINERAMA_MAJOR_VERSION: u32 = 1;
+pub const XCB_XINERAMA_MINOR_VERSION: u32 = 1;
+pub const XCB_XINERAMA_QUERY_VERSION: u8 = 0;
+pub const XCB_XINERAMA_GET_STATE: u8 = 1;
+pub const XCB_XINERAMA_GET_SCREEN_COUNT: u8 = 2;
+pub const XCB_XINERAMA_GET_SCREEN_SIZE: u8 = 3;
+pub const XCB_XINERAMA_IS_ACTIVE: u8 = 4;
+pub const XCB_XINERAMA_QUERY_SCREENS: u8 = 5;
@@ -0,0 +1,155 @@
+// Generated by xcbgen from xml/xinerama.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use proto::xproto::XCBWindow;
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError};
+
+pub type XCBXineramaScreenInfo = cdef::XCBXineramaScreenInfo;
+pub type XCBXineramaScreenInfoIterator = cdef::XCBXineramaScreenInfoIterator;
+pub type XCBXineramaQueryVersionRequest = cdef::XCBXineramaQueryVersionRequest;
+pub type XCBXineramaQueryVersionCookie = cdef::XCBXineramaQueryVersionCookie;
+pub type XCBXineramaQueryVersionReply = cdef::XCBXineramaQueryVersionReply;
+pub type XCBXineramaGetStateRequest = cdef::XCBXineramaGetStateRequest;
+pub type XCBXineramaGetStateCookie = cdef::XCBXineramaGetStateCookie;
+pub type XCBXineramaGetStateReply = cdef::XCBXineramaGetStateReply;
+pub type XCBXineramaGetScreenCountRequest = cdef::XCBXineramaGetScreenCountRequest;
+pub type XCBXineramaGetScreenCountCookie = cdef::XCBXineramaGetScreenCountCookie;
+pub type XCBXineramaGetScreenCountReply = cdef::XCBXineramaGetScreenCountReply;
+pub type XCBXineramaGetScreenSizeRequest = cdef::XCBXineramaGetScreenSizeRequest;
+pub type XCBXineramaGetScreenSizeCookie = cdef::XCBXineramaGetScreenSizeCookie;
+pub type XCBXineramaGetScreenSizeReply = cdef::XCBXineramaGetScreenSizeReply;
+pub type XCBXineramaIsActiveRequest = cdef::XCBXineramaIsActiveRequest;
+pub type XCBXineramaIsActiveCookie = cdef::XCBXineramaIsActiveCookie;
+pub type XCBXineramaIsActiveReply = cdef::XCBXineramaIsActiveReply;
+pub type XCBXineramaQueryScreensRequest = cdef::XCBXineramaQueryScreensRequest;
+pub type XCBXineramaQueryScreensCookie = cdef::XCBXineramaQueryScreensCookie;
+pub type XCBXineramaQueryScreensReply = cdef::XCBXineramaQueryScreensReply;
+
+/// The Xinerama extension, known to the server as `XINERAMA`.
+pub struct Xinerama;
+
+impl Extension for Xinerama {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_xinerama_id)
+    }
+}
+
+pub fn xcb_xinerama_screen_info_next(iter: &mut XCBXineramaScreenInfoIterator) {
+    unsafe { cdef::xcb_xinerama_screen_info_next(iter) }
+}
+
+impl Reply for XCBXineramaQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xinerama_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xinerama_query_version(
+    connection: &XCBConnection,
+    major: u8,
+    minor: u8,
+) -> XCBXineramaQueryVersionCookie {
+    unsafe { cdef::xcb_xinerama_query_version(connection.raw, major, minor) }
+}
+
+impl Reply for XCBXineramaGetStateReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xinerama_get_state_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xinerama_get_state(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBXineramaGetStateCookie {
+    unsafe { cdef::xcb_xinerama_get_state(connection.raw, window) }
+}
+
+impl Reply for XCBXineramaGetScreenCountReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xinerama_get_screen_count_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xinerama_get_screen_count(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBXineramaGetScreenCountCookie {
+    unsafe { cdef::xcb_xinerama_get_screen_count(connection.raw, window) }
+}
+
+impl Reply for XCBXineramaGetScreenSizeReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xinerama_get_screen_size_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xinerama_get_screen_size(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    screen: u32,
+) -> XCBXineramaGetScreenSizeCookie {
+    unsafe { cdef::xcb_xinerama_get_screen_size(connection.raw, window, screen) }
+}
+
+impl Reply for XCBXineramaIsActiveReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xinerama_is_active_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xinerama_is_active(connection: &XCBConnection) -> XCBXineramaIsActiveCookie {
+    unsafe { cdef::xcb_xinerama_is_active(connection.raw) }
+}
+
+pub fn xcb_xinerama_query_screens_screen_info(
+    reply: &XCBXineramaQueryScreensReply,
+) -> &[XCBXineramaScreenInfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xinerama_query_screens_screen_info(reply),
+            cdef::xcb_xinerama_query_screens_screen_info_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXineramaQueryScreensReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xinerama_query_screens_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xinerama_query_screens(connection: &XCBConnection) -> XCBXineramaQueryScreensCookie {
+    unsafe { cdef::xcb_xinerama_query_screens(connection.raw) }
+}
@@ -0,0 +1,4160 @@
+// Generated by xcbgen from xml/xinput.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int, c_void};
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xfixes::cdef::XCBXfixesBarrier;
+use proto::xproto::cdef::{XCBAtom, XCBCursor, XCBKeysym, XCBStrIterator, XCBTimestamp, XCBWindow};
+
+pub type XCBInputEventClass = u32;
+
+#[repr(C)]
+pub struct XCBInputEventClassIterator {
+    pub data: *mut XCBInputEventClass,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBInputKeyCode = u8;
+
+#[repr(C)]
+pub struct XCBInputKeyCodeIterator {
+    pub data: *mut XCBInputKeyCode,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBInputDeviceId = u16;
+
+#[repr(C)]
+pub struct XCBInputDeviceIdIterator {
+    pub data: *mut XCBInputDeviceId,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBInputFp1616 = i32;
+
+#[repr(C)]
+pub struct XCBInputFp1616Iterator {
+    pub data: *mut XCBInputFp1616,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputFp3232 {
+    pub integral: i32,
+    pub frac: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputFp3232Iterator {
+    pub data: *mut XCBInputFp3232,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputGetExtensionVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub name_len: u16,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBInputGetExtensionVersionCookie = XCBCookie<XCBInputGetExtensionVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGetExtensionVersionReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub server_major: u16,
+    pub server_minor: u16,
+    pub present: u8,
+    pub pad0: [u8; 19],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceInfo {
+    pub device_type: XCBAtom,
+    pub device_id: u8,
+    pub num_class_info: u8,
+    pub device_use: u8,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceInfoIterator {
+    pub data: *mut XCBInputDeviceInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputKeyInfo {
+    pub class_id: u8,
+    pub len: u8,
+    pub min_keycode: XCBInputKeyCode,
+    pub max_keycode: XCBInputKeyCode,
+    pub num_keys: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBInputKeyInfoIterator {
+    pub data: *mut XCBInputKeyInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputButtonInfo {
+    pub class_id: u8,
+    pub len: u8,
+    pub num_buttons: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputButtonInfoIterator {
+    pub data: *mut XCBInputButtonInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputAxisInfo {
+    pub resolution: u32,
+    pub minimum: i32,
+    pub maximum: i32,
+}
+
+#[repr(C)]
+pub struct XCBInputAxisInfoIterator {
+    pub data: *mut XCBInputAxisInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputValuatorInfo {
+    pub class_id: u8,
+    pub len: u8,
+    pub axes_len: u8,
+    pub mode: u8,
+    pub motion_size: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputValuatorInfoIterator {
+    pub data: *mut XCBInputValuatorInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputInputInfo {
+    pub class_id: u8,
+    pub len: u8,
+}
+
+#[repr(C)]
+pub struct XCBInputInputInfoIterator {
+    pub data: *mut XCBInputInputInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceName {
+    pub len: u8,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceNameIterator {
+    pub data: *mut XCBInputDeviceName,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputListInputDevicesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBInputListInputDevicesCookie = XCBCookie<XCBInputListInputDevicesReply>;
+
+#[repr(C)]
+pub struct XCBInputListInputDevicesReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub devices_len: u8,
+    pub pad0: [u8; 23],
+}
+
+pub type XCBInputEventTypeBase = u8;
+
+#[repr(C)]
+pub struct XCBInputEventTypeBaseIterator {
+    pub data: *mut XCBInputEventTypeBase,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputInputClassInfo {
+    pub class_id: u8,
+    pub event_type_base: XCBInputEventTypeBase,
+}
+
+#[repr(C)]
+pub struct XCBInputInputClassInfoIterator {
+    pub data: *mut XCBInputInputClassInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputOpenDeviceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBInputOpenDeviceCookie = XCBCookie<XCBInputOpenDeviceReply>;
+
+#[repr(C)]
+pub struct XCBInputOpenDeviceReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_classes: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputCloseDeviceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputSetDeviceModeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub mode: u8,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBInputSetDeviceModeCookie = XCBCookie<XCBInputSetDeviceModeReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputSetDeviceModeReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub status: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+pub struct XCBInputSelectExtensionEventRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub num_classes: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGetSelectedExtensionEventsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBInputGetSelectedExtensionEventsCookie =
+    XCBCookie<XCBInputGetSelectedExtensionEventsReply>;
+
+#[repr(C)]
+pub struct XCBInputGetSelectedExtensionEventsReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_this_classes: u16,
+    pub num_all_classes: u16,
+    pub pad0: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBInputChangeDeviceDontPropagateListRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub num_classes: u16,
+    pub mode: u8,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGetDeviceDontPropagateListRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBInputGetDeviceDontPropagateListCookie =
+    XCBCookie<XCBInputGetDeviceDontPropagateListReply>;
+
+#[repr(C)]
+pub struct XCBInputGetDeviceDontPropagateListReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_classes: u16,
+    pub pad0: [u8; 22],
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceTimeCoord {
+    pub time: XCBTimestamp,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceTimeCoordIterator {
+    pub data: *mut XCBInputDeviceTimeCoord,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGetDeviceMotionEventsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub start: XCBTimestamp,
+    pub stop: XCBTimestamp,
+    pub device_id: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBInputGetDeviceMotionEventsCookie = XCBCookie<XCBInputGetDeviceMotionEventsReply>;
+
+#[repr(C)]
+pub struct XCBInputGetDeviceMotionEventsReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_events: u32,
+    pub num_axes: u8,
+    pub device_mode: u8,
+    pub pad0: [u8; 18],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputChangeKeyboardDeviceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBInputChangeKeyboardDeviceCookie = XCBCookie<XCBInputChangeKeyboardDeviceReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputChangeKeyboardDeviceReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub status: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputChangePointerDeviceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub x_axis: u8,
+    pub y_axis: u8,
+    pub device_id: u8,
+    pub pad0: u8,
+}
+
+pub type XCBInputChangePointerDeviceCookie = XCBCookie<XCBInputChangePointerDeviceReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputChangePointerDeviceReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub status: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+pub struct XCBInputGrabDeviceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub grab_window: XCBWindow,
+    pub time: XCBTimestamp,
+    pub num_classes: u16,
+    pub this_device_mode: u8,
+    pub other_device_mode: u8,
+    pub owner_events: u8,
+    pub device_id: u8,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBInputGrabDeviceCookie = XCBCookie<XCBInputGrabDeviceReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGrabDeviceReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub status: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputUngrabDeviceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub time: XCBTimestamp,
+    pub device_id: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+pub struct XCBInputGrabDeviceKeyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub grab_window: XCBWindow,
+    pub num_classes: u16,
+    pub modifiers: u16,
+    pub modifier_device: u8,
+    pub grabbed_device: u8,
+    pub key: u8,
+    pub this_device_mode: u8,
+    pub other_device_mode: u8,
+    pub owner_events: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputUngrabDeviceKeyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub grabWindow: XCBWindow,
+    pub modifiers: u16,
+    pub modifier_device: u8,
+    pub key: u8,
+    pub grabbed_device: u8,
+}
+
+#[repr(C)]
+pub struct XCBInputGrabDeviceButtonRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub grab_window: XCBWindow,
+    pub grabbed_device: u8,
+    pub modifier_device: u8,
+    pub num_classes: u16,
+    pub modifiers: u16,
+    pub this_device_mode: u8,
+    pub other_device_mode: u8,
+    pub button: u8,
+    pub owner_events: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputUngrabDeviceButtonRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub grab_window: XCBWindow,
+    pub modifiers: u16,
+    pub modifier_device: u8,
+    pub button: u8,
+    pub grabbed_device: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputAllowDeviceEventsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub time: XCBTimestamp,
+    pub mode: u8,
+    pub device_id: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGetDeviceFocusRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBInputGetDeviceFocusCookie = XCBCookie<XCBInputGetDeviceFocusReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGetDeviceFocusReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub focus: XCBWindow,
+    pub time: XCBTimestamp,
+    pub revert_to: u8,
+    pub pad0: [u8; 15],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputSetDeviceFocusRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub focus: XCBWindow,
+    pub time: XCBTimestamp,
+    pub revert_to: u8,
+    pub device_id: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputKbdFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub pitch: u16,
+    pub duration: u16,
+    pub led_mask: u32,
+    pub led_values: u32,
+    pub global_auto_repeat: u8,
+    pub click: u8,
+    pub percent: u8,
+    pub pad0: u8,
+    pub auto_repeats: [u8; 32],
+}
+
+#[repr(C)]
+pub struct XCBInputKbdFeedbackStateIterator {
+    pub data: *mut XCBInputKbdFeedbackState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputPtrFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub pad0: [u8; 2],
+    pub accel_num: u16,
+    pub accel_denom: u16,
+    pub threshold: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputPtrFeedbackStateIterator {
+    pub data: *mut XCBInputPtrFeedbackState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputIntegerFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub resolution: u32,
+    pub min_value: i32,
+    pub max_value: i32,
+}
+
+#[repr(C)]
+pub struct XCBInputIntegerFeedbackStateIterator {
+    pub data: *mut XCBInputIntegerFeedbackState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputStringFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub max_symbols: u16,
+    pub num_keysyms: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputStringFeedbackStateIterator {
+    pub data: *mut XCBInputStringFeedbackState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputBellFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub percent: u8,
+    pub pad0: [u8; 3],
+    pub pitch: u16,
+    pub duration: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputBellFeedbackStateIterator {
+    pub data: *mut XCBInputBellFeedbackState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputLedFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub led_mask: u32,
+    pub led_values: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputLedFeedbackStateIterator {
+    pub data: *mut XCBInputLedFeedbackState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputFeedbackStateIterator {
+    pub data: *mut XCBInputFeedbackState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGetFeedbackControlRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBInputGetFeedbackControlCookie = XCBCookie<XCBInputGetFeedbackControlReply>;
+
+#[repr(C)]
+pub struct XCBInputGetFeedbackControlReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_feedbacks: u16,
+    pub pad0: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputKbdFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub key: XCBInputKeyCode,
+    pub auto_repeat_mode: u8,
+    pub key_click_percent: i8,
+    pub bell_percent: i8,
+    pub bell_pitch: i16,
+    pub bell_duration: i16,
+    pub led_mask: u32,
+    pub led_values: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputKbdFeedbackCtlIterator {
+    pub data: *mut XCBInputKbdFeedbackCtl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputPtrFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub pad0: [u8; 2],
+    pub num: i16,
+    pub denom: i16,
+    pub threshold: i16,
+}
+
+#[repr(C)]
+pub struct XCBInputPtrFeedbackCtlIterator {
+    pub data: *mut XCBInputPtrFeedbackCtl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputIntegerFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub int_to_display: i32,
+}
+
+#[repr(C)]
+pub struct XCBInputIntegerFeedbackCtlIterator {
+    pub data: *mut XCBInputIntegerFeedbackCtl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputStringFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub pad0: [u8; 2],
+    pub num_keysyms: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputStringFeedbackCtlIterator {
+    pub data: *mut XCBInputStringFeedbackCtl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputBellFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub percent: i8,
+    pub pad0: [u8; 3],
+    pub pitch: i16,
+    pub duration: i16,
+}
+
+#[repr(C)]
+pub struct XCBInputBellFeedbackCtlIterator {
+    pub data: *mut XCBInputBellFeedbackCtl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputLedFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub led_mask: u32,
+    pub led_values: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputLedFeedbackCtlIterator {
+    pub data: *mut XCBInputLedFeedbackCtl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputFeedbackCtlIterator {
+    pub data: *mut XCBInputFeedbackCtl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputChangeFeedbackControlRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub mask: u32,
+    pub device_id: u8,
+    pub feedback_id: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGetDeviceKeyMappingRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub first_keycode: XCBInputKeyCode,
+    pub count: u8,
+    pub pad0: u8,
+}
+
+pub type XCBInputGetDeviceKeyMappingCookie = XCBCookie<XCBInputGetDeviceKeyMappingReply>;
+
+#[repr(C)]
+pub struct XCBInputGetDeviceKeyMappingReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub keysyms_per_keycode: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+pub struct XCBInputChangeDeviceKeyMappingRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub first_keycode: XCBInputKeyCode,
+    pub keysyms_per_keycode: u8,
+    pub keycode_count: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGetDeviceModifierMappingRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBInputGetDeviceModifierMappingCookie = XCBCookie<XCBInputGetDeviceModifierMappingReply>;
+
+#[repr(C)]
+pub struct XCBInputGetDeviceModifierMappingReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub keycodes_per_modifier: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+pub struct XCBInputSetDeviceModifierMappingRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub keycodes_per_modifier: u8,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBInputSetDeviceModifierMappingCookie = XCBCookie<XCBInputSetDeviceModifierMappingReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputSetDeviceModifierMappingReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub status: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGetDeviceButtonMappingRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBInputGetDeviceButtonMappingCookie = XCBCookie<XCBInputGetDeviceButtonMappingReply>;
+
+#[repr(C)]
+pub struct XCBInputGetDeviceButtonMappingReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub map_size: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+pub struct XCBInputSetDeviceButtonMappingRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub map_size: u8,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBInputSetDeviceButtonMappingCookie = XCBCookie<XCBInputSetDeviceButtonMappingReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputSetDeviceButtonMappingReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub status: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputKeyState {
+    pub class_id: u8,
+    pub len: u8,
+    pub num_keys: u8,
+    pub pad0: u8,
+    pub keys: [u8; 32],
+}
+
+#[repr(C)]
+pub struct XCBInputKeyStateIterator {
+    pub data: *mut XCBInputKeyState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputButtonState {
+    pub class_id: u8,
+    pub len: u8,
+    pub num_buttons: u8,
+    pub pad0: u8,
+    pub buttons: [u8; 32],
+}
+
+#[repr(C)]
+pub struct XCBInputButtonStateIterator {
+    pub data: *mut XCBInputButtonState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputValuatorState {
+    pub class_id: u8,
+    pub len: u8,
+    pub num_valuators: u8,
+    pub mode: u8,
+}
+
+#[repr(C)]
+pub struct XCBInputValuatorStateIterator {
+    pub data: *mut XCBInputValuatorState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputInputState {
+    pub class_id: u8,
+    pub len: u8,
+}
+
+#[repr(C)]
+pub struct XCBInputInputStateIterator {
+    pub data: *mut XCBInputInputState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputQueryDeviceStateRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBInputQueryDeviceStateCookie = XCBCookie<XCBInputQueryDeviceStateReply>;
+
+#[repr(C)]
+pub struct XCBInputQueryDeviceStateReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_classes: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceBellRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub feedback_id: u8,
+    pub feedback_class: u8,
+    pub percent: i8,
+}
+
+#[repr(C)]
+pub struct XCBInputSetDeviceValuatorsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub first_valuator: u8,
+    pub num_valuators: u8,
+    pub pad0: u8,
+}
+
+pub type XCBInputSetDeviceValuatorsCookie = XCBCookie<XCBInputSetDeviceValuatorsReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputSetDeviceValuatorsReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub status: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceResolutionState {
+    pub control_id: u16,
+    pub len: u16,
+    pub num_valuators: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceResolutionStateIterator {
+    pub data: *mut XCBInputDeviceResolutionState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceAbsCalibState {
+    pub control_id: u16,
+    pub len: u16,
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+    pub flip_x: u32,
+    pub flip_y: u32,
+    pub rotation: u32,
+    pub button_threshold: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceAbsCalibStateIterator {
+    pub data: *mut XCBInputDeviceAbsCalibState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceAbsAreaState {
+    pub control_id: u16,
+    pub len: u16,
+    pub offset_x: u32,
+    pub offset_y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub screen: u32,
+    pub following: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceAbsAreaStateIterator {
+    pub data: *mut XCBInputDeviceAbsAreaState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceCoreState {
+    pub control_id: u16,
+    pub len: u16,
+    pub status: u8,
+    pub iscore: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceCoreStateIterator {
+    pub data: *mut XCBInputDeviceCoreState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceEnableState {
+    pub control_id: u16,
+    pub len: u16,
+    pub enable: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceEnableStateIterator {
+    pub data: *mut XCBInputDeviceEnableState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceState {
+    pub control_id: u16,
+    pub len: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceStateIterator {
+    pub data: *mut XCBInputDeviceState,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGetDeviceControlRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub control_id: u16,
+    pub device_id: u8,
+    pub pad0: u8,
+}
+
+pub type XCBInputGetDeviceControlCookie = XCBCookie<XCBInputGetDeviceControlReply>;
+
+#[repr(C)]
+pub struct XCBInputGetDeviceControlReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub status: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceResolutionCtl {
+    pub control_id: u16,
+    pub len: u16,
+    pub first_valuator: u8,
+    pub num_valuators: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceResolutionCtlIterator {
+    pub data: *mut XCBInputDeviceResolutionCtl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceAbsCalibCtl {
+    pub control_id: u16,
+    pub len: u16,
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+    pub flip_x: u32,
+    pub flip_y: u32,
+    pub rotation: u32,
+    pub button_threshold: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceAbsCalibCtlIterator {
+    pub data: *mut XCBInputDeviceAbsCalibCtl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceAbsAreaCtrl {
+    pub control_id: u16,
+    pub len: u16,
+    pub offset_x: u32,
+    pub offset_y: u32,
+    pub width: i32,
+    pub height: i32,
+    pub screen: i32,
+    pub following: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceAbsAreaCtrlIterator {
+    pub data: *mut XCBInputDeviceAbsAreaCtrl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceCoreCtrl {
+    pub control_id: u16,
+    pub len: u16,
+    pub status: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceCoreCtrlIterator {
+    pub data: *mut XCBInputDeviceCoreCtrl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceEnableCtrl {
+    pub control_id: u16,
+    pub len: u16,
+    pub enable: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceEnableCtrlIterator {
+    pub data: *mut XCBInputDeviceEnableCtrl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceCtl {
+    pub control_id: u16,
+    pub len: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceCtlIterator {
+    pub data: *mut XCBInputDeviceCtl,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputChangeDeviceControlRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub control_id: u16,
+    pub device_id: u8,
+    pub pad0: u8,
+}
+
+pub type XCBInputChangeDeviceControlCookie = XCBCookie<XCBInputChangeDeviceControlReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputChangeDeviceControlReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub status: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputListDevicePropertiesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device_id: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBInputListDevicePropertiesCookie = XCBCookie<XCBInputListDevicePropertiesReply>;
+
+#[repr(C)]
+pub struct XCBInputListDevicePropertiesReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_atoms: u16,
+    pub pad0: [u8; 22],
+}
+
+#[repr(C)]
+pub struct XCBInputChangeDevicePropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub property: XCBAtom,
+    pub type_: XCBAtom,
+    pub device_id: u8,
+    pub format: u8,
+    pub mode: u8,
+    pub pad0: u8,
+    pub num_items: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeleteDevicePropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub property: XCBAtom,
+    pub device_id: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGetDevicePropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub property: XCBAtom,
+    pub type_: XCBAtom,
+    pub offset: u32,
+    pub len: u32,
+    pub device_id: u8,
+    pub delete: u8,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBInputGetDevicePropertyCookie = XCBCookie<XCBInputGetDevicePropertyReply>;
+
+#[repr(C)]
+pub struct XCBInputGetDevicePropertyReply {
+    pub response_type: u8,
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub type_: XCBAtom,
+    pub bytes_after: u32,
+    pub num_items: u32,
+    pub format: u8,
+    pub device_id: u8,
+    pub pad0: [u8; 10],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGroupInfo {
+    pub base: u8,
+    pub latched: u8,
+    pub locked: u8,
+    pub effective: u8,
+}
+
+#[repr(C)]
+pub struct XCBInputGroupInfoIterator {
+    pub data: *mut XCBInputGroupInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputModifierInfo {
+    pub base: u32,
+    pub latched: u32,
+    pub locked: u32,
+    pub effective: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputModifierInfoIterator {
+    pub data: *mut XCBInputModifierInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiQueryPointerRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub deviceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBInputXiQueryPointerCookie = XCBCookie<XCBInputXiQueryPointerReply>;
+
+#[repr(C)]
+pub struct XCBInputXiQueryPointerReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub root: XCBWindow,
+    pub child: XCBWindow,
+    pub root_x: XCBInputFp1616,
+    pub root_y: XCBInputFp1616,
+    pub win_x: XCBInputFp1616,
+    pub win_y: XCBInputFp1616,
+    pub same_screen: u8,
+    pub pad1: u8,
+    pub buttons_len: u16,
+    pub mods: XCBInputModifierInfo,
+    pub group: XCBInputGroupInfo,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiWarpPointerRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub src_win: XCBWindow,
+    pub dst_win: XCBWindow,
+    pub src_x: XCBInputFp1616,
+    pub src_y: XCBInputFp1616,
+    pub src_width: u16,
+    pub src_height: u16,
+    pub dst_x: XCBInputFp1616,
+    pub dst_y: XCBInputFp1616,
+    pub deviceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiChangeCursorRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub cursor: XCBCursor,
+    pub deviceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBInputAddMaster {
+    pub type_: u16,
+    pub len: u16,
+    pub name_len: u16,
+    pub send_core: u8,
+    pub enable: u8,
+}
+
+#[repr(C)]
+pub struct XCBInputAddMasterIterator {
+    pub data: *mut XCBInputAddMaster,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputRemoveMaster {
+    pub type_: u16,
+    pub len: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub return_mode: u8,
+    pub pad0: u8,
+    pub return_pointer: XCBInputDeviceId,
+    pub return_keyboard: XCBInputDeviceId,
+}
+
+#[repr(C)]
+pub struct XCBInputRemoveMasterIterator {
+    pub data: *mut XCBInputRemoveMaster,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputAttachSlave {
+    pub type_: u16,
+    pub len: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub master: XCBInputDeviceId,
+}
+
+#[repr(C)]
+pub struct XCBInputAttachSlaveIterator {
+    pub data: *mut XCBInputAttachSlave,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDetachSlave {
+    pub type_: u16,
+    pub len: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBInputDetachSlaveIterator {
+    pub data: *mut XCBInputDetachSlave,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputHierarchyChange {
+    pub type_: u16,
+    pub len: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputHierarchyChangeIterator {
+    pub data: *mut XCBInputHierarchyChange,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputXiChangeHierarchyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub num_changes: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiSetClientPointerRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub deviceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiGetClientPointerRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBInputXiGetClientPointerCookie = XCBCookie<XCBInputXiGetClientPointerReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiGetClientPointerReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub set: u8,
+    pub pad1: u8,
+    pub deviceid: XCBInputDeviceId,
+    pub pad2: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBInputEventMask {
+    pub deviceid: XCBInputDeviceId,
+    pub mask_len: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputEventMaskIterator {
+    pub data: *mut XCBInputEventMask,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputXiSelectEventsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub num_mask: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+
+pub type XCBInputXiQueryVersionCookie = XCBCookie<XCBInputXiQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBInputButtonClass {
+    pub type_: u16,
+    pub len: u16,
+    pub sourceid: XCBInputDeviceId,
+    pub num_buttons: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputButtonClassIterator {
+    pub data: *mut XCBInputButtonClass,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputKeyClass {
+    pub type_: u16,
+    pub len: u16,
+    pub sourceid: XCBInputDeviceId,
+    pub num_keys: u16,
+}
+
+#[repr(C)]
+pub struct XCBInputKeyClassIterator {
+    pub data: *mut XCBInputKeyClass,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputScrollClass {
+    pub type_: u16,
+    pub len: u16,
+    pub sourceid: XCBInputDeviceId,
+    pub number: u16,
+    pub scroll_type: u16,
+    pub pad0: [u8; 2],
+    pub flags: u32,
+    pub increment: XCBInputFp3232,
+}
+
+#[repr(C)]
+pub struct XCBInputScrollClassIterator {
+    pub data: *mut XCBInputScrollClass,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputTouchClass {
+    pub type_: u16,
+    pub len: u16,
+    pub sourceid: XCBInputDeviceId,
+    pub mode: u8,
+    pub num_touches: u8,
+}
+
+#[repr(C)]
+pub struct XCBInputTouchClassIterator {
+    pub data: *mut XCBInputTouchClass,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputValuatorClass {
+    pub type_: u16,
+    pub len: u16,
+    pub sourceid: XCBInputDeviceId,
+    pub number: u16,
+    pub label: XCBAtom,
+    pub min: XCBInputFp3232,
+    pub max: XCBInputFp3232,
+    pub value: XCBInputFp3232,
+    pub resolution: u32,
+    pub mode: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+pub struct XCBInputValuatorClassIterator {
+    pub data: *mut XCBInputValuatorClass,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceClass {
+    pub type_: u16,
+    pub len: u16,
+    pub sourceid: XCBInputDeviceId,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceClassIterator {
+    pub data: *mut XCBInputDeviceClass,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputXiDeviceInfo {
+    pub deviceid: XCBInputDeviceId,
+    pub type_: u16,
+    pub attachment: XCBInputDeviceId,
+    pub num_classes: u16,
+    pub name_len: u16,
+    pub enabled: u8,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+pub struct XCBInputXiDeviceInfoIterator {
+    pub data: *mut XCBInputXiDeviceInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiQueryDeviceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBInputXiQueryDeviceCookie = XCBCookie<XCBInputXiQueryDeviceReply>;
+
+#[repr(C)]
+pub struct XCBInputXiQueryDeviceReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_infos: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiSetFocusRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub time: XCBTimestamp,
+    pub deviceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiGetFocusRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBInputXiGetFocusCookie = XCBCookie<XCBInputXiGetFocusReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiGetFocusReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub focus: XCBWindow,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBInputXiGrabDeviceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub time: XCBTimestamp,
+    pub cursor: XCBCursor,
+    pub deviceid: XCBInputDeviceId,
+    pub mode: u8,
+    pub paired_device_mode: u8,
+    pub owner_events: u8,
+    pub pad0: u8,
+    pub mask_len: u16,
+}
+
+pub type XCBInputXiGrabDeviceCookie = XCBCookie<XCBInputXiGrabDeviceReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiGrabDeviceReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub status: u8,
+    pub pad1: [u8; 23],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiUngrabDeviceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub time: XCBTimestamp,
+    pub deviceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiAllowEventsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub time: XCBTimestamp,
+    pub deviceid: XCBInputDeviceId,
+    pub event_mode: u8,
+    pub pad0: u8,
+    pub touchid: u32,
+    pub grab_window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputGrabModifierInfo {
+    pub modifiers: u32,
+    pub status: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+pub struct XCBInputGrabModifierInfoIterator {
+    pub data: *mut XCBInputGrabModifierInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputXiPassiveGrabDeviceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub time: XCBTimestamp,
+    pub grab_window: XCBWindow,
+    pub cursor: XCBCursor,
+    pub detail: u32,
+    pub deviceid: XCBInputDeviceId,
+    pub num_modifiers: u16,
+    pub mask_len: u16,
+    pub grab_type: u8,
+    pub grab_mode: u8,
+    pub paired_device_mode: u8,
+    pub owner_events: u8,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBInputXiPassiveGrabDeviceCookie = XCBCookie<XCBInputXiPassiveGrabDeviceReply>;
+
+#[repr(C)]
+pub struct XCBInputXiPassiveGrabDeviceReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_modifiers: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+pub struct XCBInputXiPassiveUngrabDeviceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub grab_window: XCBWindow,
+    pub detail: u32,
+    pub deviceid: XCBInputDeviceId,
+    pub num_modifiers: u16,
+    pub grab_type: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiListPropertiesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBInputXiListPropertiesCookie = XCBCookie<XCBInputXiListPropertiesReply>;
+
+#[repr(C)]
+pub struct XCBInputXiListPropertiesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_properties: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+pub struct XCBInputXiChangePropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub mode: u8,
+    pub format: u8,
+    pub property: XCBAtom,
+    pub type_: XCBAtom,
+    pub num_items: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiDeletePropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+    pub property: XCBAtom,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiGetPropertyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub delete: u8,
+    pub pad0: u8,
+    pub property: XCBAtom,
+    pub type_: XCBAtom,
+    pub offset: u32,
+    pub len: u32,
+}
+
+pub type XCBInputXiGetPropertyCookie = XCBCookie<XCBInputXiGetPropertyReply>;
+
+#[repr(C)]
+pub struct XCBInputXiGetPropertyReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub type_: XCBAtom,
+    pub bytes_after: u32,
+    pub num_items: u32,
+    pub format: u8,
+    pub pad1: [u8; 11],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputXiGetSelectedEventsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBInputXiGetSelectedEventsCookie = XCBCookie<XCBInputXiGetSelectedEventsReply>;
+
+#[repr(C)]
+pub struct XCBInputXiGetSelectedEventsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_masks: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputBarrierReleasePointerInfo {
+    pub deviceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+    pub barrier: XCBXfixesBarrier,
+    pub eventid: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputBarrierReleasePointerInfoIterator {
+    pub data: *mut XCBInputBarrierReleasePointerInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputXiBarrierReleasePointerRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub num_barriers: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceValuatorEvent {
+    pub response_type: u8,
+    pub device_id: u8,
+    pub sequence: u16,
+    pub device_state: u16,
+    pub num_valuators: u8,
+    pub first_valuator: u8,
+    pub valuators: [i32; 6],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceKeyPressEvent {
+    pub response_type: u8,
+    pub detail: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub root: XCBWindow,
+    pub event: XCBWindow,
+    pub child: XCBWindow,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub event_x: i16,
+    pub event_y: i16,
+    pub state: u16,
+    pub same_screen: u8,
+    pub device_id: u8,
+}
+
+pub type XCBInputDeviceKeyReleaseEvent = XCBInputDeviceKeyPressEvent;
+
+pub type XCBInputDeviceButtonPressEvent = XCBInputDeviceKeyPressEvent;
+
+pub type XCBInputDeviceButtonReleaseEvent = XCBInputDeviceKeyPressEvent;
+
+pub type XCBInputDeviceMotionNotifyEvent = XCBInputDeviceKeyPressEvent;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceFocusInEvent {
+    pub response_type: u8,
+    pub detail: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub window: XCBWindow,
+    pub mode: u8,
+    pub device_id: u8,
+    pub pad0: [u8; 18],
+}
+
+pub type XCBInputDeviceFocusOutEvent = XCBInputDeviceFocusInEvent;
+
+pub type XCBInputProximityInEvent = XCBInputDeviceKeyPressEvent;
+
+pub type XCBInputProximityOutEvent = XCBInputDeviceKeyPressEvent;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceStateNotifyEvent {
+    pub response_type: u8,
+    pub device_id: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub num_keys: u8,
+    pub num_buttons: u8,
+    pub num_valuators: u8,
+    pub classes_reported: u8,
+    pub buttons: [u8; 4],
+    pub keys: [u8; 4],
+    pub valuators: [u32; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceMappingNotifyEvent {
+    pub response_type: u8,
+    pub device_id: u8,
+    pub sequence: u16,
+    pub request: u8,
+    pub first_keycode: XCBInputKeyCode,
+    pub count: u8,
+    pub pad0: u8,
+    pub time: XCBTimestamp,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputChangeDeviceNotifyEvent {
+    pub response_type: u8,
+    pub device_id: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub request: u8,
+    pub pad0: [u8; 23],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceKeyStateNotifyEvent {
+    pub response_type: u8,
+    pub device_id: u8,
+    pub sequence: u16,
+    pub keys: [u8; 28],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceButtonStateNotifyEvent {
+    pub response_type: u8,
+    pub device_id: u8,
+    pub sequence: u16,
+    pub buttons: [u8; 28],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDevicePresenceNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub devchange: u8,
+    pub device_id: u8,
+    pub control: u16,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDevicePropertyNotifyEvent {
+    pub response_type: u8,
+    pub state: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub property: XCBAtom,
+    pub pad0: [u8; 19],
+    pub device_id: u8,
+}
+
+#[repr(C)]
+pub struct XCBInputDeviceChangedEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub time: XCBTimestamp,
+    pub num_classes: u16,
+    pub sourceid: XCBInputDeviceId,
+    pub reason: u8,
+    pub pad0: [u8; 11],
+    pub full_sequence: u32,
+}
+
+#[repr(C, packed)]
+pub struct XCBInputKeyPressEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub time: XCBTimestamp,
+    pub detail: u32,
+    pub root: XCBWindow,
+    pub event: XCBWindow,
+    pub child: XCBWindow,
+    pub full_sequence: u32,
+    pub root_x: XCBInputFp1616,
+    pub root_y: XCBInputFp1616,
+    pub event_x: XCBInputFp1616,
+    pub event_y: XCBInputFp1616,
+    pub buttons_len: u16,
+    pub valuators_len: u16,
+    pub sourceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+    pub flags: u32,
+    pub mods: XCBInputModifierInfo,
+    pub group: XCBInputGroupInfo,
+}
+
+pub type XCBInputKeyReleaseEvent = XCBInputKeyPressEvent;
+
+#[repr(C, packed)]
+pub struct XCBInputButtonPressEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub time: XCBTimestamp,
+    pub detail: u32,
+    pub root: XCBWindow,
+    pub event: XCBWindow,
+    pub child: XCBWindow,
+    pub full_sequence: u32,
+    pub root_x: XCBInputFp1616,
+    pub root_y: XCBInputFp1616,
+    pub event_x: XCBInputFp1616,
+    pub event_y: XCBInputFp1616,
+    pub buttons_len: u16,
+    pub valuators_len: u16,
+    pub sourceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+    pub flags: u32,
+    pub mods: XCBInputModifierInfo,
+    pub group: XCBInputGroupInfo,
+}
+
+pub type XCBInputButtonReleaseEvent = XCBInputButtonPressEvent;
+
+pub type XCBInputMotionEvent = XCBInputButtonPressEvent;
+
+#[repr(C)]
+pub struct XCBInputEnterEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub time: XCBTimestamp,
+    pub sourceid: XCBInputDeviceId,
+    pub mode: u8,
+    pub detail: u8,
+    pub root: XCBWindow,
+    pub event: XCBWindow,
+    pub child: XCBWindow,
+    pub full_sequence: u32,
+    pub root_x: XCBInputFp1616,
+    pub root_y: XCBInputFp1616,
+    pub event_x: XCBInputFp1616,
+    pub event_y: XCBInputFp1616,
+    pub same_screen: u8,
+    pub focus: u8,
+    pub buttons_len: u16,
+    pub mods: XCBInputModifierInfo,
+    pub group: XCBInputGroupInfo,
+}
+
+pub type XCBInputLeaveEvent = XCBInputEnterEvent;
+
+pub type XCBInputFocusInEvent = XCBInputEnterEvent;
+
+pub type XCBInputFocusOutEvent = XCBInputEnterEvent;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputHierarchyInfo {
+    pub deviceid: XCBInputDeviceId,
+    pub attachment: XCBInputDeviceId,
+    pub type_: u8,
+    pub enabled: u8,
+    pub pad0: [u8; 2],
+    pub flags: u32,
+}
+
+#[repr(C)]
+pub struct XCBInputHierarchyInfoIterator {
+    pub data: *mut XCBInputHierarchyInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputHierarchyEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub time: XCBTimestamp,
+    pub flags: u32,
+    pub num_infos: u16,
+    pub pad0: [u8; 10],
+    pub full_sequence: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputPropertyEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub time: XCBTimestamp,
+    pub property: XCBAtom,
+    pub what: u8,
+    pub pad0: [u8; 11],
+    pub full_sequence: u32,
+}
+
+#[repr(C, packed)]
+pub struct XCBInputRawKeyPressEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub time: XCBTimestamp,
+    pub detail: u32,
+    pub sourceid: XCBInputDeviceId,
+    pub valuators_len: u16,
+    pub flags: u32,
+    pub pad0: [u8; 4],
+    pub full_sequence: u32,
+}
+
+pub type XCBInputRawKeyReleaseEvent = XCBInputRawKeyPressEvent;
+
+#[repr(C, packed)]
+pub struct XCBInputRawButtonPressEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub time: XCBTimestamp,
+    pub detail: u32,
+    pub sourceid: XCBInputDeviceId,
+    pub valuators_len: u16,
+    pub flags: u32,
+    pub pad0: [u8; 4],
+    pub full_sequence: u32,
+}
+
+pub type XCBInputRawButtonReleaseEvent = XCBInputRawButtonPressEvent;
+
+pub type XCBInputRawMotionEvent = XCBInputRawButtonPressEvent;
+
+#[repr(C, packed)]
+pub struct XCBInputTouchBeginEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub time: XCBTimestamp,
+    pub detail: u32,
+    pub root: XCBWindow,
+    pub event: XCBWindow,
+    pub child: XCBWindow,
+    pub full_sequence: u32,
+    pub root_x: XCBInputFp1616,
+    pub root_y: XCBInputFp1616,
+    pub event_x: XCBInputFp1616,
+    pub event_y: XCBInputFp1616,
+    pub buttons_len: u16,
+    pub valuators_len: u16,
+    pub sourceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+    pub flags: u32,
+    pub mods: XCBInputModifierInfo,
+    pub group: XCBInputGroupInfo,
+}
+
+pub type XCBInputTouchUpdateEvent = XCBInputTouchBeginEvent;
+
+pub type XCBInputTouchEndEvent = XCBInputTouchBeginEvent;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputTouchOwnershipEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub time: XCBTimestamp,
+    pub touchid: u32,
+    pub root: XCBWindow,
+    pub event: XCBWindow,
+    pub child: XCBWindow,
+    pub full_sequence: u32,
+    pub sourceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+    pub flags: u32,
+    pub pad1: [u8; 8],
+}
+
+#[repr(C, packed)]
+pub struct XCBInputRawTouchBeginEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub time: XCBTimestamp,
+    pub detail: u32,
+    pub sourceid: XCBInputDeviceId,
+    pub valuators_len: u16,
+    pub flags: u32,
+    pub pad0: [u8; 4],
+    pub full_sequence: u32,
+}
+
+pub type XCBInputRawTouchUpdateEvent = XCBInputRawTouchBeginEvent;
+
+pub type XCBInputRawTouchEndEvent = XCBInputRawTouchBeginEvent;
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct XCBInputBarrierHitEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub deviceid: XCBInputDeviceId,
+    pub time: XCBTimestamp,
+    pub eventid: u32,
+    pub root: XCBWindow,
+    pub event: XCBWindow,
+    pub barrier: XCBXfixesBarrier,
+    pub full_sequence: u32,
+    pub dtime: u32,
+    pub flags: u32,
+    pub sourceid: XCBInputDeviceId,
+    pub pad0: [u8; 2],
+    pub root_x: XCBInputFp1616,
+    pub root_y: XCBInputFp1616,
+    pub dx: XCBInputFp3232,
+    pub dy: XCBInputFp3232,
+}
+
+pub type XCBInputBarrierLeaveEvent = XCBInputBarrierHitEvent;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union XCBInputEventForSend {
+    pub event_device_valuator: XCBInputDeviceValuatorEvent,
+    pub event_device_key_press: XCBInputDeviceKeyPressEvent,
+    pub event_device_key_release: XCBInputDeviceKeyReleaseEvent,
+    pub event_device_button_press: XCBInputDeviceButtonPressEvent,
+    pub event_device_button_release: XCBInputDeviceButtonReleaseEvent,
+    pub event_device_motion_notify: XCBInputDeviceMotionNotifyEvent,
+    pub event_device_focus_in: XCBInputDeviceFocusInEvent,
+    pub event_device_focus_out: XCBInputDeviceFocusOutEvent,
+    pub event_proximity_in: XCBInputProximityInEvent,
+    pub event_proximity_out: XCBInputProximityOutEvent,
+    pub event_device_state_notify: XCBInputDeviceStateNotifyEvent,
+    pub event_device_mapping_notify: XCBInputDeviceMappingNotifyEvent,
+    pub event_change_device_notify: XCBInputChangeDeviceNotifyEvent,
+    pub event_device_key_state_notify: XCBInputDeviceKeyStateNotifyEvent,
+    pub event_device_button_state_notify: XCBInputDeviceButtonStateNotifyEvent,
+    pub event_device_presence_notify: XCBInputDevicePresenceNotifyEvent,
+    pub event_device_property_notify: XCBInputDevicePropertyNotifyEvent,
+    pub event_header: [u8; 32],
+}
+
+#[repr(C)]
+pub struct XCBInputEventForSendIterator {
+    pub data: *mut XCBInputEventForSend,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBInputSendExtensionEventRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub destination: XCBWindow,
+    pub device_id: u8,
+    pub propagate: u8,
+    pub num_classes: u16,
+    pub num_events: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputEventError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputModeError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputDeviceBusyError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInputClassError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[link(name = "xcb-xinput")]
+extern "system" {
+    pub static mut xcb_input_id: XCBExtension;
+    pub fn xcb_input_event_class_next(i: *mut XCBInputEventClassIterator);
+    pub fn xcb_input_event_class_end(i: XCBInputEventClassIterator) -> XCBGenericIterator;
+    pub fn xcb_input_key_code_next(i: *mut XCBInputKeyCodeIterator);
+    pub fn xcb_input_key_code_end(i: XCBInputKeyCodeIterator) -> XCBGenericIterator;
+    pub fn xcb_input_device_id_next(i: *mut XCBInputDeviceIdIterator);
+    pub fn xcb_input_device_id_end(i: XCBInputDeviceIdIterator) -> XCBGenericIterator;
+    pub fn xcb_input_fp1616_next(i: *mut XCBInputFp1616Iterator);
+    pub fn xcb_input_fp1616_end(i: XCBInputFp1616Iterator) -> XCBGenericIterator;
+    pub fn xcb_input_fp3232_next(i: *mut XCBInputFp3232Iterator);
+    pub fn xcb_input_fp3232_end(i: XCBInputFp3232Iterator) -> XCBGenericIterator;
+    pub fn xcb_input_get_extension_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputGetExtensionVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputGetExtensionVersionReply;
+    pub fn xcb_input_get_extension_version(
+        connection: *mut XCBConnection,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBInputGetExtensionVersionCookie;
+    pub fn xcb_input_get_extension_version_unchecked(
+        connection: *mut XCBConnection,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBInputGetExtensionVersionCookie;
+    pub fn xcb_input_device_info_next(i: *mut XCBInputDeviceInfoIterator);
+    pub fn xcb_input_device_info_end(i: XCBInputDeviceInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_input_key_info_next(i: *mut XCBInputKeyInfoIterator);
+    pub fn xcb_input_key_info_end(i: XCBInputKeyInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_input_button_info_next(i: *mut XCBInputButtonInfoIterator);
+    pub fn xcb_input_button_info_end(i: XCBInputButtonInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_input_axis_info_next(i: *mut XCBInputAxisInfoIterator);
+    pub fn xcb_input_axis_info_end(i: XCBInputAxisInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_input_valuator_info_next(i: *mut XCBInputValuatorInfoIterator);
+    pub fn xcb_input_valuator_info_end(i: XCBInputValuatorInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_input_valuator_info_axes(R: *const XCBInputValuatorInfo) -> *mut XCBInputAxisInfo;
+    pub fn xcb_input_valuator_info_axes_length(R: *const XCBInputValuatorInfo) -> c_int;
+    pub fn xcb_input_valuator_info_axes_iterator(
+        R: *const XCBInputValuatorInfo,
+    ) -> XCBInputAxisInfoIterator;
+    pub fn xcb_input_input_info_next(i: *mut XCBInputInputInfoIterator);
+    pub fn xcb_input_input_info_end(i: XCBInputInputInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_input_device_name_next(i: *mut XCBInputDeviceNameIterator);
+    pub fn xcb_input_device_name_end(i: XCBInputDeviceNameIterator) -> XCBGenericIterator;
+    pub fn xcb_input_device_name_string(R: *const XCBInputDeviceName) -> *mut c_char;
+    pub fn xcb_input_device_name_string_length(R: *const XCBInputDeviceName) -> c_int;
+    pub fn xcb_input_device_name_string_end(R: *const XCBInputDeviceName) -> XCBGenericIterator;
+    pub fn xcb_input_list_input_devices_devices(
+        R: *const XCBInputListInputDevicesReply,
+    ) -> *mut XCBInputDeviceInfo;
+    pub fn xcb_input_list_input_devices_devices_length(
+        R: *const XCBInputListInputDevicesReply,
+    ) -> c_int;
+    pub fn xcb_input_list_input_devices_devices_iterator(
+        R: *const XCBInputListInputDevicesReply,
+    ) -> XCBInputDeviceInfoIterator;
+    pub fn xcb_input_list_input_devices_infos_length(
+        R: *const XCBInputListInputDevicesReply,
+    ) -> c_int;
+    pub fn xcb_input_list_input_devices_infos_iterator(
+        R: *const XCBInputListInputDevicesReply,
+    ) -> XCBInputInputInfoIterator;
+    pub fn xcb_input_list_input_devices_names_length(
+        R: *const XCBInputListInputDevicesReply,
+    ) -> c_int;
+    pub fn xcb_input_list_input_devices_names_iterator(
+        R: *const XCBInputListInputDevicesReply,
+    ) -> XCBStrIterator;
+    pub fn xcb_input_list_input_devices_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputListInputDevicesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputListInputDevicesReply;
+    pub fn xcb_input_list_input_devices(
+        connection: *mut XCBConnection,
+    ) -> XCBInputListInputDevicesCookie;
+    pub fn xcb_input_list_input_devices_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBInputListInputDevicesCookie;
+    pub fn xcb_input_event_type_base_next(i: *mut XCBInputEventTypeBaseIterator);
+    pub fn xcb_input_event_type_base_end(i: XCBInputEventTypeBaseIterator) -> XCBGenericIterator;
+    pub fn xcb_input_input_class_info_next(i: *mut XCBInputInputClassInfoIterator);
+    pub fn xcb_input_input_class_info_end(i: XCBInputInputClassInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_input_open_device_class_info(
+        R: *const XCBInputOpenDeviceReply,
+    ) -> *mut XCBInputInputClassInfo;
+    pub fn xcb_input_open_device_class_info_length(R: *const XCBInputOpenDeviceReply) -> c_int;
+    pub fn xcb_input_open_device_class_info_iterator(
+        R: *const XCBInputOpenDeviceReply,
+    ) -> XCBInputInputClassInfoIterator;
+    pub fn xcb_input_open_device_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputOpenDeviceCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputOpenDeviceReply;
+    pub fn xcb_input_open_device(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputOpenDeviceCookie;
+    pub fn xcb_input_open_device_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputOpenDeviceCookie;
+    pub fn xcb_input_close_device_checked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_close_device(connection: *mut XCBConnection, device_id: u8) -> XCBVoidCookie;
+    pub fn xcb_input_set_device_mode_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputSetDeviceModeCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputSetDeviceModeReply;
+    pub fn xcb_input_set_device_mode(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        mode: u8,
+    ) -> XCBInputSetDeviceModeCookie;
+    pub fn xcb_input_set_device_mode_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        mode: u8,
+    ) -> XCBInputSetDeviceModeCookie;
+    pub fn xcb_input_select_extension_event_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        num_classes: u16,
+        classes: *const XCBInputEventClass,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_select_extension_event(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        num_classes: u16,
+        classes: *const XCBInputEventClass,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_get_selected_extension_events_this_classes(
+        R: *const XCBInputGetSelectedExtensionEventsReply,
+    ) -> *mut XCBInputEventClass;
+    pub fn xcb_input_get_selected_extension_events_this_classes_length(
+        R: *const XCBInputGetSelectedExtensionEventsReply,
+    ) -> c_int;
+    pub fn xcb_input_get_selected_extension_events_this_classes_end(
+        R: *const XCBInputGetSelectedExtensionEventsReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_get_selected_extension_events_all_classes(
+        R: *const XCBInputGetSelectedExtensionEventsReply,
+    ) -> *mut XCBInputEventClass;
+    pub fn xcb_input_get_selected_extension_events_all_classes_length(
+        R: *const XCBInputGetSelectedExtensionEventsReply,
+    ) -> c_int;
+    pub fn xcb_input_get_selected_extension_events_all_classes_end(
+        R: *const XCBInputGetSelectedExtensionEventsReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_get_selected_extension_events_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputGetSelectedExtensionEventsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputGetSelectedExtensionEventsReply;
+    pub fn xcb_input_get_selected_extension_events(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBInputGetSelectedExtensionEventsCookie;
+    pub fn xcb_input_get_selected_extension_events_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBInputGetSelectedExtensionEventsCookie;
+    pub fn xcb_input_change_device_dont_propagate_list_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        num_classes: u16,
+        mode: u8,
+        classes: *const XCBInputEventClass,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_change_device_dont_propagate_list(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        num_classes: u16,
+        mode: u8,
+        classes: *const XCBInputEventClass,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_get_device_dont_propagate_list_classes(
+        R: *const XCBInputGetDeviceDontPropagateListReply,
+    ) -> *mut XCBInputEventClass;
+    pub fn xcb_input_get_device_dont_propagate_list_classes_length(
+        R: *const XCBInputGetDeviceDontPropagateListReply,
+    ) -> c_int;
+    pub fn xcb_input_get_device_dont_propagate_list_classes_end(
+        R: *const XCBInputGetDeviceDontPropagateListReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_get_device_dont_propagate_list_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputGetDeviceDontPropagateListCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputGetDeviceDontPropagateListReply;
+    pub fn xcb_input_get_device_dont_propagate_list(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBInputGetDeviceDontPropagateListCookie;
+    pub fn xcb_input_get_device_dont_propagate_list_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBInputGetDeviceDontPropagateListCookie;
+    pub fn xcb_input_device_time_coord_next(i: *mut XCBInputDeviceTimeCoordIterator);
+    pub fn xcb_input_device_time_coord_end(
+        i: XCBInputDeviceTimeCoordIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_time_coord_axisvalues(R: *const XCBInputDeviceTimeCoord) -> *mut i32;
+    pub fn xcb_input_device_time_coord_axisvalues_length(
+        R: *const XCBInputDeviceTimeCoord,
+    ) -> c_int;
+    pub fn xcb_input_device_time_coord_axisvalues_end(
+        R: *const XCBInputDeviceTimeCoord,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_get_device_motion_events_events_length(
+        R: *const XCBInputGetDeviceMotionEventsReply,
+    ) -> c_int;
+    pub fn xcb_input_get_device_motion_events_events_iterator(
+        R: *const XCBInputGetDeviceMotionEventsReply,
+    ) -> XCBInputDeviceTimeCoordIterator;
+    pub fn xcb_input_get_device_motion_events_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputGetDeviceMotionEventsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputGetDeviceMotionEventsReply;
+    pub fn xcb_input_get_device_motion_events(
+        connection: *mut XCBConnection,
+        start: XCBTimestamp,
+        stop: XCBTimestamp,
+        device_id: u8,
+    ) -> XCBInputGetDeviceMotionEventsCookie;
+    pub fn xcb_input_get_device_motion_events_unchecked(
+        connection: *mut XCBConnection,
+        start: XCBTimestamp,
+        stop: XCBTimestamp,
+        device_id: u8,
+    ) -> XCBInputGetDeviceMotionEventsCookie;
+    pub fn xcb_input_change_keyboard_device_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputChangeKeyboardDeviceCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputChangeKeyboardDeviceReply;
+    pub fn xcb_input_change_keyboard_device(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputChangeKeyboardDeviceCookie;
+    pub fn xcb_input_change_keyboard_device_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputChangeKeyboardDeviceCookie;
+    pub fn xcb_input_change_pointer_device_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputChangePointerDeviceCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputChangePointerDeviceReply;
+    pub fn xcb_input_change_pointer_device(
+        connection: *mut XCBConnection,
+        x_axis: u8,
+        y_axis: u8,
+        device_id: u8,
+    ) -> XCBInputChangePointerDeviceCookie;
+    pub fn xcb_input_change_pointer_device_unchecked(
+        connection: *mut XCBConnection,
+        x_axis: u8,
+        y_axis: u8,
+        device_id: u8,
+    ) -> XCBInputChangePointerDeviceCookie;
+    pub fn xcb_input_grab_device_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputGrabDeviceCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputGrabDeviceReply;
+    pub fn xcb_input_grab_device(
+        connection: *mut XCBConnection,
+        grab_window: XCBWindow,
+        time: XCBTimestamp,
+        num_classes: u16,
+        this_device_mode: u8,
+        other_device_mode: u8,
+        owner_events: u8,
+        device_id: u8,
+        classes: *const XCBInputEventClass,
+    ) -> XCBInputGrabDeviceCookie;
+    pub fn xcb_input_grab_device_unchecked(
+        connection: *mut XCBConnection,
+        grab_window: XCBWindow,
+        time: XCBTimestamp,
+        num_classes: u16,
+        this_device_mode: u8,
+        other_device_mode: u8,
+        owner_events: u8,
+        device_id: u8,
+        classes: *const XCBInputEventClass,
+    ) -> XCBInputGrabDeviceCookie;
+    pub fn xcb_input_ungrab_device_checked(
+        connection: *mut XCBConnection,
+        time: XCBTimestamp,
+        device_id: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_ungrab_device(
+        connection: *mut XCBConnection,
+        time: XCBTimestamp,
+        device_id: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_grab_device_key_checked(
+        connection: *mut XCBConnection,
+        grab_window: XCBWindow,
+        num_classes: u16,
+        modifiers: u16,
+        modifier_device: u8,
+        grabbed_device: u8,
+        key: u8,
+        this_device_mode: u8,
+        other_device_mode: u8,
+        owner_events: u8,
+        classes: *const XCBInputEventClass,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_grab_device_key(
+        connection: *mut XCBConnection,
+        grab_window: XCBWindow,
+        num_classes: u16,
+        modifiers: u16,
+        modifier_device: u8,
+        grabbed_device: u8,
+        key: u8,
+        this_device_mode: u8,
+        other_device_mode: u8,
+        owner_events: u8,
+        classes: *const XCBInputEventClass,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_ungrab_device_key_checked(
+        connection: *mut XCBConnection,
+        grabWindow: XCBWindow,
+        modifiers: u16,
+        modifier_device: u8,
+        key: u8,
+        grabbed_device: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_ungrab_device_key(
+        connection: *mut XCBConnection,
+        grabWindow: XCBWindow,
+        modifiers: u16,
+        modifier_device: u8,
+        key: u8,
+        grabbed_device: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_grab_device_button_checked(
+        connection: *mut XCBConnection,
+        grab_window: XCBWindow,
+        grabbed_device: u8,
+        modifier_device: u8,
+        num_classes: u16,
+        modifiers: u16,
+        this_device_mode: u8,
+        other_device_mode: u8,
+        button: u8,
+        owner_events: u8,
+        classes: *const XCBInputEventClass,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_grab_device_button(
+        connection: *mut XCBConnection,
+        grab_window: XCBWindow,
+        grabbed_device: u8,
+        modifier_device: u8,
+        num_classes: u16,
+        modifiers: u16,
+        this_device_mode: u8,
+        other_device_mode: u8,
+        button: u8,
+        owner_events: u8,
+        classes: *const XCBInputEventClass,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_ungrab_device_button_checked(
+        connection: *mut XCBConnection,
+        grab_window: XCBWindow,
+        modifiers: u16,
+        modifier_device: u8,
+        button: u8,
+        grabbed_device: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_ungrab_device_button(
+        connection: *mut XCBConnection,
+        grab_window: XCBWindow,
+        modifiers: u16,
+        modifier_device: u8,
+        button: u8,
+        grabbed_device: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_allow_device_events_checked(
+        connection: *mut XCBConnection,
+        time: XCBTimestamp,
+        mode: u8,
+        device_id: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_allow_device_events(
+        connection: *mut XCBConnection,
+        time: XCBTimestamp,
+        mode: u8,
+        device_id: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_get_device_focus_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputGetDeviceFocusCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputGetDeviceFocusReply;
+    pub fn xcb_input_get_device_focus(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputGetDeviceFocusCookie;
+    pub fn xcb_input_get_device_focus_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputGetDeviceFocusCookie;
+    pub fn xcb_input_set_device_focus_checked(
+        connection: *mut XCBConnection,
+        focus: XCBWindow,
+        time: XCBTimestamp,
+        revert_to: u8,
+        device_id: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_set_device_focus(
+        connection: *mut XCBConnection,
+        focus: XCBWindow,
+        time: XCBTimestamp,
+        revert_to: u8,
+        device_id: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_kbd_feedback_state_next(i: *mut XCBInputKbdFeedbackStateIterator);
+    pub fn xcb_input_kbd_feedback_state_end(
+        i: XCBInputKbdFeedbackStateIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_ptr_feedback_state_next(i: *mut XCBInputPtrFeedbackStateIterator);
+    pub fn xcb_input_ptr_feedback_state_end(
+        i: XCBInputPtrFeedbackStateIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_integer_feedback_state_next(i: *mut XCBInputIntegerFeedbackStateIterator);
+    pub fn xcb_input_integer_feedback_state_end(
+        i: XCBInputIntegerFeedbackStateIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_string_feedback_state_next(i: *mut XCBInputStringFeedbackStateIterator);
+    pub fn xcb_input_string_feedback_state_end(
+        i: XCBInputStringFeedbackStateIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_string_feedback_state_keysyms(
+        R: *const XCBInputStringFeedbackState,
+    ) -> *mut XCBKeysym;
+    pub fn xcb_input_string_feedback_state_keysyms_length(
+        R: *const XCBInputStringFeedbackState,
+    ) -> c_int;
+    pub fn xcb_input_string_feedback_state_keysyms_end(
+        R: *const XCBInputStringFeedbackState,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_bell_feedback_state_next(i: *mut XCBInputBellFeedbackStateIterator);
+    pub fn xcb_input_bell_feedback_state_end(
+        i: XCBInputBellFeedbackStateIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_led_feedback_state_next(i: *mut XCBInputLedFeedbackStateIterator);
+    pub fn xcb_input_led_feedback_state_end(
+        i: XCBInputLedFeedbackStateIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_feedback_state_next(i: *mut XCBInputFeedbackStateIterator);
+    pub fn xcb_input_feedback_state_end(i: XCBInputFeedbackStateIterator) -> XCBGenericIterator;
+    pub fn xcb_input_get_feedback_control_feedbacks_length(
+        R: *const XCBInputGetFeedbackControlReply,
+    ) -> c_int;
+    pub fn xcb_input_get_feedback_control_feedbacks_iterator(
+        R: *const XCBInputGetFeedbackControlReply,
+    ) -> XCBInputFeedbackStateIterator;
+    pub fn xcb_input_get_feedback_control_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputGetFeedbackControlCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputGetFeedbackControlReply;
+    pub fn xcb_input_get_feedback_control(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputGetFeedbackControlCookie;
+    pub fn xcb_input_get_feedback_control_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputGetFeedbackControlCookie;
+    pub fn xcb_input_kbd_feedback_ctl_next(i: *mut XCBInputKbdFeedbackCtlIterator);
+    pub fn xcb_input_kbd_feedback_ctl_end(i: XCBInputKbdFeedbackCtlIterator) -> XCBGenericIterator;
+    pub fn xcb_input_ptr_feedback_ctl_next(i: *mut XCBInputPtrFeedbackCtlIterator);
+    pub fn xcb_input_ptr_feedback_ctl_end(i: XCBInputPtrFeedbackCtlIterator) -> XCBGenericIterator;
+    pub fn xcb_input_integer_feedback_ctl_next(i: *mut XCBInputIntegerFeedbackCtlIterator);
+    pub fn xcb_input_integer_feedback_ctl_end(
+        i: XCBInputIntegerFeedbackCtlIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_string_feedback_ctl_next(i: *mut XCBInputStringFeedbackCtlIterator);
+    pub fn xcb_input_string_feedback_ctl_end(
+        i: XCBInputStringFeedbackCtlIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_string_feedback_ctl_keysyms(
+        R: *const XCBInputStringFeedbackCtl,
+    ) -> *mut XCBKeysym;
+    pub fn xcb_input_string_feedback_ctl_keysyms_length(
+        R: *const XCBInputStringFeedbackCtl,
+    ) -> c_int;
+    pub fn xcb_input_string_feedback_ctl_keysyms_end(
+        R: *const XCBInputStringFeedbackCtl,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_bell_feedback_ctl_next(i: *mut XCBInputBellFeedbackCtlIterator);
+    pub fn xcb_input_bell_feedback_ctl_end(
+        i: XCBInputBellFeedbackCtlIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_led_feedback_ctl_next(i: *mut XCBInputLedFeedbackCtlIterator);
+    pub fn xcb_input_led_feedback_ctl_end(i: XCBInputLedFeedbackCtlIterator) -> XCBGenericIterator;
+    pub fn xcb_input_feedback_ctl_next(i: *mut XCBInputFeedbackCtlIterator);
+    pub fn xcb_input_feedback_ctl_end(i: XCBInputFeedbackCtlIterator) -> XCBGenericIterator;
+    pub fn xcb_input_change_feedback_control_checked(
+        connection: *mut XCBConnection,
+        mask: u32,
+        device_id: u8,
+        feedback_id: u8,
+        feedback: *const XCBInputFeedbackCtl,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_change_feedback_control(
+        connection: *mut XCBConnection,
+        mask: u32,
+        device_id: u8,
+        feedback_id: u8,
+        feedback: *const XCBInputFeedbackCtl,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_get_device_key_mapping_keysyms(
+        R: *const XCBInputGetDeviceKeyMappingReply,
+    ) -> *mut XCBKeysym;
+    pub fn xcb_input_get_device_key_mapping_keysyms_length(
+        R: *const XCBInputGetDeviceKeyMappingReply,
+    ) -> c_int;
+    pub fn xcb_input_get_device_key_mapping_keysyms_end(
+        R: *const XCBInputGetDeviceKeyMappingReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_get_device_key_mapping_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputGetDeviceKeyMappingCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputGetDeviceKeyMappingReply;
+    pub fn xcb_input_get_device_key_mapping(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        first_keycode: XCBInputKeyCode,
+        count: u8,
+    ) -> XCBInputGetDeviceKeyMappingCookie;
+    pub fn xcb_input_get_device_key_mapping_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        first_keycode: XCBInputKeyCode,
+        count: u8,
+    ) -> XCBInputGetDeviceKeyMappingCookie;
+    pub fn xcb_input_change_device_key_mapping_checked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        first_keycode: XCBInputKeyCode,
+        keysyms_per_keycode: u8,
+        keycode_count: u8,
+        keysyms: *const XCBKeysym,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_change_device_key_mapping(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        first_keycode: XCBInputKeyCode,
+        keysyms_per_keycode: u8,
+        keycode_count: u8,
+        keysyms: *const XCBKeysym,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_get_device_modifier_mapping_keymaps(
+        R: *const XCBInputGetDeviceModifierMappingReply,
+    ) -> *mut u8;
+    pub fn xcb_input_get_device_modifier_mapping_keymaps_length(
+        R: *const XCBInputGetDeviceModifierMappingReply,
+    ) -> c_int;
+    pub fn xcb_input_get_device_modifier_mapping_keymaps_end(
+        R: *const XCBInputGetDeviceModifierMappingReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_get_device_modifier_mapping_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputGetDeviceModifierMappingCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputGetDeviceModifierMappingReply;
+    pub fn xcb_input_get_device_modifier_mapping(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputGetDeviceModifierMappingCookie;
+    pub fn xcb_input_get_device_modifier_mapping_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputGetDeviceModifierMappingCookie;
+    pub fn xcb_input_set_device_modifier_mapping_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputSetDeviceModifierMappingCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputSetDeviceModifierMappingReply;
+    pub fn xcb_input_set_device_modifier_mapping(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        keycodes_per_modifier: u8,
+        keymaps: *const u8,
+    ) -> XCBInputSetDeviceModifierMappingCookie;
+    pub fn xcb_input_set_device_modifier_mapping_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        keycodes_per_modifier: u8,
+        keymaps: *const u8,
+    ) -> XCBInputSetDeviceModifierMappingCookie;
+    pub fn xcb_input_get_device_button_mapping_map(
+        R: *const XCBInputGetDeviceButtonMappingReply,
+    ) -> *mut u8;
+    pub fn xcb_input_get_device_button_mapping_map_length(
+        R: *const XCBInputGetDeviceButtonMappingReply,
+    ) -> c_int;
+    pub fn xcb_input_get_device_button_mapping_map_end(
+        R: *const XCBInputGetDeviceButtonMappingReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_get_device_button_mapping_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputGetDeviceButtonMappingCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputGetDeviceButtonMappingReply;
+    pub fn xcb_input_get_device_button_mapping(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputGetDeviceButtonMappingCookie;
+    pub fn xcb_input_get_device_button_mapping_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputGetDeviceButtonMappingCookie;
+    pub fn xcb_input_set_device_button_mapping_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputSetDeviceButtonMappingCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputSetDeviceButtonMappingReply;
+    pub fn xcb_input_set_device_button_mapping(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        map_size: u8,
+        map: *const u8,
+    ) -> XCBInputSetDeviceButtonMappingCookie;
+    pub fn xcb_input_set_device_button_mapping_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        map_size: u8,
+        map: *const u8,
+    ) -> XCBInputSetDeviceButtonMappingCookie;
+    pub fn xcb_input_key_state_next(i: *mut XCBInputKeyStateIterator);
+    pub fn xcb_input_key_state_end(i: XCBInputKeyStateIterator) -> XCBGenericIterator;
+    pub fn xcb_input_button_state_next(i: *mut XCBInputButtonStateIterator);
+    pub fn xcb_input_button_state_end(i: XCBInputButtonStateIterator) -> XCBGenericIterator;
+    pub fn xcb_input_valuator_state_next(i: *mut XCBInputValuatorStateIterator);
+    pub fn xcb_input_valuator_state_end(i: XCBInputValuatorStateIterator) -> XCBGenericIterator;
+    pub fn xcb_input_valuator_state_valuators(R: *const XCBInputValuatorState) -> *mut i32;
+    pub fn xcb_input_valuator_state_valuators_length(R: *const XCBInputValuatorState) -> c_int;
+    pub fn xcb_input_valuator_state_valuators_end(
+        R: *const XCBInputValuatorState,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_input_state_next(i: *mut XCBInputInputStateIterator);
+    pub fn xcb_input_input_state_end(i: XCBInputInputStateIterator) -> XCBGenericIterator;
+    pub fn xcb_input_query_device_state_classes_length(
+        R: *const XCBInputQueryDeviceStateReply,
+    ) -> c_int;
+    pub fn xcb_input_query_device_state_classes_iterator(
+        R: *const XCBInputQueryDeviceStateReply,
+    ) -> XCBInputInputStateIterator;
+    pub fn xcb_input_query_device_state_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputQueryDeviceStateCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputQueryDeviceStateReply;
+    pub fn xcb_input_query_device_state(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputQueryDeviceStateCookie;
+    pub fn xcb_input_query_device_state_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputQueryDeviceStateCookie;
+    pub fn xcb_input_device_bell_checked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        feedback_id: u8,
+        feedback_class: u8,
+        percent: i8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_device_bell(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        feedback_id: u8,
+        feedback_class: u8,
+        percent: i8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_set_device_valuators_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputSetDeviceValuatorsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputSetDeviceValuatorsReply;
+    pub fn xcb_input_set_device_valuators(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        first_valuator: u8,
+        num_valuators: u8,
+        valuators: *const i32,
+    ) -> XCBInputSetDeviceValuatorsCookie;
+    pub fn xcb_input_set_device_valuators_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+        first_valuator: u8,
+        num_valuators: u8,
+        valuators: *const i32,
+    ) -> XCBInputSetDeviceValuatorsCookie;
+    pub fn xcb_input_device_resolution_state_next(i: *mut XCBInputDeviceResolutionStateIterator);
+    pub fn xcb_input_device_resolution_state_end(
+        i: XCBInputDeviceResolutionStateIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_resolution_state_resolution_values(
+        R: *const XCBInputDeviceResolutionState,
+    ) -> *mut u32;
+    pub fn xcb_input_device_resolution_state_resolution_values_length(
+        R: *const XCBInputDeviceResolutionState,
+    ) -> c_int;
+    pub fn xcb_input_device_resolution_state_resolution_values_end(
+        R: *const XCBInputDeviceResolutionState,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_resolution_state_resolution_min(
+        R: *const XCBInputDeviceResolutionState,
+    ) -> *mut u32;
+    pub fn xcb_input_device_resolution_state_resolution_min_length(
+        R: *const XCBInputDeviceResolutionState,
+    ) -> c_int;
+    pub fn xcb_input_device_resolution_state_resolution_min_end(
+        R: *const XCBInputDeviceResolutionState,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_resolution_state_resolution_max(
+        R: *const XCBInputDeviceResolutionState,
+    ) -> *mut u32;
+    pub fn xcb_input_device_resolution_state_resolution_max_length(
+        R: *const XCBInputDeviceResolutionState,
+    ) -> c_int;
+    pub fn xcb_input_device_resolution_state_resolution_max_end(
+        R: *const XCBInputDeviceResolutionState,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_abs_calib_state_next(i: *mut XCBInputDeviceAbsCalibStateIterator);
+    pub fn xcb_input_device_abs_calib_state_end(
+        i: XCBInputDeviceAbsCalibStateIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_abs_area_state_next(i: *mut XCBInputDeviceAbsAreaStateIterator);
+    pub fn xcb_input_device_abs_area_state_end(
+        i: XCBInputDeviceAbsAreaStateIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_core_state_next(i: *mut XCBInputDeviceCoreStateIterator);
+    pub fn xcb_input_device_core_state_end(
+        i: XCBInputDeviceCoreStateIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_enable_state_next(i: *mut XCBInputDeviceEnableStateIterator);
+    pub fn xcb_input_device_enable_state_end(
+        i: XCBInputDeviceEnableStateIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_state_next(i: *mut XCBInputDeviceStateIterator);
+    pub fn xcb_input_device_state_end(i: XCBInputDeviceStateIterator) -> XCBGenericIterator;
+    pub fn xcb_input_get_device_control_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputGetDeviceControlCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputGetDeviceControlReply;
+    pub fn xcb_input_get_device_control(
+        connection: *mut XCBConnection,
+        control_id: u16,
+        device_id: u8,
+    ) -> XCBInputGetDeviceControlCookie;
+    pub fn xcb_input_get_device_control_unchecked(
+        connection: *mut XCBConnection,
+        control_id: u16,
+        device_id: u8,
+    ) -> XCBInputGetDeviceControlCookie;
+    pub fn xcb_input_device_resolution_ctl_next(i: *mut XCBInputDeviceResolutionCtlIterator);
+    pub fn xcb_input_device_resolution_ctl_end(
+        i: XCBInputDeviceResolutionCtlIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_resolution_ctl_resolution_values(
+        R: *const XCBInputDeviceResolutionCtl,
+    ) -> *mut u32;
+    pub fn xcb_input_device_resolution_ctl_resolution_values_length(
+        R: *const XCBInputDeviceResolutionCtl,
+    ) -> c_int;
+    pub fn xcb_input_device_resolution_ctl_resolution_values_end(
+        R: *const XCBInputDeviceResolutionCtl,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_abs_calib_ctl_next(i: *mut XCBInputDeviceAbsCalibCtlIterator);
+    pub fn xcb_input_device_abs_calib_ctl_end(
+        i: XCBInputDeviceAbsCalibCtlIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_abs_area_ctrl_next(i: *mut XCBInputDeviceAbsAreaCtrlIterator);
+    pub fn xcb_input_device_abs_area_ctrl_end(
+        i: XCBInputDeviceAbsAreaCtrlIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_core_ctrl_next(i: *mut XCBInputDeviceCoreCtrlIterator);
+    pub fn xcb_input_device_core_ctrl_end(i: XCBInputDeviceCoreCtrlIterator) -> XCBGenericIterator;
+    pub fn xcb_input_device_enable_ctrl_next(i: *mut XCBInputDeviceEnableCtrlIterator);
+    pub fn xcb_input_device_enable_ctrl_end(
+        i: XCBInputDeviceEnableCtrlIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_device_ctl_next(i: *mut XCBInputDeviceCtlIterator);
+    pub fn xcb_input_device_ctl_end(i: XCBInputDeviceCtlIterator) -> XCBGenericIterator;
+    pub fn xcb_input_change_device_control_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputChangeDeviceControlCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputChangeDeviceControlReply;
+    pub fn xcb_input_change_device_control(
+        connection: *mut XCBConnection,
+        control_id: u16,
+        device_id: u8,
+        control: *const XCBInputDeviceCtl,
+    ) -> XCBInputChangeDeviceControlCookie;
+    pub fn xcb_input_change_device_control_unchecked(
+        connection: *mut XCBConnection,
+        control_id: u16,
+        device_id: u8,
+        control: *const XCBInputDeviceCtl,
+    ) -> XCBInputChangeDeviceControlCookie;
+    pub fn xcb_input_list_device_properties_atoms(
+        R: *const XCBInputListDevicePropertiesReply,
+    ) -> *mut XCBAtom;
+    pub fn xcb_input_list_device_properties_atoms_length(
+        R: *const XCBInputListDevicePropertiesReply,
+    ) -> c_int;
+    pub fn xcb_input_list_device_properties_atoms_end(
+        R: *const XCBInputListDevicePropertiesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_list_device_properties_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputListDevicePropertiesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputListDevicePropertiesReply;
+    pub fn xcb_input_list_device_properties(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputListDevicePropertiesCookie;
+    pub fn xcb_input_list_device_properties_unchecked(
+        connection: *mut XCBConnection,
+        device_id: u8,
+    ) -> XCBInputListDevicePropertiesCookie;
+    pub fn xcb_input_change_device_property_checked(
+        connection: *mut XCBConnection,
+        property: XCBAtom,
+        type_: XCBAtom,
+        device_id: u8,
+        format: u8,
+        mode: u8,
+        num_items: u32,
+        items: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_change_device_property(
+        connection: *mut XCBConnection,
+        property: XCBAtom,
+        type_: XCBAtom,
+        device_id: u8,
+        format: u8,
+        mode: u8,
+        num_items: u32,
+        items: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_delete_device_property_checked(
+        connection: *mut XCBConnection,
+        property: XCBAtom,
+        device_id: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_delete_device_property(
+        connection: *mut XCBConnection,
+        property: XCBAtom,
+        device_id: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_get_device_property_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputGetDevicePropertyCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputGetDevicePropertyReply;
+    pub fn xcb_input_get_device_property(
+        connection: *mut XCBConnection,
+        property: XCBAtom,
+        type_: XCBAtom,
+        offset: u32,
+        len: u32,
+        device_id: u8,
+        delete: u8,
+    ) -> XCBInputGetDevicePropertyCookie;
+    pub fn xcb_input_get_device_property_unchecked(
+        connection: *mut XCBConnection,
+        property: XCBAtom,
+        type_: XCBAtom,
+        offset: u32,
+        len: u32,
+        device_id: u8,
+        delete: u8,
+    ) -> XCBInputGetDevicePropertyCookie;
+    pub fn xcb_input_group_info_next(i: *mut XCBInputGroupInfoIterator);
+    pub fn xcb_input_group_info_end(i: XCBInputGroupInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_input_modifier_info_next(i: *mut XCBInputModifierInfoIterator);
+    pub fn xcb_input_modifier_info_end(i: XCBInputModifierInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_input_xi_query_pointer_buttons(R: *const XCBInputXiQueryPointerReply) -> *mut u32;
+    pub fn xcb_input_xi_query_pointer_buttons_length(
+        R: *const XCBInputXiQueryPointerReply,
+    ) -> c_int;
+    pub fn xcb_input_xi_query_pointer_buttons_end(
+        R: *const XCBInputXiQueryPointerReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_xi_query_pointer_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputXiQueryPointerCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputXiQueryPointerReply;
+    pub fn xcb_input_xi_query_pointer(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBInputXiQueryPointerCookie;
+    pub fn xcb_input_xi_query_pointer_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBInputXiQueryPointerCookie;
+    pub fn xcb_input_xi_warp_pointer_checked(
+        connection: *mut XCBConnection,
+        src_win: XCBWindow,
+        dst_win: XCBWindow,
+        src_x: XCBInputFp1616,
+        src_y: XCBInputFp1616,
+        src_width: u16,
+        src_height: u16,
+        dst_x: XCBInputFp1616,
+        dst_y: XCBInputFp1616,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_warp_pointer(
+        connection: *mut XCBConnection,
+        src_win: XCBWindow,
+        dst_win: XCBWindow,
+        src_x: XCBInputFp1616,
+        src_y: XCBInputFp1616,
+        src_width: u16,
+        src_height: u16,
+        dst_x: XCBInputFp1616,
+        dst_y: XCBInputFp1616,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_change_cursor_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        cursor: XCBCursor,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_change_cursor(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        cursor: XCBCursor,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_add_master_next(i: *mut XCBInputAddMasterIterator);
+    pub fn xcb_input_add_master_end(i: XCBInputAddMasterIterator) -> XCBGenericIterator;
+    pub fn xcb_input_add_master_name(R: *const XCBInputAddMaster) -> *mut c_char;
+    pub fn xcb_input_add_master_name_length(R: *const XCBInputAddMaster) -> c_int;
+    pub fn xcb_input_add_master_name_end(R: *const XCBInputAddMaster) -> XCBGenericIterator;
+    pub fn xcb_input_remove_master_next(i: *mut XCBInputRemoveMasterIterator);
+    pub fn xcb_input_remove_master_end(i: XCBInputRemoveMasterIterator) -> XCBGenericIterator;
+    pub fn xcb_input_attach_slave_next(i: *mut XCBInputAttachSlaveIterator);
+    pub fn xcb_input_attach_slave_end(i: XCBInputAttachSlaveIterator) -> XCBGenericIterator;
+    pub fn xcb_input_detach_slave_next(i: *mut XCBInputDetachSlaveIterator);
+    pub fn xcb_input_detach_slave_end(i: XCBInputDetachSlaveIterator) -> XCBGenericIterator;
+    pub fn xcb_input_hierarchy_change_next(i: *mut XCBInputHierarchyChangeIterator);
+    pub fn xcb_input_hierarchy_change_end(i: XCBInputHierarchyChangeIterator)
+        -> XCBGenericIterator;
+    pub fn xcb_input_xi_change_hierarchy_checked(
+        connection: *mut XCBConnection,
+        num_changes: u8,
+        changes: *const XCBInputHierarchyChange,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_change_hierarchy(
+        connection: *mut XCBConnection,
+        num_changes: u8,
+        changes: *const XCBInputHierarchyChange,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_set_client_pointer_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_set_client_pointer(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_get_client_pointer_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputXiGetClientPointerCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputXiGetClientPointerReply;
+    pub fn xcb_input_xi_get_client_pointer(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBInputXiGetClientPointerCookie;
+    pub fn xcb_input_xi_get_client_pointer_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBInputXiGetClientPointerCookie;
+    pub fn xcb_input_event_mask_next(i: *mut XCBInputEventMaskIterator);
+    pub fn xcb_input_event_mask_end(i: XCBInputEventMaskIterator) -> XCBGenericIterator;
+    pub fn xcb_input_event_mask_mask(R: *const XCBInputEventMask) -> *mut u32;
+    pub fn xcb_input_event_mask_mask_length(R: *const XCBInputEventMask) -> c_int;
+    pub fn xcb_input_event_mask_mask_end(R: *const XCBInputEventMask) -> XCBGenericIterator;
+    pub fn xcb_input_xi_select_events_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        num_mask: u16,
+        masks: *const XCBInputEventMask,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_select_events(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        num_mask: u16,
+        masks: *const XCBInputEventMask,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputXiQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputXiQueryVersionReply;
+    pub fn xcb_input_xi_query_version(
+        connection: *mut XCBConnection,
+        major_version: u16,
+        minor_version: u16,
+    ) -> XCBInputXiQueryVersionCookie;
+    pub fn xcb_input_xi_query_version_unchecked(
+        connection: *mut XCBConnection,
+        major_version: u16,
+        minor_version: u16,
+    ) -> XCBInputXiQueryVersionCookie;
+    pub fn xcb_input_button_class_next(i: *mut XCBInputButtonClassIterator);
+    pub fn xcb_input_button_class_end(i: XCBInputButtonClassIterator) -> XCBGenericIterator;
+    pub fn xcb_input_button_class_state(R: *const XCBInputButtonClass) -> *mut u32;
+    pub fn xcb_input_button_class_state_length(R: *const XCBInputButtonClass) -> c_int;
+    pub fn xcb_input_button_class_state_end(R: *const XCBInputButtonClass) -> XCBGenericIterator;
+    pub fn xcb_input_button_class_labels(R: *const XCBInputButtonClass) -> *mut XCBAtom;
+    pub fn xcb_input_button_class_labels_length(R: *const XCBInputButtonClass) -> c_int;
+    pub fn xcb_input_button_class_labels_end(R: *const XCBInputButtonClass) -> XCBGenericIterator;
+    pub fn xcb_input_key_class_next(i: *mut XCBInputKeyClassIterator);
+    pub fn xcb_input_key_class_end(i: XCBInputKeyClassIterator) -> XCBGenericIterator;
+    pub fn xcb_input_key_class_keys(R: *const XCBInputKeyClass) -> *mut u32;
+    pub fn xcb_input_key_class_keys_length(R: *const XCBInputKeyClass) -> c_int;
+    pub fn xcb_input_key_class_keys_end(R: *const XCBInputKeyClass) -> XCBGenericIterator;
+    pub fn xcb_input_scroll_class_next(i: *mut XCBInputScrollClassIterator);
+    pub fn xcb_input_scroll_class_end(i: XCBInputScrollClassIterator) -> XCBGenericIterator;
+    pub fn xcb_input_touch_class_next(i: *mut XCBInputTouchClassIterator);
+    pub fn xcb_input_touch_class_end(i: XCBInputTouchClassIterator) -> XCBGenericIterator;
+    pub fn xcb_input_valuator_class_next(i: *mut XCBInputValuatorClassIterator);
+    pub fn xcb_input_valuator_class_end(i: XCBInputValuatorClassIterator) -> XCBGenericIterator;
+    pub fn xcb_input_device_class_next(i: *mut XCBInputDeviceClassIterator);
+    pub fn xcb_input_device_class_end(i: XCBInputDeviceClassIterator) -> XCBGenericIterator;
+    pub fn xcb_input_xi_device_info_next(i: *mut XCBInputXiDeviceInfoIterator);
+    pub fn xcb_input_xi_device_info_end(i: XCBInputXiDeviceInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_input_xi_device_info_name(R: *const XCBInputXiDeviceInfo) -> *mut c_char;
+    pub fn xcb_input_xi_device_info_name_length(R: *const XCBInputXiDeviceInfo) -> c_int;
+    pub fn xcb_input_xi_device_info_name_end(R: *const XCBInputXiDeviceInfo) -> XCBGenericIterator;
+    pub fn xcb_input_xi_device_info_classes_length(R: *const XCBInputXiDeviceInfo) -> c_int;
+    pub fn xcb_input_xi_device_info_classes_iterator(
+        R: *const XCBInputXiDeviceInfo,
+    ) -> XCBInputDeviceClassIterator;
+    pub fn xcb_input_xi_query_device_infos_length(R: *const XCBInputXiQueryDeviceReply) -> c_int;
+    pub fn xcb_input_xi_query_device_infos_iterator(
+        R: *const XCBInputXiQueryDeviceReply,
+    ) -> XCBInputXiDeviceInfoIterator;
+    pub fn xcb_input_xi_query_device_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputXiQueryDeviceCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputXiQueryDeviceReply;
+    pub fn xcb_input_xi_query_device(
+        connection: *mut XCBConnection,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBInputXiQueryDeviceCookie;
+    pub fn xcb_input_xi_query_device_unchecked(
+        connection: *mut XCBConnection,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBInputXiQueryDeviceCookie;
+    pub fn xcb_input_xi_set_focus_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        time: XCBTimestamp,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_set_focus(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        time: XCBTimestamp,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_get_focus_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputXiGetFocusCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputXiGetFocusReply;
+    pub fn xcb_input_xi_get_focus(
+        connection: *mut XCBConnection,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBInputXiGetFocusCookie;
+    pub fn xcb_input_xi_get_focus_unchecked(
+        connection: *mut XCBConnection,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBInputXiGetFocusCookie;
+    pub fn xcb_input_xi_grab_device_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputXiGrabDeviceCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputXiGrabDeviceReply;
+    pub fn xcb_input_xi_grab_device(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        time: XCBTimestamp,
+        cursor: XCBCursor,
+        deviceid: XCBInputDeviceId,
+        mode: u8,
+        paired_device_mode: u8,
+        owner_events: u8,
+        mask_len: u16,
+        mask: *const u32,
+    ) -> XCBInputXiGrabDeviceCookie;
+    pub fn xcb_input_xi_grab_device_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        time: XCBTimestamp,
+        cursor: XCBCursor,
+        deviceid: XCBInputDeviceId,
+        mode: u8,
+        paired_device_mode: u8,
+        owner_events: u8,
+        mask_len: u16,
+        mask: *const u32,
+    ) -> XCBInputXiGrabDeviceCookie;
+    pub fn xcb_input_xi_ungrab_device_checked(
+        connection: *mut XCBConnection,
+        time: XCBTimestamp,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_ungrab_device(
+        connection: *mut XCBConnection,
+        time: XCBTimestamp,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_allow_events_checked(
+        connection: *mut XCBConnection,
+        time: XCBTimestamp,
+        deviceid: XCBInputDeviceId,
+        event_mode: u8,
+        touchid: u32,
+        grab_window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_allow_events(
+        connection: *mut XCBConnection,
+        time: XCBTimestamp,
+        deviceid: XCBInputDeviceId,
+        event_mode: u8,
+        touchid: u32,
+        grab_window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_grab_modifier_info_next(i: *mut XCBInputGrabModifierInfoIterator);
+    pub fn xcb_input_grab_modifier_info_end(
+        i: XCBInputGrabModifierInfoIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_xi_passive_grab_device_modifiers(
+        R: *const XCBInputXiPassiveGrabDeviceReply,
+    ) -> *mut XCBInputGrabModifierInfo;
+    pub fn xcb_input_xi_passive_grab_device_modifiers_length(
+        R: *const XCBInputXiPassiveGrabDeviceReply,
+    ) -> c_int;
+    pub fn xcb_input_xi_passive_grab_device_modifiers_iterator(
+        R: *const XCBInputXiPassiveGrabDeviceReply,
+    ) -> XCBInputGrabModifierInfoIterator;
+    pub fn xcb_input_xi_passive_grab_device_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputXiPassiveGrabDeviceCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputXiPassiveGrabDeviceReply;
+    pub fn xcb_input_xi_passive_grab_device(
+        connection: *mut XCBConnection,
+        time: XCBTimestamp,
+        grab_window: XCBWindow,
+        cursor: XCBCursor,
+        detail: u32,
+        deviceid: XCBInputDeviceId,
+        num_modifiers: u16,
+        mask_len: u16,
+        grab_type: u8,
+        grab_mode: u8,
+        paired_device_mode: u8,
+        owner_events: u8,
+        mask: *const u32,
+        modifiers: *const u32,
+    ) -> XCBInputXiPassiveGrabDeviceCookie;
+    pub fn xcb_input_xi_passive_grab_device_unchecked(
+        connection: *mut XCBConnection,
+        time: XCBTimestamp,
+        grab_window: XCBWindow,
+        cursor: XCBCursor,
+        detail: u32,
+        deviceid: XCBInputDeviceId,
+        num_modifiers: u16,
+        mask_len: u16,
+        grab_type: u8,
+        grab_mode: u8,
+        paired_device_mode: u8,
+        owner_events: u8,
+        mask: *const u32,
+        modifiers: *const u32,
+    ) -> XCBInputXiPassiveGrabDeviceCookie;
+    pub fn xcb_input_xi_passive_ungrab_device_checked(
+        connection: *mut XCBConnection,
+        grab_window: XCBWindow,
+        detail: u32,
+        deviceid: XCBInputDeviceId,
+        num_modifiers: u16,
+        grab_type: u8,
+        modifiers: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_passive_ungrab_device(
+        connection: *mut XCBConnection,
+        grab_window: XCBWindow,
+        detail: u32,
+        deviceid: XCBInputDeviceId,
+        num_modifiers: u16,
+        grab_type: u8,
+        modifiers: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_list_properties_properties(
+        R: *const XCBInputXiListPropertiesReply,
+    ) -> *mut XCBAtom;
+    pub fn xcb_input_xi_list_properties_properties_length(
+        R: *const XCBInputXiListPropertiesReply,
+    ) -> c_int;
+    pub fn xcb_input_xi_list_properties_properties_end(
+        R: *const XCBInputXiListPropertiesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_xi_list_properties_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputXiListPropertiesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputXiListPropertiesReply;
+    pub fn xcb_input_xi_list_properties(
+        connection: *mut XCBConnection,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBInputXiListPropertiesCookie;
+    pub fn xcb_input_xi_list_properties_unchecked(
+        connection: *mut XCBConnection,
+        deviceid: XCBInputDeviceId,
+    ) -> XCBInputXiListPropertiesCookie;
+    pub fn xcb_input_xi_change_property_checked(
+        connection: *mut XCBConnection,
+        deviceid: XCBInputDeviceId,
+        mode: u8,
+        format: u8,
+        property: XCBAtom,
+        type_: XCBAtom,
+        num_items: u32,
+        items: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_change_property(
+        connection: *mut XCBConnection,
+        deviceid: XCBInputDeviceId,
+        mode: u8,
+        format: u8,
+        property: XCBAtom,
+        type_: XCBAtom,
+        num_items: u32,
+        items: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_delete_property_checked(
+        connection: *mut XCBConnection,
+        deviceid: XCBInputDeviceId,
+        property: XCBAtom,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_delete_property(
+        connection: *mut XCBConnection,
+        deviceid: XCBInputDeviceId,
+        property: XCBAtom,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_get_property_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputXiGetPropertyCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputXiGetPropertyReply;
+    pub fn xcb_input_xi_get_property(
+        connection: *mut XCBConnection,
+        deviceid: XCBInputDeviceId,
+        delete: u8,
+        property: XCBAtom,
+        type_: XCBAtom,
+        offset: u32,
+        len: u32,
+    ) -> XCBInputXiGetPropertyCookie;
+    pub fn xcb_input_xi_get_property_unchecked(
+        connection: *mut XCBConnection,
+        deviceid: XCBInputDeviceId,
+        delete: u8,
+        property: XCBAtom,
+        type_: XCBAtom,
+        offset: u32,
+        len: u32,
+    ) -> XCBInputXiGetPropertyCookie;
+    pub fn xcb_input_xi_get_selected_events_masks_length(
+        R: *const XCBInputXiGetSelectedEventsReply,
+    ) -> c_int;
+    pub fn xcb_input_xi_get_selected_events_masks_iterator(
+        R: *const XCBInputXiGetSelectedEventsReply,
+    ) -> XCBInputEventMaskIterator;
+    pub fn xcb_input_xi_get_selected_events_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInputXiGetSelectedEventsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInputXiGetSelectedEventsReply;
+    pub fn xcb_input_xi_get_selected_events(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBInputXiGetSelectedEventsCookie;
+    pub fn xcb_input_xi_get_selected_events_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBInputXiGetSelectedEventsCookie;
+    pub fn xcb_input_barrier_release_pointer_info_next(
+        i: *mut XCBInputBarrierReleasePointerInfoIterator,
+    );
+    pub fn xcb_input_barrier_release_pointer_info_end(
+        i: XCBInputBarrierReleasePointerInfoIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_xi_barrier_release_pointer_checked(
+        connection: *mut XCBConnection,
+        num_barriers: u32,
+        barriers: *const XCBInputBarrierReleasePointerInfo,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_xi_barrier_release_pointer(
+        connection: *mut XCBConnection,
+        num_barriers: u32,
+        barriers: *const XCBInputBarrierReleasePointerInfo,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_device_changed_classes_length(R: *const XCBInputDeviceChangedEvent) -> c_int;
+    pub fn xcb_input_device_changed_classes_iterator(
+        R: *const XCBInputDeviceChangedEvent,
+    ) -> XCBInputDeviceClassIterator;
+    pub fn xcb_input_key_press_button_mask(R: *const XCBInputKeyPressEvent) -> *mut u32;
+    pub fn xcb_input_key_press_button_mask_length(R: *const XCBInputKeyPressEvent) -> c_int;
+    pub fn xcb_input_key_press_button_mask_end(
+        R: *const XCBInputKeyPressEvent,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_key_press_valuator_mask(R: *const XCBInputKeyPressEvent) -> *mut u32;
+    pub fn xcb_input_key_press_valuator_mask_length(R: *const XCBInputKeyPressEvent) -> c_int;
+    pub fn xcb_input_key_press_valuator_mask_end(
+        R: *const XCBInputKeyPressEvent,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_key_press_axisvalues(R: *const XCBInputKeyPressEvent) -> *mut XCBInputFp3232;
+    pub fn xcb_input_key_press_axisvalues_length(R: *const XCBInputKeyPressEvent) -> c_int;
+    pub fn xcb_input_key_press_axisvalues_iterator(
+        R: *const XCBInputKeyPressEvent,
+    ) -> XCBInputFp3232Iterator;
+    pub fn xcb_input_button_press_button_mask(R: *const XCBInputButtonPressEvent) -> *mut u32;
+    pub fn xcb_input_button_press_button_mask_length(R: *const XCBInputButtonPressEvent) -> c_int;
+    pub fn xcb_input_button_press_button_mask_end(
+        R: *const XCBInputButtonPressEvent,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_button_press_valuator_mask(R: *const XCBInputButtonPressEvent) -> *mut u32;
+    pub fn xcb_input_button_press_valuator_mask_length(R: *const XCBInputButtonPressEvent)
+        -> c_int;
+    pub fn xcb_input_button_press_valuator_mask_end(
+        R: *const XCBInputButtonPressEvent,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_button_press_axisvalues(
+        R: *const XCBInputButtonPressEvent,
+    ) -> *mut XCBInputFp3232;
+    pub fn xcb_input_button_press_axisvalues_length(R: *const XCBInputButtonPressEvent) -> c_int;
+    pub fn xcb_input_button_press_axisvalues_iterator(
+        R: *const XCBInputButtonPressEvent,
+    ) -> XCBInputFp3232Iterator;
+    pub fn xcb_input_enter_buttons(R: *const XCBInputEnterEvent) -> *mut u32;
+    pub fn xcb_input_enter_buttons_length(R: *const XCBInputEnterEvent) -> c_int;
+    pub fn xcb_input_enter_buttons_end(R: *const XCBInputEnterEvent) -> XCBGenericIterator;
+    pub fn xcb_input_hierarchy_info_next(i: *mut XCBInputHierarchyInfoIterator);
+    pub fn xcb_input_hierarchy_info_end(i: XCBInputHierarchyInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_input_hierarchy_infos(
+        R: *const XCBInputHierarchyEvent,
+    ) -> *mut XCBInputHierarchyInfo;
+    pub fn xcb_input_hierarchy_infos_length(R: *const XCBInputHierarchyEvent) -> c_int;
+    pub fn xcb_input_hierarchy_infos_iterator(
+        R: *const XCBInputHierarchyEvent,
+    ) -> XCBInputHierarchyInfoIterator;
+    pub fn xcb_input_raw_key_press_valuator_mask(R: *const XCBInputRawKeyPressEvent) -> *mut u32;
+    pub fn xcb_input_raw_key_press_valuator_mask_length(
+        R: *const XCBInputRawKeyPressEvent,
+    ) -> c_int;
+    pub fn xcb_input_raw_key_press_valuator_mask_end(
+        R: *const XCBInputRawKeyPressEvent,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_raw_key_press_axisvalues(
+        R: *const XCBInputRawKeyPressEvent,
+    ) -> *mut XCBInputFp3232;
+    pub fn xcb_input_raw_key_press_axisvalues_length(R: *const XCBInputRawKeyPressEvent) -> c_int;
+    pub fn xcb_input_raw_key_press_axisvalues_iterator(
+        R: *const XCBInputRawKeyPressEvent,
+    ) -> XCBInputFp3232Iterator;
+    pub fn xcb_input_raw_key_press_axisvalues_raw(
+        R: *const XCBInputRawKeyPressEvent,
+    ) -> *mut XCBInputFp3232;
+    pub fn xcb_input_raw_key_press_axisvalues_raw_length(
+        R: *const XCBInputRawKeyPressEvent,
+    ) -> c_int;
+    pub fn xcb_input_raw_key_press_axisvalues_raw_iterator(
+        R: *const XCBInputRawKeyPressEvent,
+    ) -> XCBInputFp3232Iterator;
+    pub fn xcb_input_raw_button_press_valuator_mask(
+        R: *const XCBInputRawButtonPressEvent,
+    ) -> *mut u32;
+    pub fn xcb_input_raw_button_press_valuator_mask_length(
+        R: *const XCBInputRawButtonPressEvent,
+    ) -> c_int;
+    pub fn xcb_input_raw_button_press_valuator_mask_end(
+        R: *const XCBInputRawButtonPressEvent,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_raw_button_press_axisvalues(
+        R: *const XCBInputRawButtonPressEvent,
+    ) -> *mut XCBInputFp3232;
+    pub fn xcb_input_raw_button_press_axisvalues_length(
+        R: *const XCBInputRawButtonPressEvent,
+    ) -> c_int;
+    pub fn xcb_input_raw_button_press_axisvalues_iterator(
+        R: *const XCBInputRawButtonPressEvent,
+    ) -> XCBInputFp3232Iterator;
+    pub fn xcb_input_raw_button_press_axisvalues_raw(
+        R: *const XCBInputRawButtonPressEvent,
+    ) -> *mut XCBInputFp3232;
+    pub fn xcb_input_raw_button_press_axisvalues_raw_length(
+        R: *const XCBInputRawButtonPressEvent,
+    ) -> c_int;
+    pub fn xcb_input_raw_button_press_axisvalues_raw_iterator(
+        R: *const XCBInputRawButtonPressEvent,
+    ) -> XCBInputFp3232Iterator;
+    pub fn xcb_input_touch_begin_button_mask(R: *const XCBInputTouchBeginEvent) -> *mut u32;
+    pub fn xcb_input_touch_begin_button_mask_length(R: *const XCBInputTouchBeginEvent) -> c_int;
+    pub fn xcb_input_touch_begin_button_mask_end(
+        R: *const XCBInputTouchBeginEvent,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_touch_begin_valuator_mask(R: *const XCBInputTouchBeginEvent) -> *mut u32;
+    pub fn xcb_input_touch_begin_valuator_mask_length(R: *const XCBInputTouchBeginEvent) -> c_int;
+    pub fn xcb_input_touch_begin_valuator_mask_end(
+        R: *const XCBInputTouchBeginEvent,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_touch_begin_axisvalues(
+        R: *const XCBInputTouchBeginEvent,
+    ) -> *mut XCBInputFp3232;
+    pub fn xcb_input_touch_begin_axisvalues_length(R: *const XCBInputTouchBeginEvent) -> c_int;
+    pub fn xcb_input_touch_begin_axisvalues_iterator(
+        R: *const XCBInputTouchBeginEvent,
+    ) -> XCBInputFp3232Iterator;
+    pub fn xcb_input_raw_touch_begin_valuator_mask(
+        R: *const XCBInputRawTouchBeginEvent,
+    ) -> *mut u32;
+    pub fn xcb_input_raw_touch_begin_valuator_mask_length(
+        R: *const XCBInputRawTouchBeginEvent,
+    ) -> c_int;
+    pub fn xcb_input_raw_touch_begin_valuator_mask_end(
+        R: *const XCBInputRawTouchBeginEvent,
+    ) -> XCBGenericIterator;
+    pub fn xcb_input_raw_touch_begin_axisvalues(
+        R: *const XCBInputRawTouchBeginEvent,
+    ) -> *mut XCBInputFp3232;
+    pub fn xcb_input_raw_touch_begin_axisvalues_length(
+        R: *const XCBInputRawTouchBeginEvent,
+    ) -> c_int;
+    pub fn xcb_input_raw_touch_begin_axisvalues_iterator(
+        R: *const XCBInputRawTouchBeginEvent,
+    ) -> XCBInputFp3232Iterator;
+    pub fn xcb_input_raw_touch_begin_axisvalues_raw(
+        R: *const XCBInputRawTouchBeginEvent,
+    ) -> *mut XCBInputFp3232;
+    pub fn xcb_input_raw_touch_begin_axisvalues_raw_length(
+        R: *const XCBInputRawTouchBeginEvent,
+    ) -> c_int;
+    pub fn xcb_input_raw_touch_begin_axisvalues_raw_iterator(
+        R: *const XCBInputRawTouchBeginEvent,
+    ) -> XCBInputFp3232Iterator;
+    pub fn xcb_input_event_for_send_next(i: *mut XCBInputEventForSendIterator);
+    pub fn xcb_input_event_for_send_end(i: XCBInputEventForSendIterator) -> XCBGenericIterator;
+    pub fn xcb_input_send_extension_event_checked(
+        connection: *mut XCBConnection,
+        destination: XCBWindow,
+        device_id: u8,
+        propagate: u8,
+        num_classes: u16,
+        num_events: u8,
+        events: *const XCBInputEventForSend,
+        classes: *const XCBInputEventClass,
+    ) -> XCBVoidCookie;
+    pub fn xcb_input_send_extension_event(
+        connection: *mut XCBConnection,
+        destination: XCBWindow,
+        device_id: u8,
+        propagate: u8,
+        num_classes: u16,
+        num_events: u8,
+        events: *const XCBInputEventForSend,
+        classes: *const XCBInputEventClass,
+    ) -> XCBVoidCookie;
+}
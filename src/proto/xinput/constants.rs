@@ -0,0 +1,282 @@
+// Generated by xcbgen from xml/xinput.xml, do not edit.
+
+pub const XCB_INPUT_MAJOR_VERSION: u32 = 2;
+pub const XCB_INPUT_MINOR_VERSION: u32 = 3;
+pub const XCB_INPUT_GET_EXTENSION_VERSION: u8 = 1;
+pub const XCB_INPUT_DEVICE_USE_IS_X_POINTER: u8 = 0;
+pub const XCB_INPUT_DEVICE_USE_IS_X_KEYBOARD: u8 = 1;
+pub const XCB_INPUT_DEVICE_USE_IS_X_EXTENSION_DEVICE: u8 = 2;
+pub const XCB_INPUT_DEVICE_USE_IS_X_EXTENSION_KEYBOARD: u8 = 3;
+pub const XCB_INPUT_DEVICE_USE_IS_X_EXTENSION_POINTER: u8 = 4;
+pub const XCB_INPUT_INPUT_CLASS_KEY: u8 = 0;
+pub const XCB_INPUT_INPUT_CLASS_BUTTON: u8 = 1;
+pub const XCB_INPUT_INPUT_CLASS_VALUATOR: u8 = 2;
+pub const XCB_INPUT_INPUT_CLASS_FEEDBACK: u8 = 3;
+pub const XCB_INPUT_INPUT_CLASS_PROXIMITY: u8 = 4;
+pub const XCB_INPUT_INPUT_CLASS_FOCUS: u8 = 5;
+pub const XCB_INPUT_INPUT_CLASS_OTHER: u8 = 6;
+pub const XCB_INPUT_VALUATOR_MODE_RELATIVE: u8 = 0;
+pub const XCB_INPUT_VALUATOR_MODE_ABSOLUTE: u8 = 1;
+pub const XCB_INPUT_LIST_INPUT_DEVICES: u8 = 2;
+pub const XCB_INPUT_OPEN_DEVICE: u8 = 3;
+pub const XCB_INPUT_CLOSE_DEVICE: u8 = 4;
+pub const XCB_INPUT_SET_DEVICE_MODE: u8 = 5;
+pub const XCB_INPUT_SELECT_EXTENSION_EVENT: u8 = 6;
+pub const XCB_INPUT_GET_SELECTED_EXTENSION_EVENTS: u8 = 7;
+pub const XCB_INPUT_PROPAGATE_MODE_ADD_TO_LIST: u8 = 0;
+pub const XCB_INPUT_PROPAGATE_MODE_DELETE_FROM_LIST: u8 = 1;
+pub const XCB_INPUT_CHANGE_DEVICE_DONT_PROPAGATE_LIST: u8 = 8;
+pub const XCB_INPUT_GET_DEVICE_DONT_PROPAGATE_LIST: u8 = 9;
+pub const XCB_INPUT_GET_DEVICE_MOTION_EVENTS: u8 = 10;
+pub const XCB_INPUT_CHANGE_KEYBOARD_DEVICE: u8 = 11;
+pub const XCB_INPUT_CHANGE_POINTER_DEVICE: u8 = 12;
+pub const XCB_INPUT_GRAB_DEVICE: u8 = 13;
+pub const XCB_INPUT_UNGRAB_DEVICE: u8 = 14;
+pub const XCB_INPUT_MODIFIER_DEVICE_USE_X_KEYBOARD: u8 = 255;
+pub const XCB_INPUT_GRAB_DEVICE_KEY: u8 = 15;
+pub const XCB_INPUT_UNGRAB_DEVICE_KEY: u8 = 16;
+pub const XCB_INPUT_GRAB_DEVICE_BUTTON: u8 = 17;
+pub const XCB_INPUT_UNGRAB_DEVICE_BUTTON: u8 = 18;
+pub const XCB_INPUT_DEVICE_INPUT_MODE_ASYNC_THIS_DEVICE: u8 = 0;
+pub const XCB_INPUT_DEVICE_INPUT_MODE_SYNC_THIS_DEVICE: u8 = 1;
+pub const XCB_INPUT_DEVICE_INPUT_MODE_REPLAY_THIS_DEVICE: u8 = 2;
+pub const XCB_INPUT_DEVICE_INPUT_MODE_ASYNC_OTHER_DEVICES: u8 = 3;
+pub const XCB_INPUT_DEVICE_INPUT_MODE_ASYNC_ALL: u8 = 4;
+pub const XCB_INPUT_DEVICE_INPUT_MODE_SYNC_ALL: u8 = 5;
+pub const XCB_INPUT_ALLOW_DEVICE_EVENTS: u8 = 19;
+pub const XCB_INPUT_GET_DEVICE_FOCUS: u8 = 20;
+pub const XCB_INPUT_SET_DEVICE_FOCUS: u8 = 21;
+pub const XCB_INPUT_FEEDBACK_CLASS_KEYBOARD: u8 = 0;
+pub const XCB_INPUT_FEEDBACK_CLASS_POINTER: u8 = 1;
+pub const XCB_INPUT_FEEDBACK_CLASS_STRING: u8 = 2;
+pub const XCB_INPUT_FEEDBACK_CLASS_INTEGER: u8 = 3;
+pub const XCB_INPUT_FEEDBACK_CLASS_LED: u8 = 4;
+pub const XCB_INPUT_FEEDBACK_CLASS_BELL: u8 = 5;
+pub const XCB_INPUT_GET_FEEDBACK_CONTROL: u8 = 22;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_KEY_CLICK_PERCENT: u32 = 1;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_PERCENT: u32 = 2;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_PITCH: u32 = 4;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_DURATION: u32 = 8;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_LED: u32 = 16;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_LED_MODE: u32 = 32;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_KEY: u32 = 64;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_AUTO_REPEAT_MODE: u32 = 128;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_STRING: u32 = 1;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_INTEGER: u32 = 1;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_ACCEL_NUM: u32 = 1;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_ACCEL_DENOM: u32 = 2;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL_MASK_THRESHOLD: u32 = 4;
+pub const XCB_INPUT_CHANGE_FEEDBACK_CONTROL: u8 = 23;
+pub const XCB_INPUT_GET_DEVICE_KEY_MAPPING: u8 = 24;
+pub const XCB_INPUT_CHANGE_DEVICE_KEY_MAPPING: u8 = 25;
+pub const XCB_INPUT_GET_DEVICE_MODIFIER_MAPPING: u8 = 26;
+pub const XCB_INPUT_SET_DEVICE_MODIFIER_MAPPING: u8 = 27;
+pub const XCB_INPUT_GET_DEVICE_BUTTON_MAPPING: u8 = 28;
+pub const XCB_INPUT_SET_DEVICE_BUTTON_MAPPING: u8 = 29;
+pub const XCB_INPUT_VALUATOR_STATE_MODE_MASK_DEVICE_MODE_ABSOLUTE: u8 = 1;
+pub const XCB_INPUT_VALUATOR_STATE_MODE_MASK_OUT_OF_PROXIMITY: u8 = 2;
+pub const XCB_INPUT_QUERY_DEVICE_STATE: u8 = 30;
+pub const XCB_INPUT_DEVICE_BELL: u8 = 32;
+pub const XCB_INPUT_SET_DEVICE_VALUATORS: u8 = 33;
+pub const XCB_INPUT_DEVICE_CONTROL_RESOLUTION: u16 = 1;
+pub const XCB_INPUT_DEVICE_CONTROL_ABS_CALIB: u16 = 2;
+pub const XCB_INPUT_DEVICE_CONTROL_CORE: u16 = 3;
+pub const XCB_INPUT_DEVICE_CONTROL_ENABLE: u16 = 4;
+pub const XCB_INPUT_DEVICE_CONTROL_ABS_AREA: u16 = 5;
+pub const XCB_INPUT_GET_DEVICE_CONTROL: u8 = 34;
+pub const XCB_INPUT_CHANGE_DEVICE_CONTROL: u8 = 35;
+pub const XCB_INPUT_LIST_DEVICE_PROPERTIES: u8 = 36;
+pub const XCB_INPUT_PROPERTY_FORMAT_8_BITS: u8 = 8;
+pub const XCB_INPUT_PROPERTY_FORMAT_16_BITS: u8 = 16;
+pub const XCB_INPUT_PROPERTY_FORMAT_32_BITS: u8 = 32;
+pub const XCB_INPUT_CHANGE_DEVICE_PROPERTY: u8 = 37;
+pub const XCB_INPUT_DELETE_DEVICE_PROPERTY: u8 = 38;
+pub const XCB_INPUT_GET_DEVICE_PROPERTY: u8 = 39;
+pub const XCB_INPUT_DEVICE_ALL: u16 = 0;
+pub const XCB_INPUT_DEVICE_ALL_MASTER: u16 = 1;
+pub const XCB_INPUT_XI_QUERY_POINTER: u8 = 40;
+pub const XCB_INPUT_XI_WARP_POINTER: u8 = 41;
+pub const XCB_INPUT_XI_CHANGE_CURSOR: u8 = 42;
+pub const XCB_INPUT_HIERARCHY_CHANGE_TYPE_ADD_MASTER: u16 = 1;
+pub const XCB_INPUT_HIERARCHY_CHANGE_TYPE_REMOVE_MASTER: u16 = 2;
+pub const XCB_INPUT_HIERARCHY_CHANGE_TYPE_ATTACH_SLAVE: u16 = 3;
+pub const XCB_INPUT_HIERARCHY_CHANGE_TYPE_DETACH_SLAVE: u16 = 4;
+pub const XCB_INPUT_CHANGE_MODE_ATTACH: u8 = 1;
+pub const XCB_INPUT_CHANGE_MODE_FLOAT: u8 = 2;
+pub const XCB_INPUT_XI_CHANGE_HIERARCHY: u8 = 43;
+pub const XCB_INPUT_XI_SET_CLIENT_POINTER: u8 = 44;
+pub const XCB_INPUT_XI_GET_CLIENT_POINTER: u8 = 45;
+pub const XCB_INPUT_XI_EVENT_MASK_DEVICE_CHANGED: u32 = 2;
+pub const XCB_INPUT_XI_EVENT_MASK_KEY_PRESS: u32 = 4;
+pub const XCB_INPUT_XI_EVENT_MASK_KEY_RELEASE: u32 = 8;
+pub const XCB_INPUT_XI_EVENT_MASK_BUTTON_PRESS: u32 = 16;
+pub const XCB_INPUT_XI_EVENT_MASK_BUTTON_RELEASE: u32 = 32;
+pub const XCB_INPUT_XI_EVENT_MASK_MOTION: u32 = 64;
+pub const XCB_INPUT_XI_EVENT_MASK_ENTER: u32 = 128;
+pub const XCB_INPUT_XI_EVENT_MASK_LEAVE: u32 = 256;
+pub const XCB_INPUT_XI_EVENT_MASK_FOCUS_IN: u32 = 512;
+pub const XCB_INPUT_XI_EVENT_MASK_FOCUS_OUT: u32 = 1024;
+pub const XCB_INPUT_XI_EVENT_MASK_HIERARCHY: u32 = 2048;
+pub const XCB_INPUT_XI_EVENT_MASK_PROPERTY: u32 = 4096;
+pub const XCB_INPUT_XI_EVENT_MASK_RAW_KEY_PRESS: u32 = 8192;
+pub const XCB_INPUT_XI_EVENT_MASK_RAW_KEY_RELEASE: u32 = 16384;
+pub const XCB_INPUT_XI_EVENT_MASK_RAW_BUTTON_PRESS: u32 = 32768;
+pub const XCB_INPUT_XI_EVENT_MASK_RAW_BUTTON_RELEASE: u32 = 65536;
+pub const XCB_INPUT_XI_EVENT_MASK_RAW_MOTION: u32 = 131072;
+pub const XCB_INPUT_XI_EVENT_MASK_TOUCH_BEGIN: u32 = 262144;
+pub const XCB_INPUT_XI_EVENT_MASK_TOUCH_UPDATE: u32 = 524288;
+pub const XCB_INPUT_XI_EVENT_MASK_TOUCH_END: u32 = 1048576;
+pub const XCB_INPUT_XI_EVENT_MASK_TOUCH_OWNERSHIP: u32 = 2097152;
+pub const XCB_INPUT_XI_EVENT_MASK_RAW_TOUCH_BEGIN: u32 = 4194304;
+pub const XCB_INPUT_XI_EVENT_MASK_RAW_TOUCH_UPDATE: u32 = 8388608;
+pub const XCB_INPUT_XI_EVENT_MASK_RAW_TOUCH_END: u32 = 16777216;
+pub const XCB_INPUT_XI_EVENT_MASK_BARRIER_HIT: u32 = 33554432;
+pub const XCB_INPUT_XI_EVENT_MASK_BARRIER_LEAVE: u32 = 67108864;
+pub const XCB_INPUT_XI_SELECT_EVENTS: u8 = 46;
+pub const XCB_INPUT_XI_QUERY_VERSION: u8 = 47;
+pub const XCB_INPUT_DEVICE_CLASS_TYPE_KEY: u16 = 0;
+pub const XCB_INPUT_DEVICE_CLASS_TYPE_BUTTON: u16 = 1;
+pub const XCB_INPUT_DEVICE_CLASS_TYPE_VALUATOR: u16 = 2;
+pub const XCB_INPUT_DEVICE_CLASS_TYPE_SCROLL: u16 = 3;
+pub const XCB_INPUT_DEVICE_CLASS_TYPE_TOUCH: u16 = 8;
+pub const XCB_INPUT_DEVICE_TYPE_MASTER_POINTER: u16 = 1;
+pub const XCB_INPUT_DEVICE_TYPE_MASTER_KEYBOARD: u16 = 2;
+pub const XCB_INPUT_DEVICE_TYPE_SLAVE_POINTER: u16 = 3;
+pub const XCB_INPUT_DEVICE_TYPE_SLAVE_KEYBOARD: u16 = 4;
+pub const XCB_INPUT_DEVICE_TYPE_FLOATING_SLAVE: u16 = 5;
+pub const XCB_INPUT_SCROLL_FLAGS_NO_EMULATION: u32 = 1;
+pub const XCB_INPUT_SCROLL_FLAGS_PREFERRED: u32 = 2;
+pub const XCB_INPUT_SCROLL_TYPE_VERTICAL: u16 = 1;
+pub const XCB_INPUT_SCROLL_TYPE_HORIZONTAL: u16 = 2;
+pub const XCB_INPUT_TOUCH_MODE_DIRECT: u8 = 1;
+pub const XCB_INPUT_TOUCH_MODE_DEPENDENT: u8 = 2;
+pub const XCB_INPUT_XI_QUERY_DEVICE: u8 = 48;
+pub const XCB_INPUT_XI_SET_FOCUS: u8 = 49;
+pub const XCB_INPUT_XI_GET_FOCUS: u8 = 50;
+pub const XCB_INPUT_GRAB_OWNER_NO_OWNER: u8 = 0;
+pub const XCB_INPUT_GRAB_OWNER_OWNER: u8 = 1;
+pub const XCB_INPUT_XI_GRAB_DEVICE: u8 = 51;
+pub const XCB_INPUT_XI_UNGRAB_DEVICE: u8 = 52;
+pub const XCB_INPUT_EVENT_MODE_ASYNC_DEVICE: u8 = 0;
+pub const XCB_INPUT_EVENT_MODE_SYNC_DEVICE: u8 = 1;
+pub const XCB_INPUT_EVENT_MODE_REPLAY_DEVICE: u8 = 2;
+pub const XCB_INPUT_EVENT_MODE_ASYNC_PAIRED_DEVICE: u8 = 3;
+pub const XCB_INPUT_EVENT_MODE_ASYNC_PAIR: u8 = 4;
+pub const XCB_INPUT_EVENT_MODE_SYNC_PAIR: u8 = 5;
+pub const XCB_INPUT_EVENT_MODE_ACCEPT_TOUCH: u8 = 6;
+pub const XCB_INPUT_EVENT_MODE_REJECT_TOUCH: u8 = 7;
+pub const XCB_INPUT_XI_ALLOW_EVENTS: u8 = 53;
+pub const XCB_INPUT_GRAB_MODE_22_SYNC: u8 = 0;
+pub const XCB_INPUT_GRAB_MODE_22_ASYNC: u8 = 1;
+pub const XCB_INPUT_GRAB_MODE_22_TOUCH: u8 = 2;
+pub const XCB_INPUT_GRAB_TYPE_BUTTON: u8 = 0;
+pub const XCB_INPUT_GRAB_TYPE_KEYCODE: u8 = 1;
+pub const XCB_INPUT_GRAB_TYPE_ENTER: u8 = 2;
+pub const XCB_INPUT_GRAB_TYPE_FOCUS_IN: u8 = 3;
+pub const XCB_INPUT_GRAB_TYPE_TOUCH_BEGIN: u8 = 4;
+pub const XCB_INPUT_MODIFIER_MASK_ANY: u32 = 2147483648;
+pub const XCB_INPUT_XI_PASSIVE_GRAB_DEVICE: u8 = 54;
+pub const XCB_INPUT_XI_PASSIVE_UNGRAB_DEVICE: u8 = 55;
+pub const XCB_INPUT_XI_LIST_PROPERTIES: u8 = 56;
+pub const XCB_INPUT_XI_CHANGE_PROPERTY: u8 = 57;
+pub const XCB_INPUT_XI_DELETE_PROPERTY: u8 = 58;
+pub const XCB_INPUT_XI_GET_PROPERTY: u8 = 59;
+pub const XCB_INPUT_XI_GET_SELECTED_EVENTS: u8 = 60;
+pub const XCB_INPUT_XI_BARRIER_RELEASE_POINTER: u8 = 61;
+pub const XCB_INPUT_DEVICE_VALUATOR: u8 = 0;
+pub const XCB_INPUT_MORE_EVENTS_MASK_MORE_EVENTS: u8 = 128;
+pub const XCB_INPUT_DEVICE_KEY_PRESS: u8 = 1;
+pub const XCB_INPUT_DEVICE_KEY_RELEASE: u8 = 2;
+pub const XCB_INPUT_DEVICE_BUTTON_PRESS: u8 = 3;
+pub const XCB_INPUT_DEVICE_BUTTON_RELEASE: u8 = 4;
+pub const XCB_INPUT_DEVICE_MOTION_NOTIFY: u8 = 5;
+pub const XCB_INPUT_DEVICE_FOCUS_IN: u8 = 6;
+pub const XCB_INPUT_DEVICE_FOCUS_OUT: u8 = 7;
+pub const XCB_INPUT_PROXIMITY_IN: u8 = 8;
+pub const XCB_INPUT_PROXIMITY_OUT: u8 = 9;
+pub const XCB_INPUT_CLASSES_REPORTED_MASK_OUT_OF_PROXIMITY: u8 = 128;
+pub const XCB_INPUT_CLASSES_REPORTED_MASK_DEVICE_MODE_ABSOLUTE: u8 = 64;
+pub const XCB_INPUT_CLASSES_REPORTED_MASK_REPORTING_VALUATORS: u8 = 4;
+pub const XCB_INPUT_CLASSES_REPORTED_MASK_REPORTING_BUTTONS: u8 = 2;
+pub const XCB_INPUT_CLASSES_REPORTED_MASK_REPORTING_KEYS: u8 = 1;
+pub const XCB_INPUT_DEVICE_STATE_NOTIFY: u8 = 10;
+pub const XCB_INPUT_DEVICE_MAPPING_NOTIFY: u8 = 11;
+pub const XCB_INPUT_CHANGE_DEVICE_NEW_POINTER: u8 = 0;
+pub const XCB_INPUT_CHANGE_DEVICE_NEW_KEYBOARD: u8 = 1;
+pub const XCB_INPUT_CHANGE_DEVICE_NOTIFY: u8 = 12;
+pub const XCB_INPUT_DEVICE_KEY_STATE_NOTIFY: u8 = 13;
+pub const XCB_INPUT_DEVICE_BUTTON_STATE_NOTIFY: u8 = 14;
+pub const XCB_INPUT_DEVICE_CHANGE_ADDED: u8 = 0;
+pub const XCB_INPUT_DEVICE_CHANGE_REMOVED: u8 = 1;
+pub const XCB_INPUT_DEVICE_CHANGE_ENABLED: u8 = 2;
+pub const XCB_INPUT_DEVICE_CHANGE_DISABLED: u8 = 3;
+pub const XCB_INPUT_DEVICE_CHANGE_UNRECOVERABLE: u8 = 4;
+pub const XCB_INPUT_DEVICE_CHANGE_CONTROL_CHANGED: u8 = 5;
+pub const XCB_INPUT_DEVICE_PRESENCE_NOTIFY: u8 = 15;
+pub const XCB_INPUT_DEVICE_PROPERTY_NOTIFY: u8 = 16;
+pub const XCB_INPUT_CHANGE_REASON_SLAVE_SWITCH: u8 = 1;
+pub const XCB_INPUT_CHANGE_REASON_DEVICE_CHANGE: u8 = 2;
+pub const XCB_INPUT_DEVICE_CHANGED: u16 = 1;
+pub const XCB_INPUT_KEY_EVENT_FLAGS_KEY_REPEAT: u32 = 65536;
+pub const XCB_INPUT_KEY_PRESS: u16 = 2;
+pub const XCB_INPUT_KEY_RELEASE: u16 = 3;
+pub const XCB_INPUT_POINTER_EVENT_FLAGS_POINTER_EMULATED: u32 = 65536;
+pub const XCB_INPUT_BUTTON_PRESS: u16 = 4;
+pub const XCB_INPUT_BUTTON_RELEASE: u16 = 5;
+pub const XCB_INPUT_MOTION: u16 = 6;
+pub const XCB_INPUT_NOTIFY_MODE_NORMAL: u8 = 0;
+pub const XCB_INPUT_NOTIFY_MODE_GRAB: u8 = 1;
+pub const XCB_INPUT_NOTIFY_MODE_UNGRAB: u8 = 2;
+pub const XCB_INPUT_NOTIFY_MODE_WHILE_GRABBED: u8 = 3;
+pub const XCB_INPUT_NOTIFY_MODE_PASSIVE_GRAB: u8 = 4;
+pub const XCB_INPUT_NOTIFY_MODE_PASSIVE_UNGRAB: u8 = 5;
+pub const XCB_INPUT_NOTIFY_DETAIL_ANCESTOR: u8 = 0;
+pub const XCB_INPUT_NOTIFY_DETAIL_VIRTUAL: u8 = 1;
+pub const XCB_INPUT_NOTIFY_DETAIL_INFERIOR: u8 = 2;
+pub const XCB_INPUT_NOTIFY_DETAIL_NONLINEAR: u8 = 3;
+pub const XCB_INPUT_NOTIFY_DETAIL_NONLINEAR_VIRTUAL: u8 = 4;
+pub const XCB_INPUT_NOTIFY_DETAIL_POINTER: u8 = 5;
+pub const XCB_INPUT_NOTIFY_DETAIL_POINTER_ROOT: u8 = 6;
+pub const XCB_INPUT_NOTIFY_DETAIL_NONE: u8 = 7;
+pub const XCB_INPUT_ENTER: u16 = 7;
+pub const XCB_INPUT_LEAVE: u16 = 8;
+pub const XCB_INPUT_FOCUS_IN: u16 = 9;
+pub const XCB_INPUT_FOCUS_OUT: u16 = 10;
+pub const XCB_INPUT_HIERARCHY_MASK_MASTER_ADDED: u32 = 1;
+pub const XCB_INPUT_HIERARCHY_MASK_MASTER_REMOVED: u32 = 2;
+pub const XCB_INPUT_HIERARCHY_MASK_SLAVE_ADDED: u32 = 4;
+pub const XCB_INPUT_HIERARCHY_MASK_SLAVE_REMOVED: u32 = 8;
+pub const XCB_INPUT_HIERARCHY_MASK_SLAVE_ATTACHED: u32 = 16;
+pub const XCB_INPUT_HIERARCHY_MASK_SLAVE_DETACHED: u32 = 32;
+pub const XCB_INPUT_HIERARCHY_MASK_DEVICE_ENABLED: u32 = 64;
+pub const XCB_INPUT_HIERARCHY_MASK_DEVICE_DISABLED: u32 = 128;
+pub const XCB_INPUT_HIERARCHY: u16 = 11;
+pub const XCB_INPUT_PROPERTY_FLAG_DELETED: u8 = 0;
+pub const XCB_INPUT_PROPERTY_FLAG_CREATED: u8 = 1;
+pub const XCB_INPUT_PROPERTY_FLAG_MODIFIED: u8 = 2;
+pub const XCB_INPUT_PROPERTY: u16 = 12;
+pub const XCB_INPUT_RAW_KEY_PRESS: u16 = 13;
+pub const XCB_INPUT_RAW_KEY_RELEASE: u16 = 14;
+pub const XCB_INPUT_RAW_BUTTON_PRESS: u16 = 15;
+pub const XCB_INPUT_RAW_BUTTON_RELEASE: u16 = 16;
+pub const XCB_INPUT_RAW_MOTION: u16 = 17;
+pub const XCB_INPUT_TOUCH_EVENT_FLAGS_TOUCH_PENDING_END: u32 = 65536;
+pub const XCB_INPUT_TOUCH_EVENT_FLAGS_TOUCH_EMULATING_POINTER: u32 = 131072;
+pub const XCB_INPUT_TOUCH_BEGIN: u16 = 18;
+pub const XCB_INPUT_TOUCH_UPDATE: u16 = 19;
+pub const XCB_INPUT_TOUCH_END: u16 = 20;
+pub const XCB_INPUT_TOUCH_OWNERSHIP_FLAGS_NONE: u32 = 0;
+pub const XCB_INPUT_TOUCH_OWNERSHIP: u16 = 21;
+pub const XCB_INPUT_RAW_TOUCH_BEGIN: u16 = 22;
+pub const XCB_INPUT_RAW_TOUCH_UPDATE: u16 = 23;
+pub const XCB_INPUT_RAW_TOUCH_END: u16 = 24;
+pub const XCB_INPUT_BARRIER_FLAGS_POINTER_RELEASED: u32 = 1;
+pub const XCB_INPUT_BARRIER_FLAGS_DEVICE_IS_GRABBED: u32 = 2;
+pub const XCB_INPUT_BARRIER_HIT: u16 = 25;
+pub const XCB_INPUT_BARRIER_LEAVE: u16 = 26;
+pub const XCB_INPUT_SEND_EXTENSION_EVENT: u8 = 31;
+pub const XCB_INPUT_DEVICE: u8 = 0;
+pub const XCB_INPUT_EVENT: u8 = 1;
+pub const XCB_INPUT_MODE: u8 = 2;
+pub const XCB_INPUT_DEVICE_BUSY: u8 = 3;
+pub const XCB_INPUT_CLASS: u8 = 4;
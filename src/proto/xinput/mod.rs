@@ -0,0 +1,2385 @@
+// Generated by xcbgen from xml/xinput.xml, do not edit.
+
+//! Requests whose parameters the safe wrappers cannot express only have raw bindings
+//! in `cdef`:
+//!
+//! - `xcb_input_change_feedback_control`
+//! - `xcb_input_change_device_control`
+//! - `xcb_input_change_device_property`
+//! - `xcb_input_xi_change_hierarchy`
+//! - `xcb_input_xi_select_events`
+//! - `xcb_input_xi_change_property`
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::os::raw::c_char;
+use std::ptr;
+use std::slice;
+
+use proto::xproto::{XCBAtom, XCBCursor, XCBKeysym, XCBStrIterator, XCBTimestamp, XCBWindow};
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBInputEventClass = cdef::XCBInputEventClass;
+pub type XCBInputEventClassIterator = cdef::XCBInputEventClassIterator;
+pub type XCBInputKeyCode = cdef::XCBInputKeyCode;
+pub type XCBInputKeyCodeIterator = cdef::XCBInputKeyCodeIterator;
+pub type XCBInputDeviceId = cdef::XCBInputDeviceId;
+pub type XCBInputDeviceIdIterator = cdef::XCBInputDeviceIdIterator;
+pub type XCBInputFp1616 = cdef::XCBInputFp1616;
+pub type XCBInputFp1616Iterator = cdef::XCBInputFp1616Iterator;
+pub type XCBInputFp3232 = cdef::XCBInputFp3232;
+pub type XCBInputFp3232Iterator = cdef::XCBInputFp3232Iterator;
+pub type XCBInputGetExtensionVersionRequest = cdef::XCBInputGetExtensionVersionRequest;
+pub type XCBInputGetExtensionVersionCookie = cdef::XCBInputGetExtensionVersionCookie;
+pub type XCBInputGetExtensionVersionReply = cdef::XCBInputGetExtensionVersionReply;
+pub type XCBInputDeviceInfo = cdef::XCBInputDeviceInfo;
+pub type XCBInputDeviceInfoIterator = cdef::XCBInputDeviceInfoIterator;
+pub type XCBInputKeyInfo = cdef::XCBInputKeyInfo;
+pub type XCBInputKeyInfoIterator = cdef::XCBInputKeyInfoIterator;
+pub type XCBInputButtonInfo = cdef::XCBInputButtonInfo;
+pub type XCBInputButtonInfoIterator = cdef::XCBInputButtonInfoIterator;
+pub type XCBInputAxisInfo = cdef::XCBInputAxisInfo;
+pub type XCBInputAxisInfoIterator = cdef::XCBInputAxisInfoIterator;
+pub type XCBInputValuatorInfo = cdef::XCBInputValuatorInfo;
+pub type XCBInputValuatorInfoIterator = cdef::XCBInputValuatorInfoIterator;
+pub type XCBInputInputInfo = cdef::XCBInputInputInfo;
+pub type XCBInputInputInfoIterator = cdef::XCBInputInputInfoIterator;
+pub type XCBInputDeviceName = cdef::XCBInputDeviceName;
+pub type XCBInputDeviceNameIterator = cdef::XCBInputDeviceNameIterator;
+pub type XCBInputListInputDevicesRequest = cdef::XCBInputListInputDevicesRequest;
+pub type XCBInputListInputDevicesCookie = cdef::XCBInputListInputDevicesCookie;
+pub type XCBInputListInputDevicesReply = cdef::XCBInputListInputDevicesReply;
+pub type XCBInputEventTypeBase = cdef::XCBInputEventTypeBase;
+pub type XCBInputEventTypeBaseIterator = cdef::XCBInputEventTypeBaseIterator;
+pub type XCBInputInputClassInfo = cdef::XCBInputInputClassInfo;
+pub type XCBInputInputClassInfoIterator = cdef::XCBInputInputClassInfoIterator;
+pub type XCBInputOpenDeviceRequest = cdef::XCBInputOpenDeviceRequest;
+pub type XCBInputOpenDeviceCookie = cdef::XCBInputOpenDeviceCookie;
+pub type XCBInputOpenDeviceReply = cdef::XCBInputOpenDeviceReply;
+pub type XCBInputCloseDeviceRequest = cdef::XCBInputCloseDeviceRequest;
+pub type XCBInputSetDeviceModeRequest = cdef::XCBInputSetDeviceModeRequest;
+pub type XCBInputSetDeviceModeCookie = cdef::XCBInputSetDeviceModeCookie;
+pub type XCBInputSetDeviceModeReply = cdef::XCBInputSetDeviceModeReply;
+pub type XCBInputSelectExtensionEventRequest = cdef::XCBInputSelectExtensionEventRequest;
+pub type XCBInputGetSelectedExtensionEventsRequest =
+    cdef::XCBInputGetSelectedExtensionEventsRequest;
+pub type XCBInputGetSelectedExtensionEventsCookie = cdef::XCBInputGetSelectedExtensionEventsCookie;
+pub type XCBInputGetSelectedExtensionEventsReply = cdef::XCBInputGetSelectedExtensionEventsReply;
+pub type XCBInputChangeDeviceDontPropagateListRequest =
+    cdef::XCBInputChangeDeviceDontPropagateListRequest;
+pub type XCBInputGetDeviceDontPropagateListRequest =
+    cdef::XCBInputGetDeviceDontPropagateListRequest;
+pub type XCBInputGetDeviceDontPropagateListCookie = cdef::XCBInputGetDeviceDontPropagateListCookie;
+pub type XCBInputGetDeviceDontPropagateListReply = cdef::XCBInputGetDeviceDontPropagateListReply;
+pub type XCBInputDeviceTimeCoord = cdef::XCBInputDeviceTimeCoord;
+pub type XCBInputDeviceTimeCoordIterator = cdef::XCBInputDeviceTimeCoordIterator;
+pub type XCBInputGetDeviceMotionEventsRequest = cdef::XCBInputGetDeviceMotionEventsRequest;
+pub type XCBInputGetDeviceMotionEventsCookie = cdef::XCBInputGetDeviceMotionEventsCookie;
+pub type XCBInputGetDeviceMotionEventsReply = cdef::XCBInputGetDeviceMotionEventsReply;
+pub type XCBInputChangeKeyboardDeviceRequest = cdef::XCBInputChangeKeyboardDeviceRequest;
+pub type XCBInputChangeKeyboardDeviceCookie = cdef::XCBInputChangeKeyboardDeviceCookie;
+pub type XCBInputChangeKeyboardDeviceReply = cdef::XCBInputChangeKeyboardDeviceReply;
+pub type XCBInputChangePointerDeviceRequest = cdef::XCBInputChangePointerDeviceRequest;
+pub type XCBInputChangePointerDeviceCookie = cdef::XCBInputChangePointerDeviceCookie;
+pub type XCBInputChangePointerDeviceReply = cdef::XCBInputChangePointerDeviceReply;
+pub type XCBInputGrabDeviceRequest = cdef::XCBInputGrabDeviceRequest;
+pub type XCBInputGrabDeviceCookie = cdef::XCBInputGrabDeviceCookie;
+pub type XCBInputGrabDeviceReply = cdef::XCBInputGrabDeviceReply;
+pub type XCBInputUngrabDeviceRequest = cdef::XCBInputUngrabDeviceRequest;
+pub type XCBInputGrabDeviceKeyRequest = cdef::XCBInputGrabDeviceKeyRequest;
+pub type XCBInputUngrabDeviceKeyRequest = cdef::XCBInputUngrabDeviceKeyRequest;
+pub type XCBInputGrabDeviceButtonRequest = cdef::XCBInputGrabDeviceButtonRequest;
+pub type XCBInputUngrabDeviceButtonRequest = cdef::XCBInputUngrabDeviceButtonRequest;
+pub type XCBInputAllowDeviceEventsRequest = cdef::XCBInputAllowDeviceEventsRequest;
+pub type XCBInputGetDeviceFocusRequest = cdef::XCBInputGetDeviceFocusRequest;
+pub type XCBInputGetDeviceFocusCookie = cdef::XCBInputGetDeviceFocusCookie;
+pub type XCBInputGetDeviceFocusReply = cdef::XCBInputGetDeviceFocusReply;
+pub type XCBInputSetDeviceFocusRequest = cdef::XCBInputSetDeviceFocusRequest;
+pub type XCBInputKbdFeedbackState = cdef::XCBInputKbdFeedbackState;
+pub type XCBInputKbdFeedbackStateIterator = cdef::XCBInputKbdFeedbackStateIterator;
+pub type XCBInputPtrFeedbackState = cdef::XCBInputPtrFeedbackState;
+pub type XCBInputPtrFeedbackStateIterator = cdef::XCBInputPtrFeedbackStateIterator;
+pub type XCBInputIntegerFeedbackState = cdef::XCBInputIntegerFeedbackState;
+pub type XCBInputIntegerFeedbackStateIterator = cdef::XCBInputIntegerFeedbackStateIterator;
+pub type XCBInputStringFeedbackState = cdef::XCBInputStringFeedbackState;
+pub type XCBInputStringFeedbackStateIterator = cdef::XCBInputStringFeedbackStateIterator;
+pub type XCBInputBellFeedbackState = cdef::XCBInputBellFeedbackState;
+pub type XCBInputBellFeedbackStateIterator = cdef::XCBInputBellFeedbackStateIterator;
+pub type XCBInputLedFeedbackState = cdef::XCBInputLedFeedbackState;
+pub type XCBInputLedFeedbackStateIterator = cdef::XCBInputLedFeedbackStateIterator;
+pub type XCBInputFeedbackState = cdef::XCBInputFeedbackState;
+pub type XCBInputFeedbackStateIterator = cdef::XCBInputFeedbackStateIterator;
+pub type XCBInputGetFeedbackControlRequest = cdef::XCBInputGetFeedbackControlRequest;
+pub type XCBInputGetFeedbackControlCookie = cdef::XCBInputGetFeedbackControlCookie;
+pub type XCBInputGetFeedbackControlReply = cdef::XCBInputGetFeedbackControlReply;
+pub type XCBInputKbdFeedbackCtl = cdef::XCBInputKbdFeedbackCtl;
+pub type XCBInputKbdFeedbackCtlIterator = cdef::XCBInputKbdFeedbackCtlIterator;
+pub type XCBInputPtrFeedbackCtl = cdef::XCBInputPtrFeedbackCtl;
+pub type XCBInputPtrFeedbackCtlIterator = cdef::XCBInputPtrFeedbackCtlIterator;
+pub type XCBInputIntegerFeedbackCtl = cdef::XCBInputIntegerFeedbackCtl;
+pub type XCBInputIntegerFeedbackCtlIterator = cdef::XCBInputIntegerFeedbackCtlIterator;
+pub type XCBInputStringFeedbackCtl = cdef::XCBInputStringFeedbackCtl;
+pub type XCBInputStringFeedbackCtlIterator = cdef::XCBInputStringFeedbackCtlIterator;
+pub type XCBInputBellFeedbackCtl = cdef::XCBInputBellFeedbackCtl;
+pub type XCBInputBellFeedbackCtlIterator = cdef::XCBInputBellFeedbackCtlIterator;
+pub type XCBInputLedFeedbackCtl = cdef::XCBInputLedFeedbackCtl;
+pub type XCBInputLedFeedbackCtlIterator = cdef::XCBInputLedFeedbackCtlIterator;
+pub type XCBInputFeedbackCtl = cdef::XCBInputFeedbackCtl;
+pub type XCBInputFeedbackCtlIterator = cdef::XCBInputFeedbackCtlIterator;
+pub type XCBInputChangeFeedbackControlRequest = cdef::XCBInputChangeFeedbackControlRequest;
+pub type XCBInputGetDeviceKeyMappingRequest = cdef::XCBInputGetDeviceKeyMappingRequest;
+pub type XCBInputGetDeviceKeyMappingCookie = cdef::XCBInputGetDeviceKeyMappingCookie;
+pub type XCBInputGetDeviceKeyMappingReply = cdef::XCBInputGetDeviceKeyMappingReply;
+pub type XCBInputChangeDeviceKeyMappingRequest = cdef::XCBInputChangeDeviceKeyMappingRequest;
+pub type XCBInputGetDeviceModifierMappingRequest = cdef::XCBInputGetDeviceModifierMappingRequest;
+pub type XCBInputGetDeviceModifierMappingCookie = cdef::XCBInputGetDeviceModifierMappingCookie;
+pub type XCBInputGetDeviceModifierMappingReply = cdef::XCBInputGetDeviceModifierMappingReply;
+pub type XCBInputSetDeviceModifierMappingRequest = cdef::XCBInputSetDeviceModifierMappingRequest;
+pub type XCBInputSetDeviceModifierMappingCookie = cdef::XCBInputSetDeviceModifierMappingCookie;
+pub type XCBInputSetDeviceModifierMappingReply = cdef::XCBInputSetDeviceModifierMappingReply;
+pub type XCBInputGetDeviceButtonMappingRequest = cdef::XCBInputGetDeviceButtonMappingRequest;
+pub type XCBInputGetDeviceButtonMappingCookie = cdef::XCBInputGetDeviceButtonMappingCookie;
+pub type XCBInputGetDeviceButtonMappingReply = cdef::XCBInputGetDeviceButtonMappingReply;
+pub type XCBInputSetDeviceButtonMappingRequest = cdef::XCBInputSetDeviceButtonMappingRequest;
+pub type XCBInputSetDeviceButtonMappingCookie = cdef::XCBInputSetDeviceButtonMappingCookie;
+pub type XCBInputSetDeviceButtonMappingReply = cdef::XCBInputSetDeviceButtonMappingReply;
+pub type XCBInputKeyState = cdef::XCBInputKeyState;
+pub type XCBInputKeyStateIterator = cdef::XCBInputKeyStateIterator;
+pub type XCBInputButtonState = cdef::XCBInputButtonState;
+pub type XCBInputButtonStateIterator = cdef::XCBInputButtonStateIterator;
+pub type XCBInputValuatorState = cdef::XCBInputValuatorState;
+pub type XCBInputValuatorStateIterator = cdef::XCBInputValuatorStateIterator;
+pub type XCBInputInputState = cdef::XCBInputInputState;
+pub type XCBInputInputStateIterator = cdef::XCBInputInputStateIterator;
+pub type XCBInputQueryDeviceStateRequest = cdef::XCBInputQueryDeviceStateRequest;
+pub type XCBInputQueryDeviceStateCookie = cdef::XCBInputQueryDeviceStateCookie;
+pub type XCBInputQueryDeviceStateReply = cdef::XCBInputQueryDeviceStateReply;
+pub type XCBInputDeviceBellRequest = cdef::XCBInputDeviceBellRequest;
+pub type XCBInputSetDeviceValuatorsRequest = cdef::XCBInputSetDeviceValuatorsRequest;
+pub type XCBInputSetDeviceValuatorsCookie = cdef::XCBInputSetDeviceValuatorsCookie;
+pub type XCBInputSetDeviceValuatorsReply = cdef::XCBInputSetDeviceValuatorsReply;
+pub type XCBInputDeviceResolutionState = cdef::XCBInputDeviceResolutionState;
+pub type XCBInputDeviceResolutionStateIterator = cdef::XCBInputDeviceResolutionStateIterator;
+pub type XCBInputDeviceAbsCalibState = cdef::XCBInputDeviceAbsCalibState;
+pub type XCBInputDeviceAbsCalibStateIterator = cdef::XCBInputDeviceAbsCalibStateIterator;
+pub type XCBInputDeviceAbsAreaState = cdef::XCBInputDeviceAbsAreaState;
+pub type XCBInputDeviceAbsAreaStateIterator = cdef::XCBInputDeviceAbsAreaStateIterator;
+pub type XCBInputDeviceCoreState = cdef::XCBInputDeviceCoreState;
+pub type XCBInputDeviceCoreStateIterator = cdef::XCBInputDeviceCoreStateIterator;
+pub type XCBInputDeviceEnableState = cdef::XCBInputDeviceEnableState;
+pub type XCBInputDeviceEnableStateIterator = cdef::XCBInputDeviceEnableStateIterator;
+pub type XCBInputDeviceState = cdef::XCBInputDeviceState;
+pub type XCBInputDeviceStateIterator = cdef::XCBInputDeviceStateIterator;
+pub type XCBInputGetDeviceControlRequest = cdef::XCBInputGetDeviceControlRequest;
+pub type XCBInputGetDeviceControlCookie = cdef::XCBInputGetDeviceControlCookie;
+pub type XCBInputGetDeviceControlReply = cdef::XCBInputGetDeviceControlReply;
+pub type XCBInputDeviceResolutionCtl = cdef::XCBInputDeviceResolutionCtl;
+pub type XCBInputDeviceResolutionCtlIterator = cdef::XCBInputDeviceResolutionCtlIterator;
+pub type XCBInputDeviceAbsCalibCtl = cdef::XCBInputDeviceAbsCalibCtl;
+pub type XCBInputDeviceAbsCalibCtlIterator = cdef::XCBInputDeviceAbsCalibCtlIterator;
+pub type XCBInputDeviceAbsAreaCtrl = cdef::XCBInputDeviceAbsAreaCtrl;
+pub type XCBInputDeviceAbsAreaCtrlIterator = cdef::XCBInputDeviceAbsAreaCtrlIterator;
+pub type XCBInputDeviceCoreCtrl = cdef::XCBInputDeviceCoreCtrl;
+pub type XCBInputDeviceCoreCtrlIterator = cdef::XCBInputDeviceCoreCtrlIterator;
+pub type XCBInputDeviceEnableCtrl = cdef::XCBInputDeviceEnableCtrl;
+pub type XCBInputDeviceEnableCtrlIterator = cdef::XCBInputDeviceEnableCtrlIterator;
+pub type XCBInputDeviceCtl = cdef::XCBInputDeviceCtl;
+pub type XCBInputDeviceCtlIterator = cdef::XCBInputDeviceCtlIterator;
+pub type XCBInputChangeDeviceControlRequest = cdef::XCBInputChangeDeviceControlRequest;
+pub type XCBInputChangeDeviceControlCookie = cdef::XCBInputChangeDeviceControlCookie;
+pub type XCBInputChangeDeviceControlReply = cdef::XCBInputChangeDeviceControlReply;
+pub type XCBInputListDevicePropertiesRequest = cdef::XCBInputListDevicePropertiesRequest;
+pub type XCBInputListDevicePropertiesCookie = cdef::XCBInputListDevicePropertiesCookie;
+pub type XCBInputListDevicePropertiesReply = cdef::XCBInputListDevicePropertiesReply;
+pub type XCBInputChangeDevicePropertyRequest = cdef::XCBInputChangeDevicePropertyRequest;
+pub type XCBInputDeleteDevicePropertyRequest = cdef::XCBInputDeleteDevicePropertyRequest;
+pub type XCBInputGetDevicePropertyRequest = cdef::XCBInputGetDevicePropertyRequest;
+pub type XCBInputGetDevicePropertyCookie = cdef::XCBInputGetDevicePropertyCookie;
+pub type XCBInputGetDevicePropertyReply = cdef::XCBInputGetDevicePropertyReply;
+pub type XCBInputGroupInfo = cdef::XCBInputGroupInfo;
+pub type XCBInputGroupInfoIterator = cdef::XCBInputGroupInfoIterator;
+pub type XCBInputModifierInfo = cdef::XCBInputModifierInfo;
+pub type XCBInputModifierInfoIterator = cdef::XCBInputModifierInfoIterator;
+pub type XCBInputXiQueryPointerRequest = cdef::XCBInputXiQueryPointerRequest;
+pub type XCBInputXiQueryPointerCookie = cdef::XCBInputXiQueryPointerCookie;
+pub type XCBInputXiQueryPointerReply = cdef::XCBInputXiQueryPointerReply;
+pub type XCBInputXiWarpPointerRequest = cdef::XCBInputXiWarpPointerRequest;
+pub type XCBInputXiChangeCursorRequest = cdef::XCBInputXiChangeCursorRequest;
+pub type XCBInputAddMaster = cdef::XCBInputAddMaster;
+pub type XCBInputAddMasterIterator = cdef::XCBInputAddMasterIterator;
+pub type XCBInputRemoveMaster = cdef::XCBInputRemoveMaster;
+pub type XCBInputRemoveMasterIterator = cdef::XCBInputRemoveMasterIterator;
+pub type XCBInputAttachSlave = cdef::XCBInputAttachSlave;
+pub type XCBInputAttachSlaveIterator = cdef::XCBInputAttachSlaveIterator;
+pub type XCBInputDetachSlave = cdef::XCBInputDetachSlave;
+pub type XCBInputDetachSlaveIterator = cdef::XCBInputDetachSlaveIterator;
+pub type XCBInputHierarchyChange = cdef::XCBInputHierarchyChange;
+pub type XCBInputHierarchyChangeIterator = cdef::XCBInputHierarchyChangeIterator;
+pub type XCBInputXiChangeHierarchyRequest = cdef::XCBInputXiChangeHierarchyRequest;
+pub type XCBInputXiSetClientPointerRequest = cdef::XCBInputXiSetClientPointerRequest;
+pub type XCBInputXiGetClientPointerRequest = cdef::XCBInputXiGetClientPointerRequest;
+pub type XCBInputXiGetClientPointerCookie = cdef::XCBInputXiGetClientPointerCookie;
+pub type XCBInputXiGetClientPointerReply = cdef::XCBInputXiGetClientPointerReply;
+pub type XCBInputEventMask = cdef::XCBInputEventMask;
+pub type XCBInputEventMaskIterator = cdef::XCBInputEventMaskIterator;
+pub type XCBInputXiSelectEventsRequest = cdef::XCBInputXiSelectEventsRequest;
+pub type XCBInputXiQueryVersionRequest = cdef::XCBInputXiQueryVersionRequest;
+pub type XCBInputXiQueryVersionCookie = cdef::XCBInputXiQueryVersionCookie;
+pub type XCBInputXiQueryVersionReply = cdef::XCBInputXiQueryVersionReply;
+pub type XCBInputButtonClass = cdef::XCBInputButtonClass;
+pub type XCBInputButtonClassIterator = cdef::XCBInputButtonClassIterator;
+pub type XCBInputKeyClass = cdef::XCBInputKeyClass;
+pub type XCBInputKeyClassIterator = cdef::XCBInputKeyClassIterator;
+pub type XCBInputScrollClass = cdef::XCBInputScrollClass;
+pub type XCBInputScrollClassIterator = cdef::XCBInputScrollClassIterator;
+pub type XCBInputTouchClass = cdef::XCBInputTouchClass;
+pub type XCBInputTouchClassIterator = cdef::XCBInputTouchClassIterator;
+pub type XCBInputValuatorClass = cdef::XCBInputValuatorClass;
+pub type XCBInputValuatorClassIterator = cdef::XCBInputValuatorClassIterator;
+pub type XCBInputDeviceClass = cdef::XCBInputDeviceClass;
+pub type XCBInputDeviceClassIterator = cdef::XCBInputDeviceClassIterator;
+pub type XCBInputXiDeviceInfo = cdef::XCBInputXiDeviceInfo;
+pub type XCBInputXiDeviceInfoIterator = cdef::XCBInputXiDeviceInfoIterator;
+pub type XCBInputXiQueryDeviceRequest = cdef::XCBInputXiQueryDeviceRequest;
+pub type XCBInputXiQueryDeviceCookie = cdef::XCBInputXiQueryDeviceCookie;
+pub type XCBInputXiQueryDeviceReply = cdef::XCBInputXiQueryDeviceReply;
+pub type XCBInputXiSetFocusRequest = cdef::XCBInputXiSetFocusRequest;
+pub type XCBInputXiGetFocusRequest = cdef::XCBInputXiGetFocusRequest;
+pub type XCBInputXiGetFocusCookie = cdef::XCBInputXiGetFocusCookie;
+pub type XCBInputXiGetFocusReply = cdef::XCBInputXiGetFocusReply;
+pub type XCBInputXiGrabDeviceRequest = cdef::XCBInputXiGrabDeviceRequest;
+pub type XCBInputXiGrabDeviceCookie = cdef::XCBInputXiGrabDeviceCookie;
+pub type XCBInputXiGrabDeviceReply = cdef::XCBInputXiGrabDeviceReply;
+pub type XCBInputXiUngrabDeviceRequest = cdef::XCBInputXiUngrabDeviceRequest;
+pub type XCBInputXiAllowEventsRequest = cdef::XCBInputXiAllowEventsRequest;
+pub type XCBInputGrabModifierInfo = cdef::XCBInputGrabModifierInfo;
+pub type XCBInputGrabModifierInfoIterator = cdef::XCBInputGrabModifierInfoIterator;
+pub type XCBInputXiPassiveGrabDeviceRequest = cdef::XCBInputXiPassiveGrabDeviceRequest;
+pub type XCBInputXiPassiveGrabDeviceCookie = cdef::XCBInputXiPassiveGrabDeviceCookie;
+pub type XCBInputXiPassiveGrabDeviceReply = cdef::XCBInputXiPassiveGrabDeviceReply;
+pub type XCBInputXiPassiveUngrabDeviceRequest = cdef::XCBInputXiPassiveUngrabDeviceRequest;
+pub type XCBInputXiListPropertiesRequest = cdef::XCBInputXiListPropertiesRequest;
+pub type XCBInputXiListPropertiesCookie = cdef::XCBInputXiListPropertiesCookie;
+pub type XCBInputXiListPropertiesReply = cdef::XCBInputXiListPropertiesReply;
+pub type XCBInputXiChangePropertyRequest = cdef::XCBInputXiChangePropertyRequest;
+pub type XCBInputXiDeletePropertyRequest = cdef::XCBInputXiDeletePropertyRequest;
+pub type XCBInputXiGetPropertyRequest = cdef::XCBInputXiGetPropertyRequest;
+pub type XCBInputXiGetPropertyCookie = cdef::XCBInputXiGetPropertyCookie;
+pub type XCBInputXiGetPropertyReply = cdef::XCBInputXiGetPropertyReply;
+pub type XCBInputXiGetSelectedEventsRequest = cdef::XCBInputXiGetSelectedEventsRequest;
+pub type XCBInputXiGetSelectedEventsCookie = cdef::XCBInputXiGetSelectedEventsCookie;
+pub type XCBInputXiGetSelectedEventsReply = cdef::XCBInputXiGetSelectedEventsReply;
+pub type XCBInputBarrierReleasePointerInfo = cdef::XCBInputBarrierReleasePointerInfo;
+pub type XCBInputBarrierReleasePointerInfoIterator =
+    cdef::XCBInputBarrierReleasePointerInfoIterator;
+pub type XCBInputXiBarrierReleasePointerRequest = cdef::XCBInputXiBarrierReleasePointerRequest;
+pub type XCBInputDeviceValuatorEvent = cdef::XCBInputDeviceValuatorEvent;
+pub type XCBInputDeviceKeyPressEvent = cdef::XCBInputDeviceKeyPressEvent;
+pub type XCBInputDeviceKeyReleaseEvent = cdef::XCBInputDeviceKeyReleaseEvent;
+pub type XCBInputDeviceButtonPressEvent = cdef::XCBInputDeviceButtonPressEvent;
+pub type XCBInputDeviceButtonReleaseEvent = cdef::XCBInputDeviceButtonReleaseEvent;
+pub type XCBInputDeviceMotionNotifyEvent = cdef::XCBInputDeviceMotionNotifyEvent;
+pub type XCBInputDeviceFocusInEvent = cdef::XCBInputDeviceFocusInEvent;
+pub type XCBInputDeviceFocusOutEvent = cdef::XCBInputDeviceFocusOutEvent;
+pub type XCBInputProximityInEvent = cdef::XCBInputProximityInEvent;
+pub type XCBInputProximityOutEvent = cdef::XCBInputProximityOutEvent;
+pub type XCBInputDeviceStateNotifyEvent = cdef::XCBInputDeviceStateNotifyEvent;
+pub type XCBInputDeviceMappingNotifyEvent = cdef::XCBInputDeviceMappingNotifyEvent;
+pub type XCBInputChangeDeviceNotifyEvent = cdef::XCBInputChangeDeviceNotifyEvent;
+pub type XCBInputDeviceKeyStateNotifyEvent = cdef::XCBInputDeviceKeyStateNotifyEvent;
+pub type XCBInputDeviceButtonStateNotifyEvent = cdef::XCBInputDeviceButtonStateNotifyEvent;
+pub type XCBInputDevicePresenceNotifyEvent = cdef::XCBInputDevicePresenceNotifyEvent;
+pub type XCBInputDevicePropertyNotifyEvent = cdef::XCBInputDevicePropertyNotifyEvent;
+pub type XCBInputDeviceChangedEvent = cdef::XCBInputDeviceChangedEvent;
+pub type XCBInputKeyPressEvent = cdef::XCBInputKeyPressEvent;
+pub type XCBInputKeyReleaseEvent = cdef::XCBInputKeyReleaseEvent;
+pub type XCBInputButtonPressEvent = cdef::XCBInputButtonPressEvent;
+pub type XCBInputButtonReleaseEvent = cdef::XCBInputButtonReleaseEvent;
+pub type XCBInputMotionEvent = cdef::XCBInputMotionEvent;
+pub type XCBInputEnterEvent = cdef::XCBInputEnterEvent;
+pub type XCBInputLeaveEvent = cdef::XCBInputLeaveEvent;
+pub type XCBInputFocusInEvent = cdef::XCBInputFocusInEvent;
+pub type XCBInputFocusOutEvent = cdef::XCBInputFocusOutEvent;
+pub type XCBInputHierarchyInfo = cdef::XCBInputHierarchyInfo;
+pub type XCBInputHierarchyInfoIterator = cdef::XCBInputHierarchyInfoIterator;
+pub type XCBInputHierarchyEvent = cdef::XCBInputHierarchyEvent;
+pub type XCBInputPropertyEvent = cdef::XCBInputPropertyEvent;
+pub type XCBInputRawKeyPressEvent = cdef::XCBInputRawKeyPressEvent;
+pub type XCBInputRawKeyReleaseEvent = cdef::XCBInputRawKeyReleaseEvent;
+pub type XCBInputRawButtonPressEvent = cdef::XCBInputRawButtonPressEvent;
+pub type XCBInputRawButtonReleaseEvent = cdef::XCBInputRawButtonReleaseEvent;
+pub type XCBInputRawMotionEvent = cdef::XCBInputRawMotionEvent;
+pub type XCBInputTouchBeginEvent = cdef::XCBInputTouchBeginEvent;
+pub type XCBInputTouchUpdateEvent = cdef::XCBInputTouchUpdateEvent;
+pub type XCBInputTouchEndEvent = cdef::XCBInputTouchEndEvent;
+pub type XCBInputTouchOwnershipEvent = cdef::XCBInputTouchOwnershipEvent;
+pub type XCBInputRawTouchBeginEvent = cdef::XCBInputRawTouchBeginEvent;
+pub type XCBInputRawTouchUpdateEvent = cdef::XCBInputRawTouchUpdateEvent;
+pub type XCBInputRawTouchEndEvent = cdef::XCBInputRawTouchEndEvent;
+pub type XCBInputBarrierHitEvent = cdef::XCBInputBarrierHitEvent;
+pub type XCBInputBarrierLeaveEvent = cdef::XCBInputBarrierLeaveEvent;
+pub type XCBInputEventForSend = cdef::XCBInputEventForSend;
+pub type XCBInputEventForSendIterator = cdef::XCBInputEventForSendIterator;
+pub type XCBInputSendExtensionEventRequest = cdef::XCBInputSendExtensionEventRequest;
+pub type XCBInputDeviceError = cdef::XCBInputDeviceError;
+pub type XCBInputEventError = cdef::XCBInputEventError;
+pub type XCBInputModeError = cdef::XCBInputModeError;
+pub type XCBInputDeviceBusyError = cdef::XCBInputDeviceBusyError;
+pub type XCBInputClassError = cdef::XCBInputClassError;
+
+/// The Input extension, known to the server as `XInputExtension`.
+pub struct Xinput;
+
+impl Extension for Xinput {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_input_id)
+    }
+}
+
+pub fn xcb_input_event_class_next(iter: &mut XCBInputEventClassIterator) {
+    unsafe { cdef::xcb_input_event_class_next(iter) }
+}
+
+pub fn xcb_input_key_code_next(iter: &mut XCBInputKeyCodeIterator) {
+    unsafe { cdef::xcb_input_key_code_next(iter) }
+}
+
+pub fn xcb_input_device_id_next(iter: &mut XCBInputDeviceIdIterator) {
+    unsafe { cdef::xcb_input_device_id_next(iter) }
+}
+
+pub fn xcb_input_fp1616_next(iter: &mut XCBInputFp1616Iterator) {
+    unsafe { cdef::xcb_input_fp1616_next(iter) }
+}
+
+pub fn xcb_input_fp3232_next(iter: &mut XCBInputFp3232Iterator) {
+    unsafe { cdef::xcb_input_fp3232_next(iter) }
+}
+
+impl Reply for XCBInputGetExtensionVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_get_extension_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_get_extension_version(
+    connection: &XCBConnection,
+    name: &[u8],
+) -> XCBInputGetExtensionVersionCookie {
+    unsafe {
+        cdef::xcb_input_get_extension_version(
+            connection.raw,
+            name.len() as u16,
+            name.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_input_device_info_next(iter: &mut XCBInputDeviceInfoIterator) {
+    unsafe { cdef::xcb_input_device_info_next(iter) }
+}
+
+pub fn xcb_input_key_info_next(iter: &mut XCBInputKeyInfoIterator) {
+    unsafe { cdef::xcb_input_key_info_next(iter) }
+}
+
+pub fn xcb_input_button_info_next(iter: &mut XCBInputButtonInfoIterator) {
+    unsafe { cdef::xcb_input_button_info_next(iter) }
+}
+
+pub fn xcb_input_axis_info_next(iter: &mut XCBInputAxisInfoIterator) {
+    unsafe { cdef::xcb_input_axis_info_next(iter) }
+}
+
+pub fn xcb_input_valuator_info_next(iter: &mut XCBInputValuatorInfoIterator) {
+    unsafe { cdef::xcb_input_valuator_info_next(iter) }
+}
+
+pub fn xcb_input_valuator_info_axes(data: &XCBInputValuatorInfo) -> &[XCBInputAxisInfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_valuator_info_axes(data),
+            cdef::xcb_input_valuator_info_axes_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_input_info_next(iter: &mut XCBInputInputInfoIterator) {
+    unsafe { cdef::xcb_input_input_info_next(iter) }
+}
+
+pub fn xcb_input_device_name_next(iter: &mut XCBInputDeviceNameIterator) {
+    unsafe { cdef::xcb_input_device_name_next(iter) }
+}
+
+pub fn xcb_input_device_name_string(data: &XCBInputDeviceName) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_device_name_string(data) as *const u8,
+            cdef::xcb_input_device_name_string_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_list_input_devices_devices(
+    reply: &XCBInputListInputDevicesReply,
+) -> &[XCBInputDeviceInfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_list_input_devices_devices(reply),
+            cdef::xcb_input_list_input_devices_devices_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_list_input_devices_infos_iterator(
+    reply: &XCBInputListInputDevicesReply,
+) -> XCBInputInputInfoIterator {
+    unsafe { cdef::xcb_input_list_input_devices_infos_iterator(reply) }
+}
+
+pub fn xcb_input_list_input_devices_names_iterator(
+    reply: &XCBInputListInputDevicesReply,
+) -> XCBStrIterator {
+    unsafe { cdef::xcb_input_list_input_devices_names_iterator(reply) }
+}
+
+impl Reply for XCBInputListInputDevicesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_list_input_devices_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_list_input_devices(connection: &XCBConnection) -> XCBInputListInputDevicesCookie {
+    unsafe { cdef::xcb_input_list_input_devices(connection.raw) }
+}
+
+pub fn xcb_input_event_type_base_next(iter: &mut XCBInputEventTypeBaseIterator) {
+    unsafe { cdef::xcb_input_event_type_base_next(iter) }
+}
+
+pub fn xcb_input_input_class_info_next(iter: &mut XCBInputInputClassInfoIterator) {
+    unsafe { cdef::xcb_input_input_class_info_next(iter) }
+}
+
+pub fn xcb_input_open_device_class_info(
+    reply: &XCBInputOpenDeviceReply,
+) -> &[XCBInputInputClassInfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_open_device_class_info(reply),
+            cdef::xcb_input_open_device_class_info_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBInputOpenDeviceReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_open_device_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_open_device(
+    connection: &XCBConnection,
+    device_id: u8,
+) -> XCBInputOpenDeviceCookie {
+    unsafe { cdef::xcb_input_open_device(connection.raw, device_id) }
+}
+
+pub fn xcb_input_close_device(connection: &XCBConnection, device_id: u8) -> XCBVoidCookie {
+    unsafe { cdef::xcb_input_close_device(connection.raw, device_id) }
+}
+
+impl Reply for XCBInputSetDeviceModeReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_set_device_mode_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_set_device_mode(
+    connection: &XCBConnection,
+    device_id: u8,
+    mode: u8,
+) -> XCBInputSetDeviceModeCookie {
+    unsafe { cdef::xcb_input_set_device_mode(connection.raw, device_id, mode) }
+}
+
+pub fn xcb_input_select_extension_event(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    classes: &[XCBInputEventClass],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_select_extension_event(
+            connection.raw,
+            window,
+            classes.len() as u16,
+            classes.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_get_selected_extension_events_this_classes(
+    reply: &XCBInputGetSelectedExtensionEventsReply,
+) -> &[XCBInputEventClass] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_get_selected_extension_events_this_classes(reply),
+            cdef::xcb_input_get_selected_extension_events_this_classes_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_get_selected_extension_events_all_classes(
+    reply: &XCBInputGetSelectedExtensionEventsReply,
+) -> &[XCBInputEventClass] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_get_selected_extension_events_all_classes(reply),
+            cdef::xcb_input_get_selected_extension_events_all_classes_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBInputGetSelectedExtensionEventsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_get_selected_extension_events_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_get_selected_extension_events(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBInputGetSelectedExtensionEventsCookie {
+    unsafe { cdef::xcb_input_get_selected_extension_events(connection.raw, window) }
+}
+
+pub fn xcb_input_change_device_dont_propagate_list(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    mode: u8,
+    classes: &[XCBInputEventClass],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_change_device_dont_propagate_list(
+            connection.raw,
+            window,
+            classes.len() as u16,
+            mode,
+            classes.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_get_device_dont_propagate_list_classes(
+    reply: &XCBInputGetDeviceDontPropagateListReply,
+) -> &[XCBInputEventClass] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_get_device_dont_propagate_list_classes(reply),
+            cdef::xcb_input_get_device_dont_propagate_list_classes_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBInputGetDeviceDontPropagateListReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_get_device_dont_propagate_list_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_get_device_dont_propagate_list(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBInputGetDeviceDontPropagateListCookie {
+    unsafe { cdef::xcb_input_get_device_dont_propagate_list(connection.raw, window) }
+}
+
+pub fn xcb_input_device_time_coord_next(iter: &mut XCBInputDeviceTimeCoordIterator) {
+    unsafe { cdef::xcb_input_device_time_coord_next(iter) }
+}
+
+pub fn xcb_input_device_time_coord_axisvalues(data: &XCBInputDeviceTimeCoord) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_device_time_coord_axisvalues(data),
+            cdef::xcb_input_device_time_coord_axisvalues_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_get_device_motion_events_events_iterator(
+    reply: &XCBInputGetDeviceMotionEventsReply,
+) -> XCBInputDeviceTimeCoordIterator {
+    unsafe { cdef::xcb_input_get_device_motion_events_events_iterator(reply) }
+}
+
+impl Reply for XCBInputGetDeviceMotionEventsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_get_device_motion_events_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_get_device_motion_events(
+    connection: &XCBConnection,
+    start: XCBTimestamp,
+    stop: XCBTimestamp,
+    device_id: u8,
+) -> XCBInputGetDeviceMotionEventsCookie {
+    unsafe { cdef::xcb_input_get_device_motion_events(connection.raw, start, stop, device_id) }
+}
+
+impl Reply for XCBInputChangeKeyboardDeviceReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_change_keyboard_device_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_change_keyboard_device(
+    connection: &XCBConnection,
+    device_id: u8,
+) -> XCBInputChangeKeyboardDeviceCookie {
+    unsafe { cdef::xcb_input_change_keyboard_device(connection.raw, device_id) }
+}
+
+impl Reply for XCBInputChangePointerDeviceReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_change_pointer_device_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_change_pointer_device(
+    connection: &XCBConnection,
+    x_axis: u8,
+    y_axis: u8,
+    device_id: u8,
+) -> XCBInputChangePointerDeviceCookie {
+    unsafe { cdef::xcb_input_change_pointer_device(connection.raw, x_axis, y_axis, device_id) }
+}
+
+impl Reply for XCBInputGrabDeviceReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_grab_device_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_grab_device(
+    connection: &XCBConnection,
+    grab_window: XCBWindow,
+    time: XCBTimestamp,
+    this_device_mode: u8,
+    other_device_mode: u8,
+    owner_events: bool,
+    device_id: u8,
+    classes: &[XCBInputEventClass],
+) -> XCBInputGrabDeviceCookie {
+    unsafe {
+        cdef::xcb_input_grab_device(
+            connection.raw,
+            grab_window,
+            time,
+            classes.len() as u16,
+            this_device_mode,
+            other_device_mode,
+            owner_events as u8,
+            device_id,
+            classes.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_ungrab_device(
+    connection: &XCBConnection,
+    time: XCBTimestamp,
+    device_id: u8,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_input_ungrab_device(connection.raw, time, device_id) }
+}
+
+pub fn xcb_input_grab_device_key(
+    connection: &XCBConnection,
+    grab_window: XCBWindow,
+    modifiers: u16,
+    modifier_device: u8,
+    grabbed_device: u8,
+    key: u8,
+    this_device_mode: u8,
+    other_device_mode: u8,
+    owner_events: bool,
+    classes: &[XCBInputEventClass],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_grab_device_key(
+            connection.raw,
+            grab_window,
+            classes.len() as u16,
+            modifiers,
+            modifier_device,
+            grabbed_device,
+            key,
+            this_device_mode,
+            other_device_mode,
+            owner_events as u8,
+            classes.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_ungrab_device_key(
+    connection: &XCBConnection,
+    grabWindow: XCBWindow,
+    modifiers: u16,
+    modifier_device: u8,
+    key: u8,
+    grabbed_device: u8,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_ungrab_device_key(
+            connection.raw,
+            grabWindow,
+            modifiers,
+            modifier_device,
+            key,
+            grabbed_device,
+        )
+    }
+}
+
+pub fn xcb_input_grab_device_button(
+    connection: &XCBConnection,
+    grab_window: XCBWindow,
+    grabbed_device: u8,
+    modifier_device: u8,
+    modifiers: u16,
+    this_device_mode: u8,
+    other_device_mode: u8,
+    button: u8,
+    owner_events: bool,
+    classes: &[XCBInputEventClass],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_grab_device_button(
+            connection.raw,
+            grab_window,
+            grabbed_device,
+            modifier_device,
+            classes.len() as u16,
+            modifiers,
+            this_device_mode,
+            other_device_mode,
+            button,
+            owner_events as u8,
+            classes.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_ungrab_device_button(
+    connection: &XCBConnection,
+    grab_window: XCBWindow,
+    modifiers: u16,
+    modifier_device: u8,
+    button: u8,
+    grabbed_device: u8,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_ungrab_device_button(
+            connection.raw,
+            grab_window,
+            modifiers,
+            modifier_device,
+            button,
+            grabbed_device,
+        )
+    }
+}
+
+pub fn xcb_input_allow_device_events(
+    connection: &XCBConnection,
+    time: XCBTimestamp,
+    mode: u8,
+    device_id: u8,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_input_allow_device_events(connection.raw, time, mode, device_id) }
+}
+
+impl Reply for XCBInputGetDeviceFocusReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_get_device_focus_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_get_device_focus(
+    connection: &XCBConnection,
+    device_id: u8,
+) -> XCBInputGetDeviceFocusCookie {
+    unsafe { cdef::xcb_input_get_device_focus(connection.raw, device_id) }
+}
+
+pub fn xcb_input_set_device_focus(
+    connection: &XCBConnection,
+    focus: XCBWindow,
+    time: XCBTimestamp,
+    revert_to: u8,
+    device_id: u8,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_input_set_device_focus(connection.raw, focus, time, revert_to, device_id) }
+}
+
+pub fn xcb_input_kbd_feedback_state_next(iter: &mut XCBInputKbdFeedbackStateIterator) {
+    unsafe { cdef::xcb_input_kbd_feedback_state_next(iter) }
+}
+
+pub fn xcb_input_ptr_feedback_state_next(iter: &mut XCBInputPtrFeedbackStateIterator) {
+    unsafe { cdef::xcb_input_ptr_feedback_state_next(iter) }
+}
+
+pub fn xcb_input_integer_feedback_state_next(iter: &mut XCBInputIntegerFeedbackStateIterator) {
+    unsafe { cdef::xcb_input_integer_feedback_state_next(iter) }
+}
+
+pub fn xcb_input_string_feedback_state_next(iter: &mut XCBInputStringFeedbackStateIterator) {
+    unsafe { cdef::xcb_input_string_feedback_state_next(iter) }
+}
+
+pub fn xcb_input_string_feedback_state_keysyms(data: &XCBInputStringFeedbackState) -> &[XCBKeysym] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_string_feedback_state_keysyms(data),
+            cdef::xcb_input_string_feedback_state_keysyms_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_bell_feedback_state_next(iter: &mut XCBInputBellFeedbackStateIterator) {
+    unsafe { cdef::xcb_input_bell_feedback_state_next(iter) }
+}
+
+pub fn xcb_input_led_feedback_state_next(iter: &mut XCBInputLedFeedbackStateIterator) {
+    unsafe { cdef::xcb_input_led_feedback_state_next(iter) }
+}
+
+pub fn xcb_input_feedback_state_next(iter: &mut XCBInputFeedbackStateIterator) {
+    unsafe { cdef::xcb_input_feedback_state_next(iter) }
+}
+
+pub fn xcb_input_get_feedback_control_feedbacks_iterator(
+    reply: &XCBInputGetFeedbackControlReply,
+) -> XCBInputFeedbackStateIterator {
+    unsafe { cdef::xcb_input_get_feedback_control_feedbacks_iterator(reply) }
+}
+
+impl Reply for XCBInputGetFeedbackControlReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_get_feedback_control_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_get_feedback_control(
+    connection: &XCBConnection,
+    device_id: u8,
+) -> XCBInputGetFeedbackControlCookie {
+    unsafe { cdef::xcb_input_get_feedback_control(connection.raw, device_id) }
+}
+
+pub fn xcb_input_kbd_feedback_ctl_next(iter: &mut XCBInputKbdFeedbackCtlIterator) {
+    unsafe { cdef::xcb_input_kbd_feedback_ctl_next(iter) }
+}
+
+pub fn xcb_input_ptr_feedback_ctl_next(iter: &mut XCBInputPtrFeedbackCtlIterator) {
+    unsafe { cdef::xcb_input_ptr_feedback_ctl_next(iter) }
+}
+
+pub fn xcb_input_integer_feedback_ctl_next(iter: &mut XCBInputIntegerFeedbackCtlIterator) {
+    unsafe { cdef::xcb_input_integer_feedback_ctl_next(iter) }
+}
+
+pub fn xcb_input_string_feedback_ctl_next(iter: &mut XCBInputStringFeedbackCtlIterator) {
+    unsafe { cdef::xcb_input_string_feedback_ctl_next(iter) }
+}
+
+pub fn xcb_input_string_feedback_ctl_keysyms(data: &XCBInputStringFeedbackCtl) -> &[XCBKeysym] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_string_feedback_ctl_keysyms(data),
+            cdef::xcb_input_string_feedback_ctl_keysyms_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_bell_feedback_ctl_next(iter: &mut XCBInputBellFeedbackCtlIterator) {
+    unsafe { cdef::xcb_input_bell_feedback_ctl_next(iter) }
+}
+
+pub fn xcb_input_led_feedback_ctl_next(iter: &mut XCBInputLedFeedbackCtlIterator) {
+    unsafe { cdef::xcb_input_led_feedback_ctl_next(iter) }
+}
+
+pub fn xcb_input_feedback_ctl_next(iter: &mut XCBInputFeedbackCtlIterator) {
+    unsafe { cdef::xcb_input_feedback_ctl_next(iter) }
+}
+
+pub fn xcb_input_get_device_key_mapping_keysyms(
+    reply: &XCBInputGetDeviceKeyMappingReply,
+) -> &[XCBKeysym] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_get_device_key_mapping_keysyms(reply),
+            cdef::xcb_input_get_device_key_mapping_keysyms_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBInputGetDeviceKeyMappingReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_get_device_key_mapping_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_get_device_key_mapping(
+    connection: &XCBConnection,
+    device_id: u8,
+    first_keycode: XCBInputKeyCode,
+    count: u8,
+) -> XCBInputGetDeviceKeyMappingCookie {
+    unsafe {
+        cdef::xcb_input_get_device_key_mapping(connection.raw, device_id, first_keycode, count)
+    }
+}
+
+pub fn xcb_input_change_device_key_mapping(
+    connection: &XCBConnection,
+    device_id: u8,
+    first_keycode: XCBInputKeyCode,
+    keysyms_per_keycode: u8,
+    keycode_count: u8,
+    keysyms: &[XCBKeysym],
+) -> XCBVoidCookie {
+    assert!(keysyms.len() >= (keycode_count as usize * keysyms_per_keycode as usize));
+    unsafe {
+        cdef::xcb_input_change_device_key_mapping(
+            connection.raw,
+            device_id,
+            first_keycode,
+            keysyms_per_keycode,
+            keycode_count,
+            keysyms.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_get_device_modifier_mapping_keymaps(
+    reply: &XCBInputGetDeviceModifierMappingReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_get_device_modifier_mapping_keymaps(reply),
+            cdef::xcb_input_get_device_modifier_mapping_keymaps_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBInputGetDeviceModifierMappingReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_get_device_modifier_mapping_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_get_device_modifier_mapping(
+    connection: &XCBConnection,
+    device_id: u8,
+) -> XCBInputGetDeviceModifierMappingCookie {
+    unsafe { cdef::xcb_input_get_device_modifier_mapping(connection.raw, device_id) }
+}
+
+impl Reply for XCBInputSetDeviceModifierMappingReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_set_device_modifier_mapping_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_set_device_modifier_mapping(
+    connection: &XCBConnection,
+    device_id: u8,
+    keycodes_per_modifier: u8,
+    keymaps: &[u8],
+) -> XCBInputSetDeviceModifierMappingCookie {
+    assert!(keymaps.len() >= (keycodes_per_modifier as usize * 8));
+    unsafe {
+        cdef::xcb_input_set_device_modifier_mapping(
+            connection.raw,
+            device_id,
+            keycodes_per_modifier,
+            keymaps.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_get_device_button_mapping_map(
+    reply: &XCBInputGetDeviceButtonMappingReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_get_device_button_mapping_map(reply),
+            cdef::xcb_input_get_device_button_mapping_map_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBInputGetDeviceButtonMappingReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_get_device_button_mapping_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_get_device_button_mapping(
+    connection: &XCBConnection,
+    device_id: u8,
+) -> XCBInputGetDeviceButtonMappingCookie {
+    unsafe { cdef::xcb_input_get_device_button_mapping(connection.raw, device_id) }
+}
+
+impl Reply for XCBInputSetDeviceButtonMappingReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_set_device_button_mapping_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_set_device_button_mapping(
+    connection: &XCBConnection,
+    device_id: u8,
+    map: &[u8],
+) -> XCBInputSetDeviceButtonMappingCookie {
+    unsafe {
+        cdef::xcb_input_set_device_button_mapping(
+            connection.raw,
+            device_id,
+            map.len() as u8,
+            map.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_key_state_next(iter: &mut XCBInputKeyStateIterator) {
+    unsafe { cdef::xcb_input_key_state_next(iter) }
+}
+
+pub fn xcb_input_button_state_next(iter: &mut XCBInputButtonStateIterator) {
+    unsafe { cdef::xcb_input_button_state_next(iter) }
+}
+
+pub fn xcb_input_valuator_state_next(iter: &mut XCBInputValuatorStateIterator) {
+    unsafe { cdef::xcb_input_valuator_state_next(iter) }
+}
+
+pub fn xcb_input_valuator_state_valuators(data: &XCBInputValuatorState) -> &[i32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_valuator_state_valuators(data),
+            cdef::xcb_input_valuator_state_valuators_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_input_state_next(iter: &mut XCBInputInputStateIterator) {
+    unsafe { cdef::xcb_input_input_state_next(iter) }
+}
+
+pub fn xcb_input_query_device_state_classes_iterator(
+    reply: &XCBInputQueryDeviceStateReply,
+) -> XCBInputInputStateIterator {
+    unsafe { cdef::xcb_input_query_device_state_classes_iterator(reply) }
+}
+
+impl Reply for XCBInputQueryDeviceStateReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_query_device_state_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_query_device_state(
+    connection: &XCBConnection,
+    device_id: u8,
+) -> XCBInputQueryDeviceStateCookie {
+    unsafe { cdef::xcb_input_query_device_state(connection.raw, device_id) }
+}
+
+pub fn xcb_input_device_bell(
+    connection: &XCBConnection,
+    device_id: u8,
+    feedback_id: u8,
+    feedback_class: u8,
+    percent: i8,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_device_bell(
+            connection.raw,
+            device_id,
+            feedback_id,
+            feedback_class,
+            percent,
+        )
+    }
+}
+
+impl Reply for XCBInputSetDeviceValuatorsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_set_device_valuators_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_set_device_valuators(
+    connection: &XCBConnection,
+    device_id: u8,
+    first_valuator: u8,
+    valuators: &[i32],
+) -> XCBInputSetDeviceValuatorsCookie {
+    unsafe {
+        cdef::xcb_input_set_device_valuators(
+            connection.raw,
+            device_id,
+            first_valuator,
+            valuators.len() as u8,
+            valuators.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_device_resolution_state_next(iter: &mut XCBInputDeviceResolutionStateIterator) {
+    unsafe { cdef::xcb_input_device_resolution_state_next(iter) }
+}
+
+pub fn xcb_input_device_resolution_state_resolution_values(
+    data: &XCBInputDeviceResolutionState,
+) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_device_resolution_state_resolution_values(data),
+            cdef::xcb_input_device_resolution_state_resolution_values_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_device_resolution_state_resolution_min(
+    data: &XCBInputDeviceResolutionState,
+) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_device_resolution_state_resolution_min(data),
+            cdef::xcb_input_device_resolution_state_resolution_min_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_device_resolution_state_resolution_max(
+    data: &XCBInputDeviceResolutionState,
+) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_device_resolution_state_resolution_max(data),
+            cdef::xcb_input_device_resolution_state_resolution_max_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_device_abs_calib_state_next(iter: &mut XCBInputDeviceAbsCalibStateIterator) {
+    unsafe { cdef::xcb_input_device_abs_calib_state_next(iter) }
+}
+
+pub fn xcb_input_device_abs_area_state_next(iter: &mut XCBInputDeviceAbsAreaStateIterator) {
+    unsafe { cdef::xcb_input_device_abs_area_state_next(iter) }
+}
+
+pub fn xcb_input_device_core_state_next(iter: &mut XCBInputDeviceCoreStateIterator) {
+    unsafe { cdef::xcb_input_device_core_state_next(iter) }
+}
+
+pub fn xcb_input_device_enable_state_next(iter: &mut XCBInputDeviceEnableStateIterator) {
+    unsafe { cdef::xcb_input_device_enable_state_next(iter) }
+}
+
+pub fn xcb_input_device_state_next(iter: &mut XCBInputDeviceStateIterator) {
+    unsafe { cdef::xcb_input_device_state_next(iter) }
+}
+
+impl Reply for XCBInputGetDeviceControlReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_get_device_control_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_get_device_control(
+    connection: &XCBConnection,
+    control_id: u16,
+    device_id: u8,
+) -> XCBInputGetDeviceControlCookie {
+    unsafe { cdef::xcb_input_get_device_control(connection.raw, control_id, device_id) }
+}
+
+pub fn xcb_input_device_resolution_ctl_next(iter: &mut XCBInputDeviceResolutionCtlIterator) {
+    unsafe { cdef::xcb_input_device_resolution_ctl_next(iter) }
+}
+
+pub fn xcb_input_device_resolution_ctl_resolution_values(
+    data: &XCBInputDeviceResolutionCtl,
+) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_device_resolution_ctl_resolution_values(data),
+            cdef::xcb_input_device_resolution_ctl_resolution_values_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_device_abs_calib_ctl_next(iter: &mut XCBInputDeviceAbsCalibCtlIterator) {
+    unsafe { cdef::xcb_input_device_abs_calib_ctl_next(iter) }
+}
+
+pub fn xcb_input_device_abs_area_ctrl_next(iter: &mut XCBInputDeviceAbsAreaCtrlIterator) {
+    unsafe { cdef::xcb_input_device_abs_area_ctrl_next(iter) }
+}
+
+pub fn xcb_input_device_core_ctrl_next(iter: &mut XCBInputDeviceCoreCtrlIterator) {
+    unsafe { cdef::xcb_input_device_core_ctrl_next(iter) }
+}
+
+pub fn xcb_input_device_enable_ctrl_next(iter: &mut XCBInputDeviceEnableCtrlIterator) {
+    unsafe { cdef::xcb_input_device_enable_ctrl_next(iter) }
+}
+
+pub fn xcb_input_device_ctl_next(iter: &mut XCBInputDeviceCtlIterator) {
+    unsafe { cdef::xcb_input_device_ctl_next(iter) }
+}
+
+impl Reply for XCBInputChangeDeviceControlReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_change_device_control_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_list_device_properties_atoms(
+    reply: &XCBInputListDevicePropertiesReply,
+) -> &[XCBAtom] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_list_device_properties_atoms(reply),
+            cdef::xcb_input_list_device_properties_atoms_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBInputListDevicePropertiesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_list_device_properties_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_list_device_properties(
+    connection: &XCBConnection,
+    device_id: u8,
+) -> XCBInputListDevicePropertiesCookie {
+    unsafe { cdef::xcb_input_list_device_properties(connection.raw, device_id) }
+}
+
+pub fn xcb_input_delete_device_property(
+    connection: &XCBConnection,
+    property: XCBAtom,
+    device_id: u8,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_input_delete_device_property(connection.raw, property, device_id) }
+}
+
+impl Reply for XCBInputGetDevicePropertyReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_get_device_property_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_get_device_property(
+    connection: &XCBConnection,
+    property: XCBAtom,
+    type_: XCBAtom,
+    offset: u32,
+    len: u32,
+    device_id: u8,
+    delete: bool,
+) -> XCBInputGetDevicePropertyCookie {
+    unsafe {
+        cdef::xcb_input_get_device_property(
+            connection.raw,
+            property,
+            type_,
+            offset,
+            len,
+            device_id,
+            delete as u8,
+        )
+    }
+}
+
+pub fn xcb_input_group_info_next(iter: &mut XCBInputGroupInfoIterator) {
+    unsafe { cdef::xcb_input_group_info_next(iter) }
+}
+
+pub fn xcb_input_modifier_info_next(iter: &mut XCBInputModifierInfoIterator) {
+    unsafe { cdef::xcb_input_modifier_info_next(iter) }
+}
+
+pub fn xcb_input_xi_query_pointer_buttons(reply: &XCBInputXiQueryPointerReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_xi_query_pointer_buttons(reply),
+            cdef::xcb_input_xi_query_pointer_buttons_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBInputXiQueryPointerReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_xi_query_pointer_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_xi_query_pointer(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    deviceid: XCBInputDeviceId,
+) -> XCBInputXiQueryPointerCookie {
+    unsafe { cdef::xcb_input_xi_query_pointer(connection.raw, window, deviceid) }
+}
+
+pub fn xcb_input_xi_warp_pointer(
+    connection: &XCBConnection,
+    src_win: XCBWindow,
+    dst_win: XCBWindow,
+    src_x: XCBInputFp1616,
+    src_y: XCBInputFp1616,
+    src_width: u16,
+    src_height: u16,
+    dst_x: XCBInputFp1616,
+    dst_y: XCBInputFp1616,
+    deviceid: XCBInputDeviceId,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_xi_warp_pointer(
+            connection.raw,
+            src_win,
+            dst_win,
+            src_x,
+            src_y,
+            src_width,
+            src_height,
+            dst_x,
+            dst_y,
+            deviceid,
+        )
+    }
+}
+
+pub fn xcb_input_xi_change_cursor(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    cursor: XCBCursor,
+    deviceid: XCBInputDeviceId,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_input_xi_change_cursor(connection.raw, window, cursor, deviceid) }
+}
+
+pub fn xcb_input_add_master_next(iter: &mut XCBInputAddMasterIterator) {
+    unsafe { cdef::xcb_input_add_master_next(iter) }
+}
+
+pub fn xcb_input_add_master_name(data: &XCBInputAddMaster) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_add_master_name(data) as *const u8,
+            cdef::xcb_input_add_master_name_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_remove_master_next(iter: &mut XCBInputRemoveMasterIterator) {
+    unsafe { cdef::xcb_input_remove_master_next(iter) }
+}
+
+pub fn xcb_input_attach_slave_next(iter: &mut XCBInputAttachSlaveIterator) {
+    unsafe { cdef::xcb_input_attach_slave_next(iter) }
+}
+
+pub fn xcb_input_detach_slave_next(iter: &mut XCBInputDetachSlaveIterator) {
+    unsafe { cdef::xcb_input_detach_slave_next(iter) }
+}
+
+pub fn xcb_input_hierarchy_change_next(iter: &mut XCBInputHierarchyChangeIterator) {
+    unsafe { cdef::xcb_input_hierarchy_change_next(iter) }
+}
+
+pub fn xcb_input_xi_set_client_pointer(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    deviceid: XCBInputDeviceId,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_input_xi_set_client_pointer(connection.raw, window, deviceid) }
+}
+
+impl Reply for XCBInputXiGetClientPointerReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_xi_get_client_pointer_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_xi_get_client_pointer(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBInputXiGetClientPointerCookie {
+    unsafe { cdef::xcb_input_xi_get_client_pointer(connection.raw, window) }
+}
+
+pub fn xcb_input_event_mask_next(iter: &mut XCBInputEventMaskIterator) {
+    unsafe { cdef::xcb_input_event_mask_next(iter) }
+}
+
+pub fn xcb_input_event_mask_mask(data: &XCBInputEventMask) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_event_mask_mask(data),
+            cdef::xcb_input_event_mask_mask_length(data) as usize,
+        )
+    }
+}
+
+impl Reply for XCBInputXiQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_xi_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_xi_query_version(
+    connection: &XCBConnection,
+    major_version: u16,
+    minor_version: u16,
+) -> XCBInputXiQueryVersionCookie {
+    unsafe { cdef::xcb_input_xi_query_version(connection.raw, major_version, minor_version) }
+}
+
+pub fn xcb_input_button_class_next(iter: &mut XCBInputButtonClassIterator) {
+    unsafe { cdef::xcb_input_button_class_next(iter) }
+}
+
+pub fn xcb_input_button_class_state(data: &XCBInputButtonClass) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_button_class_state(data),
+            cdef::xcb_input_button_class_state_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_button_class_labels(data: &XCBInputButtonClass) -> &[XCBAtom] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_button_class_labels(data),
+            cdef::xcb_input_button_class_labels_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_key_class_next(iter: &mut XCBInputKeyClassIterator) {
+    unsafe { cdef::xcb_input_key_class_next(iter) }
+}
+
+pub fn xcb_input_key_class_keys(data: &XCBInputKeyClass) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_key_class_keys(data),
+            cdef::xcb_input_key_class_keys_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_scroll_class_next(iter: &mut XCBInputScrollClassIterator) {
+    unsafe { cdef::xcb_input_scroll_class_next(iter) }
+}
+
+pub fn xcb_input_touch_class_next(iter: &mut XCBInputTouchClassIterator) {
+    unsafe { cdef::xcb_input_touch_class_next(iter) }
+}
+
+pub fn xcb_input_valuator_class_next(iter: &mut XCBInputValuatorClassIterator) {
+    unsafe { cdef::xcb_input_valuator_class_next(iter) }
+}
+
+pub fn xcb_input_device_class_next(iter: &mut XCBInputDeviceClassIterator) {
+    unsafe { cdef::xcb_input_device_class_next(iter) }
+}
+
+pub fn xcb_input_xi_device_info_next(iter: &mut XCBInputXiDeviceInfoIterator) {
+    unsafe { cdef::xcb_input_xi_device_info_next(iter) }
+}
+
+pub fn xcb_input_xi_device_info_name(data: &XCBInputXiDeviceInfo) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_xi_device_info_name(data) as *const u8,
+            cdef::xcb_input_xi_device_info_name_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_xi_device_info_classes_iterator(
+    data: &XCBInputXiDeviceInfo,
+) -> XCBInputDeviceClassIterator {
+    unsafe { cdef::xcb_input_xi_device_info_classes_iterator(data) }
+}
+
+pub fn xcb_input_xi_query_device_infos_iterator(
+    reply: &XCBInputXiQueryDeviceReply,
+) -> XCBInputXiDeviceInfoIterator {
+    unsafe { cdef::xcb_input_xi_query_device_infos_iterator(reply) }
+}
+
+impl Reply for XCBInputXiQueryDeviceReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_xi_query_device_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_xi_query_device(
+    connection: &XCBConnection,
+    deviceid: XCBInputDeviceId,
+) -> XCBInputXiQueryDeviceCookie {
+    unsafe { cdef::xcb_input_xi_query_device(connection.raw, deviceid) }
+}
+
+pub fn xcb_input_xi_set_focus(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    time: XCBTimestamp,
+    deviceid: XCBInputDeviceId,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_input_xi_set_focus(connection.raw, window, time, deviceid) }
+}
+
+impl Reply for XCBInputXiGetFocusReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_xi_get_focus_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_xi_get_focus(
+    connection: &XCBConnection,
+    deviceid: XCBInputDeviceId,
+) -> XCBInputXiGetFocusCookie {
+    unsafe { cdef::xcb_input_xi_get_focus(connection.raw, deviceid) }
+}
+
+impl Reply for XCBInputXiGrabDeviceReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_xi_grab_device_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_xi_grab_device(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    time: XCBTimestamp,
+    cursor: XCBCursor,
+    deviceid: XCBInputDeviceId,
+    mode: u8,
+    paired_device_mode: u8,
+    owner_events: bool,
+    mask: &[u32],
+) -> XCBInputXiGrabDeviceCookie {
+    unsafe {
+        cdef::xcb_input_xi_grab_device(
+            connection.raw,
+            window,
+            time,
+            cursor,
+            deviceid,
+            mode,
+            paired_device_mode,
+            owner_events as u8,
+            mask.len() as u16,
+            mask.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_xi_ungrab_device(
+    connection: &XCBConnection,
+    time: XCBTimestamp,
+    deviceid: XCBInputDeviceId,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_input_xi_ungrab_device(connection.raw, time, deviceid) }
+}
+
+pub fn xcb_input_xi_allow_events(
+    connection: &XCBConnection,
+    time: XCBTimestamp,
+    deviceid: XCBInputDeviceId,
+    event_mode: u8,
+    touchid: u32,
+    grab_window: XCBWindow,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_xi_allow_events(
+            connection.raw,
+            time,
+            deviceid,
+            event_mode,
+            touchid,
+            grab_window,
+        )
+    }
+}
+
+pub fn xcb_input_grab_modifier_info_next(iter: &mut XCBInputGrabModifierInfoIterator) {
+    unsafe { cdef::xcb_input_grab_modifier_info_next(iter) }
+}
+
+pub fn xcb_input_xi_passive_grab_device_modifiers(
+    reply: &XCBInputXiPassiveGrabDeviceReply,
+) -> &[XCBInputGrabModifierInfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_xi_passive_grab_device_modifiers(reply),
+            cdef::xcb_input_xi_passive_grab_device_modifiers_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBInputXiPassiveGrabDeviceReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_xi_passive_grab_device_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_xi_passive_grab_device(
+    connection: &XCBConnection,
+    time: XCBTimestamp,
+    grab_window: XCBWindow,
+    cursor: XCBCursor,
+    detail: u32,
+    deviceid: XCBInputDeviceId,
+    grab_type: u8,
+    grab_mode: u8,
+    paired_device_mode: u8,
+    owner_events: bool,
+    mask: &[u32],
+    modifiers: &[u32],
+) -> XCBInputXiPassiveGrabDeviceCookie {
+    unsafe {
+        cdef::xcb_input_xi_passive_grab_device(
+            connection.raw,
+            time,
+            grab_window,
+            cursor,
+            detail,
+            deviceid,
+            modifiers.len() as u16,
+            mask.len() as u16,
+            grab_type,
+            grab_mode,
+            paired_device_mode,
+            owner_events as u8,
+            mask.as_ptr(),
+            modifiers.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_xi_passive_ungrab_device(
+    connection: &XCBConnection,
+    grab_window: XCBWindow,
+    detail: u32,
+    deviceid: XCBInputDeviceId,
+    grab_type: u8,
+    modifiers: &[u32],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_xi_passive_ungrab_device(
+            connection.raw,
+            grab_window,
+            detail,
+            deviceid,
+            modifiers.len() as u16,
+            grab_type,
+            modifiers.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_xi_list_properties_properties(
+    reply: &XCBInputXiListPropertiesReply,
+) -> &[XCBAtom] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_xi_list_properties_properties(reply),
+            cdef::xcb_input_xi_list_properties_properties_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBInputXiListPropertiesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_xi_list_properties_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_xi_list_properties(
+    connection: &XCBConnection,
+    deviceid: XCBInputDeviceId,
+) -> XCBInputXiListPropertiesCookie {
+    unsafe { cdef::xcb_input_xi_list_properties(connection.raw, deviceid) }
+}
+
+pub fn xcb_input_xi_delete_property(
+    connection: &XCBConnection,
+    deviceid: XCBInputDeviceId,
+    property: XCBAtom,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_input_xi_delete_property(connection.raw, deviceid, property) }
+}
+
+impl Reply for XCBInputXiGetPropertyReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_xi_get_property_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_xi_get_property(
+    connection: &XCBConnection,
+    deviceid: XCBInputDeviceId,
+    delete: bool,
+    property: XCBAtom,
+    type_: XCBAtom,
+    offset: u32,
+    len: u32,
+) -> XCBInputXiGetPropertyCookie {
+    unsafe {
+        cdef::xcb_input_xi_get_property(
+            connection.raw,
+            deviceid,
+            delete as u8,
+            property,
+            type_,
+            offset,
+            len,
+        )
+    }
+}
+
+pub fn xcb_input_xi_get_selected_events_masks_iterator(
+    reply: &XCBInputXiGetSelectedEventsReply,
+) -> XCBInputEventMaskIterator {
+    unsafe { cdef::xcb_input_xi_get_selected_events_masks_iterator(reply) }
+}
+
+impl Reply for XCBInputXiGetSelectedEventsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_input_xi_get_selected_events_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_input_xi_get_selected_events(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBInputXiGetSelectedEventsCookie {
+    unsafe { cdef::xcb_input_xi_get_selected_events(connection.raw, window) }
+}
+
+pub fn xcb_input_barrier_release_pointer_info_next(
+    iter: &mut XCBInputBarrierReleasePointerInfoIterator,
+) {
+    unsafe { cdef::xcb_input_barrier_release_pointer_info_next(iter) }
+}
+
+pub fn xcb_input_xi_barrier_release_pointer(
+    connection: &XCBConnection,
+    barriers: &[XCBInputBarrierReleasePointerInfo],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_xi_barrier_release_pointer(
+            connection.raw,
+            barriers.len() as u32,
+            barriers.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_input_device_changed_classes_iterator(
+    event: &XCBInputDeviceChangedEvent,
+) -> XCBInputDeviceClassIterator {
+    unsafe { cdef::xcb_input_device_changed_classes_iterator(event) }
+}
+
+pub fn xcb_input_key_press_button_mask(event: &XCBInputKeyPressEvent) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_key_press_button_mask(event),
+            cdef::xcb_input_key_press_button_mask_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_key_press_valuator_mask(event: &XCBInputKeyPressEvent) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_key_press_valuator_mask(event),
+            cdef::xcb_input_key_press_valuator_mask_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_key_press_axisvalues(event: &XCBInputKeyPressEvent) -> &[XCBInputFp3232] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_key_press_axisvalues(event),
+            cdef::xcb_input_key_press_axisvalues_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_button_press_button_mask(event: &XCBInputButtonPressEvent) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_button_press_button_mask(event),
+            cdef::xcb_input_button_press_button_mask_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_button_press_valuator_mask(event: &XCBInputButtonPressEvent) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_button_press_valuator_mask(event),
+            cdef::xcb_input_button_press_valuator_mask_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_button_press_axisvalues(event: &XCBInputButtonPressEvent) -> &[XCBInputFp3232] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_button_press_axisvalues(event),
+            cdef::xcb_input_button_press_axisvalues_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_enter_buttons(event: &XCBInputEnterEvent) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_enter_buttons(event),
+            cdef::xcb_input_enter_buttons_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_hierarchy_info_next(iter: &mut XCBInputHierarchyInfoIterator) {
+    unsafe { cdef::xcb_input_hierarchy_info_next(iter) }
+}
+
+pub fn xcb_input_hierarchy_infos(event: &XCBInputHierarchyEvent) -> &[XCBInputHierarchyInfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_hierarchy_infos(event),
+            cdef::xcb_input_hierarchy_infos_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_raw_key_press_valuator_mask(event: &XCBInputRawKeyPressEvent) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_raw_key_press_valuator_mask(event),
+            cdef::xcb_input_raw_key_press_valuator_mask_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_raw_key_press_axisvalues(event: &XCBInputRawKeyPressEvent) -> &[XCBInputFp3232] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_raw_key_press_axisvalues(event),
+            cdef::xcb_input_raw_key_press_axisvalues_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_raw_key_press_axisvalues_raw(
+    event: &XCBInputRawKeyPressEvent,
+) -> &[XCBInputFp3232] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_raw_key_press_axisvalues_raw(event),
+            cdef::xcb_input_raw_key_press_axisvalues_raw_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_raw_button_press_valuator_mask(event: &XCBInputRawButtonPressEvent) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_raw_button_press_valuator_mask(event),
+            cdef::xcb_input_raw_button_press_valuator_mask_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_raw_button_press_axisvalues(
+    event: &XCBInputRawButtonPressEvent,
+) -> &[XCBInputFp3232] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_raw_button_press_axisvalues(event),
+            cdef::xcb_input_raw_button_press_axisvalues_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_raw_button_press_axisvalues_raw(
+    event: &XCBInputRawButtonPressEvent,
+) -> &[XCBInputFp3232] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_raw_button_press_axisvalues_raw(event),
+            cdef::xcb_input_raw_button_press_axisvalues_raw_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_touch_begin_button_mask(event: &XCBInputTouchBeginEvent) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_touch_begin_button_mask(event),
+            cdef::xcb_input_touch_begin_button_mask_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_touch_begin_valuator_mask(event: &XCBInputTouchBeginEvent) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_touch_begin_valuator_mask(event),
+            cdef::xcb_input_touch_begin_valuator_mask_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_touch_begin_axisvalues(event: &XCBInputTouchBeginEvent) -> &[XCBInputFp3232] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_touch_begin_axisvalues(event),
+            cdef::xcb_input_touch_begin_axisvalues_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_raw_touch_begin_valuator_mask(event: &XCBInputRawTouchBeginEvent) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_raw_touch_begin_valuator_mask(event),
+            cdef::xcb_input_raw_touch_begin_valuator_mask_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_raw_touch_begin_axisvalues(
+    event: &XCBInputRawTouchBeginEvent,
+) -> &[XCBInputFp3232] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_raw_touch_begin_axisvalues(event),
+            cdef::xcb_input_raw_touch_begin_axisvalues_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_raw_touch_begin_axisvalues_raw(
+    event: &XCBInputRawTouchBeginEvent,
+) -> &[XCBInputFp3232] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_input_raw_touch_begin_axisvalues_raw(event),
+            cdef::xcb_input_raw_touch_begin_axisvalues_raw_length(event) as usize,
+        )
+    }
+}
+
+pub fn xcb_input_event_for_send_next(iter: &mut XCBInputEventForSendIterator) {
+    unsafe { cdef::xcb_input_event_for_send_next(iter) }
+}
+
+pub fn xcb_input_send_extension_event(
+    connection: &XCBConnection,
+    destination: XCBWindow,
+    device_id: u8,
+    propagate: bool,
+    events: &[XCBInputEventForSend],
+    classes: &[XCBInputEventClass],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_send_extension_event(
+            connection.raw,
+            destination,
+            device_id,
+            propagate as u8,
+            classes.len() as u16,
+            events.len() as u8,
+            events.as_ptr(),
+            classes.as_ptr(),
+        )
+    }
+}
+
+/// An event of the Input extension.
+pub enum Event {
+    DeviceValuator(Box<XCBInputDeviceValuatorEvent>),
+    DeviceKeyPress(Box<XCBInputDeviceKeyPressEvent>),
+    DeviceKeyRelease(Box<XCBInputDeviceKeyReleaseEvent>),
+    DeviceButtonPress(Box<XCBInputDeviceButtonPressEvent>),
+    DeviceButtonRelease(Box<XCBInputDeviceButtonReleaseEvent>),
+    DeviceMotionNotify(Box<XCBInputDeviceMotionNotifyEvent>),
+    DeviceFocusIn(Box<XCBInputDeviceFocusInEvent>),
+    DeviceFocusOut(Box<XCBInputDeviceFocusOutEvent>),
+    ProximityIn(Box<XCBInputProximityInEvent>),
+    ProximityOut(Box<XCBInputProximityOutEvent>),
+    DeviceStateNotify(Box<XCBInputDeviceStateNotifyEvent>),
+    DeviceMappingNotify(Box<XCBInputDeviceMappingNotifyEvent>),
+    ChangeDeviceNotify(Box<XCBInputChangeDeviceNotifyEvent>),
+    DeviceKeyStateNotify(Box<XCBInputDeviceKeyStateNotifyEvent>),
+    DeviceButtonStateNotify(Box<XCBInputDeviceButtonStateNotifyEvent>),
+    DevicePresenceNotify(Box<XCBInputDevicePresenceNotifyEvent>),
+    DevicePropertyNotify(Box<XCBInputDevicePropertyNotifyEvent>),
+    DeviceChanged(Box<XCBInputDeviceChangedEvent>),
+    KeyPress(Box<XCBInputKeyPressEvent>),
+    KeyRelease(Box<XCBInputKeyReleaseEvent>),
+    ButtonPress(Box<XCBInputButtonPressEvent>),
+    ButtonRelease(Box<XCBInputButtonReleaseEvent>),
+    Motion(Box<XCBInputMotionEvent>),
+    Enter(Box<XCBInputEnterEvent>),
+    Leave(Box<XCBInputLeaveEvent>),
+    FocusIn(Box<XCBInputFocusInEvent>),
+    FocusOut(Box<XCBInputFocusOutEvent>),
+    Hierarchy(Box<XCBInputHierarchyEvent>),
+    Property(Box<XCBInputPropertyEvent>),
+    RawKeyPress(Box<XCBInputRawKeyPressEvent>),
+    RawKeyRelease(Box<XCBInputRawKeyReleaseEvent>),
+    RawButtonPress(Box<XCBInputRawButtonPressEvent>),
+    RawButtonRelease(Box<XCBInputRawButtonReleaseEvent>),
+    RawMotion(Box<XCBInputRawMotionEvent>),
+    TouchBegin(Box<XCBInputTouchBeginEvent>),
+    TouchUpdate(Box<XCBInputTouchUpdateEvent>),
+    TouchEnd(Box<XCBInputTouchEndEvent>),
+    TouchOwnership(Box<XCBInputTouchOwnershipEvent>),
+    RawTouchBegin(Box<XCBInputRawTouchBeginEvent>),
+    RawTouchUpdate(Box<XCBInputRawTouchUpdateEvent>),
+    RawTouchEnd(Box<XCBInputRawTouchEndEvent>),
+    BarrierHit(Box<XCBInputBarrierHitEvent>),
+    BarrierLeave(Box<XCBInputBarrierLeaveEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::DeviceValuator(Box::from_raw(
+                    raw as *mut XCBInputDeviceValuatorEvent,
+                ))),
+                1 => Ok(Event::DeviceKeyPress(Box::from_raw(
+                    raw as *mut XCBInputDeviceKeyPressEvent,
+                ))),
+                2 => Ok(Event::DeviceKeyRelease(Box::from_raw(
+                    raw as *mut XCBInputDeviceKeyReleaseEvent,
+                ))),
+                3 => Ok(Event::DeviceButtonPress(Box::from_raw(
+                    raw as *mut XCBInputDeviceButtonPressEvent,
+                ))),
+                4 => Ok(Event::DeviceButtonRelease(Box::from_raw(
+                    raw as *mut XCBInputDeviceButtonReleaseEvent,
+                ))),
+                5 => Ok(Event::DeviceMotionNotify(Box::from_raw(
+                    raw as *mut XCBInputDeviceMotionNotifyEvent,
+                ))),
+                6 => Ok(Event::DeviceFocusIn(Box::from_raw(
+                    raw as *mut XCBInputDeviceFocusInEvent,
+                ))),
+                7 => Ok(Event::DeviceFocusOut(Box::from_raw(
+                    raw as *mut XCBInputDeviceFocusOutEvent,
+                ))),
+                8 => Ok(Event::ProximityIn(Box::from_raw(
+                    raw as *mut XCBInputProximityInEvent,
+                ))),
+                9 => Ok(Event::ProximityOut(Box::from_raw(
+                    raw as *mut XCBInputProximityOutEvent,
+                ))),
+                10 => Ok(Event::DeviceStateNotify(Box::from_raw(
+                    raw as *mut XCBInputDeviceStateNotifyEvent,
+                ))),
+                11 => Ok(Event::DeviceMappingNotify(Box::from_raw(
+                    raw as *mut XCBInputDeviceMappingNotifyEvent,
+                ))),
+                12 => Ok(Event::ChangeDeviceNotify(Box::from_raw(
+                    raw as *mut XCBInputChangeDeviceNotifyEvent,
+                ))),
+                13 => Ok(Event::DeviceKeyStateNotify(Box::from_raw(
+                    raw as *mut XCBInputDeviceKeyStateNotifyEvent,
+                ))),
+                14 => Ok(Event::DeviceButtonStateNotify(Box::from_raw(
+                    raw as *mut XCBInputDeviceButtonStateNotifyEvent,
+                ))),
+                15 => Ok(Event::DevicePresenceNotify(Box::from_raw(
+                    raw as *mut XCBInputDevicePresenceNotifyEvent,
+                ))),
+                16 => Ok(Event::DevicePropertyNotify(Box::from_raw(
+                    raw as *mut XCBInputDevicePropertyNotifyEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+    /// Decodes a generic event whose type is `event_type`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode_generic(
+        event_type: u16,
+        event: Box<XCBGenericEvent>,
+    ) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match event_type {
+                1 => Ok(Event::DeviceChanged(Box::from_raw(
+                    raw as *mut XCBInputDeviceChangedEvent,
+                ))),
+                2 => Ok(Event::KeyPress(Box::from_raw(
+                    raw as *mut XCBInputKeyPressEvent,
+                ))),
+                3 => Ok(Event::KeyRelease(Box::from_raw(
+                    raw as *mut XCBInputKeyReleaseEvent,
+                ))),
+                4 => Ok(Event::ButtonPress(Box::from_raw(
+                    raw as *mut XCBInputButtonPressEvent,
+                ))),
+                5 => Ok(Event::ButtonRelease(Box::from_raw(
+                    raw as *mut XCBInputButtonReleaseEvent,
+                ))),
+                6 => Ok(Event::Motion(Box::from_raw(
+                    raw as *mut XCBInputMotionEvent,
+                ))),
+                7 => Ok(Event::Enter(Box::from_raw(raw as *mut XCBInputEnterEvent))),
+                8 => Ok(Event::Leave(Box::from_raw(raw as *mut XCBInputLeaveEvent))),
+                9 => Ok(Event::FocusIn(Box::from_raw(
+                    raw as *mut XCBInputFocusInEvent,
+                ))),
+                10 => Ok(Event::FocusOut(Box::from_raw(
+                    raw as *mut XCBInputFocusOutEvent,
+                ))),
+                11 => Ok(Event::Hierarchy(Box::from_raw(
+                    raw as *mut XCBInputHierarchyEvent,
+                ))),
+                12 => Ok(Event::Property(Box::from_raw(
+                    raw as *mut XCBInputPropertyEvent,
+                ))),
+                13 => Ok(Event::RawKeyPress(Box::from_raw(
+                    raw as *mut XCBInputRawKeyPressEvent,
+                ))),
+                14 => Ok(Event::RawKeyRelease(Box::from_raw(
+                    raw as *mut XCBInputRawKeyReleaseEvent,
+                ))),
+                15 => Ok(Event::RawButtonPress(Box::from_raw(
+                    raw as *mut XCBInputRawButtonPressEvent,
+                ))),
+                16 => Ok(Event::RawButtonRelease(Box::from_raw(
+                    raw as *mut XCBInputRawButtonReleaseEvent,
+                ))),
+                17 => Ok(Event::RawMotion(Box::from_raw(
+                    raw as *mut XCBInputRawMotionEvent,
+                ))),
+                18 => Ok(Event::TouchBegin(Box::from_raw(
+                    raw as *mut XCBInputTouchBeginEvent,
+                ))),
+                19 => Ok(Event::TouchUpdate(Box::from_raw(
+                    raw as *mut XCBInputTouchUpdateEvent,
+                ))),
+                20 => Ok(Event::TouchEnd(Box::from_raw(
+                    raw as *mut XCBInputTouchEndEvent,
+                ))),
+                21 => Ok(Event::TouchOwnership(Box::from_raw(
+                    raw as *mut XCBInputTouchOwnershipEvent,
+                ))),
+                22 => Ok(Event::RawTouchBegin(Box::from_raw(
+                    raw as *mut XCBInputRawTouchBeginEvent,
+                ))),
+                23 => Ok(Event::RawTouchUpdate(Box::from_raw(
+                    raw as *mut XCBInputRawTouchUpdateEvent,
+                ))),
+                24 => Ok(Event::RawTouchEnd(Box::from_raw(
+                    raw as *mut XCBInputRawTouchEndEvent,
+                ))),
+                25 => Ok(Event::BarrierHit(Box::from_raw(
+                    raw as *mut XCBInputBarrierHitEvent,
+                ))),
+                26 => Ok(Event::BarrierLeave(Box::from_raw(
+                    raw as *mut XCBInputBarrierLeaveEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
+
+/// Returns the name of the error whose code, relative to the first error of the extension, is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        0 => Some("Device"),
+        1 => Some("Event"),
+        2 => Some("Mode"),
+        3 => Some("DeviceBusy"),
+        4 => Some("Class"),
+        _ => None,
+    }
+}
@@ -0,0 +1,2703 @@
+// Generated by xcbgen from xml/xkb.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int, c_void};
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xproto::cdef::{
+    XCBAtom, XCBKeycode, XCBKeysym, XCBPoint, XCBPointIterator, XCBTimestamp, XCBWindow,
+};
+
+pub type XCBXkbDeviceSpec = u16;
+
+#[repr(C)]
+pub struct XCBXkbDeviceSpecIterator {
+    pub data: *mut XCBXkbDeviceSpec,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbLedClassSpec = u16;
+
+#[repr(C)]
+pub struct XCBXkbLedClassSpecIterator {
+    pub data: *mut XCBXkbLedClassSpec,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbBellClassSpec = u16;
+
+#[repr(C)]
+pub struct XCBXkbBellClassSpecIterator {
+    pub data: *mut XCBXkbBellClassSpec,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbIdSpec = u16;
+
+#[repr(C)]
+pub struct XCBXkbIdSpecIterator {
+    pub data: *mut XCBXkbIdSpec,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbIndicatorMap {
+    pub flags: u8,
+    pub whichGroups: u8,
+    pub groups: u8,
+    pub whichMods: u8,
+    pub mods: u8,
+    pub realMods: u8,
+    pub vmods: u16,
+    pub ctrls: u32,
+}
+
+#[repr(C)]
+pub struct XCBXkbIndicatorMapIterator {
+    pub data: *mut XCBXkbIndicatorMap,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbModDef {
+    pub mask: u8,
+    pub realMods: u8,
+    pub vmods: u16,
+}
+
+#[repr(C)]
+pub struct XCBXkbModDefIterator {
+    pub data: *mut XCBXkbModDef,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbKeyName {
+    pub name: [c_char; 4],
+}
+
+#[repr(C)]
+pub struct XCBXkbKeyNameIterator {
+    pub data: *mut XCBXkbKeyName,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbKeyAlias {
+    pub real: [c_char; 4],
+    pub alias: [c_char; 4],
+}
+
+#[repr(C)]
+pub struct XCBXkbKeyAliasIterator {
+    pub data: *mut XCBXkbKeyAlias,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXkbCountedString16 {
+    pub length: u16,
+}
+
+#[repr(C)]
+pub struct XCBXkbCountedString16Iterator {
+    pub data: *mut XCBXkbCountedString16,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbKtMapEntry {
+    pub active: u8,
+    pub mods_mask: u8,
+    pub level: u8,
+    pub mods_mods: u8,
+    pub mods_vmods: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBXkbKtMapEntryIterator {
+    pub data: *mut XCBXkbKtMapEntry,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXkbKeyType {
+    pub mods_mask: u8,
+    pub mods_mods: u8,
+    pub mods_vmods: u16,
+    pub numLevels: u8,
+    pub nMapEntries: u8,
+    pub hasPreserve: u8,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbKeyTypeIterator {
+    pub data: *mut XCBXkbKeyType,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXkbKeySymMap {
+    pub kt_index: [u8; 4],
+    pub groupInfo: u8,
+    pub width: u8,
+    pub nSyms: u16,
+}
+
+#[repr(C)]
+pub struct XCBXkbKeySymMapIterator {
+    pub data: *mut XCBXkbKeySymMap,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbCommonBehavior {
+    pub type_: u8,
+    pub data: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbCommonBehaviorIterator {
+    pub data: *mut XCBXkbCommonBehavior,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbDefaultBehavior {
+    pub type_: u8,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbDefaultBehaviorIterator {
+    pub data: *mut XCBXkbDefaultBehavior,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbLockBehavior = XCBXkbDefaultBehavior;
+
+#[repr(C)]
+pub struct XCBXkbLockBehaviorIterator {
+    pub data: *mut XCBXkbLockBehavior,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbRadioGroupBehavior {
+    pub type_: u8,
+    pub group: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbRadioGroupBehaviorIterator {
+    pub data: *mut XCBXkbRadioGroupBehavior,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbOverlayBehavior {
+    pub type_: u8,
+    pub key: XCBKeycode,
+}
+
+#[repr(C)]
+pub struct XCBXkbOverlayBehaviorIterator {
+    pub data: *mut XCBXkbOverlayBehavior,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbPermamentLockBehavior = XCBXkbLockBehavior;
+
+#[repr(C)]
+pub struct XCBXkbPermamentLockBehaviorIterator {
+    pub data: *mut XCBXkbPermamentLockBehavior,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbPermamentRadioGroupBehavior = XCBXkbRadioGroupBehavior;
+
+#[repr(C)]
+pub struct XCBXkbPermamentRadioGroupBehaviorIterator {
+    pub data: *mut XCBXkbPermamentRadioGroupBehavior,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbPermamentOverlayBehavior = XCBXkbOverlayBehavior;
+
+#[repr(C)]
+pub struct XCBXkbPermamentOverlayBehaviorIterator {
+    pub data: *mut XCBXkbPermamentOverlayBehavior,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union XCBXkbBehavior {
+    pub common: XCBXkbCommonBehavior,
+    pub default: XCBXkbDefaultBehavior,
+    pub lock: XCBXkbLockBehavior,
+    pub radioGroup: XCBXkbRadioGroupBehavior,
+    pub overlay1: XCBXkbOverlayBehavior,
+    pub overlay2: XCBXkbOverlayBehavior,
+    pub permamentLock: XCBXkbPermamentLockBehavior,
+    pub permamentRadioGroup: XCBXkbPermamentRadioGroupBehavior,
+    pub permamentOverlay1: XCBXkbPermamentOverlayBehavior,
+    pub permamentOverlay2: XCBXkbPermamentOverlayBehavior,
+    pub type_: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbBehaviorIterator {
+    pub data: *mut XCBXkbBehavior,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSetBehavior {
+    pub keycode: XCBKeycode,
+    pub behavior: XCBXkbBehavior,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbSetBehaviorIterator {
+    pub data: *mut XCBXkbSetBehavior,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSetExplicit {
+    pub keycode: XCBKeycode,
+    pub explicit: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbSetExplicitIterator {
+    pub data: *mut XCBXkbSetExplicit,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbKeyModMap {
+    pub keycode: XCBKeycode,
+    pub mods: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbKeyModMapIterator {
+    pub data: *mut XCBXkbKeyModMap,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbKeyVModMap {
+    pub keycode: XCBKeycode,
+    pub pad0: u8,
+    pub vmods: u16,
+}
+
+#[repr(C)]
+pub struct XCBXkbKeyVModMapIterator {
+    pub data: *mut XCBXkbKeyVModMap,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbKtSetMapEntry {
+    pub level: u8,
+    pub realMods: u8,
+    pub virtualMods: u16,
+}
+
+#[repr(C)]
+pub struct XCBXkbKtSetMapEntryIterator {
+    pub data: *mut XCBXkbKtSetMapEntry,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXkbSetKeyType {
+    pub mask: u8,
+    pub realMods: u8,
+    pub virtualMods: u16,
+    pub numLevels: u8,
+    pub nMapEntries: u8,
+    pub preserve: u8,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbSetKeyTypeIterator {
+    pub data: *mut XCBXkbSetKeyType,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbString8 = c_char;
+
+#[repr(C)]
+pub struct XCBXkbString8Iterator {
+    pub data: *mut XCBXkbString8,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXkbOutline {
+    pub nPoints: u8,
+    pub cornerRadius: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBXkbOutlineIterator {
+    pub data: *mut XCBXkbOutline,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXkbShape {
+    pub name: XCBAtom,
+    pub nOutlines: u8,
+    pub primaryNdx: u8,
+    pub approxNdx: u8,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbShapeIterator {
+    pub data: *mut XCBXkbShape,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbKey {
+    pub name: [XCBXkbString8; 4],
+    pub gap: i16,
+    pub shapeNdx: u8,
+    pub colorNdx: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbKeyIterator {
+    pub data: *mut XCBXkbKey,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbOverlayKey {
+    pub over: [XCBXkbString8; 4],
+    pub under: [XCBXkbString8; 4],
+}
+
+#[repr(C)]
+pub struct XCBXkbOverlayKeyIterator {
+    pub data: *mut XCBXkbOverlayKey,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXkbOverlayRow {
+    pub rowUnder: u8,
+    pub nKeys: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBXkbOverlayRowIterator {
+    pub data: *mut XCBXkbOverlayRow,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXkbOverlay {
+    pub name: XCBAtom,
+    pub nRows: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+pub struct XCBXkbOverlayIterator {
+    pub data: *mut XCBXkbOverlay,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXkbRow {
+    pub top: i16,
+    pub left: i16,
+    pub nKeys: u8,
+    pub vertical: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBXkbRowIterator {
+    pub data: *mut XCBXkbRow,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXkbListing {
+    pub flags: u16,
+    pub length: u16,
+}
+
+#[repr(C)]
+pub struct XCBXkbListingIterator {
+    pub data: *mut XCBXkbListing,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXkbDeviceLedInfo {
+    pub ledClass: XCBXkbLedClassSpec,
+    pub ledID: XCBXkbIdSpec,
+    pub namesPresent: u32,
+    pub mapsPresent: u32,
+    pub physIndicators: u32,
+    pub state: u32,
+}
+
+#[repr(C)]
+pub struct XCBXkbDeviceLedInfoIterator {
+    pub data: *mut XCBXkbDeviceLedInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbKeyboardError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+    pub value: u32,
+    pub minorOpcode: u16,
+    pub majorOpcode: u8,
+    pub pad0: [u8; 21],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaNoAction {
+    pub type_: u8,
+    pub pad0: [u8; 7],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaNoActionIterator {
+    pub data: *mut XCBXkbSaNoAction,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaSetMods {
+    pub type_: u8,
+    pub flags: u8,
+    pub mask: u8,
+    pub realMods: u8,
+    pub vmodsHigh: u8,
+    pub vmodsLow: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaSetModsIterator {
+    pub data: *mut XCBXkbSaSetMods,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbSaLatchMods = XCBXkbSaSetMods;
+
+#[repr(C)]
+pub struct XCBXkbSaLatchModsIterator {
+    pub data: *mut XCBXkbSaLatchMods,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbSaLockMods = XCBXkbSaSetMods;
+
+#[repr(C)]
+pub struct XCBXkbSaLockModsIterator {
+    pub data: *mut XCBXkbSaLockMods,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaSetGroup {
+    pub type_: u8,
+    pub flags: u8,
+    pub group: i8,
+    pub pad0: [u8; 5],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaSetGroupIterator {
+    pub data: *mut XCBXkbSaSetGroup,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbSaLatchGroup = XCBXkbSaSetGroup;
+
+#[repr(C)]
+pub struct XCBXkbSaLatchGroupIterator {
+    pub data: *mut XCBXkbSaLatchGroup,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbSaLockGroup = XCBXkbSaSetGroup;
+
+#[repr(C)]
+pub struct XCBXkbSaLockGroupIterator {
+    pub data: *mut XCBXkbSaLockGroup,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaMovePtr {
+    pub type_: u8,
+    pub flags: u8,
+    pub xHigh: i8,
+    pub xLow: u8,
+    pub yHigh: i8,
+    pub yLow: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaMovePtrIterator {
+    pub data: *mut XCBXkbSaMovePtr,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaPtrBtn {
+    pub type_: u8,
+    pub flags: u8,
+    pub count: u8,
+    pub button: u8,
+    pub pad0: [u8; 4],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaPtrBtnIterator {
+    pub data: *mut XCBXkbSaPtrBtn,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaLockPtrBtn {
+    pub type_: u8,
+    pub flags: u8,
+    pub pad0: u8,
+    pub button: u8,
+    pub pad1: [u8; 4],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaLockPtrBtnIterator {
+    pub data: *mut XCBXkbSaLockPtrBtn,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaSetPtrDflt {
+    pub type_: u8,
+    pub flags: u8,
+    pub affect: u8,
+    pub value: i8,
+    pub pad0: [u8; 4],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaSetPtrDfltIterator {
+    pub data: *mut XCBXkbSaSetPtrDflt,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaIsoLock {
+    pub type_: u8,
+    pub flags: u8,
+    pub mask: u8,
+    pub realMods: u8,
+    pub group: i8,
+    pub affect: u8,
+    pub vmodsHigh: u8,
+    pub vmodsLow: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbSaIsoLockIterator {
+    pub data: *mut XCBXkbSaIsoLock,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaTerminate {
+    pub type_: u8,
+    pub pad0: [u8; 7],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaTerminateIterator {
+    pub data: *mut XCBXkbSaTerminate,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaSwitchScreen {
+    pub type_: u8,
+    pub flags: u8,
+    pub newScreen: i8,
+    pub pad0: [u8; 5],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaSwitchScreenIterator {
+    pub data: *mut XCBXkbSaSwitchScreen,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaSetControls {
+    pub type_: u8,
+    pub pad0: [u8; 3],
+    pub boolCtrlsHigh: u8,
+    pub boolCtrlsLow: u8,
+    pub pad1: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaSetControlsIterator {
+    pub data: *mut XCBXkbSaSetControls,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXkbSaLockControls = XCBXkbSaSetControls;
+
+#[repr(C)]
+pub struct XCBXkbSaLockControlsIterator {
+    pub data: *mut XCBXkbSaLockControls,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaActionMessage {
+    pub type_: u8,
+    pub flags: u8,
+    pub message: [u8; 6],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaActionMessageIterator {
+    pub data: *mut XCBXkbSaActionMessage,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaRedirectKey {
+    pub type_: u8,
+    pub newkey: XCBKeycode,
+    pub mask: u8,
+    pub realModifiers: u8,
+    pub vmodsMaskHigh: u8,
+    pub vmodsMaskLow: u8,
+    pub vmodsHigh: u8,
+    pub vmodsLow: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbSaRedirectKeyIterator {
+    pub data: *mut XCBXkbSaRedirectKey,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaDeviceBtn {
+    pub type_: u8,
+    pub flags: u8,
+    pub count: u8,
+    pub button: u8,
+    pub device: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaDeviceBtnIterator {
+    pub data: *mut XCBXkbSaDeviceBtn,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaLockDeviceBtn {
+    pub type_: u8,
+    pub flags: u8,
+    pub pad0: u8,
+    pub button: u8,
+    pub device: u8,
+    pub pad1: [u8; 3],
+}
+
+#[repr(C)]
+pub struct XCBXkbSaLockDeviceBtnIterator {
+    pub data: *mut XCBXkbSaLockDeviceBtn,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSaDeviceValuator {
+    pub type_: u8,
+    pub device: u8,
+    pub val1what: u8,
+    pub val1index: u8,
+    pub val1value: u8,
+    pub val2what: u8,
+    pub val2index: u8,
+    pub val2value: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbSaDeviceValuatorIterator {
+    pub data: *mut XCBXkbSaDeviceValuator,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSiAction {
+    pub type_: u8,
+    pub data: [u8; 7],
+}
+
+#[repr(C)]
+pub struct XCBXkbSiActionIterator {
+    pub data: *mut XCBXkbSiAction,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSymInterpret {
+    pub sym: XCBKeysym,
+    pub mods: u8,
+    pub match_: u8,
+    pub virtualMod: u8,
+    pub flags: u8,
+    pub action: XCBXkbSiAction,
+}
+
+#[repr(C)]
+pub struct XCBXkbSymInterpretIterator {
+    pub data: *mut XCBXkbSymInterpret,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union XCBXkbAction {
+    pub no_action: XCBXkbSaNoAction,
+    pub set_mods: XCBXkbSaSetMods,
+    pub latch_mods: XCBXkbSaLatchMods,
+    pub lock_mods: XCBXkbSaLockMods,
+    pub set_group: XCBXkbSaSetGroup,
+    pub latch_group: XCBXkbSaLatchGroup,
+    pub lock_group: XCBXkbSaLockGroup,
+    pub move_ptr: XCBXkbSaMovePtr,
+    pub ptr_btn: XCBXkbSaPtrBtn,
+    pub lock_ptr_btn: XCBXkbSaLockPtrBtn,
+    pub set_ptr_dflt: XCBXkbSaSetPtrDflt,
+    pub iso_lock: XCBXkbSaIsoLock,
+    pub terminate: XCBXkbSaTerminate,
+    pub switch_screen: XCBXkbSaSwitchScreen,
+    pub set_controls: XCBXkbSaSetControls,
+    pub lock_controls: XCBXkbSaLockControls,
+    pub message: XCBXkbSaActionMessage,
+    pub redirect_key: XCBXkbSaRedirectKey,
+    pub device_btn: XCBXkbSaDeviceBtn,
+    pub lock_device_btn: XCBXkbSaLockDeviceBtn,
+    pub device_valuator: XCBXkbSaDeviceValuator,
+    pub type_: u8,
+}
+
+#[repr(C)]
+pub struct XCBXkbActionIterator {
+    pub data: *mut XCBXkbAction,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbUseExtensionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub wantedMajor: u16,
+    pub wantedMinor: u16,
+}
+
+pub type XCBXkbUseExtensionCookie = XCBCookie<XCBXkbUseExtensionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbUseExtensionReply {
+    pub response_type: u8,
+    pub supported: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub serverMajor: u16,
+    pub serverMinor: u16,
+    pub pad0: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBXkbSelectEventsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub affectWhich: u16,
+    pub clear: u16,
+    pub selectAll: u16,
+    pub affectMap: u16,
+    pub map: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbBellRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub bellClass: XCBXkbBellClassSpec,
+    pub bellID: XCBXkbIdSpec,
+    pub percent: i8,
+    pub forceSound: u8,
+    pub eventOnly: u8,
+    pub pad0: u8,
+    pub pitch: i16,
+    pub duration: i16,
+    pub pad1: [u8; 2],
+    pub name: XCBAtom,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetStateRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBXkbGetStateCookie = XCBCookie<XCBXkbGetStateReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetStateReply {
+    pub response_type: u8,
+    pub deviceID: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub mods: u8,
+    pub baseMods: u8,
+    pub latchedMods: u8,
+    pub lockedMods: u8,
+    pub group: u8,
+    pub lockedGroup: u8,
+    pub baseGroup: i16,
+    pub latchedGroup: i16,
+    pub compatState: u8,
+    pub grabMods: u8,
+    pub compatGrabMods: u8,
+    pub lookupMods: u8,
+    pub compatLookupMods: u8,
+    pub pad0: u8,
+    pub ptrBtnState: u16,
+    pub pad1: [u8; 6],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbLatchLockStateRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub affectModLocks: u8,
+    pub modLocks: u8,
+    pub lockGroup: u8,
+    pub groupLock: u8,
+    pub affectModLatches: u8,
+    pub pad0: u8,
+    pub pad1: u8,
+    pub latchGroup: u8,
+    pub groupLatch: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetControlsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBXkbGetControlsCookie = XCBCookie<XCBXkbGetControlsReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetControlsReply {
+    pub response_type: u8,
+    pub deviceID: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub mouseKeysDfltBtn: u8,
+    pub numGroups: u8,
+    pub groupsWrap: u8,
+    pub internalModsMask: u8,
+    pub ignoreLockModsMask: u8,
+    pub internalModsRealMods: u8,
+    pub ignoreLockModsRealMods: u8,
+    pub pad0: u8,
+    pub internalModsVmods: u16,
+    pub ignoreLockModsVmods: u16,
+    pub repeatDelay: u16,
+    pub repeatInterval: u16,
+    pub slowKeysDelay: u16,
+    pub debounceDelay: u16,
+    pub mouseKeysDelay: u16,
+    pub mouseKeysInterval: u16,
+    pub mouseKeysTimeToMax: u16,
+    pub mouseKeysMaxSpeed: u16,
+    pub mouseKeysCurve: i16,
+    pub accessXOption: u16,
+    pub accessXTimeout: u16,
+    pub accessXTimeoutOptionsMask: u16,
+    pub accessXTimeoutOptionsValues: u16,
+    pub pad1: [u8; 2],
+    pub accessXTimeoutMask: u32,
+    pub accessXTimeoutValues: u32,
+    pub enabledControls: u32,
+    pub perKeyRepeat: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSetControlsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub affectInternalRealMods: u8,
+    pub internalRealMods: u8,
+    pub affectIgnoreLockRealMods: u8,
+    pub ignoreLockRealMods: u8,
+    pub affectInternalVirtualMods: u16,
+    pub internalVirtualMods: u16,
+    pub affectIgnoreLockVirtualMods: u16,
+    pub ignoreLockVirtualMods: u16,
+    pub mouseKeysDfltBtn: u8,
+    pub groupsWrap: u8,
+    pub accessXOptions: u16,
+    pub pad0: [u8; 2],
+    pub affectEnabledControls: u32,
+    pub enabledControls: u32,
+    pub changeControls: u32,
+    pub repeatDelay: u16,
+    pub repeatInterval: u16,
+    pub slowKeysDelay: u16,
+    pub debounceDelay: u16,
+    pub mouseKeysDelay: u16,
+    pub mouseKeysInterval: u16,
+    pub mouseKeysTimeToMax: u16,
+    pub mouseKeysMaxSpeed: u16,
+    pub mouseKeysCurve: i16,
+    pub accessXTimeout: u16,
+    pub accessXTimeoutMask: u32,
+    pub accessXTimeoutValues: u32,
+    pub accessXTimeoutOptionsMask: u16,
+    pub accessXTimeoutOptionsValues: u16,
+    pub perKeyRepeat: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetMapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub full: u16,
+    pub partial: u16,
+    pub firstType: u8,
+    pub nTypes: u8,
+    pub firstKeySym: XCBKeycode,
+    pub nKeySyms: u8,
+    pub firstKeyAction: XCBKeycode,
+    pub nKeyActions: u8,
+    pub firstKeyBehavior: XCBKeycode,
+    pub nKeyBehaviors: u8,
+    pub virtualMods: u16,
+    pub firstKeyExplicit: XCBKeycode,
+    pub nKeyExplicit: u8,
+    pub firstModMapKey: XCBKeycode,
+    pub nModMapKeys: u8,
+    pub firstVModMapKey: XCBKeycode,
+    pub nVModMapKeys: u8,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBXkbGetMapCookie = XCBCookie<XCBXkbGetMapReply>;
+
+#[repr(C)]
+pub struct XCBXkbGetMapReply {
+    pub response_type: u8,
+    pub deviceID: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad0: [u8; 2],
+    pub minKeyCode: XCBKeycode,
+    pub maxKeyCode: XCBKeycode,
+    pub present: u16,
+    pub firstType: u8,
+    pub nTypes: u8,
+    pub totalTypes: u8,
+    pub firstKeySym: XCBKeycode,
+    pub totalSyms: u16,
+    pub nKeySyms: u8,
+    pub firstKeyAction: XCBKeycode,
+    pub totalActions: u16,
+    pub nKeyActions: u8,
+    pub firstKeyBehavior: XCBKeycode,
+    pub nKeyBehaviors: u8,
+    pub totalKeyBehaviors: u8,
+    pub firstKeyExplicit: XCBKeycode,
+    pub nKeyExplicit: u8,
+    pub totalKeyExplicit: u8,
+    pub firstModMapKey: XCBKeycode,
+    pub nModMapKeys: u8,
+    pub totalModMapKeys: u8,
+    pub firstVModMapKey: XCBKeycode,
+    pub nVModMapKeys: u8,
+    pub totalVModMapKeys: u8,
+    pub pad1: u8,
+    pub virtualMods: u16,
+}
+
+#[repr(C)]
+pub struct XCBXkbSetMapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub present: u16,
+    pub flags: u16,
+    pub minKeyCode: XCBKeycode,
+    pub maxKeyCode: XCBKeycode,
+    pub firstType: u8,
+    pub nTypes: u8,
+    pub firstKeySym: XCBKeycode,
+    pub nKeySyms: u8,
+    pub totalSyms: u16,
+    pub firstKeyAction: XCBKeycode,
+    pub nKeyActions: u8,
+    pub totalActions: u16,
+    pub firstKeyBehavior: XCBKeycode,
+    pub nKeyBehaviors: u8,
+    pub totalKeyBehaviors: u8,
+    pub firstKeyExplicit: XCBKeycode,
+    pub nKeyExplicit: u8,
+    pub totalKeyExplicit: u8,
+    pub firstModMapKey: XCBKeycode,
+    pub nModMapKeys: u8,
+    pub totalModMapKeys: u8,
+    pub firstVModMapKey: XCBKeycode,
+    pub nVModMapKeys: u8,
+    pub totalVModMapKeys: u8,
+    pub virtualMods: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetCompatMapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub groups: u8,
+    pub getAllSI: u8,
+    pub firstSI: u16,
+    pub nSI: u16,
+}
+
+pub type XCBXkbGetCompatMapCookie = XCBCookie<XCBXkbGetCompatMapReply>;
+
+#[repr(C)]
+pub struct XCBXkbGetCompatMapReply {
+    pub response_type: u8,
+    pub deviceID: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub groupsRtrn: u8,
+    pub pad0: u8,
+    pub firstSIRtrn: u16,
+    pub nSIRtrn: u16,
+    pub nTotalSI: u16,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+pub struct XCBXkbSetCompatMapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub pad0: u8,
+    pub recomputeActions: u8,
+    pub truncateSI: u8,
+    pub groups: u8,
+    pub firstSI: u16,
+    pub nSI: u16,
+    pub pad1: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetIndicatorStateRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBXkbGetIndicatorStateCookie = XCBCookie<XCBXkbGetIndicatorStateReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetIndicatorStateReply {
+    pub response_type: u8,
+    pub deviceID: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub state: u32,
+    pub pad0: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetIndicatorMapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub pad0: [u8; 2],
+    pub which: u32,
+}
+
+pub type XCBXkbGetIndicatorMapCookie = XCBCookie<XCBXkbGetIndicatorMapReply>;
+
+#[repr(C)]
+pub struct XCBXkbGetIndicatorMapReply {
+    pub response_type: u8,
+    pub deviceID: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub which: u32,
+    pub realIndicators: u32,
+    pub nIndicators: u8,
+    pub pad0: [u8; 15],
+}
+
+#[repr(C)]
+pub struct XCBXkbSetIndicatorMapRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub pad0: [u8; 2],
+    pub which: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetNamedIndicatorRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub ledClass: XCBXkbLedClassSpec,
+    pub ledID: XCBXkbIdSpec,
+    pub pad0: [u8; 2],
+    pub indicator: XCBAtom,
+}
+
+pub type XCBXkbGetNamedIndicatorCookie = XCBCookie<XCBXkbGetNamedIndicatorReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetNamedIndicatorReply {
+    pub response_type: u8,
+    pub deviceID: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub indicator: XCBAtom,
+    pub found: u8,
+    pub on: u8,
+    pub realIndicator: u8,
+    pub ndx: u8,
+    pub map_flags: u8,
+    pub map_whichGroups: u8,
+    pub map_groups: u8,
+    pub map_whichMods: u8,
+    pub map_mods: u8,
+    pub map_realMods: u8,
+    pub map_vmod: u16,
+    pub map_ctrls: u32,
+    pub supported: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSetNamedIndicatorRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub ledClass: XCBXkbLedClassSpec,
+    pub ledID: XCBXkbIdSpec,
+    pub pad0: [u8; 2],
+    pub indicator: XCBAtom,
+    pub setState: u8,
+    pub on: u8,
+    pub setMap: u8,
+    pub createMap: u8,
+    pub pad1: u8,
+    pub map_flags: u8,
+    pub map_whichGroups: u8,
+    pub map_groups: u8,
+    pub map_whichMods: u8,
+    pub map_realMods: u8,
+    pub map_vmods: u16,
+    pub map_ctrls: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetNamesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub pad0: [u8; 2],
+    pub which: u32,
+}
+
+pub type XCBXkbGetNamesCookie = XCBCookie<XCBXkbGetNamesReply>;
+
+#[repr(C)]
+pub struct XCBXkbGetNamesReply {
+    pub response_type: u8,
+    pub deviceID: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub which: u32,
+    pub minKeyCode: XCBKeycode,
+    pub maxKeyCode: XCBKeycode,
+    pub nTypes: u8,
+    pub groupNames: u8,
+    pub virtualMods: u16,
+    pub firstKey: XCBKeycode,
+    pub nKeys: u8,
+    pub indicators: u32,
+    pub nRadioGroups: u8,
+    pub nKeyAliases: u8,
+    pub nKTLevels: u16,
+    pub pad0: [u8; 4],
+}
+
+#[repr(C)]
+pub struct XCBXkbSetNamesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub virtualMods: u16,
+    pub which: u32,
+    pub firstType: u8,
+    pub nTypes: u8,
+    pub firstKTLevelt: u8,
+    pub nKTLevels: u8,
+    pub indicators: u32,
+    pub groupNames: u8,
+    pub nRadioGroups: u8,
+    pub firstKey: XCBKeycode,
+    pub nKeys: u8,
+    pub nKeyAliases: u8,
+    pub pad0: u8,
+    pub totalKTLevelNames: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbPerClientFlagsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub pad0: [u8; 2],
+    pub change: u32,
+    pub value: u32,
+    pub ctrlsToChange: u32,
+    pub autoCtrls: u32,
+    pub autoCtrlsValues: u32,
+}
+
+pub type XCBXkbPerClientFlagsCookie = XCBCookie<XCBXkbPerClientFlagsReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbPerClientFlagsReply {
+    pub response_type: u8,
+    pub deviceID: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub supported: u32,
+    pub value: u32,
+    pub autoCtrls: u32,
+    pub autoCtrlsValues: u32,
+    pub pad0: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbListComponentsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub maxNames: u16,
+}
+
+pub type XCBXkbListComponentsCookie = XCBCookie<XCBXkbListComponentsReply>;
+
+#[repr(C)]
+pub struct XCBXkbListComponentsReply {
+    pub response_type: u8,
+    pub deviceID: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub nKeymaps: u16,
+    pub nKeycodes: u16,
+    pub nTypes: u16,
+    pub nCompatMaps: u16,
+    pub nSymbols: u16,
+    pub nGeometries: u16,
+    pub extra: u16,
+    pub pad0: [u8; 10],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetKbdByNameRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub need: u16,
+    pub want: u16,
+    pub load: u8,
+    pub pad0: u8,
+}
+
+pub type XCBXkbGetKbdByNameCookie = XCBCookie<XCBXkbGetKbdByNameReply>;
+
+#[repr(C)]
+pub struct XCBXkbGetKbdByNameReply {
+    pub response_type: u8,
+    pub deviceID: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub minKeyCode: XCBKeycode,
+    pub maxKeyCode: XCBKeycode,
+    pub loaded: u8,
+    pub newKeyboard: u8,
+    pub found: u16,
+    pub reported: u16,
+    pub pad0: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbGetDeviceInfoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub wanted: u16,
+    pub allButtons: u8,
+    pub firstButton: u8,
+    pub nButtons: u8,
+    pub pad0: u8,
+    pub ledClass: XCBXkbLedClassSpec,
+    pub ledID: XCBXkbIdSpec,
+}
+
+pub type XCBXkbGetDeviceInfoCookie = XCBCookie<XCBXkbGetDeviceInfoReply>;
+
+#[repr(C)]
+pub struct XCBXkbGetDeviceInfoReply {
+    pub response_type: u8,
+    pub deviceID: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub present: u16,
+    pub supported: u16,
+    pub unsupported: u16,
+    pub nDeviceLedFBs: u16,
+    pub firstBtnWanted: u8,
+    pub nBtnsWanted: u8,
+    pub firstBtnRtrn: u8,
+    pub nBtnsRtrn: u8,
+    pub totalBtns: u8,
+    pub hasOwnState: u8,
+    pub dfltKbdFB: u16,
+    pub dfltLedFB: u16,
+    pub pad0: [u8; 2],
+    pub devType: XCBAtom,
+    pub nameLen: u16,
+}
+
+#[repr(C)]
+pub struct XCBXkbSetDeviceInfoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub deviceSpec: XCBXkbDeviceSpec,
+    pub firstBtn: u8,
+    pub nBtns: u8,
+    pub change: u16,
+    pub nDeviceLedFBs: u16,
+}
+
+#[repr(C)]
+pub struct XCBXkbSetDebuggingFlagsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub msgLength: u16,
+    pub pad0: [u8; 2],
+    pub affectFlags: u32,
+    pub flags: u32,
+    pub affectCtrls: u32,
+    pub ctrls: u32,
+}
+
+pub type XCBXkbSetDebuggingFlagsCookie = XCBCookie<XCBXkbSetDebuggingFlagsReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbSetDebuggingFlagsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub currentFlags: u32,
+    pub currentCtrls: u32,
+    pub supportedFlags: u32,
+    pub supportedCtrls: u32,
+    pub pad1: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbNewKeyboardNotifyEvent {
+    pub response_type: u8,
+    pub xkbType: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub deviceID: u8,
+    pub oldDeviceID: u8,
+    pub minKeyCode: XCBKeycode,
+    pub maxKeyCode: XCBKeycode,
+    pub oldMinKeyCode: XCBKeycode,
+    pub oldMaxKeyCode: XCBKeycode,
+    pub requestMajor: u8,
+    pub requestMinor: u8,
+    pub changed: u16,
+    pub pad0: [u8; 14],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbMapNotifyEvent {
+    pub response_type: u8,
+    pub xkbType: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub deviceID: u8,
+    pub ptrBtnActions: u8,
+    pub changed: u16,
+    pub minKeyCode: XCBKeycode,
+    pub maxKeyCode: XCBKeycode,
+    pub firstType: u8,
+    pub nTypes: u8,
+    pub firstKeySym: XCBKeycode,
+    pub nKeySyms: u8,
+    pub firstKeyAct: XCBKeycode,
+    pub nKeyActs: u8,
+    pub firstKeyBehavior: XCBKeycode,
+    pub nKeyBehavior: u8,
+    pub firstKeyExplicit: XCBKeycode,
+    pub nKeyExplicit: u8,
+    pub firstModMapKey: XCBKeycode,
+    pub nModMapKeys: u8,
+    pub firstVModMapKey: XCBKeycode,
+    pub nVModMapKeys: u8,
+    pub virtualMods: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbStateNotifyEvent {
+    pub response_type: u8,
+    pub xkbType: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub deviceID: u8,
+    pub mods: u8,
+    pub baseMods: u8,
+    pub latchedMods: u8,
+    pub lockedMods: u8,
+    pub group: u8,
+    pub baseGroup: i16,
+    pub latchedGroup: i16,
+    pub lockedGroup: u8,
+    pub compatState: u8,
+    pub grabMods: u8,
+    pub compatGrabMods: u8,
+    pub lookupMods: u8,
+    pub compatLoockupMods: u8,
+    pub ptrBtnState: u16,
+    pub changed: u16,
+    pub keycode: XCBKeycode,
+    pub eventType: u8,
+    pub requestMajor: u8,
+    pub requestMinor: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbControlsNotifyEvent {
+    pub response_type: u8,
+    pub xkbType: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub deviceID: u8,
+    pub numGroups: u8,
+    pub pad0: [u8; 2],
+    pub changedControls: u32,
+    pub enabledControls: u32,
+    pub enabledControlChanges: u32,
+    pub keycode: XCBKeycode,
+    pub eventType: u8,
+    pub requestMajor: u8,
+    pub requestMinor: u8,
+    pub pad1: [u8; 4],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbIndicatorStateNotifyEvent {
+    pub response_type: u8,
+    pub xkbType: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub deviceID: u8,
+    pub pad0: [u8; 3],
+    pub state: u32,
+    pub stateChanged: u32,
+    pub pad1: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbIndicatorMapNotifyEvent {
+    pub response_type: u8,
+    pub xkbType: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub deviceID: u8,
+    pub pad0: [u8; 3],
+    pub state: u32,
+    pub mapChanged: u32,
+    pub pad1: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbNamesNotifyEvent {
+    pub response_type: u8,
+    pub xkbType: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub deviceID: u8,
+    pub pad0: u8,
+    pub changed: u16,
+    pub firstType: u8,
+    pub nTypes: u8,
+    pub firstLevelName: u8,
+    pub nLevelNames: u8,
+    pub pad1: u8,
+    pub nRadioGroups: u8,
+    pub nKeyAliases: u8,
+    pub changedGroupNames: u8,
+    pub changedVirtualMods: u16,
+    pub firstKey: XCBKeycode,
+    pub nKeys: u8,
+    pub changedIndicators: u32,
+    pub pad2: [u8; 4],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbCompatMapNotifyEvent {
+    pub response_type: u8,
+    pub xkbType: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub deviceID: u8,
+    pub changedGroups: u8,
+    pub firstSI: u16,
+    pub nSI: u16,
+    pub nTotalSI: u16,
+    pub pad0: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbBellNotifyEvent {
+    pub response_type: u8,
+    pub xkbType: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub deviceID: u8,
+    pub bellClass: u8,
+    pub bellID: u8,
+    pub percent: u8,
+    pub pitch: u16,
+    pub duration: u16,
+    pub name: XCBAtom,
+    pub window: XCBWindow,
+    pub eventOnly: u8,
+    pub pad0: [u8; 7],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbActionMessageEvent {
+    pub response_type: u8,
+    pub xkbType: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub deviceID: u8,
+    pub keycode: XCBKeycode,
+    pub press: u8,
+    pub keyEventFollows: u8,
+    pub mods: u8,
+    pub group: u8,
+    pub message: [XCBXkbString8; 8],
+    pub pad0: [u8; 10],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbAccessXNotifyEvent {
+    pub response_type: u8,
+    pub xkbType: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub deviceID: u8,
+    pub keycode: XCBKeycode,
+    pub detailt: u16,
+    pub slowKeysDelay: u16,
+    pub debounceDelay: u16,
+    pub pad0: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXkbExtensionDeviceNotifyEvent {
+    pub response_type: u8,
+    pub xkbType: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub deviceID: u8,
+    pub pad0: u8,
+    pub reason: u16,
+    pub ledClass: u16,
+    pub ledID: u16,
+    pub ledsDefined: u32,
+    pub ledState: u32,
+    pub firstButton: u8,
+    pub nButtons: u8,
+    pub supported: u16,
+    pub unsupported: u16,
+    pub pad1: [u8; 2],
+}
+
+#[link(name = "xcb-xkb")]
+extern "system" {
+    pub static mut xcb_xkb_id: XCBExtension;
+    pub fn xcb_xkb_device_spec_next(i: *mut XCBXkbDeviceSpecIterator);
+    pub fn xcb_xkb_device_spec_end(i: XCBXkbDeviceSpecIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_led_class_spec_next(i: *mut XCBXkbLedClassSpecIterator);
+    pub fn xcb_xkb_led_class_spec_end(i: XCBXkbLedClassSpecIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_bell_class_spec_next(i: *mut XCBXkbBellClassSpecIterator);
+    pub fn xcb_xkb_bell_class_spec_end(i: XCBXkbBellClassSpecIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_id_spec_next(i: *mut XCBXkbIdSpecIterator);
+    pub fn xcb_xkb_id_spec_end(i: XCBXkbIdSpecIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_indicator_map_next(i: *mut XCBXkbIndicatorMapIterator);
+    pub fn xcb_xkb_indicator_map_end(i: XCBXkbIndicatorMapIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_mod_def_next(i: *mut XCBXkbModDefIterator);
+    pub fn xcb_xkb_mod_def_end(i: XCBXkbModDefIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_key_name_next(i: *mut XCBXkbKeyNameIterator);
+    pub fn xcb_xkb_key_name_end(i: XCBXkbKeyNameIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_key_alias_next(i: *mut XCBXkbKeyAliasIterator);
+    pub fn xcb_xkb_key_alias_end(i: XCBXkbKeyAliasIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_counted_string_16_next(i: *mut XCBXkbCountedString16Iterator);
+    pub fn xcb_xkb_counted_string_16_end(i: XCBXkbCountedString16Iterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_counted_string_16_string(R: *const XCBXkbCountedString16) -> *mut c_char;
+    pub fn xcb_xkb_counted_string_16_string_length(R: *const XCBXkbCountedString16) -> c_int;
+    pub fn xcb_xkb_counted_string_16_string_end(
+        R: *const XCBXkbCountedString16,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xkb_counted_string_16_alignment_pad(R: *const XCBXkbCountedString16) -> *mut c_void;
+    pub fn xcb_xkb_counted_string_16_alignment_pad_length(R: *const XCBXkbCountedString16)
+        -> c_int;
+    pub fn xcb_xkb_counted_string_16_alignment_pad_end(
+        R: *const XCBXkbCountedString16,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xkb_kt_map_entry_next(i: *mut XCBXkbKtMapEntryIterator);
+    pub fn xcb_xkb_kt_map_entry_end(i: XCBXkbKtMapEntryIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_key_type_next(i: *mut XCBXkbKeyTypeIterator);
+    pub fn xcb_xkb_key_type_end(i: XCBXkbKeyTypeIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_key_type_map(R: *const XCBXkbKeyType) -> *mut XCBXkbKtMapEntry;
+    pub fn xcb_xkb_key_type_map_length(R: *const XCBXkbKeyType) -> c_int;
+    pub fn xcb_xkb_key_type_map_iterator(R: *const XCBXkbKeyType) -> XCBXkbKtMapEntryIterator;
+    pub fn xcb_xkb_key_type_preserve(R: *const XCBXkbKeyType) -> *mut XCBXkbModDef;
+    pub fn xcb_xkb_key_type_preserve_length(R: *const XCBXkbKeyType) -> c_int;
+    pub fn xcb_xkb_key_type_preserve_iterator(R: *const XCBXkbKeyType) -> XCBXkbModDefIterator;
+    pub fn xcb_xkb_key_sym_map_next(i: *mut XCBXkbKeySymMapIterator);
+    pub fn xcb_xkb_key_sym_map_end(i: XCBXkbKeySymMapIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_key_sym_map_syms(R: *const XCBXkbKeySymMap) -> *mut XCBKeysym;
+    pub fn xcb_xkb_key_sym_map_syms_length(R: *const XCBXkbKeySymMap) -> c_int;
+    pub fn xcb_xkb_key_sym_map_syms_end(R: *const XCBXkbKeySymMap) -> XCBGenericIterator;
+    pub fn xcb_xkb_common_behavior_next(i: *mut XCBXkbCommonBehaviorIterator);
+    pub fn xcb_xkb_common_behavior_end(i: XCBXkbCommonBehaviorIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_default_behavior_next(i: *mut XCBXkbDefaultBehaviorIterator);
+    pub fn xcb_xkb_default_behavior_end(i: XCBXkbDefaultBehaviorIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_lock_behavior_next(i: *mut XCBXkbLockBehaviorIterator);
+    pub fn xcb_xkb_lock_behavior_end(i: XCBXkbLockBehaviorIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_radio_group_behavior_next(i: *mut XCBXkbRadioGroupBehaviorIterator);
+    pub fn xcb_xkb_radio_group_behavior_end(
+        i: XCBXkbRadioGroupBehaviorIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xkb_overlay_behavior_next(i: *mut XCBXkbOverlayBehaviorIterator);
+    pub fn xcb_xkb_overlay_behavior_end(i: XCBXkbOverlayBehaviorIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_permament_lock_behavior_next(i: *mut XCBXkbPermamentLockBehaviorIterator);
+    pub fn xcb_xkb_permament_lock_behavior_end(
+        i: XCBXkbPermamentLockBehaviorIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xkb_permament_radio_group_behavior_next(
+        i: *mut XCBXkbPermamentRadioGroupBehaviorIterator,
+    );
+    pub fn xcb_xkb_permament_radio_group_behavior_end(
+        i: XCBXkbPermamentRadioGroupBehaviorIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xkb_permament_overlay_behavior_next(i: *mut XCBXkbPermamentOverlayBehaviorIterator);
+    pub fn xcb_xkb_permament_overlay_behavior_end(
+        i: XCBXkbPermamentOverlayBehaviorIterator,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xkb_behavior_next(i: *mut XCBXkbBehaviorIterator);
+    pub fn xcb_xkb_behavior_end(i: XCBXkbBehaviorIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_set_behavior_next(i: *mut XCBXkbSetBehaviorIterator);
+    pub fn xcb_xkb_set_behavior_end(i: XCBXkbSetBehaviorIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_set_explicit_next(i: *mut XCBXkbSetExplicitIterator);
+    pub fn xcb_xkb_set_explicit_end(i: XCBXkbSetExplicitIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_key_mod_map_next(i: *mut XCBXkbKeyModMapIterator);
+    pub fn xcb_xkb_key_mod_map_end(i: XCBXkbKeyModMapIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_key_v_mod_map_next(i: *mut XCBXkbKeyVModMapIterator);
+    pub fn xcb_xkb_key_v_mod_map_end(i: XCBXkbKeyVModMapIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_kt_set_map_entry_next(i: *mut XCBXkbKtSetMapEntryIterator);
+    pub fn xcb_xkb_kt_set_map_entry_end(i: XCBXkbKtSetMapEntryIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_set_key_type_next(i: *mut XCBXkbSetKeyTypeIterator);
+    pub fn xcb_xkb_set_key_type_end(i: XCBXkbSetKeyTypeIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_set_key_type_entries(R: *const XCBXkbSetKeyType) -> *mut XCBXkbKtSetMapEntry;
+    pub fn xcb_xkb_set_key_type_entries_length(R: *const XCBXkbSetKeyType) -> c_int;
+    pub fn xcb_xkb_set_key_type_entries_iterator(
+        R: *const XCBXkbSetKeyType,
+    ) -> XCBXkbKtSetMapEntryIterator;
+    pub fn xcb_xkb_set_key_type_preserve_entries(
+        R: *const XCBXkbSetKeyType,
+    ) -> *mut XCBXkbKtSetMapEntry;
+    pub fn xcb_xkb_set_key_type_preserve_entries_length(R: *const XCBXkbSetKeyType) -> c_int;
+    pub fn xcb_xkb_set_key_type_preserve_entries_iterator(
+        R: *const XCBXkbSetKeyType,
+    ) -> XCBXkbKtSetMapEntryIterator;
+    pub fn xcb_xkb_string8_next(i: *mut XCBXkbString8Iterator);
+    pub fn xcb_xkb_string8_end(i: XCBXkbString8Iterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_outline_next(i: *mut XCBXkbOutlineIterator);
+    pub fn xcb_xkb_outline_end(i: XCBXkbOutlineIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_outline_points(R: *const XCBXkbOutline) -> *mut XCBPoint;
+    pub fn xcb_xkb_outline_points_length(R: *const XCBXkbOutline) -> c_int;
+    pub fn xcb_xkb_outline_points_iterator(R: *const XCBXkbOutline) -> XCBPointIterator;
+    pub fn xcb_xkb_shape_next(i: *mut XCBXkbShapeIterator);
+    pub fn xcb_xkb_shape_end(i: XCBXkbShapeIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_shape_outlines_length(R: *const XCBXkbShape) -> c_int;
+    pub fn xcb_xkb_shape_outlines_iterator(R: *const XCBXkbShape) -> XCBXkbOutlineIterator;
+    pub fn xcb_xkb_key_next(i: *mut XCBXkbKeyIterator);
+    pub fn xcb_xkb_key_end(i: XCBXkbKeyIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_overlay_key_next(i: *mut XCBXkbOverlayKeyIterator);
+    pub fn xcb_xkb_overlay_key_end(i: XCBXkbOverlayKeyIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_overlay_row_next(i: *mut XCBXkbOverlayRowIterator);
+    pub fn xcb_xkb_overlay_row_end(i: XCBXkbOverlayRowIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_overlay_row_keys(R: *const XCBXkbOverlayRow) -> *mut XCBXkbOverlayKey;
+    pub fn xcb_xkb_overlay_row_keys_length(R: *const XCBXkbOverlayRow) -> c_int;
+    pub fn xcb_xkb_overlay_row_keys_iterator(
+        R: *const XCBXkbOverlayRow,
+    ) -> XCBXkbOverlayKeyIterator;
+    pub fn xcb_xkb_overlay_next(i: *mut XCBXkbOverlayIterator);
+    pub fn xcb_xkb_overlay_end(i: XCBXkbOverlayIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_overlay_rows_length(R: *const XCBXkbOverlay) -> c_int;
+    pub fn xcb_xkb_overlay_rows_iterator(R: *const XCBXkbOverlay) -> XCBXkbOverlayRowIterator;
+    pub fn xcb_xkb_row_next(i: *mut XCBXkbRowIterator);
+    pub fn xcb_xkb_row_end(i: XCBXkbRowIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_row_keys(R: *const XCBXkbRow) -> *mut XCBXkbKey;
+    pub fn xcb_xkb_row_keys_length(R: *const XCBXkbRow) -> c_int;
+    pub fn xcb_xkb_row_keys_iterator(R: *const XCBXkbRow) -> XCBXkbKeyIterator;
+    pub fn xcb_xkb_listing_next(i: *mut XCBXkbListingIterator);
+    pub fn xcb_xkb_listing_end(i: XCBXkbListingIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_listing_string(R: *const XCBXkbListing) -> *mut XCBXkbString8;
+    pub fn xcb_xkb_listing_string_length(R: *const XCBXkbListing) -> c_int;
+    pub fn xcb_xkb_listing_string_end(R: *const XCBXkbListing) -> XCBGenericIterator;
+    pub fn xcb_xkb_device_led_info_next(i: *mut XCBXkbDeviceLedInfoIterator);
+    pub fn xcb_xkb_device_led_info_end(i: XCBXkbDeviceLedInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_device_led_info_names(R: *const XCBXkbDeviceLedInfo) -> *mut XCBAtom;
+    pub fn xcb_xkb_device_led_info_names_length(R: *const XCBXkbDeviceLedInfo) -> c_int;
+    pub fn xcb_xkb_device_led_info_names_end(R: *const XCBXkbDeviceLedInfo) -> XCBGenericIterator;
+    pub fn xcb_xkb_device_led_info_maps(R: *const XCBXkbDeviceLedInfo) -> *mut XCBXkbIndicatorMap;
+    pub fn xcb_xkb_device_led_info_maps_length(R: *const XCBXkbDeviceLedInfo) -> c_int;
+    pub fn xcb_xkb_device_led_info_maps_iterator(
+        R: *const XCBXkbDeviceLedInfo,
+    ) -> XCBXkbIndicatorMapIterator;
+    pub fn xcb_xkb_sa_no_action_next(i: *mut XCBXkbSaNoActionIterator);
+    pub fn xcb_xkb_sa_no_action_end(i: XCBXkbSaNoActionIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_set_mods_next(i: *mut XCBXkbSaSetModsIterator);
+    pub fn xcb_xkb_sa_set_mods_end(i: XCBXkbSaSetModsIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_latch_mods_next(i: *mut XCBXkbSaLatchModsIterator);
+    pub fn xcb_xkb_sa_latch_mods_end(i: XCBXkbSaLatchModsIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_lock_mods_next(i: *mut XCBXkbSaLockModsIterator);
+    pub fn xcb_xkb_sa_lock_mods_end(i: XCBXkbSaLockModsIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_set_group_next(i: *mut XCBXkbSaSetGroupIterator);
+    pub fn xcb_xkb_sa_set_group_end(i: XCBXkbSaSetGroupIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_latch_group_next(i: *mut XCBXkbSaLatchGroupIterator);
+    pub fn xcb_xkb_sa_latch_group_end(i: XCBXkbSaLatchGroupIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_lock_group_next(i: *mut XCBXkbSaLockGroupIterator);
+    pub fn xcb_xkb_sa_lock_group_end(i: XCBXkbSaLockGroupIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_move_ptr_next(i: *mut XCBXkbSaMovePtrIterator);
+    pub fn xcb_xkb_sa_move_ptr_end(i: XCBXkbSaMovePtrIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_ptr_btn_next(i: *mut XCBXkbSaPtrBtnIterator);
+    pub fn xcb_xkb_sa_ptr_btn_end(i: XCBXkbSaPtrBtnIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_lock_ptr_btn_next(i: *mut XCBXkbSaLockPtrBtnIterator);
+    pub fn xcb_xkb_sa_lock_ptr_btn_end(i: XCBXkbSaLockPtrBtnIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_set_ptr_dflt_next(i: *mut XCBXkbSaSetPtrDfltIterator);
+    pub fn xcb_xkb_sa_set_ptr_dflt_end(i: XCBXkbSaSetPtrDfltIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_iso_lock_next(i: *mut XCBXkbSaIsoLockIterator);
+    pub fn xcb_xkb_sa_iso_lock_end(i: XCBXkbSaIsoLockIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_terminate_next(i: *mut XCBXkbSaTerminateIterator);
+    pub fn xcb_xkb_sa_terminate_end(i: XCBXkbSaTerminateIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_switch_screen_next(i: *mut XCBXkbSaSwitchScreenIterator);
+    pub fn xcb_xkb_sa_switch_screen_end(i: XCBXkbSaSwitchScreenIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_set_controls_next(i: *mut XCBXkbSaSetControlsIterator);
+    pub fn xcb_xkb_sa_set_controls_end(i: XCBXkbSaSetControlsIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_lock_controls_next(i: *mut XCBXkbSaLockControlsIterator);
+    pub fn xcb_xkb_sa_lock_controls_end(i: XCBXkbSaLockControlsIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_action_message_next(i: *mut XCBXkbSaActionMessageIterator);
+    pub fn xcb_xkb_sa_action_message_end(i: XCBXkbSaActionMessageIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_redirect_key_next(i: *mut XCBXkbSaRedirectKeyIterator);
+    pub fn xcb_xkb_sa_redirect_key_end(i: XCBXkbSaRedirectKeyIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_device_btn_next(i: *mut XCBXkbSaDeviceBtnIterator);
+    pub fn xcb_xkb_sa_device_btn_end(i: XCBXkbSaDeviceBtnIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_lock_device_btn_next(i: *mut XCBXkbSaLockDeviceBtnIterator);
+    pub fn xcb_xkb_sa_lock_device_btn_end(i: XCBXkbSaLockDeviceBtnIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sa_device_valuator_next(i: *mut XCBXkbSaDeviceValuatorIterator);
+    pub fn xcb_xkb_sa_device_valuator_end(i: XCBXkbSaDeviceValuatorIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_si_action_next(i: *mut XCBXkbSiActionIterator);
+    pub fn xcb_xkb_si_action_end(i: XCBXkbSiActionIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_sym_interpret_next(i: *mut XCBXkbSymInterpretIterator);
+    pub fn xcb_xkb_sym_interpret_end(i: XCBXkbSymInterpretIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_action_next(i: *mut XCBXkbActionIterator);
+    pub fn xcb_xkb_action_end(i: XCBXkbActionIterator) -> XCBGenericIterator;
+    pub fn xcb_xkb_use_extension_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbUseExtensionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbUseExtensionReply;
+    pub fn xcb_xkb_use_extension(
+        connection: *mut XCBConnection,
+        wantedMajor: u16,
+        wantedMinor: u16,
+    ) -> XCBXkbUseExtensionCookie;
+    pub fn xcb_xkb_use_extension_unchecked(
+        connection: *mut XCBConnection,
+        wantedMajor: u16,
+        wantedMinor: u16,
+    ) -> XCBXkbUseExtensionCookie;
+    pub fn xcb_xkb_select_events_checked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        affectWhich: u16,
+        clear: u16,
+        selectAll: u16,
+        affectMap: u16,
+        map: u16,
+        details: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_select_events(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        affectWhich: u16,
+        clear: u16,
+        selectAll: u16,
+        affectMap: u16,
+        map: u16,
+        details: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_bell_checked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        bellClass: XCBXkbBellClassSpec,
+        bellID: XCBXkbIdSpec,
+        percent: i8,
+        forceSound: u8,
+        eventOnly: u8,
+        pitch: i16,
+        duration: i16,
+        name: XCBAtom,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_bell(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        bellClass: XCBXkbBellClassSpec,
+        bellID: XCBXkbIdSpec,
+        percent: i8,
+        forceSound: u8,
+        eventOnly: u8,
+        pitch: i16,
+        duration: i16,
+        name: XCBAtom,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_get_state_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbGetStateCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbGetStateReply;
+    pub fn xcb_xkb_get_state(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+    ) -> XCBXkbGetStateCookie;
+    pub fn xcb_xkb_get_state_unchecked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+    ) -> XCBXkbGetStateCookie;
+    pub fn xcb_xkb_latch_lock_state_checked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        affectModLocks: u8,
+        modLocks: u8,
+        lockGroup: u8,
+        groupLock: u8,
+        affectModLatches: u8,
+        latchGroup: u8,
+        groupLatch: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_latch_lock_state(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        affectModLocks: u8,
+        modLocks: u8,
+        lockGroup: u8,
+        groupLock: u8,
+        affectModLatches: u8,
+        latchGroup: u8,
+        groupLatch: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_get_controls_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbGetControlsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbGetControlsReply;
+    pub fn xcb_xkb_get_controls(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+    ) -> XCBXkbGetControlsCookie;
+    pub fn xcb_xkb_get_controls_unchecked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+    ) -> XCBXkbGetControlsCookie;
+    pub fn xcb_xkb_set_controls_checked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        affectInternalRealMods: u8,
+        internalRealMods: u8,
+        affectIgnoreLockRealMods: u8,
+        ignoreLockRealMods: u8,
+        affectInternalVirtualMods: u16,
+        internalVirtualMods: u16,
+        affectIgnoreLockVirtualMods: u16,
+        ignoreLockVirtualMods: u16,
+        mouseKeysDfltBtn: u8,
+        groupsWrap: u8,
+        accessXOptions: u16,
+        affectEnabledControls: u32,
+        enabledControls: u32,
+        changeControls: u32,
+        repeatDelay: u16,
+        repeatInterval: u16,
+        slowKeysDelay: u16,
+        debounceDelay: u16,
+        mouseKeysDelay: u16,
+        mouseKeysInterval: u16,
+        mouseKeysTimeToMax: u16,
+        mouseKeysMaxSpeed: u16,
+        mouseKeysCurve: i16,
+        accessXTimeout: u16,
+        accessXTimeoutMask: u32,
+        accessXTimeoutValues: u32,
+        accessXTimeoutOptionsMask: u16,
+        accessXTimeoutOptionsValues: u16,
+        perKeyRepeat: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_set_controls(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        affectInternalRealMods: u8,
+        internalRealMods: u8,
+        affectIgnoreLockRealMods: u8,
+        ignoreLockRealMods: u8,
+        affectInternalVirtualMods: u16,
+        internalVirtualMods: u16,
+        affectIgnoreLockVirtualMods: u16,
+        ignoreLockVirtualMods: u16,
+        mouseKeysDfltBtn: u8,
+        groupsWrap: u8,
+        accessXOptions: u16,
+        affectEnabledControls: u32,
+        enabledControls: u32,
+        changeControls: u32,
+        repeatDelay: u16,
+        repeatInterval: u16,
+        slowKeysDelay: u16,
+        debounceDelay: u16,
+        mouseKeysDelay: u16,
+        mouseKeysInterval: u16,
+        mouseKeysTimeToMax: u16,
+        mouseKeysMaxSpeed: u16,
+        mouseKeysCurve: i16,
+        accessXTimeout: u16,
+        accessXTimeoutMask: u32,
+        accessXTimeoutValues: u32,
+        accessXTimeoutOptionsMask: u16,
+        accessXTimeoutOptionsValues: u16,
+        perKeyRepeat: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_get_map_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbGetMapCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbGetMapReply;
+    pub fn xcb_xkb_get_map(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        full: u16,
+        partial: u16,
+        firstType: u8,
+        nTypes: u8,
+        firstKeySym: XCBKeycode,
+        nKeySyms: u8,
+        firstKeyAction: XCBKeycode,
+        nKeyActions: u8,
+        firstKeyBehavior: XCBKeycode,
+        nKeyBehaviors: u8,
+        virtualMods: u16,
+        firstKeyExplicit: XCBKeycode,
+        nKeyExplicit: u8,
+        firstModMapKey: XCBKeycode,
+        nModMapKeys: u8,
+        firstVModMapKey: XCBKeycode,
+        nVModMapKeys: u8,
+    ) -> XCBXkbGetMapCookie;
+    pub fn xcb_xkb_get_map_unchecked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        full: u16,
+        partial: u16,
+        firstType: u8,
+        nTypes: u8,
+        firstKeySym: XCBKeycode,
+        nKeySyms: u8,
+        firstKeyAction: XCBKeycode,
+        nKeyActions: u8,
+        firstKeyBehavior: XCBKeycode,
+        nKeyBehaviors: u8,
+        virtualMods: u16,
+        firstKeyExplicit: XCBKeycode,
+        nKeyExplicit: u8,
+        firstModMapKey: XCBKeycode,
+        nModMapKeys: u8,
+        firstVModMapKey: XCBKeycode,
+        nVModMapKeys: u8,
+    ) -> XCBXkbGetMapCookie;
+    pub fn xcb_xkb_set_map_checked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        present: u16,
+        flags: u16,
+        minKeyCode: XCBKeycode,
+        maxKeyCode: XCBKeycode,
+        firstType: u8,
+        nTypes: u8,
+        firstKeySym: XCBKeycode,
+        nKeySyms: u8,
+        totalSyms: u16,
+        firstKeyAction: XCBKeycode,
+        nKeyActions: u8,
+        totalActions: u16,
+        firstKeyBehavior: XCBKeycode,
+        nKeyBehaviors: u8,
+        totalKeyBehaviors: u8,
+        firstKeyExplicit: XCBKeycode,
+        nKeyExplicit: u8,
+        totalKeyExplicit: u8,
+        firstModMapKey: XCBKeycode,
+        nModMapKeys: u8,
+        totalModMapKeys: u8,
+        firstVModMapKey: XCBKeycode,
+        nVModMapKeys: u8,
+        totalVModMapKeys: u8,
+        virtualMods: u16,
+        values: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_set_map(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        present: u16,
+        flags: u16,
+        minKeyCode: XCBKeycode,
+        maxKeyCode: XCBKeycode,
+        firstType: u8,
+        nTypes: u8,
+        firstKeySym: XCBKeycode,
+        nKeySyms: u8,
+        totalSyms: u16,
+        firstKeyAction: XCBKeycode,
+        nKeyActions: u8,
+        totalActions: u16,
+        firstKeyBehavior: XCBKeycode,
+        nKeyBehaviors: u8,
+        totalKeyBehaviors: u8,
+        firstKeyExplicit: XCBKeycode,
+        nKeyExplicit: u8,
+        totalKeyExplicit: u8,
+        firstModMapKey: XCBKeycode,
+        nModMapKeys: u8,
+        totalModMapKeys: u8,
+        firstVModMapKey: XCBKeycode,
+        nVModMapKeys: u8,
+        totalVModMapKeys: u8,
+        virtualMods: u16,
+        values: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_get_compat_map_si_rtrn(
+        R: *const XCBXkbGetCompatMapReply,
+    ) -> *mut XCBXkbSymInterpret;
+    pub fn xcb_xkb_get_compat_map_si_rtrn_length(R: *const XCBXkbGetCompatMapReply) -> c_int;
+    pub fn xcb_xkb_get_compat_map_si_rtrn_iterator(
+        R: *const XCBXkbGetCompatMapReply,
+    ) -> XCBXkbSymInterpretIterator;
+    pub fn xcb_xkb_get_compat_map_group_rtrn(
+        R: *const XCBXkbGetCompatMapReply,
+    ) -> *mut XCBXkbModDef;
+    pub fn xcb_xkb_get_compat_map_group_rtrn_length(R: *const XCBXkbGetCompatMapReply) -> c_int;
+    pub fn xcb_xkb_get_compat_map_group_rtrn_iterator(
+        R: *const XCBXkbGetCompatMapReply,
+    ) -> XCBXkbModDefIterator;
+    pub fn xcb_xkb_get_compat_map_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbGetCompatMapCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbGetCompatMapReply;
+    pub fn xcb_xkb_get_compat_map(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        groups: u8,
+        getAllSI: u8,
+        firstSI: u16,
+        nSI: u16,
+    ) -> XCBXkbGetCompatMapCookie;
+    pub fn xcb_xkb_get_compat_map_unchecked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        groups: u8,
+        getAllSI: u8,
+        firstSI: u16,
+        nSI: u16,
+    ) -> XCBXkbGetCompatMapCookie;
+    pub fn xcb_xkb_set_compat_map_checked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        recomputeActions: u8,
+        truncateSI: u8,
+        groups: u8,
+        firstSI: u16,
+        nSI: u16,
+        si: *const XCBXkbSymInterpret,
+        groupMaps: *const XCBXkbModDef,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_set_compat_map(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        recomputeActions: u8,
+        truncateSI: u8,
+        groups: u8,
+        firstSI: u16,
+        nSI: u16,
+        si: *const XCBXkbSymInterpret,
+        groupMaps: *const XCBXkbModDef,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_get_indicator_state_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbGetIndicatorStateCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbGetIndicatorStateReply;
+    pub fn xcb_xkb_get_indicator_state(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+    ) -> XCBXkbGetIndicatorStateCookie;
+    pub fn xcb_xkb_get_indicator_state_unchecked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+    ) -> XCBXkbGetIndicatorStateCookie;
+    pub fn xcb_xkb_get_indicator_map_maps(
+        R: *const XCBXkbGetIndicatorMapReply,
+    ) -> *mut XCBXkbIndicatorMap;
+    pub fn xcb_xkb_get_indicator_map_maps_length(R: *const XCBXkbGetIndicatorMapReply) -> c_int;
+    pub fn xcb_xkb_get_indicator_map_maps_iterator(
+        R: *const XCBXkbGetIndicatorMapReply,
+    ) -> XCBXkbIndicatorMapIterator;
+    pub fn xcb_xkb_get_indicator_map_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbGetIndicatorMapCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbGetIndicatorMapReply;
+    pub fn xcb_xkb_get_indicator_map(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        which: u32,
+    ) -> XCBXkbGetIndicatorMapCookie;
+    pub fn xcb_xkb_get_indicator_map_unchecked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        which: u32,
+    ) -> XCBXkbGetIndicatorMapCookie;
+    pub fn xcb_xkb_set_indicator_map_checked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        which: u32,
+        maps: *const XCBXkbIndicatorMap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_set_indicator_map(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        which: u32,
+        maps: *const XCBXkbIndicatorMap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_get_named_indicator_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbGetNamedIndicatorCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbGetNamedIndicatorReply;
+    pub fn xcb_xkb_get_named_indicator(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        ledClass: XCBXkbLedClassSpec,
+        ledID: XCBXkbIdSpec,
+        indicator: XCBAtom,
+    ) -> XCBXkbGetNamedIndicatorCookie;
+    pub fn xcb_xkb_get_named_indicator_unchecked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        ledClass: XCBXkbLedClassSpec,
+        ledID: XCBXkbIdSpec,
+        indicator: XCBAtom,
+    ) -> XCBXkbGetNamedIndicatorCookie;
+    pub fn xcb_xkb_set_named_indicator_checked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        ledClass: XCBXkbLedClassSpec,
+        ledID: XCBXkbIdSpec,
+        indicator: XCBAtom,
+        setState: u8,
+        on: u8,
+        setMap: u8,
+        createMap: u8,
+        map_flags: u8,
+        map_whichGroups: u8,
+        map_groups: u8,
+        map_whichMods: u8,
+        map_realMods: u8,
+        map_vmods: u16,
+        map_ctrls: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_set_named_indicator(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        ledClass: XCBXkbLedClassSpec,
+        ledID: XCBXkbIdSpec,
+        indicator: XCBAtom,
+        setState: u8,
+        on: u8,
+        setMap: u8,
+        createMap: u8,
+        map_flags: u8,
+        map_whichGroups: u8,
+        map_groups: u8,
+        map_whichMods: u8,
+        map_realMods: u8,
+        map_vmods: u16,
+        map_ctrls: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_get_names_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbGetNamesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbGetNamesReply;
+    pub fn xcb_xkb_get_names(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        which: u32,
+    ) -> XCBXkbGetNamesCookie;
+    pub fn xcb_xkb_get_names_unchecked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        which: u32,
+    ) -> XCBXkbGetNamesCookie;
+    pub fn xcb_xkb_set_names_checked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        virtualMods: u16,
+        which: u32,
+        firstType: u8,
+        nTypes: u8,
+        firstKTLevelt: u8,
+        nKTLevels: u8,
+        indicators: u32,
+        groupNames: u8,
+        nRadioGroups: u8,
+        firstKey: XCBKeycode,
+        nKeys: u8,
+        nKeyAliases: u8,
+        totalKTLevelNames: u16,
+        values: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_set_names(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        virtualMods: u16,
+        which: u32,
+        firstType: u8,
+        nTypes: u8,
+        firstKTLevelt: u8,
+        nKTLevels: u8,
+        indicators: u32,
+        groupNames: u8,
+        nRadioGroups: u8,
+        firstKey: XCBKeycode,
+        nKeys: u8,
+        nKeyAliases: u8,
+        totalKTLevelNames: u16,
+        values: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_per_client_flags_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbPerClientFlagsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbPerClientFlagsReply;
+    pub fn xcb_xkb_per_client_flags(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        change: u32,
+        value: u32,
+        ctrlsToChange: u32,
+        autoCtrls: u32,
+        autoCtrlsValues: u32,
+    ) -> XCBXkbPerClientFlagsCookie;
+    pub fn xcb_xkb_per_client_flags_unchecked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        change: u32,
+        value: u32,
+        ctrlsToChange: u32,
+        autoCtrls: u32,
+        autoCtrlsValues: u32,
+    ) -> XCBXkbPerClientFlagsCookie;
+    pub fn xcb_xkb_list_components_keymaps_length(R: *const XCBXkbListComponentsReply) -> c_int;
+    pub fn xcb_xkb_list_components_keymaps_iterator(
+        R: *const XCBXkbListComponentsReply,
+    ) -> XCBXkbListingIterator;
+    pub fn xcb_xkb_list_components_keycodes_length(R: *const XCBXkbListComponentsReply) -> c_int;
+    pub fn xcb_xkb_list_components_keycodes_iterator(
+        R: *const XCBXkbListComponentsReply,
+    ) -> XCBXkbListingIterator;
+    pub fn xcb_xkb_list_components_types_length(R: *const XCBXkbListComponentsReply) -> c_int;
+    pub fn xcb_xkb_list_components_types_iterator(
+        R: *const XCBXkbListComponentsReply,
+    ) -> XCBXkbListingIterator;
+    pub fn xcb_xkb_list_components_compat_maps_length(R: *const XCBXkbListComponentsReply)
+        -> c_int;
+    pub fn xcb_xkb_list_components_compat_maps_iterator(
+        R: *const XCBXkbListComponentsReply,
+    ) -> XCBXkbListingIterator;
+    pub fn xcb_xkb_list_components_symbols_length(R: *const XCBXkbListComponentsReply) -> c_int;
+    pub fn xcb_xkb_list_components_symbols_iterator(
+        R: *const XCBXkbListComponentsReply,
+    ) -> XCBXkbListingIterator;
+    pub fn xcb_xkb_list_components_geometries_length(R: *const XCBXkbListComponentsReply) -> c_int;
+    pub fn xcb_xkb_list_components_geometries_iterator(
+        R: *const XCBXkbListComponentsReply,
+    ) -> XCBXkbListingIterator;
+    pub fn xcb_xkb_list_components_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbListComponentsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbListComponentsReply;
+    pub fn xcb_xkb_list_components(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        maxNames: u16,
+    ) -> XCBXkbListComponentsCookie;
+    pub fn xcb_xkb_list_components_unchecked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        maxNames: u16,
+    ) -> XCBXkbListComponentsCookie;
+    pub fn xcb_xkb_get_kbd_by_name_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbGetKbdByNameCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbGetKbdByNameReply;
+    pub fn xcb_xkb_get_kbd_by_name(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        need: u16,
+        want: u16,
+        load: u8,
+    ) -> XCBXkbGetKbdByNameCookie;
+    pub fn xcb_xkb_get_kbd_by_name_unchecked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        need: u16,
+        want: u16,
+        load: u8,
+    ) -> XCBXkbGetKbdByNameCookie;
+    pub fn xcb_xkb_get_device_info_name(R: *const XCBXkbGetDeviceInfoReply) -> *mut XCBXkbString8;
+    pub fn xcb_xkb_get_device_info_name_length(R: *const XCBXkbGetDeviceInfoReply) -> c_int;
+    pub fn xcb_xkb_get_device_info_name_end(
+        R: *const XCBXkbGetDeviceInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xkb_get_device_info_btn_actions(
+        R: *const XCBXkbGetDeviceInfoReply,
+    ) -> *mut XCBXkbAction;
+    pub fn xcb_xkb_get_device_info_btn_actions_length(R: *const XCBXkbGetDeviceInfoReply) -> c_int;
+    pub fn xcb_xkb_get_device_info_btn_actions_iterator(
+        R: *const XCBXkbGetDeviceInfoReply,
+    ) -> XCBXkbActionIterator;
+    pub fn xcb_xkb_get_device_info_leds_length(R: *const XCBXkbGetDeviceInfoReply) -> c_int;
+    pub fn xcb_xkb_get_device_info_leds_iterator(
+        R: *const XCBXkbGetDeviceInfoReply,
+    ) -> XCBXkbDeviceLedInfoIterator;
+    pub fn xcb_xkb_get_device_info_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbGetDeviceInfoCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbGetDeviceInfoReply;
+    pub fn xcb_xkb_get_device_info(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        wanted: u16,
+        allButtons: u8,
+        firstButton: u8,
+        nButtons: u8,
+        ledClass: XCBXkbLedClassSpec,
+        ledID: XCBXkbIdSpec,
+    ) -> XCBXkbGetDeviceInfoCookie;
+    pub fn xcb_xkb_get_device_info_unchecked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        wanted: u16,
+        allButtons: u8,
+        firstButton: u8,
+        nButtons: u8,
+        ledClass: XCBXkbLedClassSpec,
+        ledID: XCBXkbIdSpec,
+    ) -> XCBXkbGetDeviceInfoCookie;
+    pub fn xcb_xkb_set_device_info_checked(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        firstBtn: u8,
+        nBtns: u8,
+        change: u16,
+        nDeviceLedFBs: u16,
+        btnActions: *const XCBXkbAction,
+        leds: *const XCBXkbDeviceLedInfo,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_set_device_info(
+        connection: *mut XCBConnection,
+        deviceSpec: XCBXkbDeviceSpec,
+        firstBtn: u8,
+        nBtns: u8,
+        change: u16,
+        nDeviceLedFBs: u16,
+        btnActions: *const XCBXkbAction,
+        leds: *const XCBXkbDeviceLedInfo,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xkb_set_debugging_flags_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXkbSetDebuggingFlagsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXkbSetDebuggingFlagsReply;
+    pub fn xcb_xkb_set_debugging_flags(
+        connection: *mut XCBConnection,
+        msgLength: u16,
+        affectFlags: u32,
+        flags: u32,
+        affectCtrls: u32,
+        ctrls: u32,
+        message: *const XCBXkbString8,
+    ) -> XCBXkbSetDebuggingFlagsCookie;
+    pub fn xcb_xkb_set_debugging_flags_unchecked(
+        connection: *mut XCBConnection,
+        msgLength: u16,
+        affectFlags: u32,
+        flags: u32,
+        affectCtrls: u32,
+        ctrls: u32,
+        message: *const XCBXkbString8,
+    ) -> XCBXkbSetDebuggingFlagsCookie;
+}
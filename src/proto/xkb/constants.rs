@@ -0,0 +1,328 @@
+// Generated by xcbgen from xml/xkb.xml, do not edit.
+
+pub const XCB_XKB_MAJOR_VERSION: u32 = 1;
+pub const XCB_XKB_MINOR_VERSION: u32 = 0;
+pub const XCB_XKB_CONST_MAX_LEGAL_KEY_CODE: u32 = 255;
+pub const XCB_XKB_CONST_PER_KEY_BIT_ARRAY_SIZE: u32 = 32;
+pub const XCB_XKB_CONST_KEY_NAME_LENGTH: u32 = 4;
+pub const XCB_XKB_EVENT_TYPE_NEW_KEYBOARD_NOTIFY: u16 = 1;
+pub const XCB_XKB_EVENT_TYPE_MAP_NOTIFY: u16 = 2;
+pub const XCB_XKB_EVENT_TYPE_STATE_NOTIFY: u16 = 4;
+pub const XCB_XKB_EVENT_TYPE_CONTROLS_NOTIFY: u16 = 8;
+pub const XCB_XKB_EVENT_TYPE_INDICATOR_STATE_NOTIFY: u16 = 16;
+pub const XCB_XKB_EVENT_TYPE_INDICATOR_MAP_NOTIFY: u16 = 32;
+pub const XCB_XKB_EVENT_TYPE_NAMES_NOTIFY: u16 = 64;
+pub const XCB_XKB_EVENT_TYPE_COMPAT_MAP_NOTIFY: u16 = 128;
+pub const XCB_XKB_EVENT_TYPE_BELL_NOTIFY: u16 = 256;
+pub const XCB_XKB_EVENT_TYPE_ACTION_MESSAGE: u16 = 512;
+pub const XCB_XKB_EVENT_TYPE_ACCESS_X_NOTIFY: u16 = 1024;
+pub const XCB_XKB_EVENT_TYPE_EXTENSION_DEVICE_NOTIFY: u16 = 2048;
+pub const XCB_XKB_NKN_DETAIL_KEYCODES: u16 = 1;
+pub const XCB_XKB_NKN_DETAIL_GEOMETRY: u16 = 2;
+pub const XCB_XKB_NKN_DETAIL_DEVICE_ID: u16 = 4;
+pub const XCB_XKB_AXN_DETAIL_SK_PRESS: u16 = 1;
+pub const XCB_XKB_AXN_DETAIL_SK_ACCEPT: u16 = 2;
+pub const XCB_XKB_AXN_DETAIL_SK_REJECT: u16 = 4;
+pub const XCB_XKB_AXN_DETAIL_SK_RELEASE: u16 = 8;
+pub const XCB_XKB_AXN_DETAIL_BK_ACCEPT: u16 = 16;
+pub const XCB_XKB_AXN_DETAIL_BK_REJECT: u16 = 32;
+pub const XCB_XKB_AXN_DETAIL_AXK_WARNING: u16 = 64;
+pub const XCB_XKB_MAP_PART_KEY_TYPES: u16 = 1;
+pub const XCB_XKB_MAP_PART_KEY_SYMS: u16 = 2;
+pub const XCB_XKB_MAP_PART_MODIFIER_MAP: u16 = 4;
+pub const XCB_XKB_MAP_PART_EXPLICIT_COMPONENTS: u16 = 8;
+pub const XCB_XKB_MAP_PART_KEY_ACTIONS: u16 = 16;
+pub const XCB_XKB_MAP_PART_KEY_BEHAVIORS: u16 = 32;
+pub const XCB_XKB_MAP_PART_VIRTUAL_MODS: u16 = 64;
+pub const XCB_XKB_MAP_PART_VIRTUAL_MOD_MAP: u16 = 128;
+pub const XCB_XKB_SET_MAP_FLAGS_RESIZE_TYPES: u16 = 1;
+pub const XCB_XKB_SET_MAP_FLAGS_RECOMPUTE_ACTIONS: u16 = 2;
+pub const XCB_XKB_STATE_PART_MODIFIER_STATE: u16 = 1;
+pub const XCB_XKB_STATE_PART_MODIFIER_BASE: u16 = 2;
+pub const XCB_XKB_STATE_PART_MODIFIER_LATCH: u16 = 4;
+pub const XCB_XKB_STATE_PART_MODIFIER_LOCK: u16 = 8;
+pub const XCB_XKB_STATE_PART_GROUP_STATE: u16 = 16;
+pub const XCB_XKB_STATE_PART_GROUP_BASE: u16 = 32;
+pub const XCB_XKB_STATE_PART_GROUP_LATCH: u16 = 64;
+pub const XCB_XKB_STATE_PART_GROUP_LOCK: u16 = 128;
+pub const XCB_XKB_STATE_PART_COMPAT_STATE: u16 = 256;
+pub const XCB_XKB_STATE_PART_GRAB_MODS: u16 = 512;
+pub const XCB_XKB_STATE_PART_COMPAT_GRAB_MODS: u16 = 1024;
+pub const XCB_XKB_STATE_PART_LOOKUP_MODS: u16 = 2048;
+pub const XCB_XKB_STATE_PART_COMPAT_LOOKUP_MODS: u16 = 4096;
+pub const XCB_XKB_STATE_PART_POINTER_BUTTONS: u16 = 8192;
+pub const XCB_XKB_BOOL_CTRL_REPEAT_KEYS: u32 = 1;
+pub const XCB_XKB_BOOL_CTRL_SLOW_KEYS: u32 = 2;
+pub const XCB_XKB_BOOL_CTRL_BOUNCE_KEYS: u32 = 4;
+pub const XCB_XKB_BOOL_CTRL_STICKY_KEYS: u32 = 8;
+pub const XCB_XKB_BOOL_CTRL_MOUSE_KEYS: u32 = 16;
+pub const XCB_XKB_BOOL_CTRL_MOUSE_KEYS_ACCEL: u32 = 32;
+pub const XCB_XKB_BOOL_CTRL_ACCESS_X_KEYS: u32 = 64;
+pub const XCB_XKB_BOOL_CTRL_ACCESS_X_TIMEOUT_MASK: u32 = 128;
+pub const XCB_XKB_BOOL_CTRL_ACCESS_X_FEEDBACK_MASK: u32 = 256;
+pub const XCB_XKB_BOOL_CTRL_AUDIBLE_BELL_MASK: u32 = 512;
+pub const XCB_XKB_BOOL_CTRL_OVERLAY_1_MASK: u32 = 1024;
+pub const XCB_XKB_BOOL_CTRL_OVERLAY_2_MASK: u32 = 2048;
+pub const XCB_XKB_BOOL_CTRL_IGNORE_GROUP_LOCK_MASK: u32 = 4096;
+pub const XCB_XKB_CONTROL_GROUPS_WRAP: u32 = 134217728;
+pub const XCB_XKB_CONTROL_INTERNAL_MODS: u32 = 268435456;
+pub const XCB_XKB_CONTROL_IGNORE_LOCK_MODS: u32 = 536870912;
+pub const XCB_XKB_CONTROL_PER_KEY_REPEAT: u32 = 1073741824;
+pub const XCB_XKB_CONTROL_CONTROLS_ENABLED: u32 = 2147483648;
+pub const XCB_XKB_AX_OPTION_SK_PRESS_FB: u16 = 1;
+pub const XCB_XKB_AX_OPTION_SK_ACCEPT_FB: u16 = 2;
+pub const XCB_XKB_AX_OPTION_FEATURE_FB: u16 = 4;
+pub const XCB_XKB_AX_OPTION_SLOW_WARN_FB: u16 = 8;
+pub const XCB_XKB_AX_OPTION_INDICATOR_FB: u16 = 16;
+pub const XCB_XKB_AX_OPTION_STICKY_KEYS_FB: u16 = 32;
+pub const XCB_XKB_AX_OPTION_TWO_KEYS: u16 = 64;
+pub const XCB_XKB_AX_OPTION_LATCH_TO_LOCK: u16 = 128;
+pub const XCB_XKB_AX_OPTION_SK_RELEASE_FB: u16 = 256;
+pub const XCB_XKB_AX_OPTION_SK_REJECT_FB: u16 = 512;
+pub const XCB_XKB_AX_OPTION_BK_REJECT_FB: u16 = 1024;
+pub const XCB_XKB_AX_OPTION_DUMB_BELL: u16 = 2048;
+pub const XCB_XKB_LED_CLASS_RESULT_KBD_FEEDBACK_CLASS: u16 = 0;
+pub const XCB_XKB_LED_CLASS_RESULT_LED_FEEDBACK_CLASS: u16 = 4;
+pub const XCB_XKB_LED_CLASS_KBD_FEEDBACK_CLASS: u16 = 0;
+pub const XCB_XKB_LED_CLASS_LED_FEEDBACK_CLASS: u16 = 4;
+pub const XCB_XKB_LED_CLASS_DFLT_XI_CLASS: u16 = 768;
+pub const XCB_XKB_LED_CLASS_ALL_XI_CLASSES: u16 = 1280;
+pub const XCB_XKB_BELL_CLASS_RESULT_KBD_FEEDBACK_CLASS: u8 = 0;
+pub const XCB_XKB_BELL_CLASS_RESULT_BELL_FEEDBACK_CLASS: u8 = 5;
+pub const XCB_XKB_BELL_CLASS_KBD_FEEDBACK_CLASS: u32 = 0;
+pub const XCB_XKB_BELL_CLASS_BELL_FEEDBACK_CLASS: u32 = 5;
+pub const XCB_XKB_BELL_CLASS_DFLT_XI_CLASS: u32 = 768;
+pub const XCB_XKB_ID_USE_CORE_KBD: u16 = 256;
+pub const XCB_XKB_ID_USE_CORE_PTR: u16 = 512;
+pub const XCB_XKB_ID_DFLT_XI_CLASS: u16 = 768;
+pub const XCB_XKB_ID_DFLT_XI_ID: u16 = 1024;
+pub const XCB_XKB_ID_ALL_XI_CLASS: u16 = 1280;
+pub const XCB_XKB_ID_ALL_XI_ID: u16 = 1536;
+pub const XCB_XKB_ID_XI_NONE: u16 = 65280;
+pub const XCB_XKB_GROUP_1: u8 = 0;
+pub const XCB_XKB_GROUP_2: u8 = 1;
+pub const XCB_XKB_GROUP_3: u8 = 2;
+pub const XCB_XKB_GROUP_4: u8 = 3;
+pub const XCB_XKB_GROUPS_ANY: u32 = 254;
+pub const XCB_XKB_GROUPS_ALL: u32 = 255;
+pub const XCB_XKB_SET_OF_GROUP_GROUP_1: u8 = 1;
+pub const XCB_XKB_SET_OF_GROUP_GROUP_2: u8 = 2;
+pub const XCB_XKB_SET_OF_GROUP_GROUP_3: u8 = 4;
+pub const XCB_XKB_SET_OF_GROUP_GROUP_4: u8 = 8;
+pub const XCB_XKB_SET_OF_GROUPS_ANY: u8 = 128;
+pub const XCB_XKB_GROUPS_WRAP_WRAP_INTO_RANGE: u32 = 0;
+pub const XCB_XKB_GROUPS_WRAP_CLAMP_INTO_RANGE: u32 = 64;
+pub const XCB_XKB_GROUPS_WRAP_REDIRECT_INTO_RANGE: u32 = 128;
+pub const XCB_XKB_V_MODS_HIGH_15: u8 = 128;
+pub const XCB_XKB_V_MODS_HIGH_14: u8 = 64;
+pub const XCB_XKB_V_MODS_HIGH_13: u8 = 32;
+pub const XCB_XKB_V_MODS_HIGH_12: u8 = 16;
+pub const XCB_XKB_V_MODS_HIGH_11: u8 = 8;
+pub const XCB_XKB_V_MODS_HIGH_10: u8 = 4;
+pub const XCB_XKB_V_MODS_HIGH_9: u8 = 2;
+pub const XCB_XKB_V_MODS_HIGH_8: u8 = 1;
+pub const XCB_XKB_V_MODS_LOW_7: u8 = 128;
+pub const XCB_XKB_V_MODS_LOW_6: u8 = 64;
+pub const XCB_XKB_V_MODS_LOW_5: u8 = 32;
+pub const XCB_XKB_V_MODS_LOW_4: u8 = 16;
+pub const XCB_XKB_V_MODS_LOW_3: u8 = 8;
+pub const XCB_XKB_V_MODS_LOW_2: u8 = 4;
+pub const XCB_XKB_V_MODS_LOW_1: u8 = 2;
+pub const XCB_XKB_V_MODS_LOW_0: u8 = 1;
+pub const XCB_XKB_V_MOD_15: u16 = 32768;
+pub const XCB_XKB_V_MOD_14: u16 = 16384;
+pub const XCB_XKB_V_MOD_13: u16 = 8192;
+pub const XCB_XKB_V_MOD_12: u16 = 4096;
+pub const XCB_XKB_V_MOD_11: u16 = 2048;
+pub const XCB_XKB_V_MOD_10: u16 = 1024;
+pub const XCB_XKB_V_MOD_9: u16 = 512;
+pub const XCB_XKB_V_MOD_8: u16 = 256;
+pub const XCB_XKB_V_MOD_7: u16 = 128;
+pub const XCB_XKB_V_MOD_6: u16 = 64;
+pub const XCB_XKB_V_MOD_5: u16 = 32;
+pub const XCB_XKB_V_MOD_4: u16 = 16;
+pub const XCB_XKB_V_MOD_3: u16 = 8;
+pub const XCB_XKB_V_MOD_2: u16 = 4;
+pub const XCB_XKB_V_MOD_1: u16 = 2;
+pub const XCB_XKB_V_MOD_0: u16 = 1;
+pub const XCB_XKB_EXPLICIT_V_MOD_MAP: u8 = 128;
+pub const XCB_XKB_EXPLICIT_BEHAVIOR: u8 = 64;
+pub const XCB_XKB_EXPLICIT_AUTO_REPEAT: u8 = 32;
+pub const XCB_XKB_EXPLICIT_INTERPRET: u8 = 16;
+pub const XCB_XKB_EXPLICIT_KEY_TYPE_4: u8 = 8;
+pub const XCB_XKB_EXPLICIT_KEY_TYPE_3: u8 = 4;
+pub const XCB_XKB_EXPLICIT_KEY_TYPE_2: u8 = 2;
+pub const XCB_XKB_EXPLICIT_KEY_TYPE_1: u8 = 1;
+pub const XCB_XKB_SYM_INTERPRET_MATCH_NONE_OF: u8 = 0;
+pub const XCB_XKB_SYM_INTERPRET_MATCH_ANY_OF_OR_NONE: u8 = 1;
+pub const XCB_XKB_SYM_INTERPRET_MATCH_ANY_OF: u8 = 2;
+pub const XCB_XKB_SYM_INTERPRET_MATCH_ALL_OF: u8 = 3;
+pub const XCB_XKB_SYM_INTERPRET_MATCH_EXACTLY: u8 = 4;
+pub const XCB_XKB_SYM_INTERP_MATCH_LEVEL_ONE_ONLY: u32 = 128;
+pub const XCB_XKB_SYM_INTERP_MATCH_OP_MASK: u32 = 127;
+pub const XCB_XKB_IM_FLAG_NO_EXPLICIT: u8 = 128;
+pub const XCB_XKB_IM_FLAG_NO_AUTOMATIC: u8 = 64;
+pub const XCB_XKB_IM_FLAG_LED_DRIVES_KB: u8 = 32;
+pub const XCB_XKB_IM_MODS_WHICH_USE_COMPAT: u8 = 16;
+pub const XCB_XKB_IM_MODS_WHICH_USE_EFFECTIVE: u8 = 8;
+pub const XCB_XKB_IM_MODS_WHICH_USE_LOCKED: u8 = 4;
+pub const XCB_XKB_IM_MODS_WHICH_USE_LATCHED: u8 = 2;
+pub const XCB_XKB_IM_MODS_WHICH_USE_BASE: u8 = 1;
+pub const XCB_XKB_IM_GROUPS_WHICH_USE_COMPAT: u8 = 16;
+pub const XCB_XKB_IM_GROUPS_WHICH_USE_EFFECTIVE: u8 = 8;
+pub const XCB_XKB_IM_GROUPS_WHICH_USE_LOCKED: u8 = 4;
+pub const XCB_XKB_IM_GROUPS_WHICH_USE_LATCHED: u8 = 2;
+pub const XCB_XKB_IM_GROUPS_WHICH_USE_BASE: u8 = 1;
+pub const XCB_XKB_CM_DETAIL_SYM_INTERP: u8 = 1;
+pub const XCB_XKB_CM_DETAIL_GROUP_COMPAT: u8 = 2;
+pub const XCB_XKB_NAME_DETAIL_KEYCODES: u32 = 1;
+pub const XCB_XKB_NAME_DETAIL_GEOMETRY: u32 = 2;
+pub const XCB_XKB_NAME_DETAIL_SYMBOLS: u32 = 4;
+pub const XCB_XKB_NAME_DETAIL_PHYS_SYMBOLS: u32 = 8;
+pub const XCB_XKB_NAME_DETAIL_TYPES: u32 = 16;
+pub const XCB_XKB_NAME_DETAIL_COMPAT: u32 = 32;
+pub const XCB_XKB_NAME_DETAIL_KEY_TYPE_NAMES: u32 = 64;
+pub const XCB_XKB_NAME_DETAIL_KT_LEVEL_NAMES: u32 = 128;
+pub const XCB_XKB_NAME_DETAIL_INDICATOR_NAMES: u32 = 256;
+pub const XCB_XKB_NAME_DETAIL_KEY_NAMES: u32 = 512;
+pub const XCB_XKB_NAME_DETAIL_KEY_ALIASES: u32 = 1024;
+pub const XCB_XKB_NAME_DETAIL_VIRTUAL_MOD_NAMES: u32 = 2048;
+pub const XCB_XKB_NAME_DETAIL_GROUP_NAMES: u32 = 4096;
+pub const XCB_XKB_NAME_DETAIL_RG_NAMES: u32 = 8192;
+pub const XCB_XKB_GBN_DETAIL_TYPES: u16 = 1;
+pub const XCB_XKB_GBN_DETAIL_COMPAT_MAP: u16 = 2;
+pub const XCB_XKB_GBN_DETAIL_CLIENT_SYMBOLS: u16 = 4;
+pub const XCB_XKB_GBN_DETAIL_SERVER_SYMBOLS: u16 = 8;
+pub const XCB_XKB_GBN_DETAIL_INDICATOR_MAPS: u16 = 16;
+pub const XCB_XKB_GBN_DETAIL_KEY_NAMES: u16 = 32;
+pub const XCB_XKB_GBN_DETAIL_GEOMETRY: u16 = 64;
+pub const XCB_XKB_GBN_DETAIL_OTHER_NAMES: u16 = 128;
+pub const XCB_XKB_XI_FEATURE_KEYBOARDS: u16 = 1;
+pub const XCB_XKB_XI_FEATURE_BUTTON_ACTIONS: u16 = 2;
+pub const XCB_XKB_XI_FEATURE_INDICATOR_NAMES: u16 = 4;
+pub const XCB_XKB_XI_FEATURE_INDICATOR_MAPS: u16 = 8;
+pub const XCB_XKB_XI_FEATURE_INDICATOR_STATE: u16 = 16;
+pub const XCB_XKB_PER_CLIENT_FLAG_DETECTABLE_AUTO_REPEAT: u32 = 1;
+pub const XCB_XKB_PER_CLIENT_FLAG_GRABS_USE_XKB_STATE: u32 = 2;
+pub const XCB_XKB_PER_CLIENT_FLAG_AUTO_RESET_CONTROLS: u32 = 4;
+pub const XCB_XKB_PER_CLIENT_FLAG_LOOKUP_STATE_WHEN_GRABBED: u32 = 8;
+pub const XCB_XKB_PER_CLIENT_FLAG_SEND_EVENT_USES_XKB_STATE: u32 = 16;
+pub const XCB_XKB_BEHAVIOR_TYPE_DEFAULT: u32 = 0;
+pub const XCB_XKB_BEHAVIOR_TYPE_LOCK: u32 = 1;
+pub const XCB_XKB_BEHAVIOR_TYPE_RADIO_GROUP: u32 = 2;
+pub const XCB_XKB_BEHAVIOR_TYPE_OVERLAY_1: u32 = 3;
+pub const XCB_XKB_BEHAVIOR_TYPE_OVERLAY_2: u32 = 4;
+pub const XCB_XKB_BEHAVIOR_TYPE_PERMAMENT_LOCK: u32 = 129;
+pub const XCB_XKB_BEHAVIOR_TYPE_PERMAMENT_RADIO_GROUP: u32 = 130;
+pub const XCB_XKB_BEHAVIOR_TYPE_PERMAMENT_OVERLAY_1: u32 = 131;
+pub const XCB_XKB_BEHAVIOR_TYPE_PERMAMENT_OVERLAY_2: u32 = 132;
+pub const XCB_XKB_DOODAD_TYPE_OUTLINE: u32 = 1;
+pub const XCB_XKB_DOODAD_TYPE_SOLID: u32 = 2;
+pub const XCB_XKB_DOODAD_TYPE_TEXT: u32 = 3;
+pub const XCB_XKB_DOODAD_TYPE_INDICATOR: u32 = 4;
+pub const XCB_XKB_DOODAD_TYPE_LOGO: u32 = 5;
+pub const XCB_XKB_ERROR_BAD_DEVICE: u32 = 255;
+pub const XCB_XKB_ERROR_BAD_CLASS: u32 = 254;
+pub const XCB_XKB_ERROR_BAD_ID: u32 = 253;
+pub const XCB_XKB_KEYBOARD: u8 = 0;
+pub const XCB_XKB_SA_CLEAR_LOCKS: u8 = 1;
+pub const XCB_XKB_SA_LATCH_TO_LOCK: u8 = 2;
+pub const XCB_XKB_SA_USE_MOD_MAP_MODS: u8 = 4;
+pub const XCB_XKB_SA_GROUP_ABSOLUTE: u8 = 4;
+pub const XCB_XKB_SA_TYPE_NO_ACTION: u8 = 0;
+pub const XCB_XKB_SA_TYPE_SET_MODS: u8 = 1;
+pub const XCB_XKB_SA_TYPE_LATCH_MODS: u8 = 2;
+pub const XCB_XKB_SA_TYPE_LOCK_MODS: u8 = 3;
+pub const XCB_XKB_SA_TYPE_SET_GROUP: u8 = 4;
+pub const XCB_XKB_SA_TYPE_LATCH_GROUP: u8 = 5;
+pub const XCB_XKB_SA_TYPE_LOCK_GROUP: u8 = 6;
+pub const XCB_XKB_SA_TYPE_MOVE_PTR: u8 = 7;
+pub const XCB_XKB_SA_TYPE_PTR_BTN: u8 = 8;
+pub const XCB_XKB_SA_TYPE_LOCK_PTR_BTN: u8 = 9;
+pub const XCB_XKB_SA_TYPE_SET_PTR_DFLT: u8 = 10;
+pub const XCB_XKB_SA_TYPE_ISO_LOCK: u8 = 11;
+pub const XCB_XKB_SA_TYPE_TERMINATE: u8 = 12;
+pub const XCB_XKB_SA_TYPE_SWITCH_SCREEN: u8 = 13;
+pub const XCB_XKB_SA_TYPE_SET_CONTROLS: u8 = 14;
+pub const XCB_XKB_SA_TYPE_LOCK_CONTROLS: u8 = 15;
+pub const XCB_XKB_SA_TYPE_MESSAGE: u8 = 16;
+pub const XCB_XKB_SA_TYPE_REDIRECT_KEY: u8 = 17;
+pub const XCB_XKB_SA_TYPE_DEVICE_BTN: u8 = 18;
+pub const XCB_XKB_SA_TYPE_LOCK_DEVICE_BTN: u8 = 19;
+pub const XCB_XKB_SA_TYPE_DEVICE_VALUATOR: u8 = 20;
+pub const XCB_XKB_SA_MOVE_PTR_FLAG_NO_ACCELERATION: u8 = 1;
+pub const XCB_XKB_SA_MOVE_PTR_FLAG_MOVE_ABSOLUTE_X: u8 = 2;
+pub const XCB_XKB_SA_MOVE_PTR_FLAG_MOVE_ABSOLUTE_Y: u8 = 4;
+pub const XCB_XKB_SA_SET_PTR_DFLT_FLAG_DFLT_BTN_ABSOLUTE: u8 = 4;
+pub const XCB_XKB_SA_SET_PTR_DFLT_FLAG_AFFECT_DFLT_BUTTON: u8 = 1;
+pub const XCB_XKB_SA_ISO_LOCK_FLAG_NO_LOCK: u8 = 1;
+pub const XCB_XKB_SA_ISO_LOCK_FLAG_NO_UNLOCK: u8 = 2;
+pub const XCB_XKB_SA_ISO_LOCK_FLAG_USE_MOD_MAP_MODS: u8 = 4;
+pub const XCB_XKB_SA_ISO_LOCK_FLAG_GROUP_ABSOLUTE: u8 = 4;
+pub const XCB_XKB_SA_ISO_LOCK_FLAG_ISO_DFLT_IS_GROUP: u8 = 8;
+pub const XCB_XKB_SA_ISO_LOCK_NO_AFFECT_CTRLS: u8 = 8;
+pub const XCB_XKB_SA_ISO_LOCK_NO_AFFECT_PTR: u8 = 16;
+pub const XCB_XKB_SA_ISO_LOCK_NO_AFFECT_GROUP: u8 = 32;
+pub const XCB_XKB_SA_ISO_LOCK_NO_AFFECT_MODS: u8 = 64;
+pub const XCB_XKB_SWITCH_SCREEN_FLAG_APPLICATION: u32 = 1;
+pub const XCB_XKB_SWITCH_SCREEN_FLAG_ABSOLUTE: u32 = 4;
+pub const XCB_XKB_BOOL_CTRLS_HIGH_ACCESS_X_FEEDBACK: u8 = 1;
+pub const XCB_XKB_BOOL_CTRLS_HIGH_AUDIBLE_BELL: u8 = 2;
+pub const XCB_XKB_BOOL_CTRLS_HIGH_OVERLAY_1: u8 = 4;
+pub const XCB_XKB_BOOL_CTRLS_HIGH_OVERLAY_2: u8 = 8;
+pub const XCB_XKB_BOOL_CTRLS_HIGH_IGNORE_GROUP_LOCK: u8 = 16;
+pub const XCB_XKB_BOOL_CTRLS_LOW_REPEAT_KEYS: u8 = 1;
+pub const XCB_XKB_BOOL_CTRLS_LOW_SLOW_KEYS: u8 = 2;
+pub const XCB_XKB_BOOL_CTRLS_LOW_BOUNCE_KEYS: u8 = 4;
+pub const XCB_XKB_BOOL_CTRLS_LOW_STICKY_KEYS: u8 = 8;
+pub const XCB_XKB_BOOL_CTRLS_LOW_MOUSE_KEYS: u8 = 16;
+pub const XCB_XKB_BOOL_CTRLS_LOW_MOUSE_KEYS_ACCEL: u8 = 32;
+pub const XCB_XKB_BOOL_CTRLS_LOW_ACCESS_X_KEYS: u8 = 64;
+pub const XCB_XKB_BOOL_CTRLS_LOW_ACCESS_X_TIMEOUT: u8 = 128;
+pub const XCB_XKB_ACTION_MESSAGE_FLAG_ON_PRESS: u8 = 1;
+pub const XCB_XKB_ACTION_MESSAGE_FLAG_ON_RELEASE: u8 = 2;
+pub const XCB_XKB_ACTION_MESSAGE_FLAG_GEN_KEY_EVENT: u8 = 4;
+pub const XCB_XKB_LOCK_DEVICE_FLAGS_NO_LOCK: u8 = 1;
+pub const XCB_XKB_LOCK_DEVICE_FLAGS_NO_UNLOCK: u8 = 2;
+pub const XCB_XKB_SA_VAL_WHAT_IGNORE_VAL: u8 = 0;
+pub const XCB_XKB_SA_VAL_WHAT_SET_VAL_MIN: u8 = 1;
+pub const XCB_XKB_SA_VAL_WHAT_SET_VAL_CENTER: u8 = 2;
+pub const XCB_XKB_SA_VAL_WHAT_SET_VAL_MAX: u8 = 3;
+pub const XCB_XKB_SA_VAL_WHAT_SET_VAL_RELATIVE: u8 = 4;
+pub const XCB_XKB_SA_VAL_WHAT_SET_VAL_ABSOLUTE: u8 = 5;
+pub const XCB_XKB_USE_EXTENSION: u8 = 0;
+pub const XCB_XKB_SELECT_EVENTS: u8 = 1;
+pub const XCB_XKB_BELL: u8 = 3;
+pub const XCB_XKB_GET_STATE: u8 = 4;
+pub const XCB_XKB_LATCH_LOCK_STATE: u8 = 5;
+pub const XCB_XKB_GET_CONTROLS: u8 = 6;
+pub const XCB_XKB_SET_CONTROLS: u8 = 7;
+pub const XCB_XKB_GET_MAP: u8 = 8;
+pub const XCB_XKB_SET_MAP: u8 = 9;
+pub const XCB_XKB_GET_COMPAT_MAP: u8 = 10;
+pub const XCB_XKB_SET_COMPAT_MAP: u8 = 11;
+pub const XCB_XKB_GET_INDICATOR_STATE: u8 = 12;
+pub const XCB_XKB_GET_INDICATOR_MAP: u8 = 13;
+pub const XCB_XKB_SET_INDICATOR_MAP: u8 = 14;
+pub const XCB_XKB_GET_NAMED_INDICATOR: u8 = 15;
+pub const XCB_XKB_SET_NAMED_INDICATOR: u8 = 16;
+pub const XCB_XKB_GET_NAMES: u8 = 17;
+pub const XCB_XKB_SET_NAMES: u8 = 18;
+pub const XCB_XKB_PER_CLIENT_FLAGS: u8 = 21;
+pub const XCB_XKB_LIST_COMPONENTS: u8 = 22;
+pub const XCB_XKB_GET_KBD_BY_NAME: u8 = 23;
+pub const XCB_XKB_GET_DEVICE_INFO: u8 = 24;
+pub const XCB_XKB_SET_DEVICE_INFO: u8 = 25;
+pub const XCB_XKB_SET_DEBUGGING_FLAGS: u8 = 101;
+pub const XCB_XKB_NEW_KEYBOARD_NOTIFY: u8 = 0;
+pub const XCB_XKB_MAP_NOTIFY: u8 = 1;
+pub const XCB_XKB_STATE_NOTIFY: u8 = 2;
+pub const XCB_XKB_CONTROLS_NOTIFY: u8 = 3;
+pub const XCB_XKB_INDICATOR_STATE_NOTIFY: u8 = 4;
+pub const XCB_XKB_INDICATOR_MAP_NOTIFY: u8 = 5;
+pub const XCB_XKB_NAMES_NOTIFY: u8 = 6;
+pub const XCB_XKB_COMPAT_MAP_NOTIFY: u8 = 7;
+pub const XCB_XKB_BELL_NOTIFY: u8 = 8;
+pub const XCB_XKB_ACTION_MESSAGE: u8 = 9;
+pub const XCB_XKB_ACCESS_X_NOTIFY: u8 = 10;
+pub const XCB_XKB_EXTENSION_DEVICE_NOTIFY: u8 = 11;
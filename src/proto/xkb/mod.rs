@@ -0,0 +1,1307 @@
+// Generated by xcbgen from xml/xkb.xml, do not edit.
+
+//! Requests whose parameters the safe wrappers cannot express only have raw bindings
+//! in `cdef`:
+//!
+//! - `xcb_xkb_select_events`
+//! - `xcb_xkb_set_map`
+//! - `xcb_xkb_set_names`
+//! - `xcb_xkb_set_device_info`
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use proto::xproto::{XCBAtom, XCBKeycode, XCBKeysym, XCBPoint, XCBWindow};
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBXkbDeviceSpec = cdef::XCBXkbDeviceSpec;
+pub type XCBXkbDeviceSpecIterator = cdef::XCBXkbDeviceSpecIterator;
+pub type XCBXkbLedClassSpec = cdef::XCBXkbLedClassSpec;
+pub type XCBXkbLedClassSpecIterator = cdef::XCBXkbLedClassSpecIterator;
+pub type XCBXkbBellClassSpec = cdef::XCBXkbBellClassSpec;
+pub type XCBXkbBellClassSpecIterator = cdef::XCBXkbBellClassSpecIterator;
+pub type XCBXkbIdSpec = cdef::XCBXkbIdSpec;
+pub type XCBXkbIdSpecIterator = cdef::XCBXkbIdSpecIterator;
+pub type XCBXkbIndicatorMap = cdef::XCBXkbIndicatorMap;
+pub type XCBXkbIndicatorMapIterator = cdef::XCBXkbIndicatorMapIterator;
+pub type XCBXkbModDef = cdef::XCBXkbModDef;
+pub type XCBXkbModDefIterator = cdef::XCBXkbModDefIterator;
+pub type XCBXkbKeyName = cdef::XCBXkbKeyName;
+pub type XCBXkbKeyNameIterator = cdef::XCBXkbKeyNameIterator;
+pub type XCBXkbKeyAlias = cdef::XCBXkbKeyAlias;
+pub type XCBXkbKeyAliasIterator = cdef::XCBXkbKeyAliasIterator;
+pub type XCBXkbCountedString16 = cdef::XCBXkbCountedString16;
+pub type XCBXkbCountedString16Iterator = cdef::XCBXkbCountedString16Iterator;
+pub type XCBXkbKtMapEntry = cdef::XCBXkbKtMapEntry;
+pub type XCBXkbKtMapEntryIterator = cdef::XCBXkbKtMapEntryIterator;
+pub type XCBXkbKeyType = cdef::XCBXkbKeyType;
+pub type XCBXkbKeyTypeIterator = cdef::XCBXkbKeyTypeIterator;
+pub type XCBXkbKeySymMap = cdef::XCBXkbKeySymMap;
+pub type XCBXkbKeySymMapIterator = cdef::XCBXkbKeySymMapIterator;
+pub type XCBXkbCommonBehavior = cdef::XCBXkbCommonBehavior;
+pub type XCBXkbCommonBehaviorIterator = cdef::XCBXkbCommonBehaviorIterator;
+pub type XCBXkbDefaultBehavior = cdef::XCBXkbDefaultBehavior;
+pub type XCBXkbDefaultBehaviorIterator = cdef::XCBXkbDefaultBehaviorIterator;
+pub type XCBXkbLockBehavior = cdef::XCBXkbLockBehavior;
+pub type XCBXkbLockBehaviorIterator = cdef::XCBXkbLockBehaviorIterator;
+pub type XCBXkbRadioGroupBehavior = cdef::XCBXkbRadioGroupBehavior;
+pub type XCBXkbRadioGroupBehaviorIterator = cdef::XCBXkbRadioGroupBehaviorIterator;
+pub type XCBXkbOverlayBehavior = cdef::XCBXkbOverlayBehavior;
+pub type XCBXkbOverlayBehaviorIterator = cdef::XCBXkbOverlayBehaviorIterator;
+pub type XCBXkbPermamentLockBehavior = cdef::XCBXkbPermamentLockBehavior;
+pub type XCBXkbPermamentLockBehaviorIterator = cdef::XCBXkbPermamentLockBehaviorIterator;
+pub type XCBXkbPermamentRadioGroupBehavior = cdef::XCBXkbPermamentRadioGroupBehavior;
+pub type XCBXkbPermamentRadioGroupBehaviorIterator =
+    cdef::XCBXkbPermamentRadioGroupBehaviorIterator;
+pub type XCBXkbPermamentOverlayBehavior = cdef::XCBXkbPermamentOverlayBehavior;
+pub type XCBXkbPermamentOverlayBehaviorIterator = cdef::XCBXkbPermamentOverlayBehaviorIterator;
+pub type XCBXkbBehavior = cdef::XCBXkbBehavior;
+pub type XCBXkbBehaviorIterator = cdef::XCBXkbBehaviorIterator;
+pub type XCBXkbSetBehavior = cdef::XCBXkbSetBehavior;
+pub type XCBXkbSetBehaviorIterator = cdef::XCBXkbSetBehaviorIterator;
+pub type XCBXkbSetExplicit = cdef::XCBXkbSetExplicit;
+pub type XCBXkbSetExplicitIterator = cdef::XCBXkbSetExplicitIterator;
+pub type XCBXkbKeyModMap = cdef::XCBXkbKeyModMap;
+pub type XCBXkbKeyModMapIterator = cdef::XCBXkbKeyModMapIterator;
+pub type XCBXkbKeyVModMap = cdef::XCBXkbKeyVModMap;
+pub type XCBXkbKeyVModMapIterator = cdef::XCBXkbKeyVModMapIterator;
+pub type XCBXkbKtSetMapEntry = cdef::XCBXkbKtSetMapEntry;
+pub type XCBXkbKtSetMapEntryIterator = cdef::XCBXkbKtSetMapEntryIterator;
+pub type XCBXkbSetKeyType = cdef::XCBXkbSetKeyType;
+pub type XCBXkbSetKeyTypeIterator = cdef::XCBXkbSetKeyTypeIterator;
+pub type XCBXkbString8 = cdef::XCBXkbString8;
+pub type XCBXkbString8Iterator = cdef::XCBXkbString8Iterator;
+pub type XCBXkbOutline = cdef::XCBXkbOutline;
+pub type XCBXkbOutlineIterator = cdef::XCBXkbOutlineIterator;
+pub type XCBXkbShape = cdef::XCBXkbShape;
+pub type XCBXkbShapeIterator = cdef::XCBXkbShapeIterator;
+pub type XCBXkbKey = cdef::XCBXkbKey;
+pub type XCBXkbKeyIterator = cdef::XCBXkbKeyIterator;
+pub type XCBXkbOverlayKey = cdef::XCBXkbOverlayKey;
+pub type XCBXkbOverlayKeyIterator = cdef::XCBXkbOverlayKeyIterator;
+pub type XCBXkbOverlayRow = cdef::XCBXkbOverlayRow;
+pub type XCBXkbOverlayRowIterator = cdef::XCBXkbOverlayRowIterator;
+pub type XCBXkbOverlay = cdef::XCBXkbOverlay;
+pub type XCBXkbOverlayIterator = cdef::XCBXkbOverlayIterator;
+pub type XCBXkbRow = cdef::XCBXkbRow;
+pub type XCBXkbRowIterator = cdef::XCBXkbRowIterator;
+pub type XCBXkbListing = cdef::XCBXkbListing;
+pub type XCBXkbListingIterator = cdef::XCBXkbListingIterator;
+pub type XCBXkbDeviceLedInfo = cdef::XCBXkbDeviceLedInfo;
+pub type XCBXkbDeviceLedInfoIterator = cdef::XCBXkbDeviceLedInfoIterator;
+pub type XCBXkbKeyboardError = cdef::XCBXkbKeyboardError;
+pub type XCBXkbSaNoAction = cdef::XCBXkbSaNoAction;
+pub type XCBXkbSaNoActionIterator = cdef::XCBXkbSaNoActionIterator;
+pub type XCBXkbSaSetMods = cdef::XCBXkbSaSetMods;
+pub type XCBXkbSaSetModsIterator = cdef::XCBXkbSaSetModsIterator;
+pub type XCBXkbSaLatchMods = cdef::XCBXkbSaLatchMods;
+pub type XCBXkbSaLatchModsIterator = cdef::XCBXkbSaLatchModsIterator;
+pub type XCBXkbSaLockMods = cdef::XCBXkbSaLockMods;
+pub type XCBXkbSaLockModsIterator = cdef::XCBXkbSaLockModsIterator;
+pub type XCBXkbSaSetGroup = cdef::XCBXkbSaSetGroup;
+pub type XCBXkbSaSetGroupIterator = cdef::XCBXkbSaSetGroupIterator;
+pub type XCBXkbSaLatchGroup = cdef::XCBXkbSaLatchGroup;
+pub type XCBXkbSaLatchGroupIterator = cdef::XCBXkbSaLatchGroupIterator;
+pub type XCBXkbSaLockGroup = cdef::XCBXkbSaLockGroup;
+pub type XCBXkbSaLockGroupIterator = cdef::XCBXkbSaLockGroupIterator;
+pub type XCBXkbSaMovePtr = cdef::XCBXkbSaMovePtr;
+pub type XCBXkbSaMovePtrIterator = cdef::XCBXkbSaMovePtrIterator;
+pub type XCBXkbSaPtrBtn = cdef::XCBXkbSaPtrBtn;
+pub type XCBXkbSaPtrBtnIterator = cdef::XCBXkbSaPtrBtnIterator;
+pub type XCBXkbSaLockPtrBtn = cdef::XCBXkbSaLockPtrBtn;
+pub type XCBXkbSaLockPtrBtnIterator = cdef::XCBXkbSaLockPtrBtnIterator;
+pub type XCBXkbSaSetPtrDflt = cdef::XCBXkbSaSetPtrDflt;
+pub type XCBXkbSaSetPtrDfltIterator = cdef::XCBXkbSaSetPtrDfltIterator;
+pub type XCBXkbSaIsoLock = cdef::XCBXkbSaIsoLock;
+pub type XCBXkbSaIsoLockIterator = cdef::XCBXkbSaIsoLockIterator;
+pub type XCBXkbSaTerminate = cdef::XCBXkbSaTerminate;
+pub type XCBXkbSaTerminateIterator = cdef::XCBXkbSaTerminateIterator;
+pub type XCBXkbSaSwitchScreen = cdef::XCBXkbSaSwitchScreen;
+pub type XCBXkbSaSwitchScreenIterator = cdef::XCBXkbSaSwitchScreenIterator;
+pub type XCBXkbSaSetControls = cdef::XCBXkbSaSetControls;
+pub type XCBXkbSaSetControlsIterator = cdef::XCBXkbSaSetControlsIterator;
+pub type XCBXkbSaLockControls = cdef::XCBXkbSaLockControls;
+pub type XCBXkbSaLockControlsIterator = cdef::XCBXkbSaLockControlsIterator;
+pub type XCBXkbSaActionMessage = cdef::XCBXkbSaActionMessage;
+pub type XCBXkbSaActionMessageIterator = cdef::XCBXkbSaActionMessageIterator;
+pub type XCBXkbSaRedirectKey = cdef::XCBXkbSaRedirectKey;
+pub type XCBXkbSaRedirectKeyIterator = cdef::XCBXkbSaRedirectKeyIterator;
+pub type XCBXkbSaDeviceBtn = cdef::XCBXkbSaDeviceBtn;
+pub type XCBXkbSaDeviceBtnIterator = cdef::XCBXkbSaDeviceBtnIterator;
+pub type XCBXkbSaLockDeviceBtn = cdef::XCBXkbSaLockDeviceBtn;
+pub type XCBXkbSaLockDeviceBtnIterator = cdef::XCBXkbSaLockDeviceBtnIterator;
+pub type XCBXkbSaDeviceValuator = cdef::XCBXkbSaDeviceValuator;
+pub type XCBXkbSaDeviceValuatorIterator = cdef::XCBXkbSaDeviceValuatorIterator;
+pub type XCBXkbSiAction = cdef::XCBXkbSiAction;
+pub type XCBXkbSiActionIterator = cdef::XCBXkbSiActionIterator;
+pub type XCBXkbSymInterpret = cdef::XCBXkbSymInterpret;
+pub type XCBXkbSymInterpretIterator = cdef::XCBXkbSymInterpretIterator;
+pub type XCBXkbAction = cdef::XCBXkbAction;
+pub type XCBXkbActionIterator = cdef::XCBXkbActionIterator;
+pub type XCBXkbUseExtensionRequest = cdef::XCBXkbUseExtensionRequest;
+pub type XCBXkbUseExtensionCookie = cdef::XCBXkbUseExtensionCookie;
+pub type XCBXkbUseExtensionReply = cdef::XCBXkbUseExtensionReply;
+pub type XCBXkbSelectEventsRequest = cdef::XCBXkbSelectEventsRequest;
+pub type XCBXkbBellRequest = cdef::XCBXkbBellRequest;
+pub type XCBXkbGetStateRequest = cdef::XCBXkbGetStateRequest;
+pub type XCBXkbGetStateCookie = cdef::XCBXkbGetStateCookie;
+pub type XCBXkbGetStateReply = cdef::XCBXkbGetStateReply;
+pub type XCBXkbLatchLockStateRequest = cdef::XCBXkbLatchLockStateRequest;
+pub type XCBXkbGetControlsRequest = cdef::XCBXkbGetControlsRequest;
+pub type XCBXkbGetControlsCookie = cdef::XCBXkbGetControlsCookie;
+pub type XCBXkbGetControlsReply = cdef::XCBXkbGetControlsReply;
+pub type XCBXkbSetControlsRequest = cdef::XCBXkbSetControlsRequest;
+pub type XCBXkbGetMapRequest = cdef::XCBXkbGetMapRequest;
+pub type XCBXkbGetMapCookie = cdef::XCBXkbGetMapCookie;
+pub type XCBXkbGetMapReply = cdef::XCBXkbGetMapReply;
+pub type XCBXkbSetMapRequest = cdef::XCBXkbSetMapRequest;
+pub type XCBXkbGetCompatMapRequest = cdef::XCBXkbGetCompatMapRequest;
+pub type XCBXkbGetCompatMapCookie = cdef::XCBXkbGetCompatMapCookie;
+pub type XCBXkbGetCompatMapReply = cdef::XCBXkbGetCompatMapReply;
+pub type XCBXkbSetCompatMapRequest = cdef::XCBXkbSetCompatMapRequest;
+pub type XCBXkbGetIndicatorStateRequest = cdef::XCBXkbGetIndicatorStateRequest;
+pub type XCBXkbGetIndicatorStateCookie = cdef::XCBXkbGetIndicatorStateCookie;
+pub type XCBXkbGetIndicatorStateReply = cdef::XCBXkbGetIndicatorStateReply;
+pub type XCBXkbGetIndicatorMapRequest = cdef::XCBXkbGetIndicatorMapRequest;
+pub type XCBXkbGetIndicatorMapCookie = cdef::XCBXkbGetIndicatorMapCookie;
+pub type XCBXkbGetIndicatorMapReply = cdef::XCBXkbGetIndicatorMapReply;
+pub type XCBXkbSetIndicatorMapRequest = cdef::XCBXkbSetIndicatorMapRequest;
+pub type XCBXkbGetNamedIndicatorRequest = cdef::XCBXkbGetNamedIndicatorRequest;
+pub type XCBXkbGetNamedIndicatorCookie = cdef::XCBXkbGetNamedIndicatorCookie;
+pub type XCBXkbGetNamedIndicatorReply = cdef::XCBXkbGetNamedIndicatorReply;
+pub type XCBXkbSetNamedIndicatorRequest = cdef::XCBXkbSetNamedIndicatorRequest;
+pub type XCBXkbGetNamesRequest = cdef::XCBXkbGetNamesRequest;
+pub type XCBXkbGetNamesCookie = cdef::XCBXkbGetNamesCookie;
+pub type XCBXkbGetNamesReply = cdef::XCBXkbGetNamesReply;
+pub type XCBXkbSetNamesRequest = cdef::XCBXkbSetNamesRequest;
+pub type XCBXkbPerClientFlagsRequest = cdef::XCBXkbPerClientFlagsRequest;
+pub type XCBXkbPerClientFlagsCookie = cdef::XCBXkbPerClientFlagsCookie;
+pub type XCBXkbPerClientFlagsReply = cdef::XCBXkbPerClientFlagsReply;
+pub type XCBXkbListComponentsRequest = cdef::XCBXkbListComponentsRequest;
+pub type XCBXkbListComponentsCookie = cdef::XCBXkbListComponentsCookie;
+pub type XCBXkbListComponentsReply = cdef::XCBXkbListComponentsReply;
+pub type XCBXkbGetKbdByNameRequest = cdef::XCBXkbGetKbdByNameRequest;
+pub type XCBXkbGetKbdByNameCookie = cdef::XCBXkbGetKbdByNameCookie;
+pub type XCBXkbGetKbdByNameReply = cdef::XCBXkbGetKbdByNameReply;
+pub type XCBXkbGetDeviceInfoRequest = cdef::XCBXkbGetDeviceInfoRequest;
+pub type XCBXkbGetDeviceInfoCookie = cdef::XCBXkbGetDeviceInfoCookie;
+pub type XCBXkbGetDeviceInfoReply = cdef::XCBXkbGetDeviceInfoReply;
+pub type XCBXkbSetDeviceInfoRequest = cdef::XCBXkbSetDeviceInfoRequest;
+pub type XCBXkbSetDebuggingFlagsRequest = cdef::XCBXkbSetDebuggingFlagsRequest;
+pub type XCBXkbSetDebuggingFlagsCookie = cdef::XCBXkbSetDebuggingFlagsCookie;
+pub type XCBXkbSetDebuggingFlagsReply = cdef::XCBXkbSetDebuggingFlagsReply;
+pub type XCBXkbNewKeyboardNotifyEvent = cdef::XCBXkbNewKeyboardNotifyEvent;
+pub type XCBXkbMapNotifyEvent = cdef::XCBXkbMapNotifyEvent;
+pub type XCBXkbStateNotifyEvent = cdef::XCBXkbStateNotifyEvent;
+pub type XCBXkbControlsNotifyEvent = cdef::XCBXkbControlsNotifyEvent;
+pub type XCBXkbIndicatorStateNotifyEvent = cdef::XCBXkbIndicatorStateNotifyEvent;
+pub type XCBXkbIndicatorMapNotifyEvent = cdef::XCBXkbIndicatorMapNotifyEvent;
+pub type XCBXkbNamesNotifyEvent = cdef::XCBXkbNamesNotifyEvent;
+pub type XCBXkbCompatMapNotifyEvent = cdef::XCBXkbCompatMapNotifyEvent;
+pub type XCBXkbBellNotifyEvent = cdef::XCBXkbBellNotifyEvent;
+pub type XCBXkbActionMessageEvent = cdef::XCBXkbActionMessageEvent;
+pub type XCBXkbAccessXNotifyEvent = cdef::XCBXkbAccessXNotifyEvent;
+pub type XCBXkbExtensionDeviceNotifyEvent = cdef::XCBXkbExtensionDeviceNotifyEvent;
+
+/// The xkb extension, known to the server as `XKEYBOARD`.
+pub struct Xkb;
+
+impl Extension for Xkb {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_xkb_id)
+    }
+}
+
+pub fn xcb_xkb_device_spec_next(iter: &mut XCBXkbDeviceSpecIterator) {
+    unsafe { cdef::xcb_xkb_device_spec_next(iter) }
+}
+
+pub fn xcb_xkb_led_class_spec_next(iter: &mut XCBXkbLedClassSpecIterator) {
+    unsafe { cdef::xcb_xkb_led_class_spec_next(iter) }
+}
+
+pub fn xcb_xkb_bell_class_spec_next(iter: &mut XCBXkbBellClassSpecIterator) {
+    unsafe { cdef::xcb_xkb_bell_class_spec_next(iter) }
+}
+
+pub fn xcb_xkb_id_spec_next(iter: &mut XCBXkbIdSpecIterator) {
+    unsafe { cdef::xcb_xkb_id_spec_next(iter) }
+}
+
+pub fn xcb_xkb_indicator_map_next(iter: &mut XCBXkbIndicatorMapIterator) {
+    unsafe { cdef::xcb_xkb_indicator_map_next(iter) }
+}
+
+pub fn xcb_xkb_mod_def_next(iter: &mut XCBXkbModDefIterator) {
+    unsafe { cdef::xcb_xkb_mod_def_next(iter) }
+}
+
+pub fn xcb_xkb_key_name_next(iter: &mut XCBXkbKeyNameIterator) {
+    unsafe { cdef::xcb_xkb_key_name_next(iter) }
+}
+
+pub fn xcb_xkb_key_alias_next(iter: &mut XCBXkbKeyAliasIterator) {
+    unsafe { cdef::xcb_xkb_key_alias_next(iter) }
+}
+
+pub fn xcb_xkb_counted_string_16_next(iter: &mut XCBXkbCountedString16Iterator) {
+    unsafe { cdef::xcb_xkb_counted_string_16_next(iter) }
+}
+
+pub fn xcb_xkb_counted_string_16_string(data: &XCBXkbCountedString16) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_counted_string_16_string(data) as *const u8,
+            cdef::xcb_xkb_counted_string_16_string_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_counted_string_16_alignment_pad(data: &XCBXkbCountedString16) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_counted_string_16_alignment_pad(data) as *const u8,
+            cdef::xcb_xkb_counted_string_16_alignment_pad_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_kt_map_entry_next(iter: &mut XCBXkbKtMapEntryIterator) {
+    unsafe { cdef::xcb_xkb_kt_map_entry_next(iter) }
+}
+
+pub fn xcb_xkb_key_type_next(iter: &mut XCBXkbKeyTypeIterator) {
+    unsafe { cdef::xcb_xkb_key_type_next(iter) }
+}
+
+pub fn xcb_xkb_key_type_map(data: &XCBXkbKeyType) -> &[XCBXkbKtMapEntry] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_key_type_map(data),
+            cdef::xcb_xkb_key_type_map_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_key_type_preserve(data: &XCBXkbKeyType) -> &[XCBXkbModDef] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_key_type_preserve(data),
+            cdef::xcb_xkb_key_type_preserve_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_key_sym_map_next(iter: &mut XCBXkbKeySymMapIterator) {
+    unsafe { cdef::xcb_xkb_key_sym_map_next(iter) }
+}
+
+pub fn xcb_xkb_key_sym_map_syms(data: &XCBXkbKeySymMap) -> &[XCBKeysym] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_key_sym_map_syms(data),
+            cdef::xcb_xkb_key_sym_map_syms_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_common_behavior_next(iter: &mut XCBXkbCommonBehaviorIterator) {
+    unsafe { cdef::xcb_xkb_common_behavior_next(iter) }
+}
+
+pub fn xcb_xkb_default_behavior_next(iter: &mut XCBXkbDefaultBehaviorIterator) {
+    unsafe { cdef::xcb_xkb_default_behavior_next(iter) }
+}
+
+pub fn xcb_xkb_lock_behavior_next(iter: &mut XCBXkbLockBehaviorIterator) {
+    unsafe { cdef::xcb_xkb_lock_behavior_next(iter) }
+}
+
+pub fn xcb_xkb_radio_group_behavior_next(iter: &mut XCBXkbRadioGroupBehaviorIterator) {
+    unsafe { cdef::xcb_xkb_radio_group_behavior_next(iter) }
+}
+
+pub fn xcb_xkb_overlay_behavior_next(iter: &mut XCBXkbOverlayBehaviorIterator) {
+    unsafe { cdef::xcb_xkb_overlay_behavior_next(iter) }
+}
+
+pub fn xcb_xkb_permament_lock_behavior_next(iter: &mut XCBXkbPermamentLockBehaviorIterator) {
+    unsafe { cdef::xcb_xkb_permament_lock_behavior_next(iter) }
+}
+
+pub fn xcb_xkb_permament_radio_group_behavior_next(
+    iter: &mut XCBXkbPermamentRadioGroupBehaviorIterator,
+) {
+    unsafe { cdef::xcb_xkb_permament_radio_group_behavior_next(iter) }
+}
+
+pub fn xcb_xkb_permament_overlay_behavior_next(iter: &mut XCBXkbPermamentOverlayBehaviorIterator) {
+    unsafe { cdef::xcb_xkb_permament_overlay_behavior_next(iter) }
+}
+
+pub fn xcb_xkb_behavior_next(iter: &mut XCBXkbBehaviorIterator) {
+    unsafe { cdef::xcb_xkb_behavior_next(iter) }
+}
+
+pub fn xcb_xkb_set_behavior_next(iter: &mut XCBXkbSetBehaviorIterator) {
+    unsafe { cdef::xcb_xkb_set_behavior_next(iter) }
+}
+
+pub fn xcb_xkb_set_explicit_next(iter: &mut XCBXkbSetExplicitIterator) {
+    unsafe { cdef::xcb_xkb_set_explicit_next(iter) }
+}
+
+pub fn xcb_xkb_key_mod_map_next(iter: &mut XCBXkbKeyModMapIterator) {
+    unsafe { cdef::xcb_xkb_key_mod_map_next(iter) }
+}
+
+pub fn xcb_xkb_key_v_mod_map_next(iter: &mut XCBXkbKeyVModMapIterator) {
+    unsafe { cdef::xcb_xkb_key_v_mod_map_next(iter) }
+}
+
+pub fn xcb_xkb_kt_set_map_entry_next(iter: &mut XCBXkbKtSetMapEntryIterator) {
+    unsafe { cdef::xcb_xkb_kt_set_map_entry_next(iter) }
+}
+
+pub fn xcb_xkb_set_key_type_next(iter: &mut XCBXkbSetKeyTypeIterator) {
+    unsafe { cdef::xcb_xkb_set_key_type_next(iter) }
+}
+
+pub fn xcb_xkb_set_key_type_entries(data: &XCBXkbSetKeyType) -> &[XCBXkbKtSetMapEntry] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_set_key_type_entries(data),
+            cdef::xcb_xkb_set_key_type_entries_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_set_key_type_preserve_entries(data: &XCBXkbSetKeyType) -> &[XCBXkbKtSetMapEntry] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_set_key_type_preserve_entries(data),
+            cdef::xcb_xkb_set_key_type_preserve_entries_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_string8_next(iter: &mut XCBXkbString8Iterator) {
+    unsafe { cdef::xcb_xkb_string8_next(iter) }
+}
+
+pub fn xcb_xkb_outline_next(iter: &mut XCBXkbOutlineIterator) {
+    unsafe { cdef::xcb_xkb_outline_next(iter) }
+}
+
+pub fn xcb_xkb_outline_points(data: &XCBXkbOutline) -> &[XCBPoint] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_outline_points(data),
+            cdef::xcb_xkb_outline_points_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_shape_next(iter: &mut XCBXkbShapeIterator) {
+    unsafe { cdef::xcb_xkb_shape_next(iter) }
+}
+
+pub fn xcb_xkb_shape_outlines_iterator(data: &XCBXkbShape) -> XCBXkbOutlineIterator {
+    unsafe { cdef::xcb_xkb_shape_outlines_iterator(data) }
+}
+
+pub fn xcb_xkb_key_next(iter: &mut XCBXkbKeyIterator) {
+    unsafe { cdef::xcb_xkb_key_next(iter) }
+}
+
+pub fn xcb_xkb_overlay_key_next(iter: &mut XCBXkbOverlayKeyIterator) {
+    unsafe { cdef::xcb_xkb_overlay_key_next(iter) }
+}
+
+pub fn xcb_xkb_overlay_row_next(iter: &mut XCBXkbOverlayRowIterator) {
+    unsafe { cdef::xcb_xkb_overlay_row_next(iter) }
+}
+
+pub fn xcb_xkb_overlay_row_keys(data: &XCBXkbOverlayRow) -> &[XCBXkbOverlayKey] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_overlay_row_keys(data),
+            cdef::xcb_xkb_overlay_row_keys_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_overlay_next(iter: &mut XCBXkbOverlayIterator) {
+    unsafe { cdef::xcb_xkb_overlay_next(iter) }
+}
+
+pub fn xcb_xkb_overlay_rows_iterator(data: &XCBXkbOverlay) -> XCBXkbOverlayRowIterator {
+    unsafe { cdef::xcb_xkb_overlay_rows_iterator(data) }
+}
+
+pub fn xcb_xkb_row_next(iter: &mut XCBXkbRowIterator) {
+    unsafe { cdef::xcb_xkb_row_next(iter) }
+}
+
+pub fn xcb_xkb_row_keys(data: &XCBXkbRow) -> &[XCBXkbKey] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_row_keys(data),
+            cdef::xcb_xkb_row_keys_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_listing_next(iter: &mut XCBXkbListingIterator) {
+    unsafe { cdef::xcb_xkb_listing_next(iter) }
+}
+
+pub fn xcb_xkb_listing_string(data: &XCBXkbListing) -> &[XCBXkbString8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_listing_string(data),
+            cdef::xcb_xkb_listing_string_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_device_led_info_next(iter: &mut XCBXkbDeviceLedInfoIterator) {
+    unsafe { cdef::xcb_xkb_device_led_info_next(iter) }
+}
+
+pub fn xcb_xkb_device_led_info_names(data: &XCBXkbDeviceLedInfo) -> &[XCBAtom] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_device_led_info_names(data),
+            cdef::xcb_xkb_device_led_info_names_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_device_led_info_maps(data: &XCBXkbDeviceLedInfo) -> &[XCBXkbIndicatorMap] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_device_led_info_maps(data),
+            cdef::xcb_xkb_device_led_info_maps_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_sa_no_action_next(iter: &mut XCBXkbSaNoActionIterator) {
+    unsafe { cdef::xcb_xkb_sa_no_action_next(iter) }
+}
+
+pub fn xcb_xkb_sa_set_mods_next(iter: &mut XCBXkbSaSetModsIterator) {
+    unsafe { cdef::xcb_xkb_sa_set_mods_next(iter) }
+}
+
+pub fn xcb_xkb_sa_latch_mods_next(iter: &mut XCBXkbSaLatchModsIterator) {
+    unsafe { cdef::xcb_xkb_sa_latch_mods_next(iter) }
+}
+
+pub fn xcb_xkb_sa_lock_mods_next(iter: &mut XCBXkbSaLockModsIterator) {
+    unsafe { cdef::xcb_xkb_sa_lock_mods_next(iter) }
+}
+
+pub fn xcb_xkb_sa_set_group_next(iter: &mut XCBXkbSaSetGroupIterator) {
+    unsafe { cdef::xcb_xkb_sa_set_group_next(iter) }
+}
+
+pub fn xcb_xkb_sa_latch_group_next(iter: &mut XCBXkbSaLatchGroupIterator) {
+    unsafe { cdef::xcb_xkb_sa_latch_group_next(iter) }
+}
+
+pub fn xcb_xkb_sa_lock_group_next(iter: &mut XCBXkbSaLockGroupIterator) {
+    unsafe { cdef::xcb_xkb_sa_lock_group_next(iter) }
+}
+
+pub fn xcb_xkb_sa_move_ptr_next(iter: &mut XCBXkbSaMovePtrIterator) {
+    unsafe { cdef::xcb_xkb_sa_move_ptr_next(iter) }
+}
+
+pub fn xcb_xkb_sa_ptr_btn_next(iter: &mut XCBXkbSaPtrBtnIterator) {
+    unsafe { cdef::xcb_xkb_sa_ptr_btn_next(iter) }
+}
+
+pub fn xcb_xkb_sa_lock_ptr_btn_next(iter: &mut XCBXkbSaLockPtrBtnIterator) {
+    unsafe { cdef::xcb_xkb_sa_lock_ptr_btn_next(iter) }
+}
+
+pub fn xcb_xkb_sa_set_ptr_dflt_next(iter: &mut XCBXkbSaSetPtrDfltIterator) {
+    unsafe { cdef::xcb_xkb_sa_set_ptr_dflt_next(iter) }
+}
+
+pub fn xcb_xkb_sa_iso_lock_next(iter: &mut XCBXkbSaIsoLockIterator) {
+    unsafe { cdef::xcb_xkb_sa_iso_lock_next(iter) }
+}
+
+pub fn xcb_xkb_sa_terminate_next(iter: &mut XCBXkbSaTerminateIterator) {
+    unsafe { cdef::xcb_xkb_sa_terminate_next(iter) }
+}
+
+pub fn xcb_xkb_sa_switch_screen_next(iter: &mut XCBXkbSaSwitchScreenIterator) {
+    unsafe { cdef::xcb_xkb_sa_switch_screen_next(iter) }
+}
+
+pub fn xcb_xkb_sa_set_controls_next(iter: &mut XCBXkbSaSetControlsIterator) {
+    unsafe { cdef::xcb_xkb_sa_set_controls_next(iter) }
+}
+
+pub fn xcb_xkb_sa_lock_controls_next(iter: &mut XCBXkbSaLockControlsIterator) {
+    unsafe { cdef::xcb_xkb_sa_lock_controls_next(iter) }
+}
+
+pub fn xcb_xkb_sa_action_message_next(iter: &mut XCBXkbSaActionMessageIterator) {
+    unsafe { cdef::xcb_xkb_sa_action_message_next(iter) }
+}
+
+pub fn xcb_xkb_sa_redirect_key_next(iter: &mut XCBXkbSaRedirectKeyIterator) {
+    unsafe { cdef::xcb_xkb_sa_redirect_key_next(iter) }
+}
+
+pub fn xcb_xkb_sa_device_btn_next(iter: &mut XCBXkbSaDeviceBtnIterator) {
+    unsafe { cdef::xcb_xkb_sa_device_btn_next(iter) }
+}
+
+pub fn xcb_xkb_sa_lock_device_btn_next(iter: &mut XCBXkbSaLockDeviceBtnIterator) {
+    unsafe { cdef::xcb_xkb_sa_lock_device_btn_next(iter) }
+}
+
+pub fn xcb_xkb_sa_device_valuator_next(iter: &mut XCBXkbSaDeviceValuatorIterator) {
+    unsafe { cdef::xcb_xkb_sa_device_valuator_next(iter) }
+}
+
+pub fn xcb_xkb_si_action_next(iter: &mut XCBXkbSiActionIterator) {
+    unsafe { cdef::xcb_xkb_si_action_next(iter) }
+}
+
+pub fn xcb_xkb_sym_interpret_next(iter: &mut XCBXkbSymInterpretIterator) {
+    unsafe { cdef::xcb_xkb_sym_interpret_next(iter) }
+}
+
+pub fn xcb_xkb_action_next(iter: &mut XCBXkbActionIterator) {
+    unsafe { cdef::xcb_xkb_action_next(iter) }
+}
+
+impl Reply for XCBXkbUseExtensionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_use_extension_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_use_extension(
+    connection: &XCBConnection,
+    wantedMajor: u16,
+    wantedMinor: u16,
+) -> XCBXkbUseExtensionCookie {
+    unsafe { cdef::xcb_xkb_use_extension(connection.raw, wantedMajor, wantedMinor) }
+}
+
+pub fn xcb_xkb_bell(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    bellClass: XCBXkbBellClassSpec,
+    bellID: XCBXkbIdSpec,
+    percent: i8,
+    forceSound: bool,
+    eventOnly: bool,
+    pitch: i16,
+    duration: i16,
+    name: XCBAtom,
+    window: XCBWindow,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xkb_bell(
+            connection.raw,
+            deviceSpec,
+            bellClass,
+            bellID,
+            percent,
+            forceSound as u8,
+            eventOnly as u8,
+            pitch,
+            duration,
+            name,
+            window,
+        )
+    }
+}
+
+impl Reply for XCBXkbGetStateReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_get_state_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_get_state(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+) -> XCBXkbGetStateCookie {
+    unsafe { cdef::xcb_xkb_get_state(connection.raw, deviceSpec) }
+}
+
+pub fn xcb_xkb_latch_lock_state(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    affectModLocks: u8,
+    modLocks: u8,
+    lockGroup: bool,
+    groupLock: u8,
+    affectModLatches: u8,
+    latchGroup: bool,
+    groupLatch: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xkb_latch_lock_state(
+            connection.raw,
+            deviceSpec,
+            affectModLocks,
+            modLocks,
+            lockGroup as u8,
+            groupLock,
+            affectModLatches,
+            latchGroup as u8,
+            groupLatch,
+        )
+    }
+}
+
+impl Reply for XCBXkbGetControlsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_get_controls_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_get_controls(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+) -> XCBXkbGetControlsCookie {
+    unsafe { cdef::xcb_xkb_get_controls(connection.raw, deviceSpec) }
+}
+
+pub fn xcb_xkb_set_controls(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    affectInternalRealMods: u8,
+    internalRealMods: u8,
+    affectIgnoreLockRealMods: u8,
+    ignoreLockRealMods: u8,
+    affectInternalVirtualMods: u16,
+    internalVirtualMods: u16,
+    affectIgnoreLockVirtualMods: u16,
+    ignoreLockVirtualMods: u16,
+    mouseKeysDfltBtn: u8,
+    groupsWrap: u8,
+    accessXOptions: u16,
+    affectEnabledControls: u32,
+    enabledControls: u32,
+    changeControls: u32,
+    repeatDelay: u16,
+    repeatInterval: u16,
+    slowKeysDelay: u16,
+    debounceDelay: u16,
+    mouseKeysDelay: u16,
+    mouseKeysInterval: u16,
+    mouseKeysTimeToMax: u16,
+    mouseKeysMaxSpeed: u16,
+    mouseKeysCurve: i16,
+    accessXTimeout: u16,
+    accessXTimeoutMask: u32,
+    accessXTimeoutValues: u32,
+    accessXTimeoutOptionsMask: u16,
+    accessXTimeoutOptionsValues: u16,
+    perKeyRepeat: &[u8],
+) -> XCBVoidCookie {
+    assert!(perKeyRepeat.len() >= 32);
+    unsafe {
+        cdef::xcb_xkb_set_controls(
+            connection.raw,
+            deviceSpec,
+            affectInternalRealMods,
+            internalRealMods,
+            affectIgnoreLockRealMods,
+            ignoreLockRealMods,
+            affectInternalVirtualMods,
+            internalVirtualMods,
+            affectIgnoreLockVirtualMods,
+            ignoreLockVirtualMods,
+            mouseKeysDfltBtn,
+            groupsWrap,
+            accessXOptions,
+            affectEnabledControls,
+            enabledControls,
+            changeControls,
+            repeatDelay,
+            repeatInterval,
+            slowKeysDelay,
+            debounceDelay,
+            mouseKeysDelay,
+            mouseKeysInterval,
+            mouseKeysTimeToMax,
+            mouseKeysMaxSpeed,
+            mouseKeysCurve,
+            accessXTimeout,
+            accessXTimeoutMask,
+            accessXTimeoutValues,
+            accessXTimeoutOptionsMask,
+            accessXTimeoutOptionsValues,
+            perKeyRepeat.as_ptr(),
+        )
+    }
+}
+
+impl Reply for XCBXkbGetMapReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_get_map_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_get_map(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    full: u16,
+    partial: u16,
+    firstType: u8,
+    nTypes: u8,
+    firstKeySym: XCBKeycode,
+    nKeySyms: u8,
+    firstKeyAction: XCBKeycode,
+    nKeyActions: u8,
+    firstKeyBehavior: XCBKeycode,
+    nKeyBehaviors: u8,
+    virtualMods: u16,
+    firstKeyExplicit: XCBKeycode,
+    nKeyExplicit: u8,
+    firstModMapKey: XCBKeycode,
+    nModMapKeys: u8,
+    firstVModMapKey: XCBKeycode,
+    nVModMapKeys: u8,
+) -> XCBXkbGetMapCookie {
+    unsafe {
+        cdef::xcb_xkb_get_map(
+            connection.raw,
+            deviceSpec,
+            full,
+            partial,
+            firstType,
+            nTypes,
+            firstKeySym,
+            nKeySyms,
+            firstKeyAction,
+            nKeyActions,
+            firstKeyBehavior,
+            nKeyBehaviors,
+            virtualMods,
+            firstKeyExplicit,
+            nKeyExplicit,
+            firstModMapKey,
+            nModMapKeys,
+            firstVModMapKey,
+            nVModMapKeys,
+        )
+    }
+}
+
+pub fn xcb_xkb_get_compat_map_si_rtrn(reply: &XCBXkbGetCompatMapReply) -> &[XCBXkbSymInterpret] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_get_compat_map_si_rtrn(reply),
+            cdef::xcb_xkb_get_compat_map_si_rtrn_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_get_compat_map_group_rtrn(reply: &XCBXkbGetCompatMapReply) -> &[XCBXkbModDef] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_get_compat_map_group_rtrn(reply),
+            cdef::xcb_xkb_get_compat_map_group_rtrn_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXkbGetCompatMapReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_get_compat_map_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_get_compat_map(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    groups: u8,
+    getAllSI: bool,
+    firstSI: u16,
+    nSI: u16,
+) -> XCBXkbGetCompatMapCookie {
+    unsafe {
+        cdef::xcb_xkb_get_compat_map(
+            connection.raw,
+            deviceSpec,
+            groups,
+            getAllSI as u8,
+            firstSI,
+            nSI,
+        )
+    }
+}
+
+pub fn xcb_xkb_set_compat_map(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    recomputeActions: bool,
+    truncateSI: bool,
+    groups: u8,
+    firstSI: u16,
+    si: &[XCBXkbSymInterpret],
+    groupMaps: &[XCBXkbModDef],
+) -> XCBVoidCookie {
+    assert!(groupMaps.len() >= groups.count_ones() as usize);
+    unsafe {
+        cdef::xcb_xkb_set_compat_map(
+            connection.raw,
+            deviceSpec,
+            recomputeActions as u8,
+            truncateSI as u8,
+            groups,
+            firstSI,
+            si.len() as u16,
+            si.as_ptr(),
+            groupMaps.as_ptr(),
+        )
+    }
+}
+
+impl Reply for XCBXkbGetIndicatorStateReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_get_indicator_state_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_get_indicator_state(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+) -> XCBXkbGetIndicatorStateCookie {
+    unsafe { cdef::xcb_xkb_get_indicator_state(connection.raw, deviceSpec) }
+}
+
+pub fn xcb_xkb_get_indicator_map_maps(reply: &XCBXkbGetIndicatorMapReply) -> &[XCBXkbIndicatorMap] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_get_indicator_map_maps(reply),
+            cdef::xcb_xkb_get_indicator_map_maps_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXkbGetIndicatorMapReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_get_indicator_map_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_get_indicator_map(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    which: u32,
+) -> XCBXkbGetIndicatorMapCookie {
+    unsafe { cdef::xcb_xkb_get_indicator_map(connection.raw, deviceSpec, which) }
+}
+
+pub fn xcb_xkb_set_indicator_map(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    which: u32,
+    maps: &[XCBXkbIndicatorMap],
+) -> XCBVoidCookie {
+    assert!(maps.len() >= which.count_ones() as usize);
+    unsafe { cdef::xcb_xkb_set_indicator_map(connection.raw, deviceSpec, which, maps.as_ptr()) }
+}
+
+impl Reply for XCBXkbGetNamedIndicatorReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_get_named_indicator_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_get_named_indicator(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    ledClass: XCBXkbLedClassSpec,
+    ledID: XCBXkbIdSpec,
+    indicator: XCBAtom,
+) -> XCBXkbGetNamedIndicatorCookie {
+    unsafe {
+        cdef::xcb_xkb_get_named_indicator(connection.raw, deviceSpec, ledClass, ledID, indicator)
+    }
+}
+
+pub fn xcb_xkb_set_named_indicator(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    ledClass: XCBXkbLedClassSpec,
+    ledID: XCBXkbIdSpec,
+    indicator: XCBAtom,
+    setState: bool,
+    on: bool,
+    setMap: bool,
+    createMap: bool,
+    map_flags: u8,
+    map_whichGroups: u8,
+    map_groups: u8,
+    map_whichMods: u8,
+    map_realMods: u8,
+    map_vmods: u16,
+    map_ctrls: u32,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xkb_set_named_indicator(
+            connection.raw,
+            deviceSpec,
+            ledClass,
+            ledID,
+            indicator,
+            setState as u8,
+            on as u8,
+            setMap as u8,
+            createMap as u8,
+            map_flags,
+            map_whichGroups,
+            map_groups,
+            map_whichMods,
+            map_realMods,
+            map_vmods,
+            map_ctrls,
+        )
+    }
+}
+
+impl Reply for XCBXkbGetNamesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_get_names_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_get_names(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    which: u32,
+) -> XCBXkbGetNamesCookie {
+    unsafe { cdef::xcb_xkb_get_names(connection.raw, deviceSpec, which) }
+}
+
+impl Reply for XCBXkbPerClientFlagsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_per_client_flags_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_per_client_flags(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    change: u32,
+    value: u32,
+    ctrlsToChange: u32,
+    autoCtrls: u32,
+    autoCtrlsValues: u32,
+) -> XCBXkbPerClientFlagsCookie {
+    unsafe {
+        cdef::xcb_xkb_per_client_flags(
+            connection.raw,
+            deviceSpec,
+            change,
+            value,
+            ctrlsToChange,
+            autoCtrls,
+            autoCtrlsValues,
+        )
+    }
+}
+
+pub fn xcb_xkb_list_components_keymaps_iterator(
+    reply: &XCBXkbListComponentsReply,
+) -> XCBXkbListingIterator {
+    unsafe { cdef::xcb_xkb_list_components_keymaps_iterator(reply) }
+}
+
+pub fn xcb_xkb_list_components_keycodes_iterator(
+    reply: &XCBXkbListComponentsReply,
+) -> XCBXkbListingIterator {
+    unsafe { cdef::xcb_xkb_list_components_keycodes_iterator(reply) }
+}
+
+pub fn xcb_xkb_list_components_types_iterator(
+    reply: &XCBXkbListComponentsReply,
+) -> XCBXkbListingIterator {
+    unsafe { cdef::xcb_xkb_list_components_types_iterator(reply) }
+}
+
+pub fn xcb_xkb_list_components_compat_maps_iterator(
+    reply: &XCBXkbListComponentsReply,
+) -> XCBXkbListingIterator {
+    unsafe { cdef::xcb_xkb_list_components_compat_maps_iterator(reply) }
+}
+
+pub fn xcb_xkb_list_components_symbols_iterator(
+    reply: &XCBXkbListComponentsReply,
+) -> XCBXkbListingIterator {
+    unsafe { cdef::xcb_xkb_list_components_symbols_iterator(reply) }
+}
+
+pub fn xcb_xkb_list_components_geometries_iterator(
+    reply: &XCBXkbListComponentsReply,
+) -> XCBXkbListingIterator {
+    unsafe { cdef::xcb_xkb_list_components_geometries_iterator(reply) }
+}
+
+impl Reply for XCBXkbListComponentsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_list_components_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_list_components(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    maxNames: u16,
+) -> XCBXkbListComponentsCookie {
+    unsafe { cdef::xcb_xkb_list_components(connection.raw, deviceSpec, maxNames) }
+}
+
+impl Reply for XCBXkbGetKbdByNameReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_get_kbd_by_name_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_get_kbd_by_name(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    need: u16,
+    want: u16,
+    load: bool,
+) -> XCBXkbGetKbdByNameCookie {
+    unsafe { cdef::xcb_xkb_get_kbd_by_name(connection.raw, deviceSpec, need, want, load as u8) }
+}
+
+pub fn xcb_xkb_get_device_info_name(reply: &XCBXkbGetDeviceInfoReply) -> &[XCBXkbString8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_get_device_info_name(reply),
+            cdef::xcb_xkb_get_device_info_name_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_get_device_info_btn_actions(reply: &XCBXkbGetDeviceInfoReply) -> &[XCBXkbAction] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xkb_get_device_info_btn_actions(reply),
+            cdef::xcb_xkb_get_device_info_btn_actions_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_xkb_get_device_info_leds_iterator(
+    reply: &XCBXkbGetDeviceInfoReply,
+) -> XCBXkbDeviceLedInfoIterator {
+    unsafe { cdef::xcb_xkb_get_device_info_leds_iterator(reply) }
+}
+
+impl Reply for XCBXkbGetDeviceInfoReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_get_device_info_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_get_device_info(
+    connection: &XCBConnection,
+    deviceSpec: XCBXkbDeviceSpec,
+    wanted: u16,
+    allButtons: bool,
+    firstButton: u8,
+    nButtons: u8,
+    ledClass: XCBXkbLedClassSpec,
+    ledID: XCBXkbIdSpec,
+) -> XCBXkbGetDeviceInfoCookie {
+    unsafe {
+        cdef::xcb_xkb_get_device_info(
+            connection.raw,
+            deviceSpec,
+            wanted,
+            allButtons as u8,
+            firstButton,
+            nButtons,
+            ledClass,
+            ledID,
+        )
+    }
+}
+
+impl Reply for XCBXkbSetDebuggingFlagsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xkb_set_debugging_flags_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xkb_set_debugging_flags(
+    connection: &XCBConnection,
+    affectFlags: u32,
+    flags: u32,
+    affectCtrls: u32,
+    ctrls: u32,
+    message: &[XCBXkbString8],
+) -> XCBXkbSetDebuggingFlagsCookie {
+    unsafe {
+        cdef::xcb_xkb_set_debugging_flags(
+            connection.raw,
+            message.len() as u16,
+            affectFlags,
+            flags,
+            affectCtrls,
+            ctrls,
+            message.as_ptr(),
+        )
+    }
+}
+
+/// An event of the xkb extension.
+pub enum Event {
+    NewKeyboardNotify(Box<XCBXkbNewKeyboardNotifyEvent>),
+    MapNotify(Box<XCBXkbMapNotifyEvent>),
+    StateNotify(Box<XCBXkbStateNotifyEvent>),
+    ControlsNotify(Box<XCBXkbControlsNotifyEvent>),
+    IndicatorStateNotify(Box<XCBXkbIndicatorStateNotifyEvent>),
+    IndicatorMapNotify(Box<XCBXkbIndicatorMapNotifyEvent>),
+    NamesNotify(Box<XCBXkbNamesNotifyEvent>),
+    CompatMapNotify(Box<XCBXkbCompatMapNotifyEvent>),
+    BellNotify(Box<XCBXkbBellNotifyEvent>),
+    ActionMessage(Box<XCBXkbActionMessageEvent>),
+    AccessXNotify(Box<XCBXkbAccessXNotifyEvent>),
+    ExtensionDeviceNotify(Box<XCBXkbExtensionDeviceNotifyEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        if number != 0 {
+            return Err(event);
+        }
+        let raw = Box::into_raw(event);
+        unsafe {
+            match (*raw).pad0 {
+                0 => Ok(Event::NewKeyboardNotify(Box::from_raw(
+                    raw as *mut XCBXkbNewKeyboardNotifyEvent,
+                ))),
+                1 => Ok(Event::MapNotify(Box::from_raw(
+                    raw as *mut XCBXkbMapNotifyEvent,
+                ))),
+                2 => Ok(Event::StateNotify(Box::from_raw(
+                    raw as *mut XCBXkbStateNotifyEvent,
+                ))),
+                3 => Ok(Event::ControlsNotify(Box::from_raw(
+                    raw as *mut XCBXkbControlsNotifyEvent,
+                ))),
+                4 => Ok(Event::IndicatorStateNotify(Box::from_raw(
+                    raw as *mut XCBXkbIndicatorStateNotifyEvent,
+                ))),
+                5 => Ok(Event::IndicatorMapNotify(Box::from_raw(
+                    raw as *mut XCBXkbIndicatorMapNotifyEvent,
+                ))),
+                6 => Ok(Event::NamesNotify(Box::from_raw(
+                    raw as *mut XCBXkbNamesNotifyEvent,
+                ))),
+                7 => Ok(Event::CompatMapNotify(Box::from_raw(
+                    raw as *mut XCBXkbCompatMapNotifyEvent,
+                ))),
+                8 => Ok(Event::BellNotify(Box::from_raw(
+                    raw as *mut XCBXkbBellNotifyEvent,
+                ))),
+                9 => Ok(Event::ActionMessage(Box::from_raw(
+                    raw as *mut XCBXkbActionMessageEvent,
+                ))),
+                10 => Ok(Event::AccessXNotify(Box::from_raw(
+                    raw as *mut XCBXkbAccessXNotifyEvent,
+                ))),
+                11 => Ok(Event::ExtensionDeviceNotify(Box::from_raw(
+                    raw as *mut XCBXkbExtensionDeviceNotifyEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
+
+/// Returns the name of the error whose code, relative to the first error of the extension, is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        0 => Some("Keyboard"),
+        _ => None,
+    }
+}
@@ -0,0 +1,811 @@
+// Generated by xcbgen from xml/xprint.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int};
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xproto::cdef::{XCBDrawable, XCBWindow};
+
+pub type XCBXPrintString8 = c_char;
+
+#[repr(C)]
+pub struct XCBXPrintString8Iterator {
+    pub data: *mut XCBXPrintString8,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXPrintPrinter {
+    pub nameLen: u32,
+    pub descLen: u32,
+}
+
+#[repr(C)]
+pub struct XCBXPrintPrinterIterator {
+    pub data: *mut XCBXPrintPrinter,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXPrintPcontext = u32;
+
+#[repr(C)]
+pub struct XCBXPrintPcontextIterator {
+    pub data: *mut XCBXPrintPcontext,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBXPrintPrintQueryVersionCookie = XCBCookie<XCBXPrintPrintQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+
+#[repr(C)]
+pub struct XCBXPrintPrintGetPrinterListRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub printerNameLen: u32,
+    pub localeLen: u32,
+}
+
+pub type XCBXPrintPrintGetPrinterListCookie = XCBCookie<XCBXPrintPrintGetPrinterListReply>;
+
+#[repr(C)]
+pub struct XCBXPrintPrintGetPrinterListReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub listCount: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintRehashPrinterListRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+#[repr(C)]
+pub struct XCBXPrintCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_id: u32,
+    pub printerNameLen: u32,
+    pub localeLen: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintSetContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintGetContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBXPrintPrintGetContextCookie = XCBCookie<XCBXPrintPrintGetContextReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintGetContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintDestroyContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintGetScreenOfContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBXPrintPrintGetScreenOfContextCookie = XCBCookie<XCBXPrintPrintGetScreenOfContextReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintGetScreenOfContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub root: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintStartJobRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub output_mode: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintEndJobRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub cancel: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintStartDocRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub driver_mode: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintEndDocRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub cancel: u8,
+}
+
+#[repr(C)]
+pub struct XCBXPrintPrintPutDocumentDataRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub len_data: u32,
+    pub len_fmt: u16,
+    pub len_options: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintGetDocumentDataRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBXPrintPcontext,
+    pub max_bytes: u32,
+}
+
+pub type XCBXPrintPrintGetDocumentDataCookie = XCBCookie<XCBXPrintPrintGetDocumentDataReply>;
+
+#[repr(C)]
+pub struct XCBXPrintPrintGetDocumentDataReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub status_code: u32,
+    pub finished_flag: u32,
+    pub dataLen: u32,
+    pub pad1: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintStartPageRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintEndPageRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub cancel: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintSelectInputRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBXPrintPcontext,
+    pub event_mask: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintInputSelectedRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBXPrintPcontext,
+}
+
+pub type XCBXPrintPrintInputSelectedCookie = XCBCookie<XCBXPrintPrintInputSelectedReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintInputSelectedReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_mask: u32,
+    pub all_events_mask: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintGetAttributesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBXPrintPcontext,
+    pub pool: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBXPrintPrintGetAttributesCookie = XCBCookie<XCBXPrintPrintGetAttributesReply>;
+
+#[repr(C)]
+pub struct XCBXPrintPrintGetAttributesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub stringLen: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBXPrintPrintGetOneAttributesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBXPrintPcontext,
+    pub nameLen: u32,
+    pub pool: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBXPrintPrintGetOneAttributesCookie = XCBCookie<XCBXPrintPrintGetOneAttributesReply>;
+
+#[repr(C)]
+pub struct XCBXPrintPrintGetOneAttributesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub valueLen: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBXPrintPrintSetAttributesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBXPrintPcontext,
+    pub stringLen: u32,
+    pub pool: u8,
+    pub rule: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintGetPageDimensionsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBXPrintPcontext,
+}
+
+pub type XCBXPrintPrintGetPageDimensionsCookie = XCBCookie<XCBXPrintPrintGetPageDimensionsReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintGetPageDimensionsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub width: u16,
+    pub height: u16,
+    pub offset_x: u16,
+    pub offset_y: u16,
+    pub reproducible_width: u16,
+    pub reproducible_height: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintQueryScreensRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBXPrintPrintQueryScreensCookie = XCBCookie<XCBXPrintPrintQueryScreensReply>;
+
+#[repr(C)]
+pub struct XCBXPrintPrintQueryScreensReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub listCount: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintSetImageResolutionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBXPrintPcontext,
+    pub image_resolution: u16,
+}
+
+pub type XCBXPrintPrintSetImageResolutionCookie = XCBCookie<XCBXPrintPrintSetImageResolutionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintSetImageResolutionReply {
+    pub response_type: u8,
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub previous_resolutions: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintGetImageResolutionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context: XCBXPrintPcontext,
+}
+
+pub type XCBXPrintPrintGetImageResolutionCookie = XCBCookie<XCBXPrintPrintGetImageResolutionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintPrintGetImageResolutionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub image_resolution: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintNotifyEvent {
+    pub response_type: u8,
+    pub detail: u8,
+    pub sequence: u16,
+    pub context: XCBXPrintPcontext,
+    pub cancel: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintAttributNotifyEvent {
+    pub response_type: u8,
+    pub detail: u8,
+    pub sequence: u16,
+    pub context: XCBXPrintPcontext,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintBadContextError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXPrintBadSequenceError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[link(name = "xcb-xprint")]
+extern "system" {
+    pub static mut xcb_x_print_id: XCBExtension;
+    pub fn xcb_x_print_string8_next(i: *mut XCBXPrintString8Iterator);
+    pub fn xcb_x_print_string8_end(i: XCBXPrintString8Iterator) -> XCBGenericIterator;
+    pub fn xcb_x_print_printer_next(i: *mut XCBXPrintPrinterIterator);
+    pub fn xcb_x_print_printer_end(i: XCBXPrintPrinterIterator) -> XCBGenericIterator;
+    pub fn xcb_x_print_printer_name(R: *const XCBXPrintPrinter) -> *mut XCBXPrintString8;
+    pub fn xcb_x_print_printer_name_length(R: *const XCBXPrintPrinter) -> c_int;
+    pub fn xcb_x_print_printer_name_end(R: *const XCBXPrintPrinter) -> XCBGenericIterator;
+    pub fn xcb_x_print_printer_description(R: *const XCBXPrintPrinter) -> *mut XCBXPrintString8;
+    pub fn xcb_x_print_printer_description_length(R: *const XCBXPrintPrinter) -> c_int;
+    pub fn xcb_x_print_printer_description_end(R: *const XCBXPrintPrinter) -> XCBGenericIterator;
+    pub fn xcb_x_print_pcontext_next(i: *mut XCBXPrintPcontextIterator);
+    pub fn xcb_x_print_pcontext_end(i: XCBXPrintPcontextIterator) -> XCBGenericIterator;
+    pub fn xcb_x_print_print_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXPrintPrintQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXPrintPrintQueryVersionReply;
+    pub fn xcb_x_print_print_query_version(
+        connection: *mut XCBConnection,
+    ) -> XCBXPrintPrintQueryVersionCookie;
+    pub fn xcb_x_print_print_query_version_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBXPrintPrintQueryVersionCookie;
+    pub fn xcb_x_print_print_get_printer_list_printers_length(
+        R: *const XCBXPrintPrintGetPrinterListReply,
+    ) -> c_int;
+    pub fn xcb_x_print_print_get_printer_list_printers_iterator(
+        R: *const XCBXPrintPrintGetPrinterListReply,
+    ) -> XCBXPrintPrinterIterator;
+    pub fn xcb_x_print_print_get_printer_list_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXPrintPrintGetPrinterListCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXPrintPrintGetPrinterListReply;
+    pub fn xcb_x_print_print_get_printer_list(
+        connection: *mut XCBConnection,
+        printerNameLen: u32,
+        localeLen: u32,
+        printer_name: *const XCBXPrintString8,
+        locale: *const XCBXPrintString8,
+    ) -> XCBXPrintPrintGetPrinterListCookie;
+    pub fn xcb_x_print_print_get_printer_list_unchecked(
+        connection: *mut XCBConnection,
+        printerNameLen: u32,
+        localeLen: u32,
+        printer_name: *const XCBXPrintString8,
+        locale: *const XCBXPrintString8,
+    ) -> XCBXPrintPrintGetPrinterListCookie;
+    pub fn xcb_x_print_print_rehash_printer_list_checked(
+        connection: *mut XCBConnection,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_rehash_printer_list(connection: *mut XCBConnection) -> XCBVoidCookie;
+    pub fn xcb_x_print_create_context_checked(
+        connection: *mut XCBConnection,
+        context_id: u32,
+        printerNameLen: u32,
+        localeLen: u32,
+        printerName: *const XCBXPrintString8,
+        locale: *const XCBXPrintString8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_create_context(
+        connection: *mut XCBConnection,
+        context_id: u32,
+        printerNameLen: u32,
+        localeLen: u32,
+        printerName: *const XCBXPrintString8,
+        locale: *const XCBXPrintString8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_set_context_checked(
+        connection: *mut XCBConnection,
+        context: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_set_context(
+        connection: *mut XCBConnection,
+        context: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_get_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXPrintPrintGetContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXPrintPrintGetContextReply;
+    pub fn xcb_x_print_print_get_context(
+        connection: *mut XCBConnection,
+    ) -> XCBXPrintPrintGetContextCookie;
+    pub fn xcb_x_print_print_get_context_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBXPrintPrintGetContextCookie;
+    pub fn xcb_x_print_print_destroy_context_checked(
+        connection: *mut XCBConnection,
+        context: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_destroy_context(
+        connection: *mut XCBConnection,
+        context: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_get_screen_of_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXPrintPrintGetScreenOfContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXPrintPrintGetScreenOfContextReply;
+    pub fn xcb_x_print_print_get_screen_of_context(
+        connection: *mut XCBConnection,
+    ) -> XCBXPrintPrintGetScreenOfContextCookie;
+    pub fn xcb_x_print_print_get_screen_of_context_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBXPrintPrintGetScreenOfContextCookie;
+    pub fn xcb_x_print_print_start_job_checked(
+        connection: *mut XCBConnection,
+        output_mode: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_start_job(
+        connection: *mut XCBConnection,
+        output_mode: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_end_job_checked(
+        connection: *mut XCBConnection,
+        cancel: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_end_job(connection: *mut XCBConnection, cancel: u8) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_start_doc_checked(
+        connection: *mut XCBConnection,
+        driver_mode: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_start_doc(
+        connection: *mut XCBConnection,
+        driver_mode: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_end_doc_checked(
+        connection: *mut XCBConnection,
+        cancel: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_end_doc(connection: *mut XCBConnection, cancel: u8) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_put_document_data_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        len_data: u32,
+        len_fmt: u16,
+        len_options: u16,
+        data: *const u8,
+        doc_format: *const XCBXPrintString8,
+        options: *const XCBXPrintString8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_put_document_data(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        len_data: u32,
+        len_fmt: u16,
+        len_options: u16,
+        data: *const u8,
+        doc_format: *const XCBXPrintString8,
+        options: *const XCBXPrintString8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_get_document_data_data(
+        R: *const XCBXPrintPrintGetDocumentDataReply,
+    ) -> *mut u8;
+    pub fn xcb_x_print_print_get_document_data_data_length(
+        R: *const XCBXPrintPrintGetDocumentDataReply,
+    ) -> c_int;
+    pub fn xcb_x_print_print_get_document_data_data_end(
+        R: *const XCBXPrintPrintGetDocumentDataReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_x_print_print_get_document_data_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXPrintPrintGetDocumentDataCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXPrintPrintGetDocumentDataReply;
+    pub fn xcb_x_print_print_get_document_data(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+        max_bytes: u32,
+    ) -> XCBXPrintPrintGetDocumentDataCookie;
+    pub fn xcb_x_print_print_get_document_data_unchecked(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+        max_bytes: u32,
+    ) -> XCBXPrintPrintGetDocumentDataCookie;
+    pub fn xcb_x_print_print_start_page_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_start_page(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_end_page_checked(
+        connection: *mut XCBConnection,
+        cancel: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_end_page(connection: *mut XCBConnection, cancel: u8) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_select_input_checked(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+        event_mask: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_select_input(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+        event_mask: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_input_selected_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXPrintPrintInputSelectedCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXPrintPrintInputSelectedReply;
+    pub fn xcb_x_print_print_input_selected(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+    ) -> XCBXPrintPrintInputSelectedCookie;
+    pub fn xcb_x_print_print_input_selected_unchecked(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+    ) -> XCBXPrintPrintInputSelectedCookie;
+    pub fn xcb_x_print_print_get_attributes_attributes(
+        R: *const XCBXPrintPrintGetAttributesReply,
+    ) -> *mut XCBXPrintString8;
+    pub fn xcb_x_print_print_get_attributes_attributes_length(
+        R: *const XCBXPrintPrintGetAttributesReply,
+    ) -> c_int;
+    pub fn xcb_x_print_print_get_attributes_attributes_end(
+        R: *const XCBXPrintPrintGetAttributesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_x_print_print_get_attributes_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXPrintPrintGetAttributesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXPrintPrintGetAttributesReply;
+    pub fn xcb_x_print_print_get_attributes(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+        pool: u8,
+    ) -> XCBXPrintPrintGetAttributesCookie;
+    pub fn xcb_x_print_print_get_attributes_unchecked(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+        pool: u8,
+    ) -> XCBXPrintPrintGetAttributesCookie;
+    pub fn xcb_x_print_print_get_one_attributes_value(
+        R: *const XCBXPrintPrintGetOneAttributesReply,
+    ) -> *mut XCBXPrintString8;
+    pub fn xcb_x_print_print_get_one_attributes_value_length(
+        R: *const XCBXPrintPrintGetOneAttributesReply,
+    ) -> c_int;
+    pub fn xcb_x_print_print_get_one_attributes_value_end(
+        R: *const XCBXPrintPrintGetOneAttributesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_x_print_print_get_one_attributes_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXPrintPrintGetOneAttributesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXPrintPrintGetOneAttributesReply;
+    pub fn xcb_x_print_print_get_one_attributes(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+        nameLen: u32,
+        pool: u8,
+        name: *const XCBXPrintString8,
+    ) -> XCBXPrintPrintGetOneAttributesCookie;
+    pub fn xcb_x_print_print_get_one_attributes_unchecked(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+        nameLen: u32,
+        pool: u8,
+        name: *const XCBXPrintString8,
+    ) -> XCBXPrintPrintGetOneAttributesCookie;
+    pub fn xcb_x_print_print_set_attributes_checked(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+        stringLen: u32,
+        pool: u8,
+        rule: u8,
+        attributes_len: u32,
+        attributes: *const XCBXPrintString8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_set_attributes(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+        stringLen: u32,
+        pool: u8,
+        rule: u8,
+        attributes_len: u32,
+        attributes: *const XCBXPrintString8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_x_print_print_get_page_dimensions_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXPrintPrintGetPageDimensionsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXPrintPrintGetPageDimensionsReply;
+    pub fn xcb_x_print_print_get_page_dimensions(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+    ) -> XCBXPrintPrintGetPageDimensionsCookie;
+    pub fn xcb_x_print_print_get_page_dimensions_unchecked(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+    ) -> XCBXPrintPrintGetPageDimensionsCookie;
+    pub fn xcb_x_print_print_query_screens_roots(
+        R: *const XCBXPrintPrintQueryScreensReply,
+    ) -> *mut XCBWindow;
+    pub fn xcb_x_print_print_query_screens_roots_length(
+        R: *const XCBXPrintPrintQueryScreensReply,
+    ) -> c_int;
+    pub fn xcb_x_print_print_query_screens_roots_end(
+        R: *const XCBXPrintPrintQueryScreensReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_x_print_print_query_screens_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXPrintPrintQueryScreensCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXPrintPrintQueryScreensReply;
+    pub fn xcb_x_print_print_query_screens(
+        connection: *mut XCBConnection,
+    ) -> XCBXPrintPrintQueryScreensCookie;
+    pub fn xcb_x_print_print_query_screens_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBXPrintPrintQueryScreensCookie;
+    pub fn xcb_x_print_print_set_image_resolution_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXPrintPrintSetImageResolutionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXPrintPrintSetImageResolutionReply;
+    pub fn xcb_x_print_print_set_image_resolution(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+        image_resolution: u16,
+    ) -> XCBXPrintPrintSetImageResolutionCookie;
+    pub fn xcb_x_print_print_set_image_resolution_unchecked(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+        image_resolution: u16,
+    ) -> XCBXPrintPrintSetImageResolutionCookie;
+    pub fn xcb_x_print_print_get_image_resolution_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXPrintPrintGetImageResolutionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXPrintPrintGetImageResolutionReply;
+    pub fn xcb_x_print_print_get_image_resolution(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+    ) -> XCBXPrintPrintGetImageResolutionCookie;
+    pub fn xcb_x_print_print_get_image_resolution_unchecked(
+        connection: *mut XCBConnection,
+        context: XCBXPrintPcontext,
+    ) -> XCBXPrintPrintGetImageResolutionCookie;
+}
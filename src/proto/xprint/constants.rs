@@ -0,0 +1,51 @@
+// Generated by xcbgen from xml/xprint.xml, do not edit.
+
+pub const XCB_X_PRINT_MAJOR_VERSION: u32 = 1;
+pub const XCB_X_PRINT_MINOR_VERSION: u32 = 0;
+pub const XCB_X_PRINT_GET_DOC_FINISHED: u32 = 0;
+pub const XCB_X_PRINT_GET_DOC_SECOND_CONSUMER: u32 = 1;
+pub const XCB_X_PRINT_EV_MASK_NO_EVENT_MASK: u32 = 0;
+pub const XCB_X_PRINT_EV_MASK_PRINT_MASK: u32 = 1;
+pub const XCB_X_PRINT_EV_MASK_ATTRIBUTE_MASK: u32 = 2;
+pub const XCB_X_PRINT_DETAIL_START_JOB_NOTIFY: u32 = 1;
+pub const XCB_X_PRINT_DETAIL_END_JOB_NOTIFY: u32 = 2;
+pub const XCB_X_PRINT_DETAIL_START_DOC_NOTIFY: u32 = 3;
+pub const XCB_X_PRINT_DETAIL_END_DOC_NOTIFY: u32 = 4;
+pub const XCB_X_PRINT_DETAIL_START_PAGE_NOTIFY: u32 = 5;
+pub const XCB_X_PRINT_DETAIL_END_PAGE_NOTIFY: u32 = 6;
+pub const XCB_X_PRINT_ATTR_JOB_ATTR: u32 = 1;
+pub const XCB_X_PRINT_ATTR_DOC_ATTR: u32 = 2;
+pub const XCB_X_PRINT_ATTR_PAGE_ATTR: u32 = 3;
+pub const XCB_X_PRINT_ATTR_PRINTER_ATTR: u32 = 4;
+pub const XCB_X_PRINT_ATTR_SERVER_ATTR: u32 = 5;
+pub const XCB_X_PRINT_ATTR_MEDIUM_ATTR: u32 = 6;
+pub const XCB_X_PRINT_ATTR_SPOOLER_ATTR: u32 = 7;
+pub const XCB_X_PRINT_PRINT_QUERY_VERSION: u8 = 0;
+pub const XCB_X_PRINT_PRINT_GET_PRINTER_LIST: u8 = 1;
+pub const XCB_X_PRINT_PRINT_REHASH_PRINTER_LIST: u8 = 20;
+pub const XCB_X_PRINT_CREATE_CONTEXT: u8 = 2;
+pub const XCB_X_PRINT_PRINT_SET_CONTEXT: u8 = 3;
+pub const XCB_X_PRINT_PRINT_GET_CONTEXT: u8 = 4;
+pub const XCB_X_PRINT_PRINT_DESTROY_CONTEXT: u8 = 5;
+pub const XCB_X_PRINT_PRINT_GET_SCREEN_OF_CONTEXT: u8 = 6;
+pub const XCB_X_PRINT_PRINT_START_JOB: u8 = 7;
+pub const XCB_X_PRINT_PRINT_END_JOB: u8 = 8;
+pub const XCB_X_PRINT_PRINT_START_DOC: u8 = 9;
+pub const XCB_X_PRINT_PRINT_END_DOC: u8 = 10;
+pub const XCB_X_PRINT_PRINT_PUT_DOCUMENT_DATA: u8 = 11;
+pub const XCB_X_PRINT_PRINT_GET_DOCUMENT_DATA: u8 = 12;
+pub const XCB_X_PRINT_PRINT_START_PAGE: u8 = 13;
+pub const XCB_X_PRINT_PRINT_END_PAGE: u8 = 14;
+pub const XCB_X_PRINT_PRINT_SELECT_INPUT: u8 = 15;
+pub const XCB_X_PRINT_PRINT_INPUT_SELECTED: u8 = 16;
+pub const XCB_X_PRINT_PRINT_GET_ATTRIBUTES: u8 = 17;
+pub const XCB_X_PRINT_PRINT_GET_ONE_ATTRIBUTES: u8 = 19;
+pub const XCB_X_PRINT_PRINT_SET_ATTRIBUTES: u8 = 18;
+pub const XCB_X_PRINT_PRINT_GET_PAGE_DIMENSIONS: u8 = 21;
+pub const XCB_X_PRINT_PRINT_QUERY_SCREENS: u8 = 22;
+pub const XCB_X_PRINT_PRINT_SET_IMAGE_RESOLUTION: u8 = 23;
+pub const XCB_X_PRINT_PRINT_GET_IMAGE_RESOLUTION: u8 = 24;
+pub const XCB_X_PRINT_NOTIFY: u8 = 0;
+pub const XCB_X_PRINT_ATTRIBUT_NOTIFY: u8 = 1;
+pub const XCB_X_PRINT_BAD_CONTEXT: u8 = 0;
+pub const XCB_X_PRINT_BAD_SEQUENCE: u8 = 1;
@@ -0,0 +1,531 @@
+// Generated by xcbgen from xml/xprint.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use proto::xproto::{XCBDrawable, XCBWindow};
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBXPrintString8 = cdef::XCBXPrintString8;
+pub type XCBXPrintString8Iterator = cdef::XCBXPrintString8Iterator;
+pub type XCBXPrintPrinter = cdef::XCBXPrintPrinter;
+pub type XCBXPrintPrinterIterator = cdef::XCBXPrintPrinterIterator;
+pub type XCBXPrintPcontext = cdef::XCBXPrintPcontext;
+pub type XCBXPrintPcontextIterator = cdef::XCBXPrintPcontextIterator;
+pub type XCBXPrintPrintQueryVersionRequest = cdef::XCBXPrintPrintQueryVersionRequest;
+pub type XCBXPrintPrintQueryVersionCookie = cdef::XCBXPrintPrintQueryVersionCookie;
+pub type XCBXPrintPrintQueryVersionReply = cdef::XCBXPrintPrintQueryVersionReply;
+pub type XCBXPrintPrintGetPrinterListRequest = cdef::XCBXPrintPrintGetPrinterListRequest;
+pub type XCBXPrintPrintGetPrinterListCookie = cdef::XCBXPrintPrintGetPrinterListCookie;
+pub type XCBXPrintPrintGetPrinterListReply = cdef::XCBXPrintPrintGetPrinterListReply;
+pub type XCBXPrintPrintRehashPrinterListRequest = cdef::XCBXPrintPrintRehashPrinterListRequest;
+pub type XCBXPrintCreateContextRequest = cdef::XCBXPrintCreateContextRequest;
+pub type XCBXPrintPrintSetContextRequest = cdef::XCBXPrintPrintSetContextRequest;
+pub type XCBXPrintPrintGetContextRequest = cdef::XCBXPrintPrintGetContextRequest;
+pub type XCBXPrintPrintGetContextCookie = cdef::XCBXPrintPrintGetContextCookie;
+pub type XCBXPrintPrintGetContextReply = cdef::XCBXPrintPrintGetContextReply;
+pub type XCBXPrintPrintDestroyContextRequest = cdef::XCBXPrintPrintDestroyContextRequest;
+pub type XCBXPrintPrintGetScreenOfContextRequest = cdef::XCBXPrintPrintGetScreenOfContextRequest;
+pub type XCBXPrintPrintGetScreenOfContextCookie = cdef::XCBXPrintPrintGetScreenOfContextCookie;
+pub type XCBXPrintPrintGetScreenOfContextReply = cdef::XCBXPrintPrintGetScreenOfContextReply;
+pub type XCBXPrintPrintStartJobRequest = cdef::XCBXPrintPrintStartJobRequest;
+pub type XCBXPrintPrintEndJobRequest = cdef::XCBXPrintPrintEndJobRequest;
+pub type XCBXPrintPrintStartDocRequest = cdef::XCBXPrintPrintStartDocRequest;
+pub type XCBXPrintPrintEndDocRequest = cdef::XCBXPrintPrintEndDocRequest;
+pub type XCBXPrintPrintPutDocumentDataRequest = cdef::XCBXPrintPrintPutDocumentDataRequest;
+pub type XCBXPrintPrintGetDocumentDataRequest = cdef::XCBXPrintPrintGetDocumentDataRequest;
+pub type XCBXPrintPrintGetDocumentDataCookie = cdef::XCBXPrintPrintGetDocumentDataCookie;
+pub type XCBXPrintPrintGetDocumentDataReply = cdef::XCBXPrintPrintGetDocumentDataReply;
+pub type XCBXPrintPrintStartPageRequest = cdef::XCBXPrintPrintStartPageRequest;
+pub type XCBXPrintPrintEndPageRequest = cdef::XCBXPrintPrintEndPageRequest;
+pub type XCBXPrintPrintSelectInputRequest = cdef::XCBXPrintPrintSelectInputRequest;
+pub type XCBXPrintPrintInputSelectedRequest = cdef::XCBXPrintPrintInputSelectedRequest;
+pub type XCBXPrintPrintInputSelectedCookie = cdef::XCBXPrintPrintInputSelectedCookie;
+pub type XCBXPrintPrintInputSelectedReply = cdef::XCBXPrintPrintInputSelectedReply;
+pub type XCBXPrintPrintGetAttributesRequest = cdef::XCBXPrintPrintGetAttributesRequest;
+pub type XCBXPrintPrintGetAttributesCookie = cdef::XCBXPrintPrintGetAttributesCookie;
+pub type XCBXPrintPrintGetAttributesReply = cdef::XCBXPrintPrintGetAttributesReply;
+pub type XCBXPrintPrintGetOneAttributesRequest = cdef::XCBXPrintPrintGetOneAttributesRequest;
+pub type XCBXPrintPrintGetOneAttributesCookie = cdef::XCBXPrintPrintGetOneAttributesCookie;
+pub type XCBXPrintPrintGetOneAttributesReply = cdef::XCBXPrintPrintGetOneAttributesReply;
+pub type XCBXPrintPrintSetAttributesRequest = cdef::XCBXPrintPrintSetAttributesRequest;
+pub type XCBXPrintPrintGetPageDimensionsRequest = cdef::XCBXPrintPrintGetPageDimensionsRequest;
+pub type XCBXPrintPrintGetPageDimensionsCookie = cdef::XCBXPrintPrintGetPageDimensionsCookie;
+pub type XCBXPrintPrintGetPageDimensionsReply = cdef::XCBXPrintPrintGetPageDimensionsReply;
+pub type XCBXPrintPrintQueryScreensRequest = cdef::XCBXPrintPrintQueryScreensRequest;
+pub type XCBXPrintPrintQueryScreensCookie = cdef::XCBXPrintPrintQueryScreensCookie;
+pub type XCBXPrintPrintQueryScreensReply = cdef::XCBXPrintPrintQueryScreensReply;
+pub type XCBXPrintPrintSetImageResolutionRequest = cdef::XCBXPrintPrintSetImageResolutionRequest;
+pub type XCBXPrintPrintSetImageResolutionCookie = cdef::XCBXPrintPrintSetImageResolutionCookie;
+pub type XCBXPrintPrintSetImageResolutionReply = cdef::XCBXPrintPrintSetImageResolutionReply;
+pub type XCBXPrintPrintGetImageResolutionRequest = cdef::XCBXPrintPrintGetImageResolutionRequest;
+pub type XCBXPrintPrintGetImageResolutionCookie = cdef::XCBXPrintPrintGetImageResolutionCookie;
+pub type XCBXPrintPrintGetImageResolutionReply = cdef::XCBXPrintPrintGetImageResolutionReply;
+pub type XCBXPrintNotifyEvent = cdef::XCBXPrintNotifyEvent;
+pub type XCBXPrintAttributNotifyEvent = cdef::XCBXPrintAttributNotifyEvent;
+pub type XCBXPrintBadContextError = cdef::XCBXPrintBadContextError;
+pub type XCBXPrintBadSequenceError = cdef::XCBXPrintBadSequenceError;
+
+/// The XPrint extension, known to the server as `XpExtension`.
+pub struct Xprint;
+
+impl Extension for Xprint {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_x_print_id)
+    }
+}
+
+pub fn xcb_x_print_string8_next(iter: &mut XCBXPrintString8Iterator) {
+    unsafe { cdef::xcb_x_print_string8_next(iter) }
+}
+
+pub fn xcb_x_print_printer_next(iter: &mut XCBXPrintPrinterIterator) {
+    unsafe { cdef::xcb_x_print_printer_next(iter) }
+}
+
+pub fn xcb_x_print_printer_name(data: &XCBXPrintPrinter) -> &[XCBXPrintString8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_x_print_printer_name(data),
+            cdef::xcb_x_print_printer_name_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_x_print_printer_description(data: &XCBXPrintPrinter) -> &[XCBXPrintString8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_x_print_printer_description(data),
+            cdef::xcb_x_print_printer_description_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_x_print_pcontext_next(iter: &mut XCBXPrintPcontextIterator) {
+    unsafe { cdef::xcb_x_print_pcontext_next(iter) }
+}
+
+impl Reply for XCBXPrintPrintQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_x_print_print_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_x_print_print_query_version(
+    connection: &XCBConnection,
+) -> XCBXPrintPrintQueryVersionCookie {
+    unsafe { cdef::xcb_x_print_print_query_version(connection.raw) }
+}
+
+pub fn xcb_x_print_print_get_printer_list_printers_iterator(
+    reply: &XCBXPrintPrintGetPrinterListReply,
+) -> XCBXPrintPrinterIterator {
+    unsafe { cdef::xcb_x_print_print_get_printer_list_printers_iterator(reply) }
+}
+
+impl Reply for XCBXPrintPrintGetPrinterListReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_x_print_print_get_printer_list_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_x_print_print_get_printer_list(
+    connection: &XCBConnection,
+    printer_name: &[XCBXPrintString8],
+    locale: &[XCBXPrintString8],
+) -> XCBXPrintPrintGetPrinterListCookie {
+    unsafe {
+        cdef::xcb_x_print_print_get_printer_list(
+            connection.raw,
+            printer_name.len() as u32,
+            locale.len() as u32,
+            printer_name.as_ptr(),
+            locale.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_x_print_print_rehash_printer_list(connection: &XCBConnection) -> XCBVoidCookie {
+    unsafe { cdef::xcb_x_print_print_rehash_printer_list(connection.raw) }
+}
+
+pub fn xcb_x_print_create_context(
+    connection: &XCBConnection,
+    context_id: u32,
+    printerName: &[XCBXPrintString8],
+    locale: &[XCBXPrintString8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_x_print_create_context(
+            connection.raw,
+            context_id,
+            printerName.len() as u32,
+            locale.len() as u32,
+            printerName.as_ptr(),
+            locale.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_x_print_print_set_context(connection: &XCBConnection, context: u32) -> XCBVoidCookie {
+    unsafe { cdef::xcb_x_print_print_set_context(connection.raw, context) }
+}
+
+impl Reply for XCBXPrintPrintGetContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_x_print_print_get_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_x_print_print_get_context(connection: &XCBConnection) -> XCBXPrintPrintGetContextCookie {
+    unsafe { cdef::xcb_x_print_print_get_context(connection.raw) }
+}
+
+pub fn xcb_x_print_print_destroy_context(
+    connection: &XCBConnection,
+    context: u32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_x_print_print_destroy_context(connection.raw, context) }
+}
+
+impl Reply for XCBXPrintPrintGetScreenOfContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_x_print_print_get_screen_of_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_x_print_print_get_screen_of_context(
+    connection: &XCBConnection,
+) -> XCBXPrintPrintGetScreenOfContextCookie {
+    unsafe { cdef::xcb_x_print_print_get_screen_of_context(connection.raw) }
+}
+
+pub fn xcb_x_print_print_start_job(connection: &XCBConnection, output_mode: u8) -> XCBVoidCookie {
+    unsafe { cdef::xcb_x_print_print_start_job(connection.raw, output_mode) }
+}
+
+pub fn xcb_x_print_print_end_job(connection: &XCBConnection, cancel: bool) -> XCBVoidCookie {
+    unsafe { cdef::xcb_x_print_print_end_job(connection.raw, cancel as u8) }
+}
+
+pub fn xcb_x_print_print_start_doc(connection: &XCBConnection, driver_mode: u8) -> XCBVoidCookie {
+    unsafe { cdef::xcb_x_print_print_start_doc(connection.raw, driver_mode) }
+}
+
+pub fn xcb_x_print_print_end_doc(connection: &XCBConnection, cancel: bool) -> XCBVoidCookie {
+    unsafe { cdef::xcb_x_print_print_end_doc(connection.raw, cancel as u8) }
+}
+
+pub fn xcb_x_print_print_put_document_data(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    data: &[u8],
+    doc_format: &[XCBXPrintString8],
+    options: &[XCBXPrintString8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_x_print_print_put_document_data(
+            connection.raw,
+            drawable,
+            data.len() as u32,
+            doc_format.len() as u16,
+            options.len() as u16,
+            data.as_ptr(),
+            doc_format.as_ptr(),
+            options.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_x_print_print_get_document_data_data(
+    reply: &XCBXPrintPrintGetDocumentDataReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_x_print_print_get_document_data_data(reply),
+            cdef::xcb_x_print_print_get_document_data_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXPrintPrintGetDocumentDataReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_x_print_print_get_document_data_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_x_print_print_get_document_data(
+    connection: &XCBConnection,
+    context: XCBXPrintPcontext,
+    max_bytes: u32,
+) -> XCBXPrintPrintGetDocumentDataCookie {
+    unsafe { cdef::xcb_x_print_print_get_document_data(connection.raw, context, max_bytes) }
+}
+
+pub fn xcb_x_print_print_start_page(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_x_print_print_start_page(connection.raw, window) }
+}
+
+pub fn xcb_x_print_print_end_page(connection: &XCBConnection, cancel: bool) -> XCBVoidCookie {
+    unsafe { cdef::xcb_x_print_print_end_page(connection.raw, cancel as u8) }
+}
+
+pub fn xcb_x_print_print_select_input(
+    connection: &XCBConnection,
+    context: XCBXPrintPcontext,
+    event_mask: u32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_x_print_print_select_input(connection.raw, context, event_mask) }
+}
+
+impl Reply for XCBXPrintPrintInputSelectedReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_x_print_print_input_selected_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_x_print_print_input_selected(
+    connection: &XCBConnection,
+    context: XCBXPrintPcontext,
+) -> XCBXPrintPrintInputSelectedCookie {
+    unsafe { cdef::xcb_x_print_print_input_selected(connection.raw, context) }
+}
+
+pub fn xcb_x_print_print_get_attributes_attributes(
+    reply: &XCBXPrintPrintGetAttributesReply,
+) -> &[XCBXPrintString8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_x_print_print_get_attributes_attributes(reply),
+            cdef::xcb_x_print_print_get_attributes_attributes_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXPrintPrintGetAttributesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_x_print_print_get_attributes_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_x_print_print_get_attributes(
+    connection: &XCBConnection,
+    context: XCBXPrintPcontext,
+    pool: u8,
+) -> XCBXPrintPrintGetAttributesCookie {
+    unsafe { cdef::xcb_x_print_print_get_attributes(connection.raw, context, pool) }
+}
+
+pub fn xcb_x_print_print_get_one_attributes_value(
+    reply: &XCBXPrintPrintGetOneAttributesReply,
+) -> &[XCBXPrintString8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_x_print_print_get_one_attributes_value(reply),
+            cdef::xcb_x_print_print_get_one_attributes_value_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXPrintPrintGetOneAttributesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_x_print_print_get_one_attributes_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_x_print_print_get_one_attributes(
+    connection: &XCBConnection,
+    context: XCBXPrintPcontext,
+    pool: u8,
+    name: &[XCBXPrintString8],
+) -> XCBXPrintPrintGetOneAttributesCookie {
+    unsafe {
+        cdef::xcb_x_print_print_get_one_attributes(
+            connection.raw,
+            context,
+            name.len() as u32,
+            pool,
+            name.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_x_print_print_set_attributes(
+    connection: &XCBConnection,
+    context: XCBXPrintPcontext,
+    stringLen: u32,
+    pool: u8,
+    rule: u8,
+    attributes: &[XCBXPrintString8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_x_print_print_set_attributes(
+            connection.raw,
+            context,
+            stringLen,
+            pool,
+            rule,
+            attributes.len() as u32,
+            attributes.as_ptr(),
+        )
+    }
+}
+
+impl Reply for XCBXPrintPrintGetPageDimensionsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_x_print_print_get_page_dimensions_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_x_print_print_get_page_dimensions(
+    connection: &XCBConnection,
+    context: XCBXPrintPcontext,
+) -> XCBXPrintPrintGetPageDimensionsCookie {
+    unsafe { cdef::xcb_x_print_print_get_page_dimensions(connection.raw, context) }
+}
+
+pub fn xcb_x_print_print_query_screens_roots(
+    reply: &XCBXPrintPrintQueryScreensReply,
+) -> &[XCBWindow] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_x_print_print_query_screens_roots(reply),
+            cdef::xcb_x_print_print_query_screens_roots_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXPrintPrintQueryScreensReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_x_print_print_query_screens_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_x_print_print_query_screens(
+    connection: &XCBConnection,
+) -> XCBXPrintPrintQueryScreensCookie {
+    unsafe { cdef::xcb_x_print_print_query_screens(connection.raw) }
+}
+
+impl Reply for XCBXPrintPrintSetImageResolutionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_x_print_print_set_image_resolution_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_x_print_print_set_image_resolution(
+    connection: &XCBConnection,
+    context: XCBXPrintPcontext,
+    image_resolution: u16,
+) -> XCBXPrintPrintSetImageResolutionCookie {
+    unsafe {
+        cdef::xcb_x_print_print_set_image_resolution(connection.raw, context, image_resolution)
+    }
+}
+
+impl Reply for XCBXPrintPrintGetImageResolutionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_x_print_print_get_image_resolution_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_x_print_print_get_image_resolution(
+    connection: &XCBConnection,
+    context: XCBXPrintPcontext,
+) -> XCBXPrintPrintGetImageResolutionCookie {
+    unsafe { cdef::xcb_x_print_print_get_image_resolution(connection.raw, context) }
+}
+
+/// An event of the XPrint extension.
+pub enum Event {
+    Notify(Box<XCBXPrintNotifyEvent>),
+    AttributNotify(Box<XCBXPrintAttributNotifyEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::Notify(Box::from_raw(
+                    raw as *mut XCBXPrintNotifyEvent,
+                ))),
+                1 => Ok(Event::AttributNotify(Box::from_raw(
+                    raw as *mut XCBXPrintAttributNotifyEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
+
+/// Returns the name of the error whose code, relative to the first error of the extension, is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        0 => Some("BadContext"),
+        1 => Some("BadSequence"),
+        _ => None,
+    }
+}
@@ -0,0 +1,4569 @@
+// Generated by xcbgen from xml/xproto.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int, c_void};
+
+use cdef::{XCBConnection, XCBCookie, XCBGenericError, XCBGenericIterator, XCBVoidCookie};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBChar2b {
+    pub byte1: u8,
+    pub byte2: u8,
+}
+
+#[repr(C)]
+pub struct XCBChar2bIterator {
+    pub data: *mut XCBChar2b,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBWindow = u32;
+
+#[repr(C)]
+pub struct XCBWindowIterator {
+    pub data: *mut XCBWindow,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBPixmap = u32;
+
+#[repr(C)]
+pub struct XCBPixmapIterator {
+    pub data: *mut XCBPixmap,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBCursor = u32;
+
+#[repr(C)]
+pub struct XCBCursorIterator {
+    pub data: *mut XCBCursor,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBFont = u32;
+
+#[repr(C)]
+pub struct XCBFontIterator {
+    pub data: *mut XCBFont,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBGcontext = u32;
+
+#[repr(C)]
+pub struct XCBGcontextIterator {
+    pub data: *mut XCBGcontext,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBColormap = u32;
+
+#[repr(C)]
+pub struct XCBColormapIterator {
+    pub data: *mut XCBColormap,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBAtom = u32;
+
+#[repr(C)]
+pub struct XCBAtomIterator {
+    pub data: *mut XCBAtom,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBDrawable = u32;
+
+#[repr(C)]
+pub struct XCBDrawableIterator {
+    pub data: *mut XCBDrawable,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBFontable = u32;
+
+#[repr(C)]
+pub struct XCBFontableIterator {
+    pub data: *mut XCBFontable,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBBool32 = u32;
+
+#[repr(C)]
+pub struct XCBBool32Iterator {
+    pub data: *mut XCBBool32,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBVisualid = u32;
+
+#[repr(C)]
+pub struct XCBVisualidIterator {
+    pub data: *mut XCBVisualid,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBTimestamp = u32;
+
+#[repr(C)]
+pub struct XCBTimestampIterator {
+    pub data: *mut XCBTimestamp,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBKeysym = u32;
+
+#[repr(C)]
+pub struct XCBKeysymIterator {
+    pub data: *mut XCBKeysym,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBKeycode = u8;
+
+#[repr(C)]
+pub struct XCBKeycodeIterator {
+    pub data: *mut XCBKeycode,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBKeycode32 = u32;
+
+#[repr(C)]
+pub struct XCBKeycode32Iterator {
+    pub data: *mut XCBKeycode32,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBButton = u8;
+
+#[repr(C)]
+pub struct XCBButtonIterator {
+    pub data: *mut XCBButton,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBPoint {
+    pub x: i16,
+    pub y: i16,
+}
+
+#[repr(C)]
+pub struct XCBPointIterator {
+    pub data: *mut XCBPoint,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRectangle {
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+}
+
+#[repr(C)]
+pub struct XCBRectangleIterator {
+    pub data: *mut XCBRectangle,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBArc {
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub angle1: i16,
+    pub angle2: i16,
+}
+
+#[repr(C)]
+pub struct XCBArcIterator {
+    pub data: *mut XCBArc,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBFormat {
+    pub depth: u8,
+    pub bits_per_pixel: u8,
+    pub scanline_pad: u8,
+    pub pad0: [u8; 5],
+}
+
+#[repr(C)]
+pub struct XCBFormatIterator {
+    pub data: *mut XCBFormat,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBVisualtype {
+    pub visual_id: XCBVisualid,
+    pub class: u8,
+    pub bits_per_rgb_value: u8,
+    pub colormap_entries: u16,
+    pub red_mask: u32,
+    pub green_mask: u32,
+    pub blue_mask: u32,
+    pub pad0: [u8; 4],
+}
+
+#[repr(C)]
+pub struct XCBVisualtypeIterator {
+    pub data: *mut XCBVisualtype,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBDepth {
+    pub depth: u8,
+    pub pad0: u8,
+    pub visuals_len: u16,
+    pub pad1: [u8; 4],
+}
+
+#[repr(C)]
+pub struct XCBDepthIterator {
+    pub data: *mut XCBDepth,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBScreen {
+    pub root: XCBWindow,
+    pub default_colormap: XCBColormap,
+    pub white_pixel: u32,
+    pub black_pixel: u32,
+    pub current_input_masks: u32,
+    pub width_in_pixels: u16,
+    pub height_in_pixels: u16,
+    pub width_in_millimeters: u16,
+    pub height_in_millimeters: u16,
+    pub min_installed_maps: u16,
+    pub max_installed_maps: u16,
+    pub root_visual: XCBVisualid,
+    pub backing_stores: u8,
+    pub save_unders: u8,
+    pub root_depth: u8,
+    pub allowed_depths_len: u8,
+}
+
+#[repr(C)]
+pub struct XCBScreenIterator {
+    pub data: *mut XCBScreen,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBSetupRequest {
+    pub byte_order: u8,
+    pub pad0: u8,
+    pub protocol_major_version: u16,
+    pub protocol_minor_version: u16,
+    pub authorization_protocol_name_len: u16,
+    pub authorization_protocol_data_len: u16,
+    pub pad1: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBSetupRequestIterator {
+    pub data: *mut XCBSetupRequest,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBSetupFailed {
+    pub status: u8,
+    pub reason_len: u8,
+    pub protocol_major_version: u16,
+    pub protocol_minor_version: u16,
+    pub length: u16,
+}
+
+#[repr(C)]
+pub struct XCBSetupFailedIterator {
+    pub data: *mut XCBSetupFailed,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBSetupAuthenticate {
+    pub status: u8,
+    pub pad0: [u8; 5],
+    pub length: u16,
+}
+
+#[repr(C)]
+pub struct XCBSetupAuthenticateIterator {
+    pub data: *mut XCBSetupAuthenticate,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBSetup {
+    pub status: u8,
+    pub pad0: u8,
+    pub protocol_major_version: u16,
+    pub protocol_minor_version: u16,
+    pub length: u16,
+    pub release_number: u32,
+    pub resource_id_base: u32,
+    pub resource_id_mask: u32,
+    pub motion_buffer_size: u32,
+    pub vendor_len: u16,
+    pub maximum_request_length: u16,
+    pub roots_len: u8,
+    pub pixmap_formats_len: u8,
+    pub image_byte_order: u8,
+    pub bitmap_format_bit_order: u8,
+    pub bitmap_format_scanline_unit: u8,
+    pub bitmap_format_scanline_pad: u8,
+    pub min_keycode: XCBKeycode,
+    pub max_keycode: XCBKeycode,
+    pub pad1: [u8; 4],
+}
+
+#[repr(C)]
+pub struct XCBSetupIterator {
+    pub data: *mut XCBSetup,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBKeyPressEvent {
+    pub response_type: u8,
+    pub detail: XCBKeycode,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub root: XCBWindow,
+    pub event: XCBWindow,
+    pub child: XCBWindow,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub event_x: i16,
+    pub event_y: i16,
+    pub state: u16,
+    pub same_screen: u8,
+    pub pad0: u8,
+}
+
+pub type XCBKeyReleaseEvent = XCBKeyPressEvent;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBButtonPressEvent {
+    pub response_type: u8,
+    pub detail: XCBButton,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub root: XCBWindow,
+    pub event: XCBWindow,
+    pub child: XCBWindow,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub event_x: i16,
+    pub event_y: i16,
+    pub state: u16,
+    pub same_screen: u8,
+    pub pad0: u8,
+}
+
+pub type XCBButtonReleaseEvent = XCBButtonPressEvent;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBMotionNotifyEvent {
+    pub response_type: u8,
+    pub detail: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub root: XCBWindow,
+    pub event: XCBWindow,
+    pub child: XCBWindow,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub event_x: i16,
+    pub event_y: i16,
+    pub state: u16,
+    pub same_screen: u8,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBEnterNotifyEvent {
+    pub response_type: u8,
+    pub detail: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub root: XCBWindow,
+    pub event: XCBWindow,
+    pub child: XCBWindow,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub event_x: i16,
+    pub event_y: i16,
+    pub state: u16,
+    pub mode: u8,
+    pub same_screen_focus: u8,
+}
+
+pub type XCBLeaveNotifyEvent = XCBEnterNotifyEvent;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBFocusInEvent {
+    pub response_type: u8,
+    pub detail: u8,
+    pub sequence: u16,
+    pub event: XCBWindow,
+    pub mode: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBFocusOutEvent = XCBFocusInEvent;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBKeymapNotifyEvent {
+    pub response_type: u8,
+    pub keys: [u8; 31],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBExposeEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub window: XCBWindow,
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+    pub count: u16,
+    pub pad1: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGraphicsExposureEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub drawable: XCBDrawable,
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+    pub minor_opcode: u16,
+    pub count: u16,
+    pub major_opcode: u8,
+    pub pad1: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBNoExposureEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub drawable: XCBDrawable,
+    pub minor_opcode: u16,
+    pub major_opcode: u8,
+    pub pad1: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBVisibilityNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub window: XCBWindow,
+    pub state: u8,
+    pub pad1: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCreateNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub parent: XCBWindow,
+    pub window: XCBWindow,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub border_width: u16,
+    pub override_redirect: u8,
+    pub pad1: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDestroyNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub event: XCBWindow,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBUnmapNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub event: XCBWindow,
+    pub window: XCBWindow,
+    pub from_configure: u8,
+    pub pad1: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBMapNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub event: XCBWindow,
+    pub window: XCBWindow,
+    pub override_redirect: u8,
+    pub pad1: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBMapRequestEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub parent: XCBWindow,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBReparentNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub event: XCBWindow,
+    pub window: XCBWindow,
+    pub parent: XCBWindow,
+    pub x: i16,
+    pub y: i16,
+    pub override_redirect: u8,
+    pub pad1: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBConfigureNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub event: XCBWindow,
+    pub window: XCBWindow,
+    pub above_sibling: XCBWindow,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub border_width: u16,
+    pub override_redirect: u8,
+    pub pad1: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBConfigureRequestEvent {
+    pub response_type: u8,
+    pub stack_mode: u8,
+    pub sequence: u16,
+    pub parent: XCBWindow,
+    pub window: XCBWindow,
+    pub sibling: XCBWindow,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub border_width: u16,
+    pub value_mask: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGravityNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub event: XCBWindow,
+    pub window: XCBWindow,
+    pub x: i16,
+    pub y: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBResizeRequestEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub window: XCBWindow,
+    pub width: u16,
+    pub height: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCirculateNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub event: XCBWindow,
+    pub window: XCBWindow,
+    pub pad1: [u8; 4],
+    pub place: u8,
+    pub pad2: [u8; 3],
+}
+
+pub type XCBCirculateRequestEvent = XCBCirculateNotifyEvent;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBPropertyNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub window: XCBWindow,
+    pub atom: XCBAtom,
+    pub time: XCBTimestamp,
+    pub state: u8,
+    pub pad1: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelectionClearEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub owner: XCBWindow,
+    pub selection: XCBAtom,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelectionRequestEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub owner: XCBWindow,
+    pub requestor: XCBWindow,
+    pub selection: XCBAtom,
+    pub target: XCBAtom,
+    pub property: XCBAtom,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelectionNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub requestor: XCBWindow,
+    pub selection: XCBAtom,
+    pub target: XCBAtom,
+    pub property: XCBAtom,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBColormapNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub window: XCBWindow,
+    pub colormap: XCBColormap,
+    pub new: u8,
+    pub state: u8,
+    pub pad1: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union XCBClientMessageData {
+    pub data8: [u8; 20],
+    pub data16: [u16; 10],
+    pub data32: [u32; 5],
+}
+
+#[repr(C)]
+pub struct XCBClientMessageDataIterator {
+    pub data: *mut XCBClientMessageData,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBClientMessageEvent {
+    pub response_type: u8,
+    pub format: u8,
+    pub sequence: u16,
+    pub window: XCBWindow,
+    pub type_: XCBAtom,
+    pub data: XCBClientMessageData,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBMappingNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub request: u8,
+    pub first_keycode: XCBKeycode,
+    pub count: u8,
+    pub pad1: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGeGenericEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub pad0: [u8; 22],
+    pub full_sequence: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRequestError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+    pub bad_value: u32,
+    pub minor_opcode: u16,
+    pub major_opcode: u8,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBValueError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+    pub bad_value: u32,
+    pub minor_opcode: u16,
+    pub major_opcode: u8,
+    pub pad0: u8,
+}
+
+pub type XCBWindowError = XCBValueError;
+
+pub type XCBPixmapError = XCBValueError;
+
+pub type XCBAtomError = XCBValueError;
+
+pub type XCBCursorError = XCBValueError;
+
+pub type XCBFontError = XCBValueError;
+
+pub type XCBMatchError = XCBRequestError;
+
+pub type XCBDrawableError = XCBValueError;
+
+pub type XCBAccessError = XCBRequestError;
+
+pub type XCBAllocError = XCBRequestError;
+
+pub type XCBColormapError = XCBValueError;
+
+pub type XCBGContextError = XCBValueError;
+
+pub type XCBIdChoiceError = XCBValueError;
+
+pub type XCBNameError = XCBRequestError;
+
+pub type XCBLengthError = XCBRequestError;
+
+pub type XCBImplementationError = XCBRequestError;
+
+#[repr(C)]
+pub struct XCBCreateWindowRequest {
+    pub major_opcode: u8,
+    pub depth: u8,
+    pub length: u16,
+    pub wid: XCBWindow,
+    pub parent: XCBWindow,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub border_width: u16,
+    pub class: u16,
+    pub visual: XCBVisualid,
+    pub value_mask: u32,
+}
+
+#[repr(C)]
+pub struct XCBChangeWindowAttributesRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub value_mask: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetWindowAttributesRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBGetWindowAttributesCookie = XCBCookie<XCBGetWindowAttributesReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetWindowAttributesReply {
+    pub response_type: u8,
+    pub backing_store: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub visual: XCBVisualid,
+    pub class: u16,
+    pub bit_gravity: u8,
+    pub win_gravity: u8,
+    pub backing_planes: u32,
+    pub backing_pixel: u32,
+    pub save_under: u8,
+    pub map_is_installed: u8,
+    pub map_state: u8,
+    pub override_redirect: u8,
+    pub colormap: XCBColormap,
+    pub all_event_masks: u32,
+    pub your_event_mask: u32,
+    pub do_not_propagate_mask: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDestroyWindowRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDestroySubwindowsRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBChangeSaveSetRequest {
+    pub major_opcode: u8,
+    pub mode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBReparentWindowRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub parent: XCBWindow,
+    pub x: i16,
+    pub y: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBMapWindowRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBMapSubwindowsRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBUnmapWindowRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBUnmapSubwindowsRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+pub struct XCBConfigureWindowRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub value_mask: u16,
+    pub pad1: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCirculateWindowRequest {
+    pub major_opcode: u8,
+    pub direction: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetGeometryRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+}
+
+pub type XCBGetGeometryCookie = XCBCookie<XCBGetGeometryReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetGeometryReply {
+    pub response_type: u8,
+    pub depth: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub root: XCBWindow,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub border_width: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBQueryTreeRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBQueryTreeCookie = XCBCookie<XCBQueryTreeReply>;
+
+#[repr(C)]
+pub struct XCBQueryTreeReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub root: XCBWindow,
+    pub parent: XCBWindow,
+    pub children_len: u16,
+    pub pad1: [u8; 14],
+}
+
+#[repr(C)]
+pub struct XCBInternAtomRequest {
+    pub major_opcode: u8,
+    pub only_if_exists: u8,
+    pub length: u16,
+    pub name_len: u16,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBInternAtomCookie = XCBCookie<XCBInternAtomReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInternAtomReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub atom: XCBAtom,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetAtomNameRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub atom: XCBAtom,
+}
+
+pub type XCBGetAtomNameCookie = XCBCookie<XCBGetAtomNameReply>;
+
+#[repr(C)]
+pub struct XCBGetAtomNameReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub name_len: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+pub struct XCBChangePropertyRequest {
+    pub major_opcode: u8,
+    pub mode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub property: XCBAtom,
+    pub type_: XCBAtom,
+    pub format: u8,
+    pub pad0: [u8; 3],
+    pub data_len: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBDeletePropertyRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub property: XCBAtom,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetPropertyRequest {
+    pub major_opcode: u8,
+    pub delete: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub property: XCBAtom,
+    pub type_: XCBAtom,
+    pub long_offset: u32,
+    pub long_length: u32,
+}
+
+pub type XCBGetPropertyCookie = XCBCookie<XCBGetPropertyReply>;
+
+#[repr(C)]
+pub struct XCBGetPropertyReply {
+    pub response_type: u8,
+    pub format: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub type_: XCBAtom,
+    pub bytes_after: u32,
+    pub value_len: u32,
+    pub pad0: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBListPropertiesRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBListPropertiesCookie = XCBCookie<XCBListPropertiesReply>;
+
+#[repr(C)]
+pub struct XCBListPropertiesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub atoms_len: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSetSelectionOwnerRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub owner: XCBWindow,
+    pub selection: XCBAtom,
+    pub time: XCBTimestamp,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetSelectionOwnerRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub selection: XCBAtom,
+}
+
+pub type XCBGetSelectionOwnerCookie = XCBCookie<XCBGetSelectionOwnerReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetSelectionOwnerReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub owner: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBConvertSelectionRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub requestor: XCBWindow,
+    pub selection: XCBAtom,
+    pub target: XCBAtom,
+    pub property: XCBAtom,
+    pub time: XCBTimestamp,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSendEventRequest {
+    pub major_opcode: u8,
+    pub propagate: u8,
+    pub length: u16,
+    pub destination: XCBWindow,
+    pub event_mask: u32,
+    pub event: [c_char; 32],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGrabPointerRequest {
+    pub major_opcode: u8,
+    pub owner_events: u8,
+    pub length: u16,
+    pub grab_window: XCBWindow,
+    pub event_mask: u16,
+    pub pointer_mode: u8,
+    pub keyboard_mode: u8,
+    pub confine_to: XCBWindow,
+    pub cursor: XCBCursor,
+    pub time: XCBTimestamp,
+}
+
+pub type XCBGrabPointerCookie = XCBCookie<XCBGrabPointerReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGrabPointerReply {
+    pub response_type: u8,
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBUngrabPointerRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub time: XCBTimestamp,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGrabButtonRequest {
+    pub major_opcode: u8,
+    pub owner_events: u8,
+    pub length: u16,
+    pub grab_window: XCBWindow,
+    pub event_mask: u16,
+    pub pointer_mode: u8,
+    pub keyboard_mode: u8,
+    pub confine_to: XCBWindow,
+    pub cursor: XCBCursor,
+    pub button: u8,
+    pub pad0: u8,
+    pub modifiers: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBUngrabButtonRequest {
+    pub major_opcode: u8,
+    pub button: u8,
+    pub length: u16,
+    pub grab_window: XCBWindow,
+    pub modifiers: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBChangeActivePointerGrabRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cursor: XCBCursor,
+    pub time: XCBTimestamp,
+    pub event_mask: u16,
+    pub pad1: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGrabKeyboardRequest {
+    pub major_opcode: u8,
+    pub owner_events: u8,
+    pub length: u16,
+    pub grab_window: XCBWindow,
+    pub time: XCBTimestamp,
+    pub pointer_mode: u8,
+    pub keyboard_mode: u8,
+    pub pad0: [u8; 2],
+}
+
+pub type XCBGrabKeyboardCookie = XCBCookie<XCBGrabKeyboardReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGrabKeyboardReply {
+    pub response_type: u8,
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBUngrabKeyboardRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub time: XCBTimestamp,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGrabKeyRequest {
+    pub major_opcode: u8,
+    pub owner_events: u8,
+    pub length: u16,
+    pub grab_window: XCBWindow,
+    pub modifiers: u16,
+    pub key: XCBKeycode,
+    pub pointer_mode: u8,
+    pub keyboard_mode: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBUngrabKeyRequest {
+    pub major_opcode: u8,
+    pub key: XCBKeycode,
+    pub length: u16,
+    pub grab_window: XCBWindow,
+    pub modifiers: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBAllowEventsRequest {
+    pub major_opcode: u8,
+    pub mode: u8,
+    pub length: u16,
+    pub time: XCBTimestamp,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGrabServerRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBUngrabServerRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBQueryPointerRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBQueryPointerCookie = XCBCookie<XCBQueryPointerReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBQueryPointerReply {
+    pub response_type: u8,
+    pub same_screen: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub root: XCBWindow,
+    pub child: XCBWindow,
+    pub root_x: i16,
+    pub root_y: i16,
+    pub win_x: i16,
+    pub win_y: i16,
+    pub mask: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBTimecoord {
+    pub time: XCBTimestamp,
+    pub x: i16,
+    pub y: i16,
+}
+
+#[repr(C)]
+pub struct XCBTimecoordIterator {
+    pub data: *mut XCBTimecoord,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetMotionEventsRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub start: XCBTimestamp,
+    pub stop: XCBTimestamp,
+}
+
+pub type XCBGetMotionEventsCookie = XCBCookie<XCBGetMotionEventsReply>;
+
+#[repr(C)]
+pub struct XCBGetMotionEventsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub events_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBTranslateCoordinatesRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub src_window: XCBWindow,
+    pub dst_window: XCBWindow,
+    pub src_x: i16,
+    pub src_y: i16,
+}
+
+pub type XCBTranslateCoordinatesCookie = XCBCookie<XCBTranslateCoordinatesReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBTranslateCoordinatesReply {
+    pub response_type: u8,
+    pub same_screen: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub child: XCBWindow,
+    pub dst_x: i16,
+    pub dst_y: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBWarpPointerRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub src_window: XCBWindow,
+    pub dst_window: XCBWindow,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub src_width: u16,
+    pub src_height: u16,
+    pub dst_x: i16,
+    pub dst_y: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSetInputFocusRequest {
+    pub major_opcode: u8,
+    pub revert_to: u8,
+    pub length: u16,
+    pub focus: XCBWindow,
+    pub time: XCBTimestamp,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetInputFocusRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+pub type XCBGetInputFocusCookie = XCBCookie<XCBGetInputFocusReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetInputFocusReply {
+    pub response_type: u8,
+    pub revert_to: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub focus: XCBWindow,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBQueryKeymapRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+pub type XCBQueryKeymapCookie = XCBCookie<XCBQueryKeymapReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBQueryKeymapReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub keys: [u8; 32],
+}
+
+#[repr(C)]
+pub struct XCBOpenFontRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub fid: XCBFont,
+    pub name_len: u16,
+    pub pad1: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCloseFontRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub font: XCBFont,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBFontprop {
+    pub name: XCBAtom,
+    pub value: u32,
+}
+
+#[repr(C)]
+pub struct XCBFontpropIterator {
+    pub data: *mut XCBFontprop,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCharinfo {
+    pub left_side_bearing: i16,
+    pub right_side_bearing: i16,
+    pub character_width: i16,
+    pub ascent: i16,
+    pub descent: i16,
+    pub attributes: u16,
+}
+
+#[repr(C)]
+pub struct XCBCharinfoIterator {
+    pub data: *mut XCBCharinfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBQueryFontRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub font: XCBFontable,
+}
+
+pub type XCBQueryFontCookie = XCBCookie<XCBQueryFontReply>;
+
+#[repr(C)]
+pub struct XCBQueryFontReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub min_bounds: XCBCharinfo,
+    pub pad1: [u8; 4],
+    pub max_bounds: XCBCharinfo,
+    pub pad2: [u8; 4],
+    pub min_char_or_byte2: u16,
+    pub max_char_or_byte2: u16,
+    pub default_char: u16,
+    pub properties_len: u16,
+    pub draw_direction: u8,
+    pub min_byte1: u8,
+    pub max_byte1: u8,
+    pub all_chars_exist: u8,
+    pub font_ascent: i16,
+    pub font_descent: i16,
+    pub char_infos_len: u32,
+}
+
+#[repr(C)]
+pub struct XCBQueryTextExtentsRequest {
+    pub major_opcode: u8,
+    pub odd_length: u8,
+    pub length: u16,
+    pub font: XCBFontable,
+}
+
+pub type XCBQueryTextExtentsCookie = XCBCookie<XCBQueryTextExtentsReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBQueryTextExtentsReply {
+    pub response_type: u8,
+    pub draw_direction: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub font_ascent: i16,
+    pub font_descent: i16,
+    pub overall_ascent: i16,
+    pub overall_descent: i16,
+    pub overall_width: i32,
+    pub overall_left: i32,
+    pub overall_right: i32,
+}
+
+#[repr(C)]
+pub struct XCBStr {
+    pub name_len: u8,
+}
+
+#[repr(C)]
+pub struct XCBStrIterator {
+    pub data: *mut XCBStr,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBListFontsRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub max_names: u16,
+    pub pattern_len: u16,
+}
+
+pub type XCBListFontsCookie = XCBCookie<XCBListFontsReply>;
+
+#[repr(C)]
+pub struct XCBListFontsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub names_len: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+pub struct XCBListFontsWithInfoRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub max_names: u16,
+    pub pattern_len: u16,
+}
+
+pub type XCBListFontsWithInfoCookie = XCBCookie<XCBListFontsWithInfoReply>;
+
+#[repr(C)]
+pub struct XCBListFontsWithInfoReply {
+    pub response_type: u8,
+    pub name_len: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub min_bounds: XCBCharinfo,
+    pub pad0: [u8; 4],
+    pub max_bounds: XCBCharinfo,
+    pub pad1: [u8; 4],
+    pub min_char_or_byte2: u16,
+    pub max_char_or_byte2: u16,
+    pub default_char: u16,
+    pub properties_len: u16,
+    pub draw_direction: u8,
+    pub min_byte1: u8,
+    pub max_byte1: u8,
+    pub all_chars_exist: u8,
+    pub font_ascent: i16,
+    pub font_descent: i16,
+    pub replies_hint: u32,
+}
+
+#[repr(C)]
+pub struct XCBSetFontPathRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub font_qty: u16,
+    pub pad1: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetFontPathRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+pub type XCBGetFontPathCookie = XCBCookie<XCBGetFontPathReply>;
+
+#[repr(C)]
+pub struct XCBGetFontPathReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub path_len: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCreatePixmapRequest {
+    pub major_opcode: u8,
+    pub depth: u8,
+    pub length: u16,
+    pub pid: XCBPixmap,
+    pub drawable: XCBDrawable,
+    pub width: u16,
+    pub height: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBFreePixmapRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub pixmap: XCBPixmap,
+}
+
+#[repr(C)]
+pub struct XCBCreateGcRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cid: XCBGcontext,
+    pub drawable: XCBDrawable,
+    pub value_mask: u32,
+}
+
+#[repr(C)]
+pub struct XCBChangeGcRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub gc: XCBGcontext,
+    pub value_mask: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCopyGcRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub src_gc: XCBGcontext,
+    pub dst_gc: XCBGcontext,
+    pub value_mask: u32,
+}
+
+#[repr(C)]
+pub struct XCBSetDashesRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub gc: XCBGcontext,
+    pub dash_offset: u16,
+    pub dashes_len: u16,
+}
+
+#[repr(C)]
+pub struct XCBSetClipRectanglesRequest {
+    pub major_opcode: u8,
+    pub ordering: u8,
+    pub length: u16,
+    pub gc: XCBGcontext,
+    pub clip_x_origin: i16,
+    pub clip_y_origin: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBFreeGcRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub gc: XCBGcontext,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBClearAreaRequest {
+    pub major_opcode: u8,
+    pub exposures: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCopyAreaRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub src_drawable: XCBDrawable,
+    pub dst_drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub dst_x: i16,
+    pub dst_y: i16,
+    pub width: u16,
+    pub height: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCopyPlaneRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub src_drawable: XCBDrawable,
+    pub dst_drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub dst_x: i16,
+    pub dst_y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub bit_plane: u32,
+}
+
+#[repr(C)]
+pub struct XCBPolyPointRequest {
+    pub major_opcode: u8,
+    pub coordinate_mode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+}
+
+#[repr(C)]
+pub struct XCBPolyLineRequest {
+    pub major_opcode: u8,
+    pub coordinate_mode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSegment {
+    pub x1: i16,
+    pub y1: i16,
+    pub x2: i16,
+    pub y2: i16,
+}
+
+#[repr(C)]
+pub struct XCBSegmentIterator {
+    pub data: *mut XCBSegment,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBPolySegmentRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+}
+
+#[repr(C)]
+pub struct XCBPolyRectangleRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+}
+
+#[repr(C)]
+pub struct XCBPolyArcRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+}
+
+#[repr(C)]
+pub struct XCBFillPolyRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub shape: u8,
+    pub coordinate_mode: u8,
+    pub pad1: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBPolyFillRectangleRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+}
+
+#[repr(C)]
+pub struct XCBPolyFillArcRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+}
+
+#[repr(C)]
+pub struct XCBPutImageRequest {
+    pub major_opcode: u8,
+    pub format: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub width: u16,
+    pub height: u16,
+    pub dst_x: i16,
+    pub dst_y: i16,
+    pub left_pad: u8,
+    pub depth: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetImageRequest {
+    pub major_opcode: u8,
+    pub format: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub plane_mask: u32,
+}
+
+pub type XCBGetImageCookie = XCBCookie<XCBGetImageReply>;
+
+#[repr(C)]
+pub struct XCBGetImageReply {
+    pub response_type: u8,
+    pub depth: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub visual: XCBVisualid,
+    pub pad0: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBPolyText8Request {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub x: i16,
+    pub y: i16,
+}
+
+#[repr(C)]
+pub struct XCBPolyText16Request {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub x: i16,
+    pub y: i16,
+}
+
+#[repr(C)]
+pub struct XCBImageText8Request {
+    pub major_opcode: u8,
+    pub string_len: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub x: i16,
+    pub y: i16,
+}
+
+#[repr(C)]
+pub struct XCBImageText16Request {
+    pub major_opcode: u8,
+    pub string_len: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub x: i16,
+    pub y: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCreateColormapRequest {
+    pub major_opcode: u8,
+    pub alloc: u8,
+    pub length: u16,
+    pub mid: XCBColormap,
+    pub window: XCBWindow,
+    pub visual: XCBVisualid,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBFreeColormapRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cmap: XCBColormap,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCopyColormapAndFreeRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub mid: XCBColormap,
+    pub src_cmap: XCBColormap,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBInstallColormapRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cmap: XCBColormap,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBUninstallColormapRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cmap: XCBColormap,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBListInstalledColormapsRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBListInstalledColormapsCookie = XCBCookie<XCBListInstalledColormapsReply>;
+
+#[repr(C)]
+pub struct XCBListInstalledColormapsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub cmaps_len: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBAllocColorRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cmap: XCBColormap,
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub pad1: [u8; 2],
+}
+
+pub type XCBAllocColorCookie = XCBCookie<XCBAllocColorReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBAllocColorReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub pad1: [u8; 2],
+    pub pixel: u32,
+}
+
+#[repr(C)]
+pub struct XCBAllocNamedColorRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cmap: XCBColormap,
+    pub name_len: u16,
+    pub pad1: [u8; 2],
+}
+
+pub type XCBAllocNamedColorCookie = XCBCookie<XCBAllocNamedColorReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBAllocNamedColorReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pixel: u32,
+    pub exact_red: u16,
+    pub exact_green: u16,
+    pub exact_blue: u16,
+    pub visual_red: u16,
+    pub visual_green: u16,
+    pub visual_blue: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBAllocColorCellsRequest {
+    pub major_opcode: u8,
+    pub contiguous: u8,
+    pub length: u16,
+    pub cmap: XCBColormap,
+    pub colors: u16,
+    pub planes: u16,
+}
+
+pub type XCBAllocColorCellsCookie = XCBCookie<XCBAllocColorCellsReply>;
+
+#[repr(C)]
+pub struct XCBAllocColorCellsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pixels_len: u16,
+    pub masks_len: u16,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBAllocColorPlanesRequest {
+    pub major_opcode: u8,
+    pub contiguous: u8,
+    pub length: u16,
+    pub cmap: XCBColormap,
+    pub colors: u16,
+    pub reds: u16,
+    pub greens: u16,
+    pub blues: u16,
+}
+
+pub type XCBAllocColorPlanesCookie = XCBCookie<XCBAllocColorPlanesReply>;
+
+#[repr(C)]
+pub struct XCBAllocColorPlanesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pixels_len: u16,
+    pub pad1: [u8; 2],
+    pub red_mask: u32,
+    pub green_mask: u32,
+    pub blue_mask: u32,
+    pub pad2: [u8; 8],
+}
+
+#[repr(C)]
+pub struct XCBFreeColorsRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cmap: XCBColormap,
+    pub plane_mask: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBColoritem {
+    pub pixel: u32,
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub flags: u8,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+pub struct XCBColoritemIterator {
+    pub data: *mut XCBColoritem,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBStoreColorsRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cmap: XCBColormap,
+}
+
+#[repr(C)]
+pub struct XCBStoreNamedColorRequest {
+    pub major_opcode: u8,
+    pub flags: u8,
+    pub length: u16,
+    pub cmap: XCBColormap,
+    pub pixel: u32,
+    pub name_len: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRgb {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+pub struct XCBRgbIterator {
+    pub data: *mut XCBRgb,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBQueryColorsRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cmap: XCBColormap,
+}
+
+pub type XCBQueryColorsCookie = XCBCookie<XCBQueryColorsReply>;
+
+#[repr(C)]
+pub struct XCBQueryColorsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub colors_len: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+pub struct XCBLookupColorRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cmap: XCBColormap,
+    pub name_len: u16,
+    pub pad1: [u8; 2],
+}
+
+pub type XCBLookupColorCookie = XCBCookie<XCBLookupColorReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBLookupColorReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub exact_red: u16,
+    pub exact_green: u16,
+    pub exact_blue: u16,
+    pub visual_red: u16,
+    pub visual_green: u16,
+    pub visual_blue: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCreateCursorRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cid: XCBCursor,
+    pub source: XCBPixmap,
+    pub mask: XCBPixmap,
+    pub fore_red: u16,
+    pub fore_green: u16,
+    pub fore_blue: u16,
+    pub back_red: u16,
+    pub back_green: u16,
+    pub back_blue: u16,
+    pub x: u16,
+    pub y: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBCreateGlyphCursorRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cid: XCBCursor,
+    pub source_font: XCBFont,
+    pub mask_font: XCBFont,
+    pub source_char: u16,
+    pub mask_char: u16,
+    pub fore_red: u16,
+    pub fore_green: u16,
+    pub fore_blue: u16,
+    pub back_red: u16,
+    pub back_green: u16,
+    pub back_blue: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBFreeCursorRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cursor: XCBCursor,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBRecolorCursorRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub cursor: XCBCursor,
+    pub fore_red: u16,
+    pub fore_green: u16,
+    pub fore_blue: u16,
+    pub back_red: u16,
+    pub back_green: u16,
+    pub back_blue: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBQueryBestSizeRequest {
+    pub major_opcode: u8,
+    pub class: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub width: u16,
+    pub height: u16,
+}
+
+pub type XCBQueryBestSizeCookie = XCBCookie<XCBQueryBestSizeReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBQueryBestSizeReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub width: u16,
+    pub height: u16,
+}
+
+#[repr(C)]
+pub struct XCBQueryExtensionRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub name_len: u16,
+    pub pad1: [u8; 2],
+}
+
+pub type XCBQueryExtensionCookie = XCBCookie<XCBQueryExtensionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBQueryExtensionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub present: u8,
+    pub major_opcode: u8,
+    pub first_event: u8,
+    pub first_error: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBListExtensionsRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+pub type XCBListExtensionsCookie = XCBCookie<XCBListExtensionsReply>;
+
+#[repr(C)]
+pub struct XCBListExtensionsReply {
+    pub response_type: u8,
+    pub names_len: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad0: [u8; 24],
+}
+
+#[repr(C)]
+pub struct XCBChangeKeyboardMappingRequest {
+    pub major_opcode: u8,
+    pub keycode_count: u8,
+    pub length: u16,
+    pub first_keycode: XCBKeycode,
+    pub keysyms_per_keycode: u8,
+    pub pad0: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetKeyboardMappingRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub first_keycode: XCBKeycode,
+    pub count: u8,
+}
+
+pub type XCBGetKeyboardMappingCookie = XCBCookie<XCBGetKeyboardMappingReply>;
+
+#[repr(C)]
+pub struct XCBGetKeyboardMappingReply {
+    pub response_type: u8,
+    pub keysyms_per_keycode: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad0: [u8; 24],
+}
+
+#[repr(C)]
+pub struct XCBChangeKeyboardControlRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub value_mask: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetKeyboardControlRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+pub type XCBGetKeyboardControlCookie = XCBCookie<XCBGetKeyboardControlReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetKeyboardControlReply {
+    pub response_type: u8,
+    pub global_auto_repeat: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub led_mask: u32,
+    pub key_click_percent: u8,
+    pub bell_percent: u8,
+    pub bell_pitch: u16,
+    pub bell_duration: u16,
+    pub pad0: [u8; 2],
+    pub auto_repeats: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBBellRequest {
+    pub major_opcode: u8,
+    pub percent: i8,
+    pub length: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBChangePointerControlRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub acceleration_numerator: i16,
+    pub acceleration_denominator: i16,
+    pub threshold: i16,
+    pub do_acceleration: u8,
+    pub do_threshold: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetPointerControlRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+pub type XCBGetPointerControlCookie = XCBCookie<XCBGetPointerControlReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetPointerControlReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub acceleration_numerator: u16,
+    pub acceleration_denominator: u16,
+    pub threshold: u16,
+    pub pad1: [u8; 18],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSetScreenSaverRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub timeout: i16,
+    pub interval: i16,
+    pub prefer_blanking: u8,
+    pub allow_exposures: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetScreenSaverRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+pub type XCBGetScreenSaverCookie = XCBCookie<XCBGetScreenSaverReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetScreenSaverReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timeout: u16,
+    pub interval: u16,
+    pub prefer_blanking: u8,
+    pub allow_exposures: u8,
+    pub pad1: [u8; 18],
+}
+
+#[repr(C)]
+pub struct XCBChangeHostsRequest {
+    pub major_opcode: u8,
+    pub mode: u8,
+    pub length: u16,
+    pub family: u8,
+    pub pad0: u8,
+    pub address_len: u16,
+}
+
+#[repr(C)]
+pub struct XCBHost {
+    pub family: u8,
+    pub pad0: u8,
+    pub address_len: u16,
+}
+
+#[repr(C)]
+pub struct XCBHostIterator {
+    pub data: *mut XCBHost,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBListHostsRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+pub type XCBListHostsCookie = XCBCookie<XCBListHostsReply>;
+
+#[repr(C)]
+pub struct XCBListHostsReply {
+    pub response_type: u8,
+    pub mode: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub hosts_len: u16,
+    pub pad0: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSetAccessControlRequest {
+    pub major_opcode: u8,
+    pub mode: u8,
+    pub length: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSetCloseDownModeRequest {
+    pub major_opcode: u8,
+    pub mode: u8,
+    pub length: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBKillClientRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub resource: u32,
+}
+
+#[repr(C)]
+pub struct XCBRotatePropertiesRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub atoms_len: u16,
+    pub delta: i16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBForceScreenSaverRequest {
+    pub major_opcode: u8,
+    pub mode: u8,
+    pub length: u16,
+}
+
+#[repr(C)]
+pub struct XCBSetPointerMappingRequest {
+    pub major_opcode: u8,
+    pub map_len: u8,
+    pub length: u16,
+}
+
+pub type XCBSetPointerMappingCookie = XCBCookie<XCBSetPointerMappingReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSetPointerMappingReply {
+    pub response_type: u8,
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetPointerMappingRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+pub type XCBGetPointerMappingCookie = XCBCookie<XCBGetPointerMappingReply>;
+
+#[repr(C)]
+pub struct XCBGetPointerMappingReply {
+    pub response_type: u8,
+    pub map_len: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad0: [u8; 24],
+}
+
+#[repr(C)]
+pub struct XCBSetModifierMappingRequest {
+    pub major_opcode: u8,
+    pub keycodes_per_modifier: u8,
+    pub length: u16,
+}
+
+pub type XCBSetModifierMappingCookie = XCBCookie<XCBSetModifierMappingReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSetModifierMappingReply {
+    pub response_type: u8,
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBGetModifierMappingRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+pub type XCBGetModifierMappingCookie = XCBCookie<XCBGetModifierMappingReply>;
+
+#[repr(C)]
+pub struct XCBGetModifierMappingReply {
+    pub response_type: u8,
+    pub keycodes_per_modifier: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad0: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBNoOperationRequest {
+    pub major_opcode: u8,
+    pub pad0: u8,
+    pub length: u16,
+}
+
+#[link(name = "xcb")]
+extern "system" {
+    pub fn xcb_char2b_next(i: *mut XCBChar2bIterator);
+    pub fn xcb_char2b_end(i: XCBChar2bIterator) -> XCBGenericIterator;
+    pub fn xcb_window_next(i: *mut XCBWindowIterator);
+    pub fn xcb_window_end(i: XCBWindowIterator) -> XCBGenericIterator;
+    pub fn xcb_pixmap_next(i: *mut XCBPixmapIterator);
+    pub fn xcb_pixmap_end(i: XCBPixmapIterator) -> XCBGenericIterator;
+    pub fn xcb_cursor_next(i: *mut XCBCursorIterator);
+    pub fn xcb_cursor_end(i: XCBCursorIterator) -> XCBGenericIterator;
+    pub fn xcb_font_next(i: *mut XCBFontIterator);
+    pub fn xcb_font_end(i: XCBFontIterator) -> XCBGenericIterator;
+    pub fn xcb_gcontext_next(i: *mut XCBGcontextIterator);
+    pub fn xcb_gcontext_end(i: XCBGcontextIterator) -> XCBGenericIterator;
+    pub fn xcb_colormap_next(i: *mut XCBColormapIterator);
+    pub fn xcb_colormap_end(i: XCBColormapIterator) -> XCBGenericIterator;
+    pub fn xcb_atom_next(i: *mut XCBAtomIterator);
+    pub fn xcb_atom_end(i: XCBAtomIterator) -> XCBGenericIterator;
+    pub fn xcb_drawable_next(i: *mut XCBDrawableIterator);
+    pub fn xcb_drawable_end(i: XCBDrawableIterator) -> XCBGenericIterator;
+    pub fn xcb_fontable_next(i: *mut XCBFontableIterator);
+    pub fn xcb_fontable_end(i: XCBFontableIterator) -> XCBGenericIterator;
+    pub fn xcb_bool32_next(i: *mut XCBBool32Iterator);
+    pub fn xcb_bool32_end(i: XCBBool32Iterator) -> XCBGenericIterator;
+    pub fn xcb_visualid_next(i: *mut XCBVisualidIterator);
+    pub fn xcb_visualid_end(i: XCBVisualidIterator) -> XCBGenericIterator;
+    pub fn xcb_timestamp_next(i: *mut XCBTimestampIterator);
+    pub fn xcb_timestamp_end(i: XCBTimestampIterator) -> XCBGenericIterator;
+    pub fn xcb_keysym_next(i: *mut XCBKeysymIterator);
+    pub fn xcb_keysym_end(i: XCBKeysymIterator) -> XCBGenericIterator;
+    pub fn xcb_keycode_next(i: *mut XCBKeycodeIterator);
+    pub fn xcb_keycode_end(i: XCBKeycodeIterator) -> XCBGenericIterator;
+    pub fn xcb_keycode32_next(i: *mut XCBKeycode32Iterator);
+    pub fn xcb_keycode32_end(i: XCBKeycode32Iterator) -> XCBGenericIterator;
+    pub fn xcb_button_next(i: *mut XCBButtonIterator);
+    pub fn xcb_button_end(i: XCBButtonIterator) -> XCBGenericIterator;
+    pub fn xcb_point_next(i: *mut XCBPointIterator);
+    pub fn xcb_point_end(i: XCBPointIterator) -> XCBGenericIterator;
+    pub fn xcb_rectangle_next(i: *mut XCBRectangleIterator);
+    pub fn xcb_rectangle_end(i: XCBRectangleIterator) -> XCBGenericIterator;
+    pub fn xcb_arc_next(i: *mut XCBArcIterator);
+    pub fn xcb_arc_end(i: XCBArcIterator) -> XCBGenericIterator;
+    pub fn xcb_format_next(i: *mut XCBFormatIterator);
+    pub fn xcb_format_end(i: XCBFormatIterator) -> XCBGenericIterator;
+    pub fn xcb_visualtype_next(i: *mut XCBVisualtypeIterator);
+    pub fn xcb_visualtype_end(i: XCBVisualtypeIterator) -> XCBGenericIterator;
+    pub fn xcb_depth_next(i: *mut XCBDepthIterator);
+    pub fn xcb_depth_end(i: XCBDepthIterator) -> XCBGenericIterator;
+    pub fn xcb_depth_visuals(R: *const XCBDepth) -> *mut XCBVisualtype;
+    pub fn xcb_depth_visuals_length(R: *const XCBDepth) -> c_int;
+    pub fn xcb_depth_visuals_iterator(R: *const XCBDepth) -> XCBVisualtypeIterator;
+    pub fn xcb_screen_next(i: *mut XCBScreenIterator);
+    pub fn xcb_screen_end(i: XCBScreenIterator) -> XCBGenericIterator;
+    pub fn xcb_screen_allowed_depths_length(R: *const XCBScreen) -> c_int;
+    pub fn xcb_screen_allowed_depths_iterator(R: *const XCBScreen) -> XCBDepthIterator;
+    pub fn xcb_setup_request_next(i: *mut XCBSetupRequestIterator);
+    pub fn xcb_setup_request_end(i: XCBSetupRequestIterator) -> XCBGenericIterator;
+    pub fn xcb_setup_request_authorization_protocol_name(R: *const XCBSetupRequest) -> *mut c_char;
+    pub fn xcb_setup_request_authorization_protocol_name_length(R: *const XCBSetupRequest)
+        -> c_int;
+    pub fn xcb_setup_request_authorization_protocol_name_end(
+        R: *const XCBSetupRequest,
+    ) -> XCBGenericIterator;
+    pub fn xcb_setup_request_authorization_protocol_data(R: *const XCBSetupRequest) -> *mut c_char;
+    pub fn xcb_setup_request_authorization_protocol_data_length(R: *const XCBSetupRequest)
+        -> c_int;
+    pub fn xcb_setup_request_authorization_protocol_data_end(
+        R: *const XCBSetupRequest,
+    ) -> XCBGenericIterator;
+    pub fn xcb_setup_failed_next(i: *mut XCBSetupFailedIterator);
+    pub fn xcb_setup_failed_end(i: XCBSetupFailedIterator) -> XCBGenericIterator;
+    pub fn xcb_setup_failed_reason(R: *const XCBSetupFailed) -> *mut c_char;
+    pub fn xcb_setup_failed_reason_length(R: *const XCBSetupFailed) -> c_int;
+    pub fn xcb_setup_failed_reason_end(R: *const XCBSetupFailed) -> XCBGenericIterator;
+    pub fn xcb_setup_authenticate_next(i: *mut XCBSetupAuthenticateIterator);
+    pub fn xcb_setup_authenticate_end(i: XCBSetupAuthenticateIterator) -> XCBGenericIterator;
+    pub fn xcb_setup_authenticate_reason(R: *const XCBSetupAuthenticate) -> *mut c_char;
+    pub fn xcb_setup_authenticate_reason_length(R: *const XCBSetupAuthenticate) -> c_int;
+    pub fn xcb_setup_authenticate_reason_end(R: *const XCBSetupAuthenticate) -> XCBGenericIterator;
+    pub fn xcb_setup_next(i: *mut XCBSetupIterator);
+    pub fn xcb_setup_end(i: XCBSetupIterator) -> XCBGenericIterator;
+    pub fn xcb_setup_vendor(R: *const XCBSetup) -> *mut c_char;
+    pub fn xcb_setup_vendor_length(R: *const XCBSetup) -> c_int;
+    pub fn xcb_setup_vendor_end(R: *const XCBSetup) -> XCBGenericIterator;
+    pub fn xcb_setup_pixmap_formats(R: *const XCBSetup) -> *mut XCBFormat;
+    pub fn xcb_setup_pixmap_formats_length(R: *const XCBSetup) -> c_int;
+    pub fn xcb_setup_pixmap_formats_iterator(R: *const XCBSetup) -> XCBFormatIterator;
+    pub fn xcb_setup_roots_length(R: *const XCBSetup) -> c_int;
+    pub fn xcb_setup_roots_iterator(R: *const XCBSetup) -> XCBScreenIterator;
+    pub fn xcb_client_message_data_next(i: *mut XCBClientMessageDataIterator);
+    pub fn xcb_client_message_data_end(i: XCBClientMessageDataIterator) -> XCBGenericIterator;
+    pub fn xcb_create_window_checked(
+        connection: *mut XCBConnection,
+        depth: u8,
+        wid: XCBWindow,
+        parent: XCBWindow,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        border_width: u16,
+        class: u16,
+        visual: XCBVisualid,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_create_window(
+        connection: *mut XCBConnection,
+        depth: u8,
+        wid: XCBWindow,
+        parent: XCBWindow,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        border_width: u16,
+        class: u16,
+        visual: XCBVisualid,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_window_attributes_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_window_attributes(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_get_window_attributes_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetWindowAttributesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetWindowAttributesReply;
+    pub fn xcb_get_window_attributes(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBGetWindowAttributesCookie;
+    pub fn xcb_get_window_attributes_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBGetWindowAttributesCookie;
+    pub fn xcb_destroy_window_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_destroy_window(connection: *mut XCBConnection, window: XCBWindow) -> XCBVoidCookie;
+    pub fn xcb_destroy_subwindows_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_destroy_subwindows(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_save_set_checked(
+        connection: *mut XCBConnection,
+        mode: u8,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_save_set(
+        connection: *mut XCBConnection,
+        mode: u8,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_reparent_window_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        parent: XCBWindow,
+        x: i16,
+        y: i16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_reparent_window(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        parent: XCBWindow,
+        x: i16,
+        y: i16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_map_window_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_map_window(connection: *mut XCBConnection, window: XCBWindow) -> XCBVoidCookie;
+    pub fn xcb_map_subwindows_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_map_subwindows(connection: *mut XCBConnection, window: XCBWindow) -> XCBVoidCookie;
+    pub fn xcb_unmap_window_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_unmap_window(connection: *mut XCBConnection, window: XCBWindow) -> XCBVoidCookie;
+    pub fn xcb_unmap_subwindows_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_unmap_subwindows(connection: *mut XCBConnection, window: XCBWindow)
+        -> XCBVoidCookie;
+    pub fn xcb_configure_window_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        value_mask: u16,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_configure_window(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        value_mask: u16,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_circulate_window_checked(
+        connection: *mut XCBConnection,
+        direction: u8,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_circulate_window(
+        connection: *mut XCBConnection,
+        direction: u8,
+        window: XCBWindow,
+    ) -> XCBVoidCookie;
+    pub fn xcb_get_geometry_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetGeometryCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetGeometryReply;
+    pub fn xcb_get_geometry(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBGetGeometryCookie;
+    pub fn xcb_get_geometry_unchecked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+    ) -> XCBGetGeometryCookie;
+    pub fn xcb_query_tree_children(R: *const XCBQueryTreeReply) -> *mut XCBWindow;
+    pub fn xcb_query_tree_children_length(R: *const XCBQueryTreeReply) -> c_int;
+    pub fn xcb_query_tree_children_end(R: *const XCBQueryTreeReply) -> XCBGenericIterator;
+    pub fn xcb_query_tree_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBQueryTreeCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBQueryTreeReply;
+    pub fn xcb_query_tree(connection: *mut XCBConnection, window: XCBWindow) -> XCBQueryTreeCookie;
+    pub fn xcb_query_tree_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBQueryTreeCookie;
+    pub fn xcb_intern_atom_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBInternAtomCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBInternAtomReply;
+    pub fn xcb_intern_atom(
+        connection: *mut XCBConnection,
+        only_if_exists: u8,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBInternAtomCookie;
+    pub fn xcb_intern_atom_unchecked(
+        connection: *mut XCBConnection,
+        only_if_exists: u8,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBInternAtomCookie;
+    pub fn xcb_get_atom_name_name(R: *const XCBGetAtomNameReply) -> *mut c_char;
+    pub fn xcb_get_atom_name_name_length(R: *const XCBGetAtomNameReply) -> c_int;
+    pub fn xcb_get_atom_name_name_end(R: *const XCBGetAtomNameReply) -> XCBGenericIterator;
+    pub fn xcb_get_atom_name_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetAtomNameCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetAtomNameReply;
+    pub fn xcb_get_atom_name(connection: *mut XCBConnection, atom: XCBAtom)
+        -> XCBGetAtomNameCookie;
+    pub fn xcb_get_atom_name_unchecked(
+        connection: *mut XCBConnection,
+        atom: XCBAtom,
+    ) -> XCBGetAtomNameCookie;
+    pub fn xcb_change_property_checked(
+        connection: *mut XCBConnection,
+        mode: u8,
+        window: XCBWindow,
+        property: XCBAtom,
+        type_: XCBAtom,
+        format: u8,
+        data_len: u32,
+        data: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_property(
+        connection: *mut XCBConnection,
+        mode: u8,
+        window: XCBWindow,
+        property: XCBAtom,
+        type_: XCBAtom,
+        format: u8,
+        data_len: u32,
+        data: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_delete_property_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        property: XCBAtom,
+    ) -> XCBVoidCookie;
+    pub fn xcb_delete_property(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        property: XCBAtom,
+    ) -> XCBVoidCookie;
+    pub fn xcb_get_property_value(R: *const XCBGetPropertyReply) -> *mut c_void;
+    pub fn xcb_get_property_value_length(R: *const XCBGetPropertyReply) -> c_int;
+    pub fn xcb_get_property_value_end(R: *const XCBGetPropertyReply) -> XCBGenericIterator;
+    pub fn xcb_get_property_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetPropertyCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetPropertyReply;
+    pub fn xcb_get_property(
+        connection: *mut XCBConnection,
+        delete: u8,
+        window: XCBWindow,
+        property: XCBAtom,
+        type_: XCBAtom,
+        long_offset: u32,
+        long_length: u32,
+    ) -> XCBGetPropertyCookie;
+    pub fn xcb_get_property_unchecked(
+        connection: *mut XCBConnection,
+        delete: u8,
+        window: XCBWindow,
+        property: XCBAtom,
+        type_: XCBAtom,
+        long_offset: u32,
+        long_length: u32,
+    ) -> XCBGetPropertyCookie;
+    pub fn xcb_list_properties_atoms(R: *const XCBListPropertiesReply) -> *mut XCBAtom;
+    pub fn xcb_list_properties_atoms_length(R: *const XCBListPropertiesReply) -> c_int;
+    pub fn xcb_list_properties_atoms_end(R: *const XCBListPropertiesReply) -> XCBGenericIterator;
+    pub fn xcb_list_properties_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBListPropertiesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBListPropertiesReply;
+    pub fn xcb_list_properties(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBListPropertiesCookie;
+    pub fn xcb_list_properties_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBListPropertiesCookie;
+    pub fn xcb_set_selection_owner_checked(
+        connection: *mut XCBConnection,
+        owner: XCBWindow,
+        selection: XCBAtom,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_set_selection_owner(
+        connection: *mut XCBConnection,
+        owner: XCBWindow,
+        selection: XCBAtom,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_get_selection_owner_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetSelectionOwnerCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetSelectionOwnerReply;
+    pub fn xcb_get_selection_owner(
+        connection: *mut XCBConnection,
+        selection: XCBAtom,
+    ) -> XCBGetSelectionOwnerCookie;
+    pub fn xcb_get_selection_owner_unchecked(
+        connection: *mut XCBConnection,
+        selection: XCBAtom,
+    ) -> XCBGetSelectionOwnerCookie;
+    pub fn xcb_convert_selection_checked(
+        connection: *mut XCBConnection,
+        requestor: XCBWindow,
+        selection: XCBAtom,
+        target: XCBAtom,
+        property: XCBAtom,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_convert_selection(
+        connection: *mut XCBConnection,
+        requestor: XCBWindow,
+        selection: XCBAtom,
+        target: XCBAtom,
+        property: XCBAtom,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_send_event_checked(
+        connection: *mut XCBConnection,
+        propagate: u8,
+        destination: XCBWindow,
+        event_mask: u32,
+        event: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_send_event(
+        connection: *mut XCBConnection,
+        propagate: u8,
+        destination: XCBWindow,
+        event_mask: u32,
+        event: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_grab_pointer_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGrabPointerCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGrabPointerReply;
+    pub fn xcb_grab_pointer(
+        connection: *mut XCBConnection,
+        owner_events: u8,
+        grab_window: XCBWindow,
+        event_mask: u16,
+        pointer_mode: u8,
+        keyboard_mode: u8,
+        confine_to: XCBWindow,
+        cursor: XCBCursor,
+        time: XCBTimestamp,
+    ) -> XCBGrabPointerCookie;
+    pub fn xcb_grab_pointer_unchecked(
+        connection: *mut XCBConnection,
+        owner_events: u8,
+        grab_window: XCBWindow,
+        event_mask: u16,
+        pointer_mode: u8,
+        keyboard_mode: u8,
+        confine_to: XCBWindow,
+        cursor: XCBCursor,
+        time: XCBTimestamp,
+    ) -> XCBGrabPointerCookie;
+    pub fn xcb_ungrab_pointer_checked(
+        connection: *mut XCBConnection,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_ungrab_pointer(connection: *mut XCBConnection, time: XCBTimestamp) -> XCBVoidCookie;
+    pub fn xcb_grab_button_checked(
+        connection: *mut XCBConnection,
+        owner_events: u8,
+        grab_window: XCBWindow,
+        event_mask: u16,
+        pointer_mode: u8,
+        keyboard_mode: u8,
+        confine_to: XCBWindow,
+        cursor: XCBCursor,
+        button: u8,
+        modifiers: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_grab_button(
+        connection: *mut XCBConnection,
+        owner_events: u8,
+        grab_window: XCBWindow,
+        event_mask: u16,
+        pointer_mode: u8,
+        keyboard_mode: u8,
+        confine_to: XCBWindow,
+        cursor: XCBCursor,
+        button: u8,
+        modifiers: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_ungrab_button_checked(
+        connection: *mut XCBConnection,
+        button: u8,
+        grab_window: XCBWindow,
+        modifiers: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_ungrab_button(
+        connection: *mut XCBConnection,
+        button: u8,
+        grab_window: XCBWindow,
+        modifiers: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_active_pointer_grab_checked(
+        connection: *mut XCBConnection,
+        cursor: XCBCursor,
+        time: XCBTimestamp,
+        event_mask: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_active_pointer_grab(
+        connection: *mut XCBConnection,
+        cursor: XCBCursor,
+        time: XCBTimestamp,
+        event_mask: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_grab_keyboard_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGrabKeyboardCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGrabKeyboardReply;
+    pub fn xcb_grab_keyboard(
+        connection: *mut XCBConnection,
+        owner_events: u8,
+        grab_window: XCBWindow,
+        time: XCBTimestamp,
+        pointer_mode: u8,
+        keyboard_mode: u8,
+    ) -> XCBGrabKeyboardCookie;
+    pub fn xcb_grab_keyboard_unchecked(
+        connection: *mut XCBConnection,
+        owner_events: u8,
+        grab_window: XCBWindow,
+        time: XCBTimestamp,
+        pointer_mode: u8,
+        keyboard_mode: u8,
+    ) -> XCBGrabKeyboardCookie;
+    pub fn xcb_ungrab_keyboard_checked(
+        connection: *mut XCBConnection,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_ungrab_keyboard(connection: *mut XCBConnection, time: XCBTimestamp)
+        -> XCBVoidCookie;
+    pub fn xcb_grab_key_checked(
+        connection: *mut XCBConnection,
+        owner_events: u8,
+        grab_window: XCBWindow,
+        modifiers: u16,
+        key: XCBKeycode,
+        pointer_mode: u8,
+        keyboard_mode: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_grab_key(
+        connection: *mut XCBConnection,
+        owner_events: u8,
+        grab_window: XCBWindow,
+        modifiers: u16,
+        key: XCBKeycode,
+        pointer_mode: u8,
+        keyboard_mode: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_ungrab_key_checked(
+        connection: *mut XCBConnection,
+        key: XCBKeycode,
+        grab_window: XCBWindow,
+        modifiers: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_ungrab_key(
+        connection: *mut XCBConnection,
+        key: XCBKeycode,
+        grab_window: XCBWindow,
+        modifiers: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_allow_events_checked(
+        connection: *mut XCBConnection,
+        mode: u8,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_allow_events(
+        connection: *mut XCBConnection,
+        mode: u8,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_grab_server_checked(connection: *mut XCBConnection) -> XCBVoidCookie;
+    pub fn xcb_grab_server(connection: *mut XCBConnection) -> XCBVoidCookie;
+    pub fn xcb_ungrab_server_checked(connection: *mut XCBConnection) -> XCBVoidCookie;
+    pub fn xcb_ungrab_server(connection: *mut XCBConnection) -> XCBVoidCookie;
+    pub fn xcb_query_pointer_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBQueryPointerCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBQueryPointerReply;
+    pub fn xcb_query_pointer(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBQueryPointerCookie;
+    pub fn xcb_query_pointer_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBQueryPointerCookie;
+    pub fn xcb_timecoord_next(i: *mut XCBTimecoordIterator);
+    pub fn xcb_timecoord_end(i: XCBTimecoordIterator) -> XCBGenericIterator;
+    pub fn xcb_get_motion_events_events(R: *const XCBGetMotionEventsReply) -> *mut XCBTimecoord;
+    pub fn xcb_get_motion_events_events_length(R: *const XCBGetMotionEventsReply) -> c_int;
+    pub fn xcb_get_motion_events_events_iterator(
+        R: *const XCBGetMotionEventsReply,
+    ) -> XCBTimecoordIterator;
+    pub fn xcb_get_motion_events_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetMotionEventsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetMotionEventsReply;
+    pub fn xcb_get_motion_events(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        start: XCBTimestamp,
+        stop: XCBTimestamp,
+    ) -> XCBGetMotionEventsCookie;
+    pub fn xcb_get_motion_events_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        start: XCBTimestamp,
+        stop: XCBTimestamp,
+    ) -> XCBGetMotionEventsCookie;
+    pub fn xcb_translate_coordinates_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBTranslateCoordinatesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBTranslateCoordinatesReply;
+    pub fn xcb_translate_coordinates(
+        connection: *mut XCBConnection,
+        src_window: XCBWindow,
+        dst_window: XCBWindow,
+        src_x: i16,
+        src_y: i16,
+    ) -> XCBTranslateCoordinatesCookie;
+    pub fn xcb_translate_coordinates_unchecked(
+        connection: *mut XCBConnection,
+        src_window: XCBWindow,
+        dst_window: XCBWindow,
+        src_x: i16,
+        src_y: i16,
+    ) -> XCBTranslateCoordinatesCookie;
+    pub fn xcb_warp_pointer_checked(
+        connection: *mut XCBConnection,
+        src_window: XCBWindow,
+        dst_window: XCBWindow,
+        src_x: i16,
+        src_y: i16,
+        src_width: u16,
+        src_height: u16,
+        dst_x: i16,
+        dst_y: i16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_warp_pointer(
+        connection: *mut XCBConnection,
+        src_window: XCBWindow,
+        dst_window: XCBWindow,
+        src_x: i16,
+        src_y: i16,
+        src_width: u16,
+        src_height: u16,
+        dst_x: i16,
+        dst_y: i16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_set_input_focus_checked(
+        connection: *mut XCBConnection,
+        revert_to: u8,
+        focus: XCBWindow,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_set_input_focus(
+        connection: *mut XCBConnection,
+        revert_to: u8,
+        focus: XCBWindow,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_get_input_focus_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetInputFocusCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetInputFocusReply;
+    pub fn xcb_get_input_focus(connection: *mut XCBConnection) -> XCBGetInputFocusCookie;
+    pub fn xcb_get_input_focus_unchecked(connection: *mut XCBConnection) -> XCBGetInputFocusCookie;
+    pub fn xcb_query_keymap_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBQueryKeymapCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBQueryKeymapReply;
+    pub fn xcb_query_keymap(connection: *mut XCBConnection) -> XCBQueryKeymapCookie;
+    pub fn xcb_query_keymap_unchecked(connection: *mut XCBConnection) -> XCBQueryKeymapCookie;
+    pub fn xcb_open_font_checked(
+        connection: *mut XCBConnection,
+        fid: XCBFont,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_open_font(
+        connection: *mut XCBConnection,
+        fid: XCBFont,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_close_font_checked(connection: *mut XCBConnection, font: XCBFont) -> XCBVoidCookie;
+    pub fn xcb_close_font(connection: *mut XCBConnection, font: XCBFont) -> XCBVoidCookie;
+    pub fn xcb_fontprop_next(i: *mut XCBFontpropIterator);
+    pub fn xcb_fontprop_end(i: XCBFontpropIterator) -> XCBGenericIterator;
+    pub fn xcb_charinfo_next(i: *mut XCBCharinfoIterator);
+    pub fn xcb_charinfo_end(i: XCBCharinfoIterator) -> XCBGenericIterator;
+    pub fn xcb_query_font_properties(R: *const XCBQueryFontReply) -> *mut XCBFontprop;
+    pub fn xcb_query_font_properties_length(R: *const XCBQueryFontReply) -> c_int;
+    pub fn xcb_query_font_properties_iterator(R: *const XCBQueryFontReply) -> XCBFontpropIterator;
+    pub fn xcb_query_font_char_infos(R: *const XCBQueryFontReply) -> *mut XCBCharinfo;
+    pub fn xcb_query_font_char_infos_length(R: *const XCBQueryFontReply) -> c_int;
+    pub fn xcb_query_font_char_infos_iterator(R: *const XCBQueryFontReply) -> XCBCharinfoIterator;
+    pub fn xcb_query_font_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBQueryFontCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBQueryFontReply;
+    pub fn xcb_query_font(connection: *mut XCBConnection, font: XCBFontable) -> XCBQueryFontCookie;
+    pub fn xcb_query_font_unchecked(
+        connection: *mut XCBConnection,
+        font: XCBFontable,
+    ) -> XCBQueryFontCookie;
+    pub fn xcb_query_text_extents_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBQueryTextExtentsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBQueryTextExtentsReply;
+    pub fn xcb_query_text_extents(
+        connection: *mut XCBConnection,
+        font: XCBFontable,
+        string_len: u32,
+        string: *const XCBChar2b,
+    ) -> XCBQueryTextExtentsCookie;
+    pub fn xcb_query_text_extents_unchecked(
+        connection: *mut XCBConnection,
+        font: XCBFontable,
+        string_len: u32,
+        string: *const XCBChar2b,
+    ) -> XCBQueryTextExtentsCookie;
+    pub fn xcb_str_next(i: *mut XCBStrIterator);
+    pub fn xcb_str_end(i: XCBStrIterator) -> XCBGenericIterator;
+    pub fn xcb_str_name(R: *const XCBStr) -> *mut c_char;
+    pub fn xcb_str_name_length(R: *const XCBStr) -> c_int;
+    pub fn xcb_str_name_end(R: *const XCBStr) -> XCBGenericIterator;
+    pub fn xcb_list_fonts_names_length(R: *const XCBListFontsReply) -> c_int;
+    pub fn xcb_list_fonts_names_iterator(R: *const XCBListFontsReply) -> XCBStrIterator;
+    pub fn xcb_list_fonts_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBListFontsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBListFontsReply;
+    pub fn xcb_list_fonts(
+        connection: *mut XCBConnection,
+        max_names: u16,
+        pattern_len: u16,
+        pattern: *const c_char,
+    ) -> XCBListFontsCookie;
+    pub fn xcb_list_fonts_unchecked(
+        connection: *mut XCBConnection,
+        max_names: u16,
+        pattern_len: u16,
+        pattern: *const c_char,
+    ) -> XCBListFontsCookie;
+    pub fn xcb_list_fonts_with_info_properties(
+        R: *const XCBListFontsWithInfoReply,
+    ) -> *mut XCBFontprop;
+    pub fn xcb_list_fonts_with_info_properties_length(R: *const XCBListFontsWithInfoReply)
+        -> c_int;
+    pub fn xcb_list_fonts_with_info_properties_iterator(
+        R: *const XCBListFontsWithInfoReply,
+    ) -> XCBFontpropIterator;
+    pub fn xcb_list_fonts_with_info_name(R: *const XCBListFontsWithInfoReply) -> *mut c_char;
+    pub fn xcb_list_fonts_with_info_name_length(R: *const XCBListFontsWithInfoReply) -> c_int;
+    pub fn xcb_list_fonts_with_info_name_end(
+        R: *const XCBListFontsWithInfoReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_list_fonts_with_info_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBListFontsWithInfoCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBListFontsWithInfoReply;
+    pub fn xcb_list_fonts_with_info(
+        connection: *mut XCBConnection,
+        max_names: u16,
+        pattern_len: u16,
+        pattern: *const c_char,
+    ) -> XCBListFontsWithInfoCookie;
+    pub fn xcb_list_fonts_with_info_unchecked(
+        connection: *mut XCBConnection,
+        max_names: u16,
+        pattern_len: u16,
+        pattern: *const c_char,
+    ) -> XCBListFontsWithInfoCookie;
+    pub fn xcb_set_font_path_checked(
+        connection: *mut XCBConnection,
+        font_qty: u16,
+        font: *const XCBStr,
+    ) -> XCBVoidCookie;
+    pub fn xcb_set_font_path(
+        connection: *mut XCBConnection,
+        font_qty: u16,
+        font: *const XCBStr,
+    ) -> XCBVoidCookie;
+    pub fn xcb_get_font_path_path_length(R: *const XCBGetFontPathReply) -> c_int;
+    pub fn xcb_get_font_path_path_iterator(R: *const XCBGetFontPathReply) -> XCBStrIterator;
+    pub fn xcb_get_font_path_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetFontPathCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetFontPathReply;
+    pub fn xcb_get_font_path(connection: *mut XCBConnection) -> XCBGetFontPathCookie;
+    pub fn xcb_get_font_path_unchecked(connection: *mut XCBConnection) -> XCBGetFontPathCookie;
+    pub fn xcb_create_pixmap_checked(
+        connection: *mut XCBConnection,
+        depth: u8,
+        pid: XCBPixmap,
+        drawable: XCBDrawable,
+        width: u16,
+        height: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_create_pixmap(
+        connection: *mut XCBConnection,
+        depth: u8,
+        pid: XCBPixmap,
+        drawable: XCBDrawable,
+        width: u16,
+        height: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_free_pixmap_checked(
+        connection: *mut XCBConnection,
+        pixmap: XCBPixmap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_free_pixmap(connection: *mut XCBConnection, pixmap: XCBPixmap) -> XCBVoidCookie;
+    pub fn xcb_create_gc_checked(
+        connection: *mut XCBConnection,
+        cid: XCBGcontext,
+        drawable: XCBDrawable,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_create_gc(
+        connection: *mut XCBConnection,
+        cid: XCBGcontext,
+        drawable: XCBDrawable,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_gc_checked(
+        connection: *mut XCBConnection,
+        gc: XCBGcontext,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_gc(
+        connection: *mut XCBConnection,
+        gc: XCBGcontext,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_copy_gc_checked(
+        connection: *mut XCBConnection,
+        src_gc: XCBGcontext,
+        dst_gc: XCBGcontext,
+        value_mask: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_copy_gc(
+        connection: *mut XCBConnection,
+        src_gc: XCBGcontext,
+        dst_gc: XCBGcontext,
+        value_mask: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_set_dashes_checked(
+        connection: *mut XCBConnection,
+        gc: XCBGcontext,
+        dash_offset: u16,
+        dashes_len: u16,
+        dashes: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_set_dashes(
+        connection: *mut XCBConnection,
+        gc: XCBGcontext,
+        dash_offset: u16,
+        dashes_len: u16,
+        dashes: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_set_clip_rectangles_checked(
+        connection: *mut XCBConnection,
+        ordering: u8,
+        gc: XCBGcontext,
+        clip_x_origin: i16,
+        clip_y_origin: i16,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_set_clip_rectangles(
+        connection: *mut XCBConnection,
+        ordering: u8,
+        gc: XCBGcontext,
+        clip_x_origin: i16,
+        clip_y_origin: i16,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_free_gc_checked(connection: *mut XCBConnection, gc: XCBGcontext) -> XCBVoidCookie;
+    pub fn xcb_free_gc(connection: *mut XCBConnection, gc: XCBGcontext) -> XCBVoidCookie;
+    pub fn xcb_clear_area_checked(
+        connection: *mut XCBConnection,
+        exposures: u8,
+        window: XCBWindow,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_clear_area(
+        connection: *mut XCBConnection,
+        exposures: u8,
+        window: XCBWindow,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_copy_area_checked(
+        connection: *mut XCBConnection,
+        src_drawable: XCBDrawable,
+        dst_drawable: XCBDrawable,
+        gc: XCBGcontext,
+        src_x: i16,
+        src_y: i16,
+        dst_x: i16,
+        dst_y: i16,
+        width: u16,
+        height: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_copy_area(
+        connection: *mut XCBConnection,
+        src_drawable: XCBDrawable,
+        dst_drawable: XCBDrawable,
+        gc: XCBGcontext,
+        src_x: i16,
+        src_y: i16,
+        dst_x: i16,
+        dst_y: i16,
+        width: u16,
+        height: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_copy_plane_checked(
+        connection: *mut XCBConnection,
+        src_drawable: XCBDrawable,
+        dst_drawable: XCBDrawable,
+        gc: XCBGcontext,
+        src_x: i16,
+        src_y: i16,
+        dst_x: i16,
+        dst_y: i16,
+        width: u16,
+        height: u16,
+        bit_plane: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_copy_plane(
+        connection: *mut XCBConnection,
+        src_drawable: XCBDrawable,
+        dst_drawable: XCBDrawable,
+        gc: XCBGcontext,
+        src_x: i16,
+        src_y: i16,
+        dst_x: i16,
+        dst_y: i16,
+        width: u16,
+        height: u16,
+        bit_plane: u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_point_checked(
+        connection: *mut XCBConnection,
+        coordinate_mode: u8,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        points_len: u32,
+        points: *const XCBPoint,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_point(
+        connection: *mut XCBConnection,
+        coordinate_mode: u8,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        points_len: u32,
+        points: *const XCBPoint,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_line_checked(
+        connection: *mut XCBConnection,
+        coordinate_mode: u8,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        points_len: u32,
+        points: *const XCBPoint,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_line(
+        connection: *mut XCBConnection,
+        coordinate_mode: u8,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        points_len: u32,
+        points: *const XCBPoint,
+    ) -> XCBVoidCookie;
+    pub fn xcb_segment_next(i: *mut XCBSegmentIterator);
+    pub fn xcb_segment_end(i: XCBSegmentIterator) -> XCBGenericIterator;
+    pub fn xcb_poly_segment_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        segments_len: u32,
+        segments: *const XCBSegment,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_segment(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        segments_len: u32,
+        segments: *const XCBSegment,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_rectangle_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_rectangle(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_arc_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        arcs_len: u32,
+        arcs: *const XCBArc,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_arc(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        arcs_len: u32,
+        arcs: *const XCBArc,
+    ) -> XCBVoidCookie;
+    pub fn xcb_fill_poly_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        shape: u8,
+        coordinate_mode: u8,
+        points_len: u32,
+        points: *const XCBPoint,
+    ) -> XCBVoidCookie;
+    pub fn xcb_fill_poly(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        shape: u8,
+        coordinate_mode: u8,
+        points_len: u32,
+        points: *const XCBPoint,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_fill_rectangle_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_fill_rectangle(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        rectangles_len: u32,
+        rectangles: *const XCBRectangle,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_fill_arc_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        arcs_len: u32,
+        arcs: *const XCBArc,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_fill_arc(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        arcs_len: u32,
+        arcs: *const XCBArc,
+    ) -> XCBVoidCookie;
+    pub fn xcb_put_image_checked(
+        connection: *mut XCBConnection,
+        format: u8,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        width: u16,
+        height: u16,
+        dst_x: i16,
+        dst_y: i16,
+        left_pad: u8,
+        depth: u8,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_put_image(
+        connection: *mut XCBConnection,
+        format: u8,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        width: u16,
+        height: u16,
+        dst_x: i16,
+        dst_y: i16,
+        left_pad: u8,
+        depth: u8,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_get_image_data(R: *const XCBGetImageReply) -> *mut u8;
+    pub fn xcb_get_image_data_length(R: *const XCBGetImageReply) -> c_int;
+    pub fn xcb_get_image_data_end(R: *const XCBGetImageReply) -> XCBGenericIterator;
+    pub fn xcb_get_image_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetImageCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetImageReply;
+    pub fn xcb_get_image(
+        connection: *mut XCBConnection,
+        format: u8,
+        drawable: XCBDrawable,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        plane_mask: u32,
+    ) -> XCBGetImageCookie;
+    pub fn xcb_get_image_unchecked(
+        connection: *mut XCBConnection,
+        format: u8,
+        drawable: XCBDrawable,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        plane_mask: u32,
+    ) -> XCBGetImageCookie;
+    pub fn xcb_poly_text_8_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        x: i16,
+        y: i16,
+        items_len: u32,
+        items: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_text_8(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        x: i16,
+        y: i16,
+        items_len: u32,
+        items: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_text_16_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        x: i16,
+        y: i16,
+        items_len: u32,
+        items: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_poly_text_16(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        x: i16,
+        y: i16,
+        items_len: u32,
+        items: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_image_text_8_checked(
+        connection: *mut XCBConnection,
+        string_len: u8,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        x: i16,
+        y: i16,
+        string: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_image_text_8(
+        connection: *mut XCBConnection,
+        string_len: u8,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        x: i16,
+        y: i16,
+        string: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_image_text_16_checked(
+        connection: *mut XCBConnection,
+        string_len: u8,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        x: i16,
+        y: i16,
+        string: *const XCBChar2b,
+    ) -> XCBVoidCookie;
+    pub fn xcb_image_text_16(
+        connection: *mut XCBConnection,
+        string_len: u8,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        x: i16,
+        y: i16,
+        string: *const XCBChar2b,
+    ) -> XCBVoidCookie;
+    pub fn xcb_create_colormap_checked(
+        connection: *mut XCBConnection,
+        alloc: u8,
+        mid: XCBColormap,
+        window: XCBWindow,
+        visual: XCBVisualid,
+    ) -> XCBVoidCookie;
+    pub fn xcb_create_colormap(
+        connection: *mut XCBConnection,
+        alloc: u8,
+        mid: XCBColormap,
+        window: XCBWindow,
+        visual: XCBVisualid,
+    ) -> XCBVoidCookie;
+    pub fn xcb_free_colormap_checked(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_free_colormap(connection: *mut XCBConnection, cmap: XCBColormap) -> XCBVoidCookie;
+    pub fn xcb_copy_colormap_and_free_checked(
+        connection: *mut XCBConnection,
+        mid: XCBColormap,
+        src_cmap: XCBColormap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_copy_colormap_and_free(
+        connection: *mut XCBConnection,
+        mid: XCBColormap,
+        src_cmap: XCBColormap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_install_colormap_checked(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_install_colormap(connection: *mut XCBConnection, cmap: XCBColormap)
+        -> XCBVoidCookie;
+    pub fn xcb_uninstall_colormap_checked(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_uninstall_colormap(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+    ) -> XCBVoidCookie;
+    pub fn xcb_list_installed_colormaps_cmaps(
+        R: *const XCBListInstalledColormapsReply,
+    ) -> *mut XCBColormap;
+    pub fn xcb_list_installed_colormaps_cmaps_length(
+        R: *const XCBListInstalledColormapsReply,
+    ) -> c_int;
+    pub fn xcb_list_installed_colormaps_cmaps_end(
+        R: *const XCBListInstalledColormapsReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_list_installed_colormaps_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBListInstalledColormapsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBListInstalledColormapsReply;
+    pub fn xcb_list_installed_colormaps(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBListInstalledColormapsCookie;
+    pub fn xcb_list_installed_colormaps_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBListInstalledColormapsCookie;
+    pub fn xcb_alloc_color_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBAllocColorCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBAllocColorReply;
+    pub fn xcb_alloc_color(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+        red: u16,
+        green: u16,
+        blue: u16,
+    ) -> XCBAllocColorCookie;
+    pub fn xcb_alloc_color_unchecked(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+        red: u16,
+        green: u16,
+        blue: u16,
+    ) -> XCBAllocColorCookie;
+    pub fn xcb_alloc_named_color_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBAllocNamedColorCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBAllocNamedColorReply;
+    pub fn xcb_alloc_named_color(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBAllocNamedColorCookie;
+    pub fn xcb_alloc_named_color_unchecked(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBAllocNamedColorCookie;
+    pub fn xcb_alloc_color_cells_pixels(R: *const XCBAllocColorCellsReply) -> *mut u32;
+    pub fn xcb_alloc_color_cells_pixels_length(R: *const XCBAllocColorCellsReply) -> c_int;
+    pub fn xcb_alloc_color_cells_pixels_end(
+        R: *const XCBAllocColorCellsReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_alloc_color_cells_masks(R: *const XCBAllocColorCellsReply) -> *mut u32;
+    pub fn xcb_alloc_color_cells_masks_length(R: *const XCBAllocColorCellsReply) -> c_int;
+    pub fn xcb_alloc_color_cells_masks_end(R: *const XCBAllocColorCellsReply)
+        -> XCBGenericIterator;
+    pub fn xcb_alloc_color_cells_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBAllocColorCellsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBAllocColorCellsReply;
+    pub fn xcb_alloc_color_cells(
+        connection: *mut XCBConnection,
+        contiguous: u8,
+        cmap: XCBColormap,
+        colors: u16,
+        planes: u16,
+    ) -> XCBAllocColorCellsCookie;
+    pub fn xcb_alloc_color_cells_unchecked(
+        connection: *mut XCBConnection,
+        contiguous: u8,
+        cmap: XCBColormap,
+        colors: u16,
+        planes: u16,
+    ) -> XCBAllocColorCellsCookie;
+    pub fn xcb_alloc_color_planes_pixels(R: *const XCBAllocColorPlanesReply) -> *mut u32;
+    pub fn xcb_alloc_color_planes_pixels_length(R: *const XCBAllocColorPlanesReply) -> c_int;
+    pub fn xcb_alloc_color_planes_pixels_end(
+        R: *const XCBAllocColorPlanesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_alloc_color_planes_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBAllocColorPlanesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBAllocColorPlanesReply;
+    pub fn xcb_alloc_color_planes(
+        connection: *mut XCBConnection,
+        contiguous: u8,
+        cmap: XCBColormap,
+        colors: u16,
+        reds: u16,
+        greens: u16,
+        blues: u16,
+    ) -> XCBAllocColorPlanesCookie;
+    pub fn xcb_alloc_color_planes_unchecked(
+        connection: *mut XCBConnection,
+        contiguous: u8,
+        cmap: XCBColormap,
+        colors: u16,
+        reds: u16,
+        greens: u16,
+        blues: u16,
+    ) -> XCBAllocColorPlanesCookie;
+    pub fn xcb_free_colors_checked(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+        plane_mask: u32,
+        pixels_len: u32,
+        pixels: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_free_colors(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+        plane_mask: u32,
+        pixels_len: u32,
+        pixels: *const u32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_coloritem_next(i: *mut XCBColoritemIterator);
+    pub fn xcb_coloritem_end(i: XCBColoritemIterator) -> XCBGenericIterator;
+    pub fn xcb_store_colors_checked(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+        items_len: u32,
+        items: *const XCBColoritem,
+    ) -> XCBVoidCookie;
+    pub fn xcb_store_colors(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+        items_len: u32,
+        items: *const XCBColoritem,
+    ) -> XCBVoidCookie;
+    pub fn xcb_store_named_color_checked(
+        connection: *mut XCBConnection,
+        flags: u8,
+        cmap: XCBColormap,
+        pixel: u32,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_store_named_color(
+        connection: *mut XCBConnection,
+        flags: u8,
+        cmap: XCBColormap,
+        pixel: u32,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_rgb_next(i: *mut XCBRgbIterator);
+    pub fn xcb_rgb_end(i: XCBRgbIterator) -> XCBGenericIterator;
+    pub fn xcb_query_colors_colors(R: *const XCBQueryColorsReply) -> *mut XCBRgb;
+    pub fn xcb_query_colors_colors_length(R: *const XCBQueryColorsReply) -> c_int;
+    pub fn xcb_query_colors_colors_iterator(R: *const XCBQueryColorsReply) -> XCBRgbIterator;
+    pub fn xcb_query_colors_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBQueryColorsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBQueryColorsReply;
+    pub fn xcb_query_colors(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+        pixels_len: u32,
+        pixels: *const u32,
+    ) -> XCBQueryColorsCookie;
+    pub fn xcb_query_colors_unchecked(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+        pixels_len: u32,
+        pixels: *const u32,
+    ) -> XCBQueryColorsCookie;
+    pub fn xcb_lookup_color_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBLookupColorCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBLookupColorReply;
+    pub fn xcb_lookup_color(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBLookupColorCookie;
+    pub fn xcb_lookup_color_unchecked(
+        connection: *mut XCBConnection,
+        cmap: XCBColormap,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBLookupColorCookie;
+    pub fn xcb_create_cursor_checked(
+        connection: *mut XCBConnection,
+        cid: XCBCursor,
+        source: XCBPixmap,
+        mask: XCBPixmap,
+        fore_red: u16,
+        fore_green: u16,
+        fore_blue: u16,
+        back_red: u16,
+        back_green: u16,
+        back_blue: u16,
+        x: u16,
+        y: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_create_cursor(
+        connection: *mut XCBConnection,
+        cid: XCBCursor,
+        source: XCBPixmap,
+        mask: XCBPixmap,
+        fore_red: u16,
+        fore_green: u16,
+        fore_blue: u16,
+        back_red: u16,
+        back_green: u16,
+        back_blue: u16,
+        x: u16,
+        y: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_create_glyph_cursor_checked(
+        connection: *mut XCBConnection,
+        cid: XCBCursor,
+        source_font: XCBFont,
+        mask_font: XCBFont,
+        source_char: u16,
+        mask_char: u16,
+        fore_red: u16,
+        fore_green: u16,
+        fore_blue: u16,
+        back_red: u16,
+        back_green: u16,
+        back_blue: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_create_glyph_cursor(
+        connection: *mut XCBConnection,
+        cid: XCBCursor,
+        source_font: XCBFont,
+        mask_font: XCBFont,
+        source_char: u16,
+        mask_char: u16,
+        fore_red: u16,
+        fore_green: u16,
+        fore_blue: u16,
+        back_red: u16,
+        back_green: u16,
+        back_blue: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_free_cursor_checked(
+        connection: *mut XCBConnection,
+        cursor: XCBCursor,
+    ) -> XCBVoidCookie;
+    pub fn xcb_free_cursor(connection: *mut XCBConnection, cursor: XCBCursor) -> XCBVoidCookie;
+    pub fn xcb_recolor_cursor_checked(
+        connection: *mut XCBConnection,
+        cursor: XCBCursor,
+        fore_red: u16,
+        fore_green: u16,
+        fore_blue: u16,
+        back_red: u16,
+        back_green: u16,
+        back_blue: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_recolor_cursor(
+        connection: *mut XCBConnection,
+        cursor: XCBCursor,
+        fore_red: u16,
+        fore_green: u16,
+        fore_blue: u16,
+        back_red: u16,
+        back_green: u16,
+        back_blue: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_query_best_size_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBQueryBestSizeCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBQueryBestSizeReply;
+    pub fn xcb_query_best_size(
+        connection: *mut XCBConnection,
+        class: u8,
+        drawable: XCBDrawable,
+        width: u16,
+        height: u16,
+    ) -> XCBQueryBestSizeCookie;
+    pub fn xcb_query_best_size_unchecked(
+        connection: *mut XCBConnection,
+        class: u8,
+        drawable: XCBDrawable,
+        width: u16,
+        height: u16,
+    ) -> XCBQueryBestSizeCookie;
+    pub fn xcb_query_extension_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBQueryExtensionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBQueryExtensionReply;
+    pub fn xcb_query_extension(
+        connection: *mut XCBConnection,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBQueryExtensionCookie;
+    pub fn xcb_query_extension_unchecked(
+        connection: *mut XCBConnection,
+        name_len: u16,
+        name: *const c_char,
+    ) -> XCBQueryExtensionCookie;
+    pub fn xcb_list_extensions_names_length(R: *const XCBListExtensionsReply) -> c_int;
+    pub fn xcb_list_extensions_names_iterator(R: *const XCBListExtensionsReply) -> XCBStrIterator;
+    pub fn xcb_list_extensions_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBListExtensionsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBListExtensionsReply;
+    pub fn xcb_list_extensions(connection: *mut XCBConnection) -> XCBListExtensionsCookie;
+    pub fn xcb_list_extensions_unchecked(connection: *mut XCBConnection)
+        -> XCBListExtensionsCookie;
+    pub fn xcb_change_keyboard_mapping_checked(
+        connection: *mut XCBConnection,
+        keycode_count: u8,
+        first_keycode: XCBKeycode,
+        keysyms_per_keycode: u8,
+        keysyms: *const XCBKeysym,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_keyboard_mapping(
+        connection: *mut XCBConnection,
+        keycode_count: u8,
+        first_keycode: XCBKeycode,
+        keysyms_per_keycode: u8,
+        keysyms: *const XCBKeysym,
+    ) -> XCBVoidCookie;
+    pub fn xcb_get_keyboard_mapping_keysyms(R: *const XCBGetKeyboardMappingReply)
+        -> *mut XCBKeysym;
+    pub fn xcb_get_keyboard_mapping_keysyms_length(R: *const XCBGetKeyboardMappingReply) -> c_int;
+    pub fn xcb_get_keyboard_mapping_keysyms_end(
+        R: *const XCBGetKeyboardMappingReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_get_keyboard_mapping_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetKeyboardMappingCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetKeyboardMappingReply;
+    pub fn xcb_get_keyboard_mapping(
+        connection: *mut XCBConnection,
+        first_keycode: XCBKeycode,
+        count: u8,
+    ) -> XCBGetKeyboardMappingCookie;
+    pub fn xcb_get_keyboard_mapping_unchecked(
+        connection: *mut XCBConnection,
+        first_keycode: XCBKeycode,
+        count: u8,
+    ) -> XCBGetKeyboardMappingCookie;
+    pub fn xcb_change_keyboard_control_checked(
+        connection: *mut XCBConnection,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_keyboard_control(
+        connection: *mut XCBConnection,
+        value_mask: u32,
+        value_list: *const c_void,
+    ) -> XCBVoidCookie;
+    pub fn xcb_get_keyboard_control_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetKeyboardControlCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetKeyboardControlReply;
+    pub fn xcb_get_keyboard_control(connection: *mut XCBConnection) -> XCBGetKeyboardControlCookie;
+    pub fn xcb_get_keyboard_control_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBGetKeyboardControlCookie;
+    pub fn xcb_bell_checked(connection: *mut XCBConnection, percent: i8) -> XCBVoidCookie;
+    pub fn xcb_bell(connection: *mut XCBConnection, percent: i8) -> XCBVoidCookie;
+    pub fn xcb_change_pointer_control_checked(
+        connection: *mut XCBConnection,
+        acceleration_numerator: i16,
+        acceleration_denominator: i16,
+        threshold: i16,
+        do_acceleration: u8,
+        do_threshold: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_pointer_control(
+        connection: *mut XCBConnection,
+        acceleration_numerator: i16,
+        acceleration_denominator: i16,
+        threshold: i16,
+        do_acceleration: u8,
+        do_threshold: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_get_pointer_control_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetPointerControlCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetPointerControlReply;
+    pub fn xcb_get_pointer_control(connection: *mut XCBConnection) -> XCBGetPointerControlCookie;
+    pub fn xcb_get_pointer_control_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBGetPointerControlCookie;
+    pub fn xcb_set_screen_saver_checked(
+        connection: *mut XCBConnection,
+        timeout: i16,
+        interval: i16,
+        prefer_blanking: u8,
+        allow_exposures: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_set_screen_saver(
+        connection: *mut XCBConnection,
+        timeout: i16,
+        interval: i16,
+        prefer_blanking: u8,
+        allow_exposures: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_get_screen_saver_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetScreenSaverCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetScreenSaverReply;
+    pub fn xcb_get_screen_saver(connection: *mut XCBConnection) -> XCBGetScreenSaverCookie;
+    pub fn xcb_get_screen_saver_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBGetScreenSaverCookie;
+    pub fn xcb_change_hosts_checked(
+        connection: *mut XCBConnection,
+        mode: u8,
+        family: u8,
+        address_len: u16,
+        address: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_change_hosts(
+        connection: *mut XCBConnection,
+        mode: u8,
+        family: u8,
+        address_len: u16,
+        address: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_host_next(i: *mut XCBHostIterator);
+    pub fn xcb_host_end(i: XCBHostIterator) -> XCBGenericIterator;
+    pub fn xcb_host_address(R: *const XCBHost) -> *mut u8;
+    pub fn xcb_host_address_length(R: *const XCBHost) -> c_int;
+    pub fn xcb_host_address_end(R: *const XCBHost) -> XCBGenericIterator;
+    pub fn xcb_list_hosts_hosts_length(R: *const XCBListHostsReply) -> c_int;
+    pub fn xcb_list_hosts_hosts_iterator(R: *const XCBListHostsReply) -> XCBHostIterator;
+    pub fn xcb_list_hosts_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBListHostsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBListHostsReply;
+    pub fn xcb_list_hosts(connection: *mut XCBConnection) -> XCBListHostsCookie;
+    pub fn xcb_list_hosts_unchecked(connection: *mut XCBConnection) -> XCBListHostsCookie;
+    pub fn xcb_set_access_control_checked(
+        connection: *mut XCBConnection,
+        mode: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_set_access_control(connection: *mut XCBConnection, mode: u8) -> XCBVoidCookie;
+    pub fn xcb_set_close_down_mode_checked(
+        connection: *mut XCBConnection,
+        mode: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_set_close_down_mode(connection: *mut XCBConnection, mode: u8) -> XCBVoidCookie;
+    pub fn xcb_kill_client_checked(connection: *mut XCBConnection, resource: u32) -> XCBVoidCookie;
+    pub fn xcb_kill_client(connection: *mut XCBConnection, resource: u32) -> XCBVoidCookie;
+    pub fn xcb_rotate_properties_checked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        atoms_len: u16,
+        delta: i16,
+        atoms: *const XCBAtom,
+    ) -> XCBVoidCookie;
+    pub fn xcb_rotate_properties(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        atoms_len: u16,
+        delta: i16,
+        atoms: *const XCBAtom,
+    ) -> XCBVoidCookie;
+    pub fn xcb_force_screen_saver_checked(
+        connection: *mut XCBConnection,
+        mode: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_force_screen_saver(connection: *mut XCBConnection, mode: u8) -> XCBVoidCookie;
+    pub fn xcb_set_pointer_mapping_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSetPointerMappingCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSetPointerMappingReply;
+    pub fn xcb_set_pointer_mapping(
+        connection: *mut XCBConnection,
+        map_len: u8,
+        map: *const u8,
+    ) -> XCBSetPointerMappingCookie;
+    pub fn xcb_set_pointer_mapping_unchecked(
+        connection: *mut XCBConnection,
+        map_len: u8,
+        map: *const u8,
+    ) -> XCBSetPointerMappingCookie;
+    pub fn xcb_get_pointer_mapping_map(R: *const XCBGetPointerMappingReply) -> *mut u8;
+    pub fn xcb_get_pointer_mapping_map_length(R: *const XCBGetPointerMappingReply) -> c_int;
+    pub fn xcb_get_pointer_mapping_map_end(
+        R: *const XCBGetPointerMappingReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_get_pointer_mapping_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetPointerMappingCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetPointerMappingReply;
+    pub fn xcb_get_pointer_mapping(connection: *mut XCBConnection) -> XCBGetPointerMappingCookie;
+    pub fn xcb_get_pointer_mapping_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBGetPointerMappingCookie;
+    pub fn xcb_set_modifier_mapping_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSetModifierMappingCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSetModifierMappingReply;
+    pub fn xcb_set_modifier_mapping(
+        connection: *mut XCBConnection,
+        keycodes_per_modifier: u8,
+        keycodes: *const XCBKeycode,
+    ) -> XCBSetModifierMappingCookie;
+    pub fn xcb_set_modifier_mapping_unchecked(
+        connection: *mut XCBConnection,
+        keycodes_per_modifier: u8,
+        keycodes: *const XCBKeycode,
+    ) -> XCBSetModifierMappingCookie;
+    pub fn xcb_get_modifier_mapping_keycodes(
+        R: *const XCBGetModifierMappingReply,
+    ) -> *mut XCBKeycode;
+    pub fn xcb_get_modifier_mapping_keycodes_length(R: *const XCBGetModifierMappingReply) -> c_int;
+    pub fn xcb_get_modifier_mapping_keycodes_end(
+        R: *const XCBGetModifierMappingReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_get_modifier_mapping_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBGetModifierMappingCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBGetModifierMappingReply;
+    pub fn xcb_get_modifier_mapping(connection: *mut XCBConnection) -> XCBGetModifierMappingCookie;
+    pub fn xcb_get_modifier_mapping_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBGetModifierMappingCookie;
+    pub fn xcb_no_operation_checked(connection: *mut XCBConnection) -> XCBVoidCookie;
+    pub fn xcb_no_operation(connection: *mut XCBConnection) -> XCBVoidCookie;
+}
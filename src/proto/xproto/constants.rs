@@ -0,0 +1,549 @@
+// Generated by xcbgen from xml/xproto.xml, do not edit.
+
+pub const XCB_VISUAL_CLASS_STATIC_GRAY: u8 = 0;
+pub const XCB_VISUAL_CLASS_GRAY_SCALE: u8 = 1;
+pub const XCB_VISUAL_CLASS_STATIC_COLOR: u8 = 2;
+pub const XCB_VISUAL_CLASS_PSEUDO_COLOR: u8 = 3;
+pub const XCB_VISUAL_CLASS_TRUE_COLOR: u8 = 4;
+pub const XCB_VISUAL_CLASS_DIRECT_COLOR: u8 = 5;
+pub const XCB_EVENT_MASK_NO_EVENT: u32 = 0;
+pub const XCB_EVENT_MASK_KEY_PRESS: u32 = 1;
+pub const XCB_EVENT_MASK_KEY_RELEASE: u32 = 2;
+pub const XCB_EVENT_MASK_BUTTON_PRESS: u32 = 4;
+pub const XCB_EVENT_MASK_BUTTON_RELEASE: u32 = 8;
+pub const XCB_EVENT_MASK_ENTER_WINDOW: u32 = 16;
+pub const XCB_EVENT_MASK_LEAVE_WINDOW: u32 = 32;
+pub const XCB_EVENT_MASK_POINTER_MOTION: u32 = 64;
+pub const XCB_EVENT_MASK_POINTER_MOTION_HINT: u32 = 128;
+pub const XCB_EVENT_MASK_BUTTON_1_MOTION: u32 = 256;
+pub const XCB_EVENT_MASK_BUTTON_2_MOTION: u32 = 512;
+pub const XCB_EVENT_MASK_BUTTON_3_MOTION: u32 = 1024;
+pub const XCB_EVENT_MASK_BUTTON_4_MOTION: u32 = 2048;
+pub const XCB_EVENT_MASK_BUTTON_5_MOTION: u32 = 4096;
+pub const XCB_EVENT_MASK_BUTTON_MOTION: u32 = 8192;
+pub const XCB_EVENT_MASK_KEYMAP_STATE: u32 = 16384;
+pub const XCB_EVENT_MASK_EXPOSURE: u32 = 32768;
+pub const XCB_EVENT_MASK_VISIBILITY_CHANGE: u32 = 65536;
+pub const XCB_EVENT_MASK_STRUCTURE_NOTIFY: u32 = 131072;
+pub const XCB_EVENT_MASK_RESIZE_REDIRECT: u32 = 262144;
+pub const XCB_EVENT_MASK_SUBSTRUCTURE_NOTIFY: u32 = 524288;
+pub const XCB_EVENT_MASK_SUBSTRUCTURE_REDIRECT: u32 = 1048576;
+pub const XCB_EVENT_MASK_FOCUS_CHANGE: u32 = 2097152;
+pub const XCB_EVENT_MASK_PROPERTY_CHANGE: u32 = 4194304;
+pub const XCB_EVENT_MASK_COLOR_MAP_CHANGE: u32 = 8388608;
+pub const XCB_EVENT_MASK_OWNER_GRAB_BUTTON: u32 = 16777216;
+pub const XCB_BACKING_STORE_NOT_USEFUL: u32 = 0;
+pub const XCB_BACKING_STORE_WHEN_MAPPED: u32 = 1;
+pub const XCB_BACKING_STORE_ALWAYS: u32 = 2;
+pub const XCB_IMAGE_ORDER_LSB_FIRST: u8 = 0;
+pub const XCB_IMAGE_ORDER_MSB_FIRST: u8 = 1;
+pub const XCB_MOD_MASK_SHIFT: u16 = 1;
+pub const XCB_MOD_MASK_LOCK: u16 = 2;
+pub const XCB_MOD_MASK_CONTROL: u16 = 4;
+pub const XCB_MOD_MASK_1: u16 = 8;
+pub const XCB_MOD_MASK_2: u16 = 16;
+pub const XCB_MOD_MASK_3: u16 = 32;
+pub const XCB_MOD_MASK_4: u16 = 64;
+pub const XCB_MOD_MASK_5: u16 = 128;
+pub const XCB_MOD_MASK_ANY: u16 = 32768;
+pub const XCB_KEY_BUT_MASK_SHIFT: u16 = 1;
+pub const XCB_KEY_BUT_MASK_LOCK: u16 = 2;
+pub const XCB_KEY_BUT_MASK_CONTROL: u16 = 4;
+pub const XCB_KEY_BUT_MASK_MOD_1: u16 = 8;
+pub const XCB_KEY_BUT_MASK_MOD_2: u16 = 16;
+pub const XCB_KEY_BUT_MASK_MOD_3: u16 = 32;
+pub const XCB_KEY_BUT_MASK_MOD_4: u16 = 64;
+pub const XCB_KEY_BUT_MASK_MOD_5: u16 = 128;
+pub const XCB_KEY_BUT_MASK_BUTTON_1: u16 = 256;
+pub const XCB_KEY_BUT_MASK_BUTTON_2: u16 = 512;
+pub const XCB_KEY_BUT_MASK_BUTTON_3: u16 = 1024;
+pub const XCB_KEY_BUT_MASK_BUTTON_4: u16 = 2048;
+pub const XCB_KEY_BUT_MASK_BUTTON_5: u16 = 4096;
+pub const XCB_WINDOW_NONE: u32 = 0;
+pub const XCB_KEY_PRESS: u8 = 2;
+pub const XCB_KEY_RELEASE: u8 = 3;
+pub const XCB_BUTTON_MASK_1: u32 = 256;
+pub const XCB_BUTTON_MASK_2: u32 = 512;
+pub const XCB_BUTTON_MASK_3: u32 = 1024;
+pub const XCB_BUTTON_MASK_4: u32 = 2048;
+pub const XCB_BUTTON_MASK_5: u32 = 4096;
+pub const XCB_BUTTON_MASK_ANY: u32 = 32768;
+pub const XCB_BUTTON_PRESS: u8 = 4;
+pub const XCB_BUTTON_RELEASE: u8 = 5;
+pub const XCB_MOTION_NORMAL: u8 = 0;
+pub const XCB_MOTION_HINT: u8 = 1;
+pub const XCB_MOTION_NOTIFY: u8 = 6;
+pub const XCB_NOTIFY_DETAIL_ANCESTOR: u8 = 0;
+pub const XCB_NOTIFY_DETAIL_VIRTUAL: u8 = 1;
+pub const XCB_NOTIFY_DETAIL_INFERIOR: u8 = 2;
+pub const XCB_NOTIFY_DETAIL_NONLINEAR: u8 = 3;
+pub const XCB_NOTIFY_DETAIL_NONLINEAR_VIRTUAL: u8 = 4;
+pub const XCB_NOTIFY_DETAIL_POINTER: u8 = 5;
+pub const XCB_NOTIFY_DETAIL_POINTER_ROOT: u8 = 6;
+pub const XCB_NOTIFY_DETAIL_NONE: u8 = 7;
+pub const XCB_NOTIFY_MODE_NORMAL: u8 = 0;
+pub const XCB_NOTIFY_MODE_GRAB: u8 = 1;
+pub const XCB_NOTIFY_MODE_UNGRAB: u8 = 2;
+pub const XCB_NOTIFY_MODE_WHILE_GRABBED: u8 = 3;
+pub const XCB_ENTER_NOTIFY: u8 = 7;
+pub const XCB_LEAVE_NOTIFY: u8 = 8;
+pub const XCB_FOCUS_IN: u8 = 9;
+pub const XCB_FOCUS_OUT: u8 = 10;
+pub const XCB_KEYMAP_NOTIFY: u8 = 11;
+pub const XCB_EXPOSE: u8 = 12;
+pub const XCB_GRAPHICS_EXPOSURE: u8 = 13;
+pub const XCB_NO_EXPOSURE: u8 = 14;
+pub const XCB_VISIBILITY_UNOBSCURED: u8 = 0;
+pub const XCB_VISIBILITY_PARTIALLY_OBSCURED: u8 = 1;
+pub const XCB_VISIBILITY_FULLY_OBSCURED: u8 = 2;
+pub const XCB_VISIBILITY_NOTIFY: u8 = 15;
+pub const XCB_CREATE_NOTIFY: u8 = 16;
+pub const XCB_DESTROY_NOTIFY: u8 = 17;
+pub const XCB_UNMAP_NOTIFY: u8 = 18;
+pub const XCB_MAP_NOTIFY: u8 = 19;
+pub const XCB_MAP_REQUEST: u8 = 20;
+pub const XCB_REPARENT_NOTIFY: u8 = 21;
+pub const XCB_CONFIGURE_NOTIFY: u8 = 22;
+pub const XCB_CONFIGURE_REQUEST: u8 = 23;
+pub const XCB_GRAVITY_NOTIFY: u8 = 24;
+pub const XCB_RESIZE_REQUEST: u8 = 25;
+pub const XCB_PLACE_ON_TOP: u8 = 0;
+pub const XCB_PLACE_ON_BOTTOM: u8 = 1;
+pub const XCB_CIRCULATE_NOTIFY: u8 = 26;
+pub const XCB_CIRCULATE_REQUEST: u8 = 27;
+pub const XCB_PROPERTY_NEW_VALUE: u8 = 0;
+pub const XCB_PROPERTY_DELETE: u8 = 1;
+pub const XCB_PROPERTY_NOTIFY: u8 = 28;
+pub const XCB_SELECTION_CLEAR: u8 = 29;
+pub const XCB_TIME_CURRENT_TIME: u32 = 0;
+pub const XCB_ATOM_NONE: u32 = 0;
+pub const XCB_ATOM_ANY: u32 = 0;
+pub const XCB_ATOM_PRIMARY: u32 = 1;
+pub const XCB_ATOM_SECONDARY: u32 = 2;
+pub const XCB_ATOM_ARC: u32 = 3;
+pub const XCB_ATOM_ATOM: u32 = 4;
+pub const XCB_ATOM_BITMAP: u32 = 5;
+pub const XCB_ATOM_CARDINAL: u32 = 6;
+pub const XCB_ATOM_COLORMAP: u32 = 7;
+pub const XCB_ATOM_CURSOR: u32 = 8;
+pub const XCB_ATOM_CUT_BUFFER0: u32 = 9;
+pub const XCB_ATOM_CUT_BUFFER1: u32 = 10;
+pub const XCB_ATOM_CUT_BUFFER2: u32 = 11;
+pub const XCB_ATOM_CUT_BUFFER3: u32 = 12;
+pub const XCB_ATOM_CUT_BUFFER4: u32 = 13;
+pub const XCB_ATOM_CUT_BUFFER5: u32 = 14;
+pub const XCB_ATOM_CUT_BUFFER6: u32 = 15;
+pub const XCB_ATOM_CUT_BUFFER7: u32 = 16;
+pub const XCB_ATOM_DRAWABLE: u32 = 17;
+pub const XCB_ATOM_FONT: u32 = 18;
+pub const XCB_ATOM_INTEGER: u32 = 19;
+pub const XCB_ATOM_PIXMAP: u32 = 20;
+pub const XCB_ATOM_POINT: u32 = 21;
+pub const XCB_ATOM_RECTANGLE: u32 = 22;
+pub const XCB_ATOM_RESOURCE_MANAGER: u32 = 23;
+pub const XCB_ATOM_RGB_COLOR_MAP: u32 = 24;
+pub const XCB_ATOM_RGB_BEST_MAP: u32 = 25;
+pub const XCB_ATOM_RGB_BLUE_MAP: u32 = 26;
+pub const XCB_ATOM_RGB_DEFAULT_MAP: u32 = 27;
+pub const XCB_ATOM_RGB_GRAY_MAP: u32 = 28;
+pub const XCB_ATOM_RGB_GREEN_MAP: u32 = 29;
+pub const XCB_ATOM_RGB_RED_MAP: u32 = 30;
+pub const XCB_ATOM_STRING: u32 = 31;
+pub const XCB_ATOM_VISUALID: u32 = 32;
+pub const XCB_ATOM_WINDOW: u32 = 33;
+pub const XCB_ATOM_WM_COMMAND: u32 = 34;
+pub const XCB_ATOM_WM_HINTS: u32 = 35;
+pub const XCB_ATOM_WM_CLIENT_MACHINE: u32 = 36;
+pub const XCB_ATOM_WM_ICON_NAME: u32 = 37;
+pub const XCB_ATOM_WM_ICON_SIZE: u32 = 38;
+pub const XCB_ATOM_WM_NAME: u32 = 39;
+pub const XCB_ATOM_WM_NORMAL_HINTS: u32 = 40;
+pub const XCB_ATOM_WM_SIZE_HINTS: u32 = 41;
+pub const XCB_ATOM_WM_ZOOM_HINTS: u32 = 42;
+pub const XCB_ATOM_MIN_SPACE: u32 = 43;
+pub const XCB_ATOM_NORM_SPACE: u32 = 44;
+pub const XCB_ATOM_MAX_SPACE: u32 = 45;
+pub const XCB_ATOM_END_SPACE: u32 = 46;
+pub const XCB_ATOM_SUPERSCRIPT_X: u32 = 47;
+pub const XCB_ATOM_SUPERSCRIPT_Y: u32 = 48;
+pub const XCB_ATOM_SUBSCRIPT_X: u32 = 49;
+pub const XCB_ATOM_SUBSCRIPT_Y: u32 = 50;
+pub const XCB_ATOM_UNDERLINE_POSITION: u32 = 51;
+pub const XCB_ATOM_UNDERLINE_THICKNESS: u32 = 52;
+pub const XCB_ATOM_STRIKEOUT_ASCENT: u32 = 53;
+pub const XCB_ATOM_STRIKEOUT_DESCENT: u32 = 54;
+pub const XCB_ATOM_ITALIC_ANGLE: u32 = 55;
+pub const XCB_ATOM_X_HEIGHT: u32 = 56;
+pub const XCB_ATOM_QUAD_WIDTH: u32 = 57;
+pub const XCB_ATOM_WEIGHT: u32 = 58;
+pub const XCB_ATOM_POINT_SIZE: u32 = 59;
+pub const XCB_ATOM_RESOLUTION: u32 = 60;
+pub const XCB_ATOM_COPYRIGHT: u32 = 61;
+pub const XCB_ATOM_NOTICE: u32 = 62;
+pub const XCB_ATOM_FONT_NAME: u32 = 63;
+pub const XCB_ATOM_FAMILY_NAME: u32 = 64;
+pub const XCB_ATOM_FULL_NAME: u32 = 65;
+pub const XCB_ATOM_CAP_HEIGHT: u32 = 66;
+pub const XCB_ATOM_WM_CLASS: u32 = 67;
+pub const XCB_ATOM_WM_TRANSIENT_FOR: u32 = 68;
+pub const XCB_SELECTION_REQUEST: u8 = 30;
+pub const XCB_SELECTION_NOTIFY: u8 = 31;
+pub const XCB_COLORMAP_STATE_UNINSTALLED: u8 = 0;
+pub const XCB_COLORMAP_STATE_INSTALLED: u8 = 1;
+pub const XCB_COLORMAP_NONE: u32 = 0;
+pub const XCB_COLORMAP_NOTIFY: u8 = 32;
+pub const XCB_CLIENT_MESSAGE: u8 = 33;
+pub const XCB_MAPPING_MODIFIER: u8 = 0;
+pub const XCB_MAPPING_KEYBOARD: u8 = 1;
+pub const XCB_MAPPING_POINTER: u8 = 2;
+pub const XCB_MAPPING_NOTIFY: u8 = 34;
+pub const XCB_GE_GENERIC: u16 = 35;
+pub const XCB_REQUEST: u8 = 1;
+pub const XCB_VALUE: u8 = 2;
+pub const XCB_WINDOW: u8 = 3;
+pub const XCB_PIXMAP: u8 = 4;
+pub const XCB_ATOM: u8 = 5;
+pub const XCB_CURSOR: u8 = 6;
+pub const XCB_FONT: u8 = 7;
+pub const XCB_MATCH: u8 = 8;
+pub const XCB_DRAWABLE: u8 = 9;
+pub const XCB_ACCESS: u8 = 10;
+pub const XCB_ALLOC: u8 = 11;
+pub const XCB_COLORMAP: u8 = 12;
+pub const XCB_G_CONTEXT: u8 = 13;
+pub const XCB_ID_CHOICE: u8 = 14;
+pub const XCB_NAME: u8 = 15;
+pub const XCB_LENGTH: u8 = 16;
+pub const XCB_IMPLEMENTATION: u8 = 17;
+pub const XCB_WINDOW_CLASS_COPY_FROM_PARENT: u16 = 0;
+pub const XCB_WINDOW_CLASS_INPUT_OUTPUT: u16 = 1;
+pub const XCB_WINDOW_CLASS_INPUT_ONLY: u16 = 2;
+pub const XCB_CW_BACK_PIXMAP: u32 = 1;
+pub const XCB_CW_BACK_PIXEL: u32 = 2;
+pub const XCB_CW_BORDER_PIXMAP: u32 = 4;
+pub const XCB_CW_BORDER_PIXEL: u32 = 8;
+pub const XCB_CW_BIT_GRAVITY: u32 = 16;
+pub const XCB_CW_WIN_GRAVITY: u32 = 32;
+pub const XCB_CW_BACKING_STORE: u32 = 64;
+pub const XCB_CW_BACKING_PLANES: u32 = 128;
+pub const XCB_CW_BACKING_PIXEL: u32 = 256;
+pub const XCB_CW_OVERRIDE_REDIRECT: u32 = 512;
+pub const XCB_CW_SAVE_UNDER: u32 = 1024;
+pub const XCB_CW_EVENT_MASK: u32 = 2048;
+pub const XCB_CW_DONT_PROPAGATE: u32 = 4096;
+pub const XCB_CW_COLORMAP: u32 = 8192;
+pub const XCB_CW_CURSOR: u32 = 16384;
+pub const XCB_BACK_PIXMAP_NONE: u32 = 0;
+pub const XCB_BACK_PIXMAP_PARENT_RELATIVE: u32 = 1;
+pub const XCB_GRAVITY_BIT_FORGET: u32 = 0;
+pub const XCB_GRAVITY_WIN_UNMAP: u32 = 0;
+pub const XCB_GRAVITY_NORTH_WEST: u32 = 1;
+pub const XCB_GRAVITY_NORTH: u32 = 2;
+pub const XCB_GRAVITY_NORTH_EAST: u32 = 3;
+pub const XCB_GRAVITY_WEST: u32 = 4;
+pub const XCB_GRAVITY_CENTER: u32 = 5;
+pub const XCB_GRAVITY_EAST: u32 = 6;
+pub const XCB_GRAVITY_SOUTH_WEST: u32 = 7;
+pub const XCB_GRAVITY_SOUTH: u32 = 8;
+pub const XCB_GRAVITY_SOUTH_EAST: u32 = 9;
+pub const XCB_GRAVITY_STATIC: u32 = 10;
+pub const XCB_CREATE_WINDOW: u8 = 1;
+pub const XCB_CHANGE_WINDOW_ATTRIBUTES: u8 = 2;
+pub const XCB_MAP_STATE_UNMAPPED: u8 = 0;
+pub const XCB_MAP_STATE_UNVIEWABLE: u8 = 1;
+pub const XCB_MAP_STATE_VIEWABLE: u8 = 2;
+pub const XCB_GET_WINDOW_ATTRIBUTES: u8 = 3;
+pub const XCB_DESTROY_WINDOW: u8 = 4;
+pub const XCB_DESTROY_SUBWINDOWS: u8 = 5;
+pub const XCB_SET_MODE_INSERT: u8 = 0;
+pub const XCB_SET_MODE_DELETE: u8 = 1;
+pub const XCB_CHANGE_SAVE_SET: u8 = 6;
+pub const XCB_REPARENT_WINDOW: u8 = 7;
+pub const XCB_MAP_WINDOW: u8 = 8;
+pub const XCB_MAP_SUBWINDOWS: u8 = 9;
+pub const XCB_UNMAP_WINDOW: u8 = 10;
+pub const XCB_UNMAP_SUBWINDOWS: u8 = 11;
+pub const XCB_CONFIG_WINDOW_X: u16 = 1;
+pub const XCB_CONFIG_WINDOW_Y: u16 = 2;
+pub const XCB_CONFIG_WINDOW_WIDTH: u16 = 4;
+pub const XCB_CONFIG_WINDOW_HEIGHT: u16 = 8;
+pub const XCB_CONFIG_WINDOW_BORDER_WIDTH: u16 = 16;
+pub const XCB_CONFIG_WINDOW_SIBLING: u16 = 32;
+pub const XCB_CONFIG_WINDOW_STACK_MODE: u16 = 64;
+pub const XCB_STACK_MODE_ABOVE: u32 = 0;
+pub const XCB_STACK_MODE_BELOW: u32 = 1;
+pub const XCB_STACK_MODE_TOP_IF: u32 = 2;
+pub const XCB_STACK_MODE_BOTTOM_IF: u32 = 3;
+pub const XCB_STACK_MODE_OPPOSITE: u32 = 4;
+pub const XCB_CONFIGURE_WINDOW: u8 = 12;
+pub const XCB_CIRCULATE_RAISE_LOWEST: u8 = 0;
+pub const XCB_CIRCULATE_LOWER_HIGHEST: u8 = 1;
+pub const XCB_CIRCULATE_WINDOW: u8 = 13;
+pub const XCB_GET_GEOMETRY: u8 = 14;
+pub const XCB_QUERY_TREE: u8 = 15;
+pub const XCB_INTERN_ATOM: u8 = 16;
+pub const XCB_GET_ATOM_NAME: u8 = 17;
+pub const XCB_PROP_MODE_REPLACE: u8 = 0;
+pub const XCB_PROP_MODE_PREPEND: u8 = 1;
+pub const XCB_PROP_MODE_APPEND: u8 = 2;
+pub const XCB_CHANGE_PROPERTY: u8 = 18;
+pub const XCB_DELETE_PROPERTY: u8 = 19;
+pub const XCB_GET_PROPERTY_TYPE_ANY: u32 = 0;
+pub const XCB_GET_PROPERTY: u8 = 20;
+pub const XCB_LIST_PROPERTIES: u8 = 21;
+pub const XCB_SET_SELECTION_OWNER: u8 = 22;
+pub const XCB_GET_SELECTION_OWNER: u8 = 23;
+pub const XCB_CONVERT_SELECTION: u8 = 24;
+pub const XCB_SEND_EVENT_DEST_POINTER_WINDOW: u32 = 0;
+pub const XCB_SEND_EVENT_DEST_ITEM_FOCUS: u32 = 1;
+pub const XCB_SEND_EVENT: u8 = 25;
+pub const XCB_GRAB_MODE_SYNC: u8 = 0;
+pub const XCB_GRAB_MODE_ASYNC: u8 = 1;
+pub const XCB_GRAB_STATUS_SUCCESS: u8 = 0;
+pub const XCB_GRAB_STATUS_ALREADY_GRABBED: u8 = 1;
+pub const XCB_GRAB_STATUS_INVALID_TIME: u8 = 2;
+pub const XCB_GRAB_STATUS_NOT_VIEWABLE: u8 = 3;
+pub const XCB_GRAB_STATUS_FROZEN: u8 = 4;
+pub const XCB_CURSOR_NONE: u32 = 0;
+pub const XCB_GRAB_POINTER: u8 = 26;
+pub const XCB_UNGRAB_POINTER: u8 = 27;
+pub const XCB_BUTTON_INDEX_ANY: u8 = 0;
+pub const XCB_BUTTON_INDEX_1: u8 = 1;
+pub const XCB_BUTTON_INDEX_2: u8 = 2;
+pub const XCB_BUTTON_INDEX_3: u8 = 3;
+pub const XCB_BUTTON_INDEX_4: u8 = 4;
+pub const XCB_BUTTON_INDEX_5: u8 = 5;
+pub const XCB_GRAB_BUTTON: u8 = 28;
+pub const XCB_UNGRAB_BUTTON: u8 = 29;
+pub const XCB_CHANGE_ACTIVE_POINTER_GRAB: u8 = 30;
+pub const XCB_GRAB_KEYBOARD: u8 = 31;
+pub const XCB_UNGRAB_KEYBOARD: u8 = 32;
+pub const XCB_GRAB_ANY: u8 = 0;
+pub const XCB_GRAB_KEY: u8 = 33;
+pub const XCB_UNGRAB_KEY: u8 = 34;
+pub const XCB_ALLOW_ASYNC_POINTER: u8 = 0;
+pub const XCB_ALLOW_SYNC_POINTER: u8 = 1;
+pub const XCB_ALLOW_REPLAY_POINTER: u8 = 2;
+pub const XCB_ALLOW_ASYNC_KEYBOARD: u8 = 3;
+pub const XCB_ALLOW_SYNC_KEYBOARD: u8 = 4;
+pub const XCB_ALLOW_REPLAY_KEYBOARD: u8 = 5;
+pub const XCB_ALLOW_ASYNC_BOTH: u8 = 6;
+pub const XCB_ALLOW_SYNC_BOTH: u8 = 7;
+pub const XCB_ALLOW_EVENTS: u8 = 35;
+pub const XCB_GRAB_SERVER: u8 = 36;
+pub const XCB_UNGRAB_SERVER: u8 = 37;
+pub const XCB_QUERY_POINTER: u8 = 38;
+pub const XCB_GET_MOTION_EVENTS: u8 = 39;
+pub const XCB_TRANSLATE_COORDINATES: u8 = 40;
+pub const XCB_WARP_POINTER: u8 = 41;
+pub const XCB_INPUT_FOCUS_NONE: u32 = 0;
+pub const XCB_INPUT_FOCUS_POINTER_ROOT: u32 = 1;
+pub const XCB_INPUT_FOCUS_PARENT: u32 = 2;
+pub const XCB_INPUT_FOCUS_FOLLOW_KEYBOARD: u32 = 3;
+pub const XCB_SET_INPUT_FOCUS: u8 = 42;
+pub const XCB_GET_INPUT_FOCUS: u8 = 43;
+pub const XCB_QUERY_KEYMAP: u8 = 44;
+pub const XCB_OPEN_FONT: u8 = 45;
+pub const XCB_CLOSE_FONT: u8 = 46;
+pub const XCB_FONT_DRAW_LEFT_TO_RIGHT: u8 = 0;
+pub const XCB_FONT_DRAW_RIGHT_TO_LEFT: u8 = 1;
+pub const XCB_QUERY_FONT: u8 = 47;
+pub const XCB_QUERY_TEXT_EXTENTS: u8 = 48;
+pub const XCB_LIST_FONTS: u8 = 49;
+pub const XCB_LIST_FONTS_WITH_INFO: u8 = 50;
+pub const XCB_SET_FONT_PATH: u8 = 51;
+pub const XCB_GET_FONT_PATH: u8 = 52;
+pub const XCB_CREATE_PIXMAP: u8 = 53;
+pub const XCB_FREE_PIXMAP: u8 = 54;
+pub const XCB_GC_FUNCTION: u32 = 1;
+pub const XCB_GC_PLANE_MASK: u32 = 2;
+pub const XCB_GC_FOREGROUND: u32 = 4;
+pub const XCB_GC_BACKGROUND: u32 = 8;
+pub const XCB_GC_LINE_WIDTH: u32 = 16;
+pub const XCB_GC_LINE_STYLE: u32 = 32;
+pub const XCB_GC_CAP_STYLE: u32 = 64;
+pub const XCB_GC_JOIN_STYLE: u32 = 128;
+pub const XCB_GC_FILL_STYLE: u32 = 256;
+pub const XCB_GC_FILL_RULE: u32 = 512;
+pub const XCB_GC_TILE: u32 = 1024;
+pub const XCB_GC_STIPPLE: u32 = 2048;
+pub const XCB_GC_TILE_STIPPLE_ORIGIN_X: u32 = 4096;
+pub const XCB_GC_TILE_STIPPLE_ORIGIN_Y: u32 = 8192;
+pub const XCB_GC_FONT: u32 = 16384;
+pub const XCB_GC_SUBWINDOW_MODE: u32 = 32768;
+pub const XCB_GC_GRAPHICS_EXPOSURES: u32 = 65536;
+pub const XCB_GC_CLIP_ORIGIN_X: u32 = 131072;
+pub const XCB_GC_CLIP_ORIGIN_Y: u32 = 262144;
+pub const XCB_GC_CLIP_MASK: u32 = 524288;
+pub const XCB_GC_DASH_OFFSET: u32 = 1048576;
+pub const XCB_GC_DASH_LIST: u32 = 2097152;
+pub const XCB_GC_ARC_MODE: u32 = 4194304;
+pub const XCB_GX_CLEAR: u32 = 0;
+pub const XCB_GX_AND: u32 = 1;
+pub const XCB_GX_AND_REVERSE: u32 = 2;
+pub const XCB_GX_COPY: u32 = 3;
+pub const XCB_GX_AND_INVERTED: u32 = 4;
+pub const XCB_GX_NOOP: u32 = 5;
+pub const XCB_GX_XOR: u32 = 6;
+pub const XCB_GX_OR: u32 = 7;
+pub const XCB_GX_NOR: u32 = 8;
+pub const XCB_GX_EQUIV: u32 = 9;
+pub const XCB_GX_INVERT: u32 = 10;
+pub const XCB_GX_OR_REVERSE: u32 = 11;
+pub const XCB_GX_COPY_INVERTED: u32 = 12;
+pub const XCB_GX_OR_INVERTED: u32 = 13;
+pub const XCB_GX_NAND: u32 = 14;
+pub const XCB_GX_SET: u32 = 15;
+pub const XCB_LINE_STYLE_SOLID: u32 = 0;
+pub const XCB_LINE_STYLE_ON_OFF_DASH: u32 = 1;
+pub const XCB_LINE_STYLE_DOUBLE_DASH: u32 = 2;
+pub const XCB_CAP_STYLE_NOT_LAST: u32 = 0;
+pub const XCB_CAP_STYLE_BUTT: u32 = 1;
+pub const XCB_CAP_STYLE_ROUND: u32 = 2;
+pub const XCB_CAP_STYLE_PROJECTING: u32 = 3;
+pub const XCB_JOIN_STYLE_MITER: u32 = 0;
+pub const XCB_JOIN_STYLE_ROUND: u32 = 1;
+pub const XCB_JOIN_STYLE_BEVEL: u32 = 2;
+pub const XCB_FILL_STYLE_SOLID: u32 = 0;
+pub const XCB_FILL_STYLE_TILED: u32 = 1;
+pub const XCB_FILL_STYLE_STIPPLED: u32 = 2;
+pub const XCB_FILL_STYLE_OPAQUE_STIPPLED: u32 = 3;
+pub const XCB_FILL_RULE_EVEN_ODD: u32 = 0;
+pub const XCB_FILL_RULE_WINDING: u32 = 1;
+pub const XCB_SUBWINDOW_MODE_CLIP_BY_CHILDREN: u32 = 0;
+pub const XCB_SUBWINDOW_MODE_INCLUDE_INFERIORS: u32 = 1;
+pub const XCB_ARC_MODE_CHORD: u32 = 0;
+pub const XCB_ARC_MODE_PIE_SLICE: u32 = 1;
+pub const XCB_CREATE_GC: u8 = 55;
+pub const XCB_CHANGE_GC: u8 = 56;
+pub const XCB_COPY_GC: u8 = 57;
+pub const XCB_SET_DASHES: u8 = 58;
+pub const XCB_CLIP_ORDERING_UNSORTED: u8 = 0;
+pub const XCB_CLIP_ORDERING_Y_SORTED: u8 = 1;
+pub const XCB_CLIP_ORDERING_YX_SORTED: u8 = 2;
+pub const XCB_CLIP_ORDERING_YX_BANDED: u8 = 3;
+pub const XCB_SET_CLIP_RECTANGLES: u8 = 59;
+pub const XCB_FREE_GC: u8 = 60;
+pub const XCB_CLEAR_AREA: u8 = 61;
+pub const XCB_COPY_AREA: u8 = 62;
+pub const XCB_COPY_PLANE: u8 = 63;
+pub const XCB_COORD_MODE_ORIGIN: u8 = 0;
+pub const XCB_COORD_MODE_PREVIOUS: u8 = 1;
+pub const XCB_POLY_POINT: u8 = 64;
+pub const XCB_POLY_LINE: u8 = 65;
+pub const XCB_POLY_SEGMENT: u8 = 66;
+pub const XCB_POLY_RECTANGLE: u8 = 67;
+pub const XCB_POLY_ARC: u8 = 68;
+pub const XCB_POLY_SHAPE_COMPLEX: u8 = 0;
+pub const XCB_POLY_SHAPE_NONCONVEX: u8 = 1;
+pub const XCB_POLY_SHAPE_CONVEX: u8 = 2;
+pub const XCB_FILL_POLY: u8 = 69;
+pub const XCB_POLY_FILL_RECTANGLE: u8 = 70;
+pub const XCB_POLY_FILL_ARC: u8 = 71;
+pub const XCB_IMAGE_FORMAT_XY_BITMAP: u8 = 0;
+pub const XCB_IMAGE_FORMAT_XY_PIXMAP: u8 = 1;
+pub const XCB_IMAGE_FORMAT_Z_PIXMAP: u8 = 2;
+pub const XCB_PUT_IMAGE: u8 = 72;
+pub const XCB_GET_IMAGE: u8 = 73;
+pub const XCB_POLY_TEXT_8: u8 = 74;
+pub const XCB_POLY_TEXT_16: u8 = 75;
+pub const XCB_IMAGE_TEXT_8: u8 = 76;
+pub const XCB_IMAGE_TEXT_16: u8 = 77;
+pub const XCB_COLORMAP_ALLOC_NONE: u8 = 0;
+pub const XCB_COLORMAP_ALLOC_ALL: u8 = 1;
+pub const XCB_CREATE_COLORMAP: u8 = 78;
+pub const XCB_FREE_COLORMAP: u8 = 79;
+pub const XCB_COPY_COLORMAP_AND_FREE: u8 = 80;
+pub const XCB_INSTALL_COLORMAP: u8 = 81;
+pub const XCB_UNINSTALL_COLORMAP: u8 = 82;
+pub const XCB_LIST_INSTALLED_COLORMAPS: u8 = 83;
+pub const XCB_ALLOC_COLOR: u8 = 84;
+pub const XCB_ALLOC_NAMED_COLOR: u8 = 85;
+pub const XCB_ALLOC_COLOR_CELLS: u8 = 86;
+pub const XCB_ALLOC_COLOR_PLANES: u8 = 87;
+pub const XCB_FREE_COLORS: u8 = 88;
+pub const XCB_COLOR_FLAG_RED: u8 = 1;
+pub const XCB_COLOR_FLAG_GREEN: u8 = 2;
+pub const XCB_COLOR_FLAG_BLUE: u8 = 4;
+pub const XCB_STORE_COLORS: u8 = 89;
+pub const XCB_STORE_NAMED_COLOR: u8 = 90;
+pub const XCB_QUERY_COLORS: u8 = 91;
+pub const XCB_LOOKUP_COLOR: u8 = 92;
+pub const XCB_PIXMAP_NONE: u32 = 0;
+pub const XCB_CREATE_CURSOR: u8 = 93;
+pub const XCB_FONT_NONE: u32 = 0;
+pub const XCB_CREATE_GLYPH_CURSOR: u8 = 94;
+pub const XCB_FREE_CURSOR: u8 = 95;
+pub const XCB_RECOLOR_CURSOR: u8 = 96;
+pub const XCB_QUERY_SHAPE_OF_LARGEST_CURSOR: u8 = 0;
+pub const XCB_QUERY_SHAPE_OF_FASTEST_TILE: u8 = 1;
+pub const XCB_QUERY_SHAPE_OF_FASTEST_STIPPLE: u8 = 2;
+pub const XCB_QUERY_BEST_SIZE: u8 = 97;
+pub const XCB_QUERY_EXTENSION: u8 = 98;
+pub const XCB_LIST_EXTENSIONS: u8 = 99;
+pub const XCB_CHANGE_KEYBOARD_MAPPING: u8 = 100;
+pub const XCB_GET_KEYBOARD_MAPPING: u8 = 101;
+pub const XCB_KB_KEY_CLICK_PERCENT: u32 = 1;
+pub const XCB_KB_BELL_PERCENT: u32 = 2;
+pub const XCB_KB_BELL_PITCH: u32 = 4;
+pub const XCB_KB_BELL_DURATION: u32 = 8;
+pub const XCB_KB_LED: u32 = 16;
+pub const XCB_KB_LED_MODE: u32 = 32;
+pub const XCB_KB_KEY: u32 = 64;
+pub const XCB_KB_AUTO_REPEAT_MODE: u32 = 128;
+pub const XCB_LED_MODE_OFF: u32 = 0;
+pub const XCB_LED_MODE_ON: u32 = 1;
+pub const XCB_AUTO_REPEAT_MODE_OFF: u32 = 0;
+pub const XCB_AUTO_REPEAT_MODE_ON: u32 = 1;
+pub const XCB_AUTO_REPEAT_MODE_DEFAULT: u32 = 2;
+pub const XCB_CHANGE_KEYBOARD_CONTROL: u8 = 102;
+pub const XCB_GET_KEYBOARD_CONTROL: u8 = 103;
+pub const XCB_BELL: u8 = 104;
+pub const XCB_CHANGE_POINTER_CONTROL: u8 = 105;
+pub const XCB_GET_POINTER_CONTROL: u8 = 106;
+pub const XCB_BLANKING_NOT_PREFERRED: u8 = 0;
+pub const XCB_BLANKING_PREFERRED: u8 = 1;
+pub const XCB_BLANKING_DEFAULT: u8 = 2;
+pub const XCB_EXPOSURES_NOT_ALLOWED: u8 = 0;
+pub const XCB_EXPOSURES_ALLOWED: u8 = 1;
+pub const XCB_EXPOSURES_DEFAULT: u8 = 2;
+pub const XCB_SET_SCREEN_SAVER: u8 = 107;
+pub const XCB_GET_SCREEN_SAVER: u8 = 108;
+pub const XCB_HOST_MODE_INSERT: u8 = 0;
+pub const XCB_HOST_MODE_DELETE: u8 = 1;
+pub const XCB_FAMILY_INTERNET: u8 = 0;
+pub const XCB_FAMILY_DECNET: u8 = 1;
+pub const XCB_FAMILY_CHAOS: u8 = 2;
+pub const XCB_FAMILY_SERVER_INTERPRETED: u8 = 5;
+pub const XCB_FAMILY_INTERNET_6: u8 = 6;
+pub const XCB_CHANGE_HOSTS: u8 = 109;
+pub const XCB_LIST_HOSTS: u8 = 110;
+pub const XCB_ACCESS_CONTROL_DISABLE: u8 = 0;
+pub const XCB_ACCESS_CONTROL_ENABLE: u8 = 1;
+pub const XCB_SET_ACCESS_CONTROL: u8 = 111;
+pub const XCB_CLOSE_DOWN_DESTROY_ALL: u8 = 0;
+pub const XCB_CLOSE_DOWN_RETAIN_PERMANENT: u8 = 1;
+pub const XCB_CLOSE_DOWN_RETAIN_TEMPORARY: u8 = 2;
+pub const XCB_SET_CLOSE_DOWN_MODE: u8 = 112;
+pub const XCB_KILL_ALL_TEMPORARY: u32 = 0;
+pub const XCB_KILL_CLIENT: u8 = 113;
+pub const XCB_ROTATE_PROPERTIES: u8 = 114;
+pub const XCB_SCREEN_SAVER_RESET: u8 = 0;
+pub const XCB_SCREEN_SAVER_ACTIVE: u8 = 1;
+pub const XCB_FORCE_SCREEN_SAVER: u8 = 115;
+pub const XCB_MAPPING_STATUS_SUCCESS: u8 = 0;
+pub const XCB_MAPPING_STATUS_BUSY: u8 = 1;
+pub const XCB_MAPPING_STATUS_FAILURE: u8 = 2;
+pub const XCB_SET_POINTER_MAPPING: u8 = 116;
+pub const XCB_GET_POINTER_MAPPING: u8 = 117;
+pub const XCB_MAP_INDEX_SHIFT: u32 = 0;
+pub const XCB_MAP_INDEX_LOCK: u32 = 1;
+pub const XCB_MAP_INDEX_CONTROL: u32 = 2;
+pub const XCB_MAP_INDEX_1: u32 = 3;
+pub const XCB_MAP_INDEX_2: u32 = 4;
+pub const XCB_MAP_INDEX_3: u32 = 5;
+pub const XCB_MAP_INDEX_4: u32 = 6;
+pub const XCB_MAP_INDEX_5: u32 = 7;
+pub const XCB_SET_MODIFIER_MAPPING: u8 = 118;
+pub const XCB_GET_MODIFIER_MAPPING: u8 = 119;
+pub const XCB_NO_OPERATION: u8 = 127;
@@ -0,0 +1,2790 @@
+// Generated by xcbgen from xml/xproto.xml, do not edit.
+
+//! Requests whose parameters the safe wrappers cannot express only have raw bindings
+//! in `cdef`:
+//!
+//! - `xcb_set_font_path`
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::os::raw::{c_char, c_void};
+use std::slice;
+
+use {Reply, XCBConnection, XCBCookie, XCBGenericError, XCBGenericEvent, XCBVoidCookie};
+
+pub type XCBChar2b = cdef::XCBChar2b;
+pub type XCBChar2bIterator = cdef::XCBChar2bIterator;
+pub type XCBWindow = cdef::XCBWindow;
+pub type XCBWindowIterator = cdef::XCBWindowIterator;
+pub type XCBPixmap = cdef::XCBPixmap;
+pub type XCBPixmapIterator = cdef::XCBPixmapIterator;
+pub type XCBCursor = cdef::XCBCursor;
+pub type XCBCursorIterator = cdef::XCBCursorIterator;
+pub type XCBFont = cdef::XCBFont;
+pub type XCBFontIterator = cdef::XCBFontIterator;
+pub type XCBGcontext = cdef::XCBGcontext;
+pub type XCBGcontextIterator = cdef::XCBGcontextIterator;
+pub type XCBColormap = cdef::XCBColormap;
+pub type XCBColormapIterator = cdef::XCBColormapIterator;
+pub type XCBAtom = cdef::XCBAtom;
+pub type XCBAtomIterator = cdef::XCBAtomIterator;
+pub type XCBDrawable = cdef::XCBDrawable;
+pub type XCBDrawableIterator = cdef::XCBDrawableIterator;
+pub type XCBFontable = cdef::XCBFontable;
+pub type XCBFontableIterator = cdef::XCBFontableIterator;
+pub type XCBBool32 = cdef::XCBBool32;
+pub type XCBBool32Iterator = cdef::XCBBool32Iterator;
+pub type XCBVisualid = cdef::XCBVisualid;
+pub type XCBVisualidIterator = cdef::XCBVisualidIterator;
+pub type XCBTimestamp = cdef::XCBTimestamp;
+pub type XCBTimestampIterator = cdef::XCBTimestampIterator;
+pub type XCBKeysym = cdef::XCBKeysym;
+pub type XCBKeysymIterator = cdef::XCBKeysymIterator;
+pub type XCBKeycode = cdef::XCBKeycode;
+pub type XCBKeycodeIterator = cdef::XCBKeycodeIterator;
+pub type XCBKeycode32 = cdef::XCBKeycode32;
+pub type XCBKeycode32Iterator = cdef::XCBKeycode32Iterator;
+pub type XCBButton = cdef::XCBButton;
+pub type XCBButtonIterator = cdef::XCBButtonIterator;
+pub type XCBPoint = cdef::XCBPoint;
+pub type XCBPointIterator = cdef::XCBPointIterator;
+pub type XCBRectangle = cdef::XCBRectangle;
+pub type XCBRectangleIterator = cdef::XCBRectangleIterator;
+pub type XCBArc = cdef::XCBArc;
+pub type XCBArcIterator = cdef::XCBArcIterator;
+pub type XCBFormat = cdef::XCBFormat;
+pub type XCBFormatIterator = cdef::XCBFormatIterator;
+pub type XCBVisualtype = cdef::XCBVisualtype;
+pub type XCBVisualtypeIterator = cdef::XCBVisualtypeIterator;
+pub type XCBDepth = cdef::XCBDepth;
+pub type XCBDepthIterator = cdef::XCBDepthIterator;
+pub type XCBScreen = cdef::XCBScreen;
+pub type XCBScreenIterator = cdef::XCBScreenIterator;
+pub type XCBSetupRequest = cdef::XCBSetupRequest;
+pub type XCBSetupRequestIterator = cdef::XCBSetupRequestIterator;
+pub type XCBSetupFailed = cdef::XCBSetupFailed;
+pub type XCBSetupFailedIterator = cdef::XCBSetupFailedIterator;
+pub type XCBSetupAuthenticate = cdef::XCBSetupAuthenticate;
+pub type XCBSetupAuthenticateIterator = cdef::XCBSetupAuthenticateIterator;
+pub type XCBSetup = cdef::XCBSetup;
+pub type XCBSetupIterator = cdef::XCBSetupIterator;
+pub type XCBKeyPressEvent = cdef::XCBKeyPressEvent;
+pub type XCBKeyReleaseEvent = cdef::XCBKeyReleaseEvent;
+pub type XCBButtonPressEvent = cdef::XCBButtonPressEvent;
+pub type XCBButtonReleaseEvent = cdef::XCBButtonReleaseEvent;
+pub type XCBMotionNotifyEvent = cdef::XCBMotionNotifyEvent;
+pub type XCBEnterNotifyEvent = cdef::XCBEnterNotifyEvent;
+pub type XCBLeaveNotifyEvent = cdef::XCBLeaveNotifyEvent;
+pub type XCBFocusInEvent = cdef::XCBFocusInEvent;
+pub type XCBFocusOutEvent = cdef::XCBFocusOutEvent;
+pub type XCBKeymapNotifyEvent = cdef::XCBKeymapNotifyEvent;
+pub type XCBExposeEvent = cdef::XCBExposeEvent;
+pub type XCBGraphicsExposureEvent = cdef::XCBGraphicsExposureEvent;
+pub type XCBNoExposureEvent = cdef::XCBNoExposureEvent;
+pub type XCBVisibilityNotifyEvent = cdef::XCBVisibilityNotifyEvent;
+pub type XCBCreateNotifyEvent = cdef::XCBCreateNotifyEvent;
+pub type XCBDestroyNotifyEvent = cdef::XCBDestroyNotifyEvent;
+pub type XCBUnmapNotifyEvent = cdef::XCBUnmapNotifyEvent;
+pub type XCBMapNotifyEvent = cdef::XCBMapNotifyEvent;
+pub type XCBMapRequestEvent = cdef::XCBMapRequestEvent;
+pub type XCBReparentNotifyEvent = cdef::XCBReparentNotifyEvent;
+pub type XCBConfigureNotifyEvent = cdef::XCBConfigureNotifyEvent;
+pub type XCBConfigureRequestEvent = cdef::XCBConfigureRequestEvent;
+pub type XCBGravityNotifyEvent = cdef::XCBGravityNotifyEvent;
+pub type XCBResizeRequestEvent = cdef::XCBResizeRequestEvent;
+pub type XCBCirculateNotifyEvent = cdef::XCBCirculateNotifyEvent;
+pub type XCBCirculateRequestEvent = cdef::XCBCirculateRequestEvent;
+pub type XCBPropertyNotifyEvent = cdef::XCBPropertyNotifyEvent;
+pub type XCBSelectionClearEvent = cdef::XCBSelectionClearEvent;
+pub type XCBSelectionRequestEvent = cdef::XCBSelectionRequestEvent;
+pub type XCBSelectionNotifyEvent = cdef::XCBSelectionNotifyEvent;
+pub type XCBColormapNotifyEvent = cdef::XCBColormapNotifyEvent;
+pub type XCBClientMessageData = cdef::XCBClientMessageData;
+pub type XCBClientMessageDataIterator = cdef::XCBClientMessageDataIterator;
+pub type XCBClientMessageEvent = cdef::XCBClientMessageEvent;
+pub type XCBMappingNotifyEvent = cdef::XCBMappingNotifyEvent;
+pub type XCBGeGenericEvent = cdef::XCBGeGenericEvent;
+pub type XCBRequestError = cdef::XCBRequestError;
+pub type XCBValueError = cdef::XCBValueError;
+pub type XCBWindowError = cdef::XCBWindowError;
+pub type XCBPixmapError = cdef::XCBPixmapError;
+pub type XCBAtomError = cdef::XCBAtomError;
+pub type XCBCursorError = cdef::XCBCursorError;
+pub type XCBFontError = cdef::XCBFontError;
+pub type XCBMatchError = cdef::XCBMatchError;
+pub type XCBDrawableError = cdef::XCBDrawableError;
+pub type XCBAccessError = cdef::XCBAccessError;
+pub type XCBAllocError = cdef::XCBAllocError;
+pub type XCBColormapError = cdef::XCBColormapError;
+pub type XCBGContextError = cdef::XCBGContextError;
+pub type XCBIdChoiceError = cdef::XCBIdChoiceError;
+pub type XCBNameError = cdef::XCBNameError;
+pub type XCBLengthError = cdef::XCBLengthError;
+pub type XCBImplementationError = cdef::XCBImplementationError;
+pub type XCBCreateWindowRequest = cdef::XCBCreateWindowRequest;
+pub type XCBChangeWindowAttributesRequest = cdef::XCBChangeWindowAttributesRequest;
+pub type XCBGetWindowAttributesRequest = cdef::XCBGetWindowAttributesRequest;
+pub type XCBGetWindowAttributesCookie = cdef::XCBGetWindowAttributesCookie;
+pub type XCBGetWindowAttributesReply = cdef::XCBGetWindowAttributesReply;
+pub type XCBDestroyWindowRequest = cdef::XCBDestroyWindowRequest;
+pub type XCBDestroySubwindowsRequest = cdef::XCBDestroySubwindowsRequest;
+pub type XCBChangeSaveSetRequest = cdef::XCBChangeSaveSetRequest;
+pub type XCBReparentWindowRequest = cdef::XCBReparentWindowRequest;
+pub type XCBMapWindowRequest = cdef::XCBMapWindowRequest;
+pub type XCBMapSubwindowsRequest = cdef::XCBMapSubwindowsRequest;
+pub type XCBUnmapWindowRequest = cdef::XCBUnmapWindowRequest;
+pub type XCBUnmapSubwindowsRequest = cdef::XCBUnmapSubwindowsRequest;
+pub type XCBConfigureWindowRequest = cdef::XCBConfigureWindowRequest;
+pub type XCBCirculateWindowRequest = cdef::XCBCirculateWindowRequest;
+pub type XCBGetGeometryRequest = cdef::XCBGetGeometryRequest;
+pub type XCBGetGeometryCookie = cdef::XCBGetGeometryCookie;
+pub type XCBGetGeometryReply = cdef::XCBGetGeometryReply;
+pub type XCBQueryTreeRequest = cdef::XCBQueryTreeRequest;
+pub type XCBQueryTreeCookie = cdef::XCBQueryTreeCookie;
+pub type XCBQueryTreeReply = cdef::XCBQueryTreeReply;
+pub type XCBInternAtomRequest = cdef::XCBInternAtomRequest;
+pub type XCBInternAtomCookie = cdef::XCBInternAtomCookie;
+pub type XCBInternAtomReply = cdef::XCBInternAtomReply;
+pub type XCBGetAtomNameRequest = cdef::XCBGetAtomNameRequest;
+pub type XCBGetAtomNameCookie = cdef::XCBGetAtomNameCookie;
+pub type XCBGetAtomNameReply = cdef::XCBGetAtomNameReply;
+pub type XCBChangePropertyRequest = cdef::XCBChangePropertyRequest;
+pub type XCBDeletePropertyRequest = cdef::XCBDeletePropertyRequest;
+pub type XCBGetPropertyRequest = cdef::XCBGetPropertyRequest;
+pub type XCBGetPropertyCookie = cdef::XCBGetPropertyCookie;
+pub type XCBGetPropertyReply = cdef::XCBGetPropertyReply;
+pub type XCBListPropertiesRequest = cdef::XCBListPropertiesRequest;
+pub type XCBListPropertiesCookie = cdef::XCBListPropertiesCookie;
+pub type XCBListPropertiesReply = cdef::XCBListPropertiesReply;
+pub type XCBSetSelectionOwnerRequest = cdef::XCBSetSelectionOwnerRequest;
+pub type XCBGetSelectionOwnerRequest = cdef::XCBGetSelectionOwnerRequest;
+pub type XCBGetSelectionOwnerCookie = cdef::XCBGetSelectionOwnerCookie;
+pub type XCBGetSelectionOwnerReply = cdef::XCBGetSelectionOwnerReply;
+pub type XCBConvertSelectionRequest = cdef::XCBConvertSelectionRequest;
+pub type XCBSendEventRequest = cdef::XCBSendEventRequest;
+pub type XCBGrabPointerRequest = cdef::XCBGrabPointerRequest;
+pub type XCBGrabPointerCookie = cdef::XCBGrabPointerCookie;
+pub type XCBGrabPointerReply = cdef::XCBGrabPointerReply;
+pub type XCBUngrabPointerRequest = cdef::XCBUngrabPointerRequest;
+pub type XCBGrabButtonRequest = cdef::XCBGrabButtonRequest;
+pub type XCBUngrabButtonRequest = cdef::XCBUngrabButtonRequest;
+pub type XCBChangeActivePointerGrabRequest = cdef::XCBChangeActivePointerGrabRequest;
+pub type XCBGrabKeyboardRequest = cdef::XCBGrabKeyboardRequest;
+pub type XCBGrabKeyboardCookie = cdef::XCBGrabKeyboardCookie;
+pub type XCBGrabKeyboardReply = cdef::XCBGrabKeyboardReply;
+pub type XCBUngrabKeyboardRequest = cdef::XCBUngrabKeyboardRequest;
+pub type XCBGrabKeyRequest = cdef::XCBGrabKeyRequest;
+pub type XCBUngrabKeyRequest = cdef::XCBUngrabKeyRequest;
+pub type XCBAllowEventsRequest = cdef::XCBAllowEventsRequest;
+pub type XCBGrabServerRequest = cdef::XCBGrabServerRequest;
+pub type XCBUngrabServerRequest = cdef::XCBUngrabServerRequest;
+pub type XCBQueryPointerRequest = cdef::XCBQueryPointerRequest;
+pub type XCBQueryPointerCookie = cdef::XCBQueryPointerCookie;
+pub type XCBQueryPointerReply = cdef::XCBQueryPointerReply;
+pub type XCBTimecoord = cdef::XCBTimecoord;
+pub type XCBTimecoordIterator = cdef::XCBTimecoordIterator;
+pub type XCBGetMotionEventsRequest = cdef::XCBGetMotionEventsRequest;
+pub type XCBGetMotionEventsCookie = cdef::XCBGetMotionEventsCookie;
+pub type XCBGetMotionEventsReply = cdef::XCBGetMotionEventsReply;
+pub type XCBTranslateCoordinatesRequest = cdef::XCBTranslateCoordinatesRequest;
+pub type XCBTranslateCoordinatesCookie = cdef::XCBTranslateCoordinatesCookie;
+pub type XCBTranslateCoordinatesReply = cdef::XCBTranslateCoordinatesReply;
+pub type XCBWarpPointerRequest = cdef::XCBWarpPointerRequest;
+pub type XCBSetInputFocusRequest = cdef::XCBSetInputFocusRequest;
+pub type XCBGetInputFocusRequest = cdef::XCBGetInputFocusRequest;
+pub type XCBGetInputFocusCookie = cdef::XCBGetInputFocusCookie;
+pub type XCBGetInputFocusReply = cdef::XCBGetInputFocusReply;
+pub type XCBQueryKeymapRequest = cdef::XCBQueryKeymapRequest;
+pub type XCBQueryKeymapCookie = cdef::XCBQueryKeymapCookie;
+pub type XCBQueryKeymapReply = cdef::XCBQueryKeymapReply;
+pub type XCBOpenFontRequest = cdef::XCBOpenFontRequest;
+pub type XCBCloseFontRequest = cdef::XCBCloseFontRequest;
+pub type XCBFontprop = cdef::XCBFontprop;
+pub type XCBFontpropIterator = cdef::XCBFontpropIterator;
+pub type XCBCharinfo = cdef::XCBCharinfo;
+pub type XCBCharinfoIterator = cdef::XCBCharinfoIterator;
+pub type XCBQueryFontRequest = cdef::XCBQueryFontRequest;
+pub type XCBQueryFontCookie = cdef::XCBQueryFontCookie;
+pub type XCBQueryFontReply = cdef::XCBQueryFontReply;
+pub type XCBQueryTextExtentsRequest = cdef::XCBQueryTextExtentsRequest;
+pub type XCBQueryTextExtentsCookie = cdef::XCBQueryTextExtentsCookie;
+pub type XCBQueryTextExtentsReply = cdef::XCBQueryTextExtentsReply;
+pub type XCBStr = cdef::XCBStr;
+pub type XCBStrIterator = cdef::XCBStrIterator;
+pub type XCBListFontsRequest = cdef::XCBListFontsRequest;
+pub type XCBListFontsCookie = cdef::XCBListFontsCookie;
+pub type XCBListFontsReply = cdef::XCBListFontsReply;
+pub type XCBListFontsWithInfoRequest = cdef::XCBListFontsWithInfoRequest;
+pub type XCBListFontsWithInfoCookie = cdef::XCBListFontsWithInfoCookie;
+pub type XCBListFontsWithInfoReply = cdef::XCBListFontsWithInfoReply;
+pub type XCBSetFontPathRequest = cdef::XCBSetFontPathRequest;
+pub type XCBGetFontPathRequest = cdef::XCBGetFontPathRequest;
+pub type XCBGetFontPathCookie = cdef::XCBGetFontPathCookie;
+pub type XCBGetFontPathReply = cdef::XCBGetFontPathReply;
+pub type XCBCreatePixmapRequest = cdef::XCBCreatePixmapRequest;
+pub type XCBFreePixmapRequest = cdef::XCBFreePixmapRequest;
+pub type XCBCreateGcRequest = cdef::XCBCreateGcRequest;
+pub type XCBChangeGcRequest = cdef::XCBChangeGcRequest;
+pub type XCBCopyGcRequest = cdef::XCBCopyGcRequest;
+pub type XCBSetDashesRequest = cdef::XCBSetDashesRequest;
+pub type XCBSetClipRectanglesRequest = cdef::XCBSetClipRectanglesRequest;
+pub type XCBFreeGcRequest = cdef::XCBFreeGcRequest;
+pub type XCBClearAreaRequest = cdef::XCBClearAreaRequest;
+pub type XCBCopyAreaRequest = cdef::XCBCopyAreaRequest;
+pub type XCBCopyPlaneRequest = cdef::XCBCopyPlaneRequest;
+pub type XCBPolyPointRequest = cdef::XCBPolyPointRequest;
+pub type XCBPolyLineRequest = cdef::XCBPolyLineRequest;
+pub type XCBSegment = cdef::XCBSegment;
+pub type XCBSegmentIterator = cdef::XCBSegmentIterator;
+pub type XCBPolySegmentRequest = cdef::XCBPolySegmentRequest;
+pub type XCBPolyRectangleRequest = cdef::XCBPolyRectangleRequest;
+pub type XCBPolyArcRequest = cdef::XCBPolyArcRequest;
+pub type XCBFillPolyRequest = cdef::XCBFillPolyRequest;
+pub type XCBPolyFillRectangleRequest = cdef::XCBPolyFillRectangleRequest;
+pub type XCBPolyFillArcRequest = cdef::XCBPolyFillArcRequest;
+pub type XCBPutImageRequest = cdef::XCBPutImageRequest;
+pub type XCBGetImageRequest = cdef::XCBGetImageRequest;
+pub type XCBGetImageCookie = cdef::XCBGetImageCookie;
+pub type XCBGetImageReply = cdef::XCBGetImageReply;
+pub type XCBPolyText8Request = cdef::XCBPolyText8Request;
+pub type XCBPolyText16Request = cdef::XCBPolyText16Request;
+pub type XCBImageText8Request = cdef::XCBImageText8Request;
+pub type XCBImageText16Request = cdef::XCBImageText16Request;
+pub type XCBCreateColormapRequest = cdef::XCBCreateColormapRequest;
+pub type XCBFreeColormapRequest = cdef::XCBFreeColormapRequest;
+pub type XCBCopyColormapAndFreeRequest = cdef::XCBCopyColormapAndFreeRequest;
+pub type XCBInstallColormapRequest = cdef::XCBInstallColormapRequest;
+pub type XCBUninstallColormapRequest = cdef::XCBUninstallColormapRequest;
+pub type XCBListInstalledColormapsRequest = cdef::XCBListInstalledColormapsRequest;
+pub type XCBListInstalledColormapsCookie = cdef::XCBListInstalledColormapsCookie;
+pub type XCBListInstalledColormapsReply = cdef::XCBListInstalledColormapsReply;
+pub type XCBAllocColorRequest = cdef::XCBAllocColorRequest;
+pub type XCBAllocColorCookie = cdef::XCBAllocColorCookie;
+pub type XCBAllocColorReply = cdef::XCBAllocColorReply;
+pub type XCBAllocNamedColorRequest = cdef::XCBAllocNamedColorRequest;
+pub type XCBAllocNamedColorCookie = cdef::XCBAllocNamedColorCookie;
+pub type XCBAllocNamedColorReply = cdef::XCBAllocNamedColorReply;
+pub type XCBAllocColorCellsRequest = cdef::XCBAllocColorCellsRequest;
+pub type XCBAllocColorCellsCookie = cdef::XCBAllocColorCellsCookie;
+pub type XCBAllocColorCellsReply = cdef::XCBAllocColorCellsReply;
+pub type XCBAllocColorPlanesRequest = cdef::XCBAllocColorPlanesRequest;
+pub type XCBAllocColorPlanesCookie = cdef::XCBAllocColorPlanesCookie;
+pub type XCBAllocColorPlanesReply = cdef::XCBAllocColorPlanesReply;
+pub type XCBFreeColorsRequest = cdef::XCBFreeColorsRequest;
+pub type XCBColoritem = cdef::XCBColoritem;
+pub type XCBColoritemIterator = cdef::XCBColoritemIterator;
+pub type XCBStoreColorsRequest = cdef::XCBStoreColorsRequest;
+pub type XCBStoreNamedColorRequest = cdef::XCBStoreNamedColorRequest;
+pub type XCBRgb = cdef::XCBRgb;
+pub type XCBRgbIterator = cdef::XCBRgbIterator;
+pub type XCBQueryColorsRequest = cdef::XCBQueryColorsRequest;
+pub type XCBQueryColorsCookie = cdef::XCBQueryColorsCookie;
+pub type XCBQueryColorsReply = cdef::XCBQueryColorsReply;
+pub type XCBLookupColorRequest = cdef::XCBLookupColorRequest;
+pub type XCBLookupColorCookie = cdef::XCBLookupColorCookie;
+pub type XCBLookupColorReply = cdef::XCBLookupColorReply;
+pub type XCBCreateCursorRequest = cdef::XCBCreateCursorRequest;
+pub type XCBCreateGlyphCursorRequest = cdef::XCBCreateGlyphCursorRequest;
+pub type XCBFreeCursorRequest = cdef::XCBFreeCursorRequest;
+pub type XCBRecolorCursorRequest = cdef::XCBRecolorCursorRequest;
+pub type XCBQueryBestSizeRequest = cdef::XCBQueryBestSizeRequest;
+pub type XCBQueryBestSizeCookie = cdef::XCBQueryBestSizeCookie;
+pub type XCBQueryBestSizeReply = cdef::XCBQueryBestSizeReply;
+pub type XCBQueryExtensionRequest = cdef::XCBQueryExtensionRequest;
+pub type XCBQueryExtensionCookie = cdef::XCBQueryExtensionCookie;
+pub type XCBQueryExtensionReply = cdef::XCBQueryExtensionReply;
+pub type XCBListExtensionsRequest = cdef::XCBListExtensionsRequest;
+pub type XCBListExtensionsCookie = cdef::XCBListExtensionsCookie;
+pub type XCBListExtensionsReply = cdef::XCBListExtensionsReply;
+pub type XCBChangeKeyboardMappingRequest = cdef::XCBChangeKeyboardMappingRequest;
+pub type XCBGetKeyboardMappingRequest = cdef::XCBGetKeyboardMappingRequest;
+pub type XCBGetKeyboardMappingCookie = cdef::XCBGetKeyboardMappingCookie;
+pub type XCBGetKeyboardMappingReply = cdef::XCBGetKeyboardMappingReply;
+pub type XCBChangeKeyboardControlRequest = cdef::XCBChangeKeyboardControlRequest;
+pub type XCBGetKeyboardControlRequest = cdef::XCBGetKeyboardControlRequest;
+pub type XCBGetKeyboardControlCookie = cdef::XCBGetKeyboardControlCookie;
+pub type XCBGetKeyboardControlReply = cdef::XCBGetKeyboardControlReply;
+pub type XCBBellRequest = cdef::XCBBellRequest;
+pub type XCBChangePointerControlRequest = cdef::XCBChangePointerControlRequest;
+pub type XCBGetPointerControlRequest = cdef::XCBGetPointerControlRequest;
+pub type XCBGetPointerControlCookie = cdef::XCBGetPointerControlCookie;
+pub type XCBGetPointerControlReply = cdef::XCBGetPointerControlReply;
+pub type XCBSetScreenSaverRequest = cdef::XCBSetScreenSaverRequest;
+pub type XCBGetScreenSaverRequest = cdef::XCBGetScreenSaverRequest;
+pub type XCBGetScreenSaverCookie = cdef::XCBGetScreenSaverCookie;
+pub type XCBGetScreenSaverReply = cdef::XCBGetScreenSaverReply;
+pub type XCBChangeHostsRequest = cdef::XCBChangeHostsRequest;
+pub type XCBHost = cdef::XCBHost;
+pub type XCBHostIterator = cdef::XCBHostIterator;
+pub type XCBListHostsRequest = cdef::XCBListHostsRequest;
+pub type XCBListHostsCookie = cdef::XCBListHostsCookie;
+pub type XCBListHostsReply = cdef::XCBListHostsReply;
+pub type XCBSetAccessControlRequest = cdef::XCBSetAccessControlRequest;
+pub type XCBSetCloseDownModeRequest = cdef::XCBSetCloseDownModeRequest;
+pub type XCBKillClientRequest = cdef::XCBKillClientRequest;
+pub type XCBRotatePropertiesRequest = cdef::XCBRotatePropertiesRequest;
+pub type XCBForceScreenSaverRequest = cdef::XCBForceScreenSaverRequest;
+pub type XCBSetPointerMappingRequest = cdef::XCBSetPointerMappingRequest;
+pub type XCBSetPointerMappingCookie = cdef::XCBSetPointerMappingCookie;
+pub type XCBSetPointerMappingReply = cdef::XCBSetPointerMappingReply;
+pub type XCBGetPointerMappingRequest = cdef::XCBGetPointerMappingRequest;
+pub type XCBGetPointerMappingCookie = cdef::XCBGetPointerMappingCookie;
+pub type XCBGetPointerMappingReply = cdef::XCBGetPointerMappingReply;
+pub type XCBSetModifierMappingRequest = cdef::XCBSetModifierMappingRequest;
+pub type XCBSetModifierMappingCookie = cdef::XCBSetModifierMappingCookie;
+pub type XCBSetModifierMappingReply = cdef::XCBSetModifierMappingReply;
+pub type XCBGetModifierMappingRequest = cdef::XCBGetModifierMappingRequest;
+pub type XCBGetModifierMappingCookie = cdef::XCBGetModifierMappingCookie;
+pub type XCBGetModifierMappingReply = cdef::XCBGetModifierMappingReply;
+pub type XCBNoOperationRequest = cdef::XCBNoOperationRequest;
+
+pub fn xcb_char2b_next(iter: &mut XCBChar2bIterator) {
+    unsafe { cdef::xcb_char2b_next(iter) }
+}
+
+pub fn xcb_window_next(iter: &mut XCBWindowIterator) {
+    unsafe { cdef::xcb_window_next(iter) }
+}
+
+pub fn xcb_pixmap_next(iter: &mut XCBPixmapIterator) {
+    unsafe { cdef::xcb_pixmap_next(iter) }
+}
+
+pub fn xcb_cursor_next(iter: &mut XCBCursorIterator) {
+    unsafe { cdef::xcb_cursor_next(iter) }
+}
+
+pub fn xcb_font_next(iter: &mut XCBFontIterator) {
+    unsafe { cdef::xcb_font_next(iter) }
+}
+
+pub fn xcb_gcontext_next(iter: &mut XCBGcontextIterator) {
+    unsafe { cdef::xcb_gcontext_next(iter) }
+}
+
+pub fn xcb_colormap_next(iter: &mut XCBColormapIterator) {
+    unsafe { cdef::xcb_colormap_next(iter) }
+}
+
+pub fn xcb_atom_next(iter: &mut XCBAtomIterator) {
+    unsafe { cdef::xcb_atom_next(iter) }
+}
+
+pub fn xcb_drawable_next(iter: &mut XCBDrawableIterator) {
+    unsafe { cdef::xcb_drawable_next(iter) }
+}
+
+pub fn xcb_fontable_next(iter: &mut XCBFontableIterator) {
+    unsafe { cdef::xcb_fontable_next(iter) }
+}
+
+pub fn xcb_bool32_next(iter: &mut XCBBool32Iterator) {
+    unsafe { cdef::xcb_bool32_next(iter) }
+}
+
+pub fn xcb_visualid_next(iter: &mut XCBVisualidIterator) {
+    unsafe { cdef::xcb_visualid_next(iter) }
+}
+
+pub fn xcb_timestamp_next(iter: &mut XCBTimestampIterator) {
+    unsafe { cdef::xcb_timestamp_next(iter) }
+}
+
+pub fn xcb_keysym_next(iter: &mut XCBKeysymIterator) {
+    unsafe { cdef::xcb_keysym_next(iter) }
+}
+
+pub fn xcb_keycode_next(iter: &mut XCBKeycodeIterator) {
+    unsafe { cdef::xcb_keycode_next(iter) }
+}
+
+pub fn xcb_keycode32_next(iter: &mut XCBKeycode32Iterator) {
+    unsafe { cdef::xcb_keycode32_next(iter) }
+}
+
+pub fn xcb_button_next(iter: &mut XCBButtonIterator) {
+    unsafe { cdef::xcb_button_next(iter) }
+}
+
+pub fn xcb_point_next(iter: &mut XCBPointIterator) {
+    unsafe { cdef::xcb_point_next(iter) }
+}
+
+pub fn xcb_rectangle_next(iter: &mut XCBRectangleIterator) {
+    unsafe { cdef::xcb_rectangle_next(iter) }
+}
+
+pub fn xcb_arc_next(iter: &mut XCBArcIterator) {
+    unsafe { cdef::xcb_arc_next(iter) }
+}
+
+pub fn xcb_format_next(iter: &mut XCBFormatIterator) {
+    unsafe { cdef::xcb_format_next(iter) }
+}
+
+pub fn xcb_visualtype_next(iter: &mut XCBVisualtypeIterator) {
+    unsafe { cdef::xcb_visualtype_next(iter) }
+}
+
+pub fn xcb_depth_next(iter: &mut XCBDepthIterator) {
+    unsafe { cdef::xcb_depth_next(iter) }
+}
+
+pub fn xcb_depth_visuals(data: &XCBDepth) -> &[XCBVisualtype] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_depth_visuals(data),
+            cdef::xcb_depth_visuals_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_screen_next(iter: &mut XCBScreenIterator) {
+    unsafe { cdef::xcb_screen_next(iter) }
+}
+
+pub fn xcb_screen_allowed_depths_iterator(data: &XCBScreen) -> XCBDepthIterator {
+    unsafe { cdef::xcb_screen_allowed_depths_iterator(data) }
+}
+
+pub fn xcb_setup_request_next(iter: &mut XCBSetupRequestIterator) {
+    unsafe { cdef::xcb_setup_request_next(iter) }
+}
+
+pub fn xcb_setup_request_authorization_protocol_name(data: &XCBSetupRequest) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_setup_request_authorization_protocol_name(data) as *const u8,
+            cdef::xcb_setup_request_authorization_protocol_name_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_setup_request_authorization_protocol_data(data: &XCBSetupRequest) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_setup_request_authorization_protocol_data(data) as *const u8,
+            cdef::xcb_setup_request_authorization_protocol_data_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_setup_failed_next(iter: &mut XCBSetupFailedIterator) {
+    unsafe { cdef::xcb_setup_failed_next(iter) }
+}
+
+pub fn xcb_setup_failed_reason(data: &XCBSetupFailed) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_setup_failed_reason(data) as *const u8,
+            cdef::xcb_setup_failed_reason_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_setup_authenticate_next(iter: &mut XCBSetupAuthenticateIterator) {
+    unsafe { cdef::xcb_setup_authenticate_next(iter) }
+}
+
+pub fn xcb_setup_authenticate_reason(data: &XCBSetupAuthenticate) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_setup_authenticate_reason(data) as *const u8,
+            cdef::xcb_setup_authenticate_reason_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_setup_next(iter: &mut XCBSetupIterator) {
+    unsafe { cdef::xcb_setup_next(iter) }
+}
+
+pub fn xcb_setup_vendor(data: &XCBSetup) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_setup_vendor(data) as *const u8,
+            cdef::xcb_setup_vendor_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_setup_pixmap_formats(data: &XCBSetup) -> &[XCBFormat] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_setup_pixmap_formats(data),
+            cdef::xcb_setup_pixmap_formats_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_setup_roots_iterator(data: &XCBSetup) -> XCBScreenIterator {
+    unsafe { cdef::xcb_setup_roots_iterator(data) }
+}
+
+pub fn xcb_client_message_data_next(iter: &mut XCBClientMessageDataIterator) {
+    unsafe { cdef::xcb_client_message_data_next(iter) }
+}
+
+pub fn xcb_create_window(
+    connection: &XCBConnection,
+    depth: u8,
+    wid: XCBWindow,
+    parent: XCBWindow,
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+    border_width: u16,
+    class: u16,
+    visual: XCBVisualid,
+    value_mask: u32,
+    value_list: &[u32],
+) -> XCBVoidCookie {
+    assert!(value_list.len() >= value_mask.count_ones() as usize);
+    unsafe {
+        cdef::xcb_create_window(
+            connection.raw,
+            depth,
+            wid,
+            parent,
+            x,
+            y,
+            width,
+            height,
+            border_width,
+            class,
+            visual,
+            value_mask,
+            value_list.as_ptr() as *const c_void,
+        )
+    }
+}
+
+pub fn xcb_change_window_attributes(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    value_mask: u32,
+    value_list: &[u32],
+) -> XCBVoidCookie {
+    assert!(value_list.len() >= value_mask.count_ones() as usize);
+    unsafe {
+        cdef::xcb_change_window_attributes(
+            connection.raw,
+            window,
+            value_mask,
+            value_list.as_ptr() as *const c_void,
+        )
+    }
+}
+
+impl Reply for XCBGetWindowAttributesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_window_attributes_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_window_attributes(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBGetWindowAttributesCookie {
+    unsafe { cdef::xcb_get_window_attributes(connection.raw, window) }
+}
+
+pub fn xcb_destroy_window(connection: &XCBConnection, window: XCBWindow) -> XCBVoidCookie {
+    unsafe { cdef::xcb_destroy_window(connection.raw, window) }
+}
+
+pub fn xcb_destroy_subwindows(connection: &XCBConnection, window: XCBWindow) -> XCBVoidCookie {
+    unsafe { cdef::xcb_destroy_subwindows(connection.raw, window) }
+}
+
+pub fn xcb_change_save_set(
+    connection: &XCBConnection,
+    mode: u8,
+    window: XCBWindow,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_change_save_set(connection.raw, mode, window) }
+}
+
+pub fn xcb_reparent_window(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    parent: XCBWindow,
+    x: i16,
+    y: i16,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_reparent_window(connection.raw, window, parent, x, y) }
+}
+
+pub fn xcb_map_window(connection: &XCBConnection, window: XCBWindow) -> XCBVoidCookie {
+    unsafe { cdef::xcb_map_window(connection.raw, window) }
+}
+
+pub fn xcb_map_subwindows(connection: &XCBConnection, window: XCBWindow) -> XCBVoidCookie {
+    unsafe { cdef::xcb_map_subwindows(connection.raw, window) }
+}
+
+pub fn xcb_unmap_window(connection: &XCBConnection, window: XCBWindow) -> XCBVoidCookie {
+    unsafe { cdef::xcb_unmap_window(connection.raw, window) }
+}
+
+pub fn xcb_unmap_subwindows(connection: &XCBConnection, window: XCBWindow) -> XCBVoidCookie {
+    unsafe { cdef::xcb_unmap_subwindows(connection.raw, window) }
+}
+
+pub fn xcb_configure_window(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    value_mask: u16,
+    value_list: &[u32],
+) -> XCBVoidCookie {
+    assert!(value_list.len() >= value_mask.count_ones() as usize);
+    unsafe {
+        cdef::xcb_configure_window(
+            connection.raw,
+            window,
+            value_mask,
+            value_list.as_ptr() as *const c_void,
+        )
+    }
+}
+
+pub fn xcb_circulate_window(
+    connection: &XCBConnection,
+    direction: u8,
+    window: XCBWindow,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_circulate_window(connection.raw, direction, window) }
+}
+
+impl Reply for XCBGetGeometryReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_geometry_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_geometry(connection: &XCBConnection, drawable: XCBDrawable) -> XCBGetGeometryCookie {
+    unsafe { cdef::xcb_get_geometry(connection.raw, drawable) }
+}
+
+pub fn xcb_query_tree_children(reply: &XCBQueryTreeReply) -> &[XCBWindow] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_query_tree_children(reply),
+            cdef::xcb_query_tree_children_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBQueryTreeReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_query_tree_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_query_tree(connection: &XCBConnection, window: XCBWindow) -> XCBQueryTreeCookie {
+    unsafe { cdef::xcb_query_tree(connection.raw, window) }
+}
+
+impl Reply for XCBInternAtomReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_intern_atom_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_intern_atom(
+    connection: &XCBConnection,
+    only_if_exists: bool,
+    name: &[u8],
+) -> XCBInternAtomCookie {
+    unsafe {
+        cdef::xcb_intern_atom(
+            connection.raw,
+            only_if_exists as u8,
+            name.len() as u16,
+            name.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_get_atom_name_name(reply: &XCBGetAtomNameReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_get_atom_name_name(reply) as *const u8,
+            cdef::xcb_get_atom_name_name_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGetAtomNameReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_atom_name_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_atom_name(connection: &XCBConnection, atom: XCBAtom) -> XCBGetAtomNameCookie {
+    unsafe { cdef::xcb_get_atom_name(connection.raw, atom) }
+}
+
+pub fn xcb_change_property(
+    connection: &XCBConnection,
+    mode: u8,
+    window: XCBWindow,
+    property: XCBAtom,
+    type_: XCBAtom,
+    format: u8,
+    data_len: u32,
+    data: &[u8],
+) -> XCBVoidCookie {
+    assert!(data.len() >= (data_len as usize * (format as usize / 8)));
+    unsafe {
+        cdef::xcb_change_property(
+            connection.raw,
+            mode,
+            window,
+            property,
+            type_,
+            format,
+            data_len,
+            data.as_ptr() as *const c_void,
+        )
+    }
+}
+
+pub fn xcb_delete_property(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    property: XCBAtom,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_delete_property(connection.raw, window, property) }
+}
+
+pub fn xcb_get_property_value(reply: &XCBGetPropertyReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_get_property_value(reply) as *const u8,
+            cdef::xcb_get_property_value_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGetPropertyReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_property_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_property(
+    connection: &XCBConnection,
+    delete: bool,
+    window: XCBWindow,
+    property: XCBAtom,
+    type_: XCBAtom,
+    long_offset: u32,
+    long_length: u32,
+) -> XCBGetPropertyCookie {
+    unsafe {
+        cdef::xcb_get_property(
+            connection.raw,
+            delete as u8,
+            window,
+            property,
+            type_,
+            long_offset,
+            long_length,
+        )
+    }
+}
+
+pub fn xcb_list_properties_atoms(reply: &XCBListPropertiesReply) -> &[XCBAtom] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_list_properties_atoms(reply),
+            cdef::xcb_list_properties_atoms_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBListPropertiesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_list_properties_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_list_properties(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBListPropertiesCookie {
+    unsafe { cdef::xcb_list_properties(connection.raw, window) }
+}
+
+pub fn xcb_set_selection_owner(
+    connection: &XCBConnection,
+    owner: XCBWindow,
+    selection: XCBAtom,
+    time: XCBTimestamp,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_set_selection_owner(connection.raw, owner, selection, time) }
+}
+
+impl Reply for XCBGetSelectionOwnerReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_selection_owner_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_selection_owner(
+    connection: &XCBConnection,
+    selection: XCBAtom,
+) -> XCBGetSelectionOwnerCookie {
+    unsafe { cdef::xcb_get_selection_owner(connection.raw, selection) }
+}
+
+pub fn xcb_convert_selection(
+    connection: &XCBConnection,
+    requestor: XCBWindow,
+    selection: XCBAtom,
+    target: XCBAtom,
+    property: XCBAtom,
+    time: XCBTimestamp,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_convert_selection(connection.raw, requestor, selection, target, property, time)
+    }
+}
+
+pub fn xcb_send_event(
+    connection: &XCBConnection,
+    propagate: bool,
+    destination: XCBWindow,
+    event_mask: u32,
+    event: &[u8],
+) -> XCBVoidCookie {
+    assert!(event.len() >= 32);
+    unsafe {
+        cdef::xcb_send_event(
+            connection.raw,
+            propagate as u8,
+            destination,
+            event_mask,
+            event.as_ptr() as *const c_char,
+        )
+    }
+}
+
+impl Reply for XCBGrabPointerReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_grab_pointer_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_grab_pointer(
+    connection: &XCBConnection,
+    owner_events: bool,
+    grab_window: XCBWindow,
+    event_mask: u16,
+    pointer_mode: u8,
+    keyboard_mode: u8,
+    confine_to: XCBWindow,
+    cursor: XCBCursor,
+    time: XCBTimestamp,
+) -> XCBGrabPointerCookie {
+    unsafe {
+        cdef::xcb_grab_pointer(
+            connection.raw,
+            owner_events as u8,
+            grab_window,
+            event_mask,
+            pointer_mode,
+            keyboard_mode,
+            confine_to,
+            cursor,
+            time,
+        )
+    }
+}
+
+pub fn xcb_ungrab_pointer(connection: &XCBConnection, time: XCBTimestamp) -> XCBVoidCookie {
+    unsafe { cdef::xcb_ungrab_pointer(connection.raw, time) }
+}
+
+pub fn xcb_grab_button(
+    connection: &XCBConnection,
+    owner_events: bool,
+    grab_window: XCBWindow,
+    event_mask: u16,
+    pointer_mode: u8,
+    keyboard_mode: u8,
+    confine_to: XCBWindow,
+    cursor: XCBCursor,
+    button: u8,
+    modifiers: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_grab_button(
+            connection.raw,
+            owner_events as u8,
+            grab_window,
+            event_mask,
+            pointer_mode,
+            keyboard_mode,
+            confine_to,
+            cursor,
+            button,
+            modifiers,
+        )
+    }
+}
+
+pub fn xcb_ungrab_button(
+    connection: &XCBConnection,
+    button: u8,
+    grab_window: XCBWindow,
+    modifiers: u16,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_ungrab_button(connection.raw, button, grab_window, modifiers) }
+}
+
+pub fn xcb_change_active_pointer_grab(
+    connection: &XCBConnection,
+    cursor: XCBCursor,
+    time: XCBTimestamp,
+    event_mask: u16,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_change_active_pointer_grab(connection.raw, cursor, time, event_mask) }
+}
+
+impl Reply for XCBGrabKeyboardReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_grab_keyboard_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_grab_keyboard(
+    connection: &XCBConnection,
+    owner_events: bool,
+    grab_window: XCBWindow,
+    time: XCBTimestamp,
+    pointer_mode: u8,
+    keyboard_mode: u8,
+) -> XCBGrabKeyboardCookie {
+    unsafe {
+        cdef::xcb_grab_keyboard(
+            connection.raw,
+            owner_events as u8,
+            grab_window,
+            time,
+            pointer_mode,
+            keyboard_mode,
+        )
+    }
+}
+
+pub fn xcb_ungrab_keyboard(connection: &XCBConnection, time: XCBTimestamp) -> XCBVoidCookie {
+    unsafe { cdef::xcb_ungrab_keyboard(connection.raw, time) }
+}
+
+pub fn xcb_grab_key(
+    connection: &XCBConnection,
+    owner_events: bool,
+    grab_window: XCBWindow,
+    modifiers: u16,
+    key: XCBKeycode,
+    pointer_mode: u8,
+    keyboard_mode: u8,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_grab_key(
+            connection.raw,
+            owner_events as u8,
+            grab_window,
+            modifiers,
+            key,
+            pointer_mode,
+            keyboard_mode,
+        )
+    }
+}
+
+pub fn xcb_ungrab_key(
+    connection: &XCBConnection,
+    key: XCBKeycode,
+    grab_window: XCBWindow,
+    modifiers: u16,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_ungrab_key(connection.raw, key, grab_window, modifiers) }
+}
+
+pub fn xcb_allow_events(connection: &XCBConnection, mode: u8, time: XCBTimestamp) -> XCBVoidCookie {
+    unsafe { cdef::xcb_allow_events(connection.raw, mode, time) }
+}
+
+pub fn xcb_grab_server(connection: &XCBConnection) -> XCBVoidCookie {
+    unsafe { cdef::xcb_grab_server(connection.raw) }
+}
+
+pub fn xcb_ungrab_server(connection: &XCBConnection) -> XCBVoidCookie {
+    unsafe { cdef::xcb_ungrab_server(connection.raw) }
+}
+
+impl Reply for XCBQueryPointerReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_query_pointer_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_query_pointer(connection: &XCBConnection, window: XCBWindow) -> XCBQueryPointerCookie {
+    unsafe { cdef::xcb_query_pointer(connection.raw, window) }
+}
+
+pub fn xcb_timecoord_next(iter: &mut XCBTimecoordIterator) {
+    unsafe { cdef::xcb_timecoord_next(iter) }
+}
+
+pub fn xcb_get_motion_events_events(reply: &XCBGetMotionEventsReply) -> &[XCBTimecoord] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_get_motion_events_events(reply),
+            cdef::xcb_get_motion_events_events_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGetMotionEventsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_motion_events_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_motion_events(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    start: XCBTimestamp,
+    stop: XCBTimestamp,
+) -> XCBGetMotionEventsCookie {
+    unsafe { cdef::xcb_get_motion_events(connection.raw, window, start, stop) }
+}
+
+impl Reply for XCBTranslateCoordinatesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_translate_coordinates_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_translate_coordinates(
+    connection: &XCBConnection,
+    src_window: XCBWindow,
+    dst_window: XCBWindow,
+    src_x: i16,
+    src_y: i16,
+) -> XCBTranslateCoordinatesCookie {
+    unsafe { cdef::xcb_translate_coordinates(connection.raw, src_window, dst_window, src_x, src_y) }
+}
+
+pub fn xcb_warp_pointer(
+    connection: &XCBConnection,
+    src_window: XCBWindow,
+    dst_window: XCBWindow,
+    src_x: i16,
+    src_y: i16,
+    src_width: u16,
+    src_height: u16,
+    dst_x: i16,
+    dst_y: i16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_warp_pointer(
+            connection.raw,
+            src_window,
+            dst_window,
+            src_x,
+            src_y,
+            src_width,
+            src_height,
+            dst_x,
+            dst_y,
+        )
+    }
+}
+
+pub fn xcb_set_input_focus(
+    connection: &XCBConnection,
+    revert_to: u8,
+    focus: XCBWindow,
+    time: XCBTimestamp,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_set_input_focus(connection.raw, revert_to, focus, time) }
+}
+
+impl Reply for XCBGetInputFocusReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_input_focus_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_input_focus(connection: &XCBConnection) -> XCBGetInputFocusCookie {
+    unsafe { cdef::xcb_get_input_focus(connection.raw) }
+}
+
+impl Reply for XCBQueryKeymapReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_query_keymap_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_query_keymap(connection: &XCBConnection) -> XCBQueryKeymapCookie {
+    unsafe { cdef::xcb_query_keymap(connection.raw) }
+}
+
+pub fn xcb_open_font(connection: &XCBConnection, fid: XCBFont, name: &[u8]) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_open_font(
+            connection.raw,
+            fid,
+            name.len() as u16,
+            name.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_close_font(connection: &XCBConnection, font: XCBFont) -> XCBVoidCookie {
+    unsafe { cdef::xcb_close_font(connection.raw, font) }
+}
+
+pub fn xcb_fontprop_next(iter: &mut XCBFontpropIterator) {
+    unsafe { cdef::xcb_fontprop_next(iter) }
+}
+
+pub fn xcb_charinfo_next(iter: &mut XCBCharinfoIterator) {
+    unsafe { cdef::xcb_charinfo_next(iter) }
+}
+
+pub fn xcb_query_font_properties(reply: &XCBQueryFontReply) -> &[XCBFontprop] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_query_font_properties(reply),
+            cdef::xcb_query_font_properties_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_query_font_char_infos(reply: &XCBQueryFontReply) -> &[XCBCharinfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_query_font_char_infos(reply),
+            cdef::xcb_query_font_char_infos_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBQueryFontReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_query_font_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_query_font(connection: &XCBConnection, font: XCBFontable) -> XCBQueryFontCookie {
+    unsafe { cdef::xcb_query_font(connection.raw, font) }
+}
+
+impl Reply for XCBQueryTextExtentsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_query_text_extents_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_query_text_extents(
+    connection: &XCBConnection,
+    font: XCBFontable,
+    string: &[XCBChar2b],
+) -> XCBQueryTextExtentsCookie {
+    unsafe {
+        cdef::xcb_query_text_extents(connection.raw, font, string.len() as u32, string.as_ptr())
+    }
+}
+
+pub fn xcb_str_next(iter: &mut XCBStrIterator) {
+    unsafe { cdef::xcb_str_next(iter) }
+}
+
+pub fn xcb_str_name(data: &XCBStr) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_str_name(data) as *const u8,
+            cdef::xcb_str_name_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_list_fonts_names_iterator(reply: &XCBListFontsReply) -> XCBStrIterator {
+    unsafe { cdef::xcb_list_fonts_names_iterator(reply) }
+}
+
+impl Reply for XCBListFontsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_list_fonts_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_list_fonts(
+    connection: &XCBConnection,
+    max_names: u16,
+    pattern: &[u8],
+) -> XCBListFontsCookie {
+    unsafe {
+        cdef::xcb_list_fonts(
+            connection.raw,
+            max_names,
+            pattern.len() as u16,
+            pattern.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_list_fonts_with_info_properties(reply: &XCBListFontsWithInfoReply) -> &[XCBFontprop] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_list_fonts_with_info_properties(reply),
+            cdef::xcb_list_fonts_with_info_properties_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_list_fonts_with_info_name(reply: &XCBListFontsWithInfoReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_list_fonts_with_info_name(reply) as *const u8,
+            cdef::xcb_list_fonts_with_info_name_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBListFontsWithInfoReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_list_fonts_with_info_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_list_fonts_with_info(
+    connection: &XCBConnection,
+    max_names: u16,
+    pattern: &[u8],
+) -> XCBListFontsWithInfoCookie {
+    unsafe {
+        cdef::xcb_list_fonts_with_info(
+            connection.raw,
+            max_names,
+            pattern.len() as u16,
+            pattern.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_get_font_path_path_iterator(reply: &XCBGetFontPathReply) -> XCBStrIterator {
+    unsafe { cdef::xcb_get_font_path_path_iterator(reply) }
+}
+
+impl Reply for XCBGetFontPathReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_font_path_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_font_path(connection: &XCBConnection) -> XCBGetFontPathCookie {
+    unsafe { cdef::xcb_get_font_path(connection.raw) }
+}
+
+pub fn xcb_create_pixmap(
+    connection: &XCBConnection,
+    depth: u8,
+    pid: XCBPixmap,
+    drawable: XCBDrawable,
+    width: u16,
+    height: u16,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_create_pixmap(connection.raw, depth, pid, drawable, width, height) }
+}
+
+pub fn xcb_free_pixmap(connection: &XCBConnection, pixmap: XCBPixmap) -> XCBVoidCookie {
+    unsafe { cdef::xcb_free_pixmap(connection.raw, pixmap) }
+}
+
+pub fn xcb_create_gc(
+    connection: &XCBConnection,
+    cid: XCBGcontext,
+    drawable: XCBDrawable,
+    value_mask: u32,
+    value_list: &[u32],
+) -> XCBVoidCookie {
+    assert!(value_list.len() >= value_mask.count_ones() as usize);
+    unsafe {
+        cdef::xcb_create_gc(
+            connection.raw,
+            cid,
+            drawable,
+            value_mask,
+            value_list.as_ptr() as *const c_void,
+        )
+    }
+}
+
+pub fn xcb_change_gc(
+    connection: &XCBConnection,
+    gc: XCBGcontext,
+    value_mask: u32,
+    value_list: &[u32],
+) -> XCBVoidCookie {
+    assert!(value_list.len() >= value_mask.count_ones() as usize);
+    unsafe {
+        cdef::xcb_change_gc(
+            connection.raw,
+            gc,
+            value_mask,
+            value_list.as_ptr() as *const c_void,
+        )
+    }
+}
+
+pub fn xcb_copy_gc(
+    connection: &XCBConnection,
+    src_gc: XCBGcontext,
+    dst_gc: XCBGcontext,
+    value_mask: u32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_copy_gc(connection.raw, src_gc, dst_gc, value_mask) }
+}
+
+pub fn xcb_set_dashes(
+    connection: &XCBConnection,
+    gc: XCBGcontext,
+    dash_offset: u16,
+    dashes: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_set_dashes(
+            connection.raw,
+            gc,
+            dash_offset,
+            dashes.len() as u16,
+            dashes.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_set_clip_rectangles(
+    connection: &XCBConnection,
+    ordering: u8,
+    gc: XCBGcontext,
+    clip_x_origin: i16,
+    clip_y_origin: i16,
+    rectangles: &[XCBRectangle],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_set_clip_rectangles(
+            connection.raw,
+            ordering,
+            gc,
+            clip_x_origin,
+            clip_y_origin,
+            rectangles.len() as u32,
+            rectangles.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_free_gc(connection: &XCBConnection, gc: XCBGcontext) -> XCBVoidCookie {
+    unsafe { cdef::xcb_free_gc(connection.raw, gc) }
+}
+
+pub fn xcb_clear_area(
+    connection: &XCBConnection,
+    exposures: bool,
+    window: XCBWindow,
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_clear_area(connection.raw, exposures as u8, window, x, y, width, height) }
+}
+
+pub fn xcb_copy_area(
+    connection: &XCBConnection,
+    src_drawable: XCBDrawable,
+    dst_drawable: XCBDrawable,
+    gc: XCBGcontext,
+    src_x: i16,
+    src_y: i16,
+    dst_x: i16,
+    dst_y: i16,
+    width: u16,
+    height: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_copy_area(
+            connection.raw,
+            src_drawable,
+            dst_drawable,
+            gc,
+            src_x,
+            src_y,
+            dst_x,
+            dst_y,
+            width,
+            height,
+        )
+    }
+}
+
+pub fn xcb_copy_plane(
+    connection: &XCBConnection,
+    src_drawable: XCBDrawable,
+    dst_drawable: XCBDrawable,
+    gc: XCBGcontext,
+    src_x: i16,
+    src_y: i16,
+    dst_x: i16,
+    dst_y: i16,
+    width: u16,
+    height: u16,
+    bit_plane: u32,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_copy_plane(
+            connection.raw,
+            src_drawable,
+            dst_drawable,
+            gc,
+            src_x,
+            src_y,
+            dst_x,
+            dst_y,
+            width,
+            height,
+            bit_plane,
+        )
+    }
+}
+
+pub fn xcb_poly_point(
+    connection: &XCBConnection,
+    coordinate_mode: u8,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    points: &[XCBPoint],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_poly_point(
+            connection.raw,
+            coordinate_mode,
+            drawable,
+            gc,
+            points.len() as u32,
+            points.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_poly_line(
+    connection: &XCBConnection,
+    coordinate_mode: u8,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    points: &[XCBPoint],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_poly_line(
+            connection.raw,
+            coordinate_mode,
+            drawable,
+            gc,
+            points.len() as u32,
+            points.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_segment_next(iter: &mut XCBSegmentIterator) {
+    unsafe { cdef::xcb_segment_next(iter) }
+}
+
+pub fn xcb_poly_segment(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    segments: &[XCBSegment],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_poly_segment(
+            connection.raw,
+            drawable,
+            gc,
+            segments.len() as u32,
+            segments.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_poly_rectangle(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    rectangles: &[XCBRectangle],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_poly_rectangle(
+            connection.raw,
+            drawable,
+            gc,
+            rectangles.len() as u32,
+            rectangles.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_poly_arc(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    arcs: &[XCBArc],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_poly_arc(
+            connection.raw,
+            drawable,
+            gc,
+            arcs.len() as u32,
+            arcs.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_fill_poly(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    shape: u8,
+    coordinate_mode: u8,
+    points: &[XCBPoint],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_fill_poly(
+            connection.raw,
+            drawable,
+            gc,
+            shape,
+            coordinate_mode,
+            points.len() as u32,
+            points.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_poly_fill_rectangle(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    rectangles: &[XCBRectangle],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_poly_fill_rectangle(
+            connection.raw,
+            drawable,
+            gc,
+            rectangles.len() as u32,
+            rectangles.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_poly_fill_arc(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    arcs: &[XCBArc],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_poly_fill_arc(
+            connection.raw,
+            drawable,
+            gc,
+            arcs.len() as u32,
+            arcs.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_put_image(
+    connection: &XCBConnection,
+    format: u8,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    width: u16,
+    height: u16,
+    dst_x: i16,
+    dst_y: i16,
+    left_pad: u8,
+    depth: u8,
+    data: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_put_image(
+            connection.raw,
+            format,
+            drawable,
+            gc,
+            width,
+            height,
+            dst_x,
+            dst_y,
+            left_pad,
+            depth,
+            data.len() as u32,
+            data.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_get_image_data(reply: &XCBGetImageReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_get_image_data(reply),
+            cdef::xcb_get_image_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGetImageReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_image_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_image(
+    connection: &XCBConnection,
+    format: u8,
+    drawable: XCBDrawable,
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+    plane_mask: u32,
+) -> XCBGetImageCookie {
+    unsafe {
+        cdef::xcb_get_image(
+            connection.raw,
+            format,
+            drawable,
+            x,
+            y,
+            width,
+            height,
+            plane_mask,
+        )
+    }
+}
+
+pub fn xcb_poly_text_8(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    x: i16,
+    y: i16,
+    items: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_poly_text_8(
+            connection.raw,
+            drawable,
+            gc,
+            x,
+            y,
+            items.len() as u32,
+            items.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_poly_text_16(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    x: i16,
+    y: i16,
+    items: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_poly_text_16(
+            connection.raw,
+            drawable,
+            gc,
+            x,
+            y,
+            items.len() as u32,
+            items.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_image_text_8(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    x: i16,
+    y: i16,
+    string: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_image_text_8(
+            connection.raw,
+            string.len() as u8,
+            drawable,
+            gc,
+            x,
+            y,
+            string.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_image_text_16(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    x: i16,
+    y: i16,
+    string: &[XCBChar2b],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_image_text_16(
+            connection.raw,
+            string.len() as u8,
+            drawable,
+            gc,
+            x,
+            y,
+            string.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_create_colormap(
+    connection: &XCBConnection,
+    alloc: u8,
+    mid: XCBColormap,
+    window: XCBWindow,
+    visual: XCBVisualid,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_create_colormap(connection.raw, alloc, mid, window, visual) }
+}
+
+pub fn xcb_free_colormap(connection: &XCBConnection, cmap: XCBColormap) -> XCBVoidCookie {
+    unsafe { cdef::xcb_free_colormap(connection.raw, cmap) }
+}
+
+pub fn xcb_copy_colormap_and_free(
+    connection: &XCBConnection,
+    mid: XCBColormap,
+    src_cmap: XCBColormap,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_copy_colormap_and_free(connection.raw, mid, src_cmap) }
+}
+
+pub fn xcb_install_colormap(connection: &XCBConnection, cmap: XCBColormap) -> XCBVoidCookie {
+    unsafe { cdef::xcb_install_colormap(connection.raw, cmap) }
+}
+
+pub fn xcb_uninstall_colormap(connection: &XCBConnection, cmap: XCBColormap) -> XCBVoidCookie {
+    unsafe { cdef::xcb_uninstall_colormap(connection.raw, cmap) }
+}
+
+pub fn xcb_list_installed_colormaps_cmaps(
+    reply: &XCBListInstalledColormapsReply,
+) -> &[XCBColormap] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_list_installed_colormaps_cmaps(reply),
+            cdef::xcb_list_installed_colormaps_cmaps_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBListInstalledColormapsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_list_installed_colormaps_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_list_installed_colormaps(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBListInstalledColormapsCookie {
+    unsafe { cdef::xcb_list_installed_colormaps(connection.raw, window) }
+}
+
+impl Reply for XCBAllocColorReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_alloc_color_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_alloc_color(
+    connection: &XCBConnection,
+    cmap: XCBColormap,
+    red: u16,
+    green: u16,
+    blue: u16,
+) -> XCBAllocColorCookie {
+    unsafe { cdef::xcb_alloc_color(connection.raw, cmap, red, green, blue) }
+}
+
+impl Reply for XCBAllocNamedColorReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_alloc_named_color_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_alloc_named_color(
+    connection: &XCBConnection,
+    cmap: XCBColormap,
+    name: &[u8],
+) -> XCBAllocNamedColorCookie {
+    unsafe {
+        cdef::xcb_alloc_named_color(
+            connection.raw,
+            cmap,
+            name.len() as u16,
+            name.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_alloc_color_cells_pixels(reply: &XCBAllocColorCellsReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_alloc_color_cells_pixels(reply),
+            cdef::xcb_alloc_color_cells_pixels_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_alloc_color_cells_masks(reply: &XCBAllocColorCellsReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_alloc_color_cells_masks(reply),
+            cdef::xcb_alloc_color_cells_masks_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBAllocColorCellsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_alloc_color_cells_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_alloc_color_cells(
+    connection: &XCBConnection,
+    contiguous: bool,
+    cmap: XCBColormap,
+    colors: u16,
+    planes: u16,
+) -> XCBAllocColorCellsCookie {
+    unsafe { cdef::xcb_alloc_color_cells(connection.raw, contiguous as u8, cmap, colors, planes) }
+}
+
+pub fn xcb_alloc_color_planes_pixels(reply: &XCBAllocColorPlanesReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_alloc_color_planes_pixels(reply),
+            cdef::xcb_alloc_color_planes_pixels_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBAllocColorPlanesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_alloc_color_planes_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_alloc_color_planes(
+    connection: &XCBConnection,
+    contiguous: bool,
+    cmap: XCBColormap,
+    colors: u16,
+    reds: u16,
+    greens: u16,
+    blues: u16,
+) -> XCBAllocColorPlanesCookie {
+    unsafe {
+        cdef::xcb_alloc_color_planes(
+            connection.raw,
+            contiguous as u8,
+            cmap,
+            colors,
+            reds,
+            greens,
+            blues,
+        )
+    }
+}
+
+pub fn xcb_free_colors(
+    connection: &XCBConnection,
+    cmap: XCBColormap,
+    plane_mask: u32,
+    pixels: &[u32],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_free_colors(
+            connection.raw,
+            cmap,
+            plane_mask,
+            pixels.len() as u32,
+            pixels.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_coloritem_next(iter: &mut XCBColoritemIterator) {
+    unsafe { cdef::xcb_coloritem_next(iter) }
+}
+
+pub fn xcb_store_colors(
+    connection: &XCBConnection,
+    cmap: XCBColormap,
+    items: &[XCBColoritem],
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_store_colors(connection.raw, cmap, items.len() as u32, items.as_ptr()) }
+}
+
+pub fn xcb_store_named_color(
+    connection: &XCBConnection,
+    flags: u8,
+    cmap: XCBColormap,
+    pixel: u32,
+    name: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_store_named_color(
+            connection.raw,
+            flags,
+            cmap,
+            pixel,
+            name.len() as u16,
+            name.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_rgb_next(iter: &mut XCBRgbIterator) {
+    unsafe { cdef::xcb_rgb_next(iter) }
+}
+
+pub fn xcb_query_colors_colors(reply: &XCBQueryColorsReply) -> &[XCBRgb] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_query_colors_colors(reply),
+            cdef::xcb_query_colors_colors_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBQueryColorsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_query_colors_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_query_colors(
+    connection: &XCBConnection,
+    cmap: XCBColormap,
+    pixels: &[u32],
+) -> XCBQueryColorsCookie {
+    unsafe { cdef::xcb_query_colors(connection.raw, cmap, pixels.len() as u32, pixels.as_ptr()) }
+}
+
+impl Reply for XCBLookupColorReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_lookup_color_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_lookup_color(
+    connection: &XCBConnection,
+    cmap: XCBColormap,
+    name: &[u8],
+) -> XCBLookupColorCookie {
+    unsafe {
+        cdef::xcb_lookup_color(
+            connection.raw,
+            cmap,
+            name.len() as u16,
+            name.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_create_cursor(
+    connection: &XCBConnection,
+    cid: XCBCursor,
+    source: XCBPixmap,
+    mask: XCBPixmap,
+    fore_red: u16,
+    fore_green: u16,
+    fore_blue: u16,
+    back_red: u16,
+    back_green: u16,
+    back_blue: u16,
+    x: u16,
+    y: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_create_cursor(
+            connection.raw,
+            cid,
+            source,
+            mask,
+            fore_red,
+            fore_green,
+            fore_blue,
+            back_red,
+            back_green,
+            back_blue,
+            x,
+            y,
+        )
+    }
+}
+
+pub fn xcb_create_glyph_cursor(
+    connection: &XCBConnection,
+    cid: XCBCursor,
+    source_font: XCBFont,
+    mask_font: XCBFont,
+    source_char: u16,
+    mask_char: u16,
+    fore_red: u16,
+    fore_green: u16,
+    fore_blue: u16,
+    back_red: u16,
+    back_green: u16,
+    back_blue: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_create_glyph_cursor(
+            connection.raw,
+            cid,
+            source_font,
+            mask_font,
+            source_char,
+            mask_char,
+            fore_red,
+            fore_green,
+            fore_blue,
+            back_red,
+            back_green,
+            back_blue,
+        )
+    }
+}
+
+pub fn xcb_free_cursor(connection: &XCBConnection, cursor: XCBCursor) -> XCBVoidCookie {
+    unsafe { cdef::xcb_free_cursor(connection.raw, cursor) }
+}
+
+pub fn xcb_recolor_cursor(
+    connection: &XCBConnection,
+    cursor: XCBCursor,
+    fore_red: u16,
+    fore_green: u16,
+    fore_blue: u16,
+    back_red: u16,
+    back_green: u16,
+    back_blue: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_recolor_cursor(
+            connection.raw,
+            cursor,
+            fore_red,
+            fore_green,
+            fore_blue,
+            back_red,
+            back_green,
+            back_blue,
+        )
+    }
+}
+
+impl Reply for XCBQueryBestSizeReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_query_best_size_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_query_best_size(
+    connection: &XCBConnection,
+    class: u8,
+    drawable: XCBDrawable,
+    width: u16,
+    height: u16,
+) -> XCBQueryBestSizeCookie {
+    unsafe { cdef::xcb_query_best_size(connection.raw, class, drawable, width, height) }
+}
+
+impl Reply for XCBQueryExtensionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_query_extension_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_query_extension(connection: &XCBConnection, name: &[u8]) -> XCBQueryExtensionCookie {
+    unsafe {
+        cdef::xcb_query_extension(
+            connection.raw,
+            name.len() as u16,
+            name.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_list_extensions_names_iterator(reply: &XCBListExtensionsReply) -> XCBStrIterator {
+    unsafe { cdef::xcb_list_extensions_names_iterator(reply) }
+}
+
+impl Reply for XCBListExtensionsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_list_extensions_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_list_extensions(connection: &XCBConnection) -> XCBListExtensionsCookie {
+    unsafe { cdef::xcb_list_extensions(connection.raw) }
+}
+
+pub fn xcb_change_keyboard_mapping(
+    connection: &XCBConnection,
+    keycode_count: u8,
+    first_keycode: XCBKeycode,
+    keysyms_per_keycode: u8,
+    keysyms: &[XCBKeysym],
+) -> XCBVoidCookie {
+    assert!(keysyms.len() >= (keycode_count as usize * keysyms_per_keycode as usize));
+    unsafe {
+        cdef::xcb_change_keyboard_mapping(
+            connection.raw,
+            keycode_count,
+            first_keycode,
+            keysyms_per_keycode,
+            keysyms.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_get_keyboard_mapping_keysyms(reply: &XCBGetKeyboardMappingReply) -> &[XCBKeysym] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_get_keyboard_mapping_keysyms(reply),
+            cdef::xcb_get_keyboard_mapping_keysyms_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGetKeyboardMappingReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_keyboard_mapping_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_keyboard_mapping(
+    connection: &XCBConnection,
+    first_keycode: XCBKeycode,
+    count: u8,
+) -> XCBGetKeyboardMappingCookie {
+    unsafe { cdef::xcb_get_keyboard_mapping(connection.raw, first_keycode, count) }
+}
+
+pub fn xcb_change_keyboard_control(
+    connection: &XCBConnection,
+    value_mask: u32,
+    value_list: &[u32],
+) -> XCBVoidCookie {
+    assert!(value_list.len() >= value_mask.count_ones() as usize);
+    unsafe {
+        cdef::xcb_change_keyboard_control(
+            connection.raw,
+            value_mask,
+            value_list.as_ptr() as *const c_void,
+        )
+    }
+}
+
+impl Reply for XCBGetKeyboardControlReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_keyboard_control_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_keyboard_control(connection: &XCBConnection) -> XCBGetKeyboardControlCookie {
+    unsafe { cdef::xcb_get_keyboard_control(connection.raw) }
+}
+
+pub fn xcb_bell(connection: &XCBConnection, percent: i8) -> XCBVoidCookie {
+    unsafe { cdef::xcb_bell(connection.raw, percent) }
+}
+
+pub fn xcb_change_pointer_control(
+    connection: &XCBConnection,
+    acceleration_numerator: i16,
+    acceleration_denominator: i16,
+    threshold: i16,
+    do_acceleration: bool,
+    do_threshold: bool,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_change_pointer_control(
+            connection.raw,
+            acceleration_numerator,
+            acceleration_denominator,
+            threshold,
+            do_acceleration as u8,
+            do_threshold as u8,
+        )
+    }
+}
+
+impl Reply for XCBGetPointerControlReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_pointer_control_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_pointer_control(connection: &XCBConnection) -> XCBGetPointerControlCookie {
+    unsafe { cdef::xcb_get_pointer_control(connection.raw) }
+}
+
+pub fn xcb_set_screen_saver(
+    connection: &XCBConnection,
+    timeout: i16,
+    interval: i16,
+    prefer_blanking: u8,
+    allow_exposures: u8,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_set_screen_saver(
+            connection.raw,
+            timeout,
+            interval,
+            prefer_blanking,
+            allow_exposures,
+        )
+    }
+}
+
+impl Reply for XCBGetScreenSaverReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_screen_saver_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_screen_saver(connection: &XCBConnection) -> XCBGetScreenSaverCookie {
+    unsafe { cdef::xcb_get_screen_saver(connection.raw) }
+}
+
+pub fn xcb_change_hosts(
+    connection: &XCBConnection,
+    mode: u8,
+    family: u8,
+    address: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_change_hosts(
+            connection.raw,
+            mode,
+            family,
+            address.len() as u16,
+            address.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_host_next(iter: &mut XCBHostIterator) {
+    unsafe { cdef::xcb_host_next(iter) }
+}
+
+pub fn xcb_host_address(data: &XCBHost) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_host_address(data),
+            cdef::xcb_host_address_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_list_hosts_hosts_iterator(reply: &XCBListHostsReply) -> XCBHostIterator {
+    unsafe { cdef::xcb_list_hosts_hosts_iterator(reply) }
+}
+
+impl Reply for XCBListHostsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_list_hosts_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_list_hosts(connection: &XCBConnection) -> XCBListHostsCookie {
+    unsafe { cdef::xcb_list_hosts(connection.raw) }
+}
+
+pub fn xcb_set_access_control(connection: &XCBConnection, mode: u8) -> XCBVoidCookie {
+    unsafe { cdef::xcb_set_access_control(connection.raw, mode) }
+}
+
+pub fn xcb_set_close_down_mode(connection: &XCBConnection, mode: u8) -> XCBVoidCookie {
+    unsafe { cdef::xcb_set_close_down_mode(connection.raw, mode) }
+}
+
+pub fn xcb_kill_client(connection: &XCBConnection, resource: u32) -> XCBVoidCookie {
+    unsafe { cdef::xcb_kill_client(connection.raw, resource) }
+}
+
+pub fn xcb_rotate_properties(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    delta: i16,
+    atoms: &[XCBAtom],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_rotate_properties(
+            connection.raw,
+            window,
+            atoms.len() as u16,
+            delta,
+            atoms.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_force_screen_saver(connection: &XCBConnection, mode: u8) -> XCBVoidCookie {
+    unsafe { cdef::xcb_force_screen_saver(connection.raw, mode) }
+}
+
+impl Reply for XCBSetPointerMappingReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_set_pointer_mapping_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_set_pointer_mapping(
+    connection: &XCBConnection,
+    map: &[u8],
+) -> XCBSetPointerMappingCookie {
+    unsafe { cdef::xcb_set_pointer_mapping(connection.raw, map.len() as u8, map.as_ptr()) }
+}
+
+pub fn xcb_get_pointer_mapping_map(reply: &XCBGetPointerMappingReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_get_pointer_mapping_map(reply),
+            cdef::xcb_get_pointer_mapping_map_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGetPointerMappingReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_pointer_mapping_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_pointer_mapping(connection: &XCBConnection) -> XCBGetPointerMappingCookie {
+    unsafe { cdef::xcb_get_pointer_mapping(connection.raw) }
+}
+
+impl Reply for XCBSetModifierMappingReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_set_modifier_mapping_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_set_modifier_mapping(
+    connection: &XCBConnection,
+    keycodes_per_modifier: u8,
+    keycodes: &[XCBKeycode],
+) -> XCBSetModifierMappingCookie {
+    assert!(keycodes.len() >= (keycodes_per_modifier as usize * 8));
+    unsafe {
+        cdef::xcb_set_modifier_mapping(connection.raw, keycodes_per_modifier, keycodes.as_ptr())
+    }
+}
+
+pub fn xcb_get_modifier_mapping_keycodes(reply: &XCBGetModifierMappingReply) -> &[XCBKeycode] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_get_modifier_mapping_keycodes(reply),
+            cdef::xcb_get_modifier_mapping_keycodes_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBGetModifierMappingReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_get_modifier_mapping_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_get_modifier_mapping(connection: &XCBConnection) -> XCBGetModifierMappingCookie {
+    unsafe { cdef::xcb_get_modifier_mapping(connection.raw) }
+}
+
+pub fn xcb_no_operation(connection: &XCBConnection) -> XCBVoidCookie {
+    unsafe { cdef::xcb_no_operation(connection.raw) }
+}
+
+/// An event of the core protocol.
+pub enum Event {
+    KeyPress(Box<XCBKeyPressEvent>),
+    KeyRelease(Box<XCBKeyReleaseEvent>),
+    ButtonPress(Box<XCBButtonPressEvent>),
+    ButtonRelease(Box<XCBButtonReleaseEvent>),
+    MotionNotify(Box<XCBMotionNotifyEvent>),
+    EnterNotify(Box<XCBEnterNotifyEvent>),
+    LeaveNotify(Box<XCBLeaveNotifyEvent>),
+    FocusIn(Box<XCBFocusInEvent>),
+    FocusOut(Box<XCBFocusOutEvent>),
+    KeymapNotify(Box<XCBKeymapNotifyEvent>),
+    Expose(Box<XCBExposeEvent>),
+    GraphicsExposure(Box<XCBGraphicsExposureEvent>),
+    NoExposure(Box<XCBNoExposureEvent>),
+    VisibilityNotify(Box<XCBVisibilityNotifyEvent>),
+    CreateNotify(Box<XCBCreateNotifyEvent>),
+    DestroyNotify(Box<XCBDestroyNotifyEvent>),
+    UnmapNotify(Box<XCBUnmapNotifyEvent>),
+    MapNotify(Box<XCBMapNotifyEvent>),
+    MapRequest(Box<XCBMapRequestEvent>),
+    ReparentNotify(Box<XCBReparentNotifyEvent>),
+    ConfigureNotify(Box<XCBConfigureNotifyEvent>),
+    ConfigureRequest(Box<XCBConfigureRequestEvent>),
+    GravityNotify(Box<XCBGravityNotifyEvent>),
+    ResizeRequest(Box<XCBResizeRequestEvent>),
+    CirculateNotify(Box<XCBCirculateNotifyEvent>),
+    CirculateRequest(Box<XCBCirculateRequestEvent>),
+    PropertyNotify(Box<XCBPropertyNotifyEvent>),
+    SelectionClear(Box<XCBSelectionClearEvent>),
+    SelectionRequest(Box<XCBSelectionRequestEvent>),
+    SelectionNotify(Box<XCBSelectionNotifyEvent>),
+    ColormapNotify(Box<XCBColormapNotifyEvent>),
+    ClientMessage(Box<XCBClientMessageEvent>),
+    MappingNotify(Box<XCBMappingNotifyEvent>),
+    GeGeneric(Box<XCBGeGenericEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                2 => Ok(Event::KeyPress(Box::from_raw(raw as *mut XCBKeyPressEvent))),
+                3 => Ok(Event::KeyRelease(Box::from_raw(
+                    raw as *mut XCBKeyReleaseEvent,
+                ))),
+                4 => Ok(Event::ButtonPress(Box::from_raw(
+                    raw as *mut XCBButtonPressEvent,
+                ))),
+                5 => Ok(Event::ButtonRelease(Box::from_raw(
+                    raw as *mut XCBButtonReleaseEvent,
+                ))),
+                6 => Ok(Event::MotionNotify(Box::from_raw(
+                    raw as *mut XCBMotionNotifyEvent,
+                ))),
+                7 => Ok(Event::EnterNotify(Box::from_raw(
+                    raw as *mut XCBEnterNotifyEvent,
+                ))),
+                8 => Ok(Event::LeaveNotify(Box::from_raw(
+                    raw as *mut XCBLeaveNotifyEvent,
+                ))),
+                9 => Ok(Event::FocusIn(Box::from_raw(raw as *mut XCBFocusInEvent))),
+                10 => Ok(Event::FocusOut(Box::from_raw(raw as *mut XCBFocusOutEvent))),
+                11 => Ok(Event::KeymapNotify(Box::from_raw(
+                    raw as *mut XCBKeymapNotifyEvent,
+                ))),
+                12 => Ok(Event::Expose(Box::from_raw(raw as *mut XCBExposeEvent))),
+                13 => Ok(Event::GraphicsExposure(Box::from_raw(
+                    raw as *mut XCBGraphicsExposureEvent,
+                ))),
+                14 => Ok(Event::NoExposure(Box::from_raw(
+                    raw as *mut XCBNoExposureEvent,
+                ))),
+                15 => Ok(Event::VisibilityNotify(Box::from_raw(
+                    raw as *mut XCBVisibilityNotifyEvent,
+                ))),
+                16 => Ok(Event::CreateNotify(Box::from_raw(
+                    raw as *mut XCBCreateNotifyEvent,
+                ))),
+                17 => Ok(Event::DestroyNotify(Box::from_raw(
+                    raw as *mut XCBDestroyNotifyEvent,
+                ))),
+                18 => Ok(Event::UnmapNotify(Box::from_raw(
+                    raw as *mut XCBUnmapNotifyEvent,
+                ))),
+                19 => Ok(Event::MapNotify(Box::from_raw(
+                    raw as *mut XCBMapNotifyEvent,
+                ))),
+                20 => Ok(Event::MapRequest(Box::from_raw(
+                    raw as *mut XCBMapRequestEvent,
+                ))),
+                21 => Ok(Event::ReparentNotify(Box::from_raw(
+                    raw as *mut XCBReparentNotifyEvent,
+                ))),
+                22 => Ok(Event::ConfigureNotify(Box::from_raw(
+                    raw as *mut XCBConfigureNotifyEvent,
+                ))),
+                23 => Ok(Event::ConfigureRequest(Box::from_raw(
+                    raw as *mut XCBConfigureRequestEvent,
+                ))),
+                24 => Ok(Event::GravityNotify(Box::from_raw(
+                    raw as *mut XCBGravityNotifyEvent,
+                ))),
+                25 => Ok(Event::ResizeRequest(Box::from_raw(
+                    raw as *mut XCBResizeRequestEvent,
+                ))),
+                26 => Ok(Event::CirculateNotify(Box::from_raw(
+                    raw as *mut XCBCirculateNotifyEvent,
+                ))),
+                27 => Ok(Event::CirculateRequest(Box::from_raw(
+                    raw as *mut XCBCirculateRequestEvent,
+                ))),
+                28 => Ok(Event::PropertyNotify(Box::from_raw(
+                    raw as *mut XCBPropertyNotifyEvent,
+                ))),
+                29 => Ok(Event::SelectionClear(Box::from_raw(
+                    raw as *mut XCBSelectionClearEvent,
+                ))),
+                30 => Ok(Event::SelectionRequest(Box::from_raw(
+                    raw as *mut XCBSelectionRequestEvent,
+                ))),
+                31 => Ok(Event::SelectionNotify(Box::from_raw(
+                    raw as *mut XCBSelectionNotifyEvent,
+                ))),
+                32 => Ok(Event::ColormapNotify(Box::from_raw(
+                    raw as *mut XCBColormapNotifyEvent,
+                ))),
+                33 => Ok(Event::ClientMessage(Box::from_raw(
+                    raw as *mut XCBClientMessageEvent,
+                ))),
+                34 => Ok(Event::MappingNotify(Box::from_raw(
+                    raw as *mut XCBMappingNotifyEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+    /// Decodes a generic event whose type is `event_type`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode_generic(
+        event_type: u16,
+        event: Box<XCBGenericEvent>,
+    ) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match event_type {
+                35 => Ok(Event::GeGeneric(Box::from_raw(
+                    raw as *mut XCBGeGenericEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
+
+/// Returns the name of the error whose code is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        1 => Some("Request"),
+        2 => Some("Value"),
+        3 => Some("Window"),
+        4 => Some("Pixmap"),
+        5 => Some("Atom"),
+        6 => Some("Cursor"),
+        7 => Some("Font"),
+        8 => Some("Match"),
+        9 => Some("Drawable"),
+        10 => Some("Access"),
+        11 => Some("Alloc"),
+        12 => Some("Colormap"),
+        13 => Some("GContext"),
+        14 => Some("IDChoice"),
+        15 => Some("Name"),
+        16 => Some("Length"),
+        17 => Some("Implementation"),
+        _ => None,
+    }
+}
@@ -0,0 +1,835 @@
+// Generated by xcbgen from xml/xselinux.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int};
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xproto::cdef::{XCBAtom, XCBWindow};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub client_major: u8,
+    pub client_minor: u8,
+}
+
+pub type XCBSelinuxQueryVersionCookie = XCBCookie<XCBSelinuxQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub server_major: u16,
+    pub server_minor: u16,
+}
+
+#[repr(C)]
+pub struct XCBSelinuxSetDeviceCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_len: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetDeviceCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBSelinuxGetDeviceCreateContextCookie = XCBCookie<XCBSelinuxGetDeviceCreateContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetDeviceCreateContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBSelinuxSetDeviceContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device: u32,
+    pub context_len: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetDeviceContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub device: u32,
+}
+
+pub type XCBSelinuxGetDeviceContextCookie = XCBCookie<XCBSelinuxGetDeviceContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetDeviceContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBSelinuxSetWindowCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_len: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetWindowCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBSelinuxGetWindowCreateContextCookie = XCBCookie<XCBSelinuxGetWindowCreateContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetWindowCreateContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetWindowContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBSelinuxGetWindowContextCookie = XCBCookie<XCBSelinuxGetWindowContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetWindowContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBSelinuxListItem {
+    pub name: XCBAtom,
+    pub object_context_len: u32,
+    pub data_context_len: u32,
+}
+
+#[repr(C)]
+pub struct XCBSelinuxListItemIterator {
+    pub data: *mut XCBSelinuxListItem,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBSelinuxSetPropertyCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_len: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetPropertyCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBSelinuxGetPropertyCreateContextCookie =
+    XCBCookie<XCBSelinuxGetPropertyCreateContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetPropertyCreateContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBSelinuxSetPropertyUseContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_len: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetPropertyUseContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBSelinuxGetPropertyUseContextCookie = XCBCookie<XCBSelinuxGetPropertyUseContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetPropertyUseContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetPropertyContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub property: XCBAtom,
+}
+
+pub type XCBSelinuxGetPropertyContextCookie = XCBCookie<XCBSelinuxGetPropertyContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetPropertyContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetPropertyDataContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub property: XCBAtom,
+}
+
+pub type XCBSelinuxGetPropertyDataContextCookie = XCBCookie<XCBSelinuxGetPropertyDataContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetPropertyDataContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxListPropertiesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBSelinuxListPropertiesCookie = XCBCookie<XCBSelinuxListPropertiesReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxListPropertiesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub properties_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBSelinuxSetSelectionCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_len: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetSelectionCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBSelinuxGetSelectionCreateContextCookie =
+    XCBCookie<XCBSelinuxGetSelectionCreateContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetSelectionCreateContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+pub struct XCBSelinuxSetSelectionUseContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_len: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetSelectionUseContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBSelinuxGetSelectionUseContextCookie = XCBCookie<XCBSelinuxGetSelectionUseContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetSelectionUseContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetSelectionContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub selection: XCBAtom,
+}
+
+pub type XCBSelinuxGetSelectionContextCookie = XCBCookie<XCBSelinuxGetSelectionContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetSelectionContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetSelectionDataContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub selection: XCBAtom,
+}
+
+pub type XCBSelinuxGetSelectionDataContextCookie =
+    XCBCookie<XCBSelinuxGetSelectionDataContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetSelectionDataContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxListSelectionsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBSelinuxListSelectionsCookie = XCBCookie<XCBSelinuxListSelectionsReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxListSelectionsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub selections_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBSelinuxGetClientContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub resource: u32,
+}
+
+pub type XCBSelinuxGetClientContextCookie = XCBCookie<XCBSelinuxGetClientContextReply>;
+
+#[repr(C)]
+pub struct XCBSelinuxGetClientContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub context_len: u32,
+    pub pad1: [u8; 20],
+}
+
+#[link(name = "xcb-xselinux")]
+extern "system" {
+    pub static mut xcb_selinux_id: XCBExtension;
+    pub fn xcb_selinux_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxQueryVersionReply;
+    pub fn xcb_selinux_query_version(
+        connection: *mut XCBConnection,
+        client_major: u8,
+        client_minor: u8,
+    ) -> XCBSelinuxQueryVersionCookie;
+    pub fn xcb_selinux_query_version_unchecked(
+        connection: *mut XCBConnection,
+        client_major: u8,
+        client_minor: u8,
+    ) -> XCBSelinuxQueryVersionCookie;
+    pub fn xcb_selinux_set_device_create_context_checked(
+        connection: *mut XCBConnection,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_set_device_create_context(
+        connection: *mut XCBConnection,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_get_device_create_context_context(
+        R: *const XCBSelinuxGetDeviceCreateContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_device_create_context_context_length(
+        R: *const XCBSelinuxGetDeviceCreateContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_device_create_context_context_end(
+        R: *const XCBSelinuxGetDeviceCreateContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_device_create_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetDeviceCreateContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetDeviceCreateContextReply;
+    pub fn xcb_selinux_get_device_create_context(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxGetDeviceCreateContextCookie;
+    pub fn xcb_selinux_get_device_create_context_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxGetDeviceCreateContextCookie;
+    pub fn xcb_selinux_set_device_context_checked(
+        connection: *mut XCBConnection,
+        device: u32,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_set_device_context(
+        connection: *mut XCBConnection,
+        device: u32,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_get_device_context_context(
+        R: *const XCBSelinuxGetDeviceContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_device_context_context_length(
+        R: *const XCBSelinuxGetDeviceContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_device_context_context_end(
+        R: *const XCBSelinuxGetDeviceContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_device_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetDeviceContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetDeviceContextReply;
+    pub fn xcb_selinux_get_device_context(
+        connection: *mut XCBConnection,
+        device: u32,
+    ) -> XCBSelinuxGetDeviceContextCookie;
+    pub fn xcb_selinux_get_device_context_unchecked(
+        connection: *mut XCBConnection,
+        device: u32,
+    ) -> XCBSelinuxGetDeviceContextCookie;
+    pub fn xcb_selinux_set_window_create_context_checked(
+        connection: *mut XCBConnection,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_set_window_create_context(
+        connection: *mut XCBConnection,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_get_window_create_context_context(
+        R: *const XCBSelinuxGetWindowCreateContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_window_create_context_context_length(
+        R: *const XCBSelinuxGetWindowCreateContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_window_create_context_context_end(
+        R: *const XCBSelinuxGetWindowCreateContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_window_create_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetWindowCreateContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetWindowCreateContextReply;
+    pub fn xcb_selinux_get_window_create_context(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxGetWindowCreateContextCookie;
+    pub fn xcb_selinux_get_window_create_context_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxGetWindowCreateContextCookie;
+    pub fn xcb_selinux_get_window_context_context(
+        R: *const XCBSelinuxGetWindowContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_window_context_context_length(
+        R: *const XCBSelinuxGetWindowContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_window_context_context_end(
+        R: *const XCBSelinuxGetWindowContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_window_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetWindowContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetWindowContextReply;
+    pub fn xcb_selinux_get_window_context(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBSelinuxGetWindowContextCookie;
+    pub fn xcb_selinux_get_window_context_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBSelinuxGetWindowContextCookie;
+    pub fn xcb_selinux_list_item_next(i: *mut XCBSelinuxListItemIterator);
+    pub fn xcb_selinux_list_item_end(i: XCBSelinuxListItemIterator) -> XCBGenericIterator;
+    pub fn xcb_selinux_list_item_object_context(R: *const XCBSelinuxListItem) -> *mut c_char;
+    pub fn xcb_selinux_list_item_object_context_length(R: *const XCBSelinuxListItem) -> c_int;
+    pub fn xcb_selinux_list_item_object_context_end(
+        R: *const XCBSelinuxListItem,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_list_item_data_context(R: *const XCBSelinuxListItem) -> *mut c_char;
+    pub fn xcb_selinux_list_item_data_context_length(R: *const XCBSelinuxListItem) -> c_int;
+    pub fn xcb_selinux_list_item_data_context_end(
+        R: *const XCBSelinuxListItem,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_set_property_create_context_checked(
+        connection: *mut XCBConnection,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_set_property_create_context(
+        connection: *mut XCBConnection,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_get_property_create_context_context(
+        R: *const XCBSelinuxGetPropertyCreateContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_property_create_context_context_length(
+        R: *const XCBSelinuxGetPropertyCreateContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_property_create_context_context_end(
+        R: *const XCBSelinuxGetPropertyCreateContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_property_create_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetPropertyCreateContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetPropertyCreateContextReply;
+    pub fn xcb_selinux_get_property_create_context(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxGetPropertyCreateContextCookie;
+    pub fn xcb_selinux_get_property_create_context_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxGetPropertyCreateContextCookie;
+    pub fn xcb_selinux_set_property_use_context_checked(
+        connection: *mut XCBConnection,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_set_property_use_context(
+        connection: *mut XCBConnection,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_get_property_use_context_context(
+        R: *const XCBSelinuxGetPropertyUseContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_property_use_context_context_length(
+        R: *const XCBSelinuxGetPropertyUseContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_property_use_context_context_end(
+        R: *const XCBSelinuxGetPropertyUseContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_property_use_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetPropertyUseContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetPropertyUseContextReply;
+    pub fn xcb_selinux_get_property_use_context(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxGetPropertyUseContextCookie;
+    pub fn xcb_selinux_get_property_use_context_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxGetPropertyUseContextCookie;
+    pub fn xcb_selinux_get_property_context_context(
+        R: *const XCBSelinuxGetPropertyContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_property_context_context_length(
+        R: *const XCBSelinuxGetPropertyContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_property_context_context_end(
+        R: *const XCBSelinuxGetPropertyContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_property_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetPropertyContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetPropertyContextReply;
+    pub fn xcb_selinux_get_property_context(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        property: XCBAtom,
+    ) -> XCBSelinuxGetPropertyContextCookie;
+    pub fn xcb_selinux_get_property_context_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        property: XCBAtom,
+    ) -> XCBSelinuxGetPropertyContextCookie;
+    pub fn xcb_selinux_get_property_data_context_context(
+        R: *const XCBSelinuxGetPropertyDataContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_property_data_context_context_length(
+        R: *const XCBSelinuxGetPropertyDataContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_property_data_context_context_end(
+        R: *const XCBSelinuxGetPropertyDataContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_property_data_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetPropertyDataContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetPropertyDataContextReply;
+    pub fn xcb_selinux_get_property_data_context(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        property: XCBAtom,
+    ) -> XCBSelinuxGetPropertyDataContextCookie;
+    pub fn xcb_selinux_get_property_data_context_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        property: XCBAtom,
+    ) -> XCBSelinuxGetPropertyDataContextCookie;
+    pub fn xcb_selinux_list_properties_properties_length(
+        R: *const XCBSelinuxListPropertiesReply,
+    ) -> c_int;
+    pub fn xcb_selinux_list_properties_properties_iterator(
+        R: *const XCBSelinuxListPropertiesReply,
+    ) -> XCBSelinuxListItemIterator;
+    pub fn xcb_selinux_list_properties_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxListPropertiesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxListPropertiesReply;
+    pub fn xcb_selinux_list_properties(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBSelinuxListPropertiesCookie;
+    pub fn xcb_selinux_list_properties_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBSelinuxListPropertiesCookie;
+    pub fn xcb_selinux_set_selection_create_context_checked(
+        connection: *mut XCBConnection,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_set_selection_create_context(
+        connection: *mut XCBConnection,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_get_selection_create_context_context(
+        R: *const XCBSelinuxGetSelectionCreateContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_selection_create_context_context_length(
+        R: *const XCBSelinuxGetSelectionCreateContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_selection_create_context_context_end(
+        R: *const XCBSelinuxGetSelectionCreateContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_selection_create_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetSelectionCreateContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetSelectionCreateContextReply;
+    pub fn xcb_selinux_get_selection_create_context(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxGetSelectionCreateContextCookie;
+    pub fn xcb_selinux_get_selection_create_context_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxGetSelectionCreateContextCookie;
+    pub fn xcb_selinux_set_selection_use_context_checked(
+        connection: *mut XCBConnection,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_set_selection_use_context(
+        connection: *mut XCBConnection,
+        context_len: u32,
+        context: *const c_char,
+    ) -> XCBVoidCookie;
+    pub fn xcb_selinux_get_selection_use_context_context(
+        R: *const XCBSelinuxGetSelectionUseContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_selection_use_context_context_length(
+        R: *const XCBSelinuxGetSelectionUseContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_selection_use_context_context_end(
+        R: *const XCBSelinuxGetSelectionUseContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_selection_use_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetSelectionUseContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetSelectionUseContextReply;
+    pub fn xcb_selinux_get_selection_use_context(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxGetSelectionUseContextCookie;
+    pub fn xcb_selinux_get_selection_use_context_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxGetSelectionUseContextCookie;
+    pub fn xcb_selinux_get_selection_context_context(
+        R: *const XCBSelinuxGetSelectionContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_selection_context_context_length(
+        R: *const XCBSelinuxGetSelectionContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_selection_context_context_end(
+        R: *const XCBSelinuxGetSelectionContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_selection_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetSelectionContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetSelectionContextReply;
+    pub fn xcb_selinux_get_selection_context(
+        connection: *mut XCBConnection,
+        selection: XCBAtom,
+    ) -> XCBSelinuxGetSelectionContextCookie;
+    pub fn xcb_selinux_get_selection_context_unchecked(
+        connection: *mut XCBConnection,
+        selection: XCBAtom,
+    ) -> XCBSelinuxGetSelectionContextCookie;
+    pub fn xcb_selinux_get_selection_data_context_context(
+        R: *const XCBSelinuxGetSelectionDataContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_selection_data_context_context_length(
+        R: *const XCBSelinuxGetSelectionDataContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_selection_data_context_context_end(
+        R: *const XCBSelinuxGetSelectionDataContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_selection_data_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetSelectionDataContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetSelectionDataContextReply;
+    pub fn xcb_selinux_get_selection_data_context(
+        connection: *mut XCBConnection,
+        selection: XCBAtom,
+    ) -> XCBSelinuxGetSelectionDataContextCookie;
+    pub fn xcb_selinux_get_selection_data_context_unchecked(
+        connection: *mut XCBConnection,
+        selection: XCBAtom,
+    ) -> XCBSelinuxGetSelectionDataContextCookie;
+    pub fn xcb_selinux_list_selections_selections_length(
+        R: *const XCBSelinuxListSelectionsReply,
+    ) -> c_int;
+    pub fn xcb_selinux_list_selections_selections_iterator(
+        R: *const XCBSelinuxListSelectionsReply,
+    ) -> XCBSelinuxListItemIterator;
+    pub fn xcb_selinux_list_selections_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxListSelectionsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxListSelectionsReply;
+    pub fn xcb_selinux_list_selections(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxListSelectionsCookie;
+    pub fn xcb_selinux_list_selections_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBSelinuxListSelectionsCookie;
+    pub fn xcb_selinux_get_client_context_context(
+        R: *const XCBSelinuxGetClientContextReply,
+    ) -> *mut c_char;
+    pub fn xcb_selinux_get_client_context_context_length(
+        R: *const XCBSelinuxGetClientContextReply,
+    ) -> c_int;
+    pub fn xcb_selinux_get_client_context_context_end(
+        R: *const XCBSelinuxGetClientContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_selinux_get_client_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBSelinuxGetClientContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBSelinuxGetClientContextReply;
+    pub fn xcb_selinux_get_client_context(
+        connection: *mut XCBConnection,
+        resource: u32,
+    ) -> XCBSelinuxGetClientContextCookie;
+    pub fn xcb_selinux_get_client_context_unchecked(
+        connection: *mut XCBConnection,
+        resource: u32,
+    ) -> XCBSelinuxGetClientContextCookie;
+}
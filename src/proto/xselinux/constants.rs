@@ -0,0 +1,27 @@
+// Generated by xcbgen from xml/xselinux.xml, do not edit.
+
+pub const XCB_SELINUX_MAJOR_VERSION: u32 = 1;
+pub const XCB_SELINUX_MINOR_VERSION: u32 = 0;
+pub const XCB_SELINUX_QUERY_VERSION: u8 = 0;
+pub const XCB_SELINUX_SET_DEVICE_CREATE_CONTEXT: u8 = 1;
+pub const XCB_SELINUX_GET_DEVICE_CREATE_CONTEXT: u8 = 2;
+pub const XCB_SELINUX_SET_DEVICE_CONTEXT: u8 = 3;
+pub const XCB_SELINUX_GET_DEVICE_CONTEXT: u8 = 4;
+pub const XCB_SELINUX_SET_WINDOW_CREATE_CONTEXT: u8 = 5;
+pub const XCB_SELINUX_GET_WINDOW_CREATE_CONTEXT: u8 = 6;
+pub const XCB_SELINUX_GET_WINDOW_CONTEXT: u8 = 7;
+pub const XCB_SELINUX_SET_PROPERTY_CREATE_CONTEXT: u8 = 8;
+pub const XCB_SELINUX_GET_PROPERTY_CREATE_CONTEXT: u8 = 9;
+pub const XCB_SELINUX_SET_PROPERTY_USE_CONTEXT: u8 = 10;
+pub const XCB_SELINUX_GET_PROPERTY_USE_CONTEXT: u8 = 11;
+pub const XCB_SELINUX_GET_PROPERTY_CONTEXT: u8 = 12;
+pub const XCB_SELINUX_GET_PROPERTY_DATA_CONTEXT: u8 = 13;
+pub const XCB_SELINUX_LIST_PROPERTIES: u8 = 14;
+pub const XCB_SELINUX_SET_SELECTION_CREATE_CONTEXT: u8 = 15;
+pub const XCB_SELINUX_GET_SELECTION_CREATE_CONTEXT: u8 = 16;
+pub const XCB_SELINUX_SET_SELECTION_USE_CONTEXT: u8 = 17;
+pub const XCB_SELINUX_GET_SELECTION_USE_CONTEXT: u8 = 18;
+pub const XCB_SELINUX_GET_SELECTION_CONTEXT: u8 = 19;
+pub const XCB_SELINUX_GET_SELECTION_DATA_CONTEXT: u8 = 20;
+pub const XCB_SELINUX_LIST_SELECTIONS: u8 = 21;
+pub const XCB_SELINUX_GET_CLIENT_CONTEXT: u8 = 22;
@@ -0,0 +1,615 @@
+// Generated by xcbgen from xml/xselinux.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::os::raw::c_char;
+use std::ptr;
+use std::slice;
+
+use proto::xproto::{XCBAtom, XCBWindow};
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+
+pub type XCBSelinuxQueryVersionRequest = cdef::XCBSelinuxQueryVersionRequest;
+pub type XCBSelinuxQueryVersionCookie = cdef::XCBSelinuxQueryVersionCookie;
+pub type XCBSelinuxQueryVersionReply = cdef::XCBSelinuxQueryVersionReply;
+pub type XCBSelinuxSetDeviceCreateContextRequest = cdef::XCBSelinuxSetDeviceCreateContextRequest;
+pub type XCBSelinuxGetDeviceCreateContextRequest = cdef::XCBSelinuxGetDeviceCreateContextRequest;
+pub type XCBSelinuxGetDeviceCreateContextCookie = cdef::XCBSelinuxGetDeviceCreateContextCookie;
+pub type XCBSelinuxGetDeviceCreateContextReply = cdef::XCBSelinuxGetDeviceCreateContextReply;
+pub type XCBSelinuxSetDeviceContextRequest = cdef::XCBSelinuxSetDeviceContextRequest;
+pub type XCBSelinuxGetDeviceContextRequest = cdef::XCBSelinuxGetDeviceContextRequest;
+pub type XCBSelinuxGetDeviceContextCookie = cdef::XCBSelinuxGetDeviceContextCookie;
+pub type XCBSelinuxGetDeviceContextReply = cdef::XCBSelinuxGetDeviceContextReply;
+pub type XCBSelinuxSetWindowCreateContextRequest = cdef::XCBSelinuxSetWindowCreateContextRequest;
+pub type XCBSelinuxGetWindowCreateContextRequest = cdef::XCBSelinuxGetWindowCreateContextRequest;
+pub type XCBSelinuxGetWindowCreateContextCookie = cdef::XCBSelinuxGetWindowCreateContextCookie;
+pub type XCBSelinuxGetWindowCreateContextReply = cdef::XCBSelinuxGetWindowCreateContextReply;
+pub type XCBSelinuxGetWindowContextRequest = cdef::XCBSelinuxGetWindowContextRequest;
+pub type XCBSelinuxGetWindowContextCookie = cdef::XCBSelinuxGetWindowContextCookie;
+pub type XCBSelinuxGetWindowContextReply = cdef::XCBSelinuxGetWindowContextReply;
+pub type XCBSelinuxListItem = cdef::XCBSelinuxListItem;
+pub type XCBSelinuxListItemIterator = cdef::XCBSelinuxListItemIterator;
+pub type XCBSelinuxSetPropertyCreateContextRequest =
+    cdef::XCBSelinuxSetPropertyCreateContextRequest;
+pub type XCBSelinuxGetPropertyCreateContextRequest =
+    cdef::XCBSelinuxGetPropertyCreateContextRequest;
+pub type XCBSelinuxGetPropertyCreateContextCookie = cdef::XCBSelinuxGetPropertyCreateContextCookie;
+pub type XCBSelinuxGetPropertyCreateContextReply = cdef::XCBSelinuxGetPropertyCreateContextReply;
+pub type XCBSelinuxSetPropertyUseContextRequest = cdef::XCBSelinuxSetPropertyUseContextRequest;
+pub type XCBSelinuxGetPropertyUseContextRequest = cdef::XCBSelinuxGetPropertyUseContextRequest;
+pub type XCBSelinuxGetPropertyUseContextCookie = cdef::XCBSelinuxGetPropertyUseContextCookie;
+pub type XCBSelinuxGetPropertyUseContextReply = cdef::XCBSelinuxGetPropertyUseContextReply;
+pub type XCBSelinuxGetPropertyContextRequest = cdef::XCBSelinuxGetPropertyContextRequest;
+pub type XCBSelinuxGetPropertyContextCookie = cdef::XCBSelinuxGetPropertyContextCookie;
+pub type XCBSelinuxGetPropertyContextReply = cdef::XCBSelinuxGetPropertyContextReply;
+pub type XCBSelinuxGetPropertyDataContextRequest = cdef::XCBSelinuxGetPropertyDataContextRequest;
+pub type XCBSelinuxGetPropertyDataContextCookie = cdef::XCBSelinuxGetPropertyDataContextCookie;
+pub type XCBSelinuxGetPropertyDataContextReply = cdef::XCBSelinuxGetPropertyDataContextReply;
+pub type XCBSelinuxListPropertiesRequest = cdef::XCBSelinuxListPropertiesRequest;
+pub type XCBSelinuxListPropertiesCookie = cdef::XCBSelinuxListPropertiesCookie;
+pub type XCBSelinuxListPropertiesReply = cdef::XCBSelinuxListPropertiesReply;
+pub type XCBSelinuxSetSelectionCreateContextRequest =
+    cdef::XCBSelinuxSetSelectionCreateContextRequest;
+pub type XCBSelinuxGetSelectionCreateContextRequest =
+    cdef::XCBSelinuxGetSelectionCreateContextRequest;
+pub type XCBSelinuxGetSelectionCreateContextCookie =
+    cdef::XCBSelinuxGetSelectionCreateContextCookie;
+pub type XCBSelinuxGetSelectionCreateContextReply = cdef::XCBSelinuxGetSelectionCreateContextReply;
+pub type XCBSelinuxSetSelectionUseContextRequest = cdef::XCBSelinuxSetSelectionUseContextRequest;
+pub type XCBSelinuxGetSelectionUseContextRequest = cdef::XCBSelinuxGetSelectionUseContextRequest;
+pub type XCBSelinuxGetSelectionUseContextCookie = cdef::XCBSelinuxGetSelectionUseContextCookie;
+pub type XCBSelinuxGetSelectionUseContextReply = cdef::XCBSelinuxGetSelectionUseContextReply;
+pub type XCBSelinuxGetSelectionContextRequest = cdef::XCBSelinuxGetSelectionContextRequest;
+pub type XCBSelinuxGetSelectionContextCookie = cdef::XCBSelinuxGetSelectionContextCookie;
+pub type XCBSelinuxGetSelectionContextReply = cdef::XCBSelinuxGetSelectionContextReply;
+pub type XCBSelinuxGetSelectionDataContextRequest = cdef::XCBSelinuxGetSelectionDataContextRequest;
+pub type XCBSelinuxGetSelectionDataContextCookie = cdef::XCBSelinuxGetSelectionDataContextCookie;
+pub type XCBSelinuxGetSelectionDataContextReply = cdef::XCBSelinuxGetSelectionDataContextReply;
+pub type XCBSelinuxListSelectionsRequest = cdef::XCBSelinuxListSelectionsRequest;
+pub type XCBSelinuxListSelectionsCookie = cdef::XCBSelinuxListSelectionsCookie;
+pub type XCBSelinuxListSelectionsReply = cdef::XCBSelinuxListSelectionsReply;
+pub type XCBSelinuxGetClientContextRequest = cdef::XCBSelinuxGetClientContextRequest;
+pub type XCBSelinuxGetClientContextCookie = cdef::XCBSelinuxGetClientContextCookie;
+pub type XCBSelinuxGetClientContextReply = cdef::XCBSelinuxGetClientContextReply;
+
+/// The SELinux extension, known to the server as `SELinux`.
+pub struct Xselinux;
+
+impl Extension for Xselinux {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_selinux_id)
+    }
+}
+
+impl Reply for XCBSelinuxQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_query_version(
+    connection: &XCBConnection,
+    client_major: u8,
+    client_minor: u8,
+) -> XCBSelinuxQueryVersionCookie {
+    unsafe { cdef::xcb_selinux_query_version(connection.raw, client_major, client_minor) }
+}
+
+pub fn xcb_selinux_set_device_create_context(
+    connection: &XCBConnection,
+    context: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_selinux_set_device_create_context(
+            connection.raw,
+            context.len() as u32,
+            context.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_selinux_get_device_create_context_context(
+    reply: &XCBSelinuxGetDeviceCreateContextReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_device_create_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_device_create_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetDeviceCreateContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_device_create_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_device_create_context(
+    connection: &XCBConnection,
+) -> XCBSelinuxGetDeviceCreateContextCookie {
+    unsafe { cdef::xcb_selinux_get_device_create_context(connection.raw) }
+}
+
+pub fn xcb_selinux_set_device_context(
+    connection: &XCBConnection,
+    device: u32,
+    context: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_selinux_set_device_context(
+            connection.raw,
+            device,
+            context.len() as u32,
+            context.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_selinux_get_device_context_context(reply: &XCBSelinuxGetDeviceContextReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_device_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_device_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetDeviceContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_device_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_device_context(
+    connection: &XCBConnection,
+    device: u32,
+) -> XCBSelinuxGetDeviceContextCookie {
+    unsafe { cdef::xcb_selinux_get_device_context(connection.raw, device) }
+}
+
+pub fn xcb_selinux_set_window_create_context(
+    connection: &XCBConnection,
+    context: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_selinux_set_window_create_context(
+            connection.raw,
+            context.len() as u32,
+            context.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_selinux_get_window_create_context_context(
+    reply: &XCBSelinuxGetWindowCreateContextReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_window_create_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_window_create_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetWindowCreateContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_window_create_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_window_create_context(
+    connection: &XCBConnection,
+) -> XCBSelinuxGetWindowCreateContextCookie {
+    unsafe { cdef::xcb_selinux_get_window_create_context(connection.raw) }
+}
+
+pub fn xcb_selinux_get_window_context_context(reply: &XCBSelinuxGetWindowContextReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_window_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_window_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetWindowContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_window_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_window_context(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBSelinuxGetWindowContextCookie {
+    unsafe { cdef::xcb_selinux_get_window_context(connection.raw, window) }
+}
+
+pub fn xcb_selinux_list_item_next(iter: &mut XCBSelinuxListItemIterator) {
+    unsafe { cdef::xcb_selinux_list_item_next(iter) }
+}
+
+pub fn xcb_selinux_list_item_object_context(data: &XCBSelinuxListItem) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_list_item_object_context(data) as *const u8,
+            cdef::xcb_selinux_list_item_object_context_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_selinux_list_item_data_context(data: &XCBSelinuxListItem) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_list_item_data_context(data) as *const u8,
+            cdef::xcb_selinux_list_item_data_context_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_selinux_set_property_create_context(
+    connection: &XCBConnection,
+    context: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_selinux_set_property_create_context(
+            connection.raw,
+            context.len() as u32,
+            context.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_selinux_get_property_create_context_context(
+    reply: &XCBSelinuxGetPropertyCreateContextReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_property_create_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_property_create_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetPropertyCreateContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_property_create_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_property_create_context(
+    connection: &XCBConnection,
+) -> XCBSelinuxGetPropertyCreateContextCookie {
+    unsafe { cdef::xcb_selinux_get_property_create_context(connection.raw) }
+}
+
+pub fn xcb_selinux_set_property_use_context(
+    connection: &XCBConnection,
+    context: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_selinux_set_property_use_context(
+            connection.raw,
+            context.len() as u32,
+            context.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_selinux_get_property_use_context_context(
+    reply: &XCBSelinuxGetPropertyUseContextReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_property_use_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_property_use_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetPropertyUseContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_property_use_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_property_use_context(
+    connection: &XCBConnection,
+) -> XCBSelinuxGetPropertyUseContextCookie {
+    unsafe { cdef::xcb_selinux_get_property_use_context(connection.raw) }
+}
+
+pub fn xcb_selinux_get_property_context_context(
+    reply: &XCBSelinuxGetPropertyContextReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_property_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_property_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetPropertyContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_property_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_property_context(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    property: XCBAtom,
+) -> XCBSelinuxGetPropertyContextCookie {
+    unsafe { cdef::xcb_selinux_get_property_context(connection.raw, window, property) }
+}
+
+pub fn xcb_selinux_get_property_data_context_context(
+    reply: &XCBSelinuxGetPropertyDataContextReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_property_data_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_property_data_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetPropertyDataContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_property_data_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_property_data_context(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    property: XCBAtom,
+) -> XCBSelinuxGetPropertyDataContextCookie {
+    unsafe { cdef::xcb_selinux_get_property_data_context(connection.raw, window, property) }
+}
+
+pub fn xcb_selinux_list_properties_properties_iterator(
+    reply: &XCBSelinuxListPropertiesReply,
+) -> XCBSelinuxListItemIterator {
+    unsafe { cdef::xcb_selinux_list_properties_properties_iterator(reply) }
+}
+
+impl Reply for XCBSelinuxListPropertiesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_list_properties_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_list_properties(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBSelinuxListPropertiesCookie {
+    unsafe { cdef::xcb_selinux_list_properties(connection.raw, window) }
+}
+
+pub fn xcb_selinux_set_selection_create_context(
+    connection: &XCBConnection,
+    context: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_selinux_set_selection_create_context(
+            connection.raw,
+            context.len() as u32,
+            context.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_selinux_get_selection_create_context_context(
+    reply: &XCBSelinuxGetSelectionCreateContextReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_selection_create_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_selection_create_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetSelectionCreateContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_selection_create_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_selection_create_context(
+    connection: &XCBConnection,
+) -> XCBSelinuxGetSelectionCreateContextCookie {
+    unsafe { cdef::xcb_selinux_get_selection_create_context(connection.raw) }
+}
+
+pub fn xcb_selinux_set_selection_use_context(
+    connection: &XCBConnection,
+    context: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_selinux_set_selection_use_context(
+            connection.raw,
+            context.len() as u32,
+            context.as_ptr() as *const c_char,
+        )
+    }
+}
+
+pub fn xcb_selinux_get_selection_use_context_context(
+    reply: &XCBSelinuxGetSelectionUseContextReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_selection_use_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_selection_use_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetSelectionUseContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_selection_use_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_selection_use_context(
+    connection: &XCBConnection,
+) -> XCBSelinuxGetSelectionUseContextCookie {
+    unsafe { cdef::xcb_selinux_get_selection_use_context(connection.raw) }
+}
+
+pub fn xcb_selinux_get_selection_context_context(
+    reply: &XCBSelinuxGetSelectionContextReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_selection_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_selection_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetSelectionContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_selection_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_selection_context(
+    connection: &XCBConnection,
+    selection: XCBAtom,
+) -> XCBSelinuxGetSelectionContextCookie {
+    unsafe { cdef::xcb_selinux_get_selection_context(connection.raw, selection) }
+}
+
+pub fn xcb_selinux_get_selection_data_context_context(
+    reply: &XCBSelinuxGetSelectionDataContextReply,
+) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_selection_data_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_selection_data_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetSelectionDataContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_selection_data_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_selection_data_context(
+    connection: &XCBConnection,
+    selection: XCBAtom,
+) -> XCBSelinuxGetSelectionDataContextCookie {
+    unsafe { cdef::xcb_selinux_get_selection_data_context(connection.raw, selection) }
+}
+
+pub fn xcb_selinux_list_selections_selections_iterator(
+    reply: &XCBSelinuxListSelectionsReply,
+) -> XCBSelinuxListItemIterator {
+    unsafe { cdef::xcb_selinux_list_selections_selections_iterator(reply) }
+}
+
+impl Reply for XCBSelinuxListSelectionsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_list_selections_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_list_selections(connection: &XCBConnection) -> XCBSelinuxListSelectionsCookie {
+    unsafe { cdef::xcb_selinux_list_selections(connection.raw) }
+}
+
+pub fn xcb_selinux_get_client_context_context(reply: &XCBSelinuxGetClientContextReply) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_selinux_get_client_context_context(reply) as *const u8,
+            cdef::xcb_selinux_get_client_context_context_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBSelinuxGetClientContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_selinux_get_client_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_selinux_get_client_context(
+    connection: &XCBConnection,
+    resource: u32,
+) -> XCBSelinuxGetClientContextCookie {
+    unsafe { cdef::xcb_selinux_get_client_context(connection.raw, resource) }
+}
@@ -0,0 +1,138 @@
+// Generated by xcbgen from xml/xtest.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use cdef::{XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+use proto::xproto::cdef::{XCBCursor, XCBWindow};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBTestGetVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub major_version: u8,
+    pub pad0: u8,
+    pub minor_version: u16,
+}
+
+pub type XCBTestGetVersionCookie = XCBCookie<XCBTestGetVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBTestGetVersionReply {
+    pub response_type: u8,
+    pub major_version: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub minor_version: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBTestCompareCursorRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+    pub cursor: XCBCursor,
+}
+
+pub type XCBTestCompareCursorCookie = XCBCookie<XCBTestCompareCursorReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBTestCompareCursorReply {
+    pub response_type: u8,
+    pub same: u8,
+    pub sequence: u16,
+    pub length: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBTestFakeInputRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub type_: u8,
+    pub detail: u8,
+    pub pad0: [u8; 2],
+    pub time: u32,
+    pub root: XCBWindow,
+    pub pad1: [u8; 8],
+    pub rootX: i16,
+    pub rootY: i16,
+    pub pad2: [u8; 7],
+    pub deviceid: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBTestGrabControlRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub impervious: u8,
+    pub pad0: [u8; 3],
+}
+
+#[link(name = "xcb-xtest")]
+extern "system" {
+    pub static mut xcb_test_id: XCBExtension;
+    pub fn xcb_test_get_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBTestGetVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBTestGetVersionReply;
+    pub fn xcb_test_get_version(
+        connection: *mut XCBConnection,
+        major_version: u8,
+        minor_version: u16,
+    ) -> XCBTestGetVersionCookie;
+    pub fn xcb_test_get_version_unchecked(
+        connection: *mut XCBConnection,
+        major_version: u8,
+        minor_version: u16,
+    ) -> XCBTestGetVersionCookie;
+    pub fn xcb_test_compare_cursor_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBTestCompareCursorCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBTestCompareCursorReply;
+    pub fn xcb_test_compare_cursor(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        cursor: XCBCursor,
+    ) -> XCBTestCompareCursorCookie;
+    pub fn xcb_test_compare_cursor_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+        cursor: XCBCursor,
+    ) -> XCBTestCompareCursorCookie;
+    pub fn xcb_test_fake_input_checked(
+        connection: *mut XCBConnection,
+        type_: u8,
+        detail: u8,
+        time: u32,
+        root: XCBWindow,
+        rootX: i16,
+        rootY: i16,
+        deviceid: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_test_fake_input(
+        connection: *mut XCBConnection,
+        type_: u8,
+        detail: u8,
+        time: u32,
+        root: XCBWindow,
+        rootX: i16,
+        rootY: i16,
+        deviceid: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_test_grab_control_checked(
+        connection: *mut XCBConnection,
+        impervious: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_test_grab_control(connection: *mut XCBConnection, impervious: u8) -> XCBVoidCookie;
+}
@@ -0,0 +1,10 @@
+// Generated by xcbgen from xml/xtest.xml, do not edit.
+
+pub const XCB_TEST_MAJOR_VERSION: u32 = 2;
+pub const XCB_TEST_MINOR_VERSION: u32 = 2;
+pub const XCB_TEST_GET_VERSION: u8 = 0;
+pub const XCB_TEST_CURSOR_NONE: u32 = 0;
+pub const XCB_TEST_CURSOR_CURRENT: u32 = 1;
+pub const XCB_TEST_COMPARE_CURSOR: u8 = 1;
+pub const XCB_TEST_FAKE_INPUT: u8 = 2;
+pub const XCB_TEST_GRAB_CONTROL: u8 = 3;
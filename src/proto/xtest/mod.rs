@@ -0,0 +1,93 @@
+// Generated by xcbgen from xml/xtest.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+
+use proto::xproto::{XCBCursor, XCBWindow};
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+
+pub type XCBTestGetVersionRequest = cdef::XCBTestGetVersionRequest;
+pub type XCBTestGetVersionCookie = cdef::XCBTestGetVersionCookie;
+pub type XCBTestGetVersionReply = cdef::XCBTestGetVersionReply;
+pub type XCBTestCompareCursorRequest = cdef::XCBTestCompareCursorRequest;
+pub type XCBTestCompareCursorCookie = cdef::XCBTestCompareCursorCookie;
+pub type XCBTestCompareCursorReply = cdef::XCBTestCompareCursorReply;
+pub type XCBTestFakeInputRequest = cdef::XCBTestFakeInputRequest;
+pub type XCBTestGrabControlRequest = cdef::XCBTestGrabControlRequest;
+
+/// The Test extension, known to the server as `XTEST`.
+pub struct Xtest;
+
+impl Extension for Xtest {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_test_id)
+    }
+}
+
+impl Reply for XCBTestGetVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_test_get_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_test_get_version(
+    connection: &XCBConnection,
+    major_version: u8,
+    minor_version: u16,
+) -> XCBTestGetVersionCookie {
+    unsafe { cdef::xcb_test_get_version(connection.raw, major_version, minor_version) }
+}
+
+impl Reply for XCBTestCompareCursorReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_test_compare_cursor_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_test_compare_cursor(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    cursor: XCBCursor,
+) -> XCBTestCompareCursorCookie {
+    unsafe { cdef::xcb_test_compare_cursor(connection.raw, window, cursor) }
+}
+
+pub fn xcb_test_fake_input(
+    connection: &XCBConnection,
+    type_: u8,
+    detail: u8,
+    time: u32,
+    root: XCBWindow,
+    rootX: i16,
+    rootY: i16,
+    deviceid: u8,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_test_fake_input(
+            connection.raw,
+            type_,
+            detail,
+            time,
+            root,
+            rootX,
+            rootY,
+            deviceid,
+        )
+    }
+}
+
+pub fn xcb_test_grab_control(connection: &XCBConnection, impervious: bool) -> XCBVoidCookie {
+    unsafe { cdef::xcb_test_grab_control(connection.raw, impervious as u8) }
+}
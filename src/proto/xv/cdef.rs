@@ -0,0 +1,1049 @@
+// Generated by xcbgen from xml/xv.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int};
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::shm::cdef::XCBShmSeg;
+use proto::xproto::cdef::{
+    XCBAtom, XCBDrawable, XCBGcontext, XCBTimestamp, XCBVisualid, XCBWindow,
+};
+
+pub type XCBXvPort = u32;
+
+#[repr(C)]
+pub struct XCBXvPortIterator {
+    pub data: *mut XCBXvPort,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXvEncoding = u32;
+
+#[repr(C)]
+pub struct XCBXvEncodingIterator {
+    pub data: *mut XCBXvEncoding,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvRational {
+    pub numerator: i32,
+    pub denominator: i32,
+}
+
+#[repr(C)]
+pub struct XCBXvRationalIterator {
+    pub data: *mut XCBXvRational,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvFormat {
+    pub visual: XCBVisualid,
+    pub depth: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+pub struct XCBXvFormatIterator {
+    pub data: *mut XCBXvFormat,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXvAdaptorInfo {
+    pub base_id: XCBXvPort,
+    pub name_size: u16,
+    pub num_ports: u16,
+    pub num_formats: u16,
+    pub type_: u8,
+    pub pad0: u8,
+}
+
+#[repr(C)]
+pub struct XCBXvAdaptorInfoIterator {
+    pub data: *mut XCBXvAdaptorInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXvEncodingInfo {
+    pub encoding: XCBXvEncoding,
+    pub name_size: u16,
+    pub width: u16,
+    pub height: u16,
+    pub pad0: [u8; 2],
+    pub rate: XCBXvRational,
+}
+
+#[repr(C)]
+pub struct XCBXvEncodingInfoIterator {
+    pub data: *mut XCBXvEncodingInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXvImage {
+    pub id: u32,
+    pub width: u16,
+    pub height: u16,
+    pub data_size: u32,
+    pub num_planes: u32,
+}
+
+#[repr(C)]
+pub struct XCBXvImageIterator {
+    pub data: *mut XCBXvImage,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+pub struct XCBXvAttributeInfo {
+    pub flags: u32,
+    pub min: i32,
+    pub max: i32,
+    pub size: u32,
+}
+
+#[repr(C)]
+pub struct XCBXvAttributeInfoIterator {
+    pub data: *mut XCBXvAttributeInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvImageFormatInfo {
+    pub id: u32,
+    pub type_: u8,
+    pub byte_order: u8,
+    pub pad0: [u8; 2],
+    pub guid: [u8; 16],
+    pub bpp: u8,
+    pub num_planes: u8,
+    pub pad1: [u8; 2],
+    pub depth: u8,
+    pub pad2: [u8; 3],
+    pub red_mask: u32,
+    pub green_mask: u32,
+    pub blue_mask: u32,
+    pub format: u8,
+    pub pad3: [u8; 3],
+    pub y_sample_bits: u32,
+    pub u_sample_bits: u32,
+    pub v_sample_bits: u32,
+    pub vhorz_y_period: u32,
+    pub vhorz_u_period: u32,
+    pub vhorz_v_period: u32,
+    pub vvert_y_period: u32,
+    pub vvert_u_period: u32,
+    pub vvert_v_period: u32,
+    pub vcomp_order: [u8; 32],
+    pub vscanline_order: u8,
+    pub pad4: [u8; 11],
+}
+
+#[repr(C)]
+pub struct XCBXvImageFormatInfoIterator {
+    pub data: *mut XCBXvImageFormatInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvBadPortError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvBadEncodingError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvBadControlError {
+    pub response_type: u8,
+    pub error_code: u8,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvVideoNotifyEvent {
+    pub response_type: u8,
+    pub reason: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub drawable: XCBDrawable,
+    pub port: XCBXvPort,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvPortNotifyEvent {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub time: XCBTimestamp,
+    pub port: XCBXvPort,
+    pub attribute: XCBAtom,
+    pub value: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvQueryExtensionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBXvQueryExtensionCookie = XCBCookie<XCBXvQueryExtensionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvQueryExtensionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major: u16,
+    pub minor: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvQueryAdaptorsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub window: XCBWindow,
+}
+
+pub type XCBXvQueryAdaptorsCookie = XCBCookie<XCBXvQueryAdaptorsReply>;
+
+#[repr(C)]
+pub struct XCBXvQueryAdaptorsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_adaptors: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvQueryEncodingsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+}
+
+pub type XCBXvQueryEncodingsCookie = XCBCookie<XCBXvQueryEncodingsReply>;
+
+#[repr(C)]
+pub struct XCBXvQueryEncodingsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_encodings: u16,
+    pub pad1: [u8; 22],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvGrabPortRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub time: XCBTimestamp,
+}
+
+pub type XCBXvGrabPortCookie = XCBCookie<XCBXvGrabPortReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvGrabPortReply {
+    pub response_type: u8,
+    pub result: u8,
+    pub sequence: u16,
+    pub length: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvUngrabPortRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub time: XCBTimestamp,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvPutVideoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub vid_x: i16,
+    pub vid_y: i16,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvPutStillRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub vid_x: i16,
+    pub vid_y: i16,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvGetVideoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub vid_x: i16,
+    pub vid_y: i16,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvGetStillRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub vid_x: i16,
+    pub vid_y: i16,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvStopVideoRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub drawable: XCBDrawable,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvSelectVideoNotifyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub drawable: XCBDrawable,
+    pub onoff: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvSelectPortNotifyRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub onoff: u8,
+    pub pad0: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvQueryBestSizeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+    pub motion: u8,
+    pub pad0: [u8; 3],
+}
+
+pub type XCBXvQueryBestSizeCookie = XCBCookie<XCBXvQueryBestSizeReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvQueryBestSizeReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub actual_width: u16,
+    pub actual_height: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvSetPortAttributeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub attribute: XCBAtom,
+    pub value: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvGetPortAttributeRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub attribute: XCBAtom,
+}
+
+pub type XCBXvGetPortAttributeCookie = XCBCookie<XCBXvGetPortAttributeReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvGetPortAttributeReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub value: i32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvQueryPortAttributesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+}
+
+pub type XCBXvQueryPortAttributesCookie = XCBCookie<XCBXvQueryPortAttributesReply>;
+
+#[repr(C)]
+pub struct XCBXvQueryPortAttributesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_attributes: u32,
+    pub text_size: u32,
+    pub pad1: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvListImageFormatsRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+}
+
+pub type XCBXvListImageFormatsCookie = XCBCookie<XCBXvListImageFormatsReply>;
+
+#[repr(C)]
+pub struct XCBXvListImageFormatsReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_formats: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvQueryImageAttributesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub id: u32,
+    pub width: u16,
+    pub height: u16,
+}
+
+pub type XCBXvQueryImageAttributesCookie = XCBCookie<XCBXvQueryImageAttributesReply>;
+
+#[repr(C)]
+pub struct XCBXvQueryImageAttributesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num_planes: u32,
+    pub data_size: u32,
+    pub width: u16,
+    pub height: u16,
+    pub pad1: [u8; 12],
+}
+
+#[repr(C)]
+pub struct XCBXvPutImageRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub id: u32,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub src_w: u16,
+    pub src_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvShmPutImageRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port: XCBXvPort,
+    pub drawable: XCBDrawable,
+    pub gc: XCBGcontext,
+    pub shmseg: XCBShmSeg,
+    pub id: u32,
+    pub offset: u32,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub src_w: u16,
+    pub src_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+    pub width: u16,
+    pub height: u16,
+    pub send_event: u8,
+    pub pad0: [u8; 3],
+}
+
+#[link(name = "xcb-xv")]
+extern "system" {
+    pub static mut xcb_xv_id: XCBExtension;
+    pub fn xcb_xv_port_next(i: *mut XCBXvPortIterator);
+    pub fn xcb_xv_port_end(i: XCBXvPortIterator) -> XCBGenericIterator;
+    pub fn xcb_xv_encoding_next(i: *mut XCBXvEncodingIterator);
+    pub fn xcb_xv_encoding_end(i: XCBXvEncodingIterator) -> XCBGenericIterator;
+    pub fn xcb_xv_rational_next(i: *mut XCBXvRationalIterator);
+    pub fn xcb_xv_rational_end(i: XCBXvRationalIterator) -> XCBGenericIterator;
+    pub fn xcb_xv_format_next(i: *mut XCBXvFormatIterator);
+    pub fn xcb_xv_format_end(i: XCBXvFormatIterator) -> XCBGenericIterator;
+    pub fn xcb_xv_adaptor_info_next(i: *mut XCBXvAdaptorInfoIterator);
+    pub fn xcb_xv_adaptor_info_end(i: XCBXvAdaptorInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_xv_adaptor_info_name(R: *const XCBXvAdaptorInfo) -> *mut c_char;
+    pub fn xcb_xv_adaptor_info_name_length(R: *const XCBXvAdaptorInfo) -> c_int;
+    pub fn xcb_xv_adaptor_info_name_end(R: *const XCBXvAdaptorInfo) -> XCBGenericIterator;
+    pub fn xcb_xv_adaptor_info_formats(R: *const XCBXvAdaptorInfo) -> *mut XCBXvFormat;
+    pub fn xcb_xv_adaptor_info_formats_length(R: *const XCBXvAdaptorInfo) -> c_int;
+    pub fn xcb_xv_adaptor_info_formats_iterator(R: *const XCBXvAdaptorInfo) -> XCBXvFormatIterator;
+    pub fn xcb_xv_encoding_info_next(i: *mut XCBXvEncodingInfoIterator);
+    pub fn xcb_xv_encoding_info_end(i: XCBXvEncodingInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_xv_encoding_info_name(R: *const XCBXvEncodingInfo) -> *mut c_char;
+    pub fn xcb_xv_encoding_info_name_length(R: *const XCBXvEncodingInfo) -> c_int;
+    pub fn xcb_xv_encoding_info_name_end(R: *const XCBXvEncodingInfo) -> XCBGenericIterator;
+    pub fn xcb_xv_image_next(i: *mut XCBXvImageIterator);
+    pub fn xcb_xv_image_end(i: XCBXvImageIterator) -> XCBGenericIterator;
+    pub fn xcb_xv_image_pitches(R: *const XCBXvImage) -> *mut u32;
+    pub fn xcb_xv_image_pitches_length(R: *const XCBXvImage) -> c_int;
+    pub fn xcb_xv_image_pitches_end(R: *const XCBXvImage) -> XCBGenericIterator;
+    pub fn xcb_xv_image_offsets(R: *const XCBXvImage) -> *mut u32;
+    pub fn xcb_xv_image_offsets_length(R: *const XCBXvImage) -> c_int;
+    pub fn xcb_xv_image_offsets_end(R: *const XCBXvImage) -> XCBGenericIterator;
+    pub fn xcb_xv_image_data(R: *const XCBXvImage) -> *mut u8;
+    pub fn xcb_xv_image_data_length(R: *const XCBXvImage) -> c_int;
+    pub fn xcb_xv_image_data_end(R: *const XCBXvImage) -> XCBGenericIterator;
+    pub fn xcb_xv_attribute_info_next(i: *mut XCBXvAttributeInfoIterator);
+    pub fn xcb_xv_attribute_info_end(i: XCBXvAttributeInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_xv_attribute_info_name(R: *const XCBXvAttributeInfo) -> *mut c_char;
+    pub fn xcb_xv_attribute_info_name_length(R: *const XCBXvAttributeInfo) -> c_int;
+    pub fn xcb_xv_attribute_info_name_end(R: *const XCBXvAttributeInfo) -> XCBGenericIterator;
+    pub fn xcb_xv_image_format_info_next(i: *mut XCBXvImageFormatInfoIterator);
+    pub fn xcb_xv_image_format_info_end(i: XCBXvImageFormatInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_xv_query_extension_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvQueryExtensionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvQueryExtensionReply;
+    pub fn xcb_xv_query_extension(connection: *mut XCBConnection) -> XCBXvQueryExtensionCookie;
+    pub fn xcb_xv_query_extension_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBXvQueryExtensionCookie;
+    pub fn xcb_xv_query_adaptors_info_length(R: *const XCBXvQueryAdaptorsReply) -> c_int;
+    pub fn xcb_xv_query_adaptors_info_iterator(
+        R: *const XCBXvQueryAdaptorsReply,
+    ) -> XCBXvAdaptorInfoIterator;
+    pub fn xcb_xv_query_adaptors_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvQueryAdaptorsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvQueryAdaptorsReply;
+    pub fn xcb_xv_query_adaptors(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBXvQueryAdaptorsCookie;
+    pub fn xcb_xv_query_adaptors_unchecked(
+        connection: *mut XCBConnection,
+        window: XCBWindow,
+    ) -> XCBXvQueryAdaptorsCookie;
+    pub fn xcb_xv_query_encodings_info_length(R: *const XCBXvQueryEncodingsReply) -> c_int;
+    pub fn xcb_xv_query_encodings_info_iterator(
+        R: *const XCBXvQueryEncodingsReply,
+    ) -> XCBXvEncodingInfoIterator;
+    pub fn xcb_xv_query_encodings_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvQueryEncodingsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvQueryEncodingsReply;
+    pub fn xcb_xv_query_encodings(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+    ) -> XCBXvQueryEncodingsCookie;
+    pub fn xcb_xv_query_encodings_unchecked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+    ) -> XCBXvQueryEncodingsCookie;
+    pub fn xcb_xv_grab_port_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvGrabPortCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvGrabPortReply;
+    pub fn xcb_xv_grab_port(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        time: XCBTimestamp,
+    ) -> XCBXvGrabPortCookie;
+    pub fn xcb_xv_grab_port_unchecked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        time: XCBTimestamp,
+    ) -> XCBXvGrabPortCookie;
+    pub fn xcb_xv_ungrab_port_checked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_ungrab_port(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_put_video_checked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        vid_x: i16,
+        vid_y: i16,
+        vid_w: u16,
+        vid_h: u16,
+        drw_x: i16,
+        drw_y: i16,
+        drw_w: u16,
+        drw_h: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_put_video(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        vid_x: i16,
+        vid_y: i16,
+        vid_w: u16,
+        vid_h: u16,
+        drw_x: i16,
+        drw_y: i16,
+        drw_w: u16,
+        drw_h: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_put_still_checked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        vid_x: i16,
+        vid_y: i16,
+        vid_w: u16,
+        vid_h: u16,
+        drw_x: i16,
+        drw_y: i16,
+        drw_w: u16,
+        drw_h: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_put_still(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        vid_x: i16,
+        vid_y: i16,
+        vid_w: u16,
+        vid_h: u16,
+        drw_x: i16,
+        drw_y: i16,
+        drw_w: u16,
+        drw_h: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_get_video_checked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        vid_x: i16,
+        vid_y: i16,
+        vid_w: u16,
+        vid_h: u16,
+        drw_x: i16,
+        drw_y: i16,
+        drw_w: u16,
+        drw_h: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_get_video(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        vid_x: i16,
+        vid_y: i16,
+        vid_w: u16,
+        vid_h: u16,
+        drw_x: i16,
+        drw_y: i16,
+        drw_w: u16,
+        drw_h: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_get_still_checked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        vid_x: i16,
+        vid_y: i16,
+        vid_w: u16,
+        vid_h: u16,
+        drw_x: i16,
+        drw_y: i16,
+        drw_w: u16,
+        drw_h: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_get_still(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        vid_x: i16,
+        vid_y: i16,
+        vid_w: u16,
+        vid_h: u16,
+        drw_x: i16,
+        drw_y: i16,
+        drw_w: u16,
+        drw_h: u16,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_stop_video_checked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_stop_video(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_select_video_notify_checked(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        onoff: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_select_video_notify(
+        connection: *mut XCBConnection,
+        drawable: XCBDrawable,
+        onoff: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_select_port_notify_checked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        onoff: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_select_port_notify(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        onoff: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_query_best_size_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvQueryBestSizeCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvQueryBestSizeReply;
+    pub fn xcb_xv_query_best_size(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        vid_w: u16,
+        vid_h: u16,
+        drw_w: u16,
+        drw_h: u16,
+        motion: u8,
+    ) -> XCBXvQueryBestSizeCookie;
+    pub fn xcb_xv_query_best_size_unchecked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        vid_w: u16,
+        vid_h: u16,
+        drw_w: u16,
+        drw_h: u16,
+        motion: u8,
+    ) -> XCBXvQueryBestSizeCookie;
+    pub fn xcb_xv_set_port_attribute_checked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        attribute: XCBAtom,
+        value: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_set_port_attribute(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        attribute: XCBAtom,
+        value: i32,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_get_port_attribute_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvGetPortAttributeCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvGetPortAttributeReply;
+    pub fn xcb_xv_get_port_attribute(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        attribute: XCBAtom,
+    ) -> XCBXvGetPortAttributeCookie;
+    pub fn xcb_xv_get_port_attribute_unchecked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        attribute: XCBAtom,
+    ) -> XCBXvGetPortAttributeCookie;
+    pub fn xcb_xv_query_port_attributes_attributes_length(
+        R: *const XCBXvQueryPortAttributesReply,
+    ) -> c_int;
+    pub fn xcb_xv_query_port_attributes_attributes_iterator(
+        R: *const XCBXvQueryPortAttributesReply,
+    ) -> XCBXvAttributeInfoIterator;
+    pub fn xcb_xv_query_port_attributes_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvQueryPortAttributesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvQueryPortAttributesReply;
+    pub fn xcb_xv_query_port_attributes(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+    ) -> XCBXvQueryPortAttributesCookie;
+    pub fn xcb_xv_query_port_attributes_unchecked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+    ) -> XCBXvQueryPortAttributesCookie;
+    pub fn xcb_xv_list_image_formats_format(
+        R: *const XCBXvListImageFormatsReply,
+    ) -> *mut XCBXvImageFormatInfo;
+    pub fn xcb_xv_list_image_formats_format_length(R: *const XCBXvListImageFormatsReply) -> c_int;
+    pub fn xcb_xv_list_image_formats_format_iterator(
+        R: *const XCBXvListImageFormatsReply,
+    ) -> XCBXvImageFormatInfoIterator;
+    pub fn xcb_xv_list_image_formats_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvListImageFormatsCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvListImageFormatsReply;
+    pub fn xcb_xv_list_image_formats(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+    ) -> XCBXvListImageFormatsCookie;
+    pub fn xcb_xv_list_image_formats_unchecked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+    ) -> XCBXvListImageFormatsCookie;
+    pub fn xcb_xv_query_image_attributes_pitches(
+        R: *const XCBXvQueryImageAttributesReply,
+    ) -> *mut u32;
+    pub fn xcb_xv_query_image_attributes_pitches_length(
+        R: *const XCBXvQueryImageAttributesReply,
+    ) -> c_int;
+    pub fn xcb_xv_query_image_attributes_pitches_end(
+        R: *const XCBXvQueryImageAttributesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xv_query_image_attributes_offsets(
+        R: *const XCBXvQueryImageAttributesReply,
+    ) -> *mut u32;
+    pub fn xcb_xv_query_image_attributes_offsets_length(
+        R: *const XCBXvQueryImageAttributesReply,
+    ) -> c_int;
+    pub fn xcb_xv_query_image_attributes_offsets_end(
+        R: *const XCBXvQueryImageAttributesReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xv_query_image_attributes_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvQueryImageAttributesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvQueryImageAttributesReply;
+    pub fn xcb_xv_query_image_attributes(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        id: u32,
+        width: u16,
+        height: u16,
+    ) -> XCBXvQueryImageAttributesCookie;
+    pub fn xcb_xv_query_image_attributes_unchecked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        id: u32,
+        width: u16,
+        height: u16,
+    ) -> XCBXvQueryImageAttributesCookie;
+    pub fn xcb_xv_put_image_checked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        id: u32,
+        src_x: i16,
+        src_y: i16,
+        src_w: u16,
+        src_h: u16,
+        drw_x: i16,
+        drw_y: i16,
+        drw_w: u16,
+        drw_h: u16,
+        width: u16,
+        height: u16,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_put_image(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        id: u32,
+        src_x: i16,
+        src_y: i16,
+        src_w: u16,
+        src_h: u16,
+        drw_x: i16,
+        drw_y: i16,
+        drw_w: u16,
+        drw_h: u16,
+        width: u16,
+        height: u16,
+        data_len: u32,
+        data: *const u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_shm_put_image_checked(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        shmseg: XCBShmSeg,
+        id: u32,
+        offset: u32,
+        src_x: i16,
+        src_y: i16,
+        src_w: u16,
+        src_h: u16,
+        drw_x: i16,
+        drw_y: i16,
+        drw_w: u16,
+        drw_h: u16,
+        width: u16,
+        height: u16,
+        send_event: u8,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xv_shm_put_image(
+        connection: *mut XCBConnection,
+        port: XCBXvPort,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        shmseg: XCBShmSeg,
+        id: u32,
+        offset: u32,
+        src_x: i16,
+        src_y: i16,
+        src_w: u16,
+        src_h: u16,
+        drw_x: i16,
+        drw_y: i16,
+        drw_w: u16,
+        drw_h: u16,
+        width: u16,
+        height: u16,
+        send_event: u8,
+    ) -> XCBVoidCookie;
+}
@@ -0,0 +1,53 @@
+// Generated by xcbgen from xml/xv.xml, do not edit.
+
+pub const XCB_XV_MAJOR_VERSION: u32 = 2;
+pub const XCB_XV_MINOR_VERSION: u32 = 2;
+pub const XCB_XV_TYPE_INPUT_MASK: u8 = 1;
+pub const XCB_XV_TYPE_OUTPUT_MASK: u8 = 2;
+pub const XCB_XV_TYPE_VIDEO_MASK: u8 = 4;
+pub const XCB_XV_TYPE_STILL_MASK: u8 = 8;
+pub const XCB_XV_TYPE_IMAGE_MASK: u8 = 16;
+pub const XCB_XV_IMAGE_FORMAT_INFO_TYPE_RGB: u8 = 0;
+pub const XCB_XV_IMAGE_FORMAT_INFO_TYPE_YUV: u8 = 1;
+pub const XCB_XV_IMAGE_FORMAT_INFO_FORMAT_PACKED: u8 = 0;
+pub const XCB_XV_IMAGE_FORMAT_INFO_FORMAT_PLANAR: u8 = 1;
+pub const XCB_XV_ATTRIBUTE_FLAG_GETTABLE: u32 = 1;
+pub const XCB_XV_ATTRIBUTE_FLAG_SETTABLE: u32 = 2;
+pub const XCB_XV_VIDEO_NOTIFY_REASON_STARTED: u8 = 0;
+pub const XCB_XV_VIDEO_NOTIFY_REASON_STOPPED: u8 = 1;
+pub const XCB_XV_VIDEO_NOTIFY_REASON_BUSY: u8 = 2;
+pub const XCB_XV_VIDEO_NOTIFY_REASON_PREEMPTED: u8 = 3;
+pub const XCB_XV_VIDEO_NOTIFY_REASON_HARD_ERROR: u8 = 4;
+pub const XCB_XV_SCANLINE_ORDER_TOP_TO_BOTTOM: u8 = 0;
+pub const XCB_XV_SCANLINE_ORDER_BOTTOM_TO_TOP: u8 = 1;
+pub const XCB_XV_GRAB_PORT_STATUS_SUCCESS: u8 = 0;
+pub const XCB_XV_GRAB_PORT_STATUS_BAD_EXTENSION: u8 = 1;
+pub const XCB_XV_GRAB_PORT_STATUS_ALREADY_GRABBED: u8 = 2;
+pub const XCB_XV_GRAB_PORT_STATUS_INVALID_TIME: u8 = 3;
+pub const XCB_XV_GRAB_PORT_STATUS_BAD_REPLY: u8 = 4;
+pub const XCB_XV_GRAB_PORT_STATUS_BAD_ALLOC: u8 = 5;
+pub const XCB_XV_BAD_PORT: u8 = 0;
+pub const XCB_XV_BAD_ENCODING: u8 = 1;
+pub const XCB_XV_BAD_CONTROL: u8 = 2;
+pub const XCB_XV_VIDEO_NOTIFY: u8 = 0;
+pub const XCB_XV_PORT_NOTIFY: u8 = 1;
+pub const XCB_XV_QUERY_EXTENSION: u8 = 0;
+pub const XCB_XV_QUERY_ADAPTORS: u8 = 1;
+pub const XCB_XV_QUERY_ENCODINGS: u8 = 2;
+pub const XCB_XV_GRAB_PORT: u8 = 3;
+pub const XCB_XV_UNGRAB_PORT: u8 = 4;
+pub const XCB_XV_PUT_VIDEO: u8 = 5;
+pub const XCB_XV_PUT_STILL: u8 = 6;
+pub const XCB_XV_GET_VIDEO: u8 = 7;
+pub const XCB_XV_GET_STILL: u8 = 8;
+pub const XCB_XV_STOP_VIDEO: u8 = 9;
+pub const XCB_XV_SELECT_VIDEO_NOTIFY: u8 = 10;
+pub const XCB_XV_SELECT_PORT_NOTIFY: u8 = 11;
+pub const XCB_XV_QUERY_BEST_SIZE: u8 = 12;
+pub const XCB_XV_SET_PORT_ATTRIBUTE: u8 = 13;
+pub const XCB_XV_GET_PORT_ATTRIBUTE: u8 = 14;
+pub const XCB_XV_QUERY_PORT_ATTRIBUTES: u8 = 15;
+pub const XCB_XV_LIST_IMAGE_FORMATS: u8 = 16;
+pub const XCB_XV_QUERY_IMAGE_ATTRIBUTES: u8 = 17;
+pub const XCB_XV_PUT_IMAGE: u8 = 18;
+pub const XCB_XV_SHM_PUT_IMAGE: u8 = 19;
@@ -0,0 +1,693 @@
+// Generated by xcbgen from xml/xv.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use proto::shm::XCBShmSeg;
+use proto::xproto::{XCBAtom, XCBDrawable, XCBGcontext, XCBTimestamp, XCBWindow};
+use {
+    Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericEvent,
+    XCBVoidCookie,
+};
+
+pub type XCBXvPort = cdef::XCBXvPort;
+pub type XCBXvPortIterator = cdef::XCBXvPortIterator;
+pub type XCBXvEncoding = cdef::XCBXvEncoding;
+pub type XCBXvEncodingIterator = cdef::XCBXvEncodingIterator;
+pub type XCBXvRational = cdef::XCBXvRational;
+pub type XCBXvRationalIterator = cdef::XCBXvRationalIterator;
+pub type XCBXvFormat = cdef::XCBXvFormat;
+pub type XCBXvFormatIterator = cdef::XCBXvFormatIterator;
+pub type XCBXvAdaptorInfo = cdef::XCBXvAdaptorInfo;
+pub type XCBXvAdaptorInfoIterator = cdef::XCBXvAdaptorInfoIterator;
+pub type XCBXvEncodingInfo = cdef::XCBXvEncodingInfo;
+pub type XCBXvEncodingInfoIterator = cdef::XCBXvEncodingInfoIterator;
+pub type XCBXvImage = cdef::XCBXvImage;
+pub type XCBXvImageIterator = cdef::XCBXvImageIterator;
+pub type XCBXvAttributeInfo = cdef::XCBXvAttributeInfo;
+pub type XCBXvAttributeInfoIterator = cdef::XCBXvAttributeInfoIterator;
+pub type XCBXvImageFormatInfo = cdef::XCBXvImageFormatInfo;
+pub type XCBXvImageFormatInfoIterator = cdef::XCBXvImageFormatInfoIterator;
+pub type XCBXvBadPortError = cdef::XCBXvBadPortError;
+pub type XCBXvBadEncodingError = cdef::XCBXvBadEncodingError;
+pub type XCBXvBadControlError = cdef::XCBXvBadControlError;
+pub type XCBXvVideoNotifyEvent = cdef::XCBXvVideoNotifyEvent;
+pub type XCBXvPortNotifyEvent = cdef::XCBXvPortNotifyEvent;
+pub type XCBXvQueryExtensionRequest = cdef::XCBXvQueryExtensionRequest;
+pub type XCBXvQueryExtensionCookie = cdef::XCBXvQueryExtensionCookie;
+pub type XCBXvQueryExtensionReply = cdef::XCBXvQueryExtensionReply;
+pub type XCBXvQueryAdaptorsRequest = cdef::XCBXvQueryAdaptorsRequest;
+pub type XCBXvQueryAdaptorsCookie = cdef::XCBXvQueryAdaptorsCookie;
+pub type XCBXvQueryAdaptorsReply = cdef::XCBXvQueryAdaptorsReply;
+pub type XCBXvQueryEncodingsRequest = cdef::XCBXvQueryEncodingsRequest;
+pub type XCBXvQueryEncodingsCookie = cdef::XCBXvQueryEncodingsCookie;
+pub type XCBXvQueryEncodingsReply = cdef::XCBXvQueryEncodingsReply;
+pub type XCBXvGrabPortRequest = cdef::XCBXvGrabPortRequest;
+pub type XCBXvGrabPortCookie = cdef::XCBXvGrabPortCookie;
+pub type XCBXvGrabPortReply = cdef::XCBXvGrabPortReply;
+pub type XCBXvUngrabPortRequest = cdef::XCBXvUngrabPortRequest;
+pub type XCBXvPutVideoRequest = cdef::XCBXvPutVideoRequest;
+pub type XCBXvPutStillRequest = cdef::XCBXvPutStillRequest;
+pub type XCBXvGetVideoRequest = cdef::XCBXvGetVideoRequest;
+pub type XCBXvGetStillRequest = cdef::XCBXvGetStillRequest;
+pub type XCBXvStopVideoRequest = cdef::XCBXvStopVideoRequest;
+pub type XCBXvSelectVideoNotifyRequest = cdef::XCBXvSelectVideoNotifyRequest;
+pub type XCBXvSelectPortNotifyRequest = cdef::XCBXvSelectPortNotifyRequest;
+pub type XCBXvQueryBestSizeRequest = cdef::XCBXvQueryBestSizeRequest;
+pub type XCBXvQueryBestSizeCookie = cdef::XCBXvQueryBestSizeCookie;
+pub type XCBXvQueryBestSizeReply = cdef::XCBXvQueryBestSizeReply;
+pub type XCBXvSetPortAttributeRequest = cdef::XCBXvSetPortAttributeRequest;
+pub type XCBXvGetPortAttributeRequest = cdef::XCBXvGetPortAttributeRequest;
+pub type XCBXvGetPortAttributeCookie = cdef::XCBXvGetPortAttributeCookie;
+pub type XCBXvGetPortAttributeReply = cdef::XCBXvGetPortAttributeReply;
+pub type XCBXvQueryPortAttributesRequest = cdef::XCBXvQueryPortAttributesRequest;
+pub type XCBXvQueryPortAttributesCookie = cdef::XCBXvQueryPortAttributesCookie;
+pub type XCBXvQueryPortAttributesReply = cdef::XCBXvQueryPortAttributesReply;
+pub type XCBXvListImageFormatsRequest = cdef::XCBXvListImageFormatsRequest;
+pub type XCBXvListImageFormatsCookie = cdef::XCBXvListImageFormatsCookie;
+pub type XCBXvListImageFormatsReply = cdef::XCBXvListImageFormatsReply;
+pub type XCBXvQueryImageAttributesRequest = cdef::XCBXvQueryImageAttributesRequest;
+pub type XCBXvQueryImageAttributesCookie = cdef::XCBXvQueryImageAttributesCookie;
+pub type XCBXvQueryImageAttributesReply = cdef::XCBXvQueryImageAttributesReply;
+pub type XCBXvPutImageRequest = cdef::XCBXvPutImageRequest;
+pub type XCBXvShmPutImageRequest = cdef::XCBXvShmPutImageRequest;
+
+/// The Xv extension, known to the server as `XVideo`.
+pub struct Xv;
+
+impl Extension for Xv {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_xv_id)
+    }
+}
+
+pub fn xcb_xv_port_next(iter: &mut XCBXvPortIterator) {
+    unsafe { cdef::xcb_xv_port_next(iter) }
+}
+
+pub fn xcb_xv_encoding_next(iter: &mut XCBXvEncodingIterator) {
+    unsafe { cdef::xcb_xv_encoding_next(iter) }
+}
+
+pub fn xcb_xv_rational_next(iter: &mut XCBXvRationalIterator) {
+    unsafe { cdef::xcb_xv_rational_next(iter) }
+}
+
+pub fn xcb_xv_format_next(iter: &mut XCBXvFormatIterator) {
+    unsafe { cdef::xcb_xv_format_next(iter) }
+}
+
+pub fn xcb_xv_adaptor_info_next(iter: &mut XCBXvAdaptorInfoIterator) {
+    unsafe { cdef::xcb_xv_adaptor_info_next(iter) }
+}
+
+pub fn xcb_xv_adaptor_info_name(data: &XCBXvAdaptorInfo) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xv_adaptor_info_name(data) as *const u8,
+            cdef::xcb_xv_adaptor_info_name_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xv_adaptor_info_formats(data: &XCBXvAdaptorInfo) -> &[XCBXvFormat] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xv_adaptor_info_formats(data),
+            cdef::xcb_xv_adaptor_info_formats_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xv_encoding_info_next(iter: &mut XCBXvEncodingInfoIterator) {
+    unsafe { cdef::xcb_xv_encoding_info_next(iter) }
+}
+
+pub fn xcb_xv_encoding_info_name(data: &XCBXvEncodingInfo) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xv_encoding_info_name(data) as *const u8,
+            cdef::xcb_xv_encoding_info_name_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xv_image_next(iter: &mut XCBXvImageIterator) {
+    unsafe { cdef::xcb_xv_image_next(iter) }
+}
+
+pub fn xcb_xv_image_pitches(data: &XCBXvImage) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xv_image_pitches(data),
+            cdef::xcb_xv_image_pitches_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xv_image_offsets(data: &XCBXvImage) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xv_image_offsets(data),
+            cdef::xcb_xv_image_offsets_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xv_image_data(data: &XCBXvImage) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xv_image_data(data),
+            cdef::xcb_xv_image_data_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xv_attribute_info_next(iter: &mut XCBXvAttributeInfoIterator) {
+    unsafe { cdef::xcb_xv_attribute_info_next(iter) }
+}
+
+pub fn xcb_xv_attribute_info_name(data: &XCBXvAttributeInfo) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xv_attribute_info_name(data) as *const u8,
+            cdef::xcb_xv_attribute_info_name_length(data) as usize,
+        )
+    }
+}
+
+pub fn xcb_xv_image_format_info_next(iter: &mut XCBXvImageFormatInfoIterator) {
+    unsafe { cdef::xcb_xv_image_format_info_next(iter) }
+}
+
+impl Reply for XCBXvQueryExtensionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xv_query_extension_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xv_query_extension(connection: &XCBConnection) -> XCBXvQueryExtensionCookie {
+    unsafe { cdef::xcb_xv_query_extension(connection.raw) }
+}
+
+pub fn xcb_xv_query_adaptors_info_iterator(
+    reply: &XCBXvQueryAdaptorsReply,
+) -> XCBXvAdaptorInfoIterator {
+    unsafe { cdef::xcb_xv_query_adaptors_info_iterator(reply) }
+}
+
+impl Reply for XCBXvQueryAdaptorsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xv_query_adaptors_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xv_query_adaptors(
+    connection: &XCBConnection,
+    window: XCBWindow,
+) -> XCBXvQueryAdaptorsCookie {
+    unsafe { cdef::xcb_xv_query_adaptors(connection.raw, window) }
+}
+
+pub fn xcb_xv_query_encodings_info_iterator(
+    reply: &XCBXvQueryEncodingsReply,
+) -> XCBXvEncodingInfoIterator {
+    unsafe { cdef::xcb_xv_query_encodings_info_iterator(reply) }
+}
+
+impl Reply for XCBXvQueryEncodingsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xv_query_encodings_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xv_query_encodings(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+) -> XCBXvQueryEncodingsCookie {
+    unsafe { cdef::xcb_xv_query_encodings(connection.raw, port) }
+}
+
+impl Reply for XCBXvGrabPortReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xv_grab_port_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xv_grab_port(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    time: XCBTimestamp,
+) -> XCBXvGrabPortCookie {
+    unsafe { cdef::xcb_xv_grab_port(connection.raw, port, time) }
+}
+
+pub fn xcb_xv_ungrab_port(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    time: XCBTimestamp,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xv_ungrab_port(connection.raw, port, time) }
+}
+
+pub fn xcb_xv_put_video(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    vid_x: i16,
+    vid_y: i16,
+    vid_w: u16,
+    vid_h: u16,
+    drw_x: i16,
+    drw_y: i16,
+    drw_w: u16,
+    drw_h: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xv_put_video(
+            connection.raw,
+            port,
+            drawable,
+            gc,
+            vid_x,
+            vid_y,
+            vid_w,
+            vid_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+        )
+    }
+}
+
+pub fn xcb_xv_put_still(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    vid_x: i16,
+    vid_y: i16,
+    vid_w: u16,
+    vid_h: u16,
+    drw_x: i16,
+    drw_y: i16,
+    drw_w: u16,
+    drw_h: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xv_put_still(
+            connection.raw,
+            port,
+            drawable,
+            gc,
+            vid_x,
+            vid_y,
+            vid_w,
+            vid_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+        )
+    }
+}
+
+pub fn xcb_xv_get_video(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    vid_x: i16,
+    vid_y: i16,
+    vid_w: u16,
+    vid_h: u16,
+    drw_x: i16,
+    drw_y: i16,
+    drw_w: u16,
+    drw_h: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xv_get_video(
+            connection.raw,
+            port,
+            drawable,
+            gc,
+            vid_x,
+            vid_y,
+            vid_w,
+            vid_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+        )
+    }
+}
+
+pub fn xcb_xv_get_still(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    vid_x: i16,
+    vid_y: i16,
+    vid_w: u16,
+    vid_h: u16,
+    drw_x: i16,
+    drw_y: i16,
+    drw_w: u16,
+    drw_h: u16,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xv_get_still(
+            connection.raw,
+            port,
+            drawable,
+            gc,
+            vid_x,
+            vid_y,
+            vid_w,
+            vid_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+        )
+    }
+}
+
+pub fn xcb_xv_stop_video(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    drawable: XCBDrawable,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xv_stop_video(connection.raw, port, drawable) }
+}
+
+pub fn xcb_xv_select_video_notify(
+    connection: &XCBConnection,
+    drawable: XCBDrawable,
+    onoff: bool,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xv_select_video_notify(connection.raw, drawable, onoff as u8) }
+}
+
+pub fn xcb_xv_select_port_notify(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    onoff: bool,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xv_select_port_notify(connection.raw, port, onoff as u8) }
+}
+
+impl Reply for XCBXvQueryBestSizeReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xv_query_best_size_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xv_query_best_size(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    vid_w: u16,
+    vid_h: u16,
+    drw_w: u16,
+    drw_h: u16,
+    motion: bool,
+) -> XCBXvQueryBestSizeCookie {
+    unsafe {
+        cdef::xcb_xv_query_best_size(
+            connection.raw,
+            port,
+            vid_w,
+            vid_h,
+            drw_w,
+            drw_h,
+            motion as u8,
+        )
+    }
+}
+
+pub fn xcb_xv_set_port_attribute(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    attribute: XCBAtom,
+    value: i32,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xv_set_port_attribute(connection.raw, port, attribute, value) }
+}
+
+impl Reply for XCBXvGetPortAttributeReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xv_get_port_attribute_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xv_get_port_attribute(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    attribute: XCBAtom,
+) -> XCBXvGetPortAttributeCookie {
+    unsafe { cdef::xcb_xv_get_port_attribute(connection.raw, port, attribute) }
+}
+
+pub fn xcb_xv_query_port_attributes_attributes_iterator(
+    reply: &XCBXvQueryPortAttributesReply,
+) -> XCBXvAttributeInfoIterator {
+    unsafe { cdef::xcb_xv_query_port_attributes_attributes_iterator(reply) }
+}
+
+impl Reply for XCBXvQueryPortAttributesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xv_query_port_attributes_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xv_query_port_attributes(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+) -> XCBXvQueryPortAttributesCookie {
+    unsafe { cdef::xcb_xv_query_port_attributes(connection.raw, port) }
+}
+
+pub fn xcb_xv_list_image_formats_format(
+    reply: &XCBXvListImageFormatsReply,
+) -> &[XCBXvImageFormatInfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xv_list_image_formats_format(reply),
+            cdef::xcb_xv_list_image_formats_format_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXvListImageFormatsReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xv_list_image_formats_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xv_list_image_formats(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+) -> XCBXvListImageFormatsCookie {
+    unsafe { cdef::xcb_xv_list_image_formats(connection.raw, port) }
+}
+
+pub fn xcb_xv_query_image_attributes_pitches(reply: &XCBXvQueryImageAttributesReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xv_query_image_attributes_pitches(reply),
+            cdef::xcb_xv_query_image_attributes_pitches_length(reply) as usize,
+        )
+    }
+}
+
+pub fn xcb_xv_query_image_attributes_offsets(reply: &XCBXvQueryImageAttributesReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xv_query_image_attributes_offsets(reply),
+            cdef::xcb_xv_query_image_attributes_offsets_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXvQueryImageAttributesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xv_query_image_attributes_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xv_query_image_attributes(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    id: u32,
+    width: u16,
+    height: u16,
+) -> XCBXvQueryImageAttributesCookie {
+    unsafe { cdef::xcb_xv_query_image_attributes(connection.raw, port, id, width, height) }
+}
+
+pub fn xcb_xv_put_image(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    id: u32,
+    src_x: i16,
+    src_y: i16,
+    src_w: u16,
+    src_h: u16,
+    drw_x: i16,
+    drw_y: i16,
+    drw_w: u16,
+    drw_h: u16,
+    width: u16,
+    height: u16,
+    data: &[u8],
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xv_put_image(
+            connection.raw,
+            port,
+            drawable,
+            gc,
+            id,
+            src_x,
+            src_y,
+            src_w,
+            src_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+            width,
+            height,
+            data.len() as u32,
+            data.as_ptr(),
+        )
+    }
+}
+
+pub fn xcb_xv_shm_put_image(
+    connection: &XCBConnection,
+    port: XCBXvPort,
+    drawable: XCBDrawable,
+    gc: XCBGcontext,
+    shmseg: XCBShmSeg,
+    id: u32,
+    offset: u32,
+    src_x: i16,
+    src_y: i16,
+    src_w: u16,
+    src_h: u16,
+    drw_x: i16,
+    drw_y: i16,
+    drw_w: u16,
+    drw_h: u16,
+    width: u16,
+    height: u16,
+    send_event: u8,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_xv_shm_put_image(
+            connection.raw,
+            port,
+            drawable,
+            gc,
+            shmseg,
+            id,
+            offset,
+            src_x,
+            src_y,
+            src_w,
+            src_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+            width,
+            height,
+            send_event,
+        )
+    }
+}
+
+/// An event of the Xv extension.
+pub enum Event {
+    VideoNotify(Box<XCBXvVideoNotifyEvent>),
+    PortNotify(Box<XCBXvPortNotifyEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let raw = Box::into_raw(event);
+        unsafe {
+            match number {
+                0 => Ok(Event::VideoNotify(Box::from_raw(
+                    raw as *mut XCBXvVideoNotifyEvent,
+                ))),
+                1 => Ok(Event::PortNotify(Box::from_raw(
+                    raw as *mut XCBXvPortNotifyEvent,
+                ))),
+                _ => Err(Box::from_raw(raw)),
+            }
+        }
+    }
+}
+
+/// Returns the name of the error whose code, relative to the first error of the extension, is `number`.
+pub fn error_name(number: u8) -> Option<&'static str> {
+    match number {
+        0 => Some("BadPort"),
+        1 => Some("BadEncoding"),
+        2 => Some("BadControl"),
+        _ => None,
+    }
+}
@@ -0,0 +1,396 @@
+// Generated by xcbgen from xml/xvmc.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+use cdef::{
+    XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBGenericIterator, XCBVoidCookie,
+};
+use proto::xv::cdef::{XCBXvImageFormatInfo, XCBXvImageFormatInfoIterator, XCBXvPort};
+
+pub type XCBXvmcContext = u32;
+
+#[repr(C)]
+pub struct XCBXvmcContextIterator {
+    pub data: *mut XCBXvmcContext,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXvmcSurface = u32;
+
+#[repr(C)]
+pub struct XCBXvmcSurfaceIterator {
+    pub data: *mut XCBXvmcSurface,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+pub type XCBXvmcSubpicture = u32;
+
+#[repr(C)]
+pub struct XCBXvmcSubpictureIterator {
+    pub data: *mut XCBXvmcSubpicture,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvmcSurfaceInfo {
+    pub id: XCBXvmcSurface,
+    pub chroma_format: u16,
+    pub pad0: u16,
+    pub max_width: u16,
+    pub max_height: u16,
+    pub subpicture_max_width: u16,
+    pub subpicture_max_height: u16,
+    pub mc_type: u32,
+    pub flags: u32,
+}
+
+#[repr(C)]
+pub struct XCBXvmcSurfaceInfoIterator {
+    pub data: *mut XCBXvmcSurfaceInfo,
+    pub rem: c_int,
+    pub index: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvmcQueryVersionRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+}
+
+pub type XCBXvmcQueryVersionCookie = XCBCookie<XCBXvmcQueryVersionReply>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvmcQueryVersionReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major: u32,
+    pub minor: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvmcListSurfaceTypesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port_id: XCBXvPort,
+}
+
+pub type XCBXvmcListSurfaceTypesCookie = XCBCookie<XCBXvmcListSurfaceTypesReply>;
+
+#[repr(C)]
+pub struct XCBXvmcListSurfaceTypesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvmcCreateContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_id: XCBXvmcContext,
+    pub port_id: XCBXvPort,
+    pub surface_id: XCBXvmcSurface,
+    pub width: u16,
+    pub height: u16,
+    pub flags: u32,
+}
+
+pub type XCBXvmcCreateContextCookie = XCBCookie<XCBXvmcCreateContextReply>;
+
+#[repr(C)]
+pub struct XCBXvmcCreateContextReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub width_actual: u16,
+    pub height_actual: u16,
+    pub flags_return: u32,
+    pub pad1: [u8; 20],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvmcDestroyContextRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub context_id: XCBXvmcContext,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvmcCreateSurfaceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub surface_id: XCBXvmcSurface,
+    pub context_id: XCBXvmcContext,
+}
+
+pub type XCBXvmcCreateSurfaceCookie = XCBCookie<XCBXvmcCreateSurfaceReply>;
+
+#[repr(C)]
+pub struct XCBXvmcCreateSurfaceReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub pad1: [u8; 24],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvmcDestroySurfaceRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub surface_id: XCBXvmcSurface,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvmcCreateSubpictureRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub subpicture_id: XCBXvmcSubpicture,
+    pub context: XCBXvmcContext,
+    pub xvimage_id: u32,
+    pub width: u16,
+    pub height: u16,
+}
+
+pub type XCBXvmcCreateSubpictureCookie = XCBCookie<XCBXvmcCreateSubpictureReply>;
+
+#[repr(C)]
+pub struct XCBXvmcCreateSubpictureReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub width_actual: u16,
+    pub height_actual: u16,
+    pub num_palette_entries: u16,
+    pub entry_bytes: u16,
+    pub component_order: [u8; 4],
+    pub pad1: [u8; 12],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvmcDestroySubpictureRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub subpicture_id: XCBXvmcSubpicture,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XCBXvmcListSubpictureTypesRequest {
+    pub major_opcode: u8,
+    pub minor_opcode: u8,
+    pub length: u16,
+    pub port_id: XCBXvPort,
+    pub surface_id: XCBXvmcSurface,
+}
+
+pub type XCBXvmcListSubpictureTypesCookie = XCBCookie<XCBXvmcListSubpictureTypesReply>;
+
+#[repr(C)]
+pub struct XCBXvmcListSubpictureTypesReply {
+    pub response_type: u8,
+    pub pad0: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub num: u32,
+    pub pad1: [u8; 20],
+}
+
+#[link(name = "xcb-xvmc")]
+extern "system" {
+    pub static mut xcb_xvmc_id: XCBExtension;
+    pub fn xcb_xvmc_context_next(i: *mut XCBXvmcContextIterator);
+    pub fn xcb_xvmc_context_end(i: XCBXvmcContextIterator) -> XCBGenericIterator;
+    pub fn xcb_xvmc_surface_next(i: *mut XCBXvmcSurfaceIterator);
+    pub fn xcb_xvmc_surface_end(i: XCBXvmcSurfaceIterator) -> XCBGenericIterator;
+    pub fn xcb_xvmc_subpicture_next(i: *mut XCBXvmcSubpictureIterator);
+    pub fn xcb_xvmc_subpicture_end(i: XCBXvmcSubpictureIterator) -> XCBGenericIterator;
+    pub fn xcb_xvmc_surface_info_next(i: *mut XCBXvmcSurfaceInfoIterator);
+    pub fn xcb_xvmc_surface_info_end(i: XCBXvmcSurfaceInfoIterator) -> XCBGenericIterator;
+    pub fn xcb_xvmc_query_version_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvmcQueryVersionCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvmcQueryVersionReply;
+    pub fn xcb_xvmc_query_version(connection: *mut XCBConnection) -> XCBXvmcQueryVersionCookie;
+    pub fn xcb_xvmc_query_version_unchecked(
+        connection: *mut XCBConnection,
+    ) -> XCBXvmcQueryVersionCookie;
+    pub fn xcb_xvmc_list_surface_types_surfaces(
+        R: *const XCBXvmcListSurfaceTypesReply,
+    ) -> *mut XCBXvmcSurfaceInfo;
+    pub fn xcb_xvmc_list_surface_types_surfaces_length(
+        R: *const XCBXvmcListSurfaceTypesReply,
+    ) -> c_int;
+    pub fn xcb_xvmc_list_surface_types_surfaces_iterator(
+        R: *const XCBXvmcListSurfaceTypesReply,
+    ) -> XCBXvmcSurfaceInfoIterator;
+    pub fn xcb_xvmc_list_surface_types_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvmcListSurfaceTypesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvmcListSurfaceTypesReply;
+    pub fn xcb_xvmc_list_surface_types(
+        connection: *mut XCBConnection,
+        port_id: XCBXvPort,
+    ) -> XCBXvmcListSurfaceTypesCookie;
+    pub fn xcb_xvmc_list_surface_types_unchecked(
+        connection: *mut XCBConnection,
+        port_id: XCBXvPort,
+    ) -> XCBXvmcListSurfaceTypesCookie;
+    pub fn xcb_xvmc_create_context_priv_data(R: *const XCBXvmcCreateContextReply) -> *mut u32;
+    pub fn xcb_xvmc_create_context_priv_data_length(R: *const XCBXvmcCreateContextReply) -> c_int;
+    pub fn xcb_xvmc_create_context_priv_data_end(
+        R: *const XCBXvmcCreateContextReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xvmc_create_context_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvmcCreateContextCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvmcCreateContextReply;
+    pub fn xcb_xvmc_create_context(
+        connection: *mut XCBConnection,
+        context_id: XCBXvmcContext,
+        port_id: XCBXvPort,
+        surface_id: XCBXvmcSurface,
+        width: u16,
+        height: u16,
+        flags: u32,
+    ) -> XCBXvmcCreateContextCookie;
+    pub fn xcb_xvmc_create_context_unchecked(
+        connection: *mut XCBConnection,
+        context_id: XCBXvmcContext,
+        port_id: XCBXvPort,
+        surface_id: XCBXvmcSurface,
+        width: u16,
+        height: u16,
+        flags: u32,
+    ) -> XCBXvmcCreateContextCookie;
+    pub fn xcb_xvmc_destroy_context_checked(
+        connection: *mut XCBConnection,
+        context_id: XCBXvmcContext,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xvmc_destroy_context(
+        connection: *mut XCBConnection,
+        context_id: XCBXvmcContext,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xvmc_create_surface_priv_data(R: *const XCBXvmcCreateSurfaceReply) -> *mut u32;
+    pub fn xcb_xvmc_create_surface_priv_data_length(R: *const XCBXvmcCreateSurfaceReply) -> c_int;
+    pub fn xcb_xvmc_create_surface_priv_data_end(
+        R: *const XCBXvmcCreateSurfaceReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xvmc_create_surface_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvmcCreateSurfaceCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvmcCreateSurfaceReply;
+    pub fn xcb_xvmc_create_surface(
+        connection: *mut XCBConnection,
+        surface_id: XCBXvmcSurface,
+        context_id: XCBXvmcContext,
+    ) -> XCBXvmcCreateSurfaceCookie;
+    pub fn xcb_xvmc_create_surface_unchecked(
+        connection: *mut XCBConnection,
+        surface_id: XCBXvmcSurface,
+        context_id: XCBXvmcContext,
+    ) -> XCBXvmcCreateSurfaceCookie;
+    pub fn xcb_xvmc_destroy_surface_checked(
+        connection: *mut XCBConnection,
+        surface_id: XCBXvmcSurface,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xvmc_destroy_surface(
+        connection: *mut XCBConnection,
+        surface_id: XCBXvmcSurface,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xvmc_create_subpicture_priv_data(R: *const XCBXvmcCreateSubpictureReply)
+        -> *mut u32;
+    pub fn xcb_xvmc_create_subpicture_priv_data_length(
+        R: *const XCBXvmcCreateSubpictureReply,
+    ) -> c_int;
+    pub fn xcb_xvmc_create_subpicture_priv_data_end(
+        R: *const XCBXvmcCreateSubpictureReply,
+    ) -> XCBGenericIterator;
+    pub fn xcb_xvmc_create_subpicture_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvmcCreateSubpictureCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvmcCreateSubpictureReply;
+    pub fn xcb_xvmc_create_subpicture(
+        connection: *mut XCBConnection,
+        subpicture_id: XCBXvmcSubpicture,
+        context: XCBXvmcContext,
+        xvimage_id: u32,
+        width: u16,
+        height: u16,
+    ) -> XCBXvmcCreateSubpictureCookie;
+    pub fn xcb_xvmc_create_subpicture_unchecked(
+        connection: *mut XCBConnection,
+        subpicture_id: XCBXvmcSubpicture,
+        context: XCBXvmcContext,
+        xvimage_id: u32,
+        width: u16,
+        height: u16,
+    ) -> XCBXvmcCreateSubpictureCookie;
+    pub fn xcb_xvmc_destroy_subpicture_checked(
+        connection: *mut XCBConnection,
+        subpicture_id: XCBXvmcSubpicture,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xvmc_destroy_subpicture(
+        connection: *mut XCBConnection,
+        subpicture_id: XCBXvmcSubpicture,
+    ) -> XCBVoidCookie;
+    pub fn xcb_xvmc_list_subpicture_types_types(
+        R: *const XCBXvmcListSubpictureTypesReply,
+    ) -> *mut XCBXvImageFormatInfo;
+    pub fn xcb_xvmc_list_subpicture_types_types_length(
+        R: *const XCBXvmcListSubpictureTypesReply,
+    ) -> c_int;
+    pub fn xcb_xvmc_list_subpicture_types_types_iterator(
+        R: *const XCBXvmcListSubpictureTypesReply,
+    ) -> XCBXvImageFormatInfoIterator;
+    pub fn xcb_xvmc_list_subpicture_types_reply(
+        connection: *mut XCBConnection,
+        cookie: XCBXvmcListSubpictureTypesCookie,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut XCBXvmcListSubpictureTypesReply;
+    pub fn xcb_xvmc_list_subpicture_types(
+        connection: *mut XCBConnection,
+        port_id: XCBXvPort,
+        surface_id: XCBXvmcSurface,
+    ) -> XCBXvmcListSubpictureTypesCookie;
+    pub fn xcb_xvmc_list_subpicture_types_unchecked(
+        connection: *mut XCBConnection,
+        port_id: XCBXvPort,
+        surface_id: XCBXvmcSurface,
+    ) -> XCBXvmcListSubpictureTypesCookie;
+}
@@ -0,0 +1,13 @@
+// Generated by xcbgen from xml/xvmc.xml, do not edit.
+
+pub const XCB_XVMC_MAJOR_VERSION: u32 = 1;
+pub const XCB_XVMC_MINOR_VERSION: u32 = 1;
+pub const XCB_XVMC_QUERY_VERSION: u8 = 0;
+pub const XCB_XVMC_LIST_SURFACE_TYPES: u8 = 1;
+pub const XCB_XVMC_CREATE_CONTEXT: u8 = 2;
+pub const XCB_XVMC_DESTROY_CONTEXT: u8 = 3;
+pub const XCB_XVMC_CREATE_SURFACE: u8 = 4;
+pub const XCB_XVMC_DESTROY_SURFACE: u8 = 5;
+pub const XCB_XVMC_CREATE_SUBPICTURE: u8 = 6;
+pub const XCB_XVMC_DESTROY_SUBPICTURE: u8 = 7;
+pub const XCB_XVMC_LIST_SUBPICTURE_TYPES: u8 = 8;
@@ -0,0 +1,266 @@
+// Generated by xcbgen from xml/xvmc.xml, do not edit.
+
+#![allow(non_snake_case)]
+
+pub mod cdef;
+pub mod constants;
+
+use std::ptr;
+use std::slice;
+
+use proto::xv::{XCBXvImageFormatInfo, XCBXvPort};
+use {Extension, Reply, XCBConnection, XCBCookie, XCBExtension, XCBGenericError, XCBVoidCookie};
+
+pub type XCBXvmcContext = cdef::XCBXvmcContext;
+pub type XCBXvmcContextIterator = cdef::XCBXvmcContextIterator;
+pub type XCBXvmcSurface = cdef::XCBXvmcSurface;
+pub type XCBXvmcSurfaceIterator = cdef::XCBXvmcSurfaceIterator;
+pub type XCBXvmcSubpicture = cdef::XCBXvmcSubpicture;
+pub type XCBXvmcSubpictureIterator = cdef::XCBXvmcSubpictureIterator;
+pub type XCBXvmcSurfaceInfo = cdef::XCBXvmcSurfaceInfo;
+pub type XCBXvmcSurfaceInfoIterator = cdef::XCBXvmcSurfaceInfoIterator;
+pub type XCBXvmcQueryVersionRequest = cdef::XCBXvmcQueryVersionRequest;
+pub type XCBXvmcQueryVersionCookie = cdef::XCBXvmcQueryVersionCookie;
+pub type XCBXvmcQueryVersionReply = cdef::XCBXvmcQueryVersionReply;
+pub type XCBXvmcListSurfaceTypesRequest = cdef::XCBXvmcListSurfaceTypesRequest;
+pub type XCBXvmcListSurfaceTypesCookie = cdef::XCBXvmcListSurfaceTypesCookie;
+pub type XCBXvmcListSurfaceTypesReply = cdef::XCBXvmcListSurfaceTypesReply;
+pub type XCBXvmcCreateContextRequest = cdef::XCBXvmcCreateContextRequest;
+pub type XCBXvmcCreateContextCookie = cdef::XCBXvmcCreateContextCookie;
+pub type XCBXvmcCreateContextReply = cdef::XCBXvmcCreateContextReply;
+pub type XCBXvmcDestroyContextRequest = cdef::XCBXvmcDestroyContextRequest;
+pub type XCBXvmcCreateSurfaceRequest = cdef::XCBXvmcCreateSurfaceRequest;
+pub type XCBXvmcCreateSurfaceCookie = cdef::XCBXvmcCreateSurfaceCookie;
+pub type XCBXvmcCreateSurfaceReply = cdef::XCBXvmcCreateSurfaceReply;
+pub type XCBXvmcDestroySurfaceRequest = cdef::XCBXvmcDestroySurfaceRequest;
+pub type XCBXvmcCreateSubpictureRequest = cdef::XCBXvmcCreateSubpictureRequest;
+pub type XCBXvmcCreateSubpictureCookie = cdef::XCBXvmcCreateSubpictureCookie;
+pub type XCBXvmcCreateSubpictureReply = cdef::XCBXvmcCreateSubpictureReply;
+pub type XCBXvmcDestroySubpictureRequest = cdef::XCBXvmcDestroySubpictureRequest;
+pub type XCBXvmcListSubpictureTypesRequest = cdef::XCBXvmcListSubpictureTypesRequest;
+pub type XCBXvmcListSubpictureTypesCookie = cdef::XCBXvmcListSubpictureTypesCookie;
+pub type XCBXvmcListSubpictureTypesReply = cdef::XCBXvmcListSubpictureTypesReply;
+
+/// The XvMC extension, known to the server as `XVideo-MotionCompensation`.
+pub struct Xvmc;
+
+impl Extension for Xvmc {
+    fn id() -> *mut XCBExtension {
+        ptr::addr_of_mut!(cdef::xcb_xvmc_id)
+    }
+}
+
+pub fn xcb_xvmc_context_next(iter: &mut XCBXvmcContextIterator) {
+    unsafe { cdef::xcb_xvmc_context_next(iter) }
+}
+
+pub fn xcb_xvmc_surface_next(iter: &mut XCBXvmcSurfaceIterator) {
+    unsafe { cdef::xcb_xvmc_surface_next(iter) }
+}
+
+pub fn xcb_xvmc_subpicture_next(iter: &mut XCBXvmcSubpictureIterator) {
+    unsafe { cdef::xcb_xvmc_subpicture_next(iter) }
+}
+
+pub fn xcb_xvmc_surface_info_next(iter: &mut XCBXvmcSurfaceInfoIterator) {
+    unsafe { cdef::xcb_xvmc_surface_info_next(iter) }
+}
+
+impl Reply for XCBXvmcQueryVersionReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xvmc_query_version_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xvmc_query_version(connection: &XCBConnection) -> XCBXvmcQueryVersionCookie {
+    unsafe { cdef::xcb_xvmc_query_version(connection.raw) }
+}
+
+pub fn xcb_xvmc_list_surface_types_surfaces(
+    reply: &XCBXvmcListSurfaceTypesReply,
+) -> &[XCBXvmcSurfaceInfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xvmc_list_surface_types_surfaces(reply),
+            cdef::xcb_xvmc_list_surface_types_surfaces_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXvmcListSurfaceTypesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xvmc_list_surface_types_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xvmc_list_surface_types(
+    connection: &XCBConnection,
+    port_id: XCBXvPort,
+) -> XCBXvmcListSurfaceTypesCookie {
+    unsafe { cdef::xcb_xvmc_list_surface_types(connection.raw, port_id) }
+}
+
+pub fn xcb_xvmc_create_context_priv_data(reply: &XCBXvmcCreateContextReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xvmc_create_context_priv_data(reply),
+            cdef::xcb_xvmc_create_context_priv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXvmcCreateContextReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xvmc_create_context_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xvmc_create_context(
+    connection: &XCBConnection,
+    context_id: XCBXvmcContext,
+    port_id: XCBXvPort,
+    surface_id: XCBXvmcSurface,
+    width: u16,
+    height: u16,
+    flags: u32,
+) -> XCBXvmcCreateContextCookie {
+    unsafe {
+        cdef::xcb_xvmc_create_context(
+            connection.raw,
+            context_id,
+            port_id,
+            surface_id,
+            width,
+            height,
+            flags,
+        )
+    }
+}
+
+pub fn xcb_xvmc_destroy_context(
+    connection: &XCBConnection,
+    context_id: XCBXvmcContext,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xvmc_destroy_context(connection.raw, context_id) }
+}
+
+pub fn xcb_xvmc_create_surface_priv_data(reply: &XCBXvmcCreateSurfaceReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xvmc_create_surface_priv_data(reply),
+            cdef::xcb_xvmc_create_surface_priv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXvmcCreateSurfaceReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xvmc_create_surface_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xvmc_create_surface(
+    connection: &XCBConnection,
+    surface_id: XCBXvmcSurface,
+    context_id: XCBXvmcContext,
+) -> XCBXvmcCreateSurfaceCookie {
+    unsafe { cdef::xcb_xvmc_create_surface(connection.raw, surface_id, context_id) }
+}
+
+pub fn xcb_xvmc_destroy_surface(
+    connection: &XCBConnection,
+    surface_id: XCBXvmcSurface,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xvmc_destroy_surface(connection.raw, surface_id) }
+}
+
+pub fn xcb_xvmc_create_subpicture_priv_data(reply: &XCBXvmcCreateSubpictureReply) -> &[u32] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xvmc_create_subpicture_priv_data(reply),
+            cdef::xcb_xvmc_create_subpicture_priv_data_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXvmcCreateSubpictureReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xvmc_create_subpicture_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xvmc_create_subpicture(
+    connection: &XCBConnection,
+    subpicture_id: XCBXvmcSubpicture,
+    context: XCBXvmcContext,
+    xvimage_id: u32,
+    width: u16,
+    height: u16,
+) -> XCBXvmcCreateSubpictureCookie {
+    unsafe {
+        cdef::xcb_xvmc_create_subpicture(
+            connection.raw,
+            subpicture_id,
+            context,
+            xvimage_id,
+            width,
+            height,
+        )
+    }
+}
+
+pub fn xcb_xvmc_destroy_subpicture(
+    connection: &XCBConnection,
+    subpicture_id: XCBXvmcSubpicture,
+) -> XCBVoidCookie {
+    unsafe { cdef::xcb_xvmc_destroy_subpicture(connection.raw, subpicture_id) }
+}
+
+pub fn xcb_xvmc_list_subpicture_types_types(
+    reply: &XCBXvmcListSubpictureTypesReply,
+) -> &[XCBXvImageFormatInfo] {
+    unsafe {
+        slice::from_raw_parts(
+            cdef::xcb_xvmc_list_subpicture_types_types(reply),
+            cdef::xcb_xvmc_list_subpicture_types_types_length(reply) as usize,
+        )
+    }
+}
+
+impl Reply for XCBXvmcListSubpictureTypesReply {
+    unsafe fn reply(
+        connection: *mut ::cdef::XCBConnection,
+        cookie: XCBCookie<Self>,
+        e: *mut *mut XCBGenericError,
+    ) -> *mut Self {
+        cdef::xcb_xvmc_list_subpicture_types_reply(connection, cookie, e)
+    }
+}
+
+pub fn xcb_xvmc_list_subpicture_types(
+    connection: &XCBConnection,
+    port_id: XCBXvPort,
+    surface_id: XCBXvmcSurface,
+) -> XCBXvmcListSubpictureTypesCookie {
+    unsafe { cdef::xcb_xvmc_list_subpicture_types(connection.raw, port_id, surface_id) }
+}
@@ -0,0 +1,122 @@
+//! Events of the RandR extension with the notifications told apart by their sub-code.
+//!
+//! RandR reports changes of CRTCs, outputs, providers and leases with a single `Notify` event
+//! whose payload depends on its sub-code, so the payload is read out of the event here.
+//!
+//! `set_monitor` sends the monitor info together with its outputs, which the generated bindings
+//! only offer as a raw request.
+
+use proto::randr::constants::{
+    XCB_RANDR_NOTIFY_CRTC_CHANGE, XCB_RANDR_NOTIFY_LEASE, XCB_RANDR_NOTIFY_OUTPUT_CHANGE,
+    XCB_RANDR_NOTIFY_OUTPUT_PROPERTY, XCB_RANDR_NOTIFY_PROVIDER_CHANGE,
+    XCB_RANDR_NOTIFY_PROVIDER_PROPERTY, XCB_RANDR_NOTIFY_RESOURCE_CHANGE,
+};
+use proto::randr::{
+    self, cdef, XCBRandrCrtcChange, XCBRandrLeaseNotify, XCBRandrMonitorInfo, XCBRandrNotifyEvent,
+    XCBRandrOutput, XCBRandrOutputChange, XCBRandrOutputProperty, XCBRandrProviderChange,
+    XCBRandrProviderProperty, XCBRandrResourceChange, XCBRandrScreenChangeNotifyEvent,
+};
+use proto::xproto::{XCBAtom, XCBWindow};
+use {XCBConnection, XCBGenericEvent, XCBVoidCookie};
+
+/// An event of the RandR extension.
+///
+/// The notifications are only sent for the kinds selected with `xcb_randr_select_input`, e.g.
+/// `XCB_RANDR_NOTIFY_MASK_CRTC_CHANGE`.
+pub enum Event {
+    /// The size, rotation or configuration of a screen has changed.
+    ScreenChangeNotify(Box<XCBRandrScreenChangeNotifyEvent>),
+    /// The mode, position or rotation of a CRTC has changed.
+    CrtcChange(XCBRandrCrtcChange),
+    /// An output has been connected, disconnected or assigned to another CRTC or mode.
+    OutputChange(XCBRandrOutputChange),
+    /// A property of an output has been changed or deleted.
+    OutputProperty(XCBRandrOutputProperty),
+    /// A provider has been added or changed.
+    ProviderChange(XCBRandrProviderChange),
+    /// A property of a provider has been changed or deleted.
+    ProviderProperty(XCBRandrProviderProperty),
+    /// The list of CRTCs, outputs or providers has changed.
+    ResourceChange(XCBRandrResourceChange),
+    /// A lease has been created or freed.
+    Lease(XCBRandrLeaseNotify),
+    /// A notification with a sub-code that is not known.
+    Notify(Box<XCBRandrNotifyEvent>),
+}
+
+impl Event {
+    /// Decodes an event whose code, relative to the first event of the extension, is `number`.
+    ///
+    /// The event is handed back if it is not known.
+    pub fn decode(number: u8, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {
+        let notify = match randr::Event::decode(number, event)? {
+            randr::Event::ScreenChangeNotify(event) => return Ok(Event::ScreenChangeNotify(event)),
+            randr::Event::Notify(notify) => notify,
+        };
+
+        unsafe {
+            Ok(match notify.subCode {
+                XCB_RANDR_NOTIFY_CRTC_CHANGE => Event::CrtcChange(notify.u.cc),
+                XCB_RANDR_NOTIFY_OUTPUT_CHANGE => Event::OutputChange(notify.u.oc),
+                XCB_RANDR_NOTIFY_OUTPUT_PROPERTY => Event::OutputProperty(notify.u.op),
+                XCB_RANDR_NOTIFY_PROVIDER_CHANGE => Event::ProviderChange(notify.u.pc),
+                XCB_RANDR_NOTIFY_PROVIDER_PROPERTY => Event::ProviderProperty(notify.u.pp),
+                XCB_RANDR_NOTIFY_RESOURCE_CHANGE => Event::ResourceChange(notify.u.rc),
+                XCB_RANDR_NOTIFY_LEASE => Event::Lease(notify.u.lc),
+                _ => Event::Notify(notify),
+            })
+        }
+    }
+}
+
+/// Creates the monitor `name` on the screen of `window` covering the given area and `outputs`, or
+/// replaces the monitor of that name.
+///
+/// A monitor without outputs is only a named area of the screen, `width_in_millimeters` and
+/// `height_in_millimeters` give its physical size.
+pub fn set_monitor(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    name: XCBAtom,
+    primary: bool,
+    automatic: bool,
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+    width_in_millimeters: u32,
+    height_in_millimeters: u32,
+    outputs: &[XCBRandrOutput],
+) -> XCBVoidCookie {
+    assert!(outputs.len() <= usize::from(u16::MAX));
+
+    // The outputs follow the fixed part of the monitor info in the request.
+    let mut flags = [0; 4];
+    flags[0] = primary as u8;
+    flags[1] = automatic as u8;
+    flags[2..].copy_from_slice(&(outputs.len() as u16).to_ne_bytes());
+    let mut position = [0; 4];
+    position[..2].copy_from_slice(&x.to_ne_bytes());
+    position[2..].copy_from_slice(&y.to_ne_bytes());
+    let mut size = [0; 4];
+    size[..2].copy_from_slice(&width.to_ne_bytes());
+    size[2..].copy_from_slice(&height.to_ne_bytes());
+
+    let mut words = vec![
+        name,
+        u32::from_ne_bytes(flags),
+        u32::from_ne_bytes(position),
+        u32::from_ne_bytes(size),
+        width_in_millimeters,
+        height_in_millimeters,
+    ];
+    words.extend_from_slice(outputs);
+
+    unsafe {
+        cdef::xcb_randr_set_monitor(
+            connection.raw,
+            window,
+            words.as_ptr() as *const XCBRandrMonitorInfo,
+        )
+    }
+}
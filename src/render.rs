@@ -0,0 +1,152 @@
+//! Helpers for the RENDER extension: looking up picture formats and building the glyph
+//! elements drawn by `xcb_render_composite_glyphs_32`.
+
+use proto::render::constants::XCB_RENDER_PICT_TYPE_DIRECT;
+use proto::render::{
+    xcb_render_pictdepth_next, xcb_render_pictdepth_visuals, xcb_render_pictscreen_depths_iterator,
+    xcb_render_pictscreen_next, xcb_render_query_pict_formats_formats,
+    xcb_render_query_pict_formats_screens_iterator, XCBRenderDirectformat, XCBRenderFixed,
+    XCBRenderGlyphset, XCBRenderPictformat, XCBRenderPictforminfo, XCBRenderQueryPictFormatsReply,
+};
+use proto::xproto::XCBVisualid;
+
+/// The picture formats every server implementing RENDER provides.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PictStandard {
+    /// 8 bits each of alpha, red, green and blue.
+    Argb32,
+    /// 8 bits each of red, green and blue.
+    Rgb24,
+    /// 8 bits of alpha.
+    A8,
+    /// 4 bits of alpha.
+    A4,
+    /// 1 bit of alpha.
+    A1,
+}
+
+impl PictStandard {
+    fn depth_and_channels(self) -> (u8, XCBRenderDirectformat) {
+        let channels = |alpha_mask, color_mask| XCBRenderDirectformat {
+            red_shift: if color_mask != 0 { 16 } else { 0 },
+            red_mask: color_mask,
+            green_shift: if color_mask != 0 { 8 } else { 0 },
+            green_mask: color_mask,
+            blue_shift: 0,
+            blue_mask: color_mask,
+            alpha_shift: if alpha_mask != 0 && color_mask != 0 {
+                24
+            } else {
+                0
+            },
+            alpha_mask,
+        };
+        match self {
+            PictStandard::Argb32 => (32, channels(0xff, 0xff)),
+            PictStandard::Rgb24 => (24, channels(0, 0xff)),
+            PictStandard::A8 => (8, channels(0xff, 0)),
+            PictStandard::A4 => (4, channels(0x0f, 0)),
+            PictStandard::A1 => (1, channels(0x01, 0)),
+        }
+    }
+}
+
+/// Looks up one of the standard formats among the formats returned by
+/// `xcb_render_query_pict_formats`.
+pub fn find_standard_format(
+    formats: &XCBRenderQueryPictFormatsReply,
+    standard: PictStandard,
+) -> Option<&XCBRenderPictforminfo> {
+    let (depth, channels) = standard.depth_and_channels();
+    xcb_render_query_pict_formats_formats(formats)
+        .iter()
+        .find(|format| {
+            let direct = &format.direct;
+            format.type_ == XCB_RENDER_PICT_TYPE_DIRECT
+                && format.depth == depth
+                && direct.red_mask == channels.red_mask
+                && direct.green_mask == channels.green_mask
+                && direct.blue_mask == channels.blue_mask
+                && direct.alpha_mask == channels.alpha_mask
+                && (direct.red_mask == 0 || direct.red_shift == channels.red_shift)
+                && (direct.green_mask == 0 || direct.green_shift == channels.green_shift)
+                && (direct.blue_mask == 0 || direct.blue_shift == channels.blue_shift)
+                && (direct.alpha_mask == 0 || direct.alpha_shift == channels.alpha_shift)
+        })
+}
+
+/// Looks up the format of pictures of windows with the given visual, e.g. the `root_visual` of
+/// an `XCBScreen`, among the formats returned by `xcb_render_query_pict_formats`.
+pub fn find_visual_format(
+    formats: &XCBRenderQueryPictFormatsReply,
+    visual: XCBVisualid,
+) -> Option<XCBRenderPictformat> {
+    let mut screens = xcb_render_query_pict_formats_screens_iterator(formats);
+    while screens.rem > 0 {
+        let mut depths = xcb_render_pictscreen_depths_iterator(unsafe { &*screens.data });
+        while depths.rem > 0 {
+            let visuals = xcb_render_pictdepth_visuals(unsafe { &*depths.data });
+            if let Some(found) = visuals.iter().find(|candidate| candidate.visual == visual) {
+                return Some(found.format);
+            }
+            xcb_render_pictdepth_next(&mut depths);
+        }
+        xcb_render_pictscreen_next(&mut screens);
+    }
+    None
+}
+
+/// Converts a number to the 16.16 fixed point format used for coordinates, transforms and
+/// gradient stops.
+pub fn fixed(value: f64) -> XCBRenderFixed {
+    (value * 65536.0).round() as XCBRenderFixed
+}
+
+/// The glyph elements drawn by `xcb_render_composite_glyphs_32`.
+#[derive(Clone, Debug, Default)]
+pub struct GlyphElements {
+    data: Vec<u8>,
+}
+
+impl GlyphElements {
+    /// The most glyphs a single element can hold.
+    const MAX_GLYPHS: usize = 254;
+    /// The element count marking an element that switches the glyph set.
+    const GLYPHSET: u8 = 255;
+
+    pub fn new() -> GlyphElements {
+        GlyphElements { data: Vec::new() }
+    }
+
+    /// Appends glyphs, moving the pen by `dx`, `dy` before the first one.
+    ///
+    /// After each glyph the pen is moved by its `x_off` and `y_off`.
+    pub fn glyphs(&mut self, dx: i16, dy: i16, glyphs: &[u32]) -> &mut GlyphElements {
+        for (i, chunk) in glyphs.chunks(Self::MAX_GLYPHS).enumerate() {
+            let (dx, dy) = if i == 0 { (dx, dy) } else { (0, 0) };
+            self.header(chunk.len() as u8, dx, dy);
+            for glyph in chunk {
+                self.data.extend_from_slice(&glyph.to_ne_bytes());
+            }
+        }
+        self
+    }
+
+    /// Switches the glyph set the following glyphs are taken from.
+    pub fn glyph_set(&mut self, glyphset: XCBRenderGlyphset) -> &mut GlyphElements {
+        self.header(Self::GLYPHSET, 0, 0);
+        self.data.extend_from_slice(&glyphset.to_ne_bytes());
+        self
+    }
+
+    /// The encoded elements, to be passed as `glyphcmds`.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn header(&mut self, count: u8, dx: i16, dy: i16) {
+        self.data.extend_from_slice(&[count, 0, 0, 0]);
+        self.data.extend_from_slice(&dx.to_ne_bytes());
+        self.data.extend_from_slice(&dy.to_ne_bytes());
+    }
+}
@@ -0,0 +1,335 @@
+//! The X resource database, in which users configure applications with lines such as
+//! `XTerm*background: black`.
+//!
+//! The database is usually loaded by xrdb into the `RESOURCE_MANAGER` property of the root
+//! window. A resource is looked up by its name and class, e.g. `xterm.vt100.background` and
+//! `XTerm.VT100.Background`, and the most specific matching entry wins.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::mem;
+use std::path::Path;
+
+use property;
+use proto::xproto::constants::{XCB_ATOM_RESOURCE_MANAGER, XCB_ATOM_STRING};
+use proto::xproto::xcb_get_property_value;
+use {XCBConnection, XCBWindow, XError};
+
+/// How deeply files may include each other, which stops include cycles.
+const MAX_INCLUDE_DEPTH: u32 = 16;
+
+/// How a component is bound to the one before it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Binding {
+    /// `.`, the components are adjacent.
+    Tight,
+    /// `*`, any number of components may lie in between.
+    Loose,
+}
+
+/// How well an entry matches one level of a query, compared in the order of the fields: a
+/// level matched by a component beats a level skipped by a loose binding, a name beats a class
+/// beats `?`, and a tight binding beats a loose one.
+type Score = (bool, u8, bool);
+
+struct Entry {
+    components: Vec<(Binding, String)>,
+    value: String,
+}
+
+/// A resource database.
+#[derive(Default)]
+pub struct Database {
+    entries: Vec<Entry>,
+}
+
+impl Database {
+    pub fn new() -> Database {
+        Database::default()
+    }
+
+    /// Loads the database of the screen with the root window `root`, which is the
+    /// `RESOURCE_MANAGER` property of the root window or, if there is none, `~/.Xresources`.
+    pub fn load(connection: &XCBConnection, root: XCBWindow) -> Result<Database, XError> {
+        let mut database = Database::new();
+        match property::get(connection, root, XCB_ATOM_RESOURCE_MANAGER, XCB_ATOM_STRING)? {
+            Some(reply) => {
+                database.parse(&String::from_utf8_lossy(xcb_get_property_value(&reply)));
+            }
+            None => {
+                if let Some(home) = env::var_os("HOME") {
+                    let _ = database.parse_file(&Path::new(&home).join(".Xresources"));
+                }
+            }
+        }
+        Ok(database)
+    }
+
+    /// Adds the entries of a resource file, whose includes are relative to the working
+    /// directory. Entries replace those with the same specifier.
+    pub fn parse(&mut self, text: &str) {
+        self.parse_text(text, Path::new(""), 0);
+    }
+
+    /// Adds the entries of a resource file, whose includes are relative to its directory.
+    pub fn parse_file(&mut self, path: &Path) -> io::Result<()> {
+        self.parse_file_at(path, 0)
+    }
+
+    /// Returns the value of the resource with the name `name`, e.g. `Xft.dpi`, which is also
+    /// used as its class.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.get_class(name, name)
+    }
+
+    /// Returns the value of the resource with the name `name` and the class `class`, which
+    /// have to have as many components.
+    pub fn get_class(&self, name: &str, class: &str) -> Option<&str> {
+        let names: Vec<&str> = name.split('.').collect();
+        let classes: Vec<&str> = class.split('.').collect();
+        if names.len() != classes.len() {
+            return None;
+        }
+        let mut best: Option<(Vec<Score>, &str)> = None;
+        for entry in &self.entries {
+            if let Some(score) = best_match(&entry.components, &names, &classes) {
+                if best.as_ref().is_none_or(|(best, _)| score > *best) {
+                    best = Some((score, &entry.value));
+                }
+            }
+        }
+        best.map(|(_, value)| value)
+    }
+
+    fn parse_file_at(&mut self, path: &Path, depth: u32) -> io::Result<()> {
+        let text = fs::read(path)?;
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+        self.parse_text(&String::from_utf8_lossy(&text), directory, depth);
+        Ok(())
+    }
+
+    fn parse_text(&mut self, text: &str, directory: &Path, depth: u32) {
+        // A backslash at the end of a line continues it on the next one.
+        let mut line = String::new();
+        for part in text.split('\n') {
+            let trailing = part.len() - part.trim_end_matches('\\').len();
+            if trailing % 2 == 1 {
+                line.push_str(&part[..part.len() - 1]);
+                continue;
+            }
+            line.push_str(part);
+            self.parse_line(&line, directory, depth);
+            line.clear();
+        }
+        if !line.is_empty() {
+            self.parse_line(&line, directory, depth);
+        }
+    }
+
+    fn parse_line(&mut self, line: &str, directory: &Path, depth: u32) {
+        let line = line.trim_start_matches([' ', '\t']);
+        if line.is_empty() || line.starts_with('!') {
+            return;
+        }
+        if let Some(directive) = line.strip_prefix('#') {
+            // Other directives are left over by the preprocessor and ignored.
+            if let Some(file) = directive.trim_start().strip_prefix("include") {
+                let file = file.trim().trim_matches('"');
+                if depth < MAX_INCLUDE_DEPTH {
+                    let _ = self.parse_file_at(&directory.join(file), depth + 1);
+                }
+            }
+            return;
+        }
+        let colon = match line.find(':') {
+            Some(colon) => colon,
+            None => return,
+        };
+        let components = match parse_specifier(line[..colon].trim()) {
+            Some(components) => components,
+            None => return,
+        };
+        let value = unescape(line[colon + 1..].trim_start_matches([' ', '\t']));
+        match self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.components == components)
+        {
+            Some(entry) => entry.value = value,
+            None => self.entries.push(Entry { components, value }),
+        }
+    }
+}
+
+/// Splits a specifier such as `*vt100.background` into its bound components.
+fn parse_specifier(specifier: &str) -> Option<Vec<(Binding, String)>> {
+    let mut components = Vec::new();
+    let mut binding = Binding::Tight;
+    let mut component = String::new();
+    for c in specifier.chars() {
+        match c {
+            '.' | '*' => {
+                if !component.is_empty() {
+                    components.push((binding, mem::take(&mut component)));
+                    binding = Binding::Tight;
+                }
+                if c == '*' {
+                    binding = Binding::Loose;
+                }
+            }
+            c if c.is_whitespace() => return None,
+            c => component.push(c),
+        }
+    }
+    if component.is_empty() {
+        return None;
+    }
+    components.push((binding, component));
+    Some(components)
+}
+
+/// Replaces the escapes of a value: `\n` by a newline, `\nnn` by the byte with the octal code
+/// nnn, and a backslash before any other character, e.g. a leading space, by that character.
+fn unescape(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut unescaped = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' || i + 1 == bytes.len() {
+            unescaped.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+        let octal = bytes
+            .get(i + 1..i + 4)
+            .filter(|digits| digits.iter().all(|digit| (b'0'..=b'7').contains(digit)));
+        match octal {
+            Some(digits) => {
+                let code = digits
+                    .iter()
+                    .fold(0u32, |code, digit| code * 8 + u32::from(digit - b'0'));
+                unescaped.push(code as u8);
+                i += 4;
+            }
+            None => {
+                unescaped.push(match bytes[i + 1] {
+                    b'n' => b'\n',
+                    byte => byte,
+                });
+                i += 2;
+            }
+        }
+    }
+    String::from_utf8_lossy(&unescaped).into_owned()
+}
+
+/// Returns how well the components of an entry match the remaining levels of a query in the
+/// best of the possible ways, or `None` if they do not match.
+fn best_match(
+    components: &[(Binding, String)],
+    names: &[&str],
+    classes: &[&str],
+) -> Option<Vec<Score>> {
+    let (&(binding, ref component), rest) = match components.split_first() {
+        Some(split) => split,
+        None if names.is_empty() => return Some(Vec::new()),
+        None => return None,
+    };
+    if names.is_empty() {
+        return None;
+    }
+    let kind = if component == names[0] {
+        3
+    } else if component == classes[0] {
+        2
+    } else if component == "?" {
+        1
+    } else {
+        0
+    };
+    let mut best = None;
+    if kind > 0 {
+        if let Some(mut scores) = best_match(rest, &names[1..], &classes[1..]) {
+            scores.insert(0, (true, kind, binding == Binding::Tight));
+            best = Some(scores);
+        }
+    }
+    if binding == Binding::Loose {
+        if let Some(mut scores) = best_match(components, &names[1..], &classes[1..]) {
+            scores.insert(0, (false, 0, false));
+            if best.as_ref().is_none_or(|best| scores > *best) {
+                best = Some(scores);
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Database {
+        let mut database = Database::new();
+        database.parse(text);
+        database
+    }
+
+    #[test]
+    fn name_beats_class_beats_any() {
+        let database = parse("?.background: any\nXTerm.background: class\n");
+        assert_eq!(
+            database.get_class("xterm.background", "XTerm.Background"),
+            Some("class")
+        );
+
+        let database = parse("XTerm.background: class\nxterm.background: name\n");
+        assert_eq!(
+            database.get_class("xterm.background", "XTerm.Background"),
+            Some("name")
+        );
+    }
+
+    #[test]
+    fn matched_level_beats_skipped_level() {
+        let database = parse("*background: skipped\n?*background: matched\n");
+        assert_eq!(
+            database.get_class("xterm.vt100.background", "XTerm.VT100.Background"),
+            Some("matched")
+        );
+    }
+
+    #[test]
+    fn tight_beats_loose() {
+        let database = parse("*xterm.background: loose\nxterm.background: tight\n");
+        assert_eq!(
+            database.get_class("xterm.background", "XTerm.Background"),
+            Some("tight")
+        );
+    }
+
+    #[test]
+    fn no_match() {
+        let database = parse("xterm.background: black\n");
+        assert_eq!(database.get("xterm.foreground"), None);
+        assert_eq!(database.get("xterm.vt100.background"), None);
+        assert_eq!(database.get_class("xterm.background", "XTerm"), None);
+    }
+
+    #[test]
+    fn continuation() {
+        let database = parse("xterm.title: one \\\ntwo\nxterm.path: c:\\\\\nxterm.next: 1\n");
+        assert_eq!(database.get("xterm.title"), Some("one two"));
+        assert_eq!(database.get("xterm.path"), Some("c:\\"));
+        assert_eq!(database.get("xterm.next"), Some("1"));
+    }
+
+    #[test]
+    fn escapes() {
+        let database = parse("a: \\101\\102\\nC\nb: \\ leading\nc: \\8\n");
+        assert_eq!(database.get("a"), Some("AB\nC"));
+        assert_eq!(database.get("b"), Some(" leading"));
+        assert_eq!(database.get("c"), Some("8"));
+    }
+}
@@ -0,0 +1,844 @@
+//! Selections, through which clients exchange data such as the contents of the clipboard, and
+//! `Clipboard`, which serves and fetches them.
+//!
+//! Contents larger than a request can carry are transferred in chunks with the INCR protocol of
+//! the ICCCM.
+
+use std::cmp;
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use property;
+use proto::xproto::constants::{
+    XCB_ATOM_ATOM, XCB_ATOM_INTEGER, XCB_ATOM_NONE, XCB_ATOM_STRING, XCB_CW_EVENT_MASK,
+    XCB_EVENT_MASK_NO_EVENT, XCB_EVENT_MASK_PROPERTY_CHANGE, XCB_EVENT_MASK_STRUCTURE_NOTIFY,
+    XCB_GET_PROPERTY_TYPE_ANY, XCB_PROPERTY_DELETE, XCB_PROPERTY_NEW_VALUE, XCB_SELECTION_NOTIFY,
+    XCB_TIME_CURRENT_TIME, XCB_WINDOW_CLASS_INPUT_ONLY, XCB_WINDOW_NONE,
+};
+use proto::xproto::{
+    self, xcb_change_window_attributes, xcb_convert_selection, xcb_destroy_window,
+    xcb_get_property, xcb_get_property_value, xcb_get_selection_owner, xcb_get_window_attributes,
+    xcb_set_selection_owner, XCBPropertyNotifyEvent, XCBSelectionClearEvent,
+    XCBSelectionNotifyEvent, XCBSelectionRequestEvent,
+};
+use send::{send_event, ClientMessage, ClientMessageData, SendableEvent};
+use {
+    intern_atoms, xcb_create_window, xcb_generate_id, xcb_get_setup, Event, XCBAtom, XCBConnection,
+    XCBTimestamp, XCBVoidCookie, XCBWindow, XError,
+};
+
+/// Makes `owner` the owner of `selection`, or leaves the selection without owner for `None`.
+///
+/// The request is ignored if `time` is earlier than the last change of the owner, so the time
+/// of the event that caused the change should be passed rather than `XCB_TIME_CURRENT_TIME`.
+pub fn set_selection_owner(
+    connection: &XCBConnection,
+    owner: Option<XCBWindow>,
+    selection: XCBAtom,
+    time: XCBTimestamp,
+) -> XCBVoidCookie {
+    xcb_set_selection_owner(
+        connection,
+        owner.unwrap_or(XCB_WINDOW_NONE),
+        selection,
+        time,
+    )
+}
+
+/// Returns the owner of `selection`, or `None` if it has none.
+pub fn get_selection_owner(
+    connection: &XCBConnection,
+    selection: XCBAtom,
+) -> Result<Option<XCBWindow>, XError> {
+    let reply = xcb_get_selection_owner(connection, selection).reply(connection)?;
+    match reply.owner {
+        XCB_WINDOW_NONE => Ok(None),
+        owner => Ok(Some(owner)),
+    }
+}
+
+/// Makes `owner` the owner of a manager selection such as `_NET_SYSTEM_TRAY_S0` and announces
+/// it to the clients with a message of type `manager`, the `MANAGER` atom, on `root`, as
+/// described by the ICCCM.
+///
+/// # Return value
+/// Whether `owner` became the owner of the selection.
+pub fn claim_manager(
+    connection: &XCBConnection,
+    root: XCBWindow,
+    owner: XCBWindow,
+    selection: XCBAtom,
+    manager: XCBAtom,
+    time: XCBTimestamp,
+) -> Result<bool, XError> {
+    set_selection_owner(connection, Some(owner), selection, time);
+    if get_selection_owner(connection, selection)? != Some(owner) {
+        return Ok(false);
+    }
+    let message = ClientMessage::new(
+        root,
+        manager,
+        ClientMessageData::U32([time, selection, owner, 0, 0]),
+    );
+    send_event(
+        connection,
+        false,
+        root,
+        XCB_EVENT_MASK_STRUCTURE_NOTIFY,
+        &message,
+    );
+    Ok(true)
+}
+
+/// Asks the owner of `selection` to convert it to `target` and to store the result in
+/// `property` of `requestor`, which is then sent a `SelectionNotify` event.
+pub fn convert_selection(
+    connection: &XCBConnection,
+    requestor: XCBWindow,
+    selection: XCBAtom,
+    target: XCBAtom,
+    property: XCBAtom,
+    time: XCBTimestamp,
+) -> XCBVoidCookie {
+    xcb_convert_selection(connection, requestor, selection, target, property, time)
+}
+
+/// A client asks the owner of a selection to convert it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SelectionRequest {
+    pub time: XCBTimestamp,
+    pub owner: XCBWindow,
+    pub requestor: XCBWindow,
+    pub selection: XCBAtom,
+    pub target: XCBAtom,
+    /// The property of `requestor` to store the result in.
+    pub property: XCBAtom,
+}
+
+impl SelectionRequest {
+    /// Reads a received request. Obsolete clients do not name a property, in which case the
+    /// target is used as property.
+    pub fn from_event(event: &XCBSelectionRequestEvent) -> SelectionRequest {
+        SelectionRequest {
+            time: event.time,
+            owner: event.owner,
+            requestor: event.requestor,
+            selection: event.selection,
+            target: event.target,
+            property: match event.property {
+                XCB_ATOM_NONE => event.target,
+                property => property,
+            },
+        }
+    }
+
+    /// Returns the answer to the request, whose property is `None` if the conversion failed.
+    pub fn reply(&self, property: Option<XCBAtom>) -> SelectionNotify {
+        SelectionNotify {
+            time: self.time,
+            requestor: self.requestor,
+            selection: self.selection,
+            target: self.target,
+            property,
+        }
+    }
+}
+
+/// The owner of a selection answers a request to convert it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SelectionNotify {
+    pub time: XCBTimestamp,
+    pub requestor: XCBWindow,
+    pub selection: XCBAtom,
+    pub target: XCBAtom,
+    /// The property holding the result, or `None` if the conversion failed.
+    pub property: Option<XCBAtom>,
+}
+
+impl SelectionNotify {
+    /// Reads a received answer.
+    pub fn from_event(event: &XCBSelectionNotifyEvent) -> SelectionNotify {
+        SelectionNotify {
+            time: event.time,
+            requestor: event.requestor,
+            selection: event.selection,
+            target: event.target,
+            property: match event.property {
+                XCB_ATOM_NONE => None,
+                property => Some(property),
+            },
+        }
+    }
+
+    /// Returns the answer as an event.
+    pub fn to_event(&self) -> XCBSelectionNotifyEvent {
+        XCBSelectionNotifyEvent {
+            response_type: XCB_SELECTION_NOTIFY,
+            pad0: 0,
+            sequence: 0,
+            time: self.time,
+            requestor: self.requestor,
+            selection: self.selection,
+            target: self.target,
+            property: self.property.unwrap_or(XCB_ATOM_NONE),
+        }
+    }
+}
+
+impl SendableEvent for SelectionNotify {
+    fn to_wire(&self) -> [u8; 32] {
+        self.to_event().to_wire()
+    }
+}
+
+/// The owner of a selection lost it to another client.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SelectionClear {
+    pub time: XCBTimestamp,
+    pub owner: XCBWindow,
+    pub selection: XCBAtom,
+}
+
+impl SelectionClear {
+    /// Reads a received event.
+    pub fn from_event(event: &XCBSelectionClearEvent) -> SelectionClear {
+        SelectionClear {
+            time: event.time,
+            owner: event.owner,
+            selection: event.selection,
+        }
+    }
+}
+
+/// The atoms of the selection protocol that are not predefined by the core protocol.
+#[derive(Copy, Clone, Debug)]
+pub struct Atoms {
+    pub clipboard: XCBAtom,
+    pub targets: XCBAtom,
+    pub multiple: XCBAtom,
+    pub timestamp: XCBAtom,
+    pub incr: XCBAtom,
+    pub atom_pair: XCBAtom,
+    pub utf8_string: XCBAtom,
+    pub text_plain_utf8: XCBAtom,
+}
+
+impl Atoms {
+    pub fn intern(connection: &XCBConnection) -> Result<Atoms, XError> {
+        let atoms = intern_atoms(
+            connection,
+            &[
+                "CLIPBOARD",
+                "TARGETS",
+                "MULTIPLE",
+                "TIMESTAMP",
+                "INCR",
+                "ATOM_PAIR",
+                "UTF8_STRING",
+                "text/plain;charset=utf-8",
+            ],
+        )?;
+        Ok(Atoms {
+            clipboard: atoms[0],
+            targets: atoms[1],
+            multiple: atoms[2],
+            timestamp: atoms[3],
+            incr: atoms[4],
+            atom_pair: atoms[5],
+            utf8_string: atoms[6],
+            text_plain_utf8: atoms[7],
+        })
+    }
+}
+
+/// The contents of a selection converted to a target, as stored in a property.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Data {
+    /// The type of the property, which is usually the target itself.
+    pub type_: XCBAtom,
+    /// The size of the values in bits, 8, 16 or 32.
+    pub format: u8,
+    pub bytes: Vec<u8>,
+}
+
+impl Data {
+    /// Contents of format 8, e.g. text or an image file.
+    pub fn new(type_: XCBAtom, bytes: Vec<u8>) -> Data {
+        Data {
+            type_,
+            format: 8,
+            bytes,
+        }
+    }
+}
+
+/// The result of `Clipboard::request`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Received {
+    pub selection: XCBAtom,
+    pub target: XCBAtom,
+    /// The contents, or `None` if the selection has no owner or it could not convert them.
+    pub data: Option<Data>,
+}
+
+/// The contents of a selection owned by the clipboard.
+struct Offer {
+    time: XCBTimestamp,
+    contents: Vec<(XCBAtom, Rc<Data>)>,
+}
+
+/// How long a requestor may take to ask for the next chunk of an INCR transfer before the
+/// transfer is given up.
+const TRANSFER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// An INCR transfer to another client, which asks for each chunk by deleting the property.
+struct Outgoing {
+    data: Rc<Data>,
+    offset: usize,
+    /// The events of the requestor selected by this client before the transfer, which are
+    /// selected again once all transfers to the requestor are finished.
+    event_mask: u32,
+    /// When the last chunk was sent.
+    sent: Instant,
+}
+
+/// A conversion requested by the clipboard, and the chunks received so far if the owner
+/// transfers it with INCR.
+struct Incoming {
+    target: XCBAtom,
+    incr: Option<Data>,
+}
+
+/// Serves and fetches the contents of selections such as `CLIPBOARD` and `PRIMARY`.
+///
+/// The clipboard has its own unmapped window, whose events are handed to it through `handle`
+/// along with the other events of the connection. Conversions are stored in the property
+/// named like the selection.
+pub struct Clipboard<'a> {
+    connection: &'a XCBConnection,
+    window: XCBWindow,
+    atoms: Atoms,
+    /// The largest number of bytes written to a property at once.
+    chunk_size: usize,
+    offers: HashMap<XCBAtom, Offer>,
+    outgoing: HashMap<(XCBWindow, XCBAtom), Outgoing>,
+    incoming: HashMap<XCBAtom, Incoming>,
+    received: VecDeque<Received>,
+}
+
+impl<'a> Clipboard<'a> {
+    /// Creates the window of the clipboard on the screen of `root`.
+    pub fn new(connection: &'a XCBConnection, root: XCBWindow) -> Result<Clipboard<'a>, XError> {
+        let atoms = Atoms::intern(connection)?;
+        let window = xcb_generate_id(connection);
+        xcb_create_window(
+            connection,
+            0,
+            window,
+            root,
+            0,
+            0,
+            1,
+            1,
+            0,
+            XCB_WINDOW_CLASS_INPUT_ONLY,
+            0,
+            XCB_CW_EVENT_MASK,
+            &[XCB_EVENT_MASK_PROPERTY_CHANGE],
+        );
+        // ChangeProperty takes 24 bytes besides the data, which has to hold whole values of
+        // format 32.
+        let maximum_request_length = xcb_get_setup(connection).maximum_request_length as usize;
+        Ok(Clipboard {
+            connection,
+            window,
+            atoms,
+            chunk_size: (maximum_request_length * 4 - 24) & !3,
+            offers: HashMap::new(),
+            outgoing: HashMap::new(),
+            incoming: HashMap::new(),
+            received: VecDeque::new(),
+        })
+    }
+
+    pub fn atoms(&self) -> &Atoms {
+        &self.atoms
+    }
+
+    pub fn window(&self) -> XCBWindow {
+        self.window
+    }
+
+    /// Takes ownership of `selection` and offers `contents`, which pair the targets with the
+    /// contents converted to them. `TARGETS`, `MULTIPLE` and `TIMESTAMP` are answered by the
+    /// clipboard itself.
+    ///
+    /// # Return value
+    /// Whether the clipboard became the owner, which fails if `time` is earlier than the last
+    /// change of the owner.
+    pub fn offer(
+        &mut self,
+        selection: XCBAtom,
+        time: XCBTimestamp,
+        contents: Vec<(XCBAtom, Data)>,
+    ) -> Result<bool, XError> {
+        set_selection_owner(self.connection, Some(self.window), selection, time);
+        if get_selection_owner(self.connection, selection)? != Some(self.window) {
+            return Ok(false);
+        }
+        let contents = contents
+            .into_iter()
+            .map(|(target, data)| (target, Rc::new(data)))
+            .collect();
+        self.offers.insert(selection, Offer { time, contents });
+        Ok(true)
+    }
+
+    /// Offers text as `UTF8_STRING` and `text/plain;charset=utf-8`, and as `STRING` if it is
+    /// ASCII, see `offer`.
+    pub fn offer_text(
+        &mut self,
+        selection: XCBAtom,
+        time: XCBTimestamp,
+        text: &str,
+    ) -> Result<bool, XError> {
+        let bytes = text.as_bytes().to_vec();
+        let mut contents = vec![
+            (
+                self.atoms.utf8_string,
+                Data::new(self.atoms.utf8_string, bytes.clone()),
+            ),
+            (
+                self.atoms.text_plain_utf8,
+                Data::new(self.atoms.text_plain_utf8, bytes.clone()),
+            ),
+        ];
+        if text.is_ascii() {
+            contents.push((XCB_ATOM_STRING, Data::new(XCB_ATOM_STRING, bytes)));
+        }
+        self.offer(selection, time, contents)
+    }
+
+    /// Whether the clipboard owns `selection`.
+    pub fn owns(&self, selection: XCBAtom) -> bool {
+        self.offers.contains_key(&selection)
+    }
+
+    /// Gives up `selection` if the clipboard owns it.
+    pub fn clear(&mut self, selection: XCBAtom, time: XCBTimestamp) {
+        if self.offers.remove(&selection).is_some() {
+            set_selection_owner(self.connection, None, selection, time);
+        }
+    }
+
+    /// Asks the owner of `selection` for its contents converted to `target`, e.g. `TARGETS`
+    /// for the list of targets it offers. The result is returned by `next_received` once it
+    /// has been transferred.
+    pub fn request(
+        &mut self,
+        selection: XCBAtom,
+        target: XCBAtom,
+        time: XCBTimestamp,
+    ) -> XCBVoidCookie {
+        self.incoming
+            .insert(selection, Incoming { target, incr: None });
+        convert_selection(
+            self.connection,
+            self.window,
+            selection,
+            target,
+            selection,
+            time,
+        )
+    }
+
+    /// Returns the next finished request.
+    pub fn next_received(&mut self) -> Option<Received> {
+        self.received.pop_front()
+    }
+
+    /// Serves requests for the owned selections, continues transfers and forgets selections
+    /// taken over by other clients. Transfers to requestors that have been destroyed or have
+    /// not asked for the next chunk in time are given up.
+    ///
+    /// # Return value
+    /// Whether the event was meant for the clipboard.
+    pub fn handle(&mut self, event: &Event) -> bool {
+        self.expire_transfers();
+        match *event {
+            Event::Core(xproto::Event::SelectionRequest(ref request))
+                if request.owner == self.window =>
+            {
+                self.serve(&SelectionRequest::from_event(request));
+                true
+            }
+            Event::Core(xproto::Event::SelectionClear(ref clear)) if clear.owner == self.window => {
+                self.offers.remove(&clear.selection);
+                true
+            }
+            Event::Core(xproto::Event::SelectionNotify(ref notify))
+                if notify.requestor == self.window =>
+            {
+                self.receive(&SelectionNotify::from_event(notify));
+                true
+            }
+            Event::Core(xproto::Event::PropertyNotify(ref notify)) => self.transfer(notify),
+            Event::Core(xproto::Event::DestroyNotify(ref notify)) => {
+                self.requestor_destroyed(notify.window)
+            }
+            _ => false,
+        }
+    }
+
+    fn serve(&mut self, request: &SelectionRequest) {
+        let converted = if request.target == self.atoms.multiple {
+            self.convert_multiple(request)
+        } else {
+            self.convert(
+                request.requestor,
+                request.selection,
+                request.target,
+                request.property,
+                request.time,
+            )
+        };
+        let notify = request.reply(if converted {
+            Some(request.property)
+        } else {
+            None
+        });
+        send_event(
+            self.connection,
+            false,
+            request.requestor,
+            XCB_EVENT_MASK_NO_EVENT,
+            &notify,
+        );
+    }
+
+    /// Converts an owned selection and stores it in `property` of `requestor`.
+    ///
+    /// # Return value
+    /// Whether the target is offered and the selection was owned at `time`.
+    fn convert(
+        &mut self,
+        requestor: XCBWindow,
+        selection: XCBAtom,
+        target: XCBAtom,
+        property: XCBAtom,
+        time: XCBTimestamp,
+    ) -> bool {
+        let offer = match self.offers.get(&selection) {
+            Some(offer) if time == XCB_TIME_CURRENT_TIME || time >= offer.time => offer,
+            _ => return false,
+        };
+        if target == self.atoms.targets {
+            let mut targets = vec![
+                self.atoms.targets,
+                self.atoms.multiple,
+                self.atoms.timestamp,
+            ];
+            targets.extend(offer.contents.iter().map(|&(target, _)| target));
+            property::set_words(
+                self.connection,
+                requestor,
+                property,
+                XCB_ATOM_ATOM,
+                &targets,
+            );
+            return true;
+        }
+        if target == self.atoms.timestamp {
+            property::set_words(
+                self.connection,
+                requestor,
+                property,
+                XCB_ATOM_INTEGER,
+                &[offer.time],
+            );
+            return true;
+        }
+        let data = match offer
+            .contents
+            .iter()
+            .find(|&&(offered, _)| offered == target)
+        {
+            Some((_, data)) => data.clone(),
+            None => return false,
+        };
+        if data.bytes.len() <= self.chunk_size {
+            property::set(
+                self.connection,
+                requestor,
+                property,
+                data.type_,
+                data.format,
+                &data.bytes,
+            );
+        } else {
+            // The requestor deletes the property to ask for each chunk, which is only reported
+            // to clients that selected the changes of its properties, and its destruction ends
+            // the transfer. The requestor may be a window of this client, whose other events
+            // must stay selected.
+            let event_mask = match self.requestor_event_mask(requestor) {
+                Some(event_mask) => event_mask,
+                None => return false,
+            };
+            xcb_change_window_attributes(
+                self.connection,
+                requestor,
+                XCB_CW_EVENT_MASK,
+                &[event_mask | XCB_EVENT_MASK_PROPERTY_CHANGE | XCB_EVENT_MASK_STRUCTURE_NOTIFY],
+            );
+            property::set_words(
+                self.connection,
+                requestor,
+                property,
+                self.atoms.incr,
+                &[data.bytes.len() as u32],
+            );
+            self.outgoing.insert(
+                (requestor, property),
+                Outgoing {
+                    data,
+                    offset: 0,
+                    event_mask,
+                    sent: Instant::now(),
+                },
+            );
+        }
+        true
+    }
+
+    /// Converts an owned selection to the targets listed in the `ATOM_PAIR` property of the
+    /// request, which pairs each target with the property to store it in. The properties of
+    /// the failed conversions are replaced by `None` in the list.
+    fn convert_multiple(&mut self, request: &SelectionRequest) -> bool {
+        let mut pairs = match property::get_words(
+            self.connection,
+            request.requestor,
+            request.property,
+            self.atoms.atom_pair,
+        ) {
+            Ok(Some(pairs)) => pairs,
+            _ => return false,
+        };
+        for pair in pairs.chunks_mut(2) {
+            if pair.len() == 2
+                && !self.convert(
+                    request.requestor,
+                    request.selection,
+                    pair[0],
+                    pair[1],
+                    request.time,
+                )
+            {
+                pair[1] = XCB_ATOM_NONE;
+            }
+        }
+        property::set_words(
+            self.connection,
+            request.requestor,
+            request.property,
+            self.atoms.atom_pair,
+            &pairs,
+        );
+        true
+    }
+
+    fn receive(&mut self, notify: &SelectionNotify) {
+        let incoming = match self.incoming.remove(&notify.selection) {
+            Some(incoming) => incoming,
+            None => return,
+        };
+        let data = notify
+            .property
+            .and_then(|property| self.take_property(property));
+        match data {
+            // Taking the property deleted it, which asks the owner for the first chunk.
+            Some(ref data) if data.type_ == self.atoms.incr => {
+                self.incoming.insert(
+                    notify.selection,
+                    Incoming {
+                        target: incoming.target,
+                        incr: Some(Data::new(XCB_ATOM_NONE, Vec::new())),
+                    },
+                );
+            }
+            data => self.received.push_back(Received {
+                selection: notify.selection,
+                target: incoming.target,
+                data,
+            }),
+        }
+    }
+
+    /// Sends the next chunk of an outgoing INCR transfer once the requestor deleted the
+    /// property, or appends the next chunk of an incoming one once the owner stored it.
+    fn transfer(&mut self, notify: &XCBPropertyNotifyEvent) -> bool {
+        if notify.state == XCB_PROPERTY_DELETE {
+            let key = (notify.window, notify.atom);
+            let finished = match self.outgoing.get_mut(&key) {
+                Some(outgoing) => {
+                    let end =
+                        cmp::min(outgoing.offset + self.chunk_size, outgoing.data.bytes.len());
+                    // The transfer ends with an empty chunk.
+                    property::set(
+                        self.connection,
+                        notify.window,
+                        notify.atom,
+                        outgoing.data.type_,
+                        outgoing.data.format,
+                        &outgoing.data.bytes[outgoing.offset..end],
+                    );
+                    let finished = outgoing.offset == end;
+                    outgoing.offset = end;
+                    outgoing.sent = Instant::now();
+                    finished
+                }
+                None => return false,
+            };
+            if finished {
+                self.end_transfer(key, true);
+            }
+            return true;
+        }
+        if notify.window != self.window || notify.state != XCB_PROPERTY_NEW_VALUE {
+            return false;
+        }
+        let chunk = match self.incoming.get(&notify.atom) {
+            Some(&Incoming { incr: Some(_), .. }) => self.take_property(notify.atom),
+            _ => return false,
+        };
+        let incoming = self.incoming.get_mut(&notify.atom).unwrap();
+        let data = incoming.incr.as_mut().unwrap();
+        match chunk {
+            Some(ref chunk) if !chunk.bytes.is_empty() => {
+                data.type_ = chunk.type_;
+                data.format = chunk.format;
+                data.bytes.extend_from_slice(&chunk.bytes);
+            }
+            _ => {
+                let incoming = self.incoming.remove(&notify.atom).unwrap();
+                self.received.push_back(Received {
+                    selection: notify.atom,
+                    target: incoming.target,
+                    data: incoming.incr,
+                });
+            }
+        }
+        true
+    }
+
+    /// Forgets an outgoing transfer, and selects the events of the requestor as before once
+    /// no other transfer to it is left, unless the requestor is gone.
+    fn end_transfer(&mut self, key: (XCBWindow, XCBAtom), restore: bool) {
+        let outgoing = match self.outgoing.remove(&key) {
+            Some(outgoing) => outgoing,
+            None => return,
+        };
+        if restore && !self.outgoing.keys().any(|&(window, _)| window == key.0) {
+            xcb_change_window_attributes(
+                self.connection,
+                key.0,
+                XCB_CW_EVENT_MASK,
+                &[outgoing.event_mask],
+            );
+        }
+    }
+
+    /// Gives up the transfers whose requestors have not asked for the next chunk within
+    /// `TRANSFER_TIMEOUT`, as the ICCCM asks owners to.
+    fn expire_transfers(&mut self) {
+        let expired: Vec<_> = self
+            .outgoing
+            .iter()
+            .filter(|&(_, outgoing)| outgoing.sent.elapsed() > TRANSFER_TIMEOUT)
+            .map(|(&key, _)| key)
+            .collect();
+        for key in expired {
+            self.end_transfer(key, true);
+        }
+    }
+
+    /// Gives up the transfers to a destroyed requestor.
+    ///
+    /// # Return value
+    /// Whether the `DestroyNotify` was only reported because of the transfers.
+    fn requestor_destroyed(&mut self, requestor: XCBWindow) -> bool {
+        let keys: Vec<_> = self
+            .outgoing
+            .keys()
+            .filter(|&&(window, _)| window == requestor)
+            .cloned()
+            .collect();
+        let selected = match keys.first() {
+            Some(key) => self.outgoing[key].event_mask & XCB_EVENT_MASK_STRUCTURE_NOTIFY != 0,
+            None => true,
+        };
+        for key in keys {
+            self.end_transfer(key, false);
+        }
+        !selected
+    }
+
+    /// Returns the events of `requestor` selected by this client before any transfer to it,
+    /// or `None` if the window is gone.
+    fn requestor_event_mask(&self, requestor: XCBWindow) -> Option<u32> {
+        if let Some((_, outgoing)) = self
+            .outgoing
+            .iter()
+            .find(|&(&(window, _), _)| window == requestor)
+        {
+            return Some(outgoing.event_mask);
+        }
+        let attributes = xcb_get_window_attributes(self.connection, requestor)
+            .reply(self.connection)
+            .ok()?;
+        Some(attributes.your_event_mask)
+    }
+
+    /// Reads and deletes a property of the window of the clipboard.
+    fn take_property(&self, property: XCBAtom) -> Option<Data> {
+        let reply = xcb_get_property(
+            self.connection,
+            true,
+            self.window,
+            property,
+            XCB_GET_PROPERTY_TYPE_ANY,
+            0,
+            u32::MAX,
+        )
+        .reply(self.connection)
+        .ok()?;
+        if reply.type_ == XCB_ATOM_NONE {
+            return None;
+        }
+        Some(Data {
+            type_: reply.type_,
+            format: reply.format,
+            bytes: xcb_get_property_value(&reply).to_vec(),
+        })
+    }
+}
+
+impl<'a> Drop for Clipboard<'a> {
+    fn drop(&mut self) {
+        // Transfers left unfinished select the events of their requestors as before.
+        let mut requestors: HashMap<XCBWindow, u32> = HashMap::new();
+        for (&(requestor, _), outgoing) in &self.outgoing {
+            requestors.insert(requestor, outgoing.event_mask);
+        }
+        for (requestor, event_mask) in requestors {
+            xcb_change_window_attributes(
+                self.connection,
+                requestor,
+                XCB_CW_EVENT_MASK,
+                &[event_mask],
+            );
+        }
+        // Destroying the window gives up the selections it owns.
+        xcb_destroy_window(self.connection, self.window);
+    }
+}
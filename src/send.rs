@@ -0,0 +1,164 @@
+//! Sending events of the core protocol to other clients, e.g. the client messages of the
+//! conventions between clients.
+
+use std::mem;
+use std::slice;
+
+use proto::xproto::constants::XCB_CLIENT_MESSAGE;
+use proto::xproto::{
+    xcb_send_event, XCBButtonPressEvent, XCBCirculateNotifyEvent, XCBClientMessageData,
+    XCBClientMessageEvent, XCBColormapNotifyEvent, XCBConfigureNotifyEvent,
+    XCBConfigureRequestEvent, XCBCreateNotifyEvent, XCBDestroyNotifyEvent, XCBEnterNotifyEvent,
+    XCBExposeEvent, XCBFocusInEvent, XCBGraphicsExposureEvent, XCBGravityNotifyEvent,
+    XCBKeyPressEvent, XCBKeymapNotifyEvent, XCBMapNotifyEvent, XCBMapRequestEvent,
+    XCBMappingNotifyEvent, XCBMotionNotifyEvent, XCBNoExposureEvent, XCBPropertyNotifyEvent,
+    XCBReparentNotifyEvent, XCBResizeRequestEvent, XCBSelectionClearEvent, XCBSelectionNotifyEvent,
+    XCBSelectionRequestEvent, XCBUnmapNotifyEvent, XCBVisibilityNotifyEvent,
+};
+use {XCBAtom, XCBConnection, XCBVoidCookie, XCBWindow};
+
+/// An event that can be sent to other clients with `send_event`.
+pub trait SendableEvent {
+    /// Returns the event in the 32 byte form it has on the wire.
+    fn to_wire(&self) -> [u8; 32];
+}
+
+macro_rules! sendable_events {
+    ($($event:ty,)*) => {
+        $(
+            // The generated events omit the trailing padding of their 32 byte wire form.
+            const _: () = assert!(mem::size_of::<$event>() <= 32);
+
+            impl SendableEvent for $event {
+                fn to_wire(&self) -> [u8; 32] {
+                    let mut wire = [0; 32];
+                    let bytes = unsafe {
+                        slice::from_raw_parts(
+                            self as *const $event as *const u8,
+                            mem::size_of::<$event>(),
+                        )
+                    };
+                    wire[..bytes.len()].copy_from_slice(bytes);
+                    wire
+                }
+            }
+        )*
+    };
+}
+
+sendable_events! {
+    XCBKeyPressEvent,
+    XCBButtonPressEvent,
+    XCBMotionNotifyEvent,
+    XCBEnterNotifyEvent,
+    XCBFocusInEvent,
+    XCBKeymapNotifyEvent,
+    XCBExposeEvent,
+    XCBGraphicsExposureEvent,
+    XCBNoExposureEvent,
+    XCBVisibilityNotifyEvent,
+    XCBCreateNotifyEvent,
+    XCBDestroyNotifyEvent,
+    XCBUnmapNotifyEvent,
+    XCBMapNotifyEvent,
+    XCBMapRequestEvent,
+    XCBReparentNotifyEvent,
+    XCBConfigureNotifyEvent,
+    XCBConfigureRequestEvent,
+    XCBGravityNotifyEvent,
+    XCBResizeRequestEvent,
+    XCBCirculateNotifyEvent,
+    XCBPropertyNotifyEvent,
+    XCBSelectionClearEvent,
+    XCBSelectionRequestEvent,
+    XCBSelectionNotifyEvent,
+    XCBColormapNotifyEvent,
+    XCBClientMessageEvent,
+    XCBMappingNotifyEvent,
+}
+
+/// Sends an event to the clients that selected `event_mask` on `destination`.
+///
+/// With an empty mask the event goes to the creator of `destination`. If `propagate` is set
+/// and no client selected the mask, the event is propagated to the ancestors of `destination`
+/// as if it was a device event.
+pub fn send_event<E: SendableEvent>(
+    connection: &XCBConnection,
+    propagate: bool,
+    destination: XCBWindow,
+    event_mask: u32,
+    event: &E,
+) -> XCBVoidCookie {
+    xcb_send_event(
+        connection,
+        propagate,
+        destination,
+        event_mask,
+        &event.to_wire(),
+    )
+}
+
+/// The data of a client message, in one of the three formats.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ClientMessageData {
+    U8([u8; 20]),
+    U16([u16; 10]),
+    U32([u32; 5]),
+}
+
+/// A client message, whose meaning is given by its type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ClientMessage {
+    pub window: XCBWindow,
+    pub type_: XCBAtom,
+    pub data: ClientMessageData,
+}
+
+impl ClientMessage {
+    pub fn new(window: XCBWindow, type_: XCBAtom, data: ClientMessageData) -> ClientMessage {
+        ClientMessage {
+            window,
+            type_,
+            data,
+        }
+    }
+
+    /// Reads a received client message.
+    ///
+    /// # Return value
+    /// The message, or `None` if its format is not 8, 16 or 32.
+    pub fn from_event(event: &XCBClientMessageEvent) -> Option<ClientMessage> {
+        let data = unsafe {
+            match event.format {
+                8 => ClientMessageData::U8(event.data.data8),
+                16 => ClientMessageData::U16(event.data.data16),
+                32 => ClientMessageData::U32(event.data.data32),
+                _ => return None,
+            }
+        };
+        Some(ClientMessage::new(event.window, event.type_, data))
+    }
+
+    /// Returns the message as an event.
+    pub fn to_event(&self) -> XCBClientMessageEvent {
+        let (format, data) = match self.data {
+            ClientMessageData::U8(data8) => (8, XCBClientMessageData { data8 }),
+            ClientMessageData::U16(data16) => (16, XCBClientMessageData { data16 }),
+            ClientMessageData::U32(data32) => (32, XCBClientMessageData { data32 }),
+        };
+        XCBClientMessageEvent {
+            response_type: XCB_CLIENT_MESSAGE,
+            format,
+            sequence: 0,
+            window: self.window,
+            type_: self.type_,
+            data,
+        }
+    }
+}
+
+impl SendableEvent for ClientMessage {
+    fn to_wire(&self) -> [u8; 32] {
+        self.to_event().to_wire()
+    }
+}
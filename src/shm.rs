@@ -0,0 +1,387 @@
+//! Shared memory segments for transferring images with the MIT-SHM extension.
+//!
+//! Instead of sending the pixels through the socket, `ShmSegment` allocates memory that both
+//! the client and the server map, so only the request describing the image is sent.
+
+use std::cmp;
+use std::error::Error;
+use std::io;
+use std::os::raw::{c_char, c_int, c_long, c_uint, c_void};
+use std::ptr::null_mut;
+use std::slice;
+
+use proto::shm::{self, cdef, XCBShmCompletionEvent, XCBShmGetImageReply, XCBShmSeg};
+use proto::xproto::constants::{XCB_IMAGE_FORMAT_XY_BITMAP, XCB_IMAGE_FORMAT_Z_PIXMAP};
+use proto::xproto::{xcb_setup_pixmap_formats, XCBDrawable, XCBGcontext, XCBPixmap};
+use {xcb_generate_id, xcb_get_setup, XCBConnection, XCBVoidCookie};
+
+const IPC_PRIVATE: c_int = 0;
+const IPC_CREAT: c_int = 0o1000;
+const IPC_RMID: c_int = 0;
+#[cfg(target_os = "linux")]
+const MFD_CLOEXEC: c_uint = 1;
+#[cfg(target_os = "linux")]
+const PROT_READ: c_int = 1;
+#[cfg(target_os = "linux")]
+const PROT_WRITE: c_int = 2;
+#[cfg(target_os = "linux")]
+const MAP_SHARED: c_int = 1;
+
+extern "C" {
+    fn shmget(key: c_int, size: usize, flags: c_int) -> c_int;
+    fn shmat(id: c_int, address: *const c_void, flags: c_int) -> *mut c_void;
+    fn shmdt(address: *const c_void) -> c_int;
+    fn shmctl(id: c_int, command: c_int, buffer: *mut c_void) -> c_int;
+    #[cfg(target_os = "linux")]
+    fn memfd_create(name: *const c_char, flags: c_uint) -> c_int;
+    #[cfg(target_os = "linux")]
+    fn ftruncate(fd: c_int, length: c_long) -> c_int;
+    #[cfg(target_os = "linux")]
+    fn mmap(
+        address: *mut c_void,
+        length: usize,
+        protection: c_int,
+        flags: c_int,
+        fd: c_int,
+        offset: c_long,
+    ) -> *mut c_void;
+    #[cfg(target_os = "linux")]
+    fn munmap(address: *mut c_void, length: usize) -> c_int;
+    #[cfg(target_os = "linux")]
+    fn close(fd: c_int) -> c_int;
+}
+
+/// How the memory of a segment has been mapped, which decides how it is unmapped.
+enum Memory {
+    SysV,
+    #[cfg(target_os = "linux")]
+    Mapped,
+}
+
+/// A shared memory segment attached to the server, which is detached and freed when dropped.
+///
+/// Drawing into a segment while the server still reads from it for a `put_image` tears the
+/// image, so `is_busy` tells whether completion events of images put are outstanding.
+pub struct ShmSegment<'a> {
+    connection: &'a XCBConnection,
+    id: XCBShmSeg,
+    data: *mut u8,
+    size: usize,
+    memory: Memory,
+    pending: usize,
+}
+
+impl<'a> ShmSegment<'a> {
+    /// Creates a System V shared memory segment of `size` bytes and attaches it to the server.
+    ///
+    /// The segment is marked for removal as soon as the server has attached it, so it does not
+    /// outlive the client even if it exits without dropping the segment.
+    pub fn new(
+        connection: &'a XCBConnection,
+        size: usize,
+    ) -> Result<ShmSegment<'a>, Box<dyn Error>> {
+        unsafe {
+            let shmid = shmget(IPC_PRIVATE, size, IPC_CREAT | 0o600);
+            if shmid == -1 {
+                return Err(Box::new(io::Error::last_os_error()));
+            }
+            let data = shmat(shmid, null_mut(), 0);
+            if data as isize == -1 {
+                let error = io::Error::last_os_error();
+                shmctl(shmid, IPC_RMID, null_mut());
+                return Err(Box::new(error));
+            }
+
+            let id = xcb_generate_id(connection);
+            let attached =
+                cdef::xcb_shm_attach_checked(connection.raw, id, shmid as u32, 0).check(connection);
+            shmctl(shmid, IPC_RMID, null_mut());
+            if let Err(error) = attached {
+                shmdt(data);
+                return Err(Box::new(error));
+            }
+
+            Ok(ShmSegment {
+                connection,
+                id,
+                data: data as *mut u8,
+                size,
+                memory: Memory::SysV,
+                pending: 0,
+            })
+        }
+    }
+
+    /// Creates a segment of `size` bytes backed by a memfd and passes it to the server.
+    ///
+    /// This requires MIT-SHM 1.2, but works when the server can not access the System V
+    /// segments of the client, e.g. because they are in different IPC namespaces.
+    #[cfg(target_os = "linux")]
+    pub fn new_memfd(
+        connection: &'a XCBConnection,
+        size: usize,
+    ) -> Result<ShmSegment<'a>, Box<dyn Error>> {
+        unsafe {
+            let fd = memfd_create(b"xcb-shm\0".as_ptr() as *const c_char, MFD_CLOEXEC);
+            if fd == -1 {
+                return Err(Box::new(io::Error::last_os_error()));
+            }
+            if ftruncate(fd, size as c_long) == -1 {
+                let error = io::Error::last_os_error();
+                close(fd);
+                return Err(Box::new(error));
+            }
+            let data = mmap(null_mut(), size, PROT_READ | PROT_WRITE, MAP_SHARED, fd, 0);
+            if data as isize == -1 {
+                let error = io::Error::last_os_error();
+                close(fd);
+                return Err(Box::new(error));
+            }
+
+            // libxcb closes the descriptor once it has been sent.
+            let id = xcb_generate_id(connection);
+            let attached =
+                cdef::xcb_shm_attach_fd_checked(connection.raw, id, fd, 0).check(connection);
+            if let Err(error) = attached {
+                munmap(data, size);
+                return Err(Box::new(error));
+            }
+
+            Ok(ShmSegment {
+                connection,
+                id,
+                data: data as *mut u8,
+                size,
+                memory: Memory::Mapped,
+                pending: 0,
+            })
+        }
+    }
+
+    /// The id the server knows the segment by.
+    pub fn id(&self) -> XCBShmSeg {
+        self.id
+    }
+
+    /// The size of the segment in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The memory of the segment.
+    pub fn data(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.data, self.size) }
+    }
+
+    /// The memory of the segment, to draw the images to put into.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.data, self.size) }
+    }
+
+    /// Draws the image at `offset` in the segment, which is `total_width` by `total_height`
+    /// pixels large, to `drawable`.
+    ///
+    /// The server sends a completion event once it is done reading the segment, which has to be
+    /// passed to `completed`.
+    ///
+    /// # Return value
+    /// An error without sending the request if the image does not fit into the segment.
+    pub fn put_image(
+        &mut self,
+        drawable: XCBDrawable,
+        gc: XCBGcontext,
+        total_width: u16,
+        total_height: u16,
+        src_x: u16,
+        src_y: u16,
+        src_width: u16,
+        src_height: u16,
+        dst_x: i16,
+        dst_y: i16,
+        depth: u8,
+        format: u8,
+        offset: u32,
+    ) -> Result<XCBVoidCookie, Box<dyn Error>> {
+        let planes = if format == XCB_IMAGE_FORMAT_XY_BITMAP {
+            1
+        } else {
+            u32::from(depth)
+        };
+        let length = self.image_length(total_width, total_height, depth, format, planes);
+        self.check_bounds(offset, length)?;
+        self.pending += 1;
+        Ok(shm::xcb_shm_put_image(
+            self.connection,
+            drawable,
+            gc,
+            total_width,
+            total_height,
+            src_x,
+            src_y,
+            src_width,
+            src_height,
+            dst_x,
+            dst_y,
+            depth,
+            format,
+            true,
+            self.id,
+            offset,
+        ))
+    }
+
+    /// Copies the contents of a rectangle of `drawable`, whose depth is `depth`, to `offset` in
+    /// the segment.
+    ///
+    /// Waits for the reply, so the image is in the segment when this returns.
+    ///
+    /// # Return value
+    /// The reply, or an error without sending the request if the image does not fit into the
+    /// segment.
+    pub fn get_image(
+        &mut self,
+        drawable: XCBDrawable,
+        depth: u8,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        plane_mask: u32,
+        format: u8,
+        offset: u32,
+    ) -> Result<Box<XCBShmGetImageReply>, Box<dyn Error>> {
+        // Images in the XY formats hold the planes selected by the plane mask.
+        let depth_mask = 1u64
+            .checked_shl(u32::from(depth))
+            .map_or(u64::MAX, |bit| bit - 1);
+        let planes = (u64::from(plane_mask) & depth_mask).count_ones();
+        let length = self.image_length(width, height, depth, format, planes);
+        self.check_bounds(offset, length)?;
+        let reply = shm::xcb_shm_get_image(
+            self.connection,
+            drawable,
+            x,
+            y,
+            width,
+            height,
+            plane_mask,
+            format,
+            self.id,
+            offset,
+        )
+        .reply(self.connection)?;
+        Ok(reply)
+    }
+
+    /// Creates a pixmap whose contents are the image at `offset` in the segment.
+    ///
+    /// The pixmap keeps using the memory of the segment until it is freed.
+    ///
+    /// # Return value
+    /// The pixmap, or an error without sending the request if the image does not fit into the
+    /// segment.
+    pub fn create_pixmap(
+        &self,
+        drawable: XCBDrawable,
+        width: u16,
+        height: u16,
+        depth: u8,
+        offset: u32,
+    ) -> Result<XCBPixmap, Box<dyn Error>> {
+        let length = self.image_length(width, height, depth, XCB_IMAGE_FORMAT_Z_PIXMAP, 1);
+        self.check_bounds(offset, length)?;
+        let pixmap = xcb_generate_id(self.connection);
+        shm::xcb_shm_create_pixmap(
+            self.connection,
+            pixmap,
+            drawable,
+            width,
+            height,
+            depth,
+            self.id,
+            offset,
+        );
+        Ok(pixmap)
+    }
+
+    /// Takes note of a completion event.
+    ///
+    /// # Return value
+    /// `true` if the event belongs to a `put_image` of this segment.
+    pub fn completed(&mut self, event: &XCBShmCompletionEvent) -> bool {
+        if event.shmseg == self.id && self.pending > 0 {
+            self.pending -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether the server may still be reading images put from the segment.
+    pub fn is_busy(&self) -> bool {
+        self.pending > 0
+    }
+
+    /// Returns how many bytes an image takes in the segment, following the pixmap formats of
+    /// the server for the Z format and its bitmap format for `planes` planes in the XY formats.
+    ///
+    /// # Return value
+    /// The length, or `None` if the server has no pixmap format of `depth`.
+    fn image_length(
+        &self,
+        width: u16,
+        height: u16,
+        depth: u8,
+        format: u8,
+        planes: u32,
+    ) -> Option<usize> {
+        let setup = xcb_get_setup(self.connection);
+        let (bits_per_line, pad, planes) = if format == XCB_IMAGE_FORMAT_Z_PIXMAP {
+            let pixmap_format = xcb_setup_pixmap_formats(&setup)
+                .iter()
+                .find(|pixmap_format| pixmap_format.depth == depth)?;
+            (
+                usize::from(width) * usize::from(pixmap_format.bits_per_pixel),
+                usize::from(pixmap_format.scanline_pad),
+                1,
+            )
+        } else {
+            (
+                usize::from(width),
+                usize::from(setup.bitmap_format_scanline_pad),
+                planes as usize,
+            )
+        };
+        // Each line is padded to a multiple of the scanline pad, which is given in bits.
+        let pad = cmp::max(pad, 8);
+        let bytes_per_line = bits_per_line.div_ceil(pad) * pad / 8;
+        Some(bytes_per_line * usize::from(height) * planes)
+    }
+
+    fn check_bounds(&self, offset: u32, length: Option<usize>) -> Result<(), Box<dyn Error>> {
+        match length.and_then(|length| (offset as usize).checked_add(length)) {
+            Some(end) if end <= self.size => Ok(()),
+            _ => Err(Box::new(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the image does not fit into the shared memory segment",
+            ))),
+        }
+    }
+}
+
+impl<'a> Drop for ShmSegment<'a> {
+    fn drop(&mut self) {
+        shm::xcb_shm_detach(self.connection, self.id);
+        unsafe {
+            match self.memory {
+                Memory::SysV => {
+                    shmdt(self.data as *const c_void);
+                }
+                #[cfg(target_os = "linux")]
+                Memory::Mapped => {
+                    munmap(self.data as *mut c_void, self.size);
+                }
+            }
+        }
+    }
+}
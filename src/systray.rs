@@ -0,0 +1,358 @@
+//! The system tray protocol of freedesktop.org.
+//!
+//! The tray, usually part of a panel, owns the `_NET_SYSTEM_TRAY_Sn` selection of its screen.
+//! Clients ask it to dock their icons with a client message, after which the tray embeds the
+//! icons with XEmbed.
+
+use std::collections::VecDeque;
+
+use property;
+use proto::xproto::constants::{XCB_ATOM_CARDINAL, XCB_ATOM_VISUALID, XCB_EVENT_MASK_NO_EVENT};
+use proto::xproto::{self, xcb_map_window, xcb_unmap_window, XCBVisualid};
+use selection::{claim_manager, get_selection_owner};
+use send::{send_event, ClientMessage, ClientMessageData};
+use xembed::{self, XEmbed};
+use {intern_atoms, Event, XCBAtom, XCBConnection, XCBTimestamp, XCBVoidCookie, XCBWindow, XError};
+
+/// Opcode of the messages asking the tray to dock an icon.
+const REQUEST_DOCK: u32 = 0;
+
+/// The atoms of the system tray protocol for one screen.
+#[derive(Copy, Clone, Debug)]
+pub struct Atoms {
+    /// The selection of the tray of the screen, `_NET_SYSTEM_TRAY_Sn`.
+    pub net_system_tray_s: XCBAtom,
+    pub net_system_tray_opcode: XCBAtom,
+    pub net_system_tray_orientation: XCBAtom,
+    pub net_system_tray_visual: XCBAtom,
+    pub manager: XCBAtom,
+}
+
+impl Atoms {
+    /// Interns the atoms for the screen with the index `screen`.
+    pub fn intern(connection: &XCBConnection, screen: usize) -> Result<Atoms, XError> {
+        let selection = format!("_NET_SYSTEM_TRAY_S{}", screen);
+        let atoms = intern_atoms(
+            connection,
+            &[
+                &selection,
+                "_NET_SYSTEM_TRAY_OPCODE",
+                "_NET_SYSTEM_TRAY_ORIENTATION",
+                "_NET_SYSTEM_TRAY_VISUAL",
+                "MANAGER",
+            ],
+        )?;
+        Ok(Atoms {
+            net_system_tray_s: atoms[0],
+            net_system_tray_opcode: atoms[1],
+            net_system_tray_orientation: atoms[2],
+            net_system_tray_visual: atoms[3],
+            manager: atoms[4],
+        })
+    }
+}
+
+/// How the tray lays out its icons, `_NET_SYSTEM_TRAY_ORIENTATION`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+impl Orientation {
+    fn raw(self) -> u32 {
+        match self {
+            Orientation::Horizontal => 0,
+            Orientation::Vertical => 1,
+        }
+    }
+}
+
+/// A change of the tray reported by `SystemTray::next_event`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TrayEvent {
+    /// An icon was embedded into the window of the tray.
+    Docked(XCBWindow),
+    /// An icon was destroyed or reparented away by its client.
+    Undocked(XCBWindow),
+    /// Another client took over the selection of the tray.
+    Lost,
+}
+
+/// The tray, which embeds the icons into its window.
+///
+/// The tray does not lay out the icons, which are embedded at the origin of its window and have
+/// to be moved by the panel when they are docked.
+pub struct SystemTray<'a> {
+    connection: &'a XCBConnection,
+    atoms: Atoms,
+    xembed: XEmbed<'a>,
+    root: XCBWindow,
+    window: XCBWindow,
+    icons: Vec<XCBWindow>,
+    events: VecDeque<TrayEvent>,
+}
+
+impl<'a> SystemTray<'a> {
+    /// Creates the tray of the screen with the index `screen` and the root window `root`,
+    /// which embeds the icons into `window`.
+    pub fn new(
+        connection: &'a XCBConnection,
+        screen: usize,
+        root: XCBWindow,
+        window: XCBWindow,
+    ) -> Result<SystemTray<'a>, XError> {
+        Ok(SystemTray {
+            connection,
+            atoms: Atoms::intern(connection, screen)?,
+            xembed: XEmbed::new(connection)?,
+            root,
+            window,
+            icons: Vec::new(),
+            events: VecDeque::new(),
+        })
+    }
+
+    pub fn atoms(&self) -> &Atoms {
+        &self.atoms
+    }
+
+    /// The docked icons, in the order they were docked.
+    pub fn icons(&self) -> &[XCBWindow] {
+        &self.icons
+    }
+
+    /// Takes the selection of the tray and tells the clients with a `MANAGER` message on the
+    /// root window, which makes them dock their icons.
+    ///
+    /// # Return value
+    /// Whether the tray became the owner of the selection.
+    pub fn claim(&mut self, time: XCBTimestamp) -> Result<bool, XError> {
+        claim_manager(
+            self.connection,
+            self.root,
+            self.window,
+            self.atoms.net_system_tray_s,
+            self.atoms.manager,
+            time,
+        )
+    }
+
+    pub fn set_orientation(&self, orientation: Orientation) -> XCBVoidCookie {
+        property::set_words(
+            self.connection,
+            self.window,
+            self.atoms.net_system_tray_orientation,
+            XCB_ATOM_CARDINAL,
+            &[orientation.raw()],
+        )
+    }
+
+    /// Sets the visual the icons should use for their windows, e.g. one with an alpha channel.
+    pub fn set_visual(&self, visual: XCBVisualid) -> XCBVoidCookie {
+        property::set_words(
+            self.connection,
+            self.window,
+            self.atoms.net_system_tray_visual,
+            XCB_ATOM_VISUALID,
+            &[visual],
+        )
+    }
+
+    /// Returns the next change of the tray.
+    pub fn next_event(&mut self) -> Option<TrayEvent> {
+        self.events.pop_front()
+    }
+
+    /// Docks the icons of requests, maps and unmaps them following their `_XEMBED_INFO` and
+    /// forgets them once they are destroyed or reparented away.
+    ///
+    /// # Return value
+    /// Whether the event was meant for the tray.
+    pub fn handle(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::Core(xproto::Event::ClientMessage(ref message))
+                if message.window == self.window
+                    && message.type_ == self.atoms.net_system_tray_opcode =>
+            {
+                if let Some(ClientMessage {
+                    data: ClientMessageData::U32(data),
+                    ..
+                }) = ClientMessage::from_event(message)
+                {
+                    if data[1] == REQUEST_DOCK {
+                        self.dock(data[2], data[0]);
+                    }
+                }
+                true
+            }
+            Event::Core(xproto::Event::DestroyNotify(ref notify)) => self.undock(notify.window),
+            Event::Core(xproto::Event::ReparentNotify(ref notify))
+                if notify.parent != self.window =>
+            {
+                self.undock(notify.window)
+            }
+            Event::Core(xproto::Event::PropertyNotify(ref notify))
+                if notify.atom == self.xembed.atoms().xembed_info
+                    && self.icons.contains(&notify.window) =>
+            {
+                if let Ok(Some(info)) = self.xembed.get_info(notify.window) {
+                    if info.mapped {
+                        xcb_map_window(self.connection, notify.window);
+                    } else {
+                        xcb_unmap_window(self.connection, notify.window);
+                    }
+                }
+                true
+            }
+            Event::Core(xproto::Event::SelectionClear(ref clear))
+                if clear.owner == self.window
+                    && clear.selection == self.atoms.net_system_tray_s =>
+            {
+                self.events.push_back(TrayEvent::Lost);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn dock(&mut self, icon: XCBWindow, time: XCBTimestamp) {
+        if self.icons.contains(&icon) {
+            return;
+        }
+        // The icon may already be gone, in which case there is nothing to dock.
+        if self.xembed.embed(self.window, icon, time).is_ok() {
+            self.icons.push(icon);
+            self.events.push_back(TrayEvent::Docked(icon));
+        }
+    }
+
+    fn undock(&mut self, icon: XCBWindow) -> bool {
+        match self.icons.iter().position(|&docked| docked == icon) {
+            Some(index) => {
+                self.icons.remove(index);
+                self.events.push_back(TrayEvent::Undocked(icon));
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The icon of a client, which docks into the tray of its screen.
+///
+/// The client has to select `XCB_EVENT_MASK_STRUCTURE_NOTIFY` on the root window to receive
+/// the `MANAGER` messages of new trays, after which the icon is docked again, and on the icon
+/// to notice when the tray goes away.
+pub struct TrayIcon<'a> {
+    connection: &'a XCBConnection,
+    atoms: Atoms,
+    xembed: XEmbed<'a>,
+    root: XCBWindow,
+    window: XCBWindow,
+    embedder: Option<XCBWindow>,
+}
+
+impl<'a> TrayIcon<'a> {
+    /// Makes `window` an icon for the tray of the screen with the index `screen` and the root
+    /// window `root`, setting its `_XEMBED_INFO` so the tray maps it.
+    pub fn new(
+        connection: &'a XCBConnection,
+        screen: usize,
+        root: XCBWindow,
+        window: XCBWindow,
+    ) -> Result<TrayIcon<'a>, XError> {
+        let xembed = XEmbed::new(connection)?;
+        xembed.set_info(
+            window,
+            &xembed::Info {
+                version: xembed::VERSION,
+                mapped: true,
+            },
+        );
+        Ok(TrayIcon {
+            connection,
+            atoms: Atoms::intern(connection, screen)?,
+            xembed,
+            root,
+            window,
+            embedder: None,
+        })
+    }
+
+    pub fn atoms(&self) -> &Atoms {
+        &self.atoms
+    }
+
+    /// The window the icon is embedded into, or `None` if it is not docked.
+    pub fn embedder(&self) -> Option<XCBWindow> {
+        self.embedder
+    }
+
+    /// Asks the tray to dock the icon.
+    ///
+    /// # Return value
+    /// Whether there is a tray to ask.
+    pub fn dock(&self, time: XCBTimestamp) -> Result<bool, XError> {
+        let tray = match get_selection_owner(self.connection, self.atoms.net_system_tray_s)? {
+            Some(tray) => tray,
+            None => return Ok(false),
+        };
+        let message = ClientMessage::new(
+            tray,
+            self.atoms.net_system_tray_opcode,
+            ClientMessageData::U32([time, REQUEST_DOCK, self.window, 0, 0]),
+        );
+        send_event(
+            self.connection,
+            false,
+            tray,
+            XCB_EVENT_MASK_NO_EVENT,
+            &message,
+        );
+        Ok(true)
+    }
+
+    /// Docks the icon into new trays and follows its embedding.
+    ///
+    /// # Return value
+    /// Whether the event was meant for the icon. The other XEmbed messages, e.g. for the
+    /// focus, are left to the client.
+    pub fn handle(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::Core(xproto::Event::ClientMessage(ref message))
+                if message.window == self.root && message.type_ == self.atoms.manager =>
+            {
+                match ClientMessage::from_event(message) {
+                    Some(ClientMessage {
+                        data: ClientMessageData::U32(data),
+                        ..
+                    }) if data[1] == self.atoms.net_system_tray_s => {
+                        let _ = self.dock(data[0]);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            Event::Core(xproto::Event::ClientMessage(ref message))
+                if message.window == self.window =>
+            {
+                match self.xembed.decode(message) {
+                    Some((_, xembed::Message::EmbeddedNotify { embedder, .. })) => {
+                        self.embedder = Some(embedder);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            Event::Core(xproto::Event::ReparentNotify(ref notify))
+                if notify.window == self.window && notify.parent == self.root =>
+            {
+                // The tray went away and the icon was moved back by its save-set.
+                self.embedder = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! The XEmbed protocol, through which a client embeds the window of another client, e.g. a panel
+//! hosting the icons of the system tray.
+//!
+//! The embedder reparents the window of the client into one of its own windows and both sides
+//! coordinate the focus, activation and mapping with `_XEMBED` client messages.
+
+use std::cmp;
+
+use property;
+use proto::xproto::constants::{
+    XCB_CW_EVENT_MASK, XCB_EVENT_MASK_NO_EVENT, XCB_EVENT_MASK_PROPERTY_CHANGE,
+    XCB_EVENT_MASK_STRUCTURE_NOTIFY, XCB_SET_MODE_INSERT,
+};
+use proto::xproto::{
+    xcb_change_save_set, xcb_change_window_attributes, xcb_map_window, xcb_reparent_window,
+    XCBClientMessageEvent,
+};
+use send::{send_event, ClientMessage, ClientMessageData};
+use {intern_atoms, XCBAtom, XCBConnection, XCBTimestamp, XCBVoidCookie, XCBWindow, XError};
+
+/// The version of the protocol that is implemented.
+pub const VERSION: u32 = 0;
+
+/// The atoms of the XEmbed protocol.
+#[derive(Copy, Clone, Debug)]
+pub struct Atoms {
+    pub xembed: XCBAtom,
+    pub xembed_info: XCBAtom,
+}
+
+impl Atoms {
+    pub fn intern(connection: &XCBConnection) -> Result<Atoms, XError> {
+        let atoms = intern_atoms(connection, &["_XEMBED", "_XEMBED_INFO"])?;
+        Ok(Atoms {
+            xembed: atoms[0],
+            xembed_info: atoms[1],
+        })
+    }
+}
+
+/// The `_XEMBED_INFO` property of an embedded window.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Info {
+    /// The version of the protocol the client supports.
+    pub version: u32,
+    /// Whether the client wants the embedder to map its window, `XEMBED_MAPPED`.
+    pub mapped: bool,
+}
+
+/// Where the focus moves to when it enters the embedded window.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Focus {
+    /// The window focused within the client the last time.
+    Current,
+    First,
+    Last,
+}
+
+impl Focus {
+    fn raw(self) -> u32 {
+        match self {
+            Focus::Current => 0,
+            Focus::First => 1,
+            Focus::Last => 2,
+        }
+    }
+
+    fn from_raw(focus: u32) -> Focus {
+        match focus {
+            1 => Focus::First,
+            2 => Focus::Last,
+            _ => Focus::Current,
+        }
+    }
+}
+
+/// A message of the XEmbed protocol.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Message {
+    /// The client was embedded into `embedder`, with the lower of the two versions.
+    EmbeddedNotify {
+        embedder: XCBWindow,
+        version: u32,
+    },
+    /// The top-level window of the embedder was activated.
+    WindowActivate,
+    WindowDeactivate,
+    /// The client asks the embedder for the focus.
+    RequestFocus,
+    FocusIn(Focus),
+    FocusOut,
+    /// The client moved the focus past its last window, which moves it on in the embedder.
+    FocusNext,
+    FocusPrev,
+    /// The embedder shows a modal dialog, which blocks the input of the client.
+    ModalityOn,
+    ModalityOff,
+    /// The client asks the embedder to report a key combination with `ActivateAccelerator`.
+    RegisterAccelerator {
+        id: u32,
+        keysym: u32,
+        modifiers: u32,
+    },
+    UnregisterAccelerator {
+        id: u32,
+    },
+    ActivateAccelerator {
+        id: u32,
+        flags: u32,
+    },
+}
+
+impl Message {
+    /// Returns the opcode and the three words of details of the message.
+    fn raw(self) -> (u32, [u32; 3]) {
+        match self {
+            Message::EmbeddedNotify { embedder, version } => (0, [0, embedder, version]),
+            Message::WindowActivate => (1, [0; 3]),
+            Message::WindowDeactivate => (2, [0; 3]),
+            Message::RequestFocus => (3, [0; 3]),
+            Message::FocusIn(focus) => (4, [focus.raw(), 0, 0]),
+            Message::FocusOut => (5, [0; 3]),
+            Message::FocusNext => (6, [0; 3]),
+            Message::FocusPrev => (7, [0; 3]),
+            Message::ModalityOn => (10, [0; 3]),
+            Message::ModalityOff => (11, [0; 3]),
+            Message::RegisterAccelerator {
+                id,
+                keysym,
+                modifiers,
+            } => (12, [id, keysym, modifiers]),
+            Message::UnregisterAccelerator { id } => (13, [id, 0, 0]),
+            Message::ActivateAccelerator { id, flags } => (14, [id, flags, 0]),
+        }
+    }
+
+    fn from_raw(opcode: u32, details: [u32; 3]) -> Option<Message> {
+        let message = match opcode {
+            0 => Message::EmbeddedNotify {
+                embedder: details[1],
+                version: details[2],
+            },
+            1 => Message::WindowActivate,
+            2 => Message::WindowDeactivate,
+            3 => Message::RequestFocus,
+            4 => Message::FocusIn(Focus::from_raw(details[0])),
+            5 => Message::FocusOut,
+            6 => Message::FocusNext,
+            7 => Message::FocusPrev,
+            10 => Message::ModalityOn,
+            11 => Message::ModalityOff,
+            12 => Message::RegisterAccelerator {
+                id: details[0],
+                keysym: details[1],
+                modifiers: details[2],
+            },
+            13 => Message::UnregisterAccelerator { id: details[0] },
+            14 => Message::ActivateAccelerator {
+                id: details[0],
+                flags: details[1],
+            },
+            _ => return None,
+        };
+        Some(message)
+    }
+}
+
+/// Sends and receives the messages of the XEmbed protocol and embeds windows.
+pub struct XEmbed<'a> {
+    connection: &'a XCBConnection,
+    atoms: Atoms,
+}
+
+impl<'a> XEmbed<'a> {
+    /// Interns the atoms of the XEmbed protocol.
+    pub fn new(connection: &'a XCBConnection) -> Result<XEmbed<'a>, XError> {
+        Ok(XEmbed {
+            connection,
+            atoms: Atoms::intern(connection)?,
+        })
+    }
+
+    pub fn atoms(&self) -> &Atoms {
+        &self.atoms
+    }
+
+    pub fn get_info(&self, window: XCBWindow) -> Result<Option<Info>, XError> {
+        let words = property::get_words(
+            self.connection,
+            window,
+            self.atoms.xembed_info,
+            self.atoms.xembed_info,
+        )?;
+        match words {
+            Some(ref words) if words.len() >= 2 => Ok(Some(Info {
+                version: words[0],
+                mapped: words[1] & 1 != 0,
+            })),
+            _ => Ok(None),
+        }
+    }
+
+    pub fn set_info(&self, window: XCBWindow, info: &Info) -> XCBVoidCookie {
+        property::set_words(
+            self.connection,
+            window,
+            self.atoms.xembed_info,
+            self.atoms.xembed_info,
+            &[info.version, info.mapped as u32],
+        )
+    }
+
+    /// Sends a message to `window`, which is the embedded window for the messages of the
+    /// embedder and the embedder for those of the client.
+    pub fn send(&self, window: XCBWindow, time: XCBTimestamp, message: Message) -> XCBVoidCookie {
+        let (opcode, details) = message.raw();
+        let data = [time, opcode, details[0], details[1], details[2]];
+        let message = ClientMessage::new(window, self.atoms.xembed, ClientMessageData::U32(data));
+        send_event(
+            self.connection,
+            false,
+            window,
+            XCB_EVENT_MASK_NO_EVENT,
+            &message,
+        )
+    }
+
+    /// Decodes a client message as a message of the XEmbed protocol.
+    ///
+    /// # Return value
+    /// The time the message was sent at and the message.
+    pub fn decode(&self, message: &XCBClientMessageEvent) -> Option<(XCBTimestamp, Message)> {
+        if message.type_ != self.atoms.xembed {
+            return None;
+        }
+        let data = match ClientMessage::from_event(message)?.data {
+            ClientMessageData::U32(data) => data,
+            _ => return None,
+        };
+        let message = Message::from_raw(data[1], [data[2], data[3], data[4]])?;
+        Some((data[0], message))
+    }
+
+    /// Embeds `client` into `embedder` and maps it unless its `_XEMBED_INFO` asks otherwise.
+    ///
+    /// The client is added to the save-set, which moves it back to the root window if the
+    /// embedder goes away, and its structure and property changes are selected to follow its
+    /// lifecycle and its `_XEMBED_INFO`.
+    ///
+    /// # Return value
+    /// The `_XEMBED_INFO` of the client, or `None` if it has none.
+    pub fn embed(
+        &self,
+        embedder: XCBWindow,
+        client: XCBWindow,
+        time: XCBTimestamp,
+    ) -> Result<Option<Info>, XError> {
+        let info = self.get_info(client)?;
+        xcb_change_window_attributes(
+            self.connection,
+            client,
+            XCB_CW_EVENT_MASK,
+            &[XCB_EVENT_MASK_STRUCTURE_NOTIFY | XCB_EVENT_MASK_PROPERTY_CHANGE],
+        );
+        xcb_change_save_set(self.connection, XCB_SET_MODE_INSERT, client);
+        xcb_reparent_window(self.connection, client, embedder, 0, 0);
+        let version = info.map_or(VERSION, |info| cmp::min(info.version, VERSION));
+        self.send(client, time, Message::EmbeddedNotify { embedder, version });
+        if info.is_none_or(|info| info.mapped) {
+            xcb_map_window(self.connection, client);
+        }
+        Ok(info)
+    }
+}
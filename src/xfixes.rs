@@ -0,0 +1,213 @@
+//! Helpers for the XFixes extension: notifications of selection owners and cursors, the image
+//! of the cursor, regions and hiding the cursor.
+//!
+//! The server only accepts XFixes requests once `query_version` has been called. The events
+//! are decoded into `Event::Xfixes`, e.g. `proto::xfixes::Event::SelectionNotify`.
+
+use proto::shape::constants::{XCB_SHAPE_SK_BOUNDING, XCB_SHAPE_SK_CLIP, XCB_SHAPE_SK_INPUT};
+use proto::xfixes::constants::{
+    XCB_XFIXES_CURSOR_NOTIFY_MASK_DISPLAY_CURSOR, XCB_XFIXES_MAJOR_VERSION,
+    XCB_XFIXES_MINOR_VERSION, XCB_XFIXES_REGION_NONE,
+    XCB_XFIXES_SELECTION_EVENT_MASK_SELECTION_CLIENT_CLOSE,
+    XCB_XFIXES_SELECTION_EVENT_MASK_SELECTION_WINDOW_DESTROY,
+    XCB_XFIXES_SELECTION_EVENT_MASK_SET_SELECTION_OWNER,
+};
+use proto::xfixes::{
+    xcb_xfixes_create_region, xcb_xfixes_create_region_from_window, xcb_xfixes_destroy_region,
+    xcb_xfixes_fetch_region, xcb_xfixes_fetch_region_rectangles, xcb_xfixes_get_cursor_image,
+    xcb_xfixes_get_cursor_image_cursor_image, xcb_xfixes_hide_cursor, xcb_xfixes_query_version,
+    xcb_xfixes_select_cursor_input, xcb_xfixes_select_selection_input,
+    xcb_xfixes_set_window_shape_region, xcb_xfixes_show_cursor, XCBXfixesRegion,
+};
+use proto::xproto::{XCBAtom, XCBRectangle, XCBWindow};
+use {xcb_generate_id, XCBConnection, XCBVoidCookie, XError};
+
+/// Tells the server the version of XFixes the client speaks, which it has to do before any
+/// other XFixes request.
+///
+/// # Return value
+/// The major and minor version both sides support.
+pub fn query_version(connection: &XCBConnection) -> Result<(u32, u32), XError> {
+    let reply = xcb_xfixes_query_version(
+        connection,
+        XCB_XFIXES_MAJOR_VERSION,
+        XCB_XFIXES_MINOR_VERSION,
+    )
+    .reply(connection)?;
+    Ok((reply.major_version, reply.minor_version))
+}
+
+/// The changes of a selection reported by `SelectionNotify` events.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectionEvents {
+    /// A client took the selection.
+    pub set_owner: bool,
+    /// The window owning the selection was destroyed.
+    pub window_destroy: bool,
+    /// The client owning the selection closed its connection.
+    pub client_close: bool,
+}
+
+impl SelectionEvents {
+    /// All changes, which is what clipboard managers follow.
+    pub const ALL: SelectionEvents = SelectionEvents {
+        set_owner: true,
+        window_destroy: true,
+        client_close: true,
+    };
+
+    fn raw(self) -> u32 {
+        let mut mask = 0;
+        if self.set_owner {
+            mask |= XCB_XFIXES_SELECTION_EVENT_MASK_SET_SELECTION_OWNER;
+        }
+        if self.window_destroy {
+            mask |= XCB_XFIXES_SELECTION_EVENT_MASK_SELECTION_WINDOW_DESTROY;
+        }
+        if self.client_close {
+            mask |= XCB_XFIXES_SELECTION_EVENT_MASK_SELECTION_CLIENT_CLOSE;
+        }
+        mask
+    }
+}
+
+/// Selects the changes of `selection` reported to `window`, replacing those selected before.
+pub fn select_selection_input(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    selection: XCBAtom,
+    events: SelectionEvents,
+) -> XCBVoidCookie {
+    xcb_xfixes_select_selection_input(connection, window, selection, events.raw())
+}
+
+/// Selects whether `window` gets a `CursorNotify` event whenever the displayed cursor changes.
+pub fn select_cursor_input(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    enabled: bool,
+) -> XCBVoidCookie {
+    let mask = if enabled {
+        XCB_XFIXES_CURSOR_NOTIFY_MASK_DISPLAY_CURSOR
+    } else {
+        0
+    };
+    xcb_xfixes_select_cursor_input(connection, window, mask)
+}
+
+/// The image of the displayed cursor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CursorImage {
+    /// The position of the pointer on the screen.
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub xhot: u16,
+    pub yhot: u16,
+    /// Changes with the cursor, as reported by `CursorNotify` events.
+    pub serial: u32,
+    /// The pixels in rows, as ARGB with premultiplied alpha.
+    pub pixels: Vec<u32>,
+}
+
+pub fn get_cursor_image(connection: &XCBConnection) -> Result<CursorImage, XError> {
+    let reply = xcb_xfixes_get_cursor_image(connection).reply(connection)?;
+    Ok(CursorImage {
+        x: reply.x,
+        y: reply.y,
+        width: reply.width,
+        height: reply.height,
+        xhot: reply.xhot,
+        yhot: reply.yhot,
+        serial: reply.cursor_serial,
+        pixels: xcb_xfixes_get_cursor_image_cursor_image(&reply).to_vec(),
+    })
+}
+
+/// Hides the cursor while the pointer is within `window` or its children. The cursor is shown
+/// again by `show_cursor`, or when the client disconnects.
+pub fn hide_cursor(connection: &XCBConnection, window: XCBWindow) -> XCBVoidCookie {
+    xcb_xfixes_hide_cursor(connection, window)
+}
+
+pub fn show_cursor(connection: &XCBConnection, window: XCBWindow) -> XCBVoidCookie {
+    xcb_xfixes_show_cursor(connection, window)
+}
+
+/// One of the shapes of a window.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShapeKind {
+    /// The area the window covers, including its border.
+    Bounding,
+    /// The area the window draws into.
+    Clip,
+    /// The area the window receives input in.
+    Input,
+}
+
+impl ShapeKind {
+    fn raw(self) -> u8 {
+        match self {
+            ShapeKind::Bounding => XCB_SHAPE_SK_BOUNDING,
+            ShapeKind::Clip => XCB_SHAPE_SK_CLIP,
+            ShapeKind::Input => XCB_SHAPE_SK_INPUT,
+        }
+    }
+}
+
+/// A region of the server, which is destroyed when dropped.
+pub struct Region<'a> {
+    connection: &'a XCBConnection,
+    id: XCBXfixesRegion,
+}
+
+impl<'a> Region<'a> {
+    /// Creates the region covered by `rectangles`.
+    pub fn new(connection: &'a XCBConnection, rectangles: &[XCBRectangle]) -> Region<'a> {
+        let id = xcb_generate_id(connection);
+        xcb_xfixes_create_region(connection, id, rectangles);
+        Region { connection, id }
+    }
+
+    /// Creates the region of a shape of `window`, relative to its origin.
+    pub fn from_window(
+        connection: &'a XCBConnection,
+        window: XCBWindow,
+        kind: ShapeKind,
+    ) -> Region<'a> {
+        let id = xcb_generate_id(connection);
+        xcb_xfixes_create_region_from_window(connection, id, window, kind.raw());
+        Region { connection, id }
+    }
+
+    /// The id the server knows the region by.
+    pub fn id(&self) -> XCBXfixesRegion {
+        self.id
+    }
+
+    /// Returns the rectangles the region is made of.
+    pub fn rectangles(&self) -> Result<Vec<XCBRectangle>, XError> {
+        let reply = xcb_xfixes_fetch_region(self.connection, self.id).reply(self.connection)?;
+        Ok(xcb_xfixes_fetch_region_rectangles(&reply).to_vec())
+    }
+}
+
+impl<'a> Drop for Region<'a> {
+    fn drop(&mut self) {
+        xcb_xfixes_destroy_region(self.connection, self.id);
+    }
+}
+
+/// Sets a shape of `window` to `region` moved by `x` and `y`, or removes the shape with `None`.
+pub fn set_window_shape_region(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    kind: ShapeKind,
+    x: i16,
+    y: i16,
+    region: Option<&Region>,
+) -> XCBVoidCookie {
+    let region = region.map_or(XCB_XFIXES_REGION_NONE, Region::id);
+    xcb_xfixes_set_window_shape_region(connection, window, kind.raw(), x, y, region)
+}
@@ -0,0 +1,92 @@
+//! Helpers for the X Input extension version 2: selecting events per device and reading the
+//! fixed point coordinates and valuators of the events.
+//!
+//! The events themselves arrive as generic events and are decoded into `Event::Xinput`, e.g.
+//! `proto::xinput::Event::Motion` or `proto::xinput::Event::TouchBegin`.
+
+use proto::xinput::{cdef, XCBInputDeviceId, XCBInputEventMask, XCBInputFp1616, XCBInputFp3232};
+use proto::xproto::XCBWindow;
+use {XCBConnection, XCBVoidCookie};
+
+/// Converts a 16.16 fixed point number, e.g. the `event_x` of a device event, to a float.
+pub fn fp1616(value: XCBInputFp1616) -> f64 {
+    f64::from(value) / 65536.0
+}
+
+/// Converts a 32.32 fixed point number, e.g. an axis value of a device or raw event, to a float.
+pub fn fp3232(value: &XCBInputFp3232) -> f64 {
+    f64::from(value.integral) + f64::from(value.frac) / 4294967296.0
+}
+
+/// Pairs the axes set in the `valuator_mask` of an event with their values, which are listed in
+/// `axisvalues` in the order of the axes.
+///
+/// # Return value
+/// An iterator over the number of each axis and its value.
+pub fn valuators<'a>(
+    valuator_mask: &'a [u32],
+    axisvalues: &'a [XCBInputFp3232],
+) -> impl Iterator<Item = (u16, f64)> + 'a {
+    (0..valuator_mask.len() * 32)
+        .filter(move |&axis| valuator_mask[axis / 32] & (1 << (axis % 32)) != 0)
+        .zip(axisvalues)
+        .map(|(axis, value)| (axis as u16, fp3232(value)))
+}
+
+/// The events to select for each device, as passed to `xcb_input_xi_select_events`.
+///
+/// The events are given as the `XCB_INPUT_XI_EVENT_MASK_*` constants, the devices either as a
+/// device id or as `XCB_INPUT_DEVICE_ALL` or `XCB_INPUT_DEVICE_ALL_MASTER`.
+#[derive(Clone, Debug, Default)]
+pub struct EventMasks {
+    // Each mask is a word holding the device id and the length of the mask, followed by the
+    // words of the mask.
+    words: Vec<u32>,
+    count: u16,
+}
+
+impl EventMasks {
+    pub fn new() -> EventMasks {
+        EventMasks {
+            words: Vec::new(),
+            count: 0,
+        }
+    }
+
+    /// Selects the events in `mask` for `deviceid`.
+    ///
+    /// All event masks fit into a single word, so this covers all events of XI 2.3.
+    pub fn add(&mut self, deviceid: XCBInputDeviceId, mask: u32) -> &mut EventMasks {
+        self.add_words(deviceid, &[mask])
+    }
+
+    /// Selects the events whose bits are set in `mask`, which holds 32 events per word.
+    pub fn add_words(&mut self, deviceid: XCBInputDeviceId, mask: &[u32]) -> &mut EventMasks {
+        let mut header = [0; 4];
+        header[..2].copy_from_slice(&deviceid.to_ne_bytes());
+        header[2..].copy_from_slice(&(mask.len() as u16).to_ne_bytes());
+        self.words.push(u32::from_ne_bytes(header));
+        self.words.extend_from_slice(mask);
+        self.count += 1;
+        self
+    }
+}
+
+/// Selects the XI2 events `window` reports to this client.
+///
+/// Events selected for a device before are replaced, a mask without any bits set deselects all
+/// events of the device.
+pub fn xcb_input_xi_select_events(
+    connection: &XCBConnection,
+    window: XCBWindow,
+    masks: &EventMasks,
+) -> XCBVoidCookie {
+    unsafe {
+        cdef::xcb_input_xi_select_events(
+            connection.raw,
+            window,
+            masks.count,
+            masks.words.as_ptr() as *const XCBInputEventMask,
+        )
+    }
+}
@@ -0,0 +1,433 @@
+//! Translation of keycodes into keysyms and text with the keymap of the XKB extension, in pure
+//! Rust.
+//!
+//! `Keyboard` loads the key types and symbols of a keyboard with GetMap and follows the
+//! changes of its modifiers and group reported by StateNotify events. Enable the `xkbcommon`
+//! feature to use libxkbcommon-x11 instead, see `xkbcommon::Keyboard`.
+
+use std::error::Error;
+use std::mem;
+use std::ptr;
+use std::slice;
+
+use proto::keysym::keysym_to_char;
+use proto::xkb::constants::{
+    XCB_XKB_EVENT_TYPE_MAP_NOTIFY, XCB_XKB_EVENT_TYPE_NEW_KEYBOARD_NOTIFY,
+    XCB_XKB_EVENT_TYPE_STATE_NOTIFY, XCB_XKB_MAP_PART_KEY_SYMS, XCB_XKB_MAP_PART_KEY_TYPES,
+};
+use proto::xkb::{
+    self, cdef, xcb_xkb_get_map, xcb_xkb_get_state, xcb_xkb_use_extension, XCBXkbDeviceSpec,
+    XCBXkbGetMapReply,
+};
+use proto::xproto::XCBKeycode;
+use {Event, XCBConnection};
+
+pub use keysyms::NO_SYMBOL;
+
+/// How a key type maps modifiers to shift levels.
+struct KeyType {
+    mods_mask: u8,
+    /// The modifiers, masked with `mods_mask`, that select each level other than the first.
+    levels: Vec<(u8, u8)>,
+}
+
+/// The symbols of a key, `width` per group.
+struct KeySyms {
+    kt_index: [u8; 4],
+    group_info: u8,
+    width: u8,
+    syms: Vec<u32>,
+}
+
+/// Reads the native endian values of a reply.
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, count: usize) -> Option<&'a [u8]> {
+        if self.data.len() < count {
+            return None;
+        }
+        let (bytes, rest) = self.data.split_at(count);
+        self.data = rest;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.bytes(1).map(|bytes| bytes[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.bytes(2)
+            .map(|bytes| u16::from_ne_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.bytes(4)
+            .map(|bytes| u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+/// The key types and symbols of a keyboard.
+pub struct Keymap {
+    types: Vec<KeyType>,
+    first_key: XCBKeycode,
+    keys: Vec<KeySyms>,
+}
+
+impl Keymap {
+    /// Reads the keymap from the reply to a GetMap request for all key types and key symbols,
+    /// and nothing else.
+    ///
+    /// # Return value
+    /// The keymap, or `None` if the reply does not hold the key types and symbols.
+    pub fn from_reply(reply: &XCBXkbGetMapReply) -> Option<Keymap> {
+        let parts = XCB_XKB_MAP_PART_KEY_TYPES | XCB_XKB_MAP_PART_KEY_SYMS;
+        if reply.present != parts {
+            return None;
+        }
+        let size = 32 + reply.length as usize * 4;
+        let data = unsafe { slice::from_raw_parts(reply as *const _ as *const u8, size) };
+        let mut reader = Reader {
+            data: &data[mem::size_of::<XCBXkbGetMapReply>()..],
+        };
+
+        let mut types = Vec::with_capacity(reply.nTypes as usize);
+        for _ in 0..reply.nTypes {
+            let mods_mask = reader.u8()?;
+            reader.bytes(3)?;
+            let _num_levels = reader.u8()?;
+            let n_map_entries = reader.u8()?;
+            let has_preserve = reader.u8()? != 0;
+            reader.bytes(1)?;
+
+            let mut levels = Vec::with_capacity(n_map_entries as usize);
+            for _ in 0..n_map_entries {
+                let active = reader.u8()? != 0;
+                let mods = reader.u8()?;
+                let level = reader.u8()?;
+                reader.bytes(5)?;
+                if active {
+                    levels.push((mods, level));
+                }
+            }
+            if has_preserve {
+                reader.bytes(4 * n_map_entries as usize)?;
+            }
+            types.push(KeyType { mods_mask, levels });
+        }
+
+        let mut keys = Vec::with_capacity(reply.nKeySyms as usize);
+        for _ in 0..reply.nKeySyms {
+            let kt = reader.bytes(4)?;
+            let kt_index = [kt[0], kt[1], kt[2], kt[3]];
+            let group_info = reader.u8()?;
+            let width = reader.u8()?;
+            let n_syms = reader.u16()?;
+            let mut syms = Vec::with_capacity(n_syms as usize);
+            for _ in 0..n_syms {
+                syms.push(reader.u32()?);
+            }
+            keys.push(KeySyms {
+                kt_index,
+                group_info,
+                width,
+                syms,
+            });
+        }
+
+        Some(Keymap {
+            types,
+            first_key: reply.firstKeySym,
+            keys,
+        })
+    }
+
+    /// Returns the keysym of a key when the modifiers `mods` are active and `group` is the
+    /// effective group, or `NO_SYMBOL`.
+    pub fn keysym(&self, keycode: XCBKeycode, mods: u8, group: u8) -> u32 {
+        let key = match keycode
+            .checked_sub(self.first_key)
+            .and_then(|index| self.keys.get(index as usize))
+        {
+            Some(key) => key,
+            None => return NO_SYMBOL,
+        };
+
+        let num_groups = key.group_info & 0x0f;
+        if num_groups == 0 {
+            return NO_SYMBOL;
+        }
+        let group = if group < num_groups {
+            group
+        } else {
+            // Groups the key has no symbols for are wrapped, clamped or redirected.
+            match key.group_info & 0xc0 {
+                0x40 => num_groups - 1,
+                0x80 => {
+                    let redirect = (key.group_info >> 4) & 0x03;
+                    if redirect < num_groups {
+                        redirect
+                    } else {
+                        0
+                    }
+                }
+                _ => group % num_groups,
+            }
+        };
+
+        let level = match self.types.get(key.kt_index[group as usize & 0x03] as usize) {
+            Some(key_type) => key_type
+                .levels
+                .iter()
+                .find(|&&(entry_mods, _)| entry_mods == mods & key_type.mods_mask)
+                .map_or(0, |&(_, level)| level),
+            None => 0,
+        };
+        if level >= key.width {
+            return NO_SYMBOL;
+        }
+        let index = group as usize * key.width as usize + level as usize;
+        key.syms.get(index).cloned().unwrap_or(NO_SYMBOL)
+    }
+}
+
+/// A keyboard whose keycodes are translated with its XKB keymap and state.
+pub struct Keyboard<'a> {
+    connection: &'a XCBConnection,
+    device: XCBXkbDeviceSpec,
+    /// The id `device` resolves to, which the events of the keyboard carry.
+    device_id: u8,
+    keymap: Keymap,
+    mods: u8,
+    group: u8,
+}
+
+impl<'a> Keyboard<'a> {
+    /// Enables XKB, selects the events telling about changes of the keyboard `device`, e.g.
+    /// `XCB_XKB_ID_USE_CORE_KBD`, and loads its keymap and state.
+    pub fn new(
+        connection: &'a XCBConnection,
+        device: XCBXkbDeviceSpec,
+    ) -> Result<Keyboard<'a>, Box<dyn Error>> {
+        let version = xcb_xkb_use_extension(connection, 1, 0).reply(connection)?;
+        if version.supported == 0 {
+            return Err(From::from("the X server does not support XKB 1.0"));
+        }
+
+        let events = XCB_XKB_EVENT_TYPE_NEW_KEYBOARD_NOTIFY
+            | XCB_XKB_EVENT_TYPE_MAP_NOTIFY
+            | XCB_XKB_EVENT_TYPE_STATE_NOTIFY;
+        // Selecting all details of the events leaves nothing to pass in the details.
+        unsafe {
+            cdef::xcb_xkb_select_events(
+                connection.raw,
+                device,
+                events,
+                0,
+                events,
+                0,
+                0,
+                ptr::null(),
+            );
+        }
+
+        let state = xcb_xkb_get_state(connection, device).reply(connection)?;
+        Ok(Keyboard {
+            connection,
+            device,
+            device_id: state.deviceID,
+            keymap: Keyboard::load_keymap(connection, device)?,
+            mods: state.mods,
+            group: state.group,
+        })
+    }
+
+    fn load_keymap(
+        connection: &XCBConnection,
+        device: XCBXkbDeviceSpec,
+    ) -> Result<Keymap, Box<dyn Error>> {
+        let parts = XCB_XKB_MAP_PART_KEY_TYPES | XCB_XKB_MAP_PART_KEY_SYMS;
+        let reply = xcb_xkb_get_map(
+            connection, device, parts, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        )
+        .reply(connection)?;
+        Keymap::from_reply(&reply).ok_or_else(|| From::from("malformed XKB keymap"))
+    }
+
+    /// The keymap of the keyboard.
+    pub fn keymap(&self) -> &Keymap {
+        &self.keymap
+    }
+
+    /// Follows the changes of the keymap and state of the keyboard.
+    ///
+    /// # Return value
+    /// Whether the event was an XKB event of the keyboard, the events of other keyboards are
+    /// left alone.
+    pub fn handle(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::Xkb(xkb::Event::StateNotify(ref notify))
+                if notify.deviceID == self.device_id =>
+            {
+                self.mods = notify.mods;
+                self.group = notify.group;
+                true
+            }
+            Event::Xkb(xkb::Event::MapNotify(ref notify)) if notify.deviceID == self.device_id => {
+                self.reload();
+                true
+            }
+            // The core keyboard moves to another device when a different one is used.
+            Event::Xkb(xkb::Event::NewKeyboardNotify(ref notify))
+                if notify.deviceID == self.device_id || notify.oldDeviceID == self.device_id =>
+            {
+                self.device_id = notify.deviceID;
+                self.reload();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn reload(&mut self) {
+        if let Ok(keymap) = Keyboard::load_keymap(self.connection, self.device) {
+            self.keymap = keymap;
+        }
+    }
+
+    /// Returns the keysym of a key in the current state of the keyboard.
+    pub fn keysym(&self, keycode: XCBKeycode) -> u32 {
+        self.keymap.keysym(keycode, self.mods, self.group)
+    }
+
+    /// Returns the keysym of a key in the state reported by a key event, which tells the
+    /// modifiers in its lower and the group in bits 13 and 14.
+    pub fn keysym_for_state(&self, keycode: XCBKeycode, state: u16) -> u32 {
+        self.keymap
+            .keysym(keycode, state as u8, ((state >> 13) & 0x03) as u8)
+    }
+
+    /// Returns the text a key types in the current state of the keyboard.
+    pub fn utf8(&self, keycode: XCBKeycode) -> String {
+        keysym_to_char(self.keysym(keycode))
+            .map(String::from)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a GetMap reply holding `types` and the key symbols `keys` of the keys starting at
+    /// `first_key`, both in wire format.
+    fn reply(
+        n_types: u8,
+        types: &[u8],
+        first_key: XCBKeycode,
+        n_keys: u8,
+        keys: &[u8],
+    ) -> Vec<u32> {
+        let header = mem::size_of::<XCBXkbGetMapReply>();
+        let size = header + types.len() + keys.len();
+        let mut words = vec![0u32; size.div_ceil(4)];
+        unsafe {
+            let bytes = words.as_mut_ptr() as *mut u8;
+            ptr::copy_nonoverlapping(types.as_ptr(), bytes.add(header), types.len());
+            ptr::copy_nonoverlapping(keys.as_ptr(), bytes.add(header + types.len()), keys.len());
+            let reply = &mut *(words.as_mut_ptr() as *mut XCBXkbGetMapReply);
+            reply.length = (words.len() as u32 * 4 - 32) / 4;
+            reply.present = XCB_XKB_MAP_PART_KEY_TYPES | XCB_XKB_MAP_PART_KEY_SYMS;
+            reply.nTypes = n_types;
+            reply.firstKeySym = first_key;
+            reply.nKeySyms = n_keys;
+        }
+        words
+    }
+
+    fn key(kt_index: [u8; 4], group_info: u8, width: u8, syms: &[u32]) -> Vec<u8> {
+        let mut bytes = kt_index.to_vec();
+        bytes.push(group_info);
+        bytes.push(width);
+        bytes.extend_from_slice(&(syms.len() as u16).to_ne_bytes());
+        for sym in syms {
+            bytes.extend_from_slice(&sym.to_ne_bytes());
+        }
+        bytes
+    }
+
+    fn keymap() -> Keymap {
+        // Each type is followed by its map entries and preserve entries.
+        #[rustfmt::skip]
+        let types = [
+            // ONE_LEVEL.
+            0, 0, 0, 0, 1, 0, 0, 0,
+            // TWO_LEVEL, Shift selects the second level and is preserved.
+            1, 1, 0, 0, 2, 1, 1, 0,
+            1, 1, 1, 1, 0, 0, 0, 0,
+            1, 1, 0, 0,
+            // Lock would select the second level, but the entry is inactive.
+            2, 2, 0, 0, 2, 1, 0, 0,
+            0, 2, 1, 2, 0, 0, 0, 0,
+        ];
+        let mut keys = Vec::new();
+        // Two groups, wrapped.
+        keys.extend(key([1, 1, 0, 0], 0x02, 2, &[0x61, 0x41, 0x62, 0x42]));
+        // Two groups, clamped.
+        keys.extend(key([0, 0, 0, 0], 0x42, 1, &[0x63, 0x64]));
+        // Three groups, redirected to the second.
+        keys.extend(key([0, 0, 0, 0], 0x93, 1, &[0x65, 0x66, 0x67]));
+        // Two groups, redirected to a group the key lacks.
+        keys.extend(key([0, 0, 0, 0], 0xb2, 1, &[0x68, 0x69]));
+        keys.extend(key([2, 0, 0, 0], 0x01, 2, &[0x78, 0x58]));
+        // No symbols at all.
+        keys.extend(key([0, 0, 0, 0], 0x00, 0, &[]));
+
+        let words = reply(3, &types, 10, 6, &keys);
+        Keymap::from_reply(unsafe { &*(words.as_ptr() as *const XCBXkbGetMapReply) }).unwrap()
+    }
+
+    #[test]
+    fn levels() {
+        let keymap = keymap();
+        assert_eq!(keymap.keysym(10, 0, 0), 0x61);
+        assert_eq!(keymap.keysym(10, 1, 0), 0x41);
+        // Modifiers outside the mask of the type are ignored.
+        assert_eq!(keymap.keysym(10, 1 | 4, 0), 0x41);
+        assert_eq!(keymap.keysym(10, 1, 1), 0x42);
+        assert_eq!(keymap.keysym(14, 2, 0), 0x78);
+    }
+
+    #[test]
+    fn groups() {
+        let keymap = keymap();
+        assert_eq!(keymap.keysym(10, 0, 2), 0x61);
+        assert_eq!(keymap.keysym(10, 0, 3), 0x62);
+        assert_eq!(keymap.keysym(11, 0, 3), 0x64);
+        assert_eq!(keymap.keysym(12, 0, 2), 0x67);
+        assert_eq!(keymap.keysym(12, 0, 3), 0x66);
+        assert_eq!(keymap.keysym(13, 0, 2), 0x68);
+    }
+
+    #[test]
+    fn missing_keys() {
+        let keymap = keymap();
+        assert_eq!(keymap.keysym(9, 0, 0), NO_SYMBOL);
+        assert_eq!(keymap.keysym(15, 0, 0), NO_SYMBOL);
+        assert_eq!(keymap.keysym(16, 0, 0), NO_SYMBOL);
+    }
+
+    #[test]
+    fn truncated() {
+        let keys = key([0, 0, 0, 0], 0x01, 1, &[0x61, 0x62]);
+        let mut words = reply(0, &[], 8, 1, &keys);
+        unsafe {
+            (*(words.as_mut_ptr() as *mut XCBXkbGetMapReply)).length -= 1;
+        }
+        assert!(
+            Keymap::from_reply(unsafe { &*(words.as_ptr() as *const XCBXkbGetMapReply) }).is_none()
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! Translation of keycodes into keysyms and text with libxkbcommon-x11.
+//!
+//! `Keyboard` offers the same methods as `xkb::Keyboard`, but leaves the keymap to
+//! libxkbcommon, which implements all of XKB's key types, actions and consumed modifiers.
+
+use std::error::Error;
+use std::os::raw::{c_char, c_int};
+use std::ptr::{null, null_mut};
+
+use proto::xkb::constants::{
+    XCB_XKB_EVENT_TYPE_MAP_NOTIFY, XCB_XKB_EVENT_TYPE_NEW_KEYBOARD_NOTIFY,
+    XCB_XKB_EVENT_TYPE_STATE_NOTIFY,
+};
+use proto::xkb::{self, cdef as xkb_cdef};
+use proto::xproto::XCBKeycode;
+use {cdef, Event, XCBConnection};
+
+#[repr(C)]
+struct XkbContext {
+    _opaque: [u8; 0],
+}
+
+#[repr(C)]
+struct XkbKeymap {
+    _opaque: [u8; 0],
+}
+
+#[repr(C)]
+struct XkbState {
+    _opaque: [u8; 0],
+}
+
+#[link(name = "xkbcommon")]
+extern "C" {
+    fn xkb_context_new(flags: c_int) -> *mut XkbContext;
+    fn xkb_context_unref(context: *mut XkbContext);
+    fn xkb_keymap_unref(keymap: *mut XkbKeymap);
+    fn xkb_state_unref(state: *mut XkbState);
+    fn xkb_state_key_get_one_sym(state: *mut XkbState, key: u32) -> u32;
+    fn xkb_state_key_get_utf8(
+        state: *mut XkbState,
+        key: u32,
+        buffer: *mut c_char,
+        size: usize,
+    ) -> c_int;
+    fn xkb_state_update_mask(
+        state: *mut XkbState,
+        depressed_mods: u32,
+        latched_mods: u32,
+        locked_mods: u32,
+        depressed_layout: u32,
+        latched_layout: u32,
+        locked_layout: u32,
+    ) -> c_int;
+}
+
+#[link(name = "xkbcommon-x11")]
+extern "C" {
+    fn xkb_x11_setup_xkb_extension(
+        connection: *mut cdef::XCBConnection,
+        major_xkb_version: u16,
+        minor_xkb_version: u16,
+        flags: c_int,
+        major_xkb_version_out: *mut u16,
+        minor_xkb_version_out: *mut u16,
+        base_event_out: *mut u8,
+        base_error_out: *mut u8,
+    ) -> c_int;
+    fn xkb_x11_get_core_keyboard_device_id(connection: *mut cdef::XCBConnection) -> i32;
+    fn xkb_x11_keymap_new_from_device(
+        context: *mut XkbContext,
+        connection: *mut cdef::XCBConnection,
+        device_id: i32,
+        flags: c_int,
+    ) -> *mut XkbKeymap;
+    fn xkb_x11_state_new_from_device(
+        keymap: *mut XkbKeymap,
+        connection: *mut cdef::XCBConnection,
+        device_id: i32,
+    ) -> *mut XkbState;
+}
+
+/// A keyboard whose keycodes are translated by libxkbcommon.
+pub struct Keyboard<'a> {
+    connection: &'a XCBConnection,
+    device: i32,
+    context: *mut XkbContext,
+    keymap: *mut XkbKeymap,
+    state: *mut XkbState,
+}
+
+impl<'a> Keyboard<'a> {
+    /// Enables XKB, selects the events telling about changes of the core keyboard, and loads
+    /// its keymap and state.
+    pub fn new(connection: &'a XCBConnection) -> Result<Keyboard<'a>, Box<dyn Error>> {
+        unsafe {
+            let supported = xkb_x11_setup_xkb_extension(
+                connection.raw,
+                1,
+                0,
+                0,
+                null_mut(),
+                null_mut(),
+                null_mut(),
+                null_mut(),
+            );
+            if supported == 0 {
+                return Err(From::from("the X server does not support XKB 1.0"));
+            }
+            let device = xkb_x11_get_core_keyboard_device_id(connection.raw);
+            if device == -1 {
+                return Err(From::from("the X server has no core keyboard"));
+            }
+
+            let events = XCB_XKB_EVENT_TYPE_NEW_KEYBOARD_NOTIFY
+                | XCB_XKB_EVENT_TYPE_MAP_NOTIFY
+                | XCB_XKB_EVENT_TYPE_STATE_NOTIFY;
+            xkb_cdef::xcb_xkb_select_events(
+                connection.raw,
+                device as u16,
+                events,
+                0,
+                events,
+                0,
+                0,
+                null(),
+            );
+
+            let context = xkb_context_new(0);
+            if context.is_null() {
+                return Err(From::from("creating the xkbcommon context failed"));
+            }
+            let mut keyboard = Keyboard {
+                connection,
+                device,
+                context,
+                keymap: null_mut(),
+                state: null_mut(),
+            };
+            if !keyboard.load() {
+                return Err(From::from("loading the XKB keymap failed"));
+            }
+            Ok(keyboard)
+        }
+    }
+
+    /// Replaces the keymap and state with the current ones of the server.
+    fn load(&mut self) -> bool {
+        unsafe {
+            let keymap =
+                xkb_x11_keymap_new_from_device(self.context, self.connection.raw, self.device, 0);
+            if keymap.is_null() {
+                return false;
+            }
+            let state = xkb_x11_state_new_from_device(keymap, self.connection.raw, self.device);
+            if state.is_null() {
+                xkb_keymap_unref(keymap);
+                return false;
+            }
+            self.release();
+            self.keymap = keymap;
+            self.state = state;
+            true
+        }
+    }
+
+    fn release(&mut self) {
+        unsafe {
+            if !self.state.is_null() {
+                xkb_state_unref(self.state);
+            }
+            if !self.keymap.is_null() {
+                xkb_keymap_unref(self.keymap);
+            }
+        }
+    }
+
+    /// Follows the changes of the keymap and state of the keyboard.
+    ///
+    /// # Return value
+    /// Whether the event was an XKB event of the keyboard, the events of other keyboards are
+    /// left alone.
+    pub fn handle(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::Xkb(xkb::Event::StateNotify(ref notify))
+                if i32::from(notify.deviceID) == self.device =>
+            {
+                unsafe {
+                    xkb_state_update_mask(
+                        self.state,
+                        u32::from(notify.baseMods),
+                        u32::from(notify.latchedMods),
+                        u32::from(notify.lockedMods),
+                        notify.baseGroup as u32,
+                        notify.latchedGroup as u32,
+                        u32::from(notify.lockedGroup),
+                    );
+                }
+                true
+            }
+            Event::Xkb(xkb::Event::MapNotify(ref notify))
+                if i32::from(notify.deviceID) == self.device =>
+            {
+                self.load();
+                true
+            }
+            // The core keyboard moves to another device when a different one is used.
+            Event::Xkb(xkb::Event::NewKeyboardNotify(ref notify))
+                if i32::from(notify.deviceID) == self.device
+                    || i32::from(notify.oldDeviceID) == self.device =>
+            {
+                self.device = i32::from(notify.deviceID);
+                self.load();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the keysym of a key in the current state of the keyboard.
+    pub fn keysym(&self, keycode: XCBKeycode) -> u32 {
+        unsafe { xkb_state_key_get_one_sym(self.state, u32::from(keycode)) }
+    }
+
+    /// Returns the text a key types in the current state of the keyboard.
+    pub fn utf8(&self, keycode: XCBKeycode) -> String {
+        let mut buffer = [0u8; 64];
+        let length = unsafe {
+            xkb_state_key_get_utf8(
+                self.state,
+                u32::from(keycode),
+                buffer.as_mut_ptr() as *mut c_char,
+                buffer.len(),
+            )
+        };
+        if length <= 0 {
+            return String::new();
+        }
+        let length = (length as usize).min(buffer.len() - 1);
+        String::from_utf8_lossy(&buffer[..length]).into_owned()
+    }
+}
+
+impl<'a> Drop for Keyboard<'a> {
+    fn drop(&mut self) {
+        self.release();
+        unsafe { xkb_context_unref(self.context) }
+    }
+}
@@ -0,0 +1,540 @@
+//! The XSETTINGS protocol, through which a settings manager shares settings such as the theme,
+//! the resolution and the fonts with the clients of a screen.
+//!
+//! The manager owns the `_XSETTINGS_Sn` selection of the screen and stores the settings in the
+//! `_XSETTINGS_SETTINGS` property of its window, which the clients read and watch for changes.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use property;
+use proto::xproto::constants::{
+    XCB_CW_EVENT_MASK, XCB_EVENT_MASK_PROPERTY_CHANGE, XCB_EVENT_MASK_STRUCTURE_NOTIFY,
+};
+use proto::xproto::{self, xcb_change_window_attributes, xcb_get_property_value};
+use selection::{claim_manager, get_selection_owner};
+use send::{ClientMessage, ClientMessageData};
+use {intern_atoms, Event, XCBAtom, XCBConnection, XCBTimestamp, XCBVoidCookie, XCBWindow, XError};
+
+/// The atoms of the XSETTINGS protocol for one screen.
+#[derive(Copy, Clone, Debug)]
+pub struct Atoms {
+    /// The selection of the manager of the screen, `_XSETTINGS_Sn`.
+    pub xsettings_s: XCBAtom,
+    pub xsettings_settings: XCBAtom,
+    pub manager: XCBAtom,
+}
+
+impl Atoms {
+    /// Interns the atoms for the screen with the index `screen`.
+    pub fn intern(connection: &XCBConnection, screen: usize) -> Result<Atoms, XError> {
+        let selection = format!("_XSETTINGS_S{}", screen);
+        let atoms = intern_atoms(connection, &[&selection, "_XSETTINGS_SETTINGS", "MANAGER"])?;
+        Ok(Atoms {
+            xsettings_s: atoms[0],
+            xsettings_settings: atoms[1],
+            manager: atoms[2],
+        })
+    }
+}
+
+/// The value of a setting.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Integer(i32),
+    String(String),
+    Color {
+        red: u16,
+        green: u16,
+        blue: u16,
+        alpha: u16,
+    },
+}
+
+impl Value {
+    fn raw_type(&self) -> u8 {
+        match *self {
+            Value::Integer(_) => 0,
+            Value::String(_) => 1,
+            Value::Color { .. } => 2,
+        }
+    }
+}
+
+/// A setting and the serial of the settings it last changed in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Setting {
+    pub value: Value,
+    pub serial: u32,
+}
+
+/// The settings of a manager, `_XSETTINGS_SETTINGS`, by name, e.g. `Net/ThemeName` or
+/// `Xft/DPI`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Settings {
+    /// Increased by the manager every time it changes the settings.
+    pub serial: u32,
+    pub settings: BTreeMap<String, Setting>,
+}
+
+/// Reads the values of the property, which are in the byte order of the manager.
+struct Reader<'b> {
+    bytes: &'b [u8],
+    big_endian: bool,
+}
+
+impl<'b> Reader<'b> {
+    fn take(&mut self, length: usize) -> Option<&'b [u8]> {
+        if self.bytes.len() < length {
+            return None;
+        }
+        let (taken, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+        Some(taken)
+    }
+
+    /// Takes `length` bytes and the padding up to the next multiple of 4.
+    fn take_padded(&mut self, length: usize) -> Option<&'b [u8]> {
+        let taken = self.take(length)?;
+        self.take(pad(length))?;
+        Some(taken)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        let bytes = self.take(2)?;
+        let bytes = [bytes[0], bytes[1]];
+        Some(if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let bytes = self.take(4)?;
+        let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        Some(if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+}
+
+/// The padding after `length` bytes up to the next multiple of 4.
+fn pad(length: usize) -> usize {
+    (4 - length % 4) % 4
+}
+
+impl Settings {
+    /// Parses the value of a `_XSETTINGS_SETTINGS` property.
+    ///
+    /// # Return value
+    /// The settings, or `None` if the value is malformed.
+    pub fn parse(bytes: &[u8]) -> Option<Settings> {
+        let mut reader = Reader {
+            bytes,
+            big_endian: bytes.first() == Some(&1),
+        };
+        reader.take(4)?;
+        let serial = reader.u32()?;
+        let count = reader.u32()?;
+        let mut settings = BTreeMap::new();
+        for _ in 0..count {
+            let type_ = reader.u8()?;
+            reader.take(1)?;
+            let name_length = reader.u16()? as usize;
+            let name = String::from_utf8_lossy(reader.take_padded(name_length)?).into_owned();
+            let last_change = reader.u32()?;
+            let value = match type_ {
+                0 => Value::Integer(reader.u32()? as i32),
+                1 => {
+                    let length = reader.u32()? as usize;
+                    Value::String(String::from_utf8_lossy(reader.take_padded(length)?).into_owned())
+                }
+                2 => {
+                    let red = reader.u16()?;
+                    let green = reader.u16()?;
+                    let blue = reader.u16()?;
+                    let alpha = reader.u16()?;
+                    Value::Color {
+                        red,
+                        green,
+                        blue,
+                        alpha,
+                    }
+                }
+                _ => return None,
+            };
+            settings.insert(
+                name,
+                Setting {
+                    value,
+                    serial: last_change,
+                },
+            );
+        }
+        Some(Settings { serial, settings })
+    }
+
+    /// Returns the settings as the value of a `_XSETTINGS_SETTINGS` property, in the native
+    /// byte order.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![cfg!(target_endian = "big") as u8, 0, 0, 0];
+        bytes.extend_from_slice(&self.serial.to_ne_bytes());
+        bytes.extend_from_slice(&(self.settings.len() as u32).to_ne_bytes());
+        for (name, setting) in &self.settings {
+            bytes.push(setting.value.raw_type());
+            bytes.push(0);
+            bytes.extend_from_slice(&(name.len() as u16).to_ne_bytes());
+            bytes.extend_from_slice(name.as_bytes());
+            bytes.resize(bytes.len() + pad(name.len()), 0);
+            bytes.extend_from_slice(&setting.serial.to_ne_bytes());
+            match setting.value {
+                Value::Integer(value) => bytes.extend_from_slice(&value.to_ne_bytes()),
+                Value::String(ref value) => {
+                    bytes.extend_from_slice(&(value.len() as u32).to_ne_bytes());
+                    bytes.extend_from_slice(value.as_bytes());
+                    bytes.resize(bytes.len() + pad(value.len()), 0);
+                }
+                Value::Color {
+                    red,
+                    green,
+                    blue,
+                    alpha,
+                } => {
+                    for component in &[red, green, blue, alpha] {
+                        bytes.extend_from_slice(&component.to_ne_bytes());
+                    }
+                }
+            }
+        }
+        bytes
+    }
+
+    /// Returns a setting.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.settings.get(name).map(|setting| &setting.value)
+    }
+}
+
+/// Reads the settings of the manager of a screen and follows their changes.
+///
+/// The client has to select `XCB_EVENT_MASK_STRUCTURE_NOTIFY` on the root window to receive
+/// the `MANAGER` messages of new managers.
+pub struct Client<'a> {
+    connection: &'a XCBConnection,
+    atoms: Atoms,
+    root: XCBWindow,
+    manager: Option<XCBWindow>,
+    settings: Settings,
+    changes: VecDeque<String>,
+}
+
+impl<'a> Client<'a> {
+    /// Reads the settings of the screen with the index `screen` and the root window `root`.
+    pub fn new(
+        connection: &'a XCBConnection,
+        screen: usize,
+        root: XCBWindow,
+    ) -> Result<Client<'a>, XError> {
+        let mut client = Client {
+            connection,
+            atoms: Atoms::intern(connection, screen)?,
+            root,
+            manager: None,
+            settings: Settings::default(),
+            changes: VecDeque::new(),
+        };
+        client.find_manager()?;
+        // The settings read first are not changes.
+        client.changes.clear();
+        Ok(client)
+    }
+
+    pub fn atoms(&self) -> &Atoms {
+        &self.atoms
+    }
+
+    /// The window of the manager, or `None` if the screen has none.
+    pub fn manager(&self) -> Option<XCBWindow> {
+        self.manager
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Returns the name of the next setting that changed or was removed.
+    pub fn next_change(&mut self) -> Option<String> {
+        self.changes.pop_front()
+    }
+
+    /// Rereads the settings once the manager changes them, and follows the manager when it
+    /// goes away or is replaced.
+    ///
+    /// # Return value
+    /// Whether the event was meant for the client.
+    pub fn handle(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::Core(xproto::Event::PropertyNotify(ref notify))
+                if Some(notify.window) == self.manager
+                    && notify.atom == self.atoms.xsettings_settings =>
+            {
+                let _ = self.read();
+                true
+            }
+            Event::Core(xproto::Event::DestroyNotify(ref notify))
+                if Some(notify.window) == self.manager =>
+            {
+                let _ = self.find_manager();
+                true
+            }
+            Event::Core(xproto::Event::ClientMessage(ref message))
+                if message.window == self.root && message.type_ == self.atoms.manager =>
+            {
+                match ClientMessage::from_event(message) {
+                    Some(ClientMessage {
+                        data: ClientMessageData::U32(data),
+                        ..
+                    }) if data[1] == self.atoms.xsettings_s => {
+                        let _ = self.find_manager();
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Looks up the owner of the selection, selects the changes of its properties and its
+    /// destruction, and reads its settings.
+    fn find_manager(&mut self) -> Result<(), XError> {
+        self.manager = get_selection_owner(self.connection, self.atoms.xsettings_s)?;
+        if let Some(manager) = self.manager {
+            xcb_change_window_attributes(
+                self.connection,
+                manager,
+                XCB_CW_EVENT_MASK,
+                &[XCB_EVENT_MASK_PROPERTY_CHANGE | XCB_EVENT_MASK_STRUCTURE_NOTIFY],
+            );
+        }
+        self.read()
+    }
+
+    /// Reads the settings of the manager and queues the names of those that changed.
+    fn read(&mut self) -> Result<(), XError> {
+        let settings = match self.manager {
+            Some(manager) => property::get(
+                self.connection,
+                manager,
+                self.atoms.xsettings_settings,
+                self.atoms.xsettings_settings,
+            )?
+            .and_then(|reply| Settings::parse(xcb_get_property_value(&reply)))
+            .unwrap_or_default(),
+            None => Settings::default(),
+        };
+        for (name, setting) in &settings.settings {
+            if self.settings.get(name) != Some(&setting.value) {
+                self.changes.push_back(name.clone());
+            }
+        }
+        for name in self.settings.settings.keys() {
+            if !settings.settings.contains_key(name) {
+                self.changes.push_back(name.clone());
+            }
+        }
+        self.settings = settings;
+        Ok(())
+    }
+}
+
+/// The settings manager of a screen, which publishes the settings in the property of its
+/// window.
+pub struct Manager<'a> {
+    connection: &'a XCBConnection,
+    atoms: Atoms,
+    root: XCBWindow,
+    window: XCBWindow,
+    settings: Settings,
+    owner: bool,
+}
+
+impl<'a> Manager<'a> {
+    /// Creates the manager of the screen with the index `screen` and the root window `root`,
+    /// which publishes the settings on `window`.
+    pub fn new(
+        connection: &'a XCBConnection,
+        screen: usize,
+        root: XCBWindow,
+        window: XCBWindow,
+    ) -> Result<Manager<'a>, XError> {
+        Ok(Manager {
+            connection,
+            atoms: Atoms::intern(connection, screen)?,
+            root,
+            window,
+            settings: Settings::default(),
+            owner: false,
+        })
+    }
+
+    pub fn atoms(&self) -> &Atoms {
+        &self.atoms
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Whether the manager owns the selection, which it loses when another manager takes it.
+    pub fn is_owner(&self) -> bool {
+        self.owner
+    }
+
+    /// Publishes the settings and takes the selection of the manager.
+    ///
+    /// # Return value
+    /// Whether the manager became the owner of the selection.
+    pub fn claim(&mut self, time: XCBTimestamp) -> Result<bool, XError> {
+        self.publish();
+        self.owner = claim_manager(
+            self.connection,
+            self.root,
+            self.window,
+            self.atoms.xsettings_s,
+            self.atoms.manager,
+            time,
+        )?;
+        Ok(self.owner)
+    }
+
+    /// Changes a setting, which takes effect with the next `publish`.
+    pub fn set(&mut self, name: &str, value: Value) {
+        if self.settings.get(name) == Some(&value) {
+            return;
+        }
+        let setting = Setting {
+            value,
+            serial: self.settings.serial.wrapping_add(1),
+        };
+        self.settings.settings.insert(name.to_owned(), setting);
+    }
+
+    /// Removes a setting, which takes effect with the next `publish`.
+    pub fn remove(&mut self, name: &str) {
+        self.settings.settings.remove(name);
+    }
+
+    /// Increases the serial and writes the settings to the property.
+    pub fn publish(&mut self) -> XCBVoidCookie {
+        self.settings.serial = self.settings.serial.wrapping_add(1);
+        property::set(
+            self.connection,
+            self.window,
+            self.atoms.xsettings_settings,
+            self.atoms.xsettings_settings,
+            8,
+            &self.settings.to_bytes(),
+        )
+    }
+
+    /// Notices when another manager takes the selection.
+    ///
+    /// # Return value
+    /// Whether the event was meant for the manager.
+    pub fn handle(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::Core(xproto::Event::SelectionClear(ref clear))
+                if clear.owner == self.window && clear.selection == self.atoms.xsettings_s =>
+            {
+                self.owner = false;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> Settings {
+        let mut settings = Settings {
+            serial: 7,
+            settings: BTreeMap::new(),
+        };
+        settings.settings.insert(
+            "Xft/DPI".to_string(),
+            Setting {
+                value: Value::Integer(98304),
+                serial: 3,
+            },
+        );
+        settings.settings.insert(
+            "Net/ThemeName".to_string(),
+            Setting {
+                value: Value::String("Adwaita".to_string()),
+                serial: 5,
+            },
+        );
+        settings.settings.insert(
+            "Gtk/Color".to_string(),
+            Setting {
+                value: Value::Color {
+                    red: 1,
+                    green: 2,
+                    blue: 3,
+                    alpha: 4,
+                },
+                serial: 6,
+            },
+        );
+        settings
+    }
+
+    #[test]
+    fn round_trip() {
+        let settings = settings();
+        assert_eq!(Settings::parse(&settings.to_bytes()), Some(settings));
+    }
+
+    #[test]
+    fn big_endian() {
+        let mut bytes = vec![1, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 2];
+        bytes.extend_from_slice(&[0, 0, 0, 7]);
+        bytes.extend_from_slice(b"Xft/DPI\0");
+        bytes.extend_from_slice(&[0, 0, 0, 3, 0, 1, 0x80, 0]);
+        bytes.extend_from_slice(&[2, 0, 0, 9]);
+        bytes.extend_from_slice(b"Gtk/Color\0\0\0");
+        bytes.extend_from_slice(&[0, 0, 0, 6, 0, 1, 0, 2, 0, 3, 0, 4]);
+
+        let parsed = Settings::parse(&bytes).unwrap();
+        assert_eq!(parsed.serial, 7);
+        assert_eq!(parsed.get("Xft/DPI"), Some(&Value::Integer(98304)));
+        assert_eq!(parsed.settings["Xft/DPI"].serial, 3);
+        assert_eq!(
+            parsed.get("Gtk/Color"),
+            Some(&Value::Color {
+                red: 1,
+                green: 2,
+                blue: 3,
+                alpha: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = settings().to_bytes();
+        for length in 0..bytes.len() {
+            assert_eq!(Settings::parse(&bytes[..length]), None, "{} bytes", length);
+        }
+    }
+}
@@ -0,0 +1,1181 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+
+use layout::{Field, FieldKind, Header, Kind, Layout, Protocol, TypeInfo};
+use names::{rust_field_name, rust_type_name, split_words};
+use parse::{Expr, FieldDecl, Item};
+
+/// The three files generated for every module, mirroring the layout of the crate root.
+pub struct Files {
+    pub cdef: String,
+    pub constants: String,
+    pub module: String,
+}
+
+/// Name of the library providing the C functions of a module.
+pub fn library(header: &str) -> String {
+    match header {
+        "xproto" | "bigreq" | "xc_misc" => "xcb".to_string(),
+        other => format!("xcb-{}", other),
+    }
+}
+
+/// Determines the integer type used for the constants of every enum, which is the widest field
+/// type the enum is used with.
+pub fn enum_types(protocol: &Protocol) -> HashMap<(usize, String), &'static str> {
+    let mut widths: HashMap<(usize, String), usize> = HashMap::new();
+
+    fn visit(
+        protocol: &Protocol,
+        module: usize,
+        decls: &[FieldDecl],
+        widths: &mut HashMap<(usize, String), usize>,
+    ) {
+        for decl in decls {
+            match *decl {
+                FieldDecl::Field {
+                    ref ty,
+                    ref enum_name,
+                    ref mask,
+                    ..
+                } => {
+                    let size = protocol
+                        .lookup(module, ty)
+                        .ok()
+                        .and_then(|info| info.size)
+                        .unwrap_or(4);
+                    for name in enum_name.iter().chain(mask.iter()) {
+                        if let Some(key) = protocol.lookup_enum(module, name) {
+                            let width = widths.entry(key).or_insert(0);
+                            *width = (*width).max(size);
+                        }
+                    }
+                }
+                FieldDecl::Switch { ref cases, .. } => {
+                    for case in cases {
+                        visit(protocol, module, case, widths);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for (index, module) in protocol.modules.iter().enumerate() {
+        for item in &module.items {
+            match *item {
+                Item::Struct(ref c) | Item::Union(ref c) => {
+                    visit(protocol, index, &c.fields, &mut widths)
+                }
+                Item::Event(ref e) => visit(protocol, index, &e.fields, &mut widths),
+                Item::Error(ref e) => visit(protocol, index, &e.fields, &mut widths),
+                Item::Request(ref r) => {
+                    visit(protocol, index, &r.fields, &mut widths);
+                    if let Some(ref reply) = r.reply {
+                        visit(protocol, index, reply, &mut widths);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut types = HashMap::new();
+    for (index, module) in protocol.modules.iter().enumerate() {
+        for item in &module.items {
+            if let Item::Enum(ref e) = *item {
+                let key = (index, e.name.clone());
+                let mut width = widths.get(&key).cloned().unwrap_or(4);
+                let negative = e.items.iter().any(|&(_, value)| value < 0);
+                let max = e.items.iter().map(|&(_, value)| value).max().unwrap_or(0);
+                while width < 8 && max >= 1 << (8 * width) {
+                    width *= 2;
+                }
+                let ty = match (negative, width) {
+                    (true, _) => "i32",
+                    (false, 1) => "u8",
+                    (false, 2) => "u16",
+                    (false, 4) => "u32",
+                    (false, _) => "u64",
+                };
+                types.insert(key, ty);
+            }
+        }
+    }
+    types
+}
+
+/// Imports a generated file needs.
+#[derive(Default)]
+struct Uses {
+    os_raw: BTreeSet<&'static str>,
+    root: BTreeSet<&'static str>,
+    foreign: BTreeMap<usize, BTreeSet<String>>,
+    ptr: bool,
+    slice: bool,
+}
+
+/// An event as it appears in the event enum of a module.
+struct EventVariant {
+    name: String,
+    rust_name: String,
+    number: i64,
+    xge: bool,
+}
+
+/// A parameter of a safe request wrapper.
+struct Param {
+    name: String,
+    ty: String,
+}
+
+pub struct Emitter<'a> {
+    protocol: &'a Protocol,
+    enum_types: &'a HashMap<(usize, String), &'static str>,
+    module: usize,
+    header: String,
+    uses: Uses,
+    wrapper_uses: Uses,
+    types: String,
+    functions: String,
+    aliases: String,
+    wrappers: String,
+    constants: String,
+    constant_names: HashSet<String>,
+    events: Vec<EventVariant>,
+    errors: Vec<(String, i64)>,
+    /// The requests without a safe wrapper.
+    raw_only: Vec<String>,
+}
+
+impl<'a> Emitter<'a> {
+    pub fn new(
+        protocol: &'a Protocol,
+        enum_types: &'a HashMap<(usize, String), &'static str>,
+        module: usize,
+    ) -> Emitter<'a> {
+        Emitter {
+            protocol,
+            enum_types,
+            module,
+            header: protocol.modules[module].header.clone(),
+            uses: Uses::default(),
+            wrapper_uses: Uses::default(),
+            types: String::new(),
+            functions: String::new(),
+            aliases: String::new(),
+            wrappers: String::new(),
+            constants: String::new(),
+            constant_names: HashSet::new(),
+            events: Vec::new(),
+            errors: Vec::new(),
+            raw_only: Vec::new(),
+        }
+    }
+
+    fn c_name(&self, parts: &[&str]) -> String {
+        self.protocol.c_name(self.module, parts)
+    }
+
+    /// Name of a type as used in the raw bindings, recording the import it needs.
+    fn raw_type(&mut self, info: &TypeInfo) -> String {
+        match info.kind {
+            Kind::Char => {
+                self.uses.os_raw.insert("c_char");
+            }
+            Kind::Void => {
+                self.uses.os_raw.insert("c_void");
+            }
+            _ => {}
+        }
+        import(&mut self.uses, self.module, info, info.rust_name.clone())
+    }
+
+    /// Name of the iterator of a type as used in the raw bindings.
+    fn raw_iterator(&mut self, info: &TypeInfo) -> String {
+        import(
+            &mut self.uses,
+            self.module,
+            info,
+            format!("{}Iterator", info.rust_name),
+        )
+    }
+
+    /// Name of a type as used in the safe wrappers, recording the import it needs.
+    fn wrapper_type(&mut self, info: &TypeInfo) -> String {
+        import(
+            &mut self.wrapper_uses,
+            self.module,
+            info,
+            info.rust_name.clone(),
+        )
+    }
+
+    /// Name of the iterator of a type as used in the safe wrappers.
+    fn wrapper_iterator(&mut self, info: &TypeInfo) -> String {
+        import(
+            &mut self.wrapper_uses,
+            self.module,
+            info,
+            format!("{}Iterator", info.rust_name),
+        )
+    }
+
+    fn constant(&mut self, name: String, ty: &str, value: i64) {
+        if self.constant_names.insert(name.clone()) {
+            writeln!(self.constants, "pub const {}: {} = {};", name, ty, value).unwrap();
+        }
+    }
+
+    fn alias(&mut self, rust_name: &str) {
+        writeln!(self.aliases, "pub type {0} = cdef::{0};", rust_name).unwrap();
+    }
+
+    pub fn emit(mut self) -> Files {
+        let protocol = self.protocol;
+        let module = &protocol.modules[self.module];
+
+        if let Some(ref extension) = module.extension {
+            let prefix = self.c_name(&[]).to_uppercase();
+            self.constant(
+                format!("{}_MAJOR_VERSION", prefix),
+                "u32",
+                extension.major_version as i64,
+            );
+            self.constant(
+                format!("{}_MINOR_VERSION", prefix),
+                "u32",
+                extension.minor_version as i64,
+            );
+            self.uses.root.insert("XCBExtension");
+            writeln!(
+                self.functions,
+                "    pub static mut {}_id: XCBExtension;",
+                self.c_name(&[])
+            )
+            .unwrap();
+
+            self.wrapper_uses.root.insert("Extension");
+            self.wrapper_uses.root.insert("XCBExtension");
+            writeln!(
+                self.wrappers,
+                "\n/// The {name} extension, known to the server as `{xname}`.\npub struct {marker};\n\nimpl Extension for {marker} {{\n    fn id() -> *mut XCBExtension {{\n        ptr::addr_of_mut!(cdef::{c}_id)\n    }}\n}}",
+                name = extension.name,
+                xname = extension.xname,
+                marker = extension_marker(&self.header),
+                c = self.c_name(&[])
+            )
+            .unwrap();
+            self.wrapper_uses.ptr = true;
+        }
+
+        for item in &module.items {
+            match *item {
+                Item::XidType(ref name) | Item::XidUnion(ref name) => {
+                    let info = protocol.lookup(self.module, name).unwrap();
+                    writeln!(self.types, "\npub type {} = u32;", info.rust_name).unwrap();
+                    self.alias(&info.rust_name);
+                    self.iterator(name, &info);
+                }
+                Item::Typedef { ref old, ref new } => {
+                    let old = protocol.lookup(self.module, old).unwrap();
+                    let info = protocol.lookup(self.module, new).unwrap();
+                    let old_name = self.raw_type(&old);
+                    writeln!(self.types, "\npub type {} = {};", info.rust_name, old_name).unwrap();
+                    self.alias(&info.rust_name);
+                    self.iterator(new, &info);
+                }
+                Item::Enum(ref e) => {
+                    let ty = self.enum_types[&(self.module, e.name.clone())];
+                    for &(ref item, value) in &e.items {
+                        let name = self.c_name(&[&e.name, item]).to_uppercase();
+                        self.constant(name, ty, value);
+                    }
+                }
+                Item::Struct(ref complex) | Item::Union(ref complex) => {
+                    let is_union = matches!(*item, Item::Union(_));
+                    let info = protocol.lookup(self.module, &complex.name).unwrap();
+                    let layout = protocol.layout(self.module, &complex.fields, Header::None);
+                    self.structure(&info.rust_name, &layout, is_union);
+                    self.alias(&info.rust_name);
+                    self.iterator(&complex.name, &info);
+                    if !is_union {
+                        self.accessors(&[&complex.name], &info.rust_name, "data", &layout);
+                    }
+                }
+                Item::EventStruct(ref event_struct) => self.event_struct(event_struct),
+                Item::Request(ref request) => self.request(request),
+                Item::Event(ref event) => {
+                    let c = self.c_name(&[&event.name]);
+                    let number_type = if event.xge { "u16" } else { "u8" };
+                    self.constant(c.to_uppercase(), number_type, event.number);
+
+                    let rust_name = rust_type_name(&format!("{}_event", c));
+                    let layout = protocol.layout(
+                        self.module,
+                        &event.fields,
+                        Header::Event {
+                            xge: event.xge,
+                            sequence: !event.no_sequence_number,
+                        },
+                    );
+                    self.structure(&rust_name, &layout, false);
+                    self.alias(&rust_name);
+                    self.accessors(&[&event.name], &rust_name, "event", &layout);
+                    self.events.push(EventVariant {
+                        name: event.name.clone(),
+                        rust_name,
+                        number: event.number,
+                        xge: event.xge,
+                    });
+                }
+                Item::Error(ref error) => {
+                    let c = self.c_name(&[&error.name]);
+                    let ty = if error.number < 0 { "i32" } else { "u8" };
+                    self.constant(c.to_uppercase(), ty, error.number);
+                    self.errors.push((error.name.clone(), error.number));
+
+                    let rust_name = rust_type_name(&format!("{}_error", c));
+                    let layout = protocol.layout(self.module, &error.fields, Header::Error);
+                    self.structure(&rust_name, &layout, false);
+                    self.alias(&rust_name);
+                }
+                Item::EventCopy(ref copy) | Item::ErrorCopy(ref copy) => {
+                    let is_event = matches!(*item, Item::EventCopy(_));
+                    let (module, name, xge) = self.copied(self.module, copy, is_event);
+                    let suffix = if is_event { "event" } else { "error" };
+                    let c = self.c_name(&[&copy.name]);
+                    self.constant(
+                        c.to_uppercase(),
+                        if xge { "u16" } else { "u8" },
+                        copy.number,
+                    );
+
+                    let rust_name = rust_type_name(&format!("{}_{}", c, suffix));
+                    let reference = rust_type_name(&protocol.c_name(module, &[&name, suffix]));
+                    if module != self.module {
+                        self.uses
+                            .foreign
+                            .entry(module)
+                            .or_default()
+                            .insert(reference.clone());
+                    }
+                    writeln!(self.types, "\npub type {} = {};", rust_name, reference).unwrap();
+                    self.alias(&rust_name);
+                    if is_event {
+                        self.events.push(EventVariant {
+                            name: copy.name.clone(),
+                            rust_name,
+                            number: copy.number,
+                            xge,
+                        });
+                    } else {
+                        self.errors.push((copy.name.clone(), copy.number));
+                    }
+                }
+            }
+        }
+
+        self.event_enum();
+        self.error_names();
+        self.finish()
+    }
+
+    /// Finds the event or error an `eventcopy` or `errorcopy` refers to, which may be defined in
+    /// an imported module, and returns its module, name and whether it is a generic event.
+    fn copied(&self, module: usize, copy: &::parse::Copy, is_event: bool) -> (usize, String, bool) {
+        let protocol = self.protocol;
+        let (candidates, name) = match copy.reference.find(':') {
+            Some(colon) => (
+                vec![protocol.module_index(&copy.reference[..colon]).unwrap()],
+                &copy.reference[colon + 1..],
+            ),
+            None => {
+                let mut candidates = vec![module];
+                for import in &protocol.modules[module].imports {
+                    candidates.extend(protocol.module_index(import));
+                }
+                (candidates, copy.reference.as_str())
+            }
+        };
+
+        for module in candidates {
+            for item in &protocol.modules[module].items {
+                match *item {
+                    Item::Event(ref event) if is_event && event.name == name => {
+                        return (module, name.to_string(), event.xge)
+                    }
+                    Item::Error(ref error) if !is_event && error.name == name => {
+                        return (module, name.to_string(), false)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        panic!("{} refers to the unknown {}", copy.name, copy.reference);
+    }
+
+    fn structure(&mut self, rust_name: &str, layout: &Layout, is_union: bool) {
+        let mut out = String::new();
+        out.push_str("\n#[repr(C");
+        if layout.packed {
+            out.push_str(", packed");
+        }
+        out.push_str(")]\n");
+        if layout.fixed_size() || is_union {
+            out.push_str("#[derive(Copy, Clone)]\n");
+        }
+        writeln!(
+            out,
+            "pub {} {} {{",
+            if is_union { "union" } else { "struct" },
+            rust_name
+        )
+        .unwrap();
+        for field in layout.struct_fields(is_union) {
+            let ty = self.raw_type(field.ty.as_ref().unwrap());
+            let ty = if field.nmemb != 1 {
+                format!("[{}; {}]", ty, field.nmemb)
+            } else {
+                ty
+            };
+            writeln!(out, "    pub {}: {},", rust_field_name(&field.name), ty).unwrap();
+        }
+        out.push_str("}\n");
+        self.types.push_str(&out);
+    }
+
+    /// Emits the iterator type of `name` together with its `_next` and `_end` functions.
+    fn iterator(&mut self, name: &str, info: &TypeInfo) {
+        let c = self.c_name(&[name]);
+        let iterator = rust_type_name(&format!("{}_iterator", c));
+        self.uses.os_raw.insert("c_int");
+        self.uses.root.insert("XCBGenericIterator");
+
+        writeln!(
+            self.types,
+            "\n#[repr(C)]\npub struct {} {{\n    pub data: *mut {},\n    pub rem: c_int,\n    pub index: c_int,\n}}",
+            iterator, info.rust_name
+        )
+        .unwrap();
+        writeln!(
+            self.functions,
+            "    pub fn {}_next(i: *mut {});",
+            c, iterator
+        )
+        .unwrap();
+        writeln!(
+            self.functions,
+            "    pub fn {}_end(i: {}) -> XCBGenericIterator;",
+            c, iterator
+        )
+        .unwrap();
+
+        self.alias(&iterator);
+        writeln!(
+            self.wrappers,
+            "\npub fn {0}_next(iter: &mut {1}) {{\n    unsafe {{ cdef::{0}_next(iter) }}\n}}",
+            c, iterator
+        )
+        .unwrap();
+    }
+
+    /// Emits the accessor functions for the variable sized lists of a structure, reply or event.
+    fn accessors(&mut self, name: &[&str], rust_name: &str, param: &str, layout: &Layout) {
+        for field in &layout.fields {
+            if !field.is_list() || field.fixed || !field.wire {
+                continue;
+            }
+            let member = field.ty.clone().unwrap();
+            let mut parts = name.to_vec();
+            parts.push(&field.name);
+            let c = self.c_name(&parts);
+            if member.size.is_some() {
+                let member_name = self.raw_type(&member);
+                writeln!(
+                    self.functions,
+                    "    pub fn {}(R: *const {}) -> *mut {};",
+                    c, rust_name, member_name
+                )
+                .unwrap();
+            }
+            self.uses.os_raw.insert("c_int");
+            writeln!(
+                self.functions,
+                "    pub fn {}_length(R: *const {}) -> c_int;",
+                c, rust_name
+            )
+            .unwrap();
+            if member.is_simple() {
+                self.uses.root.insert("XCBGenericIterator");
+                writeln!(
+                    self.functions,
+                    "    pub fn {}_end(R: *const {}) -> XCBGenericIterator;",
+                    c, rust_name
+                )
+                .unwrap();
+            } else {
+                let iterator = self.raw_iterator(&member);
+                writeln!(
+                    self.functions,
+                    "    pub fn {}_iterator(R: *const {}) -> {};",
+                    c, rust_name, iterator
+                )
+                .unwrap();
+            }
+
+            // Safe wrappers
+            if member.size.is_some() {
+                let (element, cast) = match member.kind {
+                    Kind::Void | Kind::Char => ("u8".to_string(), " as *const u8"),
+                    _ => (self.wrapper_type(&member), ""),
+                };
+                self.wrapper_uses.slice = true;
+                writeln!(
+                    self.wrappers,
+                    "\npub fn {c}({p}: &{t}) -> &[{e}] {{\n    unsafe {{\n        slice::from_raw_parts(\n            cdef::{c}({p}){cast},\n            cdef::{c}_length({p}) as usize,\n        )\n    }}\n}}",
+                    c = c,
+                    p = param,
+                    t = rust_name,
+                    e = element,
+                    cast = cast
+                )
+                .unwrap();
+            } else if !member.is_simple() {
+                let iterator = self.wrapper_iterator(&member);
+                writeln!(
+                    self.wrappers,
+                    "\npub fn {c}_iterator({p}: &{t}) -> {i} {{\n    unsafe {{ cdef::{c}_iterator({p}) }}\n}}",
+                    c = c,
+                    p = param,
+                    t = rust_name,
+                    i = iterator
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    fn event_struct(&mut self, event_struct: &::parse::EventStruct) {
+        let protocol = self.protocol;
+        let info = protocol.lookup(self.module, &event_struct.name).unwrap();
+        let mut members = Vec::new();
+
+        for allowed in &event_struct.allowed {
+            for (index, module) in protocol.modules.iter().enumerate() {
+                let matches = module
+                    .extension
+                    .as_ref()
+                    .is_some_and(|e| e.name == allowed.extension);
+                if !matches {
+                    continue;
+                }
+                for item in &module.items {
+                    let (name, number, xge) = match *item {
+                        Item::Event(ref e) => (&e.name, e.number, e.xge),
+                        Item::EventCopy(ref copy) => {
+                            (&copy.name, copy.number, self.copied(index, copy, true).2)
+                        }
+                        _ => continue,
+                    };
+                    if xge == allowed.xge
+                        && number >= allowed.opcode_min
+                        && number <= allowed.opcode_max
+                    {
+                        let c = protocol.c_name(index, &[name, "event"]);
+                        members.push((
+                            split_words(name).join("_").to_lowercase(),
+                            rust_type_name(&c),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut out = format!(
+            "\n#[repr(C)]\n#[derive(Copy, Clone)]\npub union {} {{\n",
+            info.rust_name
+        );
+        for (name, ty) in members {
+            writeln!(out, "    pub event_{}: {},", name, ty).unwrap();
+        }
+        out.push_str("    pub event_header: [u8; 32],\n}\n");
+        self.types.push_str(&out);
+        self.alias(&info.rust_name);
+        self.iterator(&event_struct.name, &info);
+    }
+
+    fn request(&mut self, request: &::parse::Request) {
+        let protocol = self.protocol;
+        let c = self.c_name(&[&request.name]);
+        self.constant(c.to_uppercase(), "u8", request.opcode);
+
+        let layout = protocol.layout(self.module, &request.fields, Header::Request);
+        self.structure(&rust_type_name(&format!("{}_request", c)), &layout, false);
+        self.alias(&rust_type_name(&format!("{}_request", c)));
+
+        let cookie = match request.reply {
+            Some(ref reply) => {
+                let reply_name = rust_type_name(&format!("{}_reply", c));
+                let cookie = rust_type_name(&format!("{}_cookie", c));
+                let reply_layout = protocol.layout(self.module, reply, Header::Reply);
+                self.uses.root.insert("XCBCookie");
+                writeln!(
+                    self.types,
+                    "\npub type {} = XCBCookie<{}>;",
+                    cookie, reply_name
+                )
+                .unwrap();
+                self.structure(&reply_name, &reply_layout, false);
+                self.alias(&cookie);
+                self.alias(&reply_name);
+                self.accessors(&[&request.name], &reply_name, "reply", &reply_layout);
+
+                self.uses.root.insert("XCBConnection");
+                self.uses.root.insert("XCBGenericError");
+                writeln!(
+                    self.functions,
+                    "    pub fn {c}_reply(\n        connection: *mut XCBConnection,\n        cookie: {k},\n        e: *mut *mut XCBGenericError,\n    ) -> *mut {r};",
+                    c = c,
+                    k = cookie,
+                    r = reply_name
+                )
+                .unwrap();
+                if reply_layout
+                    .fields
+                    .iter()
+                    .any(|f| !f.wire && f.ty.as_ref().is_some_and(|t| t.kind == Kind::Fd))
+                {
+                    self.uses.os_raw.insert("c_int");
+                    writeln!(
+                        self.functions,
+                        "    pub fn {}_reply_fds(connection: *mut XCBConnection, reply: *mut {}) -> *mut c_int;",
+                        c, reply_name
+                    )
+                    .unwrap();
+                }
+
+                writeln!(
+                    self.wrappers,
+                    "\nimpl Reply for {r} {{\n    unsafe fn reply(\n        connection: *mut ::cdef::XCBConnection,\n        cookie: XCBCookie<Self>,\n        e: *mut *mut XCBGenericError,\n    ) -> *mut Self {{\n        cdef::{c}_reply(connection, cookie, e)\n    }}\n}}",
+                    r = reply_name,
+                    c = c
+                )
+                .unwrap();
+                self.wrapper_uses.root.insert("Reply");
+                self.wrapper_uses.root.insert("XCBCookie");
+                self.wrapper_uses.root.insert("XCBGenericError");
+                cookie
+            }
+            None => {
+                self.uses.root.insert("XCBVoidCookie");
+                self.wrapper_uses.root.insert("XCBVoidCookie");
+                "XCBVoidCookie".to_string()
+            }
+        };
+
+        let mut params = Vec::new();
+        for field in layout.fields.iter().filter(|f| f.visible) {
+            let ty = match field.kind {
+                FieldKind::Switch(_) => {
+                    self.uses.os_raw.insert("c_void");
+                    "*const c_void".to_string()
+                }
+                FieldKind::List(_) => {
+                    let member = field.ty.clone().unwrap();
+                    format!("*const {}", self.raw_type(&member))
+                }
+                _ => {
+                    let ty = field.ty.clone().unwrap();
+                    if ty.size.is_some() {
+                        self.raw_type(&ty)
+                    } else {
+                        format!("*const {}", self.raw_type(&ty))
+                    }
+                }
+            };
+            params.push(Param {
+                name: rust_field_name(&field.name),
+                ty,
+            });
+        }
+
+        self.uses.root.insert("XCBConnection");
+        let variants: &[&str] = if request.reply.is_some() {
+            &["", "_unchecked"]
+        } else {
+            &["_checked", ""]
+        };
+        for variant in variants {
+            let mut signature = format!(
+                "    pub fn {}{}(\n        connection: *mut XCBConnection,\n",
+                c, variant
+            );
+            for param in &params {
+                writeln!(signature, "        {}: {},", param.name, param.ty).unwrap();
+            }
+            writeln!(signature, "    ) -> {};", cookie).unwrap();
+            self.functions.push_str(&signature);
+        }
+
+        if !self.request_wrapper(&c, &layout, &cookie) {
+            self.raw_only.push(c);
+        }
+    }
+
+    /// Emits a safe wrapper for a request, taking slices instead of pointer and length pairs.
+    ///
+    /// Requests whose parameters cannot be expressed safely, e.g. because list lengths depend on
+    /// the contents of other lists, only get raw bindings and `false` is returned.
+    fn request_wrapper(&mut self, c: &str, layout: &Layout, cookie: &str) -> bool {
+        let visible: Vec<&Field> = layout.fields.iter().filter(|f| f.visible).collect();
+
+        // Length fields that can be derived from the length of a single slice.
+        let mut derived: HashMap<String, String> = HashMap::new();
+        let mut references: HashMap<String, usize> = HashMap::new();
+        for field in &visible {
+            match field.kind {
+                FieldKind::List(Some(ref expr)) | FieldKind::Switch(ref expr) => {
+                    count_references(expr, &mut references)
+                }
+                _ => {}
+            }
+        }
+        for field in &visible {
+            match field.kind {
+                FieldKind::List(Some(Expr::FieldRef(ref length))) => {
+                    let is_param = visible
+                        .iter()
+                        .any(|f| &f.name == length && matches!(f.kind, FieldKind::Plain));
+                    if is_param && references.get(length) == Some(&1) {
+                        derived.insert(length.clone(), field.name.clone());
+                    }
+                }
+                FieldKind::List(None) => {
+                    derived.insert(format!("{}_len", field.name), field.name.clone());
+                }
+                _ => {}
+            }
+        }
+
+        let mut params = Vec::new();
+        let mut statements = Vec::new();
+        let mut arguments = Vec::new();
+
+        for field in &visible {
+            let name = rust_field_name(&field.name);
+            if let Some(list) = derived.get(&field.name) {
+                let ty = field.ty.clone().unwrap();
+                arguments.push(format!(
+                    "{}.len() as {}",
+                    rust_field_name(list),
+                    ty.rust_name
+                ));
+                continue;
+            }
+
+            match field.kind {
+                FieldKind::Switch(ref expr) => {
+                    if !field.all_cases_u32 {
+                        return false;
+                    }
+                    let count = match rust_expr(
+                        &Expr::Popcount(Box::new(expr.clone())),
+                        &visible,
+                        &derived,
+                    ) {
+                        Some(count) => count,
+                        None => return false,
+                    };
+                    params.push(format!("{}: &[u32]", name));
+                    statements.push(format!("assert!({}.len() >= {});", name, count));
+                    self.wrapper_uses.os_raw.insert("c_void");
+                    arguments.push(format!("{}.as_ptr() as *const c_void", name));
+                }
+                FieldKind::List(ref length) => {
+                    let member = field.ty.clone().unwrap();
+                    if member.size.is_none() {
+                        return false;
+                    }
+                    let (element, cast) = match member.kind {
+                        Kind::Void => {
+                            self.wrapper_uses.os_raw.insert("c_void");
+                            ("u8".to_string(), " as *const c_void")
+                        }
+                        Kind::Char => {
+                            self.wrapper_uses.os_raw.insert("c_char");
+                            ("u8".to_string(), " as *const c_char")
+                        }
+                        _ => (self.wrapper_type(&member), ""),
+                    };
+                    params.push(format!("{}: &[{}]", name, element));
+                    arguments.push(format!("{}.as_ptr(){}", name, cast));
+
+                    let derives_length = match *length {
+                        None => true,
+                        Some(Expr::FieldRef(ref l)) => derived.contains_key(l),
+                        Some(_) => false,
+                    };
+                    if !derives_length {
+                        match rust_expr(length.as_ref().unwrap(), &visible, &derived) {
+                            Some(expr) => {
+                                statements.push(format!("assert!({}.len() >= {});", name, expr))
+                            }
+                            None => return false,
+                        }
+                    }
+                }
+                _ => {
+                    let ty = field.ty.clone().unwrap();
+                    if ty.kind == Kind::Bool {
+                        params.push(format!("{}: bool", name));
+                        arguments.push(format!("{} as u8", name));
+                    } else if ty.size.is_none() {
+                        // The C side reads the variable part following the fixed fields, which a
+                        // reference to the Rust struct does not provide.
+                        return false;
+                    } else {
+                        let ty_name = self.wrapper_type(&ty);
+                        params.push(format!("{}: {}", name, ty_name));
+                        arguments.push(name);
+                    }
+                }
+            }
+        }
+
+        self.wrapper_uses.root.insert("XCBConnection");
+        let mut out = format!("\npub fn {}(\n    connection: &XCBConnection,\n", c);
+        for param in &params {
+            writeln!(out, "    {},", param).unwrap();
+        }
+        writeln!(out, ") -> {} {{", cookie).unwrap();
+        for statement in &statements {
+            writeln!(out, "    {}", statement).unwrap();
+        }
+        if arguments.is_empty() {
+            writeln!(out, "    unsafe {{ cdef::{}(connection.raw) }}", c).unwrap();
+        } else {
+            writeln!(
+                out,
+                "    unsafe {{\n        cdef::{}(\n            connection.raw,",
+                c
+            )
+            .unwrap();
+            for argument in &arguments {
+                writeln!(out, "            {},", argument).unwrap();
+            }
+            out.push_str("        )\n    }\n");
+        }
+        out.push_str("}\n");
+        self.wrappers.push_str(&out);
+        true
+    }
+
+    /// Emits the enum of all events of the module together with the functions decoding them.
+    fn event_enum(&mut self) {
+        if self.events.is_empty() {
+            return;
+        }
+        let module = &self.protocol.modules[self.module];
+        let description = match module.extension {
+            Some(ref extension) => format!("the {} extension", extension.name),
+            None => "the core protocol".to_string(),
+        };
+
+        // All XKB events share a single event code and are told apart by their second byte.
+        let mut events = module.items.iter().filter_map(|item| match *item {
+            Item::Event(ref event) => Some(event),
+            _ => None,
+        });
+        let subtyped = events.all(|event| match event.fields.first() {
+            Some(FieldDecl::Field { name, .. }) => name == "xkbType",
+            _ => false,
+        });
+
+        let mut out = format!("\n/// An event of {}.\npub enum Event {{\n", description);
+        for event in &self.events {
+            writeln!(out, "    {}(Box<{}>),", event.name, event.rust_name).unwrap();
+        }
+        out.push_str("}\n\nimpl Event {\n");
+
+        let decoders = [
+            (
+                false,
+                "decode",
+                "number: u8",
+                if module.extension.is_some() {
+                    "Decodes an event whose code, relative to the first event of the extension, is \
+                     `number`."
+                } else {
+                    "Decodes an event whose code is `number`."
+                },
+            ),
+            (
+                true,
+                "decode_generic",
+                "event_type: u16",
+                "Decodes a generic event whose type is `event_type`.",
+            ),
+        ];
+        for &(xge, name, param, doc) in &decoders {
+            let events: Vec<&EventVariant> = self.events.iter().filter(|e| e.xge == xge).collect();
+            if events.is_empty() {
+                continue;
+            }
+            let (selector, guard) = if subtyped {
+                (
+                    "(*raw).pad0".to_string(),
+                    "        if number != 0 {\n            return Err(event);\n        }\n",
+                )
+            } else {
+                (param.split(':').next().unwrap().to_string(), "")
+            };
+            writeln!(
+                out,
+                "    /// {}\n    ///\n    /// The event is handed back if it is not known.\n    pub fn {}({}, event: Box<XCBGenericEvent>) -> Result<Event, Box<XCBGenericEvent>> {{\n{}        let raw = Box::into_raw(event);\n        unsafe {{\n            match {} {{",
+                doc, name, param, guard, selector
+            )
+            .unwrap();
+            for event in events {
+                writeln!(
+                    out,
+                    "                {} => Ok(Event::{}(Box::from_raw(raw as *mut {}))),",
+                    event.number, event.name, event.rust_name
+                )
+                .unwrap();
+            }
+            out.push_str(
+                "                _ => Err(Box::from_raw(raw)),\n            }\n        }\n    }\n",
+            );
+        }
+        out.push_str("}\n");
+
+        self.wrapper_uses.root.insert("XCBGenericEvent");
+        self.wrappers.push_str(&out);
+    }
+
+    /// Emits a function returning the name of an error given its code.
+    fn error_names(&mut self) {
+        let errors: Vec<&(String, i64)> = self.errors.iter().filter(|e| e.1 >= 0).collect();
+        if errors.is_empty() {
+            return;
+        }
+        let relative = if self.protocol.modules[self.module].extension.is_some() {
+            ", relative to the first error of the extension,"
+        } else {
+            ""
+        };
+        let mut out = format!(
+            "\n/// Returns the name of the error whose code{} is `number`.\npub fn error_name(number: u8) -> Option<&'static str> {{\n    match number {{\n",
+            relative
+        );
+        for &&(ref name, number) in &errors {
+            writeln!(out, "        {} => Some(\"{}\"),", number, name).unwrap();
+        }
+        out.push_str("        _ => None,\n    }\n}\n");
+        self.wrappers.push_str(&out);
+    }
+
+    fn finish(mut self) -> Files {
+        let protocol = self.protocol;
+        let header = self.header.clone();
+
+        // cdef.rs
+        let mut cdef = format!(
+            "// Generated by xcbgen from xml/{}.xml, do not edit.\n\n#![allow(non_snake_case)]\n\n",
+            header
+        );
+        if !self.uses.os_raw.is_empty() {
+            writeln!(
+                cdef,
+                "use std::os::raw::{};",
+                import_list(self.uses.os_raw.iter().cloned())
+            )
+            .unwrap();
+            cdef.push('\n');
+        }
+        if !self.uses.root.is_empty() {
+            writeln!(
+                cdef,
+                "use cdef::{};",
+                import_list(self.uses.root.iter().cloned())
+            )
+            .unwrap();
+        }
+        for (module, names) in &self.uses.foreign {
+            writeln!(
+                cdef,
+                "use proto::{}::cdef::{};",
+                protocol.modules[*module].header,
+                import_list(names.iter().map(String::as_str))
+            )
+            .unwrap();
+        }
+        cdef.push_str(&self.types);
+        writeln!(
+            cdef,
+            "\n#[link(name = \"{}\")]\nextern \"system\" {{\n{}}}",
+            library(&header),
+            self.functions
+        )
+        .unwrap();
+
+        // constants.rs
+        let constants = format!(
+            "// Generated by xcbgen from xml/{}.xml, do not edit.\n\n{}",
+            header, self.constants
+        );
+
+        // mod.rs
+        let mut module = format!(
+            "// Generated by xcbgen from xml/{}.xml, do not edit.\n\n",
+            header
+        );
+        if !self.raw_only.is_empty() {
+            module.push_str(
+                "//! Requests whose parameters the safe wrappers cannot express only have raw bindings\n//! in `cdef`:\n//!\n",
+            );
+            for request in &self.raw_only {
+                writeln!(module, "//! - `{}`", request).unwrap();
+            }
+            module.push('\n');
+        }
+        module.push_str("#![allow(non_snake_case)]\n\npub mod cdef;\npub mod constants;\n\n");
+        let mut std_uses = Vec::new();
+        if !self.wrapper_uses.os_raw.is_empty() {
+            std_uses.push(format!(
+                "use std::os::raw::{};",
+                import_list(self.wrapper_uses.os_raw.iter().cloned())
+            ));
+        }
+        if self.wrapper_uses.ptr {
+            std_uses.push("use std::ptr;".to_string());
+        }
+        if self.wrapper_uses.slice {
+            std_uses.push("use std::slice;".to_string());
+        }
+        if !std_uses.is_empty() {
+            module.push_str(&std_uses.join("\n"));
+            module.push_str("\n\n");
+        }
+        for (index, names) in &self.wrapper_uses.foreign {
+            writeln!(
+                module,
+                "use proto::{}::{};",
+                protocol.modules[*index].header,
+                import_list(names.iter().map(String::as_str))
+            )
+            .unwrap();
+        }
+        self.wrapper_uses.root.remove("XCBGenericIterator");
+        if !self.wrapper_uses.root.is_empty() {
+            writeln!(
+                module,
+                "use {};",
+                import_list(self.wrapper_uses.root.iter().cloned())
+            )
+            .unwrap();
+        }
+        module.push('\n');
+        module.push_str(&self.aliases);
+        module.push_str(&self.wrappers);
+
+        Files {
+            cdef,
+            constants,
+            module,
+        }
+    }
+}
+
+/// Name of the type identifying the extension of a module, e.g. `XcMisc` for `xc_misc`.
+pub fn extension_marker(header: &str) -> String {
+    header
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Records the import of `name`, which belongs to the type `info`, if it is defined in another
+/// module than `module`.
+fn import(uses: &mut Uses, module: usize, info: &TypeInfo, name: String) -> String {
+    if let Some(defining) = info.module {
+        if defining != module {
+            uses.foreign
+                .entry(defining)
+                .or_default()
+                .insert(name.clone());
+        }
+    }
+    name
+}
+
+fn import_list<'a, I: Iterator<Item = &'a str>>(names: I) -> String {
+    let names: Vec<&str> = names.collect();
+    if names.len() == 1 {
+        names[0].to_string()
+    } else {
+        format!("{{{}}}", names.join(", "))
+    }
+}
+
+fn count_references(expr: &Expr, references: &mut HashMap<String, usize>) {
+    match *expr {
+        Expr::FieldRef(ref name) => *references.entry(name.clone()).or_insert(0) += 1,
+        Expr::Op(_, ref lhs, ref rhs) => {
+            count_references(lhs, references);
+            count_references(rhs, references);
+        }
+        Expr::Unop(_, ref operand) | Expr::Popcount(ref operand) => {
+            count_references(operand, references)
+        }
+        _ => {}
+    }
+}
+
+/// Translates a length expression into Rust code evaluating to a `usize`, if it only depends on
+/// parameters of the wrapper.
+fn rust_expr(expr: &Expr, params: &[&Field], derived: &HashMap<String, String>) -> Option<String> {
+    match *expr {
+        Expr::FieldRef(ref name) => {
+            let is_param = params
+                .iter()
+                .any(|f| &f.name == name && matches!(f.kind, FieldKind::Plain));
+            if is_param && !derived.contains_key(name) {
+                Some(format!("{} as usize", rust_field_name(name)))
+            } else {
+                None
+            }
+        }
+        Expr::Value(value) if value >= 0 => Some(value.to_string()),
+        Expr::Op(ref op, ref lhs, ref rhs) => {
+            let op = match op.as_str() {
+                "+" | "-" | "*" | "/" | "&" | "<<" => op,
+                _ => return None,
+            };
+            Some(format!(
+                "({} {} {})",
+                rust_expr(lhs, params, derived)?,
+                op,
+                rust_expr(rhs, params, derived)?
+            ))
+        }
+        Expr::Popcount(ref operand) => match **operand {
+            Expr::FieldRef(ref name) => {
+                rust_expr(operand, params, derived)?;
+                Some(format!("{}.count_ones() as usize", rust_field_name(name)))
+            }
+            _ => Some(format!(
+                "{}.count_ones() as usize",
+                rust_expr(operand, params, derived)?
+            )),
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,143 @@
+//! Generates the keysym constants and their Unicode characters from `keysymdef.h`.
+
+use std::fmt::Write;
+
+/// Rust code translating the keysyms that are not listed with a character in `keysymdef.h`.
+const SPECIAL: &str = "
+/// Returns the character typed by a key with the given keysym, if any.
+///
+/// Besides the keysyms of characters this covers the keypad and the keys that type control
+/// characters, e.g. `XK_Return` and `XK_BackSpace`.
+pub fn keysym_to_char(keysym: u32) -> Option<char> {
+    match keysym {
+        // The keysyms of Unicode characters not covered by a legacy keysym.
+        0x0100_0100..=0x0110_ffff => char::from_u32(keysym - 0x0100_0000),
+        XK_BackSpace | XK_Tab | XK_Linefeed | XK_Clear | XK_Return | XK_Escape | XK_KP_Tab
+        | XK_KP_Enter | XK_Delete => char::from_u32(keysym & 0x7f),
+        XK_KP_Space => Some(' '),
+        XK_KP_Multiply..=XK_KP_9 => char::from_u32(keysym & 0x7f),
+        XK_KP_Equal => Some('='),
+        _ => CHARACTERS
+            .binary_search_by_key(&keysym, |&(keysym, _)| keysym)
+            .ok()
+            .map(|index| CHARACTERS[index].1),
+    }
+}
+
+/// Returns the keysym of a character.
+///
+/// Characters with a legacy keysym are mapped to it, all others to their Unicode keysym.
+pub fn char_to_keysym(character: char) -> u32 {
+    let code = character as u32;
+    match code {
+        0x20..=0x7e | 0xa0..=0xff => code,
+        _ => CHARACTERS
+            .iter()
+            .find(|&&(_, candidate)| candidate == character)
+            .map_or(0x0100_0000 + code, |&(keysym, _)| keysym),
+    }
+}
+
+/// Returns the keysym with the given name, e.g. `Return` for `XK_Return`.
+pub fn keysym_from_name(name: &str) -> Option<u32> {
+    NAMES
+        .binary_search_by_key(&name, |&(name, _)| name)
+        .ok()
+        .map(|index| NAMES[index].1)
+}
+
+/// Returns a name of the keysym, e.g. `Return` for `XK_Return`.
+pub fn keysym_name(keysym: u32) -> Option<&'static str> {
+    NAMES
+        .iter()
+        .find(|&&(_, candidate)| candidate == keysym)
+        .map(|&(name, _)| name)
+}
+";
+
+/// Parses the `#define XK_<name> <value>` lines of the header.
+///
+/// # Return value
+/// The name, value and character of each keysym, the latter only if the header notes the
+/// keysym as corresponding one-to-one to a character.
+fn parse(input: &str) -> Result<Vec<(String, u32, Option<char>)>, String> {
+    let mut keysyms = Vec::new();
+    for (number, line) in input.lines().enumerate() {
+        let mut words = line.split_whitespace();
+        if words.next() != Some("#define") {
+            continue;
+        }
+        let name = match words.next() {
+            Some(name) if name.starts_with("XK_") => name,
+            _ => continue,
+        };
+        let value = words
+            .next()
+            .and_then(|value| value.strip_prefix("0x"))
+            .and_then(|value| u32::from_str_radix(value, 16).ok())
+            .ok_or_else(|| format!("line {}: {} has no hexadecimal value", number + 1, name))?;
+
+        // Parenthesized code points only approximate the keysym, so they are left out.
+        let character = match words.next() {
+            Some("/*") => words
+                .next()
+                .and_then(|code| code.strip_prefix("U+"))
+                .and_then(|code| u32::from_str_radix(code, 16).ok())
+                .and_then(char::from_u32),
+            _ => None,
+        };
+        keysyms.push((name.to_string(), value, character));
+    }
+    Ok(keysyms)
+}
+
+/// Generates the module of keysyms from the contents of `keysymdef.h`.
+pub fn generate(input: &str) -> Result<String, String> {
+    let keysyms = parse(input)?;
+
+    let mut out = String::from(
+        "// Generated by xcbgen from xml/keysymdef.h, do not edit.\n\n\
+         //! The keysyms of the X protocol, which name the symbols engraved on keys.\n\n\
+         #![allow(non_upper_case_globals)]\n\n",
+    );
+    for &(ref name, value, _) in &keysyms {
+        writeln!(out, "pub const {}: u32 = {:#06x};", name, value).unwrap();
+    }
+
+    let mut characters: Vec<(u32, char)> = keysyms
+        .iter()
+        .filter_map(|&(_, value, character)| character.map(|character| (value, character)))
+        .collect();
+    characters.sort_by_key(|&(value, _)| value);
+    characters.dedup_by_key(|&mut (value, _)| value);
+    writeln!(
+        out,
+        "\n/// The keysyms corresponding to a character, sorted by keysym.\nstatic CHARACTERS: [(u32, char); {}] = [",
+        characters.len()
+    )
+    .unwrap();
+    for (value, character) in characters {
+        writeln!(
+            out,
+            "    ({:#06x}, '\\u{{{:x}}}'),",
+            value, character as u32
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+
+    let mut names: Vec<&str> = keysyms.iter().map(|(name, _, _)| &name[3..]).collect();
+    names.sort();
+    writeln!(
+        out,
+        "\n/// The names of the keysyms without the `XK_` prefix, sorted by name.\nstatic NAMES: [(&str, u32); {}] = [",
+        names.len()
+    )
+    .unwrap();
+    for name in names {
+        writeln!(out, "    (\"{}\", XK_{}),", name, name).unwrap();
+    }
+    out.push_str("];\n");
+    out.push_str(SPECIAL);
+    Ok(out)
+}
@@ -0,0 +1,667 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use names::{c_name, extension_prefix, rust_type_name};
+use parse::{Expr, FieldDecl, Item, Module};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Kind {
+    Builtin,
+    Bool,
+    Char,
+    Void,
+    Fd,
+    Simple,
+    Struct,
+    Union,
+    EventStruct,
+}
+
+#[derive(Clone)]
+pub struct TypeInfo {
+    /// Qualified name of the type, e.g. `xproto:WINDOW`.
+    pub key: String,
+    pub rust_name: String,
+    /// Index of the module the type is defined in, `None` for builtin types.
+    pub module: Option<usize>,
+    pub kind: Kind,
+    /// Size in bytes, `None` if the type is of variable size.
+    pub size: Option<usize>,
+}
+
+impl TypeInfo {
+    /// Whether the type is a plain value as opposed to a structure.
+    pub fn is_simple(&self) -> bool {
+        !matches!(self.kind, Kind::Struct | Kind::Union | Kind::EventStruct)
+    }
+}
+
+#[derive(Clone)]
+pub enum FieldKind {
+    Plain,
+    Pad,
+    /// A list with its length expression, `None` if the length is implied by the request size.
+    List(Option<Expr>),
+    Switch(Expr),
+    Fd,
+    Expr,
+}
+
+/// A field of a structure after the implicit protocol fields have been added, mirroring the
+/// way libxcb's generator lays out its C structures.
+#[derive(Clone)]
+pub struct Field {
+    pub name: String,
+    /// Type of the field or of the list members, `None` for switches.
+    pub ty: Option<TypeInfo>,
+    pub kind: FieldKind,
+    /// Whether the field is transmitted as part of the structure.
+    pub wire: bool,
+    /// Whether the field is a parameter of the request function.
+    pub visible: bool,
+    pub fixed: bool,
+    /// Number of members of fixed size lists, 1 for all other fixed size fields.
+    pub nmemb: usize,
+    /// For switches, whether every field of every case is a 32 bit value.
+    pub all_cases_u32: bool,
+}
+
+impl Field {
+    fn auto(name: &str, ty: &TypeInfo) -> Field {
+        Field {
+            name: name.to_string(),
+            ty: Some(ty.clone()),
+            kind: FieldKind::Plain,
+            wire: true,
+            visible: false,
+            fixed: true,
+            nmemb: 1,
+            all_cases_u32: false,
+        }
+    }
+
+    fn pad(name: String, ty: &TypeInfo, bytes: usize, align: usize) -> Field {
+        Field {
+            name,
+            ty: Some(ty.clone()),
+            kind: FieldKind::Pad,
+            wire: true,
+            visible: false,
+            fixed: align <= 1,
+            nmemb: bytes,
+            all_cases_u32: false,
+        }
+    }
+
+    pub fn is_list(&self) -> bool {
+        matches!(self.kind, FieldKind::List(_))
+    }
+
+    /// Size of the field in bytes if it is of fixed size.
+    pub fn size(&self) -> Option<usize> {
+        if !self.fixed {
+            return None;
+        }
+        match self.ty {
+            Some(ref ty) => ty.size.map(|size| size * self.nmemb),
+            None => None,
+        }
+    }
+}
+
+pub struct Layout {
+    pub fields: Vec<Field>,
+    /// Whether the C structure has to be packed, which libxcb does for generic events whose
+    /// 64 bit fields would otherwise be misaligned by the injected `full_sequence`.
+    pub packed: bool,
+}
+
+impl Layout {
+    /// Whether every field, including those not on the wire, is of fixed size.
+    pub fn fixed_size(&self) -> bool {
+        self.fields.iter().all(|f| f.fixed)
+    }
+
+    /// The fields that make up the C structure.
+    pub fn struct_fields(&self, is_union: bool) -> Vec<&Field> {
+        self.fields
+            .iter()
+            .filter(|f| f.wire && (f.fixed || is_union))
+            .collect()
+    }
+
+    fn calc_size(&self, is_union: bool) -> Option<usize> {
+        let mut size = 0;
+        for field in self.fields.iter().filter(|f| f.wire) {
+            match field.size() {
+                None => return None,
+                Some(field_size) if is_union => size = size.max(field_size),
+                Some(field_size) => size += field_size,
+            }
+        }
+        Some(size)
+    }
+}
+
+/// The implicit fields at the start of each kind of structure.
+pub enum Header {
+    None,
+    Request,
+    Reply,
+    Event { xge: bool, sequence: bool },
+    Error,
+}
+
+/// All modules together with a registry of the types they define.
+pub struct Protocol {
+    pub modules: Vec<Module>,
+    types: HashMap<String, TypeInfo>,
+    enums: HashMap<String, usize>,
+    /// Types whose size has been determined.
+    resolved: HashSet<String>,
+}
+
+const BUILTINS: &[(&str, &str, usize, Kind)] = &[
+    ("CARD8", "u8", 1, Kind::Builtin),
+    ("CARD16", "u16", 2, Kind::Builtin),
+    ("CARD32", "u32", 4, Kind::Builtin),
+    ("CARD64", "u64", 8, Kind::Builtin),
+    ("INT8", "i8", 1, Kind::Builtin),
+    ("INT16", "i16", 2, Kind::Builtin),
+    ("INT32", "i32", 4, Kind::Builtin),
+    ("INT64", "i64", 8, Kind::Builtin),
+    ("BYTE", "u8", 1, Kind::Builtin),
+    ("BOOL", "u8", 1, Kind::Bool),
+    ("char", "c_char", 1, Kind::Char),
+    ("void", "c_void", 1, Kind::Void),
+    ("float", "f32", 4, Kind::Builtin),
+    ("double", "f64", 8, Kind::Builtin),
+    ("fd", "i32", 4, Kind::Fd),
+];
+
+impl Protocol {
+    pub fn new(modules: Vec<Module>) -> Result<Protocol, String> {
+        let mut protocol = Protocol {
+            modules,
+            types: HashMap::new(),
+            enums: HashMap::new(),
+            resolved: HashSet::new(),
+        };
+
+        for &(name, rust_name, size, kind) in BUILTINS {
+            protocol.resolved.insert(name.to_string());
+            protocol.types.insert(
+                name.to_string(),
+                TypeInfo {
+                    key: name.to_string(),
+                    rust_name: rust_name.to_string(),
+                    module: None,
+                    kind,
+                    size: Some(size),
+                },
+            );
+        }
+
+        protocol.register()?;
+        protocol.compute_sizes()?;
+        Ok(protocol)
+    }
+
+    pub fn module_index(&self, header: &str) -> Option<usize> {
+        self.modules.iter().position(|m| m.header == header)
+    }
+
+    /// The namespace prefix of a module's C names, `None` for the core protocol.
+    pub fn prefix(&self, module: usize) -> Option<String> {
+        self.modules[module]
+            .extension
+            .as_ref()
+            .map(|e| extension_prefix(&e.name))
+    }
+
+    /// Builds the lowercase C name of something defined in `module`.
+    pub fn c_name(&self, module: usize, parts: &[&str]) -> String {
+        let prefix = self.prefix(module);
+        c_name(prefix.as_deref(), parts)
+    }
+
+    fn register(&mut self) -> Result<(), String> {
+        let mut types = Vec::new();
+        let mut enums = Vec::new();
+
+        for (index, module) in self.modules.iter().enumerate() {
+            for item in &module.items {
+                let (name, kind, size) = match *item {
+                    Item::XidType(ref name) | Item::XidUnion(ref name) => {
+                        (name, Kind::Simple, Some(4))
+                    }
+                    Item::Typedef { ref new, .. } => (new, Kind::Simple, None),
+                    Item::Struct(ref complex) => (&complex.name, Kind::Struct, None),
+                    Item::Union(ref complex) => (&complex.name, Kind::Union, None),
+                    Item::EventStruct(ref event_struct) => {
+                        (&event_struct.name, Kind::EventStruct, Some(32))
+                    }
+                    Item::Enum(ref e) => {
+                        enums.push((format!("{}:{}", module.header, e.name), index));
+                        continue;
+                    }
+                    _ => continue,
+                };
+
+                let c = self.c_name(index, &[name]);
+                let key = format!("{}:{}", module.header, name);
+                types.push((
+                    key.clone(),
+                    TypeInfo {
+                        key,
+                        rust_name: rust_type_name(&c),
+                        module: Some(index),
+                        kind,
+                        size,
+                    },
+                ));
+            }
+        }
+
+        for (key, info) in types {
+            if info.size.is_some() {
+                self.resolved.insert(key.clone());
+            }
+            if self.types.insert(key.clone(), info).is_some() {
+                return Err(format!("type {} is defined twice", key));
+            }
+        }
+        self.enums.extend(enums);
+        Ok(())
+    }
+
+    /// Determines the sizes of typedefs and structures, which may depend on types defined later
+    /// in the same module or in other modules.
+    fn compute_sizes(&mut self) -> Result<(), String> {
+        loop {
+            let mut sizes = Vec::new();
+            let mut pending = Vec::new();
+
+            for (index, module) in self.modules.iter().enumerate() {
+                for item in &module.items {
+                    let (name, size) = match *item {
+                        Item::Typedef { ref old, ref new } => match self.resolved(index, old) {
+                            Ok(old) => (new, old.size),
+                            Err(Pending) => {
+                                pending.push(new.clone());
+                                continue;
+                            }
+                        },
+                        Item::Struct(ref complex) | Item::Union(ref complex) => {
+                            let is_union = matches!(*item, Item::Union(_));
+                            match self.try_layout(index, &complex.fields, Header::None) {
+                                Ok(ref layout) if layout.fixed_size() => {
+                                    (&complex.name, layout.calc_size(is_union))
+                                }
+                                Ok(_) => (&complex.name, None),
+                                Err(Pending) => {
+                                    pending.push(complex.name.clone());
+                                    continue;
+                                }
+                            }
+                        }
+                        _ => continue,
+                    };
+
+                    let key = format!("{}:{}", module.header, name);
+                    if !self.resolved.contains(&key) {
+                        sizes.push((key, size));
+                    }
+                }
+            }
+
+            if sizes.is_empty() {
+                if pending.is_empty() {
+                    return Ok(());
+                }
+                return Err(format!("could not resolve the types {:?}", pending));
+            }
+
+            for (key, size) in sizes {
+                self.types.get_mut(&key).unwrap().size = size;
+                self.resolved.insert(key);
+            }
+        }
+    }
+
+    /// Looks up a type by the name used in `module`, searching imported modules as well.
+    pub fn lookup(&self, module: usize, name: &str) -> Result<TypeInfo, String> {
+        // Modules may shadow builtin types, e.g. sync defines its own INT64 structure.
+        let own = format!("{}:{}", self.modules[module].header, name);
+        if let Some(info) = self.types.get(&own) {
+            return Ok(info.clone());
+        }
+        if let Some(info) = self.types.get(name) {
+            return Ok(info.clone());
+        }
+
+        for key in self.candidate_keys(module, name) {
+            if let Some(info) = self.types.get(&key) {
+                return Ok(info.clone());
+            }
+        }
+
+        Err(format!(
+            "type {} is not known in module {}",
+            name, self.modules[module].header
+        ))
+    }
+
+    /// Finds the module defining the enum `name` as referenced from `module`.
+    pub fn lookup_enum(&self, module: usize, name: &str) -> Option<(usize, String)> {
+        for key in self.candidate_keys(module, name) {
+            if let Some(&index) = self.enums.get(&key) {
+                let enum_name = key.split_once(':').unwrap().1.to_string();
+                return Some((index, enum_name));
+            }
+        }
+        None
+    }
+
+    fn candidate_keys(&self, module: usize, name: &str) -> Vec<String> {
+        if name.contains(':') {
+            return vec![name.to_string()];
+        }
+
+        let mut keys = Vec::new();
+        let mut visited = vec![false; self.modules.len()];
+        let mut queue = VecDeque::new();
+        queue.push_back(module);
+        while let Some(index) = queue.pop_front() {
+            if visited[index] {
+                continue;
+            }
+            visited[index] = true;
+            keys.push(format!("{}:{}", self.modules[index].header, name));
+            for import in &self.modules[index].imports {
+                if let Some(imported) = self.module_index(import) {
+                    queue.push_back(imported);
+                }
+            }
+        }
+        keys
+    }
+
+    /// Lays out the fields of a structure, request, reply, event or error.
+    pub fn layout(&self, module: usize, decls: &[FieldDecl], header: Header) -> Layout {
+        match self.try_layout(module, decls, header) {
+            Ok(layout) => layout,
+            Err(Pending) => panic!("layout requested before all types were resolved"),
+        }
+    }
+
+    fn try_layout(
+        &self,
+        module: usize,
+        decls: &[FieldDecl],
+        header: Header,
+    ) -> Result<Layout, Pending> {
+        let card8 = self.types["CARD8"].clone();
+        let card16 = self.types["CARD16"].clone();
+        let card32 = self.types["CARD32"].clone();
+        let is_ext = self.modules[module].extension.is_some();
+
+        let mut fields = Vec::new();
+        let mut placeholder = None;
+        let mut pads = 0;
+        match header {
+            Header::None => {}
+            Header::Request => {
+                fields.push(Field::auto("major_opcode", &card8));
+                if is_ext {
+                    fields.push(Field::auto("minor_opcode", &card8));
+                } else {
+                    placeholder = Some(fields.len());
+                    fields.push(Field::pad("pad0".to_string(), &card8, 1, 1));
+                }
+                fields.push(Field::auto("length", &card16));
+            }
+            Header::Reply => {
+                fields.push(Field::auto("response_type", &card8));
+                placeholder = Some(fields.len());
+                fields.push(Field::pad("pad0".to_string(), &card8, 1, 1));
+                fields.push(Field::auto("sequence", &card16));
+                fields.push(Field::auto("length", &card32));
+            }
+            Header::Event { xge: true, .. } => {
+                fields.push(Field::auto("response_type", &card8));
+                fields.push(Field::auto("extension", &card8));
+                fields.push(Field::auto("sequence", &card16));
+                fields.push(Field::auto("length", &card32));
+                fields.push(Field::auto("event_type", &card16));
+            }
+            Header::Event {
+                xge: false,
+                sequence,
+            } => {
+                fields.push(Field::auto("response_type", &card8));
+                if sequence {
+                    placeholder = Some(fields.len());
+                    fields.push(Field::pad("pad0".to_string(), &card8, 1, 1));
+                    fields.push(Field::auto("sequence", &card16));
+                }
+            }
+            Header::Error => {
+                fields.push(Field::auto("response_type", &card8));
+                fields.push(Field::auto("error_code", &card8));
+                fields.push(Field::auto("sequence", &card16));
+            }
+        }
+
+        {
+            let mut add = |field: Field, fields: &mut Vec<Field>| match placeholder.take() {
+                Some(index) => fields[index] = field,
+                None => fields.push(field),
+            };
+
+            for decl in decls {
+                match *decl {
+                    FieldDecl::Pad { bytes, align } => {
+                        let name = format!("pad{}", pads);
+                        pads += 1;
+                        add(Field::pad(name, &card8, bytes, align), &mut fields);
+                    }
+                    FieldDecl::Field {
+                        ref name, ref ty, ..
+                    } => {
+                        let ty = self.resolved(module, ty)?;
+                        add(
+                            Field {
+                                name: name.clone(),
+                                fixed: ty.size.is_some(),
+                                ty: Some(ty),
+                                kind: FieldKind::Plain,
+                                wire: true,
+                                visible: true,
+                                nmemb: 1,
+                                all_cases_u32: false,
+                            },
+                            &mut fields,
+                        );
+                    }
+                    FieldDecl::List {
+                        ref name,
+                        ref ty,
+                        ref length,
+                    } => {
+                        let ty = self.resolved(module, ty)?;
+                        let nmemb = match *length {
+                            Some(Expr::Value(n)) => Some(n as usize),
+                            _ => None,
+                        };
+                        let len_name = format!("{}_len", name);
+                        if length.is_none() && !fields.iter().any(|f| f.name == len_name) {
+                            add(
+                                Field {
+                                    name: len_name,
+                                    ty: Some(card32.clone()),
+                                    kind: FieldKind::Plain,
+                                    wire: false,
+                                    visible: true,
+                                    fixed: true,
+                                    nmemb: 1,
+                                    all_cases_u32: false,
+                                },
+                                &mut fields,
+                            );
+                        }
+                        add(
+                            Field {
+                                name: name.clone(),
+                                fixed: ty.size.is_some() && nmemb.is_some(),
+                                wire: ty.kind != Kind::Fd,
+                                ty: Some(ty),
+                                kind: FieldKind::List(length.clone()),
+                                visible: true,
+                                nmemb: nmemb.unwrap_or(1),
+                                all_cases_u32: false,
+                            },
+                            &mut fields,
+                        );
+                    }
+                    FieldDecl::ExprField {
+                        ref name, ref ty, ..
+                    } => {
+                        let ty = self.resolved(module, ty)?;
+                        add(
+                            Field {
+                                name: name.clone(),
+                                fixed: ty.size.is_some(),
+                                ty: Some(ty),
+                                kind: FieldKind::Expr,
+                                wire: true,
+                                visible: false,
+                                nmemb: 1,
+                                all_cases_u32: false,
+                            },
+                            &mut fields,
+                        );
+                    }
+                    FieldDecl::Switch {
+                        ref name,
+                        ref expr,
+                        ref cases,
+                    } => {
+                        let mut all_cases_u32 = true;
+                        for case in cases {
+                            for field in case {
+                                all_cases_u32 = all_cases_u32
+                                    && match *field {
+                                        FieldDecl::Field { ref ty, .. } => {
+                                            self.resolved(module, ty)?.size == Some(4)
+                                        }
+                                        _ => false,
+                                    };
+                            }
+                        }
+                        add(
+                            Field {
+                                name: name.clone(),
+                                ty: None,
+                                kind: FieldKind::Switch(expr.clone()),
+                                wire: true,
+                                visible: true,
+                                fixed: false,
+                                nmemb: 1,
+                                all_cases_u32,
+                            },
+                            &mut fields,
+                        );
+                    }
+                    FieldDecl::Fd { ref name } => {
+                        add(
+                            Field {
+                                name: name.clone(),
+                                ty: Some(self.types["fd"].clone()),
+                                kind: FieldKind::Fd,
+                                wire: false,
+                                visible: true,
+                                fixed: true,
+                                nmemb: 1,
+                                all_cases_u32: false,
+                            },
+                            &mut fields,
+                        );
+                    }
+                    FieldDecl::ValueParam {
+                        ref mask_type,
+                        ref mask_name,
+                        ref list_name,
+                    } => {
+                        if !fields.iter().any(|f| &f.name == mask_name) {
+                            let ty = self.resolved(module, mask_type)?;
+                            add(
+                                Field {
+                                    name: mask_name.clone(),
+                                    ty: Some(ty),
+                                    kind: FieldKind::Plain,
+                                    wire: true,
+                                    visible: true,
+                                    fixed: true,
+                                    nmemb: 1,
+                                    all_cases_u32: false,
+                                },
+                                &mut fields,
+                            );
+                        }
+                        add(
+                            Field {
+                                name: list_name.clone(),
+                                ty: Some(card32.clone()),
+                                kind: FieldKind::List(Some(Expr::Popcount(Box::new(
+                                    Expr::FieldRef(mask_name.clone()),
+                                )))),
+                                wire: true,
+                                visible: true,
+                                fixed: false,
+                                nmemb: 1,
+                                all_cases_u32: false,
+                            },
+                            &mut fields,
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut packed = false;
+        if let Header::Event { xge: true, .. } = header {
+            // libxcb moves the full_sequence member of generic events behind the first 32
+            // bytes, where xcb_generic_event_t keeps it.
+            let mut event_size = 0;
+            for index in 0..fields.len() {
+                if let Some(size) = fields[index].size() {
+                    event_size += size;
+                }
+                if event_size == 32 {
+                    fields.insert(index + 1, Field::auto("full_sequence", &card32));
+                    packed = fields[index + 2..]
+                        .iter()
+                        .any(|f| f.ty.as_ref().and_then(|t| t.size) == Some(8));
+                    break;
+                }
+            }
+        }
+
+        Ok(Layout { fields, packed })
+    }
+
+    /// Looks up a type whose size has been determined already.
+    fn resolved(&self, module: usize, name: &str) -> Result<TypeInfo, Pending> {
+        let info = self
+            .lookup(module, name)
+            .unwrap_or_else(|e| panic!("{}", e));
+        if self.resolved.contains(&info.key) {
+            Ok(info)
+        } else {
+            Err(Pending)
+        }
+    }
+}
+
+/// Marker for layouts that depend on types whose size is not known yet.
+pub struct Pending;
@@ -0,0 +1,97 @@
+//! Generates the bindings in `src/proto` from the xcb-proto XML descriptions, and the keysyms
+//! from `keysymdef.h` in the same directory.
+//!
+//! Usage: `cargo run -p xcbgen -- <xml directory> <output directory>`
+
+mod emit;
+mod keysyms;
+mod layout;
+mod names;
+mod parse;
+mod xml;
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{self, Command};
+
+use emit::Emitter;
+use layout::Protocol;
+
+fn read_modules(directory: &Path) -> Result<Vec<parse::Module>, String> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(directory).map_err(|e| format!("{}: {}", directory.display(), e))? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.extension().is_some_and(|e| e == "xml") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut modules = Vec::new();
+    for path in paths {
+        let input = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let module = xml::parse(&input)
+            .and_then(|root| parse::parse_module(&root))
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        modules.push(module);
+    }
+    Ok(modules)
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), String> {
+    fs::File::create(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn run(xml_directory: &Path, output_directory: &Path) -> Result<(), String> {
+    let protocol = Protocol::new(read_modules(xml_directory)?)?;
+    let enum_types = emit::enum_types(&protocol);
+
+    let mut root = String::from("// Generated by xcbgen, do not edit.\n\n");
+    for (index, module) in protocol.modules.iter().enumerate() {
+        if emit::library(&module.header) != "xcb" {
+            root.push_str(&format!("#[cfg(feature = \"{}\")]\n", module.header));
+        }
+        root.push_str(&format!("pub mod {};\n", module.header));
+
+        let directory = output_directory.join(&module.header);
+        fs::create_dir_all(&directory).map_err(|e| format!("{}: {}", directory.display(), e))?;
+        let files = Emitter::new(&protocol, &enum_types, index).emit();
+        write_file(&directory.join("cdef.rs"), &files.cdef)?;
+        write_file(&directory.join("constants.rs"), &files.constants)?;
+        write_file(&directory.join("mod.rs"), &files.module)?;
+    }
+
+    let keysymdef = xml_directory.join("keysymdef.h");
+    let input =
+        fs::read_to_string(&keysymdef).map_err(|e| format!("{}: {}", keysymdef.display(), e))?;
+    let keysyms =
+        keysyms::generate(&input).map_err(|e| format!("{}: {}", keysymdef.display(), e))?;
+    write_file(&output_directory.join("keysym.rs"), &keysyms)?;
+    root.push_str("pub mod keysym;\n");
+
+    let root_path = output_directory.join("mod.rs");
+    write_file(&root_path, &root)?;
+
+    // rustfmt follows the module declarations and formats all generated files at once.
+    match Command::new("rustfmt").arg(&root_path).status() {
+        Ok(ref status) if status.success() => Ok(()),
+        _ => Err("running rustfmt on the generated files failed".to_string()),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("usage: {} <xml directory> <output directory>", args[0]);
+        process::exit(2);
+    }
+
+    if let Err(error) = run(Path::new(&args[1]), Path::new(&args[2])) {
+        eprintln!("xcbgen: {}", error);
+        process::exit(1);
+    }
+}
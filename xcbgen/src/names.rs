@@ -0,0 +1,111 @@
+/// Extension names that are split at capital letters instead of simply being lowercased.
+const SPLIT_EXTENSION_NAMES: &[&str] = &["XPrint", "XCMisc", "BigRequests"];
+
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+fn is_upper_or_digit(c: char) -> bool {
+    c.is_ascii_uppercase() || c.is_ascii_digit()
+}
+
+/// Splits a protocol name into its words the same way libxcb's generator does, e.g.
+/// `GetXIDList` becomes `Get`, `XID`, `List`.
+pub fn split_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if is_upper_or_digit(c) {
+            if i + 1 < chars.len() && chars[i + 1].is_ascii_lowercase() {
+                let mut end = i + 1;
+                while end < chars.len() && chars[end].is_ascii_lowercase() {
+                    end += 1;
+                }
+                words.push(chars[i..end].iter().collect());
+                i = end;
+            } else {
+                let mut end = i;
+                while end < chars.len() && is_upper_or_digit(chars[end]) {
+                    end += 1;
+                }
+                if end < chars.len() && chars[end].is_ascii_lowercase() {
+                    end -= 1;
+                }
+                words.push(chars[i..end].iter().collect());
+                i = end;
+            }
+        } else if c.is_ascii_lowercase() {
+            let mut end = i;
+            while end < chars.len() && chars[end].is_ascii_lowercase() {
+                end += 1;
+            }
+            words.push(chars[i..end].iter().collect());
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+
+    words
+}
+
+fn c_item(name: &str) -> String {
+    if name == "DECnet" {
+        return "decnet".to_string();
+    }
+    split_words(name).join("_").to_lowercase()
+}
+
+/// The identifier used for an extension in C names, e.g. `randr` for `RandR`.
+pub fn extension_prefix(extension_name: &str) -> String {
+    if SPLIT_EXTENSION_NAMES.contains(&extension_name) {
+        c_item(extension_name)
+    } else {
+        extension_name.to_lowercase()
+    }
+}
+
+/// Builds a lowercase C name such as `xcb_randr_get_crtc_info` from a namespace prefix and name
+/// parts.
+pub fn c_name(prefix: Option<&str>, parts: &[&str]) -> String {
+    let mut name = "xcb".to_string();
+    if let Some(prefix) = prefix {
+        name.push('_');
+        name.push_str(prefix);
+    }
+    for part in parts {
+        name.push('_');
+        name.push_str(&c_item(part));
+    }
+    name
+}
+
+/// Turns a lowercase C name into the name of the corresponding Rust type, e.g.
+/// `xcb_randr_get_crtc_info_reply` into `XCBRandrGetCrtcInfoReply`.
+pub fn rust_type_name(c_name: &str) -> String {
+    let mut name = "XCB".to_string();
+    for segment in c_name.trim_start_matches("xcb_").split('_') {
+        let mut chars = segment.chars();
+        if let Some(first) = chars.next() {
+            name.extend(first.to_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+    name
+}
+
+/// Makes a field or parameter name usable as a Rust identifier.
+pub fn rust_field_name(name: &str) -> String {
+    if RUST_KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
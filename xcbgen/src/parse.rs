@@ -0,0 +1,403 @@
+use xml::Element;
+
+/// A protocol description as read from one of the xcb-proto XML files.
+pub struct Module {
+    /// Name of the file without extension, e.g. `randr`.
+    pub header: String,
+    pub extension: Option<ExtensionInfo>,
+    pub imports: Vec<String>,
+    pub items: Vec<Item>,
+}
+
+pub struct ExtensionInfo {
+    /// Name the extension is queried by on the server, e.g. `RANDR`.
+    pub xname: String,
+    /// Name used to build identifiers, e.g. `RandR`.
+    pub name: String,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+
+pub enum Item {
+    XidType(String),
+    XidUnion(String),
+    Typedef { old: String, new: String },
+    Enum(Enum),
+    Struct(Complex),
+    Union(Complex),
+    EventStruct(EventStruct),
+    Request(Request),
+    Event(Event),
+    EventCopy(Copy),
+    Error(Error),
+    ErrorCopy(Copy),
+}
+
+pub struct Enum {
+    pub name: String,
+    pub items: Vec<(String, i64)>,
+}
+
+pub struct Complex {
+    pub name: String,
+    pub fields: Vec<FieldDecl>,
+}
+
+pub struct EventStruct {
+    pub name: String,
+    pub allowed: Vec<Allowed>,
+}
+
+pub struct Allowed {
+    pub extension: String,
+    pub xge: bool,
+    pub opcode_min: i64,
+    pub opcode_max: i64,
+}
+
+pub struct Request {
+    pub name: String,
+    pub opcode: i64,
+    pub fields: Vec<FieldDecl>,
+    pub reply: Option<Vec<FieldDecl>>,
+}
+
+pub struct Event {
+    pub name: String,
+    pub number: i64,
+    pub no_sequence_number: bool,
+    pub xge: bool,
+    pub fields: Vec<FieldDecl>,
+}
+
+pub struct Error {
+    pub name: String,
+    pub number: i64,
+    pub fields: Vec<FieldDecl>,
+}
+
+/// An `eventcopy` or `errorcopy`, which reuses the layout of another event or error.
+pub struct Copy {
+    pub name: String,
+    pub number: i64,
+    pub reference: String,
+}
+
+// The declarations are kept complete even where the generator does not use every detail yet.
+#[allow(dead_code)]
+pub enum FieldDecl {
+    Pad {
+        bytes: usize,
+        align: usize,
+    },
+    Field {
+        name: String,
+        ty: String,
+        enum_name: Option<String>,
+        mask: Option<String>,
+    },
+    List {
+        name: String,
+        ty: String,
+        length: Option<Expr>,
+    },
+    ExprField {
+        name: String,
+        ty: String,
+        expr: Expr,
+    },
+    Switch {
+        name: String,
+        expr: Expr,
+        cases: Vec<Vec<FieldDecl>>,
+    },
+    Fd {
+        name: String,
+    },
+    ValueParam {
+        mask_type: String,
+        mask_name: String,
+        list_name: String,
+    },
+}
+
+#[allow(dead_code)]
+#[derive(Clone)]
+pub enum Expr {
+    FieldRef(String),
+    ParamRef(String),
+    EnumRef(String, String),
+    Value(i64),
+    Bit(u32),
+    Op(String, Box<Expr>, Box<Expr>),
+    Unop(String, Box<Expr>),
+    Popcount(Box<Expr>),
+    SumOf(String),
+    ListElementRef,
+}
+
+pub fn parse_int(text: &str) -> Result<i64, String> {
+    let text = text.trim();
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text),
+    };
+    let value = if digits.starts_with("0x") || digits.starts_with("0X") {
+        i64::from_str_radix(&digits[2..], 16)
+    } else {
+        digits.parse::<i64>()
+    };
+
+    match value {
+        Ok(value) => Ok(if negative { -value } else { value }),
+        Err(_) => Err(format!("\"{}\" is not a number", text)),
+    }
+}
+
+fn int_attr(element: &Element, name: &str) -> Result<i64, String> {
+    parse_int(element.required_attr(name)?)
+}
+
+/// Reads a module from the root element of an xcb-proto XML file.
+pub fn parse_module(root: &Element) -> Result<Module, String> {
+    if root.name != "xcb" {
+        return Err(format!(
+            "expected <xcb> as root element, found <{}>",
+            root.name
+        ));
+    }
+
+    let extension = match root.attr("extension-xname") {
+        None => None,
+        Some(xname) => Some(ExtensionInfo {
+            xname: xname.to_string(),
+            name: root.required_attr("extension-name")?.to_string(),
+            major_version: root.attr("major-version").map_or(Ok(0), parse_int)? as u32,
+            minor_version: root.attr("minor-version").map_or(Ok(0), parse_int)? as u32,
+        }),
+    };
+
+    let mut module = Module {
+        header: root.required_attr("header")?.to_string(),
+        extension,
+        imports: Vec::new(),
+        items: Vec::new(),
+    };
+
+    for child in &root.children {
+        let item = match child.name.as_str() {
+            "import" => {
+                module.imports.push(child.text.trim().to_string());
+                continue;
+            }
+            "xidtype" => Item::XidType(child.required_attr("name")?.to_string()),
+            "xidunion" => Item::XidUnion(child.required_attr("name")?.to_string()),
+            "typedef" => Item::Typedef {
+                old: child.required_attr("oldname")?.to_string(),
+                new: child.required_attr("newname")?.to_string(),
+            },
+            "enum" => Item::Enum(parse_enum(child)?),
+            "struct" => Item::Struct(parse_complex(child)?),
+            "union" => Item::Union(parse_complex(child)?),
+            "eventstruct" => Item::EventStruct(parse_event_struct(child)?),
+            "request" => Item::Request(parse_request(child)?),
+            "event" => Item::Event(Event {
+                name: child.required_attr("name")?.to_string(),
+                number: int_attr(child, "number")?,
+                no_sequence_number: child.attr("no-sequence-number") == Some("true"),
+                xge: child.attr("xge") == Some("true"),
+                fields: parse_fields(child)?,
+            }),
+            "error" => Item::Error(Error {
+                name: child.required_attr("name")?.to_string(),
+                number: int_attr(child, "number")?,
+                fields: parse_fields(child)?,
+            }),
+            "eventcopy" => Item::EventCopy(parse_copy(child)?),
+            "errorcopy" => Item::ErrorCopy(parse_copy(child)?),
+            other => return Err(format!("unexpected top level element <{}>", other)),
+        };
+        module.items.push(item);
+    }
+
+    Ok(module)
+}
+
+fn parse_enum(element: &Element) -> Result<Enum, String> {
+    let mut items = Vec::new();
+    let mut next_value = 0;
+
+    for item in element.children.iter().filter(|e| e.name == "item") {
+        let value = match item.children.first() {
+            None => next_value,
+            Some(value) if value.name == "value" => parse_int(&value.text)?,
+            Some(bit) if bit.name == "bit" => 1 << parse_int(&bit.text)?,
+            Some(other) => return Err(format!("unexpected <{}> in enum item", other.name)),
+        };
+        next_value = value + 1;
+        items.push((item.required_attr("name")?.to_string(), value));
+    }
+
+    Ok(Enum {
+        name: element.required_attr("name")?.to_string(),
+        items,
+    })
+}
+
+fn parse_complex(element: &Element) -> Result<Complex, String> {
+    Ok(Complex {
+        name: element.required_attr("name")?.to_string(),
+        fields: parse_fields(element)?,
+    })
+}
+
+fn parse_event_struct(element: &Element) -> Result<EventStruct, String> {
+    let mut allowed = Vec::new();
+    for child in element.children.iter().filter(|e| e.name == "allowed") {
+        allowed.push(Allowed {
+            extension: child.required_attr("extension")?.to_string(),
+            xge: child.attr("xge") == Some("true"),
+            opcode_min: int_attr(child, "opcode-min")?,
+            opcode_max: int_attr(child, "opcode-max")?,
+        });
+    }
+
+    Ok(EventStruct {
+        name: element.required_attr("name")?.to_string(),
+        allowed,
+    })
+}
+
+fn parse_request(element: &Element) -> Result<Request, String> {
+    let reply = match element.children.iter().find(|e| e.name == "reply") {
+        None => None,
+        Some(reply) => Some(parse_fields(reply)?),
+    };
+
+    Ok(Request {
+        name: element.required_attr("name")?.to_string(),
+        opcode: int_attr(element, "opcode")?,
+        fields: parse_fields(element)?,
+        reply,
+    })
+}
+
+fn parse_copy(element: &Element) -> Result<Copy, String> {
+    Ok(Copy {
+        name: element.required_attr("name")?.to_string(),
+        number: int_attr(element, "number")?,
+        reference: element.required_attr("ref")?.to_string(),
+    })
+}
+
+fn parse_fields(element: &Element) -> Result<Vec<FieldDecl>, String> {
+    let mut fields = Vec::new();
+
+    for child in &element.children {
+        let field = match child.name.as_str() {
+            "pad" => FieldDecl::Pad {
+                bytes: child.attr("bytes").map_or(Ok(1), parse_int)? as usize,
+                align: child.attr("align").map_or(Ok(1), parse_int)? as usize,
+            },
+            "field" => FieldDecl::Field {
+                name: child.required_attr("name")?.to_string(),
+                ty: child.required_attr("type")?.to_string(),
+                enum_name: child
+                    .attr("enum")
+                    .or_else(|| child.attr("altenum"))
+                    .map(str::to_string),
+                mask: child
+                    .attr("mask")
+                    .or_else(|| child.attr("altmask"))
+                    .map(str::to_string),
+            },
+            "list" => FieldDecl::List {
+                name: child.required_attr("name")?.to_string(),
+                ty: child.required_attr("type")?.to_string(),
+                length: match child.children.first() {
+                    None => None,
+                    Some(expr) => Some(parse_expr(expr)?),
+                },
+            },
+            "exprfield" => FieldDecl::ExprField {
+                name: child.required_attr("name")?.to_string(),
+                ty: child.required_attr("type")?.to_string(),
+                expr: parse_expr(first_child(child)?)?,
+            },
+            "switch" => {
+                let mut cases = Vec::new();
+                for case in child
+                    .children
+                    .iter()
+                    .filter(|e| e.name == "bitcase" || e.name == "case")
+                {
+                    cases.push(parse_fields(case)?);
+                }
+                FieldDecl::Switch {
+                    name: child.required_attr("name")?.to_string(),
+                    expr: parse_expr(first_child(child)?)?,
+                    cases,
+                }
+            }
+            "fd" => FieldDecl::Fd {
+                name: child.required_attr("name")?.to_string(),
+            },
+            "valueparam" => FieldDecl::ValueParam {
+                mask_type: child.required_attr("value-mask-type")?.to_string(),
+                mask_name: child.required_attr("value-mask-name")?.to_string(),
+                list_name: child.required_attr("value-list-name")?.to_string(),
+            },
+            "doc"
+            | "reply"
+            | "required_start_align"
+            | "enumref"
+            | "fieldref"
+            | "bitcase"
+            | "case" => continue,
+            other => return Err(format!("unexpected field element <{}>", other)),
+        };
+        fields.push(field);
+    }
+
+    Ok(fields)
+}
+
+fn first_child(element: &Element) -> Result<&Element, String> {
+    element
+        .children
+        .first()
+        .ok_or_else(|| format!("<{}> is missing its expression", element.name))
+}
+
+fn parse_expr(element: &Element) -> Result<Expr, String> {
+    let expr = match element.name.as_str() {
+        "fieldref" => Expr::FieldRef(element.text.trim().to_string()),
+        "paramref" => Expr::ParamRef(element.text.trim().to_string()),
+        "enumref" => Expr::EnumRef(
+            element.required_attr("ref")?.to_string(),
+            element.text.trim().to_string(),
+        ),
+        "value" => Expr::Value(parse_int(&element.text)?),
+        "bit" => Expr::Bit(parse_int(&element.text)? as u32),
+        "op" => {
+            if element.children.len() != 2 {
+                return Err("<op> needs exactly two operands".to_string());
+            }
+            Expr::Op(
+                element.required_attr("op")?.to_string(),
+                Box::new(parse_expr(&element.children[0])?),
+                Box::new(parse_expr(&element.children[1])?),
+            )
+        }
+        "unop" => Expr::Unop(
+            element.required_attr("op")?.to_string(),
+            Box::new(parse_expr(first_child(element)?)?),
+        ),
+        "popcount" => Expr::Popcount(Box::new(parse_expr(first_child(element)?)?)),
+        "sumof" => Expr::SumOf(element.required_attr("ref")?.to_string()),
+        "listelement-ref" => Expr::ListElementRef,
+        other => return Err(format!("unexpected expression element <{}>", other)),
+    };
+
+    Ok(expr)
+}
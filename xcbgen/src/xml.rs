@@ -0,0 +1,254 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// An XML element together with its attributes, child elements and text content.
+///
+/// Only the subset of XML used by the xcb-proto descriptions is supported: elements,
+/// attributes, character data, CDATA sections, comments and processing instructions.
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Element>,
+    pub text: String,
+}
+
+impl Element {
+    /// Returns the value of the attribute with the given name.
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the value of the attribute with the given name or an error naming the element.
+    pub fn required_attr(&self, name: &str) -> Result<&str, String> {
+        self.attr(name)
+            .ok_or_else(|| format!("<{}> is missing the attribute \"{}\"", self.name, name))
+    }
+}
+
+/// Parses a complete document and returns its root element.
+pub fn parse(input: &str) -> Result<Element, String> {
+    let mut parser = Parser {
+        chars: input.chars().peekable(),
+        line: 1,
+    };
+
+    parser.skip_misc()?;
+    let root = parser.element()?;
+    parser.skip_misc()?;
+    match parser.chars.peek() {
+        None => Ok(root),
+        Some(_) => Err(parser.error("trailing content after the root element")),
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> String {
+        format!("line {}: {}", self.line, message)
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        if c == Some('\n') {
+            self.line += 1;
+        }
+        c
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(self.error(&format!("expected '{}', found '{}'", expected, c))),
+            None => Err(self.error(&format!("expected '{}', found end of input", expected))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(&c) = self.chars.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.next();
+        }
+    }
+
+    /// Consumes input up to and including `terminator`.
+    fn skip_until(&mut self, terminator: &str) -> Result<(), String> {
+        self.read_until(terminator).map(|_| ())
+    }
+
+    /// Consumes input up to and including `terminator` and returns what came before it.
+    fn read_until(&mut self, terminator: &str) -> Result<String, String> {
+        let mut window = String::new();
+        loop {
+            match self.next() {
+                None => {
+                    return Err(self.error(&format!("unterminated, expected \"{}\"", terminator)))
+                }
+                Some(c) => {
+                    window.push(c);
+                    if window.ends_with(terminator) {
+                        let length = window.len() - terminator.len();
+                        window.truncate(length);
+                        return Ok(window);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Skips whitespace, comments and processing instructions outside of the root element.
+    fn skip_misc(&mut self) -> Result<(), String> {
+        loop {
+            self.skip_whitespace();
+            if self.chars.peek() != Some(&'<') {
+                return Ok(());
+            }
+
+            let mut lookahead = self.chars.clone();
+            lookahead.next();
+            match lookahead.next() {
+                Some('?') => self.skip_until("?>")?,
+                Some('!') => self.skip_until("-->")?,
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let mut name = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c.is_alphanumeric() || c == '_' || c == '-' || c == ':' || c == '.' {
+                name.push(c);
+                self.next();
+            } else {
+                break;
+            }
+        }
+
+        if name.is_empty() {
+            Err(self.error("expected a name"))
+        } else {
+            Ok(name)
+        }
+    }
+
+    fn entity(&mut self) -> Result<char, String> {
+        let mut entity = String::new();
+        loop {
+            match self.next() {
+                Some(';') => break,
+                Some(c) => entity.push(c),
+                None => return Err(self.error("unterminated entity")),
+            }
+        }
+
+        match entity.as_str() {
+            "lt" => Ok('<'),
+            "gt" => Ok('>'),
+            "amp" => Ok('&'),
+            "quot" => Ok('"'),
+            "apos" => Ok('\''),
+            _ => Err(self.error(&format!("unknown entity \"&{};\"", entity))),
+        }
+    }
+
+    fn element(&mut self) -> Result<Element, String> {
+        self.expect('<')?;
+        let mut element = Element {
+            name: self.name()?,
+            attributes: Vec::new(),
+            children: Vec::new(),
+            text: String::new(),
+        };
+
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some(&'/') => {
+                    self.next();
+                    self.expect('>')?;
+                    return Ok(element);
+                }
+                Some(&'>') => {
+                    self.next();
+                    break;
+                }
+                Some(_) => {
+                    let key = self.name()?;
+                    self.skip_whitespace();
+                    self.expect('=')?;
+                    self.skip_whitespace();
+                    let quote = match self.next() {
+                        Some(c) if c == '"' || c == '\'' => c,
+                        _ => return Err(self.error("expected a quoted attribute value")),
+                    };
+                    let mut value = String::new();
+                    loop {
+                        match self.next() {
+                            Some(c) if c == quote => break,
+                            Some('&') => value.push(self.entity()?),
+                            Some(c) => value.push(c),
+                            None => return Err(self.error("unterminated attribute value")),
+                        }
+                    }
+                    element.attributes.push((key, value));
+                }
+                None => return Err(self.error("unterminated start tag")),
+            }
+        }
+
+        loop {
+            match self.chars.peek() {
+                Some(&'<') => {
+                    let mut lookahead = self.chars.clone();
+                    lookahead.next();
+                    match lookahead.next() {
+                        Some('/') => {
+                            self.next();
+                            self.next();
+                            let name = self.name()?;
+                            if name != element.name {
+                                return Err(self.error(&format!(
+                                    "</{}> does not match <{}>",
+                                    name, element.name
+                                )));
+                            }
+                            self.skip_whitespace();
+                            self.expect('>')?;
+                            return Ok(element);
+                        }
+                        Some('!') if lookahead.next() == Some('[') => {
+                            self.skip_until("<![CDATA[")?;
+                            let text = self.read_until("]]>")?;
+                            element.text.push_str(&text);
+                        }
+                        Some('!') => self.skip_until("-->")?,
+                        Some('?') => self.skip_until("?>")?,
+                        _ => {
+                            let child = self.element()?;
+                            element.children.push(child);
+                        }
+                    }
+                }
+                Some(&'&') => {
+                    self.next();
+                    let c = self.entity()?;
+                    element.text.push(c);
+                }
+                Some(_) => {
+                    let c = self.next().unwrap();
+                    element.text.push(c);
+                }
+                None => return Err(self.error(&format!("unterminated element <{}>", element.name))),
+            }
+        }
+    }
+}